@@ -23,7 +23,7 @@ use poulpy_bin_fhe::{
 };
 use poulpy_hal::{
     api::{ModuleN, ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{Backend, DeviceBuf, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, Backend, DeviceBuf, Module, Scratch, ScratchOwned},
     source::Source,
 };
 
@@ -74,15 +74,15 @@ where
     let mut source_xa: Source = Source::new([1u8; 32]);
     let mut source_xe: Source = Source::new([1u8; 32]);
 
-    let mut sk_lwe: LWESecret<Vec<u8>> = LWESecret::alloc(n_lwe);
+    let mut sk_lwe: LWESecret<AlignedBuf> = LWESecret::alloc(n_lwe);
     sk_lwe.fill_binary_block(params.block_size, &mut source_xs);
 
-    let mut sk_glwe: GLWESecret<Vec<u8>> = GLWESecret::alloc(n_glwe, rank);
+    let mut sk_glwe: GLWESecret<AlignedBuf> = GLWESecret::alloc(n_glwe, rank);
     sk_glwe.fill_ternary_prob(0.5, &mut source_xs);
 
     // Circuit bootstrapping evaluation key
     let cbt_enc_infos = CircuitBootstrappingEncryptionInfos::from_default_sigma(&params.bdd_layout.cbt_layout).unwrap();
-    let mut cbt_key: CircuitBootstrappingKey<Vec<u8>, BRA> =
+    let mut cbt_key: CircuitBootstrappingKey<AlignedBuf, BRA> =
         CircuitBootstrappingKey::alloc_from_infos(&params.bdd_layout.cbt_layout);
     cbt_key.encrypt_sk(
         &module,
@@ -103,7 +103,7 @@ where
 
     let bdd_enc_infos = BDDEncryptionInfos::from_default_sigma(&params.bdd_layout).unwrap();
     let glwe_enc_infos = EncryptionLayout::new_from_default_sigma(params.glwe_layout).unwrap();
-    let mut bdd_key: BDDKey<Vec<u8>, BRA> = BDDKey::alloc_from_infos(&params.bdd_layout);
+    let mut bdd_key: BDDKey<AlignedBuf, BRA> = BDDKey::alloc_from_infos(&params.bdd_layout);
     bdd_key.encrypt_sk(
         &module,
         &sk_lwe,
@@ -117,7 +117,7 @@ where
     let input_a = 255_u32;
     let input_b = 30_u32;
 
-    let mut a_enc: FheUint<Vec<u8>, u32> = FheUint::alloc_from_infos(&params.glwe_layout);
+    let mut a_enc: FheUint<AlignedBuf, u32> = FheUint::alloc_from_infos(&params.glwe_layout);
     a_enc.encrypt_sk(
         &module,
         input_a,
@@ -128,7 +128,7 @@ where
         scratch.borrow(),
     );
 
-    let mut b_enc: FheUint<Vec<u8>, u32> = FheUint::alloc_from_infos(&params.glwe_layout);
+    let mut b_enc: FheUint<AlignedBuf, u32> = FheUint::alloc_from_infos(&params.glwe_layout);
     b_enc.encrypt_sk(
         &module,
         input_b,
@@ -174,7 +174,7 @@ where
     Scratch<BE>: ScratchTakeCore<BE>,
     ScratchOwned<BE>: ScratchOwnedBorrow<BE>,
     F: Fn(
-        &mut FheUint<Vec<u8>, u32>,
+        &mut FheUint<AlignedBuf, u32>,
         &Module<BE>,
         &FheUintPrepared<DeviceBuf<BE>, u32, BE>,
         &FheUintPrepared<DeviceBuf<BE>, u32, BE>,
@@ -191,7 +191,7 @@ where
         glwe_layout,
     } = setup;
 
-    let mut c_enc: FheUint<Vec<u8>, u32> = FheUint::alloc_from_infos(&glwe_layout);
+    let mut c_enc: FheUint<AlignedBuf, u32> = FheUint::alloc_from_infos(&glwe_layout);
 
     move || {
         operation(
@@ -227,7 +227,7 @@ fn bench_operation<BE: Backend, BRA: BlindRotationAlgo, F>(
     ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
     Scratch<BE>: ScratchTakeCore<BE>,
     F: Fn(
-            &mut FheUint<Vec<u8>, u32>,
+            &mut FheUint<AlignedBuf, u32>,
             &Module<BE>,
             &FheUintPrepared<DeviceBuf<BE>, u32, BE>,
             &FheUintPrepared<DeviceBuf<BE>, u32, BE>,
@@ -23,7 +23,7 @@ use poulpy_core::{
 };
 use poulpy_hal::{
     api::{ModuleNew, ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{Backend, DeviceBuf, GaloisElement, Module, Scratch, ScratchOwned, ZnxViewMut},
+    layouts::{AlignedBuf, Backend, DeviceBuf, GaloisElement, Module, Scratch, ScratchOwned, ZnxViewMut},
     oep::HalImpl,
 };
 
@@ -84,10 +84,10 @@ where
 struct CkksBenchSetup<BE: CkksBenchBackend> {
     module: Module<BE>,
     scratch: ScratchOwned<BE>,
-    ct_a: CKKSCiphertext<Vec<u8>>,
-    ct_b: CKKSCiphertext<Vec<u8>>,
-    ct_dst: CKKSCiphertext<Vec<u8>>,
-    pt_znx: poulpy_ckks::layouts::plaintext::CKKSPlaintextVecZnx<Vec<u8>>,
+    ct_a: CKKSCiphertext<AlignedBuf>,
+    ct_b: CKKSCiphertext<AlignedBuf>,
+    ct_dst: CKKSCiphertext<AlignedBuf>,
+    pt_znx: poulpy_ckks::layouts::plaintext::CKKSPlaintextVecZnx<AlignedBuf>,
     pt_rnx: CKKSPlaintextVecRnx<f64>,
     cst_znx: CKKSPlaintextCstZnx,
     cst_rnx: CKKSPlaintextCstRnx<f64>,
@@ -135,7 +135,7 @@ fn atk_layout() -> EncryptionLayout<GLWEAutomorphismKeyLayout> {
     .unwrap()
 }
 
-fn reset_dst(dst: &mut CKKSCiphertext<Vec<u8>>) {
+fn reset_dst(dst: &mut CKKSCiphertext<AlignedBuf>) {
     dst.data_mut().raw_mut().fill(0);
     dst.set_meta_checked(ckks_meta()).unwrap();
 }
@@ -539,8 +539,8 @@ pub fn bench_ckks_automorphism<BE: CkksBenchBackend>(c: &mut Criterion, label: &
 
 pub fn bench_ckks_composite<BE: CkksBenchBackend>(c: &mut Criterion, label: &str) {
     let mut s = setup::<BE>();
-    let many_a: Vec<&CKKSCiphertext<Vec<u8>>> = (0..MANY_TERMS).map(|_| &s.ct_a).collect();
-    let many_b: Vec<&CKKSCiphertext<Vec<u8>>> = (0..MANY_TERMS).map(|_| &s.ct_b).collect();
+    let many_a: Vec<&CKKSCiphertext<AlignedBuf>> = (0..MANY_TERMS).map(|_| &s.ct_a).collect();
+    let many_b: Vec<&CKKSCiphertext<AlignedBuf>> = (0..MANY_TERMS).map(|_| &s.ct_b).collect();
     let pt_znxs: Vec<&_> = (0..MANY_TERMS).map(|_| &s.pt_znx).collect();
     let pt_rnxs: Vec<&_> = (0..MANY_TERMS).map(|_| &s.pt_rnx).collect();
     let cst_znxs: Vec<&_> = (0..MANY_TERMS).map(|_| &s.cst_znx).collect();
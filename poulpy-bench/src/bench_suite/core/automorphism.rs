@@ -7,7 +7,7 @@ use poulpy_core::{
 };
 use poulpy_hal::{
     api::{ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{Backend, DeviceBuf, Module, NoiseInfos, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, Backend, DeviceBuf, Module, NoiseInfos, Scratch, ScratchOwned},
     source::Source,
 };
 use std::hint::black_box;
@@ -42,13 +42,13 @@ pub fn bench_glwe_automorphism<BE: Backend>(
     let mut source_xa = Source::new([1u8; 32]);
     let mut source_xe = Source::new([2u8; 32]);
 
-    let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(atk_infos);
+    let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(atk_infos);
     sk.fill_ternary_prob(0.5, &mut source_xs);
 
     let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(atk_infos.rank_out());
     module.glwe_secret_prepare(&mut sk_prepared, &sk);
 
-    let mut atk: GLWEAutomorphismKey<Vec<u8>> = GLWEAutomorphismKey::alloc_from_infos(atk_infos);
+    let mut atk: GLWEAutomorphismKey<AlignedBuf> = GLWEAutomorphismKey::alloc_from_infos(atk_infos);
     let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
         module.glwe_automorphism_key_encrypt_sk_tmp_bytes(atk_infos)
             | module.glwe_encrypt_sk_tmp_bytes(glwe_infos)
@@ -70,8 +70,8 @@ pub fn bench_glwe_automorphism<BE: Backend>(
         module.glwe_automorphism_key_prepared_alloc_from_infos(&atk);
     module.glwe_automorphism_key_prepare(&mut atk_prepared, &atk, scratch.borrow());
 
-    let mut ct_in: GLWE<Vec<u8>> = GLWE::alloc_from_infos(glwe_infos);
-    let mut ct_out: GLWE<Vec<u8>> = GLWE::alloc_from_infos(glwe_infos);
+    let mut ct_in: GLWE<AlignedBuf> = GLWE::alloc_from_infos(glwe_infos);
+    let mut ct_out: GLWE<AlignedBuf> = GLWE::alloc_from_infos(glwe_infos);
 
     let glwe_enc_infos = NoiseInfos::new(glwe_infos.max_k().as_usize(), DEFAULT_SIGMA_XE, DEFAULT_BOUND_XE).unwrap();
     module.glwe_encrypt_zero_sk(
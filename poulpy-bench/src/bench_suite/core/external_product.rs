@@ -7,7 +7,7 @@ use poulpy_core::{
 };
 use poulpy_hal::{
     api::{ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{Backend, DeviceBuf, Module, NoiseInfos, ScalarZnx, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, Backend, DeviceBuf, Module, NoiseInfos, ScalarZnx, Scratch, ScratchOwned},
     source::Source,
 };
 use std::hint::black_box;
@@ -36,16 +36,16 @@ pub fn bench_glwe_external_product<BE: Backend>(
     let mut source_xa = Source::new([1u8; 32]);
     let mut source_xe = Source::new([2u8; 32]);
 
-    let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(ggsw_infos);
+    let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(ggsw_infos);
     sk.fill_ternary_prob(0.5, &mut source_xs);
 
     let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(ggsw_infos.rank());
     module.glwe_secret_prepare(&mut sk_prepared, &sk);
 
     let pt = ScalarZnx::alloc(n, 1);
-    let mut ct_ggsw: GGSW<Vec<u8>> = GGSW::alloc_from_infos(ggsw_infos);
-    let mut ct_glwe_in: GLWE<Vec<u8>> = GLWE::alloc_from_infos(glwe_infos);
-    let mut ct_glwe_out: GLWE<Vec<u8>> = GLWE::alloc_from_infos(glwe_infos);
+    let mut ct_ggsw: GGSW<AlignedBuf> = GGSW::alloc_from_infos(ggsw_infos);
+    let mut ct_glwe_in: GLWE<AlignedBuf> = GLWE::alloc_from_infos(glwe_infos);
+    let mut ct_glwe_out: GLWE<AlignedBuf> = GLWE::alloc_from_infos(glwe_infos);
 
     let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
         module.ggsw_encrypt_sk_tmp_bytes(ggsw_infos)
@@ -106,15 +106,15 @@ where
     let mut source_xa = Source::new([1u8; 32]);
     let mut source_xe = Source::new([2u8; 32]);
 
-    let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(infos);
+    let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(infos);
     sk.fill_ternary_prob(0.5, &mut source_xs);
 
     let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(infos.rank());
     module.glwe_secret_prepare(&mut sk_prepared, &sk);
 
     let pt = ScalarZnx::alloc(n, 1);
-    let mut ct_ggsw: GGSW<Vec<u8>> = GGSW::alloc_from_infos(infos);
-    let mut ct_glwe: GLWE<Vec<u8>> = GLWE::alloc_from_infos(infos);
+    let mut ct_ggsw: GGSW<AlignedBuf> = GGSW::alloc_from_infos(infos);
+    let mut ct_glwe: GLWE<AlignedBuf> = GLWE::alloc_from_infos(infos);
 
     let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
         module.ggsw_encrypt_sk_tmp_bytes(infos)
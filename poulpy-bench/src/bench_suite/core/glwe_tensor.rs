@@ -10,7 +10,7 @@ use poulpy_hal::{
         CnvPVecAlloc, Convolution, ModuleNew, ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow, ScratchTakeBasic,
         VecZnxBigNormalize, VecZnxCopy, VecZnxIdftApplyConsume, VecZnxSubAssign,
     },
-    layouts::{Backend, Module, Scratch, ScratchOwned, VecZnx},
+    layouts::{AlignedBuf, Backend, Module, Scratch, ScratchOwned, VecZnx},
 };
 
 #[inline]
@@ -46,9 +46,9 @@ where
     let n: usize = glwe_infos.n().into();
     let module = Module::<BE>::new(n as u64);
 
-    let a = GLWE::<Vec<u8>>::alloc_from_infos(glwe_infos);
-    let b = GLWE::<Vec<u8>>::alloc_from_infos(glwe_infos);
-    let mut tensor = GLWETensor::<Vec<u8>>::alloc_from_infos(glwe_infos);
+    let a = GLWE::<AlignedBuf>::alloc_from_infos(glwe_infos);
+    let b = GLWE::<AlignedBuf>::alloc_from_infos(glwe_infos);
+    let mut tensor = GLWETensor::<AlignedBuf>::alloc_from_infos(glwe_infos);
     let mut scratch = ScratchOwned::<BE>::alloc(module.glwe_tensor_apply_tmp_bytes(&tensor, &a, &b));
 
     let group_name = format!("glwe_tensor_apply::{label}");
@@ -79,9 +79,9 @@ where
     let n: usize = glwe_infos.n().into();
     let module = Module::<BE>::new(n as u64);
 
-    let a = GLWE::<Vec<u8>>::alloc_from_infos(glwe_infos);
-    let b = GLWE::<Vec<u8>>::alloc_from_infos(glwe_infos);
-    let mut tensor = GLWETensor::<Vec<u8>>::alloc_from_infos(glwe_infos);
+    let a = GLWE::<AlignedBuf>::alloc_from_infos(glwe_infos);
+    let b = GLWE::<AlignedBuf>::alloc_from_infos(glwe_infos);
+    let mut tensor = GLWETensor::<AlignedBuf>::alloc_from_infos(glwe_infos);
     let mut scratch = ScratchOwned::<BE>::alloc(module.glwe_tensor_apply_tmp_bytes(&tensor, &a, &b));
 
     let group_name = format!("glwe_tensor_apply_add_assign::{label}");
@@ -113,7 +113,7 @@ where
     let cols: usize = (glwe_infos.rank() + 1).into();
     let module = Module::<BE>::new(n as u64);
 
-    let a = GLWE::<Vec<u8>>::alloc_from_infos(glwe_infos);
+    let a = GLWE::<AlignedBuf>::alloc_from_infos(glwe_infos);
     let a_mask = msb_mask_bottom_limb(glwe_infos.base2k().as_usize(), a.max_k().as_usize());
     let mut a_prep = module.cnv_pvec_left_alloc(cols, a.size());
     let mut scratch = ScratchOwned::<BE>::alloc(module.cnv_prepare_left_tmp_bytes(a.size(), a.size()));
@@ -139,7 +139,7 @@ where
     let cols: usize = (glwe_infos.rank() + 1).into();
     let module = Module::<BE>::new(n as u64);
 
-    let b = GLWE::<Vec<u8>>::alloc_from_infos(glwe_infos);
+    let b = GLWE::<AlignedBuf>::alloc_from_infos(glwe_infos);
     let b_mask = msb_mask_bottom_limb(glwe_infos.base2k().as_usize(), b.max_k().as_usize());
     let mut b_prep = module.cnv_pvec_right_alloc(cols, b.size());
     let mut scratch = ScratchOwned::<BE>::alloc(module.cnv_prepare_right_tmp_bytes(b.size(), b.size()));
@@ -170,9 +170,9 @@ where
     let cols: usize = (glwe_infos.rank() + 1).into();
     let module = Module::<BE>::new(n as u64);
 
-    let a = GLWE::<Vec<u8>>::alloc_from_infos(glwe_infos);
-    let b = GLWE::<Vec<u8>>::alloc_from_infos(glwe_infos);
-    let tensor = GLWETensor::<Vec<u8>>::alloc_from_infos(glwe_infos);
+    let a = GLWE::<AlignedBuf>::alloc_from_infos(glwe_infos);
+    let b = GLWE::<AlignedBuf>::alloc_from_infos(glwe_infos);
+    let tensor = GLWETensor::<AlignedBuf>::alloc_from_infos(glwe_infos);
     let base2k = glwe_infos.base2k().as_usize();
     let (cnv_offset_hi, cnv_offset_lo) = (0, -(base2k as i64));
     let diag_dft_size = normalize_input_limb_bound_with_offset(
@@ -234,9 +234,9 @@ where
 
     let module = Module::<BE>::new(n as u64);
 
-    let a = GLWE::<Vec<u8>>::alloc_from_infos(glwe_infos);
-    let b = GLWE::<Vec<u8>>::alloc_from_infos(glwe_infos);
-    let tensor = GLWETensor::<Vec<u8>>::alloc_from_infos(glwe_infos);
+    let a = GLWE::<AlignedBuf>::alloc_from_infos(glwe_infos);
+    let b = GLWE::<AlignedBuf>::alloc_from_infos(glwe_infos);
+    let tensor = GLWETensor::<AlignedBuf>::alloc_from_infos(glwe_infos);
     let base2k = glwe_infos.base2k().as_usize();
     let (cnv_offset_hi, cnv_offset_lo) = (0, -(base2k as i64));
     let pairwise_dft_size = normalize_input_limb_bound_with_offset(
@@ -301,8 +301,8 @@ where
     let n: usize = glwe_infos.n().into();
     let module = Module::<BE>::new(n as u64);
 
-    let a = GLWE::<Vec<u8>>::alloc_from_infos(glwe_infos);
-    let mut tensor = GLWETensor::<Vec<u8>>::alloc_from_infos(glwe_infos);
+    let a = GLWE::<AlignedBuf>::alloc_from_infos(glwe_infos);
+    let mut tensor = GLWETensor::<AlignedBuf>::alloc_from_infos(glwe_infos);
     let mut scratch = ScratchOwned::<BE>::alloc(module.glwe_tensor_square_apply_tmp_bytes(&tensor, &a));
 
     let group_name = format!("glwe_tensor_square_apply::{label}");
@@ -7,7 +7,7 @@ use poulpy_core::{
 };
 use poulpy_hal::{
     api::{ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{Backend, DeviceBuf, Module, NoiseInfos, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, Backend, DeviceBuf, Module, NoiseInfos, Scratch, ScratchOwned},
     source::Source,
 };
 
@@ -34,9 +34,9 @@ pub fn bench_glwe_keyswitch<BE: Backend>(
     let n: usize = gglwe.n().into();
     let module: Module<BE> = Module::<BE>::new(n as u64);
 
-    let mut ksk: GLWESwitchingKey<Vec<u8>> = GLWESwitchingKey::alloc_from_infos(gglwe);
-    let mut ct_in: GLWE<Vec<u8>> = GLWE::alloc_from_infos(glwe_in);
-    let mut ct_out: GLWE<Vec<u8>> = GLWE::alloc_from_infos(glwe_out);
+    let mut ksk: GLWESwitchingKey<AlignedBuf> = GLWESwitchingKey::alloc_from_infos(gglwe);
+    let mut ct_in: GLWE<AlignedBuf> = GLWE::alloc_from_infos(glwe_in);
+    let mut ct_out: GLWE<AlignedBuf> = GLWE::alloc_from_infos(glwe_out);
 
     let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
         module.glwe_switching_key_encrypt_sk_tmp_bytes(gglwe)
@@ -48,13 +48,13 @@ pub fn bench_glwe_keyswitch<BE: Backend>(
     let mut source_xe: Source = Source::new([1u8; 32]);
     let mut source_xa: Source = Source::new([2u8; 32]);
 
-    let mut sk_in: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(glwe_in);
+    let mut sk_in: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(glwe_in);
     sk_in.fill_ternary_prob(0.5, &mut source_xs);
 
     let mut sk_in_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(glwe_in.rank());
     module.glwe_secret_prepare(&mut sk_in_prepared, &sk_in);
 
-    let mut sk_out: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(glwe_out);
+    let mut sk_out: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(glwe_out);
     sk_out.fill_ternary_prob(0.5, &mut source_xs);
 
     let ksk_enc_infos = NoiseInfos::new(gglwe.max_k().as_usize(), DEFAULT_SIGMA_XE, DEFAULT_BOUND_XE).unwrap();
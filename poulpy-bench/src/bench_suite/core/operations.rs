@@ -4,7 +4,7 @@ use poulpy_core::{
 };
 use poulpy_hal::{
     api::{ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{Backend, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, Backend, Module, Scratch, ScratchOwned},
 };
 use std::hint::black_box;
 
@@ -17,9 +17,9 @@ where
     let n: usize = infos.n().into();
     let module: Module<BE> = Module::<BE>::new(n as u64);
 
-    let mut res: GLWE<Vec<u8>> = GLWE::alloc_from_infos(infos);
-    let a: GLWE<Vec<u8>> = GLWE::alloc_from_infos(infos);
-    let b: GLWE<Vec<u8>> = GLWE::alloc_from_infos(infos);
+    let mut res: GLWE<AlignedBuf> = GLWE::alloc_from_infos(infos);
+    let a: GLWE<AlignedBuf> = GLWE::alloc_from_infos(infos);
+    let b: GLWE<AlignedBuf> = GLWE::alloc_from_infos(infos);
 
     let group_name = format!("glwe_add_into::{label}");
     let mut group = c.benchmark_group(group_name);
@@ -39,8 +39,8 @@ where
     let n: usize = infos.n().into();
     let module: Module<BE> = Module::<BE>::new(n as u64);
 
-    let mut res: GLWE<Vec<u8>> = GLWE::alloc_from_infos(infos);
-    let b: GLWE<Vec<u8>> = GLWE::alloc_from_infos(infos);
+    let mut res: GLWE<AlignedBuf> = GLWE::alloc_from_infos(infos);
+    let b: GLWE<AlignedBuf> = GLWE::alloc_from_infos(infos);
 
     let group_name = format!("glwe_add_assign::{label}");
     let mut group = c.benchmark_group(group_name);
@@ -60,9 +60,9 @@ where
     let n: usize = infos.n().into();
     let module: Module<BE> = Module::<BE>::new(n as u64);
 
-    let mut res: GLWE<Vec<u8>> = GLWE::alloc_from_infos(infos);
-    let a: GLWE<Vec<u8>> = GLWE::alloc_from_infos(infos);
-    let b: GLWE<Vec<u8>> = GLWE::alloc_from_infos(infos);
+    let mut res: GLWE<AlignedBuf> = GLWE::alloc_from_infos(infos);
+    let a: GLWE<AlignedBuf> = GLWE::alloc_from_infos(infos);
+    let b: GLWE<AlignedBuf> = GLWE::alloc_from_infos(infos);
 
     let group_name = format!("glwe_sub::{label}");
     let mut group = c.benchmark_group(group_name);
@@ -82,8 +82,8 @@ where
     let n: usize = infos.n().into();
     let module: Module<BE> = Module::<BE>::new(n as u64);
 
-    let mut res: GLWE<Vec<u8>> = GLWE::alloc_from_infos(infos);
-    let b: GLWE<Vec<u8>> = GLWE::alloc_from_infos(infos);
+    let mut res: GLWE<AlignedBuf> = GLWE::alloc_from_infos(infos);
+    let b: GLWE<AlignedBuf> = GLWE::alloc_from_infos(infos);
 
     let group_name = format!("glwe_sub_assign::{label}");
     let mut group = c.benchmark_group(group_name);
@@ -105,8 +105,8 @@ where
     let n: usize = infos.n().into();
     let module: Module<BE> = Module::<BE>::new(n as u64);
 
-    let mut res: GLWE<Vec<u8>> = GLWE::alloc_from_infos(infos);
-    let a: GLWE<Vec<u8>> = GLWE::alloc_from_infos(infos);
+    let mut res: GLWE<AlignedBuf> = GLWE::alloc_from_infos(infos);
+    let a: GLWE<AlignedBuf> = GLWE::alloc_from_infos(infos);
     let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(module.glwe_normalize_tmp_bytes());
 
     let group_name = format!("glwe_normalize::{label}");
@@ -129,7 +129,7 @@ where
     let n: usize = infos.n().into();
     let module: Module<BE> = Module::<BE>::new(n as u64);
 
-    let mut res: GLWE<Vec<u8>> = GLWE::alloc_from_infos(infos);
+    let mut res: GLWE<AlignedBuf> = GLWE::alloc_from_infos(infos);
     let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(module.glwe_normalize_tmp_bytes());
 
     let group_name = format!("glwe_normalize_assign::{label}");
@@ -152,9 +152,9 @@ where
     let n: usize = infos.n().into();
     let module: Module<BE> = Module::<BE>::new(n as u64);
 
-    let mut ct_out: GLWE<Vec<u8>> = GLWE::alloc_from_infos(infos);
-    let ct_in: GLWE<Vec<u8>> = GLWE::alloc_from_infos(infos);
-    let pt: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(infos);
+    let mut ct_out: GLWE<AlignedBuf> = GLWE::alloc_from_infos(infos);
+    let ct_in: GLWE<AlignedBuf> = GLWE::alloc_from_infos(infos);
+    let pt: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(infos);
     let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(module.glwe_mul_plain_tmp_bytes(infos, infos, infos));
 
     let group_name = format!("glwe_mul_plain::{label}");
@@ -185,8 +185,8 @@ where
     let n: usize = infos.n().into();
     let module: Module<BE> = Module::<BE>::new(n as u64);
 
-    let mut ct: GLWE<Vec<u8>> = GLWE::alloc_from_infos(infos);
-    let pt: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(infos);
+    let mut ct: GLWE<AlignedBuf> = GLWE::alloc_from_infos(infos);
+    let pt: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(infos);
     let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(module.glwe_mul_plain_tmp_bytes(infos, infos, infos));
 
     let group_name = format!("glwe_mul_plain_assign::{label}");
@@ -4,7 +4,7 @@ use criterion::{BenchmarkId, Criterion};
 
 use poulpy_hal::{
     api::{CnvPVecAlloc, Convolution, ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxBigAlloc, VecZnxDftAlloc},
-    layouts::{Backend, CnvPVecL, CnvPVecR, DeviceBuf, FillUniform, Module, ScratchOwned, VecZnx, VecZnxBig},
+    layouts::{AlignedBuf, Backend, CnvPVecL, CnvPVecR, DeviceBuf, FillUniform, Module, ScratchOwned, VecZnx, VecZnxBig},
     source::Source,
 };
 
@@ -32,7 +32,7 @@ where
 
         let mut a_prep: CnvPVecL<DeviceBuf<BE>, BE> = module.cnv_pvec_left_alloc(1, size);
 
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), 1, size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), 1, size);
 
         a.fill_uniform(base2k, &mut source);
 
@@ -79,7 +79,7 @@ where
 
         let mut a_prep: CnvPVecR<DeviceBuf<BE>, BE> = module.cnv_pvec_right_alloc(1, size);
 
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), 1, size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), 1, size);
 
         a.fill_uniform(base2k, &mut source);
 
@@ -127,8 +127,8 @@ where
         let mut a_prep: CnvPVecL<DeviceBuf<BE>, BE> = module.cnv_pvec_left_alloc(1, size);
         let mut b_prep: CnvPVecR<DeviceBuf<BE>, BE> = module.cnv_pvec_right_alloc(1, size);
 
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), 1, size);
-        let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), 1, size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), 1, size);
+        let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), 1, size);
         let mut c_dft = module.vec_znx_dft_alloc(1, c_size);
 
         a.fill_uniform(base2k, &mut source);
@@ -185,8 +185,8 @@ where
         let mut a_prep: CnvPVecL<DeviceBuf<BE>, BE> = module.cnv_pvec_left_alloc(cols, size);
         let mut b_prep: CnvPVecR<DeviceBuf<BE>, BE> = module.cnv_pvec_right_alloc(cols, size);
 
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), cols, size);
-        let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), cols, size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), cols, size);
+        let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), cols, size);
         let mut c_dft = module.vec_znx_dft_alloc(1, c_size);
 
         a.fill_uniform(base2k, &mut source);
@@ -240,7 +240,7 @@ where
         let cols = 2;
         let c_size: usize = size + size - 1;
 
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), cols, size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), cols, size);
         let mut c_big: VecZnxBig<DeviceBuf<BE>, BE> = module.vec_znx_big_alloc(1, c_size);
 
         a.fill_uniform(base2k, &mut source);
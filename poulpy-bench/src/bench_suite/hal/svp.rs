@@ -5,7 +5,7 @@ use rand::Rng;
 
 use poulpy_hal::{
     api::{ModuleNew, SvpApplyDft, SvpApplyDftToDft, SvpApplyDftToDftAssign, SvpPPolAlloc, SvpPrepare, VecZnxDftAlloc},
-    layouts::{Backend, DataViewMut, DeviceBuf, FillUniform, Module, ScalarZnx, SvpPPol, VecZnx, VecZnxDft},
+    layouts::{AlignedBuf, Backend, DataViewMut, DeviceBuf, FillUniform, Module, ScalarZnx, SvpPPol, VecZnx, VecZnxDft},
     source::Source,
 };
 
@@ -28,7 +28,7 @@ where
         let cols: usize = 2;
 
         let mut svp: SvpPPol<DeviceBuf<B>, B> = module.svp_ppol_alloc(cols);
-        let mut a: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(module.n(), cols);
+        let mut a: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(module.n(), cols);
         let mut source = Source::new([0u8; 32]);
         a.fill_uniform(50, &mut source);
 
@@ -69,7 +69,7 @@ where
 
         let mut svp: SvpPPol<DeviceBuf<B>, B> = module.svp_ppol_alloc(cols);
         let mut res: VecZnxDft<DeviceBuf<B>, B> = module.vec_znx_dft_alloc(cols, size);
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
 
         let mut source = Source::new([0u8; 32]);
 
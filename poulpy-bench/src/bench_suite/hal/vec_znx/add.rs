@@ -4,7 +4,7 @@ use criterion::{BenchmarkId, Criterion};
 
 use poulpy_hal::{
     api::{ModuleNew, VecZnxAddAssign, VecZnxAddInto},
-    layouts::{Backend, FillUniform, Module, VecZnx},
+    layouts::{AlignedBuf, Backend, FillUniform, Module, VecZnx},
     source::Source,
 };
 
@@ -28,9 +28,9 @@ where
 
         let mut source: Source = Source::new([0u8; 32]);
 
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
-        let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
-        let mut c: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
+        let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
+        let mut c: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
 
         // Fill a with random i64
         a.fill_uniform(50, &mut source);
@@ -73,8 +73,8 @@ where
 
         let mut source: Source = Source::new([0u8; 32]);
 
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
-        let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
+        let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
 
         // Fill a with random i64
         a.fill_uniform(50, &mut source);
@@ -7,7 +7,7 @@ use poulpy_hal::{
         ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxAutomorphism, VecZnxAutomorphismAssign,
         VecZnxAutomorphismAssignTmpBytes,
     },
-    layouts::{Backend, FillUniform, Module, ScratchOwned, VecZnx},
+    layouts::{AlignedBuf, Backend, FillUniform, Module, ScratchOwned, VecZnx},
     source::Source,
 };
 
@@ -31,8 +31,8 @@ where
 
         let mut source: Source = Source::new([0u8; 32]);
 
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
-        let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
+        let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
 
         // Fill a with random i64
         a.fill_uniform(50, &mut source);
@@ -77,7 +77,7 @@ where
 
         let mut source: Source = Source::new([0u8; 32]);
 
-        let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
+        let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
 
         let mut scratch = ScratchOwned::alloc(module.vec_znx_automorphism_assign_tmp_bytes());
 
@@ -4,7 +4,7 @@ use criterion::{BenchmarkId, Criterion};
 
 use poulpy_hal::{
     api::{ModuleNew, VecZnxNegate, VecZnxNegateAssign},
-    layouts::{Backend, FillUniform, Module, VecZnx},
+    layouts::{AlignedBuf, Backend, FillUniform, Module, VecZnx},
     source::Source,
 };
 
@@ -28,8 +28,8 @@ where
 
         let mut source: Source = Source::new([0u8; 32]);
 
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
-        let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
+        let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
 
         // Fill a with random i64
         a.fill_uniform(50, &mut source);
@@ -72,7 +72,7 @@ where
 
         let mut source: Source = Source::new([0u8; 32]);
 
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
 
         // Fill a with random i64
         a.fill_uniform(50, &mut source);
@@ -4,7 +4,7 @@ use criterion::{BenchmarkId, Criterion};
 
 use poulpy_hal::{
     api::{ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxRotate, VecZnxRotateAssign, VecZnxRotateAssignTmpBytes},
-    layouts::{Backend, FillUniform, Module, ScratchOwned, VecZnx},
+    layouts::{AlignedBuf, Backend, FillUniform, Module, ScratchOwned, VecZnx},
     source::Source,
 };
 
@@ -28,8 +28,8 @@ where
 
         let mut source: Source = Source::new([0u8; 32]);
 
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
-        let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
+        let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
 
         // Fill a with random i64
         a.fill_uniform(50, &mut source);
@@ -74,7 +74,7 @@ where
 
         let mut source: Source = Source::new([0u8; 32]);
 
-        let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
+        let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
 
         let mut scratch = ScratchOwned::alloc(module.vec_znx_rotate_assign_tmp_bytes());
 
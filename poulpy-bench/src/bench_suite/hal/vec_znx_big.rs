@@ -11,7 +11,9 @@ use poulpy_hal::{
         VecZnxBigNormalizeTmpBytes, VecZnxBigSub, VecZnxBigSubAssign, VecZnxBigSubNegateAssign, VecZnxBigSubSmallA,
         VecZnxBigSubSmallB,
     },
-    layouts::{Backend, DataView, DataViewMut, DeviceBuf, Module, ScratchOwned, VecZnx, VecZnxBig, ZnxView, ZnxViewMut},
+    layouts::{
+        AlignedBuf, Backend, DataView, DataViewMut, DeviceBuf, Module, ScratchOwned, VecZnx, VecZnxBig, ZnxView, ZnxViewMut,
+    },
     source::Source,
 };
 
@@ -126,7 +128,7 @@ where
         let mut source: Source = Source::new([0u8; 32]);
 
         let mut a: VecZnxBig<DeviceBuf<B>, B> = module.vec_znx_big_alloc(cols, size);
-        let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
+        let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
         let mut c: VecZnxBig<DeviceBuf<B>, B> = module.vec_znx_big_alloc(cols, size);
 
         // Fill a with random i64
@@ -171,7 +173,7 @@ where
 
         let mut source: Source = Source::new([0u8; 32]);
 
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
         let mut c: VecZnxBig<DeviceBuf<B>, B> = module.vec_znx_big_alloc(cols, size);
 
         // Fill a with random i64
@@ -394,7 +396,7 @@ where
         let mut source: Source = Source::new([0u8; 32]);
 
         let mut a: VecZnxBig<DeviceBuf<B>, B> = module.vec_znx_big_alloc(cols, size);
-        let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), cols, size);
+        let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), cols, size);
 
         // Fill a with random i64
         source.fill_bytes(a.data_mut().as_mut());
@@ -441,7 +443,7 @@ where
         let mut source: Source = Source::new([0u8; 32]);
 
         let mut a: VecZnxBig<DeviceBuf<B>, B> = module.vec_znx_big_alloc(cols, size);
-        let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), cols, size);
+        let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), cols, size);
 
         source.fill_bytes(a.data_mut().as_mut());
         source.fill_bytes(res.data_mut().as_mut());
@@ -487,7 +489,7 @@ where
         let mut source: Source = Source::new([0u8; 32]);
 
         let mut a: VecZnxBig<DeviceBuf<B>, B> = module.vec_znx_big_alloc(cols, size);
-        let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), cols, size);
+        let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), cols, size);
 
         source.fill_bytes(a.data_mut().as_mut());
         source.fill_bytes(res.data_mut().as_mut());
@@ -531,9 +533,9 @@ pub fn bench_vec_znx_normalize_add_assign_compare<B: Backend>(
         let mut source: Source = Source::new([0u8; 32]);
 
         let mut a: VecZnxBig<DeviceBuf<B>, B> = module.vec_znx_big_alloc(cols, size);
-        let mut res_fused: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), cols, size);
-        let mut res_fallback: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), cols, size);
-        let mut tmp: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), 1, size);
+        let mut res_fused: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), cols, size);
+        let mut res_fallback: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), cols, size);
+        let mut tmp: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), 1, size);
 
         source.fill_bytes(a.data_mut().as_mut());
         source.fill_bytes(res_fused.data_mut().as_mut());
@@ -591,9 +593,9 @@ pub fn bench_vec_znx_normalize_sub_assign_compare<B: Backend>(
         let mut source: Source = Source::new([0u8; 32]);
 
         let mut a: VecZnxBig<DeviceBuf<B>, B> = module.vec_znx_big_alloc(cols, size);
-        let mut res_fused: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), cols, size);
-        let mut res_fallback: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), cols, size);
-        let mut tmp: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), 1, size);
+        let mut res_fused: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), cols, size);
+        let mut res_fallback: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), cols, size);
+        let mut tmp: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), 1, size);
 
         source.fill_bytes(a.data_mut().as_mut());
         source.fill_bytes(res_fused.data_mut().as_mut());
@@ -781,7 +783,7 @@ where
 
         let mut source: Source = Source::new([0u8; 32]);
 
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), cols, size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), cols, size);
         let mut b: VecZnxBig<DeviceBuf<B>, B> = module.vec_znx_big_alloc(cols, size);
         let mut c: VecZnxBig<DeviceBuf<B>, B> = module.vec_znx_big_alloc(cols, size);
 
@@ -827,7 +829,7 @@ where
         let mut source: Source = Source::new([0u8; 32]);
 
         let mut a: VecZnxBig<DeviceBuf<B>, B> = module.vec_znx_big_alloc(cols, size);
-        let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), cols, size);
+        let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), cols, size);
         let mut c: VecZnxBig<DeviceBuf<B>, B> = module.vec_znx_big_alloc(cols, size);
 
         // Fill a with random bytes
@@ -9,7 +9,7 @@ use poulpy_hal::{
         VecZnxDftApply, VecZnxDftSub, VecZnxDftSubAssign, VecZnxDftSubNegateAssign, VecZnxIdftApply, VecZnxIdftApplyTmpA,
         VecZnxIdftApplyTmpBytes,
     },
-    layouts::{Backend, DataViewMut, DeviceBuf, Module, ScratchOwned, VecZnx, VecZnxBig, VecZnxDft},
+    layouts::{AlignedBuf, Backend, DataViewMut, DeviceBuf, Module, ScratchOwned, VecZnx, VecZnxBig, VecZnxDft},
     source::Source,
 };
 
@@ -123,7 +123,7 @@ where
         let mut source: Source = Source::new([0u8; 32]);
 
         let mut res: VecZnxDft<DeviceBuf<B>, B> = module.vec_znx_dft_alloc(cols, size);
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
         source.fill_bytes(res.data_mut().as_mut());
         source.fill_bytes(a.data_mut().as_mut());
 
@@ -8,7 +8,7 @@ use poulpy_hal::{
         ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxDftAlloc, VmpApplyDft, VmpApplyDftTmpBytes, VmpApplyDftToDft,
         VmpApplyDftToDftTmpBytes, VmpPMatAlloc, VmpPrepare, VmpPrepareTmpBytes,
     },
-    layouts::{Backend, DataViewMut, DeviceBuf, MatZnx, Module, ScratchOwned, VecZnx, VecZnxDft, VmpPMat},
+    layouts::{AlignedBuf, Backend, DataViewMut, DeviceBuf, MatZnx, Module, ScratchOwned, VecZnx, VecZnxDft, VmpPMat},
     source::Source,
 };
 
@@ -37,7 +37,7 @@ where
 
         let mut scratch: ScratchOwned<B> = ScratchOwned::alloc(module.vmp_prepare_tmp_bytes(rows, cols_in, cols_out, size));
 
-        let mut mat: MatZnx<Vec<u8>> = MatZnx::alloc(module.n(), rows, cols_in, cols_out, size);
+        let mut mat: MatZnx<AlignedBuf> = MatZnx::alloc(module.n(), rows, cols_in, cols_out, size);
         let mut pmat: VmpPMat<DeviceBuf<B>, B> = module.vmp_pmat_alloc(rows, cols_in, cols_out, size);
 
         source.fill_bytes(mat.data_mut());
@@ -91,7 +91,7 @@ where
         let mut scratch: ScratchOwned<B> = ScratchOwned::alloc(1 << 20);
 
         let mut res: VecZnxDft<DeviceBuf<B>, B> = module.vec_znx_dft_alloc(cols_out, size);
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), cols_in, size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), cols_in, size);
         let mut pmat: VmpPMat<DeviceBuf<B>, B> = module.vmp_pmat_alloc(rows, cols_in, cols_out, size);
 
         source.fill_bytes(pmat.data_mut().as_mut());
@@ -10,7 +10,7 @@ use poulpy_core::{
 };
 use poulpy_hal::{
     api::{ModuleN, ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxRotateAssign},
-    layouts::{Backend, DeviceBuf, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, Backend, DeviceBuf, Module, Scratch, ScratchOwned},
     source::Source,
 };
 
@@ -95,17 +95,17 @@ where
     let mut source_xa: Source = Source::new([1u8; 32]);
     let mut source_xe: Source = Source::new([1u8; 32]);
 
-    let mut sk_lwe: LWESecret<Vec<u8>> = LWESecret::alloc(n_lwe);
+    let mut sk_lwe: LWESecret<AlignedBuf> = LWESecret::alloc(n_lwe);
     sk_lwe.fill_binary_block(7, &mut source_xs);
 
-    let mut sk_glwe: GLWESecret<Vec<u8>> = GLWESecret::alloc(n_glwe, rank);
+    let mut sk_glwe: GLWESecret<AlignedBuf> = GLWESecret::alloc(n_glwe, rank);
     sk_glwe.fill_ternary_prob(0.5, &mut source_xs);
 
-    let ct_lwe: LWE<Vec<u8>> = LWE::alloc_from_infos(&lwe_infos);
+    let ct_lwe: LWE<AlignedBuf> = LWE::alloc_from_infos(&lwe_infos);
 
     let cbt_enc_infos = CircuitBootstrappingEncryptionInfos::from_default_sigma(&cbt_infos).unwrap();
 
-    let mut cbt_key: CircuitBootstrappingKey<Vec<u8>, BRA> = CircuitBootstrappingKey::alloc_from_infos(&cbt_infos);
+    let mut cbt_key: CircuitBootstrappingKey<AlignedBuf, BRA> = CircuitBootstrappingKey::alloc_from_infos(&cbt_infos);
     module.circuit_bootstrapping_key_encrypt_sk(
         &mut cbt_key,
         &sk_lwe,
@@ -116,7 +116,7 @@ where
         scratch.borrow(),
     );
 
-    let mut res: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_infos);
+    let mut res: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_infos);
     let mut cbt_prepared: CircuitBootstrappingKeyPrepared<DeviceBuf<BE>, BRA, BE> =
         CircuitBootstrappingKeyPrepared::alloc_from_infos(&module, &cbt_infos);
     cbt_prepared.prepare(&module, &cbt_key, scratch.borrow());
@@ -57,7 +57,7 @@ pub fn ckks_criterion_config() -> criterion::Criterion {
         .measurement_time(std::time::Duration::from_secs(10))
 }
 
-/// Private: expands to every FFT64 backend in tier order (ref → avx → gpu).
+/// Private: expands to every FFT64 backend in tier order (ref → simd → avx → gpu).
 #[doc(hidden)]
 #[macro_export]
 macro_rules! for_each_fft_backend_family {
@@ -66,6 +66,11 @@ macro_rules! for_each_fft_backend_family {
             use $fn as __f;
             __f::<poulpy_cpu_ref::FFT64Ref>($($arg,)* $c, "fft64-ref");
         }
+        #[cfg(feature = "enable-simd")]
+        {
+            use $fn as __f;
+            __f::<poulpy_cpu_simd::FFT64Simd>($($arg,)* $c, "fft64-simd");
+        }
         #[cfg(all(feature = "enable-avx", target_arch = "x86_64"))]
         {
             use $fn as __f;
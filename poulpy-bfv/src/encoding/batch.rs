@@ -0,0 +1,306 @@
+use anyhow::Result;
+use poulpy_hal::{
+    GALOISGENERATOR,
+    layouts::{DataMut, DataRef},
+};
+
+use crate::{BFVInfos, ensure_plaintext_modulus_match, layouts::BFVPlaintext};
+
+/// Slot encoder/decoder for BFV integer vectors.
+///
+/// For a prime plaintext modulus `t = 1 mod 2N`, the ring `Z_t[X]/(X^N+1)`
+/// is isomorphic to `N` copies of `Z_t`, obtained by evaluating a polynomial
+/// at the primitive `2N`-th roots of unity modulo `t`. The encoder exposes
+/// these `N` slots as a `2 x N/2` matrix: slot `j < N/2` of the first row is
+/// the evaluation at `psi^(5^j)` and slot `j` of the second row is the
+/// evaluation at `psi^(-5^j)`.
+///
+/// With this ordering, the automorphism `X -> X^(5^k)` rotates both rows to
+/// the left by `k` positions, and `X -> X^-1` swaps the two rows.
+pub struct Encoder {
+    t: u64,
+    n_inv: u64,
+    psi_rev: Vec<u64>,
+    psi_inv_rev: Vec<u64>,
+    slot_map: Vec<usize>,
+}
+
+impl Encoder {
+    /// Creates a slot encoder for polynomials of degree `n` modulo `t`.
+    ///
+    /// Inputs:
+    /// - `n`: ring degree, a power of two
+    /// - `t`: plaintext modulus, a prime below `2^32` with `t = 1 mod 2n`
+    ///
+    /// Errors:
+    /// - returns an error if `n` is not a power of two greater than one, or
+    ///   if `t` is not a prime congruent to `1` modulo `2n`
+    pub fn new(n: usize, t: u64) -> Result<Self> {
+        anyhow::ensure!(n > 1 && n.is_power_of_two(), "n must be a power of two > 1, got {n}");
+        anyhow::ensure!(t < 1 << 32, "t must be smaller than 2^32, got {t}");
+        anyhow::ensure!(is_prime(t), "t must be prime, got {t}");
+        let two_n: u64 = 2 * n as u64;
+        anyhow::ensure!(t % two_n == 1, "t must be congruent to 1 mod 2n={two_n}, got {t}");
+
+        // A 2n-th root psi is primitive iff psi^n = -1, since 2n is a power of two.
+        let psi: u64 = (2..t)
+            .map(|g| pow_mod(g, (t - 1) / two_n, t))
+            .find(|&psi| pow_mod(psi, n as u64, t) == t - 1)
+            .expect("a prime t = 1 mod 2n has a primitive 2n-th root of unity");
+        let psi_inv: u64 = pow_mod(psi, t - 2, t);
+
+        let log_n: u32 = n.trailing_zeros();
+        let bit_rev = |i: usize| i.reverse_bits() >> (usize::BITS - log_n);
+
+        let mut psi_rev: Vec<u64> = vec![0; n];
+        let mut psi_inv_rev: Vec<u64> = vec![0; n];
+        let (mut pow, mut pow_inv) = (1u64, 1u64);
+        for i in 0..n {
+            psi_rev[bit_rev(i)] = pow;
+            psi_inv_rev[bit_rev(i)] = pow_inv;
+            pow = mul_mod(pow, psi, t);
+            pow_inv = mul_mod(pow_inv, psi_inv, t);
+        }
+
+        // The forward NTT stores the evaluation at psi^(2 * bitrev(p) + 1) at position p.
+        let mut slot_map: Vec<usize> = vec![0; n];
+        let mut exp: u64 = 1;
+        for j in 0..n / 2 {
+            slot_map[j] = bit_rev(((exp - 1) / 2) as usize);
+            slot_map[j + n / 2] = bit_rev(((two_n - exp - 1) / 2) as usize);
+            exp = (exp * GALOISGENERATOR) & (two_n - 1);
+        }
+
+        Ok(Self {
+            t,
+            n_inv: pow_mod(n as u64, t - 2, t),
+            psi_rev,
+            psi_inv_rev,
+            slot_map,
+        })
+    }
+
+    /// Returns the number of slots, equal to the ring degree.
+    pub fn slots(&self) -> usize {
+        self.slot_map.len()
+    }
+
+    /// Returns the plaintext modulus `t`.
+    pub fn plaintext_modulus(&self) -> u64 {
+        self.t
+    }
+
+    /// Maps slot values to the coefficients of the corresponding polynomial.
+    ///
+    /// Inputs:
+    /// - `coeffs`: destination buffer of length `N`
+    /// - `values`: at most `N` slot values; missing slots are set to zero and
+    ///   all values are reduced modulo `t`
+    pub fn slots_to_coeffs(&self, coeffs: &mut [u64], values: &[u64]) -> Result<()> {
+        let n: usize = self.slots();
+        anyhow::ensure!(coeffs.len() == n, "coeffs.len()={} != n={n}", coeffs.len());
+        anyhow::ensure!(values.len() <= n, "values.len()={} > n={n}", values.len());
+
+        coeffs.fill(0);
+        for (j, &v) in values.iter().enumerate() {
+            coeffs[self.slot_map[j]] = v % self.t;
+        }
+        self.intt(coeffs);
+        Ok(())
+    }
+
+    /// Maps polynomial coefficients to slot values.
+    ///
+    /// Inputs:
+    /// - `values`: destination buffer of length at most `N`, filled with the
+    ///   first `values.len()` slots
+    /// - `coeffs`: `N` coefficients, reduced modulo `t`
+    pub fn coeffs_to_slots(&self, values: &mut [u64], coeffs: &[u64]) -> Result<()> {
+        let n: usize = self.slots();
+        anyhow::ensure!(coeffs.len() == n, "coeffs.len()={} != n={n}", coeffs.len());
+        anyhow::ensure!(values.len() <= n, "values.len()={} > n={n}", values.len());
+
+        let mut evals: Vec<u64> = coeffs.iter().map(|&c| c % self.t).collect();
+        self.ntt(&mut evals);
+        for (j, v) in values.iter_mut().enumerate() {
+            *v = evals[self.slot_map[j]];
+        }
+        Ok(())
+    }
+
+    /// Encodes slot values into a BFV plaintext.
+    ///
+    /// Errors:
+    /// - returns an error if `pt` is encoded under another plaintext modulus
+    ///   or if `values` holds more than `N` entries
+    pub fn encode(&self, pt: &mut BFVPlaintext<impl DataMut>, values: &[u64]) -> Result<()> {
+        ensure_plaintext_modulus_match("encode", pt.plaintext_modulus(), self.t)?;
+        let mut coeffs: Vec<u64> = vec![0; self.slots()];
+        self.slots_to_coeffs(&mut coeffs, values)?;
+        pt.encode_coeffs(&coeffs)
+    }
+
+    /// Decodes the first `values.len()` slots of a BFV plaintext.
+    ///
+    /// Errors:
+    /// - returns an error if `pt` is encoded under another plaintext modulus
+    ///   or if `values` holds more than `N` entries
+    pub fn decode(&self, pt: &BFVPlaintext<impl DataRef>, values: &mut [u64]) -> Result<()> {
+        ensure_plaintext_modulus_match("decode", pt.plaintext_modulus(), self.t)?;
+        let mut coeffs: Vec<u64> = vec![0; self.slots()];
+        pt.decode_coeffs(&mut coeffs)?;
+        self.coeffs_to_slots(values, &coeffs)
+    }
+
+    /// Negacyclic Cooley-Tukey NTT, natural order in, bit-reversed order out.
+    fn ntt(&self, a: &mut [u64]) {
+        let t: u64 = self.t;
+        let n: usize = a.len();
+        let mut len: usize = n;
+        let mut m: usize = 1;
+        while m < n {
+            len >>= 1;
+            for i in 0..m {
+                let s: u64 = self.psi_rev[m + i];
+                let j1: usize = 2 * i * len;
+                for j in j1..j1 + len {
+                    let u: u64 = a[j];
+                    let v: u64 = mul_mod(a[j + len], s, t);
+                    a[j] = (u + v) % t;
+                    a[j + len] = (u + t - v) % t;
+                }
+            }
+            m <<= 1;
+        }
+    }
+
+    /// Negacyclic Gentleman-Sande inverse NTT, bit-reversed order in, natural order out.
+    fn intt(&self, a: &mut [u64]) {
+        let t: u64 = self.t;
+        let n: usize = a.len();
+        let mut len: usize = 1;
+        let mut m: usize = n;
+        while m > 1 {
+            let h: usize = m >> 1;
+            for i in 0..h {
+                let s: u64 = self.psi_inv_rev[h + i];
+                let j1: usize = 2 * i * len;
+                for j in j1..j1 + len {
+                    let u: u64 = a[j];
+                    let v: u64 = a[j + len];
+                    a[j] = (u + v) % t;
+                    a[j + len] = mul_mod(u + t - v, s, t);
+                }
+            }
+            len <<= 1;
+            m = h;
+        }
+        a.iter_mut().for_each(|x| *x = mul_mod(*x, self.n_inv, t));
+    }
+}
+
+fn mul_mod(a: u64, b: u64, t: u64) -> u64 {
+    ((a as u128 * b as u128) % t as u128) as u64
+}
+
+fn pow_mod(mut a: u64, mut e: u64, t: u64) -> u64 {
+    let mut r: u64 = 1;
+    a %= t;
+    while e > 0 {
+        if e & 1 == 1 {
+            r = mul_mod(r, a, t);
+        }
+        a = mul_mod(a, a, t);
+        e >>= 1;
+    }
+    r
+}
+
+fn is_prime(t: u64) -> bool {
+    t >= 2 && (2..).take_while(|d| d * d <= t).all(|d| !t.is_multiple_of(d))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const N: usize = 16;
+    const T: u64 = 97;
+
+    fn negacyclic_mul(a: &[u64], b: &[u64], t: u64) -> Vec<u64> {
+        let n: usize = a.len();
+        let mut res: Vec<u64> = vec![0; n];
+        for (i, &ai) in a.iter().enumerate() {
+            for (j, &bj) in b.iter().enumerate() {
+                let p: u64 = mul_mod(ai, bj, t);
+                let k: usize = (i + j) % n;
+                res[k] = if i + j < n { (res[k] + p) % t } else { (res[k] + t - p) % t };
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn slots_coeffs_roundtrip() {
+        let encoder = Encoder::new(N, T).unwrap();
+        let values: Vec<u64> = (0..N as u64).map(|i| (i * i + 3) % T).collect();
+
+        let mut coeffs: Vec<u64> = vec![0; N];
+        encoder.slots_to_coeffs(&mut coeffs, &values).unwrap();
+        let mut decoded: Vec<u64> = vec![0; N];
+        encoder.coeffs_to_slots(&mut decoded, &coeffs).unwrap();
+
+        assert_eq!(decoded, values);
+    }
+
+    #[test]
+    fn ring_product_is_slotwise() {
+        let encoder = Encoder::new(N, T).unwrap();
+        let a: Vec<u64> = (0..N as u64).map(|i| (5 * i + 1) % T).collect();
+        let b: Vec<u64> = (0..N as u64).map(|i| (i * i + 7) % T).collect();
+
+        let mut ca: Vec<u64> = vec![0; N];
+        let mut cb: Vec<u64> = vec![0; N];
+        encoder.slots_to_coeffs(&mut ca, &a).unwrap();
+        encoder.slots_to_coeffs(&mut cb, &b).unwrap();
+
+        let mut got: Vec<u64> = vec![0; N];
+        encoder.coeffs_to_slots(&mut got, &negacyclic_mul(&ca, &cb, T)).unwrap();
+        let want: Vec<u64> = a.iter().zip(&b).map(|(&x, &y)| mul_mod(x, y, T)).collect();
+
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn galois_generator_rotates_rows() {
+        let encoder = Encoder::new(N, T).unwrap();
+        let values: Vec<u64> = (0..N as u64).map(|i| i + 1).collect();
+        let mut coeffs: Vec<u64> = vec![0; N];
+        encoder.slots_to_coeffs(&mut coeffs, &values).unwrap();
+
+        // X -> X^5
+        let mut auto: Vec<u64> = vec![0; N];
+        for (i, &c) in coeffs.iter().enumerate() {
+            let j: usize = (i * GALOISGENERATOR as usize) % (2 * N);
+            if j < N {
+                auto[j] = (auto[j] + c) % T;
+            } else {
+                auto[j - N] = (auto[j - N] + T - c) % T;
+            }
+        }
+
+        let mut got: Vec<u64> = vec![0; N];
+        encoder.coeffs_to_slots(&mut got, &auto).unwrap();
+        let h: usize = N / 2;
+        let want: Vec<u64> = (0..N).map(|j| values[(j / h) * h + (j + 1) % h]).collect();
+
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn new_rejects_invalid_modulus() {
+        assert!(Encoder::new(N, 96).is_err());
+        assert!(Encoder::new(N, 101).is_err());
+        assert!(Encoder::new(12, T).is_err());
+    }
+}
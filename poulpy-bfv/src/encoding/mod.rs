@@ -0,0 +1,5 @@
+//! BFV encoding helpers.
+
+pub mod batch;
+
+pub use batch::Encoder;
@@ -0,0 +1,79 @@
+use std::{error::Error, fmt};
+
+use anyhow::Result;
+
+/// BFV composition errors returned by high-level operations.
+///
+/// These errors describe semantic failures such as operands encoded under
+/// different plaintext moduli or missing evaluation keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BFVCompositionError {
+    /// Two operands are encoded under different plaintext moduli.
+    PlaintextModulusMismatch { op: &'static str, lhs: u64, rhs: u64 },
+    /// The plaintext modulus does not fit in a single centered limb.
+    PlaintextModulusExceedsBase2K { op: &'static str, t: u64, base2k: usize },
+    /// A plaintext and ciphertext use different limb radices.
+    PlaintextBase2KMismatch {
+        op: &'static str,
+        ct_base2k: usize,
+        pt_base2k: usize,
+    },
+    /// A requested rotation key is not present in the provided key map.
+    MissingAutomorphismKey { op: &'static str, rotation: i64 },
+}
+
+impl fmt::Display for BFVCompositionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PlaintextModulusMismatch { op, lhs, rhs } => {
+                write!(f, "{op} requires matching plaintext moduli, got t={lhs} and t={rhs}")
+            }
+            Self::PlaintextModulusExceedsBase2K { op, t, base2k } => write!(
+                f,
+                "{op} requires a plaintext modulus below 2^(base2k-1), got t={t} with base2k={base2k}"
+            ),
+            Self::PlaintextBase2KMismatch {
+                op,
+                ct_base2k,
+                pt_base2k,
+            } => write!(
+                f,
+                "{op} requires matching base2k values, got ciphertext base2k={ct_base2k} and plaintext base2k={pt_base2k}"
+            ),
+            Self::MissingAutomorphismKey { op, rotation } => {
+                write!(
+                    f,
+                    "{op} requires an automorphism key for rotation {rotation}, but none was provided"
+                )
+            }
+        }
+    }
+}
+
+impl Error for BFVCompositionError {}
+
+pub(crate) fn ensure_plaintext_modulus_match(op: &'static str, lhs: u64, rhs: u64) -> Result<()> {
+    if lhs != rhs {
+        return Err(BFVCompositionError::PlaintextModulusMismatch { op, lhs, rhs }.into());
+    }
+    Ok(())
+}
+
+pub(crate) fn ensure_plaintext_modulus_fits(op: &'static str, t: u64, base2k: usize) -> Result<()> {
+    if base2k > 64 || t >= 1u64 << (base2k - 1) {
+        return Err(BFVCompositionError::PlaintextModulusExceedsBase2K { op, t, base2k }.into());
+    }
+    Ok(())
+}
+
+pub(crate) fn ensure_base2k_match(op: &'static str, ct_base2k: usize, pt_base2k: usize) -> Result<()> {
+    if ct_base2k != pt_base2k {
+        return Err(BFVCompositionError::PlaintextBase2KMismatch {
+            op,
+            ct_base2k,
+            pt_base2k,
+        }
+        .into());
+    }
+    Ok(())
+}
@@ -0,0 +1,97 @@
+//! BFV metadata attached to ciphertext storage.
+//!
+//! A BFV ciphertext is represented as [`BFVCiphertext<D>`], a thin wrapper
+//! over `poulpy-core`'s `GLWE<D>`.
+
+use std::ops::{Deref, DerefMut};
+
+use poulpy_core::layouts::{Base2K, Degree, GLWE, GLWEInfos, GLWEToMut, GLWEToRef, LWEInfos, Rank, TorusPrecision};
+use poulpy_hal::layouts::{AlignedBuf, Data, DataMut, DataRef};
+
+use crate::BFVInfos;
+
+/// BFV ciphertext storage plus its plaintext modulus.
+///
+/// `inner` contains the raw GLWE torus digits, whose phase is `m/t + e`.
+pub struct BFVCiphertext<D: Data> {
+    /// Raw GLWE ciphertext storage.
+    pub(crate) inner: GLWE<D>,
+    /// Plaintext modulus of the encrypted message.
+    pub(crate) t: u64,
+}
+
+impl<D: Data> Deref for BFVCiphertext<D> {
+    type Target = GLWE<D>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<D: Data> DerefMut for BFVCiphertext<D> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<D: Data> LWEInfos for BFVCiphertext<D> {
+    fn base2k(&self) -> Base2K {
+        self.inner.base2k()
+    }
+
+    fn n(&self) -> Degree {
+        self.inner.n()
+    }
+
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+}
+
+impl<D: Data> GLWEInfos for BFVCiphertext<D> {
+    fn rank(&self) -> Rank {
+        self.inner.rank()
+    }
+}
+
+impl<D: Data> BFVInfos for BFVCiphertext<D> {
+    fn plaintext_modulus(&self) -> u64 {
+        self.t
+    }
+}
+
+impl<D: DataRef> GLWEToRef for BFVCiphertext<D> {
+    fn to_ref(&self) -> GLWE<&[u8]> {
+        self.inner.to_ref()
+    }
+}
+
+impl<D: DataMut> GLWEToMut for BFVCiphertext<D> {
+    fn to_mut(&mut self) -> GLWE<&mut [u8]> {
+        self.inner.to_mut()
+    }
+}
+
+impl BFVCiphertext<AlignedBuf> {
+    /// Allocates an owned rank-1 ciphertext buffer.
+    ///
+    /// The plaintext modulus is left unset until the buffer is written by an
+    /// encryption or an evaluation.
+    pub fn alloc(n: Degree, k: TorusPrecision, base2k: Base2K) -> Self {
+        Self {
+            inner: GLWE::alloc(n, base2k, k, Rank(1)),
+            t: 0,
+        }
+    }
+
+    /// Allocates an owned ciphertext from an existing GLWE layout descriptor.
+    pub fn alloc_from_infos<A>(infos: &A) -> Self
+    where
+        A: GLWEInfos,
+    {
+        Self {
+            inner: GLWE::alloc(infos.n(), infos.base2k(), infos.max_k(), infos.rank()),
+            t: 0,
+        }
+    }
+}
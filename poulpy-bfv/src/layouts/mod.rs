@@ -0,0 +1,17 @@
+//! BFV-level data structures.
+//!
+//! Each layout wraps the corresponding `poulpy-core` GLWE primitive and adds
+//! the plaintext modulus `t` needed by BFV arithmetic.
+//!
+//! ## Key Structures
+//!
+//! | Type | Role |
+//! |------|------|
+//! | `BFVCiphertext<D>` | Encrypted BFV value: wrapper over the core GLWE ciphertext |
+//! | `BFVPlaintext<D>` | Message `m` stored as the torus value `m/t` |
+
+pub mod ciphertext;
+pub mod plaintext;
+
+pub use ciphertext::BFVCiphertext;
+pub use plaintext::BFVPlaintext;
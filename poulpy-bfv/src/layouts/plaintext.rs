@@ -0,0 +1,179 @@
+//! BFV plaintext storage.
+//!
+//! A message `m in Z_t[X]/(X^N+1)` is stored as the torus value `m/t`, with
+//! as many base-`2^{base2k}` digits as the plaintext has limbs.
+
+use std::ops::{Deref, DerefMut};
+
+use anyhow::Result;
+use poulpy_core::layouts::{
+    Base2K, Degree, GLWE, GLWEInfos, GLWEPlaintext, GLWEToMut, GLWEToRef, LWEInfos, Rank, TorusPrecision,
+};
+use poulpy_hal::layouts::{AlignedBuf, Data, DataMut, DataRef, VecZnx, ZnxInfos, ZnxView, ZnxViewMut};
+
+use crate::BFVInfos;
+
+/// BFV plaintext storage plus its plaintext modulus.
+pub struct BFVPlaintext<D: Data> {
+    /// Raw plaintext storage holding `m/t`.
+    pub(crate) inner: GLWEPlaintext<D>,
+    /// Plaintext modulus of the encoded message.
+    pub(crate) t: u64,
+}
+
+impl<D: Data> Deref for BFVPlaintext<D> {
+    type Target = GLWEPlaintext<D>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<D: Data> DerefMut for BFVPlaintext<D> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<D: Data> LWEInfos for BFVPlaintext<D> {
+    fn base2k(&self) -> Base2K {
+        self.inner.base2k()
+    }
+
+    fn n(&self) -> Degree {
+        self.inner.n()
+    }
+
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+}
+
+impl<D: Data> GLWEInfos for BFVPlaintext<D> {
+    fn rank(&self) -> Rank {
+        Rank(0)
+    }
+}
+
+impl<D: Data> BFVInfos for BFVPlaintext<D> {
+    fn plaintext_modulus(&self) -> u64 {
+        self.t
+    }
+}
+
+impl<D: DataRef> GLWEToRef for BFVPlaintext<D> {
+    fn to_ref(&self) -> GLWE<&[u8]> {
+        self.inner.to_ref()
+    }
+}
+
+impl<D: DataMut> GLWEToMut for BFVPlaintext<D> {
+    fn to_mut(&mut self) -> GLWE<&mut [u8]> {
+        self.inner.to_mut()
+    }
+}
+
+impl BFVPlaintext<AlignedBuf> {
+    /// Allocates an owned plaintext for messages modulo `t`.
+    ///
+    /// Inputs:
+    /// - `n`: polynomial degree
+    /// - `k`: torus storage precision in bits, usually the ciphertext precision
+    /// - `base2k`: limb radix
+    /// - `t`: plaintext modulus, at least `2`
+    pub fn alloc(n: Degree, k: TorusPrecision, base2k: Base2K, t: u64) -> Self {
+        assert!(t >= 2, "plaintext modulus must be >= 2, got {t}");
+        Self {
+            inner: GLWEPlaintext::alloc(n, base2k, k),
+            t,
+        }
+    }
+
+    /// Allocates an owned plaintext from an existing layout descriptor.
+    pub fn alloc_from_infos<A>(infos: &A, t: u64) -> Self
+    where
+        A: GLWEInfos,
+    {
+        Self::alloc(infos.n(), infos.max_k(), infos.base2k(), t)
+    }
+}
+
+impl<D: DataMut> BFVPlaintext<D> {
+    /// Writes the polynomial with coefficients `coeffs` (reduced modulo `t`).
+    ///
+    /// Missing coefficients are set to zero.
+    ///
+    /// Errors:
+    /// - returns an error if `coeffs` holds more than `N` entries
+    pub fn encode_coeffs(&mut self, coeffs: &[u64]) -> Result<()> {
+        let n: usize = self.n().into();
+        anyhow::ensure!(coeffs.len() <= n, "coeffs.len()={} > n={n}", coeffs.len());
+        let base2k: usize = self.base2k().into();
+        encode_torus(&mut self.inner.data, base2k, self.t, coeffs);
+        Ok(())
+    }
+}
+
+impl<D: DataRef> BFVPlaintext<D> {
+    /// Reads the first `coeffs.len()` coefficients, rounded to the nearest
+    /// multiple of `1/t` and reduced into `[0, t)`.
+    ///
+    /// Errors:
+    /// - returns an error if `coeffs` holds more than `N` entries
+    pub fn decode_coeffs(&self, coeffs: &mut [u64]) -> Result<()> {
+        let n: usize = self.n().into();
+        anyhow::ensure!(coeffs.len() <= n, "coeffs.len()={} > n={n}", coeffs.len());
+        let base2k: usize = self.base2k().into();
+        for (i, c) in coeffs.iter_mut().enumerate() {
+            *c = decode_torus(&self.inner.data, base2k, self.t, i).rem_euclid(self.t as i64) as u64;
+        }
+        Ok(())
+    }
+}
+
+/// Writes `coeffs[i] / t` into column 0 of `data` as normalized
+/// base-`2^{base2k}` digits, truncated to the available limbs.
+fn encode_torus(data: &mut VecZnx<impl DataMut>, base2k: usize, t: u64, coeffs: &[u64]) {
+    let size: usize = data.size();
+    let base: i64 = 1 << base2k;
+    let half: i64 = base >> 1;
+
+    for j in 0..size {
+        data.at_mut(0, j).fill(0);
+    }
+
+    for (i, &c) in coeffs.iter().enumerate() {
+        // Long division of (c mod t) by t in base 2^base2k.
+        let mut r: u128 = (c % t) as u128;
+        for j in 0..size {
+            r <<= base2k;
+            data.at_mut(0, j)[i] = (r / t as u128) as i64;
+            r %= t as u128;
+        }
+
+        // Re-center digits into [-2^(base2k-1), 2^(base2k-1)).
+        for j in (0..size).rev() {
+            if data.at(0, j)[i] >= half {
+                data.at_mut(0, j)[i] -= base;
+                if j > 0 {
+                    data.at_mut(0, j - 1)[i] += 1;
+                }
+            }
+        }
+    }
+}
+
+/// Returns the integer closest to `t * x`, where `x` is coefficient `i` of
+/// column 0 of `data`, without reduction modulo `t`.
+///
+/// Only the leading limbs that fit in a 128-bit accumulator are read, which
+/// bounds the rounding error by `t * 2^-90`.
+pub(crate) fn decode_torus(data: &VecZnx<impl DataRef>, base2k: usize, t: u64, i: usize) -> i64 {
+    let limbs: usize = (90 / base2k).clamp(1, data.size());
+    let bits: usize = limbs * base2k;
+    let mut v: i128 = 0;
+    for j in 0..limbs {
+        v = (v << base2k) + data.at(0, j)[i] as i128;
+    }
+    ((t as i128 * v + (1i128 << (bits - 1))) >> bits) as i64
+}
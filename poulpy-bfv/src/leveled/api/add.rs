@@ -0,0 +1,61 @@
+use anyhow::Result;
+use poulpy_core::{GLWEAdd, GLWENormalize, ScratchTakeCore};
+use poulpy_hal::layouts::{Backend, DataMut, DataRef, Scratch};
+
+use crate::{
+    layouts::{BFVCiphertext, BFVPlaintext},
+    oep::BFVImpl,
+};
+
+pub trait BFVAddOps<BE: Backend + BFVImpl<BE>> {
+    /// Returns the scratch size, in bytes, required by the addition methods.
+    fn bfv_add_tmp_bytes(&self) -> usize
+    where
+        Self: GLWENormalize<BE>;
+
+    /// Computes `dst = a + b`.
+    fn bfv_add_into(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        b: &BFVCiphertext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAdd + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    /// Computes `dst = dst + a`.
+    fn bfv_add_assign(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAdd + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    /// Computes `dst = a + pt`.
+    fn bfv_add_pt_into(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAdd + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    /// Computes `dst = dst + pt`.
+    fn bfv_add_pt_assign(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAdd + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+}
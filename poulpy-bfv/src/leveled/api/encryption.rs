@@ -0,0 +1,59 @@
+use anyhow::Result;
+use poulpy_core::{
+    EncryptionInfos, ScratchTakeCore,
+    layouts::{GLWEInfos, GLWESecretPreparedToRef},
+};
+use poulpy_hal::{
+    layouts::{Backend, DataMut, DataRef, Scratch},
+    source::Source,
+};
+
+use crate::{
+    layouts::{BFVCiphertext, BFVPlaintext},
+    oep::BFVImpl,
+};
+
+pub trait BFVEncrypt<BE: Backend + BFVImpl<BE>> {
+    /// Returns the scratch size, in bytes, required by [`Self::bfv_encrypt_sk`].
+    fn bfv_encrypt_sk_tmp_bytes<A>(&self, ct_infos: &A) -> usize
+    where
+        A: GLWEInfos;
+
+    /// Encrypts a BFV plaintext under a secret key.
+    #[allow(clippy::too_many_arguments)]
+    fn bfv_encrypt_sk<S, E: EncryptionInfos>(
+        &self,
+        ct: &mut BFVCiphertext<impl DataMut>,
+        pt: &BFVPlaintext<impl DataRef>,
+        sk: &S,
+        enc_infos: &E,
+        source_xa: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        S: GLWESecretPreparedToRef<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+}
+
+pub trait BFVDecrypt<BE: Backend + BFVImpl<BE>> {
+    /// Returns the scratch size, in bytes, required by [`Self::bfv_decrypt`].
+    fn bfv_decrypt_tmp_bytes<A>(&self, ct_infos: &A) -> usize
+    where
+        A: GLWEInfos;
+
+    /// Decrypts a ciphertext into a caller-provided BFV plaintext.
+    ///
+    /// The plaintext takes the plaintext modulus of the ciphertext and holds
+    /// the phase `m/t + e`, normalized to its own precision.
+    fn bfv_decrypt<S>(
+        &self,
+        pt: &mut BFVPlaintext<impl DataMut>,
+        ct: &BFVCiphertext<impl DataRef>,
+        sk: &S,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        S: GLWESecretPreparedToRef<BE> + GLWEInfos,
+        Scratch<BE>: ScratchTakeCore<BE>;
+}
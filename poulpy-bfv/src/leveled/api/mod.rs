@@ -0,0 +1,11 @@
+mod add;
+mod encryption;
+mod mul;
+mod rotate;
+mod sub;
+
+pub use add::BFVAddOps;
+pub use encryption::{BFVDecrypt, BFVEncrypt};
+pub use mul::BFVMulOps;
+pub use rotate::BFVRotateOps;
+pub use sub::BFVSubOps;
@@ -0,0 +1,86 @@
+use anyhow::Result;
+use poulpy_core::{
+    GLWEMulConst, GLWEMulPlain, GLWETensoring, ScratchTakeCore,
+    layouts::{GGLWEInfos, GLWEInfos, GLWETensorKeyPrepared},
+};
+use poulpy_hal::{
+    api::ScratchAvailable,
+    layouts::{Backend, DataMut, DataRef, Scratch},
+};
+
+use crate::{
+    layouts::{BFVCiphertext, BFVPlaintext},
+    oep::BFVImpl,
+};
+
+pub trait BFVMulOps<BE: Backend + BFVImpl<BE>> {
+    /// Returns the scratch size, in bytes, required by [`Self::bfv_mul_into`]
+    /// and [`Self::bfv_mul_assign`] with operands `a`, `b` and destination `res`.
+    ///
+    /// The tensor product is taken at the precision of the operands, so a
+    /// destination smaller than the operands does not reduce the scratch.
+    /// For [`Self::bfv_mul_assign`], pass the destination as both `res` and `a`.
+    fn bfv_mul_tmp_bytes<R, A, B, T>(&self, res: &R, a: &A, b: &B, tsk: &T) -> usize
+    where
+        R: GLWEInfos,
+        A: GLWEInfos,
+        B: GLWEInfos,
+        T: GGLWEInfos,
+        Self: GLWEMulConst<BE> + GLWETensoring<BE>;
+
+    /// Computes `dst = a * b` and relinearizes the product with `tsk`.
+    fn bfv_mul_into(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        b: &BFVCiphertext<impl DataRef>,
+        tsk: &GLWETensorKeyPrepared<impl DataRef, BE>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEMulConst<BE> + GLWETensoring<BE>,
+        Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>;
+
+    /// Computes `dst = dst * a` and relinearizes the product with `tsk`.
+    fn bfv_mul_assign(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        tsk: &GLWETensorKeyPrepared<impl DataRef, BE>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEMulConst<BE> + GLWETensoring<BE>,
+        Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>;
+
+    /// Returns the scratch size, in bytes, required by [`Self::bfv_mul_pt_into`]
+    /// and [`Self::bfv_mul_pt_assign`].
+    fn bfv_mul_pt_tmp_bytes<R, A>(&self, res: &R, a: &A) -> usize
+    where
+        R: GLWEInfos,
+        A: GLWEInfos,
+        Self: GLWEMulPlain<BE>;
+
+    /// Computes `dst = a * pt`.
+    fn bfv_mul_pt_into(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEMulPlain<BE>,
+        Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>;
+
+    /// Computes `dst = dst * pt`.
+    fn bfv_mul_pt_assign(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEMulPlain<BE>,
+        Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>;
+}
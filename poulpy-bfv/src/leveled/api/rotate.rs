@@ -0,0 +1,79 @@
+use anyhow::Result;
+use poulpy_core::{
+    GLWEAutomorphism, ScratchTakeCore,
+    layouts::{
+        GGLWEInfos, GGLWEPreparedToRef, GLWEAutomorphismKeyHelper, GLWEAutomorphismKeyPrepared, GLWEInfos, GetGaloisElement,
+    },
+};
+use poulpy_hal::layouts::{Backend, DataMut, DataRef, Scratch};
+
+use crate::{layouts::BFVCiphertext, oep::BFVImpl};
+
+/// Slot rotations.
+///
+/// [`Self::bfv_rotate_into`] looks its key up in `keys` by the rotation step
+/// `k` (key generated for the Galois element `5^k mod 2N`, i.e.
+/// `module.galois_element(k.rem_euclid(N / 2))`, which also covers `k < 0`).
+/// [`Self::bfv_swap_rows_into`] takes the key generated for the Galois
+/// element `-1` explicitly, so it cannot be confused with the key of a
+/// rotation by `k = -1`.
+pub trait BFVRotateOps<BE: Backend + BFVImpl<BE>> {
+    /// Returns the scratch size, in bytes, required by the rotation methods.
+    fn bfv_rotate_tmp_bytes<C, K>(&self, ct_infos: &C, key_infos: &K) -> usize
+    where
+        C: GLWEInfos,
+        K: GGLWEInfos,
+        Self: GLWEAutomorphism<BE>;
+
+    /// Rotates both slot rows of `src` to the left by `k` positions.
+    fn bfv_rotate_into<H, K>(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        src: &BFVCiphertext<impl DataRef>,
+        k: i64,
+        keys: &H,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAutomorphism<BE>,
+        K: GGLWEPreparedToRef<BE> + GetGaloisElement + GGLWEInfos,
+        H: GLWEAutomorphismKeyHelper<K, BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    /// Rotates both slot rows of `dst` to the left by `k` positions.
+    fn bfv_rotate_assign<H, K>(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        k: i64,
+        keys: &H,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAutomorphism<BE>,
+        K: GGLWEPreparedToRef<BE> + GetGaloisElement + GGLWEInfos,
+        H: GLWEAutomorphismKeyHelper<K, BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    /// Swaps the two slot rows of `src`.
+    fn bfv_swap_rows_into(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        src: &BFVCiphertext<impl DataRef>,
+        key: &GLWEAutomorphismKeyPrepared<impl DataRef, BE>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAutomorphism<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    /// Swaps the two slot rows of `dst`.
+    fn bfv_swap_rows_assign(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        key: &GLWEAutomorphismKeyPrepared<impl DataRef, BE>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAutomorphism<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+}
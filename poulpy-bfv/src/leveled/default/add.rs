@@ -0,0 +1,89 @@
+use anyhow::Result;
+use poulpy_core::{GLWEAdd, GLWENormalize, ScratchTakeCore, layouts::LWEInfos};
+use poulpy_hal::layouts::{Backend, DataMut, DataRef, Module, Scratch};
+
+use crate::{
+    BFVInfos, ensure_base2k_match, ensure_plaintext_modulus_match,
+    layouts::{BFVCiphertext, BFVPlaintext},
+};
+
+pub(crate) trait BFVAddDefault<BE: Backend> {
+    fn bfv_add_tmp_bytes_default(&self) -> usize
+    where
+        Self: GLWENormalize<BE>,
+    {
+        self.glwe_normalize_tmp_bytes()
+    }
+
+    fn bfv_add_into_default(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        b: &BFVCiphertext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAdd + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        ensure_plaintext_modulus_match("add", a.plaintext_modulus(), b.plaintext_modulus())?;
+        self.glwe_add_into(dst, a, b);
+        self.glwe_normalize_assign(dst, scratch);
+        dst.t = a.plaintext_modulus();
+        Ok(())
+    }
+
+    fn bfv_add_assign_default(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAdd + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        ensure_plaintext_modulus_match("add_assign", dst.plaintext_modulus(), a.plaintext_modulus())?;
+        self.glwe_add_assign(dst, a);
+        self.glwe_normalize_assign(dst, scratch);
+        Ok(())
+    }
+
+    fn bfv_add_pt_into_default(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAdd + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        ensure_plaintext_modulus_match("add_pt", a.plaintext_modulus(), pt.plaintext_modulus())?;
+        ensure_base2k_match("add_pt", a.base2k().as_usize(), pt.base2k().as_usize())?;
+        self.glwe_add_into(dst, a, pt);
+        self.glwe_normalize_assign(dst, scratch);
+        dst.t = a.plaintext_modulus();
+        Ok(())
+    }
+
+    fn bfv_add_pt_assign_default(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAdd + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        ensure_plaintext_modulus_match("add_pt_assign", dst.plaintext_modulus(), pt.plaintext_modulus())?;
+        ensure_base2k_match("add_pt_assign", dst.base2k().as_usize(), pt.base2k().as_usize())?;
+        self.glwe_add_assign(dst, pt);
+        self.glwe_normalize_assign(dst, scratch);
+        Ok(())
+    }
+}
+
+impl<BE: Backend> BFVAddDefault<BE> for Module<BE> {}
@@ -0,0 +1,4 @@
+pub(crate) mod add;
+pub(crate) mod mul;
+pub(crate) mod rotate;
+pub(crate) mod sub;
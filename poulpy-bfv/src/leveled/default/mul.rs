@@ -0,0 +1,220 @@
+use anyhow::Result;
+use poulpy_core::{
+    GLWEMulConst, GLWEMulPlain, GLWETensoring, ScratchTakeCore,
+    layouts::{
+        GGLWEInfos, GLWE, GLWEInfos, GLWELayout, GLWEPlaintext, GLWEPlaintextLayout, GLWETensor, GLWETensorKeyPrepared,
+        GLWEToMut, GLWEToRef, LWEInfos, Rank, TorusPrecision,
+    },
+};
+use poulpy_hal::{
+    api::ScratchAvailable,
+    layouts::{AlignedBuf, Backend, DataMut, DataRef, Module, Scratch, VecZnx, ZnxViewMut},
+};
+
+use crate::{
+    BFVInfos, ensure_base2k_match, ensure_plaintext_modulus_fits, ensure_plaintext_modulus_match,
+    layouts::{BFVCiphertext, BFVPlaintext, plaintext::decode_torus},
+};
+
+pub(crate) trait BFVMulDefault<BE: Backend> {
+    fn bfv_mul_tmp_bytes_default<R, A, B, T>(&self, res: &R, a: &A, b: &B, tsk: &T) -> usize
+    where
+        R: GLWEInfos,
+        A: GLWEInfos,
+        B: GLWEInfos,
+        T: GGLWEInfos,
+        Self: GLWEMulConst<BE> + GLWETensoring<BE>,
+    {
+        let tensor_layout = GLWELayout {
+            n: res.n(),
+            base2k: res.base2k(),
+            k: a.max_k().max(b.max_k()),
+            rank: res.rank(),
+        };
+
+        let lvl_0 = GLWETensor::bytes_of_from_infos(&tensor_layout);
+        let lvl_1 = self
+            .glwe_tensor_apply_tmp_bytes(&tensor_layout, a, b)
+            .max(self.glwe_mul_const_tmp_bytes(&tensor_as_glwe(&tensor_layout), &tensor_as_glwe(&tensor_layout), 1))
+            .max(self.glwe_tensor_relinearize_tmp_bytes(res, &tensor_layout, tsk));
+
+        lvl_0 + lvl_1
+    }
+
+    fn bfv_mul_into_default(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        b: &BFVCiphertext<impl DataRef>,
+        tsk: &GLWETensorKeyPrepared<impl DataRef, BE>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEMulConst<BE> + GLWETensoring<BE>,
+        Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+    {
+        let t: u64 = a.plaintext_modulus();
+        ensure_plaintext_modulus_match("mul", t, b.plaintext_modulus())?;
+        ensure_plaintext_modulus_fits("mul", t, dst.base2k().as_usize())?;
+
+        let tensor_layout = GLWELayout {
+            n: dst.n(),
+            base2k: dst.base2k(),
+            k: a.max_k().max(b.max_k()),
+            rank: dst.rank(),
+        };
+
+        let (mut tmp, scratch_1) = scratch.take_glwe_tensor(&tensor_layout);
+        tensor_scaled(self, &mut tmp, &a.to_ref(), &b.to_ref(), t, scratch_1);
+        self.glwe_tensor_relinearize(&mut dst.to_mut(), &tmp, tsk, tsk.size(), scratch_1);
+
+        dst.t = t;
+        Ok(())
+    }
+
+    fn bfv_mul_assign_default(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        tsk: &GLWETensorKeyPrepared<impl DataRef, BE>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEMulConst<BE> + GLWETensoring<BE>,
+        Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+    {
+        let t: u64 = dst.plaintext_modulus();
+        ensure_plaintext_modulus_match("mul_assign", t, a.plaintext_modulus())?;
+        ensure_plaintext_modulus_fits("mul_assign", t, dst.base2k().as_usize())?;
+
+        let tensor_layout = GLWELayout {
+            n: dst.n(),
+            base2k: dst.base2k(),
+            k: dst.max_k().max(a.max_k()),
+            rank: dst.rank(),
+        };
+
+        let (mut tmp, scratch_1) = scratch.take_glwe_tensor(&tensor_layout);
+        tensor_scaled(self, &mut tmp, &dst.to_ref(), &a.to_ref(), t, scratch_1);
+        self.glwe_tensor_relinearize(&mut dst.to_mut(), &tmp, tsk, tsk.size(), scratch_1);
+        Ok(())
+    }
+
+    fn bfv_mul_pt_tmp_bytes_default<R, A>(&self, res: &R, a: &A) -> usize
+    where
+        R: GLWEInfos,
+        A: GLWEInfos,
+        Self: GLWEMulPlain<BE>,
+    {
+        let pt_layout = pt_int_layout(a);
+        GLWEPlaintext::<AlignedBuf>::bytes_of_from_infos(&pt_layout) + self.glwe_mul_plain_tmp_bytes(res, a, &pt_layout)
+    }
+
+    fn bfv_mul_pt_into_default(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEMulPlain<BE>,
+        Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+    {
+        let t: u64 = a.plaintext_modulus();
+        let base2k: usize = a.base2k().as_usize();
+        ensure_plaintext_modulus_match("mul_pt", t, pt.plaintext_modulus())?;
+        ensure_base2k_match("mul_pt", base2k, pt.base2k().as_usize())?;
+        ensure_plaintext_modulus_fits("mul_pt", t, base2k)?;
+
+        let (mut pt_int, scratch_1) = scratch.take_glwe_plaintext(&pt_int_layout(a));
+        lift_centered(&mut pt_int.data, pt, base2k);
+        self.glwe_mul_plain(
+            base2k,
+            &mut dst.to_mut(),
+            &a.to_ref(),
+            a.max_k().as_usize(),
+            &pt_int,
+            base2k,
+            scratch_1,
+        );
+
+        dst.t = t;
+        Ok(())
+    }
+
+    fn bfv_mul_pt_assign_default(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEMulPlain<BE>,
+        Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+    {
+        let t: u64 = dst.plaintext_modulus();
+        let base2k: usize = dst.base2k().as_usize();
+        ensure_plaintext_modulus_match("mul_pt_assign", t, pt.plaintext_modulus())?;
+        ensure_base2k_match("mul_pt_assign", base2k, pt.base2k().as_usize())?;
+        ensure_plaintext_modulus_fits("mul_pt_assign", t, base2k)?;
+
+        let dst_k: usize = dst.max_k().as_usize();
+        let (mut pt_int, scratch_1) = scratch.take_glwe_plaintext(&pt_int_layout(dst));
+        lift_centered(&mut pt_int.data, pt, base2k);
+        self.glwe_mul_plain_assign(base2k, &mut dst.to_mut(), dst_k, &pt_int, base2k, scratch_1);
+        Ok(())
+    }
+}
+
+impl<BE: Backend> BFVMulDefault<BE> for Module<BE> {}
+
+/// Computes `t * (a (x) b) mod 1` into `res`.
+///
+/// The tensor product is taken over the reals (`cnv_offset = 0`) so that the
+/// integer parts of the phases of `a` and `b` only contribute integer
+/// multiples of `1/t` to the product, which vanish once it is scaled by `t`.
+fn tensor_scaled<BE: Backend, M>(
+    module: &M,
+    res: &mut GLWETensor<&mut [u8]>,
+    a: &GLWE<&[u8]>,
+    b: &GLWE<&[u8]>,
+    t: u64,
+    scratch: &mut Scratch<BE>,
+) where
+    M: GLWEMulConst<BE> + GLWETensoring<BE> + ?Sized,
+{
+    let base2k: usize = res.base2k().as_usize();
+    module.glwe_tensor_apply(0, res, a, a.max_k().as_usize(), b, b.max_k().as_usize(), scratch);
+    module.glwe_mul_const_assign(base2k, &mut res.to_mut(), &[t as i64], scratch);
+}
+
+/// Layout of the tensor of `infos` viewed as a GLWE with one column per pair.
+fn tensor_as_glwe<A: GLWEInfos>(infos: &A) -> GLWELayout {
+    let cols: u32 = infos.rank().as_u32() + 1;
+    GLWELayout {
+        n: infos.n(),
+        base2k: infos.base2k(),
+        k: infos.max_k(),
+        rank: Rank(cols * (cols + 1) / 2 - 1),
+    }
+}
+
+/// Single-limb layout holding a plaintext lifted to centered integers.
+fn pt_int_layout<A: GLWEInfos>(infos: &A) -> GLWEPlaintextLayout {
+    GLWEPlaintextLayout {
+        n: infos.n(),
+        base2k: infos.base2k(),
+        k: TorusPrecision(infos.base2k().as_u32()),
+    }
+}
+
+/// Writes the message of `pt`, lifted to `(-t/2, t/2]`, into the single limb of `res`.
+fn lift_centered(res: &mut VecZnx<&mut [u8]>, pt: &BFVPlaintext<impl DataRef>, base2k: usize) {
+    let t: i64 = pt.plaintext_modulus() as i64;
+    let n: usize = pt.n().into();
+    for i in 0..n {
+        let m: i64 = decode_torus(&pt.data, base2k, t as u64, i).rem_euclid(t);
+        res.at_mut(0, 0)[i] = if m > t / 2 { m - t } else { m };
+    }
+}
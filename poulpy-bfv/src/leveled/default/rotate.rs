@@ -0,0 +1,101 @@
+use anyhow::Result;
+use poulpy_core::{
+    GLWEAutomorphism, ScratchTakeCore,
+    layouts::{
+        GGLWEInfos, GGLWEPreparedToRef, GLWEAutomorphismKeyHelper, GLWEAutomorphismKeyPrepared, GLWEInfos, GetGaloisElement,
+    },
+};
+use poulpy_hal::layouts::{Backend, DataMut, DataRef, Module, Scratch};
+
+use crate::{BFVCompositionError, BFVInfos, layouts::BFVCiphertext};
+
+pub(crate) trait BFVRotateDefault<BE: Backend> {
+    fn bfv_rotate_tmp_bytes_default<C, K>(&self, ct_infos: &C, key_infos: &K) -> usize
+    where
+        C: GLWEInfos,
+        K: GGLWEInfos,
+        Self: GLWEAutomorphism<BE>,
+    {
+        self.glwe_automorphism_tmp_bytes(ct_infos, ct_infos, key_infos)
+    }
+
+    fn bfv_rotate_into_default<H, K>(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        src: &BFVCiphertext<impl DataRef>,
+        k: i64,
+        keys: &H,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAutomorphism<BE>,
+        K: GGLWEPreparedToRef<BE> + GetGaloisElement + GGLWEInfos,
+        H: GLWEAutomorphismKeyHelper<K, BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        let key = keys
+            .get_automorphism_key(k)
+            .ok_or(BFVCompositionError::MissingAutomorphismKey {
+                op: "rotate",
+                rotation: k,
+            })?;
+        self.glwe_automorphism(dst, src, key, scratch);
+        dst.t = src.plaintext_modulus();
+        Ok(())
+    }
+
+    fn bfv_rotate_assign_default<H, K>(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        k: i64,
+        keys: &H,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAutomorphism<BE>,
+        K: GGLWEPreparedToRef<BE> + GetGaloisElement + GGLWEInfos,
+        H: GLWEAutomorphismKeyHelper<K, BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        let key = keys
+            .get_automorphism_key(k)
+            .ok_or(BFVCompositionError::MissingAutomorphismKey {
+                op: "rotate_assign",
+                rotation: k,
+            })?;
+        self.glwe_automorphism_assign(dst, key, scratch);
+        Ok(())
+    }
+
+    fn bfv_swap_rows_into_default(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        src: &BFVCiphertext<impl DataRef>,
+        key: &GLWEAutomorphismKeyPrepared<impl DataRef, BE>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAutomorphism<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        self.glwe_automorphism(dst, src, key, scratch);
+        dst.t = src.plaintext_modulus();
+        Ok(())
+    }
+
+    fn bfv_swap_rows_assign_default(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        key: &GLWEAutomorphismKeyPrepared<impl DataRef, BE>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAutomorphism<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        self.glwe_automorphism_assign(dst, key, scratch);
+        Ok(())
+    }
+}
+
+impl<BE: Backend> BFVRotateDefault<BE> for Module<BE> {}
@@ -0,0 +1,89 @@
+use anyhow::Result;
+use poulpy_core::{GLWENormalize, GLWESub, ScratchTakeCore, layouts::LWEInfos};
+use poulpy_hal::layouts::{Backend, DataMut, DataRef, Module, Scratch};
+
+use crate::{
+    BFVInfos, ensure_base2k_match, ensure_plaintext_modulus_match,
+    layouts::{BFVCiphertext, BFVPlaintext},
+};
+
+pub(crate) trait BFVSubDefault<BE: Backend> {
+    fn bfv_sub_tmp_bytes_default(&self) -> usize
+    where
+        Self: GLWENormalize<BE>,
+    {
+        self.glwe_normalize_tmp_bytes()
+    }
+
+    fn bfv_sub_into_default(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        b: &BFVCiphertext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWESub + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        ensure_plaintext_modulus_match("sub", a.plaintext_modulus(), b.plaintext_modulus())?;
+        self.glwe_sub(dst, a, b);
+        self.glwe_normalize_assign(dst, scratch);
+        dst.t = a.plaintext_modulus();
+        Ok(())
+    }
+
+    fn bfv_sub_assign_default(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWESub + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        ensure_plaintext_modulus_match("sub_assign", dst.plaintext_modulus(), a.plaintext_modulus())?;
+        self.glwe_sub_assign(dst, a);
+        self.glwe_normalize_assign(dst, scratch);
+        Ok(())
+    }
+
+    fn bfv_sub_pt_into_default(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWESub + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        ensure_plaintext_modulus_match("sub_pt", a.plaintext_modulus(), pt.plaintext_modulus())?;
+        ensure_base2k_match("sub_pt", a.base2k().as_usize(), pt.base2k().as_usize())?;
+        self.glwe_sub(dst, a, pt);
+        self.glwe_normalize_assign(dst, scratch);
+        dst.t = a.plaintext_modulus();
+        Ok(())
+    }
+
+    fn bfv_sub_pt_assign_default(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWESub + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        ensure_plaintext_modulus_match("sub_pt_assign", dst.plaintext_modulus(), pt.plaintext_modulus())?;
+        ensure_base2k_match("sub_pt_assign", dst.base2k().as_usize(), pt.base2k().as_usize())?;
+        self.glwe_sub_assign(dst, pt);
+        self.glwe_normalize_assign(dst, scratch);
+        Ok(())
+    }
+}
+
+impl<BE: Backend> BFVSubDefault<BE> for Module<BE> {}
@@ -0,0 +1,84 @@
+use anyhow::Result;
+use poulpy_core::layouts::{GLWEPlaintext, GLWESecretPreparedToRef};
+use poulpy_core::{
+    EncryptionInfos, GLWEAdd, GLWEDecrypt, GLWEEncryptSk, GLWENormalize, ScratchTakeCore,
+    layouts::{GLWEInfos, LWEInfos},
+};
+use poulpy_hal::{
+    layouts::{AlignedBuf, Backend, DataMut, DataRef, Module, Scratch},
+    source::Source,
+};
+
+use crate::{
+    BFVInfos, ensure_base2k_match,
+    layouts::{BFVCiphertext, BFVPlaintext},
+    leveled::api::{BFVDecrypt, BFVEncrypt},
+    oep::BFVImpl,
+};
+
+impl<BE: Backend + BFVImpl<BE>> BFVEncrypt<BE> for Module<BE>
+where
+    Self: GLWEEncryptSk<BE> + GLWEAdd + GLWENormalize<BE>,
+{
+    fn bfv_encrypt_sk_tmp_bytes<A>(&self, ct_infos: &A) -> usize
+    where
+        A: GLWEInfos,
+    {
+        self.glwe_encrypt_sk_tmp_bytes(ct_infos).max(self.glwe_normalize_tmp_bytes())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn bfv_encrypt_sk<S, E: EncryptionInfos>(
+        &self,
+        ct: &mut BFVCiphertext<impl DataMut>,
+        pt: &BFVPlaintext<impl DataRef>,
+        sk: &S,
+        enc_infos: &E,
+        source_xa: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        S: GLWESecretPreparedToRef<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        ensure_base2k_match("encrypt_sk", ct.base2k().as_usize(), pt.base2k().as_usize())?;
+        self.glwe_encrypt_zero_sk(ct, sk, enc_infos, source_xe, source_xa, scratch);
+        self.glwe_add_assign(ct, pt);
+        self.glwe_normalize_assign(ct, scratch);
+        ct.t = pt.plaintext_modulus();
+        Ok(())
+    }
+}
+
+impl<BE: Backend + BFVImpl<BE>> BFVDecrypt<BE> for Module<BE>
+where
+    Self: GLWEDecrypt<BE> + GLWENormalize<BE>,
+{
+    fn bfv_decrypt_tmp_bytes<A>(&self, ct_infos: &A) -> usize
+    where
+        A: GLWEInfos,
+    {
+        GLWEPlaintext::<AlignedBuf>::bytes_of_from_infos(ct_infos)
+            + self.glwe_decrypt_tmp_bytes(ct_infos).max(self.glwe_normalize_tmp_bytes())
+    }
+
+    fn bfv_decrypt<S>(
+        &self,
+        pt: &mut BFVPlaintext<impl DataMut>,
+        ct: &BFVCiphertext<impl DataRef>,
+        sk: &S,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        S: GLWESecretPreparedToRef<BE> + GLWEInfos,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        ensure_base2k_match("decrypt", ct.base2k().as_usize(), pt.base2k().as_usize())?;
+        let (mut full_pt, scratch_rest) = scratch.take_glwe_plaintext(ct);
+        self.glwe_decrypt(ct, &mut full_pt, sk, scratch_rest);
+        self.glwe_normalize(&mut pt.inner, &full_pt, scratch_rest);
+        pt.t = ct.plaintext_modulus();
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+pub(crate) mod add;
+pub(crate) mod encryption;
+pub(crate) mod mul;
+pub(crate) mod rotate;
+pub(crate) mod sub;
@@ -0,0 +1,94 @@
+use anyhow::Result;
+use poulpy_core::{
+    GLWEMulConst, GLWEMulPlain, GLWETensoring, ScratchTakeCore,
+    layouts::{GGLWEInfos, GLWEInfos, GLWETensorKeyPrepared},
+};
+use poulpy_hal::{
+    api::ScratchAvailable,
+    layouts::{Backend, DataMut, DataRef, Module, Scratch},
+};
+
+use crate::{
+    layouts::{BFVCiphertext, BFVPlaintext},
+    oep::BFVImpl,
+};
+
+use crate::leveled::{api::BFVMulOps, oep::BFVMulOep};
+
+impl<BE: Backend + BFVImpl<BE>> BFVMulOps<BE> for Module<BE> {
+    fn bfv_mul_tmp_bytes<R, A, B, T>(&self, res: &R, a: &A, b: &B, tsk: &T) -> usize
+    where
+        R: GLWEInfos,
+        A: GLWEInfos,
+        B: GLWEInfos,
+        T: GGLWEInfos,
+        Self: GLWEMulConst<BE> + GLWETensoring<BE>,
+    {
+        BFVMulOep::bfv_mul_tmp_bytes(self, res, a, b, tsk)
+    }
+
+    fn bfv_mul_into(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        b: &BFVCiphertext<impl DataRef>,
+        tsk: &GLWETensorKeyPrepared<impl DataRef, BE>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEMulConst<BE> + GLWETensoring<BE>,
+        Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+    {
+        BFVMulOep::bfv_mul_into(self, dst, a, b, tsk, scratch)
+    }
+
+    fn bfv_mul_assign(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        tsk: &GLWETensorKeyPrepared<impl DataRef, BE>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEMulConst<BE> + GLWETensoring<BE>,
+        Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+    {
+        BFVMulOep::bfv_mul_assign(self, dst, a, tsk, scratch)
+    }
+
+    fn bfv_mul_pt_tmp_bytes<R, A>(&self, res: &R, a: &A) -> usize
+    where
+        R: GLWEInfos,
+        A: GLWEInfos,
+        Self: GLWEMulPlain<BE>,
+    {
+        BFVMulOep::bfv_mul_pt_tmp_bytes(self, res, a)
+    }
+
+    fn bfv_mul_pt_into(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEMulPlain<BE>,
+        Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+    {
+        BFVMulOep::bfv_mul_pt_into(self, dst, a, pt, scratch)
+    }
+
+    fn bfv_mul_pt_assign(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEMulPlain<BE>,
+        Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+    {
+        BFVMulOep::bfv_mul_pt_assign(self, dst, pt, scratch)
+    }
+}
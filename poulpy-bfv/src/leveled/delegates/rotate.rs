@@ -0,0 +1,83 @@
+use anyhow::Result;
+use poulpy_core::{
+    GLWEAutomorphism, ScratchTakeCore,
+    layouts::{
+        GGLWEInfos, GGLWEPreparedToRef, GLWEAutomorphismKeyHelper, GLWEAutomorphismKeyPrepared, GLWEInfos, GetGaloisElement,
+    },
+};
+use poulpy_hal::layouts::{Backend, DataMut, DataRef, Module, Scratch};
+
+use crate::{layouts::BFVCiphertext, oep::BFVImpl};
+
+use crate::leveled::{api::BFVRotateOps, oep::BFVRotateOep};
+
+impl<BE: Backend + BFVImpl<BE>> BFVRotateOps<BE> for Module<BE> {
+    fn bfv_rotate_tmp_bytes<C, K>(&self, ct_infos: &C, key_infos: &K) -> usize
+    where
+        C: GLWEInfos,
+        K: GGLWEInfos,
+        Self: GLWEAutomorphism<BE>,
+    {
+        BFVRotateOep::bfv_rotate_tmp_bytes(self, ct_infos, key_infos)
+    }
+
+    fn bfv_rotate_into<H, K>(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        src: &BFVCiphertext<impl DataRef>,
+        k: i64,
+        keys: &H,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAutomorphism<BE>,
+        K: GGLWEPreparedToRef<BE> + GetGaloisElement + GGLWEInfos,
+        H: GLWEAutomorphismKeyHelper<K, BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        BFVRotateOep::bfv_rotate_into(self, dst, src, k, keys, scratch)
+    }
+
+    fn bfv_rotate_assign<H, K>(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        k: i64,
+        keys: &H,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAutomorphism<BE>,
+        K: GGLWEPreparedToRef<BE> + GetGaloisElement + GGLWEInfos,
+        H: GLWEAutomorphismKeyHelper<K, BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        BFVRotateOep::bfv_rotate_assign(self, dst, k, keys, scratch)
+    }
+
+    fn bfv_swap_rows_into(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        src: &BFVCiphertext<impl DataRef>,
+        key: &GLWEAutomorphismKeyPrepared<impl DataRef, BE>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAutomorphism<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        BFVRotateOep::bfv_swap_rows_into(self, dst, src, key, scratch)
+    }
+
+    fn bfv_swap_rows_assign(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        key: &GLWEAutomorphismKeyPrepared<impl DataRef, BE>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWEAutomorphism<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        BFVRotateOep::bfv_swap_rows_assign(self, dst, key, scratch)
+    }
+}
@@ -0,0 +1,73 @@
+use anyhow::Result;
+use poulpy_core::{GLWENormalize, GLWESub, ScratchTakeCore};
+use poulpy_hal::layouts::{Backend, DataMut, DataRef, Module, Scratch};
+
+use crate::{
+    layouts::{BFVCiphertext, BFVPlaintext},
+    oep::BFVImpl,
+};
+
+use crate::leveled::{api::BFVSubOps, oep::BFVSubOep};
+
+impl<BE: Backend + BFVImpl<BE>> BFVSubOps<BE> for Module<BE> {
+    fn bfv_sub_tmp_bytes(&self) -> usize
+    where
+        Self: GLWENormalize<BE>,
+    {
+        BFVSubOep::bfv_sub_tmp_bytes(self)
+    }
+
+    fn bfv_sub_into(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        b: &BFVCiphertext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWESub + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        BFVSubOep::bfv_sub_into(self, dst, a, b, scratch)
+    }
+
+    fn bfv_sub_assign(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWESub + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        BFVSubOep::bfv_sub_assign(self, dst, a, scratch)
+    }
+
+    fn bfv_sub_pt_into(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWESub + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        BFVSubOep::bfv_sub_pt_into(self, dst, a, pt, scratch)
+    }
+
+    fn bfv_sub_pt_assign(
+        &self,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Self: GLWESub + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        BFVSubOep::bfv_sub_pt_assign(self, dst, pt, scratch)
+    }
+}
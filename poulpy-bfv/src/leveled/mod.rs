@@ -0,0 +1,22 @@
+//! Leveled BFV arithmetic, encryption, and decryption.
+//!
+//! This module provides the leveled evaluation pipeline:
+//!
+//! - [`api`]: public trait definitions for all BFV operations.
+//! - [`delegates`]: blanket `impl Trait for Module<BE>` forwarding to OEP/default impls.
+//! - [`oep`]: backend dispatch traits bridging delegates to `BFVImpl`.
+//! - `default`: default algorithm implementations for each operation family
+//!   (`add`, `sub`, `mul`, `rotate`).
+//!
+//! All operations use scratch-based allocation; no heap allocation occurs
+//! during leveled arithmetic.
+
+pub mod api;
+pub(crate) mod delegates;
+pub(crate) mod oep;
+
+pub(crate) mod default;
+
+pub mod tests;
+
+pub use api::*;
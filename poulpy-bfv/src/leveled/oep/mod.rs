@@ -0,0 +1,9 @@
+pub(crate) mod add;
+pub(crate) mod mul;
+pub(crate) mod rotate;
+pub(crate) mod sub;
+
+pub(crate) use add::BFVAddOep;
+pub(crate) use mul::BFVMulOep;
+pub(crate) use rotate::BFVRotateOep;
+pub(crate) use sub::BFVSubOep;
@@ -0,0 +1,8 @@
+#[cfg(test)]
+pub mod fft64_ref;
+
+#[cfg(test)]
+pub mod test_suite;
+
+#[cfg(test)]
+pub mod ntt120_ref;
@@ -0,0 +1,11 @@
+use crate::bfv_backend_test_suite;
+
+// `-1` rotates right by one slot; it is not the row swap.
+const ATK_ROTATIONS: &[i64] = &[1, 7, -1];
+
+bfv_backend_test_suite!(
+    mod tests,
+    backend = poulpy_cpu_ref::NTT120Ref,
+    params = crate::leveled::tests::test_suite::NTT120_PARAMS,
+    rotations = super::ATK_ROTATIONS,
+);
@@ -0,0 +1,66 @@
+//! Addition tests (ciphertext + ciphertext and ciphertext + plaintext).
+//!
+//! # Test inventory
+//!
+//! | Function | Path exercised |
+//! |----------|----------------|
+//! | [`test_add_into`] | out-of-place ct + ct |
+//! | [`test_add_assign`] | in-place ct + ct |
+//! | [`test_add_pt_into`] | out-of-place ct + pt |
+//! | [`test_add_pt_assign`] | in-place ct + pt |
+//! | [`test_add_modulus_mismatch_error`] | operands under different `t` are rejected |
+
+use poulpy_hal::api::ScratchOwnedBorrow;
+
+use super::helpers::{TestBackend as Backend, TestContext, add_mod, assert_bfv_error};
+use crate::{BFVCompositionError, layouts::BFVPlaintext, leveled::api::BFVAddOps};
+
+pub fn test_add_into<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let a = ctx.encrypt(&ctx.m1, scratch.borrow());
+    let b = ctx.encrypt(&ctx.m2, scratch.borrow());
+    let mut res = ctx.alloc_ct();
+    ctx.module.bfv_add_into(&mut res, &a, &b, scratch.borrow()).unwrap();
+    ctx.assert_decrypt("add_into", &res, &ctx.want_binary(add_mod), scratch.borrow());
+}
+
+pub fn test_add_assign<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let mut a = ctx.encrypt(&ctx.m1, scratch.borrow());
+    let b = ctx.encrypt(&ctx.m2, scratch.borrow());
+    ctx.module.bfv_add_assign(&mut a, &b, scratch.borrow()).unwrap();
+    ctx.assert_decrypt("add_assign", &a, &ctx.want_binary(add_mod), scratch.borrow());
+}
+
+pub fn test_add_pt_into<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let a = ctx.encrypt(&ctx.m1, scratch.borrow());
+    let pt = ctx.encode(&ctx.m2);
+    let mut res = ctx.alloc_ct();
+    ctx.module.bfv_add_pt_into(&mut res, &a, &pt, scratch.borrow()).unwrap();
+    ctx.assert_decrypt("add_pt_into", &res, &ctx.want_binary(add_mod), scratch.borrow());
+}
+
+pub fn test_add_pt_assign<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let mut a = ctx.encrypt(&ctx.m1, scratch.borrow());
+    let pt = ctx.encode(&ctx.m2);
+    ctx.module.bfv_add_pt_assign(&mut a, &pt, scratch.borrow()).unwrap();
+    ctx.assert_decrypt("add_pt_assign", &a, &ctx.want_binary(add_mod), scratch.borrow());
+}
+
+pub fn test_add_modulus_mismatch_error<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let mut a = ctx.encrypt(&ctx.m1, scratch.borrow());
+    let pt = BFVPlaintext::alloc_from_infos(&ctx.params.glwe_layout(), 257);
+    let err = ctx.module.bfv_add_pt_assign(&mut a, &pt, scratch.borrow()).unwrap_err();
+    assert_bfv_error(
+        "add_pt_assign modulus_mismatch",
+        &err,
+        BFVCompositionError::PlaintextModulusMismatch {
+            op: "add_pt_assign",
+            lhs: ctx.t(),
+            rhs: 257,
+        },
+    );
+}
@@ -0,0 +1,57 @@
+//! Encryption and decryption tests.
+//!
+//! # Test inventory
+//!
+//! | Function | Path exercised |
+//! |----------|----------------|
+//! | [`test_encrypt_decrypt`] | encode, encrypt, decrypt, decode roundtrip |
+//! | [`test_encrypt_base2k_mismatch_error`] | plaintext/ciphertext `base2k` mismatch is rejected |
+
+use poulpy_core::layouts::{GLWELayout, Rank};
+use poulpy_hal::{api::ScratchOwnedBorrow, source::Source};
+
+use super::helpers::{TestBackend as Backend, TestContext, assert_bfv_error};
+use crate::{BFVCompositionError, BFVInfos, layouts::BFVPlaintext, leveled::api::BFVEncrypt};
+
+pub fn test_encrypt_decrypt<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let ct = ctx.encrypt(&ctx.m1, scratch.borrow());
+    assert_eq!(ct.plaintext_modulus(), ctx.t());
+    ctx.assert_decrypt("encrypt_decrypt", &ct, &ctx.m1, scratch.borrow());
+}
+
+pub fn test_encrypt_base2k_mismatch_error<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let base2k = ctx.params.base2k - 1;
+    let pt = BFVPlaintext::alloc_from_infos(
+        &GLWELayout {
+            n: ctx.params.n.into(),
+            base2k: base2k.into(),
+            k: ctx.params.k.into(),
+            rank: Rank(1),
+        },
+        ctx.t(),
+    );
+    let mut ct = ctx.alloc_ct();
+    let err = ctx
+        .module
+        .bfv_encrypt_sk(
+            &mut ct,
+            &pt,
+            &ctx.sk,
+            &ctx.params.glwe_layout(),
+            &mut Source::new([3u8; 32]),
+            &mut Source::new([4u8; 32]),
+            scratch.borrow(),
+        )
+        .unwrap_err();
+    assert_bfv_error(
+        "encrypt base2k_mismatch",
+        &err,
+        BFVCompositionError::PlaintextBase2KMismatch {
+            op: "encrypt_sk",
+            ct_base2k: ctx.params.base2k,
+            pt_base2k: base2k,
+        },
+    );
+}
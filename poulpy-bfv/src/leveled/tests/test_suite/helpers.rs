@@ -0,0 +1,268 @@
+//! Test context builder and slot assertion helpers.
+//!
+//! [`TestContext`] owns the backend module, prepared secret key, tensor key,
+//! automorphism keys, slot encoder, and two test messages.  It provides
+//! convenience methods for encrypt, decrypt-and-decode, and scratch allocation.
+
+use std::collections::HashMap;
+
+use super::BFVTestParams;
+use crate::{
+    BFVCompositionError,
+    encoding::Encoder,
+    layouts::{BFVCiphertext, BFVPlaintext},
+    leveled::api::{BFVAddOps, BFVDecrypt, BFVEncrypt, BFVMulOps, BFVRotateOps, BFVSubOps},
+    oep::BFVImpl,
+};
+use poulpy_core::{
+    GLWEAdd, GLWEAutomorphism, GLWEAutomorphismKeyEncryptSk, GLWEMulConst, GLWEMulPlain, GLWENormalize, GLWESub,
+    GLWETensorKeyEncryptSk, GLWETensoring, ScratchTakeCore,
+    layouts::{
+        GLWEAutomorphismKey, GLWEAutomorphismKeyPrepared, GLWEAutomorphismKeyPreparedFactory, GLWESecret,
+        GLWESecretPreparedFactory, GLWETensorKey, GLWETensorKeyPrepared, GLWETensorKeyPreparedFactory,
+        prepared::GLWESecretPrepared,
+    },
+    oep::CoreImpl,
+};
+use poulpy_hal::{
+    api::{ModuleN, ModuleNew, ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow},
+    layouts::{AlignedBuf, Backend, DataRef, DeviceBuf, GaloisElement, Module, Scratch, ScratchOwned},
+    oep::HalImpl,
+    source::Source,
+};
+
+pub trait TestBackend: Backend + CoreImpl<Self> + HalImpl<Self> + BFVImpl<Self>
+where
+    Module<Self>: ModuleNew<Self>
+        + ModuleN
+        + GLWESecretPreparedFactory<Self>
+        + GLWETensorKeyEncryptSk<Self>
+        + GLWETensorKeyPreparedFactory<Self>
+        + GLWEAutomorphismKeyEncryptSk<Self>
+        + GLWEAutomorphism<Self>
+        + GLWEAdd
+        + GLWESub
+        + GLWENormalize<Self>
+        + GLWEMulConst<Self>
+        + GLWEMulPlain<Self>
+        + GLWETensoring<Self>
+        + BFVEncrypt<Self>
+        + BFVDecrypt<Self>,
+    ScratchOwned<Self>: ScratchOwnedAlloc<Self> + ScratchOwnedBorrow<Self>,
+    Scratch<Self>: ScratchAvailable + ScratchTakeCore<Self>,
+{
+}
+
+impl<T> TestBackend for T
+where
+    T: Backend + CoreImpl<T> + HalImpl<T> + BFVImpl<T>,
+    Module<T>: ModuleNew<T>
+        + ModuleN
+        + GLWESecretPreparedFactory<T>
+        + GLWETensorKeyEncryptSk<T>
+        + GLWETensorKeyPreparedFactory<T>
+        + GLWEAutomorphismKeyEncryptSk<T>
+        + GLWEAutomorphism<T>
+        + GLWEAdd
+        + GLWESub
+        + GLWENormalize<T>
+        + GLWEMulConst<T>
+        + GLWEMulPlain<T>
+        + GLWETensoring<T>
+        + BFVEncrypt<T>
+        + BFVDecrypt<T>,
+    ScratchOwned<T>: ScratchOwnedAlloc<T> + ScratchOwnedBorrow<T>,
+    Scratch<T>: ScratchAvailable + ScratchTakeCore<T>,
+{
+}
+
+/// Shared test state: module, keys, encoder, and two slot messages.
+pub struct TestContext<BE: TestBackend> {
+    pub module: Module<BE>,
+    pub encoder: Encoder,
+    pub params: BFVTestParams,
+    pub sk: GLWESecretPrepared<DeviceBuf<BE>, BE>,
+    pub tsk: GLWETensorKeyPrepared<DeviceBuf<BE>, BE>,
+    pub atks: HashMap<i64, GLWEAutomorphismKeyPrepared<DeviceBuf<BE>, BE>>,
+    pub swap_key: GLWEAutomorphismKeyPrepared<DeviceBuf<BE>, BE>,
+    pub scratch_size: usize,
+    pub m1: Vec<u64>,
+    pub m2: Vec<u64>,
+}
+
+impl<BE: TestBackend> TestContext<BE> {
+    /// Creates a context with a prepared secret key, a tensor key, and
+    /// automorphism keys for each of `rotations` and for the row swap.
+    pub fn new(params: BFVTestParams, rotations: &[i64]) -> Self {
+        let module = Module::<BE>::new(params.n as u64);
+        let n = module.n();
+        let glwe_infos = params.glwe_layout();
+        let tsk_infos = params.tsk_layout();
+        let atk_infos = params.atk_layout();
+
+        let mut xa = Source::new([1u8; 32]);
+        let mut xe = Source::new([2u8; 32]);
+
+        let mut source_xs = Source::new([0u8; 32]);
+        let mut sk_raw = GLWESecret::alloc_from_infos(&glwe_infos);
+        sk_raw.fill_ternary_hw(params.hw, &mut source_xs);
+        let mut sk = module.glwe_secret_prepared_alloc_from_infos(&glwe_infos);
+        module.glwe_secret_prepare(&mut sk, &sk_raw);
+
+        let scratch_size = module
+            .bfv_encrypt_sk_tmp_bytes(&glwe_infos)
+            .max(module.bfv_decrypt_tmp_bytes(&glwe_infos))
+            .max(module.bfv_add_tmp_bytes())
+            .max(module.bfv_sub_tmp_bytes())
+            .max(module.bfv_mul_tmp_bytes(&glwe_infos, &glwe_infos, &glwe_infos, &tsk_infos))
+            .max(module.bfv_mul_pt_tmp_bytes(&glwe_infos, &glwe_infos))
+            .max(module.bfv_rotate_tmp_bytes(&glwe_infos, &atk_infos))
+            .max(module.glwe_tensor_key_encrypt_sk_tmp_bytes(&tsk_infos))
+            .max(module.prepare_tensor_key_tmp_bytes(&tsk_infos))
+            .max(module.glwe_automorphism_key_encrypt_sk_tmp_bytes(&atk_infos))
+            .max(module.glwe_automorphism_key_prepare_tmp_bytes(&atk_infos));
+        let mut scratch = ScratchOwned::<BE>::alloc(scratch_size);
+
+        let mut tsk = GLWETensorKey::alloc_from_infos(&tsk_infos);
+        module.glwe_tensor_key_encrypt_sk(&mut tsk, &sk_raw, &tsk_infos, &mut xa, &mut xe, scratch.borrow());
+        let mut tsk_prepared = module.alloc_tensor_key_prepared_from_infos(&tsk_infos);
+        module.prepare_tensor_key(&mut tsk_prepared, &tsk, scratch.borrow());
+
+        // Rotation keys are stored by rotation shift `k`; the row swap key,
+        // generated for the Galois element `-1`, is kept apart.
+        let mut gen_atk = |galois_element: i64| {
+            let mut atk = GLWEAutomorphismKey::alloc_from_infos(&atk_infos);
+            module.glwe_automorphism_key_encrypt_sk(
+                &mut atk,
+                galois_element,
+                &sk_raw,
+                &atk_infos,
+                &mut xa,
+                &mut xe,
+                scratch.borrow(),
+            );
+            let mut atk_prepared = module.glwe_automorphism_key_prepared_alloc_from_infos(&atk_infos);
+            module.glwe_automorphism_key_prepare(&mut atk_prepared, &atk, scratch.borrow());
+            atk_prepared
+        };
+
+        // `5` has order `N/2` modulo `2N`, so a right rotation by `|k|` uses
+        // `5^(k mod N/2)`; `galois_element(k)` would give `-5^|k|` for `k < 0`.
+        let mut atks = HashMap::new();
+        for &k in rotations {
+            atks.insert(k, gen_atk(module.galois_element(k.rem_euclid(n as i64 / 2))));
+        }
+        let swap_key = gen_atk(-1);
+
+        let t = params.t;
+        let m1 = (0..n as u64).map(|i| (i * i * 7919 + 13) % t).collect();
+        let m2 = (0..n as u64).map(|i| (t - 1 - i * 104729 % t) % t).collect();
+
+        Self {
+            encoder: Encoder::new(n, t).unwrap(),
+            module,
+            params,
+            sk,
+            tsk: tsk_prepared,
+            atks,
+            swap_key,
+            scratch_size,
+            m1,
+            m2,
+        }
+    }
+
+    pub fn t(&self) -> u64 {
+        self.params.t
+    }
+
+    pub fn tsk(&self) -> &GLWETensorKeyPrepared<DeviceBuf<BE>, BE> {
+        &self.tsk
+    }
+
+    pub fn atks(&self) -> &HashMap<i64, GLWEAutomorphismKeyPrepared<DeviceBuf<BE>, BE>> {
+        &self.atks
+    }
+
+    pub fn swap_key(&self) -> &GLWEAutomorphismKeyPrepared<DeviceBuf<BE>, BE> {
+        &self.swap_key
+    }
+
+    pub fn alloc_scratch(&self) -> ScratchOwned<BE> {
+        ScratchOwned::<BE>::alloc(self.scratch_size)
+    }
+
+    pub fn alloc_ct(&self) -> BFVCiphertext<AlignedBuf> {
+        BFVCiphertext::alloc_from_infos(&self.params.glwe_layout())
+    }
+
+    /// Encodes `values` into a plaintext with the ciphertext precision.
+    pub fn encode(&self, values: &[u64]) -> BFVPlaintext<AlignedBuf> {
+        let mut pt = BFVPlaintext::alloc_from_infos(&self.params.glwe_layout(), self.t());
+        self.encoder.encode(&mut pt, values).unwrap();
+        pt
+    }
+
+    pub fn encrypt(&self, values: &[u64], scratch: &mut Scratch<BE>) -> BFVCiphertext<AlignedBuf> {
+        let pt = self.encode(values);
+        let mut ct = self.alloc_ct();
+        let mut xa = Source::new([3u8; 32]);
+        let mut xe = Source::new([4u8; 32]);
+        self.module
+            .bfv_encrypt_sk(&mut ct, &pt, &self.sk, &self.params.glwe_layout(), &mut xa, &mut xe, scratch)
+            .unwrap();
+        ct
+    }
+
+    pub fn decrypt_decode(&self, ct: &BFVCiphertext<impl DataRef>, scratch: &mut Scratch<BE>) -> Vec<u64> {
+        let mut pt = BFVPlaintext::alloc_from_infos(&self.params.glwe_layout(), self.t());
+        self.module.bfv_decrypt(&mut pt, ct, &self.sk, scratch).unwrap();
+        let mut values = vec![0u64; self.encoder.slots()];
+        self.encoder.decode(&pt, &mut values).unwrap();
+        values
+    }
+
+    pub fn assert_decrypt(&self, label: &str, ct: &BFVCiphertext<impl DataRef>, want: &[u64], scratch: &mut Scratch<BE>) {
+        let got = self.decrypt_decode(ct, scratch);
+        if let Some(i) = (0..want.len()).find(|&i| got[i] != want[i]) {
+            panic!("{label}: slot {i} mismatch, got {} want {}", got[i], want[i]);
+        }
+    }
+
+    /// Returns the slot-wise `f(m1, m2)`.
+    pub fn want_binary(&self, f: impl Fn(u64, u64, u64) -> u64) -> Vec<u64> {
+        let t = self.t();
+        self.m1.iter().zip(&self.m2).map(|(&a, &b)| f(a, b, t)).collect()
+    }
+
+    /// Returns `values` with both rows rotated to the left by `k`.
+    pub fn want_rotate(&self, values: &[u64], k: i64) -> Vec<u64> {
+        let h = values.len() / 2;
+        (0..values.len())
+            .map(|j| values[(j / h) * h + (j % h + k.rem_euclid(h as i64) as usize) % h])
+            .collect()
+    }
+
+    /// Returns `values` with its two rows swapped.
+    pub fn want_swap_rows(&self, values: &[u64]) -> Vec<u64> {
+        let h = values.len() / 2;
+        (0..values.len()).map(|j| values[(j + h) % values.len()]).collect()
+    }
+}
+
+pub fn add_mod(a: u64, b: u64, t: u64) -> u64 {
+    (a + b) % t
+}
+
+pub fn sub_mod(a: u64, b: u64, t: u64) -> u64 {
+    (a + t - b) % t
+}
+
+pub fn mul_mod(a: u64, b: u64, t: u64) -> u64 {
+    ((a as u128 * b as u128) % t as u128) as u64
+}
+
+pub fn assert_bfv_error(label: &str, err: &anyhow::Error, want: BFVCompositionError) {
+    let got = err.downcast_ref::<BFVCompositionError>();
+    assert_eq!(got, Some(&want), "{label}: unexpected error: {err}");
+}
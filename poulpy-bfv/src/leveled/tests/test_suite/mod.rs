@@ -0,0 +1,193 @@
+//! Backend-generic BFV test suite.
+//!
+//! All test functions are generic over `BE: Backend` and take a
+//! [`TestContext`](helpers::TestContext) that owns the module, secret key,
+//! slot encoder, and evaluation keys.  The backend-specific test harnesses
+//! (`fft64_ref`, `ntt120_ref`) instantiate and invoke these functions.
+
+use poulpy_core::{
+    EncryptionLayout,
+    layouts::{GLWEAutomorphismKeyLayout, GLWELayout, GLWETensorKeyLayout, Rank},
+};
+
+pub mod add;
+pub mod encryption;
+pub mod helpers;
+pub mod mul;
+pub mod rotate;
+pub mod sub;
+
+/// Shared BFV parameter set for test instantiation.
+#[derive(Clone, Copy)]
+pub struct BFVTestParams {
+    pub n: usize,
+    pub base2k: usize,
+    pub k: usize,
+    pub t: u64,
+    pub hw: usize,
+    pub dsize: usize,
+}
+
+impl BFVTestParams {
+    pub fn glwe_layout(&self) -> EncryptionLayout<GLWELayout> {
+        EncryptionLayout::new_from_default_sigma(GLWELayout {
+            n: self.n.into(),
+            base2k: self.base2k.into(),
+            k: self.k.into(),
+            rank: Rank(1),
+        })
+        .unwrap()
+    }
+
+    pub fn tsk_layout(&self) -> EncryptionLayout<GLWETensorKeyLayout> {
+        let k = self.k + self.dsize * self.base2k;
+        let dnum = k.div_ceil(self.dsize * self.base2k);
+        EncryptionLayout::new_from_default_sigma(GLWETensorKeyLayout {
+            n: self.n.into(),
+            base2k: self.base2k.into(),
+            k: k.into(),
+            rank: Rank(1),
+            dsize: self.dsize.into(),
+            dnum: dnum.into(),
+        })
+        .unwrap()
+    }
+
+    pub fn atk_layout(&self) -> EncryptionLayout<GLWEAutomorphismKeyLayout> {
+        let k = self.k + self.dsize * self.base2k;
+        let dnum = k.div_ceil(self.dsize * self.base2k);
+        EncryptionLayout::new_from_default_sigma(GLWEAutomorphismKeyLayout {
+            n: self.n.into(),
+            base2k: self.base2k.into(),
+            k: k.into(),
+            rank: Rank(1),
+            dsize: self.dsize.into(),
+            dnum: dnum.into(),
+        })
+        .unwrap()
+    }
+}
+
+/// FFT64 parameter set (`t = 65537 = 1 mod 512`).
+pub const FFT64_PARAMS: BFVTestParams = BFVTestParams {
+    n: 256,
+    base2k: 19,
+    k: 8 * 19,
+    t: 65537,
+    hw: 192,
+    dsize: 1,
+};
+
+/// NTT120 parameter set (`t = 65537 = 1 mod 512`).
+pub const NTT120_PARAMS: BFVTestParams = BFVTestParams {
+    n: 256,
+    base2k: 52,
+    k: 6 * 52,
+    t: 65537,
+    hw: 192,
+    dsize: 1,
+};
+
+#[macro_export]
+macro_rules! bfv_backend_test_suite {
+    (
+        mod $modname:ident,
+        backend = $backend:ty,
+        params = $params:expr,
+        rotations = $rotations:expr $(,)?
+    ) => {
+        mod $modname {
+            use std::sync::LazyLock;
+
+            use $crate::leveled::tests::test_suite::helpers::TestContext;
+
+            static CTX: LazyLock<TestContext<$backend>> = LazyLock::new(|| TestContext::new($params, $rotations));
+
+            macro_rules! run_test {
+                ($name:ident, $path:path) => {
+                    #[test]
+                    fn $name() {
+                        $path(&CTX);
+                    }
+                };
+            }
+
+            macro_rules! run_test_with_arg {
+                ($name:ident, $path:path, $arg:expr) => {
+                    #[test]
+                    fn $name() {
+                        $path(&CTX, $arg);
+                    }
+                };
+            }
+
+            run_test!(
+                encrypt_decrypt,
+                $crate::leveled::tests::test_suite::encryption::test_encrypt_decrypt
+            );
+            run_test!(
+                encrypt_base2k_mismatch_error,
+                $crate::leveled::tests::test_suite::encryption::test_encrypt_base2k_mismatch_error
+            );
+            run_test!(add_into, $crate::leveled::tests::test_suite::add::test_add_into);
+            run_test!(add_assign, $crate::leveled::tests::test_suite::add::test_add_assign);
+            run_test!(add_pt_into, $crate::leveled::tests::test_suite::add::test_add_pt_into);
+            run_test!(
+                add_pt_assign,
+                $crate::leveled::tests::test_suite::add::test_add_pt_assign
+            );
+            run_test!(
+                add_modulus_mismatch_error,
+                $crate::leveled::tests::test_suite::add::test_add_modulus_mismatch_error
+            );
+            run_test!(sub_into, $crate::leveled::tests::test_suite::sub::test_sub_into);
+            run_test!(sub_assign, $crate::leveled::tests::test_suite::sub::test_sub_assign);
+            run_test!(sub_pt_into, $crate::leveled::tests::test_suite::sub::test_sub_pt_into);
+            run_test!(
+                sub_pt_assign,
+                $crate::leveled::tests::test_suite::sub::test_sub_pt_assign
+            );
+            run_test!(mul_into, $crate::leveled::tests::test_suite::mul::test_mul_into);
+            run_test!(mul_assign, $crate::leveled::tests::test_suite::mul::test_mul_assign);
+            run_test!(
+                mul_depth_two,
+                $crate::leveled::tests::test_suite::mul::test_mul_depth_two
+            );
+            run_test!(
+                mul_into_smaller_dst,
+                $crate::leveled::tests::test_suite::mul::test_mul_into_smaller_dst
+            );
+            run_test!(mul_pt_into, $crate::leveled::tests::test_suite::mul::test_mul_pt_into);
+            run_test!(
+                mul_pt_assign,
+                $crate::leveled::tests::test_suite::mul::test_mul_pt_assign
+            );
+            run_test!(
+                mul_modulus_too_large_error,
+                $crate::leveled::tests::test_suite::mul::test_mul_modulus_too_large_error
+            );
+            run_test_with_arg!(
+                rotate_into,
+                $crate::leveled::tests::test_suite::rotate::test_rotate_into,
+                $rotations
+            );
+            run_test_with_arg!(
+                rotate_assign,
+                $crate::leveled::tests::test_suite::rotate::test_rotate_assign,
+                $rotations
+            );
+            run_test!(
+                swap_rows_into,
+                $crate::leveled::tests::test_suite::rotate::test_swap_rows_into
+            );
+            run_test!(
+                swap_rows_assign,
+                $crate::leveled::tests::test_suite::rotate::test_swap_rows_assign
+            );
+            run_test!(
+                rotate_missing_key_error,
+                $crate::leveled::tests::test_suite::rotate::test_rotate_missing_key_error
+            );
+        }
+    };
+}
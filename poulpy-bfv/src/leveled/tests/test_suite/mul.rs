@@ -0,0 +1,117 @@
+//! Multiplication tests (ciphertext x ciphertext and ciphertext x plaintext).
+//!
+//! # Test inventory
+//!
+//! | Function | Path exercised |
+//! |----------|----------------|
+//! | [`test_mul_into`] | out-of-place ct x ct with relinearization |
+//! | [`test_mul_assign`] | in-place ct x ct with relinearization |
+//! | [`test_mul_depth_two`] | two chained ct x ct products |
+//! | [`test_mul_into_smaller_dst`] | ct x ct into a destination smaller than the operands, with exactly `bfv_mul_tmp_bytes` of scratch |
+//! | [`test_mul_pt_into`] | out-of-place ct x pt |
+//! | [`test_mul_pt_assign`] | in-place ct x pt |
+//! | [`test_mul_modulus_too_large_error`] | `t >= 2^(base2k-1)` is rejected |
+
+use poulpy_core::layouts::{GLWELayout, Rank};
+use poulpy_hal::{
+    api::{ScratchOwnedAlloc, ScratchOwnedBorrow},
+    layouts::ScratchOwned,
+};
+
+use super::helpers::{TestBackend as Backend, TestContext, assert_bfv_error, mul_mod};
+use crate::{
+    BFVCompositionError,
+    layouts::{BFVCiphertext, BFVPlaintext},
+    leveled::api::BFVMulOps,
+};
+
+pub fn test_mul_into<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let a = ctx.encrypt(&ctx.m1, scratch.borrow());
+    let b = ctx.encrypt(&ctx.m2, scratch.borrow());
+    let mut res = ctx.alloc_ct();
+    ctx.module
+        .bfv_mul_into(&mut res, &a, &b, ctx.tsk(), scratch.borrow())
+        .unwrap();
+    ctx.assert_decrypt("mul_into", &res, &ctx.want_binary(mul_mod), scratch.borrow());
+}
+
+pub fn test_mul_assign<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let mut a = ctx.encrypt(&ctx.m1, scratch.borrow());
+    let b = ctx.encrypt(&ctx.m2, scratch.borrow());
+    ctx.module.bfv_mul_assign(&mut a, &b, ctx.tsk(), scratch.borrow()).unwrap();
+    ctx.assert_decrypt("mul_assign", &a, &ctx.want_binary(mul_mod), scratch.borrow());
+}
+
+pub fn test_mul_depth_two<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let a = ctx.encrypt(&ctx.m1, scratch.borrow());
+    let b = ctx.encrypt(&ctx.m2, scratch.borrow());
+    let mut res = ctx.alloc_ct();
+    ctx.module
+        .bfv_mul_into(&mut res, &a, &b, ctx.tsk(), scratch.borrow())
+        .unwrap();
+    ctx.module.bfv_mul_assign(&mut res, &a, ctx.tsk(), scratch.borrow()).unwrap();
+    let want = ctx.want_binary(|a, b, t| mul_mod(mul_mod(a, b, t), a, t));
+    ctx.assert_decrypt("mul_depth_two", &res, &want, scratch.borrow());
+}
+
+pub fn test_mul_into_smaller_dst<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let a = ctx.encrypt(&ctx.m1, scratch.borrow());
+    let b = ctx.encrypt(&ctx.m2, scratch.borrow());
+    let dst_layout = GLWELayout {
+        n: ctx.params.n.into(),
+        base2k: ctx.params.base2k.into(),
+        k: (ctx.params.k - ctx.params.base2k).into(),
+        rank: Rank(1),
+    };
+    let mut res = BFVCiphertext::alloc_from_infos(&dst_layout);
+    let mut mul_scratch = ScratchOwned::<BE>::alloc(ctx.module.bfv_mul_tmp_bytes(&dst_layout, &a, &b, &ctx.params.tsk_layout()));
+    ctx.module
+        .bfv_mul_into(&mut res, &a, &b, ctx.tsk(), mul_scratch.borrow())
+        .unwrap();
+    ctx.assert_decrypt("mul_into_smaller_dst", &res, &ctx.want_binary(mul_mod), scratch.borrow());
+}
+
+pub fn test_mul_pt_into<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let a = ctx.encrypt(&ctx.m1, scratch.borrow());
+    let pt = ctx.encode(&ctx.m2);
+    let mut res = ctx.alloc_ct();
+    ctx.module.bfv_mul_pt_into(&mut res, &a, &pt, scratch.borrow()).unwrap();
+    ctx.assert_decrypt("mul_pt_into", &res, &ctx.want_binary(mul_mod), scratch.borrow());
+}
+
+pub fn test_mul_pt_assign<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let mut a = ctx.encrypt(&ctx.m1, scratch.borrow());
+    let pt = ctx.encode(&ctx.m2);
+    ctx.module.bfv_mul_pt_assign(&mut a, &pt, scratch.borrow()).unwrap();
+    ctx.assert_decrypt("mul_pt_assign", &a, &ctx.want_binary(mul_mod), scratch.borrow());
+}
+
+pub fn test_mul_modulus_too_large_error<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let t = 1u64 << (ctx.params.base2k - 1);
+    let layout = GLWELayout {
+        n: ctx.params.n.into(),
+        base2k: ctx.params.base2k.into(),
+        k: ctx.params.k.into(),
+        rank: Rank(1),
+    };
+    let mut a = BFVCiphertext::alloc_from_infos(&layout);
+    a.t = t;
+    let pt = BFVPlaintext::alloc_from_infos(&layout, t);
+    let err = ctx.module.bfv_mul_pt_assign(&mut a, &pt, scratch.borrow()).unwrap_err();
+    assert_bfv_error(
+        "mul_pt_assign modulus_too_large",
+        &err,
+        BFVCompositionError::PlaintextModulusExceedsBase2K {
+            op: "mul_pt_assign",
+            t,
+            base2k: ctx.params.base2k,
+        },
+    );
+}
@@ -0,0 +1,89 @@
+//! Slot rotation and row swap tests.
+//!
+//! # Test inventory
+//!
+//! | Function | Path exercised |
+//! |----------|----------------|
+//! | [`test_rotate_into`] | out-of-place row rotation for each requested shift |
+//! | [`test_rotate_assign`] | in-place row rotation for each requested shift |
+//! | [`test_swap_rows_into`] | out-of-place row swap |
+//! | [`test_swap_rows_assign`] | in-place row swap |
+//! | [`test_rotate_missing_key_error`] | missing automorphism key is reported |
+
+use std::collections::HashMap;
+
+use poulpy_core::layouts::GLWEAutomorphismKeyPrepared;
+use poulpy_hal::{api::ScratchOwnedBorrow, layouts::DeviceBuf};
+
+use super::helpers::{TestBackend as Backend, TestContext, assert_bfv_error};
+use crate::{BFVCompositionError, leveled::api::BFVRotateOps};
+
+pub fn test_rotate_into<BE: Backend>(ctx: &TestContext<BE>, rotations: &[i64]) {
+    let mut scratch = ctx.alloc_scratch();
+    let ct = ctx.encrypt(&ctx.m1, scratch.borrow());
+    for &r in rotations {
+        let mut res = ctx.alloc_ct();
+        ctx.module
+            .bfv_rotate_into(&mut res, &ct, r, ctx.atks(), scratch.borrow())
+            .unwrap();
+        ctx.assert_decrypt(
+            &format!("rotate_into({r})"),
+            &res,
+            &ctx.want_rotate(&ctx.m1, r),
+            scratch.borrow(),
+        );
+    }
+}
+
+pub fn test_rotate_assign<BE: Backend>(ctx: &TestContext<BE>, rotations: &[i64]) {
+    let mut scratch = ctx.alloc_scratch();
+    for &r in rotations {
+        let mut ct = ctx.encrypt(&ctx.m1, scratch.borrow());
+        ctx.module
+            .bfv_rotate_assign(&mut ct, r, ctx.atks(), scratch.borrow())
+            .unwrap();
+        ctx.assert_decrypt(
+            &format!("rotate_assign({r})"),
+            &ct,
+            &ctx.want_rotate(&ctx.m1, r),
+            scratch.borrow(),
+        );
+    }
+}
+
+pub fn test_swap_rows_into<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let ct = ctx.encrypt(&ctx.m1, scratch.borrow());
+    let mut res = ctx.alloc_ct();
+    ctx.module
+        .bfv_swap_rows_into(&mut res, &ct, ctx.swap_key(), scratch.borrow())
+        .unwrap();
+    ctx.assert_decrypt("swap_rows_into", &res, &ctx.want_swap_rows(&ctx.m1), scratch.borrow());
+}
+
+pub fn test_swap_rows_assign<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let mut ct = ctx.encrypt(&ctx.m1, scratch.borrow());
+    ctx.module
+        .bfv_swap_rows_assign(&mut ct, ctx.swap_key(), scratch.borrow())
+        .unwrap();
+    ctx.assert_decrypt("swap_rows_assign", &ct, &ctx.want_swap_rows(&ctx.m1), scratch.borrow());
+}
+
+pub fn test_rotate_missing_key_error<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let mut ct = ctx.encrypt(&ctx.m1, scratch.borrow());
+    let empty_keys: HashMap<i64, GLWEAutomorphismKeyPrepared<DeviceBuf<BE>, BE>> = HashMap::new();
+    let err = ctx
+        .module
+        .bfv_rotate_assign(&mut ct, 1, &empty_keys, scratch.borrow())
+        .unwrap_err();
+    assert_bfv_error(
+        "rotate_assign missing_key",
+        &err,
+        BFVCompositionError::MissingAutomorphismKey {
+            op: "rotate_assign",
+            rotation: 1,
+        },
+    );
+}
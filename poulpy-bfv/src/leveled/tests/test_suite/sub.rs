@@ -0,0 +1,49 @@
+//! Subtraction tests (ciphertext - ciphertext and ciphertext - plaintext).
+//!
+//! # Test inventory
+//!
+//! | Function | Path exercised |
+//! |----------|----------------|
+//! | [`test_sub_into`] | out-of-place ct - ct |
+//! | [`test_sub_assign`] | in-place ct - ct |
+//! | [`test_sub_pt_into`] | out-of-place ct - pt |
+//! | [`test_sub_pt_assign`] | in-place ct - pt |
+
+use poulpy_hal::api::ScratchOwnedBorrow;
+
+use super::helpers::{TestBackend as Backend, TestContext, sub_mod};
+use crate::leveled::api::BFVSubOps;
+
+pub fn test_sub_into<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let a = ctx.encrypt(&ctx.m1, scratch.borrow());
+    let b = ctx.encrypt(&ctx.m2, scratch.borrow());
+    let mut res = ctx.alloc_ct();
+    ctx.module.bfv_sub_into(&mut res, &a, &b, scratch.borrow()).unwrap();
+    ctx.assert_decrypt("sub_into", &res, &ctx.want_binary(sub_mod), scratch.borrow());
+}
+
+pub fn test_sub_assign<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let mut a = ctx.encrypt(&ctx.m1, scratch.borrow());
+    let b = ctx.encrypt(&ctx.m2, scratch.borrow());
+    ctx.module.bfv_sub_assign(&mut a, &b, scratch.borrow()).unwrap();
+    ctx.assert_decrypt("sub_assign", &a, &ctx.want_binary(sub_mod), scratch.borrow());
+}
+
+pub fn test_sub_pt_into<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let a = ctx.encrypt(&ctx.m1, scratch.borrow());
+    let pt = ctx.encode(&ctx.m2);
+    let mut res = ctx.alloc_ct();
+    ctx.module.bfv_sub_pt_into(&mut res, &a, &pt, scratch.borrow()).unwrap();
+    ctx.assert_decrypt("sub_pt_into", &res, &ctx.want_binary(sub_mod), scratch.borrow());
+}
+
+pub fn test_sub_pt_assign<BE: Backend>(ctx: &TestContext<BE>) {
+    let mut scratch = ctx.alloc_scratch();
+    let mut a = ctx.encrypt(&ctx.m1, scratch.borrow());
+    let pt = ctx.encode(&ctx.m2);
+    ctx.module.bfv_sub_pt_assign(&mut a, &pt, scratch.borrow()).unwrap();
+    ctx.assert_decrypt("sub_pt_assign", &a, &ctx.want_binary(sub_mod), scratch.borrow());
+}
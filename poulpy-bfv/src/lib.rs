@@ -0,0 +1,44 @@
+//! # poulpy-bfv
+//!
+//! Backend-agnostic implementation of the BFV (Brakerski/Fan-Vercauteren)
+//! homomorphic encryption scheme, built on top of the low-level primitives
+//! provided by `poulpy-core`, `poulpy-hal`, and the available compute
+//! backends (`poulpy-cpu-ref`, `poulpy-cpu-avx`).
+//!
+//! BFV provides exact arithmetic on vectors of integers modulo a plaintext
+//! modulus `t`. Like `poulpy-ckks`, the crate uses the Torus representation
+//! (base-`2^{base2k}` digits) instead of an RNS prime chain: a message
+//! polynomial `m in Z_t[X]/(X^N+1)` is encrypted as a GLWE ciphertext whose
+//! phase is `m/t + e mod 1`. Ciphertext-ciphertext multiplication computes the
+//! tensor product over the reals, scales it by `t` and relinearizes it, so no
+//! explicit modulus switching is required.
+//!
+//! When `t` is a prime with `t = 1 mod 2N`, the plaintext ring splits into `N`
+//! integer slots arranged as a `2 x N/2` matrix (see [`encoding::Encoder`]).
+//! Slot rotations act on the rows of that matrix and are implemented with
+//! GLWE automorphisms.
+//!
+//! ## Modules
+//!
+//! | Module | Role |
+//! |--------|------|
+//! | [`encoding`] | Slot batching of integer vectors modulo `t` |
+//! | [`layouts`] | BFV ciphertext/plaintext wrappers carrying the plaintext modulus |
+//! | [`leveled`] | Leveled arithmetic (add, sub, mul, rotate), encryption, and decryption |
+
+pub mod encoding;
+mod error;
+pub mod layouts;
+pub mod leveled;
+pub mod oep;
+pub use error::BFVCompositionError;
+pub(crate) use error::{ensure_base2k_match, ensure_plaintext_modulus_fits, ensure_plaintext_modulus_match};
+
+/// Common metadata accessors for BFV ciphertext and plaintext containers.
+pub trait BFVInfos {
+    /// Returns the plaintext modulus `t` of the encoded value.
+    ///
+    /// A value of `0` denotes a ciphertext that has been allocated but not
+    /// yet written by an encryption or an evaluation.
+    fn plaintext_modulus(&self) -> u64;
+}
@@ -0,0 +1,67 @@
+#[macro_export]
+macro_rules! impl_bfv_add_default_methods {
+    ($backend:ty) => {
+        fn bfv_add_tmp_bytes(module: &poulpy_hal::layouts::Module<$backend>) -> usize
+        where
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWENormalize<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::add::BFVAddDefault<$backend>>::bfv_add_tmp_bytes_default(module)
+        }
+
+        fn bfv_add_into(
+            module: &poulpy_hal::layouts::Module<$backend>,
+            dst: &mut $crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataMut>,
+            a: &$crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataRef>,
+            b: &$crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataRef>,
+            scratch: &mut poulpy_hal::layouts::Scratch<$backend>,
+        ) -> anyhow::Result<()>
+        where
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWEAdd + poulpy_core::GLWENormalize<$backend>,
+            poulpy_hal::layouts::Scratch<$backend>: poulpy_core::ScratchTakeCore<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::add::BFVAddDefault<$backend>>::bfv_add_into_default(module, dst, a, b, scratch)
+        }
+
+        fn bfv_add_assign(
+            module: &poulpy_hal::layouts::Module<$backend>,
+            dst: &mut $crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataMut>,
+            a: &$crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataRef>,
+            scratch: &mut poulpy_hal::layouts::Scratch<$backend>,
+        ) -> anyhow::Result<()>
+        where
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWEAdd + poulpy_core::GLWENormalize<$backend>,
+            poulpy_hal::layouts::Scratch<$backend>: poulpy_core::ScratchTakeCore<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::add::BFVAddDefault<$backend>>::bfv_add_assign_default(module, dst, a, scratch)
+        }
+
+        fn bfv_add_pt_into(
+            module: &poulpy_hal::layouts::Module<$backend>,
+            dst: &mut $crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataMut>,
+            a: &$crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataRef>,
+            pt: &$crate::layouts::BFVPlaintext<impl poulpy_hal::layouts::DataRef>,
+            scratch: &mut poulpy_hal::layouts::Scratch<$backend>,
+        ) -> anyhow::Result<()>
+        where
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWEAdd + poulpy_core::GLWENormalize<$backend>,
+            poulpy_hal::layouts::Scratch<$backend>: poulpy_core::ScratchTakeCore<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::add::BFVAddDefault<$backend>>::bfv_add_pt_into_default(module, dst, a, pt, scratch)
+        }
+
+        fn bfv_add_pt_assign(
+            module: &poulpy_hal::layouts::Module<$backend>,
+            dst: &mut $crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataMut>,
+            pt: &$crate::layouts::BFVPlaintext<impl poulpy_hal::layouts::DataRef>,
+            scratch: &mut poulpy_hal::layouts::Scratch<$backend>,
+        ) -> anyhow::Result<()>
+        where
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWEAdd + poulpy_core::GLWENormalize<$backend>,
+            poulpy_hal::layouts::Scratch<$backend>: poulpy_core::ScratchTakeCore<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::add::BFVAddDefault<$backend>>::bfv_add_pt_assign_default(module, dst, pt, scratch)
+        }
+    };
+}
+
+pub use crate::impl_bfv_add_default_methods;
@@ -0,0 +1,245 @@
+#![allow(clippy::too_many_arguments)]
+
+use anyhow::Result;
+use poulpy_core::{
+    GLWEAdd, GLWEAutomorphism, GLWEMulConst, GLWEMulPlain, GLWENormalize, GLWESub, GLWETensoring, ScratchTakeCore,
+    layouts::{
+        GGLWEInfos, GGLWEPreparedToRef, GLWEAutomorphismKeyHelper, GLWEAutomorphismKeyPrepared, GLWEInfos, GLWETensorKeyPrepared,
+        GetGaloisElement,
+    },
+};
+use poulpy_hal::{
+    api::ScratchAvailable,
+    layouts::{Backend, DataMut, DataRef, Module, Scratch},
+};
+
+use crate::layouts::{BFVCiphertext, BFVPlaintext};
+
+/// Backend-owned BFV leveled-operations extension point.
+///
+/// `Module<BE>` remains the public execution surface. Backend crates can
+/// implement this trait on their backend marker type to override BFV-level
+/// algorithms while preserving the existing module-facing API.
+///
+/// # Safety
+/// Implementors must preserve the plaintext-modulus invariants of BFV
+/// ciphertexts and must obey the scratch, sizing, aliasing, and layout
+/// contracts required by the underlying `poulpy-core` and `poulpy-hal`
+/// operations they call.
+pub unsafe trait BFVImpl<BE: Backend>: Backend {
+    fn bfv_add_tmp_bytes(module: &Module<BE>) -> usize
+    where
+        Module<BE>: GLWENormalize<BE>;
+
+    fn bfv_add_into(
+        module: &Module<BE>,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        b: &BFVCiphertext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Module<BE>: GLWEAdd + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    fn bfv_add_assign(
+        module: &Module<BE>,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Module<BE>: GLWEAdd + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    fn bfv_add_pt_into(
+        module: &Module<BE>,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Module<BE>: GLWEAdd + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    fn bfv_add_pt_assign(
+        module: &Module<BE>,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Module<BE>: GLWEAdd + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    fn bfv_sub_tmp_bytes(module: &Module<BE>) -> usize
+    where
+        Module<BE>: GLWENormalize<BE>;
+
+    fn bfv_sub_into(
+        module: &Module<BE>,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        b: &BFVCiphertext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Module<BE>: GLWESub + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    fn bfv_sub_assign(
+        module: &Module<BE>,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Module<BE>: GLWESub + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    fn bfv_sub_pt_into(
+        module: &Module<BE>,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Module<BE>: GLWESub + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    fn bfv_sub_pt_assign(
+        module: &Module<BE>,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Module<BE>: GLWESub + GLWENormalize<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    fn bfv_mul_tmp_bytes<R, A, B, T>(module: &Module<BE>, res: &R, a: &A, b: &B, tsk: &T) -> usize
+    where
+        R: GLWEInfos,
+        A: GLWEInfos,
+        B: GLWEInfos,
+        T: GGLWEInfos,
+        Module<BE>: GLWEMulConst<BE> + GLWETensoring<BE>;
+
+    fn bfv_mul_into(
+        module: &Module<BE>,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        b: &BFVCiphertext<impl DataRef>,
+        tsk: &GLWETensorKeyPrepared<impl DataRef, BE>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Module<BE>: GLWEMulConst<BE> + GLWETensoring<BE>,
+        Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>;
+
+    fn bfv_mul_assign(
+        module: &Module<BE>,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        tsk: &GLWETensorKeyPrepared<impl DataRef, BE>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Module<BE>: GLWEMulConst<BE> + GLWETensoring<BE>,
+        Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>;
+
+    fn bfv_mul_pt_tmp_bytes<R, A>(module: &Module<BE>, res: &R, a: &A) -> usize
+    where
+        R: GLWEInfos,
+        A: GLWEInfos,
+        Module<BE>: GLWEMulPlain<BE>;
+
+    fn bfv_mul_pt_into(
+        module: &Module<BE>,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        a: &BFVCiphertext<impl DataRef>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Module<BE>: GLWEMulPlain<BE>,
+        Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>;
+
+    fn bfv_mul_pt_assign(
+        module: &Module<BE>,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        pt: &BFVPlaintext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Module<BE>: GLWEMulPlain<BE>,
+        Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>;
+
+    fn bfv_rotate_tmp_bytes<C, K>(module: &Module<BE>, ct_infos: &C, key_infos: &K) -> usize
+    where
+        C: GLWEInfos,
+        K: GGLWEInfos,
+        Module<BE>: GLWEAutomorphism<BE>;
+
+    fn bfv_rotate_into<H, K>(
+        module: &Module<BE>,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        src: &BFVCiphertext<impl DataRef>,
+        k: i64,
+        keys: &H,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Module<BE>: GLWEAutomorphism<BE>,
+        K: GGLWEPreparedToRef<BE> + GetGaloisElement + GGLWEInfos,
+        H: GLWEAutomorphismKeyHelper<K, BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    fn bfv_rotate_assign<H, K>(
+        module: &Module<BE>,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        k: i64,
+        keys: &H,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Module<BE>: GLWEAutomorphism<BE>,
+        K: GGLWEPreparedToRef<BE> + GetGaloisElement + GGLWEInfos,
+        H: GLWEAutomorphismKeyHelper<K, BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    fn bfv_swap_rows_into(
+        module: &Module<BE>,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        src: &BFVCiphertext<impl DataRef>,
+        key: &GLWEAutomorphismKeyPrepared<impl DataRef, BE>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Module<BE>: GLWEAutomorphism<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    fn bfv_swap_rows_assign(
+        module: &Module<BE>,
+        dst: &mut BFVCiphertext<impl DataMut>,
+        key: &GLWEAutomorphismKeyPrepared<impl DataRef, BE>,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        Module<BE>: GLWEAutomorphism<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>;
+}
+
+#[macro_export]
+macro_rules! impl_bfv_default_methods {
+    ($backend:ty) => {
+        $crate::impl_bfv_add_default_methods!($backend);
+        $crate::impl_bfv_sub_default_methods!($backend);
+        $crate::impl_bfv_mul_default_methods!($backend);
+        $crate::impl_bfv_rotate_default_methods!($backend);
+    };
+}
+
+pub use crate::impl_bfv_default_methods;
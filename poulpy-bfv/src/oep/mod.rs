@@ -0,0 +1,52 @@
+mod add;
+mod bfv_impl;
+mod mul;
+mod rotate;
+mod sub;
+
+pub use add::impl_bfv_add_default_methods;
+pub use bfv_impl::BFVImpl;
+pub use bfv_impl::impl_bfv_default_methods;
+pub use mul::impl_bfv_mul_default_methods;
+pub use rotate::impl_bfv_rotate_default_methods;
+pub use sub::impl_bfv_sub_default_methods;
+
+unsafe impl BFVImpl<poulpy_cpu_ref::FFT64Ref> for poulpy_cpu_ref::FFT64Ref {
+    crate::impl_bfv_default_methods!(poulpy_cpu_ref::FFT64Ref);
+}
+
+unsafe impl BFVImpl<poulpy_cpu_ref::NTT120Ref> for poulpy_cpu_ref::NTT120Ref {
+    crate::impl_bfv_default_methods!(poulpy_cpu_ref::NTT120Ref);
+}
+
+unsafe impl<Family: poulpy_hal::dyn_backend::DynBackendFamily> BFVImpl<poulpy_hal::dyn_backend::DynBackend<Family>>
+    for poulpy_hal::dyn_backend::DynBackend<Family>
+{
+    crate::impl_bfv_default_methods!(poulpy_hal::dyn_backend::DynBackend<Family>);
+}
+
+unsafe impl<BE: poulpy_cpu_mt::FFT64MtBase> BFVImpl<poulpy_cpu_mt::FFT64Mt<BE>> for poulpy_cpu_mt::FFT64Mt<BE> {
+    crate::impl_bfv_default_methods!(poulpy_cpu_mt::FFT64Mt<BE>);
+}
+
+unsafe impl<BE> BFVImpl<poulpy_hal::profiling::Profiled<BE>> for poulpy_hal::profiling::Profiled<BE>
+where
+    BE: poulpy_hal::layouts::Backend + poulpy_hal::oep::HalImpl<BE> + 'static,
+{
+    crate::impl_bfv_default_methods!(poulpy_hal::profiling::Profiled<BE>);
+}
+
+#[cfg(feature = "enable-avx")]
+unsafe impl BFVImpl<poulpy_cpu_avx::FFT64Avx> for poulpy_cpu_avx::FFT64Avx {
+    crate::impl_bfv_default_methods!(poulpy_cpu_avx::FFT64Avx);
+}
+
+#[cfg(feature = "enable-avx")]
+unsafe impl BFVImpl<poulpy_cpu_avx::NTT120Avx> for poulpy_cpu_avx::NTT120Avx {
+    crate::impl_bfv_default_methods!(poulpy_cpu_avx::NTT120Avx);
+}
+
+#[cfg(feature = "enable-simd")]
+unsafe impl BFVImpl<poulpy_cpu_simd::FFT64Simd> for poulpy_cpu_simd::FFT64Simd {
+    crate::impl_bfv_default_methods!(poulpy_cpu_simd::FFT64Simd);
+}
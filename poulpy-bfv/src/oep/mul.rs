@@ -0,0 +1,88 @@
+#[macro_export]
+macro_rules! impl_bfv_mul_default_methods {
+    ($backend:ty) => {
+        fn bfv_mul_tmp_bytes<R, A, B, T>(
+            module: &poulpy_hal::layouts::Module<$backend>,
+            res: &R,
+            a: &A,
+            b: &B,
+            tsk: &T,
+        ) -> usize
+        where
+            R: poulpy_core::layouts::GLWEInfos,
+            A: poulpy_core::layouts::GLWEInfos,
+            B: poulpy_core::layouts::GLWEInfos,
+            T: poulpy_core::layouts::GGLWEInfos,
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWEMulConst<$backend> + poulpy_core::GLWETensoring<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::mul::BFVMulDefault<$backend>>::bfv_mul_tmp_bytes_default(module, res, a, b, tsk)
+        }
+
+        fn bfv_mul_into(
+            module: &poulpy_hal::layouts::Module<$backend>,
+            dst: &mut $crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataMut>,
+            a: &$crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataRef>,
+            b: &$crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataRef>,
+            tsk: &poulpy_core::layouts::GLWETensorKeyPrepared<impl poulpy_hal::layouts::DataRef, $backend>,
+            scratch: &mut poulpy_hal::layouts::Scratch<$backend>,
+        ) -> anyhow::Result<()>
+        where
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWEMulConst<$backend> + poulpy_core::GLWETensoring<$backend>,
+            poulpy_hal::layouts::Scratch<$backend>: poulpy_hal::api::ScratchAvailable + poulpy_core::ScratchTakeCore<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::mul::BFVMulDefault<$backend>>::bfv_mul_into_default(module, dst, a, b, tsk, scratch)
+        }
+
+        fn bfv_mul_assign(
+            module: &poulpy_hal::layouts::Module<$backend>,
+            dst: &mut $crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataMut>,
+            a: &$crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataRef>,
+            tsk: &poulpy_core::layouts::GLWETensorKeyPrepared<impl poulpy_hal::layouts::DataRef, $backend>,
+            scratch: &mut poulpy_hal::layouts::Scratch<$backend>,
+        ) -> anyhow::Result<()>
+        where
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWEMulConst<$backend> + poulpy_core::GLWETensoring<$backend>,
+            poulpy_hal::layouts::Scratch<$backend>: poulpy_hal::api::ScratchAvailable + poulpy_core::ScratchTakeCore<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::mul::BFVMulDefault<$backend>>::bfv_mul_assign_default(module, dst, a, tsk, scratch)
+        }
+
+        fn bfv_mul_pt_tmp_bytes<R, A>(module: &poulpy_hal::layouts::Module<$backend>, res: &R, a: &A) -> usize
+        where
+            R: poulpy_core::layouts::GLWEInfos,
+            A: poulpy_core::layouts::GLWEInfos,
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWEMulPlain<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::mul::BFVMulDefault<$backend>>::bfv_mul_pt_tmp_bytes_default(module, res, a)
+        }
+
+        fn bfv_mul_pt_into(
+            module: &poulpy_hal::layouts::Module<$backend>,
+            dst: &mut $crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataMut>,
+            a: &$crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataRef>,
+            pt: &$crate::layouts::BFVPlaintext<impl poulpy_hal::layouts::DataRef>,
+            scratch: &mut poulpy_hal::layouts::Scratch<$backend>,
+        ) -> anyhow::Result<()>
+        where
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWEMulPlain<$backend>,
+            poulpy_hal::layouts::Scratch<$backend>: poulpy_hal::api::ScratchAvailable + poulpy_core::ScratchTakeCore<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::mul::BFVMulDefault<$backend>>::bfv_mul_pt_into_default(module, dst, a, pt, scratch)
+        }
+
+        fn bfv_mul_pt_assign(
+            module: &poulpy_hal::layouts::Module<$backend>,
+            dst: &mut $crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataMut>,
+            pt: &$crate::layouts::BFVPlaintext<impl poulpy_hal::layouts::DataRef>,
+            scratch: &mut poulpy_hal::layouts::Scratch<$backend>,
+        ) -> anyhow::Result<()>
+        where
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWEMulPlain<$backend>,
+            poulpy_hal::layouts::Scratch<$backend>: poulpy_hal::api::ScratchAvailable + poulpy_core::ScratchTakeCore<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::mul::BFVMulDefault<$backend>>::bfv_mul_pt_assign_default(module, dst, pt, scratch)
+        }
+    };
+}
+
+pub use crate::impl_bfv_mul_default_methods;
@@ -0,0 +1,75 @@
+#[macro_export]
+macro_rules! impl_bfv_rotate_default_methods {
+    ($backend:ty) => {
+        fn bfv_rotate_tmp_bytes<C, K>(module: &poulpy_hal::layouts::Module<$backend>, ct_infos: &C, key_infos: &K) -> usize
+        where
+            C: poulpy_core::layouts::GLWEInfos,
+            K: poulpy_core::layouts::GGLWEInfos,
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWEAutomorphism<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::rotate::BFVRotateDefault<$backend>>::bfv_rotate_tmp_bytes_default(module, ct_infos, key_infos)
+        }
+
+        fn bfv_rotate_into<H, K>(
+            module: &poulpy_hal::layouts::Module<$backend>,
+            dst: &mut $crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataMut>,
+            src: &$crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataRef>,
+            k: i64,
+            keys: &H,
+            scratch: &mut poulpy_hal::layouts::Scratch<$backend>,
+        ) -> anyhow::Result<()>
+        where
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWEAutomorphism<$backend>,
+            K: poulpy_core::layouts::GGLWEPreparedToRef<$backend> + poulpy_core::layouts::GetGaloisElement + poulpy_core::layouts::GGLWEInfos,
+            H: poulpy_core::layouts::GLWEAutomorphismKeyHelper<K, $backend>,
+            poulpy_hal::layouts::Scratch<$backend>: poulpy_core::ScratchTakeCore<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::rotate::BFVRotateDefault<$backend>>::bfv_rotate_into_default(module, dst, src, k, keys, scratch)
+        }
+
+        fn bfv_rotate_assign<H, K>(
+            module: &poulpy_hal::layouts::Module<$backend>,
+            dst: &mut $crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataMut>,
+            k: i64,
+            keys: &H,
+            scratch: &mut poulpy_hal::layouts::Scratch<$backend>,
+        ) -> anyhow::Result<()>
+        where
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWEAutomorphism<$backend>,
+            K: poulpy_core::layouts::GGLWEPreparedToRef<$backend> + poulpy_core::layouts::GetGaloisElement + poulpy_core::layouts::GGLWEInfos,
+            H: poulpy_core::layouts::GLWEAutomorphismKeyHelper<K, $backend>,
+            poulpy_hal::layouts::Scratch<$backend>: poulpy_core::ScratchTakeCore<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::rotate::BFVRotateDefault<$backend>>::bfv_rotate_assign_default(module, dst, k, keys, scratch)
+        }
+
+        fn bfv_swap_rows_into(
+            module: &poulpy_hal::layouts::Module<$backend>,
+            dst: &mut $crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataMut>,
+            src: &$crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataRef>,
+            key: &poulpy_core::layouts::GLWEAutomorphismKeyPrepared<impl poulpy_hal::layouts::DataRef, $backend>,
+            scratch: &mut poulpy_hal::layouts::Scratch<$backend>,
+        ) -> anyhow::Result<()>
+        where
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWEAutomorphism<$backend>,
+            poulpy_hal::layouts::Scratch<$backend>: poulpy_core::ScratchTakeCore<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::rotate::BFVRotateDefault<$backend>>::bfv_swap_rows_into_default(module, dst, src, key, scratch)
+        }
+
+        fn bfv_swap_rows_assign(
+            module: &poulpy_hal::layouts::Module<$backend>,
+            dst: &mut $crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataMut>,
+            key: &poulpy_core::layouts::GLWEAutomorphismKeyPrepared<impl poulpy_hal::layouts::DataRef, $backend>,
+            scratch: &mut poulpy_hal::layouts::Scratch<$backend>,
+        ) -> anyhow::Result<()>
+        where
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWEAutomorphism<$backend>,
+            poulpy_hal::layouts::Scratch<$backend>: poulpy_core::ScratchTakeCore<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::rotate::BFVRotateDefault<$backend>>::bfv_swap_rows_assign_default(module, dst, key, scratch)
+        }
+    };
+}
+
+pub use crate::impl_bfv_rotate_default_methods;
@@ -0,0 +1,67 @@
+#[macro_export]
+macro_rules! impl_bfv_sub_default_methods {
+    ($backend:ty) => {
+        fn bfv_sub_tmp_bytes(module: &poulpy_hal::layouts::Module<$backend>) -> usize
+        where
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWENormalize<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::sub::BFVSubDefault<$backend>>::bfv_sub_tmp_bytes_default(module)
+        }
+
+        fn bfv_sub_into(
+            module: &poulpy_hal::layouts::Module<$backend>,
+            dst: &mut $crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataMut>,
+            a: &$crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataRef>,
+            b: &$crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataRef>,
+            scratch: &mut poulpy_hal::layouts::Scratch<$backend>,
+        ) -> anyhow::Result<()>
+        where
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWESub + poulpy_core::GLWENormalize<$backend>,
+            poulpy_hal::layouts::Scratch<$backend>: poulpy_core::ScratchTakeCore<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::sub::BFVSubDefault<$backend>>::bfv_sub_into_default(module, dst, a, b, scratch)
+        }
+
+        fn bfv_sub_assign(
+            module: &poulpy_hal::layouts::Module<$backend>,
+            dst: &mut $crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataMut>,
+            a: &$crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataRef>,
+            scratch: &mut poulpy_hal::layouts::Scratch<$backend>,
+        ) -> anyhow::Result<()>
+        where
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWESub + poulpy_core::GLWENormalize<$backend>,
+            poulpy_hal::layouts::Scratch<$backend>: poulpy_core::ScratchTakeCore<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::sub::BFVSubDefault<$backend>>::bfv_sub_assign_default(module, dst, a, scratch)
+        }
+
+        fn bfv_sub_pt_into(
+            module: &poulpy_hal::layouts::Module<$backend>,
+            dst: &mut $crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataMut>,
+            a: &$crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataRef>,
+            pt: &$crate::layouts::BFVPlaintext<impl poulpy_hal::layouts::DataRef>,
+            scratch: &mut poulpy_hal::layouts::Scratch<$backend>,
+        ) -> anyhow::Result<()>
+        where
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWESub + poulpy_core::GLWENormalize<$backend>,
+            poulpy_hal::layouts::Scratch<$backend>: poulpy_core::ScratchTakeCore<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::sub::BFVSubDefault<$backend>>::bfv_sub_pt_into_default(module, dst, a, pt, scratch)
+        }
+
+        fn bfv_sub_pt_assign(
+            module: &poulpy_hal::layouts::Module<$backend>,
+            dst: &mut $crate::layouts::BFVCiphertext<impl poulpy_hal::layouts::DataMut>,
+            pt: &$crate::layouts::BFVPlaintext<impl poulpy_hal::layouts::DataRef>,
+            scratch: &mut poulpy_hal::layouts::Scratch<$backend>,
+        ) -> anyhow::Result<()>
+        where
+            poulpy_hal::layouts::Module<$backend>: poulpy_core::GLWESub + poulpy_core::GLWENormalize<$backend>,
+            poulpy_hal::layouts::Scratch<$backend>: poulpy_core::ScratchTakeCore<$backend>,
+        {
+            <poulpy_hal::layouts::Module<$backend> as $crate::leveled::default::sub::BFVSubDefault<$backend>>::bfv_sub_pt_assign_default(module, dst, pt, scratch)
+        }
+    };
+}
+
+pub use crate::impl_bfv_sub_default_methods;
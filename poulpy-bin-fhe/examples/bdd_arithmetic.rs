@@ -2,23 +2,19 @@ use std::collections::HashMap;
 
 use poulpy_bin_fhe::{
     bdd_arithmetic::{
-        Add, BDDEncryptionInfos, BDDKey, BDDKeyEncryptSk, BDDKeyLayout, BDDKeyPrepared, BDDKeyPreparedFactory,
-        ExecuteBDDCircuit2WTo1W, FheUint, FheUintPrepare, FheUintPrepared, GLWEBlindSelection, Xor,
+        Add, BDDKey, BDDKeyEncryptSk, BDDKeyPrepared, BDDKeyPreparedFactory, ExecuteBDDCircuit2WTo1W, FheUint, FheUintPrepare,
+        FheUintPrepared, GLWEBlindSelection, Xor,
     },
-    blind_rotation::{BlindRotationAlgo, BlindRotationKeyLayout, CGGI},
-    circuit_bootstrapping::CircuitBootstrappingKeyLayout,
+    blind_rotation::{BlindRotationAlgo, CGGI},
+    params::{BDD_1024_DEMO, BDDParameters},
 };
 use poulpy_core::{
     EncryptionLayout, GLWEDecrypt, GLWEEncryptSk, ScratchTakeCore,
-    layouts::{
-        Base2K, Degree, Dnum, Dsize, GGLWEToGGSWKeyLayout, GGSWLayout, GGSWPreparedFactory, GLWEAutomorphismKeyLayout,
-        GLWELayout, GLWESecret, GLWESecretPreparedFactory, GLWESwitchingKeyLayout, GLWEToLWEKeyLayout, LWESecret, Rank,
-        TorusPrecision,
-    },
+    layouts::{GGSWPreparedFactory, GLWESecret, GLWESecretPreparedFactory, LWESecret},
 };
 use poulpy_hal::{
     api::{ModuleN, ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{Backend, DeviceBuf, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, Backend, DeviceBuf, Module, Scratch, ScratchOwned},
     source::Source,
 };
 use rand::RngExt;
@@ -61,77 +57,21 @@ where
     Scratch<BE>: ScratchTakeCore<BE>,
 {
     ////////// Parameter Selection
-    const N_GLWE: u32 = 1024;
-    const N_LWE: u32 = 567;
-    const BINARY_BLOCK_SIZE: u32 = 7;
-    const BASE2K: u32 = 17;
-    const RANK: u32 = 1;
+    // Named parameter set shipped with the library; the demo presets are
+    // small and fast but not secure, see `poulpy_bin_fhe::params`
+    let params: &BDDParameters = &BDD_1024_DEMO;
+    params.check().unwrap();
 
     // GLWE layout, used to generate GLWE Ciphertexts, keys, switching keys, etc
-    let glwe_layout = GLWELayout {
-        n: Degree(N_GLWE),
-        base2k: Base2K(BASE2K),
-        k: TorusPrecision(2 * BASE2K),
-        rank: Rank(RANK),
-    };
+    let glwe_layout = params.glwe_layout;
 
     // Used to generate GGSW Ciphertexts
-    let ggsw_layout = GGSWLayout {
-        n: Degree(N_GLWE),
-        base2k: Base2K(BASE2K),
-        k: TorusPrecision(3 * BASE2K),
-        rank: Rank(RANK),
-        dnum: Dnum(3),
-        dsize: Dsize(1),
-    };
+    let ggsw_layout = params.ggsw_layout;
 
     // Used to generate BDD Keys, for the arithmetic operations
-    let bdd_layout = BDDKeyLayout {
-        cbt_layout: CircuitBootstrappingKeyLayout {
-            brk_layout: BlindRotationKeyLayout {
-                n_glwe: Degree(N_GLWE),
-                n_lwe: Degree(N_LWE),
-                base2k: Base2K(BASE2K),
-                k: TorusPrecision(4 * BASE2K),
-                dnum: Dnum(4),
-                rank: Rank(RANK),
-            },
-            atk_layout: GLWEAutomorphismKeyLayout {
-                n: Degree(N_GLWE),
-                base2k: Base2K(BASE2K),
-                k: TorusPrecision(4 * BASE2K),
-                dnum: Dnum(4),
-                dsize: Dsize(1),
-                rank: Rank(RANK),
-            },
-            tsk_layout: GGLWEToGGSWKeyLayout {
-                n: Degree(N_GLWE),
-                base2k: Base2K(BASE2K),
-                k: TorusPrecision(4 * BASE2K),
-                dnum: Dnum(4),
-                dsize: Dsize(1),
-                rank: Rank(RANK),
-            },
-        },
-        ks_glwe_layout: Some(GLWESwitchingKeyLayout {
-            n: Degree(N_GLWE),
-            base2k: Base2K(BASE2K),
-            k: TorusPrecision(4 * BASE2K),
-            dnum: Dnum(4),
-            dsize: Dsize(1),
-            rank_in: Rank(RANK),
-            rank_out: Rank(1),
-        }),
-        ks_lwe_layout: GLWEToLWEKeyLayout {
-            n: Degree(N_GLWE),
-            base2k: Base2K(BASE2K),
-            k: TorusPrecision(4 * BASE2K),
-            rank_in: Rank(1),
-            dnum: Dnum(4),
-        },
-    };
-
-    let module = Module::<BE>::new(N_GLWE as u64);
+    let bdd_layout = params.bdd_layout;
+
+    let module = Module::<BE>::new(glwe_layout.n.as_u32() as u64);
 
     // Secret key sampling source
     let mut source_xs: Source = Source::new([1u8; 32]);
@@ -148,10 +88,10 @@ where
     ////////// Key Generation and Preparation
     // Generating the GLWE and LWE key
     let mut sk_glwe = GLWESecret::alloc_from_infos(&glwe_layout);
-    sk_glwe.fill_ternary_prob(0.5, &mut source_xs);
+    sk_glwe.fill_dist(params.glwe_dist, &mut source_xs);
 
     let mut sk_lwe = LWESecret::alloc(bdd_layout.cbt_layout.brk_layout.n_lwe);
-    sk_lwe.fill_binary_block(BINARY_BLOCK_SIZE as usize, &mut source_xs);
+    sk_lwe.fill_dist(params.lwe_dist, &mut source_xs);
 
     // Preparing the private keys
     let mut sk_glwe_prepared = module.glwe_secret_prepared_alloc_from_infos(&glwe_layout);
@@ -160,9 +100,9 @@ where
     // Creating the public BDD Key
     // This key is required to prepare all Fhe Integers for operations,
     // and for performing the operations themselves
-    let bdd_enc_infos = BDDEncryptionInfos::from_default_sigma(&bdd_layout).unwrap();
+    let bdd_enc_infos = params.encryption_infos().unwrap();
 
-    let mut bdd_key: BDDKey<Vec<u8>, BRA> = BDDKey::alloc_from_infos(&bdd_layout);
+    let mut bdd_key: BDDKey<AlignedBuf, BRA> = BDDKey::alloc_from_infos(&bdd_layout);
     bdd_key.encrypt_sk(
         &module,
         &sk_lwe,
@@ -180,7 +120,7 @@ where
 
     let glwe_enc_infos = EncryptionLayout::new_from_default_sigma(glwe_layout).unwrap();
 
-    let mut a_enc: FheUint<Vec<u8>, u32> = FheUint::alloc_from_infos(&glwe_layout);
+    let mut a_enc: FheUint<AlignedBuf, u32> = FheUint::alloc_from_infos(&glwe_layout);
     a_enc.encrypt_sk(
         &module,
         input_a,
@@ -191,7 +131,7 @@ where
         scratch.borrow(),
     );
 
-    let mut b_enc: FheUint<Vec<u8>, u32> = FheUint::alloc_from_infos(&glwe_layout);
+    let mut b_enc: FheUint<AlignedBuf, u32> = FheUint::alloc_from_infos(&glwe_layout);
     b_enc.encrypt_sk(
         &module,
         input_b,
@@ -219,7 +159,7 @@ where
     b_enc_prepared.prepare(&module, &b_enc, &bdd_key_prepared, scratch.borrow());
 
     // Allocating the intermediate ciphertext c_enc
-    let mut c_enc: FheUint<Vec<u8>, u32> = FheUint::alloc_from_infos(&glwe_layout);
+    let mut c_enc: FheUint<AlignedBuf, u32> = FheUint::alloc_from_infos(&glwe_layout);
 
     // Performing the operation
     c_enc.add(&module, &a_enc_prepared, &b_enc_prepared, &bdd_key_prepared, scratch.borrow());
@@ -229,7 +169,7 @@ where
     c_enc_prepared.prepare(&module, &c_enc, &bdd_key_prepared, scratch.borrow());
 
     // Creating the output ciphertext d_enc
-    let mut selected_enc: FheUint<Vec<u8>, u32> = FheUint::alloc_from_infos(&glwe_layout);
+    let mut selected_enc: FheUint<AlignedBuf, u32> = FheUint::alloc_from_infos(&glwe_layout);
     selected_enc.xor(&module, &c_enc_prepared, &a_enc_prepared, &bdd_key_prepared, scratch.borrow());
 
     //////// Homomorphic computation ends here ////////
@@ -265,9 +205,9 @@ where
         .collect();
     let input_selector: u32 = rand::rng().random_range(0..number_of_inputs as u32);
 
-    let mut inputs_a_enc_vec: Vec<FheUint<Vec<u8>, u32>> = Vec::new();
+    let mut inputs_a_enc_vec: Vec<FheUint<AlignedBuf, u32>> = Vec::new();
     for input in &inputs_a_vec {
-        let mut next_input: FheUint<Vec<u8>, u32> = FheUint::alloc_from_infos(&glwe_layout);
+        let mut next_input: FheUint<AlignedBuf, u32> = FheUint::alloc_from_infos(&glwe_layout);
         next_input.encrypt_sk(
             &module,
             *input,
@@ -280,12 +220,12 @@ where
         inputs_a_enc_vec.push(next_input);
     }
 
-    let mut inputs_a_enc_vec_map: HashMap<usize, &mut FheUint<Vec<u8>, u32>> = HashMap::new();
+    let mut inputs_a_enc_vec_map: HashMap<usize, &mut FheUint<AlignedBuf, u32>> = HashMap::new();
     for (i, input) in inputs_a_enc_vec.iter_mut().enumerate() {
         inputs_a_enc_vec_map.insert(i, input);
     }
 
-    let mut input_selector_enc: FheUint<Vec<u8>, u32> = FheUint::alloc_from_infos(&glwe_layout);
+    let mut input_selector_enc: FheUint<AlignedBuf, u32> = FheUint::alloc_from_infos(&glwe_layout);
     input_selector_enc.encrypt_sk(
         &module,
         input_selector,
@@ -1,8 +1,8 @@
 use poulpy_core::{
     DEFAULT_BOUND_XE, DEFAULT_SIGMA_XE, GLWEDecrypt, GLWEEncryptSk, GLWEExternalProduct, GLWENormalize, LWEEncryptSk,
     layouts::{
-        GGLWEToGGSWKeyLayout, GGSW, GGSWInfos, GGSWLayout, GLWE, GLWEAutomorphismKeyLayout, GLWEInfos, GLWELayout, GLWEPlaintext,
-        GLWESecret, LWE, LWEInfos, LWELayout, LWEPlaintext, LWESecret,
+        GGSW, GGSWInfos, GGSWLayout, GLWE, GLWEInfos, GLWELayout, GLWEPlaintext, GLWESecret, LWE, LWEInfos, LWELayout,
+        LWEPlaintext, LWESecret,
         prepared::{GGSWPrepared, GGSWPreparedFactory, GLWESecretPrepared, GLWESecretPreparedFactory},
     },
 };
@@ -27,111 +27,51 @@ use poulpy_cpu_ref::FFT64Ref as BackendImpl;
 
 use poulpy_hal::{
     api::{ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxNormalizeAssign},
-    layouts::{DeviceBuf, Module, ScalarZnx, ScratchOwned, ZnxView, ZnxViewMut},
+    layouts::{AlignedBuf, DeviceBuf, Module, ScalarZnx, ScratchOwned, ZnxView, ZnxViewMut},
     source::Source,
 };
 
 use poulpy_bin_fhe::{
-    blind_rotation::{BlindRotationKeyLayout, CGGI},
+    blind_rotation::CGGI,
     circuit_bootstrapping::{
-        CircuitBootstrappingEncryptionInfos, CircuitBootstrappingKey, CircuitBootstrappingKeyEncryptSk,
-        CircuitBootstrappingKeyLayout, CircuitBootstrappingKeyPrepared,
+        CircuitBootstrappingKey, CircuitBootstrappingKeyEncryptSk, CircuitBootstrappingKeyLayout, CircuitBootstrappingKeyPrepared,
     },
+    params::{CBT_1024_DEMO, CircuitBootstrappingParameters},
 };
 
 fn main() {
+    // Named parameter set shipped with the library; the demo presets are
+    // small and fast but not secure, see `poulpy_bin_fhe::params`
+    let params: &CircuitBootstrappingParameters = &CBT_1024_DEMO;
+    params.check().unwrap();
+
+    let cbt_layout: CircuitBootstrappingKeyLayout = params.cbt_layout;
+    let ggsw_infos: GGSWLayout = params.ggsw_layout;
+    let lwe_infos: LWELayout = params.lwe_layout;
+
     // GLWE ring degree
-    let n_glwe: usize = 1024;
+    let n_glwe: usize = ggsw_infos.n().as_usize();
 
     // Module provides access to the backend arithmetic
     let module: Module<BackendImpl> = Module::<BackendImpl>::new(n_glwe as u64);
 
     // Base 2 loga
-    let base2k: usize = 13;
+    let base2k: usize = ggsw_infos.base2k().as_usize();
 
     // Lookup table extension factor
     let extension_factor: usize = 1;
 
     // GLWE rank
-    let rank: usize = 1;
+    let rank: usize = ggsw_infos.rank().as_usize();
 
     // LWE degree
-    let n_lwe: usize = 574;
+    let n_lwe: usize = lwe_infos.n().as_usize();
 
     // LWE plaintext modulus
-    let k_lwe_pt: usize = 1;
-
-    // LWE ciphertext modulus
-    let k_lwe_ct: usize = 13;
-
-    // LWE block binary key block size
-    let block_size: usize = 7;
-
-    // GGSW output number of dnum
-    let rows_ggsw_res: usize = 2;
+    let k_lwe_pt: usize = params.k_pt;
 
     // GGSW output modulus
-    let k_ggsw_res: usize = (rows_ggsw_res + 1) * base2k;
-
-    // Blind rotation key GGSW number of dnum
-    let rows_brk: usize = rows_ggsw_res + 1;
-
-    // Blind rotation key GGSW modulus
-    let k_brk: usize = (rows_brk + 1) * base2k;
-
-    // GGLWE automorphism keys number of dnum
-    let rows_trace: usize = rows_ggsw_res + 1;
-
-    // GGLWE automorphism keys modulus
-    let k_trace: usize = (rows_trace + 1) * base2k;
-
-    // GGLWE tensor key number of dnum
-    let rows_tsk: usize = rows_ggsw_res + 1;
-
-    // GGLWE tensor key modulus
-    let k_tsk: usize = (rows_tsk + 1) * base2k;
-
-    let cbt_layout: CircuitBootstrappingKeyLayout = CircuitBootstrappingKeyLayout {
-        brk_layout: BlindRotationKeyLayout {
-            n_glwe: n_glwe.into(),
-            n_lwe: n_lwe.into(),
-            base2k: base2k.into(),
-            k: k_brk.into(),
-            dnum: rows_brk.into(),
-            rank: rank.into(),
-        },
-        atk_layout: GLWEAutomorphismKeyLayout {
-            n: n_glwe.into(),
-            base2k: base2k.into(),
-            k: k_trace.into(),
-            dnum: rows_trace.into(),
-            dsize: 1_u32.into(),
-            rank: rank.into(),
-        },
-        tsk_layout: GGLWEToGGSWKeyLayout {
-            n: n_glwe.into(),
-            base2k: base2k.into(),
-            k: k_tsk.into(),
-            dnum: rows_tsk.into(),
-            dsize: 1_u32.into(),
-            rank: rank.into(),
-        },
-    };
-
-    let ggsw_infos: GGSWLayout = GGSWLayout {
-        n: n_glwe.into(),
-        base2k: base2k.into(),
-        k: k_ggsw_res.into(),
-        dnum: rows_ggsw_res.into(),
-        dsize: 1_u32.into(),
-        rank: rank.into(),
-    };
-
-    let lwe_infos = LWELayout {
-        n: n_lwe.into(),
-        k: k_lwe_ct.into(),
-        base2k: base2k.into(),
-    };
+    let k_ggsw_res: usize = ggsw_infos.max_k().as_usize();
 
     // Scratch space (4MB)
     let mut scratch: ScratchOwned<BackendImpl> = ScratchOwned::alloc(1 << 22);
@@ -146,13 +86,13 @@ fn main() {
     let mut source_xe: Source = Source::new([1u8; 32]);
 
     // LWE secret
-    let mut sk_lwe: LWESecret<Vec<u8>> = LWESecret::alloc(n_lwe.into());
-    sk_lwe.fill_binary_block(block_size, &mut source_xs);
+    let mut sk_lwe: LWESecret<AlignedBuf> = LWESecret::alloc(n_lwe.into());
+    sk_lwe.fill_dist(params.lwe_dist, &mut source_xs);
     // sk_lwe.fill_zero(); // for testing
 
     // GLWE secret
-    let mut sk_glwe: GLWESecret<Vec<u8>> = GLWESecret::alloc(n_glwe.into(), rank.into());
-    sk_glwe.fill_ternary_prob(0.5, &mut source_xs);
+    let mut sk_glwe: GLWESecret<AlignedBuf> = GLWESecret::alloc(n_glwe.into(), rank.into());
+    sk_glwe.fill_dist(params.glwe_dist, &mut source_xs);
     // sk_glwe.fill_zero(); // for testing
 
     // GLWE secret prepared (opaque backend dependant write only struct)
@@ -164,7 +104,7 @@ fn main() {
     let data: i64 = 1 % (1 << k_lwe_pt);
 
     // LWE plaintext
-    let mut pt_lwe: LWEPlaintext<Vec<u8>> = LWEPlaintext::alloc(base2k.into(), k_lwe_pt.into());
+    let mut pt_lwe: LWEPlaintext<AlignedBuf> = LWEPlaintext::alloc(base2k.into(), k_lwe_pt.into());
 
     // LWE plaintext(data * 2^{- (k_lwe_pt + 1)})
     pt_lwe.encode_i64(data, (k_lwe_pt + 1).into()); // +1 for padding bit
@@ -174,10 +114,10 @@ fn main() {
     println!("pt_lwe: {pt_lwe}");
 
     // LWE ciphertext
-    let mut ct_lwe: LWE<Vec<u8>> = LWE::alloc_from_infos(&lwe_infos);
+    let mut ct_lwe: LWE<AlignedBuf> = LWE::alloc_from_infos(&lwe_infos);
 
     let lwe_enc_infos = NoiseInfos::new(lwe_infos.max_k().as_usize(), DEFAULT_SIGMA_XE, DEFAULT_BOUND_XE).unwrap();
-    let cbt_enc_infos = CircuitBootstrappingEncryptionInfos::from_default_sigma(&cbt_layout).unwrap();
+    let cbt_enc_infos = params.encryption_infos().unwrap();
 
     // Encrypt LWE Plaintext
     module.lwe_encrypt_sk(
@@ -193,7 +133,7 @@ fn main() {
     let now: Instant = Instant::now();
 
     // Circuit bootstrapping evaluation key
-    let mut cbt_key: CircuitBootstrappingKey<Vec<u8>, CGGI> = CircuitBootstrappingKey::alloc_from_infos(&cbt_layout);
+    let mut cbt_key: CircuitBootstrappingKey<AlignedBuf, CGGI> = CircuitBootstrappingKey::alloc_from_infos(&cbt_layout);
 
     module.circuit_bootstrapping_key_encrypt_sk(
         &mut cbt_key,
@@ -208,7 +148,7 @@ fn main() {
     println!("CBT-KGEN: {} ms", now.elapsed().as_millis());
 
     // Output GGSW
-    let mut res: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_infos);
+    let mut res: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_infos);
 
     // Circuit bootstrapping key prepared (opaque backend dependant write only struct)
     let mut cbt_prepared: CircuitBootstrappingKeyPrepared<DeviceBuf<BackendImpl>, CGGI, BackendImpl> =
@@ -221,7 +161,7 @@ fn main() {
     println!("CBT: {} ms", now.elapsed().as_millis());
 
     // Allocate "ideal" GGSW(data) plaintext
-    let mut pt_ggsw: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n_glwe, 1);
+    let mut pt_ggsw: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n_glwe, 1);
     pt_ggsw.at_mut(0, 0)[0] = data;
 
     // Prints noise of GGSW(data)
@@ -246,11 +186,11 @@ fn main() {
     };
 
     // GLWE ciphertext modulus
-    let mut ct_glwe: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_infos);
+    let mut ct_glwe: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
 
     // Some GLWE plaintext with signed data
     let k_glwe_pt: usize = 3;
-    let mut pt_glwe: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+    let mut pt_glwe: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
     let mut data_vec: Vec<i64> = vec![0i64; n_glwe];
     data_vec
         .iter_mut()
@@ -282,7 +222,7 @@ fn main() {
     module.glwe_external_product_assign(&mut ct_glwe, &res_prepared, scratch.borrow());
 
     // Decrypt
-    let mut pt_res: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+    let mut pt_res: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
     module.glwe_decrypt(&ct_glwe, &mut pt_res, &sk_glwe_prepared, scratch.borrow());
 
     println!("pt_res: {:?}", &pt_res.data.at(0, 0)[..64]);
@@ -2,23 +2,19 @@ use std::collections::HashMap;
 
 use poulpy_bin_fhe::{
     bdd_arithmetic::{
-        BDDEncryptionInfos, BDDKey, BDDKeyEncryptSk, BDDKeyLayout, BDDKeyPrepared, BDDKeyPreparedFactory,
-        ExecuteBDDCircuit2WTo1W, FheUint, FheUintPrepare, FheUintPrepared, GLWEBlindSelection, Sltu,
+        BDDKey, BDDKeyEncryptSk, BDDKeyPrepared, BDDKeyPreparedFactory, ExecuteBDDCircuit2WTo1W, FheUint, FheUintPrepare,
+        FheUintPrepared, GLWEBlindSelection, Sltu,
     },
-    blind_rotation::{BlindRotationAlgo, BlindRotationKeyLayout, CGGI},
-    circuit_bootstrapping::CircuitBootstrappingKeyLayout,
+    blind_rotation::{BlindRotationAlgo, CGGI},
+    params::{BDD_1024_DEMO, BDDParameters},
 };
 use poulpy_core::{
     EncryptionLayout, GLWECopy, GLWEDecrypt, GLWEEncryptSk, GLWEExternalProduct, LWEEncryptSk, ScratchTakeCore,
-    layouts::{
-        Base2K, Degree, Dnum, Dsize, GGLWEToGGSWKeyLayout, GGSWLayout, GGSWPreparedFactory, GLWEAutomorphismKeyLayout,
-        GLWELayout, GLWESecret, GLWESecretPreparedFactory, GLWESwitchingKeyLayout, GLWEToLWEKeyLayout, GLWEToMut, GLWEToRef,
-        LWESecret, Rank, TorusPrecision,
-    },
+    layouts::{GGSWPreparedFactory, GLWESecret, GLWESecretPreparedFactory, GLWEToMut, GLWEToRef, LWESecret},
 };
 use poulpy_hal::{
     api::{ModuleN, ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxRotateAssign},
-    layouts::{Backend, DeviceBuf, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, Backend, DeviceBuf, Module, Scratch, ScratchOwned},
     source::Source,
 };
 use rand::RngExt;
@@ -51,77 +47,21 @@ where
     Scratch<BE>: ScratchTakeCore<BE>,
 {
     ////////// Parameter Selection
-    const N_GLWE: u32 = 1024;
-    const N_LWE: u32 = 567;
-    const BINARY_BLOCK_SIZE: u32 = 7;
-    const BASE2K: u32 = 17;
-    const RANK: u32 = 1;
+    // Named parameter set shipped with the library; the demo presets are
+    // small and fast but not secure, see `poulpy_bin_fhe::params`
+    let params: &BDDParameters = &BDD_1024_DEMO;
+    params.check().unwrap();
 
     // GLWE layout, used to generate GLWE Ciphertexts, keys, switching keys, etc
-    let glwe_layout = GLWELayout {
-        n: Degree(N_GLWE),
-        base2k: Base2K(BASE2K),
-        k: TorusPrecision(2 * BASE2K),
-        rank: Rank(RANK),
-    };
+    let glwe_layout = params.glwe_layout;
 
     // Used to generate GGSW Ciphertexts
-    let ggsw_layout = GGSWLayout {
-        n: Degree(N_GLWE),
-        base2k: Base2K(BASE2K),
-        k: TorusPrecision(3 * BASE2K),
-        rank: Rank(RANK),
-        dnum: Dnum(3),
-        dsize: Dsize(1),
-    };
+    let ggsw_layout = params.ggsw_layout;
 
     // Used to generate BDD Keys, for the arithmetic operations
-    let bdd_layout = BDDKeyLayout {
-        cbt_layout: CircuitBootstrappingKeyLayout {
-            brk_layout: BlindRotationKeyLayout {
-                n_glwe: Degree(N_GLWE),
-                n_lwe: Degree(N_LWE),
-                base2k: Base2K(BASE2K),
-                k: TorusPrecision(4 * BASE2K),
-                dnum: Dnum(4),
-                rank: Rank(RANK),
-            },
-            atk_layout: GLWEAutomorphismKeyLayout {
-                n: Degree(N_GLWE),
-                base2k: Base2K(BASE2K),
-                k: TorusPrecision(4 * BASE2K),
-                dnum: Dnum(4),
-                dsize: Dsize(1),
-                rank: Rank(RANK),
-            },
-            tsk_layout: GGLWEToGGSWKeyLayout {
-                n: Degree(N_GLWE),
-                base2k: Base2K(BASE2K),
-                k: TorusPrecision(4 * BASE2K),
-                dnum: Dnum(4),
-                dsize: Dsize(1),
-                rank: Rank(RANK),
-            },
-        },
-        ks_glwe_layout: Some(GLWESwitchingKeyLayout {
-            n: Degree(N_GLWE),
-            base2k: Base2K(BASE2K),
-            k: TorusPrecision(4 * BASE2K),
-            dnum: Dnum(4),
-            dsize: Dsize(1),
-            rank_in: Rank(RANK),
-            rank_out: Rank(1),
-        }),
-        ks_lwe_layout: GLWEToLWEKeyLayout {
-            n: Degree(N_GLWE),
-            base2k: Base2K(BASE2K),
-            k: TorusPrecision(4 * BASE2K),
-            rank_in: Rank(RANK),
-            dnum: Dnum(4),
-        },
-    };
-
-    let module = Module::<BE>::new(N_GLWE as u64);
+    let bdd_layout = params.bdd_layout;
+
+    let module = Module::<BE>::new(glwe_layout.n.as_u32() as u64);
 
     // Secret key sampling source
     let mut source_xs: Source = Source::new([1u8; 32]);
@@ -138,10 +78,10 @@ where
     ////////// Key Generation and Preparation
     // Generating the GLWE and LWE key
     let mut sk_glwe = GLWESecret::alloc_from_infos(&glwe_layout);
-    sk_glwe.fill_ternary_prob(0.5, &mut source_xs);
+    sk_glwe.fill_dist(params.glwe_dist, &mut source_xs);
 
-    let mut sk_lwe = LWESecret::alloc(Degree(N_LWE));
-    sk_lwe.fill_binary_block(BINARY_BLOCK_SIZE as usize, &mut source_xs);
+    let mut sk_lwe = LWESecret::alloc(bdd_layout.cbt_layout.brk_layout.n_lwe);
+    sk_lwe.fill_dist(params.lwe_dist, &mut source_xs);
 
     // Preparing the private keys
     let mut sk_glwe_prepared = module.glwe_secret_prepared_alloc_from_infos(&glwe_layout);
@@ -150,10 +90,10 @@ where
     // Creating the public BDD Key
     // This key is required to prepare all Fhe Integers for operations,
     // and for performing the operations themselves
-    let bdd_enc_infos = BDDEncryptionInfos::from_default_sigma(&bdd_layout).unwrap();
+    let bdd_enc_infos = params.encryption_infos().unwrap();
     let glwe_enc_infos = EncryptionLayout::new_from_default_sigma(glwe_layout).unwrap();
 
-    let mut bdd_key: BDDKey<Vec<u8>, BRA> = BDDKey::alloc_from_infos(&bdd_layout);
+    let mut bdd_key: BDDKey<AlignedBuf, BRA> = BDDKey::alloc_from_infos(&bdd_layout);
     bdd_key.encrypt_sk(
         &module,
         &sk_lwe,
@@ -169,7 +109,7 @@ where
     let mut rng = rand::rng();
     let inputs: Vec<u32> = (0..3).map(|_| rng.random_range(0..u32::MAX - 1)).collect();
 
-    let mut inputs_enc: Vec<FheUint<Vec<u8>, u32>> = Vec::new();
+    let mut inputs_enc: Vec<FheUint<AlignedBuf, u32>> = Vec::new();
     for input in &inputs {
         let mut next_input = FheUint::alloc_from_infos(&glwe_layout);
         next_input.encrypt_sk(
@@ -191,7 +131,7 @@ where
     let mut bdd_key_prepared: BDDKeyPrepared<DeviceBuf<BE>, BRA, BE> = BDDKeyPrepared::alloc_from_infos(&module, &bdd_layout);
     bdd_key_prepared.prepare(&module, &bdd_key, scratch.borrow());
 
-    let mut max_enc: FheUint<Vec<u8>, u32> = FheUint::alloc_from_infos(&glwe_layout);
+    let mut max_enc: FheUint<AlignedBuf, u32> = FheUint::alloc_from_infos(&glwe_layout);
     max_enc.encrypt_sk(
         &module,
         0,
@@ -202,10 +142,10 @@ where
         scratch.borrow(),
     );
     // Copy of max_enc for the HashMap
-    let mut max_enc_copy: FheUint<Vec<u8>, u32> = FheUint::alloc_from_infos(&glwe_layout);
+    let mut max_enc_copy: FheUint<AlignedBuf, u32> = FheUint::alloc_from_infos(&glwe_layout);
 
     // Allocating the intermediate ciphertext c_enc
-    let mut compare_enc: FheUint<Vec<u8>, u32> = FheUint::alloc_from_infos(&glwe_layout);
+    let mut compare_enc: FheUint<AlignedBuf, u32> = FheUint::alloc_from_infos(&glwe_layout);
     let mut compare_enc_prepared: FheUintPrepared<DeviceBuf<BE>, u32, BE> =
         FheUintPrepared::alloc_from_infos(&module, &ggsw_layout);
 
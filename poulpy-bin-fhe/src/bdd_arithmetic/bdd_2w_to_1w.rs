@@ -8,7 +8,7 @@ use poulpy_core::{
 };
 use poulpy_hal::{
     api::ModuleLogN,
-    layouts::{Backend, DataMut, DataRef, Module, Scratch},
+    layouts::{AlignedBuf, Backend, DataMut, DataRef, Module, Scratch},
 };
 
 use crate::bdd_arithmetic::{
@@ -68,7 +68,7 @@ where
         H: GLWEAutomorphismKeyHelper<K, BE>,
     {
         let atk_infos = key.automorphism_key_infos();
-        let glwe_slot_bytes = T::BITS as usize * GLWE::<Vec<u8>>::bytes_of_from_infos(res_infos);
+        let glwe_slot_bytes = T::BITS as usize * GLWE::<AlignedBuf>::bytes_of_from_infos(res_infos);
         let bdd_bytes = self.execute_bdd_circuit_tmp_bytes(res_infos, circuit.max_state_size(), ggsw_infos);
         let pack_bytes = self.glwe_pack_tmp_bytes(res_infos, &atk_infos);
         glwe_slot_bytes + bdd_bytes.max(pack_bytes)
@@ -92,7 +92,7 @@ where
         H: GLWEAutomorphismKeyHelper<K, BE>,
     {
         let atk_infos = key.automorphism_key_infos();
-        let glwe_slot_bytes = T::BITS as usize * GLWE::<Vec<u8>>::bytes_of_from_infos(res_infos);
+        let glwe_slot_bytes = T::BITS as usize * GLWE::<AlignedBuf>::bytes_of_from_infos(res_infos);
         let bdd_per_thread = self.execute_bdd_circuit_tmp_bytes(res_infos, circuit.max_state_size(), ggsw_infos);
         let pack_bytes = self.glwe_pack_tmp_bytes(res_infos, &atk_infos);
         glwe_slot_bytes + (threads * bdd_per_thread).max(pack_bytes)
@@ -3,7 +3,7 @@ use poulpy_core::{
     GLWECopy, ScratchTakeCore,
     layouts::{GGSWInfos, GGSWPrepared, GLWE, GLWEInfos, GLWEToMut, GLWEToRef},
 };
-use poulpy_hal::layouts::{Backend, Module, Scratch, ZnxZero};
+use poulpy_hal::layouts::{AlignedBuf, Backend, Module, Scratch, ZnxZero};
 
 use crate::bdd_arithmetic::{Cmux, Cswap, GetGGSWBit};
 
@@ -125,7 +125,7 @@ impl GLWEBlindRetriever {
 }
 
 struct Accumulator {
-    data: GLWE<Vec<u8>>,
+    data: GLWE<AlignedBuf>,
     num: usize, // Number of accumulated values
 }
 
@@ -4,7 +4,7 @@ use poulpy_core::{
     GLWECopy, GLWEDecrypt, ScratchTakeCore,
     layouts::{GGSWInfos, GGSWPrepared, GLWE, GLWEInfos, GLWEToMut},
 };
-use poulpy_hal::layouts::{Backend, Module, Scratch, ZnxZero};
+use poulpy_hal::layouts::{AlignedBuf, Backend, Module, Scratch, ZnxZero};
 
 use crate::bdd_arithmetic::{Cmux, GetGGSWBit, UnsignedInteger};
 
@@ -34,7 +34,7 @@ where
         R: GLWEInfos,
         K: GGSWInfos,
     {
-        self.cmux_tmp_bytes(res_infos, res_infos, k_infos) + GLWE::<Vec<u8>>::bytes_of_from_infos(res_infos)
+        self.cmux_tmp_bytes(res_infos, res_infos, k_infos) + GLWE::<AlignedBuf>::bytes_of_from_infos(res_infos)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -9,7 +9,7 @@ use poulpy_core::{
 };
 use poulpy_hal::{
     api::ModuleLogN,
-    layouts::{Backend, Data, DataMut, DataRef, Scratch, Stats},
+    layouts::{AlignedBuf, Backend, Data, DataMut, DataRef, Scratch, Stats},
     source::Source,
 };
 use std::{collections::HashMap, marker::PhantomData};
@@ -41,7 +41,7 @@ pub struct FheUint<D: Data, T: UnsignedInteger> {
     pub(crate) _phantom: PhantomData<T>,
 }
 
-impl<T: UnsignedInteger> FheUint<Vec<u8>, T> {
+impl<T: UnsignedInteger> FheUint<AlignedBuf, T> {
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
         A: GLWEInfos,
@@ -155,7 +155,7 @@ impl<D: DataMut, T: UnsignedInteger + ToBits> FheUint<D, T> {
             base2k: self.base2k(),
             k: 2_usize.into(),
         };
-        GLWEPlaintext::<Vec<u8>>::bytes_of_from_infos(&pt_infos) + module.glwe_encrypt_sk_tmp_bytes(self)
+        GLWEPlaintext::<AlignedBuf>::bytes_of_from_infos(&pt_infos) + module.glwe_encrypt_sk_tmp_bytes(self)
     }
 }
 
@@ -230,7 +230,7 @@ impl<D: DataRef, T: UnsignedInteger + FromBits> FheUint<D, T> {
             base2k: self.base2k(),
             k: 1_usize.into(),
         };
-        GLWEPlaintext::<Vec<u8>>::bytes_of_from_infos(&pt_infos) + module.glwe_decrypt_tmp_bytes(self)
+        GLWEPlaintext::<AlignedBuf>::bytes_of_from_infos(&pt_infos) + module.glwe_decrypt_tmp_bytes(self)
     }
 }
 
@@ -450,8 +450,8 @@ impl<D: DataMut, T: UnsignedInteger> FheUint<D, T> {
         K: GGLWEPreparedToRef<BE> + GetGaloisElement + GGLWEInfos,
         H: GLWEAutomorphismKeyHelper<K, BE>,
     {
-        let zero: GLWE<Vec<u8>> = GLWE::alloc_from_infos(self);
-        let mut one: GLWE<Vec<u8>> = GLWE::alloc_from_infos(self);
+        let zero: GLWE<AlignedBuf> = GLWE::alloc_from_infos(self);
+        let mut one: GLWE<AlignedBuf> = GLWE::alloc_from_infos(self);
         one.data_mut().encode_coeff_i64(self.base2k().into(), 0, 2, 0, 1);
 
         let (mut out_bits, scratch_1) = scratch.take_glwe_slice(T::BITS as usize, self);
@@ -14,7 +14,7 @@ use poulpy_core::{
 };
 
 use poulpy_hal::api::{ModuleN, ScratchTakeBasic};
-use poulpy_hal::layouts::{Backend, Data, DataMut, DataRef, Module, Scratch, Stats, ZnxZero};
+use poulpy_hal::layouts::{AlignedBuf, Backend, Data, DataMut, DataRef, Module, Scratch, Stats, ZnxZero};
 
 /// A debug variant of `FheUintPrepared` that stores per-bit GGSW ciphertexts
 /// in standard (non-DFT) form.
@@ -33,7 +33,7 @@ pub struct FheUintPreparedDebug<D: Data, T: UnsignedInteger> {
     pub(crate) _phantom: PhantomData<T>,
 }
 
-impl<T: UnsignedInteger> FheUintPreparedDebug<Vec<u8>, T> {
+impl<T: UnsignedInteger> FheUintPreparedDebug<AlignedBuf, T> {
     pub fn alloc_from_infos<A, M>(module: &M, infos: &A) -> Self
     where
         M: ModuleN,
@@ -24,9 +24,9 @@ use poulpy_core::{
     },
 };
 
-use poulpy_hal::layouts::{DeviceBuf, NoiseInfos};
+use poulpy_hal::layouts::{DeviceBuf, NoiseInfos, PREPARED_BLOCK_BYTES, read_prepared_meta, write_prepared_meta};
 use poulpy_hal::{
-    layouts::{Backend, Data, DataMut, DataRef, Module, ReaderFrom, Scratch, WriterTo},
+    layouts::{AlignedBuf, Backend, Data, DataMut, DataRef, Module, ReaderFrom, Scratch, WriterTo},
     source::Source,
 };
 
@@ -135,7 +135,7 @@ where
     pub(crate) ks_lwe: GLWEToLWEKey<D>,
 }
 
-impl<BRA: BlindRotationAlgo> BDDKey<Vec<u8>, BRA> {
+impl<BRA: BlindRotationAlgo> BDDKey<AlignedBuf, BRA> {
     pub fn alloc_from_infos<A: BDDKeyInfos>(infos: &A) -> Self {
         Self {
             cbt: CircuitBootstrappingKey::alloc_from_infos(&infos.cbt_infos()),
@@ -216,7 +216,7 @@ where
                 .ks_glwe
                 .as_ref()
                 .expect("ks_glwe enc_infos missing when ks_glwe key exists");
-            let mut sk_out: GLWESecret<Vec<u8>> = GLWESecret::alloc(sk_glwe.n(), key.rank_out());
+            let mut sk_out: GLWESecret<AlignedBuf> = GLWESecret::alloc(sk_glwe.n(), key.rank_out());
             sk_out.fill_ternary_prob(0.5, source_xe);
             self.glwe_switching_key_encrypt_sk(key, sk_glwe, &sk_out, ks_glwe_infos, source_xe, source_xa, scratch);
             self.glwe_to_lwe_key_encrypt_sk(
@@ -265,7 +265,7 @@ impl<D: DataMut, BRA: BlindRotationAlgo> BDDKey<D, BRA> {
     }
 }
 
-impl<BRA: BlindRotationAlgo> ReaderFrom for BDDKey<Vec<u8>, BRA> {
+impl<BRA: BlindRotationAlgo> ReaderFrom for BDDKey<AlignedBuf, BRA> {
     fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
         self.cbt.read_from(reader)?;
         match reader.read_u8()? {
@@ -304,7 +304,7 @@ impl<BRA: BlindRotationAlgo> ReaderFrom for BDDKey<Vec<u8>, BRA> {
     }
 }
 
-impl<BRA: BlindRotationAlgo> WriterTo for BDDKey<Vec<u8>, BRA> {
+impl<BRA: BlindRotationAlgo> WriterTo for BDDKey<AlignedBuf, BRA> {
     fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         self.cbt.write_to(writer)?;
         match &self.ks_glwe {
@@ -504,3 +504,44 @@ pub trait FheUintPrepareDebug<BRA: BlindRotationAlgo, T: UnsignedInteger, BE: Ba
         DR0: DataRef,
         DR1: DataRef;
 }
+
+/// Persists the prepared key in the backend-tagged format of prepared
+/// layouts, in the order of [`BDDKey`], the presence of `ks_glwe` being
+/// recorded in a metadata block.
+impl<D: DataRef, BRA: BlindRotationAlgo, BE: Backend> WriterTo for BDDKeyPrepared<D, BRA, BE> {
+    fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.cbt.write_to(writer)?;
+        write_prepared_meta(writer, &[self.ks_glwe.is_some() as u8])?;
+        if let Some(k) = &self.ks_glwe {
+            k.write_to(writer)?;
+        }
+        self.ks_lwe.write_to(writer)
+    }
+}
+
+/// Loads a prepared key persisted by [`WriterTo`] into a key allocated with
+/// the same dimensions, failing if it was written by an incompatible backend
+/// or for another ring degree.
+impl<D: DataMut, BRA: BlindRotationAlgo, BE: Backend> ReaderFrom for BDDKeyPrepared<D, BRA, BE> {
+    fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        self.cbt.read_from(reader)?;
+        let meta: [u8; PREPARED_BLOCK_BYTES] = read_prepared_meta(reader)?;
+        match (meta[0], &mut self.ks_glwe) {
+            (0, None) => {}
+            (1, Some(ks_glwe)) => ks_glwe.read_from(reader)?,
+            (tag @ (0 | 1), ks_glwe) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("self.ks_glwe.is_some()={} != read ks_glwe tag={tag}", ks_glwe.is_some()),
+                ));
+            }
+            (tag, _) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("invalid ks_glwe tag={tag} (expected 0 or 1)"),
+                ));
+            }
+        }
+        self.ks_lwe.read_from(reader)
+    }
+}
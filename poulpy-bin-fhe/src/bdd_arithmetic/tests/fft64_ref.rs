@@ -62,6 +62,11 @@ fn bdd_prepare() {
     test_suite::test_bdd_prepare(&TEST_CONTEXT_CGGI_FFT64_REF)
 }
 
+#[test]
+fn bdd_prepare_wide_input() {
+    test_suite::test_bdd_prepare_wide_input(&TEST_CONTEXT_CGGI_FFT64_REF)
+}
+
 #[test]
 fn bdd_add() {
     test_suite::test_bdd_add(&TEST_CONTEXT_CGGI_FFT64_REF)
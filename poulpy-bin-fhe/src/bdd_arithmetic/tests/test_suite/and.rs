@@ -4,7 +4,7 @@ use poulpy_core::{
 };
 use poulpy_hal::{
     api::{ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{Backend, DeviceBuf, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, Backend, DeviceBuf, Module, Scratch, ScratchOwned},
     source::Source,
 };
 use rand::Rng;
@@ -49,7 +49,7 @@ where
 
     let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(1 << 22);
 
-    let mut res: FheUint<Vec<u8>, u32> = FheUint::<Vec<u8>, u32>::alloc_from_infos(&glwe_infos);
+    let mut res: FheUint<AlignedBuf, u32> = FheUint::<AlignedBuf, u32>::alloc_from_infos(&glwe_infos);
     let mut a_enc_prep: FheUintPrepared<DeviceBuf<BE>, u32, BE> =
         FheUintPrepared::<DeviceBuf<BE>, u32, BE>::alloc_from_infos(module, &ggsw_infos);
     let mut b_enc_prep: FheUintPrepared<DeviceBuf<BE>, u32, BE> =
@@ -4,7 +4,7 @@ use poulpy_core::{
 };
 use poulpy_hal::{
     api::{ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{Backend, DeviceBuf, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, Backend, DeviceBuf, Module, Scratch, ScratchOwned},
     source::Source,
 };
 use rand::Rng;
@@ -36,7 +36,7 @@ where
 
     let glwe_enc_infos = EncryptionLayout::new_from_default_sigma(glwe_infos).unwrap();
 
-    let mut a_enc: FheUint<Vec<u8>, u32> = FheUint::<Vec<u8>, u32>::alloc_from_infos(&glwe_infos);
+    let mut a_enc: FheUint<AlignedBuf, u32> = FheUint::<AlignedBuf, u32>::alloc_from_infos(&glwe_infos);
 
     for j in 0..3 {
         let a: u32 = 0x8483_8281;
@@ -104,9 +104,9 @@ where
 
     let glwe_enc_infos = EncryptionLayout::new_from_default_sigma(glwe_infos).unwrap();
 
-    let mut a_enc: FheUint<Vec<u8>, u32> = FheUint::<Vec<u8>, u32>::alloc_from_infos(&glwe_infos);
-    let mut b_enc: FheUint<Vec<u8>, u32> = FheUint::<Vec<u8>, u32>::alloc_from_infos(&glwe_infos);
-    let mut c_enc: FheUint<Vec<u8>, u32> = FheUint::<Vec<u8>, u32>::alloc_from_infos(&glwe_infos);
+    let mut a_enc: FheUint<AlignedBuf, u32> = FheUint::<AlignedBuf, u32>::alloc_from_infos(&glwe_infos);
+    let mut b_enc: FheUint<AlignedBuf, u32> = FheUint::<AlignedBuf, u32>::alloc_from_infos(&glwe_infos);
+    let mut c_enc: FheUint<AlignedBuf, u32> = FheUint::<AlignedBuf, u32>::alloc_from_infos(&glwe_infos);
 
     let a: u32 = 0xFFFFFFFF;
     let b: u32 = 0xAABBCCDD;
@@ -165,9 +165,9 @@ where
 
     let glwe_enc_infos = EncryptionLayout::new_from_default_sigma(glwe_infos).unwrap();
 
-    let mut a_enc: FheUint<Vec<u8>, u32> = FheUint::<Vec<u8>, u32>::alloc_from_infos(&glwe_infos);
-    let mut b_enc: FheUint<Vec<u8>, u32> = FheUint::<Vec<u8>, u32>::alloc_from_infos(&glwe_infos);
-    let mut c_enc: FheUint<Vec<u8>, u32> = FheUint::<Vec<u8>, u32>::alloc_from_infos(&glwe_infos);
+    let mut a_enc: FheUint<AlignedBuf, u32> = FheUint::<AlignedBuf, u32>::alloc_from_infos(&glwe_infos);
+    let mut b_enc: FheUint<AlignedBuf, u32> = FheUint::<AlignedBuf, u32>::alloc_from_infos(&glwe_infos);
+    let mut c_enc: FheUint<AlignedBuf, u32> = FheUint::<AlignedBuf, u32>::alloc_from_infos(&glwe_infos);
 
     let a: u32 = 0xFFFFFFFF;
     let b: u32 = 0xAABBCCDD;
@@ -223,8 +223,8 @@ where
 
     let glwe_enc_infos = EncryptionLayout::new_from_default_sigma(glwe_infos).unwrap();
 
-    let mut a_enc: FheUint<Vec<u8>, u32> = FheUint::<Vec<u8>, u32>::alloc_from_infos(&glwe_infos);
-    let mut c_enc: FheUint<Vec<u8>, u32> = FheUint::<Vec<u8>, u32>::alloc_from_infos(&glwe_infos);
+    let mut a_enc: FheUint<AlignedBuf, u32> = FheUint::<AlignedBuf, u32>::alloc_from_infos(&glwe_infos);
+    let mut c_enc: FheUint<AlignedBuf, u32> = FheUint::<AlignedBuf, u32>::alloc_from_infos(&glwe_infos);
 
     let a: u32 = source_xa.next_u32();
 
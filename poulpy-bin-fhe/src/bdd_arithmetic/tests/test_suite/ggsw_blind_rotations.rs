@@ -7,7 +7,7 @@ use poulpy_core::{
 };
 use poulpy_hal::{
     api::{ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxRotateAssign},
-    layouts::{Backend, DeviceBuf, Module, ScalarZnx, Scratch, ScratchOwned, ZnxView, ZnxViewMut},
+    layouts::{AlignedBuf, Backend, DeviceBuf, Module, ScalarZnx, Scratch, ScratchOwned, ZnxView, ZnxViewMut},
     source::Source,
 };
 use rand::Rng;
@@ -65,9 +65,9 @@ where
     let mut source_xe: Source = Source::new([3u8; 32]);
 
     let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(1 << 22);
-    let mut res: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_res_infos);
+    let mut res: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_res_infos);
 
-    let mut scalar: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(module.n(), 1);
+    let mut scalar: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(module.n(), 1);
     scalar.raw_mut().iter_mut().enumerate().for_each(|(i, x)| *x = i as i64);
 
     let k: u32 = source.next_u32();
@@ -125,7 +125,7 @@ where
 
             let rot: i64 = (((k >> bit_start) & mask) << bit_step) as i64;
 
-            let mut scalar_want: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(module.n(), 1);
+            let mut scalar_want: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(module.n(), 1);
             scalar_want.raw_mut().copy_from_slice(scalar.raw());
 
             module.vec_znx_rotate_assign(-rot, &mut scalar_want.as_vec_znx_mut(), 0, scratch.borrow());
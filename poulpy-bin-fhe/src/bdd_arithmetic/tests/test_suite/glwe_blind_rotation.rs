@@ -7,7 +7,7 @@ use poulpy_core::{
 };
 use poulpy_hal::{
     api::{ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{Backend, DeviceBuf, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, Backend, DeviceBuf, Module, Scratch, ScratchOwned},
     source::Source,
 };
 use rand::Rng;
@@ -62,9 +62,9 @@ where
 
     let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(1 << 22);
 
-    let mut res: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_infos);
+    let mut res: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
 
-    let mut test_glwe: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+    let mut test_glwe: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
     let mut data: Vec<i64> = vec![0i64; module.n()];
     data.iter_mut().enumerate().for_each(|(i, x)| *x = i as i64);
     test_glwe.encode_vec_i64(&data, base2k.as_usize().into());
@@ -92,7 +92,7 @@ where
     // Starting bit
     let mut bit_start: usize = 0;
 
-    let mut pt: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+    let mut pt: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
 
     for _ in 0..32_usize.div_ceil(module.log_n()) {
         // By how many bits to left shift
@@ -9,7 +9,7 @@ use poulpy_core::{
 };
 use poulpy_hal::{
     api::{ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{Backend, DeviceBuf, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, Backend, DeviceBuf, Module, Scratch, ScratchOwned},
     source::Source,
 };
 use rand::Rng;
@@ -64,7 +64,7 @@ where
 
     let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(1 << 22);
 
-    let mut res: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_infos);
+    let mut res: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
 
     let k: u32 = source.next_u32();
 
@@ -93,10 +93,10 @@ where
     data.iter_mut().enumerate().for_each(|(i, x)| *x = i as i64);
 
     for _ in 0..32_usize.div_ceil(digit) {
-        let mut pt: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+        let mut pt: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
 
-        let mut cts_map: HashMap<usize, &mut GLWE<Vec<u8>>> = HashMap::new();
-        let mut cts: Vec<GLWE<Vec<u8>>> = Vec::new();
+        let mut cts_map: HashMap<usize, &mut GLWE<AlignedBuf>> = HashMap::new();
+        let mut cts: Vec<GLWE<AlignedBuf>> = Vec::new();
 
         for value in data.iter().take(1 << digit) {
             pt.encode_coeff_i64(*value, TorusPrecision(base2k.as_u32()), 0);
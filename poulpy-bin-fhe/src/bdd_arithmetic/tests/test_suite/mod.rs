@@ -24,7 +24,7 @@ pub use glwe_blind_selection::*;
 pub use or::*;
 use poulpy_hal::{
     api::{ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{Backend, DeviceBuf, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, Backend, DeviceBuf, Module, Scratch, ScratchOwned},
     source::Source,
 };
 pub use prepare::*;
@@ -55,7 +55,7 @@ use crate::{
 pub struct TestContext<BRA: BlindRotationAlgo, BE: Backend> {
     pub module: Module<BE>,
     pub sk_glwe: GLWESecretPrepared<DeviceBuf<BE>, BE>,
-    pub sk_lwe: LWESecret<Vec<u8>>,
+    pub sk_lwe: LWESecret<AlignedBuf>,
     pub bdd_key: BDDKeyPrepared<DeviceBuf<BE>, BRA, BE>,
 }
 
@@ -101,17 +101,17 @@ impl<BRA: BlindRotationAlgo, BE: Backend> TestContext<BRA, BE> {
 
         let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(1 << 22);
 
-        let mut sk_glwe: GLWESecret<Vec<u8>> = GLWESecret::alloc(TEST_N_GLWE.into(), TEST_RANK.into());
+        let mut sk_glwe: GLWESecret<AlignedBuf> = GLWESecret::alloc(TEST_N_GLWE.into(), TEST_RANK.into());
         sk_glwe.fill_ternary_prob(0.5, &mut source_xs);
         let mut sk_glwe_prep: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(TEST_RANK.into());
         module.glwe_secret_prepare(&mut sk_glwe_prep, &sk_glwe);
 
         let n_lwe: u32 = TEST_N_LWE;
         let block_size: u32 = TEST_BLOCK_SIZE;
-        let mut sk_lwe: LWESecret<Vec<u8>> = LWESecret::alloc(n_lwe.into());
+        let mut sk_lwe: LWESecret<AlignedBuf> = LWESecret::alloc(n_lwe.into());
         sk_lwe.fill_binary_block(block_size as usize, &mut source_xs);
         let bdd_key_infos: BDDKeyLayout = TEST_BDD_KEY_LAYOUT;
-        let mut bdd_key: BDDKey<Vec<u8>, BRA> = BDDKey::alloc_from_infos(&bdd_key_infos);
+        let mut bdd_key: BDDKey<AlignedBuf, BRA> = BDDKey::alloc_from_infos(&bdd_key_infos);
         let bdd_enc_infos = BDDEncryptionInfos::from_default_sigma(&bdd_key_infos).unwrap();
         bdd_key.encrypt_sk(
             &module,
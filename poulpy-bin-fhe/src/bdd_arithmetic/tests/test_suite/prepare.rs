@@ -1,10 +1,13 @@
 use poulpy_core::{
     DEFAULT_SIGMA_XE, EncryptionLayout, GGSWNoise, GLWEDecrypt, GLWEEncryptSk, GLWENoise, ScratchTakeCore,
-    layouts::{GGSWInfos, GGSWLayout, GLWEInfos, GLWELayout, GLWESecretPreparedFactory, LWEInfos, prepared::GLWESecretPrepared},
+    layouts::{
+        GGSWInfos, GGSWLayout, GLWEInfos, GLWELayout, GLWESecretPreparedFactory, LWEInfos, TorusPrecision,
+        prepared::GLWESecretPrepared,
+    },
 };
 use poulpy_hal::{
     api::{ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{Backend, DeviceBuf, Module, Scratch, ScratchOwned, Stats},
+    layouts::{AlignedBuf, Backend, DeviceBuf, Module, Scratch, ScratchOwned, Stats},
     source::Source,
 };
 use rand::Rng;
@@ -53,7 +56,7 @@ where
     let glwe_enc_infos = EncryptionLayout::new_from_default_sigma(glwe_infos).unwrap();
 
     // GLWE(value)
-    let mut c_enc: FheUint<Vec<u8>, u32> = FheUint::alloc_from_infos(&glwe_infos);
+    let mut c_enc: FheUint<AlignedBuf, u32> = FheUint::alloc_from_infos(&glwe_infos);
     let value: u32 = source.next_u32();
     c_enc.encrypt_sk(
         module,
@@ -66,8 +69,8 @@ where
     );
 
     // GGSW(0)
-    let mut c_enc_prep_debug: FheUintPreparedDebug<Vec<u8>, u32> =
-        FheUintPreparedDebug::<Vec<u8>, u32>::alloc_from_infos(module, &ggsw_infos);
+    let mut c_enc_prep_debug: FheUintPreparedDebug<AlignedBuf, u32> =
+        FheUintPreparedDebug::<AlignedBuf, u32>::alloc_from_infos(module, &ggsw_infos);
 
     let mut scratch_2 = ScratchOwned::alloc(module.fhe_uint_prepare_tmp_bytes(7, 1, &c_enc_prep_debug, &c_enc, bdd_key_prepared));
 
@@ -97,3 +100,61 @@ where
         }
     }
 }
+
+/// Prepares a ciphertext whose precision exceeds that of the circuit
+/// bootstrapping, with exactly [`FheUintPrepare::fhe_uint_prepare_tmp_bytes`]
+/// bytes of scratch: the bit extraction key-switches the full-width input
+/// and must be covered by the estimate.
+pub fn test_bdd_prepare_wide_input<BRA: BlindRotationAlgo, BE: Backend>(test_context: &TestContext<BRA, BE>)
+where
+    Module<BE>: ModuleNew<BE>
+        + GLWESecretPreparedFactory<BE>
+        + GLWEDecrypt<BE>
+        + FheUintPreparedFactory<u32, BE>
+        + FheUintPreparedEncryptSk<u32, BE>
+        + FheUintPrepareDebug<BRA, u32, BE>
+        + BDDKeyEncryptSk<BRA, BE>
+        + BDDKeyPreparedFactory<BRA, BE>
+        + GGSWNoise<BE>
+        + FheUintPrepare<BRA, BE>
+        + ExecuteBDDCircuit2WTo1W<BE>
+        + GLWEEncryptSk<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchTakeCore<BE>,
+{
+    let glwe_infos: GLWELayout = GLWELayout {
+        k: TorusPrecision(8 * TEST_GLWE_INFOS.base2k.0),
+        ..TEST_GLWE_INFOS
+    };
+    let ggsw_infos: GGSWLayout = TEST_GGSW_INFOS;
+
+    let module: &Module<BE> = &test_context.module;
+    let sk_glwe_prep = &test_context.sk_glwe;
+    let bdd_key_prepared = &test_context.bdd_key;
+
+    let mut source: Source = Source::new([6u8; 32]);
+    let mut source_xa: Source = Source::new([2u8; 32]);
+    let mut source_xe: Source = Source::new([3u8; 32]);
+
+    let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(1 << 22);
+
+    let glwe_enc_infos = EncryptionLayout::new_from_default_sigma(glwe_infos).unwrap();
+
+    let mut c_enc = FheUint::<_, u32>::alloc_from_infos(&glwe_infos);
+    let value: u32 = source.next_u32();
+    c_enc.encrypt_sk(
+        module,
+        value,
+        sk_glwe_prep,
+        &glwe_enc_infos,
+        &mut source_xe,
+        &mut source_xa,
+        scratch.borrow(),
+    );
+
+    let mut c_enc_prep_debug = FheUintPreparedDebug::<_, u32>::alloc_from_infos(module, &ggsw_infos);
+
+    let mut scratch_prepare =
+        ScratchOwned::alloc(module.fhe_uint_prepare_tmp_bytes(7, 1, &c_enc_prep_debug, &c_enc, bdd_key_prepared));
+    c_enc_prep_debug.prepare(module, &c_enc, bdd_key_prepared, scratch_prepare.borrow());
+}
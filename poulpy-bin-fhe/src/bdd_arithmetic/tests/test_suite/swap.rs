@@ -5,7 +5,7 @@ use poulpy_core::{
 };
 use poulpy_hal::{
     api::{ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{Backend, DeviceBuf, Module, ScalarZnx, Scratch, ScratchOwned, ZnxViewMut},
+    layouts::{AlignedBuf, Backend, DeviceBuf, Module, ScalarZnx, Scratch, ScratchOwned, ZnxViewMut},
     source::Source,
 };
 use rand::Rng;
@@ -38,15 +38,15 @@ where
     let glwe_enc_infos = EncryptionLayout::new_from_default_sigma(glwe_infos).unwrap();
     let ggsw_enc_infos = EncryptionLayout::new_from_default_sigma(ggsw_infos).unwrap();
 
-    let mut s: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_infos);
+    let mut s: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_infos);
     let mut s_prepared: GGSWPrepared<DeviceBuf<BE>, BE> = module.ggsw_prepared_alloc_from_infos(&ggsw_infos);
 
     let a: u32 = source_xa.next_u32();
     let b: u32 = source_xa.next_u32();
 
     for bit in [0, 1] {
-        let mut a_enc: FheUint<Vec<u8>, u32> = FheUint::<Vec<u8>, u32>::alloc_from_infos(&glwe_infos);
-        let mut b_enc: FheUint<Vec<u8>, u32> = FheUint::<Vec<u8>, u32>::alloc_from_infos(&glwe_infos);
+        let mut a_enc: FheUint<AlignedBuf, u32> = FheUint::<AlignedBuf, u32>::alloc_from_infos(&glwe_infos);
+        let mut b_enc: FheUint<AlignedBuf, u32> = FheUint::<AlignedBuf, u32>::alloc_from_infos(&glwe_infos);
 
         a_enc.encrypt_sk(
             module,
@@ -68,7 +68,7 @@ where
             scratch.borrow(),
         );
 
-        let mut pt: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(module.n(), 1);
+        let mut pt: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(module.n(), 1);
         pt.raw_mut()[0] = bit;
         module.ggsw_encrypt_sk(
             &mut s,
@@ -112,9 +112,9 @@ where
 
     let data: Vec<u32> = (0..25).map(|i| i as u32).collect_vec();
 
-    let mut data_enc: Vec<FheUint<Vec<u8>, u32>> = (0..data.len())
+    let mut data_enc: Vec<FheUint<AlignedBuf, u32>> = (0..data.len())
         .map(|i| {
-            let mut ct: FheUint<Vec<u8>, u32> = FheUint::<Vec<u8>, u32>::alloc_from_infos(&glwe_infos);
+            let mut ct: FheUint<AlignedBuf, u32> = FheUint::<AlignedBuf, u32>::alloc_from_infos(&glwe_infos);
             ct.encrypt_sk(
                 module,
                 data[i],
@@ -174,9 +174,9 @@ where
 
     let data: Vec<u32> = (0..25).map(|i| i as u32).collect_vec();
 
-    let data_enc: Vec<FheUint<Vec<u8>, u32>> = (0..data.len())
+    let data_enc: Vec<FheUint<AlignedBuf, u32>> = (0..data.len())
         .map(|i| {
-            let mut ct: FheUint<Vec<u8>, u32> = FheUint::<Vec<u8>, u32>::alloc_from_infos(&glwe_infos);
+            let mut ct: FheUint<AlignedBuf, u32> = FheUint::<AlignedBuf, u32>::alloc_from_infos(&glwe_infos);
             ct.encrypt_sk(
                 module,
                 data[i],
@@ -204,7 +204,7 @@ where
             scratch.borrow(),
         );
 
-        let mut res: FheUint<Vec<u8>, u32> = FheUint::alloc_from_infos(&glwe_infos);
+        let mut res: FheUint<AlignedBuf, u32> = FheUint::alloc_from_infos(&glwe_infos);
         retriever.retrieve(module, &mut res, &data_enc, &idx_enc, offset, scratch.borrow());
 
         assert_eq!(data[idx as usize], res.decrypt(module, sk, scratch.borrow()));
@@ -1,5 +1,5 @@
 use poulpy_hal::{
-    layouts::{Backend, DataMut, Module, ScalarZnx, ScalarZnxToRef, Scratch, ZnxView, ZnxViewMut},
+    layouts::{AlignedBuf, Backend, DataMut, Module, ScalarZnx, ScalarZnxToRef, Scratch, ZnxView, ZnxViewMut},
     source::Source,
 };
 
@@ -50,7 +50,7 @@ where
 
             res.dist = sk_lwe.dist();
 
-            let mut pt: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(sk_glwe.n().into(), 1);
+            let mut pt: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(sk_glwe.n().into(), 1);
             let sk_ref: ScalarZnx<&[u8]> = sk_lwe.data().to_ref();
 
             for (i, ggsw) in res.keys.iter_mut().enumerate() {
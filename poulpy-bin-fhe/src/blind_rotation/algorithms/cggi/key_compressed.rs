@@ -5,7 +5,7 @@ use poulpy_core::{
     layouts::{GGSWCompressed, GGSWInfos, GLWEInfos, GLWESecretPreparedToRef, LWEInfos, LWESecret, LWESecretToRef},
 };
 use poulpy_hal::{
-    layouts::{Backend, DataMut, DataRef, Module, ScalarZnx, ScalarZnxToRef, Scratch, ZnxView, ZnxViewMut},
+    layouts::{AlignedBuf, Backend, DataMut, DataRef, Module, ScalarZnx, ScalarZnxToRef, Scratch, ZnxView, ZnxViewMut},
     source::Source,
 };
 
@@ -15,11 +15,11 @@ use crate::blind_rotation::{
 };
 
 impl<D: DataRef> BlindRotationKeyCompressedFactory<CGGI> for BlindRotationKeyCompressed<D, CGGI> {
-    fn blind_rotation_key_compressed_alloc<A>(infos: &A) -> BlindRotationKeyCompressed<Vec<u8>, CGGI>
+    fn blind_rotation_key_compressed_alloc<A>(infos: &A) -> BlindRotationKeyCompressed<AlignedBuf, CGGI>
     where
         A: BlindRotationKeyInfos,
     {
-        let mut data: Vec<GGSWCompressed<Vec<u8>>> = Vec::with_capacity(infos.n_lwe().into());
+        let mut data: Vec<GGSWCompressed<AlignedBuf>> = Vec::with_capacity(infos.n_lwe().into());
         (0..infos.n_lwe().as_usize()).for_each(|_| data.push(GGSWCompressed::alloc_from_infos(infos)));
         BlindRotationKeyCompressed {
             keys: data,
@@ -74,7 +74,7 @@ where
 
             res.dist = sk_lwe.dist();
 
-            let mut pt: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(sk_glwe.n().into(), 1);
+            let mut pt: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(sk_glwe.n().into(), 1);
             let sk_ref: ScalarZnx<&[u8]> = sk_lwe.data().to_ref();
 
             for (i, ggsw) in res.keys.iter_mut().enumerate() {
@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::{ScratchAvailable, SvpPPolAlloc, SvpPrepare},
-    layouts::{Backend, DataMut, DataRef, DeviceBuf, Module, ScalarZnx, Scratch, SvpPPolOwned},
+    layouts::{AlignedBuf, Backend, DataMut, DataRef, DeviceBuf, Module, ScalarZnx, Scratch, SvpPPolOwned},
 };
 
 use std::marker::PhantomData;
@@ -65,7 +65,7 @@ where
 
         if let Distribution::BinaryBlock(_) = other.dist {
             let mut x_pow_a: Vec<SvpPPolOwned<BE>> = Vec::with_capacity(n << 1);
-            let mut buf: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, 1);
+            let mut buf: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
             (0..n << 1).for_each(|i| {
                 let mut res: SvpPPolOwned<BE> = self.svp_ppol_alloc(1);
                 set_xai_plus_y(self, i, 0, &mut res, &mut buf);
@@ -8,6 +8,7 @@ use std::marker::PhantomData;
 use poulpy_core::{Distribution, layouts::GGSW};
 
 use crate::blind_rotation::{BlindRotationAlgo, BlindRotationKey, BlindRotationKeyInfos};
+use poulpy_hal::layouts::AlignedBuf;
 
 /// Algorithm marker for the
 /// Chillotti-Gama-Georgieva-Izabachène (CGGI / TFHE) blind rotation.
@@ -32,7 +33,7 @@ use crate::blind_rotation::{BlindRotationAlgo, BlindRotationKey, BlindRotationKe
 pub struct CGGI {}
 
 impl BlindRotationAlgo for CGGI {
-    fn alloc_key<A: BlindRotationKeyInfos>(infos: &A) -> BlindRotationKey<Vec<u8>, Self> {
+    fn alloc_key<A: BlindRotationKeyInfos>(infos: &A) -> BlindRotationKey<AlignedBuf, Self> {
         BlindRotationKey {
             keys: (0..infos.n_lwe().as_usize()).map(|_| GGSW::alloc_from_infos(infos)).collect(),
             dist: Distribution::NONE,
@@ -7,7 +7,7 @@ use poulpy_core::{
     ScratchTakeCore,
     layouts::{GGSWInfos, GLWE, GLWEInfos, LWE, LWEInfos},
 };
-use poulpy_hal::layouts::{Backend, DataMut, DataRef, DeviceBuf, Scratch, ZnxView};
+use poulpy_hal::layouts::{AlignedBuf, Backend, DataMut, DataRef, DeviceBuf, Scratch, ZnxView};
 
 use crate::blind_rotation::{
     BlindRotationKey, BlindRotationKeyInfos, BlindRotationKeyPrepared, LookUpTableRotationDirection, LookupTable,
@@ -20,7 +20,7 @@ use crate::blind_rotation::{
 /// usage at the type level.  Currently the only implementation is [`CGGI`].
 pub trait BlindRotationAlgo: Sync {
     /// Allocates a zero-filled [`BlindRotationKey`] from a dimension descriptor.
-    fn alloc_key<A: BlindRotationKeyInfos>(infos: &A) -> BlindRotationKey<Vec<u8>, Self>
+    fn alloc_key<A: BlindRotationKeyInfos>(infos: &A) -> BlindRotationKey<AlignedBuf, Self>
     where
         Self: Sized;
 }
@@ -147,14 +147,17 @@ pub fn mod_switch_2n(n: usize, res: &mut [i64], lwe: &LWE<&[u8]>, rot_dir: LookU
         LookUpTableRotationDirection::Right => {}
     }
 
-    if base2k > log2n {
-        let diff: usize = base2k - (log2n - 1); // additional -1 because we map to [-N/2, N/2) instead of [0, N)
+    // Number of bits kept: -1 because we map to [-N/2, N/2) instead of [0, N)
+    let log2n_signed: usize = log2n - 1;
+
+    if base2k > log2n_signed {
+        let diff: usize = base2k - log2n_signed;
         res.iter_mut().for_each(|x| {
             *x = div_round_by_pow2(x, diff);
         })
     } else {
-        let rem: usize = base2k - (log2n % base2k);
-        let size: usize = log2n.div_ceil(base2k);
+        let rem: usize = base2k - (log2n_signed % base2k);
+        let size: usize = log2n_signed.div_ceil(base2k);
         (1..size).for_each(|i| {
             if i == size - 1 && rem != base2k {
                 let k_rem: usize = base2k - rem;
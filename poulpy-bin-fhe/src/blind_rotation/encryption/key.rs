@@ -1,5 +1,5 @@
 use poulpy_hal::{
-    layouts::{Backend, DataMut, Scratch},
+    layouts::{AlignedBuf, Backend, DataMut, Scratch},
     source::Source,
 };
 
@@ -76,7 +76,7 @@ impl<D: DataMut, BRA: BlindRotationAlgo> BlindRotationKey<D, BRA> {
     }
 }
 
-impl<BRA: BlindRotationAlgo> BlindRotationKey<Vec<u8>, BRA> {
+impl<BRA: BlindRotationAlgo> BlindRotationKey<AlignedBuf, BRA> {
     pub fn encrypt_sk_tmp_bytes<A, M, BE: Backend>(module: &M, infos: &A) -> usize
     where
         A: GGSWInfos,
@@ -1,5 +1,5 @@
 use poulpy_hal::{
-    layouts::{Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
+    layouts::{AlignedBuf, Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
     source::Source,
 };
 
@@ -152,8 +152,8 @@ pub struct BlindRotationKey<D: Data, BRT: BlindRotationAlgo> {
     pub(crate) _phantom: PhantomData<BRT>,
 }
 
-impl<BRA: BlindRotationAlgo> BlindRotationKey<Vec<u8>, BRA> {
-    pub fn alloc<A: BlindRotationKeyInfos>(infos: &A) -> BlindRotationKey<Vec<u8>, BRA> {
+impl<BRA: BlindRotationAlgo> BlindRotationKey<AlignedBuf, BRA> {
+    pub fn alloc<A: BlindRotationKeyInfos>(infos: &A) -> BlindRotationKey<AlignedBuf, BRA> {
         BRA::alloc_key(infos)
     }
 }
@@ -1,5 +1,5 @@
 use poulpy_hal::{
-    layouts::{Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
+    layouts::{AlignedBuf, Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
     source::Source,
 };
 
@@ -45,16 +45,16 @@ pub struct BlindRotationKeyCompressed<D: Data, BRT: BlindRotationAlgo> {
 
 /// Algorithm-specific factory for allocating a [`BlindRotationKeyCompressed`].
 pub trait BlindRotationKeyCompressedFactory<BRA: BlindRotationAlgo> {
-    fn blind_rotation_key_compressed_alloc<A>(infos: &A) -> BlindRotationKeyCompressed<Vec<u8>, BRA>
+    fn blind_rotation_key_compressed_alloc<A>(infos: &A) -> BlindRotationKeyCompressed<AlignedBuf, BRA>
     where
         A: BlindRotationKeyInfos;
 }
 
-impl<BRA: BlindRotationAlgo> BlindRotationKeyCompressed<Vec<u8>, BRA>
+impl<BRA: BlindRotationAlgo> BlindRotationKeyCompressed<AlignedBuf, BRA>
 where
     Self: BlindRotationKeyCompressedFactory<BRA>,
 {
-    pub fn alloc<A>(infos: &A) -> BlindRotationKeyCompressed<Vec<u8>, BRA>
+    pub fn alloc<A>(infos: &A) -> BlindRotationKeyCompressed<AlignedBuf, BRA>
     where
         A: BlindRotationKeyInfos,
     {
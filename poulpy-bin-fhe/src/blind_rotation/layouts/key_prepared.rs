@@ -1,4 +1,8 @@
-use poulpy_hal::layouts::{Backend, Data, DataMut, DataRef, DeviceBuf, Scratch, SvpPPolOwned};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use poulpy_hal::layouts::{
+    Backend, Data, DataMut, DataRef, DeviceBuf, PREPARED_BLOCK_BYTES, ReaderFrom, Scratch, SvpPPolOwned, WriterTo,
+    read_prepared_meta, write_prepared_meta,
+};
 
 use std::marker::PhantomData;
 
@@ -148,3 +152,73 @@ impl<D: Data, BRT: BlindRotationAlgo, B: Backend> BlindRotationKeyPrepared<D, BR
         }
     }
 }
+
+/// Persists the prepared key in the backend-tagged format of prepared
+/// layouts: a metadata block holding the distribution, the number of GGSWs
+/// and the size of the `x_pow_a` table, followed by the GGSWs and the table.
+impl<D: DataRef, BRT: BlindRotationAlgo, B: Backend> WriterTo for BlindRotationKeyPrepared<D, BRT, B> {
+    fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let mut meta: Vec<u8> = Vec::new();
+        self.dist.write_to(&mut meta)?;
+        meta.write_u64::<LittleEndian>(self.data.len() as u64)?;
+        match &self.x_pow_a {
+            None => meta.write_u8(0)?,
+            Some(x_pow_a) => {
+                meta.write_u8(1)?;
+                meta.write_u64::<LittleEndian>(x_pow_a.len() as u64)?;
+            }
+        }
+        write_prepared_meta(writer, &meta)?;
+        for ggsw in &self.data {
+            ggsw.write_to(writer)?;
+        }
+        if let Some(x_pow_a) = &self.x_pow_a {
+            for x in x_pow_a {
+                x.write_to(writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Loads a prepared key persisted by [`WriterTo`] into a key allocated with
+/// the same dimensions, failing if it was written by an incompatible backend
+/// or for another ring degree.
+impl<D: DataMut, BRT: BlindRotationAlgo, B: Backend> ReaderFrom for BlindRotationKeyPrepared<D, BRT, B> {
+    fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        let meta: [u8; PREPARED_BLOCK_BYTES] = read_prepared_meta(reader)?;
+        let mut meta: &[u8] = &meta;
+        let dist: Distribution = Distribution::read_from(&mut meta)?;
+        let len: usize = meta.read_u64::<LittleEndian>()? as usize;
+        if self.data.len() != len {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("self.data.len()={} != read len={len}", self.data.len()),
+            ));
+        }
+        let x_pow_a_len: Option<usize> = match meta.read_u8()? {
+            0 => None,
+            _ => Some(meta.read_u64::<LittleEndian>()? as usize),
+        };
+        if self.x_pow_a.as_ref().map(Vec::len) != x_pow_a_len {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "self.x_pow_a.len()={:?} != read x_pow_a len={x_pow_a_len:?}",
+                    self.x_pow_a.as_ref().map(Vec::len)
+                ),
+            ));
+        }
+
+        for ggsw in &mut self.data {
+            ggsw.read_from(reader)?;
+        }
+        if let Some(x_pow_a) = &mut self.x_pow_a {
+            for x in x_pow_a {
+                x.read_from(reader)?;
+            }
+        }
+        self.dist = dist;
+        Ok(())
+    }
+}
@@ -6,7 +6,7 @@ use poulpy_hal::{
         ScratchOwnedAlloc, ScratchOwnedBorrow, TakeSlice, VecZnxCopy, VecZnxNormalizeAssign, VecZnxNormalizeTmpBytes,
         VecZnxRotateAssign, VecZnxRotateAssignTmpBytes, VecZnxSwitchRing,
     },
-    layouts::{Backend, Module, Scratch, ScratchOwned, VecZnx, ZnxInfos, ZnxViewMut},
+    layouts::{AlignedBuf, Backend, Module, Scratch, ScratchOwned, VecZnx, ZnxInfos, ZnxViewMut},
 };
 
 /// Specifies in which direction the LUT is rotated by the LWE constant term
@@ -109,7 +109,7 @@ impl LookupTableInfos for LookUpTableLayout {
 /// - All `VecZnx` elements share the same `n`, `base2k`, and `size`.
 /// - `drift` records the half-step pre-rotation applied during encoding.
 pub struct LookupTable {
-    pub(crate) data: Vec<VecZnx<Vec<u8>>>,
+    pub(crate) data: Vec<VecZnx<AlignedBuf>>,
     pub(crate) rot_dir: LookUpTableRotationDirection,
     pub(crate) base2k: Base2K,
     pub(crate) k: TorusPrecision,
@@ -302,7 +302,7 @@ where
         let size: usize = res.k.as_usize().div_ceil(base2k);
 
         // Equivalent to AUTO([f(0), -f(n-1), -f(n-2), ..., -f(1)], -1)
-        let mut lut_full: VecZnx<Vec<u8>> = VecZnx::alloc(domain_size, 1, size);
+        let mut lut_full: VecZnx<AlignedBuf> = VecZnx::alloc(domain_size, 1, size);
 
         let lut_at: &mut [i64] = lut_full.at_mut(0, limbs - 1);
 
@@ -41,14 +41,22 @@
 //!
 //! The compressed variant stores only a 32-byte seed for the mask component,
 //! reducing serialised size at the cost of decompression during preparation.
+//!
+//! ## Sanitized Bootstrapping
+//!
+//! [`BlindRotationSanitize`] chains a blind rotation, a sample extraction and
+//! a [`poulpy_core::LWESanitize`] re-randomization, producing LWE outputs that
+//! do not reveal the evaluated lookup table (circuit privacy).
 mod algorithms;
 mod encryption;
 mod layouts;
 mod lut;
+mod sanitize;
 mod utils;
 
 pub use algorithms::*;
 pub use encryption::*;
 pub use layouts::*;
 pub use lut::*;
+pub use sanitize::*;
 pub mod tests;
@@ -0,0 +1,133 @@
+use poulpy_core::{
+    EncryptionInfos, GetDistribution, LWESampleExtract, LWESanitize, ScratchTakeCore,
+    layouts::{GLWE, GLWEInfos, GLWELayout, GLWEPreparedToRef, LWE, LWEInfos, Rank},
+};
+use poulpy_hal::{
+    api::ModuleN,
+    layouts::{AlignedBuf, Backend, DataMut, DataRef, Module, Scratch},
+    source::Source,
+};
+
+use crate::blind_rotation::{
+    BlindRotationAlgo, BlindRotationExecute, BlindRotationKeyInfos, BlindRotationKeyPrepared, LookupTable,
+};
+
+/// Programmable bootstrapping with a sanitized output (circuit privacy).
+///
+/// Blind-rotates `lwe` through `lut`, sample-extracts the constant term and
+/// sanitizes the result with [`LWESanitize`]. The bootstrapping replaces the
+/// input noise by the (input-independent) noise of the blind rotation, and the
+/// sanitization floods the residual dependency on the key and the evaluated
+/// function.
+///
+/// The output has dimension `N` and is encrypted under the LWE secret
+/// [`LWESecret::from_glwe_secret`](poulpy_core::layouts::LWESecret::from_glwe_secret)
+/// of the rank-1 GLWE secret of the blind-rotation key, which must also be the
+/// secret of `pk`.
+pub trait BlindRotationSanitize<BRA: BlindRotationAlgo, BE: Backend> {
+    /// Returns the minimum scratch-space size in bytes required by
+    /// [`blind_rotation_sanitize`][Self::blind_rotation_sanitize].
+    fn blind_rotation_sanitize_tmp_bytes<R, B>(
+        &self,
+        block_size: usize,
+        extension_factor: usize,
+        res_infos: &R,
+        brk_infos: &B,
+    ) -> usize
+    where
+        R: LWEInfos,
+        B: BlindRotationKeyInfos;
+
+    /// Evaluates `lut` at the index encrypted in `lwe` and writes the
+    /// sanitized LWE encryption of the result into `res`.
+    ///
+    /// `flood` is typically a [`NoiseFlooding`](poulpy_core::NoiseFlooding)
+    /// sized from the blind-rotation noise, e.g. with
+    /// [`NoiseFlooding::from_log2_std`](poulpy_core::NoiseFlooding::from_log2_std)
+    /// and [`NoiseModel::blind_rotation`](poulpy_core::NoiseModel::blind_rotation).
+    #[allow(clippy::too_many_arguments)]
+    fn blind_rotation_sanitize<DR, DL, DB, K, E, F>(
+        &self,
+        res: &mut LWE<DR>,
+        lwe: &LWE<DL>,
+        lut: &LookupTable,
+        brk: &BlindRotationKeyPrepared<DB, BRA, BE>,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        DR: DataMut,
+        DL: DataRef,
+        DB: DataRef,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos;
+}
+
+impl<BRA: BlindRotationAlgo, BE: Backend> BlindRotationSanitize<BRA, BE> for Module<BE>
+where
+    Self: ModuleN + BlindRotationExecute<BRA, BE> + LWESampleExtract + LWESanitize<BE>,
+    Scratch<BE>: ScratchTakeCore<BE>,
+{
+    fn blind_rotation_sanitize_tmp_bytes<R, B>(
+        &self,
+        block_size: usize,
+        extension_factor: usize,
+        res_infos: &R,
+        brk_infos: &B,
+    ) -> usize
+    where
+        R: LWEInfos,
+        B: BlindRotationKeyInfos,
+    {
+        let acc_infos: GLWELayout = accumulator_layout(self.n(), res_infos);
+
+        GLWE::<AlignedBuf>::bytes_of_from_infos(&acc_infos)
+            + self
+                .blind_rotation_execute_tmp_bytes(block_size, extension_factor, &acc_infos, brk_infos)
+                .max(self.lwe_sanitize_tmp_bytes(res_infos))
+    }
+
+    fn blind_rotation_sanitize<DR, DL, DB, K, E, F>(
+        &self,
+        res: &mut LWE<DR>,
+        lwe: &LWE<DL>,
+        lut: &LookupTable,
+        brk: &BlindRotationKeyPrepared<DB, BRA, BE>,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        DR: DataMut,
+        DL: DataRef,
+        DB: DataRef,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos,
+    {
+        let acc_infos: GLWELayout = accumulator_layout(self.n(), res);
+        let (mut acc, scratch_1) = scratch.take_glwe(&acc_infos);
+        self.blind_rotation_execute(&mut acc, lwe, lut, brk, scratch_1);
+        self.lwe_sample_extract(res, &acc);
+        self.lwe_sanitize_assign(res, pk, enc_infos, flood, source_xu, source_xe, scratch_1);
+    }
+}
+
+/// Rank-1 accumulator of the blind rotation, at the precision of the output.
+fn accumulator_layout<R>(n: usize, res_infos: &R) -> GLWELayout
+where
+    R: LWEInfos,
+{
+    GLWELayout {
+        n: n.into(),
+        base2k: res_infos.base2k(),
+        k: res_infos.max_k(),
+        rank: Rank(1),
+    }
+}
@@ -4,7 +4,7 @@ use poulpy_hal::{api::ModuleNew, layouts::Module};
 use crate::blind_rotation::{
     CGGI,
     tests::test_suite::{
-        generic_blind_rotation::test_blind_rotation,
+        generic_blind_rotation::{test_blind_rotation, test_blind_rotation_sanitize},
         generic_lut::{test_lut_extended, test_lut_standard},
     },
 };
@@ -38,3 +38,9 @@ fn block_binary_extended() {
     let module: Module<FFT64Avx> = Module::<FFT64Avx>::new(512);
     test_blind_rotation::<CGGI, _, FFT64Avx>(&module, 224, 7, 2);
 }
+
+#[test]
+fn standard_sanitize() {
+    let module: Module<FFT64Avx> = Module::<FFT64Avx>::new(512);
+    test_blind_rotation_sanitize::<CGGI, _, FFT64Avx>(&module, 224);
+}
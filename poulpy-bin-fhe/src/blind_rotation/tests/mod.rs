@@ -18,3 +18,6 @@ mod fft64_avx;
 
 #[cfg(test)]
 mod test_suite;
+
+#[cfg(test)]
+mod mod_switch;
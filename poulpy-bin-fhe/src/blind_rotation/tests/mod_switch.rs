@@ -0,0 +1,34 @@
+use poulpy_core::layouts::{LWE, LWEToRef};
+use poulpy_hal::layouts::{AlignedBuf, ZnxViewMut};
+
+use crate::blind_rotation::{LookUpTableRotationDirection, mod_switch_2n};
+
+/// Switching to `Z_{2N}` must return the top `log2(2N)` bits of the torus
+/// element, whether `base2k` is smaller than, equal to or larger than that.
+#[test]
+fn mod_switch_2n_all_base2k() {
+    let two_n: usize = 1024;
+    let log_two_n: usize = 10;
+    let n_lwe: usize = 15;
+
+    let want: Vec<i64> = (0..=n_lwe).map(|i| ((i * 67 + 13) % two_n) as i64).collect();
+
+    for base2k in [3_usize, 4, 5, 9, 10, 11, 12, 17] {
+        let size: usize = log_two_n.div_ceil(base2k).max(2);
+        let k: usize = size * base2k;
+        let mut lwe: LWE<AlignedBuf> = LWE::alloc(n_lwe.into(), base2k.into(), k.into());
+
+        // Writes want[i] / 2N as unnormalized base-2^base2k digits.
+        for (i, &x) in want.iter().enumerate() {
+            let value: i64 = x << (k - log_two_n);
+            for j in 0..size {
+                lwe.data_mut().at_mut(0, j)[i] = (value >> ((size - 1 - j) * base2k)) & ((1 << base2k) - 1);
+            }
+        }
+
+        let mut have: Vec<i64> = vec![0i64; n_lwe + 1];
+        mod_switch_2n(two_n, &mut have, &lwe.to_ref(), LookUpTableRotationDirection::Right);
+
+        assert_eq!(have, want, "base2k={base2k}");
+    }
+}
@@ -1,19 +1,22 @@
 use poulpy_hal::{
     api::{ModuleN, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{Backend, DeviceBuf, Scratch, ScratchOwned, ZnxView},
+    layouts::{AlignedBuf, Backend, DeviceBuf, Scratch, ScratchOwned, ZnxView},
     source::Source,
 };
 
 use crate::blind_rotation::{
     BlindRotationAlgo, BlindRotationExecute, BlindRotationKey, BlindRotationKeyEncryptSk, BlindRotationKeyLayout,
-    BlindRotationKeyPrepared, BlindRotationKeyPreparedFactory, LookUpTableLayout, LookupTable, LookupTableFactory, mod_switch_2n,
+    BlindRotationKeyPrepared, BlindRotationKeyPreparedFactory, BlindRotationSanitize, LookUpTableLayout, LookupTable,
+    LookupTableFactory, mod_switch_2n,
 };
 
 use poulpy_core::{
-    EncryptionLayout, GLWEDecrypt, LWEEncryptSk, ScratchTakeCore,
+    DEFAULT_SIGMA_XE, EncryptionLayout, GLWEDecrypt, GLWEPublicKeyGenerate, LWEDecrypt, LWEEncryptSk, NoiseFlooding, NoiseModel,
+    ScratchTakeCore,
     layouts::{
-        GLWE, GLWELayout, GLWEPlaintext, GLWESecret, GLWESecretPreparedFactory, LWE, LWEInfos, LWELayout, LWEPlaintext,
-        LWESecret, LWEToRef, prepared::GLWESecretPrepared,
+        GLWE, GLWELayout, GLWEPlaintext, GLWEPublicKey, GLWEPublicKeyPreparedFactory, GLWESecret, GLWESecretPreparedFactory, LWE,
+        LWEInfos, LWELayout, LWEPlaintext, LWESecret, LWEToRef,
+        prepared::{GLWEPublicKeyPrepared, GLWESecretPrepared},
     },
 };
 
@@ -78,12 +81,12 @@ pub fn test_blind_rotation<BRA: BlindRotationAlgo, M, BE: Backend>(
 
     let mut scratch: ScratchOwned<BE> = ScratchOwned::<BE>::alloc(BlindRotationKey::encrypt_sk_tmp_bytes(module, &brk_infos));
 
-    let mut sk_glwe: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&glwe_infos);
+    let mut sk_glwe: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&glwe_infos);
     sk_glwe.fill_ternary_prob(0.5, &mut source_xs);
     let mut sk_glwe_dft: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc_from_infos(&glwe_infos);
     module.glwe_secret_prepare(&mut sk_glwe_dft, &sk_glwe);
 
-    let mut sk_lwe: LWESecret<Vec<u8>> = LWESecret::alloc(n_lwe.into());
+    let mut sk_lwe: LWESecret<AlignedBuf> = LWESecret::alloc(n_lwe.into());
     sk_lwe.fill_binary_block(block_size, &mut source_xs);
 
     let mut scratch_br: ScratchOwned<BE> = ScratchOwned::<BE>::alloc(BlindRotationKeyPrepared::execute_tmp_bytes(
@@ -94,7 +97,7 @@ pub fn test_blind_rotation<BRA: BlindRotationAlgo, M, BE: Backend>(
         &brk_infos,
     ));
 
-    let mut brk: BlindRotationKey<Vec<u8>, BRA> = BlindRotationKey::<Vec<u8>, BRA>::alloc(&brk_infos);
+    let mut brk: BlindRotationKey<AlignedBuf, BRA> = BlindRotationKey::<AlignedBuf, BRA>::alloc(&brk_infos);
 
     module.blind_rotation_key_encrypt_sk(
         &mut brk,
@@ -106,9 +109,9 @@ pub fn test_blind_rotation<BRA: BlindRotationAlgo, M, BE: Backend>(
         scratch.borrow(),
     );
 
-    let mut lwe: LWE<Vec<u8>> = LWE::alloc_from_infos(&lwe_infos);
+    let mut lwe: LWE<AlignedBuf> = LWE::alloc_from_infos(&lwe_infos);
 
-    let mut pt_lwe: LWEPlaintext<Vec<u8>> = LWEPlaintext::alloc_from_infos(&lwe_infos);
+    let mut pt_lwe: LWEPlaintext<AlignedBuf> = LWEPlaintext::alloc_from_infos(&lwe_infos);
 
     let x: i64 = 15 % (message_modulus as i64);
 
@@ -139,14 +142,14 @@ pub fn test_blind_rotation<BRA: BlindRotationAlgo, M, BE: Backend>(
     let mut lut: LookupTable = LookupTable::alloc(&lut_infos);
     lut.set(module, &f_vec, log_message_modulus + 1);
 
-    let mut res: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_infos);
+    let mut res: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
 
     let mut brk_prepared: BlindRotationKeyPrepared<DeviceBuf<BE>, BRA, BE> = BlindRotationKeyPrepared::alloc(module, &brk);
     brk_prepared.prepare(module, &brk, scratch_br.borrow());
 
     brk_prepared.execute(module, &mut res, &lwe, &lut, scratch_br.borrow());
 
-    let mut pt_have: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+    let mut pt_have: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
 
     module.glwe_decrypt(&res, &mut pt_have, &sk_glwe_dft, scratch.borrow());
 
@@ -171,3 +174,159 @@ pub fn test_blind_rotation<BRA: BlindRotationAlgo, M, BE: Backend>(
 
     assert_eq!(have, f(x) % (message_modulus as i64));
 }
+
+pub fn test_blind_rotation_sanitize<BRA: BlindRotationAlgo, M, BE: Backend>(module: &M, n_lwe: usize)
+where
+    M: ModuleN
+        + BlindRotationKeyEncryptSk<BRA, BE>
+        + BlindRotationKeyPreparedFactory<BRA, BE>
+        + BlindRotationSanitize<BRA, BE>
+        + LookupTableFactory
+        + GLWESecretPreparedFactory<BE>
+        + GLWEPublicKeyGenerate<BE>
+        + GLWEPublicKeyPreparedFactory<BE>
+        + LWEEncryptSk<BE>
+        + LWEDecrypt<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchTakeCore<BE>,
+{
+    let n_glwe: usize = module.n();
+    let base2k: usize = 19;
+    let k_lwe: usize = 24;
+    let k_brk: usize = 3 * base2k;
+    let rows_brk: usize = 2;
+    let k_lut: usize = base2k;
+    let k_res: usize = 2 * base2k;
+    let rank: usize = 1;
+    let stat_dist: usize = 8;
+
+    let log_message_modulus: usize = 4;
+    let message_modulus: usize = 1 << log_message_modulus;
+
+    let mut source_xs: Source = Source::new([2u8; 32]);
+    let mut source_xe: Source = Source::new([2u8; 32]);
+    let mut source_xa: Source = Source::new([1u8; 32]);
+    let mut source_xu: Source = Source::new([3u8; 32]);
+
+    let brk_infos = EncryptionLayout::new_from_default_sigma(BlindRotationKeyLayout {
+        n_glwe: n_glwe.into(),
+        n_lwe: n_lwe.into(),
+        base2k: base2k.into(),
+        k: k_brk.into(),
+        dnum: rows_brk.into(),
+        rank: rank.into(),
+    })
+    .unwrap();
+
+    let glwe_infos = EncryptionLayout::new_from_default_sigma(GLWELayout {
+        n: n_glwe.into(),
+        base2k: base2k.into(),
+        k: k_res.into(),
+        rank: rank.into(),
+    })
+    .unwrap();
+
+    let lwe_infos = EncryptionLayout::new_from_default_sigma(LWELayout {
+        n: n_lwe.into(),
+        k: k_lwe.into(),
+        base2k: base2k.into(),
+    })
+    .unwrap();
+
+    let res_infos: LWELayout = LWELayout {
+        n: n_glwe.into(),
+        k: k_res.into(),
+        base2k: base2k.into(),
+    };
+
+    let mut scratch: ScratchOwned<BE> = ScratchOwned::<BE>::alloc(
+        BlindRotationKey::encrypt_sk_tmp_bytes(module, &brk_infos)
+            .max(module.lwe_encrypt_sk_tmp_bytes(&lwe_infos))
+            .max(module.lwe_decrypt_tmp_bytes(&res_infos)),
+    );
+    let mut scratch_br: ScratchOwned<BE> =
+        ScratchOwned::<BE>::alloc(module.blind_rotation_sanitize_tmp_bytes(1, 1, &res_infos, &brk_infos));
+
+    let mut sk_glwe: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&glwe_infos);
+    sk_glwe.fill_ternary_prob(0.5, &mut source_xs);
+    let mut sk_glwe_dft: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc_from_infos(&glwe_infos);
+    module.glwe_secret_prepare(&mut sk_glwe_dft, &sk_glwe);
+
+    let mut sk_lwe: LWESecret<AlignedBuf> = LWESecret::alloc(n_lwe.into());
+    sk_lwe.fill_binary_prob(0.5, &mut source_xs);
+
+    let mut pk: GLWEPublicKey<AlignedBuf> = GLWEPublicKey::alloc_from_infos(&glwe_infos);
+    module.glwe_public_key_generate(&mut pk, &sk_glwe_dft, &glwe_infos, &mut source_xe, &mut source_xa);
+    let mut pk_prepared: GLWEPublicKeyPrepared<DeviceBuf<BE>, BE> = module.glwe_public_key_prepared_alloc_from_infos(&glwe_infos);
+    module.glwe_public_key_prepare(&mut pk_prepared, &pk);
+
+    let mut brk: BlindRotationKey<AlignedBuf, BRA> = BlindRotationKey::<AlignedBuf, BRA>::alloc(&brk_infos);
+    module.blind_rotation_key_encrypt_sk(
+        &mut brk,
+        &sk_glwe_dft,
+        &sk_lwe,
+        &brk_infos,
+        &mut source_xe,
+        &mut source_xa,
+        scratch.borrow(),
+    );
+    let mut brk_prepared: BlindRotationKeyPrepared<DeviceBuf<BE>, BRA, BE> = BlindRotationKeyPrepared::alloc(module, &brk);
+    brk_prepared.prepare(module, &brk, scratch_br.borrow());
+
+    let f = |x: i64| -> i64 { 2 * x + 1 };
+    let mut f_vec: Vec<i64> = vec![0i64; message_modulus];
+    f_vec.iter_mut().enumerate().for_each(|(i, x)| *x = f(i as i64));
+
+    let lut_infos = LookUpTableLayout {
+        n: module.n().into(),
+        extension_factor: 1,
+        k: k_lut.into(),
+        base2k: base2k.into(),
+    };
+    let mut lut: LookupTable = LookupTable::alloc(&lut_infos);
+    lut.set(module, &f_vec, log_message_modulus + 1);
+
+    // Floods the predicted blind-rotation noise.
+    let model: NoiseModel = NoiseModel::from_secret(&sk_glwe, DEFAULT_SIGMA_XE);
+    let log2_std_br: f64 = 0.5 * model.blind_rotation(n_lwe, &glwe_infos, &brk_infos).log2();
+    let flood: NoiseFlooding = NoiseFlooding::from_log2_std(&res_infos, log2_std_br, stat_dist).unwrap();
+
+    let sk_res: LWESecret<AlignedBuf> = LWESecret::from_glwe_secret(&sk_glwe);
+
+    let mut lwe: LWE<AlignedBuf> = LWE::alloc_from_infos(&lwe_infos);
+    let mut pt_lwe: LWEPlaintext<AlignedBuf> = LWEPlaintext::alloc_from_infos(&lwe_infos);
+    let mut res: LWE<AlignedBuf> = LWE::alloc_from_infos(&res_infos);
+    let mut pt_have: LWEPlaintext<AlignedBuf> = LWEPlaintext::alloc_from_infos(&res_infos);
+
+    for x in 0..message_modulus as i64 {
+        pt_lwe.encode_i64(x, (log_message_modulus + 1).into());
+        module.lwe_encrypt_sk(
+            &mut lwe,
+            &pt_lwe,
+            &sk_lwe,
+            &lwe_infos,
+            &mut source_xe,
+            &mut source_xa,
+            scratch.borrow(),
+        );
+
+        module.blind_rotation_sanitize(
+            &mut res,
+            &lwe,
+            &lut,
+            &brk_prepared,
+            &pk_prepared,
+            &glwe_infos,
+            &flood,
+            &mut source_xu,
+            &mut source_xe,
+            scratch_br.borrow(),
+        );
+
+        module.lwe_decrypt(&res, &mut pt_have, &sk_res, scratch.borrow());
+        let have: i64 = pt_have
+            .decode_i64((log_message_modulus + 1).into())
+            .rem_euclid(message_modulus as i64);
+        assert_eq!(have, f(x) % (message_modulus as i64), "x={x}");
+    }
+}
@@ -1,6 +1,7 @@
 use poulpy_hal::test_suite::serialization::test_reader_writer_interface;
 
 use crate::blind_rotation::{BlindRotationKey, BlindRotationKeyCompressed, BlindRotationKeyLayout, CGGI};
+use poulpy_hal::layouts::AlignedBuf;
 
 #[test]
 fn test_cggi_blind_rotation_key_serialization() {
@@ -12,7 +13,7 @@ fn test_cggi_blind_rotation_key_serialization() {
         dnum: 2_usize.into(),
         rank: 2_usize.into(),
     };
-    let original: BlindRotationKey<Vec<u8>, CGGI> = BlindRotationKey::alloc(&layout);
+    let original: BlindRotationKey<AlignedBuf, CGGI> = BlindRotationKey::alloc(&layout);
     test_reader_writer_interface(original);
 }
 
@@ -26,6 +27,6 @@ fn test_cggi_blind_rotation_key_compressed_serialization() {
         dnum: 2_usize.into(),
         rank: 2_usize.into(),
     };
-    let original: BlindRotationKeyCompressed<Vec<u8>, CGGI> = BlindRotationKeyCompressed::alloc(&layout);
+    let original: BlindRotationKeyCompressed<AlignedBuf, CGGI> = BlindRotationKeyCompressed::alloc(&layout);
     test_reader_writer_interface(original);
 }
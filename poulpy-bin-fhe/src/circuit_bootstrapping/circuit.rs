@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use poulpy_hal::{
     api::{ModuleLogN, ModuleN, ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{Backend, DataRef, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, Backend, DataRef, Module, Scratch, ScratchOwned},
 };
 
 use poulpy_core::{
@@ -167,11 +167,37 @@ where
             rank_out: res_infos.rank(),
         };
 
-        self.blind_rotation_execute_tmp_bytes(block_size, extension_factor, res_infos, &cbt_infos.brk_infos())
-            .max(self.glwe_trace_tmp_bytes(res_infos, res_infos, &cbt_infos.atk_infos()))
+        // The blind rotation and the row extraction run at the precision of the
+        // blind-rotation key, which is usually larger than that of the output.
+        let brk_infos = cbt_infos.brk_infos();
+        let atk_infos = cbt_infos.atk_infos();
+        let glwe_brk_infos: GLWELayout = GLWELayout {
+            n: brk_infos.n(),
+            base2k: brk_infos.base2k(),
+            k: brk_infos.max_k(),
+            rank: brk_infos.rank(),
+        };
+        let glwe_atk_infos: GLWELayout = GLWELayout {
+            base2k: atk_infos.base2k(),
+            ..glwe_brk_infos
+        };
+
+        let blind_rotation: usize = GLWE::<AlignedBuf>::bytes_of_from_infos(&glwe_brk_infos)
+            + self
+                .blind_rotation_execute_tmp_bytes(block_size, extension_factor, &glwe_brk_infos, &brk_infos)
+                .max(self.glwe_normalize_tmp_bytes());
+        let rows: usize = self
+            .glwe_trace_tmp_bytes(res_infos, &glwe_atk_infos, &atk_infos)
+            .max(self.glwe_rotate_tmp_bytes());
+        let core: usize = GLWE::<AlignedBuf>::bytes_of_from_infos(&glwe_atk_infos) + blind_rotation.max(rows);
+
+        (self
+            .blind_rotation_execute_tmp_bytes(block_size, extension_factor, res_infos, &brk_infos)
+            .max(self.glwe_trace_tmp_bytes(res_infos, res_infos, &atk_infos))
             .max(self.ggsw_from_gglwe_tmp_bytes(res_infos, &cbt_infos.tsk_infos()))
-            + GLWE::<Vec<u8>>::bytes_of_from_infos(res_infos)
-            + GGLWE::bytes_of_from_infos(&gglwe_infos)
+            + GLWE::<AlignedBuf>::bytes_of_from_infos(res_infos)
+            + GGLWE::bytes_of_from_infos(&gglwe_infos))
+        .max(core)
     }
 
     fn circuit_bootstrapping_execute_to_constant<R, L, D>(
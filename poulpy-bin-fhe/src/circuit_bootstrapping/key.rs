@@ -14,7 +14,7 @@ use std::collections::HashMap;
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
 use poulpy_hal::{
-    layouts::{Backend, Data, DataMut, DataRef, DeviceBuf, Module, NoiseInfos, ReaderFrom, Scratch, WriterTo},
+    layouts::{AlignedBuf, Backend, Data, DataMut, DataRef, DeviceBuf, Module, NoiseInfos, ReaderFrom, Scratch, WriterTo},
     source::Source,
 };
 
@@ -133,7 +133,7 @@ pub trait CircuitBootstrappingKeyEncryptSk<BRA: BlindRotationAlgo, BE: Backend>
         S1: GLWESecretToRef + GLWEInfos + GetDistribution;
 }
 
-impl<BRA: BlindRotationAlgo> CircuitBootstrappingKey<Vec<u8>, BRA> {
+impl<BRA: BlindRotationAlgo> CircuitBootstrappingKey<AlignedBuf, BRA> {
     pub fn alloc_from_infos<A: CircuitBootstrappingKeyInfos>(infos: &A) -> Self {
         let atk_infos: &GLWEAutomorphismKeyLayout = &infos.atk_infos();
         let brk_infos: &BlindRotationKeyLayout = &infos.brk_infos();
@@ -180,8 +180,8 @@ impl<BRA: BlindRotationAlgo> CircuitBootstrappingKey<Vec<u8>, BRA> {
 /// 3. Prepare with `CircuitBootstrappingKeyPrepared::prepare`.
 pub struct CircuitBootstrappingKey<D: Data, BRA: BlindRotationAlgo> {
     pub(crate) brk: BlindRotationKey<D, BRA>,
-    pub(crate) tsk: GGLWEToGGSWKey<Vec<u8>>,
-    pub(crate) atk: HashMap<i64, GLWEAutomorphismKey<Vec<u8>>>,
+    pub(crate) tsk: GGLWEToGGSWKey<AlignedBuf>,
+    pub(crate) atk: HashMap<i64, GLWEAutomorphismKey<AlignedBuf>>,
 }
 
 impl<D: DataMut, BRA: BlindRotationAlgo> CircuitBootstrappingKey<D, BRA> {
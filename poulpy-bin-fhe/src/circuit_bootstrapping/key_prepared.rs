@@ -9,9 +9,13 @@ use poulpy_core::{
 };
 use std::collections::HashMap;
 
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use poulpy_hal::{
     api::ScratchAvailable,
-    layouts::{Backend, Data, DataMut, DataRef, DeviceBuf, Module, Scratch},
+    layouts::{
+        Backend, Data, DataMut, DataRef, DeviceBuf, Module, PREPARED_BLOCK_BYTES, ReaderFrom, Scratch, WriterTo,
+        read_prepared_meta, write_prepared_meta,
+    },
 };
 
 use crate::{
@@ -201,3 +205,52 @@ impl<D: DataRef, BRA: BlindRotationAlgo, B: Backend> CircuitBootstrappingKeyInfo
         }
     }
 }
+
+/// Persists the prepared key in the backend-tagged format of prepared
+/// layouts, in the order of [`CircuitBootstrappingKey`]: the blind-rotation
+/// key, the automorphism keys sorted by Galois element, each preceded by a
+/// metadata block holding it, and the tensor-switching key.
+impl<D: DataRef, BRA: BlindRotationAlgo, B: Backend> WriterTo for CircuitBootstrappingKeyPrepared<D, BRA, B> {
+    fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.brk.write_to(writer)?;
+        let mut meta: Vec<u8> = Vec::new();
+        meta.write_u64::<LittleEndian>(self.atk.len() as u64)?;
+        write_prepared_meta(writer, &meta)?;
+        // HashMap iteration order is undefined; sort for stable, canonical blobs.
+        let mut keys: Vec<i64> = self.atk.keys().copied().collect();
+        keys.sort_unstable();
+        for k in keys {
+            meta.clear();
+            meta.write_i64::<LittleEndian>(k)?;
+            write_prepared_meta(writer, &meta)?;
+            self.atk[&k].write_to(writer)?;
+        }
+        self.tsk.write_to(writer)
+    }
+}
+
+/// Loads a prepared key persisted by [`WriterTo`] into a key allocated with
+/// the same dimensions, failing if it was written by an incompatible backend
+/// or for another ring degree.
+impl<D: DataMut, BRA: BlindRotationAlgo, B: Backend> ReaderFrom for CircuitBootstrappingKeyPrepared<D, BRA, B> {
+    fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        self.brk.read_from(reader)?;
+        let meta: [u8; PREPARED_BLOCK_BYTES] = read_prepared_meta(reader)?;
+        let n: usize = (&meta[..]).read_u64::<LittleEndian>()? as usize;
+        if n != self.atk.len() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("self.atk.len()={} != read len={}", self.atk.len(), n),
+            ));
+        }
+        for _ in 0..n {
+            let meta: [u8; PREPARED_BLOCK_BYTES] = read_prepared_meta(reader)?;
+            let gal_el: i64 = (&meta[..]).read_i64::<LittleEndian>()?;
+            let atk = self.atk.get_mut(&gal_el).ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::InvalidData, format!("self.atk.get(gal_el={gal_el})=None"))
+            })?;
+            atk.read_from(reader)?;
+        }
+        self.tsk.read_from(reader)
+    }
+}
@@ -2,7 +2,7 @@ use std::time::Instant;
 
 use poulpy_hal::{
     api::{ModuleN, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxRotateAssign},
-    layouts::{Backend, DeviceBuf, ScalarZnx, Scratch, ScratchOwned, ZnxView, ZnxViewMut},
+    layouts::{AlignedBuf, Backend, DeviceBuf, ScalarZnx, Scratch, ScratchOwned, ZnxView, ZnxViewMut},
     source::Source,
 };
 
@@ -119,10 +119,10 @@ where
     let mut source_xa: Source = Source::new([1u8; 32]);
     let mut source_xe: Source = Source::new([1u8; 32]);
 
-    let mut sk_lwe: LWESecret<Vec<u8>> = LWESecret::alloc(n_lwe.into());
+    let mut sk_lwe: LWESecret<AlignedBuf> = LWESecret::alloc(n_lwe.into());
     sk_lwe.fill_binary_block(block_size, &mut source_xs);
 
-    let mut sk_glwe: GLWESecret<Vec<u8>> = GLWESecret::alloc(n_glwe.into(), rank.into());
+    let mut sk_glwe: GLWESecret<AlignedBuf> = GLWESecret::alloc(n_glwe.into(), rank.into());
     sk_glwe.fill_ternary_prob(0.5, &mut source_xs);
 
     let mut sk_glwe_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
@@ -130,13 +130,13 @@ where
 
     let data: i64 = 1;
 
-    let mut pt_lwe: LWEPlaintext<Vec<u8>> = LWEPlaintext::alloc(base2k_lwe.into(), k_lwe_pt.into());
+    let mut pt_lwe: LWEPlaintext<AlignedBuf> = LWEPlaintext::alloc(base2k_lwe.into(), k_lwe_pt.into());
     pt_lwe.encode_i64(data, (k_lwe_pt + 1).into());
 
     println!("pt_lwe: {pt_lwe}");
 
     let lwe_enc_infos = EncryptionLayout::new_from_default_sigma(lwe_infos).unwrap();
-    let mut ct_lwe: LWE<Vec<u8>> = LWE::alloc_from_infos(&lwe_infos);
+    let mut ct_lwe: LWE<AlignedBuf> = LWE::alloc_from_infos(&lwe_infos);
     module.lwe_encrypt_sk(
         &mut ct_lwe,
         &pt_lwe,
@@ -148,7 +148,7 @@ where
     );
 
     let now: Instant = Instant::now();
-    let mut cbt_key: CircuitBootstrappingKey<Vec<u8>, BRA> = CircuitBootstrappingKey::alloc_from_infos(&cbt_infos);
+    let mut cbt_key: CircuitBootstrappingKey<AlignedBuf, BRA> = CircuitBootstrappingKey::alloc_from_infos(&cbt_infos);
     println!("CBT-ALLOC: {} ms", now.elapsed().as_millis());
 
     let cbt_enc_infos = CircuitBootstrappingEncryptionInfos::from_default_sigma(&cbt_infos).unwrap();
@@ -164,7 +164,7 @@ where
     );
     println!("CBT-ENCRYPT: {} ms", now.elapsed().as_millis());
 
-    let mut res: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_infos);
+    let mut res: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_infos);
 
     let log_gap_out = 1;
 
@@ -185,7 +185,7 @@ where
     println!("CBT: {} ms", now.elapsed().as_millis());
 
     // X^{data * 2^log_gap_out}
-    let mut pt_ggsw: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n_glwe, 1);
+    let mut pt_ggsw: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n_glwe, 1);
     pt_ggsw.at_mut(0, 0)[0] = 1;
     module.vec_znx_rotate_assign(data * (1 << log_gap_out), &mut pt_ggsw.as_vec_znx_mut(), 0, scratch.borrow());
 
@@ -200,8 +200,8 @@ where
         }
     }
     let glwe_enc_infos = EncryptionLayout::new_from_default_sigma(ggsw_infos).unwrap();
-    let mut ct_glwe: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&ggsw_infos);
-    let mut pt_glwe: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&ggsw_infos);
+    let mut ct_glwe: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&ggsw_infos);
+    let mut pt_glwe: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&ggsw_infos);
     pt_glwe.data.at_mut(0, 0)[0] = 1 << (res_base2k - 2);
 
     module.glwe_encrypt_sk(
@@ -219,7 +219,7 @@ where
 
     module.glwe_external_product_assign(&mut ct_glwe, &res_prepared, scratch.borrow());
 
-    let mut pt_res: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&ggsw_infos);
+    let mut pt_res: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&ggsw_infos);
     module.glwe_decrypt(&ct_glwe, &mut pt_res, &sk_glwe_prepared, scratch.borrow());
 
     // Parameters are set such that the first limb should be noiseless.
@@ -319,10 +319,10 @@ where
     let mut source_xa: Source = Source::new([1u8; 32]);
     let mut source_xe: Source = Source::new([1u8; 32]);
 
-    let mut sk_lwe: LWESecret<Vec<u8>> = LWESecret::alloc(n_lwe.into());
+    let mut sk_lwe: LWESecret<AlignedBuf> = LWESecret::alloc(n_lwe.into());
     sk_lwe.fill_binary_block(block_size, &mut source_xs);
 
-    let mut sk_glwe: GLWESecret<Vec<u8>> = GLWESecret::alloc(n_glwe.into(), rank.into());
+    let mut sk_glwe: GLWESecret<AlignedBuf> = GLWESecret::alloc(n_glwe.into(), rank.into());
     sk_glwe.fill_ternary_prob(0.5, &mut source_xs);
 
     let mut sk_glwe_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
@@ -330,13 +330,13 @@ where
 
     let data: i64 = 1;
 
-    let mut pt_lwe: LWEPlaintext<Vec<u8>> = LWEPlaintext::alloc(base2k_lwe.into(), k_lwe_pt.into());
+    let mut pt_lwe: LWEPlaintext<AlignedBuf> = LWEPlaintext::alloc(base2k_lwe.into(), k_lwe_pt.into());
     pt_lwe.encode_i64(data, (k_lwe_pt + 1).into());
 
     println!("pt_lwe: {pt_lwe}");
 
     let lwe_enc_infos = EncryptionLayout::new_from_default_sigma(lwe_infos).unwrap();
-    let mut ct_lwe: LWE<Vec<u8>> = LWE::alloc_from_infos(&lwe_infos);
+    let mut ct_lwe: LWE<AlignedBuf> = LWE::alloc_from_infos(&lwe_infos);
     module.lwe_encrypt_sk(
         &mut ct_lwe,
         &pt_lwe,
@@ -348,7 +348,7 @@ where
     );
 
     let now: Instant = Instant::now();
-    let mut cbt_key: CircuitBootstrappingKey<Vec<u8>, BRA> = CircuitBootstrappingKey::alloc_from_infos(&cbt_infos);
+    let mut cbt_key: CircuitBootstrappingKey<AlignedBuf, BRA> = CircuitBootstrappingKey::alloc_from_infos(&cbt_infos);
     println!("CBT-ALLOC: {} ms", now.elapsed().as_millis());
 
     let cbt_enc_infos = CircuitBootstrappingEncryptionInfos::from_default_sigma(&cbt_infos).unwrap();
@@ -364,7 +364,7 @@ where
     );
     println!("CBT-ENCRYPT: {} ms", now.elapsed().as_millis());
 
-    let mut res: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_infos);
+    let mut res: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_infos);
 
     let mut cbt_prepared: CircuitBootstrappingKeyPrepared<DeviceBuf<BE>, BRA, BE> =
         CircuitBootstrappingKeyPrepared::alloc_from_infos(module, &cbt_infos);
@@ -375,7 +375,7 @@ where
     println!("CBT: {} ms", now.elapsed().as_millis());
 
     // X^{data * 2^log_gap_out}
-    let mut pt_ggsw: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n_glwe, 1);
+    let mut pt_ggsw: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n_glwe, 1);
     pt_ggsw.at_mut(0, 0)[0] = data;
 
     for row in 0..res.dnum().as_usize() {
@@ -390,8 +390,8 @@ where
     }
 
     let glwe_enc_infos = EncryptionLayout::new_from_default_sigma(ggsw_infos).unwrap();
-    let mut ct_glwe: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&ggsw_infos);
-    let mut pt_glwe: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&ggsw_infos);
+    let mut ct_glwe: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&ggsw_infos);
+    let mut pt_glwe: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&ggsw_infos);
     pt_glwe.data.at_mut(0, 0)[0] = 1 << (res_base2k - k_lwe_pt - 1);
 
     module.glwe_encrypt_sk(
@@ -409,7 +409,7 @@ where
 
     module.glwe_external_product_assign(&mut ct_glwe, &res_prepared, scratch.borrow());
 
-    let mut pt_res: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&ggsw_infos);
+    let mut pt_res: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&ggsw_infos);
     module.glwe_decrypt(&ct_glwe, &mut pt_res, &sk_glwe_prepared, scratch.borrow());
 
     // Parameters are set such that the first limb should be noiseless.
@@ -418,3 +418,127 @@ where
     println!("pt_res: {pt_res}");
     assert_eq!(pt_res.data.at(0, 0), pt_want);
 }
+
+/// Runs [`CircuitBootstrappingExecute::circuit_bootstrapping_execute_to_constant`]
+/// with exactly the scratch returned by `circuit_bootstrapping_execute_tmp_bytes`,
+/// for an output GGSW much smaller than the blind-rotation key. The blind
+/// rotation and the row extraction run at the precision of the key, which the
+/// estimate must cover.
+pub fn test_circuit_bootstrapping_execute_tmp_bytes<BE: Backend, M, BRA: BlindRotationAlgo>(module: &M)
+where
+    M: ModuleN
+        + LWEEncryptSk<BE>
+        + CircuitBootstrappingKeyEncryptSk<BRA, BE>
+        + CircuitBootstrappingKeyPreparedFactory<BRA, BE>
+        + CircuitBootstrappingExecute<BRA, BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchTakeCore<BE>,
+{
+    let n_glwe: usize = module.n();
+    let base2k: usize = 13;
+    let rank: usize = 1;
+    let n_lwe: usize = 77;
+    let k_lwe_pt: usize = 1;
+    let block_size: usize = 7;
+    let extension_factor: usize = 1;
+
+    let lwe_infos: LWELayout = LWELayout {
+        n: n_lwe.into(),
+        k: base2k.into(),
+        base2k: base2k.into(),
+    };
+
+    let cbt_infos: CircuitBootstrappingKeyLayout = CircuitBootstrappingKeyLayout {
+        brk_layout: BlindRotationKeyLayout {
+            n_glwe: n_glwe.into(),
+            n_lwe: n_lwe.into(),
+            base2k: base2k.into(),
+            k: (6 * base2k).into(),
+            dnum: 5_u32.into(),
+            rank: rank.into(),
+        },
+        atk_layout: GLWEAutomorphismKeyLayout {
+            n: n_glwe.into(),
+            base2k: base2k.into(),
+            k: (5 * base2k).into(),
+            dnum: 4_u32.into(),
+            rank: rank.into(),
+            dsize: Dsize(1),
+        },
+        tsk_layout: GGLWEToGGSWKeyLayout {
+            n: n_glwe.into(),
+            base2k: base2k.into(),
+            k: (5 * base2k).into(),
+            dnum: 4_u32.into(),
+            dsize: Dsize(1),
+            rank: rank.into(),
+        },
+    };
+
+    let ggsw_infos: GGSWLayout = GGSWLayout {
+        n: n_glwe.into(),
+        base2k: base2k.into(),
+        k: (3 * base2k).into(),
+        dnum: 2_u32.into(),
+        dsize: Dsize(1),
+        rank: rank.into(),
+    };
+
+    let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(1 << 23);
+
+    let mut source_xs: Source = Source::new([1u8; 32]);
+    let mut source_xa: Source = Source::new([1u8; 32]);
+    let mut source_xe: Source = Source::new([1u8; 32]);
+
+    let mut sk_lwe = LWESecret::alloc(n_lwe.into());
+    sk_lwe.fill_binary_block(block_size, &mut source_xs);
+
+    let mut sk_glwe = GLWESecret::alloc(n_glwe.into(), rank.into());
+    sk_glwe.fill_ternary_prob(0.5, &mut source_xs);
+
+    let mut pt_lwe = LWEPlaintext::alloc(base2k.into(), k_lwe_pt.into());
+    pt_lwe.encode_i64(1, (k_lwe_pt + 1).into());
+
+    let lwe_enc_infos = EncryptionLayout::new_from_default_sigma(lwe_infos).unwrap();
+    let mut ct_lwe = LWE::alloc_from_infos(&lwe_infos);
+    module.lwe_encrypt_sk(
+        &mut ct_lwe,
+        &pt_lwe,
+        &sk_lwe,
+        &lwe_enc_infos,
+        &mut source_xe,
+        &mut source_xa,
+        scratch.borrow(),
+    );
+
+    let mut cbt_key = CircuitBootstrappingKey::<_, BRA>::alloc_from_infos(&cbt_infos);
+    let cbt_enc_infos = CircuitBootstrappingEncryptionInfos::from_default_sigma(&cbt_infos).unwrap();
+    module.circuit_bootstrapping_key_encrypt_sk(
+        &mut cbt_key,
+        &sk_lwe,
+        &sk_glwe,
+        &cbt_enc_infos,
+        &mut source_xe,
+        &mut source_xa,
+        scratch.borrow(),
+    );
+
+    let mut cbt_prepared = CircuitBootstrappingKeyPrepared::<_, BRA, BE>::alloc_from_infos(module, &cbt_infos);
+    cbt_prepared.prepare(module, &cbt_key, scratch.borrow());
+
+    let mut res = GGSW::alloc_from_infos(&ggsw_infos);
+    let mut scratch_execute: ScratchOwned<BE> = ScratchOwned::alloc(module.circuit_bootstrapping_execute_tmp_bytes(
+        block_size,
+        extension_factor,
+        &ggsw_infos,
+        &cbt_infos,
+    ));
+    cbt_prepared.execute_to_constant(
+        module,
+        &mut res,
+        &ct_lwe,
+        k_lwe_pt,
+        extension_factor,
+        scratch_execute.borrow(),
+    );
+}
@@ -4,7 +4,8 @@ use poulpy_hal::{api::ModuleNew, layouts::Module};
 use crate::{
     blind_rotation::CGGI,
     circuit_bootstrapping::tests::circuit_bootstrapping::{
-        test_circuit_bootstrapping_to_constant, test_circuit_bootstrapping_to_exponent,
+        test_circuit_bootstrapping_execute_tmp_bytes, test_circuit_bootstrapping_to_constant,
+        test_circuit_bootstrapping_to_exponent,
     },
 };
 
@@ -19,3 +20,9 @@ fn to_exponent_cggi() {
     let module: Module<FFT64Ref> = Module::<FFT64Ref>::new(256);
     test_circuit_bootstrapping_to_exponent::<FFT64Ref, _, CGGI>(&module);
 }
+
+#[test]
+fn execute_tmp_bytes_cggi() {
+    let module: Module<FFT64Ref> = Module::<FFT64Ref>::new(256);
+    test_circuit_bootstrapping_execute_tmp_bytes::<FFT64Ref, _, CGGI>(&module);
+}
@@ -26,6 +26,10 @@
 //!   such as addition, subtraction, bitwise logic, and shifts over `u32` are
 //!   provided out of the box, evaluated bit-by-bit through GGSW-based CMux
 //!   gates.
+//!
+//! - **Parameter sets** ([`bin_fhe::params`]): Curated, security-annotated
+//!   presets for BDD arithmetic and circuit bootstrapping.
 pub mod bdd_arithmetic;
 pub mod blind_rotation;
 pub mod circuit_bootstrapping;
+pub mod params;
@@ -0,0 +1,381 @@
+use anyhow::{Result, ensure};
+use poulpy_core::{
+    DEFAULT_BOUND_XE, DEFAULT_SIGMA_XE, Distribution, NoiseModel, NoiseTracker, SecurityParameters,
+    layouts::{
+        Base2K, Degree, Dnum, Dsize, GGLWEInfos, GGLWEToGGSWKeyLayout, GGSWLayout, GLWEAutomorphismKeyLayout, GLWEInfos,
+        GLWELayout, GLWESwitchingKeyLayout, GLWEToLWEKeyLayout, LWEInfos, Rank, TorusPrecision,
+    },
+};
+use poulpy_hal::layouts::NoiseInfos;
+
+use crate::{
+    bdd_arithmetic::{BDDEncryptionInfos, BDDKeyLayout},
+    blind_rotation::BlindRotationKeyLayout,
+    circuit_bootstrapping::CircuitBootstrappingKeyLayout,
+    params::{
+        ensure_gglwe_fits, ensure_ggsw_fits, ensure_secret_dist, log2_failure_circuit_bootstrapping, log2_union,
+        var_lwe_rounding, var_modulus_switch,
+    },
+};
+
+/// A named parameter set for BDD arithmetic on [`FheUint`](crate::bdd_arithmetic::FheUint).
+///
+/// See the [module documentation](crate::params) for the meaning of the
+/// annotations and the list of presets.
+#[derive(Debug, Clone, Copy)]
+pub struct BDDParameters {
+    /// Human-readable identifier of the preset.
+    pub name: &'static str,
+    /// Layout of the encrypted integers ([`FheUint`](crate::bdd_arithmetic::FheUint)).
+    pub glwe_layout: GLWELayout,
+    /// Layout of the prepared integers ([`FheUintPrepared`](crate::bdd_arithmetic::FheUintPrepared)).
+    pub ggsw_layout: GGSWLayout,
+    /// Layout of the evaluation key bundle.
+    pub bdd_layout: BDDKeyLayout,
+    /// Distribution of the GLWE secret.
+    pub glwe_dist: Distribution,
+    /// Distribution of the LWE secret used by the blind rotation.
+    pub lwe_dist: Distribution,
+    /// Annotated security level, in bits.
+    pub security: f64,
+    /// Annotated `log2` failure probability of a single bit bootstrapping.
+    pub log2_failure: f64,
+}
+
+impl BDDParameters {
+    /// Plaintext precision of the bits of an [`FheUint`](crate::bdd_arithmetic::FheUint).
+    const K_PT: usize = 2;
+
+    /// Returns the encryption noise parameters of the key bundle.
+    pub fn encryption_infos(&self) -> Result<BDDEncryptionInfos> {
+        BDDEncryptionInfos::from_default_sigma(&self.bdd_layout)
+    }
+
+    /// Estimates the security, in bits, of the weakest instance exposed by
+    /// the ciphertexts and keys of this parameter set.
+    pub fn estimate_security(&self) -> f64 {
+        let cbt: &CircuitBootstrappingKeyLayout = &self.bdd_layout.cbt_layout;
+        let glwe = |n: usize, k: usize| SecurityParameters::new(n, k, self.glwe_dist, DEFAULT_SIGMA_XE).security_level();
+
+        let n_glwe: usize = self.glwe_layout.n().as_usize() * self.glwe_layout.rank().as_usize();
+        let mut security: f64 = [
+            self.glwe_layout.max_k(),
+            self.ggsw_layout.max_k(),
+            cbt.brk_layout.max_k(),
+            cbt.atk_layout.max_k(),
+            cbt.tsk_layout.max_k(),
+        ]
+        .iter()
+        .map(|k| glwe(n_glwe, k.as_usize()))
+        .fold(f64::INFINITY, f64::min);
+
+        // The bridging key is encrypted under a fresh ternary secret of rank `rank_out`.
+        if let Some(ks_glwe) = &self.bdd_layout.ks_glwe_layout {
+            let n: usize = ks_glwe.n().as_usize() * ks_glwe.rank_out().as_usize();
+            let bridge: f64 = SecurityParameters::new(
+                n,
+                ks_glwe.max_k().as_usize(),
+                Distribution::TernaryProb(0.5),
+                DEFAULT_SIGMA_XE,
+            )
+            .security_level();
+            security = security.min(bridge);
+        }
+
+        // The GLWE-to-LWE key is encrypted under the LWE secret.
+        let lwe: f64 = SecurityParameters::new(
+            cbt.brk_layout.n_lwe.as_usize(),
+            self.bdd_layout.ks_lwe_layout.max_k().as_usize(),
+            self.lwe_dist,
+            DEFAULT_SIGMA_XE,
+        )
+        .security_level();
+
+        security.min(lwe)
+    }
+
+    /// Predicts `log2` of the probability that the LWE extracted from a fresh
+    /// [`FheUint`](crate::bdd_arithmetic::FheUint) bit is bootstrapped to the
+    /// wrong value.
+    ///
+    /// This is the union bound of the extracted LWE selecting the wrong
+    /// lookup-table entry and of the prepared GGSW, whose error accumulates the
+    /// blind rotation, the trace and the row expansion, selecting the wrong
+    /// value in an external product with a fresh [`FheUint`](crate::bdd_arithmetic::FheUint).
+    pub fn estimate_log2_failure(&self) -> f64 {
+        let n_glwe: usize = self.glwe_layout.n().as_usize();
+        let n_lwe: usize = self.bdd_layout.cbt_layout.brk_layout.n_lwe.as_usize();
+        let ks_lwe: &GLWEToLWEKeyLayout = &self.bdd_layout.ks_lwe_layout;
+
+        let model: NoiseModel = NoiseModel::new(self.glwe_dist.density(n_glwe), DEFAULT_SIGMA_XE * DEFAULT_SIGMA_XE);
+        let noise: NoiseInfos = NoiseInfos::new(self.glwe_layout.max_k().as_usize(), DEFAULT_SIGMA_XE, DEFAULT_BOUND_XE)
+            .expect("invalid GLWE precision");
+        let mut tracker: NoiseTracker = NoiseTracker::fresh(model, &noise);
+
+        let glwe_in: GLWELayout = match &self.bdd_layout.ks_glwe_layout {
+            Some(ks_glwe) => {
+                tracker.keyswitch(&self.glwe_layout, ks_glwe);
+                GLWELayout {
+                    n: self.glwe_layout.n,
+                    base2k: ks_lwe.base2k,
+                    k: ks_lwe.k.min(self.glwe_layout.k),
+                    rank: ks_glwe.rank_out,
+                }
+            }
+            None => self.glwe_layout,
+        };
+        tracker.keyswitch(&glwe_in, ks_lwe);
+
+        let var: f64 = tracker.variance()
+            + var_lwe_rounding(n_lwe, self.lwe_dist, ks_lwe.max_k().as_usize())
+            + var_modulus_switch(n_lwe, self.lwe_dist, n_glwe);
+
+        let input: f64 = NoiseTracker::new(model, var).log2_failure_probability(Self::K_PT + 1);
+        let output: f64 = log2_failure_circuit_bootstrapping(
+            self.glwe_dist.density(n_glwe),
+            &self.bdd_layout.cbt_layout,
+            &self.ggsw_layout,
+            &self.glwe_layout,
+            Self::K_PT,
+        );
+
+        log2_union(input, output)
+    }
+
+    /// Checks the structural consistency of the layouts and that the
+    /// annotated security and failure probability are met.
+    pub fn check(&self) -> Result<()> {
+        let name: &str = self.name;
+        let cbt: &CircuitBootstrappingKeyLayout = &self.bdd_layout.cbt_layout;
+        let ks_lwe: &GLWEToLWEKeyLayout = &self.bdd_layout.ks_lwe_layout;
+        let n: Degree = self.glwe_layout.n;
+        let rank: Rank = self.glwe_layout.rank;
+
+        ensure!(
+            n.as_usize().is_power_of_two(),
+            "{name}: GLWE degree {n} is not a power of two"
+        );
+        for (what, other) in [
+            ("ggsw_layout", self.ggsw_layout.n),
+            ("brk_layout", cbt.brk_layout.n_glwe),
+            ("atk_layout", cbt.atk_layout.n),
+            ("tsk_layout", cbt.tsk_layout.n),
+            ("ks_lwe_layout", ks_lwe.n),
+        ] {
+            ensure!(other == n, "{name}: {what} has degree {other}, expected {n}");
+        }
+        for (what, other) in [
+            ("ggsw_layout", self.ggsw_layout.rank),
+            ("brk_layout", cbt.brk_layout.rank),
+            ("atk_layout", cbt.atk_layout.rank),
+            ("tsk_layout", cbt.tsk_layout.rank),
+        ] {
+            ensure!(other == rank, "{name}: {what} has rank {other}, expected {rank}");
+        }
+
+        match &self.bdd_layout.ks_glwe_layout {
+            Some(ks_glwe) => {
+                ensure!(
+                    ks_glwe.n == n,
+                    "{name}: ks_glwe_layout has degree {}, expected {n}",
+                    ks_glwe.n
+                );
+                ensure!(
+                    ks_glwe.rank_in == rank,
+                    "{name}: ks_glwe_layout.rank_in is {}, expected {rank}",
+                    ks_glwe.rank_in
+                );
+                ensure!(
+                    ks_lwe.rank_in == ks_glwe.rank_out,
+                    "{name}: ks_lwe_layout.rank_in ({}) != ks_glwe_layout.rank_out ({})",
+                    ks_lwe.rank_in,
+                    ks_glwe.rank_out
+                );
+                ensure_gglwe_fits(&format!("{name}: ks_glwe_layout"), ks_glwe)?;
+            }
+            None => ensure!(
+                ks_lwe.rank_in == rank,
+                "{name}: ks_lwe_layout.rank_in is {}, expected {rank}",
+                ks_lwe.rank_in
+            ),
+        }
+
+        ensure_ggsw_fits(&format!("{name}: ggsw_layout"), &self.ggsw_layout)?;
+        ensure_ggsw_fits(&format!("{name}: brk_layout"), &cbt.brk_layout)?;
+        ensure_gglwe_fits(&format!("{name}: atk_layout"), &cbt.atk_layout)?;
+        ensure_gglwe_fits(&format!("{name}: tsk_layout"), &cbt.tsk_layout)?;
+        ensure_gglwe_fits(&format!("{name}: ks_lwe_layout"), ks_lwe)?;
+        ensure!(
+            self.glwe_layout.max_k().as_usize() >= Self::K_PT,
+            "{name}: glwe_layout cannot hold a {}-bit plaintext",
+            Self::K_PT
+        );
+
+        ensure_secret_dist(&format!("{name}: glwe_dist"), self.glwe_dist, n.as_usize())?;
+        ensure_secret_dist(&format!("{name}: lwe_dist"), self.lwe_dist, cbt.brk_layout.n_lwe.as_usize())?;
+
+        self.encryption_infos()?;
+
+        let security: f64 = self.estimate_security();
+        ensure!(
+            security >= self.security,
+            "{name}: estimated security {security:.1} bits is below the annotated {} bits",
+            self.security
+        );
+
+        let log2_failure: f64 = self.estimate_log2_failure();
+        ensure!(
+            log2_failure <= self.log2_failure,
+            "{name}: predicted failure probability 2^{log2_failure:.1} exceeds the annotated 2^{}",
+            self.log2_failure
+        );
+
+        Ok(())
+    }
+}
+
+/// 128-bit BDD arithmetic over `N = 2048`, rank 1, `base2k = 13`, with a
+/// block-binary LWE secret of dimension 742.
+pub const BDD_2048_128: BDDParameters = {
+    const N_GLWE: u32 = 2048;
+    const N_LWE: u32 = 742;
+    const BASE2K: u32 = 13;
+    const LWE_BASE2K: u32 = 3;
+    const RANK: u32 = 1;
+
+    BDDParameters {
+        name: "BDD_2048_128",
+        glwe_layout: GLWELayout {
+            n: Degree(N_GLWE),
+            base2k: Base2K(BASE2K),
+            k: TorusPrecision(2 * BASE2K),
+            rank: Rank(RANK),
+        },
+        ggsw_layout: GGSWLayout {
+            n: Degree(N_GLWE),
+            base2k: Base2K(BASE2K),
+            k: TorusPrecision(3 * BASE2K),
+            rank: Rank(RANK),
+            dnum: Dnum(2),
+            dsize: Dsize(1),
+        },
+        bdd_layout: BDDKeyLayout {
+            cbt_layout: CircuitBootstrappingKeyLayout {
+                brk_layout: BlindRotationKeyLayout {
+                    n_glwe: Degree(N_GLWE),
+                    n_lwe: Degree(N_LWE),
+                    base2k: Base2K(BASE2K),
+                    k: TorusPrecision(4 * BASE2K),
+                    dnum: Dnum(4),
+                    rank: Rank(RANK),
+                },
+                atk_layout: GLWEAutomorphismKeyLayout {
+                    n: Degree(N_GLWE),
+                    base2k: Base2K(BASE2K),
+                    k: TorusPrecision(4 * BASE2K),
+                    dnum: Dnum(4),
+                    dsize: Dsize(1),
+                    rank: Rank(RANK),
+                },
+                tsk_layout: GGLWEToGGSWKeyLayout {
+                    n: Degree(N_GLWE),
+                    base2k: Base2K(BASE2K),
+                    k: TorusPrecision(4 * BASE2K),
+                    dnum: Dnum(4),
+                    dsize: Dsize(1),
+                    rank: Rank(RANK),
+                },
+            },
+            ks_glwe_layout: None,
+            ks_lwe_layout: GLWEToLWEKeyLayout {
+                n: Degree(N_GLWE),
+                base2k: Base2K(LWE_BASE2K),
+                k: TorusPrecision(17),
+                rank_in: Rank(RANK),
+                dnum: Dnum(5),
+            },
+        },
+        glwe_dist: Distribution::TernaryProb(0.5),
+        lwe_dist: Distribution::BinaryBlock(7),
+        security: 128.0,
+        log2_failure: -128.0,
+    }
+};
+
+/// Small parameter set used by the `bdd_arithmetic` and `max_array` examples
+/// to keep run times short.
+///
+/// **Not secure**: the GLWE-to-LWE key exposes the LWE secret at a 68-bit
+/// modulus, which is estimated at about 41 bits of security.
+pub const BDD_1024_DEMO: BDDParameters = {
+    const N_GLWE: u32 = 1024;
+    const N_LWE: u32 = 567;
+    const BASE2K: u32 = 17;
+    const RANK: u32 = 1;
+
+    BDDParameters {
+        name: "BDD_1024_DEMO",
+        glwe_layout: GLWELayout {
+            n: Degree(N_GLWE),
+            base2k: Base2K(BASE2K),
+            k: TorusPrecision(2 * BASE2K),
+            rank: Rank(RANK),
+        },
+        ggsw_layout: GGSWLayout {
+            n: Degree(N_GLWE),
+            base2k: Base2K(BASE2K),
+            k: TorusPrecision(3 * BASE2K),
+            rank: Rank(RANK),
+            dnum: Dnum(3),
+            dsize: Dsize(1),
+        },
+        bdd_layout: BDDKeyLayout {
+            cbt_layout: CircuitBootstrappingKeyLayout {
+                brk_layout: BlindRotationKeyLayout {
+                    n_glwe: Degree(N_GLWE),
+                    n_lwe: Degree(N_LWE),
+                    base2k: Base2K(BASE2K),
+                    k: TorusPrecision(4 * BASE2K),
+                    dnum: Dnum(4),
+                    rank: Rank(RANK),
+                },
+                atk_layout: GLWEAutomorphismKeyLayout {
+                    n: Degree(N_GLWE),
+                    base2k: Base2K(BASE2K),
+                    k: TorusPrecision(4 * BASE2K),
+                    dnum: Dnum(4),
+                    dsize: Dsize(1),
+                    rank: Rank(RANK),
+                },
+                tsk_layout: GGLWEToGGSWKeyLayout {
+                    n: Degree(N_GLWE),
+                    base2k: Base2K(BASE2K),
+                    k: TorusPrecision(4 * BASE2K),
+                    dnum: Dnum(4),
+                    dsize: Dsize(1),
+                    rank: Rank(RANK),
+                },
+            },
+            ks_glwe_layout: Some(GLWESwitchingKeyLayout {
+                n: Degree(N_GLWE),
+                base2k: Base2K(BASE2K),
+                k: TorusPrecision(4 * BASE2K),
+                dnum: Dnum(4),
+                dsize: Dsize(1),
+                rank_in: Rank(RANK),
+                rank_out: Rank(1),
+            }),
+            ks_lwe_layout: GLWEToLWEKeyLayout {
+                n: Degree(N_GLWE),
+                base2k: Base2K(BASE2K),
+                k: TorusPrecision(4 * BASE2K),
+                rank_in: Rank(1),
+                dnum: Dnum(4),
+            },
+        },
+        glwe_dist: Distribution::TernaryProb(0.5),
+        lwe_dist: Distribution::BinaryBlock(7),
+        security: 41.0,
+        log2_failure: -128.0,
+    }
+};
@@ -0,0 +1,286 @@
+use anyhow::{Result, ensure};
+use poulpy_core::{
+    DEFAULT_SIGMA_XE, Distribution, NoiseModel, NoiseTracker, SecurityParameters,
+    layouts::{
+        Base2K, Degree, Dnum, Dsize, GGLWEToGGSWKeyLayout, GGSWLayout, GLWEAutomorphismKeyLayout, GLWELayout, LWEInfos,
+        LWELayout, Rank, TorusPrecision,
+    },
+};
+
+use crate::{
+    blind_rotation::BlindRotationKeyLayout,
+    circuit_bootstrapping::{CircuitBootstrappingEncryptionInfos, CircuitBootstrappingKeyLayout},
+    params::{
+        ensure_gglwe_fits, ensure_ggsw_fits, ensure_secret_dist, log2_failure_circuit_bootstrapping, log2_union,
+        var_modulus_switch,
+    },
+};
+
+/// A named parameter set for circuit bootstrapping an LWE ciphertext into a GGSW.
+///
+/// See the [module documentation](crate::params) for the meaning of the
+/// annotations and the list of presets.
+#[derive(Debug, Clone, Copy)]
+pub struct CircuitBootstrappingParameters {
+    /// Human-readable identifier of the preset.
+    pub name: &'static str,
+    /// Layout of the input LWE ciphertexts.
+    pub lwe_layout: LWELayout,
+    /// Plaintext precision of the input LWE ciphertexts.
+    pub k_pt: usize,
+    /// Layout of the circuit-bootstrapping key bundle.
+    pub cbt_layout: CircuitBootstrappingKeyLayout,
+    /// Layout of the output GGSW ciphertexts.
+    pub ggsw_layout: GGSWLayout,
+    /// Distribution of the GLWE secret.
+    pub glwe_dist: Distribution,
+    /// Distribution of the LWE secret.
+    pub lwe_dist: Distribution,
+    /// Annotated security level, in bits.
+    pub security: f64,
+    /// Annotated `log2` failure probability of a single bootstrapping.
+    pub log2_failure: f64,
+}
+
+impl CircuitBootstrappingParameters {
+    /// Returns the encryption noise parameters of the key bundle.
+    pub fn encryption_infos(&self) -> Result<CircuitBootstrappingEncryptionInfos> {
+        CircuitBootstrappingEncryptionInfos::from_default_sigma(&self.cbt_layout)
+    }
+
+    /// Estimates the security, in bits, of the weakest instance exposed by
+    /// the ciphertexts and keys of this parameter set.
+    pub fn estimate_security(&self) -> f64 {
+        let brk: &BlindRotationKeyLayout = &self.cbt_layout.brk_layout;
+        let n_glwe: usize = brk.n_glwe.as_usize() * brk.rank.as_usize();
+
+        let glwe: f64 = [
+            brk.k,
+            self.cbt_layout.atk_layout.k,
+            self.cbt_layout.tsk_layout.k,
+            self.ggsw_layout.k,
+        ]
+        .iter()
+        .map(|k| SecurityParameters::new(n_glwe, k.as_usize(), self.glwe_dist, DEFAULT_SIGMA_XE).security_level())
+        .fold(f64::INFINITY, f64::min);
+
+        let lwe: f64 = SecurityParameters::from_lwe_infos(&self.lwe_layout, self.lwe_dist, DEFAULT_SIGMA_XE).security_level();
+
+        glwe.min(lwe)
+    }
+
+    /// Predicts `log2` of the probability that a fresh LWE encryption is
+    /// bootstrapped to the wrong value.
+    ///
+    /// This is the union bound of the modulus-switched input selecting the
+    /// wrong lookup-table entry and of the output GGSW, whose error accumulates
+    /// the blind rotation, the trace and the row expansion, selecting the wrong
+    /// value in an external product with a fresh GLWE of the GGSW precision.
+    pub fn estimate_log2_failure(&self) -> f64 {
+        let n_lwe: usize = self.lwe_layout.n().as_usize();
+        let n_glwe: usize = self.cbt_layout.brk_layout.n_glwe.as_usize();
+
+        let model: NoiseModel = NoiseModel::new(self.lwe_dist.density(n_lwe), DEFAULT_SIGMA_XE * DEFAULT_SIGMA_XE);
+        let var: f64 = DEFAULT_SIGMA_XE * DEFAULT_SIGMA_XE * (-2.0 * self.lwe_layout.max_k().as_usize() as f64).exp2()
+            + var_modulus_switch(n_lwe, self.lwe_dist, n_glwe);
+        let input: f64 = NoiseTracker::new(model, var).log2_failure_probability(self.k_pt + 1);
+
+        let row: GLWELayout = GLWELayout {
+            n: self.ggsw_layout.n,
+            base2k: self.ggsw_layout.base2k,
+            k: self.ggsw_layout.k,
+            rank: self.ggsw_layout.rank,
+        };
+        let output: f64 = log2_failure_circuit_bootstrapping(
+            self.glwe_dist.density(n_glwe),
+            &self.cbt_layout,
+            &self.ggsw_layout,
+            &row,
+            self.k_pt,
+        );
+
+        log2_union(input, output)
+    }
+
+    /// Checks the structural consistency of the layouts and that the
+    /// annotated security and failure probability are met.
+    pub fn check(&self) -> Result<()> {
+        let name: &str = self.name;
+        let brk: &BlindRotationKeyLayout = &self.cbt_layout.brk_layout;
+        let n: Degree = brk.n_glwe;
+        let rank: Rank = brk.rank;
+
+        ensure!(
+            n.as_usize().is_power_of_two(),
+            "{name}: GLWE degree {n} is not a power of two"
+        );
+        ensure!(
+            brk.n_lwe == self.lwe_layout.n,
+            "{name}: brk_layout.n_lwe ({}) != lwe_layout.n ({})",
+            brk.n_lwe,
+            self.lwe_layout.n
+        );
+        for (what, other) in [
+            ("atk_layout", self.cbt_layout.atk_layout.n),
+            ("tsk_layout", self.cbt_layout.tsk_layout.n),
+            ("ggsw_layout", self.ggsw_layout.n),
+        ] {
+            ensure!(other == n, "{name}: {what} has degree {other}, expected {n}");
+        }
+        for (what, other) in [
+            ("atk_layout", self.cbt_layout.atk_layout.rank),
+            ("tsk_layout", self.cbt_layout.tsk_layout.rank),
+            ("ggsw_layout", self.ggsw_layout.rank),
+        ] {
+            ensure!(other == rank, "{name}: {what} has rank {other}, expected {rank}");
+        }
+
+        ensure_ggsw_fits(&format!("{name}: brk_layout"), brk)?;
+        ensure_gglwe_fits(&format!("{name}: atk_layout"), &self.cbt_layout.atk_layout)?;
+        ensure_gglwe_fits(&format!("{name}: tsk_layout"), &self.cbt_layout.tsk_layout)?;
+        ensure_ggsw_fits(&format!("{name}: ggsw_layout"), &self.ggsw_layout)?;
+        ensure!(
+            self.k_pt > 0 && self.k_pt < self.lwe_layout.max_k().as_usize(),
+            "{name}: k_pt ({}) must be in [1, {})",
+            self.k_pt,
+            self.lwe_layout.max_k()
+        );
+
+        ensure_secret_dist(&format!("{name}: glwe_dist"), self.glwe_dist, n.as_usize())?;
+        ensure_secret_dist(&format!("{name}: lwe_dist"), self.lwe_dist, brk.n_lwe.as_usize())?;
+
+        self.encryption_infos()?;
+
+        let security: f64 = self.estimate_security();
+        ensure!(
+            security >= self.security,
+            "{name}: estimated security {security:.1} bits is below the annotated {} bits",
+            self.security
+        );
+
+        let log2_failure: f64 = self.estimate_log2_failure();
+        ensure!(
+            log2_failure <= self.log2_failure,
+            "{name}: predicted failure probability 2^{log2_failure:.1} exceeds the annotated 2^{}",
+            self.log2_failure
+        );
+
+        Ok(())
+    }
+}
+
+/// 128-bit circuit bootstrapping over `N = 2048`, rank 1, `base2k = 13`,
+/// from block-binary LWE ciphertexts of dimension 742.
+pub const CBT_2048_128: CircuitBootstrappingParameters = {
+    const N_GLWE: u32 = 2048;
+    const N_LWE: u32 = 742;
+    const BASE2K: u32 = 13;
+    const RANK: u32 = 1;
+
+    CircuitBootstrappingParameters {
+        name: "CBT_2048_128",
+        lwe_layout: LWELayout {
+            n: Degree(N_LWE),
+            k: TorusPrecision(BASE2K),
+            base2k: Base2K(BASE2K),
+        },
+        k_pt: 1,
+        cbt_layout: CircuitBootstrappingKeyLayout {
+            brk_layout: BlindRotationKeyLayout {
+                n_glwe: Degree(N_GLWE),
+                n_lwe: Degree(N_LWE),
+                base2k: Base2K(BASE2K),
+                k: TorusPrecision(4 * BASE2K),
+                dnum: Dnum(4),
+                rank: Rank(RANK),
+            },
+            atk_layout: GLWEAutomorphismKeyLayout {
+                n: Degree(N_GLWE),
+                base2k: Base2K(BASE2K),
+                k: TorusPrecision(4 * BASE2K),
+                dnum: Dnum(4),
+                dsize: Dsize(1),
+                rank: Rank(RANK),
+            },
+            tsk_layout: GGLWEToGGSWKeyLayout {
+                n: Degree(N_GLWE),
+                base2k: Base2K(BASE2K),
+                k: TorusPrecision(4 * BASE2K),
+                dnum: Dnum(4),
+                dsize: Dsize(1),
+                rank: Rank(RANK),
+            },
+        },
+        ggsw_layout: GGSWLayout {
+            n: Degree(N_GLWE),
+            base2k: Base2K(BASE2K),
+            k: TorusPrecision(3 * BASE2K),
+            rank: Rank(RANK),
+            dnum: Dnum(2),
+            dsize: Dsize(1),
+        },
+        glwe_dist: Distribution::TernaryProb(0.5),
+        lwe_dist: Distribution::BinaryBlock(7),
+        security: 128.0,
+        log2_failure: -128.0,
+    }
+};
+
+/// Small parameter set used by the circuit-bootstrapping example.
+///
+/// **Not secure**: the keys are GLWE encryptions over `N = 1024` at a 52-bit
+/// modulus, which is estimated at about 64 bits of security.
+pub const CBT_1024_DEMO: CircuitBootstrappingParameters = {
+    const N_GLWE: u32 = 1024;
+    const N_LWE: u32 = 574;
+    const BASE2K: u32 = 13;
+    const RANK: u32 = 1;
+
+    CircuitBootstrappingParameters {
+        name: "CBT_1024_DEMO",
+        lwe_layout: LWELayout {
+            n: Degree(N_LWE),
+            k: TorusPrecision(13),
+            base2k: Base2K(BASE2K),
+        },
+        k_pt: 1,
+        cbt_layout: CircuitBootstrappingKeyLayout {
+            brk_layout: BlindRotationKeyLayout {
+                n_glwe: Degree(N_GLWE),
+                n_lwe: Degree(N_LWE),
+                base2k: Base2K(BASE2K),
+                k: TorusPrecision(4 * BASE2K),
+                dnum: Dnum(3),
+                rank: Rank(RANK),
+            },
+            atk_layout: GLWEAutomorphismKeyLayout {
+                n: Degree(N_GLWE),
+                base2k: Base2K(BASE2K),
+                k: TorusPrecision(4 * BASE2K),
+                dnum: Dnum(3),
+                dsize: Dsize(1),
+                rank: Rank(RANK),
+            },
+            tsk_layout: GGLWEToGGSWKeyLayout {
+                n: Degree(N_GLWE),
+                base2k: Base2K(BASE2K),
+                k: TorusPrecision(4 * BASE2K),
+                dnum: Dnum(3),
+                dsize: Dsize(1),
+                rank: Rank(RANK),
+            },
+        },
+        ggsw_layout: GGSWLayout {
+            n: Degree(N_GLWE),
+            base2k: Base2K(BASE2K),
+            k: TorusPrecision(3 * BASE2K),
+            rank: Rank(RANK),
+            dnum: Dnum(2),
+            dsize: Dsize(1),
+        },
+        glwe_dist: Distribution::TernaryProb(0.5),
+        lwe_dist: Distribution::BinaryBlock(7),
+        security: 64.0,
+        log2_failure: -128.0,
+    }
+};
@@ -0,0 +1,149 @@
+//! Curated, named parameter sets.
+//!
+//! Each preset bundles every layout needed by a scheme together with the
+//! secret distributions it was designed for, and is annotated with:
+//!
+//! - `security`: the estimated bit-security of the weakest instance the keys
+//!   expose, as computed by [`poulpy_core::SecurityParameters`];
+//! - `log2_failure`: `log2` of the predicted probability that a single
+//!   bootstrapped bit decodes to the wrong value, as computed by
+//!   [`poulpy_core::NoiseModel`]. It is the union bound of the modulus-switched
+//!   input selecting the wrong lookup-table entry and of the noise of the
+//!   blind rotation, trace and GGSW expansion flipping the output.
+//!
+//! Annotations are lower bounds: [`BDDParameters::check`] and
+//! [`CircuitBootstrappingParameters::check`] recompute both quantities and
+//! verify them, together with the structural consistency of the layouts
+//! (matching degrees and ranks, gadgets that fit in their modulus, ...).
+//!
+//! | Preset | GLWE | LWE | Security |
+//! |--------|------|-----|----------|
+//! | [`BDD_2048_128`] | `N = 2048`, rank 1 | `n = 742` | 128 bits |
+//! | [`BDD_1024_DEMO`] | `N = 1024`, rank 1 | `n = 567` | **insecure**, for demos only |
+//! | [`CBT_2048_128`] | `N = 2048`, rank 1 | `n = 742` | 128 bits |
+//! | [`CBT_1024_DEMO`] | `N = 1024`, rank 1 | `n = 574` | **insecure**, for demos only |
+//!
+//! All presets assume the default error distribution
+//! ([`DEFAULT_SIGMA_XE`](poulpy_core::DEFAULT_SIGMA_XE)), i.e. keys encrypted
+//! with [`BDDEncryptionInfos::from_default_sigma`](crate::bdd_arithmetic::BDDEncryptionInfos::from_default_sigma)
+//! or [`CircuitBootstrappingEncryptionInfos::from_default_sigma`](crate::circuit_bootstrapping::CircuitBootstrappingEncryptionInfos::from_default_sigma).
+
+mod bdd;
+mod circuit_bootstrapping;
+
+pub use bdd::*;
+pub use circuit_bootstrapping::*;
+
+use anyhow::{Result, ensure};
+use poulpy_core::{
+    DEFAULT_SIGMA_XE, Distribution, NoiseModel, NoiseTracker,
+    layouts::{GGLWEInfos, GGSWInfos, GGSWLayout, GLWELayout, LWEInfos},
+};
+
+use crate::circuit_bootstrapping::CircuitBootstrappingKeyLayout;
+
+#[cfg(test)]
+mod tests;
+
+/// Checks that a GGLWE gadget (`dnum` rows of `dsize` limbs) fits in its modulus.
+fn ensure_gglwe_fits<A: GGLWEInfos>(name: &str, infos: &A) -> Result<()> {
+    ensure_gadget_fits(name, infos.dnum().as_usize(), infos.dsize().as_usize(), infos.size())
+}
+
+/// Checks that a GGSW gadget (`dnum` rows of `dsize` limbs) fits in its modulus.
+fn ensure_ggsw_fits<A: GGSWInfos>(name: &str, infos: &A) -> Result<()> {
+    ensure_gadget_fits(name, infos.dnum().as_usize(), infos.dsize().as_usize(), infos.size())
+}
+
+fn ensure_gadget_fits(name: &str, dnum: usize, dsize: usize, size: usize) -> Result<()> {
+    ensure!(dnum > 0 && dsize > 0, "{name}: dnum and dsize must be non-zero");
+    ensure!(
+        dnum * dsize <= size,
+        "{name}: dnum ({dnum}) * dsize ({dsize}) exceeds the number of limbs ({size})"
+    );
+    Ok(())
+}
+
+/// Checks that `dist` can be sampled for a secret of dimension `n`.
+fn ensure_secret_dist(name: &str, dist: Distribution, n: usize) -> Result<()> {
+    match dist {
+        Distribution::NONE | Distribution::ZERO => anyhow::bail!("{name}: secret distribution {dist:?} is not a valid secret"),
+        Distribution::BinaryBlock(block) => ensure!(
+            block > 0 && n.is_multiple_of(block),
+            "{name}: dimension {n} is not a multiple of the binary block size {block}"
+        ),
+        Distribution::TernaryFixed(hw) | Distribution::BinaryFixed(hw) => {
+            ensure!(hw <= n, "{name}: hamming weight {hw} exceeds dimension {n}")
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Variance added by switching an LWE of dimension `n_lwe` with secret
+/// distribution `dist` to the modulus `2 * n_glwe` of the blind rotation.
+fn var_modulus_switch(n_lwe: usize, dist: Distribution, n_glwe: usize) -> f64 {
+    let q: f64 = 2.0 * n_glwe as f64;
+    (1.0 + n_lwe as f64 * dist.density(n_lwe)) / (12.0 * q * q)
+}
+
+/// Variance of rounding an LWE of dimension `n_lwe` to `k` bits.
+fn var_lwe_rounding(n_lwe: usize, dist: Distribution, k: usize) -> f64 {
+    (1.0 + n_lwe as f64 * dist.density(n_lwe)) * (-2.0 * k as f64).exp2() / 12.0
+}
+
+/// Predicts `log2` of the probability that the GGSW produced by circuit
+/// bootstrapping with keys of layout `cbt` into `ggsw` selects the wrong value
+/// when used once as the selector of an external product on a GLWE of layout
+/// `glwe_in` with a plaintext of precision `k_pt`. `var_xs` is the variance of
+/// the coefficients of the GLWE secret.
+///
+/// The accumulator goes through the blind rotation, a trace over the full
+/// ring (each of the `log2(N)` steps halves it, rounds it and adds one
+/// automorphism) and the expansion of the rows with the tensor key. The
+/// resulting error of the GGSW rows is then propagated through the external
+/// product as a gadget error.
+fn log2_failure_circuit_bootstrapping(
+    var_xs: f64,
+    cbt: &CircuitBootstrappingKeyLayout,
+    ggsw: &GGSWLayout,
+    glwe_in: &GLWELayout,
+    k_pt: usize,
+) -> f64 {
+    let model: NoiseModel = NoiseModel::new(var_xs, DEFAULT_SIGMA_XE * DEFAULT_SIGMA_XE);
+    let brk = &cbt.brk_layout;
+    let acc: GLWELayout = GLWELayout {
+        n: brk.n_glwe,
+        base2k: cbt.atk_layout.base2k,
+        k: brk.k,
+        rank: brk.rank,
+    };
+
+    let mut var: f64 = model.blind_rotation(brk.n_lwe.as_usize(), &acc, brk);
+    for _ in 0..acc.n().log2() {
+        let var_rsh: f64 = var / 4.0 + model.glwe_rounding(&acc);
+        var = var_rsh + model.glwe_automorphism(var_rsh, &acc, &cbt.atk_layout);
+    }
+
+    let row: GLWELayout = GLWELayout {
+        n: ggsw.n,
+        base2k: ggsw.base2k,
+        k: ggsw.k,
+        rank: ggsw.rank,
+    };
+    let var_ggsw: f64 = model.glwe_keyswitch(var + model.glwe_rounding(&row), &row, &cbt.tsk_layout);
+
+    // The gadget error of the model is scaled by 2^{-2k} of the key.
+    let ggsw_model: NoiseModel = NoiseModel::new(var_xs, var_ggsw * (2.0 * ggsw.k.as_usize() as f64).exp2());
+    let var_in: f64 = DEFAULT_SIGMA_XE * DEFAULT_SIGMA_XE * (-2.0 * glwe_in.max_k().as_usize() as f64).exp2();
+    let var_out: f64 = ggsw_model.glwe_external_product(var_in, 1.0, glwe_in, ggsw);
+
+    NoiseTracker::new(ggsw_model, var_out).log2_failure_probability(k_pt + 1)
+}
+
+/// `log2(2^a + 2^b)`: union bound of two failure events of `log2`
+/// probabilities `a` and `b`.
+fn log2_union(a: f64, b: f64) -> f64 {
+    let (hi, lo): (f64, f64) = if a >= b { (a, b) } else { (b, a) };
+    hi + (lo - hi).exp2().ln_1p() / std::f64::consts::LN_2
+}
@@ -0,0 +1,104 @@
+use crate::params::{BDD_1024_DEMO, BDD_2048_128, BDDParameters, CBT_1024_DEMO, CBT_2048_128, CircuitBootstrappingParameters};
+
+/// Asserts that the estimators meet the annotations of a preset.
+fn assert_estimates(name: &str, security: (f64, f64), log2_failure: (f64, f64)) {
+    assert!(
+        security.0 >= security.1,
+        "{name}: security {} below {}",
+        security.0,
+        security.1
+    );
+    assert!(
+        log2_failure.0 <= log2_failure.1,
+        "{name}: failure 2^{} above 2^{}",
+        log2_failure.0,
+        log2_failure.1
+    );
+}
+
+fn assert_bdd_estimates(params: &BDDParameters) {
+    assert_estimates(
+        params.name,
+        (params.estimate_security(), params.security),
+        (params.estimate_log2_failure(), params.log2_failure),
+    );
+}
+
+fn assert_cbt_estimates(params: &CircuitBootstrappingParameters) {
+    assert_estimates(
+        params.name,
+        (params.estimate_security(), params.security),
+        (params.estimate_log2_failure(), params.log2_failure),
+    );
+}
+
+#[test]
+fn estimates_bdd_2048_128() {
+    assert_bdd_estimates(&BDD_2048_128);
+}
+
+#[test]
+fn estimates_bdd_1024_demo() {
+    assert_bdd_estimates(&BDD_1024_DEMO);
+}
+
+#[test]
+fn estimates_cbt_2048_128() {
+    assert_cbt_estimates(&CBT_2048_128);
+}
+
+#[test]
+fn estimates_cbt_1024_demo() {
+    assert_cbt_estimates(&CBT_1024_DEMO);
+}
+
+#[test]
+fn bdd_failure_includes_blind_rotation() {
+    let mut params: BDDParameters = BDD_2048_128;
+    let log2_failure: f64 = params.estimate_log2_failure();
+    params.bdd_layout.cbt_layout.brk_layout.k = (2 * params.bdd_layout.cbt_layout.brk_layout.base2k.as_usize()).into();
+    params.bdd_layout.cbt_layout.brk_layout.dnum = 2_u32.into();
+    assert!(params.estimate_log2_failure() > log2_failure);
+}
+
+#[test]
+fn bdd_presets_are_consistent() {
+    for params in [&BDD_2048_128, &BDD_1024_DEMO] {
+        params.check().unwrap();
+    }
+}
+
+#[test]
+fn cbt_presets_are_consistent() {
+    for params in [&CBT_2048_128, &CBT_1024_DEMO] {
+        params.check().unwrap();
+    }
+}
+
+#[test]
+fn bdd_check_rejects_mismatched_degree() {
+    let mut params: BDDParameters = BDD_2048_128;
+    params.ggsw_layout.n = 1024_u32.into();
+    assert!(params.check().is_err());
+}
+
+#[test]
+fn bdd_check_rejects_overstated_security() {
+    let mut params: BDDParameters = BDD_1024_DEMO;
+    params.security = 128.0;
+    assert!(params.check().is_err());
+}
+
+#[test]
+fn cbt_check_rejects_oversized_gadget() {
+    let mut params: CircuitBootstrappingParameters = CBT_2048_128;
+    params.cbt_layout.atk_layout.dnum = 5_u32.into();
+    assert!(params.check().is_err());
+}
+
+#[test]
+fn cbt_check_rejects_invalid_block_size() {
+    let mut params: CircuitBootstrappingParameters = CBT_2048_128;
+    params.lwe_dist = poulpy_core::Distribution::BinaryBlock(8);
+    assert!(params.check().is_err());
+}
@@ -25,18 +25,19 @@ use poulpy_ckks::{
         CKKSCiphertext, CKKSMaintainOps, CKKSPlaintextConversion, CKKSPlaintextCstRnx, CKKSPlaintextVecRnx, CKKSPlaintextVecZnx,
     },
     leveled::api::{CKKSAddOpsUnsafe, CKKSAllOpsTmpBytes, CKKSDecrypt, CKKSEncrypt, CKKSMulAddOps, CKKSMulOps},
+    params::{CKKS_4096_DEMO, CKKSParameters},
 };
 use poulpy_core::{
     EncryptionLayout, GLWENormalize, GLWETensorKeyEncryptSk,
     layouts::{
-        GLWELayout, GLWESecret, GLWETensorKey, GLWETensorKeyLayout, GLWETensorKeyPreparedFactory, LWEInfos, Rank,
+        GLWELayout, GLWESecret, GLWETensorKey, GLWETensorKeyLayout, GLWETensorKeyPreparedFactory, LWEInfos,
         prepared::{GLWESecretPrepared, GLWESecretPreparedFactory, GLWETensorKeyPrepared},
     },
 };
 use poulpy_cpu_ref::NTT120Ref;
 use poulpy_hal::{
     api::{ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{DeviceBuf, Module, ScratchOwned},
+    layouts::{AlignedBuf, DeviceBuf, Module, ScratchOwned},
     source::Source,
 };
 
@@ -44,15 +45,14 @@ type BakcendImpl = NTT120Ref;
 type SecretKeyPrepared = GLWESecretPrepared<DeviceBuf<BakcendImpl>, BakcendImpl>;
 type TensorKeyPrepared = GLWETensorKeyPrepared<DeviceBuf<BakcendImpl>, BakcendImpl>;
 
-const N: usize = 4096;
+const PARAMS: CKKSParameters = CKKS_4096_DEMO;
+const N: usize = PARAMS.n.0 as usize;
 const M: usize = N / 2;
-const BASE2K: usize = 52;
-const CT_K: usize = 95;
-const HW: usize = 192;
-const DSIZE: usize = 1;
+const BASE2K: usize = PARAMS.base2k.0 as usize;
+const CT_K: usize = PARAMS.k.0 as usize;
 const PREC_CT: CKKSMeta = CKKSMeta {
-    log_delta: 30,
-    log_budget: 5,
+    log_delta: PARAMS.log_delta,
+    log_budget: PARAMS.final_log_budget(),
 };
 const PREC_PT: CKKSMeta = CKKSMeta {
     log_delta: 4,
@@ -88,17 +88,17 @@ struct EncodingArtifacts {
     cst_b: CKKSPlaintextCstRnx<f64>,
     cst_c: CKKSPlaintextCstRnx<f64>,
     cst_d: CKKSPlaintextCstRnx<f64>,
-    pt_znx: CKKSPlaintextVecZnx<Vec<u8>>,
+    pt_znx: CKKSPlaintextVecZnx<AlignedBuf>,
 }
 
 /// Ciphertexts produced by the encryption phase.
 struct EncryptionArtifacts {
-    ct_x: CKKSCiphertext<Vec<u8>>,
+    ct_x: CKKSCiphertext<AlignedBuf>,
 }
 
 /// Final ciphertext produced by homomorphic evaluation.
 struct EvaluationArtifacts {
-    poly: CKKSCiphertext<Vec<u8>>,
+    poly: CKKSCiphertext<AlignedBuf>,
 }
 
 /// Decoded slot values recovered after decryption.
@@ -112,13 +112,7 @@ struct DecryptionArtifacts {
 /// `CT_K` is the allocated ciphertext torus precision, while `BASE2K`
 /// determines the limb size.
 fn glwe_layout() -> EncryptionLayout<GLWELayout> {
-    EncryptionLayout::new_from_default_sigma(GLWELayout {
-        n: N.into(),
-        base2k: BASE2K.into(),
-        k: CT_K.into(),
-        rank: Rank(1),
-    })
-    .unwrap()
+    PARAMS.glwe_encryption_layout().unwrap()
 }
 
 /// Returns the tensor-key layout used by ciphertext-ciphertext multiplication.
@@ -126,17 +120,7 @@ fn glwe_layout() -> EncryptionLayout<GLWELayout> {
 /// The tensor key is sized one decomposition block above the ciphertext
 /// precision so multiplication and relinearization have enough headroom.
 fn tsk_layout() -> EncryptionLayout<GLWETensorKeyLayout> {
-    let k = CT_K + DSIZE * BASE2K;
-    let dnum = CT_K.div_ceil(DSIZE * BASE2K);
-    EncryptionLayout::new_from_default_sigma(GLWETensorKeyLayout {
-        n: N.into(),
-        base2k: BASE2K.into(),
-        k: k.into(),
-        rank: Rank(1),
-        dsize: DSIZE.into(),
-        dnum: dnum.into(),
-    })
-    .unwrap()
+    PARAMS.tsk_encryption_layout().unwrap()
 }
 
 /// Computes the maximum absolute error between two real vectors.
@@ -159,7 +143,7 @@ fn format_complex(re: f64, im: f64, digits: usize) -> String {
 }
 
 /// Prints the semantic and storage metadata of a CKKS ciphertext.
-fn print_ct_meta(label: &str, ct: &CKKSCiphertext<Vec<u8>>) {
+fn print_ct_meta(label: &str, ct: &CKKSCiphertext<AlignedBuf>) {
     println!(
         "  {label:<28} dec={:>2} hom={:>2} eff={:>3} limbs={:>2} max={:>3}",
         ct.log_delta(),
@@ -171,7 +155,7 @@ fn print_ct_meta(label: &str, ct: &CKKSCiphertext<Vec<u8>>) {
 }
 
 /// Prints the semantic and storage metadata of a CKKS plaintext.
-fn print_pt_meta(label: &str, pt: &CKKSPlaintextVecZnx<Vec<u8>>) {
+fn print_pt_meta(label: &str, pt: &CKKSPlaintextVecZnx<AlignedBuf>) {
     println!(
         "  {label:<28} dec={:>2} hom={:>2} eff={:>3} limbs={:>2} max={:>3}",
         pt.log_delta(),
@@ -194,10 +178,11 @@ fn print_pt_meta(label: &str, pt: &CKKSPlaintextVecZnx<Vec<u8>>) {
 /// No message-dependent data is handled here yet.
 fn setup() -> Result<SetupArtifacts> {
     print_phase("setup");
+    PARAMS.check()?;
     println!("  polynomial: p(x) = (a + b*x) + (c + d*x) * x^2");
     println!(
-        "  params: n={N}, slots={M}, base2k={BASE2K}, ct_k={CT_K}, prec_ct=({}, {}), prec_pt=({}, {})",
-        PREC_CT.log_delta, PREC_CT.log_budget, PREC_PT.log_delta, PREC_PT.log_budget
+        "  params: {}, n={N}, slots={M}, base2k={BASE2K}, ct_k={CT_K}, prec_ct=({}, {}), prec_pt=({}, {})",
+        PARAMS.name, PREC_CT.log_delta, PREC_CT.log_budget, PREC_PT.log_delta, PREC_PT.log_budget
     );
 
     let module = Module::<BakcendImpl>::new(N as u64);
@@ -208,7 +193,7 @@ fn setup() -> Result<SetupArtifacts> {
     let mut source_xe = Source::new([2u8; 32]);
 
     let mut sk_raw = GLWESecret::alloc_from_infos(&glwe_layout());
-    sk_raw.fill_ternary_hw(HW, &mut source_xs);
+    sk_raw.fill_dist(PARAMS.secret_dist(), &mut source_xs);
 
     let mut sk = module.glwe_secret_prepared_alloc_from_infos(&glwe_layout());
     module.glwe_secret_prepare(&mut sk, &sk_raw);
@@ -11,6 +11,13 @@ use crate::layouts::plaintext::CKKSPlaintextVecRnx;
 ///
 /// The encoder maps `m` complex slots onto an RNX plaintext of size `2m`
 /// through the canonical FFT/IFFT packing used by the rest of the crate.
+///
+/// Plaintexts of any larger size `n` are supported through sparse packing:
+/// the `m` slots are encoded in the subring `Z[Y]/(Y^{2m}+1)` with
+/// `Y = X^{n/2m}`. Since the automorphisms `X -> X^{5^k}` and `X -> X^{-1}`
+/// restrict to `Y -> Y^{5^k}` and `Y -> Y^{-1}` on that subring, rotations
+/// by `k` act on the sparse slots as rotations by `k mod m` and conjugation
+/// remains slot-wise, using the same automorphism keys as dense packing.
 pub struct Encoder<F: Float + FloatConst + Debug> {
     fft_table: ReimFFTTable<F>,
     ifft_table: ReimIFFTTable<F>,
@@ -27,7 +34,8 @@ where
     /// - `m`: number of complex slots
     ///
     /// Output:
-    /// - an encoder configured for plaintext polynomials of size `2m`
+    /// - an encoder configured for plaintext polynomials of size `2m`, or of
+    ///   any larger power-of-two size with sparse packing
     ///
     /// Errors:
     /// - returns an error if `m == 0` or if `m` is not a power of two
@@ -49,10 +57,16 @@ where
         })
     }
 
+    /// Returns the number of complex slots `m`.
+    pub fn slots(&self) -> usize {
+        self.slot_map.len()
+    }
+
     /// Encodes complex slot values into an RNX plaintext buffer.
     ///
     /// Inputs:
-    /// - `pt`: destination plaintext polynomial of size `2m`
+    /// - `pt`: destination plaintext polynomial of size `n`, a power-of-two
+    ///   multiple of `2m`
     /// - `re`, `im`: real and imaginary slot vectors, each of length `m`
     ///
     /// Output:
@@ -61,64 +75,62 @@ where
     /// Behavior:
     /// - writes slots according to the internal CKKS slot permutation
     /// - runs the inverse FFT and normalizes by `1/m`
+    /// - if `n > 2m`, spreads the `2m` resulting coefficients with stride
+    ///   `n/2m` and zeroes all other coefficients
     ///
     /// Errors:
-    /// - returns an error if `pt`, `re`, and `im` do not match the encoder's
-    ///   configured slot count
+    /// - returns an error if `re` and `im` do not match the encoder's
+    ///   configured slot count or if `pt` is too small or not a power of two
     pub fn encode_reim(&self, pt: &mut CKKSPlaintextVecRnx<F>, re: &[F], im: &[F]) -> Result<()> {
-        let n = pt.n();
-        let m = n / 2;
+        let m = self.slots();
+        let gap = self.gap(pt.n())?;
 
-        let table = &self.ifft_table;
-
-        anyhow::ensure!(table.m() == m);
         anyhow::ensure!(re.len() == m);
         anyhow::ensure!(im.len() == m);
 
-        pt.data_mut().fill(F::zero());
-        for k in 0..m {
-            let idx = self.slot_map[k];
-            pt.data_mut()[idx] = re[k];
-            pt.data_mut()[m + idx] = im[k];
+        if gap == 1 {
+            self.encode_dense(pt.data_mut(), re, im);
+        } else {
+            let mut reim_tmp = vec![F::zero(); 2 * m];
+            self.encode_dense(&mut reim_tmp, re, im);
+            let data = pt.data_mut();
+            data.fill(F::zero());
+            for (i, x) in reim_tmp.into_iter().enumerate() {
+                data[i * gap] = x;
+            }
         }
 
-        table.execute(pt.data_mut());
-
-        let inv_m = <F as NumCast>::from(m).unwrap().recip();
-        pt.data_mut().iter_mut().for_each(|x| *x = *x * inv_m);
-
         Ok(())
     }
 
     /// Decodes an RNX plaintext buffer back into complex slot vectors.
     ///
     /// Inputs:
-    /// - `pt`: source RNX plaintext polynomial of size `2m`
+    /// - `pt`: source RNX plaintext polynomial of size `n`, a power-of-two
+    ///   multiple of `2m`
     /// - `re`, `im`: output slot buffers of length `m`
     ///
     /// Output:
     /// - fills `re` and `im` with the decoded slot values
     ///
     /// Behavior:
+    /// - if `n > 2m`, keeps only the coefficients at multiples of `n/2m`,
+    ///   which averages the `n/2m` copies of each sparse slot
     /// - runs the forward FFT and applies the inverse of the encoder slot map
     ///
     /// Errors:
     /// - returns an error if the provided buffers do not match the encoder's
-    ///   configured slot count
+    ///   configured slot count or if `pt` is too small or not a power of two
     pub fn decode_reim(&self, pt: &CKKSPlaintextVecRnx<F>, re: &mut [F], im: &mut [F]) -> Result<()> {
-        let n = pt.n();
-        let m = n / 2;
+        let m = self.slots();
+        let gap = self.gap(pt.n())?;
 
-        let table = &self.fft_table;
-
-        anyhow::ensure!(table.m() == m);
         anyhow::ensure!(re.len() == m);
         anyhow::ensure!(im.len() == m);
 
-        let mut reim_tmp = vec![F::zero(); n];
-        reim_tmp.copy_from_slice(pt.data());
+        let mut reim_tmp: Vec<F> = pt.data().iter().step_by(gap).copied().collect();
 
-        table.execute(&mut reim_tmp);
+        self.fft_table.execute(&mut reim_tmp);
 
         for k in 0..m {
             let idx = self.slot_map[k];
@@ -128,6 +140,33 @@ where
 
         Ok(())
     }
+
+    /// Returns the coefficient stride `n/2m` of the slot subring.
+    fn gap(&self, n: usize) -> Result<usize> {
+        let two_m = 2 * self.slots();
+        anyhow::ensure!(
+            n.is_power_of_two() && n >= two_m,
+            "plaintext size must be a power of two >= 2m={two_m}, got {n}"
+        );
+        Ok(n / two_m)
+    }
+
+    /// Packs the slots into `buf` of size `2m` and runs the normalized inverse FFT.
+    fn encode_dense(&self, buf: &mut [F], re: &[F], im: &[F]) {
+        let m = self.slots();
+
+        buf.fill(F::zero());
+        for k in 0..m {
+            let idx = self.slot_map[k];
+            buf[idx] = re[k];
+            buf[m + idx] = im[k];
+        }
+
+        self.ifft_table.execute(buf);
+
+        let inv_m = <F as NumCast>::from(m).unwrap().recip();
+        buf.iter_mut().for_each(|x| *x = *x * inv_m);
+    }
 }
 
 #[cfg(test)]
@@ -161,4 +200,111 @@ mod tests {
         assert!(err_re < bound, "re max_err={err_re:.2e} exceeds bound={bound:.2e}");
         assert!(err_im < bound, "im max_err={err_im:.2e} exceeds bound={bound:.2e}");
     }
+
+    /// Applies `X -> X^gal` to a real polynomial of size `n` modulo `X^n + 1`.
+    fn automorphism(a: &[f64], gal: usize) -> Vec<f64> {
+        let n = a.len();
+        let mut res = vec![0.0f64; n];
+        for (i, &x) in a.iter().enumerate() {
+            let j = (i * gal) % (2 * n);
+            if j < n {
+                res[j] += x;
+            } else {
+                res[j - n] -= x;
+            }
+        }
+        res
+    }
+
+    fn sparse_slots(m: usize) -> (Vec<f64>, Vec<f64>) {
+        let re = (0..m).map(|i| ((i * 7 + 1) as f64) / (8 * m) as f64).collect();
+        let im = (0..m).map(|i| -((i * 3 + 2) as f64) / (8 * m) as f64).collect();
+        (re, im)
+    }
+
+    #[test]
+    fn encode_decode_sparse_roundtrip() {
+        let n = 64usize;
+        for m in [1usize, 2, 8, 16] {
+            let (re_in, im_in) = sparse_slots(m);
+            let encoder = Encoder::<f64>::new(m).unwrap();
+
+            let mut rnx = CKKSPlaintextVecRnx::<f64>::alloc(n).unwrap();
+            encoder.encode_reim(&mut rnx, &re_in, &im_in).unwrap();
+
+            let gap = n / (2 * m);
+            assert!(
+                rnx.data().iter().enumerate().all(|(i, x)| i % gap == 0 || *x == 0.0),
+                "m={m}: sparse encoding must lie in the subring X^{gap}"
+            );
+
+            let mut re_out = vec![0.0f64; m];
+            let mut im_out = vec![0.0f64; m];
+            encoder.decode_reim(&rnx, &mut re_out, &mut im_out).unwrap();
+
+            assert!(max_err(&re_in, &re_out) < 1e-10, "m={m}: re mismatch");
+            assert!(max_err(&im_in, &im_out) < 1e-10, "m={m}: im mismatch");
+        }
+    }
+
+    #[test]
+    fn sparse_slots_repeat_in_dense_slots() {
+        let n = 64usize;
+        let m = 4usize;
+        let (re_in, im_in) = sparse_slots(m);
+
+        let mut rnx = CKKSPlaintextVecRnx::<f64>::alloc(n).unwrap();
+        Encoder::<f64>::new(m).unwrap().encode_reim(&mut rnx, &re_in, &im_in).unwrap();
+
+        let mut re_out = vec![0.0f64; n / 2];
+        let mut im_out = vec![0.0f64; n / 2];
+        Encoder::<f64>::new(n / 2)
+            .unwrap()
+            .decode_reim(&rnx, &mut re_out, &mut im_out)
+            .unwrap();
+
+        let re_want: Vec<f64> = (0..n / 2).map(|j| re_in[j % m]).collect();
+        let im_want: Vec<f64> = (0..n / 2).map(|j| im_in[j % m]).collect();
+        assert!(max_err(&re_want, &re_out) < 1e-10);
+        assert!(max_err(&im_want, &im_out) < 1e-10);
+    }
+
+    #[test]
+    fn sparse_automorphisms_rotate_and_conjugate() {
+        let n = 64usize;
+        for m in [2usize, 4, 8] {
+            let (re_in, im_in) = sparse_slots(m);
+            let encoder = Encoder::<f64>::new(m).unwrap();
+            let mut rnx = CKKSPlaintextVecRnx::<f64>::alloc(n).unwrap();
+            encoder.encode_reim(&mut rnx, &re_in, &im_in).unwrap();
+
+            let mut out = CKKSPlaintextVecRnx::<f64>::alloc(n).unwrap();
+            let mut re_out = vec![0.0f64; m];
+            let mut im_out = vec![0.0f64; m];
+
+            for k in [1usize, 3, 5] {
+                let gal = (0..k).fold(1usize, |g, _| (g * GALOISGENERATOR as usize) % (2 * n));
+                out.data_mut().copy_from_slice(&automorphism(rnx.data(), gal));
+                encoder.decode_reim(&out, &mut re_out, &mut im_out).unwrap();
+
+                let re_want: Vec<f64> = (0..m).map(|j| re_in[(j + k) % m]).collect();
+                let im_want: Vec<f64> = (0..m).map(|j| im_in[(j + k) % m]).collect();
+                assert!(max_err(&re_want, &re_out) < 1e-10, "m={m} rotate({k}): re mismatch");
+                assert!(max_err(&im_want, &im_out) < 1e-10, "m={m} rotate({k}): im mismatch");
+            }
+
+            out.data_mut().copy_from_slice(&automorphism(rnx.data(), 2 * n - 1));
+            encoder.decode_reim(&out, &mut re_out, &mut im_out).unwrap();
+            let im_want: Vec<f64> = im_in.iter().map(|x| -x).collect();
+            assert!(max_err(&re_in, &re_out) < 1e-10, "m={m} conjugate: re mismatch");
+            assert!(max_err(&im_want, &im_out) < 1e-10, "m={m} conjugate: im mismatch");
+        }
+    }
+
+    #[test]
+    fn encode_rejects_undersized_plaintext() {
+        let encoder = Encoder::<f64>::new(8).unwrap();
+        let mut rnx = CKKSPlaintextVecRnx::<f64>::alloc(8).unwrap();
+        assert!(encoder.encode_reim(&mut rnx, &[0.0; 8], &[0.0; 8]).is_err());
+    }
 }
@@ -7,7 +7,7 @@ use std::ops::{Deref, DerefMut};
 
 use anyhow::Result;
 use poulpy_core::layouts::{Base2K, Degree, GLWE, GLWEInfos, GLWEToMut, GLWEToRef, LWEInfos, Rank, TorusPrecision};
-use poulpy_hal::layouts::{Backend, Data, DataMut, DataRef, Module};
+use poulpy_hal::layouts::{AlignedBuf, Backend, Data, DataMut, DataRef, Module};
 
 use crate::{CKKSInfos, CKKSMeta, error::CKKSCompositionError};
 
@@ -107,7 +107,7 @@ impl<D: DataMut> GLWEToMut for CKKSCiphertext<D> {
     }
 }
 
-impl CKKSCiphertext<Vec<u8>> {
+impl CKKSCiphertext<AlignedBuf> {
     /// Allocates an owned ciphertext buffer with zeroed metadata.
     ///
     /// Inputs:
@@ -162,7 +162,7 @@ pub trait CKKSMaintainOps {
     /// Errors:
     /// - `LimbReallocationShrinksBelowMetadata` if the requested limb count
     ///   cannot represent the current metadata
-    fn ckks_reallocate_limbs_checked(&self, ct: &mut CKKSCiphertext<Vec<u8>>, size: usize) -> Result<()>;
+    fn ckks_reallocate_limbs_checked(&self, ct: &mut CKKSCiphertext<AlignedBuf>, size: usize) -> Result<()>;
 
     /// Shrinks an owned ciphertext buffer to the minimum limb count that still
     /// preserves its current metadata.
@@ -176,7 +176,7 @@ pub trait CKKSMaintainOps {
     /// Errors:
     /// - propagates `ckks_reallocate_limbs_checked` if the computed compact
     ///   size would violate metadata constraints
-    fn ckks_compact_limbs(&self, ct: &mut CKKSCiphertext<Vec<u8>>) -> Result<()>;
+    fn ckks_compact_limbs(&self, ct: &mut CKKSCiphertext<AlignedBuf>) -> Result<()>;
 
     /// Returns a newly allocated owned ciphertext holding a compacted copy of
     /// `ct`.
@@ -190,14 +190,14 @@ pub trait CKKSMaintainOps {
     ///
     /// Errors:
     /// - propagates allocation failures from the underlying GLWE type
-    fn ckks_compact_limbs_copy<D>(&self, ct: &CKKSCiphertext<D>) -> Result<CKKSCiphertext<Vec<u8>>>
+    fn ckks_compact_limbs_copy<D>(&self, ct: &CKKSCiphertext<D>) -> Result<CKKSCiphertext<AlignedBuf>>
     where
         D: DataRef;
 }
 
 #[doc(hidden)]
 pub trait CKKSMaintainOpsDefault {
-    fn ckks_reallocate_limbs_checked_default(&self, ct: &mut CKKSCiphertext<Vec<u8>>, size: usize) -> Result<()> {
+    fn ckks_reallocate_limbs_checked_default(&self, ct: &mut CKKSCiphertext<AlignedBuf>, size: usize) -> Result<()> {
         let base2k = ct.base2k().as_usize();
         let required_limbs = ct.effective_k().div_ceil(base2k);
         anyhow::ensure!(
@@ -213,13 +213,13 @@ pub trait CKKSMaintainOpsDefault {
         Ok(())
     }
 
-    fn ckks_compact_limbs_default(&self, ct: &mut CKKSCiphertext<Vec<u8>>) -> Result<()> {
+    fn ckks_compact_limbs_default(&self, ct: &mut CKKSCiphertext<AlignedBuf>) -> Result<()> {
         let size = ct.effective_k().div_ceil(ct.base2k().as_usize());
         self.ckks_reallocate_limbs_checked_default(ct, size)?;
         Ok(())
     }
 
-    fn ckks_compact_limbs_copy_default<D>(&self, ct: &CKKSCiphertext<D>) -> Result<CKKSCiphertext<Vec<u8>>>
+    fn ckks_compact_limbs_copy_default<D>(&self, ct: &CKKSCiphertext<D>) -> Result<CKKSCiphertext<AlignedBuf>>
     where
         D: DataRef,
     {
@@ -238,15 +238,15 @@ impl<BE: Backend> CKKSMaintainOps for Module<BE>
 where
     Module<BE>: CKKSMaintainOpsDefault,
 {
-    fn ckks_reallocate_limbs_checked(&self, ct: &mut CKKSCiphertext<Vec<u8>>, size: usize) -> Result<()> {
+    fn ckks_reallocate_limbs_checked(&self, ct: &mut CKKSCiphertext<AlignedBuf>, size: usize) -> Result<()> {
         self.ckks_reallocate_limbs_checked_default(ct, size)
     }
 
-    fn ckks_compact_limbs(&self, ct: &mut CKKSCiphertext<Vec<u8>>) -> Result<()> {
+    fn ckks_compact_limbs(&self, ct: &mut CKKSCiphertext<AlignedBuf>) -> Result<()> {
         self.ckks_compact_limbs_default(ct)
     }
 
-    fn ckks_compact_limbs_copy<D>(&self, ct: &CKKSCiphertext<D>) -> Result<CKKSCiphertext<Vec<u8>>>
+    fn ckks_compact_limbs_copy<D>(&self, ct: &CKKSCiphertext<D>) -> Result<CKKSCiphertext<AlignedBuf>>
     where
         D: DataRef,
     {
@@ -8,7 +8,7 @@ use poulpy_core::layouts::{
     Base2K, Degree, GLWE, GLWEInfos, GLWEPlaintext, GLWEPlaintextToMut, GLWEPlaintextToRef, GLWEToMut, GLWEToRef, LWEInfos, Rank,
     SetLWEInfos,
 };
-use poulpy_hal::layouts::{Data, DataMut, DataRef};
+use poulpy_hal::layouts::{AlignedBuf, Data, DataMut, DataRef};
 use rand_distr::num_traits::{Float, FromPrimitive, ToPrimitive, Zero};
 
 use crate::{CKKSInfos, CKKSMeta};
@@ -63,7 +63,7 @@ impl<D: Data> CKKSPlaintextVecZnx<D> {
     }
 }
 
-impl CKKSPlaintextVecZnx<Vec<u8>> {
+impl CKKSPlaintextVecZnx<AlignedBuf> {
     /// Allocates an owned ZNX plaintext using the minimum storage implied by
     /// `meta`.
     ///
@@ -87,7 +87,7 @@ impl CKKSPlaintextVecZnx<Vec<u8>> {
 }
 
 /// Allocates an owned CKKS vector plaintext in ZNX form.
-pub fn alloc_pt_vec_znx(n: Degree, base2k: Base2K, prec: CKKSMeta) -> CKKSPlaintextVecZnx<Vec<u8>> {
+pub fn alloc_pt_vec_znx(n: Degree, base2k: Base2K, prec: CKKSMeta) -> CKKSPlaintextVecZnx<AlignedBuf> {
     CKKSPlaintextVecZnx::alloc(n, base2k, prec)
 }
 
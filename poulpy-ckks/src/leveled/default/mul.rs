@@ -8,7 +8,7 @@ use poulpy_core::{
 };
 use poulpy_hal::{
     api::{ModuleN, ScratchAvailable},
-    layouts::{Backend, DataMut, DataRef, Module, Scratch, ZnxZero},
+    layouts::{AlignedBuf, Backend, DataMut, DataRef, Module, Scratch, ZnxZero},
 };
 
 use crate::{
@@ -224,7 +224,7 @@ pub(crate) trait CKKSMulDefault<BE: Backend> {
             base2k: res.base2k(),
             k: b.min_k(res.base2k()),
         };
-        GLWEPlaintext::<Vec<u8>>::bytes_of_from_infos(&b_infos) + self.glwe_mul_plain_tmp_bytes(res, a, &b_infos)
+        GLWEPlaintext::<AlignedBuf>::bytes_of_from_infos(&b_infos) + self.glwe_mul_plain_tmp_bytes(res, a, &b_infos)
     }
 
     fn ckks_mul_pt_const_tmp_bytes_default<R, A>(&self, res: &R, a: &A, b: &CKKSMeta) -> usize
@@ -234,7 +234,7 @@ pub(crate) trait CKKSMulDefault<BE: Backend> {
         Self: GLWEMulConst<BE> + GLWERotate<BE>,
     {
         let b_size = b.min_k(res.base2k()).as_usize().div_ceil(res.base2k().as_usize());
-        GLWE::<Vec<u8>>::bytes_of_from_infos(res)
+        GLWE::<AlignedBuf>::bytes_of_from_infos(res)
             + self
                 .glwe_mul_const_tmp_bytes(res, a, b_size)
                 .max(self.glwe_rotate_tmp_bytes())
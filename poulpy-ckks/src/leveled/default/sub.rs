@@ -5,7 +5,7 @@ use poulpy_core::{
 };
 use poulpy_hal::{
     api::{ModuleN, ScratchAvailable, VecZnxRshSub, VecZnxRshTmpBytes},
-    layouts::{Backend, DataMut, DataRef, Module, Scratch, ZnxViewMut},
+    layouts::{AlignedBuf, Backend, DataMut, DataRef, Module, Scratch, ZnxViewMut},
 };
 
 use crate::{
@@ -49,7 +49,7 @@ pub(crate) trait CKKSSubDefault<BE: Backend> {
             base2k: res.base2k(),
             k: b.min_k(res.base2k()),
         };
-        GLWEPlaintext::<Vec<u8>>::bytes_of_from_infos(&b_infos) + self.ckks_sub_pt_vec_znx_tmp_bytes_default()
+        GLWEPlaintext::<AlignedBuf>::bytes_of_from_infos(&b_infos) + self.ckks_sub_pt_vec_znx_tmp_bytes_default()
     }
 
     fn ckks_sub_pt_const_tmp_bytes_default(&self) -> usize
@@ -3,7 +3,7 @@ use poulpy_core::layouts::{GLWEPlaintext, GLWESecretPreparedToRef};
 use poulpy_core::{EncryptionInfos, GLWEDecrypt, GLWEEncryptSk, GLWEShift, ScratchTakeCore, layouts::GLWEInfos};
 use poulpy_hal::{
     api::{ScratchAvailable, VecZnxLsh, VecZnxLshTmpBytes, VecZnxRsh, VecZnxRshAddInto, VecZnxRshTmpBytes},
-    layouts::{Backend, DataMut, DataRef, Module, Scratch},
+    layouts::{AlignedBuf, Backend, DataMut, DataRef, Module, Scratch},
     source::Source,
 };
 
@@ -58,7 +58,7 @@ where
     where
         A: GLWEInfos,
     {
-        GLWEPlaintext::<Vec<u8>>::bytes_of_from_infos(ct_infos)
+        GLWEPlaintext::<AlignedBuf>::bytes_of_from_infos(ct_infos)
             + self
                 .glwe_decrypt_tmp_bytes(ct_infos)
                 .max(self.ckks_extract_pt_znx_tmp_bytes())
@@ -0,0 +1,43 @@
+//! Operation counts and scratch consumption of the CKKS multiplication,
+//! measured on the profiling backend.
+
+use poulpy_cpu_ref::FFT64Ref;
+use poulpy_hal::{
+    api::{ScratchOwnedAlloc, ScratchOwnedBorrow},
+    layouts::ScratchOwned,
+    profiling::{self, ProfileReport, Profiled, TmpBytesCheck},
+};
+
+use crate::leveled::{
+    api::CKKSMulOps,
+    tests::test_suite::{FFT64_PARAMS_F64, helpers::TestContext},
+};
+
+type FFT64Prof = Profiled<FFT64Ref>;
+
+#[test]
+fn test_mul_profile() {
+    let ctx: TestContext<FFT64Prof> = TestContext::new(FFT64_PARAMS_F64, &[]);
+    let mut scratch: ScratchOwned<FFT64Prof> = ctx.alloc_scratch();
+    let ct1 = ctx.encrypt(ctx.max_k(), &ctx.re1, &ctx.im1, scratch.borrow());
+    let ct2 = ctx.encrypt(ctx.max_k(), &ctx.re2, &ctx.im2, scratch.borrow());
+    let mut ct_res = ctx.alloc_ct(ctx.max_k());
+
+    let tmp_bytes: usize = ctx.module.ckks_mul_tmp_bytes(&ct_res, ctx.tsk());
+    let mut mul_scratch: ScratchOwned<FFT64Prof> = ScratchOwned::alloc(tmp_bytes);
+
+    profiling::reset();
+    ctx.module
+        .ckks_mul_into(&mut ct_res, &ct1, &ct2, ctx.tsk(), mul_scratch.borrow())
+        .unwrap();
+    let report: ProfileReport = profiling::take_report();
+
+    assert!(report.calls("vmp_apply_dft_to_dft") > 0, "{report}");
+    assert!(report.calls("vec_znx_big_normalize") > 0, "{report}");
+    let check: TmpBytesCheck = report.check_tmp_bytes(tmp_bytes);
+    assert!(check.is_sufficient(), "{check}");
+    assert!(check.peak > 0);
+
+    let (want_re, want_im) = ctx.want_mul();
+    ctx.assert_decrypt_precision("mul_profile", &ct_res, &want_re, &want_im, scratch.borrow());
+}
@@ -6,3 +6,12 @@ pub mod test_suite;
 
 #[cfg(test)]
 pub mod ntt120_ref;
+
+#[cfg(test)]
+pub mod ntt120_dyn;
+
+#[cfg(test)]
+pub mod fft64_mt;
+
+#[cfg(test)]
+pub mod fft64_profiled;
@@ -16,6 +16,7 @@ use super::helpers::{TestCiphertextBackend as Backend, TestContext, TestScalar,
 use crate::{CKKSCompositionError, CKKSInfos, CKKSMeta, layouts::plaintext::alloc_pt_vec_znx, leveled::api::CKKSDecrypt};
 use poulpy_core::layouts::LWEInfos;
 use poulpy_hal::api::ScratchOwnedBorrow;
+use poulpy_hal::layouts::AlignedBuf;
 
 fn extract_src_prec<BE: Backend, F: TestScalar>(ctx: &TestContext<BE, F>) -> CKKSMeta {
     if ctx.base2k().as_usize() == 19 {
@@ -34,7 +35,7 @@ fn extract_src_prec<BE: Backend, F: TestScalar>(ctx: &TestContext<BE, F>) -> CKK
 fn extract_fixture<BE: Backend, F: TestScalar>(
     ctx: &TestContext<BE, F>,
     scratch: &mut poulpy_hal::layouts::Scratch<BE>,
-) -> crate::layouts::CKKSCiphertext<Vec<u8>> {
+) -> crate::layouts::CKKSCiphertext<AlignedBuf> {
     let src_prec = extract_src_prec(ctx);
     ctx.encrypt_with_prec(src_prec.effective_k(), &ctx.re1, &ctx.im1, src_prec, scratch)
 }
@@ -32,7 +32,7 @@ use rand_distr::num_traits::{Float, FloatConst, FromPrimitive, ToPrimitive};
 
 use poulpy_hal::{
     api::{ModuleN, ModuleNew, ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxRshAddInto, VecZnxRshSub},
-    layouts::{Backend, DataRef, DeviceBuf, GaloisElement, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, Backend, DataRef, DeviceBuf, GaloisElement, Module, Scratch, ScratchOwned},
     oep::HalImpl,
     source::Source,
 };
@@ -408,7 +408,7 @@ impl<BE: TestBackend, F: TestScalar> TestContext<BE, F> {
     }
 
     /// Encodes and encrypts complex slot values into a fresh ciphertext.
-    pub fn encrypt(&self, k: usize, re: &[F], im: &[F], scratch: &mut Scratch<BE>) -> CKKSCiphertext<Vec<u8>>
+    pub fn encrypt(&self, k: usize, re: &[F], im: &[F], scratch: &mut Scratch<BE>) -> CKKSCiphertext<AlignedBuf>
     where
         Module<BE>: CKKSEncrypt<BE>,
         Scratch<BE>: ScratchTakeCore<BE>,
@@ -423,14 +423,32 @@ impl<BE: TestBackend, F: TestScalar> TestContext<BE, F> {
         im: &[F],
         prec: CKKSMeta,
         scratch: &mut Scratch<BE>,
-    ) -> CKKSCiphertext<Vec<u8>>
+    ) -> CKKSCiphertext<AlignedBuf>
+    where
+        Module<BE>: CKKSEncrypt<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        self.encrypt_with_encoder(&self.encoder, k, re, im, prec, scratch)
+    }
+
+    /// Encodes `re.len()` slots with `encoder`, using sparse packing when the
+    /// encoder has fewer than `N/2` slots, and encrypts them.
+    pub fn encrypt_with_encoder(
+        &self,
+        encoder: &Encoder<F>,
+        k: usize,
+        re: &[F],
+        im: &[F],
+        prec: CKKSMeta,
+        scratch: &mut Scratch<BE>,
+    ) -> CKKSCiphertext<AlignedBuf>
     where
         Module<BE>: CKKSEncrypt<BE>,
         Scratch<BE>: ScratchTakeCore<BE>,
     {
         let mut pt_rnx = CKKSPlaintextRnx::alloc(self.params.n).unwrap();
 
-        self.encoder.encode_reim(&mut pt_rnx, re, im).unwrap();
+        encoder.encode_reim(&mut pt_rnx, re, im).unwrap();
 
         let mut pt_znx = alloc_pt_vec_znx(self.degree(), self.base2k(), prec);
         pt_rnx.to_znx(&mut pt_znx).unwrap();
@@ -455,13 +473,7 @@ impl<BE: TestBackend, F: TestScalar> TestContext<BE, F> {
         Module<BE>: CKKSDecrypt<BE>,
         Scratch<BE>: ScratchTakeCore<BE>,
     {
-        let prec = CKKSMeta {
-            log_delta: ct.log_delta(),
-            log_budget: ct.log_budget().min(self.params.prec.log_budget()),
-        };
-        let pt_znx = self.decrypt_with_prec(ct, prec, scratch).unwrap();
-
-        self.decode_pt_znx(&pt_znx)
+        self.decrypt_decode_with_encoder(&self.encoder, ct, scratch)
     }
 
     /// Decrypts `ct` into a plaintext buffer allocated with the caller-provided
@@ -487,7 +499,7 @@ impl<BE: TestBackend, F: TestScalar> TestContext<BE, F> {
         ct: &CKKSCiphertext<impl DataRef>,
         prec: CKKSMeta,
         scratch: &mut Scratch<BE>,
-    ) -> anyhow::Result<CKKSPlaintextZnx<Vec<u8>>>
+    ) -> anyhow::Result<CKKSPlaintextZnx<AlignedBuf>>
     where
         Module<BE>: CKKSDecrypt<BE>,
         Scratch<BE>: ScratchTakeCore<BE>,
@@ -513,17 +525,43 @@ impl<BE: TestBackend, F: TestScalar> TestContext<BE, F> {
     /// - this helper unwraps internal conversion/decoder results and therefore
     ///   panics instead of returning an error in tests
     pub fn decode_pt_znx(&self, pt_znx: &CKKSPlaintextZnx<impl DataRef>) -> (Vec<F>, Vec<F>) {
+        self.decode_pt_znx_with_encoder(&self.encoder, pt_znx)
+    }
+
+    /// Decodes a CKKS ZNX plaintext into the `encoder.slots()` slot vectors
+    /// of `encoder`, which may use sparse packing.
+    pub fn decode_pt_znx_with_encoder(&self, encoder: &Encoder<F>, pt_znx: &CKKSPlaintextZnx<impl DataRef>) -> (Vec<F>, Vec<F>) {
         let mut pt_rnx = CKKSPlaintextRnx::alloc(self.params.n).unwrap();
         pt_rnx.decode_from_znx(pt_znx).unwrap();
 
-        let m = self.params.n / 2;
+        let m = encoder.slots();
         let mut re = vec![F::zero(); m];
         let mut im = vec![F::zero(); m];
-        self.encoder.decode_reim(&pt_rnx, &mut re, &mut im).unwrap();
+        encoder.decode_reim(&pt_rnx, &mut re, &mut im).unwrap();
 
         (re, im)
     }
 
+    /// Decrypts `ct` and decodes it with `encoder`, which may use sparse packing.
+    pub fn decrypt_decode_with_encoder(
+        &self,
+        encoder: &Encoder<F>,
+        ct: &CKKSCiphertext<impl DataRef>,
+        scratch: &mut Scratch<BE>,
+    ) -> (Vec<F>, Vec<F>)
+    where
+        Module<BE>: CKKSDecrypt<BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        let prec = CKKSMeta {
+            log_delta: ct.log_delta(),
+            log_budget: ct.log_budget().min(self.params.prec.log_budget()),
+        };
+        let pt_znx = self.decrypt_with_prec(ct, prec, scratch).unwrap();
+
+        self.decode_pt_znx_with_encoder(encoder, &pt_znx)
+    }
+
     /// Allocates enough scratch for encrypt + decrypt.
     pub fn alloc_scratch(&self) -> ScratchOwned<BE>
     where
@@ -657,7 +695,7 @@ impl<BE: TestBackend, F: TestScalar> TestContext<BE, F> {
     }
 
     /// Allocates a ciphertext with one fewer limb than the default (k − base2k).
-    pub fn alloc_ct(&self, k: usize) -> CKKSCiphertext<Vec<u8>> {
+    pub fn alloc_ct(&self, k: usize) -> CKKSCiphertext<AlignedBuf> {
         let mut layout = self.params.glwe_layout();
         layout.layout.k = k.into();
         CKKSCiphertext::alloc_from_infos(&layout).unwrap()
@@ -671,11 +709,11 @@ impl<BE: TestBackend, F: TestScalar> TestContext<BE, F> {
     }
 
     /// Encodes (re2, im2) into a ZNX plaintext (IFFT + quantise).
-    pub fn encode_pt_znx(&self, re: &[F], im: &[F]) -> CKKSPlaintextZnx<Vec<u8>> {
+    pub fn encode_pt_znx(&self, re: &[F], im: &[F]) -> CKKSPlaintextZnx<AlignedBuf> {
         self.encode_pt_znx_with_prec(re, im, self.meta())
     }
 
-    pub fn encode_pt_znx_with_prec(&self, re: &[F], im: &[F], prec: CKKSMeta) -> CKKSPlaintextZnx<Vec<u8>> {
+    pub fn encode_pt_znx_with_prec(&self, re: &[F], im: &[F], prec: CKKSMeta) -> CKKSPlaintextZnx<AlignedBuf> {
         let pt_rnx = self.encode_pt_rnx(re, im);
         let mut pt_znx = alloc_pt_vec_znx(self.degree(), self.base2k(), prec);
         pt_rnx.to_znx(&mut pt_znx).unwrap();
@@ -102,6 +102,9 @@ pub const NTT120_PARAMS_F128: CKKSTestParams = CKKSTestParams {
     dsize: 1,
 };
 
+/// Sparse slot counts (`m < N/2`) exercised by the sparse packing tests.
+pub const SPARSE_SLOTS: &[usize] = &[1, 4, 32];
+
 #[macro_export]
 macro_rules! ckks_backend_test_suite {
     (
@@ -424,6 +427,31 @@ macro_rules! ckks_backend_test_suite {
                 rotate_assign_missing_key_error,
                 $crate::leveled::tests::test_suite::rotate::test_rotate_assign_missing_key_error
             );
+            run_test_with_arg!(
+                sparse_encrypt_decrypt,
+                $crate::leveled::tests::test_suite::sparse::test_sparse_encrypt_decrypt,
+                $crate::leveled::tests::test_suite::SPARSE_SLOTS
+            );
+            run_test_with_arg!(
+                sparse_add,
+                $crate::leveled::tests::test_suite::sparse::test_sparse_add,
+                $crate::leveled::tests::test_suite::SPARSE_SLOTS
+            );
+            run_test_with_arg!(
+                sparse_mul,
+                $crate::leveled::tests::test_suite::sparse::test_sparse_mul,
+                $crate::leveled::tests::test_suite::SPARSE_SLOTS
+            );
+            run_test_with_arg!(
+                sparse_rotate,
+                $crate::leveled::tests::test_suite::sparse::test_sparse_rotate,
+                $crate::leveled::tests::test_suite::SPARSE_SLOTS
+            );
+            run_test_with_arg!(
+                sparse_conjugate,
+                $crate::leveled::tests::test_suite::sparse::test_sparse_conjugate,
+                $crate::leveled::tests::test_suite::SPARSE_SLOTS
+            );
             run_test!(
                 mul_ct_aligned,
                 $crate::leveled::tests::test_suite::mul::test_mul_ct_aligned
@@ -746,5 +774,6 @@ pub mod mul_pow2;
 pub mod mul_sub;
 pub mod neg;
 pub mod rotate;
+pub mod sparse;
 pub mod sub;
 pub mod sub_unsafe;
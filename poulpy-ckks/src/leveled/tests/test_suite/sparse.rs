@@ -0,0 +1,120 @@
+//! Sparse slot packing tests (`m < N/2` slots in the subring `X^{N/2m}`).
+//!
+//! # Test inventory
+//!
+//! | Function | Path exercised |
+//! |----------|----------------|
+//! | [`test_sparse_encrypt_decrypt`] | sparse encode, encrypt, decrypt, decode roundtrip |
+//! | [`test_sparse_add`] | ct + ct on sparse slots |
+//! | [`test_sparse_mul`] | ct x ct with relinearization on sparse slots |
+//! | [`test_sparse_rotate`] | rotations by `k` act as rotations by `k mod m` |
+//! | [`test_sparse_conjugate`] | conjugation is slot-wise on sparse slots |
+
+use super::helpers::{TestAddBackend, TestCiphertextBackend, TestContext, TestMulBackend, TestRotateBackend, TestScalar};
+use crate::{
+    CKKSInfos,
+    encoding::Encoder,
+    leveled::api::{CKKSAddOps, CKKSConjugateOps, CKKSMulOps, CKKSRotateOps},
+};
+use poulpy_hal::api::ScratchOwnedBorrow;
+
+/// Returns the encoder and the first `m` slots of both test vectors.
+#[allow(clippy::type_complexity)]
+fn sparse_inputs<BE: TestCiphertextBackend, F: TestScalar>(
+    ctx: &TestContext<BE, F>,
+    m: usize,
+) -> (Encoder<F>, Vec<F>, Vec<F>, Vec<F>, Vec<F>) {
+    (
+        Encoder::new(m).unwrap(),
+        ctx.re1[..m].to_vec(),
+        ctx.im1[..m].to_vec(),
+        ctx.re2[..m].to_vec(),
+        ctx.im2[..m].to_vec(),
+    )
+}
+
+pub fn test_sparse_encrypt_decrypt<BE: TestCiphertextBackend, F: TestScalar>(ctx: &TestContext<BE, F>, slots: &[usize]) {
+    let mut scratch = ctx.alloc_scratch();
+    for &m in slots {
+        let (encoder, re1, im1, _, _) = sparse_inputs(ctx, m);
+        let ct = ctx.encrypt_with_encoder(&encoder, ctx.max_k(), &re1, &im1, ctx.meta(), scratch.borrow());
+        let (re, im) = ctx.decrypt_decode_with_encoder(&encoder, &ct, scratch.borrow());
+        ctx.assert_precision_for_log_delta(&format!("sparse({m}) encrypt_decrypt re"), &re, &re1, ct.log_delta());
+        ctx.assert_precision_for_log_delta(&format!("sparse({m}) encrypt_decrypt im"), &im, &im1, ct.log_delta());
+    }
+}
+
+pub fn test_sparse_add<BE: TestAddBackend, F: TestScalar>(ctx: &TestContext<BE, F>, slots: &[usize]) {
+    let mut scratch = ctx.alloc_scratch();
+    for &m in slots {
+        let (encoder, re1, im1, re2, im2) = sparse_inputs(ctx, m);
+        let ct1 = ctx.encrypt_with_encoder(&encoder, ctx.max_k(), &re1, &im1, ctx.meta(), scratch.borrow());
+        let ct2 = ctx.encrypt_with_encoder(&encoder, ctx.max_k(), &re2, &im2, ctx.meta(), scratch.borrow());
+        let mut ct_res = ctx.alloc_ct(ctx.max_k());
+        ctx.module.ckks_add_into(&mut ct_res, &ct1, &ct2, scratch.borrow()).unwrap();
+
+        let (want_re, want_im) = ctx.want_add_from(&re1, &im1, &re2, &im2);
+        let (re, im) = ctx.decrypt_decode_with_encoder(&encoder, &ct_res, scratch.borrow());
+        ctx.assert_precision_for_log_delta(&format!("sparse({m}) add re"), &re, &want_re, ct_res.log_delta());
+        ctx.assert_precision_for_log_delta(&format!("sparse({m}) add im"), &im, &want_im, ct_res.log_delta());
+    }
+}
+
+pub fn test_sparse_mul<BE: TestMulBackend, F: TestScalar>(ctx: &TestContext<BE, F>, slots: &[usize]) {
+    let mut scratch = ctx.alloc_scratch();
+    for &m in slots {
+        let (encoder, re1, im1, re2, im2) = sparse_inputs(ctx, m);
+        let ct1 = ctx.encrypt_with_encoder(&encoder, ctx.max_k(), &re1, &im1, ctx.meta(), scratch.borrow());
+        let ct2 = ctx.encrypt_with_encoder(&encoder, ctx.max_k(), &re2, &im2, ctx.meta(), scratch.borrow());
+        let mut ct_res = ctx.alloc_ct(ctx.max_k());
+        ctx.module
+            .ckks_mul_into(&mut ct_res, &ct1, &ct2, ctx.tsk(), scratch.borrow())
+            .unwrap();
+
+        let want_re: Vec<F> = (0..m).map(|i| re1[i] * re2[i] - im1[i] * im2[i]).collect();
+        let want_im: Vec<F> = (0..m).map(|i| re1[i] * im2[i] + re2[i] * im1[i]).collect();
+        let (re, im) = ctx.decrypt_decode_with_encoder(&encoder, &ct_res, scratch.borrow());
+        ctx.assert_precision_for_log_delta(&format!("sparse({m}) mul re"), &re, &want_re, ct_res.log_delta());
+        ctx.assert_precision_for_log_delta(&format!("sparse({m}) mul im"), &im, &want_im, ct_res.log_delta());
+    }
+}
+
+pub fn test_sparse_rotate<BE: TestRotateBackend, F: TestScalar>(ctx: &TestContext<BE, F>, slots: &[usize]) {
+    let mut scratch = ctx.alloc_scratch();
+    let mut rotations: Vec<i64> = ctx.atks().keys().copied().filter(|&k| k > 0).collect();
+    rotations.sort();
+    for &m in slots {
+        let (encoder, re1, im1, _, _) = sparse_inputs(ctx, m);
+        let ct = ctx.encrypt_with_encoder(&encoder, ctx.max_k(), &re1, &im1, ctx.meta(), scratch.borrow());
+        for &r in &rotations {
+            let mut ct_res = ctx.alloc_ct(ctx.max_k());
+            ctx.module
+                .ckks_rotate_into(&mut ct_res, &ct, r, ctx.atks(), scratch.borrow())
+                .unwrap();
+
+            let shift = |j: usize| (j as i64 + r).rem_euclid(m as i64) as usize;
+            let want_re: Vec<F> = (0..m).map(|j| re1[shift(j)]).collect();
+            let want_im: Vec<F> = (0..m).map(|j| im1[shift(j)]).collect();
+            let (re, im) = ctx.decrypt_decode_with_encoder(&encoder, &ct_res, scratch.borrow());
+            ctx.assert_precision_for_log_delta(&format!("sparse({m}) rotate({r}) re"), &re, &want_re, ct_res.log_delta());
+            ctx.assert_precision_for_log_delta(&format!("sparse({m}) rotate({r}) im"), &im, &want_im, ct_res.log_delta());
+        }
+    }
+}
+
+pub fn test_sparse_conjugate<BE: TestRotateBackend, F: TestScalar>(ctx: &TestContext<BE, F>, slots: &[usize]) {
+    let mut scratch = ctx.alloc_scratch();
+    for &m in slots {
+        let (encoder, re1, im1, _, _) = sparse_inputs(ctx, m);
+        let ct = ctx.encrypt_with_encoder(&encoder, ctx.max_k(), &re1, &im1, ctx.meta(), scratch.borrow());
+        let mut ct_res = ctx.alloc_ct(ctx.max_k());
+        ctx.module
+            .ckks_conjugate_into(&mut ct_res, &ct, ctx.atk(-1), scratch.borrow())
+            .unwrap();
+
+        let want_im: Vec<F> = im1.iter().map(|&x| -x).collect();
+        let (re, im) = ctx.decrypt_decode_with_encoder(&encoder, &ct_res, scratch.borrow());
+        ctx.assert_precision_for_log_delta(&format!("sparse({m}) conjugate re"), &re, &re1, ct_res.log_delta());
+        ctx.assert_precision_for_log_delta(&format!("sparse({m}) conjugate im"), &im, &want_im, ct_res.log_delta());
+    }
+}
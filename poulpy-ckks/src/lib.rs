@@ -35,6 +35,7 @@
 //! | [`encoding`] | CKKS encoders/decoders, including slot-wise real/imaginary packing |
 //! | [`layouts`] | CKKS ciphertext/plaintext wrappers and metadata-aware allocation helpers |
 //! | [`leveled`] | Leveled arithmetic (add, sub, mul, neg, rotate, conjugate), encryption, decryption, and rescale |
+//! | [`params`] | Curated, security-annotated parameter sets |
 //! | bootstrapping | Planned CKKS bootstrapping |
 
 use poulpy_core::layouts::{Base2K, TorusPrecision};
@@ -44,6 +45,7 @@ mod error;
 pub mod layouts;
 pub mod leveled;
 pub mod oep;
+pub mod params;
 pub use error::CKKSCompositionError;
 pub(crate) use error::{
     checked_log_budget_sub, checked_mul_ct_log_budget, checked_mul_pt_log_budget, ensure_base2k_match, ensure_plaintext_alignment,
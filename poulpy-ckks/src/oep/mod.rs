@@ -27,6 +27,23 @@ unsafe impl CKKSImpl<poulpy_cpu_ref::NTT120Ref> for poulpy_cpu_ref::NTT120Ref {
     crate::impl_ckks_default_methods!(poulpy_cpu_ref::NTT120Ref);
 }
 
+unsafe impl<Family: poulpy_hal::dyn_backend::DynBackendFamily> CKKSImpl<poulpy_hal::dyn_backend::DynBackend<Family>>
+    for poulpy_hal::dyn_backend::DynBackend<Family>
+{
+    crate::impl_ckks_default_methods!(poulpy_hal::dyn_backend::DynBackend<Family>);
+}
+
+unsafe impl<BE: poulpy_cpu_mt::FFT64MtBase> CKKSImpl<poulpy_cpu_mt::FFT64Mt<BE>> for poulpy_cpu_mt::FFT64Mt<BE> {
+    crate::impl_ckks_default_methods!(poulpy_cpu_mt::FFT64Mt<BE>);
+}
+
+unsafe impl<BE> CKKSImpl<poulpy_hal::profiling::Profiled<BE>> for poulpy_hal::profiling::Profiled<BE>
+where
+    BE: poulpy_hal::layouts::Backend + poulpy_hal::oep::HalImpl<BE> + 'static,
+{
+    crate::impl_ckks_default_methods!(poulpy_hal::profiling::Profiled<BE>);
+}
+
 #[cfg(feature = "enable-avx")]
 unsafe impl CKKSImpl<poulpy_cpu_avx::FFT64Avx> for poulpy_cpu_avx::FFT64Avx {
     crate::impl_ckks_default_methods!(poulpy_cpu_avx::FFT64Avx);
@@ -36,3 +53,8 @@ unsafe impl CKKSImpl<poulpy_cpu_avx::FFT64Avx> for poulpy_cpu_avx::FFT64Avx {
 unsafe impl CKKSImpl<poulpy_cpu_avx::NTT120Avx> for poulpy_cpu_avx::NTT120Avx {
     crate::impl_ckks_default_methods!(poulpy_cpu_avx::NTT120Avx);
 }
+
+#[cfg(feature = "enable-simd")]
+unsafe impl CKKSImpl<poulpy_cpu_simd::FFT64Simd> for poulpy_cpu_simd::FFT64Simd {
+    crate::impl_ckks_default_methods!(poulpy_cpu_simd::FFT64Simd);
+}
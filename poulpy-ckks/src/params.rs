@@ -0,0 +1,342 @@
+//! Curated, named CKKS parameter sets.
+//!
+//! Each preset fixes the ring degree, the limb size, the ciphertext precision
+//! and the tensor-key gadget, together with the scaling factor (`log_delta`)
+//! and the number of ciphertext-ciphertext multiplications (`depth`) the
+//! ciphertext precision was sized for. Presets are annotated with:
+//!
+//! - `security`: the estimated bit-security of the tensor key, which has the
+//!   largest modulus of all the public material, as computed by
+//!   [`poulpy_core::SecurityParameters`];
+//! - `log2_fresh_error`: `log2` of the standard deviation of the slot error of
+//!   a fresh encryption;
+//! - `log2_final_error`: `log2` of the standard deviation of the slot error
+//!   after `depth` multiplications, including the tensoring and
+//!   relinearization noise, as computed by [`poulpy_core::NoiseModel`].
+//!
+//! Annotations are bounds: [`CKKSParameters::check`] recomputes the three
+//! quantities and verifies them, together with the consistency of the layouts
+//! and that `depth` multiplications at `log_delta` fit in the ciphertext.
+//!
+//! | Preset | `N` | `k` | `log_delta` | `depth` | Security |
+//! |--------|-----|-----|-------------|---------|----------|
+//! | [`CKKS_4096_DEMO`] | 4096 | 95 | 30 | 2 | **insecure**, for demos only |
+//! | [`CKKS_8192_128`] | 8192 | 156 | 36 | 3 | 128 bits |
+//! | [`CKKS_16384_128`] | 16384 | 378 | 40 | 8 | 128 bits |
+//!
+//! All presets use a ternary secret of fixed Hamming weight and the default
+//! error distribution ([`DEFAULT_SIGMA_XE`]).
+
+use anyhow::{Result, ensure};
+use poulpy_core::{
+    DEFAULT_SIGMA_XE, Distribution, EncryptionLayout, NoiseModel, SecurityParameters,
+    layouts::{Base2K, Degree, Dsize, GLWELayout, GLWETensorKeyLayout, Rank, TorusPrecision},
+};
+
+/// A named CKKS parameter set.
+///
+/// See the [module documentation](crate::params) for the meaning of the
+/// annotations and the list of presets.
+#[derive(Debug, Clone, Copy)]
+pub struct CKKSParameters {
+    /// Human-readable identifier of the preset.
+    pub name: &'static str,
+    /// Ring degree; the number of complex slots is `n / 2`.
+    pub n: Degree,
+    /// Limb size of ciphertexts and keys.
+    pub base2k: Base2K,
+    /// Torus precision of fresh ciphertexts.
+    pub k: TorusPrecision,
+    /// Digit size of the tensor-key gadget.
+    pub dsize: Dsize,
+    /// Base-2 logarithm of the scaling factor the preset is sized for.
+    pub log_delta: usize,
+    /// Number of ciphertext-ciphertext multiplications supported at `log_delta`.
+    pub depth: usize,
+    /// Hamming weight of the ternary secret.
+    pub hw: usize,
+    /// Annotated security level, in bits.
+    pub security: f64,
+    /// Annotated `log2` standard deviation of the slot error of a fresh encryption.
+    pub log2_fresh_error: f64,
+    /// Annotated `log2` standard deviation of the slot error after `depth`
+    /// multiplications.
+    pub log2_final_error: f64,
+}
+
+impl CKKSParameters {
+    /// Returns the number of complex slots.
+    pub fn slots(&self) -> usize {
+        self.n.as_usize() / 2
+    }
+
+    /// Returns the distribution of the secret.
+    pub fn secret_dist(&self) -> Distribution {
+        Distribution::TernaryFixed(self.hw)
+    }
+
+    /// Returns the layout of fresh ciphertexts.
+    pub fn glwe_layout(&self) -> GLWELayout {
+        GLWELayout {
+            n: self.n,
+            base2k: self.base2k,
+            k: self.k,
+            rank: Rank(1),
+        }
+    }
+
+    /// Returns the layout of the tensor key used by ciphertext-ciphertext
+    /// multiplication.
+    ///
+    /// The tensor key is sized one decomposition block above the ciphertext
+    /// precision so that relinearization has enough headroom.
+    pub fn tsk_layout(&self) -> GLWETensorKeyLayout {
+        let block: usize = self.dsize.as_usize() * self.base2k.as_usize();
+        GLWETensorKeyLayout {
+            n: self.n,
+            base2k: self.base2k,
+            k: (self.k.as_usize() + block).into(),
+            rank: Rank(1),
+            dsize: self.dsize,
+            dnum: self.k.as_usize().div_ceil(block).into(),
+        }
+    }
+
+    /// Returns the ciphertext layout with the default encryption noise.
+    pub fn glwe_encryption_layout(&self) -> Result<EncryptionLayout<GLWELayout>> {
+        EncryptionLayout::new_from_default_sigma(self.glwe_layout())
+    }
+
+    /// Returns the tensor-key layout with the default encryption noise.
+    pub fn tsk_encryption_layout(&self) -> Result<EncryptionLayout<GLWETensorKeyLayout>> {
+        EncryptionLayout::new_from_default_sigma(self.tsk_layout())
+    }
+
+    /// Returns the `log_budget` of a fresh encryption at `log_delta`.
+    pub fn fresh_log_budget(&self) -> usize {
+        self.k.as_usize() - self.log_delta
+    }
+
+    /// Returns the `log_budget` left after `depth` multiplications, which
+    /// bounds the integer part of the decrypted slots.
+    pub const fn final_log_budget(&self) -> usize {
+        self.k.0 as usize - (self.depth + 1) * self.log_delta
+    }
+
+    /// Estimates the security, in bits, of the tensor key, which is the
+    /// instance with the largest modulus.
+    pub fn estimate_security(&self) -> f64 {
+        SecurityParameters::new(
+            self.n.as_usize(),
+            self.tsk_layout().k.as_usize(),
+            self.secret_dist(),
+            DEFAULT_SIGMA_XE,
+        )
+        .security_level()
+    }
+
+    /// Predicts `log2` of the standard deviation of the slot error of a fresh
+    /// encryption, i.e. the encryption noise amplified by the canonical
+    /// embedding and divided by the scaling factor.
+    pub fn estimate_log2_fresh_error(&self) -> f64 {
+        (DEFAULT_SIGMA_XE * (self.n.as_usize() as f64).sqrt()).log2() - self.log_delta as f64
+    }
+
+    /// Predicts `log2` of the standard deviation of the slot error after
+    /// `depth` multiplications of ciphertexts with unit-magnitude slots.
+    ///
+    /// Every multiplication accounts for the rounding of the operands to
+    /// their effective precision, the tensor product of their errors with the
+    /// messages and with each other, and the relinearization. The operands
+    /// are rounded to `2^-effective_k` and the product is shifted by
+    /// `effective_k`, so the integer parts of their phases only contribute
+    /// integers and vanish.
+    pub fn estimate_log2_final_error(&self) -> f64 {
+        let n: f64 = self.n.as_usize() as f64;
+        let k: usize = self.k.as_usize();
+        let glwe: GLWELayout = self.glwe_layout();
+        let tsk: GLWETensorKeyLayout = self.tsk_layout();
+        let model: NoiseModel = NoiseModel::new(
+            self.secret_dist().density(self.n.as_usize()),
+            DEFAULT_SIGMA_XE * DEFAULT_SIGMA_XE,
+        );
+
+        let mut eff_k: usize = k;
+        let mut var: f64 = DEFAULT_SIGMA_XE * DEFAULT_SIGMA_XE * (-2.0 * k as f64).exp2();
+        for _ in 0..self.depth {
+            if eff_k < k {
+                var += model.glwe_truncate(0.0, &glwe, eff_k);
+            }
+            let msg: f64 = (2.0 * (self.log_delta as f64 - eff_k as f64)).exp2() / n;
+            let tensor: f64 = n * (2.0 * msg * var + n * var * var) * (2.0 * eff_k as f64).exp2();
+            var = model.glwe_tensor_relinearize(tensor + model.glwe_rounding(&glwe), &glwe, &tsk);
+            eff_k -= self.log_delta;
+        }
+
+        0.5 * (var * n).log2() + eff_k as f64 - self.log_delta as f64
+    }
+
+    /// Checks the consistency of the parameters and that the annotated
+    /// security and errors are met.
+    pub fn check(&self) -> Result<()> {
+        let name: &str = self.name;
+        let n: usize = self.n.as_usize();
+
+        ensure!(n.is_power_of_two() && n >= 2, "{name}: degree {n} is not a power of two");
+        ensure!(self.base2k.as_usize() > 0, "{name}: base2k must be non-zero");
+        ensure!(self.dsize.as_usize() > 0, "{name}: dsize must be non-zero");
+        ensure!(self.log_delta > 0, "{name}: log_delta must be non-zero");
+        ensure!(
+            self.hw > 0 && self.hw <= n,
+            "{name}: hamming weight {} must be in [1, {n}]",
+            self.hw
+        );
+        ensure!(
+            (self.depth + 1) * self.log_delta <= self.k.as_usize(),
+            "{name}: k ({}) cannot hold log_delta ({}) through {} multiplications",
+            self.k,
+            self.log_delta,
+            self.depth
+        );
+
+        self.glwe_encryption_layout()?;
+        self.tsk_encryption_layout()?;
+
+        let security: f64 = self.estimate_security();
+        ensure!(
+            security >= self.security,
+            "{name}: estimated security {security:.1} bits is below the annotated {} bits",
+            self.security
+        );
+
+        let log2_fresh_error: f64 = self.estimate_log2_fresh_error();
+        ensure!(
+            log2_fresh_error <= self.log2_fresh_error,
+            "{name}: predicted fresh error 2^{log2_fresh_error:.1} exceeds the annotated 2^{}",
+            self.log2_fresh_error
+        );
+
+        let log2_final_error: f64 = self.estimate_log2_final_error();
+        ensure!(
+            log2_final_error <= self.log2_final_error,
+            "{name}: predicted error 2^{log2_final_error:.1} after {} multiplications exceeds the annotated 2^{}",
+            self.depth,
+            self.log2_final_error
+        );
+
+        Ok(())
+    }
+}
+
+/// Small parameter set used by the `poly2` example.
+///
+/// **Not secure**: the tensor key sits at a 147-bit modulus over `N = 4096`,
+/// which is estimated at about 91 bits of security.
+pub const CKKS_4096_DEMO: CKKSParameters = CKKSParameters {
+    name: "CKKS_4096_DEMO",
+    n: Degree(4096),
+    base2k: Base2K(52),
+    k: TorusPrecision(95),
+    dsize: Dsize(1),
+    log_delta: 30,
+    depth: 2,
+    hw: 192,
+    security: 90.0,
+    log2_fresh_error: -22.0,
+    log2_final_error: -20.0,
+};
+
+/// 128-bit CKKS over `N = 8192` with a 36-bit scaling factor and three
+/// multiplications.
+pub const CKKS_8192_128: CKKSParameters = CKKSParameters {
+    name: "CKKS_8192_128",
+    n: Degree(8192),
+    base2k: Base2K(52),
+    k: TorusPrecision(156),
+    dsize: Dsize(1),
+    log_delta: 36,
+    depth: 3,
+    hw: 192,
+    security: 128.0,
+    log2_fresh_error: -27.0,
+    log2_final_error: -25.0,
+};
+
+/// 128-bit CKKS over `N = 16384` with a 40-bit scaling factor and eight
+/// multiplications.
+pub const CKKS_16384_128: CKKSParameters = CKKSParameters {
+    name: "CKKS_16384_128",
+    n: Degree(16384),
+    base2k: Base2K(54),
+    k: TorusPrecision(378),
+    dsize: Dsize(1),
+    log_delta: 40,
+    depth: 8,
+    hw: 192,
+    security: 128.0,
+    log2_fresh_error: -31.0,
+    log2_final_error: -28.0,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_are_consistent() {
+        for params in [&CKKS_4096_DEMO, &CKKS_8192_128, &CKKS_16384_128] {
+            params.check().unwrap();
+        }
+    }
+
+    /// Asserts that the estimators meet the annotations of `params`.
+    fn assert_estimates(params: &CKKSParameters) {
+        let security: f64 = params.estimate_security();
+        assert!(security >= params.security, "{}: security {security}", params.name);
+        let fresh: f64 = params.estimate_log2_fresh_error();
+        assert!(fresh <= params.log2_fresh_error, "{}: fresh error 2^{fresh}", params.name);
+        let last: f64 = params.estimate_log2_final_error();
+        assert!(last <= params.log2_final_error, "{}: final error 2^{last}", params.name);
+        assert!(
+            fresh < last,
+            "{}: fresh error 2^{fresh} above final error 2^{last}",
+            params.name
+        );
+    }
+
+    #[test]
+    fn estimates_ckks_4096_demo() {
+        assert_estimates(&CKKS_4096_DEMO);
+    }
+
+    #[test]
+    fn estimates_ckks_8192_128() {
+        assert_estimates(&CKKS_8192_128);
+    }
+
+    #[test]
+    fn estimates_ckks_16384_128() {
+        assert_estimates(&CKKS_16384_128);
+    }
+
+    #[test]
+    fn check_rejects_understated_final_error() {
+        let mut params: CKKSParameters = CKKS_16384_128;
+        params.log2_final_error = params.log2_fresh_error;
+        assert!(params.check().is_err());
+    }
+
+    #[test]
+    fn check_rejects_excessive_depth() {
+        let mut params: CKKSParameters = CKKS_8192_128;
+        params.depth = 4;
+        assert!(params.check().is_err());
+    }
+
+    #[test]
+    fn check_rejects_overstated_security() {
+        let mut params: CKKSParameters = CKKS_4096_DEMO;
+        params.security = 128.0;
+        assert!(params.check().is_err());
+    }
+}
@@ -10,8 +10,8 @@ use crate::{
     layouts::{
         GGLWEInfos, GGLWEToGGSWKeyCompressedToMut, GGLWEToGGSWKeyToMut, GGLWEToMut, GGSWCompressedSeedMut, GGSWCompressedToMut,
         GGSWInfos, GGSWToMut, GLWECompressedSeedMut, GLWECompressedToMut, GLWEInfos, GLWEPlaintextToRef, GLWEPreparedToRef,
-        GLWESecretPreparedToRef, GLWESecretToRef, GLWESwitchingKeyDegreesMut, GLWEToMut, LWEInfos, LWEPlaintextToRef,
-        LWESecretToRef, LWEToMut, SetGaloisElement, TorusPrecision,
+        GLWESecretPreparedToRef, GLWESecretToRef, GLWESwitchingKeyDegreesMut, GLWEToMut, GLWEToRef, LWEInfos, LWEPlaintextToRef,
+        LWESecretToRef, LWEToMut, LWEToRef, SetGaloisElement, TorusPrecision,
         compressed::{GGLWECompressedSeedMut, GGLWECompressedToMut},
     },
 };
@@ -22,6 +22,15 @@ pub trait DeclaredK: LWEInfos {
 
 pub trait EncryptionInfos {
     fn noise_infos(&self) -> NoiseInfos;
+
+    /// Minimum estimated bit-security that secret-key encryption must reach.
+    ///
+    /// When `Some`, the secret-key encryption routines estimate the security of
+    /// the produced (G)LWE instance with [`crate::SecurityParameters`] and panic
+    /// if it falls below the given level. Defaults to `None` (no check).
+    fn min_security(&self) -> Option<f64> {
+        None
+    }
 }
 
 pub trait LWEEncryptSk<BE: Backend> {
@@ -114,6 +123,96 @@ pub trait GLWEEncryptPk<BE: Backend> {
         K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos;
 }
 
+/// Sanitizes a GLWE ciphertext (circuit privacy): re-randomizes its mask with
+/// a fresh public-key encryption of zero and floods the body with the noise
+/// described by `flood` (typically a [`NoiseFlooding`](crate::NoiseFlooding)).
+///
+/// The output encrypts the same plaintext under the secret of `pk`, with a
+/// distribution that is statistically close to being independent of the
+/// circuit that produced the input.
+pub trait GLWESanitize<BE: Backend> {
+    fn glwe_sanitize_tmp_bytes<R>(&self, res_infos: &R) -> usize
+    where
+        R: GLWEInfos;
+
+    fn glwe_sanitize<R, A, K, E, F>(
+        &self,
+        res: &mut R,
+        a: &A,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos;
+
+    fn glwe_sanitize_assign<R, K, E, F>(
+        &self,
+        res: &mut R,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos;
+}
+
+/// Sanitizes an LWE ciphertext (circuit privacy) with a sample-extracted
+/// encryption of zero under the rank-1 GLWE public key `pk`, and floods its
+/// body with the noise described by `flood`.
+///
+/// The input must be encrypted under the LWE secret obtained from the secret
+/// of `pk` by [`LWESecret::from_glwe_secret`](crate::layouts::LWESecret::from_glwe_secret),
+/// e.g. the output of a blind rotation followed by sample extraction.
+pub trait LWESanitize<BE: Backend> {
+    fn lwe_sanitize_tmp_bytes<R>(&self, res_infos: &R) -> usize
+    where
+        R: LWEInfos;
+
+    fn lwe_sanitize<R, A, K, E, F>(
+        &self,
+        res: &mut R,
+        a: &A,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: LWEToMut,
+        A: LWEToRef,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos;
+
+    fn lwe_sanitize_assign<R, K, E, F>(
+        &self,
+        res: &mut R,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: LWEToMut,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos;
+}
+
 pub trait GLWEPublicKeyGenerate<BE: Backend> {
     fn glwe_public_key_generate<R, S, E>(
         &self,
@@ -170,6 +269,30 @@ pub trait GGSWEncryptSk<BE: Backend> {
         S: GLWESecretPreparedToRef<BE>;
 }
 
+/// Encrypts a [`GGSW`](crate::layouts::GGSW) under a prepared
+/// [`GLWEPublicKey`](crate::layouts::GLWEPublicKey), so that the secret is
+/// not needed. Each row is a GLWE public-key encryption and carries its noise.
+pub trait GGSWEncryptPk<BE: Backend> {
+    fn ggsw_encrypt_pk_tmp_bytes<A>(&self, infos: &A) -> usize
+    where
+        A: GGSWInfos;
+
+    fn ggsw_encrypt_pk<R, P, K, E>(
+        &self,
+        res: &mut R,
+        pt: &P,
+        pk: &K,
+        enc_infos: &E,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GGSWToMut,
+        P: ScalarZnxToRef,
+        E: EncryptionInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos;
+}
+
 pub trait GGLWEToGGSWKeyEncryptSk<BE: Backend> {
     fn gglwe_to_ggsw_key_encrypt_sk_tmp_bytes<A>(&self, infos: &A) -> usize
     where
@@ -210,6 +333,33 @@ pub trait GLWESwitchingKeyEncryptSk<BE: Backend> {
         S2: GLWESecretToRef;
 }
 
+/// Generates a [`GLWERingSwitchKey`](crate::layouts::GLWERingSwitchKey).
+///
+/// The module has the larger degree `N`; one of `sk_in` / `sk_out` has degree
+/// `N` and the other degree `N/2`. A key from `N` to `N/2` is used by
+/// [`GLWERingSwitch`](crate::GLWERingSwitch) to move ciphertexts down, and a
+/// key from `N/2` to `N` to move them up.
+pub trait GLWERingSwitchKeyEncryptSk<BE: Backend> {
+    fn glwe_ring_switch_key_encrypt_sk_tmp_bytes<A>(&self, infos: &A) -> usize
+    where
+        A: GGLWEInfos;
+
+    fn glwe_ring_switch_key_encrypt_sk<R, S1, S2, E>(
+        &self,
+        res: &mut R,
+        sk_in: &S1,
+        sk_out: &S2,
+        enc_infos: &E,
+        source_xe: &mut Source,
+        source_xa: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GGLWEToMut + GLWESwitchingKeyDegreesMut + GGLWEInfos,
+        E: EncryptionInfos,
+        S1: GLWESecretToRef,
+        S2: GLWESecretToRef;
+}
+
 pub trait GLWESwitchingKeyEncryptPk<BE: Backend> {
     fn glwe_switching_key_encrypt_pk_tmp_bytes<A>(&self, infos: &A) -> usize
     where
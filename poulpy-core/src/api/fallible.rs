@@ -0,0 +1,502 @@
+//! Fallible variants of the core operations.
+//!
+//! Each `try_*` method checks the ring degrees, ranks, `base2k`, digit sizes
+//! and scratch size that the matching operation asserts on (and, for
+//! encryptions, that the secret was prepared and reaches
+//! [`EncryptionInfos::min_security`]), and returns a
+//! [`CoreError`](crate::CoreError) describing the first mismatch instead of
+//! panicking. When all checks pass it forwards to the operation. The traits
+//! are implemented for every type implementing the underlying operation trait.
+
+#![allow(clippy::too_many_arguments)]
+
+use poulpy_hal::{
+    api::{ModuleN, ScratchAvailable},
+    layouts::{Backend, ScalarZnx, ScalarZnxToRef, Scratch, ZnxInfos},
+    source::Source,
+};
+
+use crate::{
+    CoreError, GetDistribution, ScratchTakeCore, SecurityParameters,
+    api::{
+        EncryptionInfos, GGSWAutomorphism, GGSWEncryptPk, GGSWEncryptSk, GGSWFromGGLWE, GLWEAutomorphism, GLWEEncryptPk,
+        GLWEEncryptSk, GLWEExternalProduct, GLWEFromLWE, GLWEKeyswitch, LWEFromGLWE,
+    },
+    dist::Distribution,
+    error::{Result, ensure_base2k, ensure_dimension, ensure_dimension_at_most, ensure_dsize, ensure_rank, ensure_scratch},
+    layouts::{
+        GGLWEInfos, GGLWEPreparedToRef, GGLWEToGGSWKeyPreparedToRef, GGLWEToRef, GGSWInfos, GGSWPreparedToRef, GGSWToMut,
+        GGSWToRef, GLWEInfos, GLWEPlaintext, GLWEPlaintextToRef, GLWEPreparedToRef, GLWESecretPrepared, GLWESecretPreparedToRef,
+        GLWEToMut, GLWEToRef, GetGaloisElement, LWEInfos, LWEToMut, LWEToRef,
+    },
+    security::ensure_min_security,
+};
+
+/// Checks that `dist` is the distribution of a prepared secret.
+fn ensure_prepared(op: &'static str, what: &'static str, dist: &Distribution) -> Result<()> {
+    if *dist == Distribution::NONE {
+        return Err(CoreError::UnpreparedSecret { op, what });
+    }
+    Ok(())
+}
+
+/// Checks that secret-key encryption under `sk` of degree `n` reaches the
+/// security required by `enc_infos`, as asserted by the encryption routines.
+fn ensure_sk_encryption<BE: Backend, E: EncryptionInfos>(
+    op: &'static str,
+    n: usize,
+    sk: &GLWESecretPrepared<&[u8], BE>,
+    enc_infos: &E,
+) -> Result<()> {
+    ensure_prepared(op, "sk", &sk.dist)?;
+    if let Some(min_security) = enc_infos.min_security() {
+        let noise = enc_infos.noise_infos();
+        let params: SecurityParameters = SecurityParameters::new(n * sk.rank().as_usize(), noise.k, sk.dist, noise.sigma);
+        ensure_min_security(op, &params, min_security)?;
+    }
+    Ok(())
+}
+
+pub trait GLWEEncryptSkFallible<BE: Backend>
+where
+    Self: GLWEEncryptSk<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable,
+{
+    /// [`GLWEEncryptSk::glwe_encrypt_sk`] returning a [`CoreError`](crate::CoreError) on invalid inputs.
+    fn try_glwe_encrypt_sk<R, P, S, E>(
+        &self,
+        res: &mut R,
+        pt: &P,
+        sk: &S,
+        enc_infos: &E,
+        source_xe: &mut Source,
+        source_xa: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        R: GLWEToMut + GLWEInfos,
+        P: GLWEPlaintextToRef,
+        E: EncryptionInfos,
+        S: GLWESecretPreparedToRef<BE>,
+    {
+        let op: &str = "glwe_encrypt_sk";
+        {
+            let pt: GLWEPlaintext<&[u8]> = pt.to_ref();
+            let sk: GLWESecretPrepared<&[u8], BE> = sk.to_ref();
+            ensure_dimension(op, "res.n", self.n(), res.n().as_usize())?;
+            ensure_dimension(op, "pt.n", self.n(), pt.n().as_usize())?;
+            ensure_dimension(op, "sk.n", self.n(), sk.n().as_usize())?;
+            ensure_rank(op, "res.rank", sk.rank().as_usize(), res.rank().as_usize())?;
+            ensure_sk_encryption(op, self.n(), &sk, enc_infos)?;
+        }
+        ensure_scratch(op, self.glwe_encrypt_sk_tmp_bytes(res), scratch.available())?;
+        self.glwe_encrypt_sk(res, pt, sk, enc_infos, source_xe, source_xa, scratch);
+        Ok(())
+    }
+}
+
+impl<BE: Backend, M> GLWEEncryptSkFallible<BE> for M
+where
+    M: GLWEEncryptSk<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable,
+{
+}
+
+pub trait GLWEEncryptPkFallible<BE: Backend>
+where
+    Self: GLWEEncryptPk<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable,
+{
+    /// [`GLWEEncryptPk::glwe_encrypt_pk`] returning a [`CoreError`](crate::CoreError) on invalid inputs.
+    fn try_glwe_encrypt_pk<R, P, K, E>(
+        &self,
+        res: &mut R,
+        pt: &P,
+        pk: &K,
+        enc_infos: &E,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        R: GLWEToMut + GLWEInfos,
+        P: GLWEPlaintextToRef + GLWEInfos,
+        E: EncryptionInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+    {
+        let op: &str = "glwe_encrypt_pk";
+        ensure_dimension(op, "res.n", self.n(), res.n().as_usize())?;
+        ensure_dimension(op, "pt.n", self.n(), pt.n().as_usize())?;
+        ensure_dimension(op, "pk.n", self.n(), pk.n().as_usize())?;
+        ensure_rank(op, "res.rank", pk.rank().as_usize(), res.rank().as_usize())?;
+        ensure_base2k(op, "res.base2k", pk.base2k().as_usize(), res.base2k().as_usize())?;
+        ensure_base2k(op, "pt.base2k", pk.base2k().as_usize(), pt.base2k().as_usize())?;
+        ensure_prepared(op, "pk", pk.dist())?;
+        ensure_scratch(op, self.glwe_encrypt_pk_tmp_bytes(res), scratch.available())?;
+        self.glwe_encrypt_pk(res, pt, pk, enc_infos, source_xu, source_xe, scratch);
+        Ok(())
+    }
+}
+
+impl<BE: Backend, M> GLWEEncryptPkFallible<BE> for M
+where
+    M: GLWEEncryptPk<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable,
+{
+}
+
+pub trait GGSWEncryptSkFallible<BE: Backend>
+where
+    Self: GGSWEncryptSk<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable,
+{
+    /// [`GGSWEncryptSk::ggsw_encrypt_sk`] returning a [`CoreError`](crate::CoreError) on invalid inputs.
+    fn try_ggsw_encrypt_sk<R, P, S, E>(
+        &self,
+        res: &mut R,
+        pt: &P,
+        sk: &S,
+        enc_infos: &E,
+        source_xe: &mut Source,
+        source_xa: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        R: GGSWToMut + GGSWInfos,
+        P: ScalarZnxToRef,
+        E: EncryptionInfos,
+        S: GLWESecretPreparedToRef<BE>,
+    {
+        let op: &str = "ggsw_encrypt_sk";
+        {
+            let pt: ScalarZnx<&[u8]> = pt.to_ref();
+            let sk: GLWESecretPrepared<&[u8], BE> = sk.to_ref();
+            ensure_dimension(op, "res.n", self.n(), res.n().as_usize())?;
+            ensure_dimension(op, "pt.n", self.n(), pt.n())?;
+            ensure_dimension(op, "sk.n", self.n(), sk.n().as_usize())?;
+            ensure_rank(op, "res.rank", sk.rank().as_usize(), res.rank().as_usize())?;
+            ensure_sk_encryption(op, self.n(), &sk, enc_infos)?;
+        }
+        ensure_scratch(op, self.ggsw_encrypt_sk_tmp_bytes(res), scratch.available())?;
+        self.ggsw_encrypt_sk(res, pt, sk, enc_infos, source_xe, source_xa, scratch);
+        Ok(())
+    }
+}
+
+impl<BE: Backend, M> GGSWEncryptSkFallible<BE> for M
+where
+    M: GGSWEncryptSk<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable,
+{
+}
+
+pub trait GGSWEncryptPkFallible<BE: Backend>
+where
+    Self: GGSWEncryptPk<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable,
+{
+    /// [`GGSWEncryptPk::ggsw_encrypt_pk`] returning a [`CoreError`](crate::CoreError) on invalid inputs.
+    fn try_ggsw_encrypt_pk<R, P, K, E>(
+        &self,
+        res: &mut R,
+        pt: &P,
+        pk: &K,
+        enc_infos: &E,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) -> Result<()>
+    where
+        R: GGSWToMut + GGSWInfos,
+        P: ScalarZnxToRef,
+        E: EncryptionInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+    {
+        let op: &str = "ggsw_encrypt_pk";
+        ensure_dimension(op, "res.n", self.n(), res.n().as_usize())?;
+        ensure_dimension(op, "pt.n", self.n(), pt.to_ref().n())?;
+        ensure_dimension(op, "pk.n", self.n(), pk.n().as_usize())?;
+        ensure_rank(op, "res.rank", pk.rank().as_usize(), res.rank().as_usize())?;
+        ensure_base2k(op, "res.base2k", pk.base2k().as_usize(), res.base2k().as_usize())?;
+        ensure_prepared(op, "pk", pk.dist())?;
+        ensure_scratch(op, self.ggsw_encrypt_pk_tmp_bytes(res), scratch.available())?;
+        self.ggsw_encrypt_pk(res, pt, pk, enc_infos, source_xu, source_xe, scratch);
+        Ok(())
+    }
+}
+
+impl<BE: Backend, M> GGSWEncryptPkFallible<BE> for M
+where
+    M: GGSWEncryptPk<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable,
+{
+}
+
+/// Checks the degrees and ranks of a GLWE key-switch `a -> res` under `key`.
+fn check_glwe_keyswitch<R, A, K>(op: &'static str, n: usize, res: &R, a: &A, key: &K) -> Result<()>
+where
+    R: GLWEInfos,
+    A: GLWEInfos,
+    K: GGLWEInfos,
+{
+    ensure_dimension(op, "res.n", n, res.n().as_usize())?;
+    ensure_dimension(op, "a.n", n, a.n().as_usize())?;
+    ensure_dimension(op, "key.n", n, key.n().as_usize())?;
+    ensure_rank(op, "a.rank", key.rank_in().as_usize(), a.rank().as_usize())?;
+    ensure_rank(op, "res.rank", key.rank_out().as_usize(), res.rank().as_usize())
+}
+
+pub trait GLWEKeyswitchFallible<BE: Backend>
+where
+    Self: GLWEKeyswitch<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable,
+{
+    /// [`GLWEKeyswitch::glwe_keyswitch`] returning a [`CoreError`](crate::CoreError) on invalid inputs.
+    fn try_glwe_keyswitch<R, A, K>(&self, res: &mut R, a: &A, key: &K, scratch: &mut Scratch<BE>) -> Result<()>
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos,
+    {
+        let op: &str = "glwe_keyswitch";
+        check_glwe_keyswitch(op, self.n(), res, a, key)?;
+        ensure_scratch(op, self.glwe_keyswitch_tmp_bytes(res, a, key), scratch.available())?;
+        self.glwe_keyswitch(res, a, key, scratch);
+        Ok(())
+    }
+
+    /// [`GLWEKeyswitch::glwe_keyswitch_assign`] returning a [`CoreError`](crate::CoreError) on invalid inputs.
+    fn try_glwe_keyswitch_assign<R, K>(&self, res: &mut R, key: &K, scratch: &mut Scratch<BE>) -> Result<()>
+    where
+        R: GLWEToMut + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos,
+    {
+        let op: &str = "glwe_keyswitch_assign";
+        check_glwe_keyswitch(op, self.n(), res, res, key)?;
+        ensure_scratch(op, self.glwe_keyswitch_tmp_bytes(res, res, key), scratch.available())?;
+        self.glwe_keyswitch_assign(res, key, scratch);
+        Ok(())
+    }
+}
+
+impl<BE: Backend, M> GLWEKeyswitchFallible<BE> for M
+where
+    M: GLWEKeyswitch<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable,
+{
+}
+
+pub trait GLWEAutomorphismFallible<BE: Backend>
+where
+    Self: GLWEAutomorphism<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable,
+{
+    /// [`GLWEAutomorphism::glwe_automorphism`] returning a [`CoreError`](crate::CoreError) on invalid inputs.
+    fn try_glwe_automorphism<R, A, K>(&self, res: &mut R, a: &A, key: &K, scratch: &mut Scratch<BE>) -> Result<()>
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GetGaloisElement + GGLWEPreparedToRef<BE> + GGLWEInfos,
+    {
+        let op: &str = "glwe_automorphism";
+        check_glwe_keyswitch(op, self.n(), res, a, key)?;
+        ensure_scratch(op, self.glwe_automorphism_tmp_bytes(res, a, key), scratch.available())?;
+        self.glwe_automorphism(res, a, key, scratch);
+        Ok(())
+    }
+
+    /// [`GLWEAutomorphism::glwe_automorphism_assign`] returning a [`CoreError`](crate::CoreError) on invalid inputs.
+    fn try_glwe_automorphism_assign<R, K>(&self, res: &mut R, key: &K, scratch: &mut Scratch<BE>) -> Result<()>
+    where
+        R: GLWEToMut + GLWEInfos,
+        K: GetGaloisElement + GGLWEPreparedToRef<BE> + GGLWEInfos,
+    {
+        let op: &str = "glwe_automorphism_assign";
+        check_glwe_keyswitch(op, self.n(), res, res, key)?;
+        ensure_scratch(op, self.glwe_automorphism_tmp_bytes(res, res, key), scratch.available())?;
+        self.glwe_automorphism_assign(res, key, scratch);
+        Ok(())
+    }
+}
+
+impl<BE: Backend, M> GLWEAutomorphismFallible<BE> for M
+where
+    M: GLWEAutomorphism<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable,
+{
+}
+
+pub trait GGSWAutomorphismFallible<BE: Backend>
+where
+    Self: GGSWAutomorphism<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    /// [`GGSWAutomorphism::ggsw_automorphism`] returning a [`CoreError`](crate::CoreError) on invalid inputs.
+    fn try_ggsw_automorphism<R, A, K, T>(&self, res: &mut R, a: &A, key: &K, tsk: &T, scratch: &mut Scratch<BE>) -> Result<()>
+    where
+        R: GGSWToMut + GGSWInfos,
+        A: GGSWToRef + GGSWInfos,
+        K: GetGaloisElement + GGLWEPreparedToRef<BE> + GGLWEInfos,
+        T: GGLWEToGGSWKeyPreparedToRef<BE> + GGLWEInfos,
+    {
+        let op: &str = "ggsw_automorphism";
+        check_glwe_keyswitch(op, self.n(), res, a, key)?;
+        ensure_dimension(op, "tsk.n", self.n(), tsk.n().as_usize())?;
+        ensure_rank(op, "tsk.rank_out", res.rank().as_usize(), tsk.rank_out().as_usize())?;
+        ensure_dsize(op, "res.dsize", a.dsize().as_usize(), res.dsize().as_usize())?;
+        ensure_base2k(op, "res.base2k", a.base2k().as_usize(), res.base2k().as_usize())?;
+        ensure_dimension_at_most(op, "res.dnum", a.dnum().as_usize(), res.dnum().as_usize())?;
+        ensure_scratch(op, self.ggsw_automorphism_tmp_bytes(res, a, key, tsk), scratch.available())?;
+        self.ggsw_automorphism(res, a, key, tsk, scratch);
+        Ok(())
+    }
+}
+
+impl<BE: Backend, M> GGSWAutomorphismFallible<BE> for M
+where
+    M: GGSWAutomorphism<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+}
+
+pub trait GLWEExternalProductFallible<BE: Backend>
+where
+    Self: GLWEExternalProduct<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    /// [`GLWEExternalProduct::glwe_external_product`] returning a [`CoreError`](crate::CoreError) on invalid inputs.
+    fn try_glwe_external_product<R, A, D>(&self, res: &mut R, lhs: &A, rhs: &D, scratch: &mut Scratch<BE>) -> Result<()>
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        D: GGSWPreparedToRef<BE> + GGSWInfos,
+    {
+        let op: &str = "glwe_external_product";
+        ensure_dimension(op, "res.n", self.n(), res.n().as_usize())?;
+        ensure_dimension(op, "lhs.n", self.n(), lhs.n().as_usize())?;
+        ensure_dimension(op, "rhs.n", self.n(), rhs.n().as_usize())?;
+        ensure_rank(op, "lhs.rank", rhs.rank().as_usize(), lhs.rank().as_usize())?;
+        ensure_rank(op, "res.rank", rhs.rank().as_usize(), res.rank().as_usize())?;
+        ensure_scratch(op, self.glwe_external_product_tmp_bytes(res, lhs, rhs), scratch.available())?;
+        self.glwe_external_product(res, lhs, rhs, scratch);
+        Ok(())
+    }
+
+    /// [`GLWEExternalProduct::glwe_external_product_assign`] returning a [`CoreError`](crate::CoreError) on invalid inputs.
+    fn try_glwe_external_product_assign<R, D>(&self, res: &mut R, a: &D, scratch: &mut Scratch<BE>) -> Result<()>
+    where
+        R: GLWEToMut + GLWEInfos,
+        D: GGSWPreparedToRef<BE> + GGSWInfos,
+    {
+        let op: &str = "glwe_external_product_assign";
+        ensure_dimension(op, "res.n", self.n(), res.n().as_usize())?;
+        ensure_dimension(op, "a.n", self.n(), a.n().as_usize())?;
+        ensure_rank(op, "res.rank", a.rank().as_usize(), res.rank().as_usize())?;
+        ensure_scratch(op, self.glwe_external_product_tmp_bytes(res, res, a), scratch.available())?;
+        self.glwe_external_product_assign(res, a, scratch);
+        Ok(())
+    }
+}
+
+impl<BE: Backend, M> GLWEExternalProductFallible<BE> for M
+where
+    M: GLWEExternalProduct<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+}
+
+pub trait LWEFromGLWEFallible<BE: Backend>
+where
+    Self: LWEFromGLWE<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    /// [`LWEFromGLWE::lwe_from_glwe`] returning a [`CoreError`](crate::CoreError) on invalid inputs.
+    fn try_lwe_from_glwe<R, A, K>(&self, res: &mut R, a: &A, a_idx: usize, key: &K, scratch: &mut Scratch<BE>) -> Result<()>
+    where
+        R: LWEToMut + LWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos,
+    {
+        let op: &str = "lwe_from_glwe";
+        ensure_dimension(op, "a.n", self.n(), a.n().as_usize())?;
+        ensure_dimension(op, "key.n", self.n(), key.n().as_usize())?;
+        ensure_dimension_at_most(op, "res.n", self.n(), res.n().as_usize())?;
+        ensure_rank(op, "a.rank", key.rank_in().as_usize(), a.rank().as_usize())?;
+        ensure_rank(op, "key.rank_out", 1, key.rank_out().as_usize())?;
+        ensure_scratch(op, self.lwe_from_glwe_tmp_bytes(res, a, key), scratch.available())?;
+        self.lwe_from_glwe(res, a, a_idx, key, scratch);
+        Ok(())
+    }
+}
+
+impl<BE: Backend, M> LWEFromGLWEFallible<BE> for M
+where
+    M: LWEFromGLWE<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+}
+
+pub trait GLWEFromLWEFallible<BE: Backend>
+where
+    Self: GLWEFromLWE<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable,
+{
+    /// [`GLWEFromLWE::glwe_from_lwe`] returning a [`CoreError`](crate::CoreError) on invalid inputs.
+    fn try_glwe_from_lwe<R, A, K>(&self, res: &mut R, lwe: &A, ksk: &K, scratch: &mut Scratch<BE>) -> Result<()>
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: LWEToRef + LWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos,
+    {
+        let op: &str = "glwe_from_lwe";
+        ensure_dimension(op, "res.n", self.n(), res.n().as_usize())?;
+        ensure_dimension(op, "ksk.n", self.n(), ksk.n().as_usize())?;
+        ensure_dimension_at_most(op, "lwe.n", self.n(), lwe.n().as_usize())?;
+        ensure_rank(op, "ksk.rank_in", 1, ksk.rank_in().as_usize())?;
+        ensure_rank(op, "res.rank", ksk.rank_out().as_usize(), res.rank().as_usize())?;
+        ensure_scratch(op, self.glwe_from_lwe_tmp_bytes(res, lwe, ksk), scratch.available())?;
+        self.glwe_from_lwe(res, lwe, ksk, scratch);
+        Ok(())
+    }
+}
+
+impl<BE: Backend, M> GLWEFromLWEFallible<BE> for M
+where
+    M: GLWEFromLWE<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable,
+{
+}
+
+pub trait GGSWFromGGLWEFallible<BE: Backend>
+where
+    Self: GGSWFromGGLWE<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    /// [`GGSWFromGGLWE::ggsw_from_gglwe`] returning a [`CoreError`](crate::CoreError) on invalid inputs.
+    fn try_ggsw_from_gglwe<R, A, T>(&self, res: &mut R, a: &A, tsk: &T, scratch: &mut Scratch<BE>) -> Result<()>
+    where
+        R: GGSWToMut + GGSWInfos,
+        A: GGLWEToRef + GGLWEInfos,
+        T: GGLWEToGGSWKeyPreparedToRef<BE> + GGLWEInfos,
+    {
+        let op: &str = "ggsw_from_gglwe";
+        ensure_dimension(op, "res.n", self.n(), res.n().as_usize())?;
+        ensure_dimension(op, "a.n", self.n(), a.n().as_usize())?;
+        ensure_dimension(op, "tsk.n", self.n(), tsk.n().as_usize())?;
+        ensure_dimension(op, "res.dnum", a.dnum().as_usize(), res.dnum().as_usize())?;
+        ensure_rank(op, "res.rank", a.rank_out().as_usize(), res.rank().as_usize())?;
+        ensure_rank(op, "tsk.rank_out", res.rank().as_usize(), tsk.rank_out().as_usize())?;
+        ensure_dsize(op, "res.dsize", a.dsize().as_usize(), res.dsize().as_usize())?;
+        ensure_base2k(op, "res.base2k", a.base2k().as_usize(), res.base2k().as_usize())?;
+        ensure_scratch(op, self.ggsw_from_gglwe_tmp_bytes(res, tsk), scratch.available())?;
+        self.ggsw_from_gglwe(res, a, tsk, scratch);
+        Ok(())
+    }
+}
+
+impl<BE: Backend, M> GGSWFromGGLWEFallible<BE> for M
+where
+    M: GGSWFromGGLWE<BE> + ModuleN,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+}
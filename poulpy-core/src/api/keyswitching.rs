@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::{ModuleN, ScratchAvailable},
-    layouts::{Backend, Scratch, ZnxView, ZnxViewMut, ZnxZero},
+    layouts::{AlignedBuf, Backend, Scratch, ZnxView, ZnxViewMut, ZnxZero},
 };
 
 use crate::{
@@ -8,7 +8,8 @@ use crate::{
     api::{GGSWExpandRows, LWESampleExtract},
     layouts::{
         GGLWE, GGLWEInfos, GGLWEPreparedToRef, GGLWEToGGSWKeyPreparedToRef, GGLWEToMut, GGLWEToRef, GGSWInfos, GGSWToMut,
-        GGSWToRef, GLWE, GLWEInfos, GLWELayout, GLWEToMut, GLWEToRef, LWE, LWEInfos, LWEToMut, LWEToRef, Rank, TorusPrecision,
+        GGSWToRef, GLWE, GLWEInfos, GLWELayout, GLWESwitchingKeyDegrees, GLWEToMut, GLWEToRef, LWE, LWEInfos, LWEToMut, LWEToRef,
+        Rank, TorusPrecision,
     },
 };
 
@@ -31,6 +32,53 @@ pub trait GLWEKeyswitch<BE: Backend> {
         K: GGLWEPreparedToRef<BE> + GGLWEInfos;
 }
 
+/// Switches GLWE ciphertexts between the ring degrees `N` and `N/2`.
+///
+/// The module has the larger degree `N` and `key` is a prepared
+/// [`GLWERingSwitchKey`](crate::layouts::GLWERingSwitchKey) whose stored
+/// input and output degrees select the direction. Writing `Y = X^2`, a
+/// message `m(X) = m_0(Y) + X m_1(Y)` of degree `N` splits into the two
+/// degree-`N/2` messages `m_0` (even coefficients) and `m_1` (odd
+/// coefficients).
+///
+/// [`Self::glwe_ring_switch_tmp_bytes`] covers the scratch needed by all
+/// three operations.
+pub trait GLWERingSwitch<BE: Backend> {
+    fn glwe_ring_switch_tmp_bytes<R, A, K>(&self, res_infos: &R, a_infos: &A, key_infos: &K) -> usize
+    where
+        R: GLWEInfos,
+        A: GLWEInfos,
+        K: GGLWEInfos;
+
+    /// Switches `a` to the degree of `res`.
+    ///
+    /// From `N` to `N/2`, `res` encrypts the even coefficients `m_0(Y)` of the
+    /// message of `a` (the odd ones are dropped). From `N/2` to `N`, `res`
+    /// encrypts `m(X^2)`.
+    fn glwe_ring_switch<R, A, K>(&self, res: &mut R, a: &A, key: &K, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos + GLWESwitchingKeyDegrees;
+
+    /// Splits `a` of degree `N` into `res_even` and `res_odd` of degree `N/2`,
+    /// encrypting `m_0` and `m_1` respectively. Requires a key from `N` to `N/2`.
+    fn glwe_ring_split<R, A, K>(&self, res_even: &mut R, res_odd: &mut R, a: &A, key: &K, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos + GLWESwitchingKeyDegrees;
+
+    /// Merges `a_even` and `a_odd` of degree `N/2`, encrypting `m_0` and `m_1`,
+    /// into `res` of degree `N` encrypting `m_0(X^2) + X m_1(X^2)`. Inverse of
+    /// [`Self::glwe_ring_split`]; requires a key from `N/2` to `N`.
+    fn glwe_ring_merge<R, A, K>(&self, res: &mut R, a_even: &A, a_odd: &A, key: &K, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos + GLWESwitchingKeyDegrees;
+}
+
 pub trait GGLWEKeyswitch<BE: Backend>
 where
     Self: GLWEKeyswitch<BE>,
@@ -177,8 +225,8 @@ where
             rank: Rank(1),
         };
 
-        let lvl_0: usize = GLWE::<Vec<u8>>::bytes_of_from_infos(&glwe_a_infos);
-        let lvl_1: usize = GLWE::<Vec<u8>>::bytes_of_from_infos(&glwe_res_infos);
+        let lvl_0: usize = GLWE::<AlignedBuf>::bytes_of_from_infos(&glwe_a_infos);
+        let lvl_1: usize = GLWE::<AlignedBuf>::bytes_of_from_infos(&glwe_res_infos);
         let lvl_2: usize = self.glwe_keyswitch_tmp_bytes(&glwe_res_infos, &glwe_a_infos, key_infos);
 
         lvl_0 + lvl_1 + lvl_2
@@ -6,9 +6,13 @@
 //! - `decryption` -- secret-key decryption operations.
 //! - `encryption` -- secret/public-key encryption and evaluation-key generation.
 //! - `external_product` -- GLWE/GGLWE/GGSW external products.
+//! - `fallible` -- `try_*` variants of common operations returning a
+//!   [`CoreError`](crate::CoreError) instead of panicking on invalid inputs.
 //! - `keyswitching` -- LWE/GLWE/GGLWE/GGSW key-switching.
 //! - `noise` -- runtime noise measurement helpers for ciphertexts.
 //! - `operations` -- arithmetic helpers, packing, trace, and tensoring.
+//! - `pooled` -- variants of common operations taking a
+//!   [`ScratchPool`](poulpy_hal::layouts::ScratchPool) instead of a scratch buffer.
 //!
 //! Scheme authors can program against these traits directly. Execution is
 //! dispatched through the [`crate::oep`] backend extension points by the
@@ -19,15 +23,19 @@ mod conversion;
 mod decryption;
 mod encryption;
 mod external_product;
+mod fallible;
 mod keyswitching;
 mod noise;
 mod operations;
+mod pooled;
 
 pub use automorphism::*;
 pub use conversion::*;
 pub use decryption::*;
 pub use encryption::*;
 pub use external_product::*;
+pub use fallible::*;
 pub use keyswitching::*;
 pub use noise::*;
 pub use operations::*;
+pub use pooled::*;
@@ -17,7 +17,7 @@ use crate::{
     glwe_packer::{GLWEPacker, pack_core},
     layouts::{
         GGLWEInfos, GGLWEPreparedToRef, GGSW, GGSWInfos, GGSWToMut, GGSWToRef, GLWE, GLWEAutomorphismKeyHelper, GLWEInfos,
-        GLWEPlaintext, GLWETensor, GLWETensorKeyPrepared, GLWEToMut, GLWEToRef, GetGaloisElement, LWEInfos,
+        GLWEPlaintext, GLWETensor, GLWETensorKeyPrepared, GLWEToMut, GLWEToRef, GetGaloisElement, LWEInfos, LWEToMut, LWEToRef,
     },
 };
 
@@ -66,6 +66,33 @@ pub trait GLWEPacking<BE: Backend> {
         H: GLWEAutomorphismKeyHelper<K, BE>;
 }
 
+/// Packs LWE ciphertexts into a single GLWE ciphertext.
+///
+/// The `i`-th LWE is converted with [`GLWEFromLWE`](crate::GLWEFromLWE) and its
+/// message is placed in the `i`-th coefficient of the output. The remaining
+/// coefficients encrypt zero.
+pub trait GLWEPackLWEs<BE: Backend> {
+    fn glwe_pack_lwes_galois_elements(&self) -> Vec<i64>;
+
+    fn glwe_pack_lwes_tmp_bytes<R, A, K, T>(&self, res_infos: &R, lwe_infos: &A, ksk_infos: &K, atk_infos: &T) -> usize
+    where
+        R: GLWEInfos,
+        A: LWEInfos,
+        K: GGLWEInfos,
+        T: GGLWEInfos;
+
+    /// Packs `lwes[i]` into coefficient `i` of `res`, for up to `N` LWE
+    /// ciphertexts, using the LWE-to-GLWE key `ksk` and the automorphism keys
+    /// of [`glwe_pack_lwes_galois_elements`](Self::glwe_pack_lwes_galois_elements).
+    fn glwe_pack_lwes<R, A, K, T, H>(&self, res: &mut R, lwes: &[A], ksk: &K, keys: &H, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: LWEToRef,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos,
+        T: GGLWEPreparedToRef<BE> + GetGaloisElement + GGLWEInfos,
+        H: GLWEAutomorphismKeyHelper<T, BE>;
+}
+
 pub trait GLWEPackerOps<BE: Backend>
 where
     Self: Sized
@@ -819,3 +846,61 @@ where
         }
     }
 }
+
+/// Modulus truncation of GLWE ciphertexts, to reduce their size before
+/// transmission (e.g. when returning results to a client).
+///
+/// Rounds a ciphertext to `k` bits of torus precision: the limbs below
+/// `k` are dropped and the low `ceil(k / base2k) * base2k - k` bits of the
+/// last kept limb are cleared. The rounding adds the variance predicted by
+/// [`NoiseModel::glwe_truncate`](crate::NoiseModel::glwe_truncate), and the
+/// smallest `k` that keeps decryption correct at a given failure rate is
+/// returned by [`NoiseTracker::glwe_truncate_min_k`](crate::NoiseTracker::glwe_truncate_min_k).
+///
+/// A truncated ciphertext can be serialised with
+/// [`WriteTruncated`](crate::layouts::WriteTruncated), which only stores its
+/// `k` significant bits per coefficient.
+pub trait GLWETruncate<BE: Backend> {
+    fn glwe_truncate_tmp_bytes<R>(&self, res_infos: &R) -> usize
+    where
+        R: GLWEInfos;
+
+    /// Rounds `a` to `k` bits of precision and writes the result into `res`.
+    ///
+    /// `res` must have `max_k() >= k`; its limbs below `k` are zeroed.
+    fn glwe_truncate<R, A>(&self, res: &mut R, k: usize, a: &A, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut,
+        A: GLWEToRef,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    /// Rounds `res` to `k` bits of precision in place.
+    fn glwe_truncate_assign<R>(&self, res: &mut R, k: usize, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut,
+        Scratch<BE>: ScratchTakeCore<BE>;
+}
+
+/// Modulus truncation of LWE ciphertexts, see [`GLWETruncate`].
+///
+/// The added variance is given by [`NoiseModel::lwe_truncate`](crate::NoiseModel::lwe_truncate).
+pub trait LWETruncate<BE: Backend> {
+    fn lwe_truncate_tmp_bytes<R>(&self, res_infos: &R) -> usize
+    where
+        R: LWEInfos;
+
+    /// Rounds `a` to `k` bits of precision and writes the result into `res`.
+    ///
+    /// `res` must have `max_k() >= k` and the same `base2k` as `a`.
+    fn lwe_truncate<R, A>(&self, res: &mut R, k: usize, a: &A, scratch: &mut Scratch<BE>)
+    where
+        R: LWEToMut,
+        A: LWEToRef,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    /// Rounds `res` to `k` bits of precision in place.
+    fn lwe_truncate_assign<R>(&self, res: &mut R, k: usize, scratch: &mut Scratch<BE>)
+    where
+        R: LWEToMut,
+        Scratch<BE>: ScratchTakeCore<BE>;
+}
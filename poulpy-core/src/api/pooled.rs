@@ -0,0 +1,337 @@
+//! Variants of the core operations drawing their scratch space from a [`ScratchPool`].
+//!
+//! Each `*_pooled` method sizes the scratch space with the companion
+//! `*_tmp_bytes` method of the operation, takes it from the arena of the
+//! calling thread with [`ScratchPool::with_scratch`] and forwards to the
+//! operation. The traits are implemented for every type implementing the
+//! underlying operation trait.
+//!
+//! The pooled variants are thus only as reliable as the `*_tmp_bytes`
+//! estimates: an operation whose estimate is too small panics when taking
+//! its temporaries, exactly as with a [`ScratchOwned`] sized from the same
+//! estimate. Running the operation on a `Module<Profiled<BE>>` and comparing
+//! the estimate with `ProfileReport::check_tmp_bytes` (see
+//! `poulpy_hal::profiling`) detects such estimates.
+
+#![allow(clippy::too_many_arguments)]
+
+use poulpy_hal::{
+    api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow},
+    layouts::{Backend, ScalarZnxToRef, Scratch, ScratchOwned, ScratchPool, Stats},
+    source::Source,
+};
+
+use crate::{
+    ScratchTakeCore,
+    api::{
+        EncryptionInfos, GGSWEncryptSk, GLWEAutomorphism, GLWEDecrypt, GLWEEncryptSk, GLWEExternalProduct, GLWEKeyswitch,
+        GLWENoise, GLWENormalize,
+    },
+    layouts::{
+        GGLWEInfos, GGLWEPreparedToRef, GGSWInfos, GGSWPreparedFactory, GGSWPreparedToMut, GGSWPreparedToRef, GGSWToMut,
+        GGSWToRef, GLWEInfos, GLWEPlaintextToMut, GLWEPlaintextToRef, GLWESecretPreparedToRef, GLWEToMut, GLWEToRef,
+        GetGaloisElement, SetLWEInfos,
+    },
+};
+
+pub trait GLWEEncryptSkPooled<BE: Backend>
+where
+    Self: GLWEEncryptSk<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable,
+{
+    /// [`GLWEEncryptSk::glwe_encrypt_sk`] with scratch space taken from `pool`.
+    fn glwe_encrypt_sk_pooled<R, P, S, E>(
+        &self,
+        res: &mut R,
+        pt: &P,
+        sk: &S,
+        enc_infos: &E,
+        source_xe: &mut Source,
+        source_xa: &mut Source,
+        pool: &ScratchPool<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        P: GLWEPlaintextToRef,
+        E: EncryptionInfos,
+        S: GLWESecretPreparedToRef<BE>,
+    {
+        pool.with_scratch(self.glwe_encrypt_sk_tmp_bytes(res), |scratch| {
+            self.glwe_encrypt_sk(res, pt, sk, enc_infos, source_xe, source_xa, scratch)
+        })
+    }
+}
+
+impl<BE: Backend, M> GLWEEncryptSkPooled<BE> for M
+where
+    M: GLWEEncryptSk<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable,
+{
+}
+
+pub trait GGSWEncryptSkPooled<BE: Backend>
+where
+    Self: GGSWEncryptSk<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable,
+{
+    /// [`GGSWEncryptSk::ggsw_encrypt_sk`] with scratch space taken from `pool`.
+    fn ggsw_encrypt_sk_pooled<R, P, S, E>(
+        &self,
+        res: &mut R,
+        pt: &P,
+        sk: &S,
+        enc_infos: &E,
+        source_xe: &mut Source,
+        source_xa: &mut Source,
+        pool: &ScratchPool<BE>,
+    ) where
+        R: GGSWToMut + GGSWInfos,
+        P: ScalarZnxToRef,
+        E: EncryptionInfos,
+        S: GLWESecretPreparedToRef<BE>,
+    {
+        pool.with_scratch(self.ggsw_encrypt_sk_tmp_bytes(res), |scratch| {
+            self.ggsw_encrypt_sk(res, pt, sk, enc_infos, source_xe, source_xa, scratch)
+        })
+    }
+}
+
+impl<BE: Backend, M> GGSWEncryptSkPooled<BE> for M
+where
+    M: GGSWEncryptSk<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable,
+{
+}
+
+pub trait GLWEDecryptPooled<BE: Backend>
+where
+    Self: GLWEDecrypt<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable,
+{
+    /// [`GLWEDecrypt::glwe_decrypt`] with scratch space taken from `pool`.
+    fn glwe_decrypt_pooled<R, P, S>(&self, res: &R, pt: &mut P, sk: &S, pool: &ScratchPool<BE>)
+    where
+        R: GLWEToRef + GLWEInfos,
+        P: GLWEPlaintextToMut + GLWEInfos + SetLWEInfos,
+        S: GLWESecretPreparedToRef<BE> + GLWEInfos,
+    {
+        pool.with_scratch(self.glwe_decrypt_tmp_bytes(res), |scratch| {
+            self.glwe_decrypt(res, pt, sk, scratch)
+        })
+    }
+}
+
+impl<BE: Backend, M> GLWEDecryptPooled<BE> for M
+where
+    M: GLWEDecrypt<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable,
+{
+}
+
+pub trait GLWENoisePooled<BE: Backend>
+where
+    Self: GLWENoise<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable,
+{
+    /// [`GLWENoise::glwe_noise`] with scratch space taken from `pool`.
+    fn glwe_noise_pooled<R, P, S>(&self, res: &R, pt_want: &P, sk_prepared: &S, pool: &ScratchPool<BE>) -> Stats
+    where
+        R: GLWEToRef + GLWEInfos,
+        P: GLWEToRef,
+        S: GLWESecretPreparedToRef<BE> + GLWEInfos,
+    {
+        pool.with_scratch(self.glwe_noise_tmp_bytes(res), |scratch| {
+            self.glwe_noise(res, pt_want, sk_prepared, scratch)
+        })
+    }
+}
+
+impl<BE: Backend, M> GLWENoisePooled<BE> for M
+where
+    M: GLWENoise<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable,
+{
+}
+
+pub trait GLWEExternalProductPooled<BE: Backend>
+where
+    Self: GLWEExternalProduct<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    /// [`GLWEExternalProduct::glwe_external_product`] with scratch space taken from `pool`.
+    fn glwe_external_product_pooled<R, A, D>(&self, res: &mut R, lhs: &A, rhs: &D, pool: &ScratchPool<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        D: GGSWPreparedToRef<BE> + GGSWInfos,
+    {
+        pool.with_scratch(self.glwe_external_product_tmp_bytes(res, lhs, rhs), |scratch| {
+            self.glwe_external_product(res, lhs, rhs, scratch)
+        })
+    }
+
+    /// [`GLWEExternalProduct::glwe_external_product_assign`] with scratch space taken from `pool`.
+    fn glwe_external_product_assign_pooled<R, D>(&self, res: &mut R, a: &D, pool: &ScratchPool<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        D: GGSWPreparedToRef<BE> + GGSWInfos,
+    {
+        pool.with_scratch(self.glwe_external_product_tmp_bytes(res, res, a), |scratch| {
+            self.glwe_external_product_assign(res, a, scratch)
+        })
+    }
+}
+
+impl<BE: Backend, M> GLWEExternalProductPooled<BE> for M
+where
+    M: GLWEExternalProduct<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+}
+
+pub trait GLWEKeyswitchPooled<BE: Backend>
+where
+    Self: GLWEKeyswitch<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable,
+{
+    /// [`GLWEKeyswitch::glwe_keyswitch`] with scratch space taken from `pool`.
+    fn glwe_keyswitch_pooled<R, A, K>(&self, res: &mut R, a: &A, key: &K, pool: &ScratchPool<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos,
+    {
+        pool.with_scratch(self.glwe_keyswitch_tmp_bytes(res, a, key), |scratch| {
+            self.glwe_keyswitch(res, a, key, scratch)
+        })
+    }
+
+    /// [`GLWEKeyswitch::glwe_keyswitch_assign`] with scratch space taken from `pool`.
+    fn glwe_keyswitch_assign_pooled<R, K>(&self, res: &mut R, key: &K, pool: &ScratchPool<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos,
+    {
+        pool.with_scratch(self.glwe_keyswitch_tmp_bytes(res, res, key), |scratch| {
+            self.glwe_keyswitch_assign(res, key, scratch)
+        })
+    }
+}
+
+impl<BE: Backend, M> GLWEKeyswitchPooled<BE> for M
+where
+    M: GLWEKeyswitch<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable,
+{
+}
+
+pub trait GLWEAutomorphismPooled<BE: Backend>
+where
+    Self: GLWEAutomorphism<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable,
+{
+    /// [`GLWEAutomorphism::glwe_automorphism`] with scratch space taken from `pool`.
+    fn glwe_automorphism_pooled<R, A, K>(&self, res: &mut R, a: &A, key: &K, pool: &ScratchPool<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GetGaloisElement + GGLWEPreparedToRef<BE> + GGLWEInfos,
+    {
+        pool.with_scratch(self.glwe_automorphism_tmp_bytes(res, a, key), |scratch| {
+            self.glwe_automorphism(res, a, key, scratch)
+        })
+    }
+
+    /// [`GLWEAutomorphism::glwe_automorphism_assign`] with scratch space taken from `pool`.
+    fn glwe_automorphism_assign_pooled<R, K>(&self, res: &mut R, key: &K, pool: &ScratchPool<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        K: GetGaloisElement + GGLWEPreparedToRef<BE> + GGLWEInfos,
+    {
+        pool.with_scratch(self.glwe_automorphism_tmp_bytes(res, res, key), |scratch| {
+            self.glwe_automorphism_assign(res, key, scratch)
+        })
+    }
+}
+
+impl<BE: Backend, M> GLWEAutomorphismPooled<BE> for M
+where
+    M: GLWEAutomorphism<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable,
+{
+}
+
+pub trait GGSWPreparePooled<BE: Backend>
+where
+    Self: GGSWPreparedFactory<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable,
+{
+    /// [`GGSWPreparedFactory::ggsw_prepare`] with scratch space taken from `pool`.
+    fn ggsw_prepare_pooled<R, O>(&self, res: &mut R, other: &O, pool: &ScratchPool<BE>)
+    where
+        R: GGSWPreparedToMut<BE>,
+        O: GGSWToRef + GGSWInfos,
+    {
+        pool.with_scratch(self.ggsw_prepare_tmp_bytes(other), |scratch| {
+            self.ggsw_prepare(res, other, scratch)
+        })
+    }
+}
+
+impl<BE: Backend, M> GGSWPreparePooled<BE> for M
+where
+    M: GGSWPreparedFactory<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable,
+{
+}
+
+pub trait GLWENormalizePooled<BE: Backend>
+where
+    Self: GLWENormalize<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    /// [`GLWENormalize::glwe_normalize`] with scratch space taken from `pool`.
+    fn glwe_normalize_pooled<R, A>(&self, res: &mut R, a: &A, pool: &ScratchPool<BE>)
+    where
+        R: GLWEToMut,
+        A: GLWEToRef,
+    {
+        pool.with_scratch(self.glwe_normalize_tmp_bytes(), |scratch| {
+            self.glwe_normalize(res, a, scratch)
+        })
+    }
+
+    /// [`GLWENormalize::glwe_normalize_assign`] with scratch space taken from `pool`.
+    fn glwe_normalize_assign_pooled<R>(&self, res: &mut R, pool: &ScratchPool<BE>)
+    where
+        R: GLWEToMut,
+    {
+        pool.with_scratch(self.glwe_normalize_tmp_bytes(), |scratch| {
+            self.glwe_normalize_assign(res, scratch)
+        })
+    }
+}
+
+impl<BE: Backend, M> GLWENormalizePooled<BE> for M
+where
+    M: GLWENormalize<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+}
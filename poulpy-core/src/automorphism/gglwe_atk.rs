@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::{ScratchAvailable, VecZnxAutomorphism, VecZnxAutomorphismAssign, VecZnxAutomorphismAssignTmpBytes},
-    layouts::{Backend, CyclotomicOrder, GaloisElement, Module, Scratch},
+    layouts::{AlignedBuf, Backend, CyclotomicOrder, GaloisElement, Module, Scratch},
 };
 
 use crate::{
@@ -32,7 +32,7 @@ where
         let lvl_0: usize = if res_infos.glwe_layout() == a_infos.glwe_layout() {
             self.glwe_keyswitch_tmp_bytes(res_infos, a_infos, key_infos)
         } else {
-            self.glwe_keyswitch_tmp_bytes(res_infos, a_infos, key_infos) + GLWE::<Vec<u8>>::bytes_of_from_infos(a_infos)
+            self.glwe_keyswitch_tmp_bytes(res_infos, a_infos, key_infos) + GLWE::<AlignedBuf>::bytes_of_from_infos(a_infos)
         };
         let lvl_1: usize = self.vec_znx_automorphism_assign_tmp_bytes();
 
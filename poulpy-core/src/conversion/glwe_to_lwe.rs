@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::{ModuleN, ScratchAvailable},
-    layouts::{Backend, Module, Scratch},
+    layouts::{AlignedBuf, Backend, Module, Scratch},
 };
 
 pub use crate::api::{LWEFromGLWE, LWESampleExtract};
@@ -32,9 +32,9 @@ where
             rank: Rank(1),
         };
 
-        let lvl_0: usize = GLWE::<Vec<u8>>::bytes_of(self.n().into(), lwe_infos.base2k(), lwe_infos.max_k(), 1u32.into());
+        let lvl_0: usize = GLWE::<AlignedBuf>::bytes_of(self.n().into(), lwe_infos.base2k(), lwe_infos.max_k(), 1u32.into());
         let lvl_1: usize = self.glwe_keyswitch_tmp_bytes(&res_infos, glwe_infos, key_infos);
-        let lvl_2: usize = GLWE::<Vec<u8>>::bytes_of_from_infos(glwe_infos);
+        let lvl_2: usize = GLWE::<AlignedBuf>::bytes_of_from_infos(glwe_infos);
 
         lvl_0 + lvl_1 + lvl_2
     }
@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::{ScratchAvailable, ScratchTakeBasic, VecZnxNormalize, VecZnxNormalizeTmpBytes},
-    layouts::{Backend, Module, Scratch, VecZnx, ZnxView, ZnxViewMut, ZnxZero},
+    layouts::{AlignedBuf, Backend, Module, Scratch, VecZnx, ZnxView, ZnxViewMut, ZnxZero},
 };
 
 pub use crate::api::GLWEFromLWE;
@@ -24,7 +24,7 @@ where
         assert_eq!(self.n() as u32, glwe_infos.n());
         assert_eq!(self.n() as u32, key_infos.n());
 
-        let lvl_0: usize = GLWE::<Vec<u8>>::bytes_of(
+        let lvl_0: usize = GLWE::<AlignedBuf>::bytes_of(
             self.n().into(),
             key_infos.base2k(),
             lwe_infos.max_k().max(glwe_infos.max_k()),
@@ -7,17 +7,17 @@ use crate::{
     GetDistribution, GetDistributionMut,
     api::{
         EncryptionInfos, GGLWECompressedEncryptSk, GGLWEEncryptSk, GGLWEToGGSWKeyCompressedEncryptSk, GGLWEToGGSWKeyEncryptSk,
-        GGSWCompressedEncryptSk, GGSWEncryptSk, GLWEAutomorphismKeyCompressedEncryptSk, GLWEAutomorphismKeyEncryptPk,
-        GLWEAutomorphismKeyEncryptSk, GLWECompressedEncryptSk, GLWEEncryptPk, GLWEEncryptSk, GLWEPublicKeyGenerate,
-        GLWESwitchingKeyCompressedEncryptSk, GLWESwitchingKeyEncryptPk, GLWESwitchingKeyEncryptSk,
-        GLWETensorKeyCompressedEncryptSk, GLWETensorKeyEncryptSk, GLWEToLWESwitchingKeyEncryptSk, LWEEncryptSk,
-        LWESwitchingKeyEncrypt, LWEToGLWESwitchingKeyEncryptSk,
+        GGSWCompressedEncryptSk, GGSWEncryptPk, GGSWEncryptSk, GLWEAutomorphismKeyCompressedEncryptSk,
+        GLWEAutomorphismKeyEncryptPk, GLWEAutomorphismKeyEncryptSk, GLWECompressedEncryptSk, GLWEEncryptPk, GLWEEncryptSk,
+        GLWEPublicKeyGenerate, GLWERingSwitchKeyEncryptSk, GLWESanitize, GLWESwitchingKeyCompressedEncryptSk,
+        GLWESwitchingKeyEncryptPk, GLWESwitchingKeyEncryptSk, GLWETensorKeyCompressedEncryptSk, GLWETensorKeyEncryptSk,
+        GLWEToLWESwitchingKeyEncryptSk, LWEEncryptSk, LWESanitize, LWESwitchingKeyEncrypt, LWEToGLWESwitchingKeyEncryptSk,
     },
     layouts::{
         GGLWECompressedSeedMut, GGLWECompressedToMut, GGLWEInfos, GGLWEToGGSWKeyCompressedToMut, GGLWEToGGSWKeyToMut, GGLWEToMut,
         GGSWCompressedSeedMut, GGSWCompressedToMut, GGSWInfos, GGSWToMut, GLWECompressedSeedMut, GLWECompressedToMut, GLWEInfos,
         GLWEPlaintextToRef, GLWEPreparedToRef, GLWESecretPreparedToRef, GLWESecretToRef, GLWESwitchingKeyDegreesMut, GLWEToMut,
-        LWEInfos, LWEPlaintextToRef, LWESecretToRef, LWEToMut, SetGaloisElement,
+        GLWEToRef, LWEInfos, LWEPlaintextToRef, LWESecretToRef, LWEToMut, LWEToRef, SetGaloisElement,
     },
     oep::CoreImpl,
 };
@@ -145,6 +145,106 @@ where
     }
 }
 
+impl<BE> GLWESanitize<BE> for Module<BE>
+where
+    BE: Backend + CoreImpl<BE>,
+{
+    fn glwe_sanitize_tmp_bytes<R>(&self, res_infos: &R) -> usize
+    where
+        R: GLWEInfos,
+    {
+        BE::glwe_sanitize_tmp_bytes(self, res_infos)
+    }
+
+    fn glwe_sanitize<R, A, K, E, F>(
+        &self,
+        res: &mut R,
+        a: &A,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos,
+    {
+        BE::glwe_sanitize(self, res, a, pk, enc_infos, flood, source_xu, source_xe, scratch)
+    }
+
+    fn glwe_sanitize_assign<R, K, E, F>(
+        &self,
+        res: &mut R,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos,
+    {
+        BE::glwe_sanitize_assign(self, res, pk, enc_infos, flood, source_xu, source_xe, scratch)
+    }
+}
+
+impl<BE> LWESanitize<BE> for Module<BE>
+where
+    BE: Backend + CoreImpl<BE>,
+{
+    fn lwe_sanitize_tmp_bytes<R>(&self, res_infos: &R) -> usize
+    where
+        R: LWEInfos,
+    {
+        BE::lwe_sanitize_tmp_bytes(self, res_infos)
+    }
+
+    fn lwe_sanitize<R, A, K, E, F>(
+        &self,
+        res: &mut R,
+        a: &A,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: LWEToMut,
+        A: LWEToRef,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos,
+    {
+        BE::lwe_sanitize(self, res, a, pk, enc_infos, flood, source_xu, source_xe, scratch)
+    }
+
+    fn lwe_sanitize_assign<R, K, E, F>(
+        &self,
+        res: &mut R,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: LWEToMut,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos,
+    {
+        BE::lwe_sanitize_assign(self, res, pk, enc_infos, flood, source_xu, source_xe, scratch)
+    }
+}
+
 impl<BE> GLWEPublicKeyGenerate<BE> for Module<BE>
 where
     BE: Backend + CoreImpl<BE>,
@@ -225,6 +325,36 @@ where
     }
 }
 
+impl<BE> GGSWEncryptPk<BE> for Module<BE>
+where
+    BE: Backend + CoreImpl<BE>,
+{
+    fn ggsw_encrypt_pk_tmp_bytes<A>(&self, infos: &A) -> usize
+    where
+        A: GGSWInfos,
+    {
+        BE::ggsw_encrypt_pk_tmp_bytes(self, infos)
+    }
+
+    fn ggsw_encrypt_pk<R, P, K, E>(
+        &self,
+        res: &mut R,
+        pt: &P,
+        pk: &K,
+        enc_infos: &E,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GGSWToMut,
+        P: ScalarZnxToRef,
+        E: EncryptionInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+    {
+        BE::ggsw_encrypt_pk(self, res, pt, pk, enc_infos, source_xu, source_xe, scratch)
+    }
+}
+
 impl<BE> GGLWEToGGSWKeyEncryptSk<BE> for Module<BE>
 where
     BE: Backend + CoreImpl<BE>,
@@ -283,6 +413,36 @@ where
     }
 }
 
+impl<BE> GLWERingSwitchKeyEncryptSk<BE> for Module<BE>
+where
+    BE: Backend + CoreImpl<BE>,
+{
+    fn glwe_ring_switch_key_encrypt_sk_tmp_bytes<A>(&self, infos: &A) -> usize
+    where
+        A: GGLWEInfos,
+    {
+        BE::glwe_ring_switch_key_encrypt_sk_tmp_bytes(self, infos)
+    }
+
+    fn glwe_ring_switch_key_encrypt_sk<R, S1, S2, E>(
+        &self,
+        res: &mut R,
+        sk_in: &S1,
+        sk_out: &S2,
+        enc_infos: &E,
+        source_xe: &mut Source,
+        source_xa: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GGLWEToMut + GLWESwitchingKeyDegreesMut + GGLWEInfos,
+        E: EncryptionInfos,
+        S1: GLWESecretToRef,
+        S2: GLWESecretToRef,
+    {
+        BE::glwe_ring_switch_key_encrypt_sk(self, res, sk_in, sk_out, enc_infos, source_xe, source_xa, scratch)
+    }
+}
+
 impl<BE> GLWESwitchingKeyEncryptPk<BE> for Module<BE>
 where
     BE: Backend + CoreImpl<BE>,
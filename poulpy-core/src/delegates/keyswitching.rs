@@ -1,11 +1,11 @@
 use poulpy_hal::layouts::{Backend, Module, Scratch};
 
 use crate::{
-    api::{GGLWEKeyswitch, GGSWKeyswitch, GLWEKeyswitch, LWEKeySwitch},
+    api::{GGLWEKeyswitch, GGSWKeyswitch, GLWEKeyswitch, GLWERingSwitch, LWEKeySwitch},
     keyswitching::{GGLWEKeyswitchDefault, GGSWKeyswitchDefault, LWEKeySwitchDefault},
     layouts::{
         GGLWEInfos, GGLWEPreparedToRef, GGLWEToGGSWKeyPreparedToRef, GGLWEToMut, GGLWEToRef, GGSWInfos, GGSWToMut, GGSWToRef,
-        GLWEInfos, GLWEToMut, GLWEToRef, LWEInfos, LWEToMut, LWEToRef,
+        GLWEInfos, GLWESwitchingKeyDegrees, GLWEToMut, GLWEToRef, LWEInfos, LWEToMut, LWEToRef,
     },
     oep::CoreImpl,
 };
@@ -41,6 +41,47 @@ where
     }
 }
 
+impl<BE> GLWERingSwitch<BE> for Module<BE>
+where
+    BE: Backend + CoreImpl<BE>,
+{
+    fn glwe_ring_switch_tmp_bytes<R, A, K>(&self, res_infos: &R, a_infos: &A, key_infos: &K) -> usize
+    where
+        R: GLWEInfos,
+        A: GLWEInfos,
+        K: GGLWEInfos,
+    {
+        BE::glwe_ring_switch_tmp_bytes(self, res_infos, a_infos, key_infos)
+    }
+
+    fn glwe_ring_switch<R, A, K>(&self, res: &mut R, a: &A, key: &K, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos + GLWESwitchingKeyDegrees,
+    {
+        BE::glwe_ring_switch(self, res, a, key, scratch)
+    }
+
+    fn glwe_ring_split<R, A, K>(&self, res_even: &mut R, res_odd: &mut R, a: &A, key: &K, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos + GLWESwitchingKeyDegrees,
+    {
+        BE::glwe_ring_split(self, res_even, res_odd, a, key, scratch)
+    }
+
+    fn glwe_ring_merge<R, A, K>(&self, res: &mut R, a_even: &A, a_odd: &A, key: &K, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos + GLWESwitchingKeyDegrees,
+    {
+        BE::glwe_ring_merge(self, res, a_even, a_odd, key, scratch)
+    }
+}
+
 impl<BE> GGLWEKeyswitch<BE> for Module<BE>
 where
     BE: Backend + CoreImpl<BE>,
@@ -4,21 +4,22 @@ use poulpy_hal::layouts::{Backend, DataMut, DataRef, Module, Scratch};
 
 use crate::{
     api::{
-        GGSWRotate, GLWEMulConst, GLWEMulPlain, GLWEMulXpMinusOne, GLWENormalize, GLWEPackerOps, GLWEPacking, GLWERotate,
-        GLWEShift, GLWETensoring, GLWETrace,
+        GGSWRotate, GLWEMulConst, GLWEMulPlain, GLWEMulXpMinusOne, GLWENormalize, GLWEPackLWEs, GLWEPackerOps, GLWEPacking,
+        GLWERotate, GLWEShift, GLWETensoring, GLWETrace, GLWETruncate, LWETruncate,
     },
     glwe_packer::GLWEPackerOpsDefault,
-    glwe_packing::GLWEPackingDefault,
+    glwe_packing::{GLWEPackLWEsDefault, GLWEPackingDefault},
     glwe_trace::GLWETraceDefault,
     layouts::{
         GGLWEInfos, GGLWEPreparedToRef, GGSWToMut, GGSWToRef, GLWE, GLWEAutomorphismKeyHelper, GLWEInfos, GLWEPlaintext,
-        GLWETensor, GLWETensorKeyPrepared, GLWEToMut, GLWEToRef, GetGaloisElement,
+        GLWETensor, GLWETensorKeyPrepared, GLWEToMut, GLWEToRef, GetGaloisElement, LWEInfos, LWEToMut, LWEToRef,
     },
     oep::CoreImpl,
     operations::{
         GGSWRotateDefault, GLWEMulConstDefault, GLWEMulPlainDefault, GLWEMulXpMinusOneDefault, GLWENormalizeDefault,
         GLWERotateDefault, GLWEShiftDefault, GLWETensoringDefault,
     },
+    truncate::{GLWETruncateDefault, LWETruncateDefault},
 };
 
 impl<BE> GLWEMulConst<BE> for Module<BE>
@@ -373,6 +374,66 @@ where
     }
 }
 
+impl<BE> GLWETruncate<BE> for Module<BE>
+where
+    BE: Backend + CoreImpl<BE>,
+    Module<BE>: GLWETruncateDefault<BE>,
+{
+    fn glwe_truncate_tmp_bytes<R>(&self, res_infos: &R) -> usize
+    where
+        R: GLWEInfos,
+    {
+        BE::glwe_truncate_tmp_bytes(self, res_infos)
+    }
+
+    fn glwe_truncate<R, A>(&self, res: &mut R, k: usize, a: &A, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut,
+        A: GLWEToRef,
+        Scratch<BE>: crate::ScratchTakeCore<BE>,
+    {
+        BE::glwe_truncate(self, res, k, a, scratch)
+    }
+
+    fn glwe_truncate_assign<R>(&self, res: &mut R, k: usize, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut,
+        Scratch<BE>: crate::ScratchTakeCore<BE>,
+    {
+        BE::glwe_truncate_assign(self, res, k, scratch)
+    }
+}
+
+impl<BE> LWETruncate<BE> for Module<BE>
+where
+    BE: Backend + CoreImpl<BE>,
+    Module<BE>: LWETruncateDefault<BE>,
+{
+    fn lwe_truncate_tmp_bytes<R>(&self, res_infos: &R) -> usize
+    where
+        R: LWEInfos,
+    {
+        BE::lwe_truncate_tmp_bytes(self, res_infos)
+    }
+
+    fn lwe_truncate<R, A>(&self, res: &mut R, k: usize, a: &A, scratch: &mut Scratch<BE>)
+    where
+        R: LWEToMut,
+        A: LWEToRef,
+        Scratch<BE>: crate::ScratchTakeCore<BE>,
+    {
+        BE::lwe_truncate(self, res, k, a, scratch)
+    }
+
+    fn lwe_truncate_assign<R>(&self, res: &mut R, k: usize, scratch: &mut Scratch<BE>)
+    where
+        R: LWEToMut,
+        Scratch<BE>: crate::ScratchTakeCore<BE>,
+    {
+        BE::lwe_truncate_assign(self, res, k, scratch)
+    }
+}
+
 impl<BE> GLWETrace<BE> for Module<BE>
 where
     BE: Backend + CoreImpl<BE>,
@@ -445,6 +506,37 @@ where
     }
 }
 
+impl<BE> GLWEPackLWEs<BE> for Module<BE>
+where
+    BE: Backend + CoreImpl<BE>,
+    Module<BE>: GLWEPackLWEsDefault<BE>,
+{
+    fn glwe_pack_lwes_galois_elements(&self) -> Vec<i64> {
+        BE::glwe_pack_lwes_galois_elements(self)
+    }
+
+    fn glwe_pack_lwes_tmp_bytes<R, A, K, T>(&self, res_infos: &R, lwe_infos: &A, ksk_infos: &K, atk_infos: &T) -> usize
+    where
+        R: GLWEInfos,
+        A: LWEInfos,
+        K: GGLWEInfos,
+        T: GGLWEInfos,
+    {
+        BE::glwe_pack_lwes_tmp_bytes(self, res_infos, lwe_infos, ksk_infos, atk_infos)
+    }
+
+    fn glwe_pack_lwes<R, A, K, T, H>(&self, res: &mut R, lwes: &[A], ksk: &K, keys: &H, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: LWEToRef,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos,
+        T: GGLWEPreparedToRef<BE> + GetGaloisElement + GGLWEInfos,
+        H: GLWEAutomorphismKeyHelper<T, BE>,
+    {
+        BE::glwe_pack_lwes(self, res, lwes, ksk, keys, scratch)
+    }
+}
+
 impl<BE> GLWEPackerOps<BE> for Module<BE>
 where
     BE: Backend + CoreImpl<BE>,
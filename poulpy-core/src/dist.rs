@@ -23,6 +23,7 @@ pub trait GetDistributionMut {
 /// precision loss below 2^-44 (8 least-significant mantissa bits
 /// are discarded to fit the tag byte).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Distribution {
     /// Ternary in {-1, 0, 1} with exactly `h` non-zero coefficients.
     TernaryFixed(usize),
@@ -109,6 +110,23 @@ impl Distribution {
     }
 }
 
+impl Distribution {
+    /// Returns the probability that a secret coefficient is non-zero for a
+    /// secret of dimension `n`.
+    ///
+    /// Since all supported distributions take values in {-1, 0, 1}, this is
+    /// also the second moment `E[s_i^2]` of a coefficient, which is the
+    /// quantity that drives noise growth in products with the secret.
+    pub fn density(&self, n: usize) -> f64 {
+        match self {
+            Distribution::TernaryFixed(h) | Distribution::BinaryFixed(h) => (*h as f64 / n as f64).min(1.0),
+            Distribution::TernaryProb(p) | Distribution::BinaryProb(p) => *p,
+            Distribution::BinaryBlock(block_size) => 1.0 / (*block_size as f64 + 1.0),
+            Distribution::ZERO | Distribution::NONE => 0.0,
+        }
+    }
+}
+
 impl PartialEq for Distribution {
     fn eq(&self, other: &Self) -> bool {
         use Distribution::*;
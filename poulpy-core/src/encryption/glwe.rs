@@ -5,7 +5,7 @@ use poulpy_hal::{
         VecZnxBigNormalizeTmpBytes, VecZnxDftApply, VecZnxDftBytesOf, VecZnxFillUniform, VecZnxIdftApplyConsume, VecZnxNormalize,
         VecZnxNormalizeAssign, VecZnxNormalizeTmpBytes, VecZnxSub, VecZnxSubAssign,
     },
-    layouts::{Backend, Module, ScalarZnx, Scratch, VecZnx, VecZnxBig, VecZnxToMut, ZnxInfos, ZnxZero},
+    layouts::{AlignedBuf, Backend, Module, NoiseInfos, ScalarZnx, Scratch, VecZnx, VecZnxBig, VecZnxToMut, ZnxInfos, ZnxZero},
     source::Source,
 };
 
@@ -17,6 +17,7 @@ use crate::{
         GLWE, GLWEInfos, GLWEPlaintext, GLWEPlaintextToRef, GLWEPrepared, GLWEPreparedToRef, GLWEToMut, LWEInfos,
         prepared::{GLWESecretPrepared, GLWESecretPreparedToRef},
     },
+    security::{SecurityParameters, assert_min_security},
 };
 
 #[doc(hidden)]
@@ -152,7 +153,7 @@ where
             res.data_mut(),
             cols,
             false,
-            None::<(&GLWEPlaintext<Vec<u8>>, usize)>,
+            None::<(&GLWEPlaintext<AlignedBuf>, usize)>,
             sk,
             enc_infos,
             source_xe,
@@ -262,7 +263,7 @@ where
         );
         self.glwe_encrypt_pk_internal(
             res,
-            None::<(&GLWEPlaintext<Vec<u8>>, usize)>,
+            None::<(&GLWEPlaintext<AlignedBuf>, usize)>,
             pk,
             enc_infos,
             source_xu,
@@ -453,6 +454,13 @@ where
             "glwe secret distribution is NONE (have you prepared the key?)"
         );
 
+        if let Some(min_security) = enc_infos.min_security() {
+            let noise: NoiseInfos = enc_infos.noise_infos();
+            let params: SecurityParameters =
+                SecurityParameters::new(self.n() * sk.rank().as_usize(), noise.k, sk.dist, noise.sigma);
+            assert_min_security(&params, min_security);
+        }
+
         let size: usize = ct.size();
 
         let (mut c0, scratch_1) = scratch.take_vec_znx(self.n(), 1, size);
@@ -0,0 +1,75 @@
+use poulpy_hal::{
+    api::ModuleN,
+    layouts::{Backend, Module, Scratch},
+    source::Source,
+};
+
+use crate::{
+    EncryptionInfos, GLWESwitchingKeyEncryptSk,
+    layouts::{GGLWEInfos, GGLWEToMut, GLWESecret, GLWESecretToRef, GLWESwitchingKeyDegreesMut, LWEInfos},
+};
+
+#[doc(hidden)]
+pub trait GLWERingSwitchKeyEncryptSkDefault<BE: Backend> {
+    fn glwe_ring_switch_key_encrypt_sk_tmp_bytes<A>(&self, infos: &A) -> usize
+    where
+        A: GGLWEInfos;
+
+    fn glwe_ring_switch_key_encrypt_sk<R, S1, S2, E>(
+        &self,
+        res: &mut R,
+        sk_in: &S1,
+        sk_out: &S2,
+        enc_infos: &E,
+        source_xe: &mut Source,
+        source_xa: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GGLWEToMut + GLWESwitchingKeyDegreesMut + GGLWEInfos,
+        E: EncryptionInfos,
+        S1: GLWESecretToRef,
+        S2: GLWESecretToRef;
+}
+
+impl<BE: Backend> GLWERingSwitchKeyEncryptSkDefault<BE> for Module<BE>
+where
+    Self: ModuleN + GLWESwitchingKeyEncryptSk<BE>,
+{
+    fn glwe_ring_switch_key_encrypt_sk_tmp_bytes<A>(&self, infos: &A) -> usize
+    where
+        A: GGLWEInfos,
+    {
+        assert_eq!(self.n() as u32, infos.n());
+        self.glwe_switching_key_encrypt_sk_tmp_bytes(infos)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn glwe_ring_switch_key_encrypt_sk<R, S1, S2, E>(
+        &self,
+        res: &mut R,
+        sk_in: &S1,
+        sk_out: &S2,
+        enc_infos: &E,
+        source_xe: &mut Source,
+        source_xa: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GGLWEToMut + GLWESwitchingKeyDegreesMut + GGLWEInfos,
+        E: EncryptionInfos,
+        S1: GLWESecretToRef,
+        S2: GLWESecretToRef,
+    {
+        let sk_in: &GLWESecret<&[u8]> = &sk_in.to_ref();
+        let sk_out: &GLWESecret<&[u8]> = &sk_out.to_ref();
+
+        let n: u32 = self.n() as u32;
+        let (n_in, n_out) = (sk_in.n().0, sk_out.n().0);
+        assert!(
+            (n_in == n && n_out == n >> 1) || (n_in == n >> 1 && n_out == n),
+            "ring switching requires secrets of degrees (N, N/2) or (N/2, N) with N={n}, got ({n_in}, {n_out})"
+        );
+        assert_eq!(res.rank_in(), res.rank_out(), "res.rank_in() != res.rank_out()");
+
+        self.glwe_switching_key_encrypt_sk(res, sk_in, sk_out, enc_infos, source_xe, source_xa, scratch);
+    }
+}
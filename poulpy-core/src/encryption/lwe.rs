@@ -2,7 +2,7 @@ use poulpy_hal::{
     api::{
         ScratchAvailable, ScratchTakeBasic, VecZnxAddNormal, VecZnxFillUniform, VecZnxNormalizeAssign, VecZnxNormalizeTmpBytes,
     },
-    layouts::{Backend, Module, Scratch, ZnxView, ZnxViewMut, ZnxZero},
+    layouts::{Backend, Module, NoiseInfos, Scratch, ZnxView, ZnxViewMut, ZnxZero},
     source::Source,
 };
 
@@ -10,6 +10,7 @@ pub use crate::api::LWEEncryptSk;
 use crate::{
     EncryptionInfos, ScratchTakeCore,
     layouts::{LWE, LWEInfos, LWEPlaintext, LWEPlaintextToRef, LWESecret, LWESecretToRef, LWEToMut},
+    security::{SecurityParameters, assert_min_security},
 };
 
 #[doc(hidden)]
@@ -85,6 +86,12 @@ where
             self.lwe_encrypt_sk_tmp_bytes(res)
         );
 
+        if let Some(min_security) = enc_infos.min_security() {
+            let noise: NoiseInfos = enc_infos.noise_infos();
+            let params: SecurityParameters = SecurityParameters::new(sk.n().as_usize(), noise.k, sk.dist(), noise.sigma);
+            assert_min_security(&params, min_security);
+        }
+
         let base2k: usize = res.base2k().into();
 
         self.vec_znx_fill_uniform(base2k, &mut res.data, 0, source_xa);
@@ -4,13 +4,14 @@
 //! lattice-based cryptographic objects, including:
 //!
 //! - **Ciphertexts**: [`GLWEEncryptSk`], [`GLWEEncryptPk`], [`GGLWEEncryptSk`],
-//!   [`GGSWEncryptSk`], [`LWEEncryptSk`] for encrypting plaintexts under
-//!   GLWE, GGLWE, GGSW, and LWE schemes.
+//!   [`GGSWEncryptSk`], [`GGSWEncryptPk`], [`LWEEncryptSk`] for encrypting
+//!   plaintexts under GLWE, GGLWE, GGSW, and LWE schemes.
 //!
 //! - **Key-switching keys**: [`GLWESwitchingKeyEncryptSk`], [`LWESwitchingKeyEncrypt`],
 //!   [`GLWEToLWESwitchingKeyEncryptSk`], [`LWEToGLWESwitchingKeyEncryptSk`] for
 //!   generating keys that enable switching between different secret keys or
-//!   between LWE and GLWE domains.
+//!   between LWE and GLWE domains, and [`GLWERingSwitchKeyEncryptSk`] for
+//!   switching between the ring degrees `N` and `N/2`.
 //!
 //! - **Evaluation keys**: [`GLWEAutomorphismKeyEncryptSk`], [`GLWETensorKeyEncryptSk`],
 //!   [`GGLWEToGGSWKeyEncryptSk`] for generating keys used in automorphism,
@@ -36,6 +37,7 @@ mod ggsw;
 mod glwe;
 mod glwe_automorphism_key;
 mod glwe_public_key;
+mod glwe_ring_switch_key;
 mod glwe_switching_key;
 mod glwe_tensor_key;
 mod glwe_to_lwe_key;
@@ -45,11 +47,11 @@ mod lwe_to_glwe_key;
 
 pub use crate::api::{
     DeclaredK, EncryptionInfos, GGLWECompressedEncryptSk, GGLWEEncryptSk, GGLWEToGGSWKeyCompressedEncryptSk,
-    GGLWEToGGSWKeyEncryptSk, GGSWCompressedEncryptSk, GGSWEncryptSk, GLWEAutomorphismKeyCompressedEncryptSk,
+    GGLWEToGGSWKeyEncryptSk, GGSWCompressedEncryptSk, GGSWEncryptPk, GGSWEncryptSk, GLWEAutomorphismKeyCompressedEncryptSk,
     GLWEAutomorphismKeyEncryptPk, GLWEAutomorphismKeyEncryptSk, GLWECompressedEncryptSk, GLWEEncryptPk, GLWEEncryptSk,
-    GLWEPublicKeyGenerate, GLWESwitchingKeyCompressedEncryptSk, GLWESwitchingKeyEncryptPk, GLWESwitchingKeyEncryptSk,
-    GLWETensorKeyCompressedEncryptSk, GLWETensorKeyEncryptSk, GLWEToLWESwitchingKeyEncryptSk, LWEEncryptSk,
-    LWESwitchingKeyEncrypt, LWEToGLWESwitchingKeyEncryptSk,
+    GLWEPublicKeyGenerate, GLWERingSwitchKeyEncryptSk, GLWESwitchingKeyCompressedEncryptSk, GLWESwitchingKeyEncryptPk,
+    GLWESwitchingKeyEncryptSk, GLWETensorKeyCompressedEncryptSk, GLWETensorKeyEncryptSk, GLWEToLWESwitchingKeyEncryptSk,
+    LWEEncryptSk, LWESwitchingKeyEncrypt, LWEToGLWESwitchingKeyEncryptSk,
 };
 pub use compressed::*;
 pub use gglwe::*;
@@ -58,6 +60,7 @@ pub use ggsw::*;
 pub use glwe::*;
 pub use glwe_automorphism_key::*;
 pub use glwe_public_key::*;
+pub use glwe_ring_switch_key::*;
 pub use glwe_switching_key::*;
 pub use glwe_tensor_key::*;
 pub use glwe_to_lwe_key::*;
@@ -106,6 +109,7 @@ impl DeclaredK for GGSWLayout {
 pub struct EncryptionLayout<L> {
     pub layout: L,
     pub noise: NoiseInfos,
+    pub min_security: Option<f64>,
 }
 
 impl<L: DeclaredK> EncryptionLayout<L> {
@@ -116,19 +120,34 @@ impl<L: DeclaredK> EncryptionLayout<L> {
             noise.k,
             layout.max_k()
         );
-        Ok(Self { layout, noise })
+        Ok(Self {
+            layout,
+            noise,
+            min_security: None,
+        })
     }
 
     pub fn new_from_default_sigma(layout: L) -> Result<Self> {
         let noise = NoiseInfos::new(layout.k().as_usize(), DEFAULT_SIGMA_XE, DEFAULT_BOUND_XE)?;
         Self::new(layout, noise)
     }
+
+    /// Requires secret-key encryption under this layout to reach at least
+    /// `min_security` bits, as estimated by [`crate::SecurityParameters`].
+    pub fn with_min_security(mut self, min_security: f64) -> Self {
+        self.min_security = Some(min_security);
+        self
+    }
 }
 
 impl<L> EncryptionInfos for EncryptionLayout<L> {
     fn noise_infos(&self) -> NoiseInfos {
         self.noise
     }
+
+    fn min_security(&self) -> Option<f64> {
+        self.min_security
+    }
 }
 
 impl EncryptionInfos for NoiseInfos {
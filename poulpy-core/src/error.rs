@@ -1,7 +1,64 @@
 use std::{fmt, result};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Errors returned by the fallible (`try_*`) core operations.
+///
+/// The mismatch variants carry the name of the operation, the operand or
+/// parameter that failed the check (e.g. `"res.n"`), and the expected and
+/// actual values.
+#[derive(Debug, Clone, PartialEq)]
 pub enum CoreError {
+    /// A ring degree or another layout dimension (e.g. `dnum`) differs from
+    /// the expected one.
+    DimensionMismatch {
+        op: &'static str,
+        what: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    /// A layout dimension exceeds the largest value supported by the operation.
+    DimensionTooLarge {
+        op: &'static str,
+        what: &'static str,
+        max: usize,
+        actual: usize,
+    },
+    /// A GLWE rank differs from the expected one.
+    RankMismatch {
+        op: &'static str,
+        what: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    /// A limb radix differs from the expected one.
+    Base2KMismatch {
+        op: &'static str,
+        what: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    /// A gadget digit size differs from the expected one.
+    DsizeMismatch {
+        op: &'static str,
+        what: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    /// The scratch space holds fewer bytes than the matching `*_tmp_bytes` method requires.
+    ScratchTooSmall {
+        op: &'static str,
+        required: usize,
+        available: usize,
+    },
+    /// A secret carries no distribution: it was allocated but never sampled
+    /// and prepared (or, for a public key, never generated).
+    UnpreparedSecret { op: &'static str, what: &'static str },
+    /// The encrypted instance is estimated below the minimum bit-security
+    /// requested by [`EncryptionInfos::min_security`](crate::EncryptionInfos::min_security).
+    InsufficientSecurity {
+        op: &'static str,
+        required: f64,
+        estimated: f64,
+    },
     PendingLinearTermsOverflow {
         op: &'static str,
         base2k: usize,
@@ -18,6 +75,45 @@ pub enum CoreError {
 impl fmt::Display for CoreError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            CoreError::DimensionMismatch {
+                op,
+                what,
+                expected,
+                actual,
+            } => write!(f, "{op}: {what}={actual} != expected {expected}"),
+            CoreError::DimensionTooLarge { op, what, max, actual } => {
+                write!(f, "{op}: {what}={actual} exceeds the maximum {max}")
+            }
+            CoreError::RankMismatch {
+                op,
+                what,
+                expected,
+                actual,
+            } => write!(f, "{op}: rank {what}={actual} != expected {expected}"),
+            CoreError::Base2KMismatch {
+                op,
+                what,
+                expected,
+                actual,
+            } => write!(f, "{op}: base2k {what}={actual} != expected {expected}"),
+            CoreError::DsizeMismatch {
+                op,
+                what,
+                expected,
+                actual,
+            } => write!(f, "{op}: dsize {what}={actual} != expected {expected}"),
+            CoreError::ScratchTooSmall { op, required, available } => {
+                write!(f, "{op}: scratch.available()={available} < required {required}")
+            }
+            CoreError::UnpreparedSecret { op, what } => {
+                write!(
+                    f,
+                    "{op}: {what} has no secret distribution (has it been sampled and prepared?)"
+                )
+            }
+            CoreError::InsufficientSecurity { op, required, estimated } => {
+                write!(f, "{op}: estimated security {estimated:.1} bits < required {required} bits")
+            }
             CoreError::PendingLinearTermsOverflow {
                 op,
                 base2k,
@@ -42,3 +138,65 @@ impl fmt::Display for CoreError {
 impl std::error::Error for CoreError {}
 
 pub type Result<T> = result::Result<T, CoreError>;
+
+pub(crate) fn ensure_dimension(op: &'static str, what: &'static str, expected: usize, actual: usize) -> Result<()> {
+    if expected != actual {
+        return Err(CoreError::DimensionMismatch {
+            op,
+            what,
+            expected,
+            actual,
+        });
+    }
+    Ok(())
+}
+
+pub(crate) fn ensure_dimension_at_most(op: &'static str, what: &'static str, max: usize, actual: usize) -> Result<()> {
+    if actual > max {
+        return Err(CoreError::DimensionTooLarge { op, what, max, actual });
+    }
+    Ok(())
+}
+
+pub(crate) fn ensure_rank(op: &'static str, what: &'static str, expected: usize, actual: usize) -> Result<()> {
+    if expected != actual {
+        return Err(CoreError::RankMismatch {
+            op,
+            what,
+            expected,
+            actual,
+        });
+    }
+    Ok(())
+}
+
+pub(crate) fn ensure_base2k(op: &'static str, what: &'static str, expected: usize, actual: usize) -> Result<()> {
+    if expected != actual {
+        return Err(CoreError::Base2KMismatch {
+            op,
+            what,
+            expected,
+            actual,
+        });
+    }
+    Ok(())
+}
+
+pub(crate) fn ensure_dsize(op: &'static str, what: &'static str, expected: usize, actual: usize) -> Result<()> {
+    if expected != actual {
+        return Err(CoreError::DsizeMismatch {
+            op,
+            what,
+            expected,
+            actual,
+        });
+    }
+    Ok(())
+}
+
+pub(crate) fn ensure_scratch(op: &'static str, required: usize, available: usize) -> Result<()> {
+    if available < required {
+        return Err(CoreError::ScratchTooSmall { op, required, available });
+    }
+    Ok(())
+}
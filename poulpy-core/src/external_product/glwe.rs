@@ -4,7 +4,7 @@ use poulpy_hal::{
         VecZnxDftAddAssign, VecZnxDftApply, VecZnxDftBytesOf, VecZnxIdftApplyConsume, VecZnxNormalize, VecZnxNormalizeTmpBytes,
         VmpApplyDftToDft, VmpApplyDftToDftTmpBytes,
     },
-    layouts::{Backend, DataMut, DataViewMut, Module, Scratch, VecZnxBig, VecZnxDft, ZnxInfos, ZnxZero},
+    layouts::{AlignedBuf, Backend, DataMut, DataViewMut, Module, Scratch, VecZnxBig, VecZnxDft, ZnxInfos, ZnxZero},
 };
 
 pub use crate::api::GLWEExternalProduct;
@@ -44,7 +44,7 @@ where
                 k: a.max_k(),
                 rank: a.rank(),
             };
-            let lvl_2_0: usize = GLWE::<Vec<u8>>::bytes_of_from_infos(&a_conv_infos);
+            let lvl_2_0: usize = GLWE::<AlignedBuf>::bytes_of_from_infos(&a_conv_infos);
             let lvl_2_1: usize = self
                 .glwe_normalize_tmp_bytes()
                 .max(self.glwe_external_product_internal_tmp_bytes(res, &a_conv_infos, ggsw));
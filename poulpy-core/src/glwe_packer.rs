@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::{ModuleLogN, ScratchAvailable},
-    layouts::{Backend, GaloisElement, Module, Scratch},
+    layouts::{AlignedBuf, Backend, GaloisElement, Module, Scratch},
 };
 
 pub use crate::api::GLWEPackerOps;
@@ -26,7 +26,7 @@ pub struct GLWEPacker {
 /// [Accumulator] stores intermediate packing result.
 /// There are Log(N) such accumulators in a [GLWEPacker].
 pub(crate) struct Accumulator {
-    data: GLWE<Vec<u8>>,
+    data: GLWE<AlignedBuf>,
     value: bool,   // Implicit flag for zero ciphertext
     control: bool, // Can be combined with incoming value
 }
@@ -95,7 +95,7 @@ where
     K: GGLWEInfos,
     M: GLWEPackerOps<BE>,
 {
-    GLWE::<Vec<u8>>::bytes_of_from_infos(res_infos)
+    GLWE::<AlignedBuf>::bytes_of_from_infos(res_infos)
         + module
             .glwe_shift_tmp_bytes()
             .max(module.glwe_automorphism_tmp_bytes(res_infos, res_infos, key_infos))
@@ -148,7 +148,7 @@ where
 {
     assert!(packer.counter as u32 == packer.accumulators[0].data.n());
 
-    let out: &GLWE<Vec<u8>> = &packer.accumulators[module.log_n() - packer.log_batch - 1].data;
+    let out: &GLWE<AlignedBuf> = &packer.accumulators[module.log_n() - packer.log_batch - 1].data;
 
     if out.base2k() == res.base2k() {
         module.glwe_copy(res, out)
@@ -260,7 +260,7 @@ pub(crate) fn pack_core<A, K, H, M, BE: Backend>(
         if acc_prev[0].value {
             pack_core(module, Some(&acc_prev[0].data), acc_next, i + 1, auto_keys, scratch);
         } else {
-            pack_core(module, None::<&GLWE<Vec<u8>>>, acc_next, i + 1, auto_keys, scratch);
+            pack_core(module, None::<&GLWE<AlignedBuf>>, acc_next, i + 1, auto_keys, scratch);
         }
     }
 }
@@ -288,7 +288,7 @@ fn combine<B, K, H, M, BE: Backend>(
     Scratch<BE>: ScratchTakeCore<BE>,
 {
     let log_n: usize = acc.data.n().log2();
-    let a: &mut GLWE<Vec<u8>> = &mut acc.data;
+    let a: &mut GLWE<AlignedBuf> = &mut acc.data;
 
     let gal_el: i64 = if i == 0 { -1 } else { module.galois_element(1 << (i - 1)) };
 
@@ -2,13 +2,16 @@ use std::collections::HashMap;
 
 use poulpy_hal::{
     api::{ModuleLogN, ScratchAvailable},
-    layouts::{Backend, GaloisElement, Module, Scratch},
+    layouts::{AlignedBuf, Backend, GaloisElement, Module, Scratch},
 };
 
-pub use crate::api::GLWEPacking;
+pub use crate::api::{GLWEPackLWEs, GLWEPacking};
 use crate::{
-    GLWEAdd, GLWEAutomorphism, GLWECopy, GLWENormalize, GLWERotate, GLWEShift, GLWESub, GLWETrace, ScratchTakeCore,
-    layouts::{GGLWEInfos, GGLWEPreparedToRef, GLWE, GLWEAutomorphismKeyHelper, GLWEInfos, GLWEToMut, GetGaloisElement},
+    GLWEAdd, GLWEAutomorphism, GLWECopy, GLWEFromLWE, GLWENormalize, GLWERotate, GLWEShift, GLWESub, GLWETrace, ScratchTakeCore,
+    layouts::{
+        GGLWEInfos, GGLWEPreparedToRef, GLWE, GLWEAutomorphismKeyHelper, GLWEInfos, GLWEToMut, GetGaloisElement, LWEInfos,
+        LWEToRef,
+    },
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -109,7 +112,7 @@ where
         assert_eq!(self.n() as u32, res.n());
         assert_eq!(self.n() as u32, key.n());
 
-        let lvl_0: usize = GLWE::<Vec<u8>>::bytes_of_from_infos(res);
+        let lvl_0: usize = GLWE::<AlignedBuf>::bytes_of_from_infos(res);
         let lvl_1: usize = self
             .glwe_rotate_tmp_bytes()
             .max(self.glwe_shift_tmp_bytes())
@@ -186,3 +189,150 @@ where
     Scratch<BE>: ScratchTakeCore<BE>,
 {
 }
+
+/// Packs the LWE ciphertexts of the subtree rooted at `j` at depth `i` into `res`.
+///
+/// Leaves (`i = 0`) are converted with [`GLWEFromLWE`], inner nodes combine the
+/// subtrees `j` and `j + N/2^i` with the step `i - 1` of [`GLWEPacking`], so
+/// that only one temporary per level is alive at a time. Returns `false` if the
+/// subtree holds no ciphertext, in which case `res` is left untouched.
+#[allow(clippy::too_many_arguments)]
+fn pack_lwes_internal<M, A, K, T, H, BE: Backend>(
+    module: &M,
+    res: &mut GLWE<&mut [u8]>,
+    j: usize,
+    i: usize,
+    lwes: &[A],
+    ksk: &K,
+    keys: &H,
+    scratch: &mut Scratch<BE>,
+) -> bool
+where
+    M: GLWEFromLWE<BE>
+        + GLWEAutomorphism<BE>
+        + GaloisElement
+        + ModuleLogN
+        + GLWERotate<BE>
+        + GLWESub
+        + GLWEShift<BE>
+        + GLWEAdd
+        + GLWENormalize<BE>
+        + ?Sized,
+    A: LWEToRef,
+    K: GGLWEPreparedToRef<BE> + GGLWEInfos,
+    T: GGLWEPreparedToRef<BE> + GetGaloisElement + GGLWEInfos,
+    H: GLWEAutomorphismKeyHelper<T, BE>,
+    Scratch<BE>: ScratchTakeCore<BE>,
+{
+    if j >= lwes.len() {
+        return false;
+    }
+
+    if i == 0 {
+        module.glwe_from_lwe(res, &lwes[j], ksk, scratch);
+        return true;
+    }
+
+    // j < lwes.len(), so the left subtree always holds the ciphertext j.
+    pack_lwes_internal(module, res, j, i - 1, lwes, ksk, keys, scratch);
+
+    let (mut tmp, scratch_1) = scratch.take_glwe(res);
+    let has_tmp: bool = pack_lwes_internal(module, &mut tmp, j + (module.n() >> i), i - 1, lwes, ksk, keys, scratch_1);
+
+    let step: usize = i - 1;
+    let key: &T = if step == 0 {
+        keys.get_automorphism_key(-1).unwrap()
+    } else {
+        keys.get_automorphism_key(module.galois_element(1 << (step - 1))).unwrap()
+    };
+
+    pack_internal(module, &mut Some(res), &mut has_tmp.then_some(&mut tmp), step, key, scratch_1);
+
+    true
+}
+
+#[doc(hidden)]
+pub trait GLWEPackLWEsDefault<BE: Backend>
+where
+    Self: GLWEFromLWE<BE>
+        + GLWEAutomorphism<BE>
+        + GaloisElement
+        + ModuleLogN
+        + GLWERotate<BE>
+        + GLWESub
+        + GLWEShift<BE>
+        + GLWEAdd
+        + GLWENormalize<BE>
+        + GLWETrace<BE>,
+{
+    fn glwe_pack_lwes_galois_elements_default(&self) -> Vec<i64> {
+        self.glwe_trace_galois_elements()
+    }
+
+    fn glwe_pack_lwes_tmp_bytes_default<R, A, K, T>(&self, res_infos: &R, lwe_infos: &A, ksk_infos: &K, atk_infos: &T) -> usize
+    where
+        R: GLWEInfos,
+        A: LWEInfos,
+        K: GGLWEInfos,
+        T: GGLWEInfos,
+    {
+        assert_eq!(self.n() as u32, res_infos.n());
+        assert_eq!(self.n() as u32, atk_infos.n());
+
+        // One pending subtree per level of the packing tree.
+        let lvl_0: usize = self.log_n() * GLWE::<AlignedBuf>::bytes_of_from_infos(res_infos);
+
+        let lvl_1_conv: usize = self.glwe_from_lwe_tmp_bytes(res_infos, lwe_infos, ksk_infos);
+        let lvl_1_pack: usize = GLWE::<AlignedBuf>::bytes_of_from_infos(res_infos)
+            + self
+                .glwe_rotate_tmp_bytes()
+                .max(self.glwe_shift_tmp_bytes())
+                .max(self.glwe_normalize_tmp_bytes())
+                .max(self.glwe_automorphism_tmp_bytes(res_infos, res_infos, atk_infos));
+        let lvl_1: usize = lvl_1_conv.max(lvl_1_pack);
+
+        lvl_0 + lvl_1
+    }
+
+    fn glwe_pack_lwes_default<R, A, K, T, H>(&self, res: &mut R, lwes: &[A], ksk: &K, keys: &H, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: LWEToRef,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos,
+        T: GGLWEPreparedToRef<BE> + GetGaloisElement + GGLWEInfos,
+        H: GLWEAutomorphismKeyHelper<T, BE>,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        let res: &mut GLWE<&mut [u8]> = &mut res.to_mut();
+
+        assert!(!lwes.is_empty(), "lwes is empty");
+        assert!(lwes.len() <= self.n(), "lwes.len(): {} > n: {}", lwes.len(), self.n());
+
+        let lwe_infos = lwes[0].to_ref();
+        let atk_infos = keys.automorphism_key_infos();
+        assert!(
+            scratch.available() >= self.glwe_pack_lwes_tmp_bytes_default(res, &lwe_infos, ksk, &atk_infos),
+            "scratch.available(): {} < GLWEPackLWEs::glwe_pack_lwes_tmp_bytes: {}",
+            scratch.available(),
+            self.glwe_pack_lwes_tmp_bytes_default(res, &lwe_infos, ksk, &atk_infos)
+        );
+
+        pack_lwes_internal(self, res, 0, self.log_n(), lwes, ksk, keys, scratch);
+    }
+}
+
+impl<BE: Backend> GLWEPackLWEsDefault<BE> for Module<BE>
+where
+    Self: GLWEFromLWE<BE>
+        + GLWEAutomorphism<BE>
+        + GaloisElement
+        + ModuleLogN
+        + GLWERotate<BE>
+        + GLWESub
+        + GLWEShift<BE>
+        + GLWEAdd
+        + GLWENormalize<BE>
+        + GLWETrace<BE>,
+    Scratch<BE>: ScratchTakeCore<BE>,
+{
+}
@@ -0,0 +1,231 @@
+use poulpy_hal::{
+    api::{
+        ModuleN, ScratchAvailable, ScratchTakeBasic, VecZnxAddAssign, VecZnxRotate, VecZnxSplitRing, VecZnxSplitRingTmpBytes,
+        VecZnxSwitchRing,
+    },
+    layouts::{AlignedBuf, Backend, Module, Scratch, VecZnx},
+};
+
+pub use crate::api::GLWERingSwitch;
+use crate::{
+    GLWEKeyswitch, ScratchTakeCore,
+    layouts::{
+        GGLWEInfos, GGLWEPreparedToRef, GLWE, GLWEInfos, GLWELayout, GLWESwitchingKeyDegrees, GLWEToMut, GLWEToRef, LWEInfos,
+    },
+};
+
+#[doc(hidden)]
+pub trait GLWERingSwitchDefault<BE: Backend>
+where
+    Self: ModuleN
+        + GLWEKeyswitch<BE>
+        + VecZnxSwitchRing
+        + VecZnxSplitRing<BE>
+        + VecZnxSplitRingTmpBytes
+        + VecZnxRotate
+        + VecZnxAddAssign,
+    Scratch<BE>: ScratchTakeCore<BE>,
+{
+    fn glwe_ring_switch_tmp_bytes_default<R, A, K>(&self, res_infos: &R, a_infos: &A, key_infos: &K) -> usize
+    where
+        R: GLWEInfos,
+        A: GLWEInfos,
+        K: GGLWEInfos,
+    {
+        assert_eq!(self.n() as u32, key_infos.n());
+
+        if a_infos.n() > res_infos.n() {
+            // N -> N/2: key-switch at degree N, then extract the subring(s).
+            let tmp_infos: GLWELayout = GLWELayout {
+                n: a_infos.n(),
+                base2k: res_infos.base2k(),
+                k: res_infos.max_k(),
+                rank: res_infos.rank(),
+            };
+            let lvl_0: usize = GLWE::<AlignedBuf>::bytes_of_from_infos(&tmp_infos);
+            let lvl_1: usize = self
+                .glwe_keyswitch_tmp_bytes(&tmp_infos, a_infos, key_infos)
+                .max(self.vec_znx_split_ring_tmp_bytes());
+            lvl_0 + lvl_1
+        } else {
+            // N/2 -> N: embed (or merge) at degree N, then key-switch.
+            // Merging needs one extra column at degree N for the odd part.
+            let tmp_infos: GLWELayout = GLWELayout {
+                n: res_infos.n(),
+                base2k: a_infos.base2k(),
+                k: a_infos.max_k(),
+                rank: a_infos.rank(),
+            };
+            let lvl_0: usize = GLWE::<AlignedBuf>::bytes_of_from_infos(&tmp_infos);
+            let lvl_1: usize = self
+                .glwe_keyswitch_tmp_bytes(res_infos, &tmp_infos, key_infos)
+                .max(VecZnx::bytes_of(res_infos.n().into(), 1, a_infos.size()));
+            lvl_0 + lvl_1
+        }
+    }
+
+    fn glwe_ring_switch_default<R, A, K>(&self, res: &mut R, a: &A, key: &K, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos + GLWESwitchingKeyDegrees,
+    {
+        assert_ring_switch_degrees(self.n(), a.n().as_usize(), res.n().as_usize(), key);
+        assert!(
+            scratch.available() >= self.glwe_ring_switch_tmp_bytes_default(res, a, key),
+            "scratch.available(): {} < GLWERingSwitch::glwe_ring_switch_tmp_bytes: {}",
+            scratch.available(),
+            self.glwe_ring_switch_tmp_bytes_default(res, a, key)
+        );
+
+        let cols: usize = (res.rank() + 1).into();
+
+        if a.n() > res.n() {
+            let (mut tmp, scratch_1) = scratch.take_glwe(&GLWELayout {
+                n: a.n(),
+                base2k: res.base2k(),
+                k: res.max_k(),
+                rank: res.rank(),
+            });
+            self.glwe_keyswitch(&mut tmp, a, key, scratch_1);
+
+            let res: &mut GLWE<&mut [u8]> = &mut res.to_mut();
+            for i in 0..cols {
+                self.vec_znx_switch_ring(res.data_mut(), i, tmp.data(), i);
+            }
+        } else {
+            let (mut tmp, scratch_1) = scratch.take_glwe(&GLWELayout {
+                n: res.n(),
+                base2k: a.base2k(),
+                k: a.max_k(),
+                rank: a.rank(),
+            });
+
+            {
+                let a: &GLWE<&[u8]> = &a.to_ref();
+                for i in 0..cols {
+                    self.vec_znx_switch_ring(tmp.data_mut(), i, a.data(), i);
+                }
+            }
+
+            self.glwe_keyswitch(res, &tmp, key, scratch_1);
+        }
+    }
+
+    fn glwe_ring_split_default<R, A, K>(&self, res_even: &mut R, res_odd: &mut R, a: &A, key: &K, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos + GLWESwitchingKeyDegrees,
+    {
+        assert_eq!(a.n().as_usize(), self.n(), "a.n(): {} != module.n(): {}", a.n(), self.n());
+        assert_ring_switch_degrees(self.n(), a.n().as_usize(), res_even.n().as_usize(), key);
+        assert_eq!(res_even.n(), res_odd.n());
+        assert_eq!(res_even.base2k(), res_odd.base2k());
+        assert_eq!(res_even.rank(), res_odd.rank());
+        assert!(
+            scratch.available() >= self.glwe_ring_switch_tmp_bytes_default(res_even, a, key),
+            "scratch.available(): {} < GLWERingSwitch::glwe_ring_switch_tmp_bytes: {}",
+            scratch.available(),
+            self.glwe_ring_switch_tmp_bytes_default(res_even, a, key)
+        );
+
+        let cols: usize = (res_even.rank() + 1).into();
+
+        let (mut tmp, scratch_1) = scratch.take_glwe(&GLWELayout {
+            n: a.n(),
+            base2k: res_even.base2k(),
+            k: res_even.max_k(),
+            rank: res_even.rank(),
+        });
+        self.glwe_keyswitch(&mut tmp, a, key, scratch_1);
+
+        let mut res: [VecZnx<&mut [u8]>; 2] = [res_even.to_mut().data, res_odd.to_mut().data];
+        for i in 0..cols {
+            self.vec_znx_split_ring(&mut res, i, tmp.data(), i, scratch_1);
+        }
+    }
+
+    fn glwe_ring_merge_default<R, A, K>(&self, res: &mut R, a_even: &A, a_odd: &A, key: &K, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos + GLWESwitchingKeyDegrees,
+    {
+        assert_eq!(
+            res.n().as_usize(),
+            self.n(),
+            "res.n(): {} != module.n(): {}",
+            res.n(),
+            self.n()
+        );
+        assert_ring_switch_degrees(self.n(), a_even.n().as_usize(), res.n().as_usize(), key);
+        assert_eq!(a_even.n(), a_odd.n());
+        assert_eq!(a_even.base2k(), a_odd.base2k());
+        assert_eq!(a_even.rank(), a_odd.rank());
+        assert!(
+            scratch.available() >= self.glwe_ring_switch_tmp_bytes_default(res, a_even, key),
+            "scratch.available(): {} < GLWERingSwitch::glwe_ring_switch_tmp_bytes: {}",
+            scratch.available(),
+            self.glwe_ring_switch_tmp_bytes_default(res, a_even, key)
+        );
+
+        let cols: usize = (res.rank() + 1).into();
+
+        let (mut tmp, scratch_1) = scratch.take_glwe(&GLWELayout {
+            n: res.n(),
+            base2k: a_even.base2k(),
+            k: a_even.max_k(),
+            rank: a_even.rank(),
+        });
+
+        {
+            // tmp = a_even(X^2) + X * a_odd(X^2)
+            let a_even: &GLWE<&[u8]> = &a_even.to_ref();
+            let a_odd: &GLWE<&[u8]> = &a_odd.to_ref();
+            let (mut odd, _) = scratch_1.take_vec_znx(self.n(), 1, a_even.size());
+            for i in 0..cols {
+                self.vec_znx_switch_ring(&mut odd, 0, a_odd.data(), i);
+                self.vec_znx_rotate(1, tmp.data_mut(), i, &odd, 0);
+                self.vec_znx_switch_ring(&mut odd, 0, a_even.data(), i);
+                self.vec_znx_add_assign(tmp.data_mut(), i, &odd, 0);
+            }
+        }
+
+        self.glwe_keyswitch(res, &tmp, key, scratch_1);
+    }
+}
+
+impl<BE: Backend> GLWERingSwitchDefault<BE> for Module<BE>
+where
+    Self: ModuleN
+        + GLWEKeyswitch<BE>
+        + VecZnxSwitchRing
+        + VecZnxSplitRing<BE>
+        + VecZnxSplitRingTmpBytes
+        + VecZnxRotate
+        + VecZnxAddAssign,
+    Scratch<BE>: ScratchTakeCore<BE>,
+{
+}
+
+/// Checks that `n_in -> n_out` is one of `N -> N/2` or `N/2 -> N` for the
+/// module degree `n`, and that `key` switches between the same degrees.
+fn assert_ring_switch_degrees<K: GLWESwitchingKeyDegrees>(n: usize, n_in: usize, n_out: usize, key: &K) {
+    assert!(
+        (n_in == n && n_out == n >> 1) || (n_in == n >> 1 && n_out == n),
+        "ring switching requires degrees (N, N/2) or (N/2, N) with N={n}, got ({n_in}, {n_out})"
+    );
+    assert_eq!(
+        key.input_degree().as_usize(),
+        n_in,
+        "key.input_degree(): {} != {n_in}",
+        key.input_degree()
+    );
+    assert_eq!(
+        key.output_degree().as_usize(),
+        n_out,
+        "key.output_degree(): {} != {n_out}",
+        key.output_degree()
+    );
+}
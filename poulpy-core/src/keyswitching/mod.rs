@@ -1,11 +1,13 @@
 mod gglwe;
 mod ggsw;
 mod glwe;
+mod glwe_ring_switch;
 mod lwe;
 
 pub use gglwe::*;
 pub use ggsw::*;
 pub use glwe::*;
+pub use glwe_ring_switch::*;
 pub use lwe::*;
 
 pub(crate) use glwe::GLWEKeyswitchDefault;
@@ -1,7 +1,8 @@
 use poulpy_hal::{
     api::{VecZnxCopy, VecZnxFillUniform},
     layouts::{
-        Backend, Data, DataMut, DataRef, FillUniform, MatZnx, MatZnxToMut, MatZnxToRef, Module, ReaderFrom, WriterTo, ZnxInfos,
+        AlignedBuf, Backend, Data, DataMut, DataRef, FillUniform, MatZnx, MatZnxToMut, MatZnxToRef, Module, ReaderFrom, WriterTo,
+        ZnxInfos,
     },
     source::Source,
 };
@@ -19,6 +20,11 @@ use std::fmt;
 /// the mask polynomials are regenerated deterministically from 32-byte
 /// PRNG seeds during decompression.
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "MatZnx<D>: serde::Serialize"))
+)]
 pub struct GGLWECompressed<D: Data> {
     pub(crate) data: MatZnx<D>,
     pub(crate) base2k: Base2K,
@@ -110,7 +116,7 @@ impl<D: DataRef> fmt::Display for GGLWECompressed<D> {
     }
 }
 
-impl GGLWECompressed<Vec<u8>> {
+impl GGLWECompressed<AlignedBuf> {
     /// Allocates a new compressed GGLWE by copying parameters from an existing info provider.
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
@@ -1,5 +1,5 @@
 use poulpy_hal::{
-    layouts::{Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
+    layouts::{AlignedBuf, Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
     source::Source,
 };
 
@@ -17,6 +17,11 @@ use std::fmt;
 /// used for GGLWE-to-GGSW conversion. The mask of each GGLWE is
 /// regenerated from its PRNG seed during decompression.
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "GGLWECompressed<D>: serde::Serialize"))
+)]
 pub struct GGLWEToGGSWKeyCompressed<D: Data> {
     pub(crate) keys: Vec<GGLWECompressed<D>>,
 }
@@ -83,7 +88,7 @@ impl<D: DataRef> fmt::Display for GGLWEToGGSWKeyCompressed<D> {
     }
 }
 
-impl GGLWEToGGSWKeyCompressed<Vec<u8>> {
+impl GGLWEToGGSWKeyCompressed<AlignedBuf> {
     /// Allocates a new compressed GGLWE-to-GGSW key by copying parameters from an existing info provider.
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
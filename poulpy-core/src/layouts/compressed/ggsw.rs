@@ -1,6 +1,7 @@
 use poulpy_hal::{
     layouts::{
-        Backend, Data, DataMut, DataRef, FillUniform, MatZnx, MatZnxToMut, MatZnxToRef, Module, ReaderFrom, WriterTo, ZnxInfos,
+        AlignedBuf, Backend, Data, DataMut, DataRef, FillUniform, MatZnx, MatZnxToMut, MatZnxToRef, Module, ReaderFrom, WriterTo,
+        ZnxInfos,
     },
     source::Source,
 };
@@ -18,6 +19,11 @@ use std::fmt;
 /// polynomials are regenerated deterministically from 32-byte PRNG
 /// seeds during decompression.
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "MatZnx<D>: serde::Serialize"))
+)]
 pub struct GGSWCompressed<D: Data> {
     pub(crate) data: MatZnx<D>,
     pub(crate) k: TorusPrecision,
@@ -102,7 +108,7 @@ impl<D: DataMut> FillUniform for GGSWCompressed<D> {
     }
 }
 
-impl GGSWCompressed<Vec<u8>> {
+impl GGSWCompressed<AlignedBuf> {
     /// Allocates a new compressed GGSW by copying parameters from an existing info provider.
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
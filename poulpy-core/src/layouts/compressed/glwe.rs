@@ -1,7 +1,8 @@
 use poulpy_hal::{
     api::{VecZnxCopy, VecZnxFillUniform},
     layouts::{
-        Backend, Data, DataMut, DataRef, FillUniform, Module, ReaderFrom, VecZnx, VecZnxToMut, VecZnxToRef, WriterTo, ZnxInfos,
+        AlignedBuf, Backend, Data, DataMut, DataRef, FillUniform, Module, ReaderFrom, VecZnx, VecZnxToMut, VecZnxToRef, WriterTo,
+        ZnxInfos,
     },
     source::Source,
 };
@@ -17,6 +18,11 @@ use std::fmt;
 /// seed during decompression. This reduces the serialized size by a
 /// factor proportional to the rank.
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "VecZnx<D>: serde::Serialize"))
+)]
 pub struct GLWECompressed<D: Data> {
     pub(crate) data: VecZnx<D>,
     pub(crate) base2k: Base2K,
@@ -93,7 +99,7 @@ impl<D: DataMut> FillUniform for GLWECompressed<D> {
     }
 }
 
-impl GLWECompressed<Vec<u8>> {
+impl GLWECompressed<AlignedBuf> {
     /// Allocates a new compressed GLWE by copying parameters from an existing info provider.
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
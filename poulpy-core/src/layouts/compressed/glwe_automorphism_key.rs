@@ -1,5 +1,5 @@
 use poulpy_hal::{
-    layouts::{Backend, Data, DataMut, DataRef, FillUniform, Module, ReaderFrom, WriterTo},
+    layouts::{AlignedBuf, Backend, Data, DataMut, DataRef, FillUniform, Module, ReaderFrom, WriterTo},
     source::Source,
 };
 
@@ -16,6 +16,11 @@ use std::fmt;
 /// Wraps a [`GGLWECompressed`] with a Galois element `p` for applying
 /// automorphisms `X → X^p` on GLWE ciphertexts.
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "GGLWECompressed<D>: serde::Serialize"))
+)]
 pub struct GLWEAutomorphismKeyCompressed<D: Data> {
     pub(crate) key: GGLWECompressed<D>,
     pub(crate) p: i64,
@@ -83,7 +88,7 @@ impl<D: DataRef> fmt::Display for GLWEAutomorphismKeyCompressed<D> {
     }
 }
 
-impl GLWEAutomorphismKeyCompressed<Vec<u8>> {
+impl GLWEAutomorphismKeyCompressed<AlignedBuf> {
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
         A: GGLWEInfos,
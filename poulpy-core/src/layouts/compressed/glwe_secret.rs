@@ -0,0 +1,155 @@
+use poulpy_hal::{
+    layouts::{FillUniform, ReaderFrom, WriterTo},
+    source::Source,
+};
+
+use crate::{
+    GetDistribution,
+    dist::Distribution,
+    layouts::{Base2K, Degree, GLWEInfos, LWEInfos, Rank},
+};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use std::fmt;
+use zeroize::Zeroize;
+
+/// Seed-compressed GLWE secret key.
+///
+/// Stores only the ring degree, the rank, the sampling [`Distribution`]
+/// and a 32-byte PRNG seed. The secret coefficients are regenerated
+/// deterministically with [`GLWESecret::decompress`](crate::layouts::GLWESecret::decompress),
+/// which makes this representation a compact alternative to serialising
+/// the full [`GLWESecret`](crate::layouts::GLWESecret).
+#[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GLWESecretCompressed {
+    pub(crate) n: Degree,
+    pub(crate) rank: Rank,
+    pub(crate) dist: Distribution,
+    pub(crate) seed: [u8; 32],
+}
+
+/// Zeroizes the seed, from which the secret can be regenerated.
+impl Drop for GLWESecretCompressed {
+    fn drop(&mut self) {
+        self.seed.zeroize();
+    }
+}
+
+impl LWEInfos for GLWESecretCompressed {
+    fn base2k(&self) -> Base2K {
+        Base2K(0)
+    }
+
+    fn n(&self) -> Degree {
+        self.n
+    }
+
+    fn size(&self) -> usize {
+        1
+    }
+}
+
+impl GLWEInfos for GLWESecretCompressed {
+    fn rank(&self) -> Rank {
+        self.rank
+    }
+}
+
+impl GetDistribution for GLWESecretCompressed {
+    fn dist(&self) -> &Distribution {
+        &self.dist
+    }
+}
+
+impl fmt::Debug for GLWESecretCompressed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl fmt::Display for GLWESecretCompressed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "GLWESecretCompressed: n={} rank={} dist={:?} seed=<redacted>",
+            self.n, self.rank, self.dist
+        )
+    }
+}
+
+/// Fills the seed with fresh random bytes, leaving the distribution untouched.
+impl FillUniform for GLWESecretCompressed {
+    fn fill_uniform(&mut self, _log_bound: usize, source: &mut Source) {
+        self.seed = source.new_seed();
+    }
+}
+
+impl GLWESecretCompressed {
+    /// Creates a compressed secret that expands to a secret of degree `n` and
+    /// rank `rank`, sampled from `dist` with a [`Source`] seeded by `seed`.
+    pub fn new(n: Degree, rank: Rank, dist: Distribution, seed: [u8; 32]) -> Self {
+        GLWESecretCompressed { n, rank, dist, seed }
+    }
+
+    /// Allocates an empty compressed secret by copying parameters from an existing info provider.
+    pub fn alloc_from_infos<A>(infos: &A) -> Self
+    where
+        A: GLWEInfos,
+    {
+        Self::alloc(infos.n(), infos.rank())
+    }
+
+    /// Allocates an empty compressed secret with [`Distribution::NONE`] and an all-zero seed.
+    pub fn alloc(n: Degree, rank: Rank) -> Self {
+        Self::new(n, rank, Distribution::NONE, [0u8; 32])
+    }
+
+    /// Returns the serialized byte size of a compressed secret.
+    pub fn bytes_of() -> usize {
+        2 * size_of::<u32>() + size_of::<u64>() + 32
+    }
+
+    /// Returns the 32-byte PRNG seed.
+    pub fn seed(&self) -> &[u8; 32] {
+        &self.seed
+    }
+
+    /// Draws a fresh seed from `source` and records `dist` as the sampling distribution.
+    pub fn fill_dist(&mut self, dist: Distribution, source: &mut Source) {
+        self.seed = source.new_seed();
+        self.dist = dist;
+    }
+}
+
+/// Deserializes the metadata (n, rank, distribution) followed by the seed.
+///
+/// Fails with [`std::io::ErrorKind::InvalidData`] on a [`Distribution::NONE`]
+/// tag: such a secret cannot be decompressed.
+impl ReaderFrom for GLWESecretCompressed {
+    fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        let n: Degree = Degree(reader.read_u32::<LittleEndian>()?);
+        let rank: Rank = Rank(reader.read_u32::<LittleEndian>()?);
+        let dist: Distribution = Distribution::read_from(reader)?;
+        if dist == Distribution::NONE {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "GLWESecretCompressed with Distribution::NONE cannot be decompressed",
+            ));
+        }
+        reader.read_exact(&mut self.seed)?;
+        self.n = n;
+        self.rank = rank;
+        self.dist = dist;
+        Ok(())
+    }
+}
+
+/// Serializes the metadata (n, rank, distribution) followed by the seed.
+impl WriterTo for GLWESecretCompressed {
+    fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_u32::<LittleEndian>(self.n.into())?;
+        writer.write_u32::<LittleEndian>(self.rank.into())?;
+        self.dist.write_to(writer)?;
+        writer.write_all(&self.seed)
+    }
+}
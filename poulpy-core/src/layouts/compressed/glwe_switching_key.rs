@@ -1,5 +1,5 @@
 use poulpy_hal::{
-    layouts::{Backend, Data, DataMut, DataRef, FillUniform, Module, ReaderFrom, WriterTo},
+    layouts::{AlignedBuf, Backend, Data, DataMut, DataRef, FillUniform, Module, ReaderFrom, WriterTo},
     source::Source,
 };
 
@@ -16,6 +16,11 @@ use std::fmt;
 /// Wraps a [`GGLWECompressed`] with additional input/output degree metadata
 /// for key-switching between GLWE ciphertexts with different ring degrees.
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "GGLWECompressed<D>: serde::Serialize"))
+)]
 pub struct GLWESwitchingKeyCompressed<D: Data> {
     pub(crate) key: GGLWECompressed<D>,
     pub(crate) input_degree: Degree,  // Degree of sk_in
@@ -107,7 +112,7 @@ impl<D: DataRef> fmt::Display for GLWESwitchingKeyCompressed<D> {
     }
 }
 
-impl GLWESwitchingKeyCompressed<Vec<u8>> {
+impl GLWESwitchingKeyCompressed<AlignedBuf> {
     /// Allocates a new compressed GLWE switching key by copying parameters from an existing info provider.
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
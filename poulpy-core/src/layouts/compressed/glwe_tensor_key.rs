@@ -1,5 +1,5 @@
 use poulpy_hal::{
-    layouts::{Backend, Data, DataMut, DataRef, FillUniform, Module, ReaderFrom, WriterTo},
+    layouts::{AlignedBuf, Backend, Data, DataMut, DataRef, FillUniform, Module, ReaderFrom, WriterTo},
     source::Source,
 };
 
@@ -14,6 +14,11 @@ use std::fmt;
 /// A newtype wrapper around [`GGLWECompressed`] representing
 /// the seed-compressed form of a GLWE tensor key.
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "GGLWECompressed<D>: serde::Serialize"))
+)]
 pub struct GLWETensorKeyCompressed<D: Data>(pub(crate) GGLWECompressed<D>);
 
 impl<D: DataMut> GGLWECompressedSeedMut for GLWETensorKeyCompressed<D> {
@@ -79,7 +84,7 @@ impl<D: DataRef> fmt::Display for GLWETensorKeyCompressed<D> {
     }
 }
 
-impl GLWETensorKeyCompressed<Vec<u8>> {
+impl GLWETensorKeyCompressed<AlignedBuf> {
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
         A: GGLWEInfos,
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use poulpy_hal::{
-    layouts::{Backend, Data, DataMut, DataRef, FillUniform, Module, ReaderFrom, WriterTo},
+    layouts::{AlignedBuf, Backend, Data, DataMut, DataRef, FillUniform, Module, ReaderFrom, WriterTo},
     source::Source,
 };
 
@@ -16,6 +16,11 @@ use crate::layouts::{
 /// A newtype wrapper around [`GLWESwitchingKeyCompressed`] for converting
 /// GLWE ciphertexts to LWE ciphertexts via key-switching.
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "GLWESwitchingKeyCompressed<D>: serde::Serialize"))
+)]
 pub struct GLWEToLWESwitchingKeyCompressed<D: Data>(pub(crate) GLWESwitchingKeyCompressed<D>);
 
 impl<D: Data> LWEInfos for GLWEToLWESwitchingKeyCompressed<D> {
@@ -85,7 +90,7 @@ impl<D: DataRef> WriterTo for GLWEToLWESwitchingKeyCompressed<D> {
     }
 }
 
-impl GLWEToLWESwitchingKeyCompressed<Vec<u8>> {
+impl GLWEToLWESwitchingKeyCompressed<AlignedBuf> {
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
         A: GGLWEInfos,
@@ -3,8 +3,8 @@ use std::fmt;
 use poulpy_hal::{
     api::VecZnxFillUniform,
     layouts::{
-        Backend, Data, DataMut, DataRef, FillUniform, Module, ReaderFrom, VecZnx, VecZnxToMut, VecZnxToRef, WriterTo, ZnxInfos,
-        ZnxView, ZnxViewMut,
+        AlignedBuf, Backend, Data, DataMut, DataRef, FillUniform, Module, ReaderFrom, VecZnx, VecZnxToMut, VecZnxToRef, WriterTo,
+        ZnxInfos, ZnxView, ZnxViewMut,
     },
     source::Source,
 };
@@ -17,6 +17,11 @@ use crate::layouts::{Base2K, Degree, LWE, LWEInfos, LWEToMut, TorusPrecision};
 /// mask coefficients are regenerated deterministically from a 32-byte
 /// PRNG seed during decompression.
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "VecZnx<D>: serde::Serialize"))
+)]
 pub struct LWECompressed<D: Data> {
     pub(crate) data: VecZnx<D>,
     pub(crate) k: TorusPrecision,
@@ -63,7 +68,7 @@ impl<D: DataMut> FillUniform for LWECompressed<D> {
     }
 }
 
-impl LWECompressed<Vec<u8>> {
+impl LWECompressed<AlignedBuf> {
     /// Allocates a new compressed LWE by copying parameters from an existing info provider.
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
@@ -1,5 +1,5 @@
 use poulpy_hal::{
-    layouts::{Backend, Data, DataMut, DataRef, FillUniform, Module, ReaderFrom, WriterTo},
+    layouts::{AlignedBuf, Backend, Data, DataMut, DataRef, FillUniform, Module, ReaderFrom, WriterTo},
     source::Source,
 };
 
@@ -15,6 +15,11 @@ use std::fmt;
 /// A newtype wrapper around [`GLWESwitchingKeyCompressed`] for key-switching
 /// between LWE ciphertexts encrypted under different keys.
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "GLWESwitchingKeyCompressed<D>: serde::Serialize"))
+)]
 pub struct LWESwitchingKeyCompressed<D: Data>(pub(crate) GLWESwitchingKeyCompressed<D>);
 
 impl<D: Data> LWEInfos for LWESwitchingKeyCompressed<D> {
@@ -83,7 +88,7 @@ impl<D: DataRef> WriterTo for LWESwitchingKeyCompressed<D> {
     }
 }
 
-impl LWESwitchingKeyCompressed<Vec<u8>> {
+impl LWESwitchingKeyCompressed<AlignedBuf> {
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
         A: GGLWEInfos,
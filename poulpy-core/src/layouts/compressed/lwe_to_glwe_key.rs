@@ -1,5 +1,5 @@
 use poulpy_hal::{
-    layouts::{Backend, Data, DataMut, DataRef, FillUniform, Module, ReaderFrom, WriterTo},
+    layouts::{AlignedBuf, Backend, Data, DataMut, DataRef, FillUniform, Module, ReaderFrom, WriterTo},
     source::Source,
 };
 
@@ -15,6 +15,11 @@ use std::fmt;
 /// A newtype wrapper around [`GLWESwitchingKeyCompressed`] for converting
 /// LWE ciphertexts to GLWE ciphertexts.
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "GLWESwitchingKeyCompressed<D>: serde::Serialize"))
+)]
 pub struct LWEToGLWEKeyCompressed<D: Data>(pub(crate) GLWESwitchingKeyCompressed<D>);
 
 impl<D: Data> LWEInfos for LWEToGLWEKeyCompressed<D> {
@@ -84,7 +89,7 @@ impl<D: DataRef> WriterTo for LWEToGLWEKeyCompressed<D> {
     }
 }
 
-impl LWEToGLWEKeyCompressed<Vec<u8>> {
+impl LWEToGLWEKeyCompressed<AlignedBuf> {
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
         A: GGLWEInfos,
@@ -8,17 +8,21 @@
 //!
 //! Every compressed type has a `decompress` / `GLWEDecompress`
 //! method that expands it back into the corresponding standard
-//! layout.
+//! layout. Secret keys ([`GLWESecretCompressed`], [`LWESecretCompressed`])
+//! store no body at all: the whole secret is resampled from the seed
+//! and its [`Distribution`](crate::Distribution).
 
 mod gglwe;
 mod gglwe_to_ggsw_key;
 mod ggsw;
 mod glwe;
 mod glwe_automorphism_key;
+mod glwe_secret;
 mod glwe_switching_key;
 mod glwe_tensor_key;
 mod glwe_to_lwe_key;
 mod lwe;
+mod lwe_secret;
 mod lwe_switching_key;
 mod lwe_to_glwe_key;
 
@@ -27,9 +31,11 @@ pub use gglwe_to_ggsw_key::*;
 pub use ggsw::*;
 pub use glwe::*;
 pub use glwe_automorphism_key::*;
+pub use glwe_secret::*;
 pub use glwe_switching_key::*;
 pub use glwe_tensor_key::*;
 pub use glwe_to_lwe_key::*;
 pub use lwe::*;
+pub use lwe_secret::*;
 pub use lwe_switching_key::*;
 pub use lwe_to_glwe_key::*;
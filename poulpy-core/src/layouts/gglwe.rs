@@ -1,5 +1,7 @@
 use poulpy_hal::{
-    layouts::{Data, DataMut, DataRef, FillUniform, MatZnx, MatZnxToMut, MatZnxToRef, ReaderFrom, WriterTo, ZnxInfos},
+    layouts::{
+        AlignedBuf, Data, DataMut, DataRef, FillUniform, MatZnx, MatZnxToMut, MatZnxToRef, ReaderFrom, WriterTo, ZnxInfos,
+    },
     source::Source,
 };
 
@@ -83,6 +85,11 @@ impl GGLWEInfos for GGLWELayout {
 }
 
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "MatZnx<D>: serde::Serialize"))
+)]
 pub struct GGLWE<D: Data> {
     pub(crate) data: MatZnx<D>,
     pub(crate) base2k: Base2K,
@@ -182,7 +189,7 @@ impl<D: DataMut> GGLWE<D> {
     }
 }
 
-impl GGLWE<Vec<u8>> {
+impl GGLWE<AlignedBuf> {
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
         A: GGLWEInfos,
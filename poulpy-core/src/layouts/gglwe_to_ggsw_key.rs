@@ -1,5 +1,5 @@
 use poulpy_hal::{
-    layouts::{Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
+    layouts::{AlignedBuf, Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
     source::Source,
 };
 
@@ -30,6 +30,11 @@ impl DeclaredK for GGLWEToGGSWKeyLayout {
 }
 
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "GGLWE<D>: serde::Serialize"))
+)]
 pub struct GGLWEToGGSWKey<D: Data> {
     pub(crate) keys: Vec<GGLWE<D>>,
 }
@@ -134,7 +139,7 @@ impl<D: DataRef> fmt::Display for GGLWEToGGSWKey<D> {
     }
 }
 
-impl GGLWEToGGSWKey<Vec<u8>> {
+impl GGLWEToGGSWKey<AlignedBuf> {
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
         A: GGLWEInfos,
@@ -1,5 +1,7 @@
 use poulpy_hal::{
-    layouts::{Data, DataMut, DataRef, FillUniform, MatZnx, MatZnxToMut, MatZnxToRef, ReaderFrom, WriterTo, ZnxInfos},
+    layouts::{
+        AlignedBuf, Data, DataMut, DataRef, FillUniform, MatZnx, MatZnxToMut, MatZnxToRef, ReaderFrom, WriterTo, ZnxInfos,
+    },
     source::Source,
 };
 use std::fmt;
@@ -85,8 +87,13 @@ impl GGSWInfos for GGSWLayout {
 /// `rank_in = rank + 1` input columns and `rank_out = rank + 1` output columns.
 /// Used as the left operand of external products.
 ///
-/// `D: Data` is the storage backend (e.g. `Vec<u8>`, `&[u8]`, `&mut [u8]`).
+/// `D: Data` is the storage backend (e.g. `AlignedBuf`, `&[u8]`, `&mut [u8]`).
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "MatZnx<D>: serde::Serialize"))
+)]
 pub struct GGSW<D: Data> {
     pub(crate) data: MatZnx<D>,
     pub(crate) base2k: Base2K,
@@ -166,7 +173,7 @@ impl<D: DataMut> GGSW<D> {
     }
 }
 
-impl GGSW<Vec<u8>> {
+impl GGSW<AlignedBuf> {
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
         A: GGSWInfos,
@@ -1,6 +1,7 @@
 use poulpy_hal::{
     layouts::{
-        Data, DataMut, DataRef, FillUniform, ReaderFrom, ToOwnedDeep, VecZnx, VecZnxToMut, VecZnxToRef, WriterTo, ZnxInfos,
+        AlignedBuf, Data, DataMut, DataRef, FillUniform, ReaderFrom, ToOwnedDeep, VecZnx, VecZnxToMut, VecZnxToRef, WriterTo,
+        ZnxInfos,
     },
     source::Source,
 };
@@ -69,8 +70,13 @@ impl GLWEInfos for GLWELayout {
 /// Wraps a [`VecZnx`] with `rank + 1` columns: the first column is the body
 /// polynomial, and the remaining `rank` columns are the mask polynomials.
 ///
-/// `D: Data` is the storage backend (e.g. `Vec<u8>`, `&[u8]`, `&mut [u8]`).
+/// `D: Data` is the storage backend (e.g. `AlignedBuf`, `&[u8]`, `&mut [u8]`).
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "VecZnx<D>: serde::Serialize"))
+)]
 pub struct GLWE<D: Data> {
     pub(crate) data: VecZnx<D>,
     pub(crate) base2k: Base2K,
@@ -125,7 +131,7 @@ impl<D: Data> GLWEInfos for GLWE<D> {
 }
 
 impl<D: DataRef> ToOwnedDeep for GLWE<D> {
-    type Owned = GLWE<Vec<u8>>;
+    type Owned = GLWE<AlignedBuf>;
     fn to_owned_deep(&self) -> Self::Owned {
         GLWE {
             data: self.data.to_owned_deep(),
@@ -152,7 +158,7 @@ impl<D: DataMut> FillUniform for GLWE<D> {
     }
 }
 
-impl GLWE<Vec<u8>> {
+impl GLWE<AlignedBuf> {
     /// Allocates a new [`GLWE`] with the given parameters.
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
@@ -1,5 +1,5 @@
 use poulpy_hal::{
-    layouts::{Backend, Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
+    layouts::{AlignedBuf, Backend, Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
     source::Source,
 };
 
@@ -50,9 +50,14 @@ impl DeclaredK for GLWEAutomorphismKeyLayout {
 /// Wraps a [`GGLWE`] together with the Galois element index `p` that
 /// identifies which automorphism this key materialises.
 ///
-/// `D: Data` is the backing storage type (e.g. `Vec<u8>`, `&[u8]`,
+/// `D: Data` is the backing storage type (e.g. `AlignedBuf`, `&[u8]`,
 /// `&mut [u8]`).
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "GGLWE<D>: serde::Serialize"))
+)]
 pub struct GLWEAutomorphismKey<D: Data> {
     pub(crate) key: GGLWE<D>,
     pub(crate) p: i64,
@@ -183,7 +188,7 @@ impl<D: DataRef> fmt::Display for GLWEAutomorphismKey<D> {
     }
 }
 
-impl GLWEAutomorphismKey<Vec<u8>> {
+impl GLWEAutomorphismKey<AlignedBuf> {
     /// Allocates a new [`GLWEAutomorphismKey`] with the given parameters.
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
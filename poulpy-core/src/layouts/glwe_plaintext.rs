@@ -1,6 +1,6 @@
 use std::fmt;
 
-use poulpy_hal::layouts::{Data, DataMut, DataRef, VecZnx, VecZnxToMut, VecZnxToRef, ZnxInfos};
+use poulpy_hal::layouts::{AlignedBuf, Data, DataMut, DataRef, VecZnx, VecZnxToMut, VecZnxToRef, ZnxInfos};
 
 use crate::layouts::{Base2K, Degree, GLWE, GLWEInfos, GLWEToMut, GLWEToRef, LWEInfos, Rank, SetLWEInfos, TorusPrecision};
 
@@ -31,6 +31,11 @@ impl GLWEInfos for GLWEPlaintextLayout {
     }
 }
 
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "VecZnx<D>: serde::Serialize"))
+)]
 pub struct GLWEPlaintext<D: Data> {
     pub data: VecZnx<D>,
     pub base2k: Base2K,
@@ -74,7 +79,7 @@ impl<D: DataRef> fmt::Display for GLWEPlaintext<D> {
     }
 }
 
-impl GLWEPlaintext<Vec<u8>> {
+impl GLWEPlaintext<AlignedBuf> {
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
         A: GLWEInfos,
@@ -90,7 +95,7 @@ impl GLWEPlaintext<Vec<u8>> {
     }
 }
 
-impl GLWEPlaintext<Vec<u8>> {
+impl GLWEPlaintext<AlignedBuf> {
     pub fn alloc_with_meta(n: Degree, base2k: Base2K, k: TorusPrecision) -> Self {
         GLWEPlaintext {
             data: VecZnx::alloc(n.into(), 1, k.0.div_ceil(base2k.0) as usize),
@@ -1,4 +1,4 @@
-use poulpy_hal::layouts::{Data, DataMut, DataRef, ReaderFrom, VecZnx, WriterTo};
+use poulpy_hal::layouts::{AlignedBuf, Data, DataMut, DataRef, ReaderFrom, VecZnx, WriterTo};
 
 use crate::{
     GetDistribution, GetDistributionMut,
@@ -7,6 +7,11 @@ use crate::{
 };
 
 #[derive(PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "GLWE<D>: serde::Serialize"))
+)]
 pub struct GLWEPublicKey<D: Data> {
     pub(crate) key: GLWE<D>,
     pub(crate) dist: Distribution,
@@ -72,7 +77,7 @@ impl GLWEInfos for GLWEPublicKeyLayout {
     }
 }
 
-impl GLWEPublicKey<Vec<u8>> {
+impl GLWEPublicKey<AlignedBuf> {
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
         A: GLWEInfos,
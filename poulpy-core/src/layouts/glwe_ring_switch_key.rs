@@ -0,0 +1,236 @@
+use poulpy_hal::{
+    layouts::{AlignedBuf, Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
+    source::Source,
+};
+
+use crate::{
+    DeclaredK,
+    layouts::{
+        Base2K, Degree, Dnum, Dsize, GGLWE, GGLWEInfos, GGLWEToMut, GGLWEToRef, GLWEInfos, GLWESwitchingKey,
+        GLWESwitchingKeyDegrees, GLWESwitchingKeyDegreesMut, LWEInfos, Rank, TorusPrecision,
+    },
+};
+
+use std::fmt;
+
+/// Plain-data descriptor for a [`GLWERingSwitchKey`].
+///
+/// `n` is the larger of the two ring degrees (the degree of the module
+/// performing the switch); the input and output ranks are both `rank`.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct GLWERingSwitchKeyLayout {
+    pub n: Degree,
+    pub base2k: Base2K,
+    pub k: TorusPrecision,
+    pub rank: Rank,
+    pub dnum: Dnum,
+    pub dsize: Dsize,
+}
+
+impl DeclaredK for GLWERingSwitchKeyLayout {
+    fn k(&self) -> TorusPrecision {
+        self.k
+    }
+}
+
+impl LWEInfos for GLWERingSwitchKeyLayout {
+    fn n(&self) -> Degree {
+        self.n
+    }
+
+    fn base2k(&self) -> Base2K {
+        self.base2k
+    }
+
+    fn size(&self) -> usize {
+        self.k.as_usize().div_ceil(self.base2k.as_usize())
+    }
+}
+
+impl GLWEInfos for GLWERingSwitchKeyLayout {
+    fn rank(&self) -> Rank {
+        self.rank
+    }
+}
+
+impl GGLWEInfos for GLWERingSwitchKeyLayout {
+    fn rank_in(&self) -> Rank {
+        self.rank
+    }
+
+    fn rank_out(&self) -> Rank {
+        self.rank
+    }
+
+    fn dsize(&self) -> Dsize {
+        self.dsize
+    }
+
+    fn dnum(&self) -> Dnum {
+        self.dnum
+    }
+}
+
+/// A special [`GLWESwitchingKey`] for switching GLWE ciphertexts between
+/// the ring degrees `N` and `N/2`.
+///
+/// The key lives in degree `N`. A key from `sk_N` to `sk_{N/2}` encrypts
+/// `sk_N` under `sk_{N/2}(X^2)` and moves ciphertexts down; a key from
+/// `sk_{N/2}` to `sk_N` encrypts `sk_{N/2}(X^2)` under `sk_N` and moves
+/// ciphertexts up. The direction is given by the stored input and output
+/// degrees.
+#[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "GLWESwitchingKey<D>: serde::Serialize"))
+)]
+pub struct GLWERingSwitchKey<D: Data>(pub(crate) GLWESwitchingKey<D>);
+
+impl<D: Data> LWEInfos for GLWERingSwitchKey<D> {
+    fn base2k(&self) -> Base2K {
+        self.0.base2k()
+    }
+
+    fn n(&self) -> Degree {
+        self.0.n()
+    }
+
+    fn size(&self) -> usize {
+        self.0.size()
+    }
+}
+
+impl<D: Data> GLWEInfos for GLWERingSwitchKey<D> {
+    fn rank(&self) -> Rank {
+        self.rank_out()
+    }
+}
+
+impl<D: Data> GGLWEInfos for GLWERingSwitchKey<D> {
+    fn rank_in(&self) -> Rank {
+        self.0.rank_in()
+    }
+
+    fn dsize(&self) -> Dsize {
+        self.0.dsize()
+    }
+
+    fn rank_out(&self) -> Rank {
+        self.0.rank_out()
+    }
+
+    fn dnum(&self) -> Dnum {
+        self.0.dnum()
+    }
+}
+
+impl<D: DataRef> fmt::Debug for GLWERingSwitchKey<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl<D: DataMut> FillUniform for GLWERingSwitchKey<D> {
+    fn fill_uniform(&mut self, log_bound: usize, source: &mut Source) {
+        self.0.fill_uniform(log_bound, source);
+    }
+}
+
+impl<D: DataRef> fmt::Display for GLWERingSwitchKey<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(GLWERingSwitchKey) {}", self.0)
+    }
+}
+
+impl<D: DataMut> ReaderFrom for GLWERingSwitchKey<D> {
+    fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        self.0.read_from(reader)
+    }
+}
+
+impl<D: DataRef> WriterTo for GLWERingSwitchKey<D> {
+    fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.0.write_to(writer)
+    }
+}
+
+impl GLWERingSwitchKey<AlignedBuf> {
+    pub fn alloc_from_infos<A>(infos: &A) -> Self
+    where
+        A: GGLWEInfos,
+    {
+        assert_eq!(
+            infos.rank_in(),
+            infos.rank_out(),
+            "rank_in != rank_out is not supported for GLWERingSwitchKey"
+        );
+        Self::alloc(
+            infos.n(),
+            infos.base2k(),
+            infos.max_k(),
+            infos.rank_out(),
+            infos.dnum(),
+            infos.dsize(),
+        )
+    }
+
+    pub fn alloc(n: Degree, base2k: Base2K, k: TorusPrecision, rank: Rank, dnum: Dnum, dsize: Dsize) -> Self {
+        GLWERingSwitchKey(GLWESwitchingKey::alloc(n, base2k, k, rank, rank, dnum, dsize))
+    }
+
+    pub fn bytes_of_from_infos<A>(infos: &A) -> usize
+    where
+        A: GGLWEInfos,
+    {
+        assert_eq!(
+            infos.rank_in(),
+            infos.rank_out(),
+            "rank_in != rank_out is not supported for GLWERingSwitchKey"
+        );
+        Self::bytes_of(
+            infos.n(),
+            infos.base2k(),
+            infos.max_k(),
+            infos.rank_out(),
+            infos.dnum(),
+            infos.dsize(),
+        )
+    }
+
+    pub fn bytes_of(n: Degree, base2k: Base2K, k: TorusPrecision, rank: Rank, dnum: Dnum, dsize: Dsize) -> usize {
+        GLWESwitchingKey::bytes_of(n, base2k, k, rank, rank, dnum, dsize)
+    }
+}
+
+impl<D: DataRef> GGLWEToRef for GLWERingSwitchKey<D> {
+    fn to_ref(&self) -> GGLWE<&[u8]> {
+        self.0.to_ref()
+    }
+}
+
+impl<D: DataMut> GGLWEToMut for GLWERingSwitchKey<D> {
+    fn to_mut(&mut self) -> GGLWE<&mut [u8]> {
+        self.0.to_mut()
+    }
+}
+
+impl<D: DataMut> GLWESwitchingKeyDegreesMut for GLWERingSwitchKey<D> {
+    fn input_degree(&mut self) -> &mut Degree {
+        &mut self.0.input_degree
+    }
+
+    fn output_degree(&mut self) -> &mut Degree {
+        &mut self.0.output_degree
+    }
+}
+
+impl<D: DataRef> GLWESwitchingKeyDegrees for GLWERingSwitchKey<D> {
+    fn input_degree(&self) -> &Degree {
+        &self.0.input_degree
+    }
+
+    fn output_degree(&self) -> &Degree {
+        &self.0.output_degree
+    }
+}
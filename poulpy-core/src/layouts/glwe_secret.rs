@@ -1,13 +1,17 @@
 use poulpy_hal::{
-    layouts::{Data, DataMut, DataRef, ScalarZnx, ScalarZnxToMut, ScalarZnxToRef, ZnxInfos, ZnxZero},
+    layouts::{
+        AlignedBuf, Data, DataMut, DataRef, FillUniform, ReaderFrom, ScalarZnx, ScalarZnxToMut, ScalarZnxToRef, WriterTo,
+        ZnxInfos, ZnxZero,
+    },
     source::Source,
 };
 
 use crate::{
     GetDistribution,
     dist::Distribution,
-    layouts::{Base2K, Degree, GLWEInfos, LWEInfos, Rank},
+    layouts::{Base2K, Degree, GLWEInfos, LWEInfos, Rank, compressed::GLWESecretCompressed},
 };
+use std::fmt;
 
 #[derive(PartialEq, Eq, Copy, Clone, Debug)]
 pub struct GLWESecretLayout {
@@ -35,11 +39,23 @@ impl GLWEInfos for GLWESecretLayout {
 }
 
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "ScalarZnx<D>: serde::Serialize"))
+)]
 pub struct GLWESecret<D: Data> {
     pub(crate) data: ScalarZnx<D>,
     pub(crate) dist: Distribution,
 }
 
+/// Zeroizes the coefficients of the secret if they are owned.
+impl<D: Data> Drop for GLWESecret<D> {
+    fn drop(&mut self) {
+        self.data.data.zeroize_owned();
+    }
+}
+
 impl<D: Data> LWEInfos for GLWESecret<D> {
     fn base2k(&self) -> Base2K {
         Base2K(0)
@@ -66,7 +82,25 @@ impl<D: Data> GLWEInfos for GLWESecret<D> {
     }
 }
 
-impl GLWESecret<Vec<u8>> {
+impl<D: DataRef> fmt::Debug for GLWESecret<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl<D: DataRef> fmt::Display for GLWESecret<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "GLWESecret: n={} rank={} dist={:?}", self.n(), self.rank(), self.dist)
+    }
+}
+
+impl<D: DataMut> FillUniform for GLWESecret<D> {
+    fn fill_uniform(&mut self, log_bound: usize, source: &mut Source) {
+        self.data.fill_uniform(log_bound, source);
+    }
+}
+
+impl GLWESecret<AlignedBuf> {
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
         A: GLWEInfos,
@@ -133,6 +167,63 @@ impl<D: DataMut> GLWESecret<D> {
         self.data.zero();
         self.dist = Distribution::ZERO;
     }
+
+    /// Samples the secret from `dist`, dispatching to the matching `fill_*` method.
+    ///
+    /// # Panics
+    /// Panics if `dist` is [`Distribution::NONE`].
+    pub fn fill_dist(&mut self, dist: Distribution, source: &mut Source) {
+        match dist {
+            Distribution::TernaryFixed(hw) => self.fill_ternary_hw(hw, source),
+            Distribution::TernaryProb(prob) => self.fill_ternary_prob(prob, source),
+            Distribution::BinaryFixed(hw) => self.fill_binary_hw(hw, source),
+            Distribution::BinaryProb(prob) => self.fill_binary_prob(prob, source),
+            Distribution::BinaryBlock(block_size) => self.fill_binary_block(block_size, source),
+            Distribution::ZERO => self.fill_zero(),
+            Distribution::NONE => panic!("cannot sample a GLWESecret from Distribution::NONE"),
+        }
+    }
+
+    /// Regenerates the secret from a seed-compressed [`GLWESecretCompressed`].
+    ///
+    /// The output is identical to the secret obtained by calling
+    /// [`fill_dist`](Self::fill_dist) with `other.dist()` on a [`Source`]
+    /// seeded with `other.seed()`.
+    pub fn decompress(&mut self, other: &GLWESecretCompressed) {
+        assert_eq!(
+            self.n(),
+            other.n(),
+            "invalid receiver: self.n()={} != other.n()={}",
+            self.n(),
+            other.n()
+        );
+        assert_eq!(
+            self.rank(),
+            other.rank(),
+            "invalid receiver: self.rank()={} != other.rank()={}",
+            self.rank(),
+            other.rank()
+        );
+        self.fill_dist(*other.dist(), &mut Source::new(*other.seed()));
+    }
+}
+
+/// Deserialises the [`Distribution`] tag followed by the secret coefficients.
+impl<D: DataMut> ReaderFrom for GLWESecret<D> {
+    fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        let dist: Distribution = Distribution::read_from(reader)?;
+        self.data.read_from(reader)?;
+        self.dist = dist;
+        Ok(())
+    }
+}
+
+/// Serialises the [`Distribution`] tag followed by the secret coefficients.
+impl<D: DataRef> WriterTo for GLWESecret<D> {
+    fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.dist.write_to(writer)?;
+        self.data.write_to(writer)
+    }
 }
 
 pub trait GLWESecretToMut {
@@ -4,8 +4,8 @@ use poulpy_hal::{
         VecZnxBigNormalizeTmpBytes, VecZnxDftApply, VecZnxDftBytesOf, VecZnxIdftApplyTmpA,
     },
     layouts::{
-        Backend, Data, DataMut, DataRef, Module, ScalarZnx, ScalarZnxToMut, ScalarZnxToRef, Scratch, ZnxInfos, ZnxView,
-        ZnxViewMut,
+        AlignedBuf, Backend, Data, DataMut, DataRef, Module, ScalarZnx, ScalarZnxToMut, ScalarZnxToRef, Scratch, ZnxInfos,
+        ZnxView, ZnxViewMut,
     },
 };
 
@@ -17,13 +17,25 @@ use crate::{
     },
 };
 
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "ScalarZnx<D>: serde::Serialize"))
+)]
 pub struct GLWESecretTensor<D: Data> {
     pub(crate) data: ScalarZnx<D>,
     pub(crate) rank: Rank,
     pub(crate) dist: Distribution,
 }
 
-impl GLWESecretTensor<Vec<u8>> {
+/// Zeroizes the coefficients of the secret tensor if they are owned.
+impl<D: Data> Drop for GLWESecretTensor<D> {
+    fn drop(&mut self) {
+        self.data.data.zeroize_owned();
+    }
+}
+
+impl GLWESecretTensor<AlignedBuf> {
     pub(crate) fn pairs(rank: usize) -> usize {
         (((rank + 1) * rank) >> 1).max(1)
     }
@@ -101,7 +113,7 @@ impl<D: DataMut> GLWESecretToMut for GLWESecretTensor<D> {
     }
 }
 
-impl GLWESecretTensor<Vec<u8>> {
+impl GLWESecretTensor<AlignedBuf> {
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
         A: GLWEInfos,
@@ -1,5 +1,5 @@
 use poulpy_hal::{
-    layouts::{Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
+    layouts::{AlignedBuf, Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
     source::Source,
 };
 
@@ -78,9 +78,14 @@ impl GGLWEInfos for GLWESwitchingKeyLayout {
 /// Wraps a [`GGLWE`] and additionally stores the polynomial degrees of
 /// the input and output secret keys (`input_degree` / `output_degree`).
 ///
-/// `D: Data` is the backing storage type (e.g. `Vec<u8>`, `&[u8]`,
+/// `D: Data` is the backing storage type (e.g. `AlignedBuf`, `&[u8]`,
 /// `&mut [u8]`).
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "GGLWE<D>: serde::Serialize"))
+)]
 pub struct GLWESwitchingKey<D: Data> {
     pub(crate) key: GGLWE<D>,
     pub(crate) input_degree: Degree,  // Degree of sk_in
@@ -187,7 +192,7 @@ impl<D: DataMut> FillUniform for GLWESwitchingKey<D> {
     }
 }
 
-impl GLWESwitchingKey<Vec<u8>> {
+impl GLWESwitchingKey<AlignedBuf> {
     /// Allocates a new [`GLWESwitchingKey`] with the given parameters.
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
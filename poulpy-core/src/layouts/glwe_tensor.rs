@@ -1,5 +1,5 @@
 use poulpy_hal::{
-    layouts::{Data, DataMut, DataRef, FillUniform, VecZnx, VecZnxToMut, VecZnxToRef, ZnxInfos},
+    layouts::{AlignedBuf, Data, DataMut, DataRef, FillUniform, VecZnx, VecZnxToMut, VecZnxToRef, ZnxInfos},
     source::Source,
 };
 
@@ -7,6 +7,11 @@ use crate::layouts::{Base2K, Degree, GLWE, GLWEInfos, GLWEToMut, GLWEToRef, LWEI
 use std::fmt;
 
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "VecZnx<D>: serde::Serialize"))
+)]
 pub struct GLWETensor<D: Data> {
     pub(crate) data: VecZnx<D>,
     pub(crate) base2k: Base2K,
@@ -76,7 +81,7 @@ impl<D: DataMut> FillUniform for GLWETensor<D> {
     }
 }
 
-impl GLWETensor<Vec<u8>> {
+impl GLWETensor<AlignedBuf> {
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
         A: GLWEInfos,
@@ -1,5 +1,5 @@
 use poulpy_hal::{
-    layouts::{Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
+    layouts::{AlignedBuf, Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
     source::Source,
 };
 
@@ -39,9 +39,14 @@ impl DeclaredK for GLWETensorKeyLayout {
 /// Wraps a [`GGLWE`] whose `rank_in` equals the number of unique
 /// pairs `max(1, rank*(rank+1)/2)` produced by the tensor product.
 ///
-/// `D: Data` is the backing storage type (e.g. `Vec<u8>`, `&[u8]`,
+/// `D: Data` is the backing storage type (e.g. `AlignedBuf`, `&[u8]`,
 /// `&mut [u8]`).
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "GGLWE<D>: serde::Serialize"))
+)]
 pub struct GLWETensorKey<D: Data>(pub(crate) GGLWE<D>);
 
 impl<D: Data> LWEInfos for GLWETensorKey<D> {
@@ -144,7 +149,7 @@ impl<D: DataRef> fmt::Display for GLWETensorKey<D> {
     }
 }
 
-impl GLWETensorKey<Vec<u8>> {
+impl GLWETensorKey<AlignedBuf> {
     /// Allocates a new [`GLWETensorKey`] with the given parameters.
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
@@ -1,5 +1,5 @@
 use poulpy_hal::{
-    layouts::{Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
+    layouts::{AlignedBuf, Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
     source::Source,
 };
 
@@ -68,6 +68,11 @@ impl GGLWEInfos for GLWEToLWEKeyLayout {
 
 /// A special [`GLWESwitchingKey`] required for the conversion from `GLWE` to `LWE`.
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "GLWESwitchingKey<D>: serde::Serialize"))
+)]
 pub struct GLWEToLWEKey<D: Data>(pub(crate) GLWESwitchingKey<D>);
 
 impl<D: Data> LWEInfos for GLWEToLWEKey<D> {
@@ -137,7 +142,7 @@ impl<D: DataRef> WriterTo for GLWEToLWEKey<D> {
     }
 }
 
-impl GLWEToLWEKey<Vec<u8>> {
+impl GLWEToLWEKey<AlignedBuf> {
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
         A: GGLWEInfos,
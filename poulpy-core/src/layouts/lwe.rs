@@ -1,7 +1,9 @@
 use std::fmt;
 
 use poulpy_hal::{
-    layouts::{Data, DataMut, DataRef, FillUniform, ReaderFrom, VecZnx, VecZnxToMut, VecZnxToRef, WriterTo, ZnxInfos},
+    layouts::{
+        AlignedBuf, Data, DataMut, DataRef, FillUniform, ReaderFrom, VecZnx, VecZnxToMut, VecZnxToRef, WriterTo, ZnxInfos,
+    },
     source::Source,
 };
 
@@ -75,8 +77,13 @@ impl LWEInfos for LWELayout {
 /// the body `b` and the mask `(a_1, ..., a_n)` are packed together.
 /// When `rank = 0` the mask column is embedded in the single [`VecZnx`] column.
 ///
-/// `D: Data` is the storage backend (e.g. `Vec<u8>`, `&[u8]`, `&mut [u8]`).
+/// `D: Data` is the storage backend (e.g. `AlignedBuf`, `&[u8]`, `&mut [u8]`).
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "VecZnx<D>: serde::Serialize"))
+)]
 pub struct LWE<D: Data> {
     pub(crate) data: VecZnx<D>,
     pub(crate) base2k: Base2K,
@@ -137,7 +144,7 @@ where
     }
 }
 
-impl LWE<Vec<u8>> {
+impl LWE<AlignedBuf> {
     /// Allocates a new [`LWE`] with the given parameters.
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use poulpy_hal::layouts::{Data, DataMut, DataRef, VecZnx, VecZnxToMut, VecZnxToRef, ZnxInfos};
+use poulpy_hal::layouts::{AlignedBuf, Data, DataMut, DataRef, VecZnx, VecZnxToMut, VecZnxToRef, ZnxInfos};
 
 use crate::layouts::{Base2K, Degree, LWEInfos, SetLWEInfos, TorusPrecision};
 
@@ -24,6 +24,11 @@ impl LWEInfos for LWEPlaintextLayout {
     }
 }
 
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "VecZnx<D>: serde::Serialize"))
+)]
 pub struct LWEPlaintext<D: Data> {
     pub(crate) data: VecZnx<D>,
     pub(crate) base2k: Base2K,
@@ -49,7 +54,7 @@ impl<D: Data> LWEInfos for LWEPlaintext<D> {
     }
 }
 
-impl LWEPlaintext<Vec<u8>> {
+impl LWEPlaintext<AlignedBuf> {
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
         A: LWEInfos,
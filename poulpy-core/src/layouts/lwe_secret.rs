@@ -1,26 +1,90 @@
 use poulpy_hal::{
-    layouts::{Data, DataMut, DataRef, ScalarZnx, ScalarZnxToMut, ScalarZnxToRef, ZnxInfos, ZnxView, ZnxZero},
+    layouts::{
+        AlignedBuf, Data, DataMut, DataRef, FillUniform, ReaderFrom, ScalarZnx, ScalarZnxToMut, ScalarZnxToRef, WriterTo,
+        ZnxInfos, ZnxView, ZnxViewMut, ZnxZero,
+    },
     source::Source,
 };
 
 use crate::{
     GetDistribution,
     dist::Distribution,
-    layouts::{Base2K, Degree, LWEInfos},
+    layouts::{Base2K, Degree, GLWEInfos, GLWESecret, GLWESecretToRef, LWEInfos, Rank, compressed::LWESecretCompressed},
 };
-
+use std::fmt;
+
+#[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "ScalarZnx<D>: serde::Serialize"))
+)]
 pub struct LWESecret<D: Data> {
     pub(crate) data: ScalarZnx<D>,
     pub(crate) dist: Distribution,
 }
 
-impl LWESecret<Vec<u8>> {
+/// Zeroizes the coefficients of the secret if they are owned.
+impl<D: Data> Drop for LWESecret<D> {
+    fn drop(&mut self) {
+        self.data.data.zeroize_owned();
+    }
+}
+
+impl LWESecret<AlignedBuf> {
     pub fn alloc(n: Degree) -> Self {
         LWESecret {
             data: ScalarZnx::alloc(n.into(), 1),
             dist: Distribution::NONE,
         }
     }
+
+    /// Returns the LWE secret under which [`LWESampleExtract`](crate::LWESampleExtract)
+    /// outputs decrypt, for GLWE ciphertexts encrypted under the rank-1 `sk`.
+    ///
+    /// The coefficients are those of `sk(X^-1)`: `s'_0 = s_0` and
+    /// `s'_j = -s_{N-j}`. Ternary distributions are preserved; other
+    /// distributions are not closed under negation and map to
+    /// [`Distribution::NONE`].
+    pub fn from_glwe_secret<S>(sk: &S) -> Self
+    where
+        S: GLWESecretToRef,
+    {
+        let sk: &GLWESecret<&[u8]> = &sk.to_ref();
+        assert_eq!(sk.rank(), Rank(1), "LWESecret::from_glwe_secret requires a rank-1 secret");
+
+        let n: usize = sk.n().into();
+        let mut res: Self = Self::alloc(sk.n());
+        let src: &[i64] = sk.data.at(0, 0);
+        let dst: &mut [i64] = res.data.at_mut(0, 0);
+        dst[0] = src[0];
+        for j in 1..n {
+            dst[j] = -src[n - j];
+        }
+        res.dist = match sk.dist {
+            Distribution::TernaryFixed(_) | Distribution::TernaryProb(_) | Distribution::ZERO => sk.dist,
+            _ => Distribution::NONE,
+        };
+        res
+    }
+}
+
+impl<D: DataRef> fmt::Debug for LWESecret<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl<D: DataRef> fmt::Display for LWESecret<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LWESecret: n={} dist={:?}", self.n(), self.dist)
+    }
+}
+
+impl<D: DataMut> FillUniform for LWESecret<D> {
+    fn fill_uniform(&mut self, log_bound: usize, source: &mut Source) {
+        self.data.fill_uniform(log_bound, source);
+    }
 }
 
 impl<D: DataRef> GetDistribution for LWESecret<D> {
@@ -87,6 +151,56 @@ impl<D: DataMut> LWESecret<D> {
         self.data.zero();
         self.dist = Distribution::ZERO;
     }
+
+    /// Samples the secret from `dist`, dispatching to the matching `fill_*` method.
+    ///
+    /// # Panics
+    /// Panics if `dist` is [`Distribution::NONE`].
+    pub fn fill_dist(&mut self, dist: Distribution, source: &mut Source) {
+        match dist {
+            Distribution::TernaryFixed(hw) => self.fill_ternary_hw(hw, source),
+            Distribution::TernaryProb(prob) => self.fill_ternary_prob(prob, source),
+            Distribution::BinaryFixed(hw) => self.fill_binary_hw(hw, source),
+            Distribution::BinaryProb(prob) => self.fill_binary_prob(prob, source),
+            Distribution::BinaryBlock(block_size) => self.fill_binary_block(block_size, source),
+            Distribution::ZERO => self.fill_zero(),
+            Distribution::NONE => panic!("cannot sample an LWESecret from Distribution::NONE"),
+        }
+    }
+
+    /// Regenerates the secret from a seed-compressed [`LWESecretCompressed`].
+    ///
+    /// The output is identical to the secret obtained by calling
+    /// [`fill_dist`](Self::fill_dist) with `other.dist()` on a [`Source`]
+    /// seeded with `other.seed()`.
+    pub fn decompress(&mut self, other: &LWESecretCompressed) {
+        assert_eq!(
+            self.n(),
+            other.n(),
+            "invalid receiver: self.n()={} != other.n()={}",
+            self.n(),
+            other.n()
+        );
+        self.fill_dist(*other.dist(), &mut Source::new(*other.seed()));
+    }
+}
+
+/// Deserialises the [`Distribution`] tag followed by the secret coefficients.
+impl<D: DataMut> ReaderFrom for LWESecret<D> {
+    fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        let dist: Distribution = Distribution::read_from(reader)?;
+        self.data.read_from(reader)?;
+        self.dist = dist;
+        Ok(())
+    }
+}
+
+/// Serialises the [`Distribution`] tag followed by the secret coefficients.
+impl<D: DataRef> WriterTo for LWESecret<D> {
+    fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.dist.write_to(writer)?;
+        self.data.write_to(writer)
+    }
 }
 
 pub trait LWESecretToRef {
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use poulpy_hal::{
-    layouts::{Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
+    layouts::{AlignedBuf, Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
     source::Source,
 };
 
@@ -66,6 +66,11 @@ impl GGLWEInfos for LWESwitchingKeyLayout {
 }
 
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "GLWESwitchingKey<D>: serde::Serialize"))
+)]
 pub struct LWESwitchingKey<D: Data>(pub(crate) GLWESwitchingKey<D>);
 
 impl<D: Data> LWEInfos for LWESwitchingKey<D> {
@@ -106,7 +111,7 @@ impl<D: Data> GGLWEInfos for LWESwitchingKey<D> {
     }
 }
 
-impl LWESwitchingKey<Vec<u8>> {
+impl LWESwitchingKey<AlignedBuf> {
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
         A: GGLWEInfos,
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use poulpy_hal::{
-    layouts::{Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
+    layouts::{AlignedBuf, Data, DataMut, DataRef, FillUniform, ReaderFrom, WriterTo},
     source::Source,
 };
 
@@ -67,6 +67,11 @@ impl GGLWEInfos for LWEToGLWEKeyLayout {
 }
 
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(bound(serialize = "GLWESwitchingKey<D>: serde::Serialize"))
+)]
 pub struct LWEToGLWEKey<D: Data>(pub(crate) GLWESwitchingKey<D>);
 
 impl<D: Data> LWEInfos for LWEToGLWEKey<D> {
@@ -136,7 +141,7 @@ impl<D: DataRef> WriterTo for LWEToGLWEKey<D> {
     }
 }
 
-impl LWEToGLWEKey<Vec<u8>> {
+impl LWEToGLWEKey<AlignedBuf> {
     pub fn alloc_from_infos<A>(infos: &A) -> Self
     where
         A: GGLWEInfos,
@@ -14,7 +14,7 @@
 //!   polynomial multiplication.
 //!
 //! All layout structs are generic over `D: Data`, enabling owned
-//! (`Vec<u8>`), shared (`&[u8]`), and mutable (`&mut [u8]`) backing
+//! (`AlignedBuf`), shared (`&[u8]`), and mutable (`&mut [u8]`) backing
 //! storage without copying. Conversion between ownership modes is
 //! provided by `*ToRef` / `*ToMut` traits.
 //!
@@ -32,6 +32,7 @@
 //! | [`Dnum`] | Number of gadget-decomposition digits |
 //! | [`Dsize`] | Size (in limbs) of each gadget digit |
 
+mod envelope;
 mod gglwe;
 mod gglwe_to_ggsw_key;
 mod ggsw;
@@ -39,6 +40,7 @@ mod glwe;
 mod glwe_automorphism_key;
 mod glwe_plaintext;
 mod glwe_public_key;
+mod glwe_ring_switch_key;
 mod glwe_secret;
 mod glwe_secret_tensor;
 mod glwe_switching_key;
@@ -50,11 +52,15 @@ mod lwe_plaintext;
 mod lwe_secret;
 mod lwe_switching_key;
 mod lwe_to_glwe_key;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod truncated;
 
 pub mod compressed;
 pub mod prepared;
 
 pub use compressed::*;
+pub use envelope::*;
 pub use gglwe::*;
 pub use gglwe_to_ggsw_key::*;
 pub use ggsw::*;
@@ -62,6 +68,7 @@ pub use glwe::*;
 pub use glwe_automorphism_key::*;
 pub use glwe_plaintext::*;
 pub use glwe_public_key::*;
+pub use glwe_ring_switch_key::*;
 pub use glwe_secret::*;
 pub use glwe_secret_tensor::*;
 pub use glwe_switching_key::*;
@@ -74,6 +81,7 @@ pub use lwe_secret::*;
 pub use lwe_switching_key::*;
 pub use lwe_to_glwe_key::*;
 pub use prepared::*;
+pub use truncated::*;
 
 use poulpy_hal::layouts::{Backend, Module};
 
@@ -96,6 +104,7 @@ macro_rules! newtype_u32 {
         $(#[$meta])*
         #[repr(transparent)]
         #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
         pub struct $name(pub u32);
 
         // ----- Conversions -----
@@ -1,6 +1,10 @@
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use poulpy_hal::{
     api::ScratchAvailable,
-    layouts::{Backend, Data, DataMut, DataRef, DeviceBuf, Module, Scratch},
+    layouts::{
+        Backend, Data, DataMut, DataRef, DeviceBuf, Module, PREPARED_BLOCK_BYTES, ReaderFrom, Scratch, WriterTo,
+        prepared_meta_from_bytes, read_prepared_meta, write_prepared_meta,
+    },
 };
 
 use crate::layouts::{
@@ -240,3 +244,53 @@ where
         }
     }
 }
+
+/// Persists the prepared key as a metadata block holding the number of
+/// [`GGLWEPrepared`], followed by each of them.
+impl<D: DataRef, BE: Backend> WriterTo for GGLWEToGGSWKeyPrepared<D, BE> {
+    fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let mut meta: Vec<u8> = Vec::new();
+        meta.write_u64::<LittleEndian>(self.keys.len() as u64)?;
+        write_prepared_meta(writer, &meta)?;
+        for key in &self.keys {
+            key.write_to(writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Loads a prepared key persisted by [`WriterTo`], failing if it was written
+/// by an incompatible backend, for another ring degree or with a different
+/// number of [`GGLWEPrepared`].
+impl<D: DataMut, BE: Backend> ReaderFrom for GGLWEToGGSWKeyPrepared<D, BE> {
+    fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        let meta: [u8; PREPARED_BLOCK_BYTES] = read_prepared_meta(reader)?;
+        let len: usize = (&meta[..]).read_u64::<LittleEndian>()? as usize;
+        if self.keys.len() != len {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("self.keys.len()={} != read len={}", self.keys.len(), len),
+            ));
+        }
+        for key in &mut self.keys {
+            key.read_from(reader)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, BE: Backend> GGLWEToGGSWKeyPrepared<&'a [u8], BE> {
+    /// Zero-copy variant of [`ReaderFrom`]: views the prepared key persisted at
+    /// the front of `bytes` in place, and returns it with the remaining bytes.
+    pub fn from_bytes(n: Degree, bytes: &'a [u8]) -> std::io::Result<(Self, &'a [u8])> {
+        let (mut meta, mut bytes) = prepared_meta_from_bytes(bytes)?;
+        let len: usize = meta.read_u64::<LittleEndian>()? as usize;
+        let mut keys: Vec<GGLWEPrepared<&'a [u8], BE>> = Vec::with_capacity(len);
+        for _ in 0..len {
+            let (key, rest) = GGLWEPrepared::from_bytes(n, bytes)?;
+            keys.push(key);
+            bytes = rest;
+        }
+        Ok((Self { keys }, bytes))
+    }
+}
@@ -1,6 +1,10 @@
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use poulpy_hal::{
     api::{ScratchAvailable, VmpPMatAlloc, VmpPMatBytesOf, VmpPrepare, VmpPrepareTmpBytes, VmpZero},
-    layouts::{Backend, Data, DataMut, DataRef, DeviceBuf, Module, Scratch, VmpPMat, VmpPMatToMut, VmpPMatToRef, ZnxInfos},
+    layouts::{
+        Backend, Data, DataMut, DataRef, DeviceBuf, Module, PREPARED_BLOCK_BYTES, ReaderFrom, Scratch, VmpPMat, VmpPMatToMut,
+        VmpPMatToRef, WriterTo, ZnxInfos, prepared_meta_from_bytes, read_prepared_meta, write_prepared_meta,
+    },
 };
 
 use crate::layouts::{
@@ -210,3 +214,43 @@ impl<D: DataRef, B: Backend> GGSWPreparedToRef<B> for GGSWPrepared<D, B> {
         }
     }
 }
+
+/// Persists the prepared matrix in the backend-tagged format of
+/// [`VmpPMat`], preceded by a metadata block holding `base2k` and `dsize`.
+impl<D: DataRef, B: Backend> WriterTo for GGSWPrepared<D, B> {
+    fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let mut meta: Vec<u8> = Vec::new();
+        meta.write_u32::<LittleEndian>(self.base2k.into())?;
+        meta.write_u32::<LittleEndian>(self.dsize.into())?;
+        write_prepared_meta(writer, &meta)?;
+        self.data.write_to(writer)
+    }
+}
+
+/// Loads a prepared GGSW persisted by [`WriterTo`], failing if it was written
+/// by an incompatible backend or for another ring degree.
+impl<D: DataMut, B: Backend> ReaderFrom for GGSWPrepared<D, B> {
+    fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        let meta: [u8; PREPARED_BLOCK_BYTES] = read_prepared_meta(reader)?;
+        let mut meta: &[u8] = &meta;
+        let base2k: Base2K = Base2K(meta.read_u32::<LittleEndian>()?);
+        let dsize: Dsize = Dsize(meta.read_u32::<LittleEndian>()?);
+        self.data.read_from(reader)?;
+        self.base2k = base2k;
+        self.dsize = dsize;
+        Ok(())
+    }
+}
+
+impl<'a, B: Backend> GGSWPrepared<&'a [u8], B> {
+    /// Zero-copy variant of [`ReaderFrom`]: views the prepared GGSW persisted
+    /// at the front of `bytes` in place, and returns it with the remaining
+    /// bytes. See [`VmpPMat::from_bytes`].
+    pub fn from_bytes(n: Degree, bytes: &'a [u8]) -> std::io::Result<(Self, &'a [u8])> {
+        let (mut meta, bytes) = prepared_meta_from_bytes(bytes)?;
+        let base2k: Base2K = Base2K(meta.read_u32::<LittleEndian>()?);
+        let dsize: Dsize = Dsize(meta.read_u32::<LittleEndian>()?);
+        let (data, rest) = VmpPMat::from_bytes(n.as_usize(), bytes)?;
+        Ok((Self { data, base2k, dsize }, rest))
+    }
+}
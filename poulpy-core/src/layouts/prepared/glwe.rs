@@ -1,6 +1,10 @@
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use poulpy_hal::{
     api::{VecZnxDftAlloc, VecZnxDftApply, VecZnxDftBytesOf},
-    layouts::{Backend, Data, DataMut, DataRef, DeviceBuf, Module, VecZnxDft, VecZnxDftToMut, VecZnxDftToRef, ZnxInfos},
+    layouts::{
+        Backend, Data, DataMut, DataRef, DeviceBuf, Module, PREPARED_BLOCK_BYTES, ReaderFrom, VecZnxDft, VecZnxDftToMut,
+        VecZnxDftToRef, WriterTo, ZnxInfos, prepared_meta_from_bytes, read_prepared_meta, write_prepared_meta,
+    },
 };
 
 use crate::layouts::{Base2K, Degree, GLWE, GLWEInfos, GLWEToRef, GetDegree, LWEInfos, Rank, TorusPrecision};
@@ -120,3 +124,38 @@ impl<D: DataRef, B: Backend> GLWEPreparedToRef<B> for GLWEPrepared<D, B> {
         }
     }
 }
+
+/// Persists the prepared ciphertext in the backend-tagged format of
+/// [`VecZnxDft`], preceded by a metadata block holding `base2k`.
+impl<D: DataRef, B: Backend> WriterTo for GLWEPrepared<D, B> {
+    fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let mut meta: Vec<u8> = Vec::new();
+        meta.write_u32::<LittleEndian>(self.base2k.into())?;
+        write_prepared_meta(writer, &meta)?;
+        self.data.write_to(writer)
+    }
+}
+
+/// Loads a prepared GLWE persisted by [`WriterTo`], failing if it was written
+/// by an incompatible backend or for another ring degree.
+impl<D: DataMut, B: Backend> ReaderFrom for GLWEPrepared<D, B> {
+    fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        let meta: [u8; PREPARED_BLOCK_BYTES] = read_prepared_meta(reader)?;
+        let base2k: Base2K = Base2K((&meta[..]).read_u32::<LittleEndian>()?);
+        self.data.read_from(reader)?;
+        self.base2k = base2k;
+        Ok(())
+    }
+}
+
+impl<'a, B: Backend> GLWEPrepared<&'a [u8], B> {
+    /// Zero-copy variant of [`ReaderFrom`]: views the prepared GLWE persisted
+    /// at the front of `bytes` in place, and returns it with the remaining
+    /// bytes.
+    pub fn from_bytes(n: Degree, bytes: &'a [u8]) -> std::io::Result<(Self, &'a [u8])> {
+        let (mut meta, bytes) = prepared_meta_from_bytes(bytes)?;
+        let base2k: Base2K = Base2K(meta.read_u32::<LittleEndian>()?);
+        let (data, rest) = VecZnxDft::<&[u8], B>::from_bytes(n.as_usize(), bytes)?;
+        Ok((Self { data, base2k }, rest))
+    }
+}
@@ -1,8 +1,12 @@
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use std::collections::HashMap;
 
 use poulpy_hal::{
     api::ScratchAvailable,
-    layouts::{Backend, Data, DataMut, DataRef, DeviceBuf, Module, Scratch},
+    layouts::{
+        Backend, Data, DataMut, DataRef, DeviceBuf, Module, PREPARED_BLOCK_BYTES, ReaderFrom, Scratch, WriterTo,
+        prepared_meta_from_bytes, read_prepared_meta, write_prepared_meta,
+    },
 };
 
 use crate::layouts::{
@@ -176,3 +180,38 @@ impl<D: DataRef, BE: Backend> GGLWEPreparedToRef<BE> for GLWEAutomorphismKeyPrep
         self.key.to_ref()
     }
 }
+
+/// Persists the prepared automorphism key as a metadata block holding the
+/// Galois element `p`, followed by the persisted [`GGLWEPrepared`].
+impl<D: DataRef, B: Backend> WriterTo for GLWEAutomorphismKeyPrepared<D, B> {
+    fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let mut meta: Vec<u8> = Vec::new();
+        meta.write_u64::<LittleEndian>(self.p as u64)?;
+        write_prepared_meta(writer, &meta)?;
+        self.key.write_to(writer)
+    }
+}
+
+/// Loads a prepared automorphism key persisted by [`WriterTo`], failing if it
+/// was written by an incompatible backend or for another ring degree.
+impl<D: DataMut, B: Backend> ReaderFrom for GLWEAutomorphismKeyPrepared<D, B> {
+    fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        let meta: [u8; PREPARED_BLOCK_BYTES] = read_prepared_meta(reader)?;
+        let p: i64 = (&meta[..]).read_u64::<LittleEndian>()? as i64;
+        self.key.read_from(reader)?;
+        self.p = p;
+        Ok(())
+    }
+}
+
+impl<'a, B: Backend> GLWEAutomorphismKeyPrepared<&'a [u8], B> {
+    /// Zero-copy variant of [`ReaderFrom`]: views the prepared automorphism key
+    /// persisted at the front of `bytes` in place, and returns it with the
+    /// remaining bytes.
+    pub fn from_bytes(n: Degree, bytes: &'a [u8]) -> std::io::Result<(Self, &'a [u8])> {
+        let (mut meta, bytes) = prepared_meta_from_bytes(bytes)?;
+        let p: i64 = meta.read_u64::<LittleEndian>()? as i64;
+        let (key, rest) = GGLWEPrepared::from_bytes(n, bytes)?;
+        Ok((Self { key, p }, rest))
+    }
+}
@@ -1,6 +1,9 @@
 use poulpy_hal::{
     api::{VecZnxDftAlloc, VecZnxDftApply, VecZnxDftBytesOf},
-    layouts::{Backend, Data, DataMut, DataRef, DeviceBuf, Module},
+    layouts::{
+        Backend, Data, DataMut, DataRef, DeviceBuf, Module, PREPARED_BLOCK_BYTES, ReaderFrom, WriterTo, prepared_meta_from_bytes,
+        read_prepared_meta, write_prepared_meta,
+    },
 };
 
 use crate::{
@@ -121,3 +124,38 @@ where
         self.key.to_ref()
     }
 }
+
+/// Persists the prepared public key as a metadata block holding its
+/// distribution, followed by the persisted [`GLWEPrepared`].
+impl<D: DataRef, B: Backend> WriterTo for GLWEPublicKeyPrepared<D, B> {
+    fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let mut meta: Vec<u8> = Vec::new();
+        self.dist.write_to(&mut meta)?;
+        write_prepared_meta(writer, &meta)?;
+        self.key.write_to(writer)
+    }
+}
+
+/// Loads a prepared public key persisted by [`WriterTo`], failing if it was
+/// written by an incompatible backend or for another ring degree.
+impl<D: DataMut, B: Backend> ReaderFrom for GLWEPublicKeyPrepared<D, B> {
+    fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        let meta: [u8; PREPARED_BLOCK_BYTES] = read_prepared_meta(reader)?;
+        let dist: Distribution = Distribution::read_from(&mut &meta[..])?;
+        self.key.read_from(reader)?;
+        self.dist = dist;
+        Ok(())
+    }
+}
+
+impl<'a, B: Backend> GLWEPublicKeyPrepared<&'a [u8], B> {
+    /// Zero-copy variant of [`ReaderFrom`]: views the prepared public key
+    /// persisted at the front of `bytes` in place, and returns it with the
+    /// remaining bytes.
+    pub fn from_bytes(n: Degree, bytes: &'a [u8]) -> std::io::Result<(Self, &'a [u8])> {
+        let (mut meta, bytes) = prepared_meta_from_bytes(bytes)?;
+        let dist: Distribution = Distribution::read_from(&mut meta)?;
+        let (key, rest) = GLWEPrepared::from_bytes(n, bytes)?;
+        Ok((Self { key, dist }, rest))
+    }
+}
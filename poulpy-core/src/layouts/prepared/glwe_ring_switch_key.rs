@@ -0,0 +1,192 @@
+use poulpy_hal::{
+    api::ScratchAvailable,
+    layouts::{Backend, Data, DataMut, DataRef, DeviceBuf, Module, ReaderFrom, Scratch, WriterTo},
+};
+
+use crate::layouts::{
+    Base2K, Degree, Dnum, Dsize, GGLWEInfos, GGLWEPrepared, GGLWEPreparedToMut, GGLWEPreparedToRef, GGLWEToRef, GLWEInfos,
+    GLWESwitchingKeyDegrees, GLWESwitchingKeyDegreesMut, LWEInfos, Rank, TorusPrecision,
+    prepared::{GLWESwitchingKeyPrepared, GLWESwitchingKeyPreparedFactory},
+};
+
+/// DFT-domain (prepared) variant of a [`GLWERingSwitchKey`](crate::layouts::GLWERingSwitchKey).
+///
+/// A newtype wrapper around [`GLWESwitchingKeyPrepared`] for switching
+/// GLWE ciphertexts between the ring degrees `N` and `N/2`. Tied to a
+/// specific backend via `B: Backend`.
+#[derive(PartialEq, Eq)]
+pub struct GLWERingSwitchKeyPrepared<D: Data, B: Backend>(pub(crate) GLWESwitchingKeyPrepared<D, B>);
+
+impl<D: Data, B: Backend> LWEInfos for GLWERingSwitchKeyPrepared<D, B> {
+    fn base2k(&self) -> Base2K {
+        self.0.base2k()
+    }
+
+    fn n(&self) -> Degree {
+        self.0.n()
+    }
+
+    fn size(&self) -> usize {
+        self.0.size()
+    }
+}
+
+impl<D: Data, B: Backend> GLWEInfos for GLWERingSwitchKeyPrepared<D, B> {
+    fn rank(&self) -> Rank {
+        self.rank_out()
+    }
+}
+
+impl<D: Data, B: Backend> GGLWEInfos for GLWERingSwitchKeyPrepared<D, B> {
+    fn rank_in(&self) -> Rank {
+        self.0.rank_in()
+    }
+
+    fn dsize(&self) -> Dsize {
+        self.0.dsize()
+    }
+
+    fn rank_out(&self) -> Rank {
+        self.0.rank_out()
+    }
+
+    fn dnum(&self) -> Dnum {
+        self.0.dnum()
+    }
+}
+
+pub trait GLWERingSwitchKeyPreparedFactory<B: Backend>
+where
+    Self: GLWESwitchingKeyPreparedFactory<B>,
+{
+    fn glwe_ring_switch_key_prepared_alloc(
+        &self,
+        base2k: Base2K,
+        k: TorusPrecision,
+        rank: Rank,
+        dnum: Dnum,
+        dsize: Dsize,
+    ) -> GLWERingSwitchKeyPrepared<DeviceBuf<B>, B> {
+        GLWERingSwitchKeyPrepared(self.glwe_switching_key_prepared_alloc(base2k, k, rank, rank, dnum, dsize))
+    }
+
+    fn glwe_ring_switch_key_prepared_alloc_from_infos<A>(&self, infos: &A) -> GLWERingSwitchKeyPrepared<DeviceBuf<B>, B>
+    where
+        A: GGLWEInfos,
+    {
+        debug_assert_eq!(
+            infos.rank_in(),
+            infos.rank_out(),
+            "rank_in != rank_out is not supported for GLWERingSwitchKeyPrepared"
+        );
+        self.glwe_ring_switch_key_prepared_alloc(infos.base2k(), infos.max_k(), infos.rank_out(), infos.dnum(), infos.dsize())
+    }
+
+    fn glwe_ring_switch_key_prepared_bytes_of(
+        &self,
+        base2k: Base2K,
+        k: TorusPrecision,
+        rank: Rank,
+        dnum: Dnum,
+        dsize: Dsize,
+    ) -> usize {
+        self.bytes_of_glwe_key_prepared(base2k, k, rank, rank, dnum, dsize)
+    }
+
+    fn glwe_ring_switch_key_prepared_bytes_of_from_infos<A>(&self, infos: &A) -> usize
+    where
+        A: GGLWEInfos,
+    {
+        debug_assert_eq!(
+            infos.rank_in(),
+            infos.rank_out(),
+            "rank_in != rank_out is not supported for GLWERingSwitchKeyPrepared"
+        );
+        self.glwe_ring_switch_key_prepared_bytes_of(infos.base2k(), infos.max_k(), infos.rank_out(), infos.dnum(), infos.dsize())
+    }
+
+    fn glwe_ring_switch_key_prepare_tmp_bytes<A>(&self, infos: &A) -> usize
+    where
+        A: GGLWEInfos,
+    {
+        let lvl_0: usize = self.glwe_switching_key_prepare_tmp_bytes(infos);
+        lvl_0
+    }
+
+    fn glwe_ring_switch_key_prepare<R, O>(&self, res: &mut R, other: &O, scratch: &mut Scratch<B>)
+    where
+        R: GGLWEPreparedToMut<B> + GLWESwitchingKeyDegreesMut,
+        O: GGLWEToRef + GLWESwitchingKeyDegrees,
+        Scratch<B>: ScratchAvailable,
+    {
+        let res_infos = res.to_mut();
+        assert!(
+            scratch.available() >= self.glwe_ring_switch_key_prepare_tmp_bytes(&res_infos),
+            "scratch.available(): {} < GLWERingSwitchKeyPreparedFactory::glwe_ring_switch_key_prepare_tmp_bytes: {}",
+            scratch.available(),
+            self.glwe_ring_switch_key_prepare_tmp_bytes(&res_infos)
+        );
+        self.glwe_switching_key_prepare(res, other, scratch);
+    }
+}
+
+impl<B: Backend> GLWERingSwitchKeyPreparedFactory<B> for Module<B> where Self: GLWESwitchingKeyPreparedFactory<B> {}
+
+impl<D: DataRef, B: Backend> GGLWEPreparedToRef<B> for GLWERingSwitchKeyPrepared<D, B>
+where
+    GLWESwitchingKeyPrepared<D, B>: GGLWEPreparedToRef<B>,
+{
+    fn to_ref(&self) -> GGLWEPrepared<&[u8], B> {
+        self.0.to_ref()
+    }
+}
+
+impl<D: DataMut, B: Backend> GGLWEPreparedToMut<B> for GLWERingSwitchKeyPrepared<D, B>
+where
+    GLWESwitchingKeyPrepared<D, B>: GGLWEPreparedToMut<B>,
+{
+    fn to_mut(&mut self) -> GGLWEPrepared<&mut [u8], B> {
+        self.0.to_mut()
+    }
+}
+
+impl<D: DataMut, B: Backend> GLWESwitchingKeyDegreesMut for GLWERingSwitchKeyPrepared<D, B> {
+    fn input_degree(&mut self) -> &mut Degree {
+        &mut self.0.input_degree
+    }
+
+    fn output_degree(&mut self) -> &mut Degree {
+        &mut self.0.output_degree
+    }
+}
+
+impl<D: DataRef, B: Backend> GLWESwitchingKeyDegrees for GLWERingSwitchKeyPrepared<D, B> {
+    fn input_degree(&self) -> &Degree {
+        &self.0.input_degree
+    }
+
+    fn output_degree(&self) -> &Degree {
+        &self.0.output_degree
+    }
+}
+
+impl<D: DataRef, B: Backend> WriterTo for GLWERingSwitchKeyPrepared<D, B> {
+    fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.0.write_to(writer)
+    }
+}
+
+impl<D: DataMut, B: Backend> ReaderFrom for GLWERingSwitchKeyPrepared<D, B> {
+    fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        self.0.read_from(reader)
+    }
+}
+
+impl<'a, B: Backend> GLWERingSwitchKeyPrepared<&'a [u8], B> {
+    /// Zero-copy variant of [`ReaderFrom`]: views the prepared ring-switching key persisted at the
+    /// front of `bytes` in place, and returns it with the remaining bytes.
+    pub fn from_bytes(n: Degree, bytes: &'a [u8]) -> std::io::Result<(Self, &'a [u8])> {
+        let (key, rest) = GLWESwitchingKeyPrepared::from_bytes(n, bytes)?;
+        Ok((Self(key), rest))
+    }
+}
@@ -1,6 +1,9 @@
 use poulpy_hal::{
     api::{SvpPPolAlloc, SvpPPolBytesOf, SvpPrepare},
-    layouts::{Backend, Data, DataMut, DataRef, DeviceBuf, Module, SvpPPol, SvpPPolToMut, SvpPPolToRef, ZnxInfos},
+    layouts::{
+        Backend, Data, DataMut, DataRef, DeviceBuf, Module, PREPARED_BLOCK_BYTES, ReaderFrom, SvpPPol, SvpPPolToMut,
+        SvpPPolToRef, WriterTo, ZnxInfos, prepared_meta_from_bytes, read_prepared_meta, write_prepared_meta,
+    },
 };
 
 use crate::{
@@ -19,6 +22,13 @@ pub struct GLWESecretPrepared<D: Data, B: Backend> {
     pub(crate) dist: Distribution,
 }
 
+/// Zeroizes the prepared secret if its buffer is owned.
+impl<D: Data, B: Backend> Drop for GLWESecretPrepared<D, B> {
+    fn drop(&mut self) {
+        self.data.data.zeroize_owned();
+    }
+}
+
 impl<D: DataRef, BE: Backend> GetDistribution for GLWESecretPrepared<D, BE> {
     fn dist(&self) -> &Distribution {
         &self.dist
@@ -143,3 +153,39 @@ impl<D: DataMut, B: Backend> GLWESecretPreparedToMut<B> for GLWESecretPrepared<D
         }
     }
 }
+
+/// Persists the prepared secret in the backend-tagged format of [`SvpPPol`],
+/// preceded by a metadata block holding its distribution.
+impl<D: DataRef, B: Backend> WriterTo for GLWESecretPrepared<D, B> {
+    fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let mut meta: Vec<u8> = Vec::new();
+        self.dist.write_to(&mut meta)?;
+        write_prepared_meta(writer, &meta)?;
+        self.data.write_to(writer)
+    }
+}
+
+/// Loads a prepared GLWE secret persisted by [`WriterTo`], failing if it was written by an
+/// incompatible backend or for another ring degree.
+impl<D: DataMut, B: Backend> ReaderFrom for GLWESecretPrepared<D, B> {
+    fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        let meta: [u8; PREPARED_BLOCK_BYTES] = read_prepared_meta(reader)?;
+        let mut meta: &[u8] = &meta;
+        let dist: Distribution = Distribution::read_from(&mut meta)?;
+        self.data.read_from(reader)?;
+        self.dist = dist;
+        Ok(())
+    }
+}
+
+impl<'a, B: Backend> GLWESecretPrepared<&'a [u8], B> {
+    /// Zero-copy variant of [`ReaderFrom`]: views the prepared GLWE secret persisted at the
+    /// front of `bytes` in place, and returns it with the remaining bytes.
+    /// See [`SvpPPol::from_bytes`].
+    pub fn from_bytes(n: Degree, bytes: &'a [u8]) -> std::io::Result<(Self, &'a [u8])> {
+        let (mut meta, bytes) = prepared_meta_from_bytes(bytes)?;
+        let dist: Distribution = Distribution::read_from(&mut meta)?;
+        let (data, rest) = SvpPPol::from_bytes(n.as_usize(), bytes)?;
+        Ok((Self { data, dist }, rest))
+    }
+}
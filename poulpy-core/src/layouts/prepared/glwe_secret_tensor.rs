@@ -1,6 +1,10 @@
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use poulpy_hal::{
     api::{SvpPPolAlloc, SvpPPolBytesOf},
-    layouts::{Backend, Data, DataMut, DataRef, DeviceBuf, Module, SvpPPol, SvpPPolToMut, SvpPPolToRef, ZnxInfos},
+    layouts::{
+        Backend, Data, DataMut, DataRef, DeviceBuf, Module, PREPARED_BLOCK_BYTES, ReaderFrom, SvpPPol, SvpPPolToMut,
+        SvpPPolToRef, WriterTo, ZnxInfos, prepared_meta_from_bytes, read_prepared_meta, write_prepared_meta,
+    },
 };
 
 use crate::{
@@ -22,6 +26,13 @@ pub struct GLWESecretTensorPrepared<D: Data, B: Backend> {
     pub(crate) dist: Distribution,
 }
 
+/// Zeroizes the prepared secret tensor if its buffer is owned.
+impl<D: Data, B: Backend> Drop for GLWESecretTensorPrepared<D, B> {
+    fn drop(&mut self) {
+        self.data.data.zeroize_owned();
+    }
+}
+
 impl<D: DataRef, BE: Backend> GetDistribution for GLWESecretTensorPrepared<D, BE> {
     fn dist(&self) -> &Distribution {
         &self.dist
@@ -140,3 +151,43 @@ impl<D: DataMut, B: Backend> GLWESecretPreparedToMut<B> for GLWESecretTensorPrep
         }
     }
 }
+
+/// Persists the prepared secret in the backend-tagged format of [`SvpPPol`],
+/// preceded by a metadata block holding its rank and distribution.
+impl<D: DataRef, B: Backend> WriterTo for GLWESecretTensorPrepared<D, B> {
+    fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let mut meta: Vec<u8> = Vec::new();
+        meta.write_u32::<LittleEndian>(self.rank.into())?;
+        self.dist.write_to(&mut meta)?;
+        write_prepared_meta(writer, &meta)?;
+        self.data.write_to(writer)
+    }
+}
+
+/// Loads a prepared tensor secret persisted by [`WriterTo`], failing if it was written by an
+/// incompatible backend or for another ring degree.
+impl<D: DataMut, B: Backend> ReaderFrom for GLWESecretTensorPrepared<D, B> {
+    fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        let meta: [u8; PREPARED_BLOCK_BYTES] = read_prepared_meta(reader)?;
+        let mut meta: &[u8] = &meta;
+        let rank: Rank = Rank(meta.read_u32::<LittleEndian>()?);
+        let dist: Distribution = Distribution::read_from(&mut meta)?;
+        self.data.read_from(reader)?;
+        self.rank = rank;
+        self.dist = dist;
+        Ok(())
+    }
+}
+
+impl<'a, B: Backend> GLWESecretTensorPrepared<&'a [u8], B> {
+    /// Zero-copy variant of [`ReaderFrom`]: views the prepared tensor secret persisted at the
+    /// front of `bytes` in place, and returns it with the remaining bytes.
+    /// See [`SvpPPol::from_bytes`].
+    pub fn from_bytes(n: Degree, bytes: &'a [u8]) -> std::io::Result<(Self, &'a [u8])> {
+        let (mut meta, bytes) = prepared_meta_from_bytes(bytes)?;
+        let rank: Rank = Rank(meta.read_u32::<LittleEndian>()?);
+        let dist: Distribution = Distribution::read_from(&mut meta)?;
+        let (data, rest) = SvpPPol::from_bytes(n.as_usize(), bytes)?;
+        Ok((Self { data, rank, dist }, rest))
+    }
+}
@@ -1,6 +1,10 @@
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use poulpy_hal::{
     api::ScratchAvailable,
-    layouts::{Backend, Data, DataMut, DataRef, DeviceBuf, Module, Scratch},
+    layouts::{
+        Backend, Data, DataMut, DataRef, DeviceBuf, Module, PREPARED_BLOCK_BYTES, ReaderFrom, Scratch, WriterTo,
+        prepared_meta_from_bytes, read_prepared_meta, write_prepared_meta,
+    },
 };
 
 use crate::layouts::{
@@ -189,3 +193,50 @@ where
         self.key.to_mut()
     }
 }
+
+/// Persists the prepared switching key as a metadata block holding its input
+/// and output degrees, followed by the persisted [`GGLWEPrepared`].
+impl<D: DataRef, B: Backend> WriterTo for GLWESwitchingKeyPrepared<D, B> {
+    fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let mut meta: Vec<u8> = Vec::new();
+        meta.write_u32::<LittleEndian>(self.input_degree.into())?;
+        meta.write_u32::<LittleEndian>(self.output_degree.into())?;
+        write_prepared_meta(writer, &meta)?;
+        self.key.write_to(writer)
+    }
+}
+
+/// Loads a prepared switching key persisted by [`WriterTo`], failing if it
+/// was written by an incompatible backend or for another ring degree.
+impl<D: DataMut, B: Backend> ReaderFrom for GLWESwitchingKeyPrepared<D, B> {
+    fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        let meta: [u8; PREPARED_BLOCK_BYTES] = read_prepared_meta(reader)?;
+        let mut meta: &[u8] = &meta;
+        let input_degree: Degree = Degree(meta.read_u32::<LittleEndian>()?);
+        let output_degree: Degree = Degree(meta.read_u32::<LittleEndian>()?);
+        self.key.read_from(reader)?;
+        self.input_degree = input_degree;
+        self.output_degree = output_degree;
+        Ok(())
+    }
+}
+
+impl<'a, B: Backend> GLWESwitchingKeyPrepared<&'a [u8], B> {
+    /// Zero-copy variant of [`ReaderFrom`]: views the prepared switching key
+    /// persisted at the front of `bytes` in place, and returns it with the
+    /// remaining bytes.
+    pub fn from_bytes(n: Degree, bytes: &'a [u8]) -> std::io::Result<(Self, &'a [u8])> {
+        let (mut meta, bytes) = prepared_meta_from_bytes(bytes)?;
+        let input_degree: Degree = Degree(meta.read_u32::<LittleEndian>()?);
+        let output_degree: Degree = Degree(meta.read_u32::<LittleEndian>()?);
+        let (key, rest) = GGLWEPrepared::from_bytes(n, bytes)?;
+        Ok((
+            Self {
+                key,
+                input_degree,
+                output_degree,
+            },
+            rest,
+        ))
+    }
+}
@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::ScratchAvailable,
-    layouts::{Backend, Data, DataMut, DataRef, DeviceBuf, Module, Scratch},
+    layouts::{Backend, Data, DataMut, DataRef, DeviceBuf, Module, ReaderFrom, Scratch, WriterTo},
 };
 
 use crate::layouts::{
@@ -138,3 +138,24 @@ where
         self.0.to_ref()
     }
 }
+
+impl<D: DataRef, B: Backend> WriterTo for GLWETensorKeyPrepared<D, B> {
+    fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.0.write_to(writer)
+    }
+}
+
+impl<D: DataMut, B: Backend> ReaderFrom for GLWETensorKeyPrepared<D, B> {
+    fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        self.0.read_from(reader)
+    }
+}
+
+impl<'a, B: Backend> GLWETensorKeyPrepared<&'a [u8], B> {
+    /// Zero-copy variant of [`ReaderFrom`]: views the prepared tensor key persisted at the
+    /// front of `bytes` in place, and returns it with the remaining bytes.
+    pub fn from_bytes(n: Degree, bytes: &'a [u8]) -> std::io::Result<(Self, &'a [u8])> {
+        let (key, rest) = GGLWEPrepared::from_bytes(n, bytes)?;
+        Ok((Self(key), rest))
+    }
+}
@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::ScratchAvailable,
-    layouts::{Backend, Data, DataMut, DataRef, DeviceBuf, Module, Scratch},
+    layouts::{Backend, Data, DataMut, DataRef, DeviceBuf, Module, ReaderFrom, Scratch, WriterTo},
 };
 
 use crate::layouts::{
@@ -164,3 +164,24 @@ impl<D: DataRef, B: Backend> GLWESwitchingKeyDegrees for GLWEToLWEKeyPrepared<D,
         &self.0.output_degree
     }
 }
+
+impl<D: DataRef, B: Backend> WriterTo for GLWEToLWEKeyPrepared<D, B> {
+    fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.0.write_to(writer)
+    }
+}
+
+impl<D: DataMut, B: Backend> ReaderFrom for GLWEToLWEKeyPrepared<D, B> {
+    fn read_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        self.0.read_from(reader)
+    }
+}
+
+impl<'a, B: Backend> GLWEToLWEKeyPrepared<&'a [u8], B> {
+    /// Zero-copy variant of [`ReaderFrom`]: views the prepared GLWE-to-LWE key persisted at the
+    /// front of `bytes` in place, and returns it with the remaining bytes.
+    pub fn from_bytes(n: Degree, bytes: &'a [u8]) -> std::io::Result<(Self, &'a [u8])> {
+        let (key, rest) = GLWESwitchingKeyPrepared::from_bytes(n, bytes)?;
+        Ok((Self(key), rest))
+    }
+}
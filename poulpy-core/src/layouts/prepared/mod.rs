@@ -16,6 +16,7 @@ mod ggsw;
 mod glwe;
 mod glwe_automorphism_key;
 mod glwe_public_key;
+mod glwe_ring_switch_key;
 mod glwe_secret;
 mod glwe_secret_tensor;
 mod glwe_switching_key;
@@ -30,6 +31,7 @@ pub use ggsw::*;
 pub use glwe::*;
 pub use glwe_automorphism_key::*;
 pub use glwe_public_key::*;
+pub use glwe_ring_switch_key::*;
 pub use glwe_secret::*;
 pub use glwe_secret_tensor::*;
 pub use glwe_switching_key::*;
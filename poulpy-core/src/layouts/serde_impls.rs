@@ -0,0 +1,713 @@
+//! [`serde`] deserialization of the owned layouts, enabled by the `serde`
+//! feature.
+//!
+//! The layouts derive [`serde::Serialize`] and are deserialized here into
+//! their [`AlignedBuf`]-backed form. The underlying [`VecZnx`], [`ScalarZnx`]
+//! and [`MatZnx`] check their own dimensions against their payload; the impls
+//! below check the header fields of each layout against those dimensions
+//! (ring degree, column count for the rank, `k` for the limb count, gadget
+//! shape and seed count) and reject invalid input with a
+//! [`serde::de::Error`] instead of building a layout that panics when used.
+
+use std::fmt;
+
+use poulpy_hal::layouts::{AlignedBuf, MatZnx, ScalarZnx, VecZnx, ZnxInfos};
+use serde::{Deserialize, Deserializer, de};
+
+use crate::{
+    dist::Distribution,
+    layouts::{
+        Base2K, Degree, Dsize, GGLWE, GGLWEInfos, GGLWEToGGSWKey, GGSW, GLWE, GLWEAutomorphismKey, GLWEPlaintext, GLWEPublicKey,
+        GLWERingSwitchKey, GLWESecret, GLWESecretTensor, GLWESwitchingKey, GLWETensor, GLWETensorKey, GLWEToLWEKey, LWE,
+        LWEPlaintext, LWESecret, LWESwitchingKey, LWEToGLWEKey, Rank, TorusPrecision,
+        compressed::{
+            GGLWECompressed, GGLWEToGGSWKeyCompressed, GGSWCompressed, GLWEAutomorphismKeyCompressed, GLWECompressed,
+            GLWESecretCompressed, GLWESwitchingKeyCompressed, GLWETensorKeyCompressed, GLWEToLWESwitchingKeyCompressed,
+            LWECompressed, LWESecretCompressed, LWESwitchingKeyCompressed, LWEToGLWEKeyCompressed,
+        },
+    },
+};
+
+fn ensure<E: de::Error>(ok: bool, what: &str, msg: impl fmt::Display) -> Result<(), E> {
+    if ok {
+        Ok(())
+    } else {
+        Err(E::custom(format!("{what}: {msg}")))
+    }
+}
+
+/// GLWE-level layouts live over `Z[X]/(X^n + 1)` with `n` a power of two.
+fn check_ring_degree<E: de::Error>(what: &str, n: usize) -> Result<(), E> {
+    ensure(
+        n.is_power_of_two(),
+        what,
+        format_args!("ring degree n={n} is not a non-zero power of two"),
+    )
+}
+
+fn check_base2k<E: de::Error>(what: &str, base2k: Base2K) -> Result<(), E> {
+    ensure(base2k.0 > 0, what, "base2k must be non-zero")
+}
+
+fn check_cols<E: de::Error>(what: &str, name: &str, have: usize, want: usize) -> Result<(), E> {
+    ensure(have == want, what, format_args!("{name}={have} but expected {want}"))
+}
+
+/// A stored precision `k` must span exactly `size` limbs of `base2k` bits.
+fn check_k<E: de::Error>(what: &str, k: TorusPrecision, base2k: Base2K, size: usize) -> Result<(), E> {
+    ensure(
+        k.0.div_ceil(base2k.0) as usize == size,
+        what,
+        format_args!("k={} does not span size={size} limbs of base2k={}", k.0, base2k.0),
+    )
+}
+
+/// `dnum` digits of `dsize` limbs each must fit in the `size` limbs of a gadget layout.
+fn check_gadget<E: de::Error>(what: &str, dnum: usize, dsize: Dsize, size: usize) -> Result<(), E> {
+    ensure(dsize.0 > 0, what, "dsize must be non-zero")?;
+    ensure(
+        (dnum as u64) * (dsize.0 as u64) <= size as u64,
+        what,
+        format_args!("dnum={dnum} * dsize={} exceeds size={size}", dsize.0),
+    )
+}
+
+/// Number of tensor-product pairs over `cols` columns, as allocated by the tensor layouts.
+fn pairs(cols: usize) -> usize {
+    (cols.saturating_add(1).saturating_mul(cols) >> 1).max(1)
+}
+
+fn check_glwe<E: de::Error>(what: &str, data: &VecZnx<AlignedBuf>, base2k: Base2K) -> Result<(), E> {
+    check_ring_degree(what, data.n())?;
+    check_base2k(what, base2k)?;
+    ensure(data.cols() > 0, what, "cols must be non-zero")
+}
+
+fn check_gglwe<E: de::Error>(what: &str, data: &MatZnx<AlignedBuf>, base2k: Base2K, dsize: Dsize) -> Result<(), E> {
+    check_ring_degree(what, data.n())?;
+    check_base2k(what, base2k)?;
+    ensure(data.cols_out() > 0, what, "cols_out must be non-zero")?;
+    check_gadget(what, data.rows(), dsize, data.size())
+}
+
+fn check_same_ranks<E: de::Error>(what: &str, key: &impl GGLWEInfos) -> Result<(), E> {
+    ensure(
+        key.rank_in() == key.rank_out(),
+        what,
+        format_args!("rank_in={} differs from rank_out={}", key.rank_in(), key.rank_out()),
+    )
+}
+
+fn check_lwe_key<E: de::Error>(
+    what: &str,
+    key: &impl GGLWEInfos,
+    rank_in: Option<Rank>,
+    rank_out: Option<Rank>,
+) -> Result<(), E> {
+    ensure(key.dsize().0 == 1, what, format_args!("dsize={} but expected 1", key.dsize()))?;
+    if let Some(rank_in) = rank_in {
+        check_cols(what, "rank_in", key.rank_in().as_usize(), rank_in.as_usize())?;
+    }
+    if let Some(rank_out) = rank_out {
+        check_cols(what, "rank_out", key.rank_out().as_usize(), rank_out.as_usize())?;
+    }
+    Ok(())
+}
+
+fn check_secret_dist<E: de::Error>(what: &str, dist: &Distribution) -> Result<(), E> {
+    ensure(!matches!(dist, Distribution::NONE), what, "distribution is NONE")
+}
+
+/// Keys of a [`GGLWEToGGSWKey`]: one square GGLWE per rank, all of the same shape.
+fn check_gglwe_to_ggsw_keys<E: de::Error, K: GGLWEInfos>(what: &str, keys: &[K]) -> Result<(), E> {
+    ensure(!keys.is_empty(), what, "no keys")?;
+    let rank: usize = keys.len();
+    for key in keys {
+        check_same_ranks(what, key)?;
+        check_cols(what, "rank", key.rank_out().as_usize(), rank)?;
+        ensure(
+            key.n() == keys[0].n()
+                && key.base2k() == keys[0].base2k()
+                && key.size() == keys[0].size()
+                && key.dnum() == keys[0].dnum()
+                && key.dsize() == keys[0].dsize(),
+            what,
+            "keys have different shapes",
+        )?;
+    }
+    Ok(())
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GLWE")]
+struct GLWEDe {
+    data: VecZnx<AlignedBuf>,
+    base2k: Base2K,
+}
+
+impl<'de> Deserialize<'de> for GLWE<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GLWEDe = GLWEDe::deserialize(deserializer)?;
+        check_glwe("GLWE", &de.data, de.base2k)?;
+        Ok(GLWE {
+            data: de.data,
+            base2k: de.base2k,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GLWEPlaintext")]
+struct GLWEPlaintextDe {
+    data: VecZnx<AlignedBuf>,
+    base2k: Base2K,
+}
+
+impl<'de> Deserialize<'de> for GLWEPlaintext<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GLWEPlaintextDe = GLWEPlaintextDe::deserialize(deserializer)?;
+        check_glwe("GLWEPlaintext", &de.data, de.base2k)?;
+        check_cols("GLWEPlaintext", "cols", de.data.cols(), 1)?;
+        Ok(GLWEPlaintext {
+            data: de.data,
+            base2k: de.base2k,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GLWETensor")]
+struct GLWETensorDe {
+    data: VecZnx<AlignedBuf>,
+    base2k: Base2K,
+    rank: Rank,
+}
+
+impl<'de> Deserialize<'de> for GLWETensor<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GLWETensorDe = GLWETensorDe::deserialize(deserializer)?;
+        check_glwe("GLWETensor", &de.data, de.base2k)?;
+        check_cols("GLWETensor", "cols", de.data.cols(), pairs(de.rank.as_usize() + 1))?;
+        Ok(GLWETensor {
+            data: de.data,
+            base2k: de.base2k,
+            rank: de.rank,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GLWEPublicKey")]
+struct GLWEPublicKeyDe {
+    key: GLWE<AlignedBuf>,
+    dist: Distribution,
+}
+
+impl<'de> Deserialize<'de> for GLWEPublicKey<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GLWEPublicKeyDe = GLWEPublicKeyDe::deserialize(deserializer)?;
+        Ok(GLWEPublicKey {
+            key: de.key,
+            dist: de.dist,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "LWE")]
+struct LWEDe {
+    data: VecZnx<AlignedBuf>,
+    base2k: Base2K,
+}
+
+impl<'de> Deserialize<'de> for LWE<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: LWEDe = LWEDe::deserialize(deserializer)?;
+        check_base2k("LWE", de.base2k)?;
+        check_cols("LWE", "cols", de.data.cols(), 1)?;
+        Ok(LWE {
+            data: de.data,
+            base2k: de.base2k,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "LWEPlaintext")]
+struct LWEPlaintextDe {
+    data: VecZnx<AlignedBuf>,
+    base2k: Base2K,
+}
+
+impl<'de> Deserialize<'de> for LWEPlaintext<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: LWEPlaintextDe = LWEPlaintextDe::deserialize(deserializer)?;
+        check_base2k("LWEPlaintext", de.base2k)?;
+        check_cols("LWEPlaintext", "n", de.data.n(), 1)?;
+        check_cols("LWEPlaintext", "cols", de.data.cols(), 1)?;
+        Ok(LWEPlaintext {
+            data: de.data,
+            base2k: de.base2k,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GGLWE")]
+struct GGLWEDe {
+    data: MatZnx<AlignedBuf>,
+    base2k: Base2K,
+    dsize: Dsize,
+}
+
+impl<'de> Deserialize<'de> for GGLWE<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GGLWEDe = GGLWEDe::deserialize(deserializer)?;
+        check_gglwe("GGLWE", &de.data, de.base2k, de.dsize)?;
+        Ok(GGLWE {
+            data: de.data,
+            base2k: de.base2k,
+            dsize: de.dsize,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GGSW")]
+struct GGSWDe {
+    data: MatZnx<AlignedBuf>,
+    base2k: Base2K,
+    dsize: Dsize,
+}
+
+impl<'de> Deserialize<'de> for GGSW<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GGSWDe = GGSWDe::deserialize(deserializer)?;
+        check_gglwe("GGSW", &de.data, de.base2k, de.dsize)?;
+        check_cols("GGSW", "cols_in", de.data.cols_in(), de.data.cols_out())?;
+        Ok(GGSW {
+            data: de.data,
+            base2k: de.base2k,
+            dsize: de.dsize,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GLWESwitchingKey")]
+struct GLWESwitchingKeyDe {
+    key: GGLWE<AlignedBuf>,
+    input_degree: Degree,
+    output_degree: Degree,
+}
+
+impl<'de> Deserialize<'de> for GLWESwitchingKey<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GLWESwitchingKeyDe = GLWESwitchingKeyDe::deserialize(deserializer)?;
+        Ok(GLWESwitchingKey {
+            key: de.key,
+            input_degree: de.input_degree,
+            output_degree: de.output_degree,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GLWEAutomorphismKey")]
+struct GLWEAutomorphismKeyDe {
+    key: GGLWE<AlignedBuf>,
+    p: i64,
+}
+
+impl<'de> Deserialize<'de> for GLWEAutomorphismKey<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GLWEAutomorphismKeyDe = GLWEAutomorphismKeyDe::deserialize(deserializer)?;
+        check_same_ranks("GLWEAutomorphismKey", &de.key)?;
+        Ok(GLWEAutomorphismKey { key: de.key, p: de.p })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GLWETensorKey")]
+struct GLWETensorKeyDe(GGLWE<AlignedBuf>);
+
+impl<'de> Deserialize<'de> for GLWETensorKey<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GLWETensorKeyDe = GLWETensorKeyDe::deserialize(deserializer)?;
+        check_cols(
+            "GLWETensorKey",
+            "rank_in",
+            de.0.rank_in().as_usize(),
+            pairs(de.0.rank_out().as_usize()),
+        )?;
+        Ok(GLWETensorKey(de.0))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GGLWEToGGSWKey")]
+struct GGLWEToGGSWKeyDe {
+    keys: Vec<GGLWE<AlignedBuf>>,
+}
+
+impl<'de> Deserialize<'de> for GGLWEToGGSWKey<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GGLWEToGGSWKeyDe = GGLWEToGGSWKeyDe::deserialize(deserializer)?;
+        check_gglwe_to_ggsw_keys("GGLWEToGGSWKey", &de.keys)?;
+        Ok(GGLWEToGGSWKey { keys: de.keys })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GLWEToLWEKey")]
+struct GLWEToLWEKeyDe(GLWESwitchingKey<AlignedBuf>);
+
+impl<'de> Deserialize<'de> for GLWEToLWEKey<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GLWEToLWEKeyDe = GLWEToLWEKeyDe::deserialize(deserializer)?;
+        check_lwe_key("GLWEToLWEKey", &de.0, None, Some(Rank(1)))?;
+        Ok(GLWEToLWEKey(de.0))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "LWEToGLWEKey")]
+struct LWEToGLWEKeyDe(GLWESwitchingKey<AlignedBuf>);
+
+impl<'de> Deserialize<'de> for LWEToGLWEKey<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: LWEToGLWEKeyDe = LWEToGLWEKeyDe::deserialize(deserializer)?;
+        check_lwe_key("LWEToGLWEKey", &de.0, Some(Rank(1)), None)?;
+        Ok(LWEToGLWEKey(de.0))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "LWESwitchingKey")]
+struct LWESwitchingKeyDe(GLWESwitchingKey<AlignedBuf>);
+
+impl<'de> Deserialize<'de> for LWESwitchingKey<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: LWESwitchingKeyDe = LWESwitchingKeyDe::deserialize(deserializer)?;
+        check_lwe_key("LWESwitchingKey", &de.0, Some(Rank(1)), Some(Rank(1)))?;
+        Ok(LWESwitchingKey(de.0))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GLWERingSwitchKey")]
+struct GLWERingSwitchKeyDe(GLWESwitchingKey<AlignedBuf>);
+
+impl<'de> Deserialize<'de> for GLWERingSwitchKey<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GLWERingSwitchKeyDe = GLWERingSwitchKeyDe::deserialize(deserializer)?;
+        check_same_ranks("GLWERingSwitchKey", &de.0)?;
+        Ok(GLWERingSwitchKey(de.0))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GLWESecret")]
+struct GLWESecretDe {
+    data: ScalarZnx<AlignedBuf>,
+    dist: Distribution,
+}
+
+impl<'de> Deserialize<'de> for GLWESecret<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GLWESecretDe = GLWESecretDe::deserialize(deserializer)?;
+        // Built before validation so that a rejected secret is still zeroized on drop.
+        let res: GLWESecret<AlignedBuf> = GLWESecret {
+            data: de.data,
+            dist: de.dist,
+        };
+        check_ring_degree("GLWESecret", res.data.n())?;
+        Ok(res)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GLWESecretTensor")]
+struct GLWESecretTensorDe {
+    data: ScalarZnx<AlignedBuf>,
+    rank: Rank,
+    dist: Distribution,
+}
+
+impl<'de> Deserialize<'de> for GLWESecretTensor<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GLWESecretTensorDe = GLWESecretTensorDe::deserialize(deserializer)?;
+        let res: GLWESecretTensor<AlignedBuf> = GLWESecretTensor {
+            data: de.data,
+            rank: de.rank,
+            dist: de.dist,
+        };
+        check_ring_degree("GLWESecretTensor", res.data.n())?;
+        check_cols("GLWESecretTensor", "cols", res.data.cols(), pairs(res.rank.as_usize()))?;
+        Ok(res)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "LWESecret")]
+struct LWESecretDe {
+    data: ScalarZnx<AlignedBuf>,
+    dist: Distribution,
+}
+
+impl<'de> Deserialize<'de> for LWESecret<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: LWESecretDe = LWESecretDe::deserialize(deserializer)?;
+        let res: LWESecret<AlignedBuf> = LWESecret {
+            data: de.data,
+            dist: de.dist,
+        };
+        check_cols("LWESecret", "cols", res.data.cols(), 1)?;
+        Ok(res)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GLWECompressed")]
+struct GLWECompressedDe {
+    data: VecZnx<AlignedBuf>,
+    base2k: Base2K,
+    rank: Rank,
+    seed: [u8; 32],
+}
+
+impl<'de> Deserialize<'de> for GLWECompressed<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GLWECompressedDe = GLWECompressedDe::deserialize(deserializer)?;
+        check_glwe("GLWECompressed", &de.data, de.base2k)?;
+        check_cols("GLWECompressed", "cols", de.data.cols(), 1)?;
+        Ok(GLWECompressed {
+            data: de.data,
+            base2k: de.base2k,
+            rank: de.rank,
+            seed: de.seed,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "LWECompressed")]
+struct LWECompressedDe {
+    data: VecZnx<AlignedBuf>,
+    k: TorusPrecision,
+    base2k: Base2K,
+    seed: [u8; 32],
+}
+
+impl<'de> Deserialize<'de> for LWECompressed<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: LWECompressedDe = LWECompressedDe::deserialize(deserializer)?;
+        check_base2k("LWECompressed", de.base2k)?;
+        check_cols("LWECompressed", "n", de.data.n(), 1)?;
+        check_cols("LWECompressed", "cols", de.data.cols(), 1)?;
+        check_k("LWECompressed", de.k, de.base2k, de.data.size())?;
+        Ok(LWECompressed {
+            data: de.data,
+            k: de.k,
+            base2k: de.base2k,
+            seed: de.seed,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GGLWECompressed")]
+struct GGLWECompressedDe {
+    data: MatZnx<AlignedBuf>,
+    base2k: Base2K,
+    k: TorusPrecision,
+    rank_out: Rank,
+    dsize: Dsize,
+    seed: Vec<[u8; 32]>,
+}
+
+impl<'de> Deserialize<'de> for GGLWECompressed<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GGLWECompressedDe = GGLWECompressedDe::deserialize(deserializer)?;
+        check_gglwe("GGLWECompressed", &de.data, de.base2k, de.dsize)?;
+        check_cols("GGLWECompressed", "cols_out", de.data.cols_out(), 1)?;
+        check_k("GGLWECompressed", de.k, de.base2k, de.data.size())?;
+        check_cols("GGLWECompressed", "seeds", de.seed.len(), de.data.rows() * de.data.cols_in())?;
+        Ok(GGLWECompressed {
+            data: de.data,
+            base2k: de.base2k,
+            k: de.k,
+            rank_out: de.rank_out,
+            dsize: de.dsize,
+            seed: de.seed,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GGSWCompressed")]
+struct GGSWCompressedDe {
+    data: MatZnx<AlignedBuf>,
+    k: TorusPrecision,
+    base2k: Base2K,
+    dsize: Dsize,
+    rank: Rank,
+    seed: Vec<[u8; 32]>,
+}
+
+impl<'de> Deserialize<'de> for GGSWCompressed<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GGSWCompressedDe = GGSWCompressedDe::deserialize(deserializer)?;
+        check_gglwe("GGSWCompressed", &de.data, de.base2k, de.dsize)?;
+        check_cols("GGSWCompressed", "cols_out", de.data.cols_out(), 1)?;
+        check_cols("GGSWCompressed", "cols_in", de.data.cols_in(), de.rank.as_usize() + 1)?;
+        check_k("GGSWCompressed", de.k, de.base2k, de.data.size())?;
+        check_cols("GGSWCompressed", "seeds", de.seed.len(), de.data.rows() * de.data.cols_in())?;
+        Ok(GGSWCompressed {
+            data: de.data,
+            k: de.k,
+            base2k: de.base2k,
+            dsize: de.dsize,
+            rank: de.rank,
+            seed: de.seed,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GLWESwitchingKeyCompressed")]
+struct GLWESwitchingKeyCompressedDe {
+    key: GGLWECompressed<AlignedBuf>,
+    input_degree: Degree,
+    output_degree: Degree,
+}
+
+impl<'de> Deserialize<'de> for GLWESwitchingKeyCompressed<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GLWESwitchingKeyCompressedDe = GLWESwitchingKeyCompressedDe::deserialize(deserializer)?;
+        Ok(GLWESwitchingKeyCompressed {
+            key: de.key,
+            input_degree: de.input_degree,
+            output_degree: de.output_degree,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GLWEAutomorphismKeyCompressed")]
+struct GLWEAutomorphismKeyCompressedDe {
+    key: GGLWECompressed<AlignedBuf>,
+    p: i64,
+}
+
+impl<'de> Deserialize<'de> for GLWEAutomorphismKeyCompressed<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GLWEAutomorphismKeyCompressedDe = GLWEAutomorphismKeyCompressedDe::deserialize(deserializer)?;
+        check_same_ranks("GLWEAutomorphismKeyCompressed", &de.key)?;
+        Ok(GLWEAutomorphismKeyCompressed { key: de.key, p: de.p })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GLWETensorKeyCompressed")]
+struct GLWETensorKeyCompressedDe(GGLWECompressed<AlignedBuf>);
+
+impl<'de> Deserialize<'de> for GLWETensorKeyCompressed<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GLWETensorKeyCompressedDe = GLWETensorKeyCompressedDe::deserialize(deserializer)?;
+        check_cols(
+            "GLWETensorKeyCompressed",
+            "rank_in",
+            de.0.rank_in().as_usize(),
+            pairs(de.0.rank_out().as_usize()),
+        )?;
+        Ok(GLWETensorKeyCompressed(de.0))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GGLWEToGGSWKeyCompressed")]
+struct GGLWEToGGSWKeyCompressedDe {
+    keys: Vec<GGLWECompressed<AlignedBuf>>,
+}
+
+impl<'de> Deserialize<'de> for GGLWEToGGSWKeyCompressed<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GGLWEToGGSWKeyCompressedDe = GGLWEToGGSWKeyCompressedDe::deserialize(deserializer)?;
+        check_gglwe_to_ggsw_keys("GGLWEToGGSWKeyCompressed", &de.keys)?;
+        Ok(GGLWEToGGSWKeyCompressed { keys: de.keys })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GLWEToLWESwitchingKeyCompressed")]
+struct GLWEToLWESwitchingKeyCompressedDe(GLWESwitchingKeyCompressed<AlignedBuf>);
+
+impl<'de> Deserialize<'de> for GLWEToLWESwitchingKeyCompressed<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GLWEToLWESwitchingKeyCompressedDe = GLWEToLWESwitchingKeyCompressedDe::deserialize(deserializer)?;
+        check_lwe_key("GLWEToLWESwitchingKeyCompressed", &de.0, None, Some(Rank(1)))?;
+        Ok(GLWEToLWESwitchingKeyCompressed(de.0))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "LWEToGLWEKeyCompressed")]
+struct LWEToGLWEKeyCompressedDe(GLWESwitchingKeyCompressed<AlignedBuf>);
+
+impl<'de> Deserialize<'de> for LWEToGLWEKeyCompressed<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: LWEToGLWEKeyCompressedDe = LWEToGLWEKeyCompressedDe::deserialize(deserializer)?;
+        check_lwe_key("LWEToGLWEKeyCompressed", &de.0, Some(Rank(1)), None)?;
+        Ok(LWEToGLWEKeyCompressed(de.0))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "LWESwitchingKeyCompressed")]
+struct LWESwitchingKeyCompressedDe(GLWESwitchingKeyCompressed<AlignedBuf>);
+
+impl<'de> Deserialize<'de> for LWESwitchingKeyCompressed<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: LWESwitchingKeyCompressedDe = LWESwitchingKeyCompressedDe::deserialize(deserializer)?;
+        check_lwe_key("LWESwitchingKeyCompressed", &de.0, Some(Rank(1)), Some(Rank(1)))?;
+        Ok(LWESwitchingKeyCompressed(de.0))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GLWESecretCompressed")]
+struct GLWESecretCompressedDe {
+    n: Degree,
+    rank: Rank,
+    dist: Distribution,
+    seed: [u8; 32],
+}
+
+impl<'de> Deserialize<'de> for GLWESecretCompressed {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: GLWESecretCompressedDe = GLWESecretCompressedDe::deserialize(deserializer)?;
+        let res: GLWESecretCompressed = GLWESecretCompressed::new(de.n, de.rank, de.dist, de.seed);
+        check_ring_degree("GLWESecretCompressed", res.n.as_usize())?;
+        check_secret_dist("GLWESecretCompressed", &res.dist)?;
+        Ok(res)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "LWESecretCompressed")]
+struct LWESecretCompressedDe {
+    n: Degree,
+    dist: Distribution,
+    seed: [u8; 32],
+}
+
+impl<'de> Deserialize<'de> for LWESecretCompressed {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: LWESecretCompressedDe = LWESecretCompressedDe::deserialize(deserializer)?;
+        let res: LWESecretCompressed = LWESecretCompressed::new(de.n, de.dist, de.seed);
+        ensure(res.n.0 > 0, "LWESecretCompressed", "n must be non-zero")?;
+        check_secret_dist("LWESecretCompressed", &res.dist)?;
+        Ok(res)
+    }
+}
@@ -0,0 +1,288 @@
+//! Bit-packed wire format for truncated [`GLWE`] and [`LWE`] ciphertexts.
+//!
+//! A ciphertext rounded to `k` bits of precision with
+//! [`GLWETruncate`](crate::GLWETruncate) or [`LWETruncate`](crate::LWETruncate)
+//! only has `k` significant bits per coefficient, while its [`WriterTo`]
+//! stream stores every limb as a 64-bit word. [`WriteTruncated`] stores
+//! exactly those `k` bits:
+//!
+//! | Field | Encoding |
+//! |---|---|
+//! | infos | 4 × `u32`: `n`, `base2k`, `k`, `rank` (`0` for an [`LWE`]) |
+//! | body | the first `ceil(k / base2k)` limbs of every column |
+//!
+//! All integers are little-endian. The body is a bit stream (least
+//! significant bit first, zero-padded to a byte boundary) of the centered
+//! limb digits in two's complement, column by column and limb by limb. Every
+//! digit takes `base2k` bits, except those of the last limb, whose
+//! `ceil(k / base2k) * base2k - k` low bits are zero and are not stored.
+//!
+//! [`WriterTo`]: poulpy_hal::layouts::WriterTo
+
+use std::io;
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use poulpy_hal::layouts::{DataMut, DataRef, VecZnx, ZnxInfos, ZnxView, ZnxViewMut, ZnxZero};
+
+use crate::layouts::{GLWE, GLWEInfos, LWE, LWEInfos};
+
+/// Size in bytes of the header of the truncated format.
+pub const TRUNCATED_HEADER_BYTES: usize = 4 * 4;
+
+/// Writes a ciphertext truncated to `k` bits in the bit-packed format.
+pub trait WriteTruncated {
+    /// Returns the number of bytes written by
+    /// [`write_truncated`](Self::write_truncated) at precision `k`.
+    fn truncated_bytes(&self, k: usize) -> usize;
+
+    /// Writes the `k` most significant bits of every coefficient.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidInput`], without writing anything,
+    /// if the ciphertext is not normalized or has non-zero bits below `k`,
+    /// i.e. if it was not truncated to `k` bits.
+    fn write_truncated<W: io::Write>(&self, k: usize, writer: &mut W) -> io::Result<()>;
+}
+
+/// Reads a ciphertext written by [`WriteTruncated::write_truncated`].
+pub trait ReadTruncated {
+    /// Reads a truncated ciphertext into `self`, zeroing the limbs below its
+    /// precision.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidData`] if `n`, `base2k` or `rank`
+    /// differ from those of `self`, or if the precision exceeds `self.max_k()`.
+    fn read_truncated<R: io::Read>(&mut self, reader: &mut R) -> io::Result<()>;
+}
+
+impl<D: DataRef> WriteTruncated for GLWE<D> {
+    fn truncated_bytes(&self, k: usize) -> usize {
+        truncated_bytes_of(&self.data, k)
+    }
+
+    fn write_truncated<W: io::Write>(&self, k: usize, writer: &mut W) -> io::Result<()> {
+        let infos: TruncatedInfos = TruncatedInfos {
+            n: self.n().0,
+            base2k: self.base2k().0,
+            k: k as u32,
+            rank: self.rank().0,
+        };
+        write_packed(&self.data, &infos, writer)
+    }
+}
+
+impl<D: DataMut> ReadTruncated for GLWE<D> {
+    fn read_truncated<R: io::Read>(&mut self, reader: &mut R) -> io::Result<()> {
+        let expected: TruncatedInfos = TruncatedInfos {
+            n: self.n().0,
+            base2k: self.base2k().0,
+            k: self.max_k().0,
+            rank: self.rank().0,
+        };
+        read_packed(&mut self.data, &expected, reader)
+    }
+}
+
+impl<D: DataRef> WriteTruncated for LWE<D> {
+    fn truncated_bytes(&self, k: usize) -> usize {
+        truncated_bytes_of(&self.data, k)
+    }
+
+    fn write_truncated<W: io::Write>(&self, k: usize, writer: &mut W) -> io::Result<()> {
+        let infos: TruncatedInfos = TruncatedInfos {
+            n: self.n().0,
+            base2k: self.base2k().0,
+            k: k as u32,
+            rank: 0,
+        };
+        write_packed(&self.data, &infos, writer)
+    }
+}
+
+impl<D: DataMut> ReadTruncated for LWE<D> {
+    fn read_truncated<R: io::Read>(&mut self, reader: &mut R) -> io::Result<()> {
+        let expected: TruncatedInfos = TruncatedInfos {
+            n: self.n().0,
+            base2k: self.base2k().0,
+            k: self.max_k().0,
+            rank: 0,
+        };
+        read_packed(&mut self.data, &expected, reader)
+    }
+}
+
+/// Header of the truncated format.
+struct TruncatedInfos {
+    n: u32,
+    base2k: u32,
+    k: u32,
+    rank: u32,
+}
+
+fn truncated_bytes_of<D: DataRef>(data: &VecZnx<D>, k: usize) -> usize {
+    TRUNCATED_HEADER_BYTES + (data.cols() * data.n() * k).div_ceil(8)
+}
+
+fn write_packed<D: DataRef, W: io::Write>(data: &VecZnx<D>, infos: &TruncatedInfos, writer: &mut W) -> io::Result<()> {
+    let base2k: usize = infos.base2k as usize;
+    let k: usize = infos.k as usize;
+    let size: usize = k.div_ceil(base2k);
+
+    if size > data.size() {
+        return Err(invalid_input(format!(
+            "k = {k} exceeds the precision {} of the ciphertext",
+            data.size() * base2k
+        )));
+    }
+
+    for col in 0..data.cols() {
+        for j in size..data.size() {
+            if data.at(col, j).iter().any(|&x| x != 0) {
+                return Err(invalid_input(format!(
+                    "ciphertext is not truncated to k = {k}: limb {j} is not zero"
+                )));
+            }
+        }
+    }
+
+    let shift: usize = size * base2k - k;
+    let mut bits: BitWriter = BitWriter::with_capacity((data.cols() * data.n() * k).div_ceil(8));
+    for col in 0..data.cols() {
+        for j in 0..size {
+            let (width, low) = if j == size - 1 { (base2k - shift, shift) } else { (base2k, 0) };
+            for &x in data.at(col, j) {
+                if x & ((1 << low) - 1) != 0 {
+                    return Err(invalid_input(format!(
+                        "ciphertext is not truncated to k = {k}: low bits are not zero"
+                    )));
+                }
+                let digit: i64 = x >> low;
+                let bound: i128 = 1 << (width - 1);
+                if (digit as i128) < -bound || digit as i128 >= bound {
+                    return Err(invalid_input(format!("ciphertext is not normalized: digit {x} in limb {j}")));
+                }
+                bits.write(digit, width);
+            }
+        }
+    }
+
+    writer.write_u32::<LittleEndian>(infos.n)?;
+    writer.write_u32::<LittleEndian>(infos.base2k)?;
+    writer.write_u32::<LittleEndian>(infos.k)?;
+    writer.write_u32::<LittleEndian>(infos.rank)?;
+    writer.write_all(&bits.finish())
+}
+
+fn read_packed<D: DataMut, R: io::Read>(data: &mut VecZnx<D>, expected: &TruncatedInfos, reader: &mut R) -> io::Result<()> {
+    let n: u32 = reader.read_u32::<LittleEndian>()?;
+    let base2k: u32 = reader.read_u32::<LittleEndian>()?;
+    let k: u32 = reader.read_u32::<LittleEndian>()?;
+    let rank: u32 = reader.read_u32::<LittleEndian>()?;
+
+    if n != expected.n || base2k != expected.base2k || rank != expected.rank {
+        return Err(invalid_data(format!(
+            "expected n = {}, base2k = {}, rank = {} but got n = {n}, base2k = {base2k}, rank = {rank}",
+            expected.n, expected.base2k, expected.rank
+        )));
+    }
+    if k > expected.k {
+        return Err(invalid_data(format!(
+            "k = {k} exceeds the precision {} of the receiver",
+            expected.k
+        )));
+    }
+
+    let base2k: usize = base2k as usize;
+    let k: usize = k as usize;
+    let size: usize = k.div_ceil(base2k);
+    let shift: usize = size * base2k - k;
+
+    let mut body: Vec<u8> = vec![0u8; (data.cols() * data.n() * k).div_ceil(8)];
+    reader.read_exact(&mut body)?;
+
+    let mut bits: BitReader = BitReader::new(&body);
+    for col in 0..data.cols() {
+        for j in 0..size {
+            let (width, low) = if j == size - 1 { (base2k - shift, shift) } else { (base2k, 0) };
+            for x in data.at_mut(col, j) {
+                *x = bits.read(width) << low;
+            }
+        }
+        for j in size..data.size() {
+            data.zero_at(col, j);
+        }
+    }
+
+    Ok(())
+}
+
+fn invalid_input(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Packs signed values of at most 64 bits, least significant bit first.
+struct BitWriter {
+    bytes: Vec<u8>,
+    acc: u128,
+    bits: usize,
+}
+
+impl BitWriter {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            bytes: Vec::with_capacity(capacity),
+            acc: 0,
+            bits: 0,
+        }
+    }
+
+    fn write(&mut self, value: i64, width: usize) {
+        self.acc |= (value as u64 as u128 & ((1u128 << width) - 1)) << self.bits;
+        self.bits += width;
+        while self.bits >= 8 {
+            self.bytes.push(self.acc as u8);
+            self.acc >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.acc as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Unpacks the values written by [`BitWriter`], sign-extending them.
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    acc: u128,
+    bits: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            pos: 0,
+            acc: 0,
+            bits: 0,
+        }
+    }
+
+    fn read(&mut self, width: usize) -> i64 {
+        while self.bits < width {
+            self.acc |= (self.bytes[self.pos] as u128) << self.bits;
+            self.pos += 1;
+            self.bits += 8;
+        }
+        let value: u64 = (self.acc & ((1u128 << width) - 1)) as u64;
+        self.acc >>= width;
+        self.bits -= width;
+        ((value << (64 - width)) as i64) >> (64 - width)
+    }
+}
@@ -21,7 +21,7 @@
 //! | **Compressed** (`GLWECompressed<D>`, ...) | Reduced storage via seed-based mask regeneration | `D: Data` |
 //! | **Prepared** (`GLWEPrepared<D, B>`, ...) | DFT-domain, optimised for fast polynomial arithmetic | `D: Data, B: Backend` |
 //!
-//! `D: Data` abstracts ownership: `Vec<u8>` (owned), `&[u8]` (borrowed),
+//! `D: Data` abstracts ownership: `AlignedBuf` (owned), `&[u8]` (borrowed),
 //! or `&mut [u8]` (mutable borrow).
 //!
 //! ## Scratch-space allocation
@@ -32,6 +32,11 @@
 //! scratch space has a companion `*_tmp_bytes` method returning the
 //! required byte count.
 //!
+//! Alternatively, the `*Pooled` traits (e.g. [`GLWEExternalProductPooled`])
+//! take a [`poulpy_hal::layouts::ScratchPool`], which sizes the scratch space
+//! of each call from its `*_tmp_bytes` method and keeps one growing arena per
+//! thread.
+//!
 //! ## Parameter newtypes
 //!
 //! Domain quantities are wrapped in [`u32`]-backed newtypes with
@@ -62,7 +67,10 @@
 //! | glwe\_packer | On-the-fly GLWE packing with O(log N) memory |
 //! | glwe\_packing | HashMap-based GLWE slot packing |
 //! | glwe\_trace | GLWE trace (sum of automorphisms) |
+//! | sanitize | Ciphertext sanitization (re-randomization and noise flooding) |
+//! | truncate | Modulus truncation of GLWE / LWE ciphertexts |
 //! | noise | Noise-variance estimation for parameter selection |
+//! | security | Lattice-attack cost estimation for LWE / GLWE parameters |
 //! | dist | Secret-key distribution descriptors |
 //! | scratch | Arena-style scratch allocation for ciphertext temporaries |
 
@@ -73,6 +81,7 @@ mod decryption;
 mod delegates;
 mod dist;
 mod encryption;
+mod error;
 mod external_product;
 mod glwe_packer;
 mod glwe_packing;
@@ -81,23 +90,36 @@ mod keyswitching;
 mod noise;
 pub mod oep;
 mod operations;
+mod sanitize;
 mod scratch;
+mod security;
+mod truncate;
 mod utils;
 
 pub use operations::*;
 pub mod layouts;
+pub use api::{
+    GGSWAutomorphismFallible, GGSWEncryptPkFallible, GGSWEncryptSkFallible, GGSWEncryptSkPooled, GGSWFromGGLWEFallible,
+    GGSWPreparePooled, GLWEAutomorphismFallible, GLWEAutomorphismPooled, GLWEDecryptPooled, GLWEEncryptPkFallible,
+    GLWEEncryptSkFallible, GLWEEncryptSkPooled, GLWEExternalProductFallible, GLWEExternalProductPooled, GLWEFromLWEFallible,
+    GLWEKeyswitchFallible, GLWEKeyswitchPooled, GLWENoisePooled, GLWENormalizePooled, LWEFromGLWEFallible,
+};
 pub use automorphism::*;
 pub use conversion::*;
 pub use decryption::*;
 pub use dist::*;
 pub use encryption::*;
+pub use error::CoreError;
 pub use external_product::*;
 pub use glwe_packer::*;
 pub use glwe_packing::*;
 pub use glwe_trace::*;
 pub use keyswitching::*;
 pub use noise::*;
+pub use sanitize::*;
 pub use scratch::*;
+pub use security::*;
+pub use truncate::*;
 
 pub use encryption::DEFAULT_SIGMA_XE;
 
@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::{ScratchAvailable, VecZnxAddScalarAssign},
-    layouts::{Backend, DataRef, Module, ScalarZnxToRef, Scratch, Stats, ZnxZero},
+    layouts::{AlignedBuf, Backend, DataRef, Module, ScalarZnxToRef, Scratch, Stats, ZnxZero},
 };
 
 use crate::{ScratchTakeCore, layouts::GLWEPlaintext};
@@ -37,7 +37,7 @@ where
     where
         A: GGLWEInfos,
     {
-        let lvl_0: usize = GLWEPlaintext::<Vec<u8>>::bytes_of_from_infos(infos);
+        let lvl_0: usize = GLWEPlaintext::<AlignedBuf>::bytes_of_from_infos(infos);
         let lvl_1: usize = self.glwe_noise_tmp_bytes(infos);
 
         lvl_0 + lvl_1
@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::ScratchAvailable,
-    layouts::{Backend, Module, Scratch, Stats},
+    layouts::{AlignedBuf, Backend, Module, Scratch, Stats},
 };
 
 use crate::{
@@ -19,7 +19,7 @@ where
     where
         A: GLWEInfos,
     {
-        let lvl_0: usize = GLWEPlaintext::<Vec<u8>>::bytes_of_from_infos(infos);
+        let lvl_0: usize = GLWEPlaintext::<AlignedBuf>::bytes_of_from_infos(infos);
         let lvl_1: usize = self.glwe_normalize_tmp_bytes().max(self.glwe_decrypt_tmp_bytes(infos));
 
         lvl_0 + lvl_1
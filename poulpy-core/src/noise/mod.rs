@@ -5,17 +5,37 @@
 //! These functions are intended for parameter-set design and
 //! noise-budget analysis, not for runtime use.
 //!
-//! Most helper functions are `pub(crate)`.
+//! * [`NoiseModel`] predicts the output variance of encryption, keyswitch,
+//!   automorphism, external product, tensoring and blind rotation directly
+//!   from the layouts of the operands.
+//! * [`NoiseTracker`] follows a ciphertext through a sequence of such
+//!   operations and reports the resulting decryption failure probability.
+//! * The raw helpers below ([`var_noise_gglwe_product`], [`noise_ggsw_product`],
+//!   ...) expose the underlying formulas with explicit parameters.
+//!
+//! Predictions can be checked against the measured noise of actual
+//! ciphertexts with [`GLWENoise::glwe_noise`].
 
 mod gglwe;
 mod ggsw;
 mod glwe;
+mod model;
+mod tracker;
 
 pub use crate::api::{GGLWENoise, GGSWNoise, GLWENoise};
-
+pub use model::*;
+pub use tracker::*;
+
+/// Variance (on the torus) added by a gadget product between a GLWE with
+/// `a_logq` bits of precision and a GGLWE with `b_logq` bits of precision,
+/// decomposed in base `2^base2k`.
+///
+/// `var_msg` is the variance of the message encrypted in the gadget,
+/// `var_a_err` the error of the input mask and `var_gct_err_lhs` /
+/// `var_gct_err_rhs` the error variances of the gadget ciphertext, in
+/// units of its least significant bit.
 #[allow(clippy::too_many_arguments)]
-#[allow(dead_code)]
-pub(crate) fn var_noise_gglwe_product(
+pub fn var_noise_gglwe_product(
     n: f64,
     base2k: usize,
     var_xs: f64,
@@ -45,9 +65,12 @@ pub(crate) fn var_noise_gglwe_product(
     noise
 }
 
+/// Variance (on the torus) added by a gadget product with a GGLWE of
+/// precision `k_ksk`, `dnum` rows of `dsize` limbs of `base2k` bits each.
+///
+/// Parameters are as in [`var_noise_gglwe_product`].
 #[allow(clippy::too_many_arguments)]
-#[allow(dead_code)]
-pub(crate) fn var_noise_gglwe_product_v2(
+pub fn var_noise_gglwe_product_v2(
     n: f64,
     k_ksk: usize,
     dnum: usize,
@@ -71,9 +94,10 @@ pub(crate) fn var_noise_gglwe_product_v2(
     noise
 }
 
+/// `log2` of the standard deviation of [`var_noise_gglwe_product`], clamped
+/// to `[-a_logq, -1]`.
 #[allow(clippy::too_many_arguments)]
-#[allow(dead_code)]
-pub(crate) fn log2_std_noise_gglwe_product(
+pub fn log2_std_noise_gglwe_product(
     n: f64,
     base2k: usize,
     var_xs: f64,
@@ -101,9 +125,13 @@ pub(crate) fn log2_std_noise_gglwe_product(
     noise.log2().min(-1.0).max(-(a_logq as f64)) // max noise is [-2^{-1}, 2^{-1}]
 }
 
+/// `log2` of the standard deviation of the output of an external product
+/// between a GLWE of precision `k_in` and a GGSW of precision `k_ggsw`.
+///
+/// `var_a0_err` is the error of the input body and `var_a1_err` the error
+/// of its mask, both in units of the least significant bit of the input.
 #[allow(clippy::too_many_arguments)]
-#[allow(dead_code)]
-pub(crate) fn noise_ggsw_product(
+pub fn noise_ggsw_product(
     n: f64,
     base2k: usize,
     var_xs: f64,
@@ -135,9 +163,11 @@ pub(crate) fn noise_ggsw_product(
     noise.log2().min(-1.0) // max noise is [-2^{-1}, 2^{-1}]
 }
 
+/// `log2` of the standard deviation of column `col` of a GGSW after a
+/// keyswitch with a key of precision `k_ksk` and a tensor key of precision
+/// `k_tsk`.
 #[allow(clippy::too_many_arguments)]
-#[allow(dead_code)]
-pub(crate) fn noise_ggsw_keyswitch(
+pub fn noise_ggsw_keyswitch(
     n: f64,
     base2k: usize,
     col: usize,
@@ -0,0 +1,284 @@
+use poulpy_hal::layouts::NoiseInfos;
+
+use crate::{
+    GetDistribution, NoiseFlooding,
+    layouts::{GGLWEInfos, GGSWInfos, GLWEInfos, GLWELayout, LWEInfos, Rank},
+};
+
+/// Closed-form noise model for the core GLWE operations.
+///
+/// All variances are expressed on the torus, i.e. a ciphertext whose error
+/// has standard deviation `sigma * 2^-k` has variance `sigma^2 * 2^-2k`.
+/// The model is parameterised by:
+///
+/// * `var_xs` -- second moment `E[s_i^2]` of the secret coefficients, see
+///   [`Distribution::density`](crate::Distribution::density);
+/// * `var_key_err` -- variance of the fresh error of the evaluation keys,
+///   in units of their least significant bit (`sigma^2`).
+///
+/// Every prediction takes the layout of its operands, so the same model can
+/// be reused across a whole parameter set. Predictions are averages over the
+/// randomness of the keys and the masks; they do not account for the
+/// floating-point error of `FFT64` backends, which is negligible for
+/// correctly-sized parameters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NoiseModel {
+    /// Second moment of a secret coefficient.
+    pub var_xs: f64,
+    /// Variance of the evaluation-key error, in units of the key LSB.
+    pub var_key_err: f64,
+}
+
+impl NoiseModel {
+    /// Creates a model from the secret-coefficient second moment and the key error variance.
+    pub fn new(var_xs: f64, var_key_err: f64) -> Self {
+        Self { var_xs, var_key_err }
+    }
+
+    /// Creates a model for a secret of the given distribution and keys encrypted
+    /// with error standard deviation `sigma_key`.
+    pub fn from_secret<S>(sk: &S, sigma_key: f64) -> Self
+    where
+        S: GetDistribution + LWEInfos,
+    {
+        Self::new(sk.dist().density(sk.n().as_usize()), sigma_key * sigma_key)
+    }
+
+    /// Variance of a fresh secret-key encryption.
+    pub fn glwe_encrypt_sk(&self, noise: &NoiseInfos) -> f64 {
+        noise.sigma * noise.sigma * (-2.0 * noise.k as f64).exp2()
+    }
+
+    /// Variance added by rounding the `rank + 1` columns of `res` to its precision.
+    pub fn glwe_rounding<A>(&self, res: &A) -> f64
+    where
+        A: GLWEInfos,
+    {
+        self.rounding(res.n().as_usize() * res.rank().as_usize(), res.max_k().as_usize())
+    }
+
+    /// Variance of a GLWE of variance `var_in` and layout `glwe_in` after
+    /// [`GLWETruncate`](crate::GLWETruncate) to `k` bits of precision.
+    pub fn glwe_truncate<A>(&self, var_in: f64, glwe_in: &A, k: usize) -> f64
+    where
+        A: GLWEInfos,
+    {
+        var_in + self.rounding(glwe_in.n().as_usize() * glwe_in.rank().as_usize(), k)
+    }
+
+    /// Variance of an LWE of variance `var_in` and layout `lwe_in` after
+    /// [`LWETruncate`](crate::LWETruncate) to `k` bits of precision.
+    pub fn lwe_truncate<A>(&self, var_in: f64, lwe_in: &A, k: usize) -> f64
+    where
+        A: LWEInfos,
+    {
+        var_in + self.rounding(lwe_in.n().as_usize(), k)
+    }
+
+    /// Variance of a GLWE of variance `var_in` and layout `glwe_in` after a
+    /// keyswitch with a key of layout `ksk`.
+    pub fn glwe_keyswitch<A, K>(&self, var_in: f64, glwe_in: &A, ksk: &K) -> f64
+    where
+        A: GLWEInfos,
+        K: GGLWEInfos,
+    {
+        var_in
+            + self.gglwe_product(
+                glwe_in,
+                ksk,
+                ksk.rank_in().as_usize(),
+                ksk.dnum().as_usize(),
+                ksk.dsize().as_usize(),
+            )
+    }
+
+    /// Variance of a GLWE of variance `var_in` and layout `glwe_in` after an
+    /// automorphism with a key of layout `atk`.
+    ///
+    /// Automorphisms permute the coefficients of the error, so the input
+    /// variance is preserved and the keyswitch noise is added on top.
+    pub fn glwe_automorphism<A, K>(&self, var_in: f64, glwe_in: &A, atk: &K) -> f64
+    where
+        A: GLWEInfos,
+        K: GGLWEInfos,
+    {
+        self.glwe_keyswitch(var_in, glwe_in, atk)
+    }
+
+    /// Variance of a GLWE of variance `var_in` and layout `glwe_in` after an
+    /// external product with a GGSW of layout `ggsw` encrypting a message of
+    /// squared L2 norm `msg_sq_norm` (e.g. `1` for a monomial `X^i`).
+    pub fn glwe_external_product<A, G>(&self, var_in: f64, msg_sq_norm: f64, glwe_in: &A, ggsw: &G) -> f64
+    where
+        A: GLWEInfos,
+        G: GGSWInfos,
+    {
+        let rank: usize = ggsw.rank().as_usize();
+        msg_sq_norm * var_in
+            + msg_sq_norm
+                * self.truncation(
+                    glwe_in,
+                    rank,
+                    ggsw.dnum().as_usize(),
+                    ggsw.dsize().as_usize(),
+                    ggsw.base2k().as_usize(),
+                )
+            + self.gadget(ggsw, rank + 1, ggsw.dnum().as_usize(), ggsw.dsize().as_usize())
+    }
+
+    /// Variance of the tensor product of two GLWE ciphertexts, before relinearization.
+    ///
+    /// `var_a` / `var_b` are the input variances and `msg_a` / `msg_b` the second
+    /// moments of the input message coefficients (on the torus). `cnv_offset` is
+    /// the left shift, in bits, applied to the raw product, as passed to
+    /// [`GLWETensoring::glwe_tensor_apply`](crate::api::GLWETensoring::glwe_tensor_apply).
+    ///
+    /// The product is taken over the reals, so the integer part of the phase
+    /// of each operand multiplies the error of the other. This term dominates
+    /// unless the messages are large.
+    #[allow(clippy::too_many_arguments)]
+    pub fn glwe_tensor<R>(&self, res: &R, cnv_offset: usize, var_a: f64, msg_a: f64, var_b: f64, msg_b: f64) -> f64
+    where
+        R: GLWEInfos,
+    {
+        let n: f64 = res.n().as_usize() as f64;
+        let rank: f64 = res.rank().as_usize() as f64;
+        let scale: f64 = (2.0 * cnv_offset as f64).exp2();
+        // Second moment of the integer part of `b + <a, s>` for a uniform mask.
+        let var_int: f64 = (1.0 + n * rank * self.var_xs) / 12.0;
+        let cross: f64 = n * ((msg_a + var_int) * var_b + (msg_b + var_int) * var_a + n * var_a * var_b);
+        let pairs: f64 = rank * (rank + 1.0) / 2.0;
+        let round: f64 = (1.0 + n * rank * self.var_xs + n * n * pairs * self.var_xs * self.var_xs)
+            * (-2.0 * res.max_k().as_usize() as f64).exp2()
+            / 12.0;
+        scale * cross + round
+    }
+
+    /// Variance of a tensor ciphertext of variance `var_in` after relinearization
+    /// with a tensor key of layout `tsk`.
+    pub fn glwe_tensor_relinearize<R, K>(&self, var_in: f64, res: &R, tsk: &K) -> f64
+    where
+        R: GLWEInfos,
+        K: GGLWEInfos,
+    {
+        let rank: usize = res.rank().as_usize();
+        let pairs: usize = rank * (rank + 1) / 2;
+        let dnum: usize = tsk.dnum().as_usize();
+        let dsize: usize = tsk.dsize().as_usize();
+        let n: f64 = res.n().as_usize() as f64;
+
+        // The relinearised components multiply s_i * s_j, whose coefficients have
+        // second moment n * var_xs^2. Each product s_a * s_b appears twice in the
+        // coefficients of a square s_i^2, which doubles its second moment.
+        let var_sisj: f64 = n * self.var_xs * self.var_xs;
+        let weight: usize = pairs + rank;
+        let trunc: f64 = weight as f64 * n * var_sisj * self.truncation_floor(res, dnum, dsize, tsk.base2k().as_usize());
+
+        var_in + trunc + self.gadget(tsk, pairs, dnum, dsize)
+    }
+
+    /// Variance of the accumulator after a CGGI-style blind rotation of an LWE
+    /// of dimension `lwe_n`, with blind-rotation keys of layout `brk` and an
+    /// initial (trivial) accumulator of layout `acc`.
+    ///
+    /// Each of the `lwe_n` CMux steps adds `(X^a_i - 1) * (acc (x) GGSW(s_i))`
+    /// to the normalized accumulator. The key-dependent part of
+    /// [`glwe_external_product`](Self::glwe_external_product) is multiplied by
+    /// `X^a_i - 1`, which doubles its variance.
+    pub fn blind_rotation<A, G>(&self, lwe_n: usize, acc: &A, brk: &G) -> f64
+    where
+        A: GLWEInfos,
+        G: GGSWInfos,
+    {
+        2.0 * lwe_n as f64 * self.glwe_external_product(0.0, 1.0, acc, brk)
+    }
+
+    /// Variance after [`GLWESanitize`](crate::GLWESanitize) or
+    /// [`LWESanitize`](crate::LWESanitize) with flooding noise `flood`.
+    ///
+    /// The public-key encryption of zero added to re-randomize the mask is
+    /// dominated by the flooding noise and is not accounted for.
+    pub fn glwe_sanitize(&self, var_in: f64, flood: &NoiseFlooding) -> f64 {
+        var_in + flood.variance()
+    }
+
+    /// Variance of a GLWE of layout `res` after [`GLWEPackLWEs`](crate::GLWEPackLWEs)
+    /// of `N` LWE ciphertexts of variance `var_in` and layout `lwe_in`, with an
+    /// LWE-to-GLWE key of layout `ksk` and automorphism keys of layout `atk`.
+    ///
+    /// Every level of the packing tree averages two subtrees and adds the noise
+    /// of one automorphism, so the variance grows by one automorphism per level.
+    /// Packing fewer than `N` ciphertexts averages with empty subtrees, which
+    /// only lowers the variance.
+    pub fn glwe_pack_lwes<A, R, K, T>(&self, var_in: f64, lwe_in: &A, res: &R, ksk: &K, atk: &T) -> f64
+    where
+        A: LWEInfos,
+        R: GLWEInfos,
+        K: GGLWEInfos,
+        T: GGLWEInfos,
+    {
+        let glwe_in: GLWELayout = GLWELayout {
+            n: res.n(),
+            base2k: ksk.base2k(),
+            k: lwe_in.max_k(),
+            rank: Rank(1),
+        };
+        let var_leaf: f64 = self.glwe_keyswitch(var_in, &glwe_in, ksk) + self.glwe_rounding(res);
+        let var_level: f64 = self.glwe_automorphism(0.0, res, atk) + 2.0 * self.glwe_rounding(res);
+        var_leaf + res.n().log2() as f64 * var_level
+    }
+
+    /// Variance of the decryption error added by rounding the body and the
+    /// `mask_len` mask coefficients of a ciphertext to `k` bits.
+    fn rounding(&self, mask_len: usize, k: usize) -> f64 {
+        (1.0 + mask_len as f64 * self.var_xs) * (-2.0 * k as f64).exp2() / 12.0
+    }
+
+    /// Noise of the gadget product between the decomposition of `cols` columns
+    /// and a gadget key with error variance `var_key_err`.
+    fn gadget<K>(&self, key: &K, cols: usize, dnum: usize, dsize: usize) -> f64
+    where
+        K: GLWEInfos,
+    {
+        let n: f64 = key.n().as_usize() as f64;
+        let base: f64 = ((dsize * key.base2k().as_usize()) as f64).exp2();
+        let var_base: f64 = base * base / 12.0;
+        let scale: f64 = (-2.0 * key.max_k().as_usize() as f64).exp2();
+        (cols * dnum) as f64 * n * var_base * self.var_key_err * scale
+    }
+
+    /// Noise of a gadget product between a GLWE of layout `glwe_in` and a
+    /// gadget key with `rank_in` input columns.
+    fn gglwe_product<A, K>(&self, glwe_in: &A, key: &K, rank_in: usize, dnum: usize, dsize: usize) -> f64
+    where
+        A: GLWEInfos,
+        K: GLWEInfos,
+    {
+        self.truncation(glwe_in, rank_in, dnum, dsize, key.base2k().as_usize()) + self.gadget(key, rank_in, dnum, dsize)
+    }
+
+    /// Noise from the mask bits of `glwe_in` below the gadget precision
+    /// `dnum * dsize * base2k`, which are dropped by the decomposition.
+    fn truncation<A>(&self, glwe_in: &A, rank: usize, dnum: usize, dsize: usize, base2k: usize) -> f64
+    where
+        A: GLWEInfos,
+    {
+        let n: f64 = glwe_in.n().as_usize() as f64;
+        rank as f64 * n * self.var_xs * self.truncation_floor(glwe_in, dnum, dsize, base2k)
+    }
+
+    /// Variance of the rounding error of a uniform torus element truncated to
+    /// `min(k, dnum * dsize * base2k)` bits, if it is truncated at all.
+    fn truncation_floor<A>(&self, glwe_in: &A, dnum: usize, dsize: usize, base2k: usize) -> f64
+    where
+        A: LWEInfos,
+    {
+        let k_in: usize = glwe_in.max_k().as_usize();
+        let k_gadget: usize = dnum * dsize * base2k;
+        if k_in <= k_gadget {
+            0.0
+        } else {
+            (-2.0 * k_gadget as f64).exp2() / 12.0
+        }
+    }
+}
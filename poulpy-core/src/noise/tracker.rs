@@ -0,0 +1,266 @@
+use poulpy_hal::layouts::NoiseInfos;
+
+use crate::{
+    NoiseFlooding,
+    layouts::{GGLWEInfos, GGSWInfos, GLWEInfos, LWEInfos},
+    noise::NoiseModel,
+};
+
+/// One step recorded by a [`NoiseTracker`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NoiseStep {
+    /// Name of the operation.
+    pub op: &'static str,
+    /// Predicted variance after the operation.
+    pub var: f64,
+}
+
+impl NoiseStep {
+    /// `log2` of the predicted standard deviation after the operation.
+    pub fn log2_std(&self) -> f64 {
+        0.5 * self.var.log2()
+    }
+}
+
+/// Follows the predicted noise of a GLWE ciphertext through a computation.
+///
+/// A tracker starts from a fresh encryption (or an arbitrary variance) and is
+/// advanced by calling the method matching each homomorphic operation applied
+/// to the tracked ciphertext. Every step is recorded and can be inspected with
+/// [`steps`](Self::steps). At any point, [`failure_probability`](Self::failure_probability)
+/// returns the probability that decryption rounds to the wrong plaintext.
+#[derive(Clone, Debug)]
+pub struct NoiseTracker {
+    model: NoiseModel,
+    var: f64,
+    steps: Vec<NoiseStep>,
+}
+
+impl NoiseTracker {
+    /// Starts tracking a ciphertext with known variance `var`.
+    pub fn new(model: NoiseModel, var: f64) -> Self {
+        Self {
+            model,
+            var,
+            steps: vec![NoiseStep { op: "init", var }],
+        }
+    }
+
+    /// Starts tracking a fresh secret-key encryption.
+    pub fn fresh(model: NoiseModel, noise: &NoiseInfos) -> Self {
+        let var: f64 = model.glwe_encrypt_sk(noise);
+        let mut tracker: Self = Self::new(model, var);
+        tracker.steps[0].op = "encrypt_sk";
+        tracker
+    }
+
+    /// Returns the underlying noise model.
+    pub fn model(&self) -> &NoiseModel {
+        &self.model
+    }
+
+    /// Returns the predicted variance of the tracked ciphertext.
+    pub fn variance(&self) -> f64 {
+        self.var
+    }
+
+    /// Returns the predicted standard deviation of the tracked ciphertext.
+    pub fn std(&self) -> f64 {
+        self.var.sqrt()
+    }
+
+    /// Returns `log2` of the predicted standard deviation of the tracked ciphertext.
+    pub fn log2_std(&self) -> f64 {
+        0.5 * self.var.log2()
+    }
+
+    /// Returns all recorded steps, starting with the initial state.
+    pub fn steps(&self) -> &[NoiseStep] {
+        &self.steps
+    }
+
+    fn push(&mut self, op: &'static str, var: f64) -> &mut Self {
+        self.var = var;
+        self.steps.push(NoiseStep { op, var });
+        self
+    }
+
+    /// Records the addition (or subtraction) of an independent ciphertext.
+    pub fn add(&mut self, other: &NoiseTracker) -> &mut Self {
+        self.push("add", self.var + other.var)
+    }
+
+    /// Records a multiplication by a plaintext of squared L2 norm `sq_norm`.
+    pub fn mul_plain(&mut self, sq_norm: f64) -> &mut Self {
+        self.push("mul_plain", self.var * sq_norm)
+    }
+
+    /// Records a keyswitch from `glwe_in` with a key of layout `ksk`.
+    pub fn keyswitch<A, K>(&mut self, glwe_in: &A, ksk: &K) -> &mut Self
+    where
+        A: GLWEInfos,
+        K: GGLWEInfos,
+    {
+        let var: f64 = self.model.glwe_keyswitch(self.var, glwe_in, ksk);
+        self.push("keyswitch", var)
+    }
+
+    /// Records an automorphism of `glwe_in` with a key of layout `atk`.
+    pub fn automorphism<A, K>(&mut self, glwe_in: &A, atk: &K) -> &mut Self
+    where
+        A: GLWEInfos,
+        K: GGLWEInfos,
+    {
+        let var: f64 = self.model.glwe_automorphism(self.var, glwe_in, atk);
+        self.push("automorphism", var)
+    }
+
+    /// Records an external product of `glwe_in` with a GGSW of layout `ggsw`
+    /// encrypting a message of squared L2 norm `msg_sq_norm`.
+    pub fn external_product<A, G>(&mut self, msg_sq_norm: f64, glwe_in: &A, ggsw: &G) -> &mut Self
+    where
+        A: GLWEInfos,
+        G: GGSWInfos,
+    {
+        let var: f64 = self.model.glwe_external_product(self.var, msg_sq_norm, glwe_in, ggsw);
+        self.push("external_product", var)
+    }
+
+    /// Records a tensor product with `other` followed by relinearization with
+    /// a tensor key of layout `tsk`, producing a ciphertext of layout `res`.
+    ///
+    /// `msg_self` / `msg_other` are the second moments of the message
+    /// coefficients of both operands, and `cnv_offset` is the left shift applied
+    /// to the raw product.
+    #[allow(clippy::too_many_arguments)]
+    pub fn tensor<R, K>(
+        &mut self,
+        res: &R,
+        cnv_offset: usize,
+        msg_self: f64,
+        other: &NoiseTracker,
+        msg_other: f64,
+        tsk: &K,
+    ) -> &mut Self
+    where
+        R: GLWEInfos,
+        K: GGLWEInfos,
+    {
+        let var: f64 = self
+            .model
+            .glwe_tensor(res, cnv_offset, self.var, msg_self, other.var, msg_other);
+        let var: f64 = self.model.glwe_tensor_relinearize(var, res, tsk);
+        self.push("tensor", var)
+    }
+
+    /// Replaces the tracked state by the output of a blind rotation of an LWE
+    /// of dimension `lwe_n` into an accumulator of layout `acc`.
+    pub fn blind_rotation<A, G>(&mut self, lwe_n: usize, acc: &A, brk: &G) -> &mut Self
+    where
+        A: GLWEInfos,
+        G: GGSWInfos,
+    {
+        let var: f64 = self.model.blind_rotation(lwe_n, acc, brk);
+        self.push("blind_rotation", var)
+    }
+
+    /// Replaces the tracked LWE state by the output of a
+    /// [`GLWEPackLWEs`](crate::GLWEPackLWEs) of `N` such ciphertexts of layout
+    /// `lwe_in` into a GLWE of layout `res`.
+    pub fn pack_lwes<A, R, K, T>(&mut self, lwe_in: &A, res: &R, ksk: &K, atk: &T) -> &mut Self
+    where
+        A: LWEInfos,
+        R: GLWEInfos,
+        K: GGLWEInfos,
+        T: GGLWEInfos,
+    {
+        let var: f64 = self.model.glwe_pack_lwes(self.var, lwe_in, res, ksk, atk);
+        self.push("pack_lwes", var)
+    }
+
+    /// Records a sanitization with flooding noise `flood`.
+    pub fn sanitize(&mut self, flood: &NoiseFlooding) -> &mut Self {
+        let var: f64 = self.model.glwe_sanitize(self.var, flood);
+        self.push("sanitize", var)
+    }
+
+    /// Records a [`GLWETruncate`](crate::GLWETruncate) of `glwe_in` to `k` bits.
+    pub fn glwe_truncate<A>(&mut self, glwe_in: &A, k: usize) -> &mut Self
+    where
+        A: GLWEInfos,
+    {
+        let var: f64 = self.model.glwe_truncate(self.var, glwe_in, k);
+        self.push("glwe_truncate", var)
+    }
+
+    /// Records an [`LWETruncate`](crate::LWETruncate) of `lwe_in` to `k` bits.
+    pub fn lwe_truncate<A>(&mut self, lwe_in: &A, k: usize) -> &mut Self
+    where
+        A: LWEInfos,
+    {
+        let var: f64 = self.model.lwe_truncate(self.var, lwe_in, k);
+        self.push("lwe_truncate", var)
+    }
+
+    /// Smallest precision `k` to which `glwe_in` can be truncated with
+    /// [`GLWETruncate`](crate::GLWETruncate) while keeping `log2` of the
+    /// [`failure_probability`](Self::failure_probability) at `log2_bound`
+    /// below `log2_failure`.
+    ///
+    /// Returns `None` if the tracked ciphertext already exceeds the target
+    /// at the full precision of `glwe_in`.
+    pub fn glwe_truncate_min_k<A>(&self, glwe_in: &A, log2_bound: usize, log2_failure: f64) -> Option<usize>
+    where
+        A: GLWEInfos,
+    {
+        (1..=glwe_in.max_k().as_usize())
+            .find(|&k| log2_failure_probability(self.model.glwe_truncate(self.var, glwe_in, k), log2_bound) <= log2_failure)
+    }
+
+    /// Same as [`glwe_truncate_min_k`](Self::glwe_truncate_min_k) for an
+    /// [`LWETruncate`](crate::LWETruncate) of `lwe_in`.
+    pub fn lwe_truncate_min_k<A>(&self, lwe_in: &A, log2_bound: usize, log2_failure: f64) -> Option<usize>
+    where
+        A: LWEInfos,
+    {
+        (1..=lwe_in.max_k().as_usize())
+            .find(|&k| log2_failure_probability(self.model.lwe_truncate(self.var, lwe_in, k), log2_bound) <= log2_failure)
+    }
+
+    /// Probability that the error of a single coefficient exceeds `2^-log2_bound`
+    /// in absolute value, assuming it is Gaussian.
+    ///
+    /// For a plaintext encoded with precision `k_pt`, decryption is correct as
+    /// long as the error stays below half a plaintext step, i.e.
+    /// `log2_bound = k_pt + 1`.
+    pub fn failure_probability(&self, log2_bound: usize) -> f64 {
+        self.log2_failure_probability(log2_bound).exp2()
+    }
+
+    /// `log2` of [`failure_probability`](Self::failure_probability).
+    pub fn log2_failure_probability(&self, log2_bound: usize) -> f64 {
+        log2_failure_probability(self.var, log2_bound)
+    }
+}
+
+/// `log2` of the probability that a centered Gaussian of variance `var`
+/// exceeds `2^-log2_bound` in absolute value.
+fn log2_failure_probability(var: f64, log2_bound: usize) -> f64 {
+    let x: f64 = (-(log2_bound as f64)).exp2() / (std::f64::consts::SQRT_2 * var.sqrt());
+    log2_erfc(x)
+}
+
+/// `log2(erfc(x))` for `x >= 0`, with a relative error below `1.2e-7`
+/// (Numerical Recipes, `erfcc`). Evaluated in the log domain so that it
+/// remains accurate for failure probabilities far below `f64::MIN_POSITIVE`.
+pub(crate) fn log2_erfc(x: f64) -> f64 {
+    let x: f64 = x.max(0.0);
+    let t: f64 = 1.0 / (1.0 + 0.5 * x);
+    let poly: f64 = -1.26551223
+        + t * (1.00002368
+            + t * (0.37409196
+                + t * (0.09678418
+                    + t * (-0.18628806
+                        + t * (0.27886807 + t * (-1.13520398 + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277))))))));
+    (t.ln() - x * x + poly) * std::f64::consts::LOG2_E
+}
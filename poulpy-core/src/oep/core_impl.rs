@@ -15,9 +15,9 @@ use crate::{
         GGLWEToGGSWKeyPreparedToRef, GGLWEToGGSWKeyToMut, GGLWEToMut, GGLWEToRef, GGSWCompressedSeedMut, GGSWCompressedToMut,
         GGSWInfos, GGSWPreparedToRef, GGSWToMut, GGSWToRef, GLWE, GLWEAutomorphismKeyHelper, GLWECompressedSeedMut,
         GLWECompressedToMut, GLWEInfos, GLWEPlaintext, GLWEPlaintextToMut, GLWEPlaintextToRef, GLWEPreparedToRef,
-        GLWESecretPrepared, GLWESecretPreparedToRef, GLWESecretTensorPrepared, GLWESecretToRef, GLWESwitchingKeyDegreesMut,
-        GLWETensor, GLWETensorKeyPrepared, GLWEToMut, GLWEToRef, GetGaloisElement, LWEInfos, LWEPlaintextToMut,
-        LWEPlaintextToRef, LWESecretToRef, LWEToMut, LWEToRef, SetGaloisElement, SetLWEInfos,
+        GLWESecretPrepared, GLWESecretPreparedToRef, GLWESecretTensorPrepared, GLWESecretToRef, GLWESwitchingKeyDegrees,
+        GLWESwitchingKeyDegreesMut, GLWETensor, GLWETensorKeyPrepared, GLWEToMut, GLWEToRef, GetGaloisElement, LWEInfos,
+        LWEPlaintextToMut, LWEPlaintextToRef, LWESecretToRef, LWEToMut, LWEToRef, SetGaloisElement, SetLWEInfos,
     },
 };
 
@@ -50,6 +50,36 @@ pub unsafe trait CoreImpl<BE: Backend>: Backend {
         R: GLWEToMut + GLWEInfos,
         K: GGLWEPreparedToRef<BE> + GGLWEInfos;
 
+    fn glwe_ring_switch_tmp_bytes<R, A, K>(module: &Module<BE>, res_infos: &R, a_infos: &A, key_infos: &K) -> usize
+    where
+        R: GLWEInfos,
+        A: GLWEInfos,
+        K: GGLWEInfos;
+
+    fn glwe_ring_switch<R, A, K>(module: &Module<BE>, res: &mut R, a: &A, key: &K, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos + GLWESwitchingKeyDegrees;
+
+    fn glwe_ring_split<R, A, K>(
+        module: &Module<BE>,
+        res_even: &mut R,
+        res_odd: &mut R,
+        a: &A,
+        key: &K,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos + GLWESwitchingKeyDegrees;
+
+    fn glwe_ring_merge<R, A, K>(module: &Module<BE>, res: &mut R, a_even: &A, a_odd: &A, key: &K, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos + GLWESwitchingKeyDegrees;
+
     fn gglwe_keyswitch_tmp_bytes<R, A, K>(module: &Module<BE>, res_infos: &R, a_infos: &A, key_infos: &K) -> usize
     where
         R: GGLWEInfos,
@@ -574,6 +604,36 @@ pub unsafe trait CoreImpl<BE: Backend>: Backend {
         R: GLWEToMut,
         Scratch<BE>: ScratchTakeCore<BE>;
 
+    fn glwe_truncate_tmp_bytes<R>(module: &Module<BE>, res_infos: &R) -> usize
+    where
+        R: GLWEInfos;
+
+    fn glwe_truncate<R, A>(module: &Module<BE>, res: &mut R, k: usize, a: &A, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut,
+        A: GLWEToRef,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    fn glwe_truncate_assign<R>(module: &Module<BE>, res: &mut R, k: usize, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    fn lwe_truncate_tmp_bytes<R>(module: &Module<BE>, res_infos: &R) -> usize
+    where
+        R: LWEInfos;
+
+    fn lwe_truncate<R, A>(module: &Module<BE>, res: &mut R, k: usize, a: &A, scratch: &mut Scratch<BE>)
+    where
+        R: LWEToMut,
+        A: LWEToRef,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    fn lwe_truncate_assign<R>(module: &Module<BE>, res: &mut R, k: usize, scratch: &mut Scratch<BE>)
+    where
+        R: LWEToMut,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
     fn glwe_trace_galois_elements(module: &Module<BE>) -> Vec<i64>;
 
     fn glwe_trace_tmp_bytes<R, A, K>(module: &Module<BE>, res_infos: &R, a_infos: &A, key_infos: &K) -> usize
@@ -615,6 +675,29 @@ pub unsafe trait CoreImpl<BE: Backend>: Backend {
         K: GGLWEPreparedToRef<BE> + GetGaloisElement + GGLWEInfos,
         H: GLWEAutomorphismKeyHelper<K, BE>;
 
+    fn glwe_pack_lwes_galois_elements(module: &Module<BE>) -> Vec<i64>;
+
+    fn glwe_pack_lwes_tmp_bytes<R, A, K, T>(
+        module: &Module<BE>,
+        res_infos: &R,
+        lwe_infos: &A,
+        ksk_infos: &K,
+        atk_infos: &T,
+    ) -> usize
+    where
+        R: GLWEInfos,
+        A: LWEInfos,
+        K: GGLWEInfos,
+        T: GGLWEInfos;
+
+    fn glwe_pack_lwes<R, A, K, T, H>(module: &Module<BE>, res: &mut R, lwes: &[A], ksk: &K, keys: &H, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: LWEToRef,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos,
+        T: GGLWEPreparedToRef<BE> + GetGaloisElement + GGLWEInfos,
+        H: GLWEAutomorphismKeyHelper<T, BE>;
+
     fn packer_add<A, K, H>(
         module: &Module<BE>,
         packer: &mut GLWEPacker,
@@ -713,6 +796,78 @@ pub unsafe trait CoreImpl<BE: Backend>: Backend {
         E: EncryptionInfos,
         K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos;
 
+    fn glwe_sanitize_tmp_bytes<R>(module: &Module<BE>, res_infos: &R) -> usize
+    where
+        R: GLWEInfos;
+
+    fn glwe_sanitize<R, A, K, E, F>(
+        module: &Module<BE>,
+        res: &mut R,
+        a: &A,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos;
+
+    fn glwe_sanitize_assign<R, K, E, F>(
+        module: &Module<BE>,
+        res: &mut R,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos;
+
+    fn lwe_sanitize_tmp_bytes<R>(module: &Module<BE>, res_infos: &R) -> usize
+    where
+        R: LWEInfos;
+
+    fn lwe_sanitize<R, A, K, E, F>(
+        module: &Module<BE>,
+        res: &mut R,
+        a: &A,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: LWEToMut,
+        A: LWEToRef,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos;
+
+    fn lwe_sanitize_assign<R, K, E, F>(
+        module: &Module<BE>,
+        res: &mut R,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: LWEToMut,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos;
+
     fn glwe_public_key_generate<R, S, E>(
         module: &Module<BE>,
         res: &mut R,
@@ -763,6 +918,25 @@ pub unsafe trait CoreImpl<BE: Backend>: Backend {
         E: EncryptionInfos,
         S: GLWESecretPreparedToRef<BE>;
 
+    fn ggsw_encrypt_pk_tmp_bytes<A>(module: &Module<BE>, infos: &A) -> usize
+    where
+        A: GGSWInfos;
+
+    fn ggsw_encrypt_pk<R, P, K, E>(
+        module: &Module<BE>,
+        res: &mut R,
+        pt: &P,
+        pk: &K,
+        enc_infos: &E,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GGSWToMut,
+        P: ScalarZnxToRef,
+        E: EncryptionInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos;
+
     fn gglwe_to_ggsw_key_encrypt_sk_tmp_bytes<A>(module: &Module<BE>, infos: &A) -> usize
     where
         A: GGLWEInfos;
@@ -803,6 +977,25 @@ pub unsafe trait CoreImpl<BE: Backend>: Backend {
     where
         A: GGLWEInfos;
 
+    fn glwe_ring_switch_key_encrypt_sk_tmp_bytes<A>(module: &Module<BE>, infos: &A) -> usize
+    where
+        A: GGLWEInfos;
+
+    fn glwe_ring_switch_key_encrypt_sk<R, S1, S2, E>(
+        module: &Module<BE>,
+        res: &mut R,
+        sk_in: &S1,
+        sk_out: &S2,
+        enc_infos: &E,
+        source_xe: &mut Source,
+        source_xa: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GGLWEToMut + GLWESwitchingKeyDegreesMut + GGLWEInfos,
+        E: EncryptionInfos,
+        S1: GLWESecretToRef,
+        S2: GLWESecretToRef;
+
     fn glwe_tensor_key_encrypt_sk_tmp_bytes<A>(module: &Module<BE>, infos: &A) -> usize
     where
         A: GGLWEInfos;
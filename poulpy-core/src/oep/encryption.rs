@@ -9,19 +9,21 @@ use crate::{
     EncryptionInfos, GetDistribution, GetDistributionMut, ScratchTakeCore,
     encryption::{
         GGLWECompressedEncryptSkDefault, GGLWEEncryptSkDefault, GGLWEToGGSWKeyCompressedEncryptSkDefault,
-        GGLWEToGGSWKeyEncryptSkDefault, GGSWCompressedEncryptSkDefault, GGSWEncryptSkDefault,
+        GGLWEToGGSWKeyEncryptSkDefault, GGSWCompressedEncryptSkDefault, GGSWEncryptPkDefault, GGSWEncryptSkDefault,
         GLWEAutomorphismKeyCompressedEncryptSkDefault, GLWEAutomorphismKeyEncryptPkDefault, GLWEAutomorphismKeyEncryptSkDefault,
         GLWECompressedEncryptSkDefault, GLWEEncryptPkDefault, GLWEEncryptSkDefault, GLWEPublicKeyGenerateDefault,
-        GLWESwitchingKeyCompressedEncryptSkDefault, GLWESwitchingKeyEncryptPkDefault, GLWESwitchingKeyEncryptSkDefault,
-        GLWETensorKeyCompressedEncryptSkDefault, GLWETensorKeyEncryptSkDefault, GLWEToLWESwitchingKeyEncryptSkDefault,
-        LWEEncryptSkDefault, LWESwitchingKeyEncryptDefault, LWEToGLWESwitchingKeyEncryptSkDefault,
+        GLWERingSwitchKeyEncryptSkDefault, GLWESwitchingKeyCompressedEncryptSkDefault, GLWESwitchingKeyEncryptPkDefault,
+        GLWESwitchingKeyEncryptSkDefault, GLWETensorKeyCompressedEncryptSkDefault, GLWETensorKeyEncryptSkDefault,
+        GLWEToLWESwitchingKeyEncryptSkDefault, LWEEncryptSkDefault, LWESwitchingKeyEncryptDefault,
+        LWEToGLWESwitchingKeyEncryptSkDefault,
     },
     layouts::{
         GGLWECompressedSeedMut, GGLWECompressedToMut, GGLWEInfos, GGLWEToGGSWKeyCompressedToMut, GGLWEToGGSWKeyToMut, GGLWEToMut,
         GGSWCompressedSeedMut, GGSWCompressedToMut, GGSWInfos, GGSWToMut, GLWECompressedSeedMut, GLWECompressedToMut, GLWEInfos,
         GLWEPlaintextToRef, GLWEPreparedToRef, GLWESecretPreparedToRef, GLWESecretToRef, GLWESwitchingKeyDegreesMut, GLWEToMut,
-        LWEInfos, LWEPlaintextToRef, LWESecretToRef, LWEToMut, SetGaloisElement,
+        GLWEToRef, LWEInfos, LWEPlaintextToRef, LWESecretToRef, LWEToMut, LWEToRef, SetGaloisElement,
     },
+    sanitize::{GLWESanitizeDefault, LWESanitizeDefault},
 };
 
 #[doc(hidden)]
@@ -110,6 +112,78 @@ pub trait CoreEncryptionDefaults<BE: Backend>: Backend {
         E: EncryptionInfos,
         K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos;
 
+    fn glwe_sanitize_tmp_bytes_default<R>(module: &Module<BE>, res_infos: &R) -> usize
+    where
+        R: GLWEInfos;
+
+    fn glwe_sanitize_default<R, A, K, E, F>(
+        module: &Module<BE>,
+        res: &mut R,
+        a: &A,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos;
+
+    fn glwe_sanitize_assign_default<R, K, E, F>(
+        module: &Module<BE>,
+        res: &mut R,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos;
+
+    fn lwe_sanitize_tmp_bytes_default<R>(module: &Module<BE>, res_infos: &R) -> usize
+    where
+        R: LWEInfos;
+
+    fn lwe_sanitize_default<R, A, K, E, F>(
+        module: &Module<BE>,
+        res: &mut R,
+        a: &A,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: LWEToMut,
+        A: LWEToRef,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos;
+
+    fn lwe_sanitize_assign_default<R, K, E, F>(
+        module: &Module<BE>,
+        res: &mut R,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: LWEToMut,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos;
+
     fn glwe_public_key_generate_default<R, S, E>(
         module: &Module<BE>,
         res: &mut R,
@@ -160,6 +234,25 @@ pub trait CoreEncryptionDefaults<BE: Backend>: Backend {
         E: EncryptionInfos,
         S: GLWESecretPreparedToRef<BE>;
 
+    fn ggsw_encrypt_pk_tmp_bytes_default<A>(module: &Module<BE>, infos: &A) -> usize
+    where
+        A: GGSWInfos;
+
+    fn ggsw_encrypt_pk_default<R, P, K, E>(
+        module: &Module<BE>,
+        res: &mut R,
+        pt: &P,
+        pk: &K,
+        enc_infos: &E,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GGSWToMut,
+        P: ScalarZnxToRef,
+        E: EncryptionInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos;
+
     fn gglwe_to_ggsw_key_encrypt_sk_tmp_bytes_default<A>(module: &Module<BE>, infos: &A) -> usize
     where
         A: GGLWEInfos;
@@ -200,6 +293,25 @@ pub trait CoreEncryptionDefaults<BE: Backend>: Backend {
     where
         A: GGLWEInfos;
 
+    fn glwe_ring_switch_key_encrypt_sk_tmp_bytes_default<A>(module: &Module<BE>, infos: &A) -> usize
+    where
+        A: GGLWEInfos;
+
+    fn glwe_ring_switch_key_encrypt_sk_default<R, S1, S2, E>(
+        module: &Module<BE>,
+        res: &mut R,
+        sk_in: &S1,
+        sk_out: &S2,
+        enc_infos: &E,
+        source_xe: &mut Source,
+        source_xa: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GGLWEToMut + GLWESwitchingKeyDegreesMut + GGLWEInfos,
+        E: EncryptionInfos,
+        S1: GLWESecretToRef,
+        S2: GLWESecretToRef;
+
     fn glwe_tensor_key_encrypt_sk_tmp_bytes_default<A>(module: &Module<BE>, infos: &A) -> usize
     where
         A: GGLWEInfos;
@@ -430,12 +542,16 @@ where
     Module<BE>: LWEEncryptSkDefault<BE>
         + GLWEEncryptSkDefault<BE>
         + GLWEEncryptPkDefault<BE>
+        + GLWESanitizeDefault<BE>
+        + LWESanitizeDefault<BE>
         + GLWEPublicKeyGenerateDefault<BE>
         + GGLWEEncryptSkDefault<BE>
         + GGSWEncryptSkDefault<BE>
+        + GGSWEncryptPkDefault<BE>
         + GGLWEToGGSWKeyEncryptSkDefault<BE>
         + GLWESwitchingKeyEncryptSkDefault<BE>
         + GLWESwitchingKeyEncryptPkDefault<BE>
+        + GLWERingSwitchKeyEncryptSkDefault<BE>
         + GLWETensorKeyEncryptSkDefault<BE>
         + GLWEToLWESwitchingKeyEncryptSkDefault<BE>
         + LWESwitchingKeyEncryptDefault<BE>
@@ -559,6 +675,104 @@ where
         <Module<BE> as GLWEEncryptPkDefault<BE>>::glwe_encrypt_zero_pk(module, res, pk, enc_infos, source_xu, source_xe, scratch)
     }
 
+    fn glwe_sanitize_tmp_bytes_default<R>(module: &Module<BE>, res_infos: &R) -> usize
+    where
+        R: GLWEInfos,
+    {
+        <Module<BE> as GLWESanitizeDefault<BE>>::glwe_sanitize_tmp_bytes_default(module, res_infos)
+    }
+
+    fn glwe_sanitize_default<R, A, K, E, F>(
+        module: &Module<BE>,
+        res: &mut R,
+        a: &A,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos,
+    {
+        <Module<BE> as GLWESanitizeDefault<BE>>::glwe_sanitize_default(
+            module, res, a, pk, enc_infos, flood, source_xu, source_xe, scratch,
+        )
+    }
+
+    fn glwe_sanitize_assign_default<R, K, E, F>(
+        module: &Module<BE>,
+        res: &mut R,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos,
+    {
+        <Module<BE> as GLWESanitizeDefault<BE>>::glwe_sanitize_assign_default(
+            module, res, pk, enc_infos, flood, source_xu, source_xe, scratch,
+        )
+    }
+
+    fn lwe_sanitize_tmp_bytes_default<R>(module: &Module<BE>, res_infos: &R) -> usize
+    where
+        R: LWEInfos,
+    {
+        <Module<BE> as LWESanitizeDefault<BE>>::lwe_sanitize_tmp_bytes_default(module, res_infos)
+    }
+
+    fn lwe_sanitize_default<R, A, K, E, F>(
+        module: &Module<BE>,
+        res: &mut R,
+        a: &A,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: LWEToMut,
+        A: LWEToRef,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos,
+    {
+        <Module<BE> as LWESanitizeDefault<BE>>::lwe_sanitize_default(
+            module, res, a, pk, enc_infos, flood, source_xu, source_xe, scratch,
+        )
+    }
+
+    fn lwe_sanitize_assign_default<R, K, E, F>(
+        module: &Module<BE>,
+        res: &mut R,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: LWEToMut,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos,
+    {
+        <Module<BE> as LWESanitizeDefault<BE>>::lwe_sanitize_assign_default(
+            module, res, pk, enc_infos, flood, source_xu, source_xe, scratch,
+        )
+    }
+
     fn glwe_public_key_generate_default<R, S, E>(
         module: &Module<BE>,
         res: &mut R,
@@ -626,6 +840,31 @@ where
         <Module<BE> as GGSWEncryptSkDefault<BE>>::ggsw_encrypt_sk(module, res, pt, sk, enc_infos, source_xe, source_xa, scratch)
     }
 
+    fn ggsw_encrypt_pk_tmp_bytes_default<A>(module: &Module<BE>, infos: &A) -> usize
+    where
+        A: GGSWInfos,
+    {
+        <Module<BE> as GGSWEncryptPkDefault<BE>>::ggsw_encrypt_pk_tmp_bytes(module, infos)
+    }
+
+    fn ggsw_encrypt_pk_default<R, P, K, E>(
+        module: &Module<BE>,
+        res: &mut R,
+        pt: &P,
+        pk: &K,
+        enc_infos: &E,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GGSWToMut,
+        P: ScalarZnxToRef,
+        E: EncryptionInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+    {
+        <Module<BE> as GGSWEncryptPkDefault<BE>>::ggsw_encrypt_pk(module, res, pt, pk, enc_infos, source_xu, source_xe, scratch)
+    }
+
     fn gglwe_to_ggsw_key_encrypt_sk_tmp_bytes_default<A>(module: &Module<BE>, infos: &A) -> usize
     where
         A: GGLWEInfos,
@@ -685,6 +924,33 @@ where
         <Module<BE> as GLWESwitchingKeyEncryptPkDefault<BE>>::glwe_switching_key_encrypt_pk_tmp_bytes(module, infos)
     }
 
+    fn glwe_ring_switch_key_encrypt_sk_tmp_bytes_default<A>(module: &Module<BE>, infos: &A) -> usize
+    where
+        A: GGLWEInfos,
+    {
+        <Module<BE> as GLWERingSwitchKeyEncryptSkDefault<BE>>::glwe_ring_switch_key_encrypt_sk_tmp_bytes(module, infos)
+    }
+
+    fn glwe_ring_switch_key_encrypt_sk_default<R, S1, S2, E>(
+        module: &Module<BE>,
+        res: &mut R,
+        sk_in: &S1,
+        sk_out: &S2,
+        enc_infos: &E,
+        source_xe: &mut Source,
+        source_xa: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GGLWEToMut + GLWESwitchingKeyDegreesMut + GGLWEInfos,
+        E: EncryptionInfos,
+        S1: GLWESecretToRef,
+        S2: GLWESecretToRef,
+    {
+        <Module<BE> as GLWERingSwitchKeyEncryptSkDefault<BE>>::glwe_ring_switch_key_encrypt_sk(
+            module, res, sk_in, sk_out, enc_infos, source_xe, source_xa, scratch,
+        )
+    }
+
     fn glwe_tensor_key_encrypt_sk_tmp_bytes_default<A>(module: &Module<BE>, infos: &A) -> usize
     where
         A: GGLWEInfos,
@@ -1136,6 +1402,104 @@ macro_rules! impl_core_encryption_default_methods {
             )
         }
 
+        fn glwe_sanitize_tmp_bytes<R>(module: &poulpy_hal::layouts::Module<$be>, res_infos: &R) -> usize
+        where
+            R: $crate::layouts::GLWEInfos,
+        {
+            <$be as $crate::oep::CoreEncryptionDefaults<$be>>::glwe_sanitize_tmp_bytes_default(module, res_infos)
+        }
+
+        fn glwe_sanitize<R, A, K, E, F>(
+            module: &poulpy_hal::layouts::Module<$be>,
+            res: &mut R,
+            a: &A,
+            pk: &K,
+            enc_infos: &E,
+            flood: &F,
+            source_xu: &mut poulpy_hal::source::Source,
+            source_xe: &mut poulpy_hal::source::Source,
+            scratch: &mut poulpy_hal::layouts::Scratch<$be>,
+        ) where
+            R: $crate::layouts::GLWEToMut + $crate::layouts::GLWEInfos,
+            A: $crate::layouts::GLWEToRef + $crate::layouts::GLWEInfos,
+            K: $crate::layouts::GLWEPreparedToRef<$be> + $crate::GetDistribution + $crate::layouts::GLWEInfos,
+            E: $crate::EncryptionInfos,
+            F: $crate::EncryptionInfos,
+        {
+            <$be as $crate::oep::CoreEncryptionDefaults<$be>>::glwe_sanitize_default(
+                module, res, a, pk, enc_infos, flood, source_xu, source_xe, scratch,
+            )
+        }
+
+        fn glwe_sanitize_assign<R, K, E, F>(
+            module: &poulpy_hal::layouts::Module<$be>,
+            res: &mut R,
+            pk: &K,
+            enc_infos: &E,
+            flood: &F,
+            source_xu: &mut poulpy_hal::source::Source,
+            source_xe: &mut poulpy_hal::source::Source,
+            scratch: &mut poulpy_hal::layouts::Scratch<$be>,
+        ) where
+            R: $crate::layouts::GLWEToMut + $crate::layouts::GLWEInfos,
+            K: $crate::layouts::GLWEPreparedToRef<$be> + $crate::GetDistribution + $crate::layouts::GLWEInfos,
+            E: $crate::EncryptionInfos,
+            F: $crate::EncryptionInfos,
+        {
+            <$be as $crate::oep::CoreEncryptionDefaults<$be>>::glwe_sanitize_assign_default(
+                module, res, pk, enc_infos, flood, source_xu, source_xe, scratch,
+            )
+        }
+
+        fn lwe_sanitize_tmp_bytes<R>(module: &poulpy_hal::layouts::Module<$be>, res_infos: &R) -> usize
+        where
+            R: $crate::layouts::LWEInfos,
+        {
+            <$be as $crate::oep::CoreEncryptionDefaults<$be>>::lwe_sanitize_tmp_bytes_default(module, res_infos)
+        }
+
+        fn lwe_sanitize<R, A, K, E, F>(
+            module: &poulpy_hal::layouts::Module<$be>,
+            res: &mut R,
+            a: &A,
+            pk: &K,
+            enc_infos: &E,
+            flood: &F,
+            source_xu: &mut poulpy_hal::source::Source,
+            source_xe: &mut poulpy_hal::source::Source,
+            scratch: &mut poulpy_hal::layouts::Scratch<$be>,
+        ) where
+            R: $crate::layouts::LWEToMut,
+            A: $crate::layouts::LWEToRef,
+            K: $crate::layouts::GLWEPreparedToRef<$be> + $crate::GetDistribution + $crate::layouts::GLWEInfos,
+            E: $crate::EncryptionInfos,
+            F: $crate::EncryptionInfos,
+        {
+            <$be as $crate::oep::CoreEncryptionDefaults<$be>>::lwe_sanitize_default(
+                module, res, a, pk, enc_infos, flood, source_xu, source_xe, scratch,
+            )
+        }
+
+        fn lwe_sanitize_assign<R, K, E, F>(
+            module: &poulpy_hal::layouts::Module<$be>,
+            res: &mut R,
+            pk: &K,
+            enc_infos: &E,
+            flood: &F,
+            source_xu: &mut poulpy_hal::source::Source,
+            source_xe: &mut poulpy_hal::source::Source,
+            scratch: &mut poulpy_hal::layouts::Scratch<$be>,
+        ) where
+            R: $crate::layouts::LWEToMut,
+            K: $crate::layouts::GLWEPreparedToRef<$be> + $crate::GetDistribution + $crate::layouts::GLWEInfos,
+            E: $crate::EncryptionInfos,
+            F: $crate::EncryptionInfos,
+        {
+            <$be as $crate::oep::CoreEncryptionDefaults<$be>>::lwe_sanitize_assign_default(
+                module, res, pk, enc_infos, flood, source_xu, source_xe, scratch,
+            )
+        }
+
         fn glwe_public_key_generate<R, S, E>(
             module: &poulpy_hal::layouts::Module<$be>,
             res: &mut R,
@@ -1207,6 +1571,33 @@ macro_rules! impl_core_encryption_default_methods {
             )
         }
 
+        fn ggsw_encrypt_pk_tmp_bytes<A>(module: &poulpy_hal::layouts::Module<$be>, infos: &A) -> usize
+        where
+            A: $crate::layouts::GGSWInfos,
+        {
+            <$be as $crate::oep::CoreEncryptionDefaults<$be>>::ggsw_encrypt_pk_tmp_bytes_default(module, infos)
+        }
+
+        fn ggsw_encrypt_pk<R, P, K, E>(
+            module: &poulpy_hal::layouts::Module<$be>,
+            res: &mut R,
+            pt: &P,
+            pk: &K,
+            enc_infos: &E,
+            source_xu: &mut poulpy_hal::source::Source,
+            source_xe: &mut poulpy_hal::source::Source,
+            scratch: &mut poulpy_hal::layouts::Scratch<$be>,
+        ) where
+            R: $crate::layouts::GGSWToMut,
+            P: poulpy_hal::layouts::ScalarZnxToRef,
+            E: $crate::EncryptionInfos,
+            K: $crate::layouts::GLWEPreparedToRef<$be> + $crate::GetDistribution + $crate::layouts::GLWEInfos,
+        {
+            <$be as $crate::oep::CoreEncryptionDefaults<$be>>::ggsw_encrypt_pk_default(
+                module, res, pt, pk, enc_infos, source_xu, source_xe, scratch,
+            )
+        }
+
         fn gglwe_to_ggsw_key_encrypt_sk_tmp_bytes<A>(module: &poulpy_hal::layouts::Module<$be>, infos: &A) -> usize
         where
             A: $crate::layouts::GGLWEInfos,
@@ -1266,6 +1657,33 @@ macro_rules! impl_core_encryption_default_methods {
             <$be as $crate::oep::CoreEncryptionDefaults<$be>>::glwe_switching_key_encrypt_pk_tmp_bytes_default(module, infos)
         }
 
+        fn glwe_ring_switch_key_encrypt_sk_tmp_bytes<A>(module: &poulpy_hal::layouts::Module<$be>, infos: &A) -> usize
+        where
+            A: $crate::layouts::GGLWEInfos,
+        {
+            <$be as $crate::oep::CoreEncryptionDefaults<$be>>::glwe_ring_switch_key_encrypt_sk_tmp_bytes_default(module, infos)
+        }
+
+        fn glwe_ring_switch_key_encrypt_sk<R, S1, S2, E>(
+            module: &poulpy_hal::layouts::Module<$be>,
+            res: &mut R,
+            sk_in: &S1,
+            sk_out: &S2,
+            enc_infos: &E,
+            source_xe: &mut poulpy_hal::source::Source,
+            source_xa: &mut poulpy_hal::source::Source,
+            scratch: &mut poulpy_hal::layouts::Scratch<$be>,
+        ) where
+            R: $crate::layouts::GGLWEToMut + $crate::layouts::GLWESwitchingKeyDegreesMut + $crate::layouts::GGLWEInfos,
+            E: $crate::EncryptionInfos,
+            S1: $crate::layouts::GLWESecretToRef,
+            S2: $crate::layouts::GLWESecretToRef,
+        {
+            <$be as $crate::oep::CoreEncryptionDefaults<$be>>::glwe_ring_switch_key_encrypt_sk_default(
+                module, res, sk_in, sk_out, enc_infos, source_xe, source_xa, scratch,
+            )
+        }
+
         fn glwe_tensor_key_encrypt_sk_tmp_bytes<A>(module: &poulpy_hal::layouts::Module<$be>, infos: &A) -> usize
         where
             A: $crate::layouts::GGLWEInfos,
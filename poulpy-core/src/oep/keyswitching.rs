@@ -2,10 +2,12 @@ use poulpy_hal::layouts::{Backend, Module, Scratch};
 
 use crate::{
     ScratchTakeCore,
-    keyswitching::{GGLWEKeyswitchDefault, GGSWKeyswitchDefault, GLWEKeyswitchDefault, LWEKeySwitchDefault},
+    keyswitching::{
+        GGLWEKeyswitchDefault, GGSWKeyswitchDefault, GLWEKeyswitchDefault, GLWERingSwitchDefault, LWEKeySwitchDefault,
+    },
     layouts::{
         GGLWEInfos, GGLWEPreparedToRef, GGLWEToGGSWKeyPreparedToRef, GGLWEToMut, GGLWEToRef, GGSWInfos, GGSWToMut, GGSWToRef,
-        GLWEInfos, GLWEToMut, GLWEToRef, LWEInfos, LWEToMut, LWEToRef,
+        GLWEInfos, GLWESwitchingKeyDegrees, GLWEToMut, GLWEToRef, LWEInfos, LWEToMut, LWEToRef,
     },
 };
 
@@ -87,11 +89,51 @@ pub trait CoreKeyswitchDefaults<BE: Backend>: Backend {
         A: LWEToRef,
         K: GGLWEPreparedToRef<BE> + GGLWEInfos,
         Scratch<BE>: ScratchTakeCore<BE>;
+
+    fn glwe_ring_switch_tmp_bytes_default<R, A, K>(module: &Module<BE>, res_infos: &R, a_infos: &A, key_infos: &K) -> usize
+    where
+        R: GLWEInfos,
+        A: GLWEInfos,
+        K: GGLWEInfos;
+
+    fn glwe_ring_switch_default<R, A, K>(module: &Module<BE>, res: &mut R, a: &A, key: &K, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos + GLWESwitchingKeyDegrees;
+
+    fn glwe_ring_split_default<R, A, K>(
+        module: &Module<BE>,
+        res_even: &mut R,
+        res_odd: &mut R,
+        a: &A,
+        key: &K,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos + GLWESwitchingKeyDegrees;
+
+    fn glwe_ring_merge_default<R, A, K>(
+        module: &Module<BE>,
+        res: &mut R,
+        a_even: &A,
+        a_odd: &A,
+        key: &K,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos + GLWESwitchingKeyDegrees;
 }
 
 impl<BE: Backend> CoreKeyswitchDefaults<BE> for BE
 where
-    Module<BE>: GLWEKeyswitchDefault<BE> + GGLWEKeyswitchDefault<BE> + GGSWKeyswitchDefault<BE> + LWEKeySwitchDefault<BE>,
+    Module<BE>: GLWEKeyswitchDefault<BE>
+        + GGLWEKeyswitchDefault<BE>
+        + GGSWKeyswitchDefault<BE>
+        + LWEKeySwitchDefault<BE>
+        + GLWERingSwitchDefault<BE>,
     Scratch<BE>: ScratchTakeCore<BE>,
 {
     fn glwe_keyswitch_tmp_bytes_default<R, A, K>(module: &Module<BE>, res_infos: &R, a_infos: &A, key_infos: &K) -> usize
@@ -205,6 +247,54 @@ where
     {
         <Module<BE> as LWEKeySwitchDefault<BE>>::lwe_keyswitch_default(module, res, a, ksk, scratch)
     }
+
+    fn glwe_ring_switch_tmp_bytes_default<R, A, K>(module: &Module<BE>, res_infos: &R, a_infos: &A, key_infos: &K) -> usize
+    where
+        R: GLWEInfos,
+        A: GLWEInfos,
+        K: GGLWEInfos,
+    {
+        <Module<BE> as GLWERingSwitchDefault<BE>>::glwe_ring_switch_tmp_bytes_default(module, res_infos, a_infos, key_infos)
+    }
+
+    fn glwe_ring_switch_default<R, A, K>(module: &Module<BE>, res: &mut R, a: &A, key: &K, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos + GLWESwitchingKeyDegrees,
+    {
+        <Module<BE> as GLWERingSwitchDefault<BE>>::glwe_ring_switch_default(module, res, a, key, scratch)
+    }
+
+    fn glwe_ring_split_default<R, A, K>(
+        module: &Module<BE>,
+        res_even: &mut R,
+        res_odd: &mut R,
+        a: &A,
+        key: &K,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos + GLWESwitchingKeyDegrees,
+    {
+        <Module<BE> as GLWERingSwitchDefault<BE>>::glwe_ring_split_default(module, res_even, res_odd, a, key, scratch)
+    }
+
+    fn glwe_ring_merge_default<R, A, K>(
+        module: &Module<BE>,
+        res: &mut R,
+        a_even: &A,
+        a_odd: &A,
+        key: &K,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos + GLWESwitchingKeyDegrees,
+    {
+        <Module<BE> as GLWERingSwitchDefault<BE>>::glwe_ring_merge_default(module, res, a_even, a_odd, key, scratch)
+    }
 }
 
 #[macro_export]
@@ -376,5 +466,65 @@ macro_rules! impl_core_keyswitch_default_methods {
         {
             <$be as $crate::oep::CoreKeyswitchDefaults<$be>>::lwe_keyswitch_default(module, res, a, ksk, scratch)
         }
+
+        fn glwe_ring_switch_tmp_bytes<R, A, K>(
+            module: &poulpy_hal::layouts::Module<$be>,
+            res_infos: &R,
+            a_infos: &A,
+            key_infos: &K,
+        ) -> usize
+        where
+            R: $crate::layouts::GLWEInfos,
+            A: $crate::layouts::GLWEInfos,
+            K: $crate::layouts::GGLWEInfos,
+        {
+            <$be as $crate::oep::CoreKeyswitchDefaults<$be>>::glwe_ring_switch_tmp_bytes_default(
+                module, res_infos, a_infos, key_infos,
+            )
+        }
+
+        fn glwe_ring_switch<R, A, K>(
+            module: &poulpy_hal::layouts::Module<$be>,
+            res: &mut R,
+            a: &A,
+            key: &K,
+            scratch: &mut poulpy_hal::layouts::Scratch<$be>,
+        ) where
+            R: $crate::layouts::GLWEToMut + $crate::layouts::GLWEInfos,
+            A: $crate::layouts::GLWEToRef + $crate::layouts::GLWEInfos,
+            K: $crate::layouts::GGLWEPreparedToRef<$be> + $crate::layouts::GGLWEInfos + $crate::layouts::GLWESwitchingKeyDegrees,
+        {
+            <$be as $crate::oep::CoreKeyswitchDefaults<$be>>::glwe_ring_switch_default(module, res, a, key, scratch)
+        }
+
+        fn glwe_ring_split<R, A, K>(
+            module: &poulpy_hal::layouts::Module<$be>,
+            res_even: &mut R,
+            res_odd: &mut R,
+            a: &A,
+            key: &K,
+            scratch: &mut poulpy_hal::layouts::Scratch<$be>,
+        ) where
+            R: $crate::layouts::GLWEToMut + $crate::layouts::GLWEInfos,
+            A: $crate::layouts::GLWEToRef + $crate::layouts::GLWEInfos,
+            K: $crate::layouts::GGLWEPreparedToRef<$be> + $crate::layouts::GGLWEInfos + $crate::layouts::GLWESwitchingKeyDegrees,
+        {
+            <$be as $crate::oep::CoreKeyswitchDefaults<$be>>::glwe_ring_split_default(module, res_even, res_odd, a, key, scratch)
+        }
+
+        fn glwe_ring_merge<R, A, K>(
+            module: &poulpy_hal::layouts::Module<$be>,
+            res: &mut R,
+            a_even: &A,
+            a_odd: &A,
+            key: &K,
+            scratch: &mut poulpy_hal::layouts::Scratch<$be>,
+        ) where
+            R: $crate::layouts::GLWEToMut + $crate::layouts::GLWEInfos,
+            A: $crate::layouts::GLWEToRef + $crate::layouts::GLWEInfos,
+            K: $crate::layouts::GGLWEPreparedToRef<$be> + $crate::layouts::GGLWEInfos + $crate::layouts::GLWESwitchingKeyDegrees,
+        {
+            <$be as $crate::oep::CoreKeyswitchDefaults<$be>>::glwe_ring_merge_default(module, res, a_even, a_odd, key, scratch)
+        }
     };
 }
@@ -42,3 +42,20 @@ macro_rules! impl_core_default_methods {
 }
 
 pub use crate::impl_core_default_methods;
+
+// Runtime-dispatched backends inherit the default algorithms; every HAL call
+// they issue is forwarded to the backend selected at module creation.
+unsafe impl<Family: poulpy_hal::dyn_backend::DynBackendFamily> CoreImpl<poulpy_hal::dyn_backend::DynBackend<Family>>
+    for poulpy_hal::dyn_backend::DynBackend<Family>
+{
+    impl_core_default_methods!(poulpy_hal::dyn_backend::DynBackend<Family>);
+}
+
+// The profiling backend records the HAL calls issued by the default
+// algorithms before forwarding them to the backend it wraps.
+unsafe impl<BE> CoreImpl<poulpy_hal::profiling::Profiled<BE>> for poulpy_hal::profiling::Profiled<BE>
+where
+    BE: poulpy_hal::layouts::Backend + poulpy_hal::oep::HalImpl<BE> + 'static,
+{
+    impl_core_default_methods!(poulpy_hal::profiling::Profiled<BE>);
+}
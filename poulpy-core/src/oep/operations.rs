@@ -5,16 +5,17 @@ use poulpy_hal::layouts::{Backend, DataMut, DataRef, Module, Scratch};
 use crate::{
     ScratchTakeCore,
     glwe_packer::{GLWEPacker, GLWEPackerOpsDefault},
-    glwe_packing::GLWEPackingDefault,
+    glwe_packing::{GLWEPackLWEsDefault, GLWEPackingDefault},
     glwe_trace::GLWETraceDefault,
     layouts::{
         GGLWEInfos, GGLWEPreparedToRef, GGSWToMut, GGSWToRef, GLWE, GLWEAutomorphismKeyHelper, GLWEInfos, GLWEPlaintext,
-        GLWETensor, GLWETensorKeyPrepared, GLWEToMut, GLWEToRef, GetGaloisElement,
+        GLWETensor, GLWETensorKeyPrepared, GLWEToMut, GLWEToRef, GetGaloisElement, LWEInfos, LWEToMut, LWEToRef,
     },
     operations::{
         GGSWRotateDefault, GLWEMulConstDefault, GLWEMulPlainDefault, GLWEMulXpMinusOneDefault, GLWENormalizeDefault,
         GLWERotateDefault, GLWEShiftDefault, GLWETensoringDefault,
     },
+    truncate::{GLWETruncateDefault, LWETruncateDefault},
 };
 
 #[doc(hidden)]
@@ -247,6 +248,36 @@ pub trait CoreOperationsDefaults<BE: Backend>: Backend {
         R: GLWEToMut,
         Scratch<BE>: ScratchTakeCore<BE>;
 
+    fn glwe_truncate_tmp_bytes_default<R>(module: &Module<BE>, res_infos: &R) -> usize
+    where
+        R: GLWEInfos;
+
+    fn glwe_truncate_default<R, A>(module: &Module<BE>, res: &mut R, k: usize, a: &A, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut,
+        A: GLWEToRef,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    fn glwe_truncate_assign_default<R>(module: &Module<BE>, res: &mut R, k: usize, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    fn lwe_truncate_tmp_bytes_default<R>(module: &Module<BE>, res_infos: &R) -> usize
+    where
+        R: LWEInfos;
+
+    fn lwe_truncate_default<R, A>(module: &Module<BE>, res: &mut R, k: usize, a: &A, scratch: &mut Scratch<BE>)
+    where
+        R: LWEToMut,
+        A: LWEToRef,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
+    fn lwe_truncate_assign_default<R>(module: &Module<BE>, res: &mut R, k: usize, scratch: &mut Scratch<BE>)
+    where
+        R: LWEToMut,
+        Scratch<BE>: ScratchTakeCore<BE>;
+
     fn glwe_trace_galois_elements_default(module: &Module<BE>) -> Vec<i64>;
 
     fn glwe_trace_tmp_bytes_default<R, A, K>(module: &Module<BE>, res_infos: &R, a_infos: &A, key_infos: &K) -> usize
@@ -288,6 +319,35 @@ pub trait CoreOperationsDefaults<BE: Backend>: Backend {
         K: GGLWEPreparedToRef<BE> + GetGaloisElement + GGLWEInfos,
         H: GLWEAutomorphismKeyHelper<K, BE>;
 
+    fn glwe_pack_lwes_galois_elements_default(module: &Module<BE>) -> Vec<i64>;
+
+    fn glwe_pack_lwes_tmp_bytes_default<R, A, K, T>(
+        module: &Module<BE>,
+        res_infos: &R,
+        lwe_infos: &A,
+        ksk_infos: &K,
+        atk_infos: &T,
+    ) -> usize
+    where
+        R: GLWEInfos,
+        A: LWEInfos,
+        K: GGLWEInfos,
+        T: GGLWEInfos;
+
+    fn glwe_pack_lwes_default<R, A, K, T, H>(
+        module: &Module<BE>,
+        res: &mut R,
+        lwes: &[A],
+        ksk: &K,
+        keys: &H,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        A: LWEToRef,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos,
+        T: GGLWEPreparedToRef<BE> + GetGaloisElement + GGLWEInfos,
+        H: GLWEAutomorphismKeyHelper<T, BE>;
+
     fn packer_add_default<A, K, H>(
         module: &Module<BE>,
         packer: &mut GLWEPacker,
@@ -312,8 +372,11 @@ where
         + GLWEMulXpMinusOneDefault<BE>
         + GLWEShiftDefault<BE>
         + GLWENormalizeDefault<BE>
+        + GLWETruncateDefault<BE>
+        + LWETruncateDefault<BE>
         + GLWETraceDefault<BE>
         + GLWEPackingDefault<BE>
+        + GLWEPackLWEsDefault<BE>
         + GLWEPackerOpsDefault<BE>,
     Scratch<BE>: ScratchTakeCore<BE>,
 {
@@ -679,6 +742,54 @@ where
         <Module<BE> as GLWENormalizeDefault<BE>>::glwe_normalize_assign(module, res, scratch)
     }
 
+    fn glwe_truncate_tmp_bytes_default<R>(module: &Module<BE>, res_infos: &R) -> usize
+    where
+        R: GLWEInfos,
+    {
+        <Module<BE> as GLWETruncateDefault<BE>>::glwe_truncate_tmp_bytes_default(module, res_infos)
+    }
+
+    fn glwe_truncate_default<R, A>(module: &Module<BE>, res: &mut R, k: usize, a: &A, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut,
+        A: GLWEToRef,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        <Module<BE> as GLWETruncateDefault<BE>>::glwe_truncate_default(module, res, k, a, scratch)
+    }
+
+    fn glwe_truncate_assign_default<R>(module: &Module<BE>, res: &mut R, k: usize, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        <Module<BE> as GLWETruncateDefault<BE>>::glwe_truncate_assign_default(module, res, k, scratch)
+    }
+
+    fn lwe_truncate_tmp_bytes_default<R>(module: &Module<BE>, res_infos: &R) -> usize
+    where
+        R: LWEInfos,
+    {
+        <Module<BE> as LWETruncateDefault<BE>>::lwe_truncate_tmp_bytes_default(module, res_infos)
+    }
+
+    fn lwe_truncate_default<R, A>(module: &Module<BE>, res: &mut R, k: usize, a: &A, scratch: &mut Scratch<BE>)
+    where
+        R: LWEToMut,
+        A: LWEToRef,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        <Module<BE> as LWETruncateDefault<BE>>::lwe_truncate_default(module, res, k, a, scratch)
+    }
+
+    fn lwe_truncate_assign_default<R>(module: &Module<BE>, res: &mut R, k: usize, scratch: &mut Scratch<BE>)
+    where
+        R: LWEToMut,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        <Module<BE> as LWETruncateDefault<BE>>::lwe_truncate_assign_default(module, res, k, scratch)
+    }
+
     fn glwe_trace_galois_elements_default(module: &Module<BE>) -> Vec<i64> {
         <Module<BE> as GLWETraceDefault<BE>>::glwe_trace_galois_elements_default(module)
     }
@@ -739,6 +850,45 @@ where
         <Module<BE> as GLWEPackingDefault<BE>>::glwe_pack_default(module, res, a, log_gap_out, keys, scratch)
     }
 
+    fn glwe_pack_lwes_galois_elements_default(module: &Module<BE>) -> Vec<i64> {
+        <Module<BE> as GLWEPackLWEsDefault<BE>>::glwe_pack_lwes_galois_elements_default(module)
+    }
+
+    fn glwe_pack_lwes_tmp_bytes_default<R, A, K, T>(
+        module: &Module<BE>,
+        res_infos: &R,
+        lwe_infos: &A,
+        ksk_infos: &K,
+        atk_infos: &T,
+    ) -> usize
+    where
+        R: GLWEInfos,
+        A: LWEInfos,
+        K: GGLWEInfos,
+        T: GGLWEInfos,
+    {
+        <Module<BE> as GLWEPackLWEsDefault<BE>>::glwe_pack_lwes_tmp_bytes_default(
+            module, res_infos, lwe_infos, ksk_infos, atk_infos,
+        )
+    }
+
+    fn glwe_pack_lwes_default<R, A, K, T, H>(
+        module: &Module<BE>,
+        res: &mut R,
+        lwes: &[A],
+        ksk: &K,
+        keys: &H,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        A: LWEToRef,
+        K: GGLWEPreparedToRef<BE> + GGLWEInfos,
+        T: GGLWEPreparedToRef<BE> + GetGaloisElement + GGLWEInfos,
+        H: GLWEAutomorphismKeyHelper<T, BE>,
+    {
+        <Module<BE> as GLWEPackLWEsDefault<BE>>::glwe_pack_lwes_default(module, res, lwes, ksk, keys, scratch)
+    }
+
     fn packer_add_default<A, K, H>(
         module: &Module<BE>,
         packer: &mut GLWEPacker,
@@ -1178,6 +1328,72 @@ macro_rules! impl_core_operations_default_methods {
             <$be as $crate::oep::CoreOperationsDefaults<$be>>::glwe_normalize_assign_default(module, res, scratch)
         }
 
+        fn glwe_truncate_tmp_bytes<R>(module: &poulpy_hal::layouts::Module<$be>, res_infos: &R) -> usize
+        where
+            R: $crate::layouts::GLWEInfos,
+        {
+            <$be as $crate::oep::CoreOperationsDefaults<$be>>::glwe_truncate_tmp_bytes_default(module, res_infos)
+        }
+
+        fn glwe_truncate<R, A>(
+            module: &poulpy_hal::layouts::Module<$be>,
+            res: &mut R,
+            k: usize,
+            a: &A,
+            scratch: &mut poulpy_hal::layouts::Scratch<$be>,
+        ) where
+            R: $crate::layouts::GLWEToMut,
+            A: $crate::layouts::GLWEToRef,
+            poulpy_hal::layouts::Scratch<$be>: $crate::ScratchTakeCore<$be>,
+        {
+            <$be as $crate::oep::CoreOperationsDefaults<$be>>::glwe_truncate_default(module, res, k, a, scratch)
+        }
+
+        fn glwe_truncate_assign<R>(
+            module: &poulpy_hal::layouts::Module<$be>,
+            res: &mut R,
+            k: usize,
+            scratch: &mut poulpy_hal::layouts::Scratch<$be>,
+        ) where
+            R: $crate::layouts::GLWEToMut,
+            poulpy_hal::layouts::Scratch<$be>: $crate::ScratchTakeCore<$be>,
+        {
+            <$be as $crate::oep::CoreOperationsDefaults<$be>>::glwe_truncate_assign_default(module, res, k, scratch)
+        }
+
+        fn lwe_truncate_tmp_bytes<R>(module: &poulpy_hal::layouts::Module<$be>, res_infos: &R) -> usize
+        where
+            R: $crate::layouts::LWEInfos,
+        {
+            <$be as $crate::oep::CoreOperationsDefaults<$be>>::lwe_truncate_tmp_bytes_default(module, res_infos)
+        }
+
+        fn lwe_truncate<R, A>(
+            module: &poulpy_hal::layouts::Module<$be>,
+            res: &mut R,
+            k: usize,
+            a: &A,
+            scratch: &mut poulpy_hal::layouts::Scratch<$be>,
+        ) where
+            R: $crate::layouts::LWEToMut,
+            A: $crate::layouts::LWEToRef,
+            poulpy_hal::layouts::Scratch<$be>: $crate::ScratchTakeCore<$be>,
+        {
+            <$be as $crate::oep::CoreOperationsDefaults<$be>>::lwe_truncate_default(module, res, k, a, scratch)
+        }
+
+        fn lwe_truncate_assign<R>(
+            module: &poulpy_hal::layouts::Module<$be>,
+            res: &mut R,
+            k: usize,
+            scratch: &mut poulpy_hal::layouts::Scratch<$be>,
+        ) where
+            R: $crate::layouts::LWEToMut,
+            poulpy_hal::layouts::Scratch<$be>: $crate::ScratchTakeCore<$be>,
+        {
+            <$be as $crate::oep::CoreOperationsDefaults<$be>>::lwe_truncate_assign_default(module, res, k, scratch)
+        }
+
         fn glwe_trace_galois_elements(module: &poulpy_hal::layouts::Module<$be>) -> Vec<i64> {
             <$be as $crate::oep::CoreOperationsDefaults<$be>>::glwe_trace_galois_elements_default(module)
         }
@@ -1254,6 +1470,45 @@ macro_rules! impl_core_operations_default_methods {
             <$be as $crate::oep::CoreOperationsDefaults<$be>>::glwe_pack_default(module, res, a, log_gap_out, keys, scratch)
         }
 
+        fn glwe_pack_lwes_galois_elements(module: &poulpy_hal::layouts::Module<$be>) -> Vec<i64> {
+            <$be as $crate::oep::CoreOperationsDefaults<$be>>::glwe_pack_lwes_galois_elements_default(module)
+        }
+
+        fn glwe_pack_lwes_tmp_bytes<R, A, K, T>(
+            module: &poulpy_hal::layouts::Module<$be>,
+            res_infos: &R,
+            lwe_infos: &A,
+            ksk_infos: &K,
+            atk_infos: &T,
+        ) -> usize
+        where
+            R: $crate::layouts::GLWEInfos,
+            A: $crate::layouts::LWEInfos,
+            K: $crate::layouts::GGLWEInfos,
+            T: $crate::layouts::GGLWEInfos,
+        {
+            <$be as $crate::oep::CoreOperationsDefaults<$be>>::glwe_pack_lwes_tmp_bytes_default(
+                module, res_infos, lwe_infos, ksk_infos, atk_infos,
+            )
+        }
+
+        fn glwe_pack_lwes<R, A, K, T, H>(
+            module: &poulpy_hal::layouts::Module<$be>,
+            res: &mut R,
+            lwes: &[A],
+            ksk: &K,
+            keys: &H,
+            scratch: &mut poulpy_hal::layouts::Scratch<$be>,
+        ) where
+            R: $crate::layouts::GLWEToMut + $crate::layouts::GLWEInfos,
+            A: $crate::layouts::LWEToRef,
+            K: $crate::layouts::GGLWEPreparedToRef<$be> + $crate::layouts::GGLWEInfos,
+            T: $crate::layouts::GGLWEPreparedToRef<$be> + $crate::layouts::GetGaloisElement + $crate::layouts::GGLWEInfos,
+            H: $crate::layouts::GLWEAutomorphismKeyHelper<T, $be>,
+        {
+            <$be as $crate::oep::CoreOperationsDefaults<$be>>::glwe_pack_lwes_default(module, res, lwes, ksk, keys, scratch)
+        }
+
         fn packer_add<A, K, H>(
             module: &poulpy_hal::layouts::Module<$be>,
             packer: &mut $crate::GLWEPacker,
@@ -0,0 +1,391 @@
+//! Ciphertext sanitization (circuit privacy).
+//!
+//! An evaluated ciphertext leaks more than its plaintext: its mask is a
+//! deterministic function of the inputs and its error carries the trace of
+//! the operations that produced it. Sanitization hides both before the
+//! ciphertext is handed to a party other than the key owner:
+//!
+//! 1. the mask is re-randomized by adding a fresh public-key encryption of
+//!    zero, and
+//! 2. the error is flooded with a Gaussian, described by [`NoiseFlooding`],
+//!    large enough that the output error distribution is statistically
+//!    independent of the input error.
+//!
+//! [`GLWESanitize`] sanitizes GLWE ciphertexts. [`LWESanitize`] sanitizes LWE
+//! ciphertexts through a sample-extracted GLWE encryption of zero, so it
+//! applies to LWE ciphertexts under the key of [`LWESecret::from_glwe_secret`](crate::layouts::LWESecret::from_glwe_secret).
+//! Callers that also need to forget the input noise entirely can bootstrap
+//! first, see `poulpy-bin-fhe`.
+
+use std::f64::consts::LN_2;
+
+use anyhow::Result;
+use poulpy_hal::{
+    api::{ModuleN, ScratchAvailable, ScratchTakeBasic, VecZnxAddNormal, VecZnxNormalizeAssign, VecZnxNormalizeTmpBytes},
+    layouts::{AlignedBuf, Backend, Module, NoiseInfos, Scratch, VecZnx, ZnxView, ZnxViewMut, ZnxZero},
+    source::Source,
+};
+
+pub use crate::api::{GLWESanitize, LWESanitize};
+use crate::{
+    EncryptionInfos, GLWEAdd, GLWEEncryptPk, GLWENormalize, GetDistribution, LWESampleExtract, ScratchTakeCore,
+    layouts::{
+        GLWE, GLWEInfos, GLWELayout, GLWEPreparedToRef, GLWEToMut, GLWEToRef, LWE, LWEInfos, LWELayout, LWEToMut, LWEToRef, Rank,
+    },
+};
+
+/// Flooding noise of [`GLWESanitize`] and [`LWESanitize`].
+///
+/// Hides an input error of absolute value at most `2^log2_bound_in` (on the
+/// torus) up to a statistical distance of `2^-stat_dist` per coefficient:
+/// the flooding standard deviation is `2^(log2_bound_in + stat_dist - 1)`,
+/// since two Gaussians of standard deviation `sigma` whose centers are `c`
+/// apart are at statistical distance at most `|c| / (2 * sigma)`. For a GLWE
+/// of degree `N`, the distance over the whole body is at most `N * 2^-stat_dist`.
+///
+/// The noise is sampled at a limb boundary of the ciphertext, truncated at a
+/// tail bound whose probability mass is below `2^-stat_dist`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NoiseFlooding {
+    k: usize,
+    sigma: f64,
+    bound: f64,
+    stat_dist: usize,
+}
+
+impl NoiseFlooding {
+    /// Flooding noise for ciphertexts of layout `infos` whose error is at most
+    /// `2^log2_bound_in` in absolute value.
+    ///
+    /// Fails if the flooding noise is not representable at the precision of
+    /// `infos` or would not leave any room for a message.
+    pub fn new<A>(infos: &A, log2_bound_in: f64, stat_dist: usize) -> Result<Self>
+    where
+        A: LWEInfos,
+    {
+        let base2k: usize = infos.base2k().as_usize();
+        let log2_std: f64 = log2_bound_in + stat_dist as f64 - 1.0;
+        anyhow::ensure!(
+            log2_std < -1.0,
+            "flooding standard deviation 2^{log2_std} leaves no room for a message"
+        );
+
+        // Samples on the first limb boundary at least 4 bits below the standard
+        // deviation, so that the sampler does not need to rescale.
+        let k: usize = ((4.0 - log2_std).ceil() as usize)
+            .div_ceil(base2k)
+            .saturating_mul(base2k)
+            .min(infos.max_k().as_usize());
+        let sigma: f64 = (log2_std + k as f64).exp2();
+        let tail: f64 = (2.0 * (stat_dist as f64 + 1.0) * LN_2).sqrt().max(6.0);
+        let noise: NoiseInfos = NoiseInfos::new(k, sigma, tail * sigma)?;
+
+        Ok(Self {
+            k: noise.k,
+            sigma: noise.sigma,
+            bound: noise.bound,
+            stat_dist,
+        })
+    }
+
+    /// Flooding noise for ciphertexts of layout `infos` whose error has
+    /// standard deviation `2^log2_std_in`, bounded with the same tail
+    /// probability as the flooding noise.
+    ///
+    /// `log2_std_in` is typically [`NoiseTracker::log2_std`](crate::NoiseTracker::log2_std).
+    pub fn from_log2_std<A>(infos: &A, log2_std_in: f64, stat_dist: usize) -> Result<Self>
+    where
+        A: LWEInfos,
+    {
+        let tail: f64 = (2.0 * (stat_dist as f64 + 1.0) * LN_2).sqrt();
+        Self::new(infos, log2_std_in + tail.log2(), stat_dist)
+    }
+
+    /// Target statistical distance, as `-log2`.
+    pub fn stat_dist(&self) -> usize {
+        self.stat_dist
+    }
+
+    /// Variance of the flooding noise on the torus.
+    pub fn variance(&self) -> f64 {
+        self.sigma * self.sigma * (-2.0 * self.k as f64).exp2()
+    }
+
+    /// `log2` of the standard deviation of the flooding noise on the torus.
+    pub fn log2_std(&self) -> f64 {
+        self.sigma.log2() - self.k as f64
+    }
+}
+
+impl EncryptionInfos for NoiseFlooding {
+    fn noise_infos(&self) -> NoiseInfos {
+        NoiseInfos {
+            k: self.k,
+            sigma: self.sigma,
+            bound: self.bound,
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait GLWESanitizeDefault<BE: Backend>
+where
+    Self: ModuleN + GLWEEncryptPk<BE> + GLWEAdd + GLWENormalize<BE> + VecZnxAddNormal,
+{
+    fn glwe_sanitize_tmp_bytes_default<R>(&self, res_infos: &R) -> usize
+    where
+        R: GLWEInfos,
+    {
+        assert_eq!(self.n() as u32, res_infos.n());
+
+        let lvl_0: usize = GLWE::<AlignedBuf>::bytes_of_from_infos(res_infos);
+        let lvl_1: usize = self.glwe_encrypt_pk_tmp_bytes(res_infos).max(self.glwe_normalize_tmp_bytes());
+
+        lvl_0 + lvl_1
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn glwe_sanitize_default<R, A, K, E, F>(
+        &self,
+        res: &mut R,
+        a: &A,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        A: GLWEToRef + GLWEInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        assert_eq!(res.rank(), a.rank());
+        assert_eq!(res.base2k(), a.base2k());
+        assert!(
+            scratch.available() >= self.glwe_sanitize_tmp_bytes_default(res),
+            "scratch.available(): {} < GLWESanitize::glwe_sanitize_tmp_bytes: {}",
+            scratch.available(),
+            self.glwe_sanitize_tmp_bytes_default(res)
+        );
+
+        let (mut zero, scratch_1) = scratch.take_glwe(res);
+        self.glwe_sanitize_zero(&mut zero, res, pk, enc_infos, flood, source_xu, source_xe, scratch_1);
+        self.glwe_add_into(res, a, &zero);
+        self.glwe_normalize_assign(res, scratch_1);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn glwe_sanitize_assign_default<R, K, E, F>(
+        &self,
+        res: &mut R,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEToMut + GLWEInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        assert!(
+            scratch.available() >= self.glwe_sanitize_tmp_bytes_default(res),
+            "scratch.available(): {} < GLWESanitize::glwe_sanitize_tmp_bytes: {}",
+            scratch.available(),
+            self.glwe_sanitize_tmp_bytes_default(res)
+        );
+
+        let (mut zero, scratch_1) = scratch.take_glwe(res);
+        self.glwe_sanitize_zero(&mut zero, res, pk, enc_infos, flood, source_xu, source_xe, scratch_1);
+        self.glwe_add_assign(res, &zero);
+        self.glwe_normalize_assign(res, scratch_1);
+    }
+
+    /// Writes a public-key encryption of zero plus the flooding noise on its body
+    /// into `zero`, which has the layout of `res`.
+    #[allow(clippy::too_many_arguments)]
+    fn glwe_sanitize_zero<R, K, E, F>(
+        &self,
+        zero: &mut GLWE<&mut [u8]>,
+        res: &R,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: GLWEInfos,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        assert_eq!(res.n(), self.n() as u32);
+        assert_eq!(res.rank(), pk.rank());
+        assert_eq!(res.base2k(), pk.base2k());
+
+        let flood: NoiseInfos = flood.noise_infos();
+        assert!(
+            flood.k <= res.max_k().as_usize(),
+            "flooding noise precision {} > res.max_k(): {}",
+            flood.k,
+            res.max_k()
+        );
+
+        self.glwe_encrypt_zero_pk(zero, pk, enc_infos, source_xu, source_xe, scratch);
+        self.vec_znx_add_normal(res.base2k().into(), zero.data_mut(), 0, flood, source_xe);
+    }
+}
+
+impl<BE: Backend> GLWESanitizeDefault<BE> for Module<BE> where
+    Self: ModuleN + GLWEEncryptPk<BE> + GLWEAdd + GLWENormalize<BE> + VecZnxAddNormal
+{
+}
+
+#[doc(hidden)]
+pub trait LWESanitizeDefault<BE: Backend>
+where
+    Self: ModuleN + GLWEEncryptPk<BE> + LWESampleExtract + VecZnxAddNormal + VecZnxNormalizeAssign<BE> + VecZnxNormalizeTmpBytes,
+{
+    fn lwe_sanitize_tmp_bytes_default<R>(&self, res_infos: &R) -> usize
+    where
+        R: LWEInfos,
+    {
+        let glwe_infos: GLWELayout = lwe_sanitize_glwe_layout(self.n(), res_infos);
+
+        let lvl_0: usize = GLWE::<AlignedBuf>::bytes_of_from_infos(&glwe_infos);
+        let lvl_1_encrypt: usize = self.glwe_encrypt_pk_tmp_bytes(&glwe_infos);
+        let lvl_1_add: usize = LWE::<AlignedBuf>::bytes_of_from_infos(res_infos)
+            + VecZnx::<AlignedBuf>::bytes_of(1, 1, res_infos.size())
+            + self.vec_znx_normalize_tmp_bytes();
+
+        lvl_0 + lvl_1_encrypt.max(lvl_1_add)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn lwe_sanitize_default<R, A, K, E, F>(
+        &self,
+        res: &mut R,
+        a: &A,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: LWEToMut,
+        A: LWEToRef,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        let res: &mut LWE<&mut [u8]> = &mut res.to_mut();
+        let a: &LWE<&[u8]> = &a.to_ref();
+
+        assert_eq!(res.n(), a.n());
+        assert_eq!(res.base2k(), a.base2k());
+
+        let n: usize = res.n().as_usize() + 1;
+        let min_size: usize = res.size().min(a.size());
+        for i in 0..min_size {
+            res.data.at_mut(0, i)[..n].copy_from_slice(&a.data.at(0, i)[..n]);
+        }
+        for i in min_size..res.size() {
+            res.data.at_mut(0, i)[..n].fill(0);
+        }
+
+        self.lwe_sanitize_assign_default(res, pk, enc_infos, flood, source_xu, source_xe, scratch);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn lwe_sanitize_assign_default<R, K, E, F>(
+        &self,
+        res: &mut R,
+        pk: &K,
+        enc_infos: &E,
+        flood: &F,
+        source_xu: &mut Source,
+        source_xe: &mut Source,
+        scratch: &mut Scratch<BE>,
+    ) where
+        R: LWEToMut,
+        K: GLWEPreparedToRef<BE> + GetDistribution + GLWEInfos,
+        E: EncryptionInfos,
+        F: EncryptionInfos,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        let res: &mut LWE<&mut [u8]> = &mut res.to_mut();
+
+        assert_eq!(pk.n(), self.n() as u32);
+        assert_eq!(pk.rank(), Rank(1), "LWE sanitization requires a rank-1 public key");
+        assert_eq!(res.base2k(), pk.base2k());
+        assert_eq!(res.n(), pk.n(), "LWE sanitization requires res.n() == pk.n()");
+        assert!(
+            scratch.available() >= self.lwe_sanitize_tmp_bytes_default(res),
+            "scratch.available(): {} < LWESanitize::lwe_sanitize_tmp_bytes: {}",
+            scratch.available(),
+            self.lwe_sanitize_tmp_bytes_default(res)
+        );
+
+        let flood: NoiseInfos = flood.noise_infos();
+        assert!(
+            flood.k <= res.max_k().as_usize(),
+            "flooding noise precision {} > res.max_k(): {}",
+            flood.k,
+            res.max_k()
+        );
+
+        let base2k: usize = res.base2k().into();
+        let size: usize = res.size();
+
+        let glwe_infos: GLWELayout = lwe_sanitize_glwe_layout(self.n(), res);
+        let (mut zero_glwe, scratch_1) = scratch.take_glwe(&glwe_infos);
+        self.glwe_encrypt_zero_pk(&mut zero_glwe, pk, enc_infos, source_xu, source_xe, scratch_1);
+
+        let lwe_infos: LWELayout = res.lwe_layout();
+        let (mut zero, scratch_2) = scratch_1.take_lwe(&lwe_infos);
+        self.lwe_sample_extract(&mut zero, &zero_glwe);
+
+        let (mut noise, scratch_3) = scratch_2.take_vec_znx(1, 1, size);
+        noise.zero();
+        self.vec_znx_add_normal(base2k, &mut noise, 0, flood, source_xe);
+
+        let n: usize = res.n().as_usize() + 1;
+        for i in 0..size {
+            let zero_i: &[i64] = zero.data.at(0, i);
+            let res_i: &mut [i64] = res.data.at_mut(0, i);
+            res_i[..n].iter_mut().zip(&zero_i[..n]).for_each(|(r, z)| *r += z);
+            res_i[0] += noise.at(0, i)[0];
+        }
+
+        self.vec_znx_normalize_assign(base2k, &mut res.data, 0, scratch_3);
+    }
+}
+
+impl<BE: Backend> LWESanitizeDefault<BE> for Module<BE> where
+    Self: ModuleN + GLWEEncryptPk<BE> + LWESampleExtract + VecZnxAddNormal + VecZnxNormalizeAssign<BE> + VecZnxNormalizeTmpBytes
+{
+}
+
+/// Rank-1 GLWE from which the encryption of zero of [`LWESanitize`] is extracted.
+fn lwe_sanitize_glwe_layout<R>(n: usize, res_infos: &R) -> GLWELayout
+where
+    R: LWEInfos,
+{
+    GLWELayout {
+        n: n.into(),
+        base2k: res_infos.base2k(),
+        k: res_infos.max_k(),
+        rank: Rank(1),
+    }
+}
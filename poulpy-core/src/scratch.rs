@@ -42,7 +42,7 @@ where
     where
         A: LWEInfos,
     {
-        let (data, scratch) = self.take_vec_znx(infos.n().into(), 1, infos.size());
+        let (data, scratch) = self.take_vec_znx((infos.n() + 1).into(), 1, infos.size());
         (
             LWE {
                 base2k: infos.base2k(),
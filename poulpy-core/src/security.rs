@@ -0,0 +1,454 @@
+//! Lattice security estimation for LWE and GLWE parameter sets.
+//!
+//! This module provides a lightweight, pure-Rust estimator for the
+//! concrete security of the (G)LWE instances produced by `poulpy-core`.
+//! It follows the standard methodology of the lattice estimator
+//! (Albrecht–Player–Scott) and covers the three attack families that
+//! dominate for FHE-style parameters:
+//!
+//! * **primal uSVP** -- Kannan embedding solved with BKZ, success
+//!   condition of [ADPS16] under the geometric series assumption (GSA);
+//! * **primal BDD** -- BKZ reduction followed by a final sieving call on
+//!   the projected sublattice that contains the error;
+//! * **dual hybrid** -- dual distinguisher (with sieving amortisation)
+//!   combined with coordinate dropping, which exploits sparse secrets by
+//!   guessing that a block of secret coefficients is zero.
+//!
+//! All quantities are expressed in `log2`. The modulus of a GLWE with torus
+//! precision `k` is `q = 2^k`, and the error standard deviation is the one
+//! given by [`NoiseInfos`](poulpy_hal::layouts::NoiseInfos), i.e. measured in
+//! units of `2^-k`. A GLWE of degree `N` and rank `r` is estimated as an LWE
+//! instance of dimension `N * r`, ignoring any algebraic structure.
+//!
+//! The estimator is meant for parameter selection and sanity checks; it
+//! does not replace a full run of the lattice estimator when publishing
+//! new parameter sets.
+//!
+//! [ADPS16]: https://eprint.iacr.org/2015/1092
+
+use std::f64::consts::{E, PI};
+
+use crate::{
+    CoreError,
+    dist::Distribution,
+    layouts::{GLWEInfos, LWEInfos},
+};
+
+/// Smallest BKZ block size considered; below this the root-Hermite-factor
+/// formula is not accurate and all relevant parameters require larger blocks.
+const BETA_MIN: usize = 40;
+
+/// Number of vectors of norm about `sqrt(4/3) * GH` returned by one sieving
+/// call in dimension `beta`, expressed as `log2` per unit of `beta`.
+const LOG2_SIEVE_VECTORS: f64 = 0.2075;
+
+/// Cost model for lattice reduction (BKZ) and SVP calls.
+///
+/// `beta` denotes the block size and `d` the lattice dimension.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ReductionCostModel {
+    /// Classical core-SVP: `0.292 * beta`.
+    CoreSieve,
+    /// Quantum core-SVP: `0.265 * beta`.
+    QuantumCoreSieve,
+    /// Classical BDGL16 sieving with `8d` SVP calls per BKZ tour:
+    /// `0.292 * beta + 16.4 + log2(8d)`.
+    #[default]
+    BDGL16,
+}
+
+impl ReductionCostModel {
+    /// Returns `log2` of the cost of BKZ-`beta` on a lattice of dimension `d`.
+    pub fn log2_bkz(&self, beta: usize, d: usize) -> f64 {
+        let beta: f64 = beta as f64;
+        match self {
+            ReductionCostModel::CoreSieve => 0.292 * beta,
+            ReductionCostModel::QuantumCoreSieve => 0.265 * beta,
+            ReductionCostModel::BDGL16 => 0.292 * beta + 16.4 + (8.0 * d as f64).log2(),
+        }
+    }
+
+    /// Returns `log2` of the cost of a single SVP call in dimension `eta`.
+    pub fn log2_svp(&self, eta: usize) -> f64 {
+        let eta: f64 = eta as f64;
+        match self {
+            ReductionCostModel::CoreSieve => 0.292 * eta,
+            ReductionCostModel::QuantumCoreSieve => 0.265 * eta,
+            ReductionCostModel::BDGL16 => 0.292 * eta + 16.4,
+        }
+    }
+}
+
+/// Cost of the cheapest instance of one attack family.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AttackCost {
+    /// `log2` of the number of ring operations; `f64::INFINITY` if no
+    /// block size up to the lattice dimension succeeds.
+    pub log2_rop: f64,
+    /// BKZ block size.
+    pub beta: usize,
+    /// Dimension of the final SVP call (primal BDD only, `0` otherwise).
+    pub eta: usize,
+    /// Lattice dimension.
+    pub d: usize,
+    /// Number of secret coefficients guessed to be zero (dual hybrid only, `0` otherwise).
+    pub zeta: usize,
+}
+
+impl AttackCost {
+    const INFEASIBLE: AttackCost = AttackCost {
+        log2_rop: f64::INFINITY,
+        beta: 0,
+        eta: 0,
+        d: 0,
+        zeta: 0,
+    };
+
+    const TRIVIAL: AttackCost = AttackCost {
+        log2_rop: 0.0,
+        beta: 0,
+        eta: 0,
+        d: 0,
+        zeta: 0,
+    };
+}
+
+/// Output of [`SecurityParameters::estimate`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SecurityEstimate {
+    /// Primal uSVP attack.
+    pub usvp: AttackCost,
+    /// Primal BDD attack.
+    pub bdd: AttackCost,
+    /// Dual attack with coordinate dropping.
+    pub dual_hybrid: AttackCost,
+}
+
+impl SecurityEstimate {
+    /// Returns the estimated bit-security, i.e. the `log2` cost of the cheapest attack.
+    pub fn security_level(&self) -> f64 {
+        self.usvp.log2_rop.min(self.bdd.log2_rop).min(self.dual_hybrid.log2_rop)
+    }
+}
+
+/// Plain-data description of an LWE instance for security estimation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SecurityParameters {
+    /// Secret dimension (`N * rank` for a GLWE).
+    pub n: usize,
+    /// `log2` of the modulus.
+    pub log_q: usize,
+    /// Distribution of the secret coefficients.
+    pub dist: Distribution,
+    /// Standard deviation of the error, in units of `q^-1` on the torus.
+    pub sigma: f64,
+}
+
+impl SecurityParameters {
+    /// Creates the estimator input for an LWE instance.
+    pub fn new(n: usize, log_q: usize, dist: Distribution, sigma: f64) -> Self {
+        Self { n, log_q, dist, sigma }
+    }
+
+    /// Creates the estimator input for an LWE layout, with `q = 2^max_k`.
+    pub fn from_lwe_infos<A>(infos: &A, dist: Distribution, sigma: f64) -> Self
+    where
+        A: LWEInfos + ?Sized,
+    {
+        Self::new(infos.n().as_usize(), infos.max_k().as_usize(), dist, sigma)
+    }
+
+    /// Creates the estimator input for a GLWE layout, as an LWE instance of
+    /// dimension `N * rank` with `q = 2^max_k`.
+    pub fn from_glwe_infos<A>(infos: &A, dist: Distribution, sigma: f64) -> Self
+    where
+        A: GLWEInfos + ?Sized,
+    {
+        Self::new(
+            infos.n().as_usize() * infos.rank().as_usize(),
+            infos.max_k().as_usize(),
+            dist,
+            sigma,
+        )
+    }
+
+    /// Estimates the cost of the primal uSVP, primal BDD and dual hybrid
+    /// attacks under the given reduction cost model.
+    ///
+    /// # Panics
+    /// Panics if `dist` is [`Distribution::NONE`].
+    pub fn estimate(&self, model: ReductionCostModel) -> SecurityEstimate {
+        let var_xs: f64 = secret_variance(&self.dist, self.n);
+
+        if var_xs == 0.0 || self.n == 0 {
+            return SecurityEstimate {
+                usvp: AttackCost::TRIVIAL,
+                bdd: AttackCost::TRIVIAL,
+                dual_hybrid: AttackCost::TRIVIAL,
+            };
+        }
+
+        SecurityEstimate {
+            usvp: self.primal_usvp(model, var_xs),
+            bdd: self.primal_bdd(model, var_xs),
+            dual_hybrid: self.dual_hybrid(model, var_xs),
+        }
+    }
+
+    /// Returns the estimated bit-security under the default [`ReductionCostModel`].
+    pub fn security_level(&self) -> f64 {
+        self.estimate(ReductionCostModel::default()).security_level()
+    }
+
+    /// `log2` of the scaling factor `nu = sigma_e / sigma_s` applied to the
+    /// secret coordinates so that the embedded target is balanced.
+    fn log_nu(&self, var_xs: f64) -> f64 {
+        self.sigma.log2() - 0.5 * var_xs.log2()
+    }
+
+    /// Dimension maximising the GSA-predicted norm of the last Gram-Schmidt
+    /// vectors of the primal (Kannan) embedding for BKZ-`beta`.
+    fn primal_dim(&self, log_delta: f64, log_nu: f64) -> usize {
+        let n: f64 = self.n as f64;
+        let num: f64 = (n + 1.0) * self.log_q as f64 - n * log_nu;
+        let d: f64 = (num.max(0.0) / log_delta).sqrt();
+        (d.round() as usize).max(self.n + 1)
+    }
+
+    /// `log2` of the volume of the primal embedding of dimension `d`.
+    fn primal_log_vol(&self, d: usize, log_nu: f64) -> f64 {
+        let m: usize = d - self.n - 1;
+        m as f64 * self.log_q as f64 + self.n as f64 * log_nu
+    }
+
+    fn primal_usvp(&self, model: ReductionCostModel, var_xs: f64) -> AttackCost {
+        let log_nu: f64 = self.log_nu(var_xs);
+        let log_sigma: f64 = self.sigma.log2();
+
+        let success = |beta: usize| -> Option<usize> {
+            let log_delta: f64 = log2_delta(beta);
+            let d: usize = self.primal_dim(log_delta, log_nu);
+            if beta > d {
+                return None;
+            }
+            let lhs: f64 = log_sigma + 0.5 * (beta as f64).log2();
+            let rhs: f64 = (2.0 * beta as f64 - d as f64) * log_delta + self.primal_log_vol(d, log_nu) / d as f64;
+            (lhs <= rhs).then_some(d)
+        };
+
+        let beta_max: usize = 2 * self.n + 1;
+        let Some(beta) = min_beta(BETA_MIN, beta_max, |beta| success(beta).is_some()) else {
+            return AttackCost::INFEASIBLE;
+        };
+        let d: usize = success(beta).unwrap();
+
+        AttackCost {
+            log2_rop: model.log2_bkz(beta, d),
+            beta,
+            eta: 0,
+            d,
+            zeta: 0,
+        }
+    }
+
+    fn primal_bdd(&self, model: ReductionCostModel, var_xs: f64) -> AttackCost {
+        let log_nu: f64 = self.log_nu(var_xs);
+        let log_sigma: f64 = self.sigma.log2();
+        let log_2pie: f64 = (2.0 * PI * E).log2();
+
+        let mut best: AttackCost = AttackCost::INFEASIBLE;
+
+        for beta in BETA_MIN..=2 * self.n + 1 {
+            let log_delta: f64 = log2_delta(beta);
+            let d: usize = self.primal_dim(log_delta, log_nu);
+
+            // BKZ cost is increasing in beta, so no larger block size can win.
+            if beta > d || model.log2_bkz(beta, d) >= best.log2_rop {
+                break;
+            }
+
+            // Under the GSA, the average log-norm of the last eta Gram-Schmidt
+            // vectors is (eta - d) * log_delta + log_vol / d, and the projected
+            // error of norm sigma * sqrt(eta) is the shortest vector of the
+            // projected sublattice as soon as it lies below its Gaussian
+            // heuristic sqrt(eta / 2pie) * vol^(1/eta).
+            let log_vol_d: f64 = self.primal_log_vol(d, log_nu) / d as f64;
+            let eta: f64 = d as f64 - (log_vol_d - 0.5 * log_2pie - log_sigma) / log_delta;
+            let eta: usize = (eta.ceil().max(0.0) as usize).max(beta);
+
+            if eta > d {
+                continue;
+            }
+
+            let log2_rop: f64 = log2_add(model.log2_bkz(beta, d), model.log2_svp(eta));
+
+            if log2_rop < best.log2_rop {
+                best = AttackCost {
+                    log2_rop,
+                    beta,
+                    eta,
+                    d,
+                    zeta: 0,
+                };
+            }
+        }
+
+        best
+    }
+
+    fn dual_hybrid(&self, model: ReductionCostModel, var_xs: f64) -> AttackCost {
+        let log_q: f64 = self.log_q as f64;
+        let log_nu: f64 = self.log_nu(var_xs);
+        let zeta_step: usize = (self.n / 128).max(1);
+
+        let mut best: AttackCost = AttackCost::INFEASIBLE;
+
+        for zeta in (0..self.n).step_by(zeta_step) {
+            let log2_p_zero: f64 = log2_prob_zero(&self.dist, self.n, zeta);
+            if !log2_p_zero.is_finite() {
+                break;
+            }
+
+            let n_dual: usize = self.n - zeta;
+
+            for beta in BETA_MIN..=2 * n_dual + 1 {
+                let log_delta: f64 = log2_delta(beta);
+
+                // Dual lattice {(v, w / nu) : w = v * A mod q} has dimension m + n
+                // and volume (q / nu)^n; the norm of its shortest BKZ vector is
+                // minimised at d = sqrt(n * log(q / nu) / log_delta).
+                let log_vol: f64 = n_dual as f64 * (log_q - log_nu);
+                let d: usize = ((log_vol.max(0.0) / log_delta).sqrt().round() as usize).max(n_dual);
+
+                if beta > d || model.log2_bkz(beta, d) - log2_p_zero >= best.log2_rop {
+                    break;
+                }
+
+                // Sieving returns 2^(0.2075 beta) vectors of norm sqrt(4/3) larger
+                // than the shortest one, which amortises the repetitions.
+                let log_len: f64 = (d as f64 - 1.0) * log_delta + log_vol / d as f64 + 0.5 * (4.0f64 / 3.0).log2();
+                let log_std: f64 = log_len + self.sigma.log2() - log_q;
+
+                // Distinguishing advantage eps = exp(-2 pi^2 std^2), which requires
+                // about 1/eps^2 samples.
+                let log2_inv_eps2: f64 = 4.0 * PI * PI * (2.0 * log_std).exp2() * std::f64::consts::LOG2_E;
+                let log2_reps: f64 = (log2_inv_eps2 - LOG2_SIEVE_VECTORS * beta as f64).max(0.0);
+
+                let log2_rop: f64 = model.log2_bkz(beta, d) + log2_reps - log2_p_zero;
+
+                if log2_rop < best.log2_rop {
+                    best = AttackCost {
+                        log2_rop,
+                        beta,
+                        eta: 0,
+                        d,
+                        zeta,
+                    };
+                }
+            }
+        }
+
+        best
+    }
+}
+
+/// Extension trait estimating the security of any [`LWEInfos`] as a plain LWE
+/// instance of dimension `n` with modulus `2^max_k`.
+pub trait LWESecurity: LWEInfos {
+    /// Returns the [`SecurityEstimate`] under the default [`ReductionCostModel`].
+    fn lwe_security(&self, dist: Distribution, sigma: f64) -> SecurityEstimate {
+        SecurityParameters::from_lwe_infos(self, dist, sigma).estimate(ReductionCostModel::default())
+    }
+}
+
+impl<A: LWEInfos> LWESecurity for A {}
+
+/// Extension trait estimating the security of any [`GLWEInfos`] as an LWE
+/// instance of dimension `n * rank` with modulus `2^max_k`.
+pub trait GLWESecurity: GLWEInfos {
+    /// Returns the [`SecurityEstimate`] under the default [`ReductionCostModel`].
+    fn glwe_security(&self, dist: Distribution, sigma: f64) -> SecurityEstimate {
+        SecurityParameters::from_glwe_infos(self, dist, sigma).estimate(ReductionCostModel::default())
+    }
+}
+
+impl<A: GLWEInfos> GLWESecurity for A {}
+
+/// Panics if the instance is estimated below `min_security` bits.
+pub(crate) fn assert_min_security(params: &SecurityParameters, min_security: f64) {
+    let estimate: SecurityEstimate = params.estimate(ReductionCostModel::default());
+    assert!(
+        estimate.security_level() >= min_security,
+        "estimated security {:.1} bits < required {min_security} bits for {params:?}: {estimate:?}",
+        estimate.security_level()
+    );
+}
+
+/// Returns [`CoreError::InsufficientSecurity`] if the instance is estimated
+/// below `min_security` bits.
+pub(crate) fn ensure_min_security(op: &'static str, params: &SecurityParameters, min_security: f64) -> crate::error::Result<()> {
+    let estimated: f64 = params.estimate(ReductionCostModel::default()).security_level();
+    if estimated < min_security {
+        return Err(CoreError::InsufficientSecurity {
+            op,
+            required: min_security,
+            estimated,
+        });
+    }
+    Ok(())
+}
+
+/// `log2` of the root-Hermite factor achieved by BKZ-`beta`.
+fn log2_delta(beta: usize) -> f64 {
+    let beta: f64 = beta as f64;
+    ((beta / (2.0 * PI * E)) * (PI * beta).powf(1.0 / beta)).log2() / (2.0 * (beta - 1.0))
+}
+
+/// `log2(2^a + 2^b)`.
+fn log2_add(a: f64, b: f64) -> f64 {
+    let (hi, lo): (f64, f64) = if a > b { (a, b) } else { (b, a) };
+    hi + (1.0 + (lo - hi).exp2()).log2()
+}
+
+/// Smallest `beta` in `[lo, hi]` for which the monotone predicate `ok` holds.
+fn min_beta(lo: usize, hi: usize, ok: impl Fn(usize) -> bool) -> Option<usize> {
+    if hi < lo || !ok(hi) {
+        return None;
+    }
+    let (mut lo, mut hi): (usize, usize) = (lo, hi);
+    while lo < hi {
+        let mid: usize = (lo + hi) / 2;
+        if ok(mid) { hi = mid } else { lo = mid + 1 }
+    }
+    Some(lo)
+}
+
+/// Variance of a (re-centred) secret coefficient.
+///
+/// # Panics
+/// Panics if `dist` is [`Distribution::NONE`].
+fn secret_variance(dist: &Distribution, n: usize) -> f64 {
+    let p: f64 = dist.density(n);
+    match dist {
+        Distribution::TernaryFixed(_) | Distribution::TernaryProb(_) => p,
+        Distribution::BinaryFixed(_) | Distribution::BinaryProb(_) | Distribution::BinaryBlock(_) => p * (1.0 - p),
+        Distribution::ZERO => 0.0,
+        Distribution::NONE => panic!("cannot estimate security of a secret with Distribution::NONE"),
+    }
+}
+
+/// `log2` of the probability that `zeta` given secret coefficients are all zero.
+fn log2_prob_zero(dist: &Distribution, n: usize, zeta: usize) -> f64 {
+    if zeta == 0 {
+        return 0.0;
+    }
+    match dist {
+        Distribution::TernaryFixed(h) | Distribution::BinaryFixed(h) => {
+            if zeta + h > n {
+                return f64::NEG_INFINITY;
+            }
+            (0..zeta).map(|i| ((n - h - i) as f64 / (n - i) as f64).log2()).sum()
+        }
+        _ => zeta as f64 * (1.0 - dist.density(n)).log2(),
+    }
+}
@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxAutomorphismAssign},
-    layouts::{DeviceBuf, Module, ScalarZnx, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, DeviceBuf, Module, ScalarZnx, Scratch, ScratchOwned},
     source::Source,
     test_suite::TestParams,
 };
@@ -91,11 +91,11 @@ where
             })
             .unwrap();
 
-            let mut ct_in: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_in_layout);
-            let mut ct_out: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_out_layout);
-            let mut tsk: GGLWEToGGSWKey<Vec<u8>> = GGLWEToGGSWKey::alloc_from_infos(&tsk_layout);
-            let mut auto_key: GLWEAutomorphismKey<Vec<u8>> = GLWEAutomorphismKey::alloc_from_infos(&auto_key_layout);
-            let mut pt_scalar: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, 1);
+            let mut ct_in: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_in_layout);
+            let mut ct_out: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_out_layout);
+            let mut tsk: GGLWEToGGSWKey<AlignedBuf> = GGLWEToGGSWKey::alloc_from_infos(&tsk_layout);
+            let mut auto_key: GLWEAutomorphismKey<AlignedBuf> = GLWEAutomorphismKey::alloc_from_infos(&auto_key_layout);
+            let mut pt_scalar: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
 
             let mut source_xs: Source = Source::new([0u8; 32]);
             let mut source_xe: Source = Source::new([0u8; 32]);
@@ -110,7 +110,7 @@ where
 
             let var_xs: f64 = 0.5;
 
-            let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&ct_out);
+            let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&ct_out);
             sk.fill_ternary_prob(var_xs, &mut source_xs);
 
             let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc_from_infos(&sk);
@@ -243,10 +243,10 @@ where
             })
             .unwrap();
 
-            let mut ct: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_out_layout);
-            let mut tsk: GGLWEToGGSWKey<Vec<u8>> = GGLWEToGGSWKey::alloc_from_infos(&tsk_layout);
-            let mut auto_key: GLWEAutomorphismKey<Vec<u8>> = GLWEAutomorphismKey::alloc_from_infos(&auto_key_layout);
-            let mut pt_scalar: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, 1);
+            let mut ct: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_out_layout);
+            let mut tsk: GGLWEToGGSWKey<AlignedBuf> = GGLWEToGGSWKey::alloc_from_infos(&tsk_layout);
+            let mut auto_key: GLWEAutomorphismKey<AlignedBuf> = GLWEAutomorphismKey::alloc_from_infos(&auto_key_layout);
+            let mut pt_scalar: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
 
             let mut source_xs: Source = Source::new([0u8; 32]);
             let mut source_xe: Source = Source::new([0u8; 32]);
@@ -261,7 +261,7 @@ where
 
             let var_xs: f64 = 0.5;
 
-            let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&ct);
+            let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&ct);
             sk.fill_ternary_prob(var_xs, &mut source_xs);
 
             let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc_from_infos(&sk);
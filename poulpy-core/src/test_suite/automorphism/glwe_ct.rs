@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxAutomorphismAssign, VecZnxFillUniform},
-    layouts::{DeviceBuf, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, DeviceBuf, Module, Scratch, ScratchOwned},
     source::Source,
     test_suite::TestParams,
 };
@@ -72,11 +72,11 @@ where
             })
             .unwrap();
 
-            let mut autokey: GLWEAutomorphismKey<Vec<u8>> = GLWEAutomorphismKey::alloc_from_infos(&autokey_infos);
-            let mut ct_in: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&ct_in_infos);
-            let mut ct_out: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&ct_out_infos);
-            let mut pt_in: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&ct_in_infos);
-            let mut pt_out: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&ct_out_infos);
+            let mut autokey: GLWEAutomorphismKey<AlignedBuf> = GLWEAutomorphismKey::alloc_from_infos(&autokey_infos);
+            let mut ct_in: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&ct_in_infos);
+            let mut ct_out: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&ct_out_infos);
+            let mut pt_in: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&ct_in_infos);
+            let mut pt_out: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&ct_out_infos);
 
             let mut source_xs: Source = Source::new([0u8; 32]);
             let mut source_xe: Source = Source::new([0u8; 32]);
@@ -91,7 +91,7 @@ where
                     | module.glwe_automorphism_tmp_bytes(&ct_out, &ct_in, &autokey),
             );
 
-            let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&ct_out);
+            let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&ct_out);
             sk.fill_ternary_prob(0.5, &mut source_xs);
 
             let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc_from_infos(&sk);
@@ -200,9 +200,9 @@ where
             })
             .unwrap();
 
-            let mut autokey: GLWEAutomorphismKey<Vec<u8>> = GLWEAutomorphismKey::alloc_from_infos(&autokey_infos);
-            let mut ct: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&ct_out_infos);
-            let mut pt_want: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&ct_out_infos);
+            let mut autokey: GLWEAutomorphismKey<AlignedBuf> = GLWEAutomorphismKey::alloc_from_infos(&autokey_infos);
+            let mut ct: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&ct_out_infos);
+            let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&ct_out_infos);
 
             let mut source_xs: Source = Source::new([0u8; 32]);
             let mut source_xe: Source = Source::new([0u8; 32]);
@@ -217,7 +217,7 @@ where
                     | module.glwe_automorphism_tmp_bytes(&ct, &ct, &autokey),
             );
 
-            let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&ct);
+            let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&ct);
             sk.fill_ternary_prob(0.5, &mut source_xs);
 
             let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc_from_infos(&sk);
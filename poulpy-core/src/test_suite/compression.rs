@@ -0,0 +1,139 @@
+use poulpy_hal::{
+    api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow},
+    layouts::{AlignedBuf, DeviceBuf, Module, ScalarZnx, Scratch, ScratchOwned},
+    source::Source,
+    test_suite::{TestParams, serialization::test_zstd_interface},
+};
+
+use crate::{
+    EncryptionLayout, GGSWCompressedEncryptSk, GLWEAutomorphismKeyCompressedEncryptSk, GLWESwitchingKeyCompressedEncryptSk,
+    GLWETensorKeyCompressedEncryptSk, ScratchTakeCore,
+    layouts::{
+        GGLWELayout, GGSWLayout, GLWEAutomorphismKeyLayout, GLWESecret, GLWESecretPreparedFactory, GLWETensorKeyLayout,
+        compressed::{GGSWCompressed, GLWEAutomorphismKeyCompressed, GLWESwitchingKeyCompressed, GLWETensorKeyCompressed},
+        prepared::GLWESecretPrepared,
+    },
+};
+
+/// Round-trips the compressed-seed keys through [`WriteZstd`](poulpy_hal::layouts::WriteZstd)
+/// / [`ReadZstd`](poulpy_hal::layouts::ReadZstd), and checks that zstd
+/// recovers the headroom of the `base2k`-bit limbs stored as `i64`.
+pub fn test_zstd_compressed_keys<BE: crate::test_suite::TestBackend>(params: &TestParams, module: &Module<BE>)
+where
+    Module<BE>: GLWESwitchingKeyCompressedEncryptSk<BE>
+        + GLWEAutomorphismKeyCompressedEncryptSk<BE>
+        + GLWETensorKeyCompressedEncryptSk<BE>
+        + GGSWCompressedEncryptSk<BE>
+        + GLWESecretPreparedFactory<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    let base2k: usize = params.base2k;
+    let k: usize = 4 * base2k + 1;
+    let n: usize = module.n();
+    let rank: usize = 2;
+    let dnum: usize = k / base2k;
+
+    // Each coefficient carries at most `base2k + 1` significant bits out of
+    // 64; leave a byte of slack per coefficient for the entropy coder.
+    let check_ratio = |name: &str, (raw, compressed): (usize, usize)| {
+        assert!(
+            compressed * 64 <= raw * (base2k + 9),
+            "{name}: raw={raw} compressed={compressed} base2k={base2k}"
+        );
+    };
+
+    let mut source_xs: Source = Source::new([0u8; 32]);
+    let mut source_xe: Source = Source::new([0u8; 32]);
+    let seed_xa: [u8; 32] = [1u8; 32];
+
+    let gglwe_infos = EncryptionLayout::new_from_default_sigma(GGLWELayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k.into(),
+        dnum: dnum.into(),
+        dsize: 1_usize.into(),
+        rank_in: rank.into(),
+        rank_out: rank.into(),
+    })
+    .unwrap();
+    let atk_infos = EncryptionLayout::new_from_default_sigma(GLWEAutomorphismKeyLayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k.into(),
+        dnum: dnum.into(),
+        dsize: 1_usize.into(),
+        rank: rank.into(),
+    })
+    .unwrap();
+    let tsk_infos = EncryptionLayout::new_from_default_sigma(GLWETensorKeyLayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k.into(),
+        dnum: dnum.into(),
+        dsize: 1_usize.into(),
+        rank: rank.into(),
+    })
+    .unwrap();
+    let ggsw_infos = EncryptionLayout::new_from_default_sigma(GGSWLayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k.into(),
+        dnum: dnum.into(),
+        dsize: 1_usize.into(),
+        rank: rank.into(),
+    })
+    .unwrap();
+
+    let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
+        module.glwe_switching_key_compressed_encrypt_sk_tmp_bytes(&gglwe_infos)
+            | module.glwe_automorphism_key_compressed_encrypt_sk_tmp_bytes(&atk_infos)
+            | module.glwe_tensor_key_compressed_encrypt_sk_tmp_bytes(&tsk_infos)
+            | module.ggsw_compressed_encrypt_sk_tmp_bytes(&ggsw_infos),
+    );
+
+    let mut sk_in: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank.into());
+    sk_in.fill_ternary_prob(0.5, &mut source_xs);
+    let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank.into());
+    sk.fill_ternary_prob(0.5, &mut source_xs);
+    let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
+    module.glwe_secret_prepare(&mut sk_prepared, &sk);
+
+    let mut ksk: GLWESwitchingKeyCompressed<AlignedBuf> = GLWESwitchingKeyCompressed::alloc_from_infos(&gglwe_infos);
+    module.glwe_switching_key_compressed_encrypt_sk(
+        &mut ksk,
+        &sk_in,
+        &sk,
+        seed_xa,
+        &gglwe_infos,
+        &mut source_xe,
+        scratch.borrow(),
+    );
+    let mut ksk_have: GLWESwitchingKeyCompressed<AlignedBuf> = GLWESwitchingKeyCompressed::alloc_from_infos(&gglwe_infos);
+    check_ratio("GLWESwitchingKeyCompressed", test_zstd_interface(&ksk, &mut ksk_have));
+
+    let mut atk: GLWEAutomorphismKeyCompressed<AlignedBuf> = GLWEAutomorphismKeyCompressed::alloc_from_infos(&atk_infos);
+    module.glwe_automorphism_key_compressed_encrypt_sk(&mut atk, -5, &sk, seed_xa, &atk_infos, &mut source_xe, scratch.borrow());
+    let mut atk_have: GLWEAutomorphismKeyCompressed<AlignedBuf> = GLWEAutomorphismKeyCompressed::alloc_from_infos(&atk_infos);
+    check_ratio("GLWEAutomorphismKeyCompressed", test_zstd_interface(&atk, &mut atk_have));
+
+    let mut tsk: GLWETensorKeyCompressed<AlignedBuf> = GLWETensorKeyCompressed::alloc_from_infos(&tsk_infos);
+    module.glwe_tensor_key_compressed_encrypt_sk(&mut tsk, &sk, seed_xa, &tsk_infos, &mut source_xe, scratch.borrow());
+    let mut tsk_have: GLWETensorKeyCompressed<AlignedBuf> = GLWETensorKeyCompressed::alloc_from_infos(&tsk_infos);
+    check_ratio("GLWETensorKeyCompressed", test_zstd_interface(&tsk, &mut tsk_have));
+
+    let mut pt: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
+    pt.fill_ternary_hw(0, n, &mut source_xs);
+    let mut ggsw: GGSWCompressed<AlignedBuf> = GGSWCompressed::alloc_from_infos(&ggsw_infos);
+    module.ggsw_compressed_encrypt_sk(
+        &mut ggsw,
+        &pt,
+        &sk_prepared,
+        seed_xa,
+        &ggsw_infos,
+        &mut source_xe,
+        scratch.borrow(),
+    );
+    let mut ggsw_have: GGSWCompressed<AlignedBuf> = GGSWCompressed::alloc_from_infos(&ggsw_infos);
+    check_ratio("GGSWCompressed", test_zstd_interface(&ggsw, &mut ggsw_have));
+}
@@ -1,7 +1,7 @@
 use dashu_float::{FBig, round::mode::HalfEven};
 use poulpy_hal::{
     api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxFillUniform, VecZnxNormalize},
-    layouts::{DeviceBuf, FillUniform, Module, Scratch, ScratchOwned, ZnxView},
+    layouts::{AlignedBuf, DeviceBuf, FillUniform, Module, Scratch, ScratchOwned, ZnxView},
     source::Source,
     test_suite::TestParams,
 };
@@ -56,7 +56,7 @@ where
                 rank: Rank(rank as u32),
             };
 
-            let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc(module.n().into(), rank.into());
+            let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc(module.n().into(), rank.into());
             sk.fill_ternary_prob(0.5, &mut source_xs);
 
             let mut sk_prep: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc_from_infos(&sk);
@@ -68,11 +68,11 @@ where
                     .max(module.glwe_noise_tmp_bytes(&glwe_infos_out)),
             );
 
-            let mut ct_in: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_infos_in);
-            let mut ct_out: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_infos_out);
+            let mut ct_in: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos_in);
+            let mut ct_out: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos_out);
 
-            let pt_in: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_infos_in);
-            let pt_out: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_infos_out);
+            let pt_in: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos_in);
+            let pt_out: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos_out);
 
             module.glwe_encrypt_sk(
                 &mut ct_in,
@@ -156,21 +156,21 @@ where
             | (module).glwe_decrypt_tmp_bytes(&glwe_infos),
     );
 
-    let mut sk_glwe: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&glwe_infos);
+    let mut sk_glwe: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&glwe_infos);
     sk_glwe.fill_ternary_prob(0.5, &mut source_xs);
 
     let mut sk_glwe_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc_from_infos(&sk_glwe);
     module.glwe_secret_prepare(&mut sk_glwe_prepared, &sk_glwe);
 
-    let mut sk_lwe: LWESecret<Vec<u8>> = LWESecret::alloc(n_lwe);
+    let mut sk_lwe: LWESecret<AlignedBuf> = LWESecret::alloc(n_lwe);
     sk_lwe.fill_ternary_prob(0.5, &mut source_xs);
 
     let data: i64 = 17;
 
-    let mut lwe_pt: LWEPlaintext<Vec<u8>> = LWEPlaintext::alloc_from_infos(&lwe_infos);
+    let mut lwe_pt: LWEPlaintext<AlignedBuf> = LWEPlaintext::alloc_from_infos(&lwe_infos);
     lwe_pt.encode_i64(data, k_lwe_pt);
 
-    let mut lwe_ct: LWE<Vec<u8>> = LWE::alloc_from_infos(&lwe_infos);
+    let mut lwe_ct: LWE<AlignedBuf> = LWE::alloc_from_infos(&lwe_infos);
     module.lwe_encrypt_sk(
         &mut lwe_ct,
         &lwe_pt,
@@ -181,7 +181,7 @@ where
         scratch.borrow(),
     );
 
-    let mut ksk: LWEToGLWEKey<Vec<u8>> = LWEToGLWEKey::alloc_from_infos(&lwe_to_glwe_infos);
+    let mut ksk: LWEToGLWEKey<AlignedBuf> = LWEToGLWEKey::alloc_from_infos(&lwe_to_glwe_infos);
 
     module.lwe_to_glwe_key_encrypt_sk(
         &mut ksk,
@@ -193,14 +193,14 @@ where
         scratch.borrow(),
     );
 
-    let mut glwe_ct: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_infos);
+    let mut glwe_ct: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
 
     let mut ksk_prepared: LWEToGLWEKeyPrepared<DeviceBuf<BE>, BE> = module.lwe_to_glwe_key_prepared_alloc_from_infos(&ksk);
     module.lwe_to_glwe_key_prepare(&mut ksk_prepared, &ksk, scratch.borrow());
 
     module.glwe_from_lwe(&mut glwe_ct, &lwe_ct, &ksk_prepared, scratch.borrow());
 
-    let mut glwe_pt: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+    let mut glwe_pt: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
     module.glwe_decrypt(&glwe_ct, &mut glwe_pt, &sk_glwe_prepared, scratch.borrow());
 
     let mut lwe_pt_conv = LWEPlaintext::alloc(glwe_pt.base2k(), lwe_pt.max_k());
@@ -277,23 +277,23 @@ where
             | (module).glwe_decrypt_tmp_bytes(&glwe_infos),
     );
 
-    let mut sk_glwe: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&glwe_infos);
+    let mut sk_glwe: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&glwe_infos);
     sk_glwe.fill_ternary_prob(0.5, &mut source_xs);
 
     let mut sk_glwe_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc_from_infos(&sk_glwe);
     module.glwe_secret_prepare(&mut sk_glwe_prepared, &sk_glwe);
 
-    let mut sk_lwe: LWESecret<Vec<u8>> = LWESecret::alloc(n_lwe);
+    let mut sk_lwe: LWESecret<AlignedBuf> = LWESecret::alloc(n_lwe);
     sk_lwe.fill_ternary_prob(0.5, &mut source_xs);
 
     let a_idx: usize = 1;
 
     let mut data: Vec<i64> = vec![0i64; module.n()];
     data[a_idx] = 17;
-    let mut glwe_pt: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+    let mut glwe_pt: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
     glwe_pt.encode_vec_i64(&data, k_lwe_pt);
 
-    let mut glwe_ct: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_infos);
+    let mut glwe_ct: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
     module.glwe_encrypt_sk(
         &mut glwe_ct,
         &glwe_pt,
@@ -304,7 +304,7 @@ where
         scratch.borrow(),
     );
 
-    let mut ksk: GLWEToLWEKey<Vec<u8>> = GLWEToLWEKey::alloc_from_infos(&glwe_to_lwe_infos);
+    let mut ksk: GLWEToLWEKey<AlignedBuf> = GLWEToLWEKey::alloc_from_infos(&glwe_to_lwe_infos);
 
     module.glwe_to_lwe_key_encrypt_sk(
         &mut ksk,
@@ -316,17 +316,17 @@ where
         scratch.borrow(),
     );
 
-    let mut lwe_ct: LWE<Vec<u8>> = LWE::alloc_from_infos(&lwe_infos);
+    let mut lwe_ct: LWE<AlignedBuf> = LWE::alloc_from_infos(&lwe_infos);
 
     let mut ksk_prepared: GLWEToLWEKeyPrepared<DeviceBuf<BE>, BE> = module.glwe_to_lwe_key_prepared_alloc_from_infos(&ksk);
     module.glwe_to_lwe_key_prepare(&mut ksk_prepared, &ksk, scratch.borrow());
 
     module.lwe_from_glwe(&mut lwe_ct, &glwe_ct, a_idx, &ksk_prepared, scratch.borrow());
 
-    let mut lwe_pt: LWEPlaintext<Vec<u8>> = LWEPlaintext::alloc_from_infos(&lwe_infos);
+    let mut lwe_pt: LWEPlaintext<AlignedBuf> = LWEPlaintext::alloc_from_infos(&lwe_infos);
     module.lwe_decrypt(&lwe_ct, &mut lwe_pt, &sk_lwe, scratch.borrow());
 
-    let mut glwe_pt_conv = GLWEPlaintext::<Vec<u8>>::alloc(glwe_ct.n(), lwe_pt.base2k(), lwe_pt.max_k());
+    let mut glwe_pt_conv = GLWEPlaintext::<AlignedBuf>::alloc(glwe_ct.n(), lwe_pt.base2k(), lwe_pt.max_k());
 
     module.vec_znx_normalize(
         glwe_pt_conv.data_mut(),
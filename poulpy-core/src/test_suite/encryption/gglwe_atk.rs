@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxAutomorphism, VecZnxFillUniform},
-    layouts::{DeviceBuf, GaloisElement, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, DeviceBuf, GaloisElement, Module, Scratch, ScratchOwned},
     source::Source,
     test_suite::TestParams,
 };
@@ -49,7 +49,7 @@ where
             })
             .unwrap();
 
-            let mut atk: GLWEAutomorphismKey<Vec<u8>> = GLWEAutomorphismKey::alloc_from_infos(&atk_infos);
+            let mut atk: GLWEAutomorphismKey<AlignedBuf> = GLWEAutomorphismKey::alloc_from_infos(&atk_infos);
 
             let mut source_xs: Source = Source::new([0u8; 32]);
             let mut source_xe: Source = Source::new([0u8; 32]);
@@ -58,7 +58,7 @@ where
             let mut scratch: ScratchOwned<BE> =
                 ScratchOwned::alloc((module).glwe_automorphism_key_encrypt_sk_tmp_bytes(&atk_infos));
 
-            let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&atk_infos);
+            let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&atk_infos);
             sk.fill_ternary_prob(0.5, &mut source_xs);
 
             let p = -5;
@@ -73,7 +73,7 @@ where
                 scratch.borrow(),
             );
 
-            let mut sk_out: GLWESecret<Vec<u8>> = sk.clone();
+            let mut sk_out: GLWESecret<AlignedBuf> = sk.clone();
             (0..atk.rank().into()).for_each(|i| {
                 module.vec_znx_automorphism(
                     module.galois_element_inv(p),
@@ -139,7 +139,7 @@ pub fn test_gglwe_automorphism_key_compressed_encrypt_sk<BE: crate::test_suite::
             })
             .unwrap();
 
-            let mut atk_compressed: GLWEAutomorphismKeyCompressed<Vec<u8>> =
+            let mut atk_compressed: GLWEAutomorphismKeyCompressed<AlignedBuf> =
                 GLWEAutomorphismKeyCompressed::alloc_from_infos(&atk_infos);
 
             let mut source_xs: Source = Source::new([0u8; 32]);
@@ -148,7 +148,7 @@ pub fn test_gglwe_automorphism_key_compressed_encrypt_sk<BE: crate::test_suite::
             let mut scratch: ScratchOwned<BE> =
                 ScratchOwned::alloc((module).glwe_automorphism_key_compressed_encrypt_sk_tmp_bytes(&atk_infos));
 
-            let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&atk_infos);
+            let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&atk_infos);
             sk.fill_ternary_prob(0.5, &mut source_xs);
 
             let p: i64 = -5;
@@ -165,7 +165,7 @@ pub fn test_gglwe_automorphism_key_compressed_encrypt_sk<BE: crate::test_suite::
                 scratch.borrow(),
             );
 
-            let mut sk_out: GLWESecret<Vec<u8>> = sk.clone();
+            let mut sk_out: GLWESecret<AlignedBuf> = sk.clone();
             (0..atk_compressed.rank().into()).for_each(|i| {
                 module.vec_znx_automorphism(
                     module.galois_element_inv(p),
@@ -178,7 +178,7 @@ pub fn test_gglwe_automorphism_key_compressed_encrypt_sk<BE: crate::test_suite::
             let mut sk_out_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(sk_out.rank());
             module.glwe_secret_prepare(&mut sk_out_prepared, &sk_out);
 
-            let mut atk: GLWEAutomorphismKey<Vec<u8>> = GLWEAutomorphismKey::alloc_from_infos(&atk_infos);
+            let mut atk: GLWEAutomorphismKey<AlignedBuf> = GLWEAutomorphismKey::alloc_from_infos(&atk_infos);
             module.decompress_automorphism_key(&mut atk, &atk_compressed);
 
             let max_noise: f64 = DEFAULT_SIGMA_XE.log2() - (k_ksk as f64) + 0.5;
@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxFillUniform},
-    layouts::{DeviceBuf, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, DeviceBuf, Module, Scratch, ScratchOwned},
     source::Source,
     test_suite::TestParams,
 };
@@ -51,7 +51,7 @@ where
                 })
                 .unwrap();
 
-                let mut ksk: GLWESwitchingKey<Vec<u8>> = GLWESwitchingKey::alloc_from_infos(&gglwe_infos);
+                let mut ksk: GLWESwitchingKey<AlignedBuf> = GLWESwitchingKey::alloc_from_infos(&gglwe_infos);
 
                 let mut source_xs: Source = Source::new([0u8; 32]);
                 let mut source_xe: Source = Source::new([0u8; 32]);
@@ -60,10 +60,10 @@ where
                 let mut scratch: ScratchOwned<BE> =
                     ScratchOwned::alloc((module).glwe_switching_key_encrypt_sk_tmp_bytes(&gglwe_infos));
 
-                let mut sk_in: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank_in.into());
+                let mut sk_in: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank_in.into());
                 sk_in.fill_ternary_prob(0.5, &mut source_xs);
 
-                let mut sk_out: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank_out.into());
+                let mut sk_out: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank_out.into());
                 sk_out.fill_ternary_prob(0.5, &mut source_xs);
                 let mut sk_out_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> =
                     module.glwe_secret_prepared_alloc(rank_out.into());
@@ -137,7 +137,7 @@ pub fn test_gglwe_switching_key_compressed_encrypt_sk<BE: crate::test_suite::Tes
                 })
                 .unwrap();
 
-                let mut ksk_compressed: GLWESwitchingKeyCompressed<Vec<u8>> =
+                let mut ksk_compressed: GLWESwitchingKeyCompressed<AlignedBuf> =
                     GLWESwitchingKeyCompressed::alloc_from_infos(&gglwe_infos);
 
                 let mut source_xs: Source = Source::new([0u8; 32]);
@@ -146,10 +146,10 @@ pub fn test_gglwe_switching_key_compressed_encrypt_sk<BE: crate::test_suite::Tes
                 let mut scratch: ScratchOwned<BE> =
                     ScratchOwned::alloc((module).glwe_switching_key_compressed_encrypt_sk_tmp_bytes(&gglwe_infos));
 
-                let mut sk_in: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank_in.into());
+                let mut sk_in: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank_in.into());
                 sk_in.fill_ternary_prob(0.5, &mut source_xs);
 
-                let mut sk_out: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank_out.into());
+                let mut sk_out: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank_out.into());
                 sk_out.fill_ternary_prob(0.5, &mut source_xs);
                 let mut sk_out_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> =
                     module.glwe_secret_prepared_alloc(rank_out.into());
@@ -167,7 +167,7 @@ pub fn test_gglwe_switching_key_compressed_encrypt_sk<BE: crate::test_suite::Tes
                     scratch.borrow(),
                 );
 
-                let mut ksk: GLWESwitchingKey<Vec<u8>> = GLWESwitchingKey::alloc_from_infos(&gglwe_infos);
+                let mut ksk: GLWESwitchingKey<AlignedBuf> = GLWESwitchingKey::alloc_from_infos(&gglwe_infos);
                 module.decompress_glwe_switching_key(&mut ksk, &ksk_compressed);
 
                 let max_noise: f64 = DEFAULT_SIGMA_XE.log2() - (k_ksk as f64) + 0.5;
@@ -226,7 +226,7 @@ where
                 })
                 .unwrap();
 
-                let mut ksk_compressed: GGLWECompressed<Vec<u8>> = GGLWECompressed::alloc_from_infos(&gglwe_infos);
+                let mut ksk_compressed: GGLWECompressed<AlignedBuf> = GGLWECompressed::alloc_from_infos(&gglwe_infos);
 
                 let mut source_xs: Source = Source::new([0u8; 32]);
                 let mut source_xe: Source = Source::new([0u8; 32]);
@@ -234,10 +234,10 @@ where
                 let mut scratch: ScratchOwned<BE> =
                     ScratchOwned::alloc((module).gglwe_compressed_encrypt_sk_tmp_bytes(&gglwe_infos));
 
-                let mut sk_in: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank_in.into());
+                let mut sk_in: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank_in.into());
                 sk_in.fill_ternary_prob(0.5, &mut source_xs);
 
-                let mut sk_out: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank_out.into());
+                let mut sk_out: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank_out.into());
                 sk_out.fill_ternary_prob(0.5, &mut source_xs);
                 let mut sk_out_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> =
                     module.glwe_secret_prepared_alloc(rank_out.into());
@@ -255,7 +255,7 @@ where
                     scratch.borrow(),
                 );
 
-                let mut ksk: GGLWE<Vec<u8>> = GGLWE::alloc_from_infos(&gglwe_infos);
+                let mut ksk: GGLWE<AlignedBuf> = GGLWE::alloc_from_infos(&gglwe_infos);
                 module.decompress_gglwe(&mut ksk, &ksk_compressed);
 
                 let max_noise: f64 = DEFAULT_SIGMA_XE.log2() - (k_ksk as f64) + 0.5;
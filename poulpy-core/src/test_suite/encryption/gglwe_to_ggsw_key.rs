@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxCopy},
-    layouts::{DeviceBuf, Module, ScalarZnx, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, DeviceBuf, Module, ScalarZnx, Scratch, ScratchOwned},
     source::Source,
     test_suite::TestParams,
 };
@@ -44,7 +44,7 @@ where
         })
         .unwrap();
 
-        let mut key: GGLWEToGGSWKey<Vec<u8>> = GGLWEToGGSWKey::alloc_from_infos(&key_infos);
+        let mut key: GGLWEToGGSWKey<AlignedBuf> = GGLWEToGGSWKey::alloc_from_infos(&key_infos);
 
         let mut source_xs: Source = Source::new([0u8; 32]);
         let mut source_xe: Source = Source::new([0u8; 32]);
@@ -54,7 +54,7 @@ where
             module, &key_infos,
         ));
 
-        let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&key_infos);
+        let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&key_infos);
         sk.fill_ternary_prob(0.5, &mut source_xs);
         let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
         module.glwe_secret_prepare(&mut sk_prepared, &sk);
@@ -69,19 +69,19 @@ where
             scratch.borrow(),
         );
 
-        let mut sk_tensor: GLWESecretTensor<Vec<u8>> = GLWESecretTensor::alloc_from_infos(&sk);
+        let mut sk_tensor: GLWESecretTensor<AlignedBuf> = GLWESecretTensor::alloc_from_infos(&sk);
         module.glwe_secret_tensor_prepare(&mut sk_tensor, &sk, scratch.borrow());
 
         let max_noise = DEFAULT_SIGMA_XE.log2() + 0.5 - (k as f64);
 
-        let mut pt_want: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(module.n(), rank);
+        let mut pt_want: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(module.n(), rank);
 
         for i in 0..rank {
             for j in 0..rank {
                 module.vec_znx_copy(&mut pt_want.as_vec_znx_mut(), j, &sk_tensor.at(i, j).as_vec_znx(), 0);
             }
 
-            let ksk: &GGLWE<Vec<u8>> = key.at(i);
+            let ksk: &GGLWE<AlignedBuf> = key.at(i);
             for row in 0..ksk.dnum().as_usize() {
                 for col in 0..ksk.rank_in().as_usize() {
                     let noise_have = ksk
@@ -124,7 +124,7 @@ where
         })
         .unwrap();
 
-        let mut key_compressed: GGLWEToGGSWKeyCompressed<Vec<u8>> = GGLWEToGGSWKeyCompressed::alloc_from_infos(&key_infos);
+        let mut key_compressed: GGLWEToGGSWKeyCompressed<AlignedBuf> = GGLWEToGGSWKeyCompressed::alloc_from_infos(&key_infos);
 
         let mut source_xs: Source = Source::new([0u8; 32]);
         let mut source_xe: Source = Source::new([0u8; 32]);
@@ -133,7 +133,7 @@ where
             GGLWEToGGSWKeyCompressedEncryptSk::gglwe_to_ggsw_key_encrypt_sk_tmp_bytes(module, &key_infos),
         );
 
-        let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&key_infos);
+        let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&key_infos);
         sk.fill_ternary_prob(0.5, &mut source_xs);
         let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
         module.glwe_secret_prepare(&mut sk_prepared, &sk);
@@ -150,22 +150,22 @@ where
             scratch.borrow(),
         );
 
-        let mut key: GGLWEToGGSWKey<Vec<u8>> = GGLWEToGGSWKey::alloc_from_infos(&key_infos);
+        let mut key: GGLWEToGGSWKey<AlignedBuf> = GGLWEToGGSWKey::alloc_from_infos(&key_infos);
         module.decompress_gglwe_to_ggsw_key(&mut key, &key_compressed);
 
-        let mut sk_tensor: GLWESecretTensor<Vec<u8>> = GLWESecretTensor::alloc_from_infos(&sk);
+        let mut sk_tensor: GLWESecretTensor<AlignedBuf> = GLWESecretTensor::alloc_from_infos(&sk);
         module.glwe_secret_tensor_prepare(&mut sk_tensor, &sk, scratch.borrow());
 
         let max_noise = DEFAULT_SIGMA_XE.log2() + 0.5 - (k as f64);
 
-        let mut pt_want: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(module.n(), rank);
+        let mut pt_want: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(module.n(), rank);
 
         for i in 0..rank {
             for j in 0..rank {
                 module.vec_znx_copy(&mut pt_want.as_vec_znx_mut(), j, &sk_tensor.at(i, j).as_vec_znx(), 0);
             }
 
-            let ksk: &GGLWE<Vec<u8>> = key.at(i);
+            let ksk: &GGLWE<AlignedBuf> = key.at(i);
             for row in 0..ksk.dnum().as_usize() {
                 for col in 0..ksk.rank_in().as_usize() {
                     let noise_have = ksk
@@ -1,16 +1,18 @@
 use poulpy_hal::{
     api::{ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{DeviceBuf, Module, ScalarZnx, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, DeviceBuf, Module, ScalarZnx, Scratch, ScratchOwned},
     source::Source,
     test_suite::TestParams,
 };
 
 use crate::{
-    EncryptionLayout, GGSWCompressedEncryptSk, GGSWEncryptSk, GGSWNoise, ScratchTakeCore,
+    EncryptionLayout, GGSWCompressedEncryptSk, GGSWEncryptPk, GGSWEncryptSk, GGSWNoise, GLWEPublicKeyGenerate, ScratchTakeCore,
     encryption::DEFAULT_SIGMA_XE,
     layouts::{
-        GGSW, GGSWDecompress, GGSWInfos, GGSWLayout, GLWEInfos, GLWESecret, GLWESecretPreparedFactory,
-        compressed::GGSWCompressed, prepared::GLWESecretPrepared,
+        GGSW, GGSWDecompress, GGSWInfos, GGSWLayout, GLWEInfos, GLWELayout, GLWEPublicKey, GLWEPublicKeyPreparedFactory,
+        GLWESecret, GLWESecretPreparedFactory,
+        compressed::GGSWCompressed,
+        prepared::{GLWEPublicKeyPrepared, GLWESecretPrepared},
     },
 };
 
@@ -38,9 +40,9 @@ where
             })
             .unwrap();
 
-            let mut ct: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_infos);
+            let mut ct: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_infos);
 
-            let mut pt_scalar: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, 1);
+            let mut pt_scalar: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
 
             let mut source_xs: Source = Source::new([0u8; 32]);
             let mut source_xe: Source = Source::new([0u8; 32]);
@@ -50,7 +52,7 @@ where
 
             let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc((module).ggsw_encrypt_sk_tmp_bytes(&ggsw_infos));
 
-            let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&ggsw_infos);
+            let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&ggsw_infos);
             sk.fill_ternary_prob(0.5, &mut source_xs);
 
             let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
@@ -82,6 +84,102 @@ where
     }
 }
 
+pub fn test_ggsw_encrypt_pk<BE: crate::test_suite::TestBackend>(params: &TestParams, module: &Module<BE>)
+where
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchTakeCore<BE>,
+    Module<BE>: GGSWEncryptPk<BE>
+        + GLWEPublicKeyGenerate<BE>
+        + GLWEPublicKeyPreparedFactory<BE>
+        + GLWESecretPreparedFactory<BE>
+        + GGSWNoise<BE>,
+{
+    let base2k: usize = params.base2k;
+    let k: usize = 4 * base2k + 1;
+    let dsize: usize = k / base2k;
+    for rank in 1_usize..3 {
+        for di in 1..dsize + 1 {
+            let n: usize = module.n();
+            let dnum: usize = (k - di * base2k) / (di * base2k);
+
+            let ggsw_infos = EncryptionLayout::new_from_default_sigma(GGSWLayout {
+                n: n.into(),
+                base2k: base2k.into(),
+                k: k.into(),
+                dnum: dnum.into(),
+                dsize: di.into(),
+                rank: rank.into(),
+            })
+            .unwrap();
+
+            let pk_infos = EncryptionLayout::new_from_default_sigma(GLWELayout {
+                n: n.into(),
+                base2k: base2k.into(),
+                k: k.into(),
+                rank: rank.into(),
+            })
+            .unwrap();
+
+            let mut ct: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_infos);
+
+            let mut pt_scalar: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
+
+            let mut source_xs: Source = Source::new([0u8; 32]);
+            let mut source_xe: Source = Source::new([0u8; 32]);
+            let mut source_xa: Source = Source::new([0u8; 32]);
+            let mut source_xu: Source = Source::new([0u8; 32]);
+
+            pt_scalar.fill_ternary_hw(0, n, &mut source_xs);
+
+            let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
+                module
+                    .ggsw_encrypt_pk_tmp_bytes(&ggsw_infos)
+                    .max(module.ggsw_noise_tmp_bytes(&ggsw_infos)),
+            );
+
+            let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&ggsw_infos);
+            sk.fill_ternary_prob(0.5, &mut source_xs);
+
+            let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
+            module.glwe_secret_prepare(&mut sk_prepared, &sk);
+
+            let mut pk: GLWEPublicKey<AlignedBuf> = GLWEPublicKey::alloc_from_infos(&pk_infos);
+            module.glwe_public_key_generate(&mut pk, &sk_prepared, &pk_infos, &mut source_xe, &mut source_xa);
+
+            let mut pk_prepared: GLWEPublicKeyPrepared<DeviceBuf<BE>, BE> =
+                module.glwe_public_key_prepared_alloc_from_infos(&pk_infos);
+            module.glwe_public_key_prepare(&mut pk_prepared, &pk);
+
+            module.ggsw_encrypt_pk(
+                &mut ct,
+                &pt_scalar,
+                &pk_prepared,
+                &ggsw_infos,
+                &mut source_xu,
+                &mut source_xe,
+                scratch.borrow(),
+            );
+
+            // Same bound as GLWEEncryptPk: <u, e_pk> + e_0 + sum_j e_j * s_j.
+            let noise_want: f64 =
+                ((((rank as f64) + 1.0) * n as f64 * 0.5 * DEFAULT_SIGMA_XE * DEFAULT_SIGMA_XE).sqrt()).log2() - (k as f64) + 0.5;
+
+            for row in 0..ct.dnum().as_usize() {
+                for col in 0..ct.rank().as_usize() + 1 {
+                    let noise_have: f64 = ct
+                        .noise(module, row, col, &pt_scalar, &sk_prepared, scratch.borrow())
+                        .std()
+                        .log2();
+                    assert!(
+                        noise_have <= noise_want,
+                        "row: {row} col: {col} noise_have: {noise_have} > noise_want: {noise_want}"
+                    )
+                }
+            }
+        }
+    }
+}
+
 pub fn test_ggsw_compressed_encrypt_sk<BE: crate::test_suite::TestBackend>(params: &TestParams, module: &Module<BE>)
 where
     ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
@@ -106,9 +204,9 @@ where
             })
             .unwrap();
 
-            let mut ct_compressed: GGSWCompressed<Vec<u8>> = GGSWCompressed::alloc_from_infos(&ggsw_infos);
+            let mut ct_compressed: GGSWCompressed<AlignedBuf> = GGSWCompressed::alloc_from_infos(&ggsw_infos);
 
-            let mut pt_scalar: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, 1);
+            let mut pt_scalar: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
 
             let mut source_xs: Source = Source::new([0u8; 32]);
             let mut source_xe: Source = Source::new([0u8; 32]);
@@ -117,7 +215,7 @@ where
 
             let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc((module).ggsw_compressed_encrypt_sk_tmp_bytes(&ggsw_infos));
 
-            let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&ggsw_infos);
+            let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&ggsw_infos);
             sk.fill_ternary_prob(0.5, &mut source_xs);
 
             let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
@@ -137,7 +235,7 @@ where
 
             let noise_f = |_col_i: usize| -(k as f64) + DEFAULT_SIGMA_XE.log2() + 0.5;
 
-            let mut ct: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_infos);
+            let mut ct: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_infos);
             module.decompress_ggsw(&mut ct, &ct_compressed);
 
             for row in 0..ct.dnum().as_usize() {
@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxFillUniform},
-    layouts::{DeviceBuf, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, DeviceBuf, Module, Scratch, ScratchOwned},
     source::Source,
     test_suite::TestParams,
 };
@@ -44,8 +44,8 @@ where
             k: k_pt.into(),
         };
 
-        let mut ct: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_infos);
-        let mut pt_want: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&pt_infos);
+        let mut ct: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
+        let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&pt_infos);
 
         let mut source_xs: Source = Source::new([0u8; 32]);
         let mut source_xe: Source = Source::new([0u8; 32]);
@@ -57,7 +57,7 @@ where
                 .max(module.glwe_noise_tmp_bytes(&glwe_infos)),
         );
 
-        let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&glwe_infos);
+        let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&glwe_infos);
         sk.fill_ternary_prob(0.5, &mut source_xs);
 
         let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
@@ -85,6 +85,77 @@ where
     }
 }
 
+/// Same as [`test_glwe_encrypt_sk`], with the secret, the mask and the error
+/// sampled from [`Source::from_os_entropy`].
+pub fn test_glwe_encrypt_sk_os_entropy<BE: crate::test_suite::TestBackend>(params: &TestParams, module: &Module<BE>)
+where
+    Module<BE>: GLWEEncryptSk<BE> + GLWENoise<BE> + GLWESecretPreparedFactory<BE> + VecZnxFillUniform + GLWESub,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    let base2k: usize = params.base2k;
+    let k_ct: usize = base2k * 4 + 1;
+    let k_pt: usize = base2k * 2 + 1;
+    let rank: usize = 2;
+    let n: usize = module.n();
+
+    let glwe_infos = EncryptionLayout::new_from_default_sigma(GLWELayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k_ct.into(),
+        rank: rank.into(),
+    })
+    .unwrap();
+
+    let pt_infos: GLWEPlaintextLayout = GLWEPlaintextLayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k_pt.into(),
+    };
+
+    let mut ct: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
+    let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&pt_infos);
+
+    let mut source_xs: Source = Source::from_os_entropy();
+    let mut source_xe: Source = Source::from_os_entropy();
+    let mut source_xa: Source = Source::from_os_entropy();
+    assert!(source_xs.is_secure() && source_xe.is_secure() && source_xa.is_secure());
+    assert!(source_xs.branch().1.is_secure());
+    assert_ne!(source_xe.new_seed(), source_xa.new_seed());
+
+    let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
+        (module)
+            .glwe_encrypt_sk_tmp_bytes(&glwe_infos)
+            .max(module.glwe_noise_tmp_bytes(&glwe_infos)),
+    );
+
+    let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&glwe_infos);
+    sk.fill_ternary_hw(n >> 1, &mut source_xs);
+
+    let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
+    module.glwe_secret_prepare(&mut sk_prepared, &sk);
+
+    module.vec_znx_fill_uniform(base2k, &mut pt_want.data, 0, &mut source_xa);
+
+    module.glwe_encrypt_sk(
+        &mut ct,
+        &pt_want,
+        &sk_prepared,
+        &glwe_infos,
+        &mut source_xe,
+        &mut source_xa,
+        scratch.borrow(),
+    );
+
+    let noise_have: f64 = module.glwe_noise(&ct, &pt_want, &sk_prepared, scratch.borrow()).std().log2();
+    let noise_want: f64 = DEFAULT_SIGMA_XE.log2() - (k_ct as f64) + 0.5;
+
+    assert!(
+        noise_have <= noise_want,
+        "noise_have: {noise_have} > noise_want: {noise_want}"
+    );
+}
+
 pub fn test_glwe_compressed_encrypt_sk<BE: crate::test_suite::TestBackend>(params: &TestParams, module: &Module<BE>)
 where
     Module<BE>: GLWECompressedEncryptSk<BE> + GLWENoise<BE> + GLWESecretPreparedFactory<BE> + VecZnxFillUniform + GLWESub,
@@ -112,9 +183,9 @@ where
             k: k_pt.into(),
         };
 
-        let mut ct_compressed: GLWECompressed<Vec<u8>> = GLWECompressed::alloc_from_infos(&glwe_infos);
+        let mut ct_compressed: GLWECompressed<AlignedBuf> = GLWECompressed::alloc_from_infos(&glwe_infos);
 
-        let mut pt_want: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&pt_infos);
+        let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&pt_infos);
 
         let mut source_xs: Source = Source::new([0u8; 32]);
         let mut source_xe: Source = Source::new([0u8; 32]);
@@ -126,7 +197,7 @@ where
                 .max(module.glwe_noise_tmp_bytes(&glwe_infos)),
         );
 
-        let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&glwe_infos);
+        let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&glwe_infos);
         sk.fill_ternary_prob(0.5, &mut source_xs);
 
         let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
@@ -146,7 +217,7 @@ where
             scratch.borrow(),
         );
 
-        let mut ct: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_infos);
+        let mut ct: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
         module.decompress_glwe(&mut ct, &ct_compressed);
 
         let noise_have: f64 = module.glwe_noise(&ct, &pt_want, &sk_prepared, scratch.borrow()).std().log2();
@@ -178,7 +249,7 @@ where
         })
         .unwrap();
 
-        let pt: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+        let pt: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
 
         let mut source_xs: Source = Source::new([0u8; 32]);
         let mut source_xe: Source = Source::new([1u8; 32]);
@@ -190,13 +261,13 @@ where
                 .max((module).glwe_encrypt_sk_tmp_bytes(&glwe_infos)),
         );
 
-        let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&glwe_infos);
+        let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&glwe_infos);
         sk.fill_ternary_prob(0.5, &mut source_xs);
 
         let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
         module.glwe_secret_prepare(&mut sk_prepared, &sk);
 
-        let mut ct: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_infos);
+        let mut ct: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
 
         module.glwe_encrypt_zero_sk(
             &mut ct,
@@ -242,8 +313,8 @@ where
         })
         .unwrap();
 
-        let mut ct: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_infos);
-        let mut pt_want: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+        let mut ct: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
+        let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
 
         let mut source_xs: Source = Source::new([0u8; 32]);
         let mut source_xe: Source = Source::new([0u8; 32]);
@@ -256,13 +327,13 @@ where
                 .max(module.glwe_encrypt_pk_tmp_bytes(&glwe_infos)),
         );
 
-        let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&glwe_infos);
+        let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&glwe_infos);
         sk.fill_ternary_prob(0.5, &mut source_xs);
 
         let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
         module.glwe_secret_prepare(&mut sk_prepared, &sk);
 
-        let mut pk: GLWEPublicKey<Vec<u8>> = GLWEPublicKey::alloc_from_infos(&glwe_infos);
+        let mut pk: GLWEPublicKey<AlignedBuf> = GLWEPublicKey::alloc_from_infos(&glwe_infos);
         module.glwe_public_key_generate(&mut pk, &sk_prepared, &glwe_infos, &mut source_xe, &mut source_xa);
 
         module.vec_znx_fill_uniform(base2k, &mut pt_want.data, 0, &mut source_xa);
@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{DeviceBuf, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, DeviceBuf, Module, Scratch, ScratchOwned},
     source::Source,
     test_suite::TestParams,
 };
@@ -42,7 +42,7 @@ where
         })
         .unwrap();
 
-        let mut tensor_key: GLWETensorKey<Vec<u8>> = GLWETensorKey::alloc_from_infos(&tensor_key_infos);
+        let mut tensor_key: GLWETensorKey<AlignedBuf> = GLWETensorKey::alloc_from_infos(&tensor_key_infos);
 
         let mut source_xs: Source = Source::new([0u8; 32]);
         let mut source_xe: Source = Source::new([0u8; 32]);
@@ -50,7 +50,7 @@ where
 
         let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc((module).glwe_tensor_key_encrypt_sk_tmp_bytes(&tensor_key_infos));
 
-        let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&tensor_key_infos);
+        let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&tensor_key_infos);
         sk.fill_ternary_prob(0.5, &mut source_xs);
         let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
         module.glwe_secret_prepare(&mut sk_prepared, &sk);
@@ -64,7 +64,7 @@ where
             scratch.borrow(),
         );
 
-        let mut sk_tensor: GLWESecretTensor<Vec<u8>> = GLWESecretTensor::alloc_from_infos(&sk);
+        let mut sk_tensor: GLWESecretTensor<AlignedBuf> = GLWESecretTensor::alloc_from_infos(&sk);
         module.glwe_secret_tensor_prepare(&mut sk_tensor, &sk, scratch.borrow());
 
         let max_noise: f64 = DEFAULT_SIGMA_XE.log2() - (k as f64) + 0.5;
@@ -111,7 +111,7 @@ where
         })
         .unwrap();
 
-        let mut tensor_key_compressed: GLWETensorKeyCompressed<Vec<u8>> =
+        let mut tensor_key_compressed: GLWETensorKeyCompressed<AlignedBuf> =
             GLWETensorKeyCompressed::alloc_from_infos(&tensor_key_infos);
 
         let mut source_xs: Source = Source::new([0u8; 32]);
@@ -120,7 +120,7 @@ where
         let mut scratch: ScratchOwned<BE> =
             ScratchOwned::alloc((module).glwe_tensor_key_compressed_encrypt_sk_tmp_bytes(&tensor_key_infos));
 
-        let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&tensor_key_infos);
+        let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&tensor_key_infos);
         sk.fill_ternary_prob(0.5, &mut source_xs);
         let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
         module.glwe_secret_prepare(&mut sk_prepared, &sk);
@@ -136,10 +136,10 @@ where
             scratch.borrow(),
         );
 
-        let mut tensor_key: GLWETensorKey<Vec<u8>> = GLWETensorKey::alloc_from_infos(&tensor_key_infos);
+        let mut tensor_key: GLWETensorKey<AlignedBuf> = GLWETensorKey::alloc_from_infos(&tensor_key_infos);
         module.decompress_tensor_key(&mut tensor_key, &tensor_key_compressed);
 
-        let mut sk_tensor: GLWESecretTensor<Vec<u8>> = GLWESecretTensor::alloc_from_infos(&sk);
+        let mut sk_tensor: GLWESecretTensor<AlignedBuf> = GLWESecretTensor::alloc_from_infos(&sk);
         module.glwe_secret_tensor_prepare(&mut sk_tensor, &sk, scratch.borrow());
 
         let max_noise: f64 = DEFAULT_SIGMA_XE.log2() - (k as f64) + 0.5;
@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxRotateAssign},
-    layouts::{DeviceBuf, Module, ScalarZnx, ScalarZnxToMut, Scratch, ScratchOwned, ZnxViewMut},
+    layouts::{AlignedBuf, DeviceBuf, Module, ScalarZnx, ScalarZnxToMut, Scratch, ScratchOwned, ZnxViewMut},
     source::Source,
     test_suite::TestParams,
 };
@@ -78,11 +78,11 @@ where
                 })
                 .unwrap();
 
-                let mut ct_gglwe_in: GLWESwitchingKey<Vec<u8>> = GLWESwitchingKey::alloc_from_infos(&gglwe_in_infos);
-                let mut ct_gglwe_out: GLWESwitchingKey<Vec<u8>> = GLWESwitchingKey::alloc_from_infos(&gglwe_out_infos);
-                let mut ct_rgsw: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_infos);
+                let mut ct_gglwe_in: GLWESwitchingKey<AlignedBuf> = GLWESwitchingKey::alloc_from_infos(&gglwe_in_infos);
+                let mut ct_gglwe_out: GLWESwitchingKey<AlignedBuf> = GLWESwitchingKey::alloc_from_infos(&gglwe_out_infos);
+                let mut ct_rgsw: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_infos);
 
-                let mut pt_rgsw: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, 1);
+                let mut pt_rgsw: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
 
                 let mut source_xs: Source = Source::new([0u8; 32]);
                 let mut source_xe: Source = Source::new([0u8; 32]);
@@ -100,10 +100,10 @@ where
 
                 let var_xs: f64 = 0.5;
 
-                let mut sk_in: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank_in.into());
+                let mut sk_in: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank_in.into());
                 sk_in.fill_ternary_prob(var_xs, &mut source_xs);
 
-                let mut sk_out: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank_out.into());
+                let mut sk_out: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank_out.into());
                 sk_out.fill_ternary_prob(var_xs, &mut source_xs);
 
                 let mut sk_out_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> =
@@ -230,10 +230,10 @@ pub fn test_gglwe_switching_key_external_product_assign<BE: crate::test_suite::T
                 })
                 .unwrap();
 
-                let mut ct_gglwe: GLWESwitchingKey<Vec<u8>> = GLWESwitchingKey::alloc_from_infos(&gglwe_out_infos);
-                let mut ct_rgsw: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_infos);
+                let mut ct_gglwe: GLWESwitchingKey<AlignedBuf> = GLWESwitchingKey::alloc_from_infos(&gglwe_out_infos);
+                let mut ct_rgsw: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_infos);
 
-                let mut pt_rgsw: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, 1);
+                let mut pt_rgsw: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
 
                 let mut source_xs: Source = Source::new([0u8; 32]);
                 let mut source_xe: Source = Source::new([0u8; 32]);
@@ -251,10 +251,10 @@ pub fn test_gglwe_switching_key_external_product_assign<BE: crate::test_suite::T
 
                 let var_xs: f64 = 0.5;
 
-                let mut sk_in: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank_in.into());
+                let mut sk_in: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank_in.into());
                 sk_in.fill_ternary_prob(var_xs, &mut source_xs);
 
-                let mut sk_out: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank_out.into());
+                let mut sk_out: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank_out.into());
                 sk_out.fill_ternary_prob(var_xs, &mut source_xs);
 
                 let mut sk_out_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> =
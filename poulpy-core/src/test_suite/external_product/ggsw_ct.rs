@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxRotateAssign},
-    layouts::{DeviceBuf, Module, ScalarZnx, ScalarZnxToMut, Scratch, ScratchOwned, ZnxViewMut},
+    layouts::{AlignedBuf, DeviceBuf, Module, ScalarZnx, ScalarZnxToMut, Scratch, ScratchOwned, ZnxViewMut},
     source::Source,
     test_suite::TestParams,
 };
@@ -74,11 +74,11 @@ where
             })
             .unwrap();
 
-            let mut ggsw_in: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_in_infos);
-            let mut ggsw_out: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_out_infos);
-            let mut ggsw_apply: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_apply_infos);
-            let mut pt_in: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, 1);
-            let mut pt_apply: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, 1);
+            let mut ggsw_in: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_in_infos);
+            let mut ggsw_out: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_out_infos);
+            let mut ggsw_apply: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_apply_infos);
+            let mut pt_in: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
+            let mut pt_apply: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
 
             let mut source_xs: Source = Source::new([0u8; 32]);
             let mut source_xe: Source = Source::new([0u8; 32]);
@@ -96,7 +96,7 @@ where
                     | module.ggsw_external_product_tmp_bytes(&ggsw_out_infos, &ggsw_in_infos, &ggsw_apply_infos),
             );
 
-            let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank.into());
+            let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank.into());
             sk.fill_ternary_prob(0.5, &mut source_xs);
 
             let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
@@ -213,11 +213,11 @@ where
             })
             .unwrap();
 
-            let mut ggsw_out: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_out_infos);
-            let mut ggsw_apply: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_apply_infos);
+            let mut ggsw_out: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_out_infos);
+            let mut ggsw_apply: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_apply_infos);
 
-            let mut pt_in: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, 1);
-            let mut pt_apply: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, 1);
+            let mut pt_in: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
+            let mut pt_apply: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
 
             let mut source_xs: Source = Source::new([0u8; 32]);
             let mut source_xe: Source = Source::new([0u8; 32]);
@@ -235,7 +235,7 @@ where
                     | module.ggsw_external_product_tmp_bytes(&ggsw_out_infos, &ggsw_out_infos, &ggsw_apply_infos),
             );
 
-            let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank.into());
+            let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank.into());
             sk.fill_ternary_prob(0.5, &mut source_xs);
 
             let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
@@ -1,12 +1,14 @@
 use poulpy_hal::{
-    api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxFillUniform, VecZnxRotateAssign},
-    layouts::{DeviceBuf, Module, ScalarZnx, Scratch, ScratchOwned, ZnxViewMut},
+    api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxFillUniform, VecZnxRotate, VecZnxRotateAssign},
+    layouts::{AlignedBuf, DeviceBuf, Module, ScalarZnx, Scratch, ScratchOwned, ScratchPool, ZnxViewMut},
     source::Source,
     test_suite::TestParams,
 };
 
 use crate::{
-    EncryptionLayout, GGSWEncryptSk, GLWEEncryptSk, GLWEExternalProduct, GLWENoise, GLWENormalize, ScratchTakeCore,
+    EncryptionLayout, GGSWEncryptSk, GGSWEncryptSkPooled, GGSWPreparePooled, GLWEEncryptSk, GLWEEncryptSkPooled,
+    GLWEExternalProduct, GLWEExternalProductPooled, GLWENoise, GLWENoisePooled, GLWENormalize, GLWENormalizePooled,
+    ScratchTakeCore,
     encryption::DEFAULT_SIGMA_XE,
     layouts::{
         GGSW, GGSWLayout, GGSWPreparedFactory, GLWE, GLWELayout, GLWEPlaintext, GLWESecret, GLWESecretPreparedFactory,
@@ -69,12 +71,12 @@ where
             })
             .unwrap();
 
-            let mut ggsw_apply: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_apply_infos);
-            let mut glwe_in: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_in_infos);
-            let mut glwe_out: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_out_infos);
-            let mut pt_ggsw: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, 1);
-            let mut pt_in: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_in_infos);
-            let mut pt_out: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+            let mut ggsw_apply: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_apply_infos);
+            let mut glwe_in: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_in_infos);
+            let mut glwe_out: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_out_infos);
+            let mut pt_ggsw: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
+            let mut pt_in: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_in_infos);
+            let mut pt_out: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
 
             let mut source_xs: Source = Source::new([0u8; 32]);
             let mut source_xe: Source = Source::new([0u8; 32]);
@@ -95,7 +97,7 @@ where
                     | module.glwe_external_product_tmp_bytes(&glwe_out_infos, &glwe_in_infos, &ggsw_apply_infos),
             );
 
-            let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank.into());
+            let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank.into());
             sk.fill_ternary_prob(0.5, &mut source_xs);
 
             let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
@@ -205,10 +207,10 @@ where
             })
             .unwrap();
 
-            let mut ggsw_apply: GGSW<Vec<u8>> = GGSW::alloc_from_infos(&ggsw_apply_infos);
-            let mut glwe_out: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_out_infos);
-            let mut pt_ggsw: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, 1);
-            let mut pt_want: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+            let mut ggsw_apply: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_apply_infos);
+            let mut glwe_out: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_out_infos);
+            let mut pt_ggsw: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
+            let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
 
             let mut source_xs: Source = Source::new([0u8; 32]);
             let mut source_xe: Source = Source::new([0u8; 32]);
@@ -229,7 +231,7 @@ where
                     | module.glwe_external_product_tmp_bytes(&glwe_out_infos, &glwe_out_infos, &ggsw_apply_infos),
             );
 
-            let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank.into());
+            let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank.into());
             sk.fill_ternary_prob(0.5, &mut source_xs);
 
             let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
@@ -291,3 +293,161 @@ where
         }
     }
 }
+
+/// Runs an encryption, external product and decryption drawing all of their
+/// scratch space from a [`ScratchPool`], from the main thread and from a
+/// worker thread.
+pub fn test_glwe_external_product_pooled<BE: crate::test_suite::TestBackend>(params: &TestParams, module: &Module<BE>)
+where
+    Module<BE>: GGSWEncryptSk<BE>
+        + GGSWPreparedFactory<BE>
+        + VecZnxFillUniform
+        + GLWEExternalProduct<BE>
+        + GLWEEncryptSk<BE>
+        + GLWENoise<BE>
+        + VecZnxRotate
+        + GLWESecretPreparedFactory<BE>
+        + GLWENormalize<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    let base2k: usize = params.base2k;
+    let k_in: usize = 4 * base2k + 1;
+    let k_ggsw: usize = k_in + base2k;
+    let rank: usize = 2;
+    let n: usize = module.n();
+
+    let glwe_infos = EncryptionLayout::new_from_default_sigma(GLWELayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k_in.into(),
+        rank: rank.into(),
+    })
+    .unwrap();
+
+    let ggsw_infos = EncryptionLayout::new_from_default_sigma(GGSWLayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k_ggsw.into(),
+        dnum: k_in.div_ceil(base2k).into(),
+        dsize: 1_u32.into(),
+        rank: rank.into(),
+    })
+    .unwrap();
+
+    let pool: ScratchPool<BE> = ScratchPool::new();
+
+    let mut ggsw: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_infos);
+    let mut glwe_in: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
+    let mut pt_ggsw: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
+    let mut pt_in: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+    let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+
+    let mut source_xs: Source = Source::new([0u8; 32]);
+    let mut source_xe: Source = Source::new([1u8; 32]);
+    let mut source_xa: Source = Source::new([2u8; 32]);
+
+    module.vec_znx_fill_uniform(base2k, &mut pt_in.data, 0, &mut source_xa);
+    let k: usize = 1;
+    pt_ggsw.raw_mut()[k] = 1; // X^{k}
+
+    let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank.into());
+    sk.fill_ternary_prob(0.5, &mut source_xs);
+    let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
+    module.glwe_secret_prepare(&mut sk_prepared, &sk);
+
+    module.ggsw_encrypt_sk_pooled(
+        &mut ggsw,
+        &pt_ggsw,
+        &sk_prepared,
+        &ggsw_infos,
+        &mut source_xe,
+        &mut source_xa,
+        &pool,
+    );
+    module.glwe_encrypt_sk_pooled(
+        &mut glwe_in,
+        &pt_in,
+        &sk_prepared,
+        &glwe_infos,
+        &mut source_xe,
+        &mut source_xa,
+        &pool,
+    );
+
+    let mut ggsw_prepared: GGSWPrepared<DeviceBuf<BE>, BE> = module.ggsw_prepared_alloc_from_infos(&ggsw);
+    module.ggsw_prepare_pooled(&mut ggsw_prepared, &ggsw, &pool);
+
+    module.vec_znx_rotate(k as i64, &mut pt_want.data, 0, &pt_in.data, 0);
+    module.glwe_normalize_assign_pooled(&mut pt_want, &pool);
+
+    let tmp_bytes_max: usize = module
+        .ggsw_encrypt_sk_tmp_bytes(&ggsw_infos)
+        .max(module.glwe_encrypt_sk_tmp_bytes(&glwe_infos))
+        .max(module.ggsw_prepare_tmp_bytes(&ggsw))
+        .max(module.glwe_normalize_tmp_bytes())
+        .max(module.glwe_external_product_tmp_bytes(&glwe_infos, &glwe_infos, &ggsw_infos))
+        .max(module.glwe_noise_tmp_bytes(&glwe_infos));
+
+    let var_sigma: f64 = DEFAULT_SIGMA_XE * DEFAULT_SIGMA_XE;
+    let max_noise: f64 = noise_ggsw_product(
+        n as f64,
+        base2k,
+        0.5,
+        1f64 / n as f64,
+        var_sigma,
+        1f64 / 12f64,
+        var_sigma,
+        0f64,
+        rank as f64,
+        k_in,
+        k_ggsw,
+    ) + 1.0;
+
+    // The first run grows the arena of the calling thread, later runs reuse it.
+    let mut grow_count: usize = 0;
+    for _ in 0..2 {
+        let mut glwe_out: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
+        module.glwe_external_product_pooled(&mut glwe_out, &glwe_in, &ggsw_prepared, &pool);
+        let noise: f64 = module
+            .glwe_noise_pooled(&glwe_out, &pt_want, &sk_prepared, &pool)
+            .std()
+            .log2();
+        assert!(noise <= max_noise, "noise: {noise} > max_noise: {max_noise}");
+        assert_eq!(pool.high_water_mark(), tmp_bytes_max);
+        if grow_count == 0 {
+            grow_count = pool.grow_count();
+        } else {
+            assert_eq!(pool.grow_count(), grow_count);
+        }
+    }
+    let allocated_bytes: usize = pool.allocated_bytes();
+    assert!(allocated_bytes >= pool.high_water_mark());
+
+    // A second thread gets its own arena.
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            let mut glwe_out: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
+            module.glwe_external_product_assign_pooled(&mut glwe_out, &ggsw_prepared, &pool);
+        });
+    });
+    assert_eq!(pool.grow_count(), grow_count + 1);
+    assert!(pool.allocated_bytes() > allocated_bytes);
+
+    // The arena of an exited thread stays in the pool until released.
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            let mut glwe_out: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
+            module.glwe_external_product_assign_pooled(&mut glwe_out, &ggsw_prepared, &pool);
+            pool.release();
+        });
+    });
+    assert_eq!(pool.grow_count(), grow_count + 2);
+    assert!(pool.allocated_bytes() > allocated_bytes);
+
+    pool.release();
+    assert!(pool.allocated_bytes() > 0);
+
+    pool.clear();
+    assert_eq!(pool.allocated_bytes(), 0);
+}
@@ -0,0 +1,297 @@
+use poulpy_hal::{
+    api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow},
+    layouts::{AlignedBuf, DeviceBuf, Module, ScalarZnx, Scratch, ScratchOwned},
+    source::Source,
+    test_suite::TestParams,
+};
+
+use crate::{
+    CoreError, EncryptionLayout, GGSWEncryptSk, GGSWEncryptSkFallible, GGSWFromGGLWEFallible, GLWEEncryptPkFallible,
+    GLWEEncryptSk, GLWEEncryptSkFallible, GLWEKeyswitchFallible, GLWEPublicKeyGenerate, ScratchTakeCore,
+    layouts::{
+        GGLWE, GGLWEToGGSWKeyLayout, GGLWEToGGSWKeyPreparedFactory, GGSW, GGSWLayout, GLWE, GLWELayout, GLWEPlaintext,
+        GLWEPublicKey, GLWEPublicKeyPreparedFactory, GLWESecret, GLWESecretPreparedFactory, GLWESwitchingKeyPreparedFactory,
+        prepared::{GGLWEToGGSWKeyPrepared, GLWEPublicKeyPrepared, GLWESecretPrepared, GLWESwitchingKeyPrepared},
+    },
+};
+
+/// Checks that the `try_*` operations report mismatched inputs as the
+/// matching [`CoreError`] variant, and succeed on valid inputs.
+pub fn test_fallible<BE: crate::test_suite::TestBackend>(params: &TestParams, module: &Module<BE>)
+where
+    Module<BE>: GLWEEncryptSk<BE>
+        + GLWEEncryptSkFallible<BE>
+        + GLWEEncryptPkFallible<BE>
+        + GGSWEncryptSkFallible<BE>
+        + GLWEKeyswitchFallible<BE>
+        + GGSWFromGGLWEFallible<BE>
+        + GLWEPublicKeyGenerate<BE>
+        + GLWEPublicKeyPreparedFactory<BE>
+        + GLWESecretPreparedFactory<BE>
+        + GLWESwitchingKeyPreparedFactory<BE>
+        + GGLWEToGGSWKeyPreparedFactory<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    let base2k: usize = params.base2k;
+    let k: usize = 4 * base2k + 1;
+    let n: usize = module.n();
+    let rank: usize = 1;
+
+    let glwe_infos = EncryptionLayout::new_from_default_sigma(GLWELayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k.into(),
+        rank: rank.into(),
+    })
+    .unwrap();
+
+    let mut source_xs: Source = Source::new([0u8; 32]);
+    let mut source_xe: Source = Source::new([0u8; 32]);
+    let mut source_xa: Source = Source::new([0u8; 32]);
+    let mut source_xu: Source = Source::new([0u8; 32]);
+
+    let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(module.glwe_encrypt_sk_tmp_bytes(&glwe_infos) << 2);
+
+    let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank.into());
+    sk.fill_ternary_prob(0.5, &mut source_xs);
+    let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
+    module.glwe_secret_prepare(&mut sk_prepared, &sk);
+
+    let pt: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+
+    // Valid inputs.
+    let mut ct: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
+    assert_eq!(
+        module.try_glwe_encrypt_sk(
+            &mut ct,
+            &pt,
+            &sk_prepared,
+            &glwe_infos,
+            &mut source_xe,
+            &mut source_xa,
+            scratch.borrow(),
+        ),
+        Ok(())
+    );
+
+    // Rank mismatch.
+    let mut ct_rank_2: GLWE<AlignedBuf> = GLWE::alloc(n.into(), base2k.into(), k.into(), 2_u32.into());
+    assert_eq!(
+        module.try_glwe_encrypt_sk(
+            &mut ct_rank_2,
+            &pt,
+            &sk_prepared,
+            &glwe_infos,
+            &mut source_xe,
+            &mut source_xa,
+            scratch.borrow(),
+        ),
+        Err(CoreError::RankMismatch {
+            op: "glwe_encrypt_sk",
+            what: "res.rank",
+            expected: 1,
+            actual: 2,
+        })
+    );
+
+    // Degree mismatch.
+    let mut ct_half: GLWE<AlignedBuf> = GLWE::alloc((n >> 1).into(), base2k.into(), k.into(), rank.into());
+    assert_eq!(
+        module.try_glwe_encrypt_sk(
+            &mut ct_half,
+            &pt,
+            &sk_prepared,
+            &glwe_infos,
+            &mut source_xe,
+            &mut source_xa,
+            scratch.borrow(),
+        ),
+        Err(CoreError::DimensionMismatch {
+            op: "glwe_encrypt_sk",
+            what: "res.n",
+            expected: n,
+            actual: n >> 1,
+        })
+    );
+
+    // Scratch too small.
+    let required: usize = module.glwe_encrypt_sk_tmp_bytes(&glwe_infos);
+    let mut scratch_small: ScratchOwned<BE> = ScratchOwned::alloc(required >> 1);
+    let available: usize = scratch_small.borrow().available();
+    assert_eq!(
+        module.try_glwe_encrypt_sk(
+            &mut ct,
+            &pt,
+            &sk_prepared,
+            &glwe_infos,
+            &mut source_xe,
+            &mut source_xa,
+            scratch_small.borrow(),
+        ),
+        Err(CoreError::ScratchTooSmall {
+            op: "glwe_encrypt_sk",
+            required,
+            available,
+        })
+    );
+
+    // Secret allocated but never prepared.
+    let sk_unprepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
+    assert_eq!(
+        module.try_glwe_encrypt_sk(
+            &mut ct,
+            &pt,
+            &sk_unprepared,
+            &glwe_infos,
+            &mut source_xe,
+            &mut source_xa,
+            scratch.borrow(),
+        ),
+        Err(CoreError::UnpreparedSecret {
+            op: "glwe_encrypt_sk",
+            what: "sk",
+        })
+    );
+
+    // Minimum security above the estimate of the instance.
+    let glwe_infos_secure = EncryptionLayout::new_from_default_sigma(GLWELayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k.into(),
+        rank: rank.into(),
+    })
+    .unwrap()
+    .with_min_security(1024.0);
+    match module.try_glwe_encrypt_sk(
+        &mut ct,
+        &pt,
+        &sk_prepared,
+        &glwe_infos_secure,
+        &mut source_xe,
+        &mut source_xa,
+        scratch.borrow(),
+    ) {
+        Err(CoreError::InsufficientSecurity { op, required, estimated }) => {
+            assert_eq!(op, "glwe_encrypt_sk");
+            assert_eq!(required, 1024.0);
+            assert!(estimated < required, "{estimated}");
+        }
+        other => panic!("expected InsufficientSecurity, got {other:?}"),
+    }
+
+    let ggsw_infos_secure = EncryptionLayout::new_from_default_sigma(GGSWLayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k.into(),
+        rank: rank.into(),
+        dnum: 2_u32.into(),
+        dsize: 1_u32.into(),
+    })
+    .unwrap()
+    .with_min_security(1024.0);
+    let mut ggsw_ct: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_infos_secure);
+    let pt_ggsw: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
+    let mut scratch_ggsw: ScratchOwned<BE> = ScratchOwned::alloc(module.ggsw_encrypt_sk_tmp_bytes(&ggsw_infos_secure));
+    match module.try_ggsw_encrypt_sk(
+        &mut ggsw_ct,
+        &pt_ggsw,
+        &sk_prepared,
+        &ggsw_infos_secure,
+        &mut source_xe,
+        &mut source_xa,
+        scratch_ggsw.borrow(),
+    ) {
+        Err(CoreError::InsufficientSecurity { op, required, estimated }) => {
+            assert_eq!(op, "ggsw_encrypt_sk");
+            assert_eq!(required, 1024.0);
+            assert!(estimated < required, "{estimated}");
+        }
+        other => panic!("expected InsufficientSecurity, got {other:?}"),
+    }
+    assert_eq!(
+        module.try_ggsw_encrypt_sk(
+            &mut ggsw_ct,
+            &pt_ggsw,
+            &sk_unprepared,
+            &ggsw_infos_secure,
+            &mut source_xe,
+            &mut source_xa,
+            scratch_ggsw.borrow(),
+        ),
+        Err(CoreError::UnpreparedSecret {
+            op: "ggsw_encrypt_sk",
+            what: "sk",
+        })
+    );
+
+    // Base2k mismatch between the ciphertext and the public key.
+    let mut pk: GLWEPublicKey<AlignedBuf> = GLWEPublicKey::alloc_from_infos(&glwe_infos);
+    module.glwe_public_key_generate(&mut pk, &sk_prepared, &glwe_infos, &mut source_xe, &mut source_xa);
+    let mut pk_prepared: GLWEPublicKeyPrepared<DeviceBuf<BE>, BE> = module.glwe_public_key_prepared_alloc_from_infos(&pk);
+    module.glwe_public_key_prepare(&mut pk_prepared, &pk);
+
+    let mut ct_other_base2k: GLWE<AlignedBuf> = GLWE::alloc(n.into(), (base2k - 1).into(), k.into(), rank.into());
+    assert_eq!(
+        module.try_glwe_encrypt_pk(
+            &mut ct_other_base2k,
+            &pt,
+            &pk_prepared,
+            &glwe_infos,
+            &mut source_xu,
+            &mut source_xe,
+            scratch.borrow(),
+        ),
+        Err(CoreError::Base2KMismatch {
+            op: "glwe_encrypt_pk",
+            what: "res.base2k",
+            expected: base2k,
+            actual: base2k - 1,
+        })
+    );
+
+    // Key-switching key of the wrong input rank.
+    let ksk: GLWESwitchingKeyPrepared<DeviceBuf<BE>, BE> =
+        module.glwe_switching_key_prepared_alloc(base2k.into(), k.into(), 2_u32.into(), rank.into(), 1_u32.into(), 1_u32.into());
+    let mut ct_out: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
+    assert_eq!(
+        module.try_glwe_keyswitch(&mut ct_out, &ct, &ksk, scratch.borrow()),
+        Err(CoreError::RankMismatch {
+            op: "glwe_keyswitch",
+            what: "a.rank",
+            expected: 2,
+            actual: 1,
+        })
+    );
+
+    // GGLWE and GGSW of different digit sizes.
+    let dnum: usize = 2;
+    let gglwe: GGLWE<AlignedBuf> = GGLWE::alloc(
+        n.into(),
+        base2k.into(),
+        k.into(),
+        rank.into(),
+        rank.into(),
+        dnum.into(),
+        2_u32.into(),
+    );
+    let mut ggsw: GGSW<AlignedBuf> = GGSW::alloc(n.into(), base2k.into(), k.into(), rank.into(), dnum.into(), 1_u32.into());
+    let tsk: GGLWEToGGSWKeyPrepared<DeviceBuf<BE>, BE> =
+        module.gglwe_to_ggsw_key_prepared_alloc_from_infos(&GGLWEToGGSWKeyLayout {
+            n: n.into(),
+            base2k: base2k.into(),
+            k: k.into(),
+            rank: rank.into(),
+            dnum: dnum.into(),
+            dsize: 1_u32.into(),
+        });
+    assert_eq!(
+        module.try_ggsw_from_gglwe(&mut ggsw, &gglwe, &tsk, scratch.borrow()),
+        Err(CoreError::DsizeMismatch {
+            op: "ggsw_from_gglwe",
+            what: "res.dsize",
+            expected: 2,
+            actual: 1,
+        })
+    );
+}
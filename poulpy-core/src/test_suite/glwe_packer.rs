@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use poulpy_hal::{
     api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{DeviceBuf, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, DeviceBuf, Module, Scratch, ScratchOwned},
     source::Source,
     test_suite::TestParams,
 };
@@ -71,13 +71,13 @@ where
             | glwe_packer_tmp_bytes(module, &glwe_out_infos, &key_infos),
     );
 
-    let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&glwe_out_infos);
+    let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&glwe_out_infos);
     sk.fill_ternary_prob(0.5, &mut source_xs);
 
     let mut sk_dft: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc_from_infos(&sk);
     module.glwe_secret_prepare(&mut sk_dft, &sk);
 
-    let mut pt: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+    let mut pt: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
     let mut data: Vec<i64> = vec![0i64; n];
     data.iter_mut().enumerate().for_each(|(i, x)| {
         *x = i as i64;
@@ -88,7 +88,7 @@ where
     let gal_els: Vec<i64> = glwe_packer_galois_elements(module);
 
     let mut auto_keys: HashMap<i64, GLWEAutomorphismKeyPrepared<DeviceBuf<BE>, BE>> = HashMap::new();
-    let mut tmp: GLWEAutomorphismKey<Vec<u8>> = GLWEAutomorphismKey::alloc_from_infos(&key_infos);
+    let mut tmp: GLWEAutomorphismKey<AlignedBuf> = GLWEAutomorphismKey::alloc_from_infos(&key_infos);
     gal_els.iter().for_each(|gal_el| {
         module.glwe_automorphism_key_encrypt_sk(
             &mut tmp,
@@ -109,7 +109,7 @@ where
 
     let mut packer: GLWEPacker = GLWEPacker::alloc(&glwe_out_infos, log_batch);
 
-    let mut ct: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_out_infos);
+    let mut ct: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_out_infos);
 
     module.glwe_encrypt_sk(
         &mut ct,
@@ -139,14 +139,14 @@ where
         if reverse_bits_msb(i, log_n as u32).is_multiple_of(5) {
             glwe_packer_add(module, &mut packer, Some(&ct), &auto_keys, scratch.borrow());
         } else {
-            glwe_packer_add(module, &mut packer, None::<&GLWE<Vec<u8>>>, &auto_keys, scratch.borrow())
+            glwe_packer_add(module, &mut packer, None::<&GLWE<AlignedBuf>>, &auto_keys, scratch.borrow())
         }
     });
 
-    let mut res: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_out_infos);
+    let mut res: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_out_infos);
     glwe_packer_flush(module, &mut packer, &mut res, scratch.borrow());
 
-    let mut pt_want: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+    let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
     let mut data: Vec<i64> = vec![0i64; n];
     data.iter_mut().enumerate().for_each(|(i, x)| {
         if i.is_multiple_of(5) {
@@ -3,19 +3,22 @@ use std::collections::HashMap;
 use itertools::Itertools;
 use poulpy_hal::{
     api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{DeviceBuf, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, DeviceBuf, Module, Scratch, ScratchOwned},
     source::Source,
     test_suite::TestParams,
 };
 
 use crate::{
-    EncryptionLayout, GLWEAutomorphismKeyEncryptSk, GLWEDecrypt, GLWEEncryptSk, GLWENoise, GLWEPacking, GLWERotate, GLWESub,
-    ScratchTakeCore,
+    DEFAULT_SIGMA_XE, EncryptionLayout, GLWEAutomorphismKeyEncryptSk, GLWEDecrypt, GLWEEncryptSk, GLWENoise, GLWEPackLWEs,
+    GLWEPacking, GLWERotate, GLWESub, LWEEncryptSk, LWEToGLWESwitchingKeyEncryptSk, ScratchTakeCore,
     layouts::{
         GLWE, GLWEAutomorphismKey, GLWEAutomorphismKeyLayout, GLWEAutomorphismKeyPreparedFactory, GLWELayout, GLWEPlaintext,
-        GLWESecret, GLWESecretPreparedFactory,
+        GLWESecret, GLWESecretPreparedFactory, LWE, LWELayout, LWEPlaintext, LWESecret, LWEToGLWEKey, LWEToGLWEKeyLayout,
+        LWEToGLWEKeyPrepared, LWEToGLWEKeyPreparedFactory,
         prepared::{GLWEAutomorphismKeyPrepared, GLWESecretPrepared},
     },
+    noise::{NoiseModel, NoiseTracker},
+    test_suite::{assert_noise_below, assert_noise_matches},
 };
 
 pub fn test_glwe_packing<BE: crate::test_suite::TestBackend>(params: &TestParams, module: &Module<BE>)
@@ -73,13 +76,13 @@ where
             .max(module.glwe_pack_tmp_bytes(&glwe_out_infos, &key_infos)),
     );
 
-    let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&glwe_out_infos);
+    let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&glwe_out_infos);
     sk.fill_ternary_prob(0.5, &mut source_xs);
 
     let mut sk_prep: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc_from_infos(&sk);
     module.glwe_secret_prepare(&mut sk_prep, &sk);
 
-    let mut pt: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+    let mut pt: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
     let mut data: Vec<i64> = vec![0i64; n];
     data.iter_mut().enumerate().for_each(|(i, x)| {
         *x = i as i64;
@@ -90,7 +93,7 @@ where
     let gal_els: Vec<i64> = module.glwe_pack_galois_elements();
 
     let mut auto_keys: HashMap<i64, GLWEAutomorphismKeyPrepared<DeviceBuf<BE>, BE>> = HashMap::new();
-    let mut tmp: GLWEAutomorphismKey<Vec<u8>> = GLWEAutomorphismKey::alloc_from_infos(&key_infos);
+    let mut tmp: GLWEAutomorphismKey<AlignedBuf> = GLWEAutomorphismKey::alloc_from_infos(&key_infos);
     gal_els.iter().for_each(|gal_el| {
         module.glwe_automorphism_key_encrypt_sk(
             &mut tmp,
@@ -125,17 +128,17 @@ where
         })
         .collect_vec();
 
-    let mut cts_map: HashMap<usize, &mut GLWE<Vec<u8>>> = HashMap::new();
+    let mut cts_map: HashMap<usize, &mut GLWE<AlignedBuf>> = HashMap::new();
 
     for (i, ct) in cts.iter_mut().enumerate() {
         cts_map.insert(5 * i, ct);
     }
 
-    let mut res: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_out_infos);
+    let mut res: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_out_infos);
 
     module.glwe_pack(&mut res, cts_map, 0, &auto_keys, scratch.borrow());
 
-    let mut pt_want: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+    let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
     let mut data: Vec<i64> = vec![0i64; n];
     data.iter_mut().enumerate().for_each(|(i, x)| {
         if i.is_multiple_of(5) {
@@ -147,3 +150,162 @@ where
 
     assert!(module.glwe_noise(&res, &pt_want, &sk_prep, scratch.borrow()).std().log2() <= ((k_ct - out_base2k) as f64));
 }
+
+/// Packs a full batch of `N` and a partial batch of LWE ciphertexts with
+/// [`GLWEPackLWEs`] and checks that every message lands in its coefficient,
+/// with the noise predicted by [`NoiseModel::glwe_pack_lwes`].
+pub fn test_glwe_pack_lwes<BE: crate::test_suite::TestBackend>(params: &TestParams, module: &Module<BE>)
+where
+    Module<BE>: GLWEPackLWEs<BE>
+        + GLWEAutomorphismKeyEncryptSk<BE>
+        + GLWEAutomorphismKeyPreparedFactory<BE>
+        + LWEToGLWESwitchingKeyEncryptSk<BE>
+        + LWEToGLWEKeyPreparedFactory<BE>
+        + GLWESecretPreparedFactory<BE>
+        + LWEEncryptSk<BE>
+        + GLWEDecrypt<BE>
+        + GLWENoise<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    let mut source_xs: Source = Source::new([0u8; 32]);
+    let mut source_xe: Source = Source::new([1u8; 32]);
+    let mut source_xa: Source = Source::new([2u8; 32]);
+
+    let n: usize = module.n();
+    let base2k: usize = params.base2k;
+    let k_ct: usize = 3 * base2k;
+    let k_ksk: usize = k_ct + base2k;
+    let k_pt: usize = 8;
+    let dnum: usize = k_ct.div_ceil(base2k);
+
+    let glwe_infos: GLWELayout = GLWELayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k_ct.into(),
+        rank: 1_usize.into(),
+    };
+
+    let lwe_infos = EncryptionLayout::new_from_default_sigma(LWELayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k_ct.into(),
+    })
+    .unwrap();
+
+    let ksk_infos = EncryptionLayout::new_from_default_sigma(LWEToGLWEKeyLayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k_ksk.into(),
+        dnum: dnum.into(),
+        rank_out: 1_usize.into(),
+    })
+    .unwrap();
+
+    let atk_infos = EncryptionLayout::new_from_default_sigma(GLWEAutomorphismKeyLayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k_ksk.into(),
+        rank: 1_usize.into(),
+        dsize: 1_usize.into(),
+        dnum: dnum.into(),
+    })
+    .unwrap();
+
+    let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
+        module
+            .lwe_encrypt_sk_tmp_bytes(&lwe_infos)
+            .max(module.lwe_to_glwe_key_encrypt_sk_tmp_bytes(&ksk_infos))
+            .max(module.glwe_automorphism_key_encrypt_sk_tmp_bytes(&atk_infos))
+            .max(module.glwe_pack_lwes_tmp_bytes(&glwe_infos, &lwe_infos, &ksk_infos, &atk_infos))
+            .max(module.glwe_decrypt_tmp_bytes(&glwe_infos)),
+    );
+
+    let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&glwe_infos);
+    sk.fill_ternary_prob(0.5, &mut source_xs);
+    let mut sk_prep: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc_from_infos(&sk);
+    module.glwe_secret_prepare(&mut sk_prep, &sk);
+    let sk_lwe: LWESecret<AlignedBuf> = LWESecret::from_glwe_secret(&sk);
+
+    let mut ksk: LWEToGLWEKey<AlignedBuf> = LWEToGLWEKey::alloc_from_infos(&ksk_infos);
+    module.lwe_to_glwe_key_encrypt_sk(
+        &mut ksk,
+        &sk_lwe,
+        &sk_prep,
+        &ksk_infos,
+        &mut source_xe,
+        &mut source_xa,
+        scratch.borrow(),
+    );
+    let mut ksk_prep: LWEToGLWEKeyPrepared<DeviceBuf<BE>, BE> = module.lwe_to_glwe_key_prepared_alloc_from_infos(&ksk);
+    module.lwe_to_glwe_key_prepare(&mut ksk_prep, &ksk, scratch.borrow());
+
+    let mut auto_keys: HashMap<i64, GLWEAutomorphismKeyPrepared<DeviceBuf<BE>, BE>> = HashMap::new();
+    let mut atk: GLWEAutomorphismKey<AlignedBuf> = GLWEAutomorphismKey::alloc_from_infos(&atk_infos);
+    for gal_el in module.glwe_pack_lwes_galois_elements() {
+        module.glwe_automorphism_key_encrypt_sk(
+            &mut atk,
+            gal_el,
+            &sk,
+            &atk_infos,
+            &mut source_xe,
+            &mut source_xa,
+            scratch.borrow(),
+        );
+        let mut atk_prep: GLWEAutomorphismKeyPrepared<DeviceBuf<BE>, BE> =
+            module.glwe_automorphism_key_prepared_alloc_from_infos(&atk);
+        module.glwe_automorphism_key_prepare(&mut atk_prep, &atk, scratch.borrow());
+        auto_keys.insert(gal_el, atk_prep);
+    }
+
+    let model: NoiseModel = NoiseModel::from_secret(&sk, DEFAULT_SIGMA_XE);
+    let mut tracker: NoiseTracker = NoiseTracker::fresh(model, &lwe_infos.noise);
+    tracker.pack_lwes(&lwe_infos, &glwe_infos, &ksk_infos, &atk_infos);
+    let noise_want: f64 = tracker.log2_std();
+
+    let data: Vec<i64> = (0..n as i64).map(|i| (i * 37) % 255 - 127).collect();
+
+    let mut lwe_pt: LWEPlaintext<AlignedBuf> = LWEPlaintext::alloc_from_infos(&lwe_infos);
+    let lwes: Vec<LWE<AlignedBuf>> = data
+        .iter()
+        .map(|&x| {
+            let mut lwe: LWE<AlignedBuf> = LWE::alloc_from_infos(&lwe_infos);
+            lwe_pt.encode_i64(x, k_pt.into());
+            module.lwe_encrypt_sk(
+                &mut lwe,
+                &lwe_pt,
+                &sk_lwe,
+                &lwe_infos,
+                &mut source_xe,
+                &mut source_xa,
+                scratch.borrow(),
+            );
+            lwe
+        })
+        .collect();
+
+    let mut res: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
+    let mut pt_have: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+    let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+    let mut data_have: Vec<i64> = vec![0i64; n];
+
+    for count in [n, 37, 1] {
+        module.glwe_pack_lwes(&mut res, &lwes[..count], &ksk_prep, &auto_keys, scratch.borrow());
+
+        let mut data_want: Vec<i64> = vec![0i64; n];
+        data_want[..count].copy_from_slice(&data[..count]);
+
+        module.glwe_decrypt(&res, &mut pt_have, &sk_prep, scratch.borrow());
+        pt_have.decode_vec_i64(&mut data_have, k_pt.into());
+        assert_eq!(data_have, data_want, "count={count}: wrong decryption");
+
+        pt_want.encode_vec_i64(&data_want, k_pt.into());
+        let noise_have: f64 = module.glwe_noise(&res, &pt_want, &sk_prep, scratch.borrow()).std().log2();
+
+        if count == n {
+            assert_noise_matches(&format!("count={count}"), noise_have, noise_want);
+        } else {
+            assert_noise_below(&format!("count={count}"), noise_have, noise_want);
+        }
+    }
+}
@@ -3,7 +3,7 @@ use poulpy_hal::{
         ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxCopy, VecZnxFillUniform, VecZnxNormalize,
         VecZnxNormalizeAssign,
     },
-    layouts::{DeviceBuf, FillUniform, Module, Scratch, ScratchOwned, VecZnx, ZnxView, ZnxViewMut},
+    layouts::{AlignedBuf, DeviceBuf, FillUniform, Module, Scratch, ScratchOwned, VecZnx, ZnxView, ZnxViewMut},
     source::Source,
     test_suite::TestParams,
     test_suite::convolution::bivariate_convolution_naive,
@@ -73,14 +73,14 @@ where
         })
         .unwrap();
 
-        let mut a: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_in_infos);
-        let mut b: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_in_infos);
-        let mut res_tensor: GLWETensor<Vec<u8>> = GLWETensor::alloc_from_infos(&glwe_out_infos);
-        let mut res_relin: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_out_infos);
-        let mut pt_in: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_in_infos);
-        let mut pt_have: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
-        let mut pt_want: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
-        let mut pt_tmp: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+        let mut a: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_in_infos);
+        let mut b: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_in_infos);
+        let mut res_tensor: GLWETensor<AlignedBuf> = GLWETensor::alloc_from_infos(&glwe_out_infos);
+        let mut res_relin: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_out_infos);
+        let mut pt_in: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_in_infos);
+        let mut pt_have: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+        let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+        let mut pt_tmp: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
 
         let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
             (module)
@@ -95,20 +95,20 @@ where
         let mut source_xe: Source = Source::new([0u8; 32]);
         let mut source_xa: Source = Source::new([0u8; 32]);
 
-        let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc(module.n().into(), rank.into());
+        let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc(module.n().into(), rank.into());
         sk.fill_ternary_prob(0.5, &mut source_xs);
 
         let mut sk_dft: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc_from_infos(&sk);
         module.glwe_secret_prepare(&mut sk_dft, &sk);
 
-        let mut sk_tensor: GLWESecretTensor<Vec<u8>> = GLWESecretTensor::alloc(module.n().into(), rank.into());
+        let mut sk_tensor: GLWESecretTensor<AlignedBuf> = GLWESecretTensor::alloc(module.n().into(), rank.into());
         module.glwe_secret_tensor_prepare(&mut sk_tensor, &sk, scratch.borrow());
 
         let mut sk_tensor_prep: GLWESecretTensorPrepared<DeviceBuf<BE>, BE> =
             module.glwe_secret_tensor_prepared_alloc(rank.into());
         module.glwe_secret_tensor_prepared_prepare(&mut sk_tensor_prep, &sk_tensor);
 
-        let mut tsk: GLWETensorKey<Vec<u8>> = GLWETensorKey::alloc_from_infos(&tsk_infos);
+        let mut tsk: GLWETensorKey<AlignedBuf> = GLWETensorKey::alloc_from_infos(&tsk_infos);
         module.glwe_tensor_key_encrypt_sk(&mut tsk, &sk, &tsk_infos, &mut source_xe, &mut source_xa, scratch.borrow());
 
         let mut tsk_prep: GLWETensorKeyPrepared<DeviceBuf<BE>, BE> = module.alloc_tensor_key_prepared_from_infos(&tsk_infos);
@@ -229,10 +229,10 @@ where
             rank: rank.into(),
         };
 
-        let mut a = GLWE::<Vec<u8>>::alloc_from_infos(&glwe_in_infos);
-        let mut b = GLWE::<Vec<u8>>::alloc_from_infos(&glwe_in_infos);
-        let mut product = GLWETensor::<Vec<u8>>::alloc_from_infos(&glwe_out_infos);
-        let mut acc = GLWETensor::<Vec<u8>>::alloc_from_infos(&glwe_out_infos);
+        let mut a = GLWE::<AlignedBuf>::alloc_from_infos(&glwe_in_infos);
+        let mut b = GLWE::<AlignedBuf>::alloc_from_infos(&glwe_in_infos);
+        let mut product = GLWETensor::<AlignedBuf>::alloc_from_infos(&glwe_out_infos);
+        let mut acc = GLWETensor::<AlignedBuf>::alloc_from_infos(&glwe_out_infos);
 
         for (i, x) in a.data_mut().raw_mut().iter_mut().enumerate() {
             *x = (i as i64 % 7) - 3;
@@ -320,15 +320,15 @@ where
             dsize: Dsize(1),
         };
 
-        let mut a: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_in_infos);
-        let mut res_square: GLWETensor<Vec<u8>> = GLWETensor::alloc_from_infos(&glwe_out_infos);
-        let mut res_tensor: GLWETensor<Vec<u8>> = GLWETensor::alloc_from_infos(&glwe_out_infos);
-        let mut res_relin_square: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_out_infos);
-        let mut res_relin_tensor: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_out_infos);
-        let mut pt_in: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_in_infos);
-        let mut pt_have: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
-        let mut pt_want: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
-        let mut pt_tmp: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+        let mut a: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_in_infos);
+        let mut res_square: GLWETensor<AlignedBuf> = GLWETensor::alloc_from_infos(&glwe_out_infos);
+        let mut res_tensor: GLWETensor<AlignedBuf> = GLWETensor::alloc_from_infos(&glwe_out_infos);
+        let mut res_relin_square: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_out_infos);
+        let mut res_relin_tensor: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_out_infos);
+        let mut pt_in: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_in_infos);
+        let mut pt_have: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+        let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+        let mut pt_tmp: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
 
         let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
             (module)
@@ -345,7 +345,7 @@ where
         let mut source_xe: Source = Source::new([0u8; 32]);
         let mut source_xa: Source = Source::new([0u8; 32]);
 
-        let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc(module.n().into(), rank.into());
+        let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc(module.n().into(), rank.into());
         sk.fill_ternary_prob(0.5, &mut source_xs);
 
         let mut sk_dft: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc_from_infos(&sk);
@@ -353,7 +353,7 @@ where
 
         let tsk_enc_infos = EncryptionLayout::new_from_default_sigma(tsk_infos).unwrap();
         let glwe_enc_infos = EncryptionLayout::new_from_default_sigma(glwe_in_infos).unwrap();
-        let mut tsk: GLWETensorKey<Vec<u8>> = GLWETensorKey::alloc_from_infos(&tsk_infos);
+        let mut tsk: GLWETensorKey<AlignedBuf> = GLWETensorKey::alloc_from_infos(&tsk_infos);
         module.glwe_tensor_key_encrypt_sk(
             &mut tsk,
             &sk,
@@ -467,13 +467,14 @@ where
             rank: rank.into(),
         };
 
-        let mut a: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_in_infos);
-        let mut res: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_out_infos);
-        let mut pt_a: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_in_infos);
-        let mut pt_b: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc(module.n().into(), in_base2k.into(), (2 * in_base2k).into());
-        let mut pt_have: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
-        let mut pt_want: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
-        let mut pt_tmp: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+        let mut a: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_in_infos);
+        let mut res: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_out_infos);
+        let mut pt_a: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_in_infos);
+        let mut pt_b: GLWEPlaintext<AlignedBuf> =
+            GLWEPlaintext::alloc(module.n().into(), in_base2k.into(), (2 * in_base2k).into());
+        let mut pt_have: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+        let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+        let mut pt_tmp: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
 
         let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
             (module)
@@ -485,7 +486,7 @@ where
         let mut source_xe: Source = Source::new([0u8; 32]);
         let mut source_xa: Source = Source::new([0u8; 32]);
 
-        let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc(module.n().into(), rank.into());
+        let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc(module.n().into(), rank.into());
         sk.fill_ternary_prob(0.5, &mut source_xs);
 
         let mut sk_dft: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc_from_infos(&sk);
@@ -594,13 +595,14 @@ where
             rank: rank.into(),
         };
 
-        let mut a: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_in_infos);
-        let mut res: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_out_infos);
-        let mut pt_a: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_in_infos);
-        let mut pt_b: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc(module.n().into(), in_base2k.into(), (2 * in_base2k).into());
-        let mut pt_have: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
-        let mut pt_want: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
-        let mut pt_tmp: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+        let mut a: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_in_infos);
+        let mut res: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_out_infos);
+        let mut pt_a: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_in_infos);
+        let mut pt_b: GLWEPlaintext<AlignedBuf> =
+            GLWEPlaintext::alloc(module.n().into(), in_base2k.into(), (2 * in_base2k).into());
+        let mut pt_have: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+        let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+        let mut pt_tmp: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
 
         let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
             (module)
@@ -613,7 +615,7 @@ where
         let mut source_xe: Source = Source::new([0u8; 32]);
         let mut source_xa: Source = Source::new([0u8; 32]);
 
-        let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc(module.n().into(), rank.into());
+        let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc(module.n().into(), rank.into());
         sk.fill_ternary_prob(0.5, &mut source_xs);
 
         let mut sk_dft: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc_from_infos(&sk);
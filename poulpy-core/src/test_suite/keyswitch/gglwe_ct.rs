@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow},
-    layouts::{DeviceBuf, Module, Scratch, ScratchOwned},
+    layouts::{AlignedBuf, DeviceBuf, Module, Scratch, ScratchOwned},
     source::Source,
     test_suite::TestParams,
 };
@@ -78,9 +78,9 @@ where
                         rank_out: rank_out_s1s2.into(),
                     };
 
-                    let mut gglwe_s0s1: GLWESwitchingKey<Vec<u8>> = GLWESwitchingKey::alloc_from_infos(&gglwe_s0s1_infos);
-                    let mut gglwe_s1s2: GLWESwitchingKey<Vec<u8>> = GLWESwitchingKey::alloc_from_infos(&gglwe_s1s2_infos);
-                    let mut gglwe_s0s2: GLWESwitchingKey<Vec<u8>> = GLWESwitchingKey::alloc_from_infos(&gglwe_s0s2_infos);
+                    let mut gglwe_s0s1: GLWESwitchingKey<AlignedBuf> = GLWESwitchingKey::alloc_from_infos(&gglwe_s0s1_infos);
+                    let mut gglwe_s1s2: GLWESwitchingKey<AlignedBuf> = GLWESwitchingKey::alloc_from_infos(&gglwe_s1s2_infos);
+                    let mut gglwe_s0s2: GLWESwitchingKey<AlignedBuf> = GLWESwitchingKey::alloc_from_infos(&gglwe_s0s2_infos);
 
                     let mut source_xs: Source = Source::new([0u8; 32]);
                     let mut source_xe: Source = Source::new([0u8; 32]);
@@ -96,13 +96,13 @@ where
                             | module.gglwe_noise_tmp_bytes(&gglwe_s0s2_infos),
                     );
 
-                    let mut sk0: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank_in_s0s1.into());
+                    let mut sk0: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank_in_s0s1.into());
                     sk0.fill_ternary_prob(0.5, &mut source_xs);
 
-                    let mut sk1: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank_out_s0s1.into());
+                    let mut sk1: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank_out_s0s1.into());
                     sk1.fill_ternary_prob(0.5, &mut source_xs);
 
-                    let mut sk2: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank_out_s1s2.into());
+                    let mut sk2: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank_out_s1s2.into());
                     sk2.fill_ternary_prob(0.5, &mut source_xs);
 
                     let mut sk2_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> =
@@ -221,8 +221,8 @@ where
                 })
                 .unwrap();
 
-                let mut gglwe_s0s1: GLWESwitchingKey<Vec<u8>> = GLWESwitchingKey::alloc_from_infos(&gglwe_s0s1_infos);
-                let mut gglwe_s1s2: GLWESwitchingKey<Vec<u8>> = GLWESwitchingKey::alloc_from_infos(&gglwe_s1s2_infos);
+                let mut gglwe_s0s1: GLWESwitchingKey<AlignedBuf> = GLWESwitchingKey::alloc_from_infos(&gglwe_s0s1_infos);
+                let mut gglwe_s1s2: GLWESwitchingKey<AlignedBuf> = GLWESwitchingKey::alloc_from_infos(&gglwe_s1s2_infos);
 
                 let mut source_xs: Source = Source::new([0u8; 32]);
                 let mut source_xe: Source = Source::new([0u8; 32]);
@@ -258,13 +258,13 @@ where
 
                 let var_xs: f64 = 0.5;
 
-                let mut sk0: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank_in.into());
+                let mut sk0: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank_in.into());
                 sk0.fill_ternary_prob(var_xs, &mut source_xs);
 
-                let mut sk1: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank_out.into());
+                let mut sk1: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank_out.into());
                 sk1.fill_ternary_prob(var_xs, &mut source_xs);
 
-                let mut sk2: GLWESecret<Vec<u8>> = GLWESecret::alloc(n.into(), rank_out.into());
+                let mut sk2: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank_out.into());
                 sk2.fill_ternary_prob(var_xs, &mut source_xs);
 
                 let mut sk2_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank_out.into());
@@ -306,7 +306,7 @@ where
                 );
                 module.gglwe_keyswitch_assign(&mut gglwe_s0s1, &gglwe_s1s2_prepared, scratch_apply.borrow());
 
-                let gglwe_s0s2: GLWESwitchingKey<Vec<u8>> = gglwe_s0s1;
+                let gglwe_s0s2: GLWESwitchingKey<AlignedBuf> = gglwe_s0s1;
 
                 let max_noise: f64 = log2_std_noise_gglwe_product(
                     n as f64,
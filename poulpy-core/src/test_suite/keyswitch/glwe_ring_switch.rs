@@ -0,0 +1,229 @@
+use poulpy_hal::{
+    api::{
+        ModuleNew, ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxFillUniform, VecZnxSplitRing,
+        VecZnxSplitRingTmpBytes, VecZnxSwitchRing,
+    },
+    layouts::{AlignedBuf, DeviceBuf, Module, Scratch, ScratchOwned, VecZnx, VecZnxToMut},
+    source::Source,
+    test_suite::TestParams,
+};
+
+use crate::{
+    EncryptionLayout, GLWEEncryptSk, GLWENoise, GLWERingSwitch, GLWERingSwitchKeyEncryptSk, ScratchTakeCore,
+    encryption::DEFAULT_SIGMA_XE,
+    layouts::{
+        GLWE, GLWELayout, GLWEPlaintext, GLWERingSwitchKey, GLWERingSwitchKeyLayout, GLWERingSwitchKeyPreparedFactory,
+        GLWESecret, GLWESecretPreparedFactory,
+        prepared::{GLWERingSwitchKeyPrepared, GLWESecretPrepared},
+    },
+    var_noise_gglwe_product_v2,
+};
+
+/// Switches ciphertexts from `N` to `N/2` and back, and splits/merges them,
+/// checking the decrypted messages and the noise at each step.
+pub fn test_glwe_ring_switch<BE: crate::test_suite::TestBackend>(params: &TestParams, module: &Module<BE>)
+where
+    Module<BE>: ModuleNew<BE>
+        + VecZnxFillUniform
+        + VecZnxSwitchRing
+        + VecZnxSplitRing<BE>
+        + VecZnxSplitRingTmpBytes
+        + GLWERingSwitchKeyEncryptSk<BE>
+        + GLWEEncryptSk<BE>
+        + GLWERingSwitch<BE>
+        + GLWESecretPreparedFactory<BE>
+        + GLWERingSwitchKeyPreparedFactory<BE>
+        + GLWENoise<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    let base2k: usize = params.base2k;
+    let k_ct: usize = 4 * base2k + 1;
+    let max_dsize: usize = k_ct.div_ceil(base2k);
+
+    let n: usize = module.n();
+    let n_half: usize = n >> 1;
+    let module_half: Module<BE> = Module::<BE>::new(n_half as u64);
+
+    for rank in 1_usize..3 {
+        for dsize in 1_usize..max_dsize + 1 {
+            let k_ksk: usize = k_ct + base2k * dsize;
+            let dnum: usize = k_ct.div_ceil(base2k * dsize);
+
+            let glwe_infos = |n: usize| {
+                EncryptionLayout::new_from_default_sigma(GLWELayout {
+                    n: n.into(),
+                    base2k: base2k.into(),
+                    k: k_ksk.into(),
+                    rank: rank.into(),
+                })
+                .unwrap()
+            };
+            let glwe_n_infos = glwe_infos(n);
+            let glwe_half_infos = glwe_infos(n_half);
+
+            let ksk_infos = EncryptionLayout::new_from_default_sigma(GLWERingSwitchKeyLayout {
+                n: n.into(),
+                base2k: base2k.into(),
+                k: k_ksk.into(),
+                rank: rank.into(),
+                dnum: dnum.into(),
+                dsize: dsize.into(),
+            })
+            .unwrap();
+
+            let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
+                module.glwe_ring_switch_key_encrypt_sk_tmp_bytes(&ksk_infos)
+                    | module.glwe_encrypt_sk_tmp_bytes(&glwe_n_infos)
+                    | module.glwe_ring_switch_tmp_bytes(&glwe_half_infos, &glwe_n_infos, &ksk_infos)
+                    | module.glwe_ring_switch_tmp_bytes(&glwe_n_infos, &glwe_half_infos, &ksk_infos)
+                    | module.vec_znx_split_ring_tmp_bytes(),
+            );
+            let mut scratch_half: ScratchOwned<BE> = ScratchOwned::alloc(module_half.glwe_encrypt_sk_tmp_bytes(&glwe_half_infos));
+
+            let mut source_xs: Source = Source::new([0u8; 32]);
+            let mut source_xe: Source = Source::new([0u8; 32]);
+            let mut source_xa: Source = Source::new([0u8; 32]);
+
+            let mut sk_n: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank.into());
+            sk_n.fill_ternary_prob(0.5, &mut source_xs);
+            let mut sk_n_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
+            module.glwe_secret_prepare(&mut sk_n_prepared, &sk_n);
+
+            let mut sk_half: GLWESecret<AlignedBuf> = GLWESecret::alloc(n_half.into(), rank.into());
+            sk_half.fill_ternary_prob(0.5, &mut source_xs);
+            let mut sk_half_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module_half.glwe_secret_prepared_alloc(rank.into());
+            module_half.glwe_secret_prepare(&mut sk_half_prepared, &sk_half);
+
+            let mut key_down: GLWERingSwitchKey<AlignedBuf> = GLWERingSwitchKey::alloc_from_infos(&ksk_infos);
+            module.glwe_ring_switch_key_encrypt_sk(
+                &mut key_down,
+                &sk_n,
+                &sk_half,
+                &ksk_infos,
+                &mut source_xe,
+                &mut source_xa,
+                scratch.borrow(),
+            );
+            let mut key_down_prepared: GLWERingSwitchKeyPrepared<DeviceBuf<BE>, BE> =
+                module.glwe_ring_switch_key_prepared_alloc_from_infos(&key_down);
+            module.glwe_ring_switch_key_prepare(&mut key_down_prepared, &key_down, scratch.borrow());
+
+            let mut key_up: GLWERingSwitchKey<AlignedBuf> = GLWERingSwitchKey::alloc_from_infos(&ksk_infos);
+            module.glwe_ring_switch_key_encrypt_sk(
+                &mut key_up,
+                &sk_half,
+                &sk_n,
+                &ksk_infos,
+                &mut source_xe,
+                &mut source_xa,
+                scratch.borrow(),
+            );
+            let mut key_up_prepared: GLWERingSwitchKeyPrepared<DeviceBuf<BE>, BE> =
+                module.glwe_ring_switch_key_prepared_alloc_from_infos(&key_up);
+            module.glwe_ring_switch_key_prepare(&mut key_up_prepared, &key_up, scratch.borrow());
+
+            let noise_max: f64 = var_noise_gglwe_product_v2(
+                n as f64,
+                k_ksk,
+                dnum,
+                dsize,
+                base2k,
+                0.5,
+                0.5,
+                0f64,
+                DEFAULT_SIGMA_XE * DEFAULT_SIGMA_XE,
+                0f64,
+                rank as f64,
+            )
+            .sqrt()
+            .log2()
+                + 1.0;
+
+            // Message of degree N and its even / odd halves of degree N/2.
+            let mut pt_n: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_n_infos);
+            module.vec_znx_fill_uniform(base2k, &mut pt_n.data, 0, &mut source_xa);
+            let mut pt_even: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_half_infos);
+            let mut pt_odd: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_half_infos);
+            {
+                let mut halves: [VecZnx<&mut [u8]>; 2] = [pt_even.data.to_mut(), pt_odd.data.to_mut()];
+                module.vec_znx_split_ring(&mut halves, 0, &pt_n.data, 0, scratch.borrow());
+            }
+
+            let mut ct_n: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_n_infos);
+            module.glwe_encrypt_sk(
+                &mut ct_n,
+                &pt_n,
+                &sk_n_prepared,
+                &glwe_n_infos,
+                &mut source_xe,
+                &mut source_xa,
+                scratch.borrow(),
+            );
+
+            // N -> N/2 keeps the even coefficients.
+            let mut ct_half: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_half_infos);
+            module.glwe_ring_switch(&mut ct_half, &ct_n, &key_down_prepared, scratch.borrow());
+            let noise_have: f64 = module_half
+                .glwe_noise(&ct_half, &pt_even, &sk_half_prepared, scratch.borrow())
+                .std()
+                .log2();
+            assert!(
+                noise_have <= noise_max,
+                "down: noise_have: {noise_have} > noise_max: {noise_max}"
+            );
+
+            // N -> 2 x N/2 -> N.
+            let mut ct_even: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_half_infos);
+            let mut ct_odd: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_half_infos);
+            module.glwe_ring_split(&mut ct_even, &mut ct_odd, &ct_n, &key_down_prepared, scratch.borrow());
+            for (ct, pt) in [(&ct_even, &pt_even), (&ct_odd, &pt_odd)] {
+                let noise_have: f64 = module_half
+                    .glwe_noise(ct, pt, &sk_half_prepared, scratch.borrow())
+                    .std()
+                    .log2();
+                assert!(
+                    noise_have <= noise_max,
+                    "split: noise_have: {noise_have} > noise_max: {noise_max}"
+                );
+            }
+
+            let mut ct_merged: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_n_infos);
+            module.glwe_ring_merge(&mut ct_merged, &ct_even, &ct_odd, &key_up_prepared, scratch.borrow());
+            let noise_have: f64 = module
+                .glwe_noise(&ct_merged, &pt_n, &sk_n_prepared, scratch.borrow())
+                .std()
+                .log2();
+            assert!(
+                noise_have <= noise_max + 0.5,
+                "merge: noise_have: {noise_have} > noise_max: {}",
+                noise_max + 0.5
+            );
+
+            // N/2 -> N embeds m(Y) as m(X^2).
+            let mut ct_even_fresh: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_half_infos);
+            module_half.glwe_encrypt_sk(
+                &mut ct_even_fresh,
+                &pt_even,
+                &sk_half_prepared,
+                &glwe_half_infos,
+                &mut source_xe,
+                &mut source_xa,
+                scratch_half.borrow(),
+            );
+            let mut pt_embedded: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_n_infos);
+            module.vec_znx_switch_ring(&mut pt_embedded.data, 0, &pt_even.data, 0);
+
+            let mut ct_up: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_n_infos);
+            module.glwe_ring_switch(&mut ct_up, &ct_even_fresh, &key_up_prepared, scratch.borrow());
+            let noise_have: f64 = module
+                .glwe_noise(&ct_up, &pt_embedded, &sk_n_prepared, scratch.borrow())
+                .std()
+                .log2();
+            assert!(
+                noise_have <= noise_max,
+                "up: noise_have: {noise_have} > noise_max: {noise_max}"
+            );
+        }
+    }
+}
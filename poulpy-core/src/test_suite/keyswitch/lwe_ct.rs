@@ -1,6 +1,6 @@
 use poulpy_hal::{
     api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxNormalize},
-    layouts::{DeviceBuf, Module, Scratch, ScratchOwned, ZnxView},
+    layouts::{AlignedBuf, DeviceBuf, Module, Scratch, ScratchOwned, ZnxView},
     source::Source,
     test_suite::TestParams,
 };
@@ -68,18 +68,18 @@ where
             | module.lwe_keyswitch_tmp_bytes(&lwe_out_infos, &lwe_in_infos, &key_apply_infos),
     );
 
-    let mut sk_lwe_in: LWESecret<Vec<u8>> = LWESecret::alloc(n_lwe_in.into());
+    let mut sk_lwe_in: LWESecret<AlignedBuf> = LWESecret::alloc(n_lwe_in.into());
     sk_lwe_in.fill_ternary_prob(0.5, &mut source_xs);
 
-    let mut sk_lwe_out: LWESecret<Vec<u8>> = LWESecret::alloc(n_lwe_out.into());
+    let mut sk_lwe_out: LWESecret<AlignedBuf> = LWESecret::alloc(n_lwe_out.into());
     sk_lwe_out.fill_ternary_prob(0.5, &mut source_xs);
 
     let data: i64 = 17;
 
-    let mut lwe_pt_in: LWEPlaintext<Vec<u8>> = LWEPlaintext::alloc(in_base2k.into(), k_lwe_pt.into());
+    let mut lwe_pt_in: LWEPlaintext<AlignedBuf> = LWEPlaintext::alloc(in_base2k.into(), k_lwe_pt.into());
     lwe_pt_in.encode_i64(data, k_lwe_pt.into());
 
-    let mut lwe_ct_in: LWE<Vec<u8>> = LWE::alloc_from_infos(&lwe_in_infos);
+    let mut lwe_ct_in: LWE<AlignedBuf> = LWE::alloc_from_infos(&lwe_in_infos);
     module.lwe_encrypt_sk(
         &mut lwe_ct_in,
         &lwe_pt_in,
@@ -90,7 +90,7 @@ where
         scratch.borrow(),
     );
 
-    let mut ksk: LWESwitchingKey<Vec<u8>> = LWESwitchingKey::alloc_from_infos(&key_apply_infos);
+    let mut ksk: LWESwitchingKey<AlignedBuf> = LWESwitchingKey::alloc_from_infos(&key_apply_infos);
 
     module.lwe_switching_key_encrypt_sk(
         &mut ksk,
@@ -102,17 +102,17 @@ where
         scratch.borrow(),
     );
 
-    let mut lwe_ct_out: LWE<Vec<u8>> = LWE::alloc_from_infos(&lwe_out_infos);
+    let mut lwe_ct_out: LWE<AlignedBuf> = LWE::alloc_from_infos(&lwe_out_infos);
 
     let mut ksk_prepared: LWESwitchingKeyPrepared<DeviceBuf<BE>, BE> = module.lwe_switching_key_prepared_alloc_from_infos(&ksk);
     module.lwe_switching_key_prepare(&mut ksk_prepared, &ksk, scratch.borrow());
 
     module.lwe_keyswitch(&mut lwe_ct_out, &lwe_ct_in, &ksk_prepared, scratch.borrow());
 
-    let mut lwe_pt_out: LWEPlaintext<Vec<u8>> = LWEPlaintext::alloc_from_infos(&lwe_out_infos);
+    let mut lwe_pt_out: LWEPlaintext<AlignedBuf> = LWEPlaintext::alloc_from_infos(&lwe_out_infos);
     module.lwe_decrypt(&lwe_ct_out, &mut lwe_pt_out, &sk_lwe_out, scratch.borrow());
 
-    let mut lwe_pt_want: LWEPlaintext<Vec<u8>> = LWEPlaintext::alloc_from_infos(&lwe_out_infos);
+    let mut lwe_pt_want: LWEPlaintext<AlignedBuf> = LWEPlaintext::alloc_from_infos(&lwe_out_infos);
     module.vec_znx_normalize(
         lwe_pt_want.data_mut(),
         out_base2k,
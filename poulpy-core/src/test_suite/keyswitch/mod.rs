@@ -1,9 +1,11 @@
 mod gglwe_ct;
 mod ggsw_ct;
 mod glwe_ct;
+mod glwe_ring_switch;
 mod lwe_ct;
 
 pub use gglwe_ct::*;
 pub use ggsw_ct::*;
 pub use glwe_ct::*;
+pub use glwe_ring_switch::*;
 pub use lwe_ct::*;
@@ -4,15 +4,29 @@ pub mod external_product;
 pub mod glwe_tensor;
 pub mod keyswitch;
 
+mod compression;
 mod conversion;
+mod fallible;
 mod glwe_packer;
 mod glwe_packing;
+mod noise;
+mod persistence;
+mod sanitize;
+mod scratch;
 mod trace;
+mod truncate;
 
+pub use compression::*;
 pub use conversion::*;
+pub use fallible::*;
 pub use glwe_packer::*;
 pub use glwe_packing::*;
+pub use noise::*;
+pub use persistence::*;
+pub use sanitize::*;
+pub use scratch::*;
 pub use trace::*;
+pub use truncate::*;
 
 use crate::oep::CoreImpl;
 use poulpy_hal::layouts::Backend;
@@ -21,6 +35,31 @@ pub trait TestBackend: Backend + CoreImpl<Self> {}
 
 impl<BE> TestBackend for BE where BE: Backend + CoreImpl<BE> {}
 
+/// Largest gap, in bits, allowed between a measured noise standard deviation
+/// and the one predicted by [`crate::noise::NoiseModel`].
+pub const NOISE_TOLERANCE_LOG2: f64 = 0.5;
+
+/// Asserts that the measured noise `have` agrees with the predicted noise
+/// `want`, both given as `log2` of a standard deviation, within
+/// [`NOISE_TOLERANCE_LOG2`].
+#[track_caller]
+pub fn assert_noise_matches(label: &str, have: f64, want: f64) {
+    assert!(
+        (have - want).abs() <= NOISE_TOLERANCE_LOG2,
+        "{label}: measured noise {have} vs predicted {want}"
+    );
+}
+
+/// Asserts that the measured noise `have` does not exceed the predicted
+/// bound `want` by more than [`NOISE_TOLERANCE_LOG2`].
+#[track_caller]
+pub fn assert_noise_below(label: &str, have: f64, want: f64) {
+    assert!(
+        have <= want + NOISE_TOLERANCE_LOG2,
+        "{label}: measured noise {have} above predicted {want}"
+    );
+}
+
 #[macro_export]
 macro_rules! core_backend_test_suite {
     (
@@ -34,6 +73,7 @@ macro_rules! core_backend_test_suite {
             params = $params,
             tests = {
                 glwe_encrypt_sk => $crate::test_suite::encryption::test_glwe_encrypt_sk,
+                glwe_encrypt_sk_os_entropy => $crate::test_suite::encryption::test_glwe_encrypt_sk_os_entropy,
                 glwe_compressed_encrypt_sk => $crate::test_suite::encryption::test_glwe_compressed_encrypt_sk,
                 glwe_encrypt_zero_sk => $crate::test_suite::encryption::test_glwe_encrypt_zero_sk,
                 glwe_encrypt_pk => $crate::test_suite::encryption::test_glwe_encrypt_pk,
@@ -45,12 +85,15 @@ macro_rules! core_backend_test_suite {
                 test_glwe_mul_const => $crate::test_suite::glwe_tensor::test_glwe_mul_const,
                 glwe_keyswitch => $crate::test_suite::keyswitch::test_glwe_keyswitch,
                 glwe_keyswitch_assign => $crate::test_suite::keyswitch::test_glwe_keyswitch_assign,
+                glwe_ring_switch => $crate::test_suite::keyswitch::test_glwe_ring_switch,
                 glwe_automorphism => $crate::test_suite::automorphism::test_glwe_automorphism,
                 glwe_automorphism_assign => $crate::test_suite::automorphism::test_glwe_automorphism_assign,
                 glwe_external_product => $crate::test_suite::external_product::test_glwe_external_product,
                 glwe_external_product_assign => $crate::test_suite::external_product::test_glwe_external_product_assign,
+                glwe_external_product_pooled => $crate::test_suite::external_product::test_glwe_external_product_pooled,
                 glwe_trace_assign => $crate::test_suite::test_glwe_trace_assign,
                 glwe_packing => $crate::test_suite::test_glwe_packing,
+                glwe_pack_lwes => $crate::test_suite::test_glwe_pack_lwes,
                 glwe_packer => $crate::test_suite::test_glwe_packer,
                 gglwe_switching_key_encrypt_sk => $crate::test_suite::encryption::test_gglwe_switching_key_encrypt_sk,
                 gglwe_switching_key_compressed_encrypt_sk =>
@@ -74,6 +117,7 @@ macro_rules! core_backend_test_suite {
                 gglwe_automorphism_key_automorphism_assign =>
                     $crate::test_suite::automorphism::test_gglwe_automorphism_key_automorphism_assign,
                 ggsw_encrypt_sk => $crate::test_suite::encryption::test_ggsw_encrypt_sk,
+                ggsw_encrypt_pk => $crate::test_suite::encryption::test_ggsw_encrypt_pk,
                 ggsw_compressed_encrypt_sk => $crate::test_suite::encryption::test_ggsw_compressed_encrypt_sk,
                 ggsw_keyswitch => $crate::test_suite::keyswitch::test_ggsw_keyswitch,
                 ggsw_keyswitch_assign => $crate::test_suite::keyswitch::test_ggsw_keyswitch_assign,
@@ -84,6 +128,17 @@ macro_rules! core_backend_test_suite {
                 lwe_keyswitch => $crate::test_suite::keyswitch::test_lwe_keyswitch,
                 glwe_to_lwe => $crate::test_suite::test_glwe_to_lwe,
                 lwe_to_glwe => $crate::test_suite::test_lwe_to_glwe,
+                noise_tracker => $crate::test_suite::test_noise_tracker,
+                noise_tracker_tensor => $crate::test_suite::test_noise_tracker_tensor,
+                noise_tracker_blind_rotation => $crate::test_suite::test_noise_tracker_blind_rotation,
+                prepared_persistence => $crate::test_suite::test_prepared_persistence,
+                fallible => $crate::test_suite::test_fallible,
+                zstd_compressed_keys => $crate::test_suite::test_zstd_compressed_keys,
+                take_lwe => $crate::test_suite::test_take_lwe,
+                glwe_sanitize => $crate::test_suite::test_glwe_sanitize,
+                lwe_sanitize => $crate::test_suite::test_lwe_sanitize,
+                glwe_truncate => $crate::test_suite::test_glwe_truncate,
+                lwe_truncate => $crate::test_suite::test_lwe_truncate,
             }
         );
     };
@@ -0,0 +1,472 @@
+use poulpy_hal::{
+    api::{
+        ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxCopy, VecZnxFillUniform, VecZnxNormalizeAssign,
+        VecZnxRotateAssign,
+    },
+    layouts::{AlignedBuf, DeviceBuf, Module, ScalarZnx, Scratch, ScratchOwned, ZnxViewMut},
+    source::Source,
+    test_suite::{TestParams, convolution::bivariate_convolution_naive},
+};
+
+use crate::{
+    EncryptionLayout, GGSWEncryptSk, GLWEAdd, GLWEEncryptSk, GLWEExternalProduct, GLWEKeyswitch, GLWEMulXpMinusOne, GLWENoise,
+    GLWENormalize, GLWESub, GLWESwitchingKeyEncryptSk, GLWETensorDecrypt, GLWETensorKeyEncryptSk, GLWETensoring, ScratchTakeCore,
+    encryption::DEFAULT_SIGMA_XE,
+    layouts::{
+        Dsize, GGSW, GGSWLayout, GGSWPreparedFactory, GLWE, GLWELayout, GLWEPlaintext, GLWESecret, GLWESecretPreparedFactory,
+        GLWESecretTensor, GLWESecretTensorFactory, GLWESecretTensorPrepared, GLWESecretTensorPreparedFactory, GLWESwitchingKey,
+        GLWESwitchingKeyLayout, GLWESwitchingKeyPreparedFactory, GLWETensor, GLWETensorKey, GLWETensorKeyLayout,
+        GLWETensorKeyPrepared, GLWETensorKeyPreparedFactory, LWEInfos, TorusPrecision,
+        prepared::{GGSWPrepared, GLWESecretPrepared, GLWESwitchingKeyPrepared},
+    },
+    noise::{NoiseModel, NoiseTracker},
+    test_suite::assert_noise_matches,
+};
+
+/// Follows a fresh encryption through a keyswitch and an external product and
+/// checks that [`NoiseTracker`] matches the noise measured by [`GLWENoise::glwe_noise`].
+pub fn test_noise_tracker<BE: crate::test_suite::TestBackend>(params: &TestParams, module: &Module<BE>)
+where
+    Module<BE>: VecZnxFillUniform
+        + VecZnxRotateAssign<BE>
+        + GLWESwitchingKeyEncryptSk<BE>
+        + GLWESwitchingKeyPreparedFactory<BE>
+        + GGSWEncryptSk<BE>
+        + GGSWPreparedFactory<BE>
+        + GLWEEncryptSk<BE>
+        + GLWEKeyswitch<BE>
+        + GLWEExternalProduct<BE>
+        + GLWESecretPreparedFactory<BE>
+        + GLWENoise<BE>
+        + GLWENormalize<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    let base2k: usize = params.base2k;
+    let k_in: usize = 4 * base2k;
+    let n: usize = module.n();
+
+    for rank in 1_usize..3 {
+        for dsize in 1_usize..3 {
+            let dnum: usize = k_in.div_ceil(base2k * dsize);
+            let k_key: usize = k_in + base2k * dsize;
+            let k_out: usize = k_key + base2k;
+
+            let glwe_in_infos = EncryptionLayout::new_from_default_sigma(GLWELayout {
+                n: n.into(),
+                base2k: base2k.into(),
+                k: k_in.into(),
+                rank: rank.into(),
+            })
+            .unwrap();
+
+            let glwe_out_infos: GLWELayout = GLWELayout {
+                n: n.into(),
+                base2k: base2k.into(),
+                k: k_out.into(),
+                rank: rank.into(),
+            };
+
+            let ksk_infos = EncryptionLayout::new_from_default_sigma(GLWESwitchingKeyLayout {
+                n: n.into(),
+                base2k: base2k.into(),
+                k: k_key.into(),
+                dnum: dnum.into(),
+                dsize: dsize.into(),
+                rank_in: rank.into(),
+                rank_out: rank.into(),
+            })
+            .unwrap();
+
+            let ggsw_infos = EncryptionLayout::new_from_default_sigma(GGSWLayout {
+                n: n.into(),
+                base2k: base2k.into(),
+                k: k_key.into(),
+                dnum: dnum.into(),
+                dsize: dsize.into(),
+                rank: rank.into(),
+            })
+            .unwrap();
+
+            let mut source_xs: Source = Source::new([0u8; 32]);
+            let mut source_xe: Source = Source::new([0u8; 32]);
+            let mut source_xa: Source = Source::new([0u8; 32]);
+
+            let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
+                module.glwe_switching_key_encrypt_sk_tmp_bytes(&ksk_infos)
+                    | module.ggsw_encrypt_sk_tmp_bytes(&ggsw_infos)
+                    | module.glwe_encrypt_sk_tmp_bytes(&glwe_in_infos)
+                    | module.glwe_keyswitch_tmp_bytes(&glwe_out_infos, &glwe_in_infos, &ksk_infos)
+                    | module.glwe_external_product_tmp_bytes(&glwe_out_infos, &glwe_out_infos, &ggsw_infos)
+                    | module.glwe_noise_tmp_bytes(&glwe_out_infos),
+            );
+
+            let mut sk_in: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank.into());
+            sk_in.fill_ternary_prob(0.5, &mut source_xs);
+            let mut sk_in_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
+            module.glwe_secret_prepare(&mut sk_in_prepared, &sk_in);
+
+            let mut sk_out: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank.into());
+            sk_out.fill_ternary_prob(0.5, &mut source_xs);
+            let mut sk_out_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
+            module.glwe_secret_prepare(&mut sk_out_prepared, &sk_out);
+
+            let mut ksk: GLWESwitchingKey<AlignedBuf> = GLWESwitchingKey::alloc_from_infos(&ksk_infos);
+            module.glwe_switching_key_encrypt_sk(
+                &mut ksk,
+                &sk_in,
+                &sk_out,
+                &ksk_infos,
+                &mut source_xe,
+                &mut source_xa,
+                scratch.borrow(),
+            );
+            let mut ksk_prepared: GLWESwitchingKeyPrepared<DeviceBuf<BE>, BE> =
+                module.glwe_switching_key_prepared_alloc_from_infos(&ksk);
+            module.glwe_switching_key_prepare(&mut ksk_prepared, &ksk, scratch.borrow());
+
+            let rot: usize = (n >> 1) + 1;
+            let mut pt_ggsw: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
+            pt_ggsw.raw_mut()[rot] = 1; // X^{rot}
+
+            let mut ggsw: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_infos);
+            module.ggsw_encrypt_sk(
+                &mut ggsw,
+                &pt_ggsw,
+                &sk_out_prepared,
+                &ggsw_infos,
+                &mut source_xe,
+                &mut source_xa,
+                scratch.borrow(),
+            );
+            let mut ggsw_prepared: GGSWPrepared<DeviceBuf<BE>, BE> = module.ggsw_prepared_alloc_from_infos(&ggsw);
+            module.ggsw_prepare(&mut ggsw_prepared, &ggsw, scratch.borrow());
+
+            let mut pt_in: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_in_infos);
+            let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_out_infos);
+            module.vec_znx_fill_uniform(pt_in.base2k().into(), &mut pt_in.data, 0, &mut source_xa);
+
+            let mut glwe_in: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_in_infos);
+            let mut glwe_ks: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_out_infos);
+            let mut glwe_ep: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_out_infos);
+
+            let model: NoiseModel = NoiseModel::from_secret(&sk_in, DEFAULT_SIGMA_XE);
+            let mut tracker: NoiseTracker = NoiseTracker::fresh(model, &glwe_in_infos.noise);
+
+            let check = |step: &str, have: f64, want: f64| {
+                assert_noise_matches(&format!("rank={rank} dsize={dsize} {step}"), have, want);
+            };
+
+            module.glwe_encrypt_sk(
+                &mut glwe_in,
+                &pt_in,
+                &sk_in_prepared,
+                &glwe_in_infos,
+                &mut source_xe,
+                &mut source_xa,
+                scratch.borrow(),
+            );
+            let have: f64 = module
+                .glwe_noise(&glwe_in, &pt_in, &sk_in_prepared, scratch.borrow())
+                .std()
+                .log2();
+            check("encrypt_sk", have, tracker.log2_std());
+
+            module.glwe_keyswitch(&mut glwe_ks, &glwe_in, &ksk_prepared, scratch.borrow());
+            tracker.keyswitch(&glwe_in, &ksk_infos);
+            module.glwe_normalize(&mut pt_want, &pt_in, scratch.borrow());
+            let have: f64 = module
+                .glwe_noise(&glwe_ks, &pt_want, &sk_out_prepared, scratch.borrow())
+                .std()
+                .log2();
+            check("keyswitch", have, tracker.log2_std());
+
+            module.glwe_external_product(&mut glwe_ep, &glwe_ks, &ggsw_prepared, scratch.borrow());
+            tracker.external_product(1.0, &glwe_ks, &ggsw_infos);
+            module.vec_znx_rotate_assign(rot as i64, &mut pt_want.data, 0, scratch.borrow());
+            let have: f64 = module
+                .glwe_noise(&glwe_ep, &pt_want, &sk_out_prepared, scratch.borrow())
+                .std()
+                .log2();
+            check("external_product", have, tracker.log2_std());
+
+            assert_eq!(tracker.steps().len(), 3);
+
+            // A bound well above the noise never fails, one well below always does.
+            assert!(tracker.log2_failure_probability((-tracker.log2_std()) as usize - 4) < -128.0);
+            assert!(tracker.failure_probability((-tracker.log2_std()) as usize + 8) > 0.99);
+        }
+    }
+}
+
+/// Tensors two fresh encryptions, relinearizes the product, and checks that
+/// [`NoiseModel::glwe_tensor`] and [`NoiseModel::glwe_tensor_relinearize`]
+/// match the noise measured after each step.
+pub fn test_noise_tracker_tensor<BE: crate::test_suite::TestBackend>(params: &TestParams, module: &Module<BE>)
+where
+    Module<BE>: GLWETensoring<BE>
+        + GLWEEncryptSk<BE>
+        + GLWETensorDecrypt<BE>
+        + GLWESecretPreparedFactory<BE>
+        + GLWESecretTensorFactory<BE>
+        + GLWESecretTensorPreparedFactory<BE>
+        + GLWETensorKeyEncryptSk<BE>
+        + GLWETensorKeyPreparedFactory<BE>
+        + GLWENoise<BE>
+        + GLWESub
+        + VecZnxNormalizeAssign<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    let base2k: usize = params.base2k;
+    let k: usize = 6 * base2k;
+    let k_tsk: usize = k + base2k;
+    let n: usize = module.n();
+
+    // The tensor key decomposes only the top half of the tensor, so the
+    // relinearization truncation noise dominates the tensoring noise and
+    // both steps of the model are exercised.
+    // Messages are encoded on two limbs and the product is shifted back by as
+    // many bits, so the tensor holds `m_a * m_b` at the same scale.
+    let scale: usize = 2 * base2k;
+    let msg_sq: f64 = 5.5 * (-2.0 * scale as f64).exp2();
+
+    for rank in 1_usize..3 {
+        let glwe_infos = EncryptionLayout::new_from_default_sigma(GLWELayout {
+            n: n.into(),
+            base2k: base2k.into(),
+            k: k.into(),
+            rank: rank.into(),
+        })
+        .unwrap();
+
+        let tsk_infos = EncryptionLayout::new_from_default_sigma(GLWETensorKeyLayout {
+            n: n.into(),
+            base2k: base2k.into(),
+            k: k_tsk.into(),
+            rank: rank.into(),
+            dnum: k.div_ceil(2 * base2k).into(),
+            dsize: Dsize(1),
+        })
+        .unwrap();
+
+        let mut a: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
+        let mut b: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
+        let mut res_tensor: GLWETensor<AlignedBuf> = GLWETensor::alloc_from_infos(&glwe_infos);
+        let mut res_relin: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
+        let mut pt_a: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+        let mut pt_b: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+        let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+        let mut pt_have: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+        let mut pt_diff: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+
+        let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
+            module
+                .glwe_encrypt_sk_tmp_bytes(&glwe_infos)
+                .max(module.glwe_secret_tensor_prepare_tmp_bytes(rank.into()))
+                .max(module.glwe_tensor_key_encrypt_sk_tmp_bytes(&tsk_infos))
+                .max(module.prepare_tensor_key_tmp_bytes(&tsk_infos))
+                .max(module.glwe_tensor_apply_tmp_bytes(&res_tensor, &a, &b))
+                .max(module.glwe_tensor_relinearize_tmp_bytes(&res_relin, &res_tensor, &tsk_infos))
+                .max(module.glwe_tensor_decrypt_tmp_bytes(&glwe_infos))
+                .max(module.glwe_noise_tmp_bytes(&glwe_infos)),
+        );
+
+        let mut source_xs: Source = Source::new([0u8; 32]);
+        let mut source_xe: Source = Source::new([0u8; 32]);
+        let mut source_xa: Source = Source::new([0u8; 32]);
+
+        let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank.into());
+        sk.fill_ternary_prob(0.5, &mut source_xs);
+        let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
+        module.glwe_secret_prepare(&mut sk_prepared, &sk);
+
+        let mut sk_tensor: GLWESecretTensor<AlignedBuf> = GLWESecretTensor::alloc(n.into(), rank.into());
+        module.glwe_secret_tensor_prepare(&mut sk_tensor, &sk, scratch.borrow());
+        let mut sk_tensor_prepared: GLWESecretTensorPrepared<DeviceBuf<BE>, BE> =
+            module.glwe_secret_tensor_prepared_alloc(rank.into());
+        module.glwe_secret_tensor_prepared_prepare(&mut sk_tensor_prepared, &sk_tensor);
+
+        let mut tsk: GLWETensorKey<AlignedBuf> = GLWETensorKey::alloc_from_infos(&tsk_infos);
+        module.glwe_tensor_key_encrypt_sk(&mut tsk, &sk, &tsk_infos, &mut source_xe, &mut source_xa, scratch.borrow());
+        let mut tsk_prepared: GLWETensorKeyPrepared<DeviceBuf<BE>, BE> = module.alloc_tensor_key_prepared_from_infos(&tsk_infos);
+        module.prepare_tensor_key(&mut tsk_prepared, &tsk, scratch.borrow());
+
+        let mut data: Vec<i64> = vec![0i64; n];
+        data.iter_mut().for_each(|x| *x = (source_xa.next_i64() & 7) - 4);
+        pt_a.encode_vec_i64(&data, TorusPrecision(scale as u32));
+        data.iter_mut().for_each(|x| *x = (source_xa.next_i64() & 7) - 4);
+        pt_b.encode_vec_i64(&data, TorusPrecision(scale as u32));
+
+        bivariate_convolution_naive(
+            module,
+            base2k,
+            (scale / base2k) as i64,
+            pt_want.data_mut(),
+            0,
+            pt_a.data(),
+            0,
+            pt_b.data(),
+            0,
+            scratch.borrow(),
+        );
+
+        module.glwe_encrypt_sk(
+            &mut a,
+            &pt_a,
+            &sk_prepared,
+            &glwe_infos,
+            &mut source_xe,
+            &mut source_xa,
+            scratch.borrow(),
+        );
+        module.glwe_encrypt_sk(
+            &mut b,
+            &pt_b,
+            &sk_prepared,
+            &glwe_infos,
+            &mut source_xe,
+            &mut source_xa,
+            scratch.borrow(),
+        );
+
+        let model: NoiseModel = NoiseModel::from_secret(&sk, DEFAULT_SIGMA_XE);
+        let var_fresh: f64 = NoiseTracker::fresh(model, &glwe_infos.noise).variance();
+
+        module.glwe_tensor_apply(scale, &mut res_tensor, &a, k, &b, k, scratch.borrow());
+        let var_tensor: f64 = model.glwe_tensor(&res_tensor, scale, var_fresh, msg_sq, var_fresh, msg_sq);
+        module.glwe_tensor_decrypt(&res_tensor, &mut pt_have, &sk_prepared, &sk_tensor_prepared, scratch.borrow());
+        module.glwe_sub(&mut pt_diff, &pt_have, &pt_want);
+        module.vec_znx_normalize_assign(base2k, &mut pt_diff.data, 0, scratch.borrow());
+        let have: f64 = pt_diff.stats().std().log2();
+        assert_noise_matches(&format!("rank={rank} tensor"), have, 0.5 * var_tensor.log2());
+
+        module.glwe_tensor_relinearize(
+            &mut res_relin,
+            &res_tensor,
+            &tsk_prepared,
+            tsk_prepared.size(),
+            scratch.borrow(),
+        );
+        let var_relin: f64 = model.glwe_tensor_relinearize(var_tensor, &res_relin, &tsk_infos);
+        let have: f64 = module
+            .glwe_noise(&res_relin, &pt_want, &sk_prepared, scratch.borrow())
+            .std()
+            .log2();
+        assert_noise_matches(&format!("rank={rank} tensor_relinearize"), have, 0.5 * var_relin.log2());
+    }
+}
+
+/// Runs a CGGI-style blind rotation, written out as CMux steps
+/// `acc += (X^a_i - 1) * (acc (x) GGSW(s_i))` on a normalized accumulator,
+/// from a trivial accumulator, and checks that [`NoiseModel::blind_rotation`]
+/// matches the measured noise.
+pub fn test_noise_tracker_blind_rotation<BE: crate::test_suite::TestBackend>(params: &TestParams, module: &Module<BE>)
+where
+    Module<BE>: VecZnxCopy
+        + VecZnxRotateAssign<BE>
+        + GGSWEncryptSk<BE>
+        + GGSWPreparedFactory<BE>
+        + GLWEExternalProduct<BE>
+        + GLWESecretPreparedFactory<BE>
+        + GLWEMulXpMinusOne<BE>
+        + GLWEAdd
+        + GLWENormalize<BE>
+        + GLWENoise<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    let base2k: usize = params.base2k;
+    let k_acc: usize = 4 * base2k;
+    let k_brk: usize = k_acc + base2k;
+    let k_pt: usize = 8;
+    let lwe_n: usize = 32;
+    let n: usize = module.n();
+
+    for rank in 1_usize..3 {
+        let acc_infos: GLWELayout = GLWELayout {
+            n: n.into(),
+            base2k: base2k.into(),
+            k: k_acc.into(),
+            rank: rank.into(),
+        };
+
+        let brk_infos = EncryptionLayout::new_from_default_sigma(GGSWLayout {
+            n: n.into(),
+            base2k: base2k.into(),
+            k: k_brk.into(),
+            dnum: k_acc.div_ceil(base2k).into(),
+            dsize: Dsize(1),
+            rank: rank.into(),
+        })
+        .unwrap();
+
+        let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
+            module
+                .ggsw_encrypt_sk_tmp_bytes(&brk_infos)
+                .max(module.glwe_external_product_tmp_bytes(&acc_infos, &acc_infos, &brk_infos))
+                .max(module.glwe_normalize_tmp_bytes())
+                .max(module.glwe_noise_tmp_bytes(&acc_infos)),
+        );
+
+        let mut source_xs: Source = Source::new([0u8; 32]);
+        let mut source_xe: Source = Source::new([0u8; 32]);
+        let mut source_xa: Source = Source::new([0u8; 32]);
+
+        let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank.into());
+        sk.fill_ternary_prob(0.5, &mut source_xs);
+        let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
+        module.glwe_secret_prepare(&mut sk_prepared, &sk);
+
+        // One GGSW per bit of the LWE secret, and the rotation of the LWE mask.
+        let mut brk: Vec<GGSWPrepared<DeviceBuf<BE>, BE>> = Vec::with_capacity(lwe_n);
+        let mut rotation: usize = 0;
+        let mut steps: Vec<i64> = Vec::with_capacity(lwe_n);
+        let mut pt_bit: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, 1);
+        let mut ggsw: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&brk_infos);
+        for _ in 0..lwe_n {
+            let bit: i64 = (source_xs.next_u64n(2, 1) & 1) as i64;
+            let a_i: usize = source_xa.next_u64n(2 * n as u64, (2 * n - 1) as u64) as usize;
+            pt_bit.raw_mut()[0] = bit;
+            module.ggsw_encrypt_sk(
+                &mut ggsw,
+                &pt_bit,
+                &sk_prepared,
+                &brk_infos,
+                &mut source_xe,
+                &mut source_xa,
+                scratch.borrow(),
+            );
+            let mut ggsw_prepared: GGSWPrepared<DeviceBuf<BE>, BE> = module.ggsw_prepared_alloc_from_infos(&ggsw);
+            module.ggsw_prepare(&mut ggsw_prepared, &ggsw, scratch.borrow());
+            brk.push(ggsw_prepared);
+            steps.push(a_i as i64);
+            rotation += a_i * bit as usize;
+        }
+
+        let mut data: Vec<i64> = vec![0i64; n];
+        data.iter_mut().for_each(|x| *x = (source_xa.next_i64() & 255) - 128);
+        let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&acc_infos);
+        pt_want.encode_vec_i64(&data, TorusPrecision(k_pt as u32));
+
+        // Trivial accumulator: zero mask and the test vector in the body.
+        let mut acc: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&acc_infos);
+        module.vec_znx_copy(&mut acc.data, 0, &pt_want.data, 0);
+        let mut tmp: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&acc_infos);
+        let mut cmux: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&acc_infos);
+        for (a_i, ggsw_i) in steps.iter().zip(brk.iter()) {
+            module.glwe_external_product(&mut tmp, &acc, ggsw_i, scratch.borrow());
+            module.glwe_mul_xp_minus_one(*a_i, &mut cmux, &tmp);
+            module.glwe_add_assign(&mut acc, &cmux);
+            module.glwe_normalize_assign(&mut acc, scratch.borrow());
+        }
+        module.vec_znx_rotate_assign(rotation as i64, &mut pt_want.data, 0, scratch.borrow());
+
+        let model: NoiseModel = NoiseModel::from_secret(&sk, DEFAULT_SIGMA_XE);
+        let mut tracker: NoiseTracker = NoiseTracker::new(model, 0.0);
+        tracker.blind_rotation(lwe_n, &acc_infos, &brk_infos);
+
+        let have: f64 = module.glwe_noise(&acc, &pt_want, &sk_prepared, scratch.borrow()).std().log2();
+        assert_noise_matches(&format!("rank={rank} blind_rotation"), have, tracker.log2_std());
+    }
+}
@@ -0,0 +1,115 @@
+use poulpy_hal::{
+    api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow},
+    layouts::{AlignedBuf, DeviceBuf, DigestU64, FillUniform, Module, ReaderFrom, Scratch, ScratchOwned, WriterTo},
+    source::Source,
+    test_suite::TestParams,
+};
+
+use crate::{
+    GetDistribution,
+    layouts::{
+        Degree, GGSW, GGSWInfos, GGSWLayout, GGSWPreparedFactory, GLWEAutomorphismKey, GLWEAutomorphismKeyPreparedFactory,
+        GLWESecret, GLWESecretPreparedFactory, GetGaloisElement, LWEInfos, SetGaloisElement,
+        prepared::{GGSWPrepared, GLWEAutomorphismKeyPrepared, GLWESecretPrepared},
+    },
+};
+
+/// Persists prepared keys and loads them back, through [`ReaderFrom`] and
+/// through the zero-copy `from_bytes`, checking that the loaded keys match
+/// the persisted ones and that loading for another ring degree fails.
+pub fn test_prepared_persistence<BE: crate::test_suite::TestBackend>(params: &TestParams, module: &Module<BE>)
+where
+    Module<BE>: GLWESecretPreparedFactory<BE> + GGSWPreparedFactory<BE> + GLWEAutomorphismKeyPreparedFactory<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable,
+{
+    let base2k: usize = params.base2k;
+    let n: usize = module.n();
+    let rank: usize = 2;
+    let k: usize = 4 * base2k + 1;
+
+    let ggsw_infos: GGSWLayout = GGSWLayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k.into(),
+        dnum: 2_usize.into(),
+        dsize: 1_usize.into(),
+        rank: rank.into(),
+    };
+
+    let mut source: Source = Source::new([0u8; 32]);
+
+    let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc(n.into(), rank.into());
+    sk.fill_ternary_prob(0.5, &mut source);
+    let mut ggsw: GGSW<AlignedBuf> = GGSW::alloc_from_infos(&ggsw_infos);
+    ggsw.fill_uniform(base2k, &mut source);
+    let mut atk: GLWEAutomorphismKey<AlignedBuf> =
+        GLWEAutomorphismKey::alloc(n.into(), base2k.into(), k.into(), rank.into(), 2_usize.into(), 1_usize.into());
+    atk.fill_uniform(base2k, &mut source);
+    atk.set_p(-5);
+
+    let mut scratch: ScratchOwned<BE> =
+        ScratchOwned::alloc(module.ggsw_prepare_tmp_bytes(&ggsw) | module.glwe_automorphism_key_prepare_tmp_bytes(&atk));
+
+    let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
+    module.glwe_secret_prepare(&mut sk_prepared, &sk);
+    let mut ggsw_prepared: GGSWPrepared<DeviceBuf<BE>, BE> = module.ggsw_prepared_alloc_from_infos(&ggsw);
+    module.ggsw_prepare(&mut ggsw_prepared, &ggsw, scratch.borrow());
+    let mut atk_prepared: GLWEAutomorphismKeyPrepared<DeviceBuf<BE>, BE> =
+        module.glwe_automorphism_key_prepared_alloc_from_infos(&atk);
+    module.glwe_automorphism_key_prepare(&mut atk_prepared, &atk, scratch.borrow());
+
+    let mut bytes: Vec<u8> = Vec::new();
+    sk_prepared.write_to(&mut bytes).expect("write_to failed");
+    ggsw_prepared.write_to(&mut bytes).expect("write_to failed");
+    atk_prepared.write_to(&mut bytes).expect("write_to failed");
+
+    // Streamed loading into freshly allocated keys.
+    {
+        let mut reader: &[u8] = &bytes;
+        let mut sk_have: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
+        let mut ggsw_have: GGSWPrepared<DeviceBuf<BE>, BE> = module.ggsw_prepared_alloc_from_infos(&ggsw);
+        let mut atk_have: GLWEAutomorphismKeyPrepared<DeviceBuf<BE>, BE> =
+            module.glwe_automorphism_key_prepared_alloc_from_infos(&atk);
+        sk_have.read_from(&mut reader).expect("read_from failed");
+        ggsw_have.read_from(&mut reader).expect("read_from failed");
+        atk_have.read_from(&mut reader).expect("read_from failed");
+        assert!(reader.is_empty());
+
+        assert_eq!(sk_have.dist(), sk_prepared.dist());
+        assert_eq!(sk_have.data.digest_u64(), sk_prepared.data.digest_u64());
+        assert_eq!(ggsw_have.base2k(), ggsw_prepared.base2k());
+        assert_eq!(ggsw_have.dsize(), ggsw_prepared.dsize());
+        assert_eq!(ggsw_have.data.digest_u64(), ggsw_prepared.data.digest_u64());
+        assert_eq!(atk_have.p(), atk.p());
+        assert_eq!(atk_have.key.data.digest_u64(), atk_prepared.key.data.digest_u64());
+    }
+
+    // Zero-copy loading, from an aligned copy of the stream.
+    {
+        let aligned: AlignedBuf = AlignedBuf::from_slice(&bytes);
+        let (sk_have, rest) = GLWESecretPrepared::<&[u8], BE>::from_bytes(n.into(), &aligned).expect("from_bytes failed");
+        let (ggsw_have, rest) = GGSWPrepared::<&[u8], BE>::from_bytes(n.into(), rest).expect("from_bytes failed");
+        let (atk_have, rest) = GLWEAutomorphismKeyPrepared::<&[u8], BE>::from_bytes(n.into(), rest).expect("from_bytes failed");
+        assert!(rest.is_empty());
+
+        assert_eq!(sk_have.dist(), sk_prepared.dist());
+        assert_eq!(sk_have.data.digest_u64(), sk_prepared.data.digest_u64());
+        assert_eq!(ggsw_have.base2k(), ggsw_prepared.base2k());
+        assert_eq!(ggsw_have.dnum(), ggsw_prepared.dnum());
+        assert_eq!(ggsw_have.data.digest_u64(), ggsw_prepared.data.digest_u64());
+        assert_eq!(atk_have.p(), atk.p());
+        assert_eq!(atk_have.key.data.digest_u64(), atk_prepared.key.data.digest_u64());
+    }
+
+    // Loading for another ring degree.
+    {
+        let aligned: AlignedBuf = AlignedBuf::from_slice(&bytes);
+        let n_other: Degree = (n >> 1).into();
+        assert!(GLWESecretPrepared::<&[u8], BE>::from_bytes(n_other, &aligned).is_err());
+
+        let module_other: Module<BE> = Module::<BE>::new_marker((n << 1) as u64);
+        let mut sk_have: GLWESecretPrepared<DeviceBuf<BE>, BE> = module_other.glwe_secret_prepared_alloc(rank.into());
+        assert!(sk_have.read_from(&mut bytes.as_slice()).is_err());
+    }
+}
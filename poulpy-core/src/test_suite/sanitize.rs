@@ -0,0 +1,252 @@
+use poulpy_hal::{
+    api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxFillUniform},
+    layouts::{AlignedBuf, DeviceBuf, Module, Scratch, ScratchOwned, VecZnx, ZnxView, ZnxViewMut, ZnxZero},
+    source::Source,
+    test_suite::TestParams,
+};
+
+use crate::{
+    EncryptionLayout, GLWEEncryptSk, GLWENoise, GLWEPublicKeyGenerate, GLWESanitize, LWEDecrypt, LWEEncryptSk, LWESanitize,
+    NoiseFlooding, ScratchTakeCore,
+    encryption::{DEFAULT_BOUND_XE, DEFAULT_SIGMA_XE},
+    layouts::{
+        GLWE, GLWELayout, GLWEPlaintext, GLWEPublicKey, GLWEPublicKeyPreparedFactory, GLWESecret, GLWESecretPreparedFactory, LWE,
+        LWEInfos, LWELayout, LWEPlaintext, LWESecret, TorusPrecision,
+        prepared::{GLWEPublicKeyPrepared, GLWESecretPrepared},
+    },
+    noise::NoiseModel,
+    test_suite::assert_noise_matches,
+};
+
+/// Sanitizes a fresh secret-key encryption with a public key and checks that
+/// it still decrypts to the same plaintext, with a re-randomized mask and the
+/// flooding variance predicted by [`NoiseModel::glwe_sanitize`].
+pub fn test_glwe_sanitize<BE: crate::test_suite::TestBackend>(params: &TestParams, module: &Module<BE>)
+where
+    Module<BE>: GLWESanitize<BE>
+        + GLWEEncryptSk<BE>
+        + GLWEPublicKeyGenerate<BE>
+        + GLWEPublicKeyPreparedFactory<BE>
+        + GLWESecretPreparedFactory<BE>
+        + GLWENoise<BE>
+        + VecZnxFillUniform,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    let base2k: usize = params.base2k;
+    let k_ct: usize = 4 * base2k + 1;
+    let n: usize = module.n();
+    let stat_dist: usize = 20;
+
+    for rank in 1_usize..3 {
+        let glwe_infos = EncryptionLayout::new_from_default_sigma(GLWELayout {
+            n: n.into(),
+            base2k: base2k.into(),
+            k: k_ct.into(),
+            rank: rank.into(),
+        })
+        .unwrap();
+
+        let mut source_xs: Source = Source::new([0u8; 32]);
+        let mut source_xe: Source = Source::new([1u8; 32]);
+        let mut source_xa: Source = Source::new([2u8; 32]);
+        let mut source_xu: Source = Source::new([3u8; 32]);
+
+        let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
+            module
+                .glwe_noise_tmp_bytes(&glwe_infos)
+                .max(module.glwe_encrypt_sk_tmp_bytes(&glwe_infos))
+                .max(module.glwe_sanitize_tmp_bytes(&glwe_infos)),
+        );
+
+        let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&glwe_infos);
+        sk.fill_ternary_prob(0.5, &mut source_xs);
+        let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
+        module.glwe_secret_prepare(&mut sk_prepared, &sk);
+
+        let mut pk: GLWEPublicKey<AlignedBuf> = GLWEPublicKey::alloc_from_infos(&glwe_infos);
+        module.glwe_public_key_generate(&mut pk, &sk_prepared, &glwe_infos, &mut source_xe, &mut source_xa);
+        let mut pk_prepared: GLWEPublicKeyPrepared<DeviceBuf<BE>, BE> =
+            module.glwe_public_key_prepared_alloc_from_infos(&glwe_infos);
+        module.glwe_public_key_prepare(&mut pk_prepared, &pk);
+
+        let mut pt: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+        module.vec_znx_fill_uniform(base2k, &mut pt.data, 0, &mut source_xa);
+
+        let mut ct: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
+        module.glwe_encrypt_sk(
+            &mut ct,
+            &pt,
+            &sk_prepared,
+            &glwe_infos,
+            &mut source_xe,
+            &mut source_xa,
+            scratch.borrow(),
+        );
+
+        let flood: NoiseFlooding = NoiseFlooding::new(&glwe_infos, DEFAULT_BOUND_XE.log2() - k_ct as f64, stat_dist).unwrap();
+        let model: NoiseModel = NoiseModel::from_secret(&sk, DEFAULT_SIGMA_XE);
+        let noise_want: f64 = 0.5 * model.glwe_sanitize(model.glwe_encrypt_sk(&glwe_infos.noise), &flood).log2();
+
+        let mut ct_sanitized: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
+        module.glwe_sanitize(
+            &mut ct_sanitized,
+            &ct,
+            &pk_prepared,
+            &glwe_infos,
+            &flood,
+            &mut source_xu,
+            &mut source_xe,
+            scratch.borrow(),
+        );
+
+        for col in 1..rank + 1 {
+            assert_ne!(
+                ct_sanitized.data.at(col, 0),
+                ct.data.at(col, 0),
+                "mask column {col} was not re-randomized"
+            );
+        }
+
+        let noise_have: f64 = module
+            .glwe_noise(&ct_sanitized, &pt, &sk_prepared, scratch.borrow())
+            .std()
+            .log2();
+        assert_noise_matches(&format!("rank={rank}"), noise_have, noise_want);
+
+        module.glwe_sanitize_assign(
+            &mut ct,
+            &pk_prepared,
+            &glwe_infos,
+            &flood,
+            &mut source_xu,
+            &mut source_xe,
+            scratch.borrow(),
+        );
+        let noise_have: f64 = module.glwe_noise(&ct, &pt, &sk_prepared, scratch.borrow()).std().log2();
+        assert_noise_matches(&format!("rank={rank} (assign)"), noise_have, noise_want);
+    }
+}
+
+/// Sanitizes LWE encryptions under the key extracted from a rank-1 GLWE
+/// secret, checks that they decrypt to the same message, and that the error
+/// measured over many trials has the flooding variance.
+pub fn test_lwe_sanitize<BE: crate::test_suite::TestBackend>(params: &TestParams, module: &Module<BE>)
+where
+    Module<BE>: LWESanitize<BE>
+        + LWEEncryptSk<BE>
+        + LWEDecrypt<BE>
+        + GLWEPublicKeyGenerate<BE>
+        + GLWEPublicKeyPreparedFactory<BE>
+        + GLWESecretPreparedFactory<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    let base2k: usize = params.base2k;
+    let k_ct: usize = 4 * base2k + 1;
+    let k_pt: TorusPrecision = TorusPrecision(8);
+    let n: usize = module.n();
+    let stat_dist: usize = 20;
+    let trials: usize = 256;
+
+    let glwe_infos = EncryptionLayout::new_from_default_sigma(GLWELayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k_ct.into(),
+        rank: 1_usize.into(),
+    })
+    .unwrap();
+    let lwe_infos = EncryptionLayout::new_from_default_sigma(LWELayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k_ct.into(),
+    })
+    .unwrap();
+
+    let mut source_xs: Source = Source::new([0u8; 32]);
+    let mut source_xe: Source = Source::new([1u8; 32]);
+    let mut source_xa: Source = Source::new([2u8; 32]);
+    let mut source_xu: Source = Source::new([3u8; 32]);
+
+    let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
+        module
+            .lwe_encrypt_sk_tmp_bytes(&lwe_infos)
+            .max(module.lwe_decrypt_tmp_bytes(&lwe_infos))
+            .max(module.lwe_sanitize_tmp_bytes(&lwe_infos)),
+    );
+
+    let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&glwe_infos);
+    sk.fill_ternary_prob(0.5, &mut source_xs);
+    let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(1_usize.into());
+    module.glwe_secret_prepare(&mut sk_prepared, &sk);
+    let sk_lwe: LWESecret<AlignedBuf> = LWESecret::from_glwe_secret(&sk);
+
+    let mut pk: GLWEPublicKey<AlignedBuf> = GLWEPublicKey::alloc_from_infos(&glwe_infos);
+    module.glwe_public_key_generate(&mut pk, &sk_prepared, &glwe_infos, &mut source_xe, &mut source_xa);
+    let mut pk_prepared: GLWEPublicKeyPrepared<DeviceBuf<BE>, BE> = module.glwe_public_key_prepared_alloc_from_infos(&glwe_infos);
+    module.glwe_public_key_prepare(&mut pk_prepared, &pk);
+
+    let flood: NoiseFlooding = NoiseFlooding::new(&lwe_infos, DEFAULT_BOUND_XE.log2() - k_ct as f64, stat_dist).unwrap();
+    let model: NoiseModel = NoiseModel::from_secret(&sk, DEFAULT_SIGMA_XE);
+    let noise_want: f64 = 0.5 * model.glwe_sanitize(model.glwe_encrypt_sk(&lwe_infos.noise), &flood).log2();
+
+    let mut ct: LWE<AlignedBuf> = LWE::alloc_from_infos(&lwe_infos);
+    let mut ct_sanitized: LWE<AlignedBuf> = LWE::alloc_from_infos(&lwe_infos);
+    let mut pt_want: LWEPlaintext<AlignedBuf> = LWEPlaintext::alloc_from_infos(&lwe_infos);
+    let mut pt_have: LWEPlaintext<AlignedBuf> = LWEPlaintext::alloc_from_infos(&lwe_infos);
+
+    let data: i64 = 17;
+    pt_want.encode_i64(data, k_pt);
+    module.lwe_encrypt_sk(
+        &mut ct,
+        &pt_want,
+        &sk_lwe,
+        &lwe_infos,
+        &mut source_xe,
+        &mut source_xa,
+        scratch.borrow(),
+    );
+    module.lwe_sanitize(
+        &mut ct_sanitized,
+        &ct,
+        &pk_prepared,
+        &glwe_infos,
+        &flood,
+        &mut source_xu,
+        &mut source_xe,
+        scratch.borrow(),
+    );
+    module.lwe_decrypt(&ct_sanitized, &mut pt_have, &sk_lwe, scratch.borrow());
+    assert_eq!(pt_have.decode_i64(k_pt), data);
+
+    // Error of sanitized encryptions of zero, one trial per coefficient.
+    pt_want.data_mut().zero();
+    let mut errors: VecZnx<AlignedBuf> = VecZnx::alloc(trials, 1, lwe_infos.size());
+    for t in 0..trials {
+        module.lwe_encrypt_sk(
+            &mut ct,
+            &pt_want,
+            &sk_lwe,
+            &lwe_infos,
+            &mut source_xe,
+            &mut source_xa,
+            scratch.borrow(),
+        );
+        module.lwe_sanitize_assign(
+            &mut ct,
+            &pk_prepared,
+            &glwe_infos,
+            &flood,
+            &mut source_xu,
+            &mut source_xe,
+            scratch.borrow(),
+        );
+        module.lwe_decrypt(&ct, &mut pt_have, &sk_lwe, scratch.borrow());
+        for i in 0..lwe_infos.size() {
+            errors.at_mut(0, i)[t] = pt_have.data.at(0, i)[0];
+        }
+    }
+
+    let noise_have: f64 = errors.stats(base2k, 0).std().log2();
+    assert_noise_matches("lwe_sanitize", noise_have, noise_want);
+}
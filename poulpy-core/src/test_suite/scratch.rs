@@ -0,0 +1,36 @@
+use poulpy_hal::{
+    api::{ScratchOwnedAlloc, ScratchOwnedBorrow},
+    layouts::{Module, Scratch, ScratchOwned},
+    test_suite::TestParams,
+};
+
+use crate::{
+    ScratchTakeCore,
+    layouts::{LWE, LWEInfos, LWELayout},
+};
+
+/// Checks that [`ScratchTakeCore::take_lwe`] returns an [`LWE`] with the
+/// requested degree, i.e. `n + 1` coefficients (body and mask), carved from
+/// exactly [`LWE::bytes_of_from_infos`] bytes.
+pub fn test_take_lwe<BE: crate::test_suite::TestBackend>(params: &TestParams, _module: &Module<BE>)
+where
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchTakeCore<BE>,
+{
+    let base2k: usize = params.base2k;
+
+    for n in [1_usize, 77, 512] {
+        let lwe_infos: LWELayout = LWELayout {
+            n: n.into(),
+            base2k: base2k.into(),
+            k: (3 * base2k).into(),
+        };
+
+        let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(LWE::bytes_of_from_infos(&lwe_infos));
+        let (lwe, _) = scratch.borrow().take_lwe(&lwe_infos);
+
+        assert_eq!(lwe.n(), lwe_infos.n(), "n={n}");
+        assert_eq!(lwe.base2k(), lwe_infos.base2k(), "n={n}");
+        assert_eq!(lwe.size(), lwe_infos.size(), "n={n}");
+    }
+}
@@ -0,0 +1,245 @@
+use std::io::ErrorKind;
+
+use poulpy_hal::{
+    api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow},
+    layouts::{AlignedBuf, DeviceBuf, Module, Scratch, ScratchOwned, ZnxView},
+    source::Source,
+    test_suite::TestParams,
+};
+
+use crate::{
+    EncryptionLayout, GLWEDecrypt, GLWEEncryptSk, GLWENoise, GLWETruncate, LWEDecrypt, LWEEncryptSk, LWETruncate,
+    ScratchTakeCore,
+    encryption::DEFAULT_SIGMA_XE,
+    layouts::{
+        Degree, GLWE, GLWELayout, GLWEPlaintext, GLWESecret, GLWESecretPreparedFactory, LWE, LWEInfos, LWELayout, LWEPlaintext,
+        LWESecret, ReadTruncated, TorusPrecision, WriteTruncated, prepared::GLWESecretPrepared,
+    },
+    noise::{NoiseModel, NoiseTracker},
+    test_suite::assert_noise_matches,
+};
+
+/// Truncates a fresh GLWE encryption to every precision between the smallest
+/// one allowed by [`NoiseTracker::glwe_truncate_min_k`] and its full
+/// precision, and checks that each truncated ciphertext decrypts correctly,
+/// has the noise predicted by [`NoiseModel::glwe_truncate`] and survives a
+/// round trip through the bit-packed wire format.
+pub fn test_glwe_truncate<BE: crate::test_suite::TestBackend>(params: &TestParams, module: &Module<BE>)
+where
+    Module<BE>: GLWETruncate<BE> + GLWEEncryptSk<BE> + GLWEDecrypt<BE> + GLWESecretPreparedFactory<BE> + GLWENoise<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    let base2k: usize = params.base2k;
+    let k_ct: usize = 3 * base2k + 1;
+    let k_pt: usize = 8;
+    let n: usize = module.n();
+    let log2_failure: f64 = -40.0;
+
+    for rank in 1_usize..3 {
+        let glwe_infos = EncryptionLayout::new_from_default_sigma(GLWELayout {
+            n: n.into(),
+            base2k: base2k.into(),
+            k: k_ct.into(),
+            rank: rank.into(),
+        })
+        .unwrap();
+
+        let mut source_xs: Source = Source::new([0u8; 32]);
+        let mut source_xe: Source = Source::new([1u8; 32]);
+        let mut source_xa: Source = Source::new([2u8; 32]);
+
+        let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
+            module
+                .glwe_encrypt_sk_tmp_bytes(&glwe_infos)
+                .max(module.glwe_decrypt_tmp_bytes(&glwe_infos))
+                .max(module.glwe_noise_tmp_bytes(&glwe_infos))
+                .max(module.glwe_truncate_tmp_bytes(&glwe_infos)),
+        );
+
+        let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&glwe_infos);
+        sk.fill_ternary_prob(0.5, &mut source_xs);
+        let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BE>, BE> = module.glwe_secret_prepared_alloc(rank.into());
+        module.glwe_secret_prepare(&mut sk_prepared, &sk);
+
+        let data: Vec<i64> = (0..n as i64)
+            .map(|i| (i * 37) % ((1 << k_pt) - 1) - ((1 << (k_pt - 1)) - 1))
+            .collect();
+        let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+        pt_want.encode_vec_i64(&data, TorusPrecision(k_pt as u32));
+
+        let mut ct: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
+        module.glwe_encrypt_sk(
+            &mut ct,
+            &pt_want,
+            &sk_prepared,
+            &glwe_infos,
+            &mut source_xe,
+            &mut source_xa,
+            scratch.borrow(),
+        );
+
+        // The full-precision ciphertext has non-zero bits below k_ct - 1.
+        let err = ct.write_truncated(k_ct - 1, &mut Vec::new()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+        let model: NoiseModel = NoiseModel::from_secret(&sk, DEFAULT_SIGMA_XE);
+        let tracker: NoiseTracker = NoiseTracker::fresh(model, &glwe_infos.noise);
+        let k_min: usize = tracker.glwe_truncate_min_k(&glwe_infos, k_pt + 1, log2_failure).unwrap();
+        assert!(k_min < k_ct, "rank={rank}: k_min: {k_min} >= k_ct: {k_ct}");
+
+        let mut pt_have: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_infos);
+        let mut data_have: Vec<i64> = vec![0i64; n];
+        let mut ct_received: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_infos);
+
+        for k in k_min..k_ct + 1 {
+            let trunc_infos: GLWELayout = GLWELayout {
+                n: n.into(),
+                base2k: base2k.into(),
+                k: k.into(),
+                rank: rank.into(),
+            };
+
+            let mut ct_trunc: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&trunc_infos);
+            module.glwe_truncate(&mut ct_trunc, k, &ct, scratch.borrow());
+
+            module.glwe_decrypt(&ct_trunc, &mut pt_have, &sk_prepared, scratch.borrow());
+            pt_have.decode_vec_i64(&mut data_have, TorusPrecision(k_pt as u32));
+            assert_eq!(data_have, data, "rank={rank} k={k}: wrong decryption");
+
+            let noise_want: f64 = 0.5 * model.glwe_truncate(tracker.variance(), &glwe_infos, k).log2();
+            let noise_have: f64 = module
+                .glwe_noise(&ct_trunc, &pt_want, &sk_prepared, scratch.borrow())
+                .std()
+                .log2();
+            assert_noise_matches(&format!("rank={rank} k={k}"), noise_have, noise_want);
+
+            let mut bytes: Vec<u8> = Vec::new();
+            ct_trunc.write_truncated(k, &mut bytes).unwrap();
+            assert_eq!(bytes.len(), ct_trunc.truncated_bytes(k));
+            assert_eq!(bytes.len(), 16 + ((rank + 1) * n * k).div_ceil(8));
+
+            ct_received.read_truncated(&mut bytes.as_slice()).unwrap();
+            for col in 0..rank + 1 {
+                for j in 0..ct_received.size() {
+                    if j < ct_trunc.size() {
+                        assert_eq!(ct_received.data().at(col, j), ct_trunc.data().at(col, j));
+                    } else {
+                        assert!(ct_received.data().at(col, j).iter().all(|&x| x == 0));
+                    }
+                }
+            }
+
+            let mut ct_assign: GLWE<AlignedBuf> = ct.clone();
+            module.glwe_truncate_assign(&mut ct_assign, k, scratch.borrow());
+            ct_assign.write_truncated(k, &mut Vec::new()).unwrap();
+            module.glwe_decrypt(&ct_assign, &mut pt_have, &sk_prepared, scratch.borrow());
+            pt_have.decode_vec_i64(&mut data_have, TorusPrecision(k_pt as u32));
+            assert_eq!(data_have, data, "rank={rank} k={k} (assign): wrong decryption");
+        }
+    }
+}
+
+/// Truncates LWE encryptions to every precision between the smallest one
+/// allowed by [`NoiseTracker::lwe_truncate_min_k`] and their full precision,
+/// and checks that each truncated ciphertext decrypts correctly and survives
+/// a round trip through the bit-packed wire format.
+pub fn test_lwe_truncate<BE: crate::test_suite::TestBackend>(params: &TestParams, module: &Module<BE>)
+where
+    Module<BE>: LWETruncate<BE> + LWEEncryptSk<BE> + LWEDecrypt<BE>,
+    ScratchOwned<BE>: ScratchOwnedAlloc<BE> + ScratchOwnedBorrow<BE>,
+    Scratch<BE>: ScratchAvailable + ScratchTakeCore<BE>,
+{
+    let base2k: usize = params.base2k;
+    let k_ct: usize = 3 * base2k + 1;
+    let k_pt: TorusPrecision = TorusPrecision(8);
+    let n: usize = module.n();
+    let log2_failure: f64 = -40.0;
+
+    let lwe_infos = EncryptionLayout::new_from_default_sigma(LWELayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k_ct.into(),
+    })
+    .unwrap();
+
+    let mut source_xs: Source = Source::new([0u8; 32]);
+    let mut source_xe: Source = Source::new([1u8; 32]);
+    let mut source_xa: Source = Source::new([2u8; 32]);
+
+    let mut scratch: ScratchOwned<BE> = ScratchOwned::alloc(
+        module
+            .lwe_encrypt_sk_tmp_bytes(&lwe_infos)
+            .max(module.lwe_decrypt_tmp_bytes(&lwe_infos))
+            .max(module.lwe_truncate_tmp_bytes(&lwe_infos)),
+    );
+
+    let mut sk: LWESecret<AlignedBuf> = LWESecret::alloc(n.into());
+    sk.fill_ternary_prob(0.5, &mut source_xs);
+
+    let model: NoiseModel = NoiseModel::from_secret(&sk, DEFAULT_SIGMA_XE);
+    let tracker: NoiseTracker = NoiseTracker::fresh(model, &lwe_infos.noise);
+    let k_min: usize = tracker
+        .lwe_truncate_min_k(&lwe_infos, k_pt.as_usize() + 1, log2_failure)
+        .unwrap();
+    assert!(k_min < k_ct, "k_min: {k_min} >= k_ct: {k_ct}");
+
+    let mut pt_want: LWEPlaintext<AlignedBuf> = LWEPlaintext::alloc_from_infos(&lwe_infos);
+    let mut pt_have: LWEPlaintext<AlignedBuf> = LWEPlaintext::alloc_from_infos(&lwe_infos);
+    let mut ct: LWE<AlignedBuf> = LWE::alloc_from_infos(&lwe_infos);
+    let mut ct_received: LWE<AlignedBuf> = LWE::alloc_from_infos(&lwe_infos);
+
+    for data in [-127_i64, -17, 0, 1, 127] {
+        pt_want.encode_i64(data, k_pt);
+        module.lwe_encrypt_sk(
+            &mut ct,
+            &pt_want,
+            &sk,
+            &lwe_infos,
+            &mut source_xe,
+            &mut source_xa,
+            scratch.borrow(),
+        );
+
+        for k in k_min..k_ct + 1 {
+            let trunc_infos: LWELayout = LWELayout {
+                n: n.into(),
+                base2k: base2k.into(),
+                k: k.into(),
+            };
+
+            let mut ct_trunc: LWE<AlignedBuf> = LWE::alloc_from_infos(&trunc_infos);
+            module.lwe_truncate(&mut ct_trunc, k, &ct, scratch.borrow());
+
+            module.lwe_decrypt(&ct_trunc, &mut pt_have, &sk, scratch.borrow());
+            assert_eq!(pt_have.decode_i64(k_pt), data, "k={k}: wrong decryption");
+
+            let mut bytes: Vec<u8> = Vec::new();
+            ct_trunc.write_truncated(k, &mut bytes).unwrap();
+            assert_eq!(bytes.len(), ct_trunc.truncated_bytes(k));
+            assert_eq!(bytes.len(), 16 + ((n + 1) * k).div_ceil(8));
+
+            ct_received.read_truncated(&mut bytes.as_slice()).unwrap();
+            module.lwe_decrypt(&ct_received, &mut pt_have, &sk, scratch.borrow());
+            assert_eq!(
+                pt_have.decode_i64(k_pt),
+                data,
+                "k={k}: wrong decryption after deserialization"
+            );
+
+            let mut ct_assign: LWE<AlignedBuf> = ct.clone();
+            module.lwe_truncate_assign(&mut ct_assign, k, scratch.borrow());
+            ct_assign.write_truncated(k, &mut Vec::new()).unwrap();
+            module.lwe_decrypt(&ct_assign, &mut pt_have, &sk, scratch.borrow());
+            assert_eq!(pt_have.decode_i64(k_pt), data, "k={k} (assign): wrong decryption");
+        }
+    }
+
+    // A receiver of another dimension rejects the stream.
+    let mut bytes: Vec<u8> = Vec::new();
+    module.lwe_truncate_assign(&mut ct, k_min, scratch.borrow());
+    ct.write_truncated(k_min, &mut bytes).unwrap();
+    let mut ct_other: LWE<AlignedBuf> = LWE::alloc(Degree(n as u32 / 2), base2k.into(), k_ct.into());
+    let err = ct_other.read_truncated(&mut bytes.as_slice()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
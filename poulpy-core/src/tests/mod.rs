@@ -1,4 +1,10 @@
 //! Local crate-only tests.
 
+#[cfg(test)]
+mod noise;
+#[cfg(test)]
+mod security;
+#[cfg(all(test, feature = "serde"))]
+mod serde_serialization;
 #[cfg(test)]
 mod serialization;
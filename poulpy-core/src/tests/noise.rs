@@ -0,0 +1,79 @@
+use crate::{
+    dist::Distribution,
+    layouts::{GGSWLayout, GLWELayout, GLWESecret},
+    noise::{NoiseModel, NoiseTracker},
+};
+use poulpy_hal::{
+    layouts::{AlignedBuf, NoiseInfos},
+    source::Source,
+};
+
+fn glwe(n: usize, base2k: usize, k: usize) -> GLWELayout {
+    GLWELayout {
+        n: n.into(),
+        base2k: base2k.into(),
+        k: k.into(),
+        rank: 1_usize.into(),
+    }
+}
+
+#[test]
+fn model_from_secret_uses_density() {
+    let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc(1024_u32.into(), 1_u32.into());
+    sk.fill_dist(Distribution::TernaryFixed(64), &mut Source::new([0u8; 32]));
+    let model: NoiseModel = NoiseModel::from_secret(&sk, 3.2);
+    assert_eq!(model.var_xs, 64.0 / 1024.0);
+    assert_eq!(model.var_key_err, 3.2 * 3.2);
+}
+
+#[test]
+fn failure_probability_matches_gaussian_tails() {
+    // std = 2^-20: P(|e| > k * std) = erfc(k / sqrt(2)).
+    let tracker: NoiseTracker = NoiseTracker::new(NoiseModel::new(0.5, 3.2 * 3.2), (-40.0_f64).exp2());
+    for (log2_bound, want) in [(20, 0.3173105), (19, 0.0455003), (18, 6.334248e-5)] {
+        let have: f64 = tracker.failure_probability(log2_bound);
+        assert!((have / want - 1.0).abs() < 1e-5, "log2_bound={log2_bound}: {have} vs {want}");
+    }
+    // Far below f64::MIN_POSITIVE, the log domain stays finite.
+    let log2_p: f64 = tracker.log2_failure_probability(10);
+    assert!(log2_p.is_finite() && log2_p < -700_000.0);
+}
+
+#[test]
+fn tracker_records_steps() {
+    let model: NoiseModel = NoiseModel::new(0.5, 3.2 * 3.2);
+    let noise: NoiseInfos = NoiseInfos::new(54, 3.2, 19.2).unwrap();
+    let glwe_in: GLWELayout = glwe(1024, 18, 54);
+    let ggsw: GGSWLayout = GGSWLayout {
+        n: 1024_usize.into(),
+        base2k: 18_usize.into(),
+        k: 72_usize.into(),
+        dnum: 3_usize.into(),
+        dsize: 1_usize.into(),
+        rank: 1_usize.into(),
+    };
+
+    let other: NoiseTracker = NoiseTracker::fresh(model, &noise);
+    let mut tracker: NoiseTracker = NoiseTracker::fresh(model, &noise);
+    tracker.add(&other).mul_plain(4.0).external_product(1.0, &glwe_in, &ggsw);
+
+    let ops: Vec<&str> = tracker.steps().iter().map(|s| s.op).collect();
+    assert_eq!(ops, ["encrypt_sk", "add", "mul_plain", "external_product"]);
+    assert_eq!(tracker.steps()[2].var, 8.0 * other.variance());
+    assert!(tracker.variance() > tracker.steps()[2].var);
+    assert_eq!(tracker.steps()[3].log2_std(), tracker.log2_std());
+
+    // An empty blind rotation key set contributes no noise.
+    let mut br: NoiseTracker = NoiseTracker::new(model, 0.0);
+    br.blind_rotation(0, &glwe_in, &ggsw);
+    assert_eq!(br.variance(), 0.0);
+
+    // Each CMux step adds the key noise of an external product, doubled by
+    // the product with X^a_i - 1, and the input noise is discarded.
+    let step: f64 = model.glwe_external_product(0.0, 1.0, &glwe_in, &ggsw);
+    br.blind_rotation(630, &glwe_in, &ggsw);
+    assert_eq!(br.variance(), 2.0 * 630.0 * step);
+    tracker.blind_rotation(630, &glwe_in, &ggsw);
+    assert_eq!(tracker.variance(), br.variance());
+    assert_eq!(tracker.steps().last().unwrap().op, "blind_rotation");
+}
@@ -0,0 +1,66 @@
+use crate::{
+    dist::Distribution,
+    layouts::{Base2K, Degree, GLWELayout, LWELayout, Rank, TorusPrecision},
+    security::{GLWESecurity, LWESecurity, ReductionCostModel, SecurityParameters},
+};
+
+const TERNARY: Distribution = Distribution::TernaryProb(2.0 / 3.0);
+
+/// Parameters of the HomomorphicEncryption.org standard, 128-bit classical column.
+#[test]
+fn he_standard_128() {
+    for (n, log_q) in [(1024, 27), (2048, 54), (4096, 109)] {
+        let lambda: f64 = SecurityParameters::new(n, log_q, TERNARY, 3.2).security_level();
+        assert!((120.0..140.0).contains(&lambda), "n={n} log_q={log_q}: lambda={lambda}");
+    }
+}
+
+#[test]
+fn security_decreases_with_modulus() {
+    let mut prev: f64 = f64::INFINITY;
+    for log_q in [20, 27, 40, 54, 80] {
+        let lambda: f64 = SecurityParameters::new(1024, log_q, TERNARY, 3.2).security_level();
+        assert!(lambda < prev, "log_q={log_q}: lambda={lambda} >= {prev}");
+        prev = lambda;
+    }
+}
+
+#[test]
+fn sparse_secret_is_weaker() {
+    let dense: f64 = SecurityParameters::new(2048, 54, TERNARY, 3.2).security_level();
+    let sparse: SecurityParameters = SecurityParameters::new(2048, 54, Distribution::TernaryFixed(64), 3.2);
+    let estimate = sparse.estimate(ReductionCostModel::default());
+    assert!(estimate.security_level() < dense);
+    assert!(estimate.dual_hybrid.zeta > 0);
+}
+
+#[test]
+fn cost_models_are_ordered() {
+    let params: SecurityParameters = SecurityParameters::new(2048, 54, TERNARY, 3.2);
+    let quantum: f64 = params.estimate(ReductionCostModel::QuantumCoreSieve).security_level();
+    let core: f64 = params.estimate(ReductionCostModel::CoreSieve).security_level();
+    let bdgl: f64 = params.estimate(ReductionCostModel::BDGL16).security_level();
+    assert!(quantum < core && core < bdgl);
+}
+
+#[test]
+fn zero_secret_is_insecure() {
+    let lambda: f64 = SecurityParameters::new(2048, 54, Distribution::ZERO, 3.2).security_level();
+    assert_eq!(lambda, 0.0);
+}
+
+#[test]
+fn layout_security() {
+    let glwe: GLWELayout = GLWELayout {
+        n: Degree(1024),
+        base2k: Base2K(18),
+        k: TorusPrecision(54),
+        rank: Rank(2),
+    };
+    let lwe: LWELayout = LWELayout {
+        n: Degree(2048),
+        base2k: Base2K(18),
+        k: TorusPrecision(54),
+    };
+    assert_eq!(glwe.glwe_security(TERNARY, 3.2), lwe.lwe_security(TERNARY, 3.2));
+}
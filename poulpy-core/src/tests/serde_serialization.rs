@@ -0,0 +1,226 @@
+use std::fmt::Debug;
+
+use poulpy_hal::{
+    layouts::{AlignedBuf, FillUniform, WriterTo},
+    source::Source,
+};
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::{
+    dist::Distribution,
+    layouts::{
+        Base2K, Degree, Dnum, Dsize, GGLWE, GGLWEToGGSWKey, GGSW, GLWE, GLWEAutomorphismKey, GLWEPlaintext, GLWEPublicKey,
+        GLWERingSwitchKey, GLWESecret, GLWESecretTensor, GLWESwitchingKey, GLWETensor, GLWETensorKey, GLWEToLWEKey, GLWEToMut,
+        LWE, LWEPlaintext, LWESecret, LWESwitchingKey, LWEToGLWEKey, Rank, TorusPrecision,
+        compressed::{
+            GGLWECompressed, GGLWEToGGSWKeyCompressed, GGSWCompressed, GLWEAutomorphismKeyCompressed, GLWECompressed,
+            GLWESecretCompressed, GLWESwitchingKeyCompressed, GLWETensorKeyCompressed, GLWEToLWESwitchingKeyCompressed,
+            LWECompressed, LWESecretCompressed, LWESwitchingKeyCompressed, LWEToGLWEKeyCompressed,
+        },
+    },
+};
+
+const N_GLWE: Degree = Degree(64);
+const N_LWE: Degree = Degree(32);
+const BASE2K: Base2K = Base2K(12);
+const K: TorusPrecision = TorusPrecision(33);
+const DNUM: Dnum = Dnum(3);
+const RANK: Rank = Rank(2);
+const DSIZE: Dsize = Dsize(1);
+
+/// Round trips `original` through JSON, bincode and CBOR, comparing the
+/// decoded values with `key`.
+fn test_serde_interface<T, K>(original: &T, key: impl Fn(&T) -> K)
+where
+    T: Serialize + DeserializeOwned,
+    K: PartialEq + Debug,
+{
+    let want: K = key(original);
+
+    let json: String = serde_json::to_string(original).expect("serde_json serialization failed");
+    let have: T = serde_json::from_str(&json).expect("serde_json deserialization failed");
+    assert_eq!(want, key(&have), "serde_json");
+
+    let config = bincode::config::standard();
+    let bytes: Vec<u8> = bincode::serde::encode_to_vec(original, config).expect("bincode serialization failed");
+    let (have, len): (T, usize) = bincode::serde::decode_from_slice(&bytes, config).expect("bincode deserialization failed");
+    assert_eq!(len, bytes.len());
+    assert_eq!(want, key(&have), "bincode");
+
+    let mut cbor: Vec<u8> = Vec::new();
+    ciborium::into_writer(original, &mut cbor).expect("ciborium serialization failed");
+    let have: T = ciborium::from_reader(cbor.as_slice()).expect("ciborium deserialization failed");
+    assert_eq!(want, key(&have), "ciborium");
+}
+
+/// The [`WriterTo`] encoding, which covers every field of the layout.
+fn raw<T: WriterTo>(x: &T) -> Vec<u8> {
+    let mut bytes: Vec<u8> = Vec::new();
+    x.write_to(&mut bytes).expect("write_to failed");
+    bytes
+}
+
+fn test_serde_layout<T>(mut original: T)
+where
+    T: Serialize + DeserializeOwned + WriterTo + FillUniform,
+{
+    original.fill_uniform(50, &mut Source::new([0u8; 32]));
+    test_serde_interface(&original, raw);
+}
+
+#[test]
+fn serde_standard_layouts() {
+    test_serde_layout(LWE::alloc(N_LWE, BASE2K, K));
+    test_serde_layout(GLWE::alloc(N_GLWE, BASE2K, K, RANK));
+    test_serde_layout(GGLWE::alloc(N_GLWE, BASE2K, K, RANK, RANK, DNUM, DSIZE));
+    test_serde_layout(GGSW::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE));
+    test_serde_layout(GLWESwitchingKey::alloc(N_GLWE, BASE2K, K, RANK, RANK, DNUM, DSIZE));
+    test_serde_layout(GLWEAutomorphismKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE));
+    test_serde_layout(GLWETensorKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE));
+    test_serde_layout(GGLWEToGGSWKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE));
+    test_serde_layout(GLWEToLWEKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM));
+    test_serde_layout(LWEToGLWEKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM));
+    test_serde_layout(LWESwitchingKey::alloc(N_GLWE, BASE2K, K, DNUM));
+    test_serde_layout(GLWERingSwitchKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE));
+}
+
+#[test]
+fn serde_compressed_layouts() {
+    test_serde_layout(LWECompressed::alloc(BASE2K, K));
+    test_serde_layout(GLWECompressed::alloc(N_GLWE, BASE2K, K, RANK));
+    test_serde_layout(GGLWECompressed::alloc(N_GLWE, BASE2K, K, RANK, RANK, DNUM, DSIZE));
+    test_serde_layout(GGSWCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE));
+    test_serde_layout(GLWESwitchingKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, RANK, DNUM, DSIZE));
+    test_serde_layout(GLWEAutomorphismKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE));
+    test_serde_layout(GLWETensorKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE));
+    test_serde_layout(GGLWEToGGSWKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE));
+    test_serde_layout(GLWEToLWESwitchingKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM));
+    test_serde_layout(LWEToGLWEKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM));
+    test_serde_layout(LWESwitchingKeyCompressed::alloc(N_GLWE, BASE2K, K, DNUM));
+    test_serde_layout(GLWESecretCompressed::new(
+        N_GLWE,
+        RANK,
+        Distribution::TernaryProb(0.5),
+        [7u8; 32],
+    ));
+    test_serde_layout(LWESecretCompressed::new(N_LWE, Distribution::BinaryFixed(8), [9u8; 32]));
+}
+
+#[test]
+fn serde_secrets() {
+    let mut source: Source = Source::new([1u8; 32]);
+    for dist in [
+        Distribution::TernaryFixed(16),
+        Distribution::TernaryProb(0.5),
+        Distribution::BinaryBlock(8),
+        Distribution::ZERO,
+    ] {
+        let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc(N_GLWE, RANK);
+        sk.fill_dist(dist, &mut source);
+        test_serde_interface(&sk, raw);
+    }
+
+    let mut sk: LWESecret<AlignedBuf> = LWESecret::alloc(N_LWE);
+    sk.fill_binary_block(8, &mut source);
+    test_serde_interface(&sk, raw);
+
+    let mut sk_tensor: GLWESecretTensor<AlignedBuf> = GLWESecretTensor::alloc(N_GLWE, RANK);
+    sk_tensor.data.fill_uniform(50, &mut source);
+    test_serde_interface(&sk_tensor, |x| (x.data.clone(), x.rank));
+}
+
+#[test]
+fn serde_public_key_plaintexts_and_tensor() {
+    let mut source: Source = Source::new([2u8; 32]);
+
+    let mut pk: GLWEPublicKey<AlignedBuf> = GLWEPublicKey::alloc(N_GLWE, BASE2K, K, RANK);
+    pk.to_mut().fill_uniform(50, &mut source);
+    test_serde_interface(&pk, raw);
+
+    let mut pt: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc(N_GLWE, BASE2K, K);
+    pt.data.fill_uniform(50, &mut source);
+    test_serde_interface(&pt, |x| (raw(&x.data), x.base2k));
+
+    let mut tensor: GLWETensor<AlignedBuf> = GLWETensor::alloc(N_GLWE, BASE2K, K, RANK);
+    tensor.fill_uniform(50, &mut source);
+    test_serde_interface(&tensor, |x| (raw(&x.data), x.base2k, x.rank));
+}
+
+#[test]
+fn serde_lwe_layouts() {
+    let mut source: Source = Source::new([3u8; 32]);
+
+    // An LWE of dimension n is stored with degree n + 1, which is neither a
+    // power of two nor, for small n, enough to fill the allocation padding.
+    for n in [Degree(1), Degree(4), N_LWE, Degree(630)] {
+        test_serde_layout(LWE::alloc(n, BASE2K, K));
+
+        let mut sk: LWESecret<AlignedBuf> = LWESecret::alloc(n);
+        sk.fill_ternary_hw(1, &mut source);
+        test_serde_interface(&sk, raw);
+    }
+
+    let mut pt: LWEPlaintext<AlignedBuf> = LWEPlaintext::alloc(BASE2K, K);
+    pt.data.fill_uniform(50, &mut source);
+    test_serde_interface(&pt, |x| (raw(&x.data), x.base2k));
+}
+
+/// Deserializes `value` as `T` and checks that it fails with `msg`.
+fn assert_rejected<T: DeserializeOwned>(value: serde_json::Value, msg: &str) {
+    match serde_json::from_value::<T>(value) {
+        Ok(_) => panic!("expected an error containing {msg:?}"),
+        Err(err) => assert!(err.to_string().contains(msg), "{err}"),
+    }
+}
+
+fn to_value<T: Serialize>(x: &T) -> serde_json::Value {
+    serde_json::to_value(x).unwrap()
+}
+
+#[test]
+fn serde_rejects_inconsistent_headers() {
+    // GLWE layouts require a power-of-two ring degree.
+    assert_rejected::<GLWE<AlignedBuf>>(to_value(&LWE::alloc(Degree(47), BASE2K, K)), "is not a non-zero power of two");
+
+    let mut glwe: serde_json::Value = to_value(&GLWE::alloc(N_GLWE, BASE2K, K, RANK));
+    glwe["base2k"] = 0.into();
+    assert_rejected::<GLWE<AlignedBuf>>(glwe, "base2k must be non-zero");
+
+    let mut tensor: serde_json::Value = to_value(&GLWETensor::alloc(N_GLWE, BASE2K, K, RANK));
+    tensor["rank"] = (RANK.0 + 1).into();
+    assert_rejected::<GLWETensor<AlignedBuf>>(tensor, "cols=6 but expected 10");
+
+    let mut gglwe: serde_json::Value = to_value(&GGLWE::alloc(N_GLWE, BASE2K, K, RANK, RANK, DNUM, DSIZE));
+    gglwe["dsize"] = 2.into();
+    assert_rejected::<GGLWE<AlignedBuf>>(gglwe, "dnum=3 * dsize=2 exceeds size=3");
+
+    // The stored precision must match the limb count.
+    let mut lwe: serde_json::Value = to_value(&LWECompressed::alloc(BASE2K, K));
+    lwe["k"] = (K.0 + BASE2K.0).into();
+    assert_rejected::<LWECompressed<AlignedBuf>>(lwe, "does not span size=3 limbs");
+
+    // One seed per row and input column.
+    let mut gglwe: serde_json::Value = to_value(&GGLWECompressed::alloc(N_GLWE, BASE2K, K, RANK, RANK, DNUM, DSIZE));
+    gglwe["seed"].as_array_mut().unwrap().pop();
+    assert_rejected::<GGLWECompressed<AlignedBuf>>(gglwe, "seeds=5 but expected 6");
+
+    // Composite keys check the ranks of the wrapped key.
+    let key: serde_json::Value = to_value(&GLWESwitchingKey::alloc(N_GLWE, BASE2K, K, Rank(1), RANK, DNUM, DSIZE));
+    assert_rejected::<GLWEAutomorphismKey<AlignedBuf>>(
+        serde_json::json!({ "key": key["key"], "p": 5 }),
+        "rank_in=1 differs from rank_out=2",
+    );
+    assert_rejected::<LWESwitchingKey<AlignedBuf>>(key.clone(), "rank_out=2 but expected 1");
+    assert_rejected::<GLWETensorKey<AlignedBuf>>(key["key"].clone(), "rank_in=1 but expected 3");
+    assert_rejected::<GGLWEToGGSWKey<AlignedBuf>>(serde_json::json!({ "keys": [] }), "no keys");
+
+    // A secret must carry the distribution it was sampled from.
+    assert_rejected::<GLWESecretCompressed>(
+        to_value(&GLWESecretCompressed::new(N_GLWE, RANK, Distribution::NONE, [7u8; 32])),
+        "distribution is NONE",
+    );
+    assert_rejected::<LWESecretCompressed>(
+        to_value(&LWESecretCompressed::new(N_LWE, Distribution::NONE, [9u8; 32])),
+        "distribution is NONE",
+    );
+}
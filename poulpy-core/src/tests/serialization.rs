@@ -1,12 +1,23 @@
-use poulpy_hal::test_suite::serialization::test_reader_writer_interface;
-
-use crate::layouts::{
-    Base2K, Degree, Dnum, Dsize, GGLWE, GGSW, GLWE, GLWEAutomorphismKey, GLWESwitchingKey, GLWETensorKey, GLWEToLWEKey, LWE,
-    LWESwitchingKey, LWEToGLWEKey, Rank, TorusPrecision,
-    compressed::{
-        GGLWECompressed, GGSWCompressed, GLWEAutomorphismKeyCompressed, GLWECompressed, GLWESwitchingKeyCompressed,
-        GLWETensorKeyCompressed, GLWEToLWESwitchingKeyCompressed, LWECompressed, LWESwitchingKeyCompressed,
-        LWEToGLWEKeyCompressed,
+use std::fmt::Debug;
+
+use poulpy_hal::{
+    layouts::{AlignedBuf, FillUniform, ReaderFrom, WriterTo},
+    source::Source,
+    test_suite::serialization::test_reader_writer_interface,
+};
+
+use crate::{
+    dist::Distribution,
+    layouts::{
+        Base2K, Degree, Dnum, Dsize, ENVELOPE_HEADER_BYTES, ENVELOPE_MAGIC, ENVELOPE_VERSION, EnvelopeError, EnvelopeHeader,
+        EnvelopeInfos, EnvelopeKind, GGLWE, GGLWEToGGSWKey, GGSW, GLWE, GLWEAutomorphismKey, GLWEPublicKey, GLWERingSwitchKey,
+        GLWESecret, GLWESwitchingKey, GLWETensorKey, GLWEToLWEKey, GLWEToMut, LWE, LWESecret, LWESwitchingKey, LWEToGLWEKey,
+        ObjectKind, Rank, ReadEnvelope, TorusPrecision, WriteEnvelope,
+        compressed::{
+            GGLWECompressed, GGLWEToGGSWKeyCompressed, GGSWCompressed, GLWEAutomorphismKeyCompressed, GLWECompressed,
+            GLWESecretCompressed, GLWESwitchingKeyCompressed, GLWETensorKeyCompressed, GLWEToLWESwitchingKeyCompressed,
+            LWECompressed, LWESecretCompressed, LWESwitchingKeyCompressed, LWEToGLWEKeyCompressed,
+        },
     },
 };
 
@@ -20,123 +31,429 @@ const DSIZE: Dsize = Dsize(1);
 
 #[test]
 fn glwe_serialization() {
-    let original: GLWE<Vec<u8>> = GLWE::alloc(N_GLWE, BASE2K, K, RANK);
+    let original: GLWE<AlignedBuf> = GLWE::alloc(N_GLWE, BASE2K, K, RANK);
     poulpy_hal::test_suite::serialization::test_reader_writer_interface(original);
 }
 
 #[test]
 fn glwe_compressed_serialization() {
-    let original: GLWECompressed<Vec<u8>> = GLWECompressed::alloc(N_GLWE, BASE2K, K, RANK);
+    let original: GLWECompressed<AlignedBuf> = GLWECompressed::alloc(N_GLWE, BASE2K, K, RANK);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn lwe_serialization() {
-    let original: LWE<Vec<u8>> = LWE::alloc(N_LWE, BASE2K, K);
+    let original: LWE<AlignedBuf> = LWE::alloc(N_LWE, BASE2K, K);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn lwe_compressed_serialization() {
-    let original: LWECompressed<Vec<u8>> = LWECompressed::alloc(BASE2K, K);
+    let original: LWECompressed<AlignedBuf> = LWECompressed::alloc(BASE2K, K);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn test_gglwe_serialization() {
-    let original: GGLWE<Vec<u8>> = GGLWE::alloc(N_GLWE, BASE2K, K, RANK, RANK, DNUM, DSIZE);
+    let original: GGLWE<AlignedBuf> = GGLWE::alloc(N_GLWE, BASE2K, K, RANK, RANK, DNUM, DSIZE);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn test_gglwe_compressed_serialization() {
-    let original: GGLWECompressed<Vec<u8>> = GGLWECompressed::alloc(N_GLWE, BASE2K, K, RANK, RANK, DNUM, DSIZE);
+    let original: GGLWECompressed<AlignedBuf> = GGLWECompressed::alloc(N_GLWE, BASE2K, K, RANK, RANK, DNUM, DSIZE);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn test_glwe_switching_key_serialization() {
-    let original: GLWESwitchingKey<Vec<u8>> = GLWESwitchingKey::alloc(N_GLWE, BASE2K, K, RANK, RANK, DNUM, DSIZE);
+    let original: GLWESwitchingKey<AlignedBuf> = GLWESwitchingKey::alloc(N_GLWE, BASE2K, K, RANK, RANK, DNUM, DSIZE);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn test_glwe_switching_key_compressed_serialization() {
-    let original: GLWESwitchingKeyCompressed<Vec<u8>> =
+    let original: GLWESwitchingKeyCompressed<AlignedBuf> =
         GLWESwitchingKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, RANK, DNUM, DSIZE);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn test_automorphism_key_serialization() {
-    let original: GLWEAutomorphismKey<Vec<u8>> = GLWEAutomorphismKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE);
+    let original: GLWEAutomorphismKey<AlignedBuf> = GLWEAutomorphismKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn test_automorphism_key_compressed_serialization() {
-    let original: GLWEAutomorphismKeyCompressed<Vec<u8>> =
+    let original: GLWEAutomorphismKeyCompressed<AlignedBuf> =
         GLWEAutomorphismKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn test_tensor_key_serialization() {
-    let original: GLWETensorKey<Vec<u8>> = GLWETensorKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE);
+    let original: GLWETensorKey<AlignedBuf> = GLWETensorKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn test_tensor_key_compressed_serialization() {
-    let original: GLWETensorKeyCompressed<Vec<u8>> = GLWETensorKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE);
+    let original: GLWETensorKeyCompressed<AlignedBuf> = GLWETensorKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn glwe_to_lwe_key_serialization() {
-    let original: GLWEToLWEKey<Vec<u8>> = GLWEToLWEKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM);
+    let original: GLWEToLWEKey<AlignedBuf> = GLWEToLWEKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn glwe_to_lwe_key_compressed_serialization() {
-    let original: GLWEToLWESwitchingKeyCompressed<Vec<u8>> =
+    let original: GLWEToLWESwitchingKeyCompressed<AlignedBuf> =
         GLWEToLWESwitchingKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn lwe_to_glwe_key_serialization() {
-    let original: LWEToGLWEKey<Vec<u8>> = LWEToGLWEKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM);
+    let original: LWEToGLWEKey<AlignedBuf> = LWEToGLWEKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn lwe_to_glwe_key_compressed_serialization() {
-    let original: LWEToGLWEKeyCompressed<Vec<u8>> = LWEToGLWEKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM);
+    let original: LWEToGLWEKeyCompressed<AlignedBuf> = LWEToGLWEKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn lwe_switching_key_serialization() {
-    let original: LWESwitchingKey<Vec<u8>> = LWESwitchingKey::alloc(N_GLWE, BASE2K, K, DNUM);
+    let original: LWESwitchingKey<AlignedBuf> = LWESwitchingKey::alloc(N_GLWE, BASE2K, K, DNUM);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn lwe_switching_key_compressed_serialization() {
-    let original: LWESwitchingKeyCompressed<Vec<u8>> = LWESwitchingKeyCompressed::alloc(N_GLWE, BASE2K, K, DNUM);
+    let original: LWESwitchingKeyCompressed<AlignedBuf> = LWESwitchingKeyCompressed::alloc(N_GLWE, BASE2K, K, DNUM);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn ggsw_serialization() {
-    let original: GGSW<Vec<u8>> = GGSW::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE);
+    let original: GGSW<AlignedBuf> = GGSW::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn ggsw_compressed_serialization() {
-    let original: GGSWCompressed<Vec<u8>> = GGSWCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE);
+    let original: GGSWCompressed<AlignedBuf> = GGSWCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE);
+    test_reader_writer_interface(original);
+}
+
+#[test]
+fn glwe_secret_serialization() {
+    let original: GLWESecret<AlignedBuf> = GLWESecret::alloc(N_GLWE, RANK);
+    test_reader_writer_interface(original);
+}
+
+#[test]
+fn glwe_secret_serialization_dist() {
+    let mut source: Source = Source::new([1u8; 32]);
+    for dist in [
+        Distribution::TernaryFixed(16),
+        Distribution::TernaryProb(0.5),
+        Distribution::BinaryFixed(16),
+        Distribution::BinaryProb(0.5),
+        Distribution::BinaryBlock(8),
+        Distribution::ZERO,
+    ] {
+        let mut original: GLWESecret<AlignedBuf> = GLWESecret::alloc(N_GLWE, RANK);
+        original.fill_dist(dist, &mut source);
+
+        let mut buffer: Vec<u8> = Vec::new();
+        original.write_to(&mut buffer).expect("write_to failed");
+
+        let mut receiver: GLWESecret<AlignedBuf> = GLWESecret::alloc(N_GLWE, RANK);
+        receiver.read_from(&mut buffer.as_slice()).expect("read_from failed");
+
+        assert_eq!(original, receiver);
+    }
+}
+
+#[test]
+fn glwe_secret_compressed_serialization() {
+    let original: GLWESecretCompressed = GLWESecretCompressed::new(N_GLWE, RANK, Distribution::TernaryProb(0.5), [0u8; 32]);
+    test_reader_writer_interface(original);
+}
+
+#[test]
+fn glwe_secret_compressed_decompress() {
+    let mut compressed: GLWESecretCompressed = GLWESecretCompressed::alloc(N_GLWE, RANK);
+    compressed.fill_dist(Distribution::TernaryFixed(16), &mut Source::new([2u8; 32]));
+
+    let mut buffer: Vec<u8> = Vec::new();
+    compressed.write_to(&mut buffer).expect("write_to failed");
+    assert_eq!(buffer.len(), GLWESecretCompressed::bytes_of());
+
+    let mut receiver: GLWESecretCompressed = GLWESecretCompressed::alloc(N_GLWE, RANK);
+    receiver.read_from(&mut buffer.as_slice()).expect("read_from failed");
+    assert_eq!(compressed, receiver);
+
+    let mut want: GLWESecret<AlignedBuf> = GLWESecret::alloc(N_GLWE, RANK);
+    want.fill_ternary_hw(16, &mut Source::new(*compressed.seed()));
+
+    let mut have: GLWESecret<AlignedBuf> = GLWESecret::alloc(N_GLWE, RANK);
+    have.decompress(&receiver);
+
+    assert_eq!(want, have);
+}
+
+#[test]
+fn secret_compressed_rejects_none_dist() {
+    let mut buffer: Vec<u8> = Vec::new();
+    GLWESecretCompressed::alloc(N_GLWE, RANK)
+        .write_to(&mut buffer)
+        .expect("write_to failed");
+    let mut receiver: GLWESecretCompressed = GLWESecretCompressed::alloc(N_GLWE, RANK);
+    let err: std::io::Error = receiver.read_from(&mut buffer.as_slice()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    let mut buffer: Vec<u8> = Vec::new();
+    LWESecretCompressed::alloc(N_LWE)
+        .write_to(&mut buffer)
+        .expect("write_to failed");
+    let mut receiver: LWESecretCompressed = LWESecretCompressed::alloc(N_LWE);
+    let err: std::io::Error = receiver.read_from(&mut buffer.as_slice()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn secret_compressed_fmt_redacts_seed() {
+    let seed: [u8; 32] = [0xAB; 32];
+    let glwe: GLWESecretCompressed = GLWESecretCompressed::new(N_GLWE, RANK, Distribution::TernaryProb(0.5), seed);
+    let lwe: LWESecretCompressed = LWESecretCompressed::new(N_LWE, Distribution::BinaryFixed(8), seed);
+
+    for s in [format!("{glwe}"), format!("{glwe:?}"), format!("{lwe}"), format!("{lwe:?}")] {
+        assert!(s.contains("seed=<redacted>"), "{s}");
+        assert!(!s.contains("171"), "{s}");
+    }
+}
+
+#[test]
+fn lwe_secret_serialization() {
+    let original: LWESecret<AlignedBuf> = LWESecret::alloc(N_LWE);
+    test_reader_writer_interface(original);
+}
+
+#[test]
+fn lwe_secret_serialization_dist() {
+    let mut original: LWESecret<AlignedBuf> = LWESecret::alloc(N_LWE);
+    original.fill_binary_block(8, &mut Source::new([3u8; 32]));
+
+    let mut buffer: Vec<u8> = Vec::new();
+    original.write_to(&mut buffer).expect("write_to failed");
+
+    let mut receiver: LWESecret<AlignedBuf> = LWESecret::alloc(N_LWE);
+    receiver.read_from(&mut buffer.as_slice()).expect("read_from failed");
+
+    assert_eq!(original, receiver);
+}
+
+#[test]
+fn lwe_secret_compressed_serialization() {
+    let original: LWESecretCompressed = LWESecretCompressed::new(N_LWE, Distribution::BinaryFixed(8), [0u8; 32]);
     test_reader_writer_interface(original);
 }
+
+#[test]
+fn lwe_secret_compressed_decompress() {
+    let mut compressed: LWESecretCompressed = LWESecretCompressed::alloc(N_LWE);
+    compressed.fill_dist(Distribution::BinaryProb(0.5), &mut Source::new([4u8; 32]));
+
+    let mut want: LWESecret<AlignedBuf> = LWESecret::alloc(N_LWE);
+    want.fill_binary_prob(0.5, &mut Source::new(*compressed.seed()));
+
+    let mut have: LWESecret<AlignedBuf> = LWESecret::alloc(N_LWE);
+    have.decompress(&compressed);
+
+    assert_eq!(want, have);
+}
+
+/// Envelope round trip: the receiver must match the original, and the stream
+/// must be consumed entirely.
+fn test_envelope_interface<T>(mut original: T)
+where
+    T: WriteEnvelope + ReadEnvelope + PartialEq + Eq + Debug + Clone + FillUniform,
+{
+    let mut source: Source = Source::new([0u8; 32]);
+    original.fill_uniform(50, &mut source);
+
+    let mut buffer: Vec<u8> = Vec::new();
+    original.write_envelope(&mut buffer).expect("write_envelope failed");
+
+    let header: EnvelopeHeader = EnvelopeHeader::read_from(&mut buffer.as_slice()).expect("invalid header");
+    assert_eq!(header.version, ENVELOPE_VERSION);
+    assert_eq!(header.kind, T::KIND);
+    assert_eq!(header.infos, original.envelope_infos());
+    assert_eq!(buffer.len(), ENVELOPE_HEADER_BYTES + header.payload_len as usize + 4);
+
+    let mut receiver: T = original.clone();
+    receiver.fill_uniform(50, &mut source);
+
+    let mut reader: &[u8] = &buffer;
+    receiver.read_envelope(&mut reader).expect("read_envelope failed");
+    assert!(reader.is_empty());
+    assert_eq!(original, receiver);
+}
+
+#[test]
+fn envelope_serialization() {
+    test_envelope_interface(LWE::alloc(N_LWE, BASE2K, K));
+    test_envelope_interface(GLWE::alloc(N_GLWE, BASE2K, K, RANK));
+    test_envelope_interface(GGLWE::alloc(N_GLWE, BASE2K, K, RANK, RANK, DNUM, DSIZE));
+    test_envelope_interface(GGSW::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE));
+    test_envelope_interface(LWESecret::alloc(N_LWE));
+    test_envelope_interface(GLWESecret::alloc(N_GLWE, RANK));
+    test_envelope_interface(GLWESwitchingKey::alloc(N_GLWE, BASE2K, K, RANK, RANK, DNUM, DSIZE));
+    test_envelope_interface(GLWEAutomorphismKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE));
+    test_envelope_interface(GLWETensorKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE));
+    test_envelope_interface(GGLWEToGGSWKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE));
+    test_envelope_interface(GLWEToLWEKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM));
+    test_envelope_interface(LWEToGLWEKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM));
+    test_envelope_interface(LWESwitchingKey::alloc(N_GLWE, BASE2K, K, DNUM));
+    test_envelope_interface(GLWERingSwitchKey::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE));
+}
+
+#[test]
+fn envelope_compressed_serialization() {
+    test_envelope_interface(LWECompressed::alloc(BASE2K, K));
+    test_envelope_interface(GLWECompressed::alloc(N_GLWE, BASE2K, K, RANK));
+    test_envelope_interface(GGLWECompressed::alloc(N_GLWE, BASE2K, K, RANK, RANK, DNUM, DSIZE));
+    test_envelope_interface(GGSWCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE));
+    test_envelope_interface(LWESecretCompressed::new(N_LWE, Distribution::BinaryFixed(8), [0u8; 32]));
+    test_envelope_interface(GLWESecretCompressed::new(
+        N_GLWE,
+        RANK,
+        Distribution::TernaryProb(0.5),
+        [0u8; 32],
+    ));
+    test_envelope_interface(GLWESwitchingKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, RANK, DNUM, DSIZE));
+    test_envelope_interface(GLWEAutomorphismKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE));
+    test_envelope_interface(GLWETensorKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE));
+    test_envelope_interface(GGLWEToGGSWKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM, DSIZE));
+    test_envelope_interface(GLWEToLWESwitchingKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM));
+    test_envelope_interface(LWEToGLWEKeyCompressed::alloc(N_GLWE, BASE2K, K, RANK, DNUM));
+    test_envelope_interface(LWESwitchingKeyCompressed::alloc(N_GLWE, BASE2K, K, DNUM));
+}
+
+#[test]
+fn envelope_public_key_serialization() {
+    let mut original: GLWEPublicKey<AlignedBuf> = GLWEPublicKey::alloc(N_GLWE, BASE2K, K, RANK);
+    original.to_mut().fill_uniform(50, &mut Source::new([5u8; 32]));
+
+    let mut buffer: Vec<u8> = Vec::new();
+    original.write_envelope(&mut buffer).expect("write_envelope failed");
+
+    let mut receiver: GLWEPublicKey<AlignedBuf> = GLWEPublicKey::alloc(N_GLWE, BASE2K, K, RANK);
+    receiver.read_envelope(&mut buffer.as_slice()).expect("read_envelope failed");
+    assert!(original == receiver);
+}
+
+#[test]
+fn envelope_rejects_mismatches() {
+    let mut original: GLWE<AlignedBuf> = GLWE::alloc(N_GLWE, BASE2K, K, RANK);
+    original.fill_uniform(50, &mut Source::new([6u8; 32]));
+    let mut buffer: Vec<u8> = Vec::new();
+    original.write_envelope(&mut buffer).expect("write_envelope failed");
+    let infos: EnvelopeInfos = original.envelope_infos();
+
+    // Raw (unframed) stream.
+    let mut raw: Vec<u8> = Vec::new();
+    original.write_to(&mut raw).expect("write_to failed");
+    let mut receiver: GLWE<AlignedBuf> = GLWE::alloc(N_GLWE, BASE2K, K, RANK);
+    assert!(matches!(
+        receiver.read_envelope(&mut raw.as_slice()),
+        Err(EnvelopeError::BadMagic(_))
+    ));
+
+    // Bad magic.
+    let mut bytes: Vec<u8> = buffer.clone();
+    bytes[0] ^= 1;
+    assert!(matches!(
+        receiver.read_envelope(&mut bytes.as_slice()),
+        Err(EnvelopeError::BadMagic(_))
+    ));
+    assert_eq!(&buffer[..4], &ENVELOPE_MAGIC);
+
+    // Future version.
+    let mut bytes: Vec<u8> = buffer.clone();
+    bytes[4..6].copy_from_slice(&(ENVELOPE_VERSION + 1).to_le_bytes());
+    assert!(matches!(
+        receiver.read_envelope(&mut bytes.as_slice()),
+        Err(EnvelopeError::UnsupportedVersion(v)) if v == ENVELOPE_VERSION + 1
+    ));
+
+    // Unknown kind.
+    let mut bytes: Vec<u8> = buffer.clone();
+    bytes[6..8].copy_from_slice(&0xFFFFu16.to_le_bytes());
+    assert!(matches!(
+        receiver.read_envelope(&mut bytes.as_slice()),
+        Err(EnvelopeError::UnknownKind(0xFFFF))
+    ));
+
+    // A GLWE read back as an LWE or a GGLWE.
+    let mut lwe: LWE<AlignedBuf> = LWE::alloc(N_GLWE, BASE2K, K);
+    assert!(matches!(
+        lwe.read_envelope(&mut buffer.as_slice()),
+        Err(EnvelopeError::KindMismatch {
+            expected: ObjectKind::LWE,
+            actual: ObjectKind::GLWE,
+        })
+    ));
+    let mut gglwe: GGLWE<AlignedBuf> = GGLWE::alloc(N_GLWE, BASE2K, K, RANK, RANK, DNUM, DSIZE);
+    assert!(matches!(
+        gglwe.read_envelope(&mut buffer.as_slice()),
+        Err(EnvelopeError::KindMismatch {
+            expected: ObjectKind::GGLWE,
+            actual: ObjectKind::GLWE,
+        })
+    ));
+
+    // A GLWE of another rank.
+    let mut other_rank: GLWE<AlignedBuf> = GLWE::alloc(N_GLWE, BASE2K, K, Rank(1));
+    match other_rank.read_envelope(&mut buffer.as_slice()) {
+        Err(EnvelopeError::InfosMismatch { expected, actual }) => {
+            assert_eq!(expected.rank_out, 1);
+            assert_eq!(actual, infos);
+        }
+        res => panic!("expected InfosMismatch, got {res:?}"),
+    }
+
+    // Corrupted payload and corrupted checksum.
+    for i in [buffer.len() - 12, buffer.len() - 1] {
+        let mut bytes: Vec<u8> = buffer.clone();
+        bytes[i] ^= 0x80;
+        assert!(matches!(
+            receiver.read_envelope(&mut bytes.as_slice()),
+            Err(EnvelopeError::ChecksumMismatch { .. })
+        ));
+    }
+
+    // Header announcing a longer payload than written.
+    let mut bytes: Vec<u8> = buffer.clone();
+    let mut header: EnvelopeHeader = EnvelopeHeader::read_from(&mut bytes.as_slice()).unwrap();
+    header.payload_len += 1;
+    bytes[..ENVELOPE_HEADER_BYTES].copy_from_slice(&header.to_bytes());
+    assert!(matches!(
+        receiver.read_envelope(&mut bytes.as_slice()),
+        Err(EnvelopeError::PayloadLength { .. })
+    ));
+
+    // Truncated stream.
+    let bytes: &[u8] = &buffer[..buffer.len() - 2];
+    let err: EnvelopeError = receiver.read_envelope(&mut &bytes[..]).unwrap_err();
+    assert!(matches!(err, EnvelopeError::Io(_)));
+    assert_eq!(std::io::Error::from(err).kind(), std::io::ErrorKind::UnexpectedEof);
+}
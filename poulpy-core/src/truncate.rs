@@ -0,0 +1,196 @@
+//! Modulus truncation of GLWE and LWE ciphertexts.
+//!
+//! A ciphertext only needs enough precision for its error to stay below
+//! half a plaintext step. Truncation rounds it to `k` bits of torus
+//! precision, which reduces the size of results that are sent back to a
+//! client without affecting decryption, as long as `k` is chosen with
+//! [`NoiseTracker::glwe_truncate_min_k`](crate::NoiseTracker::glwe_truncate_min_k)
+//! or [`NoiseTracker::lwe_truncate_min_k`](crate::NoiseTracker::lwe_truncate_min_k).
+//!
+//! Truncated ciphertexts are serialised with
+//! [`WriteTruncated`](crate::layouts::WriteTruncated), which packs the `k`
+//! significant bits of every coefficient.
+
+use poulpy_hal::{
+    api::{ModuleN, ScratchAvailable, VecZnxNormalize, VecZnxNormalizeTmpBytes},
+    layouts::{AlignedBuf, Backend, Module, Scratch},
+};
+
+pub use crate::api::{GLWETruncate, LWETruncate};
+use crate::{
+    GLWENormalize, GLWEShift, ScratchTakeCore,
+    layouts::{GLWE, GLWEInfos, GLWELayout, GLWEToMut, GLWEToRef, LWE, LWEInfos, LWELayout, LWEToMut, LWEToRef},
+};
+
+#[doc(hidden)]
+pub trait GLWETruncateDefault<BE: Backend>
+where
+    Self: ModuleN + GLWENormalize<BE> + GLWEShift<BE> + VecZnxNormalize<BE> + VecZnxNormalizeTmpBytes,
+{
+    fn glwe_truncate_tmp_bytes_default<R>(&self, res_infos: &R) -> usize
+    where
+        R: GLWEInfos,
+    {
+        let lvl_0: usize = GLWE::<AlignedBuf>::bytes_of_from_infos(res_infos);
+        let lvl_1: usize = self
+            .vec_znx_normalize_tmp_bytes()
+            .max(self.glwe_normalize_tmp_bytes())
+            .max(self.glwe_shift_tmp_bytes());
+
+        lvl_0 + lvl_1
+    }
+
+    fn glwe_truncate_default<R, A>(&self, res: &mut R, k: usize, a: &A, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut,
+        A: GLWEToRef,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        let res: &mut GLWE<&mut [u8]> = &mut res.to_mut();
+        let a: &GLWE<&[u8]> = &a.to_ref();
+
+        assert_eq!(res.rank(), a.rank());
+        assert!(k <= res.max_k().as_usize(), "k: {k} > res.max_k(): {}", res.max_k());
+        assert!(
+            scratch.available() >= self.glwe_truncate_tmp_bytes_default(res),
+            "scratch.available(): {} < GLWETruncate::glwe_truncate_tmp_bytes: {}",
+            scratch.available(),
+            self.glwe_truncate_tmp_bytes_default(res)
+        );
+
+        let base2k: usize = res.base2k().into();
+        let size: usize = k.div_ceil(base2k);
+        let shift: usize = size * base2k - k;
+
+        // tmp = round(a * 2^-shift) on `size` limbs, i.e. a rounded to k bits
+        // and scaled down, then res = tmp * 2^shift, which is exact.
+        let (mut tmp, scratch_1) = scratch.take_glwe(&GLWELayout {
+            n: res.n(),
+            base2k: res.base2k(),
+            k: (size * base2k).into(),
+            rank: res.rank(),
+        });
+        for col in 0..(res.rank() + 1).into() {
+            self.vec_znx_normalize(&mut tmp.data, base2k, -(shift as i64), col, &a.data, base2k, col, scratch_1);
+        }
+        self.glwe_lsh_assign(&mut tmp, shift, scratch_1);
+        self.glwe_normalize(res, &tmp, scratch_1);
+    }
+
+    fn glwe_truncate_assign_default<R>(&self, res: &mut R, k: usize, scratch: &mut Scratch<BE>)
+    where
+        R: GLWEToMut,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        let res: &mut GLWE<&mut [u8]> = &mut res.to_mut();
+
+        assert!(k <= res.max_k().as_usize(), "k: {k} > res.max_k(): {}", res.max_k());
+        assert!(
+            scratch.available() >= self.glwe_truncate_tmp_bytes_default(res),
+            "scratch.available(): {} < GLWETruncate::glwe_truncate_tmp_bytes: {}",
+            scratch.available(),
+            self.glwe_truncate_tmp_bytes_default(res)
+        );
+
+        let base2k: usize = res.base2k().into();
+        let size: usize = k.div_ceil(base2k);
+        let shift: usize = size * base2k - k;
+
+        let (mut tmp, scratch_1) = scratch.take_glwe(&GLWELayout {
+            n: res.n(),
+            base2k: res.base2k(),
+            k: (size * base2k).into(),
+            rank: res.rank(),
+        });
+        for col in 0..(res.rank() + 1).into() {
+            self.vec_znx_normalize(&mut tmp.data, base2k, -(shift as i64), col, &res.data, base2k, col, scratch_1);
+        }
+        self.glwe_lsh_assign(&mut tmp, shift, scratch_1);
+        self.glwe_normalize(res, &tmp, scratch_1);
+    }
+}
+
+impl<BE: Backend> GLWETruncateDefault<BE> for Module<BE> where
+    Self: ModuleN + GLWENormalize<BE> + GLWEShift<BE> + VecZnxNormalize<BE> + VecZnxNormalizeTmpBytes
+{
+}
+
+#[doc(hidden)]
+pub trait LWETruncateDefault<BE: Backend>
+where
+    Self: ModuleN + VecZnxNormalize<BE> + VecZnxNormalizeTmpBytes,
+{
+    fn lwe_truncate_tmp_bytes_default<R>(&self, res_infos: &R) -> usize
+    where
+        R: LWEInfos,
+    {
+        let lvl_0: usize = LWE::<AlignedBuf>::bytes_of_from_infos(res_infos);
+        let lvl_1: usize = self.vec_znx_normalize_tmp_bytes();
+
+        lvl_0 + lvl_1
+    }
+
+    fn lwe_truncate_default<R, A>(&self, res: &mut R, k: usize, a: &A, scratch: &mut Scratch<BE>)
+    where
+        R: LWEToMut,
+        A: LWEToRef,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        let res: &mut LWE<&mut [u8]> = &mut res.to_mut();
+        let a: &LWE<&[u8]> = &a.to_ref();
+
+        assert_eq!(res.n(), a.n());
+        assert_eq!(res.base2k(), a.base2k());
+        assert!(k <= res.max_k().as_usize(), "k: {k} > res.max_k(): {}", res.max_k());
+        assert!(
+            scratch.available() >= self.lwe_truncate_tmp_bytes_default(res),
+            "scratch.available(): {} < LWETruncate::lwe_truncate_tmp_bytes: {}",
+            scratch.available(),
+            self.lwe_truncate_tmp_bytes_default(res)
+        );
+
+        let base2k: usize = res.base2k().into();
+        let size: usize = k.div_ceil(base2k);
+        let shift: i64 = (size * base2k - k) as i64;
+
+        // tmp = round(a * 2^-shift) on `size` limbs, i.e. a rounded to k bits
+        // and scaled down, then res = tmp * 2^shift, which is exact.
+        let (mut tmp, scratch_1) = scratch.take_lwe(&LWELayout {
+            n: res.n(),
+            base2k: res.base2k(),
+            k: (size * base2k).into(),
+        });
+        self.vec_znx_normalize(&mut tmp.data, base2k, -shift, 0, &a.data, base2k, 0, scratch_1);
+        self.vec_znx_normalize(&mut res.data, base2k, shift, 0, &tmp.data, base2k, 0, scratch_1);
+    }
+
+    fn lwe_truncate_assign_default<R>(&self, res: &mut R, k: usize, scratch: &mut Scratch<BE>)
+    where
+        R: LWEToMut,
+        Scratch<BE>: ScratchTakeCore<BE>,
+    {
+        let res: &mut LWE<&mut [u8]> = &mut res.to_mut();
+
+        assert!(k <= res.max_k().as_usize(), "k: {k} > res.max_k(): {}", res.max_k());
+        assert!(
+            scratch.available() >= self.lwe_truncate_tmp_bytes_default(res),
+            "scratch.available(): {} < LWETruncate::lwe_truncate_tmp_bytes: {}",
+            scratch.available(),
+            self.lwe_truncate_tmp_bytes_default(res)
+        );
+
+        let base2k: usize = res.base2k().into();
+        let size: usize = k.div_ceil(base2k);
+        let shift: i64 = (size * base2k - k) as i64;
+
+        let (mut tmp, scratch_1) = scratch.take_lwe(&LWELayout {
+            n: res.n(),
+            base2k: res.base2k(),
+            k: (size * base2k).into(),
+        });
+        self.vec_znx_normalize(&mut tmp.data, base2k, -shift, 0, &res.data, base2k, 0, scratch_1);
+        self.vec_znx_normalize(&mut res.data, base2k, shift, 0, &tmp.data, base2k, 0, scratch_1);
+    }
+}
+
+impl<BE: Backend> LWETruncateDefault<BE> for Module<BE> where Self: ModuleN + VecZnxNormalize<BE> + VecZnxNormalizeTmpBytes {}
@@ -21,7 +21,7 @@ use poulpy_hal::{
         VecZnxBigAddSmallAssign, VecZnxBigAlloc, VecZnxBigNormalize, VecZnxBigNormalizeTmpBytes, VecZnxBigSubSmallNegateAssign,
         VecZnxDftAlloc, VecZnxDftApply, VecZnxFillUniform, VecZnxIdftApplyTmpA, VecZnxNormalizeAssign,
     },
-    layouts::{DeviceBuf, Module, NoiseInfos, ScalarZnx, ScratchOwned, VecZnx, VecZnxBig, VecZnxDft, ZnxInfos},
+    layouts::{AlignedBuf, DeviceBuf, Module, NoiseInfos, ScalarZnx, ScratchOwned, VecZnx, VecZnxBig, VecZnxDft, ZnxInfos},
     source::Source,
 };
 
@@ -40,7 +40,7 @@ fn main() {
     let mut source: Source = Source::new(seed);
 
     // s <- Z_{-1, 0, 1}[X]/(X^{N}+1)
-    let mut s: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(module.n(), 1);
+    let mut s: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(module.n(), 1);
     s.fill_ternary_prob(0, 0.5, &mut source);
 
     // Buffer to store s in the DFT domain
@@ -50,7 +50,7 @@ fn main() {
     module.svp_prepare(&mut s_dft, 0, &s, 0);
 
     // Allocates a VecZnx with two columns: ct=(0, 0)
-    let mut ct: VecZnx<Vec<u8>> = VecZnx::alloc(
+    let mut ct: VecZnx<AlignedBuf> = VecZnx::alloc(
         module.n(),
         2,       // Number of columns
         ct_size, // Number of small poly per column
@@ -0,0 +1,58 @@
+//! Runtime-selected CPU backend families.
+//!
+//! Each family wraps the AVX backend when the crate is built with `enable-avx`
+//! and the host CPU supports the required instruction sets, and the portable
+//! reference backend otherwise. Both members of a family share the data
+//! layout of the reference backend, so prepared keys and scratch buffers are
+//! interchangeable.
+
+use poulpy_cpu_ref::{FFT64Ref, NTT120Ref};
+use poulpy_hal::{
+    api::ModuleNew,
+    dyn_backend::{DynBackend, DynBackendFamily},
+    layouts::Module,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// [`DynBackendFamily`] of the `f64` FFT backends: [`FFT64Avx`](crate::FFT64Avx)
+/// on CPUs with AVX, AVX2 and FMA, [`FFT64Ref`] otherwise.
+pub struct FFT64Family;
+
+/// [`DynBackendFamily`] of the Q120 NTT backends: [`NTT120Avx`](crate::NTT120Avx)
+/// on CPUs with AVX2, [`NTT120Ref`] otherwise.
+pub struct NTT120Family;
+
+/// `f64` FFT backend selected at runtime, see [`FFT64Family`].
+pub type FFT64Dyn = DynBackend<FFT64Family>;
+
+/// Q120 NTT backend selected at runtime, see [`NTT120Family`].
+pub type NTT120Dyn = DynBackend<NTT120Family>;
+
+impl DynBackendFamily for FFT64Family {
+    type Layout = FFT64Ref;
+
+    fn select(n: u64) -> Module<FFT64Dyn> {
+        #[cfg(all(feature = "enable-avx", target_arch = "x86_64"))]
+        if std::arch::is_x86_feature_detected!("avx")
+            && std::arch::is_x86_feature_detected!("avx2")
+            && std::arch::is_x86_feature_detected!("fma")
+        {
+            return Module::from_module(Module::<crate::FFT64Avx>::new(n));
+        }
+        Module::from_module(Module::<FFT64Ref>::new(n))
+    }
+}
+
+impl DynBackendFamily for NTT120Family {
+    type Layout = NTT120Ref;
+
+    fn select(n: u64) -> Module<NTT120Dyn> {
+        #[cfg(all(feature = "enable-avx", target_arch = "x86_64"))]
+        if std::arch::is_x86_feature_detected!("avx2") {
+            return Module::from_module(Module::<crate::NTT120Avx>::new(n));
+        }
+        Module::from_module(Module::<NTT120Ref>::new(n))
+    }
+}
@@ -16,7 +16,10 @@ use poulpy_cpu_ref::reference::{
         znx_rotate, znx_zero_ref,
     },
 };
-use poulpy_hal::{alloc_aligned, assert_alignment, layouts::Backend};
+use poulpy_hal::{
+    alloc_aligned,
+    layouts::{AlignedBuf, Backend},
+};
 
 use crate::{
     FFT64Avx,
@@ -80,14 +83,14 @@ pub struct FFT64AvxHandle {
 impl Backend for FFT64Avx {
     type ScalarPrep = f64;
     type ScalarBig = i64;
-    type OwnedBuf = Vec<u8>;
+    type OwnedBuf = AlignedBuf;
     type Handle = FFT64AvxHandle;
+    const PREPARED_LAYOUT: &'static str = "fft64";
     fn alloc_bytes(len: usize) -> Self::OwnedBuf {
-        alloc_aligned::<u8>(len)
+        alloc_aligned(len)
     }
     fn from_bytes(bytes: Vec<u8>) -> Self::OwnedBuf {
-        assert_alignment(bytes.as_ptr());
-        bytes
+        AlignedBuf::from(bytes)
     }
     unsafe fn destroy(handle: NonNull<Self::Handle>) {
         unsafe {
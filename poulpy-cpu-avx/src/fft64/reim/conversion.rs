@@ -399,7 +399,7 @@ pub fn reim_to_znx_i64_avx2_bnd50_fma(res: &mut [i64], divisor: f64, a: &[f64])
 // Tests
 // ──────────────────────────────────────────────────────────────────────────────
 
-#[cfg(all(test, target_feature = "avx2"))]
+#[cfg(all(test, feature = "enable-avx"))]
 mod tests {
     use poulpy_cpu_ref::reference::fft64::reim::{reim_from_znx_i64_ref, reim_to_znx_i64_ref};
 
@@ -408,6 +408,10 @@ mod tests {
     /// AVX2 `reim_from_znx_i64_bnd50_fma` matches reference for bounded i64 inputs.
     #[test]
     fn reim_from_znx_i64_avx2_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") || !std::is_x86_feature_detected!("fma") {
+            eprintln!("skipping: CPU lacks avx2/fma");
+            return;
+        };
         let n = 64usize;
         let a: Vec<i64> = (0..n as i64).map(|i| i * 997 - 32000).collect();
 
@@ -423,6 +427,10 @@ mod tests {
     /// AVX2 `reim_to_znx_i64_bnd63_avx2_fma` matches reference for exact-float inputs.
     #[test]
     fn reim_to_znx_i64_avx2_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") || !std::is_x86_feature_detected!("fma") {
+            eprintln!("skipping: CPU lacks avx2/fma");
+            return;
+        };
         let n = 64usize;
         let divisor = 4.0f64;
         // Exact multiples of divisor so rounding is unambiguous
@@ -230,7 +230,7 @@ fn bitwiddle_fft_avx2_fma(h: usize, re: &mut [f64], im: &mut [f64], omg: &[f64;
 // Tests
 // ──────────────────────────────────────────────────────────────────────────────
 
-#[cfg(all(test, target_feature = "avx2"))]
+#[cfg(all(test, feature = "enable-avx"))]
 mod tests {
     use poulpy_cpu_ref::reference::fft64::reim::{ReimFFTExecute, ReimFFTRef, ReimFFTTable, ReimIFFTRef, ReimIFFTTable};
 
@@ -239,6 +239,10 @@ mod tests {
     /// AVX2 FFT → IFFT round-trip matches reference FFT → IFFT (same residual error).
     #[test]
     fn fft_ifft_roundtrip_avx2() {
+        if !std::is_x86_feature_detected!("avx2") || !std::is_x86_feature_detected!("fma") {
+            eprintln!("skipping: CPU lacks avx2/fma");
+            return;
+        };
         let m = 64usize;
         let fwd = ReimFFTTable::<f64>::new(m);
         let inv = ReimIFFTTable::<f64>::new(m);
@@ -271,6 +275,10 @@ mod tests {
     /// Multiplies in frequency domain (complex pointwise) and IFFTs.
     #[test]
     fn fft_convolution_avx2() {
+        if !std::is_x86_feature_detected!("avx2") || !std::is_x86_feature_detected!("fma") {
+            eprintln!("skipping: CPU lacks avx2/fma");
+            return;
+        };
         let m = 32usize;
         let fwd = ReimFFTTable::<f64>::new(m);
         let inv = ReimIFFTTable::<f64>::new(m);
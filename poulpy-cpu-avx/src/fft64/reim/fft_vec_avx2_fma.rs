@@ -360,7 +360,7 @@ pub fn reim_mul_assign_avx2_fma(res: &mut [f64], a: &[f64]) {
 // Tests
 // ──────────────────────────────────────────────────────────────────────────────
 
-#[cfg(all(test, target_feature = "avx2"))]
+#[cfg(all(test, feature = "enable-avx"))]
 mod tests {
     use poulpy_cpu_ref::reference::fft64::reim::{
         reim_add_ref, reim_addmul_ref, reim_mul_ref, reim_negate_ref, reim_sub_negate_assign_ref, reim_sub_ref,
@@ -374,6 +374,10 @@ mod tests {
 
     #[test]
     fn reim_add_avx2_fma_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") || !std::is_x86_feature_detected!("fma") {
+            eprintln!("skipping: CPU lacks avx2/fma");
+            return;
+        };
         let n = 64usize;
         let a = reim_data(n, 1.7);
         let b = reim_data(n, 2.3);
@@ -386,6 +390,10 @@ mod tests {
 
     #[test]
     fn reim_sub_avx2_fma_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") || !std::is_x86_feature_detected!("fma") {
+            eprintln!("skipping: CPU lacks avx2/fma");
+            return;
+        };
         let n = 64usize;
         let a = reim_data(n, 3.1);
         let b = reim_data(n, 1.4);
@@ -398,6 +406,10 @@ mod tests {
 
     #[test]
     fn reim_negate_avx2_fma_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") || !std::is_x86_feature_detected!("fma") {
+            eprintln!("skipping: CPU lacks avx2/fma");
+            return;
+        };
         let n = 64usize;
         let a = reim_data(n, 2.9);
         let mut res_avx = vec![0f64; n];
@@ -409,6 +421,10 @@ mod tests {
 
     #[test]
     fn reim_mul_avx2_fma_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") || !std::is_x86_feature_detected!("fma") {
+            eprintln!("skipping: CPU lacks avx2/fma");
+            return;
+        };
         let n = 64usize;
         let a = reim_data(n, 1.3);
         let b = reim_data(n, 2.7);
@@ -429,6 +445,10 @@ mod tests {
 
     #[test]
     fn reim_addmul_avx2_fma_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") || !std::is_x86_feature_detected!("fma") {
+            eprintln!("skipping: CPU lacks avx2/fma");
+            return;
+        };
         let n = 64usize;
         let a = reim_data(n, 1.1);
         let b = reim_data(n, 2.2);
@@ -450,6 +470,10 @@ mod tests {
 
     #[test]
     fn reim_sub_negate_assign_avx2_fma_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") || !std::is_x86_feature_detected!("fma") {
+            eprintln!("skipping: CPU lacks avx2/fma");
+            return;
+        };
         let n = 64usize;
         let a = reim_data(n, 1.8);
         let init = reim_data(n, 3.3);
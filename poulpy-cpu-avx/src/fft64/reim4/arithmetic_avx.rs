@@ -650,7 +650,7 @@ pub unsafe fn reim4_convolution_by_real_const_2coeffs_avx(k: usize, dst: &mut [f
 // Tests
 // ──────────────────────────────────────────────────────────────────────────────
 
-#[cfg(all(test, target_feature = "avx2"))]
+#[cfg(all(test, feature = "enable-avx"))]
 mod tests {
     use poulpy_cpu_ref::reference::fft64::reim4::{
         reim4_convolution_1coeff_ref, reim4_convolution_2coeffs_ref, reim4_extract_1blk_from_reim_contiguous_ref,
@@ -666,6 +666,10 @@ mod tests {
     /// AVX extract+save round-trip matches reference.
     #[test]
     fn reim4_extract_save_1blk_avx_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") || !std::is_x86_feature_detected!("fma") {
+            eprintln!("skipping: CPU lacks avx2/fma");
+            return;
+        };
         let m = 8usize; // multiple of 4
         let rows = 2usize;
         let blk = 0usize;
@@ -691,6 +695,10 @@ mod tests {
     /// AVX `reim4_vec_mat1col_product` matches reference.
     #[test]
     fn reim4_vec_mat1col_product_avx_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") || !std::is_x86_feature_detected!("fma") {
+            eprintln!("skipping: CPU lacks avx2/fma");
+            return;
+        };
         let nrows = 8usize;
         let u = reim4_data(nrows, 1.3);
         let v = reim4_data(nrows, 2.7);
@@ -714,6 +722,10 @@ mod tests {
     /// AVX `reim4_vec_mat2cols_product` matches reference.
     #[test]
     fn reim4_vec_mat2cols_product_avx_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") || !std::is_x86_feature_detected!("fma") {
+            eprintln!("skipping: CPU lacks avx2/fma");
+            return;
+        };
         let nrows = 8usize;
         let u = reim4_data(nrows, 1.1);
         let v: Vec<f64> = (0..nrows * 16).map(|i| i as f64 * 0.07 + 0.1).collect();
@@ -737,6 +749,10 @@ mod tests {
     /// AVX `reim4_convolution_1coeff` matches reference for all k values.
     #[test]
     fn reim4_convolution_1coeff_avx_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") || !std::is_x86_feature_detected!("fma") {
+            eprintln!("skipping: CPU lacks avx2/fma");
+            return;
+        };
         let a_size = 4usize;
         let b_size = 4usize;
         let a = reim4_data(a_size, 1.5);
@@ -762,6 +778,10 @@ mod tests {
     /// AVX `reim4_convolution_2coeffs` matches reference for all k values.
     #[test]
     fn reim4_convolution_2coeffs_avx_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") || !std::is_x86_feature_detected!("fma") {
+            eprintln!("skipping: CPU lacks avx2/fma");
+            return;
+        };
         let a_size = 4usize;
         let b_size = 4usize;
         let a = reim4_data(a_size, 1.7);
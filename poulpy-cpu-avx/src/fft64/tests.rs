@@ -106,6 +106,16 @@ cross_backend_test_suite! {
     }
 }
 
+cross_backend_test_suite! {
+    mod persistence,
+    backend_ref =  poulpy_cpu_ref::FFT64Ref,
+    backend_test = crate::FFT64Avx,
+    params = TestParams { size: 1<<8, base2k: 12 },
+    tests = {
+        test_prepared_persistence => poulpy_hal::test_suite::serialization::test_prepared_persistence,
+    }
+}
+
 backend_test_suite! {
     mod sampling,
     backend = crate::FFT64Avx,
@@ -117,6 +127,33 @@ backend_test_suite! {
     }
 }
 
+// Differential tests against the transform-free schoolbook oracle.
+cross_backend_test_suite! {
+    mod schoolbook,
+    backend_ref =  poulpy_cpu_ref::SchoolbookRef,
+    backend_test = crate::FFT64Avx,
+    params = TestParams { size: 1<<6, base2k: 12 },
+    tests = {
+        test_svp_apply_dft_to_dft => poulpy_hal::test_suite::svp::test_svp_apply_dft_to_dft,
+        test_vec_znx_idft_apply => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_idft_apply,
+        test_vec_znx_big_normalize => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_normalize,
+        test_vmp_apply_dft_to_dft => poulpy_hal::test_suite::vmp::test_vmp_apply_dft_to_dft,
+    }
+}
+
+// Differential tests in the lossy regime, where products exceed 2^53.
+cross_backend_test_suite! {
+    mod schoolbook_lossy,
+    backend_ref =  poulpy_cpu_ref::SchoolbookRef,
+    backend_test = crate::FFT64Avx,
+    params = TestParams { size: 1<<10, base2k: 26 },
+    tests = {
+        test_vmp_apply_dft_to_dft_lossy => poulpy_hal::test_suite::precision::test_vmp_apply_dft_to_dft_lossy,
+        test_svp_apply_dft_to_dft_lossy => poulpy_hal::test_suite::precision::test_svp_apply_dft_to_dft_lossy,
+        test_convolution_lossy => poulpy_hal::test_suite::precision::test_convolution_lossy,
+    }
+}
+
 #[test]
 fn test_convolution_direct() {
     let module = Module::<FFT64Avx>::new(1 << 8);
@@ -44,20 +44,28 @@
 //!
 //! # Compile-time requirements
 //!
-//! To compile this crate, you must enable AVX2 and FMA target features:
-//!
-//! ```text
-//! RUSTFLAGS="-C target-cpu=native" cargo build --release
-//! ```
-//!
-//! Or explicitly:
-//!
-//! ```text
-//! RUSTFLAGS="-C target-feature=+avx2,+fma" cargo build --release
+//! The `enable-avx` feature compiles the AVX2/FMA kernels on any `x86_64` target. The
+//! kernels carry their own `#[target_feature]` attributes, so no global target flags are
+//! needed and the resulting binary still runs on CPUs without AVX2; building with
+//! `RUSTFLAGS="-C target-cpu=native"` remains possible for binaries that only target the
+//! build machine.
+//!
+//! # Runtime backend selection
+//!
+//! [`FFT64Dyn`] and [`NTT120Dyn`] are [`DynBackend`](poulpy_hal::dyn_backend::DynBackend)s
+//! that pick [`FFT64Avx`] / [`NTT120Avx`] when the host CPU supports them and fall back to
+//! [`FFT64Ref`](poulpy_cpu_ref::FFT64Ref) / [`NTT120Ref`](poulpy_cpu_ref::NTT120Ref)
+//! otherwise (or when `enable-avx` is disabled). They are always compiled, so applications
+//! can be written once against them:
+//!
+//! ```ignore
+//! use poulpy_cpu_avx::FFT64Dyn;
+//! use poulpy_hal::{api::ModuleNew, layouts::Module};
+//!
+//! let module: Module<FFT64Dyn> = Module::<FFT64Dyn>::new(1 << 12);
+//! println!("running on {}", module.backend_name());
 //! ```
 //!
-//! Failure to enable these features at compile time will result in a compilation error.
-//!
 //! # Correctness guarantees
 //!
 //! ## Determinism
@@ -120,7 +128,9 @@
 //!
 //! # Feature flags
 //!
-//! - `enable-avx` (optional): Historically used for conditional compilation, currently inactive.
+//! - `enable-avx` (optional): compiles [`FFT64Avx`] and [`NTT120Avx`] and makes them
+//!   candidates for [`FFT64Dyn`] / [`NTT120Dyn`]. Without it, only the runtime-selected
+//!   families are exported and they always resolve to the reference backends.
 //!
 //! # Platform support
 //!
@@ -156,13 +166,7 @@
 #[cfg(all(feature = "enable-avx", not(target_arch = "x86_64")))]
 compile_error!("feature `enable-avx` requires target_arch = \"x86_64\".");
 
-// If the user enables this backend but AVX2 isn't enabled in the target → abort
-#[cfg(all(feature = "enable-avx", target_arch = "x86_64", not(target_feature = "avx2")))]
-compile_error!("feature `enable-avx` requires AVX2. Build with RUSTFLAGS=\"-C target-feature=+avx2\".");
-
-// If the user enables this backend but FMA isn't enabled in the target → abort
-#[cfg(all(feature = "enable-avx", target_arch = "x86_64", not(target_feature = "fma")))]
-compile_error!("feature `enable-avx` requires FMA. Build with RUSTFLAGS=\"-C target-feature=+fma\".");
+mod dispatch;
 
 // Keep the crate as a true opt-in backend: without `enable-avx`, none of the
 // AVX modules or their unit tests are compiled.
@@ -175,6 +179,7 @@ mod ntt120;
 #[cfg(feature = "enable-avx")]
 mod znx_avx;
 
+pub use dispatch::{FFT64Dyn, FFT64Family, NTT120Dyn, NTT120Family};
 #[cfg(feature = "enable-avx")]
 pub use fft64::{FFT64Avx, ReimFFTAvx, ReimIFFTAvx};
 #[cfg(feature = "enable-avx")]
@@ -783,7 +783,7 @@ pub(crate) unsafe fn b_to_znx128_avx2(nn: usize, res: &mut [i128], a: &[u64]) {
 // Tests
 // ──────────────────────────────────────────────────────────────────────────────
 
-#[cfg(all(test, target_feature = "avx2"))]
+#[cfg(all(test, feature = "enable-avx"))]
 mod tests {
     use super::*;
     use poulpy_cpu_ref::reference::ntt120::{
@@ -795,6 +795,10 @@ mod tests {
     /// AVX2 `b_from_znx64` matches reference for arbitrary i64 inputs.
     #[test]
     fn b_from_znx64_avx2_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         let n = 64usize;
         let coeffs: Vec<i64> = (0..n as i64).map(|i| i * 17 - 500).collect();
 
@@ -810,6 +814,10 @@ mod tests {
     /// AVX2 `c_from_b` (Barrett reduction to Montgomery u32) matches reference.
     #[test]
     fn c_from_b_avx2_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         let n = 64usize;
         let coeffs: Vec<i64> = (0..n as i64).map(|i| i * 11 + 3).collect();
 
@@ -828,6 +836,10 @@ mod tests {
     /// AVX2 `vec_mat1col_product_bbb` matches reference.
     #[test]
     fn vec_mat1col_product_bbb_avx2_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         let ell = 16usize;
         let n = 64usize;
         let meta = BbbMeta::<Primes30>::new();
@@ -853,6 +865,10 @@ mod tests {
     /// Fused `reduce_b_and_apply_crt` matches two-step `reduce_b_to_canonical` + barrett.
     #[test]
     fn reduce_b_and_apply_crt_vs_two_step() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         use poulpy_cpu_ref::reference::ntt120::arithmetic::b_from_znx64_ref;
         let n = 64usize;
         let coeffs: Vec<i64> = (0..n as i64).map(|i| i * 5 - 160).collect();
@@ -884,6 +900,10 @@ mod tests {
     /// AVX2 `b_to_znx128` matches reference for valid q120b input.
     #[test]
     fn b_to_znx128_avx2_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         let n = 64usize;
         let coeffs: Vec<i64> = (0..n as i64).map(|i| i * 5 - 160).collect();
 
@@ -328,7 +328,7 @@ pub(crate) unsafe fn vec_mat2cols_product_x2_bbc_avx2(
 // Tests
 // ──────────────────────────────────────────────────────────────────────────────
 
-#[cfg(all(test, target_feature = "avx2"))]
+#[cfg(all(test, feature = "enable-avx"))]
 mod tests {
     use super::*;
     use poulpy_cpu_ref::reference::ntt120::{
@@ -364,6 +364,10 @@ mod tests {
     /// AVX2 `vec_mat1col_product_bbc` matches reference (single column, single output).
     #[test]
     fn vec_mat1col_product_bbc_avx2_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         let ell = 8usize;
         let n = 1usize; // one element per row
         let meta = BbcMeta::<Primes30>::new();
@@ -383,6 +387,10 @@ mod tests {
     /// AVX2 `vec_mat1col_product_x2_bbc` matches reference (single column, two simultaneous outputs).
     #[test]
     fn vec_mat1col_product_x2_bbc_avx2_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         let ell = 8usize;
         let n = 1usize;
         let meta = BbcMeta::<Primes30>::new();
@@ -416,6 +424,10 @@ mod tests {
     /// AVX2 `vec_mat2cols_product_x2_bbc` matches reference (two columns, two simultaneous outputs).
     #[test]
     fn vec_mat2cols_product_x2_bbc_avx2_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         let ell = 8usize;
         let n = 1usize;
         let meta = BbcMeta::<Primes30>::new();
@@ -42,6 +42,8 @@ mod znx;
 /// - **ScalarPrep**: `Q120bScalar` — NTT-domain coefficients stored as 4 × u64 CRT residues.
 /// - **ScalarBig**: `i128` — large-coefficient ring elements use 128-bit signed integers.
 /// - **Prime set**: `Primes30` (four ~30-bit primes, Q ≈ 2^120).
+///   The AVX2 Barrett and CRT kernels are specialized to these primes, so unlike
+///   [`NTT120Ref`](poulpy_cpu_ref::NTT120Ref) this backend is not generic over the prime set.
 ///
 /// # CPU feature requirements
 ///
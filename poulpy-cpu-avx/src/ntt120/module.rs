@@ -20,7 +20,10 @@ use poulpy_cpu_ref::reference::ntt120::{
     types::Q120bScalar,
     vec_znx_dft::{NttHandleFactory, NttHandleProvider},
 };
-use poulpy_hal::{alloc_aligned, assert_alignment, layouts::Backend};
+use poulpy_hal::{
+    alloc_aligned,
+    layouts::{AlignedBuf, Backend},
+};
 
 use super::NTT120Avx;
 
@@ -43,14 +46,14 @@ pub struct NTT120AvxHandle {
 impl Backend for NTT120Avx {
     type ScalarPrep = Q120bScalar;
     type ScalarBig = i128;
-    type OwnedBuf = Vec<u8>;
+    type OwnedBuf = AlignedBuf;
     type Handle = NTT120AvxHandle;
+    const PREPARED_LAYOUT: &'static str = "ntt120";
     fn alloc_bytes(len: usize) -> Self::OwnedBuf {
-        alloc_aligned::<u8>(len)
+        alloc_aligned(len)
     }
     fn from_bytes(bytes: Vec<u8>) -> Self::OwnedBuf {
-        assert_alignment(bytes.as_ptr());
-        bytes
+        AlignedBuf::from(bytes)
     }
 
     unsafe fn destroy(handle: NonNull<Self::Handle>) {
@@ -89,6 +92,8 @@ unsafe impl NttHandleFactory for NTT120AvxHandle {
 /// The returned references are valid for the lifetime of `&self`.
 /// All fields are fully initialised in [`NTT120Avx::new_impl`].
 unsafe impl NttHandleProvider for NTT120AvxHandle {
+    type PrimeSet = Primes30;
+
     fn get_ntt_table(&self) -> &NttTable<Primes30> {
         &self.table_ntt
     }
@@ -395,7 +395,7 @@ pub(crate) unsafe fn ntt_avx2<P: PrimeSet>(table: &NttTable<P>, data: &mut [u64]
     unsafe {
         let begin = data.as_mut_ptr() as *mut __m256i;
         let end = begin.add(n) as *const __m256i;
-        let po_base = table.powomega.as_ptr() as *const __m256i;
+        let po_base = table.powomega().as_ptr() as *const __m256i;
 
         let mut meta_idx = 0usize;
         // po_avx: current offset into powomega, counted in __m256i units
@@ -484,7 +484,7 @@ pub(crate) unsafe fn intt_avx2<P: PrimeSet>(table: &NttTableInv<P>, data: &mut [
     unsafe {
         let begin = data.as_mut_ptr() as *mut __m256i;
         let end = begin.add(n) as *const __m256i;
-        let po_base = table.powomega.as_ptr() as *const __m256i;
+        let po_base = table.powomega().as_ptr() as *const __m256i;
 
         let mut meta_idx = 0usize;
         let mut po_avx = 0usize;
@@ -547,7 +547,7 @@ pub(crate) unsafe fn intt_avx2<P: PrimeSet>(table: &NttTableInv<P>, data: &mut [
 // Tests
 // ──────────────────────────────────────────────────────────────────────────────
 
-#[cfg(all(test, target_feature = "avx2"))]
+#[cfg(all(test, feature = "enable-avx"))]
 mod tests {
     use super::*;
     use poulpy_cpu_ref::reference::ntt120::{
@@ -559,6 +559,10 @@ mod tests {
     /// AVX2 NTT followed by AVX2 iNTT is the identity — mirrors the ref test.
     #[test]
     fn ntt_intt_identity_avx2() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         for log_n in 1..=8usize {
             let n = 1 << log_n;
             let fwd = NttTable::<Primes30>::new(n);
@@ -591,6 +595,10 @@ mod tests {
     /// a = [1, 2, 0, …], b = [3, 4, 0, …]; a*b mod (X^8+1) = [3, 10, 8, 0, …]
     #[test]
     fn ntt_convolution_avx2() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         let n = 8usize;
         let fwd = NttTable::<Primes30>::new(n);
         let inv = NttTableInv::<Primes30>::new(n);
@@ -631,6 +639,10 @@ mod tests {
     /// AVX2 NTT output matches reference NTT output.
     #[test]
     fn ntt_avx2_vs_ref() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         for log_n in 1..=8usize {
             let n = 1 << log_n;
             let fwd = NttTable::<Primes30>::new(n);
@@ -34,7 +34,8 @@ use core::arch::x86_64::{
 use poulpy_cpu_ref::reference::ntt120::{
     NttAdd, NttAddAssign, NttCFromB, NttCopy, NttDFTExecute, NttExtract1BlkContiguous, NttFromZnx64, NttMulBbb, NttMulBbc,
     NttMulBbc1ColX2, NttMulBbc2ColsX2, NttNegate, NttNegateAssign, NttPackLeft1BlkX2, NttPackRight1BlkX2,
-    NttPairwisePackLeft1BlkX2, NttPairwisePackRight1BlkX2, NttSub, NttSubAssign, NttSubNegateAssign, NttToZnx128, NttZero,
+    NttPairwisePackLeft1BlkX2, NttPairwisePackRight1BlkX2, NttPrimes, NttSub, NttSubAssign, NttSubNegateAssign, NttToZnx128,
+    NttZero,
     mat_vec::{BbbMeta, BbcMeta, extract_1blk_from_contiguous_q120b_ref},
     ntt::{NttTable, NttTableInv},
     primes::Primes30,
@@ -205,6 +206,10 @@ unsafe fn ntt_negate_assign_avx2(n: usize, res: &mut [u64]) {
     }
 }
 
+impl NttPrimes for NTT120Avx {
+    type Primes = Primes30;
+}
+
 // ──────────────────────────────────────────────────────────────────────────────
 // NTT execution — AVX2 butterfly
 // ──────────────────────────────────────────────────────────────────────────────
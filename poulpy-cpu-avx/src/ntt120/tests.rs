@@ -106,6 +106,16 @@ cross_backend_test_suite! {
     }
 }
 
+cross_backend_test_suite! {
+    mod persistence,
+    backend_ref =  poulpy_cpu_ref::NTT120Ref,
+    backend_test = crate::NTT120Avx,
+    params = TestParams { size: 1<<8, base2k: 50 },
+    tests = {
+        test_prepared_persistence => poulpy_hal::test_suite::serialization::test_prepared_persistence,
+    }
+}
+
 backend_test_suite! {
     mod sampling,
     backend = crate::NTT120Avx,
@@ -175,6 +185,20 @@ cross_backend_test_suite! {
     }
 }
 
+// Differential tests against the transform-free schoolbook oracle.
+cross_backend_test_suite! {
+    mod schoolbook,
+    backend_ref =  poulpy_cpu_ref::SchoolbookRef,
+    backend_test = crate::NTT120Avx,
+    params = TestParams { size: 1<<6, base2k: 50 },
+    tests = {
+        test_svp_apply_dft_to_dft => poulpy_hal::test_suite::svp::test_svp_apply_dft_to_dft,
+        test_vec_znx_idft_apply => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_idft_apply,
+        test_vec_znx_big_normalize => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_normalize,
+        test_vmp_apply_dft_to_dft => poulpy_hal::test_suite::vmp::test_vmp_apply_dft_to_dft,
+    }
+}
+
 #[test]
 fn test_convolution_direct() {
     let module = Module::<NTT120Avx>::new(1 << 8);
@@ -995,7 +995,7 @@ pub(super) unsafe fn vi128_neg_from_small_avx2(n: usize, res: &mut [i128], a: &[
 // Tests
 // ──────────────────────────────────────────────────────────────────────────────
 
-#[cfg(all(test, target_feature = "avx2"))]
+#[cfg(all(test, feature = "enable-avx"))]
 mod tests {
     use super::{
         nfc_final_step_assign_avx2, nfc_final_step_assign_scalar, nfc_middle_step_assign_avx2, nfc_middle_step_assign_scalar,
@@ -1013,6 +1013,10 @@ mod tests {
 
     #[test]
     fn vi128_add_avx2_vs_scalar() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         let n = 64usize;
         let a = i128_data(n, 0x1_0000_0001i128);
         let b = i128_data(n, 0x0_FFFF_FFFFi128);
@@ -1025,6 +1029,10 @@ mod tests {
 
     #[test]
     fn vi128_sub_avx2_vs_scalar() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         let n = 64usize;
         let a = i128_data(n, 0x2_0000_0003i128);
         let b = i128_data(n, 0x1_0000_0001i128);
@@ -1037,6 +1045,10 @@ mod tests {
 
     #[test]
     fn vi128_negate_avx2_vs_scalar() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         let n = 64usize;
         let a = i128_data(n, 0x1_2345_6789i128);
         let expected: Vec<i128> = a.iter().map(|x| -x).collect();
@@ -1048,6 +1060,10 @@ mod tests {
 
     #[test]
     fn vi128_from_small_avx2_vs_scalar() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         let n = 64usize;
         let a = i64_data(n, 12345);
         let expected: Vec<i128> = a.iter().map(|&x| x as i128).collect();
@@ -1059,6 +1075,10 @@ mod tests {
 
     #[test]
     fn vi128_neg_from_small_avx2_vs_scalar() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         let n = 64usize;
         let a = i64_data(n, 99);
         let expected: Vec<i128> = a.iter().map(|&x| -(x as i128)).collect();
@@ -1070,6 +1090,10 @@ mod tests {
 
     #[test]
     fn nfc_middle_step_avx2_vs_scalar() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         let n = 64usize;
         let base2k = 16usize;
         let lsh = 0usize;
@@ -1090,6 +1114,10 @@ mod tests {
 
     #[test]
     fn nfc_middle_step_assign_avx2_vs_scalar() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         let n = 64usize;
         let base2k = 16usize;
         let lsh = 8usize;
@@ -1110,6 +1138,10 @@ mod tests {
 
     #[test]
     fn nfc_final_step_assign_avx2_vs_scalar() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: CPU lacks avx2");
+            return;
+        };
         let n = 64usize;
         let base2k = 16usize;
         let lsh = 0usize;
@@ -0,0 +1,241 @@
+use std::{marker::PhantomData, num::NonZeroUsize, ptr::NonNull};
+
+use poulpy_cpu_ref::reference::{
+    fft64::{reim::ReimArith, reim4::Reim4BlkMatVec},
+    znx::{
+        ZnxAddAssign, ZnxCopy, ZnxExtractDigitAddMul, ZnxMulPowerOfTwoAssign, ZnxNormalizeDigit, ZnxNormalizeFinalStep,
+        ZnxNormalizeFinalStepAssign, ZnxNormalizeFirstStep, ZnxNormalizeFirstStepCarryOnly, ZnxNormalizeMiddleStep,
+        ZnxNormalizeMiddleStepAssign, ZnxNormalizeMiddleStepCarryOnly, ZnxZero,
+    },
+};
+use poulpy_hal::{
+    api::ModuleNew,
+    layouts::{Backend, Module},
+    oep::{HalImpl, SharedLayout},
+};
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+/// Environment variable overriding the default number of threads of a
+/// [`FFT64Mt`] module.
+pub const NUM_THREADS_ENV: &str = "POULPY_NUM_THREADS";
+
+/// FFT64 backend whose operations can be wrapped by [`FFT64Mt`].
+///
+/// Implemented by every backend with `f64` DFT-domain and `i64` big
+/// coefficients that exposes the reference FFT64 kernel traits, i.e.
+/// [`FFT64Ref`](poulpy_cpu_ref::FFT64Ref) and `FFT64Avx`.
+pub trait FFT64MtBase:
+    Backend<ScalarPrep = f64, ScalarBig = i64>
+    + HalImpl<Self>
+    + ReimArith
+    + Reim4BlkMatVec
+    + ZnxZero
+    + ZnxCopy
+    + ZnxAddAssign
+    + ZnxMulPowerOfTwoAssign
+    + ZnxNormalizeFirstStepCarryOnly
+    + ZnxNormalizeMiddleStepCarryOnly
+    + ZnxNormalizeMiddleStep
+    + ZnxNormalizeFinalStep
+    + ZnxNormalizeFirstStep
+    + ZnxExtractDigitAddMul
+    + ZnxNormalizeDigit
+    + ZnxNormalizeMiddleStepAssign
+    + ZnxNormalizeFinalStepAssign
+    + Send
+    + Sync
+    + 'static
+{
+}
+
+impl<BE> FFT64MtBase for BE where
+    BE: Backend<ScalarPrep = f64, ScalarBig = i64>
+        + HalImpl<BE>
+        + ReimArith
+        + Reim4BlkMatVec
+        + ZnxZero
+        + ZnxCopy
+        + ZnxAddAssign
+        + ZnxMulPowerOfTwoAssign
+        + ZnxNormalizeFirstStepCarryOnly
+        + ZnxNormalizeMiddleStepCarryOnly
+        + ZnxNormalizeMiddleStep
+        + ZnxNormalizeFinalStep
+        + ZnxNormalizeFirstStep
+        + ZnxExtractDigitAddMul
+        + ZnxNormalizeDigit
+        + ZnxNormalizeMiddleStepAssign
+        + ZnxNormalizeFinalStepAssign
+        + Send
+        + Sync
+        + 'static
+{
+}
+
+/// Multi-threaded CPU backend wrapping the FFT64 backend `BE`.
+///
+/// `FFT64Mt<BE>` shares the scalar types, buffer layouts and scratch
+/// management of `BE`, and forwards every operation to it. The operations
+/// that dominate the cost of a product, `vmp_apply_dft_to_dft`,
+/// `vec_znx_dft_apply` and `vec_znx_big_normalize`, are additionally split
+/// into independent parts that run on the module's thread pool, each part
+/// calling the kernels of `BE`. Results are bit-identical to those of `BE`.
+///
+/// The number of threads is fixed at module creation, see [`ThreadConfig`].
+#[derive(Debug, Clone, Copy)]
+pub struct FFT64Mt<BE: FFT64MtBase = poulpy_cpu_ref::FFT64Ref>(PhantomData<BE>);
+
+/// Handle of a [`FFT64Mt`] module: the module of the wrapped backend, the
+/// thread configuration and the thread pool.
+pub struct FFT64MtHandle<BE: FFT64MtBase> {
+    inner: Module<BE>,
+    config: ThreadConfig,
+    /// `config.threads - 1` worker threads, created with the module and shut
+    /// down when it is dropped; `None` for a single thread.
+    pool: Option<ThreadPool>,
+}
+
+/// Thread configuration of a [`FFT64Mt`] module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThreadConfig {
+    /// Maximum number of threads an operation is split across, including the
+    /// calling thread.
+    pub threads: usize,
+    /// Smallest ring degree at which operations are split. Below it, handing
+    /// work to the thread pool would outweigh the work, and every operation
+    /// runs on the calling thread.
+    pub min_n: usize,
+}
+
+impl ThreadConfig {
+    /// Default value of [`ThreadConfig::min_n`].
+    pub const DEFAULT_MIN_N: usize = 1 << 12;
+
+    /// Returns a configuration splitting operations across `threads` threads
+    /// from the default ring degree on.
+    pub fn new(threads: usize) -> Self {
+        Self {
+            threads,
+            min_n: Self::DEFAULT_MIN_N,
+        }
+    }
+
+    /// Returns the number of threads available to an operation at ring degree `n`.
+    pub(crate) fn threads_for(&self, n: usize) -> usize {
+        if n >= self.min_n { self.threads } else { 1 }
+    }
+}
+
+impl Default for ThreadConfig {
+    /// Reads the number of threads from `POULPY_NUM_THREADS` and falls back to
+    /// [`std::thread::available_parallelism`].
+    fn default() -> Self {
+        let threads: usize = std::env::var(NUM_THREADS_ENV)
+            .ok()
+            .and_then(|val| val.trim().parse::<usize>().ok())
+            .filter(|&threads| threads > 0)
+            .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, NonZeroUsize::get));
+        Self::new(threads)
+    }
+}
+
+impl<BE: FFT64MtBase> FFT64Mt<BE> {
+    /// Creates a module of degree `n` with the given thread configuration,
+    /// spawning its `config.threads - 1` worker threads.
+    ///
+    /// [`ModuleNew::new`] uses [`ThreadConfig::default`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `config.threads` is zero or if the worker threads cannot be
+    /// spawned.
+    pub fn with_config(n: u64, config: ThreadConfig) -> Module<Self> {
+        assert!(config.threads > 0, "ThreadConfig::threads must be at least 1");
+        let pool: Option<ThreadPool> = (config.threads > 1).then(|| {
+            ThreadPoolBuilder::new()
+                .num_threads(config.threads - 1)
+                .thread_name(|i| format!("poulpy-mt-{i}"))
+                .build()
+                .expect("failed to spawn the FFT64Mt worker threads")
+        });
+        let handle: FFT64MtHandle<BE> = FFT64MtHandle {
+            inner: Module::<BE>::new(n),
+            config,
+            pool,
+        };
+        let ptr: NonNull<FFT64MtHandle<BE>> = NonNull::from(Box::leak(Box::new(handle)));
+        unsafe { Module::from_nonnull(ptr, n) }
+    }
+
+    /// Returns the thread configuration of `module`.
+    pub fn config(module: &Module<Self>) -> ThreadConfig {
+        unsafe { (*module.ptr()).config }
+    }
+}
+
+/// Returns the module of the wrapped backend.
+pub(crate) fn inner<BE: FFT64MtBase>(module: &Module<FFT64Mt<BE>>) -> &Module<BE> {
+    unsafe { &(*module.ptr()).inner }
+}
+
+/// Returns the thread pool of `module`, if it has more than one thread.
+pub(crate) fn pool<BE: FFT64MtBase>(module: &Module<FFT64Mt<BE>>) -> Option<&ThreadPool> {
+    unsafe { (*module.ptr()).pool.as_ref() }
+}
+
+impl<BE: FFT64MtBase> Backend for FFT64Mt<BE> {
+    type ScalarBig = i64;
+    type ScalarPrep = f64;
+    type OwnedBuf = BE::OwnedBuf;
+    type Handle = FFT64MtHandle<BE>;
+    const PREPARED_LAYOUT: &'static str = BE::PREPARED_LAYOUT;
+
+    fn alloc_bytes(len: usize) -> Self::OwnedBuf {
+        BE::alloc_bytes(len)
+    }
+
+    fn from_bytes(bytes: Vec<u8>) -> Self::OwnedBuf {
+        BE::from_bytes(bytes)
+    }
+
+    fn size_of_scalar_big() -> usize {
+        BE::size_of_scalar_big()
+    }
+
+    fn size_of_scalar_prep() -> usize {
+        BE::size_of_scalar_prep()
+    }
+
+    fn bytes_of_vec_znx_dft(n: usize, cols: usize, size: usize) -> usize {
+        BE::bytes_of_vec_znx_dft(n, cols, size)
+    }
+
+    fn bytes_of_vec_znx_big(n: usize, cols: usize, size: usize) -> usize {
+        BE::bytes_of_vec_znx_big(n, cols, size)
+    }
+
+    fn bytes_of_svp_ppol(n: usize, cols: usize) -> usize {
+        BE::bytes_of_svp_ppol(n, cols)
+    }
+
+    fn bytes_of_vmp_pmat(n: usize, rows: usize, cols_in: usize, cols_out: usize, size: usize) -> usize {
+        BE::bytes_of_vmp_pmat(n, rows, cols_in, cols_out, size)
+    }
+
+    fn bytes_of_cnv_pvec_left(n: usize, cols: usize, size: usize) -> usize {
+        BE::bytes_of_cnv_pvec_left(n, cols, size)
+    }
+
+    fn bytes_of_cnv_pvec_right(n: usize, cols: usize, size: usize) -> usize {
+        BE::bytes_of_cnv_pvec_right(n, cols, size)
+    }
+
+    unsafe fn destroy(handle: NonNull<Self::Handle>) {
+        unsafe {
+            drop(Box::from_raw(handle.as_ptr()));
+        }
+    }
+}
+
+// `FFT64Mt<BE>` delegates its whole `Backend` implementation to `BE`.
+unsafe impl<BE: FFT64MtBase> SharedLayout<BE> for FFT64Mt<BE> {}
@@ -0,0 +1,1320 @@
+#![allow(clippy::too_many_arguments)]
+
+use std::marker::PhantomData;
+
+use poulpy_cpu_ref::reference::vec_znx::vec_znx_normalize_tmp_bytes;
+use poulpy_hal::{
+    api::{ScratchTakeBasic, VecZnxDftApply, VecZnxDftZero, VmpApplyDftToDft},
+    layouts::{
+        Backend, CnvPVecLToMut, CnvPVecLToRef, CnvPVecRToMut, CnvPVecRToRef, Data, MatZnxToRef, Module, NoiseInfos,
+        ScalarZnxToRef, Scratch, ScratchOwned, SvpPPolToMut, SvpPPolToRef, VecZnx, VecZnxBig, VecZnxBigToMut, VecZnxBigToRef,
+        VecZnxDft, VecZnxDftToMut, VecZnxDftToRef, VecZnxToMut, VecZnxToRef, VmpPMat, VmpPMatToMut, VmpPMatToRef, ZnxInfos,
+    },
+    oep::{HalImpl, retag, retag_mut, retag_scratch, retag_scratch_owned, retag_scratch_ref, wrap_scratch},
+    source::Source,
+};
+
+use crate::{
+    FFT64Mt, FFT64MtBase,
+    backend::{inner, pool},
+    parallel,
+};
+
+// Scratch management and the module-less methods are served by the wrapped
+// backend. `vec_znx_dft_apply`, `vmp_apply_dft_to_dft` and
+// `vec_znx_big_normalize` are split across threads; `vmp_apply_dft` is
+// rebuilt on top of the first two. Every other method is forwarded.
+unsafe impl<BE: FFT64MtBase> HalImpl<FFT64Mt<BE>> for FFT64Mt<BE> {
+    fn scratch_owned_alloc(size: usize) -> ScratchOwned<Self> {
+        let scratch: ScratchOwned<BE> = BE::scratch_owned_alloc(size);
+        ScratchOwned {
+            data: scratch.into_data(),
+            _phantom: PhantomData,
+        }
+    }
+
+    fn scratch_owned_borrow(scratch: &mut ScratchOwned<Self>) -> &mut Scratch<Self> {
+        wrap_scratch(BE::scratch_owned_borrow(retag_scratch_owned(scratch)))
+    }
+
+    fn scratch_from_bytes(data: &mut [u8]) -> &mut Scratch<Self> {
+        wrap_scratch(BE::scratch_from_bytes(data))
+    }
+
+    fn scratch_available(scratch: &Scratch<Self>) -> usize {
+        BE::scratch_available(retag_scratch_ref(scratch))
+    }
+
+    fn take_slice<T>(scratch: &mut Scratch<Self>, len: usize) -> (&mut [T], &mut Scratch<Self>) {
+        let (slice, rem) = BE::take_slice::<T>(retag_scratch(scratch), len);
+        (slice, wrap_scratch(rem))
+    }
+
+    fn new(n: u64) -> Module<Self> {
+        Self::with_config(n, Default::default())
+    }
+
+    fn vec_znx_big_from_small<R, A>(res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_big_from_small(retag_mut::<_, BE>(&mut res.to_mut()), res_col, a, a_col)
+    }
+
+    fn vec_znx_idft_apply_consume<D: Data>(module: &Module<Self>, mut a: VecZnxDft<D, Self>) -> VecZnxBig<D, Self>
+    where
+        VecZnxDft<D, Self>: VecZnxDftToMut<Self>,
+    {
+        let a_mut: VecZnxDft<&mut [u8], Self> = a.to_mut();
+        let a_inner: VecZnxDft<&mut [u8], BE> = VecZnxDft {
+            data: a_mut.data,
+            n: a_mut.n,
+            cols: a_mut.cols,
+            size: a_mut.size,
+            max_size: a_mut.max_size,
+            _phantom: PhantomData,
+        };
+        BE::vec_znx_idft_apply_consume(inner(module), a_inner);
+        a.into_big()
+    }
+
+    fn vec_znx_dft_apply<R, A>(
+        module: &Module<Self>,
+        step: usize,
+        offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+    ) where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxToRef,
+    {
+        parallel::vec_znx_dft_apply(
+            inner(module),
+            pool(module),
+            Self::config(module).threads_for(module.n()),
+            step,
+            offset,
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            &a.to_ref(),
+            a_col,
+        )
+    }
+
+    fn vmp_apply_dft_to_dft_tmp_bytes(
+        module: &Module<Self>,
+        res_size: usize,
+        a_size: usize,
+        b_rows: usize,
+        b_cols_in: usize,
+        b_cols_out: usize,
+        b_size: usize,
+    ) -> usize {
+        Self::config(module).threads_for(module.n())
+            * BE::vmp_apply_dft_to_dft_tmp_bytes(inner(module), res_size, a_size, b_rows, b_cols_in, b_cols_out, b_size)
+    }
+
+    fn vmp_apply_dft_to_dft<R, A, C>(
+        module: &Module<Self>,
+        res: &mut R,
+        a: &A,
+        b: &C,
+        limb_offset: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+        C: VmpPMatToRef<Self>,
+    {
+        let threads: usize = Self::config(module).threads_for(module.n());
+        if threads <= 1 {
+            return BE::vmp_apply_dft_to_dft(
+                inner(module),
+                retag_mut::<_, BE>(&mut res.to_mut()),
+                retag::<_, BE>(&a.to_ref()),
+                retag::<_, BE>(&b.to_ref()),
+                limb_offset,
+                retag_scratch(scratch),
+            );
+        }
+
+        let mut res: VecZnxDft<&mut [u8], Self> = res.to_mut();
+        let a: VecZnxDft<&[u8], Self> = a.to_ref();
+        let b: VmpPMat<&[u8], Self> = b.to_ref();
+
+        let bytes: usize =
+            Self::vmp_apply_dft_to_dft_tmp_bytes(module, res.size(), a.size(), b.rows(), b.cols_in(), b.cols_out(), b.size());
+        let (tmp, _) = Self::take_slice::<f64>(scratch, bytes / size_of::<f64>());
+        parallel::vmp_apply_dft_to_dft(
+            pool(module),
+            threads,
+            retag_mut::<_, BE>(&mut res),
+            retag::<_, BE>(&a),
+            retag::<_, BE>(&b),
+            limb_offset,
+            tmp,
+        )
+    }
+
+    fn vmp_apply_dft_tmp_bytes(
+        module: &Module<Self>,
+        res_size: usize,
+        a_size: usize,
+        b_rows: usize,
+        b_cols_in: usize,
+        b_cols_out: usize,
+        b_size: usize,
+    ) -> usize {
+        let a_dft_size: usize = a_size.min(b_rows);
+        Self::bytes_of_vec_znx_dft(module.n(), b_cols_in, a_dft_size)
+            + Self::vmp_apply_dft_to_dft_tmp_bytes(module, res_size, a_dft_size, b_rows, b_cols_in, b_cols_out, b_size)
+    }
+
+    fn vmp_apply_dft<R, A, C>(module: &Module<Self>, res: &mut R, a: &A, b: &C, scratch: &mut Scratch<Self>)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxToRef,
+        C: VmpPMatToRef<Self>,
+    {
+        let a: VecZnx<&[u8]> = a.to_ref();
+        let b: VmpPMat<&[u8], Self> = b.to_ref();
+
+        let cols_to_copy: usize = a.cols().min(b.cols_in());
+        let a_start_col: usize = a.cols() - cols_to_copy;
+        let offset: usize = b.cols_in() - cols_to_copy;
+
+        let (mut a_dft, scratch) = scratch.take_vec_znx_dft(module, b.cols_in(), a.size().min(b.rows()));
+
+        for j in 0..offset {
+            module.vec_znx_dft_zero(&mut a_dft, j);
+        }
+
+        for j in 0..cols_to_copy {
+            module.vec_znx_dft_apply(1, 0, &mut a_dft, offset + j, &a, a_start_col + j);
+        }
+
+        module.vmp_apply_dft_to_dft(res, &a_dft, &b, 0, scratch)
+    }
+
+    fn vec_znx_big_normalize<R, A>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_base2k: usize,
+        res_offset: i64,
+        res_col: usize,
+        a: &A,
+        a_base2k: usize,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxBigToRef<Self>,
+    {
+        let n: usize = module.n();
+        let mut res: VecZnx<&mut [u8]> = res.to_mut();
+        let parts: usize = parallel::vec_znx_big_normalize_parts(n, Self::config(module).threads_for(n));
+        if parts <= 1 || res.size() == 0 {
+            return BE::vec_znx_big_normalize(
+                inner(module),
+                &mut res,
+                res_base2k,
+                res_offset,
+                res_col,
+                retag::<_, BE>(&a.to_ref()),
+                a_base2k,
+                a_col,
+                retag_scratch(scratch),
+            );
+        }
+
+        let (carry, _) = Self::take_slice::<i64>(scratch, vec_znx_normalize_tmp_bytes(n) / size_of::<i64>());
+        parallel::vec_znx_big_normalize(
+            pool(module),
+            parts,
+            &mut res,
+            res_base2k,
+            res_offset,
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_base2k,
+            a_col,
+            carry,
+        )
+    }
+
+    fn vec_znx_zero<R>(module: &Module<Self>, res: &mut R, res_col: usize)
+    where
+        R: VecZnxToMut,
+    {
+        BE::vec_znx_zero(inner(module), res, res_col)
+    }
+
+    fn vec_znx_normalize_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_normalize_tmp_bytes(inner(module))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_normalize<R, A>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_base2k: usize,
+        res_offset: i64,
+        res_col: usize,
+        a: &A,
+        a_base2k: usize,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_normalize(
+            inner(module),
+            res,
+            res_base2k,
+            res_offset,
+            res_col,
+            a,
+            a_base2k,
+            a_col,
+            retag_scratch(scratch),
+        )
+    }
+
+    fn vec_znx_normalize_assign<A>(module: &Module<Self>, base2k: usize, a: &mut A, a_col: usize, scratch: &mut Scratch<Self>)
+    where
+        A: VecZnxToMut,
+    {
+        BE::vec_znx_normalize_assign(inner(module), base2k, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_add_into<R, A, C>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+        C: VecZnxToRef,
+    {
+        BE::vec_znx_add_into(inner(module), res, res_col, a, a_col, b, b_col)
+    }
+
+    fn vec_znx_add_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_add_assign(inner(module), res, res_col, a, a_col)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_add_scalar_into<R, A, B>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &B,
+        b_col: usize,
+        b_limb: usize,
+    ) where
+        R: VecZnxToMut,
+        A: ScalarZnxToRef,
+        B: VecZnxToRef,
+    {
+        BE::vec_znx_add_scalar_into(inner(module), res, res_col, a, a_col, b, b_col, b_limb)
+    }
+
+    fn vec_znx_add_scalar_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, res_limb: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: ScalarZnxToRef,
+    {
+        BE::vec_znx_add_scalar_assign(inner(module), res, res_col, res_limb, a, a_col)
+    }
+
+    fn vec_znx_sub<R, A, C>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+        C: VecZnxToRef,
+    {
+        BE::vec_znx_sub(inner(module), res, res_col, a, a_col, b, b_col)
+    }
+
+    fn vec_znx_sub_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_sub_assign(inner(module), res, res_col, a, a_col)
+    }
+
+    fn vec_znx_sub_negate_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_sub_negate_assign(inner(module), res, res_col, a, a_col)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_sub_scalar<R, A, B>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &B,
+        b_col: usize,
+        b_limb: usize,
+    ) where
+        R: VecZnxToMut,
+        A: ScalarZnxToRef,
+        B: VecZnxToRef,
+    {
+        BE::vec_znx_sub_scalar(inner(module), res, res_col, a, a_col, b, b_col, b_limb)
+    }
+
+    fn vec_znx_sub_scalar_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, res_limb: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: ScalarZnxToRef,
+    {
+        BE::vec_znx_sub_scalar_assign(inner(module), res, res_col, res_limb, a, a_col)
+    }
+
+    fn vec_znx_negate<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_negate(inner(module), res, res_col, a, a_col)
+    }
+
+    fn vec_znx_negate_assign<A>(module: &Module<Self>, a: &mut A, a_col: usize)
+    where
+        A: VecZnxToMut,
+    {
+        BE::vec_znx_negate_assign(inner(module), a, a_col)
+    }
+
+    fn vec_znx_rsh_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_rsh_tmp_bytes(inner(module))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_rsh<R, A>(
+        module: &Module<Self>,
+        base2k: usize,
+        k: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_rsh(inner(module), base2k, k, res, res_col, a, a_col, retag_scratch(scratch))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_rsh_add_into<R, A>(
+        module: &Module<Self>,
+        base2k: usize,
+        k: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_rsh_add_into(inner(module), base2k, k, res, res_col, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_lsh_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_lsh_tmp_bytes(inner(module))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_lsh<R, A>(
+        module: &Module<Self>,
+        base2k: usize,
+        k: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_lsh(inner(module), base2k, k, res, res_col, a, a_col, retag_scratch(scratch))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_lsh_add_into<R, A>(
+        module: &Module<Self>,
+        base2k: usize,
+        k: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_lsh_add_into(inner(module), base2k, k, res, res_col, a, a_col, retag_scratch(scratch))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_lsh_sub<R, A>(
+        module: &Module<Self>,
+        base2k: usize,
+        k: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_lsh_sub(inner(module), base2k, k, res, res_col, a, a_col, retag_scratch(scratch))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_rsh_sub<R, A>(
+        module: &Module<Self>,
+        base2k: usize,
+        k: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_rsh_sub(inner(module), base2k, k, res, res_col, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_rsh_assign<R>(module: &Module<Self>, base2k: usize, k: usize, a: &mut R, a_col: usize, scratch: &mut Scratch<Self>)
+    where
+        R: VecZnxToMut,
+    {
+        BE::vec_znx_rsh_assign(inner(module), base2k, k, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_lsh_assign<R>(module: &Module<Self>, base2k: usize, k: usize, a: &mut R, a_col: usize, scratch: &mut Scratch<Self>)
+    where
+        R: VecZnxToMut,
+    {
+        BE::vec_znx_lsh_assign(inner(module), base2k, k, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_rotate<R, A>(module: &Module<Self>, k: i64, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_rotate(inner(module), k, res, res_col, a, a_col)
+    }
+
+    fn vec_znx_rotate_assign_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_rotate_assign_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_rotate_assign<A>(module: &Module<Self>, k: i64, a: &mut A, a_col: usize, scratch: &mut Scratch<Self>)
+    where
+        A: VecZnxToMut,
+    {
+        BE::vec_znx_rotate_assign(inner(module), k, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_automorphism<R, A>(module: &Module<Self>, k: i64, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_automorphism(inner(module), k, res, res_col, a, a_col)
+    }
+
+    fn vec_znx_automorphism_assign_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_automorphism_assign_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_automorphism_assign<R>(module: &Module<Self>, k: i64, res: &mut R, res_col: usize, scratch: &mut Scratch<Self>)
+    where
+        R: VecZnxToMut,
+    {
+        BE::vec_znx_automorphism_assign(inner(module), k, res, res_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_mul_xp_minus_one<R, A>(module: &Module<Self>, k: i64, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_mul_xp_minus_one(inner(module), k, res, res_col, a, a_col)
+    }
+
+    fn vec_znx_mul_xp_minus_one_assign_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_mul_xp_minus_one_assign_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_mul_xp_minus_one_assign<R>(module: &Module<Self>, k: i64, res: &mut R, res_col: usize, scratch: &mut Scratch<Self>)
+    where
+        R: VecZnxToMut,
+    {
+        BE::vec_znx_mul_xp_minus_one_assign(inner(module), k, res, res_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_split_ring_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_split_ring_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_split_ring<R, A>(
+        module: &Module<Self>,
+        res: &mut [R],
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_split_ring(inner(module), res, res_col, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_merge_rings_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_merge_rings_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_merge_rings<R, A>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &[A],
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_merge_rings(inner(module), res, res_col, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_switch_ring<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_switch_ring(inner(module), res, res_col, a, a_col)
+    }
+
+    fn vec_znx_copy<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_copy(inner(module), res, res_col, a, a_col)
+    }
+
+    fn vec_znx_fill_uniform<R>(module: &Module<Self>, base2k: usize, res: &mut R, res_col: usize, source: &mut Source)
+    where
+        R: VecZnxToMut,
+    {
+        BE::vec_znx_fill_uniform(inner(module), base2k, res, res_col, source)
+    }
+
+    fn vec_znx_fill_normal<R>(
+        module: &Module<Self>,
+        res_base2k: usize,
+        res: &mut R,
+        res_col: usize,
+        noise_infos: NoiseInfos,
+        source: &mut Source,
+    ) where
+        R: VecZnxToMut,
+    {
+        BE::vec_znx_fill_normal(inner(module), res_base2k, res, res_col, noise_infos, source)
+    }
+
+    fn vec_znx_add_normal<R>(
+        module: &Module<Self>,
+        res_base2k: usize,
+        res: &mut R,
+        res_col: usize,
+        noise_infos: NoiseInfos,
+        source: &mut Source,
+    ) where
+        R: VecZnxToMut,
+    {
+        BE::vec_znx_add_normal(inner(module), res_base2k, res, res_col, noise_infos, source)
+    }
+
+    fn vec_znx_big_add_normal<R>(
+        module: &Module<Self>,
+        res_base2k: usize,
+        res: &mut R,
+        res_col: usize,
+        noise_infos: NoiseInfos,
+        source: &mut Source,
+    ) where
+        R: VecZnxBigToMut<Self>,
+    {
+        BE::vec_znx_big_add_normal(
+            inner(module),
+            res_base2k,
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            noise_infos,
+            source,
+        )
+    }
+
+    fn vec_znx_big_add_into<R, A, C>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+        C: VecZnxBigToRef<Self>,
+    {
+        BE::vec_znx_big_add_into(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+            retag::<_, BE>(&b.to_ref()),
+            b_col,
+        )
+    }
+
+    fn vec_znx_big_add_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+    {
+        BE::vec_znx_big_add_assign(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+        )
+    }
+
+    fn vec_znx_big_add_small_into<R, A, C>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &C,
+        b_col: usize,
+    ) where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+        C: VecZnxToRef,
+    {
+        BE::vec_znx_big_add_small_into(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+            b,
+            b_col,
+        )
+    }
+
+    fn vec_znx_big_add_small_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_big_add_small_assign(inner(module), retag_mut::<_, BE>(&mut res.to_mut()), res_col, a, a_col)
+    }
+
+    fn vec_znx_big_sub<R, A, C>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+        C: VecZnxBigToRef<Self>,
+    {
+        BE::vec_znx_big_sub(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+            retag::<_, BE>(&b.to_ref()),
+            b_col,
+        )
+    }
+
+    fn vec_znx_big_sub_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+    {
+        BE::vec_znx_big_sub_assign(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+        )
+    }
+
+    fn vec_znx_big_sub_negate_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+    {
+        BE::vec_znx_big_sub_negate_assign(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+        )
+    }
+
+    fn vec_znx_big_sub_small_a<R, A, C>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &C,
+        b_col: usize,
+    ) where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxToRef,
+        C: VecZnxBigToRef<Self>,
+    {
+        BE::vec_znx_big_sub_small_a(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            a,
+            a_col,
+            retag::<_, BE>(&b.to_ref()),
+            b_col,
+        )
+    }
+
+    fn vec_znx_big_sub_small_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_big_sub_small_assign(inner(module), retag_mut::<_, BE>(&mut res.to_mut()), res_col, a, a_col)
+    }
+
+    fn vec_znx_big_sub_small_b<R, A, C>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &C,
+        b_col: usize,
+    ) where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+        C: VecZnxToRef,
+    {
+        BE::vec_znx_big_sub_small_b(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+            b,
+            b_col,
+        )
+    }
+
+    fn vec_znx_big_sub_small_negate_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxToRef,
+    {
+        BE::vec_znx_big_sub_small_negate_assign(inner(module), retag_mut::<_, BE>(&mut res.to_mut()), res_col, a, a_col)
+    }
+
+    fn vec_znx_big_negate<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+    {
+        BE::vec_znx_big_negate(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+        )
+    }
+
+    fn vec_znx_big_negate_assign<A>(module: &Module<Self>, a: &mut A, a_col: usize)
+    where
+        A: VecZnxBigToMut<Self>,
+    {
+        BE::vec_znx_big_negate_assign(inner(module), retag_mut::<_, BE>(&mut a.to_mut()), a_col)
+    }
+
+    fn vec_znx_big_normalize_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_big_normalize_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_big_automorphism<R, A>(module: &Module<Self>, k: i64, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+    {
+        BE::vec_znx_big_automorphism(
+            inner(module),
+            k,
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+        )
+    }
+
+    fn vec_znx_big_automorphism_assign_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_big_automorphism_assign_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_big_automorphism_assign<A>(module: &Module<Self>, k: i64, a: &mut A, a_col: usize, scratch: &mut Scratch<Self>)
+    where
+        A: VecZnxBigToMut<Self>,
+    {
+        BE::vec_znx_big_automorphism_assign(
+            inner(module),
+            k,
+            retag_mut::<_, BE>(&mut a.to_mut()),
+            a_col,
+            retag_scratch(scratch),
+        )
+    }
+
+    fn vec_znx_idft_apply_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_idft_apply_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_idft_apply<R, A>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+    {
+        BE::vec_znx_idft_apply(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+            retag_scratch(scratch),
+        )
+    }
+
+    fn vec_znx_idft_apply_tmpa<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &mut A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxDftToMut<Self>,
+    {
+        BE::vec_znx_idft_apply_tmpa(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag_mut::<_, BE>(&mut a.to_mut()),
+            a_col,
+        )
+    }
+
+    fn vec_znx_dft_add_into<R, A, D>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &D, b_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+        D: VecZnxDftToRef<Self>,
+    {
+        BE::vec_znx_dft_add_into(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+            retag::<_, BE>(&b.to_ref()),
+            b_col,
+        )
+    }
+
+    fn vec_znx_dft_add_scaled_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, a_scale: i64)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+    {
+        BE::vec_znx_dft_add_scaled_assign(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+            a_scale,
+        )
+    }
+
+    fn vec_znx_dft_add_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+    {
+        BE::vec_znx_dft_add_assign(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+        )
+    }
+
+    fn vec_znx_dft_sub<R, A, D>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &D, b_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+        D: VecZnxDftToRef<Self>,
+    {
+        BE::vec_znx_dft_sub(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+            retag::<_, BE>(&b.to_ref()),
+            b_col,
+        )
+    }
+
+    fn vec_znx_dft_sub_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+    {
+        BE::vec_znx_dft_sub_assign(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+        )
+    }
+
+    fn vec_znx_dft_sub_negate_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+    {
+        BE::vec_znx_dft_sub_negate_assign(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+        )
+    }
+
+    fn vec_znx_dft_copy<R, A>(module: &Module<Self>, step: usize, offset: usize, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+    {
+        BE::vec_znx_dft_copy(
+            inner(module),
+            step,
+            offset,
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+        )
+    }
+
+    fn vec_znx_dft_zero<R>(module: &Module<Self>, res: &mut R, res_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+    {
+        BE::vec_znx_dft_zero(inner(module), retag_mut::<_, BE>(&mut res.to_mut()), res_col)
+    }
+
+    fn svp_prepare<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: SvpPPolToMut<Self>,
+        A: ScalarZnxToRef,
+    {
+        BE::svp_prepare(inner(module), retag_mut::<_, BE>(&mut res.to_mut()), res_col, a, a_col)
+    }
+
+    fn svp_apply_dft<R, A, C>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: SvpPPolToRef<Self>,
+        C: VecZnxToRef,
+    {
+        BE::svp_apply_dft(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+            b,
+            b_col,
+        )
+    }
+
+    fn svp_apply_dft_to_dft<R, A, C>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: SvpPPolToRef<Self>,
+        C: VecZnxDftToRef<Self>,
+    {
+        BE::svp_apply_dft_to_dft(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+            retag::<_, BE>(&b.to_ref()),
+            b_col,
+        )
+    }
+
+    fn svp_apply_dft_to_dft_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: SvpPPolToRef<Self>,
+    {
+        BE::svp_apply_dft_to_dft_assign(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+        )
+    }
+
+    fn vmp_prepare_tmp_bytes(module: &Module<Self>, rows: usize, cols_in: usize, cols_out: usize, size: usize) -> usize {
+        BE::vmp_prepare_tmp_bytes(inner(module), rows, cols_in, cols_out, size)
+    }
+
+    fn vmp_prepare<R, A>(module: &Module<Self>, res: &mut R, a: &A, scratch: &mut Scratch<Self>)
+    where
+        R: VmpPMatToMut<Self>,
+        A: MatZnxToRef,
+    {
+        BE::vmp_prepare(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            a,
+            retag_scratch(scratch),
+        )
+    }
+
+    fn vmp_zero<R>(module: &Module<Self>, res: &mut R)
+    where
+        R: VmpPMatToMut<Self>,
+    {
+        BE::vmp_zero(inner(module), retag_mut::<_, BE>(&mut res.to_mut()))
+    }
+
+    fn cnv_prepare_left_tmp_bytes(module: &Module<Self>, res_size: usize, a_size: usize) -> usize {
+        BE::cnv_prepare_left_tmp_bytes(inner(module), res_size, a_size)
+    }
+
+    fn cnv_prepare_left<R, A>(module: &Module<Self>, res: &mut R, a: &A, mask: i64, scratch: &mut Scratch<Self>)
+    where
+        R: CnvPVecLToMut<Self>,
+        A: VecZnxToRef,
+    {
+        BE::cnv_prepare_left(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            a,
+            mask,
+            retag_scratch(scratch),
+        )
+    }
+
+    fn cnv_prepare_right_tmp_bytes(module: &Module<Self>, res_size: usize, a_size: usize) -> usize {
+        BE::cnv_prepare_right_tmp_bytes(inner(module), res_size, a_size)
+    }
+
+    fn cnv_prepare_right<R, A>(module: &Module<Self>, res: &mut R, a: &A, mask: i64, scratch: &mut Scratch<Self>)
+    where
+        R: CnvPVecRToMut<Self>,
+        A: VecZnxToRef + ZnxInfos,
+    {
+        BE::cnv_prepare_right(
+            inner(module),
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            a,
+            mask,
+            retag_scratch(scratch),
+        )
+    }
+
+    fn cnv_apply_dft_tmp_bytes(module: &Module<Self>, cnv_offset: usize, res_size: usize, a_size: usize, b_size: usize) -> usize {
+        BE::cnv_apply_dft_tmp_bytes(inner(module), cnv_offset, res_size, a_size, b_size)
+    }
+
+    fn cnv_by_const_apply_tmp_bytes(
+        module: &Module<Self>,
+        cnv_offset: usize,
+        res_size: usize,
+        a_size: usize,
+        b_size: usize,
+    ) -> usize {
+        BE::cnv_by_const_apply_tmp_bytes(inner(module), cnv_offset, res_size, a_size, b_size)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn cnv_by_const_apply<R, A>(
+        module: &Module<Self>,
+        cnv_offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &[i64],
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxToRef,
+    {
+        BE::cnv_by_const_apply(
+            inner(module),
+            cnv_offset,
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            a,
+            a_col,
+            b,
+            retag_scratch(scratch),
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn cnv_apply_dft<R, A, B>(
+        module: &Module<Self>,
+        cnv_offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &B,
+        b_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxDftToMut<Self>,
+        A: CnvPVecLToRef<Self>,
+        B: CnvPVecRToRef<Self>,
+    {
+        BE::cnv_apply_dft(
+            inner(module),
+            cnv_offset,
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            a_col,
+            retag::<_, BE>(&b.to_ref()),
+            b_col,
+            retag_scratch(scratch),
+        )
+    }
+
+    fn cnv_pairwise_apply_dft_tmp_bytes(
+        module: &Module<Self>,
+        cnv_offset: usize,
+        res_size: usize,
+        a_size: usize,
+        b_size: usize,
+    ) -> usize {
+        BE::cnv_pairwise_apply_dft_tmp_bytes(inner(module), cnv_offset, res_size, a_size, b_size)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn cnv_pairwise_apply_dft<R, A, B>(
+        module: &Module<Self>,
+        cnv_offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        b: &B,
+        i: usize,
+        j: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxDftToMut<Self>,
+        A: CnvPVecLToRef<Self>,
+        B: CnvPVecRToRef<Self>,
+    {
+        BE::cnv_pairwise_apply_dft(
+            inner(module),
+            cnv_offset,
+            retag_mut::<_, BE>(&mut res.to_mut()),
+            res_col,
+            retag::<_, BE>(&a.to_ref()),
+            retag::<_, BE>(&b.to_ref()),
+            i,
+            j,
+            retag_scratch(scratch),
+        )
+    }
+
+    fn cnv_prepare_self_tmp_bytes(module: &Module<Self>, res_size: usize, a_size: usize) -> usize {
+        BE::cnv_prepare_self_tmp_bytes(inner(module), res_size, a_size)
+    }
+
+    fn cnv_prepare_self<L, R, A>(
+        module: &Module<Self>,
+        left: &mut L,
+        right: &mut R,
+        a: &A,
+        mask: i64,
+        scratch: &mut Scratch<Self>,
+    ) where
+        L: CnvPVecLToMut<Self>,
+        R: CnvPVecRToMut<Self>,
+        A: VecZnxToRef + ZnxInfos,
+    {
+        BE::cnv_prepare_self(
+            inner(module),
+            retag_mut::<_, BE>(&mut left.to_mut()),
+            retag_mut::<_, BE>(&mut right.to_mut()),
+            a,
+            mask,
+            retag_scratch(scratch),
+        )
+    }
+}
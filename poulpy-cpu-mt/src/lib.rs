@@ -0,0 +1,57 @@
+//! Multi-threaded CPU backend for the Poulpy lattice cryptography library.
+//!
+//! This crate provides [`FFT64Mt`], a backend implementation for [`poulpy_hal`] that wraps an
+//! FFT64 backend — [`FFT64Ref`](poulpy_cpu_ref::FFT64Ref) by default, or `FFT64Avx` with the
+//! `enable-avx` feature — and splits the most expensive HAL kernels across threads:
+//!
+//! | Operation               | Split over                                |
+//! |-------------------------|-------------------------------------------|
+//! | `vmp_apply_dft_to_dft`  | Output columns (limbs × columns)          |
+//! | `vmp_apply_dft`         | Same, after a multi-threaded DFT of the input |
+//! | `vec_znx_dft_apply`     | Output limbs                              |
+//! | `vec_znx_big_normalize` | Coefficients                              |
+//!
+//! Every other operation is forwarded to the wrapped backend. The wrapper shares the scalar
+//! types, buffer layouts and scratch management of the wrapped backend, and each thread runs
+//! the kernels of the wrapped backend on its share of the output, so results are bit-identical
+//! to a single-threaded run.
+//!
+//! # Threads
+//!
+//! Operations run on the calling thread and a [rayon](https://docs.rs/rayon) thread pool of
+//! `threads - 1` workers, owned by the module: the workers are spawned when the module is
+//! created and shut down when it is dropped. The number of threads is set per module by
+//! [`ThreadConfig`]:
+//!
+//! - [`Module::<FFT64Mt>::new`](poulpy_hal::api::ModuleNew::new) reads it from the
+//!   `POULPY_NUM_THREADS` environment variable, defaulting to
+//!   [`std::thread::available_parallelism`];
+//! - [`FFT64Mt::with_config`] sets it explicitly.
+//!
+//! Operations on rings of degree below [`ThreadConfig::min_n`] run on the calling thread,
+//! where handing work to the pool would outweigh the work. `vmp_apply_dft_to_dft` needs one
+//! scratch area per thread, which its `*_tmp_bytes` method accounts for, and
+//! `vec_znx_big_normalize` normalizes every part directly into the output, with no
+//! scratch beyond that of the wrapped backend.
+//!
+//! # Feature flags
+//!
+//! - `enable-avx`: provides [`FFT64AvxMt`], wrapping the AVX2/FMA backend of `poulpy-cpu-avx`.
+
+mod backend;
+mod hal_impl;
+mod parallel;
+
+#[cfg(test)]
+mod tests;
+
+pub use backend::{FFT64Mt, FFT64MtBase, FFT64MtHandle, NUM_THREADS_ENV, ThreadConfig};
+
+/// [`FFT64Mt`] wrapping the AVX2/FMA backend.
+#[cfg(feature = "enable-avx")]
+pub type FFT64AvxMt = FFT64Mt<poulpy_cpu_avx::FFT64Avx>;
+
+use poulpy_core::oep::CoreImpl;
+unsafe impl<BE: FFT64MtBase> CoreImpl<FFT64Mt<BE>> for FFT64Mt<BE> {
+    poulpy_core::impl_core_default_methods!(FFT64Mt<BE>);
+}
@@ -0,0 +1,292 @@
+//! Multi-threaded versions of the FFT64 kernels.
+//!
+//! Each function splits its output into disjoint parts and hands every part,
+//! as a task of the module's thread pool, to the kernels of the wrapped
+//! backend. The parts
+//! are chosen such that every output value is computed by exactly the same
+//! sequence of kernel calls as in the single-threaded implementation.
+
+use std::{marker::PhantomData, ops::Range};
+
+use poulpy_cpu_ref::reference::vec_znx::{vec_znx_normalize_limbs, vec_znx_normalize_tmp_bytes};
+use poulpy_hal::layouts::{Module, VecZnx, VecZnxBig, VecZnxDft, VmpPMat, ZnxInfos, ZnxView, ZnxViewMut};
+use rayon::ThreadPool;
+
+use crate::FFT64MtBase;
+
+/// Smallest number of coefficients per part when splitting a normalization.
+const NORMALIZE_MIN_CHUNK: usize = 64;
+
+/// Calls `f` on every item, each as a task of `pool` except the last one,
+/// which runs on the calling thread. Without a pool, every item runs on the
+/// calling thread.
+fn par_for_each<T: Send>(pool: Option<&ThreadPool>, items: Vec<T>, f: impl Fn(T) + Sync) {
+    let Some(pool) = pool else {
+        return items.into_iter().for_each(f);
+    };
+    pool.in_place_scope(|scope| {
+        let f = &f;
+        let mut items = items.into_iter();
+        let last: Option<T> = items.next_back();
+        for item in items {
+            scope.spawn(move |_| f(item));
+        }
+        if let Some(item) = last {
+            f(item)
+        }
+    });
+}
+
+/// Splits `0..len` into at most `parts` contiguous, non-empty ranges whose
+/// lengths differ by at most one.
+fn split_range(len: usize, parts: usize) -> Vec<Range<usize>> {
+    let parts: usize = parts.min(len).max(1);
+    let (q, r) = (len / parts, len % parts);
+    let mut start: usize = 0;
+    (0..parts)
+        .map(|i| {
+            let end: usize = start + q + usize::from(i < r);
+            let range: Range<usize> = start..end;
+            start = end;
+            range
+        })
+        .collect()
+}
+
+/// Multi-threaded `vec_znx_dft_apply`, split over the limbs of `res`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn vec_znx_dft_apply<BE: FFT64MtBase>(
+    module: &Module<BE>,
+    pool: Option<&ThreadPool>,
+    threads: usize,
+    step: usize,
+    offset: usize,
+    res: &mut VecZnxDft<&mut [u8], BE>,
+    res_col: usize,
+    a: &VecZnx<&[u8]>,
+    a_col: usize,
+) {
+    let res_size: usize = res.size();
+    let min_steps: usize = res_size.min(a.size().div_ceil(step));
+
+    if threads <= 1 || min_steps <= 1 {
+        BE::vec_znx_dft_apply(module, step, offset, res, res_col, a, a_col);
+        return;
+    }
+
+    // Limbs beyond the last transformed one are zeroed, as a single call
+    // over the whole vector would do.
+    (min_steps..res_size).for_each(|j| BE::reim_zero(res.at_mut(res_col, j)));
+
+    let (n, cols) = (res.n(), res.cols());
+    let limb_bytes: usize = n * cols * size_of::<f64>();
+
+    // Limb `j` of `res` takes limb `offset + j * step` of `a`, so the part
+    // starting at limb `j0` is a call with offset `offset + j0 * step`.
+    let mut rest: &mut [u8] = &mut res.data[..min_steps * limb_bytes];
+    let parts: Vec<(usize, VecZnxDft<&mut [u8], BE>)> = split_range(min_steps, threads)
+        .into_iter()
+        .map(|limbs| {
+            let (data, tail) = std::mem::take(&mut rest).split_at_mut(limbs.len() * limb_bytes);
+            rest = tail;
+            let part: VecZnxDft<&mut [u8], BE> = VecZnxDft {
+                data,
+                n,
+                cols,
+                size: limbs.len(),
+                max_size: limbs.len(),
+                _phantom: PhantomData,
+            };
+            (offset + limbs.start * step, part)
+        })
+        .collect();
+
+    par_for_each(pool, parts, |(offset, mut part)| {
+        BE::vec_znx_dft_apply(module, step, offset, &mut part, res_col, a, a_col)
+    });
+}
+
+/// Multi-threaded `vmp_apply_dft_to_dft`, split over the output columns.
+///
+/// Replicates `poulpy_cpu_ref::reference::fft64::vmp::vmp_apply_dft_to_dft`
+/// with each thread processing a contiguous range of columns of the matrix.
+/// Ranges start on even columns (except the first one), so that columns are
+/// paired exactly as in the single-threaded kernel. `tmp` must provide one
+/// scratch area of the single-threaded kernel per thread.
+pub(crate) fn vmp_apply_dft_to_dft<BE: FFT64MtBase>(
+    pool: Option<&ThreadPool>,
+    threads: usize,
+    res: &mut VecZnxDft<&mut [u8], BE>,
+    a: &VecZnxDft<&[u8], BE>,
+    pmat: &VmpPMat<&[u8], BE>,
+    limb_offset: usize,
+    tmp: &mut [f64],
+) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(res.n(), pmat.n());
+        assert_eq!(a.n(), pmat.n());
+        assert_eq!(res.cols(), pmat.cols_out());
+        assert_eq!(a.cols(), pmat.cols_in());
+    }
+
+    let n: usize = res.n();
+    let m: usize = n >> 1;
+    let nrows: usize = pmat.cols_in() * pmat.rows();
+    let ncols: usize = pmat.cols_out() * pmat.size();
+    let limb_offset: usize = limb_offset * pmat.cols_out();
+
+    let pmat: &[f64] = pmat.raw();
+    let a: &[f64] = a.raw();
+    let res: &mut [f64] = res.raw_mut();
+
+    let row_max: usize = nrows.min(a.len() / n);
+    let col_max: usize = ncols.min(res.len() / n);
+
+    if limb_offset >= col_max {
+        BE::reim_zero(res);
+        return;
+    }
+
+    // Column ranges: interior boundaries are even, so every pair of columns
+    // processed by `reim4_mat2cols_prod` falls in a single range.
+    let first_pair: usize = limb_offset.next_multiple_of(2);
+    let pairs: usize = col_max.saturating_sub(first_pair).div_ceil(2);
+    let mut ranges: Vec<Range<usize>> = split_range(pairs, threads)
+        .into_iter()
+        .map(|r| (first_pair + 2 * r.start)..(first_pair + 2 * r.end).min(col_max))
+        .filter(|r| !r.is_empty())
+        .collect();
+    match ranges.first_mut() {
+        Some(first) => first.start = limb_offset,
+        None => ranges.push(limb_offset..col_max),
+    }
+
+    let tmp_len: usize = tmp.len() / ranges.len();
+    let (out, tail) = res.split_at_mut(col_max * n);
+    let mut rest: &mut [f64] = &mut out[..(col_max - limb_offset) * n];
+    let parts: Vec<(Range<usize>, &mut [f64], &mut [f64])> = ranges
+        .into_iter()
+        .zip(tmp.chunks_exact_mut(tmp_len))
+        .map(|(cols, tmp)| {
+            let (part, tail) = std::mem::take(&mut rest).split_at_mut(cols.len() * n);
+            rest = tail;
+            (cols, part, tmp)
+        })
+        .collect();
+
+    par_for_each(pool, parts, |(cols, res, tmp)| {
+        let (mat2cols_output, extracted_blk) = tmp.split_at_mut(16);
+
+        for blk_i in 0..(m >> 2) {
+            let mat_blk_start: &[f64] = &pmat[blk_i * (8 * nrows * ncols)..];
+
+            BE::reim4_extract_1blk_contiguous(m, row_max, blk_i, extracted_blk, a);
+
+            let mut col_pmat: usize = cols.start;
+
+            if !col_pmat.is_multiple_of(2) {
+                let col_offset: usize = (col_pmat - 1) * (8 * nrows);
+                BE::reim4_mat2cols_2ndcol_prod(row_max, mat2cols_output, extracted_blk, &mat_blk_start[col_offset..]);
+                BE::reim4_save_1blk::<true>(m, blk_i, res, mat2cols_output);
+                col_pmat += 1;
+            }
+
+            while col_pmat + 1 < cols.end {
+                let col_offset: usize = col_pmat * (8 * nrows);
+                BE::reim4_mat2cols_prod(row_max, mat2cols_output, extracted_blk, &mat_blk_start[col_offset..]);
+                BE::reim4_save_2blks::<true>(m, blk_i, &mut res[(col_pmat - cols.start) * n..], mat2cols_output);
+                col_pmat += 2;
+            }
+
+            // Unpaired last column of the matrix.
+            if col_pmat < cols.end {
+                let col_offset: usize = col_pmat * (8 * nrows);
+                if ncols == col_max {
+                    BE::reim4_mat1col_prod(row_max, mat2cols_output, extracted_blk, &mat_blk_start[col_offset..]);
+                } else {
+                    BE::reim4_mat2cols_prod(row_max, mat2cols_output, extracted_blk, &mat_blk_start[col_offset..]);
+                }
+                BE::reim4_save_1blk::<true>(m, blk_i, &mut res[(col_pmat - cols.start) * n..], mat2cols_output);
+            }
+        }
+    });
+
+    BE::reim_zero(tail);
+}
+
+/// Returns the number of parts `vec_znx_big_normalize` is split into at ring
+/// degree `n` with `threads` threads.
+pub(crate) fn vec_znx_big_normalize_parts(n: usize, threads: usize) -> usize {
+    let mut parts: usize = 1;
+    while 2 * parts <= threads && n / (2 * parts) >= NORMALIZE_MIN_CHUNK {
+        parts *= 2;
+    }
+    parts
+}
+
+/// Multi-threaded `vec_znx_big_normalize`, split over the coefficients.
+///
+/// Normalization is carried out independently on each coefficient, so the
+/// `n` coefficients are split into `parts` chunks of `n / parts`. Viewing `a`
+/// with degree `n / parts` and `parts` times as many columns turns chunk `c`
+/// of column `a_col` into column `a_col * parts + c`, which each thread
+/// normalizes directly into chunk `c` of every limb of `res`. `carry` must
+/// hold `vec_znx_normalize_tmp_bytes(n)` bytes.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn vec_znx_big_normalize<BE: FFT64MtBase>(
+    pool: Option<&ThreadPool>,
+    parts: usize,
+    res: &mut VecZnx<&mut [u8]>,
+    res_base2k: usize,
+    res_offset: i64,
+    res_col: usize,
+    a: &VecZnxBig<&[u8], BE>,
+    a_base2k: usize,
+    a_col: usize,
+    carry: &mut [i64],
+) {
+    let n: usize = res.n();
+    let chunk: usize = n / parts;
+    let (res_cols, res_size) = (res.cols(), res.size());
+
+    let a: VecZnx<&[u8]> = VecZnx {
+        data: a.data,
+        n: chunk,
+        cols: a.cols * parts,
+        size: a.size,
+        max_size: a.max_size,
+    };
+
+    let mut chunks: Vec<Vec<&mut [i64]>> = (0..parts).map(|_| Vec::with_capacity(res_size)).collect();
+    for limb in res
+        .raw_mut()
+        .chunks_exact_mut(n)
+        .skip(res_col)
+        .step_by(res_cols)
+        .take(res_size)
+    {
+        for (res_chunks, coeffs) in chunks.iter_mut().zip(limb.chunks_exact_mut(chunk)) {
+            res_chunks.push(coeffs);
+        }
+    }
+
+    let carry_len: usize = vec_znx_normalize_tmp_bytes(chunk) / size_of::<i64>();
+    let items: Vec<_> = chunks
+        .into_iter()
+        .zip(carry.chunks_exact_mut(carry_len))
+        .enumerate()
+        .collect();
+
+    par_for_each(pool, items, |(c, (mut res_chunks, carry))| {
+        vec_znx_normalize_limbs::<_, _, BE>(
+            res_chunks.as_mut_slice(),
+            res_base2k,
+            res_offset,
+            &a,
+            a_base2k,
+            a_col * parts + c,
+            carry,
+        );
+    });
+}
@@ -0,0 +1,246 @@
+use poulpy_cpu_ref::FFT64Ref;
+use poulpy_hal::{
+    api::{
+        ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxBigNormalize, VecZnxBigNormalizeTmpBytes, VecZnxDftAlloc,
+        VecZnxDftApply, VmpApplyDftToDft, VmpApplyDftToDftTmpBytes, VmpPMatAlloc, VmpPrepare, VmpPrepareTmpBytes,
+    },
+    layouts::{
+        AlignedBuf, DataViewMut, DeviceBuf, FillUniform, MatZnx, Module, ScratchOwned, VecZnx, VecZnxBig, VecZnxDft, VmpPMat,
+    },
+    source::Source,
+    test_suite::convolution::{test_convolution, test_convolution_by_const, test_convolution_pairwise},
+};
+
+use crate::{FFT64Mt, FFT64MtBase, ThreadConfig, backend::pool};
+
+/// Splits every operation across three threads, whatever the ring degree.
+const MT: ThreadConfig = ThreadConfig { threads: 3, min_n: 0 };
+
+#[test]
+fn test_config() {
+    let module: Module<FFT64Mt> = FFT64Mt::with_config(1 << 8, MT);
+    assert_eq!(module.n(), 1 << 8);
+    assert_eq!(FFT64Mt::config(&module), MT);
+    // The calling thread takes part, so the pool holds one worker less.
+    assert_eq!(pool(&module).map(|pool| pool.current_num_threads()), Some(2));
+
+    let module: Module<FFT64Mt> = FFT64Mt::with_config(1 << 8, ThreadConfig::new(1));
+    assert!(pool(&module).is_none());
+
+    let module: Module<FFT64Mt> = Module::<FFT64Mt>::new(1 << 8);
+    assert!(FFT64Mt::config(&module).threads >= 1);
+}
+
+#[test]
+fn test_raw_bit_identity() {
+    raw_bit_identity::<FFT64Ref>();
+}
+
+#[cfg(feature = "enable-avx")]
+#[test]
+fn test_raw_bit_identity_avx() {
+    raw_bit_identity::<poulpy_cpu_avx::FFT64Avx>();
+}
+
+/// Checks that the output of the multi-threaded kernels matches the wrapped
+/// backend bit for bit, for every limb offset, step and offset, including
+/// the parts of the output the kernels only zero.
+fn raw_bit_identity<BE: FFT64MtBase>() {
+    let n: usize = 1 << 8;
+    let base2k: usize = 12;
+    let module_ref: Module<BE> = Module::<BE>::new(n as u64);
+    let module_mt: Module<FFT64Mt<BE>> = FFT64Mt::with_config(n as u64, MT);
+
+    let mut source: Source = Source::new([0u8; 32]);
+
+    let (cols_in, cols_out, size_in, size_out) = (2, 2, 5, 7);
+
+    let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols_in, size_in);
+    a.fill_uniform(base2k, &mut source);
+
+    for step in 1..4 {
+        for offset in 0..size_in {
+            let mut res_ref: VecZnxDft<DeviceBuf<BE>, BE> = module_ref.vec_znx_dft_alloc(cols_in, size_out);
+            let mut res_mt: VecZnxDft<DeviceBuf<FFT64Mt<BE>>, FFT64Mt<BE>> = module_mt.vec_znx_dft_alloc(cols_in, size_out);
+            res_ref.data_mut().as_mut().fill(0x5a);
+            res_mt.data_mut().as_mut().copy_from_slice(res_ref.data_mut().as_mut());
+            for j in 0..cols_in {
+                module_ref.vec_znx_dft_apply(step, offset, &mut res_ref, j, &a, j);
+                module_mt.vec_znx_dft_apply(step, offset, &mut res_mt, j, &a, j);
+            }
+            assert_eq!(res_ref.data_mut().as_mut(), res_mt.data_mut().as_mut());
+        }
+    }
+
+    let mut a_ref: VecZnxDft<DeviceBuf<BE>, BE> = module_ref.vec_znx_dft_alloc(cols_in, size_in);
+    let mut a_mt: VecZnxDft<DeviceBuf<FFT64Mt<BE>>, FFT64Mt<BE>> = module_mt.vec_znx_dft_alloc(cols_in, size_in);
+    for j in 0..cols_in {
+        module_ref.vec_znx_dft_apply(1, 0, &mut a_ref, j, &a, j);
+        module_mt.vec_znx_dft_apply(1, 0, &mut a_mt, j, &a, j);
+    }
+
+    let mut scratch_ref: ScratchOwned<BE> = ScratchOwned::alloc(
+        module_ref
+            .vmp_apply_dft_to_dft_tmp_bytes(size_out, size_in, size_in, cols_in, cols_out, size_out)
+            .max(module_ref.vmp_prepare_tmp_bytes(size_in, cols_in, cols_out, size_out))
+            .max(module_ref.vec_znx_big_normalize_tmp_bytes()),
+    );
+    let mut scratch_mt: ScratchOwned<FFT64Mt<BE>> = ScratchOwned::alloc(
+        module_mt
+            .vmp_apply_dft_to_dft_tmp_bytes(size_out, size_in, size_in, cols_in, cols_out, size_out)
+            .max(module_mt.vmp_prepare_tmp_bytes(size_in, cols_in, cols_out, size_out))
+            .max(module_mt.vec_znx_big_normalize_tmp_bytes()),
+    );
+
+    for mat_size in [size_out - 2, size_out, size_out + 1] {
+        let mut mat: MatZnx<AlignedBuf> = MatZnx::alloc(n, size_in, cols_in, cols_out, mat_size);
+        mat.fill_uniform(base2k, &mut source);
+
+        let mut pmat_ref: VmpPMat<DeviceBuf<BE>, BE> = module_ref.vmp_pmat_alloc(size_in, cols_in, cols_out, mat_size);
+        let mut pmat_mt: VmpPMat<DeviceBuf<FFT64Mt<BE>>, FFT64Mt<BE>> =
+            module_mt.vmp_pmat_alloc(size_in, cols_in, cols_out, mat_size);
+        module_ref.vmp_prepare(&mut pmat_ref, &mat, scratch_ref.borrow());
+        module_mt.vmp_prepare(&mut pmat_mt, &mat, scratch_mt.borrow());
+
+        for limb_offset in 0..size_out + 1 {
+            let mut res_ref: VecZnxDft<DeviceBuf<BE>, BE> = module_ref.vec_znx_dft_alloc(cols_out, size_out);
+            let mut res_mt: VecZnxDft<DeviceBuf<FFT64Mt<BE>>, FFT64Mt<BE>> = module_mt.vec_znx_dft_alloc(cols_out, size_out);
+            res_ref.data_mut().as_mut().fill(0x5a);
+            res_mt.data_mut().as_mut().copy_from_slice(res_ref.data_mut().as_mut());
+
+            module_ref.vmp_apply_dft_to_dft(&mut res_ref, &a_ref, &pmat_ref, limb_offset, scratch_ref.borrow());
+            module_mt.vmp_apply_dft_to_dft(&mut res_mt, &a_mt, &pmat_mt, limb_offset, scratch_mt.borrow());
+            assert_eq!(res_ref.data_mut().as_mut(), res_mt.data_mut().as_mut());
+        }
+    }
+
+    for (res_base2k, res_offset) in [(base2k, 0), (base2k, 5), (base2k + 7, -3)] {
+        let mut a_big: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols_in, size_in);
+        a_big.fill_uniform(50, &mut source);
+        let a_big_ref: VecZnxBig<DeviceBuf<BE>, BE> = VecZnxBig::from_bytes(n, cols_in, size_in, a_big.data.to_vec());
+        let a_big_mt: VecZnxBig<DeviceBuf<FFT64Mt<BE>>, FFT64Mt<BE>> =
+            VecZnxBig::from_bytes(n, cols_in, size_in, a_big.data.to_vec());
+
+        let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols_out, size_out);
+        res_ref.fill_uniform(base2k, &mut source);
+        let mut res_mt: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols_out, size_out);
+        res_mt.data.copy_from_slice(&res_ref.data);
+
+        for j in 0..cols_in {
+            module_ref.vec_znx_big_normalize(
+                &mut res_ref,
+                res_base2k,
+                res_offset,
+                j,
+                &a_big_ref,
+                base2k,
+                j,
+                scratch_ref.borrow(),
+            );
+            module_mt.vec_znx_big_normalize(
+                &mut res_mt,
+                res_base2k,
+                res_offset,
+                j,
+                &a_big_mt,
+                base2k,
+                j,
+                scratch_mt.borrow(),
+            );
+        }
+        assert_eq!(res_ref, res_mt);
+    }
+}
+
+mod fft64 {
+    use poulpy_hal::cross_backend_test_suite;
+
+    cross_backend_test_suite! {
+        mod vec_znx_big,
+        backend_ref =  poulpy_cpu_ref::FFT64Ref,
+        backend_test = crate::FFT64Mt,
+        params = TestParams { size: 1<<8, base2k: 12 },
+        tests = {
+            test_vec_znx_big_add_into => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_add_into,
+            test_vec_znx_big_automorphism => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_automorphism,
+            test_vec_znx_big_normalize => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_normalize,
+            test_vec_znx_big_normalize_fused => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_normalize_fused,
+            test_vec_znx_big_sub_small_a => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_sub_small_a,
+        }
+    }
+
+    cross_backend_test_suite! {
+        mod vec_znx_dft,
+        backend_ref =  poulpy_cpu_ref::FFT64Ref,
+        backend_test = crate::FFT64Mt,
+        params = TestParams { size: 1<<8, base2k: 12 },
+        tests = {
+            test_vec_znx_dft_add_into => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_dft_add_into,
+            test_vec_znx_idft_apply => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_idft_apply,
+            test_vec_znx_idft_apply_consume => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_idft_apply_consume,
+            test_vec_znx_idft_apply_tmpa => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_idft_apply_tmpa,
+        }
+    }
+
+    cross_backend_test_suite! {
+        mod vmp,
+        backend_ref =  poulpy_cpu_ref::FFT64Ref,
+        backend_test = crate::FFT64Mt,
+        params = TestParams { size: 1<<8, base2k: 12 },
+        tests = {
+            test_vmp_apply_dft => poulpy_hal::test_suite::vmp::test_vmp_apply_dft,
+            test_vmp_apply_dft_to_dft => poulpy_hal::test_suite::vmp::test_vmp_apply_dft_to_dft,
+        }
+    }
+}
+
+/// Same tests on a module splitting every operation, so that the
+/// multi-threaded paths are exercised whatever the host.
+mod fft64_threads {
+    use once_cell::sync::Lazy;
+    use poulpy_cpu_ref::FFT64Ref;
+    use poulpy_hal::{api::ModuleNew, layouts::Module, test_suite::TestParams};
+
+    use crate::FFT64Mt;
+
+    static PARAMS: Lazy<TestParams> = Lazy::new(|| TestParams {
+        size: 1 << 8,
+        base2k: 12,
+    });
+    static MODULE_REF: Lazy<Module<FFT64Ref>> = Lazy::new(|| Module::<FFT64Ref>::new(PARAMS.size as u64));
+    static MODULE_TEST: Lazy<Module<FFT64Mt>> = Lazy::new(|| FFT64Mt::with_config(PARAMS.size as u64, super::MT));
+
+    macro_rules! threads_test_suite {
+        ($($test_name:ident => $impl:path),+ $(,)?) => {
+            $(
+                #[test]
+                fn $test_name() {
+                    ($impl)(&*PARAMS, &*MODULE_REF, &*MODULE_TEST);
+                }
+            )+
+        };
+    }
+
+    threads_test_suite! {
+        test_vec_znx_big_normalize => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_normalize,
+        test_vec_znx_big_normalize_fused => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_normalize_fused,
+        test_vec_znx_idft_apply => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_idft_apply,
+        test_vec_znx_idft_apply_consume => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_idft_apply_consume,
+        test_vmp_apply_dft => poulpy_hal::test_suite::vmp::test_vmp_apply_dft,
+        test_vmp_apply_dft_to_dft => poulpy_hal::test_suite::vmp::test_vmp_apply_dft_to_dft,
+    }
+}
+
+#[test]
+fn test_convolution_threads() {
+    let module: Module<FFT64Mt> = FFT64Mt::with_config(1 << 8, MT);
+    test_convolution(&module, 12);
+    test_convolution_by_const(&module, 12);
+    test_convolution_pairwise(&module, 12);
+}
+
+poulpy_core::core_backend_test_suite!(
+    mod core_fft64,
+    backend = crate::FFT64Mt,
+    params = TestParams { size: 1<<8, base2k: 17 },
+);
@@ -8,7 +8,7 @@ use poulpy_core::{
 use poulpy_cpu_ref::FFT64Ref as BackendImpl;
 use poulpy_hal::{
     api::{ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow, VecZnxFillUniform},
-    layouts::{DeviceBuf, Module, ScratchOwned},
+    layouts::{AlignedBuf, DeviceBuf, Module, ScratchOwned},
     source::Source,
 };
 
@@ -32,18 +32,20 @@ fn main() {
 
     let glwe_pt_infos: GLWEPlaintextLayout = GLWEPlaintextLayout { n, base2k, k: k_pt };
 
-    let mut ct: GLWE<Vec<u8>> = GLWE::alloc_from_infos(&glwe_ct_infos);
-    let mut pt_want: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_pt_infos);
-    let mut pt_have: GLWEPlaintext<Vec<u8>> = GLWEPlaintext::alloc_from_infos(&glwe_pt_infos);
+    let mut ct: GLWE<AlignedBuf> = GLWE::alloc_from_infos(&glwe_ct_infos);
+    let mut pt_want: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_pt_infos);
+    let mut pt_have: GLWEPlaintext<AlignedBuf> = GLWEPlaintext::alloc_from_infos(&glwe_pt_infos);
 
-    let mut source_xs: Source = Source::new([0u8; 32]);
-    let mut source_xe: Source = Source::new([1u8; 32]);
-    let mut source_xa: Source = Source::new([2u8; 32]);
+    // Secret, error and mask are sampled from the OS-seeded CSPRNG;
+    // `Source::new(seed)` gives reproducible (but insecure) runs.
+    let mut source_xs: Source = Source::from_os_entropy();
+    let mut source_xe: Source = Source::from_os_entropy();
+    let mut source_xa: Source = Source::from_os_entropy();
 
     let mut scratch: ScratchOwned<BackendImpl> =
         ScratchOwned::alloc(module.glwe_encrypt_sk_tmp_bytes(&glwe_ct_infos) | module.glwe_decrypt_tmp_bytes(&glwe_ct_infos));
 
-    let mut sk: GLWESecret<Vec<u8>> = GLWESecret::alloc_from_infos(&glwe_ct_infos);
+    let mut sk: GLWESecret<AlignedBuf> = GLWESecret::alloc_from_infos(&glwe_ct_infos);
     sk.fill_ternary_prob(0.5, &mut source_xs);
 
     let mut sk_prepared: GLWESecretPrepared<DeviceBuf<BackendImpl>, BackendImpl> = module.glwe_secret_prepared_alloc(rank);
@@ -14,7 +14,10 @@
 
 use std::ptr::NonNull;
 
-use poulpy_hal::{alloc_aligned, assert_alignment, layouts::Backend};
+use poulpy_hal::{
+    alloc_aligned,
+    layouts::{AlignedBuf, Backend},
+};
 
 use crate::reference::fft64::{
     module::{FFT64HandleFactory, FFTHandleProvider},
@@ -40,14 +43,14 @@ pub struct FFT64RefHandle {
 impl Backend for FFT64Ref {
     type ScalarPrep = f64;
     type ScalarBig = i64;
-    type OwnedBuf = Vec<u8>;
+    type OwnedBuf = AlignedBuf;
     type Handle = FFT64RefHandle;
+    const PREPARED_LAYOUT: &'static str = "fft64";
     fn alloc_bytes(len: usize) -> Self::OwnedBuf {
-        alloc_aligned::<u8>(len)
+        alloc_aligned(len)
     }
     fn from_bytes(bytes: Vec<u8>) -> Self::OwnedBuf {
-        assert_alignment(bytes.as_ptr());
-        bytes
+        AlignedBuf::from(bytes)
     }
     unsafe fn destroy(handle: NonNull<Self::Handle>) {
         unsafe {
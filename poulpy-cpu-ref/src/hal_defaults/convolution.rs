@@ -13,6 +13,14 @@ use crate::reference::{
         reim::{ReimArith, ReimFFTExecute, ReimFFTTable},
         reim4::{Reim4BlkMatVec, Reim4Convolution},
     },
+    ntt60::{
+        convolution::{
+            ntt60_cnv_apply_dft, ntt60_cnv_pairwise_apply_dft, ntt60_cnv_pairwise_apply_dft_tmp_bytes, ntt60_cnv_prepare_left,
+            ntt60_cnv_prepare_right, ntt60_cnv_prepare_self,
+        },
+        types::Q60Scalar,
+        vec_znx_dft::Ntt60ModuleHandle,
+    },
     ntt120::{
         NttAddAssign, NttCFromB, NttDFTExecute, NttFromZnx64, NttMulBbc1ColX2, NttMulBbc2ColsX2, NttPackLeft1BlkX2,
         NttPackRight1BlkX2, NttPairwisePackLeft1BlkX2, NttPairwisePackRight1BlkX2,
@@ -23,10 +31,14 @@ use crate::reference::{
             ntt120_cnv_prepare_self, ntt120_cnv_prepare_self_tmp_bytes,
         },
         ntt::NttTable,
-        primes::Primes30,
         types::Q120bScalar,
         vec_znx_dft::NttModuleHandle,
     },
+    schoolbook::convolution::{
+        schoolbook_cnv_apply_dft, schoolbook_cnv_by_const_apply, schoolbook_cnv_by_const_apply_tmp_bytes,
+        schoolbook_cnv_pairwise_apply_dft, schoolbook_cnv_pairwise_apply_dft_tmp_bytes, schoolbook_cnv_prepare_left,
+        schoolbook_cnv_prepare_right, schoolbook_cnv_prepare_self,
+    },
 };
 use poulpy_hal::{
     api::{ModuleN, ScratchTakeBasic, TakeSlice, VecZnxDftBytesOf},
@@ -236,8 +248,8 @@ pub trait NTT120ConvolutionDefaults<BE: Backend>: Backend {
 
     fn cnv_prepare_left_default<R, A>(module: &Module<BE>, res: &mut R, a: &A, mask: i64, scratch: &mut Scratch<BE>)
     where
-        Module<BE>: NttModuleHandle,
-        BE: Backend<ScalarPrep = Q120bScalar> + NttFromZnx64 + NttDFTExecute<NttTable<Primes30>>,
+        Module<BE>: NttModuleHandle<PrimeSet = BE::Primes>,
+        BE: Backend<ScalarPrep = Q120bScalar> + NttFromZnx64 + NttDFTExecute<NttTable<BE::Primes>>,
         Scratch<BE>: TakeSlice,
         R: CnvPVecLToMut<BE>,
         A: VecZnxToRef,
@@ -256,8 +268,8 @@ pub trait NTT120ConvolutionDefaults<BE: Backend>: Backend {
 
     fn cnv_prepare_right_default<R, A>(module: &Module<BE>, res: &mut R, a: &A, mask: i64, scratch: &mut Scratch<BE>)
     where
-        Module<BE>: NttModuleHandle,
-        BE: Backend<ScalarPrep = Q120bScalar> + NttFromZnx64 + NttDFTExecute<NttTable<Primes30>> + NttCFromB,
+        Module<BE>: NttModuleHandle<PrimeSet = BE::Primes>,
+        BE: Backend<ScalarPrep = Q120bScalar> + NttFromZnx64 + NttDFTExecute<NttTable<BE::Primes>> + NttCFromB,
         Scratch<BE>: TakeSlice,
         R: CnvPVecRToMut<BE>,
         A: VecZnxToRef + ZnxInfos,
@@ -326,7 +338,7 @@ pub trait NTT120ConvolutionDefaults<BE: Backend>: Backend {
         b_col: usize,
         scratch: &mut Scratch<BE>,
     ) where
-        Module<BE>: NttModuleHandle,
+        Module<BE>: NttModuleHandle<PrimeSet = BE::Primes>,
         BE: Backend<ScalarPrep = Q120bScalar>
             + NttAddAssign
             + NttMulBbc1ColX2
@@ -371,7 +383,7 @@ pub trait NTT120ConvolutionDefaults<BE: Backend>: Backend {
         j: usize,
         scratch: &mut Scratch<BE>,
     ) where
-        Module<BE>: NttModuleHandle,
+        Module<BE>: NttModuleHandle<PrimeSet = BE::Primes>,
         BE: Backend<ScalarPrep = Q120bScalar>
             + NttAddAssign
             + NttMulBbc1ColX2
@@ -408,8 +420,8 @@ pub trait NTT120ConvolutionDefaults<BE: Backend>: Backend {
         mask: i64,
         scratch: &mut Scratch<BE>,
     ) where
-        Module<BE>: NttModuleHandle,
-        BE: Backend<ScalarPrep = Q120bScalar> + NttFromZnx64 + NttDFTExecute<NttTable<Primes30>> + NttCFromB,
+        Module<BE>: NttModuleHandle<PrimeSet = BE::Primes>,
+        BE: Backend<ScalarPrep = Q120bScalar> + NttFromZnx64 + NttDFTExecute<NttTable<BE::Primes>> + NttCFromB,
         Scratch<BE>: TakeSlice,
         L: CnvPVecLToMut<BE>,
         R: CnvPVecRToMut<BE>,
@@ -422,3 +434,335 @@ pub trait NTT120ConvolutionDefaults<BE: Backend>: Backend {
 }
 
 impl<BE: Backend> NTT120ConvolutionDefaults<BE> for BE {}
+
+#[doc(hidden)]
+pub trait NTT60ConvolutionDefaults<BE: Backend>: Backend {
+    fn cnv_prepare_left_tmp_bytes_default(_module: &Module<BE>, _res_size: usize, _a_size: usize) -> usize
+    where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+    {
+        0
+    }
+
+    fn cnv_prepare_left_default<R, A>(module: &Module<BE>, res: &mut R, a: &A, mask: i64, _scratch: &mut Scratch<BE>)
+    where
+        Module<BE>: Ntt60ModuleHandle,
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: CnvPVecLToMut<BE>,
+        A: VecZnxToRef,
+    {
+        ntt60_cnv_prepare_left::<R, A, BE>(module.get_ntt60_table(), res, a, mask);
+    }
+
+    fn cnv_prepare_right_tmp_bytes_default(_module: &Module<BE>, _res_size: usize, _a_size: usize) -> usize
+    where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+    {
+        0
+    }
+
+    fn cnv_prepare_right_default<R, A>(module: &Module<BE>, res: &mut R, a: &A, mask: i64, _scratch: &mut Scratch<BE>)
+    where
+        Module<BE>: Ntt60ModuleHandle,
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: CnvPVecRToMut<BE>,
+        A: VecZnxToRef,
+    {
+        ntt60_cnv_prepare_right::<R, A, BE>(module.get_ntt60_table(), res, a, mask);
+    }
+
+    fn cnv_apply_dft_tmp_bytes_default(
+        _module: &Module<BE>,
+        _cnv_offset: usize,
+        _res_size: usize,
+        _a_size: usize,
+        _b_size: usize,
+    ) -> usize
+    where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+    {
+        0
+    }
+
+    fn cnv_by_const_apply_tmp_bytes_default(
+        _module: &Module<BE>,
+        _cnv_offset: usize,
+        res_size: usize,
+        a_size: usize,
+        b_size: usize,
+    ) -> usize
+    where
+        BE: Backend<ScalarBig = i64>,
+    {
+        convolution_by_const_apply_tmp_bytes(res_size, a_size, b_size)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn cnv_by_const_apply_default<R, A>(
+        _module: &Module<BE>,
+        cnv_offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &[i64],
+        scratch: &mut Scratch<BE>,
+    ) where
+        BE: Backend<ScalarBig = i64> + I64Ops,
+        Scratch<BE>: TakeSlice,
+        R: VecZnxBigToMut<BE>,
+        A: VecZnxToRef,
+    {
+        let mut res: VecZnxBig<&mut [u8], BE> = res.to_mut();
+        let a: VecZnx<&[u8]> = a.to_ref();
+        let bytes = convolution_by_const_apply_tmp_bytes(res.size(), a.size(), b.len());
+        let (tmp, _) = scratch.take_slice::<i64>(bytes / size_of::<i64>());
+        convolution_by_const_apply(cnv_offset, &mut res, res_col, &a, a_col, b, tmp);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn cnv_apply_dft_default<R, A, B>(
+        _module: &Module<BE>,
+        cnv_offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &B,
+        b_col: usize,
+        _scratch: &mut Scratch<BE>,
+    ) where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: VecZnxDftToMut<BE>,
+        A: CnvPVecLToRef<BE>,
+        B: CnvPVecRToRef<BE>,
+    {
+        ntt60_cnv_apply_dft::<R, A, B, BE>(cnv_offset, res, res_col, a, a_col, b, b_col);
+    }
+
+    fn cnv_pairwise_apply_dft_tmp_bytes_default(
+        module: &Module<BE>,
+        _cnv_offset: usize,
+        res_size: usize,
+        a_size: usize,
+        b_size: usize,
+    ) -> usize
+    where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+    {
+        ntt60_cnv_pairwise_apply_dft_tmp_bytes(module.n(), res_size, a_size, b_size)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn cnv_pairwise_apply_dft_default<R, A, B>(
+        module: &Module<BE>,
+        cnv_offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        b: &B,
+        i: usize,
+        j: usize,
+        scratch: &mut Scratch<BE>,
+    ) where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        Scratch<BE>: TakeSlice,
+        R: VecZnxDftToMut<BE>,
+        A: CnvPVecLToRef<BE>,
+        B: CnvPVecRToRef<BE>,
+    {
+        let res_size: usize = res.to_mut().size();
+        let (a_size, b_size) = (a.to_ref().size(), b.to_ref().size());
+        let bytes = ntt60_cnv_pairwise_apply_dft_tmp_bytes(module.n(), res_size, a_size, b_size);
+        let (tmp, _) = scratch.take_slice::<Q60Scalar>(bytes / size_of::<Q60Scalar>());
+        ntt60_cnv_pairwise_apply_dft::<R, A, B, BE>(cnv_offset, res, res_col, a, b, i, j, tmp);
+    }
+
+    fn cnv_prepare_self_tmp_bytes_default(_module: &Module<BE>, _res_size: usize, _a_size: usize) -> usize
+    where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+    {
+        0
+    }
+
+    fn cnv_prepare_self_default<L, R, A>(
+        module: &Module<BE>,
+        left: &mut L,
+        right: &mut R,
+        a: &A,
+        mask: i64,
+        _scratch: &mut Scratch<BE>,
+    ) where
+        Module<BE>: Ntt60ModuleHandle,
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        L: CnvPVecLToMut<BE>,
+        R: CnvPVecRToMut<BE>,
+        A: VecZnxToRef + ZnxInfos,
+    {
+        ntt60_cnv_prepare_self::<L, R, A, BE>(module.get_ntt60_table(), left, right, a, mask);
+    }
+}
+
+impl<BE: Backend> NTT60ConvolutionDefaults<BE> for BE {}
+
+#[doc(hidden)]
+pub trait SchoolbookConvolutionDefaults<BE: Backend>: Backend {
+    fn cnv_prepare_left_tmp_bytes_default(_module: &Module<BE>, _res_size: usize, _a_size: usize) -> usize
+    where
+        BE: Backend<ScalarPrep = i128>,
+    {
+        0
+    }
+
+    fn cnv_prepare_left_default<R, A>(_module: &Module<BE>, res: &mut R, a: &A, mask: i64, _scratch: &mut Scratch<BE>)
+    where
+        BE: Backend<ScalarPrep = i128>,
+        R: CnvPVecLToMut<BE>,
+        A: VecZnxToRef,
+    {
+        schoolbook_cnv_prepare_left::<R, A, BE>(res, a, mask);
+    }
+
+    fn cnv_prepare_right_tmp_bytes_default(_module: &Module<BE>, _res_size: usize, _a_size: usize) -> usize
+    where
+        BE: Backend<ScalarPrep = i128>,
+    {
+        0
+    }
+
+    fn cnv_prepare_right_default<R, A>(_module: &Module<BE>, res: &mut R, a: &A, mask: i64, _scratch: &mut Scratch<BE>)
+    where
+        BE: Backend<ScalarPrep = i128>,
+        R: CnvPVecRToMut<BE>,
+        A: VecZnxToRef,
+    {
+        schoolbook_cnv_prepare_right::<R, A, BE>(res, a, mask);
+    }
+
+    fn cnv_apply_dft_tmp_bytes_default(
+        _module: &Module<BE>,
+        _cnv_offset: usize,
+        _res_size: usize,
+        _a_size: usize,
+        _b_size: usize,
+    ) -> usize
+    where
+        BE: Backend<ScalarPrep = i128>,
+    {
+        0
+    }
+
+    fn cnv_by_const_apply_tmp_bytes_default(
+        _module: &Module<BE>,
+        _cnv_offset: usize,
+        res_size: usize,
+        a_size: usize,
+        b_size: usize,
+    ) -> usize
+    where
+        BE: Backend<ScalarBig = i128>,
+    {
+        schoolbook_cnv_by_const_apply_tmp_bytes(res_size, a_size, b_size)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn cnv_by_const_apply_default<R, A>(
+        _module: &Module<BE>,
+        cnv_offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &[i64],
+        _scratch: &mut Scratch<BE>,
+    ) where
+        BE: Backend<ScalarBig = i128>,
+        R: VecZnxBigToMut<BE>,
+        A: VecZnxToRef,
+    {
+        schoolbook_cnv_by_const_apply::<R, A, BE>(cnv_offset, res, res_col, a, a_col, b);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn cnv_apply_dft_default<R, A, B>(
+        _module: &Module<BE>,
+        cnv_offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &B,
+        b_col: usize,
+        _scratch: &mut Scratch<BE>,
+    ) where
+        BE: Backend<ScalarPrep = i128>,
+        R: VecZnxDftToMut<BE>,
+        A: CnvPVecLToRef<BE>,
+        B: CnvPVecRToRef<BE>,
+    {
+        schoolbook_cnv_apply_dft::<R, A, B, BE>(cnv_offset, res, res_col, a, a_col, b, b_col);
+    }
+
+    fn cnv_pairwise_apply_dft_tmp_bytes_default(
+        module: &Module<BE>,
+        _cnv_offset: usize,
+        res_size: usize,
+        a_size: usize,
+        b_size: usize,
+    ) -> usize
+    where
+        BE: Backend<ScalarPrep = i128>,
+    {
+        schoolbook_cnv_pairwise_apply_dft_tmp_bytes(module.n(), res_size, a_size, b_size)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn cnv_pairwise_apply_dft_default<R, A, B>(
+        module: &Module<BE>,
+        cnv_offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        b: &B,
+        i: usize,
+        j: usize,
+        scratch: &mut Scratch<BE>,
+    ) where
+        BE: Backend<ScalarPrep = i128>,
+        Scratch<BE>: TakeSlice,
+        R: VecZnxDftToMut<BE>,
+        A: CnvPVecLToRef<BE>,
+        B: CnvPVecRToRef<BE>,
+    {
+        let res_size: usize = res.to_mut().size();
+        let (a_size, b_size) = (a.to_ref().size(), b.to_ref().size());
+        let bytes = schoolbook_cnv_pairwise_apply_dft_tmp_bytes(module.n(), res_size, a_size, b_size);
+        let (tmp, _) = scratch.take_slice::<i128>(bytes / size_of::<i128>());
+        schoolbook_cnv_pairwise_apply_dft::<R, A, B, BE>(cnv_offset, res, res_col, a, b, i, j, tmp);
+    }
+
+    fn cnv_prepare_self_tmp_bytes_default(_module: &Module<BE>, _res_size: usize, _a_size: usize) -> usize
+    where
+        BE: Backend<ScalarPrep = i128>,
+    {
+        0
+    }
+
+    fn cnv_prepare_self_default<L, R, A>(
+        _module: &Module<BE>,
+        left: &mut L,
+        right: &mut R,
+        a: &A,
+        mask: i64,
+        _scratch: &mut Scratch<BE>,
+    ) where
+        BE: Backend<ScalarPrep = i128>,
+        L: CnvPVecLToMut<BE>,
+        R: CnvPVecRToMut<BE>,
+        A: VecZnxToRef + ZnxInfos,
+    {
+        schoolbook_cnv_prepare_self::<L, R, A, BE>(left, right, a, mask);
+    }
+}
+
+impl<BE: Backend> SchoolbookConvolutionDefaults<BE> for BE {}
@@ -2,7 +2,9 @@
 
 use std::ptr::NonNull;
 
-use crate::reference::{fft64::module::FFT64HandleFactory, ntt120::vec_znx_dft::NttHandleFactory};
+use crate::reference::{
+    fft64::module::FFT64HandleFactory, ntt60::vec_znx_dft::Ntt60HandleFactory, ntt120::vec_znx_dft::NttHandleFactory,
+};
 use poulpy_hal::layouts::{Backend, Module};
 
 #[doc(hidden)]
@@ -34,3 +36,30 @@ pub trait NTT120ModuleDefaults<BE: Backend>: Backend {
 }
 
 impl<BE: Backend> NTT120ModuleDefaults<BE> for BE {}
+
+#[doc(hidden)]
+pub trait NTT60ModuleDefaults<BE: Backend>: Backend {
+    fn module_new_default(n: u64) -> Module<BE>
+    where
+        BE::Handle: Ntt60HandleFactory,
+    {
+        let handle = <BE::Handle as Ntt60HandleFactory>::create_ntt60_handle(n as usize);
+        let ptr: NonNull<BE::Handle> = NonNull::from(Box::leak(Box::new(handle)));
+        unsafe { Module::from_nonnull(ptr, n) }
+    }
+}
+
+impl<BE: Backend> NTT60ModuleDefaults<BE> for BE {}
+
+#[doc(hidden)]
+pub trait SchoolbookModuleDefaults<BE: Backend>: Backend {
+    fn module_new_default(n: u64) -> Module<BE>
+    where
+        BE::Handle: Default,
+    {
+        let ptr: NonNull<BE::Handle> = NonNull::from(Box::leak(Box::new(BE::Handle::default())));
+        unsafe { Module::from_nonnull(ptr, n) }
+    }
+}
+
+impl<BE: Backend> SchoolbookModuleDefaults<BE> for BE {}
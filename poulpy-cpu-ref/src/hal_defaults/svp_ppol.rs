@@ -10,14 +10,21 @@ use crate::reference::{
             svp_apply_dft_to_dft_assign as fft64_svp_apply_dft_to_dft_assign, svp_prepare as fft64_svp_prepare,
         },
     },
+    ntt60::{
+        svp::{ntt60_svp_apply_dft, ntt60_svp_apply_dft_to_dft, ntt60_svp_apply_dft_to_dft_assign, ntt60_svp_prepare},
+        types::Q60Scalar,
+        vec_znx_dft::Ntt60ModuleHandle,
+    },
     ntt120::{
         NttCFromB, NttDFTExecute, NttFromZnx64, NttMulBbc, NttZero,
         ntt::NttTable,
-        primes::Primes30,
         svp::{ntt120_svp_apply_dft_to_dft, ntt120_svp_apply_dft_to_dft_assign, ntt120_svp_prepare},
         types::Q120bScalar,
         vec_znx_dft::NttModuleHandle,
     },
+    schoolbook::svp::{
+        schoolbook_svp_apply_dft, schoolbook_svp_apply_dft_to_dft, schoolbook_svp_apply_dft_to_dft_assign, schoolbook_svp_prepare,
+    },
 };
 use poulpy_hal::{
     api::VecZnxDftApply,
@@ -82,8 +89,8 @@ impl<BE: Backend> FFT64SvpDefaults<BE> for BE {}
 pub trait NTT120SvpDefaults<BE: Backend>: Backend {
     fn svp_prepare_default<R, A>(module: &Module<BE>, res: &mut R, res_col: usize, a: &A, a_col: usize)
     where
-        Module<BE>: NttModuleHandle,
-        BE: Backend<ScalarPrep = Q120bScalar> + NttDFTExecute<NttTable<Primes30>> + NttFromZnx64 + NttCFromB,
+        Module<BE>: NttModuleHandle<PrimeSet = BE::Primes>,
+        BE: Backend<ScalarPrep = Q120bScalar> + NttDFTExecute<NttTable<BE::Primes>> + NttFromZnx64 + NttCFromB,
         R: SvpPPolToMut<BE>,
         A: ScalarZnxToRef,
     {
@@ -92,8 +99,8 @@ pub trait NTT120SvpDefaults<BE: Backend>: Backend {
 
     fn svp_apply_dft_default<R, A, C>(module: &Module<BE>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
     where
-        Module<BE>: NttModuleHandle + VecZnxDftApply<BE>,
-        BE: Backend<ScalarPrep = Q120bScalar> + NttDFTExecute<NttTable<Primes30>> + NttFromZnx64 + NttMulBbc + NttZero,
+        Module<BE>: NttModuleHandle<PrimeSet = BE::Primes> + VecZnxDftApply<BE>,
+        BE: Backend<ScalarPrep = Q120bScalar> + NttDFTExecute<NttTable<BE::Primes>> + NttFromZnx64 + NttMulBbc + NttZero,
         R: VecZnxDftToMut<BE>,
         A: SvpPPolToRef<BE>,
         C: VecZnxToRef,
@@ -115,7 +122,7 @@ pub trait NTT120SvpDefaults<BE: Backend>: Backend {
         b: &C,
         b_col: usize,
     ) where
-        Module<BE>: NttModuleHandle,
+        Module<BE>: NttModuleHandle<PrimeSet = BE::Primes>,
         BE: Backend<ScalarPrep = Q120bScalar> + NttMulBbc + NttZero,
         R: VecZnxDftToMut<BE>,
         A: SvpPPolToRef<BE>,
@@ -126,7 +133,7 @@ pub trait NTT120SvpDefaults<BE: Backend>: Backend {
 
     fn svp_apply_dft_to_dft_assign_default<R, A>(module: &Module<BE>, res: &mut R, res_col: usize, a: &A, a_col: usize)
     where
-        Module<BE>: NttModuleHandle,
+        Module<BE>: NttModuleHandle<PrimeSet = BE::Primes>,
         BE: Backend<ScalarPrep = Q120bScalar> + NttMulBbc,
         R: VecZnxDftToMut<BE>,
         A: SvpPPolToRef<BE>,
@@ -136,3 +143,105 @@ pub trait NTT120SvpDefaults<BE: Backend>: Backend {
 }
 
 impl<BE: Backend> NTT120SvpDefaults<BE> for BE {}
+
+#[doc(hidden)]
+pub trait NTT60SvpDefaults<BE: Backend>: Backend {
+    fn svp_prepare_default<R, A>(module: &Module<BE>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        Module<BE>: Ntt60ModuleHandle,
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: SvpPPolToMut<BE>,
+        A: ScalarZnxToRef,
+    {
+        ntt60_svp_prepare::<R, A, BE>(module.get_ntt60_table(), res, res_col, a, a_col);
+    }
+
+    fn svp_apply_dft_default<R, A, C>(module: &Module<BE>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        Module<BE>: Ntt60ModuleHandle,
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: VecZnxDftToMut<BE>,
+        A: SvpPPolToRef<BE>,
+        C: VecZnxToRef,
+    {
+        ntt60_svp_apply_dft::<R, A, C, BE>(module.get_ntt60_table(), res, res_col, a, a_col, b, b_col);
+    }
+
+    fn svp_apply_dft_to_dft_default<R, A, C>(
+        _module: &Module<BE>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &C,
+        b_col: usize,
+    ) where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: VecZnxDftToMut<BE>,
+        A: SvpPPolToRef<BE>,
+        C: VecZnxDftToRef<BE>,
+    {
+        ntt60_svp_apply_dft_to_dft::<R, A, C, BE>(res, res_col, a, a_col, b, b_col);
+    }
+
+    fn svp_apply_dft_to_dft_assign_default<R, A>(_module: &Module<BE>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: VecZnxDftToMut<BE>,
+        A: SvpPPolToRef<BE>,
+    {
+        ntt60_svp_apply_dft_to_dft_assign::<R, A, BE>(res, res_col, a, a_col);
+    }
+}
+
+impl<BE: Backend> NTT60SvpDefaults<BE> for BE {}
+
+#[doc(hidden)]
+pub trait SchoolbookSvpDefaults<BE: Backend>: Backend {
+    fn svp_prepare_default<R, A>(_module: &Module<BE>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        BE: Backend<ScalarPrep = i128>,
+        R: SvpPPolToMut<BE>,
+        A: ScalarZnxToRef,
+    {
+        schoolbook_svp_prepare::<R, A, BE>(res, res_col, a, a_col);
+    }
+
+    fn svp_apply_dft_default<R, A, C>(_module: &Module<BE>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        BE: Backend<ScalarPrep = i128>,
+        R: VecZnxDftToMut<BE>,
+        A: SvpPPolToRef<BE>,
+        C: VecZnxToRef,
+    {
+        schoolbook_svp_apply_dft::<R, A, C, BE>(res, res_col, a, a_col, b, b_col);
+    }
+
+    fn svp_apply_dft_to_dft_default<R, A, C>(
+        _module: &Module<BE>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &C,
+        b_col: usize,
+    ) where
+        BE: Backend<ScalarPrep = i128>,
+        R: VecZnxDftToMut<BE>,
+        A: SvpPPolToRef<BE>,
+        C: VecZnxDftToRef<BE>,
+    {
+        schoolbook_svp_apply_dft_to_dft::<R, A, C, BE>(res, res_col, a, a_col, b, b_col);
+    }
+
+    fn svp_apply_dft_to_dft_assign_default<R, A>(_module: &Module<BE>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        BE: Backend<ScalarPrep = i128>,
+        R: VecZnxDftToMut<BE>,
+        A: SvpPPolToRef<BE>,
+    {
+        schoolbook_svp_apply_dft_to_dft_assign::<R, A, BE>(res, res_col, a, a_col);
+    }
+}
+
+impl<BE: Backend> SchoolbookSvpDefaults<BE> for BE {}
@@ -16,11 +16,19 @@ use crate::reference::{
             vec_znx_idft_apply_tmpa as fft64_vec_znx_idft_apply_tmpa,
         },
     },
+    ntt60::{
+        types::Q60Scalar,
+        vec_znx_dft::{
+            Ntt60ModuleHandle, ntt60_vec_znx_dft_add_assign, ntt60_vec_znx_dft_add_into, ntt60_vec_znx_dft_add_scaled_assign,
+            ntt60_vec_znx_dft_apply, ntt60_vec_znx_dft_copy, ntt60_vec_znx_dft_sub, ntt60_vec_znx_dft_sub_assign,
+            ntt60_vec_znx_dft_sub_negate_assign, ntt60_vec_znx_dft_zero, ntt60_vec_znx_idft_apply,
+            ntt60_vec_znx_idft_apply_consume, ntt60_vec_znx_idft_apply_tmp_bytes, ntt60_vec_znx_idft_apply_tmpa,
+        },
+    },
     ntt120::{
-        NttAdd, NttAddAssign, NttCopy, NttDFTExecute, NttFromZnx64, NttNegate, NttNegateAssign, NttSub, NttSubAssign,
+        NttAdd, NttAddAssign, NttCopy, NttDFTExecute, NttFromZnx64, NttNegate, NttNegateAssign, NttPrimes, NttSub, NttSubAssign,
         NttSubNegateAssign, NttToZnx128, NttZero,
         ntt::{NttTable, NttTableInv},
-        primes::Primes30,
         types::Q120bScalar,
         vec_znx_dft::{
             NttModuleHandle, ntt120_vec_znx_dft_add_assign as ntt120_default_vec_znx_dft_add_assign,
@@ -37,6 +45,12 @@ use crate::reference::{
             ntt120_vec_znx_idft_apply_tmpa as ntt120_default_vec_znx_idft_apply_tmpa,
         },
     },
+    schoolbook::vec_znx_dft::{
+        schoolbook_vec_znx_dft_add_assign, schoolbook_vec_znx_dft_add_into, schoolbook_vec_znx_dft_add_scaled_assign,
+        schoolbook_vec_znx_dft_apply, schoolbook_vec_znx_dft_copy, schoolbook_vec_znx_dft_sub, schoolbook_vec_znx_dft_sub_assign,
+        schoolbook_vec_znx_dft_sub_negate_assign, schoolbook_vec_znx_dft_zero, schoolbook_vec_znx_idft_apply,
+        schoolbook_vec_znx_idft_apply_consume, schoolbook_vec_znx_idft_apply_tmp_bytes, schoolbook_vec_znx_idft_apply_tmpa,
+    },
     znx::ZnxZero,
 };
 use poulpy_hal::{
@@ -221,8 +235,8 @@ pub trait NTT120VecZnxDftDefaults<BE: Backend>: Backend {
         a: &A,
         a_col: usize,
     ) where
-        Module<BE>: NttModuleHandle,
-        BE: Backend<ScalarPrep = Q120bScalar> + NttDFTExecute<NttTable<Primes30>> + NttFromZnx64 + NttZero,
+        Module<BE>: NttModuleHandle<PrimeSet = BE::Primes>,
+        BE: Backend<ScalarPrep = Q120bScalar> + NttDFTExecute<NttTable<BE::Primes>> + NttFromZnx64 + NttZero,
         R: VecZnxDftToMut<BE>,
         A: VecZnxToRef,
     {
@@ -244,8 +258,8 @@ pub trait NTT120VecZnxDftDefaults<BE: Backend>: Backend {
         a_col: usize,
         scratch: &mut Scratch<BE>,
     ) where
-        Module<BE>: NttModuleHandle,
-        BE: Backend<ScalarPrep = Q120bScalar, ScalarBig = i128> + NttDFTExecute<NttTableInv<Primes30>> + NttToZnx128 + NttCopy,
+        Module<BE>: NttModuleHandle<PrimeSet = BE::Primes>,
+        BE: Backend<ScalarPrep = Q120bScalar, ScalarBig = i128> + NttDFTExecute<NttTableInv<BE::Primes>> + NttToZnx128 + NttCopy,
         Scratch<BE>: TakeSlice,
         R: VecZnxBigToMut<BE>,
         A: VecZnxDftToRef<BE>,
@@ -256,8 +270,8 @@ pub trait NTT120VecZnxDftDefaults<BE: Backend>: Backend {
 
     fn vec_znx_idft_apply_tmpa_default<R, A>(module: &Module<BE>, res: &mut R, res_col: usize, a: &mut A, a_col: usize)
     where
-        Module<BE>: NttModuleHandle,
-        BE: Backend<ScalarPrep = Q120bScalar, ScalarBig = i128> + NttDFTExecute<NttTableInv<Primes30>> + NttToZnx128,
+        Module<BE>: NttModuleHandle<PrimeSet = BE::Primes>,
+        BE: Backend<ScalarPrep = Q120bScalar, ScalarBig = i128> + NttDFTExecute<NttTableInv<BE::Primes>> + NttToZnx128,
         R: VecZnxBigToMut<BE>,
         A: VecZnxDftToMut<BE>,
     {
@@ -266,8 +280,8 @@ pub trait NTT120VecZnxDftDefaults<BE: Backend>: Backend {
 
     fn vec_znx_idft_apply_consume_default<D: Data>(module: &Module<BE>, a: VecZnxDft<D, BE>) -> VecZnxBig<D, BE>
     where
-        Module<BE>: NttModuleHandle,
-        BE: Backend<ScalarPrep = Q120bScalar, ScalarBig = i128>,
+        Module<BE>: NttModuleHandle<PrimeSet = BE::Primes>,
+        BE: Backend<ScalarPrep = Q120bScalar, ScalarBig = i128> + NttPrimes,
         VecZnxDft<D, BE>: VecZnxDftToMut<BE>,
     {
         ntt120_default_vec_znx_idft_apply_consume(module, a)
@@ -375,3 +389,327 @@ pub trait NTT120VecZnxDftDefaults<BE: Backend>: Backend {
 }
 
 impl<BE: Backend> NTT120VecZnxDftDefaults<BE> for BE {}
+
+#[doc(hidden)]
+pub trait NTT60VecZnxDftDefaults<BE: Backend>: Backend {
+    fn vec_znx_dft_apply_default<R, A>(
+        module: &Module<BE>,
+        step: usize,
+        offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+    ) where
+        Module<BE>: Ntt60ModuleHandle,
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: VecZnxDftToMut<BE>,
+        A: VecZnxToRef,
+    {
+        ntt60_vec_znx_dft_apply(module.get_ntt60_table(), step, offset, res, res_col, a, a_col);
+    }
+
+    fn vec_znx_idft_apply_tmp_bytes_default(module: &Module<BE>) -> usize
+    where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+    {
+        ntt60_vec_znx_idft_apply_tmp_bytes(module.n())
+    }
+
+    fn vec_znx_idft_apply_default<R, A>(
+        module: &Module<BE>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        _scratch: &mut Scratch<BE>,
+    ) where
+        Module<BE>: Ntt60ModuleHandle,
+        BE: Backend<ScalarPrep = Q60Scalar, ScalarBig = i64>,
+        R: VecZnxBigToMut<BE>,
+        A: VecZnxDftToRef<BE>,
+    {
+        ntt60_vec_znx_idft_apply(module.get_ntt60_table(), res, res_col, a, a_col);
+    }
+
+    fn vec_znx_idft_apply_tmpa_default<R, A>(module: &Module<BE>, res: &mut R, res_col: usize, a: &mut A, a_col: usize)
+    where
+        Module<BE>: Ntt60ModuleHandle,
+        BE: Backend<ScalarPrep = Q60Scalar, ScalarBig = i64>,
+        R: VecZnxBigToMut<BE>,
+        A: VecZnxDftToMut<BE>,
+    {
+        ntt60_vec_znx_idft_apply_tmpa(module.get_ntt60_table(), res, res_col, a, a_col);
+    }
+
+    fn vec_znx_idft_apply_consume_default<D: Data>(module: &Module<BE>, a: VecZnxDft<D, BE>) -> VecZnxBig<D, BE>
+    where
+        Module<BE>: Ntt60ModuleHandle,
+        BE: Backend<ScalarPrep = Q60Scalar, ScalarBig = i64>,
+        VecZnxDft<D, BE>: VecZnxDftToMut<BE>,
+    {
+        ntt60_vec_znx_idft_apply_consume(module.get_ntt60_table(), a)
+    }
+
+    fn vec_znx_dft_add_into_default<R, A, D>(
+        _module: &Module<BE>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &D,
+        b_col: usize,
+    ) where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: VecZnxDftToMut<BE>,
+        A: VecZnxDftToRef<BE>,
+        D: VecZnxDftToRef<BE>,
+    {
+        ntt60_vec_znx_dft_add_into(res, res_col, a, a_col, b, b_col);
+    }
+
+    fn vec_znx_dft_add_scaled_assign_default<R, A>(
+        _module: &Module<BE>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        a_scale: i64,
+    ) where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: VecZnxDftToMut<BE>,
+        A: VecZnxDftToRef<BE>,
+    {
+        ntt60_vec_znx_dft_add_scaled_assign(res, res_col, a, a_col, a_scale);
+    }
+
+    fn vec_znx_dft_add_assign_default<R, A>(_module: &Module<BE>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: VecZnxDftToMut<BE>,
+        A: VecZnxDftToRef<BE>,
+    {
+        ntt60_vec_znx_dft_add_assign(res, res_col, a, a_col);
+    }
+
+    fn vec_znx_dft_sub_default<R, A, D>(
+        _module: &Module<BE>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &D,
+        b_col: usize,
+    ) where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: VecZnxDftToMut<BE>,
+        A: VecZnxDftToRef<BE>,
+        D: VecZnxDftToRef<BE>,
+    {
+        ntt60_vec_znx_dft_sub(res, res_col, a, a_col, b, b_col);
+    }
+
+    fn vec_znx_dft_sub_assign_default<R, A>(_module: &Module<BE>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: VecZnxDftToMut<BE>,
+        A: VecZnxDftToRef<BE>,
+    {
+        ntt60_vec_znx_dft_sub_assign(res, res_col, a, a_col);
+    }
+
+    fn vec_znx_dft_sub_negate_assign_default<R, A>(_module: &Module<BE>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: VecZnxDftToMut<BE>,
+        A: VecZnxDftToRef<BE>,
+    {
+        ntt60_vec_znx_dft_sub_negate_assign(res, res_col, a, a_col);
+    }
+
+    fn vec_znx_dft_copy_default<R, A>(
+        _module: &Module<BE>,
+        step: usize,
+        offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+    ) where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: VecZnxDftToMut<BE>,
+        A: VecZnxDftToRef<BE>,
+    {
+        ntt60_vec_znx_dft_copy(step, offset, res, res_col, a, a_col);
+    }
+
+    fn vec_znx_dft_zero_default<R>(_module: &Module<BE>, res: &mut R, res_col: usize)
+    where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: VecZnxDftToMut<BE>,
+    {
+        ntt60_vec_znx_dft_zero(res, res_col);
+    }
+}
+
+impl<BE: Backend> NTT60VecZnxDftDefaults<BE> for BE {}
+
+#[doc(hidden)]
+pub trait SchoolbookVecZnxDftDefaults<BE: Backend>: Backend {
+    fn vec_znx_dft_apply_default<R, A>(
+        _module: &Module<BE>,
+        step: usize,
+        offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+    ) where
+        BE: Backend<ScalarPrep = i128>,
+        R: VecZnxDftToMut<BE>,
+        A: VecZnxToRef,
+    {
+        schoolbook_vec_znx_dft_apply(step, offset, res, res_col, a, a_col);
+    }
+
+    fn vec_znx_idft_apply_tmp_bytes_default(module: &Module<BE>) -> usize
+    where
+        BE: Backend<ScalarPrep = i128>,
+    {
+        schoolbook_vec_znx_idft_apply_tmp_bytes(module.n())
+    }
+
+    fn vec_znx_idft_apply_default<R, A>(
+        _module: &Module<BE>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        _scratch: &mut Scratch<BE>,
+    ) where
+        BE: Backend<ScalarPrep = i128, ScalarBig = i128>,
+        R: VecZnxBigToMut<BE>,
+        A: VecZnxDftToRef<BE>,
+    {
+        schoolbook_vec_znx_idft_apply(res, res_col, a, a_col);
+    }
+
+    fn vec_znx_idft_apply_tmpa_default<R, A>(_module: &Module<BE>, res: &mut R, res_col: usize, a: &mut A, a_col: usize)
+    where
+        BE: Backend<ScalarPrep = i128, ScalarBig = i128>,
+        R: VecZnxBigToMut<BE>,
+        A: VecZnxDftToMut<BE>,
+    {
+        schoolbook_vec_znx_idft_apply_tmpa(res, res_col, a, a_col);
+    }
+
+    fn vec_znx_idft_apply_consume_default<D: Data>(_module: &Module<BE>, a: VecZnxDft<D, BE>) -> VecZnxBig<D, BE>
+    where
+        BE: Backend<ScalarPrep = i128, ScalarBig = i128>,
+        VecZnxDft<D, BE>: VecZnxDftToMut<BE>,
+    {
+        schoolbook_vec_znx_idft_apply_consume(a)
+    }
+
+    fn vec_znx_dft_add_into_default<R, A, D>(
+        _module: &Module<BE>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &D,
+        b_col: usize,
+    ) where
+        BE: Backend<ScalarPrep = i128>,
+        R: VecZnxDftToMut<BE>,
+        A: VecZnxDftToRef<BE>,
+        D: VecZnxDftToRef<BE>,
+    {
+        schoolbook_vec_znx_dft_add_into(res, res_col, a, a_col, b, b_col);
+    }
+
+    fn vec_znx_dft_add_scaled_assign_default<R, A>(
+        _module: &Module<BE>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        a_scale: i64,
+    ) where
+        BE: Backend<ScalarPrep = i128>,
+        R: VecZnxDftToMut<BE>,
+        A: VecZnxDftToRef<BE>,
+    {
+        schoolbook_vec_znx_dft_add_scaled_assign(res, res_col, a, a_col, a_scale);
+    }
+
+    fn vec_znx_dft_add_assign_default<R, A>(_module: &Module<BE>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        BE: Backend<ScalarPrep = i128>,
+        R: VecZnxDftToMut<BE>,
+        A: VecZnxDftToRef<BE>,
+    {
+        schoolbook_vec_znx_dft_add_assign(res, res_col, a, a_col);
+    }
+
+    fn vec_znx_dft_sub_default<R, A, D>(
+        _module: &Module<BE>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &D,
+        b_col: usize,
+    ) where
+        BE: Backend<ScalarPrep = i128>,
+        R: VecZnxDftToMut<BE>,
+        A: VecZnxDftToRef<BE>,
+        D: VecZnxDftToRef<BE>,
+    {
+        schoolbook_vec_znx_dft_sub(res, res_col, a, a_col, b, b_col);
+    }
+
+    fn vec_znx_dft_sub_assign_default<R, A>(_module: &Module<BE>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        BE: Backend<ScalarPrep = i128>,
+        R: VecZnxDftToMut<BE>,
+        A: VecZnxDftToRef<BE>,
+    {
+        schoolbook_vec_znx_dft_sub_assign(res, res_col, a, a_col);
+    }
+
+    fn vec_znx_dft_sub_negate_assign_default<R, A>(_module: &Module<BE>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        BE: Backend<ScalarPrep = i128>,
+        R: VecZnxDftToMut<BE>,
+        A: VecZnxDftToRef<BE>,
+    {
+        schoolbook_vec_znx_dft_sub_negate_assign(res, res_col, a, a_col);
+    }
+
+    fn vec_znx_dft_copy_default<R, A>(
+        _module: &Module<BE>,
+        step: usize,
+        offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+    ) where
+        BE: Backend<ScalarPrep = i128>,
+        R: VecZnxDftToMut<BE>,
+        A: VecZnxDftToRef<BE>,
+    {
+        schoolbook_vec_znx_dft_copy(step, offset, res, res_col, a, a_col);
+    }
+
+    fn vec_znx_dft_zero_default<R>(_module: &Module<BE>, res: &mut R, res_col: usize)
+    where
+        BE: Backend<ScalarPrep = i128>,
+        R: VecZnxDftToMut<BE>,
+    {
+        schoolbook_vec_znx_dft_zero(res, res_col);
+    }
+}
+
+impl<BE: Backend> SchoolbookVecZnxDftDefaults<BE> for BE {}
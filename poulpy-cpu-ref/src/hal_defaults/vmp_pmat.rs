@@ -14,10 +14,17 @@ use crate::reference::{
             vmp_prepare_tmp_bytes as fft64_vmp_prepare_tmp_bytes, vmp_zero as fft64_vmp_zero,
         },
     },
+    ntt60::{
+        types::Q60Scalar,
+        vec_znx_dft::Ntt60ModuleHandle,
+        vmp::{
+            ntt60_vmp_apply_dft_to_dft, ntt60_vmp_apply_dft_to_dft_tmp_bytes, ntt60_vmp_prepare, ntt60_vmp_prepare_tmp_bytes,
+            ntt60_vmp_zero,
+        },
+    },
     ntt120::{
         NttCFromB, NttDFTExecute, NttExtract1BlkContiguous, NttFromZnx64, NttMulBbc1ColX2, NttMulBbc2ColsX2,
         ntt::NttTable,
-        primes::Primes30,
         types::Q120bScalar,
         vec_znx_dft::NttModuleHandle,
         vmp::{
@@ -25,6 +32,10 @@ use crate::reference::{
             ntt120_vmp_zero,
         },
     },
+    schoolbook::vmp::{
+        schoolbook_vmp_apply_dft_to_dft, schoolbook_vmp_apply_dft_to_dft_tmp_bytes, schoolbook_vmp_prepare,
+        schoolbook_vmp_prepare_tmp_bytes, schoolbook_vmp_zero,
+    },
 };
 use poulpy_hal::{
     api::TakeSlice,
@@ -115,8 +126,8 @@ pub trait NTT120VmpDefaults<BE: Backend>: Backend {
 
     fn vmp_prepare_default<R, A>(module: &Module<BE>, res: &mut R, a: &A, scratch: &mut Scratch<BE>)
     where
-        Module<BE>: NttModuleHandle,
-        BE: Backend<ScalarPrep = Q120bScalar> + NttDFTExecute<NttTable<Primes30>> + NttFromZnx64 + NttCFromB,
+        Module<BE>: NttModuleHandle<PrimeSet = BE::Primes>,
+        BE: Backend<ScalarPrep = Q120bScalar> + NttDFTExecute<NttTable<BE::Primes>> + NttFromZnx64 + NttCFromB,
         Scratch<BE>: TakeSlice,
         R: VmpPMatToMut<BE>,
         A: MatZnxToRef,
@@ -149,7 +160,7 @@ pub trait NTT120VmpDefaults<BE: Backend>: Backend {
         limb_offset: usize,
         scratch: &mut Scratch<BE>,
     ) where
-        Module<BE>: NttModuleHandle,
+        Module<BE>: NttModuleHandle<PrimeSet = BE::Primes>,
         BE: Backend<ScalarPrep = Q120bScalar> + NttExtract1BlkContiguous + NttMulBbc1ColX2 + NttMulBbc2ColsX2,
         Scratch<BE>: TakeSlice,
         R: VecZnxDftToMut<BE>,
@@ -174,3 +185,124 @@ pub trait NTT120VmpDefaults<BE: Backend>: Backend {
 }
 
 impl<BE: Backend> NTT120VmpDefaults<BE> for BE {}
+
+#[doc(hidden)]
+pub trait NTT60VmpDefaults<BE: Backend>: Backend {
+    fn vmp_prepare_tmp_bytes_default(module: &Module<BE>, _rows: usize, _cols_in: usize, _cols_out: usize, _size: usize) -> usize
+    where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+    {
+        ntt60_vmp_prepare_tmp_bytes(module.n())
+    }
+
+    fn vmp_prepare_default<R, A>(module: &Module<BE>, res: &mut R, a: &A, _scratch: &mut Scratch<BE>)
+    where
+        Module<BE>: Ntt60ModuleHandle,
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: VmpPMatToMut<BE>,
+        A: MatZnxToRef,
+    {
+        ntt60_vmp_prepare::<R, A, BE>(module.get_ntt60_table(), res, a);
+    }
+
+    fn vmp_apply_dft_to_dft_tmp_bytes_default(
+        _module: &Module<BE>,
+        _res_size: usize,
+        a_size: usize,
+        b_rows: usize,
+        b_cols_in: usize,
+        _b_cols_out: usize,
+        _b_size: usize,
+    ) -> usize
+    where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+    {
+        ntt60_vmp_apply_dft_to_dft_tmp_bytes(a_size, b_rows, b_cols_in)
+    }
+
+    fn vmp_apply_dft_to_dft_default<R, A, C>(
+        _module: &Module<BE>,
+        res: &mut R,
+        a: &A,
+        b: &C,
+        limb_offset: usize,
+        _scratch: &mut Scratch<BE>,
+    ) where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: VecZnxDftToMut<BE>,
+        A: VecZnxDftToRef<BE>,
+        C: VmpPMatToRef<BE>,
+    {
+        ntt60_vmp_apply_dft_to_dft::<R, A, C, BE>(res, a, b, limb_offset);
+    }
+
+    fn vmp_zero_default<R>(_module: &Module<BE>, res: &mut R)
+    where
+        BE: Backend<ScalarPrep = Q60Scalar>,
+        R: VmpPMatToMut<BE>,
+    {
+        ntt60_vmp_zero::<R, BE>(res);
+    }
+}
+
+impl<BE: Backend> NTT60VmpDefaults<BE> for BE {}
+
+#[doc(hidden)]
+pub trait SchoolbookVmpDefaults<BE: Backend>: Backend {
+    fn vmp_prepare_tmp_bytes_default(module: &Module<BE>, _rows: usize, _cols_in: usize, _cols_out: usize, _size: usize) -> usize
+    where
+        BE: Backend<ScalarPrep = i128>,
+    {
+        schoolbook_vmp_prepare_tmp_bytes(module.n())
+    }
+
+    fn vmp_prepare_default<R, A>(_module: &Module<BE>, res: &mut R, a: &A, _scratch: &mut Scratch<BE>)
+    where
+        BE: Backend<ScalarPrep = i128>,
+        R: VmpPMatToMut<BE>,
+        A: MatZnxToRef,
+    {
+        schoolbook_vmp_prepare::<R, A, BE>(res, a);
+    }
+
+    fn vmp_apply_dft_to_dft_tmp_bytes_default(
+        _module: &Module<BE>,
+        _res_size: usize,
+        a_size: usize,
+        b_rows: usize,
+        b_cols_in: usize,
+        _b_cols_out: usize,
+        _b_size: usize,
+    ) -> usize
+    where
+        BE: Backend<ScalarPrep = i128>,
+    {
+        schoolbook_vmp_apply_dft_to_dft_tmp_bytes(a_size, b_rows, b_cols_in)
+    }
+
+    fn vmp_apply_dft_to_dft_default<R, A, C>(
+        _module: &Module<BE>,
+        res: &mut R,
+        a: &A,
+        b: &C,
+        limb_offset: usize,
+        _scratch: &mut Scratch<BE>,
+    ) where
+        BE: Backend<ScalarPrep = i128>,
+        R: VecZnxDftToMut<BE>,
+        A: VecZnxDftToRef<BE>,
+        C: VmpPMatToRef<BE>,
+    {
+        schoolbook_vmp_apply_dft_to_dft::<R, A, C, BE>(res, a, b, limb_offset);
+    }
+
+    fn vmp_zero_default<R>(_module: &Module<BE>, res: &mut R)
+    where
+        BE: Backend<ScalarPrep = i128>,
+        R: VmpPMatToMut<BE>,
+    {
+        schoolbook_vmp_zero::<R, BE>(res);
+    }
+}
+
+impl<BE: Backend> SchoolbookVmpDefaults<BE> for BE {}
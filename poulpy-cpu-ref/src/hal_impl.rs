@@ -1,10 +1,13 @@
 use crate::{
-    FFT64Ref, NTT120Ref,
+    FFT64Ref, NTT60Ref, NTT120Ref, SchoolbookRef,
     hal_defaults::{
         FFT64ConvolutionDefaults, FFT64ModuleDefaults, FFT64SvpDefaults, FFT64VecZnxBigDefaults, FFT64VecZnxDftDefaults,
-        FFT64VmpDefaults, HalScratchDefaults, HalVecZnxDefaults, NTT120ConvolutionDefaults, NTT120ModuleDefaults,
-        NTT120SvpDefaults, NTT120VecZnxBigDefaults, NTT120VecZnxDftDefaults, NTT120VmpDefaults,
+        FFT64VmpDefaults, HalScratchDefaults, HalVecZnxDefaults, NTT60ConvolutionDefaults, NTT60ModuleDefaults, NTT60SvpDefaults,
+        NTT60VecZnxDftDefaults, NTT60VmpDefaults, NTT120ConvolutionDefaults, NTT120ModuleDefaults, NTT120SvpDefaults,
+        NTT120VecZnxBigDefaults, NTT120VecZnxDftDefaults, NTT120VmpDefaults, SchoolbookConvolutionDefaults,
+        SchoolbookModuleDefaults, SchoolbookSvpDefaults, SchoolbookVecZnxDftDefaults, SchoolbookVmpDefaults,
     },
+    reference::ntt120::primes::PrimeSet,
 };
 use poulpy_hal::{
     api::{ScratchTakeBasic, VecZnxDftApply, VecZnxDftZero, VmpApplyDftToDft},
@@ -28,14 +31,26 @@ mod module_fft64;
 #[macro_use]
 mod module_ntt120;
 #[macro_use]
+mod module_ntt60;
+#[macro_use]
+mod module_schoolbook;
+#[macro_use]
 mod vmp_fft64;
 #[macro_use]
 mod vmp_ntt120;
 #[macro_use]
+mod vmp_ntt60;
+#[macro_use]
+mod vmp_schoolbook;
+#[macro_use]
 mod convolution_fft64;
 #[macro_use]
 mod convolution_ntt120;
 #[macro_use]
+mod convolution_ntt60;
+#[macro_use]
+mod convolution_schoolbook;
+#[macro_use]
 mod vec_znx_big_fft64;
 #[macro_use]
 mod vec_znx_big_ntt120;
@@ -44,9 +59,17 @@ mod svp_fft64;
 #[macro_use]
 mod svp_ntt120;
 #[macro_use]
+mod svp_ntt60;
+#[macro_use]
+mod svp_schoolbook;
+#[macro_use]
 mod vec_znx_dft_fft64;
 #[macro_use]
 mod vec_znx_dft_ntt120;
+#[macro_use]
+mod vec_znx_dft_ntt60;
+#[macro_use]
+mod vec_znx_dft_schoolbook;
 
 unsafe impl HalImpl<FFT64Ref> for FFT64Ref {
     hal_impl_scratch!();
@@ -60,7 +83,7 @@ unsafe impl HalImpl<FFT64Ref> for FFT64Ref {
     hal_impl_vec_znx_dft_fft64!();
 }
 
-unsafe impl HalImpl<NTT120Ref> for NTT120Ref {
+unsafe impl<P: PrimeSet> HalImpl<NTT120Ref<P>> for NTT120Ref<P> {
     hal_impl_scratch!();
     hal_impl_vec_znx!();
     hal_impl_family_common!();
@@ -71,3 +94,27 @@ unsafe impl HalImpl<NTT120Ref> for NTT120Ref {
     hal_impl_svp_ntt120!();
     hal_impl_vec_znx_dft_ntt120!();
 }
+
+unsafe impl HalImpl<NTT60Ref> for NTT60Ref {
+    hal_impl_scratch!();
+    hal_impl_vec_znx!();
+    hal_impl_family_common!();
+    hal_impl_module_ntt60!();
+    hal_impl_vmp_ntt60!();
+    hal_impl_convolution_ntt60!();
+    hal_impl_vec_znx_big_fft64!();
+    hal_impl_svp_ntt60!();
+    hal_impl_vec_znx_dft_ntt60!();
+}
+
+unsafe impl HalImpl<SchoolbookRef> for SchoolbookRef {
+    hal_impl_scratch!();
+    hal_impl_vec_znx!();
+    hal_impl_family_common!();
+    hal_impl_module_schoolbook!();
+    hal_impl_vmp_schoolbook!();
+    hal_impl_convolution_schoolbook!();
+    hal_impl_vec_znx_big_ntt120!();
+    hal_impl_svp_schoolbook!();
+    hal_impl_vec_znx_dft_schoolbook!();
+}
@@ -0,0 +1,7 @@
+macro_rules! hal_impl_module_ntt60 {
+    () => {
+        fn new(n: u64) -> Module<Self> {
+            <Self as NTT60ModuleDefaults<Self>>::module_new_default(n)
+        }
+    };
+}
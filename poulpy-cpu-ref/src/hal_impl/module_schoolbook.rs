@@ -0,0 +1,7 @@
+macro_rules! hal_impl_module_schoolbook {
+    () => {
+        fn new(n: u64) -> Module<Self> {
+            <Self as SchoolbookModuleDefaults<Self>>::module_new_default(n)
+        }
+    };
+}
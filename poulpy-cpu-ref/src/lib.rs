@@ -1,11 +1,14 @@
 //! Reference (portable) CPU backend for the Poulpy lattice cryptography library.
 //!
-//! This crate provides two backend implementations for [`poulpy_hal`]:
+//! This crate provides four backend implementations for [`poulpy_hal`]:
 //!
 //! - [`FFT64Ref`]: scalar `f64` FFT arithmetic — see the [`fft64`] module.
 //! - [`NTT120Ref`]: scalar Q120 NTT arithmetic (CRT over four ~30-bit primes) — see the [`ntt120`] module.
+//! - [`NTT60Ref`]: scalar Q60 NTT arithmetic (CRT over two ~30-bit primes) — see the [`ntt60`] module.
+//! - [`SchoolbookRef`]: exact `i128` schoolbook products without any transform, a slow
+//!   oracle for differential testing — see the [`schoolbook`] module.
 //!
-//! Both are canonical reference implementations: portable across all CPU architectures,
+//! All are canonical reference implementations: portable across all CPU architectures,
 //! prioritising correctness and debuggability over throughput.
 //!
 //! # Platform support
@@ -17,7 +20,9 @@ pub mod fft64;
 pub mod hal_defaults;
 mod hal_impl;
 pub mod ntt120;
+pub mod ntt60;
 pub mod reference;
+pub mod schoolbook;
 
 #[cfg(test)]
 mod tests;
@@ -37,13 +42,23 @@ pub mod source {
 }
 
 pub use fft64::FFT64Ref;
+pub use ntt60::{NTT60Ref, NTT60RefHandle};
 pub use ntt120::{NTT120Ref, NTT120RefHandle};
+pub use schoolbook::{SchoolbookRef, SchoolbookRefHandle};
 
 use poulpy_core::oep::CoreImpl;
 unsafe impl CoreImpl<FFT64Ref> for FFT64Ref {
     poulpy_core::impl_core_default_methods!(FFT64Ref);
 }
 
-unsafe impl CoreImpl<NTT120Ref> for NTT120Ref {
-    poulpy_core::impl_core_default_methods!(NTT120Ref);
+unsafe impl CoreImpl<NTT60Ref> for NTT60Ref {
+    poulpy_core::impl_core_default_methods!(NTT60Ref);
+}
+
+unsafe impl CoreImpl<SchoolbookRef> for SchoolbookRef {
+    poulpy_core::impl_core_default_methods!(SchoolbookRef);
+}
+
+unsafe impl<Q: reference::ntt120::PrimeSet> CoreImpl<NTT120Ref<Q>> for NTT120Ref<Q> {
+    poulpy_core::impl_core_default_methods!(NTT120Ref<Q>);
 }
@@ -42,6 +42,10 @@
 //! Compiles and runs on any target supported by the Rust standard library.
 //! No platform-specific intrinsics or assembly are used.
 
+use std::marker::PhantomData;
+
+use crate::reference::ntt120::primes::{PrimeSet, Primes30};
+
 mod module;
 mod prim;
 mod vec_znx_big;
@@ -61,7 +65,11 @@ pub use module::NTT120RefHandle;
 ///
 /// - **ScalarPrep**: `Q120bScalar` — NTT-domain coefficients stored as 4 × u64 CRT residues.
 /// - **ScalarBig**: `i128` — large-coefficient ring elements use 128-bit signed integers.
-/// - **Prime set**: `Primes30` (four ~30-bit primes, Q ≈ 2^120).
+/// - **Prime set**: the type parameter `P`, [`Primes30`] by default (four ~30-bit
+///   primes, Q ≈ 2^120). [`Primes29`](crate::reference::ntt120::primes::Primes29)
+///   and [`Primes31`](crate::reference::ntt120::primes::Primes31) trade precision
+///   of the prepared products for headroom, and each prime set has its own
+///   [`Backend::PREPARED_LAYOUT`](poulpy_hal::layouts::Backend::PREPARED_LAYOUT).
 /// - **NTT tables**: precomputed twiddle factors stored in the module handle
 ///   (`NTT120RefHandle`), shared across all operations on the same module.
 ///
@@ -70,5 +78,5 @@ pub use module::NTT120RefHandle;
 /// `NTT120Ref` is `Send + Sync` (derived from being a zero-sized, field-less struct).
 /// The `Module<NTT120Ref>` that holds the NTT tables is also `Send + Sync`, so modules can
 /// be shared across threads.
-#[derive(Debug, Clone, Copy)]
-pub struct NTT120Ref;
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NTT120Ref<P: PrimeSet = Primes30>(PhantomData<P>);
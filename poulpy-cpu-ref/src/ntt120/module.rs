@@ -13,12 +13,15 @@
 
 use std::ptr::NonNull;
 
-use poulpy_hal::{alloc_aligned, assert_alignment, layouts::Backend};
+use poulpy_hal::{
+    alloc_aligned,
+    layouts::{AlignedBuf, Backend},
+};
 
 use crate::reference::ntt120::{
     mat_vec::{BbbMeta, BbcMeta},
     ntt::{NttTable, NttTableInv},
-    primes::Primes30,
+    primes::{PrimeSet, Primes30},
     types::Q120bScalar,
     vec_znx_dft::{NttHandleFactory, NttHandleProvider},
 };
@@ -31,27 +34,29 @@ use crate::NTT120Ref;
 /// of size `n`, and the lazy-accumulation metadata for `q120b × q120c` and
 /// `q120b × q120b` products.
 ///
+/// The tables are computed for the prime set `P` of the backend.
+///
 /// This struct is heap-allocated during module creation and freed when the
-/// `Module<NTT120Ref>` is dropped (via [`Backend::destroy`]).
+/// `Module<NTT120Ref<P>>` is dropped (via [`Backend::destroy`]).
 #[repr(C)]
-pub struct NTT120RefHandle {
-    table_ntt: NttTable<Primes30>,
-    table_intt: NttTableInv<Primes30>,
-    meta_bbc: BbcMeta<Primes30>,
-    meta_bbb: BbbMeta<Primes30>,
+pub struct NTT120RefHandle<P: PrimeSet = Primes30> {
+    table_ntt: NttTable<P>,
+    table_intt: NttTableInv<P>,
+    meta_bbc: BbcMeta<P>,
+    meta_bbb: BbbMeta<P>,
 }
 
-impl Backend for NTT120Ref {
+impl<P: PrimeSet> Backend for NTT120Ref<P> {
     type ScalarPrep = Q120bScalar;
     type ScalarBig = i128;
-    type OwnedBuf = Vec<u8>;
-    type Handle = NTT120RefHandle;
+    type OwnedBuf = AlignedBuf;
+    type Handle = NTT120RefHandle<P>;
+    const PREPARED_LAYOUT: &'static str = P::PREPARED_LAYOUT;
     fn alloc_bytes(len: usize) -> Self::OwnedBuf {
-        alloc_aligned::<u8>(len)
+        alloc_aligned(len)
     }
     fn from_bytes(bytes: Vec<u8>) -> Self::OwnedBuf {
-        assert_alignment(bytes.as_ptr());
-        bytes
+        AlignedBuf::from(bytes)
     }
 
     unsafe fn destroy(handle: NonNull<Self::Handle>) {
@@ -64,7 +69,7 @@ impl Backend for NTT120Ref {
 /// # Safety
 ///
 /// The returned handle must be fully initialized for `n`.
-unsafe impl NttHandleFactory for NTT120RefHandle {
+unsafe impl<P: PrimeSet> NttHandleFactory for NTT120RefHandle<P> {
     fn create_ntt_handle(n: usize) -> Self {
         NTT120RefHandle {
             table_ntt: NttTable::new(n),
@@ -79,20 +84,22 @@ unsafe impl NttHandleFactory for NTT120RefHandle {
 ///
 /// The returned references are valid for the lifetime of `&self`.
 /// All fields are fully initialised in [`NTT120Ref::new_impl`].
-unsafe impl NttHandleProvider for NTT120RefHandle {
-    fn get_ntt_table(&self) -> &NttTable<Primes30> {
+unsafe impl<P: PrimeSet> NttHandleProvider for NTT120RefHandle<P> {
+    type PrimeSet = P;
+
+    fn get_ntt_table(&self) -> &NttTable<P> {
         &self.table_ntt
     }
 
-    fn get_intt_table(&self) -> &NttTableInv<Primes30> {
+    fn get_intt_table(&self) -> &NttTableInv<P> {
         &self.table_intt
     }
 
-    fn get_bbc_meta(&self) -> &BbcMeta<Primes30> {
+    fn get_bbc_meta(&self) -> &BbcMeta<P> {
         &self.meta_bbc
     }
 
-    fn get_bbb_meta(&self) -> &BbbMeta<Primes30> {
+    fn get_bbb_meta(&self) -> &BbbMeta<P> {
         &self.meta_bbb
     }
 }
@@ -8,34 +8,38 @@
 use crate::reference::ntt120::{
     NttAdd, NttAddAssign, NttCFromB, NttCopy, NttDFTExecute, NttExtract1BlkContiguous, NttFromZnx64, NttMulBbb, NttMulBbc,
     NttMulBbc1ColX2, NttMulBbc2ColsX2, NttNegate, NttNegateAssign, NttPackLeft1BlkX2, NttPackRight1BlkX2,
-    NttPairwisePackLeft1BlkX2, NttPairwisePackRight1BlkX2, NttSub, NttSubAssign, NttSubNegateAssign, NttToZnx128, NttZero,
+    NttPairwisePackLeft1BlkX2, NttPairwisePackRight1BlkX2, NttPrimes, NttSub, NttSubAssign, NttSubNegateAssign, NttToZnx128,
+    NttZero,
     arithmetic::{add_bbb_ref, b_from_znx64_ref, b_to_znx128_ref, c_from_b_ref},
     mat_vec::{
         BbbMeta, BbcMeta, extract_1blk_from_contiguous_q120b_ref, vec_mat1col_product_bbb_ref, vec_mat1col_product_bbc_ref,
         vec_mat1col_product_x2_bbc_ref, vec_mat2cols_product_x2_bbc_ref,
     },
     ntt::{NttTable, NttTableInv, intt_ref, ntt_ref},
-    primes::{PrimeSet, Primes30},
-    types::Q_SHIFTED,
+    primes::PrimeSet,
 };
 
 use crate::NTT120Ref;
 
+impl<P: PrimeSet> NttPrimes for NTT120Ref<P> {
+    type Primes = P;
+}
+
 // ──────────────────────────────────────────────────────────────────────────────
 // NTT execution
 // ──────────────────────────────────────────────────────────────────────────────
 
-impl NttDFTExecute<NttTable<Primes30>> for NTT120Ref {
+impl<P: PrimeSet> NttDFTExecute<NttTable<P>> for NTT120Ref<P> {
     #[inline(always)]
-    fn ntt_dft_execute(table: &NttTable<Primes30>, data: &mut [u64]) {
-        ntt_ref::<Primes30>(table, data);
+    fn ntt_dft_execute(table: &NttTable<P>, data: &mut [u64]) {
+        ntt_ref::<P>(table, data);
     }
 }
 
-impl NttDFTExecute<NttTableInv<Primes30>> for NTT120Ref {
+impl<P: PrimeSet> NttDFTExecute<NttTableInv<P>> for NTT120Ref<P> {
     #[inline(always)]
-    fn ntt_dft_execute(table: &NttTableInv<Primes30>, data: &mut [u64]) {
-        intt_ref::<Primes30>(table, data);
+    fn ntt_dft_execute(table: &NttTableInv<P>, data: &mut [u64]) {
+        intt_ref::<P>(table, data);
     }
 }
 
@@ -43,17 +47,17 @@ impl NttDFTExecute<NttTableInv<Primes30>> for NTT120Ref {
 // Domain conversion
 // ──────────────────────────────────────────────────────────────────────────────
 
-impl NttFromZnx64 for NTT120Ref {
+impl<P: PrimeSet> NttFromZnx64 for NTT120Ref<P> {
     #[inline(always)]
     fn ntt_from_znx64(res: &mut [u64], a: &[i64]) {
-        b_from_znx64_ref::<Primes30>(a.len(), res, a);
+        b_from_znx64_ref::<P>(a.len(), res, a);
     }
 }
 
-impl NttToZnx128 for NTT120Ref {
+impl<P: PrimeSet> NttToZnx128 for NTT120Ref<P> {
     #[inline(always)]
     fn ntt_to_znx128(res: &mut [i128], divisor_is_n: usize, a: &[u64]) {
-        b_to_znx128_ref::<Primes30>(divisor_is_n, res, a);
+        b_to_znx128_ref::<P>(divisor_is_n, res, a);
     }
 }
 
@@ -61,19 +65,19 @@ impl NttToZnx128 for NTT120Ref {
 // Addition / subtraction / negation / copy / zero
 // ──────────────────────────────────────────────────────────────────────────────
 
-impl NttAdd for NTT120Ref {
+impl<P: PrimeSet> NttAdd for NTT120Ref<P> {
     #[inline(always)]
     fn ntt_add(res: &mut [u64], a: &[u64], b: &[u64]) {
-        add_bbb_ref::<Primes30>(res.len() / 4, res, a, b);
+        add_bbb_ref::<P>(res.len() / 4, res, a, b);
     }
 }
 
-impl NttAddAssign for NTT120Ref {
+impl<P: PrimeSet> NttAddAssign for NTT120Ref<P> {
     #[inline(always)]
     fn ntt_add_assign(res: &mut [u64], a: &[u64]) {
         let n = res.len() / 4;
         for j in 0..n {
-            for (k, &q_s) in Q_SHIFTED.iter().enumerate() {
+            for (k, &q_s) in P::Q_SHIFTED.iter().enumerate() {
                 let idx = 4 * j + k;
                 res[idx] = res[idx] % q_s + a[idx] % q_s;
             }
@@ -81,12 +85,12 @@ impl NttAddAssign for NTT120Ref {
     }
 }
 
-impl NttSub for NTT120Ref {
+impl<P: PrimeSet> NttSub for NTT120Ref<P> {
     #[inline(always)]
     fn ntt_sub(res: &mut [u64], a: &[u64], b: &[u64]) {
         let n = res.len() / 4;
         for j in 0..n {
-            for (k, &q_s) in Q_SHIFTED.iter().enumerate() {
+            for (k, &q_s) in P::Q_SHIFTED.iter().enumerate() {
                 let idx = 4 * j + k;
                 res[idx] = a[idx] % q_s + (q_s - b[idx] % q_s);
             }
@@ -94,12 +98,12 @@ impl NttSub for NTT120Ref {
     }
 }
 
-impl NttSubAssign for NTT120Ref {
+impl<P: PrimeSet> NttSubAssign for NTT120Ref<P> {
     #[inline(always)]
     fn ntt_sub_assign(res: &mut [u64], a: &[u64]) {
         let n = res.len() / 4;
         for j in 0..n {
-            for (k, &q_s) in Q_SHIFTED.iter().enumerate() {
+            for (k, &q_s) in P::Q_SHIFTED.iter().enumerate() {
                 let idx = 4 * j + k;
                 res[idx] = res[idx] % q_s + (q_s - a[idx] % q_s);
             }
@@ -107,12 +111,12 @@ impl NttSubAssign for NTT120Ref {
     }
 }
 
-impl NttSubNegateAssign for NTT120Ref {
+impl<P: PrimeSet> NttSubNegateAssign for NTT120Ref<P> {
     #[inline(always)]
     fn ntt_sub_negate_assign(res: &mut [u64], a: &[u64]) {
         let n = res.len() / 4;
         for j in 0..n {
-            for (k, &q_s) in Q_SHIFTED.iter().enumerate() {
+            for (k, &q_s) in P::Q_SHIFTED.iter().enumerate() {
                 let idx = 4 * j + k;
                 res[idx] = a[idx] % q_s + (q_s - res[idx] % q_s);
             }
@@ -122,12 +126,12 @@ impl NttSubNegateAssign for NTT120Ref {
 
 /// **Output range:** For a zero input the result is `Q_SHIFTED[k]` (≡ 0 mod Q[k]), not `0`.
 /// Output range is `(0, Q_SHIFTED[k]]`. Use `val % Q[k] == 0`, not `val == 0`, to test for zero.
-impl NttNegate for NTT120Ref {
+impl<P: PrimeSet> NttNegate for NTT120Ref<P> {
     #[inline(always)]
     fn ntt_negate(res: &mut [u64], a: &[u64]) {
         let n = res.len() / 4;
         for j in 0..n {
-            for (k, &q_s) in Q_SHIFTED.iter().enumerate() {
+            for (k, &q_s) in P::Q_SHIFTED.iter().enumerate() {
                 let idx = 4 * j + k;
                 res[idx] = q_s - a[idx] % q_s;
             }
@@ -137,12 +141,12 @@ impl NttNegate for NTT120Ref {
 
 /// **Output range:** For a zero input the result is `Q_SHIFTED[k]` (≡ 0 mod Q[k]), not `0`.
 /// Output range is `(0, Q_SHIFTED[k]]`. Use `val % Q[k] == 0`, not `val == 0`, to test for zero.
-impl NttNegateAssign for NTT120Ref {
+impl<P: PrimeSet> NttNegateAssign for NTT120Ref<P> {
     #[inline(always)]
     fn ntt_negate_assign(res: &mut [u64]) {
         let n = res.len() / 4;
         for j in 0..n {
-            for (k, &q_s) in Q_SHIFTED.iter().enumerate() {
+            for (k, &q_s) in P::Q_SHIFTED.iter().enumerate() {
                 let idx = 4 * j + k;
                 res[idx] = q_s - res[idx] % q_s;
             }
@@ -150,14 +154,14 @@ impl NttNegateAssign for NTT120Ref {
     }
 }
 
-impl NttZero for NTT120Ref {
+impl<P: PrimeSet> NttZero for NTT120Ref<P> {
     #[inline(always)]
     fn ntt_zero(res: &mut [u64]) {
         res.fill(0);
     }
 }
 
-impl NttCopy for NTT120Ref {
+impl<P: PrimeSet> NttCopy for NTT120Ref<P> {
     #[inline(always)]
     fn ntt_copy(res: &mut [u64], a: &[u64]) {
         res.copy_from_slice(a);
@@ -168,17 +172,17 @@ impl NttCopy for NTT120Ref {
 // Multiply-accumulate
 // ──────────────────────────────────────────────────────────────────────────────
 
-impl NttMulBbb for NTT120Ref {
+impl<P: PrimeSet> NttMulBbb for NTT120Ref<P> {
     #[inline(always)]
-    fn ntt_mul_bbb(meta: &BbbMeta<Primes30>, ell: usize, res: &mut [u64], a: &[u64], b: &[u64]) {
-        vec_mat1col_product_bbb_ref::<Primes30>(meta, ell, res, a, b);
+    fn ntt_mul_bbb(meta: &BbbMeta<P>, ell: usize, res: &mut [u64], a: &[u64], b: &[u64]) {
+        vec_mat1col_product_bbb_ref::<P>(meta, ell, res, a, b);
     }
 }
 
-impl NttMulBbc for NTT120Ref {
+impl<P: PrimeSet> NttMulBbc for NTT120Ref<P> {
     #[inline(always)]
-    fn ntt_mul_bbc(meta: &BbcMeta<Primes30>, ell: usize, res: &mut [u64], ntt_coeff: &[u32], prepared: &[u32]) {
-        vec_mat1col_product_bbc_ref::<Primes30>(meta, ell, res, ntt_coeff, prepared);
+    fn ntt_mul_bbc(meta: &BbcMeta<P>, ell: usize, res: &mut [u64], ntt_coeff: &[u32], prepared: &[u32]) {
+        vec_mat1col_product_bbc_ref::<P>(meta, ell, res, ntt_coeff, prepared);
     }
 }
 
@@ -186,10 +190,10 @@ impl NttMulBbc for NTT120Ref {
 // q120b → q120c conversion
 // ──────────────────────────────────────────────────────────────────────────────
 
-impl NttCFromB for NTT120Ref {
+impl<P: PrimeSet> NttCFromB for NTT120Ref<P> {
     #[inline(always)]
     fn ntt_c_from_b(n: usize, res: &mut [u32], a: &[u64]) {
-        c_from_b_ref::<Primes30>(n, res, a);
+        c_from_b_ref::<P>(n, res, a);
     }
 }
 
@@ -197,28 +201,28 @@ impl NttCFromB for NTT120Ref {
 // VMP x2-block kernels
 // ──────────────────────────────────────────────────────────────────────────────
 
-impl NttMulBbc1ColX2 for NTT120Ref {
+impl<P: PrimeSet> NttMulBbc1ColX2 for NTT120Ref<P> {
     #[inline(always)]
-    fn ntt_mul_bbc_1col_x2(meta: &BbcMeta<Primes30>, ell: usize, res: &mut [u64], a: &[u32], b: &[u32]) {
-        vec_mat1col_product_x2_bbc_ref::<Primes30>(meta, ell, res, a, b);
+    fn ntt_mul_bbc_1col_x2(meta: &BbcMeta<P>, ell: usize, res: &mut [u64], a: &[u32], b: &[u32]) {
+        vec_mat1col_product_x2_bbc_ref::<P>(meta, ell, res, a, b);
     }
 }
 
-impl NttMulBbc2ColsX2 for NTT120Ref {
+impl<P: PrimeSet> NttMulBbc2ColsX2 for NTT120Ref<P> {
     #[inline(always)]
-    fn ntt_mul_bbc_2cols_x2(meta: &BbcMeta<Primes30>, ell: usize, res: &mut [u64], a: &[u32], b: &[u32]) {
-        vec_mat2cols_product_x2_bbc_ref::<Primes30>(meta, ell, res, a, b);
+    fn ntt_mul_bbc_2cols_x2(meta: &BbcMeta<P>, ell: usize, res: &mut [u64], a: &[u32], b: &[u32]) {
+        vec_mat2cols_product_x2_bbc_ref::<P>(meta, ell, res, a, b);
     }
 }
 
-impl NttExtract1BlkContiguous for NTT120Ref {
+impl<P: PrimeSet> NttExtract1BlkContiguous for NTT120Ref<P> {
     #[inline(always)]
     fn ntt_extract_1blk_contiguous(n: usize, row_max: usize, blk: usize, dst: &mut [u64], src: &[u64]) {
         extract_1blk_from_contiguous_q120b_ref(n, row_max, blk, dst, src);
     }
 }
 
-impl NttPackLeft1BlkX2 for NTT120Ref {
+impl<P: PrimeSet> NttPackLeft1BlkX2 for NTT120Ref<P> {
     #[inline(always)]
     fn ntt_pack_left_1blk_x2(dst: &mut [u32], a: &[u64], row_count: usize, row_stride: usize, blk: usize) {
         debug_assert!(dst.len() >= 16 * row_count);
@@ -230,7 +234,7 @@ impl NttPackLeft1BlkX2 for NTT120Ref {
             for coeff in 0..2 {
                 for prime in 0..4 {
                     let idx = row_base + 4 * coeff + prime;
-                    let q = Primes30::Q[prime] as u64;
+                    let q = P::Q[prime] as u64;
                     let a_red = a[idx] % q;
                     dst[out_base + 8 * coeff + 2 * prime] = a_red as u32;
                     dst[out_base + 8 * coeff + 2 * prime + 1] = 0;
@@ -240,7 +244,7 @@ impl NttPackLeft1BlkX2 for NTT120Ref {
     }
 }
 
-impl NttPackRight1BlkX2 for NTT120Ref {
+impl<P: PrimeSet> NttPackRight1BlkX2 for NTT120Ref<P> {
     #[inline(always)]
     fn ntt_pack_right_1blk_x2(dst: &mut [u32], a: &[u32], row_count: usize, row_stride: usize, blk: usize) {
         debug_assert!(dst.len() >= 16 * row_count);
@@ -254,7 +258,7 @@ impl NttPackRight1BlkX2 for NTT120Ref {
     }
 }
 
-impl NttPairwisePackLeft1BlkX2 for NTT120Ref {
+impl<P: PrimeSet> NttPairwisePackLeft1BlkX2 for NTT120Ref<P> {
     #[inline(always)]
     fn ntt_pairwise_pack_left_1blk_x2(dst: &mut [u32], a: &[u64], b: &[u64], row_count: usize, row_stride: usize, blk: usize) {
         debug_assert!(dst.len() >= 16 * row_count);
@@ -267,7 +271,7 @@ impl NttPairwisePackLeft1BlkX2 for NTT120Ref {
             for coeff in 0..2 {
                 for prime in 0..4 {
                     let idx = row_base + 4 * coeff + prime;
-                    let q = Primes30::Q[prime] as u64;
+                    let q = P::Q[prime] as u64;
                     let mut sum = (a[idx] % q) + (b[idx] % q);
                     if sum >= q {
                         sum -= q;
@@ -280,7 +284,7 @@ impl NttPairwisePackLeft1BlkX2 for NTT120Ref {
     }
 }
 
-impl NttPairwisePackRight1BlkX2 for NTT120Ref {
+impl<P: PrimeSet> NttPairwisePackRight1BlkX2 for NTT120Ref<P> {
     #[inline(always)]
     fn ntt_pairwise_pack_right_1blk_x2(dst: &mut [u32], a: &[u32], b: &[u32], row_count: usize, row_stride: usize, blk: usize) {
         debug_assert!(dst.len() >= 16 * row_count);
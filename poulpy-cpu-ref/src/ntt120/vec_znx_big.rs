@@ -3,8 +3,8 @@
 //! The shared `poulpy-hal` NTT120 defaults rely on backend-provided `I128BigOps`
 //! and `I128NormalizeOps` hooks for vectorized i128 operations.
 
-use crate::NTT120Ref;
 use crate::reference::ntt120::{I128BigOps, I128NormalizeOps};
+use crate::{NTT120Ref, reference::ntt120::primes::PrimeSet};
 
-impl I128BigOps for NTT120Ref {}
-impl I128NormalizeOps for NTT120Ref {}
+impl<P: PrimeSet> I128BigOps for NTT120Ref<P> {}
+impl<P: PrimeSet> I128NormalizeOps for NTT120Ref<P> {}
@@ -0,0 +1,51 @@
+//! Reference NTT60 CPU backend for the Poulpy lattice cryptography library.
+//!
+//! This module provides [`NTT60Ref`], a backend implementation for [`poulpy_hal`] that uses
+//! scalar NTT arithmetic modulo two ~30-bit primes (`Q ≈ 2^60`, see
+//! `crate::reference::ntt60`). Prepared products are exact as long as the coefficients of
+//! the result stay below `2^59` in absolute value, which makes it a lighter alternative to
+//! [`NTT120Ref`](crate::NTT120Ref) for small `base2k` and few limbs (e.g. bin-fhe-style
+//! blind rotations), and an exact alternative to [`FFT64Ref`](crate::FFT64Ref).
+//!
+//! # Architecture
+//!
+//! The DFT-domain operations (`vec_znx_dft`, `svp`, `vmp`, convolutions) delegate to the
+//! reference functions of `crate::reference::ntt60`. Big-coefficient vectors use
+//! `ScalarBig = i64`, so `vec_znx_big` operations and the convolution by a constant share
+//! the FFT64 defaults.
+//!
+//! | Module          | Domain                                                         |
+//! |-----------------|----------------------------------------------------------------|
+//! | `module`        | Backend handle lifecycle, NTT table management                 |
+//! | `znx`           | Single ring element (`Z[X]/(X^n+1)`) arithmetic               |
+//!
+//! # Scalar types
+//!
+//! - `ScalarPrep = Q60Scalar`: NTT-domain coefficients (8 bytes = 2 × u32 residues).
+//! - `ScalarBig  = i64`: coefficients in the large-integer (CRT-reconstructed) domain.
+
+mod module;
+mod znx;
+
+pub use module::NTT60RefHandle;
+
+/// Reference (portable) CPU backend using two-prime (Q60) NTT arithmetic.
+///
+/// `NTT60Ref` is a zero-sized marker type that selects the reference NTT60 CPU backend
+/// when used as the type parameter `B` in [`poulpy_hal::layouts::Module<B>`](poulpy_hal::layouts::Module)
+/// and related HAL types.
+///
+/// # Backend characteristics
+///
+/// - **ScalarPrep**: `Q60Scalar` — NTT-domain coefficients stored as 2 × u32 CRT residues.
+/// - **ScalarBig**: `i64` — CRT reconstruction is centered in `(-Q/2, Q/2]`.
+/// - **Ring degree**: `n ≤ 2^16`.
+/// - **NTT tables**: precomputed twiddle factors stored in the module handle
+///   (`NTT60RefHandle`), shared across all operations on the same module.
+///
+/// # Thread safety
+///
+/// `NTT60Ref` is `Send + Sync` (derived from being a zero-sized, field-less struct).
+/// The `Module<NTT60Ref>` that holds the NTT tables is also `Send + Sync`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NTT60Ref;
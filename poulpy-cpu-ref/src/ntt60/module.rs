@@ -0,0 +1,75 @@
+//! Backend handle and module initialisation for [`NTT60Ref`](crate::NTT60Ref).
+//!
+//! This module defines:
+//!
+//! - [`NTT60RefHandle`]: the opaque handle stored inside a `Module<NTT60Ref>`,
+//!   holding the precomputed NTT twiddle-factor tables.
+//! - The [`Backend`] trait implementation, which defines scalar types and the
+//!   handle destruction path.
+//! - The [`Ntt60HandleFactory`] and [`Ntt60HandleProvider`] implementations,
+//!   which build the handle and wire it into the blanket `Ntt60ModuleHandle`
+//!   impl.
+
+use std::ptr::NonNull;
+
+use poulpy_hal::{
+    alloc_aligned,
+    layouts::{AlignedBuf, Backend},
+};
+
+use crate::reference::ntt60::{
+    ntt::Ntt60Table,
+    types::Q60Scalar,
+    vec_znx_dft::{Ntt60HandleFactory, Ntt60HandleProvider},
+};
+
+use super::NTT60Ref;
+
+/// Opaque handle for the [`NTT60Ref`](crate::NTT60Ref) backend.
+///
+/// Holds the precomputed twiddle-factor tables of the forward and inverse NTT
+/// of size `n`. Heap-allocated during module creation and freed when the
+/// `Module<NTT60Ref>` is dropped (via [`Backend::destroy`]).
+#[repr(C)]
+pub struct NTT60RefHandle {
+    table: Ntt60Table,
+}
+
+impl Backend for NTT60Ref {
+    type ScalarPrep = Q60Scalar;
+    type ScalarBig = i64;
+    type OwnedBuf = AlignedBuf;
+    type Handle = NTT60RefHandle;
+    const PREPARED_LAYOUT: &'static str = "ntt60";
+    fn alloc_bytes(len: usize) -> Self::OwnedBuf {
+        alloc_aligned(len)
+    }
+    fn from_bytes(bytes: Vec<u8>) -> Self::OwnedBuf {
+        AlignedBuf::from(bytes)
+    }
+    unsafe fn destroy(handle: NonNull<Self::Handle>) {
+        unsafe {
+            drop(Box::from_raw(handle.as_ptr()));
+        }
+    }
+}
+
+/// # Safety
+///
+/// The returned handle must be fully initialized for `n`.
+unsafe impl Ntt60HandleFactory for NTT60RefHandle {
+    fn create_ntt60_handle(n: usize) -> Self {
+        NTT60RefHandle {
+            table: Ntt60Table::new(n),
+        }
+    }
+}
+
+/// # Safety
+///
+/// The returned reference is valid for the lifetime of `&self`.
+unsafe impl Ntt60HandleProvider for NTT60RefHandle {
+    fn get_ntt60_table(&self) -> &Ntt60Table {
+        &self.table
+    }
+}
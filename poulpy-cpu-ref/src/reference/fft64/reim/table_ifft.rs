@@ -15,30 +15,32 @@
 //
 // ----------------------------------------------------------------------
 
-use std::fmt::Debug;
+use std::{fmt::Debug, marker::PhantomData};
 
 use rand_distr::num_traits::{Float, FloatConst};
 
 use crate::reference::fft64::reim::{ReimFFTExecute, frac_rev_bits, ifft_ref::ifft_ref};
-use poulpy_hal::alloc_aligned;
+use poulpy_hal::{alloc_aligned, cast, cast_mut, layouts::AlignedBuf};
 
 pub struct ReimIFFTRef;
 
 impl ReimFFTExecute<ReimIFFTTable<f64>, f64> for ReimIFFTRef {
     fn reim_dft_execute(table: &ReimIFFTTable<f64>, data: &mut [f64]) {
-        ifft_ref(table.m, &table.omg, data);
+        ifft_ref(table.m, table.omg(), data);
     }
 }
 
 pub struct ReimIFFTTable<R: Float + FloatConst + Debug> {
     m: usize,
-    omg: Vec<R>,
+    omg: AlignedBuf,
+    _phantom: PhantomData<R>,
 }
 
 impl<R: Float + FloatConst + Debug> ReimIFFTTable<R> {
     pub fn new(m: usize) -> Self {
         assert!(m & (m - 1) == 0, "m must be a power of two but is {m}");
-        let mut omg: Vec<R> = alloc_aligned::<R>(2 * m);
+        let mut omg_buf: AlignedBuf = alloc_aligned(2 * m * size_of::<R>());
+        let omg: &mut [R] = cast_mut(&mut omg_buf);
 
         let quarter: R = R::exp2(R::from(-2).unwrap());
 
@@ -46,30 +48,34 @@ impl<R: Float + FloatConst + Debug> ReimIFFTTable<R> {
             match m {
                 1 => {}
                 2 => {
-                    fill_ifft2_omegas::<R>(quarter, &mut omg, 0);
+                    fill_ifft2_omegas::<R>(quarter, omg, 0);
                 }
                 4 => {
-                    fill_ifft4_omegas(quarter, &mut omg, 0);
+                    fill_ifft4_omegas(quarter, omg, 0);
                 }
                 8 => {
-                    fill_ifft8_omegas(quarter, &mut omg, 0);
+                    fill_ifft8_omegas(quarter, omg, 0);
                 }
                 16 => {
-                    fill_ifft16_omegas(quarter, &mut omg, 0);
+                    fill_ifft16_omegas(quarter, omg, 0);
                 }
                 _ => {}
             }
         } else if m <= 2048 {
-            fill_ifft_bfs_16_omegas(m, quarter, &mut omg, 0);
+            fill_ifft_bfs_16_omegas(m, quarter, omg, 0);
         } else {
-            fill_ifft_rec_16_omegas(m, quarter, &mut omg, 0);
+            fill_ifft_rec_16_omegas(m, quarter, omg, 0);
         }
 
-        Self { m, omg }
+        Self {
+            m,
+            omg: omg_buf,
+            _phantom: PhantomData,
+        }
     }
 
     pub fn execute(&self, data: &mut [R]) {
-        ifft_ref(self.m, &self.omg, data);
+        ifft_ref(self.m, self.omg(), data);
     }
 
     pub fn m(&self) -> usize {
@@ -77,7 +83,7 @@ impl<R: Float + FloatConst + Debug> ReimIFFTTable<R> {
     }
 
     pub fn omg(&self) -> &[R] {
-        &self.omg
+        cast(&self.omg)
     }
 }
 
@@ -1,10 +1,14 @@
 //! Pure-Rust reference implementations of all polynomial operations.
 //!
 //! Contains scalar polynomial arithmetic (`znx`), vector-level operations
-//! (`vec_znx`), and an FFT64 implementation (`fft64`). Used as a
+//! (`vec_znx`), an FFT64 implementation (`fft64`) and the NTT implementations
+//! over four (`ntt120`) and two (`ntt60`) CRT primes, and transform-free
+//! schoolbook products (`schoolbook`). Used as a
 //! correctness oracle for backend testing via the [`crate::test_suite`] module.
 
 pub mod fft64;
 pub mod ntt120;
+pub mod ntt60;
+pub mod schoolbook;
 pub mod vec_znx;
 pub mod znx;
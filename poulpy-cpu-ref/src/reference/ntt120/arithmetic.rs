@@ -143,10 +143,10 @@ pub fn b_to_znx128_ref<P: PrimeSet>(nn: usize, res: &mut [i128], x: &[u64]) {
 ///
 /// For each limb `i` and prime `k`:
 /// ```text
-/// res[i] = (x[i] % (Q[k] << 33)) + (y[i] % (Q[k] << 33))
+/// res[i] = (x[i] % Q_SHIFTED[k]) + (y[i] % Q_SHIFTED[k])
 /// ```
-/// The result is congruent to `x[i] + y[i]` modulo `Q[k]` and fits
-/// in 64 bits provided the inputs satisfy `x[i], y[i] < Q[k] << 33`.
+/// with `Q_SHIFTED` = [`PrimeSet::Q_SHIFTED`]. The result is congruent to
+/// `x[i] + y[i]` modulo `Q[k]` and fits in 64 bits.
 ///
 /// # Panics
 /// Panics in debug mode if slices are shorter than `4 * nn`.
@@ -155,10 +155,8 @@ pub fn add_bbb_ref<P: PrimeSet>(nn: usize, res: &mut [u64], x: &[u64], y: &[u64]
     debug_assert!(x.len() >= 4 * nn);
     debug_assert!(y.len() >= 4 * nn);
 
-    let q_shifted: [u64; 4] = P::Q.map(|qi| (qi as u64) << 33);
-
     for j in 0..nn {
-        for (k, &q_s) in q_shifted.iter().enumerate() {
+        for (k, &q_s) in P::Q_SHIFTED.iter().enumerate() {
             let idx = 4 * j + k;
             res[idx] = x[idx] % q_s + y[idx] % q_s;
         }
@@ -37,8 +37,8 @@ use crate::{
     },
     reference::ntt120::{
         NttAddAssign, NttCFromB, NttDFTExecute, NttFromZnx64, NttMulBbc1ColX2, NttMulBbc2ColsX2, NttPackLeft1BlkX2,
-        NttPackRight1BlkX2, NttPairwisePackLeft1BlkX2, NttPairwisePackRight1BlkX2, ntt::NttTable, primes::Primes30,
-        types::Q120bScalar, vec_znx_dft::NttModuleHandle,
+        NttPackRight1BlkX2, NttPairwisePackLeft1BlkX2, NttPairwisePackRight1BlkX2, ntt::NttTable, types::Q120bScalar,
+        vec_znx_dft::NttModuleHandle,
     },
 };
 
@@ -62,9 +62,14 @@ pub fn ntt120_cnv_prepare_left_tmp_bytes(_n: usize) -> usize {
 ///
 /// Limbs of `res` beyond `a.size()` are zeroed.
 /// No scratch buffer is needed; `_tmp` is unused.
-pub fn ntt120_cnv_prepare_left<R, A, BE>(module: &impl NttModuleHandle, res: &mut R, a: &A, mask: i64, _tmp: &mut [u8])
-where
-    BE: Backend<ScalarPrep = Q120bScalar> + NttFromZnx64 + NttDFTExecute<NttTable<Primes30>>,
+pub fn ntt120_cnv_prepare_left<R, A, BE>(
+    module: &impl NttModuleHandle<PrimeSet = BE::Primes>,
+    res: &mut R,
+    a: &A,
+    mask: i64,
+    _tmp: &mut [u8],
+) where
+    BE: Backend<ScalarPrep = Q120bScalar> + NttFromZnx64 + NttDFTExecute<NttTable<BE::Primes>>,
     R: CnvPVecLToMut<BE>,
     A: VecZnxToRef,
 {
@@ -116,9 +121,14 @@ pub fn ntt120_cnv_prepare_right_tmp_bytes(n: usize) -> usize {
 ///
 /// `tmp` must hold at least `ntt120_cnv_prepare_right_tmp_bytes(n) / size_of::<u64>()` elements.
 /// Limbs of `res` beyond `a.size()` are zeroed.
-pub fn ntt120_cnv_prepare_right<R, A, BE>(module: &impl NttModuleHandle, res: &mut R, a: &A, mask: i64, tmp: &mut [u64])
-where
-    BE: Backend<ScalarPrep = Q120bScalar> + NttFromZnx64 + NttDFTExecute<NttTable<Primes30>> + NttCFromB,
+pub fn ntt120_cnv_prepare_right<R, A, BE>(
+    module: &impl NttModuleHandle<PrimeSet = BE::Primes>,
+    res: &mut R,
+    a: &A,
+    mask: i64,
+    tmp: &mut [u64],
+) where
+    BE: Backend<ScalarPrep = Q120bScalar> + NttFromZnx64 + NttDFTExecute<NttTable<BE::Primes>> + NttCFromB,
     R: CnvPVecRToMut<BE>,
     A: VecZnxToRef,
 {
@@ -175,14 +185,14 @@ pub fn ntt120_cnv_prepare_self_tmp_bytes(_n: usize) -> usize {
 /// This saves one full `b_from_znx64 + NTT` per (col, limb) compared to
 /// calling `prepare_left` + `prepare_right` separately.
 pub fn ntt120_cnv_prepare_self<L, R, A, BE>(
-    module: &impl NttModuleHandle,
+    module: &impl NttModuleHandle<PrimeSet = BE::Primes>,
     left: &mut L,
     right: &mut R,
     a: &A,
     mask: i64,
     _tmp: &mut [u8],
 ) where
-    BE: Backend<ScalarPrep = Q120bScalar> + NttFromZnx64 + NttDFTExecute<NttTable<Primes30>> + NttCFromB,
+    BE: Backend<ScalarPrep = Q120bScalar> + NttFromZnx64 + NttDFTExecute<NttTable<BE::Primes>> + NttCFromB,
     L: CnvPVecLToMut<BE>,
     R: CnvPVecRToMut<BE>,
     A: VecZnxToRef,
@@ -254,7 +264,7 @@ pub fn ntt120_cnv_apply_dft_tmp_bytes(_res_size: usize, a_size: usize, b_size: u
 /// Output limbs `min_size..res.size()` are zeroed.
 #[allow(clippy::too_many_arguments)]
 pub fn ntt120_cnv_apply_dft<R, A, B, BE>(
-    module: &impl NttModuleHandle,
+    module: &impl NttModuleHandle<PrimeSet = BE::Primes>,
     cnv_offset: usize,
     res: &mut R,
     res_col: usize,
@@ -450,7 +460,7 @@ pub fn ntt120_cnv_pairwise_apply_dft_tmp_bytes(res_size: usize, a_size: usize, b
 /// Output limbs `min_size..res.size()` are zeroed.
 #[allow(clippy::too_many_arguments)]
 pub fn ntt120_cnv_pairwise_apply_dft<R, A, B, BE>(
-    module: &impl NttModuleHandle,
+    module: &impl NttModuleHandle<PrimeSet = BE::Primes>,
     cnv_offset: usize,
     res: &mut R,
     res_col: usize,
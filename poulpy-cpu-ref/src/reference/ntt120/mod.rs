@@ -130,6 +130,17 @@ pub(super) fn pow2_mod(exp: u64, q: u64) -> u64 {
 // NTT-domain operation traits
 // ──────────────────────────────────────────────────────────────────────────────
 
+/// Selects the [`PrimeSet`] of the CRT representation used by an NTT backend.
+///
+/// Every operation trait below whose result depends on the primes has
+/// `NttPrimes` as a supertrait and works modulo `Self::Primes`; the
+/// precomputed tables of the module handle must use the same set (see
+/// [`vec_znx_dft::NttModuleHandle::PrimeSet`]).
+pub trait NttPrimes {
+    /// Prime set of the backend.
+    type Primes: PrimeSet;
+}
+
 /// Execute a forward or inverse NTT using a precomputed table.
 ///
 /// `Table` is either [`NttTable`] or [`NttTableInv`] (both generic over a
@@ -142,7 +153,7 @@ pub trait NttDFTExecute<Table> {
 
 /// Load a polynomial from the standard `i64` coefficient representation
 /// into the q120b NTT-domain format.
-pub trait NttFromZnx64 {
+pub trait NttFromZnx64: NttPrimes {
     /// Encode the `a.len()` coefficients of `a` into `res` (q120b layout).
     ///
     /// `res` must have length `4 * a.len()`.
@@ -151,7 +162,7 @@ pub trait NttFromZnx64 {
     /// Encode `a` into `res` (q120b layout), applying `mask` to each coefficient
     /// before conversion. Equivalent to `ntt_from_znx64` on `a[j] & mask`.
     fn ntt_from_znx64_masked(res: &mut [u64], a: &[i64], mask: i64) {
-        arithmetic::b_from_znx64_masked_ref::<primes::Primes30>(a.len(), res, a, mask)
+        arithmetic::b_from_znx64_masked_ref::<Self::Primes>(a.len(), res, a, mask)
     }
 }
 
@@ -160,13 +171,13 @@ pub trait NttFromZnx64 {
 /// The `divisor_is_n` parameter specifies the polynomial degree `n`; it
 /// is used to apply the `1/n` scaling that the inverse NTT does not
 /// include automatically (see [`NttTableInv`]).
-pub trait NttToZnx128 {
+pub trait NttToZnx128: NttPrimes {
     /// Decode `a` (q120b layout, length `4 * n`) into `res` (`n` × `i128`).
     fn ntt_to_znx128(res: &mut [i128], divisor_is_n: usize, a: &[u64]);
 }
 
 /// Component-wise addition of two q120b vectors.
-pub trait NttAdd {
+pub trait NttAdd: NttPrimes {
     /// `res[i] = a[i] + b[i]` for each CRT component.
     ///
     /// All three slices must have the same length (a multiple of 4).
@@ -174,7 +185,7 @@ pub trait NttAdd {
 }
 
 /// In-place component-wise addition of a q120b vector.
-pub trait NttAddAssign {
+pub trait NttAddAssign: NttPrimes {
     /// `res[i] += a[i]` for each CRT component.
     fn ntt_add_assign(res: &mut [u64], a: &[u64]);
 }
@@ -197,9 +208,9 @@ pub trait NttCopy {
 /// format) by the corresponding entry in `a` (also q120b), accumulating
 /// the results into `res`. `meta` carries the precomputed lazy-reduction
 /// constants and should be obtained via [`vec_znx_dft::NttModuleHandle::get_bbb_meta`].
-pub trait NttMulBbb {
+pub trait NttMulBbb: NttPrimes {
     /// `res += a[0..ell] ⊙ b[0..ell]` using lazy modular arithmetic.
-    fn ntt_mul_bbb(meta: &BbbMeta<Primes30>, ell: usize, res: &mut [u64], a: &[u64], b: &[u64]);
+    fn ntt_mul_bbb(meta: &BbbMeta<Self::Primes>, ell: usize, res: &mut [u64], a: &[u64], b: &[u64]);
 }
 
 /// Pointwise product: q120b × q120c → q120b (overwrite).
@@ -210,14 +221,9 @@ pub trait NttMulBbb {
 /// `meta` carries the precomputed lazy-reduction parameters for the prime set.
 ///
 /// **Overwrites** `res` with the result (does not accumulate into `res`).
-///
-/// <!-- DOCUMENTED EXCEPTION: Primes30 hardcoded for spqlios compatibility.
-///   The generalisation path is to add an associated type PrimeSet to
-///   NttModuleHandle; until then every bbc/VMP/SVP/convolution trait method
-///   is intentionally fixed to Primes30. -->
-pub trait NttMulBbc {
+pub trait NttMulBbc: NttPrimes {
     /// `res = sum_{i<ell} ntt_coeff[i] ⊙ prepared[i]` with `prepared` in q120c layout, using `meta`.
-    fn ntt_mul_bbc(meta: &BbcMeta<Primes30>, ell: usize, res: &mut [u64], ntt_coeff: &[u32], prepared: &[u32]);
+    fn ntt_mul_bbc(meta: &BbcMeta<Self::Primes>, ell: usize, res: &mut [u64], ntt_coeff: &[u32], prepared: &[u32]);
 }
 
 // ──────────────────────────────────────────────────────────────────────────────
@@ -225,13 +231,13 @@ pub trait NttMulBbc {
 // ──────────────────────────────────────────────────────────────────────────────
 
 /// Component-wise subtraction of two q120b vectors.
-pub trait NttSub {
+pub trait NttSub: NttPrimes {
     /// `res[i] = a[i] - b[i]` (lazy q120b arithmetic) for each CRT component.
     fn ntt_sub(res: &mut [u64], a: &[u64], b: &[u64]);
 }
 
 /// In-place component-wise subtraction of a q120b vector.
-pub trait NttSubAssign {
+pub trait NttSubAssign: NttPrimes {
     /// `res[i] -= a[i]` (lazy q120b arithmetic) for each CRT component.
     fn ntt_sub_assign(res: &mut [u64], a: &[u64]);
 }
@@ -239,19 +245,19 @@ pub trait NttSubAssign {
 /// In-place swap-subtract: `res = a - res`.
 ///
 /// Equivalent to negating `res` then adding `a`, in lazy q120b arithmetic.
-pub trait NttSubNegateAssign {
+pub trait NttSubNegateAssign: NttPrimes {
     /// `res[i] = a[i] - res[i]` (lazy q120b arithmetic).
     fn ntt_sub_negate_assign(res: &mut [u64], a: &[u64]);
 }
 
 /// Component-wise negation of a q120b vector.
-pub trait NttNegate {
+pub trait NttNegate: NttPrimes {
     /// `res[i] = -a[i]` (lazy q120b arithmetic).
     fn ntt_negate(res: &mut [u64], a: &[u64]);
 }
 
 /// In-place component-wise negation of a q120b vector.
-pub trait NttNegateAssign {
+pub trait NttNegateAssign: NttPrimes {
     /// `res[i] = -res[i]` (lazy q120b arithmetic).
     fn ntt_negate_assign(res: &mut [u64]);
 }
@@ -266,7 +272,7 @@ pub trait NttNegateAssign {
 /// - `r = a[4*j+k] mod Q[k]`
 /// - `res[8*j + 2*k]     = r`
 /// - `res[8*j + 2*k + 1] = (r * 2^32) mod Q[k]`
-pub trait NttCFromB {
+pub trait NttCFromB: NttPrimes {
     /// Encode `a` (q120b, length `4*n`) into `res` (q120c, length `8*n`).
     fn ntt_c_from_b(n: usize, res: &mut [u32], a: &[u64]);
 }
@@ -280,18 +286,18 @@ pub trait NttCFromB {
 /// Computes the inner product of one x2-block from `a` (q120b, as u32)
 /// against one column of the prepared matrix `b` (q120c), producing 8 u64
 /// output values (two q120b coefficients).
-pub trait NttMulBbc1ColX2 {
+pub trait NttMulBbc1ColX2: NttPrimes {
     /// `res[0..8] = sum_{i<ell} a_x2[i] ⊙ b_x2[i]`.
-    fn ntt_mul_bbc_1col_x2(meta: &BbcMeta<Primes30>, ell: usize, res: &mut [u64], a: &[u32], b: &[u32]);
+    fn ntt_mul_bbc_1col_x2(meta: &BbcMeta<Self::Primes>, ell: usize, res: &mut [u64], a: &[u32], b: &[u32]);
 }
 
 /// VMP inner loop: x2-block 2-column bbc product.
 ///
 /// Like [`NttMulBbc1ColX2`] but computes two output columns simultaneously,
 /// writing 16 u64 values: `res[0..8]` for col 0, `res[8..16]` for col 1.
-pub trait NttMulBbc2ColsX2 {
+pub trait NttMulBbc2ColsX2: NttPrimes {
     /// `res[0..16] = [sum_i a_x2[i] ⊙ b_col0_x2[i], sum_i a_x2[i] ⊙ b_col1_x2[i]]`.
-    fn ntt_mul_bbc_2cols_x2(meta: &BbcMeta<Primes30>, ell: usize, res: &mut [u64], a: &[u32], b: &[u32]);
+    fn ntt_mul_bbc_2cols_x2(meta: &BbcMeta<Self::Primes>, ell: usize, res: &mut [u64], a: &[u32], b: &[u32]);
 }
 
 /// Extract one x2-block from a contiguous q120b array.
@@ -309,7 +315,7 @@ pub trait NttExtract1BlkContiguous {
 /// `a` is a column-start q120b slice with row stride `row_stride` (in `u64` units).
 /// For each row, block `blk` is reduced to canonical residues and written to `dst`
 /// as 16 u32 values in x2 q120b/u32 layout.
-pub trait NttPackLeft1BlkX2 {
+pub trait NttPackLeft1BlkX2: NttPrimes {
     /// Pack `row_count` q120b x2-blocks for block `blk`.
     fn ntt_pack_left_1blk_x2(dst: &mut [u32], a: &[u64], row_count: usize, row_stride: usize, blk: usize);
 }
@@ -330,7 +336,7 @@ pub trait NttPackRight1BlkX2 {
 /// For each row, block `blk` (two consecutive coefficients) is reduced to canonical residues,
 /// summed mod `Q`, and written to `dst` as 16 u32 values:
 /// `[r0, 0, r1, 0, r2, 0, r3, 0, r0', 0, ..., r3', 0]`.
-pub trait NttPairwisePackLeft1BlkX2 {
+pub trait NttPairwisePackLeft1BlkX2: NttPrimes {
     /// Pack `row_count` pairwise-summed q120b x2-blocks for block `blk`.
     fn ntt_pairwise_pack_left_1blk_x2(dst: &mut [u32], a: &[u64], b: &[u64], row_count: usize, row_stride: usize, blk: usize);
 }
@@ -50,7 +50,7 @@
 use std::marker::PhantomData;
 
 use crate::reference::ntt120::primes::PrimeSet;
-use poulpy_hal::alloc_aligned;
+use poulpy_hal::{alloc_aligned, cast, cast_mut, layouts::AlignedBuf};
 
 // ──────────────────────────────────────────────────────────────────────────────
 // Precomputation data structures
@@ -105,8 +105,9 @@ pub struct NttTable<P: PrimeSet> {
     ///
     /// Packing: each u64 stores `(t1 << 32) | t` where `t = ω^i mod Q[k]`
     /// and `t1 = (t << half_bs) mod Q[k]`.  The four consecutive u64
-    /// values (one per prime) form one logical entry.
-    pub powomega: Vec<u64>,
+    /// values (one per prime) form one logical entry. Read as `u64` with
+    /// [`NttTable::powomega`].
+    pub powomega: AlignedBuf,
     /// Reduction metadata (shared across all levels).
     pub reduc_metadata: NttReducMeta,
     /// Input bit-size (64 for q120b inputs).
@@ -125,7 +126,8 @@ pub struct NttTableInv<P: PrimeSet> {
     /// Per-level metadata (length = log2(n) + 1).
     pub level_metadata: Vec<NttStepMeta>,
     /// Packed inverse twiddle factors (same packing as `NttTable::powomega`).
-    pub powomega: Vec<u64>,
+    /// Read as `u64` with [`NttTableInv::powomega`].
+    pub powomega: AlignedBuf,
     /// Reduction metadata.
     pub reduc_metadata: NttReducMeta,
     /// Input bit-size.
@@ -216,6 +218,11 @@ fn pack_omega(t: u64, half_bs: u64, q: u64) -> u64 {
 }
 
 impl<P: PrimeSet> NttTable<P> {
+    /// Packed twiddle factors, as `u64`.
+    pub fn powomega(&self) -> &[u64] {
+        cast(&self.powomega)
+    }
+
     /// Builds the forward NTT precomputation table for size `n`.
     ///
     /// `n` must be a power of two with `1 ≤ n ≤ 2^16`.
@@ -233,8 +240,8 @@ impl<P: PrimeSet> NttTable<P> {
 
         let input_bit_size = bs;
 
-        let powomega_capacity = alloc_aligned::<u64>(4 * 2 * n.max(1));
-        let mut powomega: Vec<u64> = powomega_capacity;
+        let mut powomega_buf: AlignedBuf = alloc_aligned(4 * 2 * n.max(1) * size_of::<u64>());
+        let powomega: &mut [u64] = cast_mut(&mut powomega_buf);
         let mut po_ptr = 0usize; // index into powomega
 
         let mut level_metadata: Vec<NttStepMeta> = Vec::new();
@@ -243,7 +250,7 @@ impl<P: PrimeSet> NttTable<P> {
             return Self {
                 n,
                 level_metadata,
-                powomega,
+                powomega: powomega_buf,
                 reduc_metadata,
                 input_bit_size,
                 output_bit_size: bs,
@@ -344,12 +351,12 @@ impl<P: PrimeSet> NttTable<P> {
         }
 
         let output_bit_size = bs;
-        powomega.truncate(po_ptr); // trim to used portion
+        powomega_buf.truncate(po_ptr * size_of::<u64>()); // trim to used portion
 
         Self {
             n,
             level_metadata,
-            powomega,
+            powomega: powomega_buf,
             reduc_metadata,
             input_bit_size,
             output_bit_size,
@@ -359,6 +366,11 @@ impl<P: PrimeSet> NttTable<P> {
 }
 
 impl<P: PrimeSet> NttTableInv<P> {
+    /// Packed twiddle factors, as `u64`.
+    pub fn powomega(&self) -> &[u64] {
+        cast(&self.powomega)
+    }
+
     /// Builds the inverse NTT precomputation table for size `n`.
     ///
     /// `n` must be a power of two with `1 ≤ n ≤ 2^16`.
@@ -375,8 +387,8 @@ impl<P: PrimeSet> NttTableInv<P> {
         let (reduc_metadata, bs_after_reduc) = fill_reduction_meta::<P>(bs);
 
         let input_bit_size = bs;
-        let powomega_capacity = alloc_aligned::<u64>(4 * 2 * n.max(1));
-        let mut powomega: Vec<u64> = powomega_capacity;
+        let mut powomega_buf: AlignedBuf = alloc_aligned(4 * 2 * n.max(1) * size_of::<u64>());
+        let powomega: &mut [u64] = cast_mut(&mut powomega_buf);
         let mut po_ptr = 0usize;
 
         let mut level_metadata: Vec<NttStepMeta> = Vec::new();
@@ -385,7 +397,7 @@ impl<P: PrimeSet> NttTableInv<P> {
             return Self {
                 n,
                 level_metadata,
-                powomega,
+                powomega: powomega_buf,
                 reduc_metadata,
                 input_bit_size,
                 output_bit_size: bs,
@@ -496,12 +508,12 @@ impl<P: PrimeSet> NttTableInv<P> {
         }
 
         let output_bit_size = bs;
-        powomega.truncate(po_ptr);
+        powomega_buf.truncate(po_ptr * size_of::<u64>());
 
         Self {
             n,
             level_metadata,
-            powomega,
+            powomega: powomega_buf,
             reduc_metadata,
             input_bit_size,
             output_bit_size,
@@ -574,7 +586,7 @@ pub fn ntt_ref<P: PrimeSet>(table: &NttTable<P>, data: &mut [u64]) {
         for i in 0..n {
             for k in 0..4 {
                 let x = data[4 * i + k];
-                let po = table.powomega[po_off + 4 * i + k];
+                let po = table.powomega()[po_off + 4 * i + k];
                 data[4 * i + k] = split_precompmul(x, po, h, mask);
             }
         }
@@ -591,7 +603,7 @@ pub fn ntt_ref<P: PrimeSet>(table: &NttTable<P>, data: &mut [u64]) {
         // Process all blocks of size nn.
         let mut blk = 0;
         while blk < n {
-            ntt_butterfly_block(data, blk, halfnn, meta, &table.reduc_metadata, &table.powomega, po_off);
+            ntt_butterfly_block(data, blk, halfnn, meta, &table.reduc_metadata, table.powomega(), po_off);
             blk += nn;
         }
 
@@ -633,7 +645,7 @@ pub fn intt_ref<P: PrimeSet>(table: &NttTableInv<P>, data: &mut [u64]) {
         let meta = &table.level_metadata[meta_idx];
         let mut blk = 0;
         while blk < n {
-            intt_butterfly_block(data, blk, 1, meta, &table.reduc_metadata, &table.powomega, po_off);
+            intt_butterfly_block(data, blk, 1, meta, &table.reduc_metadata, table.powomega(), po_off);
             blk += 2;
         }
         // po_off unchanged (halfnn-1 = 0 entries)
@@ -648,7 +660,7 @@ pub fn intt_ref<P: PrimeSet>(table: &NttTableInv<P>, data: &mut [u64]) {
 
         let mut blk = 0;
         while blk < n {
-            intt_butterfly_block(data, blk, halfnn, meta, &table.reduc_metadata, &table.powomega, po_off);
+            intt_butterfly_block(data, blk, halfnn, meta, &table.reduc_metadata, table.powomega(), po_off);
             blk += nn;
         }
 
@@ -676,7 +688,7 @@ pub fn intt_ref<P: PrimeSet>(table: &NttTableInv<P>, data: &mut [u64]) {
                 } else {
                     data[4 * i + k]
                 };
-                let po = table.powomega[po_off + 4 * i + k];
+                let po = table.powomega()[po_off + 4 * i + k];
                 data[4 * i + k] = split_precompmul(x, po, h, mask);
             }
         }
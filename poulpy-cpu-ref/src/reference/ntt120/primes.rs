@@ -48,12 +48,30 @@ pub trait PrimeSet: Sized + Sync + Send + 'static {
     /// to all of them.  Used during NTT precomputation to track the
     /// growth of intermediate bit-widths through the butterfly levels.
     const LOG_Q: u64;
+
+    /// Identifier of the prepared layouts computed with this prime set,
+    /// used as [`Backend::PREPARED_LAYOUT`](poulpy_hal::layouts::Backend::PREPARED_LAYOUT)
+    /// by the NTT120 backends.
+    const PREPARED_LAYOUT: &'static str;
+
+    /// Lazy-reduction bound used when adding two q120b values pointwise.
+    ///
+    /// `Q_SHIFTED[k] = Q[k] << (63 - LOG_Q)`, the largest shift keeping
+    /// `Q_SHIFTED[k] < 2^63`: reducing two values modulo `Q_SHIFTED[k]`
+    /// before adding them keeps the sum below `2^64`.
+    const Q_SHIFTED: [u64; 4] = [
+        (Self::Q[0] as u64) << (63 - Self::LOG_Q),
+        (Self::Q[1] as u64) << (63 - Self::LOG_Q),
+        (Self::Q[2] as u64) << (63 - Self::LOG_Q),
+        (Self::Q[3] as u64) << (63 - Self::LOG_Q),
+    ];
 }
 
 /// 29-bit NTT-friendly primes with `2·2^16`-th roots of unity.
 ///
 /// - `Q ≈ 2^116`
 /// - Each prime is of the form `(1 << 29) - c·(1 << 17) + 1`.
+#[derive(Debug, Clone, Copy, Default)]
 pub struct Primes29;
 
 impl PrimeSet for Primes29 {
@@ -66,6 +84,7 @@ impl PrimeSet for Primes29 {
     const OMEGA: [u32; 4] = [78_289_835, 178_519_192, 483_889_678, 239_808_033];
     const CRT_CST: [u32; 4] = [301_701_286, 536_020_447, 86_367_873, 147_030_781];
     const LOG_Q: u64 = 29;
+    const PREPARED_LAYOUT: &'static str = "q120-p29";
 }
 
 /// 30-bit NTT-friendly primes with `2·2^16`-th roots of unity.
@@ -75,6 +94,7 @@ impl PrimeSet for Primes29 {
 ///
 /// - `Q ≈ 2^120`
 /// - Each prime is of the form `(1 << 30) - c·(1 << 17) + 1`.
+#[derive(Debug, Clone, Copy, Default)]
 pub struct Primes30;
 
 impl PrimeSet for Primes30 {
@@ -87,12 +107,14 @@ impl PrimeSet for Primes30 {
     const OMEGA: [u32; 4] = [1_070_907_127, 315_046_632, 309_185_662, 846_468_380];
     const CRT_CST: [u32; 4] = [43_599_465, 292_938_863, 594_011_630, 140_177_212];
     const LOG_Q: u64 = 30;
+    const PREPARED_LAYOUT: &'static str = "ntt120";
 }
 
 /// 31-bit NTT-friendly primes with `2·2^16`-th roots of unity.
 ///
 /// - `Q ≈ 2^124`
 /// - Each prime is of the form `(1 << 31) - c·(1 << 17) + 1`.
+#[derive(Debug, Clone, Copy, Default)]
 pub struct Primes31;
 
 impl PrimeSet for Primes31 {
@@ -105,4 +127,5 @@ impl PrimeSet for Primes31 {
     const OMEGA: [u32; 4] = [1_615_402_923, 1_137_738_560, 154_880_552, 558_784_885];
     const CRT_CST: [u32; 4] = [1_811_422_063, 2_093_150_204, 164_149_010, 225_197_446];
     const LOG_Q: u64 = 31;
+    const PREPARED_LAYOUT: &'static str = "q120-p31";
 }
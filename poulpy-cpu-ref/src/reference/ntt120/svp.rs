@@ -30,7 +30,7 @@ use crate::{
         ZnxInfos, ZnxView, ZnxViewMut,
     },
     reference::ntt120::{
-        NttCFromB, NttDFTExecute, NttFromZnx64, NttMulBbc, NttZero, ntt::NttTable, primes::Primes30, types::Q120bScalar,
+        NttCFromB, NttDFTExecute, NttFromZnx64, NttMulBbc, NttZero, ntt::NttTable, types::Q120bScalar,
         vec_znx_dft::NttModuleHandle,
     },
 };
@@ -49,9 +49,14 @@ use crate::{
 /// `res` must be a [`SvpPPol`] with `ScalarPrep = Q120bScalar`.
 /// A temporary heap buffer of `4 * n` u64 values is allocated internally
 /// (this is a setup/key-preparation function, not a hot path).
-pub fn ntt120_svp_prepare<R, A, BE>(module: &impl NttModuleHandle, res: &mut R, res_col: usize, a: &A, a_col: usize)
-where
-    BE: Backend<ScalarPrep = Q120bScalar> + NttDFTExecute<NttTable<Primes30>> + NttFromZnx64 + NttCFromB,
+pub fn ntt120_svp_prepare<R, A, BE>(
+    module: &impl NttModuleHandle<PrimeSet = BE::Primes>,
+    res: &mut R,
+    res_col: usize,
+    a: &A,
+    a_col: usize,
+) where
+    BE: Backend<ScalarPrep = Q120bScalar> + NttDFTExecute<NttTable<BE::Primes>> + NttFromZnx64 + NttCFromB,
     R: SvpPPolToMut<BE>,
     A: ScalarZnxToRef,
 {
@@ -85,7 +90,7 @@ where
 /// `b`: input [`VecZnxDft`] in q120b format.
 /// `res`: output [`VecZnxDft`] in q120b format.
 pub fn ntt120_svp_apply_dft_to_dft<R, A, C, BE>(
-    module: &impl NttModuleHandle,
+    module: &impl NttModuleHandle<PrimeSet = BE::Primes>,
     res: &mut R,
     res_col: usize,
     a: &A,
@@ -146,7 +151,7 @@ pub fn ntt120_svp_apply_dft_to_dft<R, A, C, BE>(
 /// Processes each q120b coefficient by copying it (since [`Q120bScalar`] is
 /// `Copy`) before overwriting to avoid aliasing conflicts.
 pub fn ntt120_svp_apply_dft_to_dft_assign<R, A, BE>(
-    module: &impl NttModuleHandle,
+    module: &impl NttModuleHandle<PrimeSet = BE::Primes>,
     res: &mut R,
     res_col: usize,
     a: &A,
@@ -18,9 +18,9 @@
 //!
 //! # Prime set
 //!
-//! All arithmetic is hardcoded to [`Primes30`] (the spqlios-arithmetic
-//! default, Q ≈ 2^120).  Generalisation to `Primes29` / `Primes31`
-//! is future work.
+//! All functions are generic over the prime set of the backend
+//! ([`NttPrimes::Primes`]), which must match the prime set the tables of the
+//! module handle were computed for ([`NttModuleHandle::PrimeSet`]).
 
 use bytemuck::{cast_slice, cast_slice_mut};
 
@@ -30,11 +30,12 @@ use crate::{
         ZnxView, ZnxViewMut,
     },
     reference::ntt120::{
-        NttAdd, NttAddAssign, NttCopy, NttDFTExecute, NttFromZnx64, NttNegate, NttNegateAssign, NttSub, NttSubAssign,
+        NttAdd, NttAddAssign, NttCopy, NttDFTExecute, NttFromZnx64, NttNegate, NttNegateAssign, NttPrimes, NttSub, NttSubAssign,
         NttSubNegateAssign, NttToZnx128, NttZero,
+        arithmetic::b_to_znx128_ref,
         mat_vec::{BbbMeta, BbcMeta},
         ntt::{NttTable, NttTableInv, intt_ref},
-        primes::{PrimeSet, Primes30},
+        primes::PrimeSet,
         types::Q120bScalar,
     },
 };
@@ -43,9 +44,6 @@ use crate::{
 // NttModuleHandle trait + NttHandleProvider blanket impl
 // ──────────────────────────────────────────────────────────────────────────────
 
-// TODO(ntt120): Associate PrimeSet with NttModuleHandle (add associated type)
-//               to enable Primes29/Primes31 dispatch through the public API.
-
 /// Access to the precomputed NTT/iNTT tables and lazy-accumulation metadata
 /// stored inside a `Module<B>` handle.
 ///
@@ -54,18 +52,20 @@ use crate::{
 /// `NttHandleProvider` for their concrete handle type; they do *not* implement
 /// this trait directly (which would violate the orphan rule).
 ///
-/// <!-- DOCUMENTED EXCEPTION: Primes30 hardcoded for spqlios compatibility.
-///   Generalisation path: add `type PrimeSet: PrimeSet` as an associated type here,
-///   then parameterise NttTable/NttTableInv/BbcMeta accordingly. -->
+/// The tables are computed for the prime set [`NttModuleHandle::PrimeSet`],
+/// which the NTT120 functions of this crate require to match the
+/// [`NttPrimes::Primes`] of the backend.
 pub trait NttModuleHandle {
-    /// Precomputed forward NTT twiddle table (Primes30, size `n`).
-    fn get_ntt_table(&self) -> &NttTable<Primes30>;
-    /// Precomputed inverse NTT twiddle table (Primes30, size `n`).
-    fn get_intt_table(&self) -> &NttTableInv<Primes30>;
+    /// Prime set the tables were computed for.
+    type PrimeSet: PrimeSet;
+    /// Precomputed forward NTT twiddle table (size `n`).
+    fn get_ntt_table(&self) -> &NttTable<Self::PrimeSet>;
+    /// Precomputed inverse NTT twiddle table (size `n`).
+    fn get_intt_table(&self) -> &NttTableInv<Self::PrimeSet>;
     /// Precomputed metadata for `q120b × q120c` lazy multiply–accumulate.
-    fn get_bbc_meta(&self) -> &BbcMeta<Primes30>;
+    fn get_bbc_meta(&self) -> &BbcMeta<Self::PrimeSet>;
     /// Precomputed metadata for `q120b × q120b` lazy multiply–accumulate.
-    fn get_bbb_meta(&self) -> &BbbMeta<Primes30>;
+    fn get_bbb_meta(&self) -> &BbbMeta<Self::PrimeSet>;
 }
 
 /// Implemented by backend `Handle` types that store NTT/iNTT tables and BBC
@@ -85,14 +85,16 @@ pub trait NttModuleHandle {
 /// established by the module defaults (or a backend override).  There is no
 /// runtime check in release builds.
 pub unsafe trait NttHandleProvider {
+    /// Prime set the tables were computed for.
+    type PrimeSet: PrimeSet;
     /// Returns a reference to the forward NTT twiddle table.
-    fn get_ntt_table(&self) -> &NttTable<Primes30>;
+    fn get_ntt_table(&self) -> &NttTable<Self::PrimeSet>;
     /// Returns a reference to the inverse NTT twiddle table.
-    fn get_intt_table(&self) -> &NttTableInv<Primes30>;
+    fn get_intt_table(&self) -> &NttTableInv<Self::PrimeSet>;
     /// Returns a reference to the `q120b × q120c` lazy multiply–accumulate metadata.
-    fn get_bbc_meta(&self) -> &BbcMeta<Primes30>;
+    fn get_bbc_meta(&self) -> &BbcMeta<Self::PrimeSet>;
     /// Returns a reference to the `q120b × q120b` lazy multiply–accumulate metadata.
-    fn get_bbb_meta(&self) -> &BbbMeta<Primes30>;
+    fn get_bbb_meta(&self) -> &BbbMeta<Self::PrimeSet>;
 }
 
 /// Construct NTT120 backend handles for [`Module::new`](crate::api::ModuleNew::new).
@@ -117,22 +119,24 @@ where
     B: Backend,
     B::Handle: NttHandleProvider,
 {
-    fn get_ntt_table(&self) -> &NttTable<Primes30> {
+    type PrimeSet = <B::Handle as NttHandleProvider>::PrimeSet;
+
+    fn get_ntt_table(&self) -> &NttTable<Self::PrimeSet> {
         // SAFETY: `ptr()` returns a valid, non-null pointer to `B::Handle`
         // that was initialised by the module defaults and is kept alive by
         // the `Module`.
         unsafe { (&*self.ptr()).get_ntt_table() }
     }
 
-    fn get_intt_table(&self) -> &NttTableInv<Primes30> {
+    fn get_intt_table(&self) -> &NttTableInv<Self::PrimeSet> {
         unsafe { (&*self.ptr()).get_intt_table() }
     }
 
-    fn get_bbc_meta(&self) -> &BbcMeta<Primes30> {
+    fn get_bbc_meta(&self) -> &BbcMeta<Self::PrimeSet> {
         unsafe { (&*self.ptr()).get_bbc_meta() }
     }
 
-    fn get_bbb_meta(&self) -> &BbbMeta<Primes30> {
+    fn get_bbb_meta(&self) -> &BbbMeta<Self::PrimeSet> {
         unsafe { (&*self.ptr()).get_bbb_meta() }
     }
 }
@@ -175,7 +179,7 @@ fn limb_u64_mut<D: crate::layouts::DataMut, BE: Backend<ScalarPrep = Q120bScalar
 ///   then applies the forward NTT in-place via [`NttDFTExecute`].
 /// - Missing input limbs (out of range) are zeroed in `res`.
 pub fn ntt120_vec_znx_dft_apply<R, A, BE>(
-    module: &impl NttModuleHandle,
+    module: &impl NttModuleHandle<PrimeSet = BE::Primes>,
     step: usize,
     offset: usize,
     res: &mut R,
@@ -183,7 +187,7 @@ pub fn ntt120_vec_znx_dft_apply<R, A, BE>(
     a: &A,
     a_col: usize,
 ) where
-    BE: Backend<ScalarPrep = Q120bScalar> + NttDFTExecute<NttTable<Primes30>> + NttFromZnx64 + NttZero,
+    BE: Backend<ScalarPrep = Q120bScalar> + NttDFTExecute<NttTable<BE::Primes>> + NttFromZnx64 + NttZero,
     R: VecZnxDftToMut<BE>,
     A: VecZnxToRef,
 {
@@ -234,14 +238,14 @@ pub fn ntt120_vec_znx_idft_apply_tmp_bytes(n: usize) -> usize {
 ///
 /// `tmp` must hold at least `4 * n` `u64` values.
 pub fn ntt120_vec_znx_idft_apply<R, A, BE>(
-    module: &impl NttModuleHandle,
+    module: &impl NttModuleHandle<PrimeSet = BE::Primes>,
     res: &mut R,
     res_col: usize,
     a: &A,
     a_col: usize,
     tmp: &mut [u64],
 ) where
-    BE: Backend<ScalarPrep = Q120bScalar, ScalarBig = i128> + NttDFTExecute<NttTableInv<Primes30>> + NttToZnx128 + NttCopy,
+    BE: Backend<ScalarPrep = Q120bScalar, ScalarBig = i128> + NttDFTExecute<NttTableInv<BE::Primes>> + NttToZnx128 + NttCopy,
     R: VecZnxBigToMut<BE>,
     A: VecZnxDftToRef<BE>,
 {
@@ -272,13 +276,13 @@ pub fn ntt120_vec_znx_idft_apply<R, A, BE>(
 /// Like [`ntt120_vec_znx_idft_apply`] but applies the inverse NTT
 /// **in place** to `a`, modifying it.  Requires no scratch space.
 pub fn ntt120_vec_znx_idft_apply_tmpa<R, A, BE>(
-    module: &impl NttModuleHandle,
+    module: &impl NttModuleHandle<PrimeSet = BE::Primes>,
     res: &mut R,
     res_col: usize,
     a: &mut A,
     a_col: usize,
 ) where
-    BE: Backend<ScalarPrep = Q120bScalar, ScalarBig = i128> + NttDFTExecute<NttTableInv<Primes30>> + NttToZnx128,
+    BE: Backend<ScalarPrep = Q120bScalar, ScalarBig = i128> + NttDFTExecute<NttTableInv<BE::Primes>> + NttToZnx128,
     R: VecZnxBigToMut<BE>,
     A: VecZnxDftToMut<BE>,
 {
@@ -302,80 +306,23 @@ pub fn ntt120_vec_znx_idft_apply_tmpa<R, A, BE>(
     }
 }
 
-#[inline(always)]
-fn barrett_u61(x: u64, q: u64, mu: u64) -> u64 {
-    let q_approx = ((x as u128 * mu as u128) >> 61) as u64;
-    let r = x - q_approx * q;
-    let r = if r >= q { r - q } else { r };
-    if r >= q { r - q } else { r }
-}
-
-#[inline(always)]
-fn reduce_q120b_crt(x: u64, q: u64, mu: u64, pow32_crt: u64, pow16_crt: u64, crt: u64) -> u64 {
-    let x_hi = x >> 32;
-    let x_hi_r = if x_hi >= q { x_hi - q } else { x_hi };
-    let x_lo = x & 0xFFFF_FFFF;
-    let x_lo_hi = x_lo >> 16;
-    let x_lo_lo = x_lo & 0xFFFF;
-    let tmp = x_hi_r
-        .wrapping_mul(pow32_crt)
-        .wrapping_add(x_lo_hi.wrapping_mul(pow16_crt))
-        .wrapping_add(x_lo_lo.wrapping_mul(crt));
-    barrett_u61(tmp, q, mu)
-}
-
-unsafe fn compact_all_blocks_scalar(n: usize, n_blocks: usize, u64_ptr: *mut u64, table: &NttTableInv<Primes30>) {
-    let q_u64: [u64; 4] = Primes30::Q.map(|qi| qi as u64);
-    let mu: [u64; 4] = q_u64.map(|qi| (1u64 << 61) / qi);
-    let crt: [u64; 4] = Primes30::CRT_CST.map(|c| c as u64);
-
-    let pow32_crt: [u64; 4] = std::array::from_fn(|k| {
-        let pow32 = ((1u128 << 32) % q_u64[k] as u128) as u64;
-        barrett_u61(pow32 * crt[k], q_u64[k], mu[k])
-    });
-    let pow16_crt: [u64; 4] = std::array::from_fn(|k| barrett_u61((1u64 << 16) * crt[k], q_u64[k], mu[k]));
-
-    let q: [u128; 4] = q_u64.map(|qi| qi as u128);
-    let total_q: u128 = q[0] * q[1] * q[2] * q[3];
-    let qm: [u128; 4] = [q[1] * q[2] * q[3], q[0] * q[2] * q[3], q[0] * q[1] * q[3], q[0] * q[1] * q[2]];
-    let half_q: u128 = total_q.div_ceil(2);
-    let total_q_mult: [u128; 4] = [0, total_q, total_q * 2, total_q * 3];
-
+unsafe fn compact_all_blocks_scalar<P: PrimeSet>(n: usize, n_blocks: usize, u64_ptr: *mut u64, table: &NttTableInv<P>) {
     for k in 0..n_blocks {
         let src_start = 4 * n * k;
         let dst_start = 2 * n * k;
 
         {
             let blk: &mut [u64] = unsafe { std::slice::from_raw_parts_mut(u64_ptr.add(src_start), 4 * n) };
-            intt_ref::<Primes30>(table, blk);
+            intt_ref::<P>(table, blk);
         }
 
+        // The i128 of coefficient `c` overwrites u64 slots `2c, 2c+1` of the
+        // block, which only hold already consumed residues.
         for c in 0..n {
-            let (x0, x1, x2, x3) = unsafe {
-                (
-                    *u64_ptr.add(src_start + 4 * c),
-                    *u64_ptr.add(src_start + 4 * c + 1),
-                    *u64_ptr.add(src_start + 4 * c + 2),
-                    *u64_ptr.add(src_start + 4 * c + 3),
-                )
-            };
-
-            let t0 = reduce_q120b_crt(x0, q_u64[0], mu[0], pow32_crt[0], pow16_crt[0], crt[0]);
-            let t1 = reduce_q120b_crt(x1, q_u64[1], mu[1], pow32_crt[1], pow16_crt[1], crt[1]);
-            let t2 = reduce_q120b_crt(x2, q_u64[2], mu[2], pow32_crt[2], pow16_crt[2], crt[2]);
-            let t3 = reduce_q120b_crt(x3, q_u64[3], mu[3], pow32_crt[3], pow16_crt[3], crt[3]);
-
-            let mut v: u128 = t0 as u128 * qm[0] + t1 as u128 * qm[1] + t2 as u128 * qm[2] + t3 as u128 * qm[3];
-
-            let q_approx = (v >> 120) as usize;
-            v -= total_q_mult[q_approx];
-            if v >= total_q {
-                v -= total_q;
-            }
-
-            let val: i128 = if v >= half_q { v as i128 - total_q as i128 } else { v as i128 };
-
-            unsafe { (u64_ptr.add(dst_start + 2 * c) as *mut i128).write_unaligned(val) };
+            let x: [u64; 4] = unsafe { std::ptr::read(u64_ptr.add(src_start + 4 * c) as *const [u64; 4]) };
+            let mut val: [i128; 1] = [0];
+            b_to_znx128_ref::<P>(1, &mut val, &x);
+            unsafe { (u64_ptr.add(dst_start + 2 * c) as *mut i128).write_unaligned(val[0]) };
         }
     }
 }
@@ -385,9 +332,12 @@ unsafe fn compact_all_blocks_scalar(n: usize, n_blocks: usize, u64_ptr: *mut u64
 /// This applies the inverse NTT block by block, then CRT-compacts the owned
 /// `VecZnxDft` buffer from q120b (32 bytes/coeff) to the `VecZnxBig<i128>`
 /// layout (16 bytes/coeff) without allocating a new buffer.
-pub fn ntt120_vec_znx_idft_apply_consume<D: Data, BE>(module: &impl NttModuleHandle, mut a: VecZnxDft<D, BE>) -> VecZnxBig<D, BE>
+pub fn ntt120_vec_znx_idft_apply_consume<D: Data, BE>(
+    module: &impl NttModuleHandle<PrimeSet = BE::Primes>,
+    mut a: VecZnxDft<D, BE>,
+) -> VecZnxBig<D, BE>
 where
-    BE: Backend<ScalarPrep = Q120bScalar, ScalarBig = i128>,
+    BE: Backend<ScalarPrep = Q120bScalar, ScalarBig = i128> + NttPrimes,
     VecZnxDft<D, BE>: VecZnxDftToMut<BE>,
 {
     let table = module.get_intt_table();
@@ -403,7 +353,7 @@ where
         (n, n_blocks, ptr)
     };
 
-    unsafe { compact_all_blocks_scalar(n, n_blocks, u64_ptr, table) };
+    unsafe { compact_all_blocks_scalar::<BE::Primes>(n, n_blocks, u64_ptr, table) };
 
     a.into_big()
 }
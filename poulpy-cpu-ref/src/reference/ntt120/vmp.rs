@@ -35,12 +35,10 @@ use crate::{
     },
     reference::ntt120::{
         NttCFromB, NttDFTExecute, NttExtract1BlkContiguous, NttFromZnx64, NttMulBbc1ColX2, NttMulBbc2ColsX2, mat_vec::BbcMeta,
-        ntt::NttTable, primes::Primes30, types::Q120bScalar, vec_znx_dft::NttModuleHandle,
+        ntt::NttTable, primes::PrimeSet, types::Q120bScalar, vec_znx_dft::NttModuleHandle,
     },
 };
 
-use crate::reference::ntt120::types::Q_SHIFTED;
-
 // ──────────────────────────────────────────────────────────────────────────────
 // Prepare
 // ──────────────────────────────────────────────────────────────────────────────
@@ -61,9 +59,9 @@ pub fn ntt120_vmp_prepare_tmp_bytes(n: usize) -> usize {
 /// 4. Store in `res` in the block-interleaved layout (see module doc).
 ///
 /// `tmp` must hold at least `ntt120_vmp_prepare_tmp_bytes(n) / size_of::<u64>()` elements.
-pub fn ntt120_vmp_prepare<R, A, BE>(module: &impl NttModuleHandle, res: &mut R, a: &A, tmp: &mut [u64])
+pub fn ntt120_vmp_prepare<R, A, BE>(module: &impl NttModuleHandle<PrimeSet = BE::Primes>, res: &mut R, a: &A, tmp: &mut [u64])
 where
-    BE: Backend<ScalarPrep = Q120bScalar> + NttDFTExecute<NttTable<Primes30>> + NttFromZnx64 + NttCFromB,
+    BE: Backend<ScalarPrep = Q120bScalar> + NttDFTExecute<NttTable<BE::Primes>> + NttFromZnx64 + NttCFromB,
     R: VmpPMatToMut<BE>,
     A: MatZnxToRef,
 {
@@ -144,12 +142,12 @@ fn save_blk_overwrite(n: usize, blk: usize, dst: &mut [u64], src: &[u64]) {
 
 /// Save an x2-block (8 u64) into a q120b vector with lazy accumulation.
 #[inline(always)]
-fn save_blk_add(n: usize, blk: usize, dst: &mut [u64], src: &[u64]) {
+fn save_blk_add<P: PrimeSet>(n: usize, blk: usize, dst: &mut [u64], src: &[u64]) {
     debug_assert!(src.len() >= 8);
     debug_assert!(dst.len() >= 4 * n);
     for i in 0..8 {
         let k = i % 4;
-        dst[8 * blk + i] = dst[8 * blk + i] % Q_SHIFTED[k] + src[i] % Q_SHIFTED[k];
+        dst[8 * blk + i] = dst[8 * blk + i] % P::Q_SHIFTED[k] + src[i] % P::Q_SHIFTED[k];
     }
 }
 
@@ -174,7 +172,7 @@ fn vmp_apply_dft_to_dft_core<const OVERWRITE: bool, BE>(
     limb_offset: usize,
     nrows: usize,
     ncols: usize,
-    meta: &BbcMeta<Primes30>,
+    meta: &BbcMeta<BE::Primes>,
     tmp: &mut [u64],
 ) where
     BE: NttExtract1BlkContiguous + NttMulBbc1ColX2 + NttMulBbc2ColsX2,
@@ -221,8 +219,8 @@ fn vmp_apply_dft_to_dft_core<const OVERWRITE: bool, BE>(
                     save_blk_overwrite(n, blk_j, &mut res_u64[base0..], &mat2cols_output[0..8]);
                     save_blk_overwrite(n, blk_j, &mut res_u64[base1..], &mat2cols_output[8..16]);
                 } else {
-                    save_blk_add(n, blk_j, &mut res_u64[base0..], &mat2cols_output[0..8]);
-                    save_blk_add(n, blk_j, &mut res_u64[base1..], &mat2cols_output[8..16]);
+                    save_blk_add::<BE::Primes>(n, blk_j, &mut res_u64[base0..], &mat2cols_output[0..8]);
+                    save_blk_add::<BE::Primes>(n, blk_j, &mut res_u64[base1..], &mat2cols_output[8..16]);
                 }
             }
         } else {
@@ -234,7 +232,7 @@ fn vmp_apply_dft_to_dft_core<const OVERWRITE: bool, BE>(
             if OVERWRITE {
                 save_blk_overwrite(n, blk_j, &mut res_u64[0..], &mat2cols_output[8..16]);
             } else {
-                save_blk_add(n, blk_j, &mut res_u64[0..], &mat2cols_output[8..16]);
+                save_blk_add::<BE::Primes>(n, blk_j, &mut res_u64[0..], &mat2cols_output[8..16]);
             }
 
             // Process remaining paired columns.
@@ -248,8 +246,8 @@ fn vmp_apply_dft_to_dft_core<const OVERWRITE: bool, BE>(
                     save_blk_overwrite(n, blk_j, &mut res_u64[base0..], &mat2cols_output[0..8]);
                     save_blk_overwrite(n, blk_j, &mut res_u64[base1..], &mat2cols_output[8..16]);
                 } else {
-                    save_blk_add(n, blk_j, &mut res_u64[base0..], &mat2cols_output[0..8]);
-                    save_blk_add(n, blk_j, &mut res_u64[base1..], &mat2cols_output[8..16]);
+                    save_blk_add::<BE::Primes>(n, blk_j, &mut res_u64[base0..], &mat2cols_output[0..8]);
+                    save_blk_add::<BE::Primes>(n, blk_j, &mut res_u64[base1..], &mat2cols_output[8..16]);
                 }
             }
         }
@@ -272,7 +270,7 @@ fn vmp_apply_dft_to_dft_core<const OVERWRITE: bool, BE>(
                 if OVERWRITE {
                     save_blk_overwrite(n, blk_j, &mut res_u64[base..], &mat2cols_output[0..8]);
                 } else {
-                    save_blk_add(n, blk_j, &mut res_u64[base..], &mat2cols_output[0..8]);
+                    save_blk_add::<BE::Primes>(n, blk_j, &mut res_u64[base..], &mat2cols_output[0..8]);
                 }
             }
         }
@@ -299,7 +297,7 @@ fn vmp_apply_dft_to_dft_core<const OVERWRITE: bool, BE>(
 ///
 /// `tmp` must hold at least `ntt120_vmp_apply_dft_to_dft_tmp_bytes(...) / size_of::<u64>()` elements.
 pub fn ntt120_vmp_apply_dft_to_dft<R, A, M, BE>(
-    module: &impl NttModuleHandle,
+    module: &impl NttModuleHandle<PrimeSet = BE::Primes>,
     res: &mut R,
     a: &A,
     pmat: &M,
@@ -0,0 +1,143 @@
+//! Pointwise arithmetic on [`Q60Scalar`] vectors.
+//!
+//! All inputs and outputs are reduced to `[0, Q[k])`.
+
+use super::types::{Q, Q0_INV_MOD_Q1, Q60, Q60Scalar};
+
+#[inline(always)]
+fn add_mod(a: u32, b: u32, q: u64) -> u32 {
+    let s: u64 = a as u64 + b as u64;
+    (if s >= q { s - q } else { s }) as u32
+}
+
+#[inline(always)]
+fn sub_mod(a: u32, b: u32, q: u64) -> u32 {
+    (if a >= b {
+        a as u64 - b as u64
+    } else {
+        a as u64 + q - b as u64
+    }) as u32
+}
+
+#[inline(always)]
+fn mul_mod(a: u32, b: u32, q: u64) -> u32 {
+    (a as u64 * b as u64 % q) as u32
+}
+
+/// Maps `x` to its residues modulo [`Q`].
+#[inline(always)]
+pub fn q60_from_i64(x: i64) -> Q60Scalar {
+    Q60Scalar(std::array::from_fn(|k| x.rem_euclid(Q[k] as i64) as u32))
+}
+
+/// Reconstructs the representative of `x` in `(-Q/2, Q/2]` (Garner CRT).
+#[inline(always)]
+pub fn q60_to_i64(x: Q60Scalar) -> i64 {
+    let r0: u64 = x.0[0] as u64;
+    let r1: u64 = x.0[1] as u64;
+    let t: u64 = ((r1 + Q[1] - r0 % Q[1]) % Q[1]) * Q0_INV_MOD_Q1 % Q[1];
+    let v: u64 = r0 + Q[0] * t;
+    if v > Q60 / 2 { v as i64 - Q60 as i64 } else { v as i64 }
+}
+
+/// `res[i] = x[i] mod Q`.
+pub fn q60_from_znx64_ref(res: &mut [Q60Scalar], x: &[i64]) {
+    debug_assert!(res.len() <= x.len());
+    for (r, &xi) in res.iter_mut().zip(x) {
+        *r = q60_from_i64(xi);
+    }
+}
+
+/// `res[i] = (x[i] & mask) mod Q`.
+pub fn q60_from_znx64_masked_ref(res: &mut [Q60Scalar], x: &[i64], mask: i64) {
+    debug_assert!(res.len() <= x.len());
+    for (r, &xi) in res.iter_mut().zip(x) {
+        *r = q60_from_i64(xi & mask);
+    }
+}
+
+/// `res[i]` = centered CRT reconstruction of `x[i]`.
+pub fn q60_to_znx64_ref(res: &mut [i64], x: &[Q60Scalar]) {
+    debug_assert!(res.len() <= x.len());
+    for (r, &xi) in res.iter_mut().zip(x) {
+        *r = q60_to_i64(xi);
+    }
+}
+
+/// Reconstructs `x` in place: on return, the bytes of `x` hold the `i64`
+/// centered CRT reconstruction of each coefficient.
+pub fn q60_to_znx64_assign_ref(x: &mut [Q60Scalar]) {
+    for xi in x.iter_mut() {
+        *xi = bytemuck::cast(q60_to_i64(*xi));
+    }
+}
+
+/// `res = a + b`.
+pub fn q60_add_ref(res: &mut [Q60Scalar], a: &[Q60Scalar], b: &[Q60Scalar]) {
+    for ((r, x), y) in res.iter_mut().zip(a).zip(b) {
+        *r = Q60Scalar(std::array::from_fn(|k| add_mod(x.0[k], y.0[k], Q[k])));
+    }
+}
+
+/// `res += a`.
+pub fn q60_add_assign_ref(res: &mut [Q60Scalar], a: &[Q60Scalar]) {
+    for (r, x) in res.iter_mut().zip(a) {
+        *r = Q60Scalar(std::array::from_fn(|k| add_mod(r.0[k], x.0[k], Q[k])));
+    }
+}
+
+/// `res = a - b`.
+pub fn q60_sub_ref(res: &mut [Q60Scalar], a: &[Q60Scalar], b: &[Q60Scalar]) {
+    for ((r, x), y) in res.iter_mut().zip(a).zip(b) {
+        *r = Q60Scalar(std::array::from_fn(|k| sub_mod(x.0[k], y.0[k], Q[k])));
+    }
+}
+
+/// `res -= a`.
+pub fn q60_sub_assign_ref(res: &mut [Q60Scalar], a: &[Q60Scalar]) {
+    for (r, x) in res.iter_mut().zip(a) {
+        *r = Q60Scalar(std::array::from_fn(|k| sub_mod(r.0[k], x.0[k], Q[k])));
+    }
+}
+
+/// `res = a - res`.
+pub fn q60_sub_negate_assign_ref(res: &mut [Q60Scalar], a: &[Q60Scalar]) {
+    for (r, x) in res.iter_mut().zip(a) {
+        *r = Q60Scalar(std::array::from_fn(|k| sub_mod(x.0[k], r.0[k], Q[k])));
+    }
+}
+
+/// `res = -a`.
+pub fn q60_negate_ref(res: &mut [Q60Scalar], a: &[Q60Scalar]) {
+    for (r, x) in res.iter_mut().zip(a) {
+        *r = Q60Scalar(std::array::from_fn(|k| sub_mod(0, x.0[k], Q[k])));
+    }
+}
+
+/// `res = -res`.
+pub fn q60_negate_assign_ref(res: &mut [Q60Scalar]) {
+    for r in res.iter_mut() {
+        *r = Q60Scalar(std::array::from_fn(|k| sub_mod(0, r.0[k], Q[k])));
+    }
+}
+
+/// `res = a ⊙ b`.
+pub fn q60_mul_ref(res: &mut [Q60Scalar], a: &[Q60Scalar], b: &[Q60Scalar]) {
+    for ((r, x), y) in res.iter_mut().zip(a).zip(b) {
+        *r = Q60Scalar(std::array::from_fn(|k| mul_mod(x.0[k], y.0[k], Q[k])));
+    }
+}
+
+/// `res = res ⊙ a`.
+pub fn q60_mul_assign_ref(res: &mut [Q60Scalar], a: &[Q60Scalar]) {
+    for (r, x) in res.iter_mut().zip(a) {
+        *r = Q60Scalar(std::array::from_fn(|k| mul_mod(r.0[k], x.0[k], Q[k])));
+    }
+}
+
+/// `res += a ⊙ b`.
+pub fn q60_mul_add_ref(res: &mut [Q60Scalar], a: &[Q60Scalar], b: &[Q60Scalar]) {
+    for ((r, x), y) in res.iter_mut().zip(a).zip(b) {
+        *r = Q60Scalar(std::array::from_fn(|k| add_mod(r.0[k], mul_mod(x.0[k], y.0[k], Q[k]), Q[k])));
+    }
+}
@@ -0,0 +1,224 @@
+//! Bivariate convolution operations for the NTT60 backend.
+//!
+//! `CnvPVecL` and `CnvPVecR` both store the forward NTT of the limbs of a
+//! `VecZnx`, with the same [`Q60Scalar`] representation and layout as a
+//! `VecZnxDft`, so left and right operands only differ by their type.
+//!
+//! | Function | Description |
+//! |----------|-------------|
+//! | [`ntt60_cnv_prepare_left`] / [`ntt60_cnv_prepare_right`] / [`ntt60_cnv_prepare_self`] | Encode `VecZnx` → NTT domain |
+//! | [`ntt60_cnv_apply_dft`] | `res[k] = Σ a[k_abs−j] ⊙ b[j]` |
+//! | [`ntt60_cnv_pairwise_apply_dft`] | `res = (a[:,i]+a[:,j]) ⊙ (b[:,i]+b[:,j])` |
+//!
+//! The coefficient-domain convolution by a constant uses the shared `i64`
+//! implementation of the FFT64 backend.
+
+use crate::{
+    layouts::{
+        Backend, CnvPVecL, CnvPVecLToMut, CnvPVecLToRef, CnvPVecR, CnvPVecRToMut, CnvPVecRToRef, VecZnx, VecZnxDft,
+        VecZnxDftToMut, VecZnxToRef, ZnxInfos, ZnxView, ZnxViewMut,
+    },
+    reference::ntt60::{
+        arithmetic::{q60_add_ref, q60_from_znx64_masked_ref, q60_from_znx64_ref, q60_mul_add_ref},
+        ntt::{Ntt60Table, ntt60_ref},
+        types::Q60Scalar,
+    },
+};
+
+/// Forward NTT of the limbs of `a` into `res`, applying `mask` to the last
+/// encoded limb of each column; limbs of `res` beyond `a.size()` are zeroed.
+fn cnv_encode<R>(table: &Ntt60Table, res: &mut R, a: &VecZnx<&[u8]>, mask: i64)
+where
+    R: ZnxViewMut<Scalar = Q60Scalar>,
+{
+    let res_size: usize = res.size();
+    let min_size: usize = res_size.min(a.size());
+    for col in 0..res.cols() {
+        for j in 0..min_size {
+            let limb: &mut [Q60Scalar] = res.at_mut(col, j);
+            if j + 1 == min_size {
+                q60_from_znx64_masked_ref(limb, a.at(col, j), mask);
+            } else {
+                q60_from_znx64_ref(limb, a.at(col, j));
+            }
+            ntt60_ref(table, limb);
+        }
+        for j in min_size..res_size {
+            res.at_mut(col, j).fill(Q60Scalar::default());
+        }
+    }
+}
+
+/// Encode a `VecZnx` into a `CnvPVecL` (NTT domain).
+///
+/// The last encoded limb of each column is masked with `mask`; limbs of
+/// `res` beyond `a.size()` are zeroed.
+pub fn ntt60_cnv_prepare_left<R, A, BE>(table: &Ntt60Table, res: &mut R, a: &A, mask: i64)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: CnvPVecLToMut<BE>,
+    A: VecZnxToRef,
+{
+    let mut res: CnvPVecL<&mut [u8], BE> = res.to_mut();
+    cnv_encode(table, &mut res, &a.to_ref(), mask);
+}
+
+/// Encode a `VecZnx` into a `CnvPVecR` (NTT domain).
+///
+/// The last encoded limb of each column is masked with `mask`; limbs of
+/// `res` beyond `a.size()` are zeroed.
+pub fn ntt60_cnv_prepare_right<R, A, BE>(table: &Ntt60Table, res: &mut R, a: &A, mask: i64)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: CnvPVecRToMut<BE>,
+    A: VecZnxToRef,
+{
+    let mut res: CnvPVecR<&mut [u8], BE> = res.to_mut();
+    cnv_encode(table, &mut res, &a.to_ref(), mask);
+}
+
+/// Encode a `VecZnx` into both a `CnvPVecL` and a `CnvPVecR`, transforming
+/// each limb once.
+pub fn ntt60_cnv_prepare_self<L, R, A, BE>(table: &Ntt60Table, left: &mut L, right: &mut R, a: &A, mask: i64)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    L: CnvPVecLToMut<BE>,
+    R: CnvPVecRToMut<BE>,
+    A: VecZnxToRef,
+{
+    ntt60_cnv_prepare_left(table, left, a, mask);
+
+    let left: CnvPVecL<&mut [u8], BE> = left.to_mut();
+    let mut right: CnvPVecR<&mut [u8], BE> = right.to_mut();
+    assert_eq!(
+        left.cols(),
+        right.cols(),
+        "left.cols():{} != right.cols():{}",
+        left.cols(),
+        right.cols()
+    );
+    assert_eq!(
+        left.size(),
+        right.size(),
+        "left.size():{} != right.size():{}",
+        left.size(),
+        right.size()
+    );
+    right.raw_mut().copy_from_slice(left.raw());
+}
+
+/// Compute the DFT-domain bivariate convolution `res[k] = Σ a[k_abs−j] ⊙ b[j]`.
+///
+/// `k_abs = k + cnv_offset`, and `j` ranges over
+/// `[max(0, k_abs − a.size() + 1), min(k_abs + 1, b.size()))`.
+/// Output limbs past the last non-zero limb of the product are zeroed.
+pub fn ntt60_cnv_apply_dft<R, A, B, BE>(cnv_offset: usize, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &B, b_col: usize)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: VecZnxDftToMut<BE>,
+    A: CnvPVecLToRef<BE>,
+    B: CnvPVecRToRef<BE>,
+{
+    let a: CnvPVecL<&[u8], BE> = a.to_ref();
+    let b: CnvPVecR<&[u8], BE> = b.to_ref();
+    let a_limbs: Vec<&[Q60Scalar]> = (0..a.size()).map(|j| a.at(a_col, j)).collect();
+    let b_limbs: Vec<&[Q60Scalar]> = (0..b.size()).map(|j| b.at(b_col, j)).collect();
+    cnv_apply_limbs(cnv_offset, &mut res.to_mut(), res_col, &a_limbs, &b_limbs);
+}
+
+/// Scratch bytes required by [`ntt60_cnv_pairwise_apply_dft`].
+///
+/// Stores the summed left and right operands.
+pub fn ntt60_cnv_pairwise_apply_dft_tmp_bytes(n: usize, res_size: usize, a_size: usize, b_size: usize) -> usize {
+    if a_size == 0 || b_size == 0 || res_size == 0 {
+        0
+    } else {
+        n * (a_size + b_size) * size_of::<Q60Scalar>()
+    }
+}
+
+/// Compute the pairwise DFT-domain convolution:
+/// `res = (a[:,col_i] + a[:,col_j]) ⊙ (b[:,col_i] + b[:,col_j])`.
+///
+/// As for the other backends, cross-terms are present by design. When
+/// `col_i == col_j` this delegates to [`ntt60_cnv_apply_dft`].
+#[allow(clippy::too_many_arguments)]
+pub fn ntt60_cnv_pairwise_apply_dft<R, A, B, BE>(
+    cnv_offset: usize,
+    res: &mut R,
+    res_col: usize,
+    a: &A,
+    b: &B,
+    col_i: usize,
+    col_j: usize,
+    tmp: &mut [Q60Scalar],
+) where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: VecZnxDftToMut<BE>,
+    A: CnvPVecLToRef<BE>,
+    B: CnvPVecRToRef<BE>,
+{
+    if col_i == col_j {
+        ntt60_cnv_apply_dft(cnv_offset, res, res_col, a, col_i, b, col_j);
+        return;
+    }
+
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    let a: CnvPVecL<&[u8], BE> = a.to_ref();
+    let b: CnvPVecR<&[u8], BE> = b.to_ref();
+
+    let n: usize = res.n();
+    let (a_size, b_size) = (a.size(), b.size());
+    debug_assert!(tmp.len() >= n * (a_size + b_size));
+
+    let (a_sum, tmp) = tmp.split_at_mut(n * a_size);
+    let b_sum = &mut tmp[..n * b_size];
+    for (j, limb) in a_sum.chunks_exact_mut(n).enumerate() {
+        q60_add_ref(limb, a.at(col_i, j), a.at(col_j, j));
+    }
+    for (j, limb) in b_sum.chunks_exact_mut(n).enumerate() {
+        q60_add_ref(limb, b.at(col_i, j), b.at(col_j, j));
+    }
+
+    let a_limbs: Vec<&[Q60Scalar]> = a_sum.chunks_exact(n).collect();
+    let b_limbs: Vec<&[Q60Scalar]> = b_sum.chunks_exact(n).collect();
+    cnv_apply_limbs(cnv_offset, &mut res, res_col, &a_limbs, &b_limbs);
+}
+
+fn cnv_apply_limbs<BE>(
+    cnv_offset: usize,
+    res: &mut VecZnxDft<&mut [u8], BE>,
+    res_col: usize,
+    a: &[&[Q60Scalar]],
+    b: &[&[Q60Scalar]],
+) where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+{
+    let res_size: usize = res.size();
+    let (a_size, b_size) = (a.len(), b.len());
+    if res_size == 0 || a_size == 0 || b_size == 0 {
+        for k in 0..res_size {
+            res.at_mut(res_col, k).fill(Q60Scalar::default());
+        }
+        return;
+    }
+
+    let bound: usize = a_size + b_size - 1;
+    let offset: usize = cnv_offset.min(bound);
+    let min_size: usize = res_size.min((bound + 1).saturating_sub(offset));
+
+    for k in 0..min_size {
+        let k_abs: usize = k + offset;
+        let j_min: usize = k_abs.saturating_sub(a_size - 1);
+        let j_max: usize = (k_abs + 1).min(b_size);
+        let out: &mut [Q60Scalar] = res.at_mut(res_col, k);
+        out.fill(Q60Scalar::default());
+        for j in j_min..j_max {
+            q60_mul_add_ref(out, a[k_abs - j], b[j]);
+        }
+    }
+
+    for k in min_size..res_size {
+        res.at_mut(res_col, k).fill(Q60Scalar::default());
+    }
+}
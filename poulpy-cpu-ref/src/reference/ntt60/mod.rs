@@ -0,0 +1,41 @@
+//! Q60 NTT reference implementation.
+//!
+//! Two-prime counterpart of [`crate::reference::ntt120`]: ring elements are
+//! mapped to `Z_Q[X]/(X^n+1)` with `Q = Q₀·Q₁ ≈ 2^60`, the product of the
+//! first two [`Primes30`](crate::reference::ntt120::primes::Primes30) primes,
+//! and multiplied with a negacyclic NTT modulo each prime.
+//!
+//! Products are exact as long as the coefficients of the result lie in
+//! `(-Q/2, Q/2]`, which covers the small `base2k` / few-limb parameter sets
+//! of bin-fhe-style schemes at half the memory and arithmetic of the four
+//! primes of Q120.  Results are reconstructed with Garner's CRT directly
+//! into `i64`, so the backend uses the same `ScalarBig = i64` big-coefficient
+//! layout as FFT64.
+//!
+//! # Representation
+//!
+//! An NTT-domain coefficient is a [`types::Q60Scalar`]: two `u32` residues,
+//! always reduced to `[0, Qₖ)`.  A limb of a `VecZnxDft` of ring degree `n`
+//! stores `n` consecutive `Q60Scalar` (8 bytes each), in the bit-reversed
+//! order produced by [`ntt::ntt60_ref`].  Since all DFT-domain operations are
+//! pointwise, the ordering is never observable outside of this module.
+//!
+//! Prepared operands (`SvpPPol`, `VmpPMat`, `CnvPVecL`, `CnvPVecR`) use the
+//! same representation as `VecZnxDft`; `VmpPMat` stores the prepared
+//! polynomials in the row-major order of the source `MatZnx`.
+//!
+//! # Submodules
+//!
+//! - [`types`]: the moduli and the [`types::Q60Scalar`] scalar.
+//! - [`arithmetic`]: conversions to/from `i64` and pointwise modular arithmetic.
+//! - [`ntt`]: twiddle tables ([`ntt::Ntt60Table`]) and forward / inverse NTT.
+//! - [`vec_znx_dft`]: module handle traits and `VecZnxDft` operations.
+//! - [`svp`], [`vmp`], [`convolution`]: prepared products.
+
+pub mod arithmetic;
+pub mod convolution;
+pub mod ntt;
+pub mod svp;
+pub mod types;
+pub mod vec_znx_dft;
+pub mod vmp;
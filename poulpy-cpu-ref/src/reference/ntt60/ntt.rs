@@ -0,0 +1,124 @@
+//! Negacyclic NTT modulo the two NTT60 primes.
+//!
+//! The forward transform is a Cooley–Tukey NTT taking coefficients in
+//! natural order to evaluations in bit-reversed order; the inverse is the
+//! matching Gentleman–Sande transform, including the scaling by `n^{-1}`.
+//! Twiddles are powers of a primitive `2n`-th root of unity `ψ`, so that
+//! the pointwise product of two transforms is the transform of the product
+//! in `Z_Q[X]/(X^n+1)`.
+
+use super::types::{MAX_N, OMEGA, Q, Q60Scalar, pow_mod};
+
+/// Precomputed twiddle factors of the forward and inverse NTT of size `n`.
+#[derive(Debug, Clone)]
+pub struct Ntt60Table {
+    n: usize,
+    /// `psi_rev[k][i] = ψₖ^{bitrev(i)} mod Q[k]`.
+    psi_rev: [Vec<u32>; 2],
+    /// `psi_inv_rev[k][i] = ψₖ^{-bitrev(i)} mod Q[k]`.
+    psi_inv_rev: [Vec<u32>; 2],
+    /// `n_inv[k] = n^{-1} mod Q[k]`.
+    n_inv: [u32; 2],
+}
+
+impl Ntt60Table {
+    /// Builds the tables for ring degree `n` (a power of two, `n ≤ 2^16`).
+    pub fn new(n: usize) -> Self {
+        assert!(n.is_power_of_two(), "n must be a power of two, got {n}");
+        assert!(n <= MAX_N, "n must be at most {MAX_N}, got {n}");
+
+        let log_n: u32 = n.trailing_zeros();
+        let bitrev = |i: usize| -> usize {
+            if log_n == 0 {
+                0
+            } else {
+                i.reverse_bits() >> (usize::BITS - log_n)
+            }
+        };
+
+        let mut psi_rev: [Vec<u32>; 2] = [vec![0; n], vec![0; n]];
+        let mut psi_inv_rev: [Vec<u32>; 2] = [vec![0; n], vec![0; n]];
+        let mut n_inv: [u32; 2] = [0; 2];
+
+        for (k, &q) in Q.iter().enumerate() {
+            let psi: u64 = pow_mod(OMEGA[k], (MAX_N / n) as u64, q);
+            let psi_inv: u64 = pow_mod(psi, q - 2, q);
+            for i in 0..n {
+                let e: u64 = bitrev(i) as u64;
+                psi_rev[k][i] = pow_mod(psi, e, q) as u32;
+                psi_inv_rev[k][i] = pow_mod(psi_inv, e, q) as u32;
+            }
+            n_inv[k] = pow_mod(n as u64, q - 2, q) as u32;
+        }
+
+        Self {
+            n,
+            psi_rev,
+            psi_inv_rev,
+            n_inv,
+        }
+    }
+
+    /// Ring degree of the transform.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+}
+
+/// Forward negacyclic NTT of `data` (natural order in, bit-reversed order out).
+pub fn ntt60_ref(table: &Ntt60Table, data: &mut [Q60Scalar]) {
+    let n: usize = table.n;
+    debug_assert!(data.len() >= n);
+
+    for (k, &q) in Q.iter().enumerate() {
+        let psi: &[u32] = &table.psi_rev[k];
+        let mut t: usize = n;
+        let mut m: usize = 1;
+        while m < n {
+            t >>= 1;
+            for i in 0..m {
+                let s: u64 = psi[m + i] as u64;
+                let j1: usize = 2 * i * t;
+                for j in j1..j1 + t {
+                    let u: u64 = data[j].0[k] as u64;
+                    let v: u64 = data[j + t].0[k] as u64 * s % q;
+                    data[j].0[k] = ((u + v) % q) as u32;
+                    data[j + t].0[k] = ((u + q - v) % q) as u32;
+                }
+            }
+            m <<= 1;
+        }
+    }
+}
+
+/// Inverse negacyclic NTT of `data` (bit-reversed order in, natural order out).
+pub fn intt60_ref(table: &Ntt60Table, data: &mut [Q60Scalar]) {
+    let n: usize = table.n;
+    debug_assert!(data.len() >= n);
+
+    for (k, &q) in Q.iter().enumerate() {
+        let psi_inv: &[u32] = &table.psi_inv_rev[k];
+        let mut t: usize = 1;
+        let mut m: usize = n;
+        while m > 1 {
+            let h: usize = m >> 1;
+            for i in 0..h {
+                let s: u64 = psi_inv[h + i] as u64;
+                let j1: usize = 2 * i * t;
+                for j in j1..j1 + t {
+                    let u: u64 = data[j].0[k] as u64;
+                    let v: u64 = data[j + t].0[k] as u64;
+                    data[j].0[k] = ((u + v) % q) as u32;
+                    data[j + t].0[k] = ((u + q - v) % q * s % q) as u32;
+                }
+            }
+            t <<= 1;
+            m = h;
+        }
+
+        let n_inv: u64 = table.n_inv[k] as u64;
+        for x in data[..n].iter_mut() {
+            x.0[k] = (x.0[k] as u64 * n_inv % q) as u32;
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Scalar-vector product (SVP) operations for the NTT60 backend.
+//!
+//! An `SvpPPol` column is the forward NTT of a `ScalarZnx` column, stored in
+//! the same [`Q60Scalar`] representation as a `VecZnxDft` limb, so that the
+//! products below are plain pointwise products modulo `Q₀` and `Q₁`.
+
+use crate::{
+    layouts::{
+        Backend, ScalarZnx, ScalarZnxToRef, SvpPPol, SvpPPolToMut, SvpPPolToRef, VecZnx, VecZnxDft, VecZnxDftToMut,
+        VecZnxDftToRef, VecZnxToRef, ZnxInfos, ZnxView, ZnxViewMut,
+    },
+    reference::ntt60::{
+        arithmetic::{q60_from_znx64_ref, q60_mul_assign_ref, q60_mul_ref},
+        ntt::{Ntt60Table, ntt60_ref},
+        types::Q60Scalar,
+    },
+};
+
+/// `res[res_col] = NTT(a[a_col])`.
+pub fn ntt60_svp_prepare<R, A, BE>(table: &Ntt60Table, res: &mut R, res_col: usize, a: &A, a_col: usize)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: SvpPPolToMut<BE>,
+    A: ScalarZnxToRef,
+{
+    let mut res: SvpPPol<&mut [u8], BE> = res.to_mut();
+    let a: ScalarZnx<&[u8]> = a.to_ref();
+    q60_from_znx64_ref(res.at_mut(res_col, 0), a.at(a_col, 0));
+    ntt60_ref(table, res.at_mut(res_col, 0));
+}
+
+/// `res[res_col, j] = a[a_col] ⊙ NTT(b[b_col, j])`; extra limbs of `res` are zeroed.
+pub fn ntt60_svp_apply_dft<R, A, B, BE>(table: &Ntt60Table, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &B, b_col: usize)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: VecZnxDftToMut<BE>,
+    A: SvpPPolToRef<BE>,
+    B: VecZnxToRef,
+{
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    let a: SvpPPol<&[u8], BE> = a.to_ref();
+    let b: VecZnx<&[u8]> = b.to_ref();
+
+    let res_size: usize = res.size();
+    let min_size: usize = res_size.min(b.size());
+
+    let ppol: &[Q60Scalar] = a.at(a_col, 0);
+    for j in 0..min_size {
+        let out: &mut [Q60Scalar] = res.at_mut(res_col, j);
+        q60_from_znx64_ref(out, b.at(b_col, j));
+        ntt60_ref(table, out);
+        q60_mul_assign_ref(out, ppol);
+    }
+
+    for j in min_size..res_size {
+        res.at_mut(res_col, j).fill(Q60Scalar::default());
+    }
+}
+
+/// `res[res_col, j] = a[a_col] ⊙ b[b_col, j]`; extra limbs of `res` are zeroed.
+pub fn ntt60_svp_apply_dft_to_dft<R, A, B, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize, b: &B, b_col: usize)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: VecZnxDftToMut<BE>,
+    A: SvpPPolToRef<BE>,
+    B: VecZnxDftToRef<BE>,
+{
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    let a: SvpPPol<&[u8], BE> = a.to_ref();
+    let b: VecZnxDft<&[u8], BE> = b.to_ref();
+
+    let res_size: usize = res.size();
+    let min_size: usize = res_size.min(b.size());
+
+    let ppol: &[Q60Scalar] = a.at(a_col, 0);
+    for j in 0..min_size {
+        q60_mul_ref(res.at_mut(res_col, j), ppol, b.at(b_col, j));
+    }
+
+    for j in min_size..res_size {
+        res.at_mut(res_col, j).fill(Q60Scalar::default());
+    }
+}
+
+/// `res[res_col, j] = a[a_col] ⊙ res[res_col, j]`.
+pub fn ntt60_svp_apply_dft_to_dft_assign<R, A, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: VecZnxDftToMut<BE>,
+    A: SvpPPolToRef<BE>,
+{
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    let a: SvpPPol<&[u8], BE> = a.to_ref();
+
+    let ppol: &[Q60Scalar] = a.at(a_col, 0);
+    for j in 0..res.size() {
+        q60_mul_assign_ref(res.at_mut(res_col, j), ppol);
+    }
+}
@@ -0,0 +1,78 @@
+use bytemuck::{Pod, Zeroable};
+use rand_distr::num_traits::Zero;
+use std::{fmt, ops::Add};
+
+use crate::reference::ntt120::primes::{PrimeSet, Primes30};
+
+/// The two NTT-friendly primes `[Q0, Q1]`, the first two primes of [`Primes30`].
+pub const Q: [u64; 2] = [Primes30::Q[0] as u64, Primes30::Q[1] as u64];
+
+/// `OMEGA[k]` is a primitive `2^17`-th root of unity modulo `Q[k]`.
+///
+/// For an NTT of size `n ≤ 2^16`, the primitive `2n`-th root used is
+/// `OMEGA[k]^(2^16 / n) mod Q[k]`.
+pub const OMEGA: [u64; 2] = [Primes30::OMEGA[0] as u64, Primes30::OMEGA[1] as u64];
+
+/// The composite modulus `Q = Q0 · Q1 ≈ 2^60`.
+pub const Q60: u64 = Q[0] * Q[1];
+
+/// `Q0^{-1} mod Q1`, used by the Garner CRT reconstruction.
+pub const Q0_INV_MOD_Q1: u64 = pow_mod(Q[0] % Q[1], Q[1] - 2, Q[1]);
+
+/// Largest supported ring degree.
+pub const MAX_N: usize = 1 << 16;
+
+/// `a^e mod q` by square-and-multiply, for `q < 2^32`.
+pub const fn pow_mod(a: u64, mut e: u64, q: u64) -> u64 {
+    let mut base: u64 = a % q;
+    let mut acc: u64 = 1;
+    while e > 0 {
+        if e & 1 == 1 {
+            acc = acc * base % q;
+        }
+        base = base * base % q;
+        e >>= 1;
+    }
+    acc
+}
+
+/// A single NTT-domain coefficient of the NTT60 backend.
+///
+/// Stores the two residues modulo [`Q`], each reduced to `[0, Q[k])`.
+/// The struct is 8-byte aligned and 8 bytes wide, the size and alignment
+/// of an `i64`, so a `VecZnxDft` limb can be reconstructed in place into a
+/// `VecZnxBig` limb.
+///
+/// Used as `Backend::ScalarPrep` for `NTT60Ref`.
+#[repr(C, align(8))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Q60Scalar(pub [u32; 2]);
+
+// SAFETY: Q60Scalar is #[repr(C, align(8))] with a single [u32; 2] field of
+// size 8: no padding bytes, and all bit patterns are valid.
+unsafe impl Zeroable for Q60Scalar {}
+unsafe impl Pod for Q60Scalar {}
+
+impl fmt::Display for Q60Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{:#x}, {:#x}]", self.0[0], self.0[1])
+    }
+}
+
+impl Add for Q60Scalar {
+    type Output = Self;
+    /// Residue-wise addition modulo [`Q`].
+    fn add(self, rhs: Self) -> Self {
+        Self(std::array::from_fn(|k| ((self.0[k] as u64 + rhs.0[k] as u64) % Q[k]) as u32))
+    }
+}
+
+impl Zero for Q60Scalar {
+    fn zero() -> Self {
+        Self([0u32; 2])
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == [0u32; 2]
+    }
+}
@@ -0,0 +1,482 @@
+//! NTT-domain `VecZnxDft` operations for the NTT60 backend.
+//!
+//! This module provides:
+//!
+//! - The [`Ntt60ModuleHandle`] trait, which exposes the precomputed NTT
+//!   tables of a module handle.
+//! - Forward (`ntt60_vec_znx_dft_apply`) and inverse
+//!   (`ntt60_vec_znx_idft_apply`, `ntt60_vec_znx_idft_apply_tmpa`,
+//!   `ntt60_vec_znx_idft_apply_consume`) DFT operations.
+//! - Residue-wise DFT-domain arithmetic (add, sub, negate, copy, zero).
+//!
+//! The inverse DFT reconstructs each coefficient in `(-Q/2, Q/2]` directly
+//! into the `i64` limbs of a `VecZnxBig`, in place when the input buffer
+//! can be reused: a [`Q60Scalar`] has the size and alignment of an `i64`.
+
+use bytemuck::cast_slice_mut;
+
+use crate::{
+    layouts::{
+        Backend, Data, Module, VecZnxBig, VecZnxBigToMut, VecZnxDft, VecZnxDftToMut, VecZnxDftToRef, VecZnxToRef, ZnxInfos,
+        ZnxView, ZnxViewMut,
+    },
+    reference::ntt60::{
+        arithmetic::{
+            q60_add_assign_ref, q60_add_ref, q60_from_znx64_ref, q60_negate_assign_ref, q60_negate_ref, q60_sub_assign_ref,
+            q60_sub_negate_assign_ref, q60_sub_ref, q60_to_znx64_assign_ref, q60_to_znx64_ref,
+        },
+        ntt::{Ntt60Table, intt60_ref, ntt60_ref},
+        types::Q60Scalar,
+    },
+};
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Ntt60ModuleHandle trait + Ntt60HandleProvider blanket impl
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// Access to the precomputed NTT tables stored inside a `Module<B>` handle.
+///
+/// Automatically implemented for any `Module<B>` whose `B::Handle` implements
+/// [`Ntt60HandleProvider`].
+pub trait Ntt60ModuleHandle {
+    /// Precomputed forward and inverse NTT twiddle tables (size `n`).
+    fn get_ntt60_table(&self) -> &Ntt60Table;
+}
+
+/// Implemented by backend `Handle` types that store NTT60 tables.
+///
+/// # Safety
+///
+/// Implementors must ensure the returned reference is valid for the lifetime
+/// of `&self` and that the table was fully initialised before first use.
+pub unsafe trait Ntt60HandleProvider {
+    /// Returns a reference to the NTT twiddle tables.
+    fn get_ntt60_table(&self) -> &Ntt60Table;
+}
+
+/// Construct NTT60 backend handles for [`Module::new`](crate::api::ModuleNew::new).
+///
+/// # Safety
+///
+/// Implementors must return a fully initialized handle for the requested `n`.
+/// The handle is boxed and stored inside the `Module`, so it must be safe to
+/// drop via [`Backend::destroy`](crate::layouts::Backend::destroy).
+pub unsafe trait Ntt60HandleFactory: Sized {
+    /// Builds a fully initialized handle for ring dimension `n`.
+    fn create_ntt60_handle(n: usize) -> Self;
+}
+
+/// Blanket impl: any `Module<B>` whose handle implements `Ntt60HandleProvider`
+/// automatically satisfies `Ntt60ModuleHandle`.
+impl<B> Ntt60ModuleHandle for Module<B>
+where
+    B: Backend,
+    B::Handle: Ntt60HandleProvider,
+{
+    fn get_ntt60_table(&self) -> &Ntt60Table {
+        // SAFETY: `ptr()` returns a valid, non-null pointer to `B::Handle`
+        // that was initialised by the module defaults and is kept alive by
+        // the `Module`.
+        unsafe { (&*self.ptr()).get_ntt60_table() }
+    }
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Forward DFT
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// Forward NTT of the limbs `offset, offset + step, …` of `a[a_col]` into
+/// `res[res_col]`. Limbs of `res` without a source limb are zeroed.
+pub fn ntt60_vec_znx_dft_apply<R, A, BE>(
+    table: &Ntt60Table,
+    step: usize,
+    offset: usize,
+    res: &mut R,
+    res_col: usize,
+    a: &A,
+    a_col: usize,
+) where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: VecZnxDftToMut<BE>,
+    A: VecZnxToRef,
+{
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    let a = a.to_ref();
+
+    #[cfg(debug_assertions)]
+    {
+        assert!(step > 0);
+        assert_eq!(table.n(), res.n());
+        assert_eq!(a.n(), res.n());
+    }
+
+    let a_size: usize = a.size();
+    let res_size: usize = res.size();
+
+    let steps: usize = a_size.div_ceil(step);
+    let min_steps: usize = res_size.min(steps);
+
+    for j in 0..min_steps {
+        let limb: usize = offset + j * step;
+        if limb < a_size {
+            let res_limb: &mut [Q60Scalar] = res.at_mut(res_col, j);
+            q60_from_znx64_ref(res_limb, a.at(a_col, limb));
+            ntt60_ref(table, res_limb);
+        } else {
+            res.at_mut(res_col, j).fill(Q60Scalar::default());
+        }
+    }
+
+    for j in min_steps..res_size {
+        res.at_mut(res_col, j).fill(Q60Scalar::default());
+    }
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Inverse DFT
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// Returns the scratch space (in bytes) for [`ntt60_vec_znx_idft_apply`].
+///
+/// Returns 0: the inverse NTT runs in the output buffer.
+pub fn ntt60_vec_znx_idft_apply_tmp_bytes(_n: usize) -> usize {
+    0
+}
+
+/// Inverse NTT of `a[a_col]` into `res[res_col]` (`a` is not modified).
+///
+/// Each limb of `a` is copied into the corresponding `i64` limb of `res`,
+/// transformed in place and reconstructed. Extra limbs of `res` are zeroed.
+pub fn ntt60_vec_znx_idft_apply<R, A, BE>(table: &Ntt60Table, res: &mut R, res_col: usize, a: &A, a_col: usize)
+where
+    BE: Backend<ScalarPrep = Q60Scalar, ScalarBig = i64>,
+    R: VecZnxBigToMut<BE>,
+    A: VecZnxDftToRef<BE>,
+{
+    let mut res: VecZnxBig<&mut [u8], BE> = res.to_mut();
+    let a: VecZnxDft<&[u8], BE> = a.to_ref();
+
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(table.n(), res.n());
+        assert_eq!(a.n(), res.n());
+    }
+
+    let res_size: usize = res.size();
+    let min_size: usize = res_size.min(a.size());
+
+    for j in 0..min_size {
+        let res_limb: &mut [Q60Scalar] = cast_slice_mut(res.at_mut(res_col, j));
+        res_limb.copy_from_slice(a.at(a_col, j));
+        intt60_ref(table, res_limb);
+        q60_to_znx64_assign_ref(res_limb);
+    }
+
+    for j in min_size..res_size {
+        res.at_mut(res_col, j).fill(0);
+    }
+}
+
+/// Inverse NTT of `a[a_col]` into `res[res_col]`, using `a` as scratch
+/// (`a` is overwritten with the inverse transform).
+pub fn ntt60_vec_znx_idft_apply_tmpa<R, A, BE>(table: &Ntt60Table, res: &mut R, res_col: usize, a: &mut A, a_col: usize)
+where
+    BE: Backend<ScalarPrep = Q60Scalar, ScalarBig = i64>,
+    R: VecZnxBigToMut<BE>,
+    A: VecZnxDftToMut<BE>,
+{
+    let mut res: VecZnxBig<&mut [u8], BE> = res.to_mut();
+    let mut a: VecZnxDft<&mut [u8], BE> = a.to_mut();
+
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(table.n(), res.n());
+        assert_eq!(a.n(), res.n());
+    }
+
+    let res_size: usize = res.size();
+    let min_size: usize = res_size.min(a.size());
+
+    for j in 0..min_size {
+        intt60_ref(table, a.at_mut(a_col, j));
+        q60_to_znx64_ref(res.at_mut(res_col, j), a.at(a_col, j));
+    }
+
+    for j in min_size..res_size {
+        res.at_mut(res_col, j).fill(0);
+    }
+}
+
+/// Inverse NTT of every limb of `res`, reconstructed in place, and
+/// reinterpretation of the buffer as a `VecZnxBig`.
+pub fn ntt60_vec_znx_idft_apply_consume<D: Data, BE>(table: &Ntt60Table, mut res: VecZnxDft<D, BE>) -> VecZnxBig<D, BE>
+where
+    BE: Backend<ScalarPrep = Q60Scalar, ScalarBig = i64>,
+    VecZnxDft<D, BE>: VecZnxDftToMut<BE>,
+{
+    {
+        let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+
+        #[cfg(debug_assertions)]
+        {
+            assert_eq!(table.n(), res.n());
+        }
+
+        for i in 0..res.cols() {
+            for j in 0..res.size() {
+                let limb: &mut [Q60Scalar] = res.at_mut(i, j);
+                intt60_ref(table, limb);
+                q60_to_znx64_assign_ref(limb);
+            }
+        }
+    }
+
+    res.into_big()
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// DFT-domain arithmetic
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// `res[res_col] = a[a_col] + b[b_col]`.
+///
+/// Limbs present in only one operand are copied; extra limbs of `res` are zeroed.
+pub fn ntt60_vec_znx_dft_add_into<R, A, B, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize, b: &B, b_col: usize)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: VecZnxDftToMut<BE>,
+    A: VecZnxDftToRef<BE>,
+    B: VecZnxDftToRef<BE>,
+{
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    let a: VecZnxDft<&[u8], BE> = a.to_ref();
+    let b: VecZnxDft<&[u8], BE> = b.to_ref();
+
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.n(), res.n());
+        assert_eq!(b.n(), res.n());
+    }
+
+    let res_size: usize = res.size();
+    let a_size: usize = a.size();
+    let b_size: usize = b.size();
+
+    let sum_size: usize = a_size.min(b_size).min(res_size);
+    let cpy_size: usize = a_size.max(b_size).min(res_size);
+
+    for j in 0..sum_size {
+        q60_add_ref(res.at_mut(res_col, j), a.at(a_col, j), b.at(b_col, j));
+    }
+
+    for j in sum_size..cpy_size {
+        if a_size > b_size {
+            res.at_mut(res_col, j).copy_from_slice(a.at(a_col, j));
+        } else {
+            res.at_mut(res_col, j).copy_from_slice(b.at(b_col, j));
+        }
+    }
+
+    for j in cpy_size..res_size {
+        res.at_mut(res_col, j).fill(Q60Scalar::default());
+    }
+}
+
+/// `res[res_col] += a[a_col]`.
+pub fn ntt60_vec_znx_dft_add_assign<R, A, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: VecZnxDftToMut<BE>,
+    A: VecZnxDftToRef<BE>,
+{
+    let a: VecZnxDft<&[u8], BE> = a.to_ref();
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.n(), res.n());
+    }
+
+    let sum_size: usize = a.size().min(res.size());
+
+    for j in 0..sum_size {
+        q60_add_assign_ref(res.at_mut(res_col, j), a.at(a_col, j));
+    }
+}
+
+/// `res[res_col] += a[a_col] · 2^{a_scale · base2k}`, i.e. `a` shifted by
+/// `a_scale` limbs.
+pub fn ntt60_vec_znx_dft_add_scaled_assign<R, A, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize, a_scale: i64)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: VecZnxDftToMut<BE>,
+    A: VecZnxDftToRef<BE>,
+{
+    let a: VecZnxDft<&[u8], BE> = a.to_ref();
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.n(), res.n());
+    }
+
+    let res_size: usize = res.size();
+    let a_size: usize = a.size();
+
+    if a_scale > 0 {
+        let shift: usize = (a_scale as usize).min(a_size);
+        let sum_size: usize = a_size.min(res_size).saturating_sub(shift);
+        for j in 0..sum_size {
+            q60_add_assign_ref(res.at_mut(res_col, j), a.at(a_col, j + shift));
+        }
+    } else if a_scale < 0 {
+        let shift: usize = (a_scale.unsigned_abs() as usize).min(res_size);
+        let sum_size: usize = a_size.min(res_size.saturating_sub(shift));
+        for j in 0..sum_size {
+            q60_add_assign_ref(res.at_mut(res_col, j + shift), a.at(a_col, j));
+        }
+    } else {
+        let sum_size: usize = a_size.min(res_size);
+        for j in 0..sum_size {
+            q60_add_assign_ref(res.at_mut(res_col, j), a.at(a_col, j));
+        }
+    }
+}
+
+/// `res[res_col] = a[a_col] - b[b_col]`.
+///
+/// Limbs present only in `a` are copied, limbs present only in `b` are
+/// negated; extra limbs of `res` are zeroed.
+pub fn ntt60_vec_znx_dft_sub<R, A, B, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize, b: &B, b_col: usize)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: VecZnxDftToMut<BE>,
+    A: VecZnxDftToRef<BE>,
+    B: VecZnxDftToRef<BE>,
+{
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    let a: VecZnxDft<&[u8], BE> = a.to_ref();
+    let b: VecZnxDft<&[u8], BE> = b.to_ref();
+
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.n(), res.n());
+        assert_eq!(b.n(), res.n());
+    }
+
+    let res_size: usize = res.size();
+    let a_size: usize = a.size();
+    let b_size: usize = b.size();
+
+    let sum_size: usize = a_size.min(b_size).min(res_size);
+    let cpy_size: usize = a_size.max(b_size).min(res_size);
+
+    for j in 0..sum_size {
+        q60_sub_ref(res.at_mut(res_col, j), a.at(a_col, j), b.at(b_col, j));
+    }
+
+    for j in sum_size..cpy_size {
+        if a_size > b_size {
+            res.at_mut(res_col, j).copy_from_slice(a.at(a_col, j));
+        } else {
+            q60_negate_ref(res.at_mut(res_col, j), b.at(b_col, j));
+        }
+    }
+
+    for j in cpy_size..res_size {
+        res.at_mut(res_col, j).fill(Q60Scalar::default());
+    }
+}
+
+/// `res[res_col] -= a[a_col]`.
+pub fn ntt60_vec_znx_dft_sub_assign<R, A, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: VecZnxDftToMut<BE>,
+    A: VecZnxDftToRef<BE>,
+{
+    let a: VecZnxDft<&[u8], BE> = a.to_ref();
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.n(), res.n());
+    }
+
+    let sum_size: usize = a.size().min(res.size());
+
+    for j in 0..sum_size {
+        q60_sub_assign_ref(res.at_mut(res_col, j), a.at(a_col, j));
+    }
+}
+
+/// `res[res_col] = a[a_col] - res[res_col]`; limbs of `res` beyond `a` are negated.
+pub fn ntt60_vec_znx_dft_sub_negate_assign<R, A, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: VecZnxDftToMut<BE>,
+    A: VecZnxDftToRef<BE>,
+{
+    let a: VecZnxDft<&[u8], BE> = a.to_ref();
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.n(), res.n());
+    }
+
+    let res_size: usize = res.size();
+    let sum_size: usize = a.size().min(res_size);
+
+    for j in 0..sum_size {
+        q60_sub_negate_assign_ref(res.at_mut(res_col, j), a.at(a_col, j));
+    }
+
+    for j in sum_size..res_size {
+        q60_negate_assign_ref(res.at_mut(res_col, j));
+    }
+}
+
+/// Copies the limbs `offset, offset + step, …` of `a[a_col]` into `res[res_col]`.
+/// Limbs of `res` without a source limb are zeroed.
+pub fn ntt60_vec_znx_dft_copy<R, A, BE>(step: usize, offset: usize, res: &mut R, res_col: usize, a: &A, a_col: usize)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: VecZnxDftToMut<BE>,
+    A: VecZnxDftToRef<BE>,
+{
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    let a: VecZnxDft<&[u8], BE> = a.to_ref();
+
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(res.n(), a.n())
+    }
+
+    let steps: usize = a.size().div_ceil(step);
+    let min_steps: usize = res.size().min(steps);
+
+    for j in 0..min_steps {
+        let limb: usize = offset + j * step;
+        if limb < a.size() {
+            res.at_mut(res_col, j).copy_from_slice(a.at(a_col, limb));
+        } else {
+            res.at_mut(res_col, j).fill(Q60Scalar::default());
+        }
+    }
+    for j in min_steps..res.size() {
+        res.at_mut(res_col, j).fill(Q60Scalar::default());
+    }
+}
+
+/// Zero all limbs of `res[res_col]`.
+pub fn ntt60_vec_znx_dft_zero<R, BE>(res: &mut R, res_col: usize)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: VecZnxDftToMut<BE>,
+{
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    for j in 0..res.size() {
+        res.at_mut(res_col, j).fill(Q60Scalar::default());
+    }
+}
@@ -0,0 +1,119 @@
+//! Vector-matrix product (VMP) operations for the NTT60 backend.
+//!
+//! # Layout
+//!
+//! A `VmpPMat` stores the forward NTT of every polynomial of the source
+//! `MatZnx` in the same row-major order: with `nrows = cols_in · rows` and
+//! `ncols = cols_out · size`, the prepared polynomial of row `r` and
+//! output column `c` lives at `(r · ncols + c) · n` in [`Q60Scalar`] units.
+//!
+//! Row `r = row · cols_in + col_in` multiplies the input polynomial stored at
+//! the same flat index of the `VecZnxDft` (`limb · cols + col`), and output
+//! column `c = limb · cols_out + col_out` is the flat index of the output
+//! polynomial, so the product is a plain matrix–vector product over
+//! polynomials.
+
+use crate::{
+    layouts::{
+        Backend, MatZnx, MatZnxToRef, VecZnxDft, VecZnxDftToMut, VecZnxDftToRef, VmpPMat, VmpPMatToMut, VmpPMatToRef, ZnxInfos,
+        ZnxView, ZnxViewMut,
+    },
+    reference::ntt60::{
+        arithmetic::{q60_from_znx64_ref, q60_mul_add_ref},
+        ntt::{Ntt60Table, ntt60_ref},
+        types::Q60Scalar,
+    },
+};
+
+/// Scratch space (in bytes) required by [`ntt60_vmp_prepare`].
+///
+/// Returns 0: each polynomial is transformed in the output buffer.
+pub fn ntt60_vmp_prepare_tmp_bytes(_n: usize) -> usize {
+    0
+}
+
+/// Encode a polynomial matrix into the NTT-domain prepared format.
+pub fn ntt60_vmp_prepare<R, A, BE>(table: &Ntt60Table, res: &mut R, a: &A)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: VmpPMatToMut<BE>,
+    A: MatZnxToRef,
+{
+    let mut res: VmpPMat<&mut [u8], BE> = res.to_mut();
+    let a: MatZnx<&[u8]> = a.to_ref();
+    let n: usize = res.n();
+
+    debug_assert_eq!(a.n(), n);
+    debug_assert_eq!(res.cols_in(), a.cols_in());
+    debug_assert_eq!(res.rows(), a.rows());
+    debug_assert_eq!(res.cols_out(), a.cols_out());
+    debug_assert_eq!(res.size(), a.size());
+
+    let mat_i64: &[i64] = a.raw();
+    let pmat: &mut [Q60Scalar] = res.raw_mut();
+
+    for (dst, src) in pmat.chunks_exact_mut(n).zip(mat_i64.chunks_exact(n)) {
+        q60_from_znx64_ref(dst, src);
+        ntt60_ref(table, dst);
+    }
+}
+
+/// Scratch space (in bytes) required by [`ntt60_vmp_apply_dft_to_dft`].
+///
+/// Returns 0: products are accumulated directly in the output.
+pub fn ntt60_vmp_apply_dft_to_dft_tmp_bytes(_a_size: usize, _b_rows: usize, _b_cols_in: usize) -> usize {
+    0
+}
+
+/// NTT-domain vector-matrix product (overwrite): `res = a · pmat`.
+///
+/// The output polynomials are read from the columns `limb_offset · cols_out`
+/// onward of `pmat`. Output polynomials beyond the last column of `pmat` are
+/// zeroed, and input polynomials beyond its last row are ignored.
+pub fn ntt60_vmp_apply_dft_to_dft<R, A, M, BE>(res: &mut R, a: &A, pmat: &M, limb_offset: usize)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: VecZnxDftToMut<BE>,
+    A: VecZnxDftToRef<BE>,
+    M: VmpPMatToRef<BE>,
+{
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    let a: VecZnxDft<&[u8], BE> = a.to_ref();
+    let pmat: VmpPMat<&[u8], BE> = pmat.to_ref();
+
+    debug_assert_eq!(res.n(), pmat.n());
+    debug_assert_eq!(a.n(), pmat.n());
+
+    let n: usize = res.n();
+    let nrows: usize = pmat.cols_in() * pmat.rows();
+    let ncols: usize = pmat.cols_out() * pmat.size();
+    let col_offset: usize = limb_offset * pmat.cols_out();
+
+    let a_raw: &[Q60Scalar] = a.raw();
+    let pmat_raw: &[Q60Scalar] = pmat.raw();
+    let res_raw: &mut [Q60Scalar] = res.raw_mut();
+
+    let a_polys: usize = a_raw.len() / n;
+    let row_max: usize = nrows.min(a_polys);
+    let res_polys: usize = res_raw.len() / n;
+    let col_max: usize = ncols.min(res_polys + col_offset);
+
+    res_raw.fill(Q60Scalar::default());
+
+    for c in col_offset..col_max {
+        let out: &mut [Q60Scalar] = &mut res_raw[(c - col_offset) * n..(c - col_offset + 1) * n];
+        for r in 0..row_max {
+            let pos: usize = (r * ncols + c) * n;
+            q60_mul_add_ref(out, &a_raw[r * n..(r + 1) * n], &pmat_raw[pos..pos + n]);
+        }
+    }
+}
+
+/// Zero all entries of a prepared polynomial matrix.
+pub fn ntt60_vmp_zero<R, BE>(res: &mut R)
+where
+    BE: Backend<ScalarPrep = Q60Scalar>,
+    R: VmpPMatToMut<BE>,
+{
+    res.to_mut().raw_mut().fill(Q60Scalar::default());
+}
@@ -0,0 +1,101 @@
+//! Coefficient-wise arithmetic and negacyclic products on `i128` vectors.
+//!
+//! All operations wrap modulo `2^128`.
+
+/// `res[i] = x[i]`.
+pub fn i128_from_znx64_ref(res: &mut [i128], x: &[i64]) {
+    debug_assert!(res.len() <= x.len());
+    for (r, &xi) in res.iter_mut().zip(x) {
+        *r = xi as i128;
+    }
+}
+
+/// `res[i] = x[i] & mask`.
+pub fn i128_from_znx64_masked_ref(res: &mut [i128], x: &[i64], mask: i64) {
+    debug_assert!(res.len() <= x.len());
+    for (r, &xi) in res.iter_mut().zip(x) {
+        *r = (xi & mask) as i128;
+    }
+}
+
+/// `res[i] = a[i] + b[i]`.
+pub fn i128_add_ref(res: &mut [i128], a: &[i128], b: &[i128]) {
+    for ((r, &ai), &bi) in res.iter_mut().zip(a).zip(b) {
+        *r = ai.wrapping_add(bi);
+    }
+}
+
+/// `res[i] += a[i]`.
+pub fn i128_add_assign_ref(res: &mut [i128], a: &[i128]) {
+    for (r, &ai) in res.iter_mut().zip(a) {
+        *r = r.wrapping_add(ai);
+    }
+}
+
+/// `res[i] = a[i] - b[i]`.
+pub fn i128_sub_ref(res: &mut [i128], a: &[i128], b: &[i128]) {
+    for ((r, &ai), &bi) in res.iter_mut().zip(a).zip(b) {
+        *r = ai.wrapping_sub(bi);
+    }
+}
+
+/// `res[i] -= a[i]`.
+pub fn i128_sub_assign_ref(res: &mut [i128], a: &[i128]) {
+    for (r, &ai) in res.iter_mut().zip(a) {
+        *r = r.wrapping_sub(ai);
+    }
+}
+
+/// `res[i] = a[i] - res[i]`.
+pub fn i128_sub_negate_assign_ref(res: &mut [i128], a: &[i128]) {
+    for (r, &ai) in res.iter_mut().zip(a) {
+        *r = ai.wrapping_sub(*r);
+    }
+}
+
+/// `res[i] = -a[i]`.
+pub fn i128_negate_ref(res: &mut [i128], a: &[i128]) {
+    for (r, &ai) in res.iter_mut().zip(a) {
+        *r = ai.wrapping_neg();
+    }
+}
+
+/// `res[i] = -res[i]`.
+pub fn i128_negate_assign_ref(res: &mut [i128]) {
+    for r in res.iter_mut() {
+        *r = r.wrapping_neg();
+    }
+}
+
+/// `res += a · b` in `Z[X]/(X^n+1)`, by schoolbook multiplication.
+pub fn negacyclic_mul_add_ref(res: &mut [i128], a: &[i128], b: &[i128]) {
+    let n: usize = res.len();
+    debug_assert_eq!(a.len(), n);
+    debug_assert_eq!(b.len(), n);
+    for (i, &ai) in a.iter().enumerate() {
+        if ai == 0 {
+            continue;
+        }
+        for (j, &bj) in b.iter().enumerate() {
+            let prod: i128 = ai.wrapping_mul(bj);
+            let k: usize = i + j;
+            if k < n {
+                res[k] = res[k].wrapping_add(prod);
+            } else {
+                res[k - n] = res[k - n].wrapping_sub(prod);
+            }
+        }
+    }
+}
+
+/// `res = a · b` in `Z[X]/(X^n+1)`, by schoolbook multiplication.
+pub fn negacyclic_mul_ref(res: &mut [i128], a: &[i128], b: &[i128]) {
+    res.fill(0);
+    negacyclic_mul_add_ref(res, a, b);
+}
+
+/// `res = res · a` in `Z[X]/(X^n+1)`, by schoolbook multiplication.
+pub fn negacyclic_mul_assign_ref(res: &mut [i128], a: &[i128]) {
+    let b: Vec<i128> = res.to_vec();
+    negacyclic_mul_ref(res, a, &b);
+}
@@ -0,0 +1,32 @@
+//! Schoolbook exact-arithmetic reference implementation.
+//!
+//! Transform-free counterpart of [`crate::reference::fft64`] and
+//! [`crate::reference::ntt120`], intended as a differential-testing oracle:
+//! the "DFT domain" is the coefficient domain itself, widened to `i128`, and
+//! every product is a naive `O(n²)` negacyclic convolution in
+//! `Z[X]/(X^n+1)`.  There is no rounding and no modular reduction, so
+//! results are exact as long as they fit in an `i128`; all arithmetic wraps
+//! modulo `2^128`, which keeps the normalized results exact whenever the
+//! output precision (`base2k · size`) does not exceed 128 bits.
+//!
+//! # Representation
+//!
+//! A `VecZnxDft` limb of ring degree `n` stores its `n` coefficients as
+//! `i128`, in natural order.  Prepared operands (`SvpPPol`, `VmpPMat`,
+//! `CnvPVecL`, `CnvPVecR`) use the same representation; `VmpPMat` stores
+//! the polynomials in the row-major order of the source `MatZnx`.  Since a
+//! `VecZnxDft` limb and a `VecZnxBig` limb (`ScalarBig = i128`) are
+//! identical, the inverse "DFT" is a copy.
+//!
+//! # Submodules
+//!
+//! - [`arithmetic`]: conversions from `i64`, coefficient-wise arithmetic and
+//!   negacyclic products.
+//! - [`vec_znx_dft`]: `VecZnxDft` operations.
+//! - [`svp`], [`vmp`], [`convolution`]: prepared products.
+
+pub mod arithmetic;
+pub mod convolution;
+pub mod svp;
+pub mod vec_znx_dft;
+pub mod vmp;
@@ -0,0 +1,92 @@
+//! Scalar-vector product (SVP) operations for the schoolbook backend.
+//!
+//! An `SvpPPol` column is the `ScalarZnx` column widened to `i128`, and every
+//! product below is a schoolbook negacyclic product.
+
+use crate::{
+    layouts::{
+        Backend, ScalarZnx, ScalarZnxToRef, SvpPPol, SvpPPolToMut, SvpPPolToRef, VecZnx, VecZnxDft, VecZnxDftToMut,
+        VecZnxDftToRef, VecZnxToRef, ZnxInfos, ZnxView, ZnxViewMut,
+    },
+    reference::schoolbook::arithmetic::{i128_from_znx64_ref, negacyclic_mul_assign_ref, negacyclic_mul_ref},
+};
+
+/// `res[res_col] = a[a_col]`, widened to `i128`.
+pub fn schoolbook_svp_prepare<R, A, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize)
+where
+    BE: Backend<ScalarPrep = i128>,
+    R: SvpPPolToMut<BE>,
+    A: ScalarZnxToRef,
+{
+    let mut res: SvpPPol<&mut [u8], BE> = res.to_mut();
+    let a: ScalarZnx<&[u8]> = a.to_ref();
+    i128_from_znx64_ref(res.at_mut(res_col, 0), a.at(a_col, 0));
+}
+
+/// `res[res_col, j] = a[a_col] · b[b_col, j]`; extra limbs of `res` are zeroed.
+pub fn schoolbook_svp_apply_dft<R, A, B, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize, b: &B, b_col: usize)
+where
+    BE: Backend<ScalarPrep = i128>,
+    R: VecZnxDftToMut<BE>,
+    A: SvpPPolToRef<BE>,
+    B: VecZnxToRef,
+{
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    let a: SvpPPol<&[u8], BE> = a.to_ref();
+    let b: VecZnx<&[u8]> = b.to_ref();
+
+    let res_size: usize = res.size();
+    let min_size: usize = res_size.min(b.size());
+
+    let ppol: &[i128] = a.at(a_col, 0);
+    for j in 0..min_size {
+        let out: &mut [i128] = res.at_mut(res_col, j);
+        i128_from_znx64_ref(out, b.at(b_col, j));
+        negacyclic_mul_assign_ref(out, ppol);
+    }
+
+    for j in min_size..res_size {
+        res.at_mut(res_col, j).fill(0);
+    }
+}
+
+/// `res[res_col, j] = a[a_col] · b[b_col, j]`; extra limbs of `res` are zeroed.
+pub fn schoolbook_svp_apply_dft_to_dft<R, A, B, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize, b: &B, b_col: usize)
+where
+    BE: Backend<ScalarPrep = i128>,
+    R: VecZnxDftToMut<BE>,
+    A: SvpPPolToRef<BE>,
+    B: VecZnxDftToRef<BE>,
+{
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    let a: SvpPPol<&[u8], BE> = a.to_ref();
+    let b: VecZnxDft<&[u8], BE> = b.to_ref();
+
+    let res_size: usize = res.size();
+    let min_size: usize = res_size.min(b.size());
+
+    let ppol: &[i128] = a.at(a_col, 0);
+    for j in 0..min_size {
+        negacyclic_mul_ref(res.at_mut(res_col, j), ppol, b.at(b_col, j));
+    }
+
+    for j in min_size..res_size {
+        res.at_mut(res_col, j).fill(0);
+    }
+}
+
+/// `res[res_col, j] = a[a_col] · res[res_col, j]`.
+pub fn schoolbook_svp_apply_dft_to_dft_assign<R, A, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize)
+where
+    BE: Backend<ScalarPrep = i128>,
+    R: VecZnxDftToMut<BE>,
+    A: SvpPPolToRef<BE>,
+{
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    let a: SvpPPol<&[u8], BE> = a.to_ref();
+
+    let ppol: &[i128] = a.at(a_col, 0);
+    for j in 0..res.size() {
+        negacyclic_mul_assign_ref(res.at_mut(res_col, j), ppol);
+    }
+}
@@ -0,0 +1,369 @@
+//! Coefficient-domain `VecZnxDft` operations for the schoolbook backend.
+//!
+//! This module provides:
+//!
+//! - Forward (`schoolbook_vec_znx_dft_apply`) and inverse
+//!   (`schoolbook_vec_znx_idft_apply`, `schoolbook_vec_znx_idft_apply_tmpa`,
+//!   `schoolbook_vec_znx_idft_apply_consume`) "DFT" operations, which only
+//!   widen `i64` limbs to `i128` and copy `i128` limbs back.
+//! - Coefficient-wise DFT-domain arithmetic (add, sub, negate, copy, zero).
+//!
+//! A `VecZnxDft` limb and a `VecZnxBig` limb have the same `i128` layout, so
+//! the consuming inverse DFT only reinterprets the buffer.
+
+use crate::{
+    layouts::{
+        Backend, Data, VecZnxBig, VecZnxBigToMut, VecZnxDft, VecZnxDftToMut, VecZnxDftToRef, VecZnxToRef, ZnxInfos, ZnxView,
+        ZnxViewMut,
+    },
+    reference::schoolbook::arithmetic::{
+        i128_add_assign_ref, i128_add_ref, i128_from_znx64_ref, i128_negate_assign_ref, i128_negate_ref, i128_sub_assign_ref,
+        i128_sub_negate_assign_ref, i128_sub_ref,
+    },
+};
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Forward DFT
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// Widens the limbs `offset, offset + step, …` of `a[a_col]` into
+/// `res[res_col]`. Limbs of `res` without a source limb are zeroed.
+pub fn schoolbook_vec_znx_dft_apply<R, A, BE>(step: usize, offset: usize, res: &mut R, res_col: usize, a: &A, a_col: usize)
+where
+    BE: Backend<ScalarPrep = i128>,
+    R: VecZnxDftToMut<BE>,
+    A: VecZnxToRef,
+{
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    let a = a.to_ref();
+
+    #[cfg(debug_assertions)]
+    {
+        assert!(step > 0);
+        assert_eq!(a.n(), res.n());
+    }
+
+    let a_size: usize = a.size();
+    let res_size: usize = res.size();
+
+    let steps: usize = a_size.div_ceil(step);
+    let min_steps: usize = res_size.min(steps);
+
+    for j in 0..min_steps {
+        let limb: usize = offset + j * step;
+        if limb < a_size {
+            i128_from_znx64_ref(res.at_mut(res_col, j), a.at(a_col, limb));
+        } else {
+            res.at_mut(res_col, j).fill(0);
+        }
+    }
+
+    for j in min_steps..res_size {
+        res.at_mut(res_col, j).fill(0);
+    }
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Inverse DFT
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// Returns the scratch space (in bytes) for [`schoolbook_vec_znx_idft_apply`].
+///
+/// Returns 0: the limbs are copied directly into the output.
+pub fn schoolbook_vec_znx_idft_apply_tmp_bytes(_n: usize) -> usize {
+    0
+}
+
+/// Copies `a[a_col]` into `res[res_col]`. Extra limbs of `res` are zeroed.
+pub fn schoolbook_vec_znx_idft_apply<R, A, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize)
+where
+    BE: Backend<ScalarPrep = i128, ScalarBig = i128>,
+    R: VecZnxBigToMut<BE>,
+    A: VecZnxDftToRef<BE>,
+{
+    let mut res: VecZnxBig<&mut [u8], BE> = res.to_mut();
+    let a: VecZnxDft<&[u8], BE> = a.to_ref();
+
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.n(), res.n());
+    }
+
+    let res_size: usize = res.size();
+    let min_size: usize = res_size.min(a.size());
+
+    for j in 0..min_size {
+        res.at_mut(res_col, j).copy_from_slice(a.at(a_col, j));
+    }
+
+    for j in min_size..res_size {
+        res.at_mut(res_col, j).fill(0);
+    }
+}
+
+/// Same as [`schoolbook_vec_znx_idft_apply`]; `a` is left unchanged.
+pub fn schoolbook_vec_znx_idft_apply_tmpa<R, A, BE>(res: &mut R, res_col: usize, a: &mut A, a_col: usize)
+where
+    BE: Backend<ScalarPrep = i128, ScalarBig = i128>,
+    R: VecZnxBigToMut<BE>,
+    A: VecZnxDftToMut<BE>,
+{
+    let a: VecZnxDft<&mut [u8], BE> = a.to_mut();
+    schoolbook_vec_znx_idft_apply(res, res_col, &a, a_col);
+}
+
+/// Reinterprets the buffer of `res` as a `VecZnxBig`.
+pub fn schoolbook_vec_znx_idft_apply_consume<D: Data, BE>(res: VecZnxDft<D, BE>) -> VecZnxBig<D, BE>
+where
+    BE: Backend<ScalarPrep = i128, ScalarBig = i128>,
+    VecZnxDft<D, BE>: VecZnxDftToMut<BE>,
+{
+    res.into_big()
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// DFT-domain arithmetic
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// `res[res_col] = a[a_col] + b[b_col]`.
+///
+/// Limbs present in only one operand are copied; extra limbs of `res` are zeroed.
+pub fn schoolbook_vec_znx_dft_add_into<R, A, B, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize, b: &B, b_col: usize)
+where
+    BE: Backend<ScalarPrep = i128>,
+    R: VecZnxDftToMut<BE>,
+    A: VecZnxDftToRef<BE>,
+    B: VecZnxDftToRef<BE>,
+{
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    let a: VecZnxDft<&[u8], BE> = a.to_ref();
+    let b: VecZnxDft<&[u8], BE> = b.to_ref();
+
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.n(), res.n());
+        assert_eq!(b.n(), res.n());
+    }
+
+    let res_size: usize = res.size();
+    let a_size: usize = a.size();
+    let b_size: usize = b.size();
+
+    let sum_size: usize = a_size.min(b_size).min(res_size);
+    let cpy_size: usize = a_size.max(b_size).min(res_size);
+
+    for j in 0..sum_size {
+        i128_add_ref(res.at_mut(res_col, j), a.at(a_col, j), b.at(b_col, j));
+    }
+
+    for j in sum_size..cpy_size {
+        if a_size > b_size {
+            res.at_mut(res_col, j).copy_from_slice(a.at(a_col, j));
+        } else {
+            res.at_mut(res_col, j).copy_from_slice(b.at(b_col, j));
+        }
+    }
+
+    for j in cpy_size..res_size {
+        res.at_mut(res_col, j).fill(0);
+    }
+}
+
+/// `res[res_col] += a[a_col]`.
+pub fn schoolbook_vec_znx_dft_add_assign<R, A, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize)
+where
+    BE: Backend<ScalarPrep = i128>,
+    R: VecZnxDftToMut<BE>,
+    A: VecZnxDftToRef<BE>,
+{
+    let a: VecZnxDft<&[u8], BE> = a.to_ref();
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.n(), res.n());
+    }
+
+    let sum_size: usize = a.size().min(res.size());
+
+    for j in 0..sum_size {
+        i128_add_assign_ref(res.at_mut(res_col, j), a.at(a_col, j));
+    }
+}
+
+/// `res[res_col] += a[a_col] · 2^{a_scale · base2k}`, i.e. `a` shifted by
+/// `a_scale` limbs.
+pub fn schoolbook_vec_znx_dft_add_scaled_assign<R, A, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize, a_scale: i64)
+where
+    BE: Backend<ScalarPrep = i128>,
+    R: VecZnxDftToMut<BE>,
+    A: VecZnxDftToRef<BE>,
+{
+    let a: VecZnxDft<&[u8], BE> = a.to_ref();
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.n(), res.n());
+    }
+
+    let res_size: usize = res.size();
+    let a_size: usize = a.size();
+
+    if a_scale > 0 {
+        let shift: usize = (a_scale as usize).min(a_size);
+        let sum_size: usize = a_size.min(res_size).saturating_sub(shift);
+        for j in 0..sum_size {
+            i128_add_assign_ref(res.at_mut(res_col, j), a.at(a_col, j + shift));
+        }
+    } else if a_scale < 0 {
+        let shift: usize = (a_scale.unsigned_abs() as usize).min(res_size);
+        let sum_size: usize = a_size.min(res_size.saturating_sub(shift));
+        for j in 0..sum_size {
+            i128_add_assign_ref(res.at_mut(res_col, j + shift), a.at(a_col, j));
+        }
+    } else {
+        let sum_size: usize = a_size.min(res_size);
+        for j in 0..sum_size {
+            i128_add_assign_ref(res.at_mut(res_col, j), a.at(a_col, j));
+        }
+    }
+}
+
+/// `res[res_col] = a[a_col] - b[b_col]`.
+///
+/// Limbs present only in `a` are copied, limbs present only in `b` are
+/// negated; extra limbs of `res` are zeroed.
+pub fn schoolbook_vec_znx_dft_sub<R, A, B, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize, b: &B, b_col: usize)
+where
+    BE: Backend<ScalarPrep = i128>,
+    R: VecZnxDftToMut<BE>,
+    A: VecZnxDftToRef<BE>,
+    B: VecZnxDftToRef<BE>,
+{
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    let a: VecZnxDft<&[u8], BE> = a.to_ref();
+    let b: VecZnxDft<&[u8], BE> = b.to_ref();
+
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.n(), res.n());
+        assert_eq!(b.n(), res.n());
+    }
+
+    let res_size: usize = res.size();
+    let a_size: usize = a.size();
+    let b_size: usize = b.size();
+
+    let sum_size: usize = a_size.min(b_size).min(res_size);
+    let cpy_size: usize = a_size.max(b_size).min(res_size);
+
+    for j in 0..sum_size {
+        i128_sub_ref(res.at_mut(res_col, j), a.at(a_col, j), b.at(b_col, j));
+    }
+
+    for j in sum_size..cpy_size {
+        if a_size > b_size {
+            res.at_mut(res_col, j).copy_from_slice(a.at(a_col, j));
+        } else {
+            i128_negate_ref(res.at_mut(res_col, j), b.at(b_col, j));
+        }
+    }
+
+    for j in cpy_size..res_size {
+        res.at_mut(res_col, j).fill(0);
+    }
+}
+
+/// `res[res_col] -= a[a_col]`.
+pub fn schoolbook_vec_znx_dft_sub_assign<R, A, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize)
+where
+    BE: Backend<ScalarPrep = i128>,
+    R: VecZnxDftToMut<BE>,
+    A: VecZnxDftToRef<BE>,
+{
+    let a: VecZnxDft<&[u8], BE> = a.to_ref();
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.n(), res.n());
+    }
+
+    let sum_size: usize = a.size().min(res.size());
+
+    for j in 0..sum_size {
+        i128_sub_assign_ref(res.at_mut(res_col, j), a.at(a_col, j));
+    }
+}
+
+/// `res[res_col] = a[a_col] - res[res_col]`; limbs of `res` beyond `a` are negated.
+pub fn schoolbook_vec_znx_dft_sub_negate_assign<R, A, BE>(res: &mut R, res_col: usize, a: &A, a_col: usize)
+where
+    BE: Backend<ScalarPrep = i128>,
+    R: VecZnxDftToMut<BE>,
+    A: VecZnxDftToRef<BE>,
+{
+    let a: VecZnxDft<&[u8], BE> = a.to_ref();
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.n(), res.n());
+    }
+
+    let res_size: usize = res.size();
+    let sum_size: usize = a.size().min(res_size);
+
+    for j in 0..sum_size {
+        i128_sub_negate_assign_ref(res.at_mut(res_col, j), a.at(a_col, j));
+    }
+
+    for j in sum_size..res_size {
+        i128_negate_assign_ref(res.at_mut(res_col, j));
+    }
+}
+
+/// Copies the limbs `offset, offset + step, …` of `a[a_col]` into `res[res_col]`.
+/// Limbs of `res` without a source limb are zeroed.
+pub fn schoolbook_vec_znx_dft_copy<R, A, BE>(step: usize, offset: usize, res: &mut R, res_col: usize, a: &A, a_col: usize)
+where
+    BE: Backend<ScalarPrep = i128>,
+    R: VecZnxDftToMut<BE>,
+    A: VecZnxDftToRef<BE>,
+{
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    let a: VecZnxDft<&[u8], BE> = a.to_ref();
+
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(res.n(), a.n())
+    }
+
+    let steps: usize = a.size().div_ceil(step);
+    let min_steps: usize = res.size().min(steps);
+
+    for j in 0..min_steps {
+        let limb: usize = offset + j * step;
+        if limb < a.size() {
+            res.at_mut(res_col, j).copy_from_slice(a.at(a_col, limb));
+        } else {
+            res.at_mut(res_col, j).fill(0);
+        }
+    }
+    for j in min_steps..res.size() {
+        res.at_mut(res_col, j).fill(0);
+    }
+}
+
+/// Zero all limbs of `res[res_col]`.
+pub fn schoolbook_vec_znx_dft_zero<R, BE>(res: &mut R, res_col: usize)
+where
+    BE: Backend<ScalarPrep = i128>,
+    R: VecZnxDftToMut<BE>,
+{
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    for j in 0..res.size() {
+        res.at_mut(res_col, j).fill(0);
+    }
+}
@@ -0,0 +1,108 @@
+//! Vector-matrix product (VMP) operations for the schoolbook backend.
+//!
+//! # Layout
+//!
+//! A `VmpPMat` stores every polynomial of the source `MatZnx`, widened to
+//! `i128`, in the same row-major order: with `nrows = cols_in · rows` and
+//! `ncols = cols_out · size`, the polynomial of row `r` and output column `c`
+//! lives at `(r · ncols + c) · n`.
+//!
+//! Row `r = row · cols_in + col_in` multiplies the input polynomial stored at
+//! the same flat index of the `VecZnxDft` (`limb · cols + col`), and output
+//! column `c = limb · cols_out + col_out` is the flat index of the output
+//! polynomial, so the product is a plain matrix–vector product over
+//! polynomials, each computed by schoolbook multiplication.
+
+use crate::{
+    layouts::{
+        Backend, MatZnx, MatZnxToRef, VecZnxDft, VecZnxDftToMut, VecZnxDftToRef, VmpPMat, VmpPMatToMut, VmpPMatToRef, ZnxInfos,
+        ZnxView, ZnxViewMut,
+    },
+    reference::schoolbook::arithmetic::{i128_from_znx64_ref, negacyclic_mul_add_ref},
+};
+
+/// Scratch space (in bytes) required by [`schoolbook_vmp_prepare`].
+///
+/// Returns 0: the matrix is widened directly into the output buffer.
+pub fn schoolbook_vmp_prepare_tmp_bytes(_n: usize) -> usize {
+    0
+}
+
+/// Widen a polynomial matrix into the prepared format.
+pub fn schoolbook_vmp_prepare<R, A, BE>(res: &mut R, a: &A)
+where
+    BE: Backend<ScalarPrep = i128>,
+    R: VmpPMatToMut<BE>,
+    A: MatZnxToRef,
+{
+    let mut res: VmpPMat<&mut [u8], BE> = res.to_mut();
+    let a: MatZnx<&[u8]> = a.to_ref();
+
+    debug_assert_eq!(a.n(), res.n());
+    debug_assert_eq!(res.cols_in(), a.cols_in());
+    debug_assert_eq!(res.rows(), a.rows());
+    debug_assert_eq!(res.cols_out(), a.cols_out());
+    debug_assert_eq!(res.size(), a.size());
+
+    i128_from_znx64_ref(res.raw_mut(), a.raw());
+}
+
+/// Scratch space (in bytes) required by [`schoolbook_vmp_apply_dft_to_dft`].
+///
+/// Returns 0: products are accumulated directly in the output.
+pub fn schoolbook_vmp_apply_dft_to_dft_tmp_bytes(_a_size: usize, _b_rows: usize, _b_cols_in: usize) -> usize {
+    0
+}
+
+/// Coefficient-domain vector-matrix product (overwrite): `res = a · pmat`.
+///
+/// The output polynomials are read from the columns `limb_offset · cols_out`
+/// onward of `pmat`. Output polynomials beyond the last column of `pmat` are
+/// zeroed, and input polynomials beyond its last row are ignored.
+pub fn schoolbook_vmp_apply_dft_to_dft<R, A, M, BE>(res: &mut R, a: &A, pmat: &M, limb_offset: usize)
+where
+    BE: Backend<ScalarPrep = i128>,
+    R: VecZnxDftToMut<BE>,
+    A: VecZnxDftToRef<BE>,
+    M: VmpPMatToRef<BE>,
+{
+    let mut res: VecZnxDft<&mut [u8], BE> = res.to_mut();
+    let a: VecZnxDft<&[u8], BE> = a.to_ref();
+    let pmat: VmpPMat<&[u8], BE> = pmat.to_ref();
+
+    debug_assert_eq!(res.n(), pmat.n());
+    debug_assert_eq!(a.n(), pmat.n());
+
+    let n: usize = res.n();
+    let nrows: usize = pmat.cols_in() * pmat.rows();
+    let ncols: usize = pmat.cols_out() * pmat.size();
+    let col_offset: usize = limb_offset * pmat.cols_out();
+
+    let a_raw: &[i128] = a.raw();
+    let pmat_raw: &[i128] = pmat.raw();
+    let res_raw: &mut [i128] = res.raw_mut();
+
+    let a_polys: usize = a_raw.len() / n;
+    let row_max: usize = nrows.min(a_polys);
+    let res_polys: usize = res_raw.len() / n;
+    let col_max: usize = ncols.min(res_polys + col_offset);
+
+    res_raw.fill(0);
+
+    for c in col_offset..col_max {
+        let out: &mut [i128] = &mut res_raw[(c - col_offset) * n..(c - col_offset + 1) * n];
+        for r in 0..row_max {
+            let pos: usize = (r * ncols + c) * n;
+            negacyclic_mul_add_ref(out, &a_raw[r * n..(r + 1) * n], &pmat_raw[pos..pos + n]);
+        }
+    }
+}
+
+/// Zero all entries of a prepared polynomial matrix.
+pub fn schoolbook_vmp_zero<R, BE>(res: &mut R)
+where
+    BE: Backend<ScalarPrep = i128>,
+    R: VmpPMatToMut<BE>,
+{
+    res.to_mut().raw_mut().fill(0);
+}
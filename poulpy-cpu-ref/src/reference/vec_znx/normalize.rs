@@ -8,12 +8,63 @@ use crate::{
 };
 
 #[cfg(test)]
-use crate::{layouts::FillUniform, source::Source};
+use crate::{
+    layouts::{AlignedBuf, FillUniform},
+    source::Source,
+};
 
 pub fn vec_znx_normalize_tmp_bytes(n: usize) -> usize {
     3 * n * size_of::<i64>()
 }
 
+/// Mutable access to the limbs of one column of a normalization output.
+///
+/// Implemented for a column of a [`VecZnx`] and for a slice of limbs, so that
+/// [`vec_znx_normalize_limbs`] can also write into limbs that are not laid
+/// out as a [`VecZnx`] (e.g. the coefficient chunks of a multi-threaded split).
+pub trait ZnxLimbsMut {
+    /// Number of coefficients of a limb.
+    fn n(&self) -> usize;
+    /// Number of limbs.
+    fn size(&self) -> usize;
+    /// Returns the `j`-th limb.
+    fn limb_mut(&mut self, j: usize) -> &mut [i64];
+}
+
+/// Column `col` of a [`VecZnx`].
+struct VecZnxColMut<'a> {
+    vec: VecZnx<&'a mut [u8]>,
+    col: usize,
+}
+
+impl ZnxLimbsMut for VecZnxColMut<'_> {
+    fn n(&self) -> usize {
+        self.vec.n()
+    }
+
+    fn size(&self) -> usize {
+        self.vec.size()
+    }
+
+    fn limb_mut(&mut self, j: usize) -> &mut [i64] {
+        self.vec.at_mut(self.col, j)
+    }
+}
+
+impl ZnxLimbsMut for [&mut [i64]] {
+    fn n(&self) -> usize {
+        self.first().map_or(0, |limb| limb.len())
+    }
+
+    fn size(&self) -> usize {
+        self.len()
+    }
+
+    fn limb_mut(&mut self, j: usize) -> &mut [i64] {
+        self[j]
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn vec_znx_normalize<R, A, ZNXARI>(
     res: &mut R,
@@ -40,23 +91,56 @@ pub fn vec_znx_normalize<R, A, ZNXARI>(
         + ZnxNormalizeMiddleStepAssign
         + ZnxNormalizeFinalStepAssign
         + ZnxNormalizeDigit,
+{
+    let mut res: VecZnxColMut = VecZnxColMut {
+        vec: res.to_mut(),
+        col: res_col,
+    };
+    vec_znx_normalize_limbs::<_, A, ZNXARI>(&mut res, res_base2k, res_offset, a, a_base2k, a_col, carry)
+}
+
+/// [`vec_znx_normalize`] writing into the limbs of `res`.
+#[allow(clippy::too_many_arguments)]
+pub fn vec_znx_normalize_limbs<L, A, ZNXARI>(
+    res: &mut L,
+    res_base2k: usize,
+    res_offset: i64,
+    a: &A,
+    a_base2k: usize,
+    a_col: usize,
+    carry: &mut [i64],
+) where
+    L: ZnxLimbsMut + ?Sized,
+    A: VecZnxToRef,
+    ZNXARI: ZnxZero
+        + ZnxCopy
+        + ZnxAddAssign
+        + ZnxMulPowerOfTwoAssign
+        + ZnxNormalizeFirstStepCarryOnly
+        + ZnxNormalizeMiddleStepCarryOnly
+        + ZnxNormalizeMiddleStep
+        + ZnxNormalizeFinalStep
+        + ZnxNormalizeFirstStep
+        + ZnxExtractDigitAddMul
+        + ZnxNormalizeMiddleStepAssign
+        + ZnxNormalizeFinalStepAssign
+        + ZnxNormalizeDigit,
 {
     match res_base2k == a_base2k {
-        true => vec_znx_normalize_inter_base2k::<R, A, ZNXARI>(res_base2k, res, res_offset, res_col, a, a_col, carry),
-        false => vec_znx_normalize_cross_base2k::<R, A, ZNXARI>(res, res_base2k, res_offset, res_col, a, a_base2k, a_col, carry),
+        true => vec_znx_normalize_inter_base2k::<L, A, ZNXARI>(res_base2k, res, res_offset, a, a_col, carry),
+        false => vec_znx_normalize_cross_base2k::<L, A, ZNXARI>(res, res_base2k, res_offset, a, a_base2k, a_col, carry),
     }
 }
 
-fn vec_znx_normalize_inter_base2k<R, A, ZNXARI>(
+fn vec_znx_normalize_inter_base2k<L, A, ZNXARI>(
     base2k: usize,
-    res: &mut R,
+    res: &mut L,
     res_offset: i64,
-    res_col: usize,
     a: &A,
     a_col: usize,
     carry: &mut [i64],
 ) where
-    R: VecZnxToMut,
+    L: ZnxLimbsMut + ?Sized,
     A: VecZnxToRef,
     ZNXARI: ZnxZero
         + ZnxNormalizeFirstStepCarryOnly
@@ -65,7 +149,6 @@ fn vec_znx_normalize_inter_base2k<R, A, ZNXARI>(
         + ZnxNormalizeFinalStepAssign
         + ZnxNormalizeMiddleStepAssign,
 {
-    let mut res: VecZnx<&mut [u8]> = res.to_mut();
     let a: VecZnx<&[u8]> = a.to_ref();
 
     #[cfg(debug_assertions)]
@@ -116,7 +199,7 @@ fn vec_znx_normalize_inter_base2k<R, A, ZNXARI>(
 
     // Zeroes bottom limbs that will not be interacted with
     for j in res_start..res_size {
-        ZNXARI::znx_zero(res.at_mut(res_col, j));
+        ZNXARI::znx_zero(res.limb_mut(j));
     }
 
     let mid_range: usize = a_start.saturating_sub(a_end);
@@ -126,7 +209,7 @@ fn vec_znx_normalize_inter_base2k<R, A, ZNXARI>(
         ZNXARI::znx_normalize_middle_step::<true>(
             base2k,
             lsh_pos,
-            res.at_mut(res_col, res_start - j - 1),
+            res.limb_mut(res_start - j - 1),
             a.at(a_col, a_start - j - 1),
             carry,
         );
@@ -134,27 +217,26 @@ fn vec_znx_normalize_inter_base2k<R, A, ZNXARI>(
 
     // Propagates the carry over the non-overlapping limbs between res and a
     for j in 0..res_end {
-        ZNXARI::znx_zero(res.at_mut(res_col, res_end - j - 1));
+        ZNXARI::znx_zero(res.limb_mut(res_end - j - 1));
         if j == res_end - 1 {
-            ZNXARI::znx_normalize_final_step_assign(base2k, lsh_pos, res.at_mut(res_col, res_end - j - 1), carry);
+            ZNXARI::znx_normalize_final_step_assign(base2k, lsh_pos, res.limb_mut(res_end - j - 1), carry);
         } else {
-            ZNXARI::znx_normalize_middle_step_assign(base2k, lsh_pos, res.at_mut(res_col, res_end - j - 1), carry);
+            ZNXARI::znx_normalize_middle_step_assign(base2k, lsh_pos, res.limb_mut(res_end - j - 1), carry);
         }
     }
 }
 
 #[allow(clippy::too_many_arguments)]
-fn vec_znx_normalize_cross_base2k<R, A, ZNXARI>(
-    res: &mut R,
+fn vec_znx_normalize_cross_base2k<L, A, ZNXARI>(
+    res: &mut L,
     res_base2k: usize,
     res_offset: i64,
-    res_col: usize,
     a: &A,
     a_base2k: usize,
     a_col: usize,
     carry: &mut [i64],
 ) where
-    R: VecZnxToMut,
+    L: ZnxLimbsMut + ?Sized,
     A: VecZnxToRef,
     ZNXARI: ZnxZero
         + ZnxCopy
@@ -170,7 +252,6 @@ fn vec_znx_normalize_cross_base2k<R, A, ZNXARI>(
         + ZnxNormalizeFinalStepAssign
         + ZnxNormalizeDigit,
 {
-    let mut res: VecZnx<&mut [u8]> = res.to_mut();
     let a: VecZnx<&[u8]> = a.to_ref();
 
     #[cfg(debug_assertions)]
@@ -221,7 +302,7 @@ fn vec_znx_normalize_cross_base2k<R, A, ZNXARI>(
     // need to ensure that the limbs starting from `res_end`
     // are zero.
     for j in 0..res_size {
-        ZNXARI::znx_zero(res.at_mut(res_col, j));
+        ZNXARI::znx_zero(res.limb_mut(j));
     }
 
     // Case where offset is positive and greater or equal
@@ -299,7 +380,7 @@ fn vec_znx_normalize_cross_base2k<R, A, ZNXARI>(
         // extracted.
         'inner: loop {
             // Current limb of res
-            let res_slice: &mut [i64] = res.at_mut(res_col, res_limb);
+            let res_slice: &mut [i64] = res.limb_mut(res_limb);
 
             // We can take at most a_base2k bits
             // but not more than what is left on a_norm or what is left to
@@ -392,9 +473,9 @@ fn vec_znx_normalize_cross_base2k<R, A, ZNXARI>(
 
         for j in 0..res_end {
             if j == res_end - 1 {
-                ZNXARI::znx_normalize_final_step_assign(res_base2k, 0, res.at_mut(res_col, res_end - j - 1), carry_to_use);
+                ZNXARI::znx_normalize_final_step_assign(res_base2k, 0, res.limb_mut(res_end - j - 1), carry_to_use);
             } else {
-                ZNXARI::znx_normalize_middle_step_assign(res_base2k, 0, res.at_mut(res_col, res_end - j - 1), carry_to_use);
+                ZNXARI::znx_normalize_middle_step_assign(res_base2k, 0, res.limb_mut(res_end - j - 1), carry_to_use);
             }
         }
     }
@@ -484,12 +565,23 @@ fn test_vec_znx_normalize_cross_base2k() {
                 let out_size: usize = (in_prec as usize).div_ceil(out_base2k);
 
                 let min_prec: u32 = (in_size * in_base2k).min(out_size * out_base2k) as u32;
-                let mut want: VecZnx<Vec<u8>> = VecZnx::alloc(n, 1, in_size);
+                let mut want: VecZnx<AlignedBuf> = VecZnx::alloc(n, 1, in_size);
                 want.fill_uniform(60, &mut source);
 
-                let mut have: VecZnx<Vec<u8>> = VecZnx::alloc(n, 1, out_size);
+                let mut have: VecZnx<AlignedBuf> = VecZnx::alloc(n, 1, out_size);
                 have.fill_uniform(60, &mut source);
-                vec_znx_normalize_cross_base2k::<_, _, ZnxRef>(&mut have, out_base2k, offset, 0, &want, in_base2k, 0, &mut carry);
+                vec_znx_normalize_cross_base2k::<_, _, ZnxRef>(
+                    &mut VecZnxColMut {
+                        vec: have.to_mut(),
+                        col: 0,
+                    },
+                    out_base2k,
+                    offset,
+                    &want,
+                    in_base2k,
+                    0,
+                    &mut carry,
+                );
 
                 let mut data_have: Vec<FBig<HalfEven>> = (0..n).map(|_| FBig::ZERO).collect();
                 let mut data_want: Vec<FBig<HalfEven>> = (0..n).map(|_| FBig::ZERO).collect();
@@ -579,13 +671,23 @@ fn test_vec_znx_normalize_inter_base2k() {
             let out_prec: u32 = (size * base2k) as u32;
 
             // Fills "want" with uniform values
-            let mut want: VecZnx<Vec<u8>> = VecZnx::alloc(n, 1, size);
+            let mut want: VecZnx<AlignedBuf> = VecZnx::alloc(n, 1, size);
             want.fill_uniform(60, &mut source);
 
             // Fills "have" with the shifted normalization of "want"
-            let mut have: VecZnx<Vec<u8>> = VecZnx::alloc(n, 1, size);
+            let mut have: VecZnx<AlignedBuf> = VecZnx::alloc(n, 1, size);
             have.fill_uniform(60, &mut source);
-            vec_znx_normalize_inter_base2k::<_, _, ZnxRef>(base2k, &mut have, offset, 0, &want, 0, &mut carry);
+            vec_znx_normalize_inter_base2k::<_, _, ZnxRef>(
+                base2k,
+                &mut VecZnxColMut {
+                    vec: have.to_mut(),
+                    col: 0,
+                },
+                offset,
+                &want,
+                0,
+                &mut carry,
+            );
 
             let mut data_have: Vec<FBig<HalfEven>> = (0..n).map(|_| FBig::ZERO).collect();
             let mut data_want: Vec<FBig<HalfEven>> = (0..n).map(|_| FBig::ZERO).collect();
@@ -414,7 +414,7 @@ where
 #[cfg(test)]
 mod tests {
     use crate::{
-        layouts::{FillUniform, VecZnx, ZnxView},
+        layouts::{AlignedBuf, FillUniform, VecZnx, ZnxView},
         reference::{
             vec_znx::{
                 vec_znx_copy, vec_znx_lsh, vec_znx_lsh_assign, vec_znx_lsh_tmp_bytes, vec_znx_normalize_assign, vec_znx_rsh,
@@ -431,9 +431,9 @@ mod tests {
         let cols: usize = 2;
         let size: usize = 7;
 
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
-        let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
-        let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
+        let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
+        let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
 
         let mut source: Source = Source::new([0u8; 32]);
 
@@ -471,9 +471,9 @@ mod tests {
         let res_size: usize = 4;
         let k: usize = 2 * base2k;
 
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
-        let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-        let zero: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
+        let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+        let zero: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
         a.fill_uniform(base2k, &mut source);
         res_test.fill_uniform(base2k, &mut source);
@@ -494,8 +494,8 @@ mod tests {
 
         let res_size: usize = 7;
 
-        let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-        let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+        let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+        let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
         let mut carry: Vec<i64> = vec![0i64; vec_znx_rsh_tmp_bytes(n) / size_of::<i64>()];
 
@@ -506,7 +506,7 @@ mod tests {
         let zero: Vec<i64> = vec![0i64; n];
 
         for a_size in [res_size - 1, res_size, res_size + 1] {
-            let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+            let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
 
             for k in 0..res_size * base2k {
                 a.fill_uniform(50, &mut source);
@@ -0,0 +1,51 @@
+//! Schoolbook exact-arithmetic CPU backend, used as a differential-testing oracle.
+//!
+//! This module provides [`SchoolbookRef`], a backend implementation for [`poulpy_hal`]
+//! that performs no transform at all: DFT-domain vectors and prepared operands hold the
+//! coefficients themselves as `i128`, and every product is an `O(n²)` negacyclic
+//! schoolbook product (see `crate::reference::schoolbook`). It is orders of magnitude
+//! slower than the other backends, but its results are exact and do not share the
+//! transform code of [`FFT64Ref`](crate::FFT64Ref) or [`NTT120Ref`](crate::NTT120Ref),
+//! which makes it an independent reference for the `poulpy_hal` test suites.
+//!
+//! # Architecture
+//!
+//! The DFT-domain operations (`vec_znx_dft`, `svp`, `vmp`, convolutions) delegate to the
+//! reference functions of `crate::reference::schoolbook`. Big-coefficient vectors use
+//! `ScalarBig = i128`, so `vec_znx_big` operations share the NTT120 defaults.
+//!
+//! | Module          | Domain                                                         |
+//! |-----------------|----------------------------------------------------------------|
+//! | `module`        | Backend handle lifecycle                                       |
+//! | `znx`           | Single ring element (`Z[X]/(X^n+1)`) arithmetic               |
+//! | `vec_znx_big`   | Large-coefficient (i128) ring element vectors                  |
+//!
+//! # Scalar types
+//!
+//! - `ScalarPrep = i128`: coefficient-domain values (16 bytes).
+//! - `ScalarBig  = i128`: coefficients in the large-integer domain.
+
+mod module;
+mod vec_znx_big;
+mod znx;
+
+pub use module::SchoolbookRefHandle;
+
+/// Reference CPU backend using exact schoolbook polynomial multiplication.
+///
+/// `SchoolbookRef` is a zero-sized marker type that selects the schoolbook CPU backend
+/// when used as the type parameter `B` in [`poulpy_hal::layouts::Module<B>`](poulpy_hal::layouts::Module)
+/// and related HAL types.
+///
+/// # Backend characteristics
+///
+/// - **ScalarPrep**: `i128` — the "DFT domain" is the coefficient domain.
+/// - **ScalarBig**: `i128` — all arithmetic wraps modulo `2^128`, so normalized
+///   results are exact whenever `base2k · size ≤ 128`.
+/// - **Complexity**: `O(n²)` per polynomial product; intended for tests only.
+///
+/// # Thread safety
+///
+/// `SchoolbookRef` is `Send + Sync` (derived from being a zero-sized, field-less struct).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SchoolbookRef;
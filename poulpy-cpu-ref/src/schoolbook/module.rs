@@ -0,0 +1,45 @@
+//! Backend handle and module initialisation for [`SchoolbookRef`](crate::SchoolbookRef).
+//!
+//! This module defines:
+//!
+//! - [`SchoolbookRefHandle`]: the handle stored inside a `Module<SchoolbookRef>`.
+//!   Schoolbook products need no precomputation, so the handle is empty.
+//! - The [`Backend`] trait implementation, which defines scalar types and the
+//!   handle destruction path.
+
+use std::ptr::NonNull;
+
+use poulpy_hal::{
+    alloc_aligned,
+    layouts::{AlignedBuf, Backend},
+};
+
+use super::SchoolbookRef;
+
+/// Opaque handle for the [`SchoolbookRef`](crate::SchoolbookRef) backend.
+///
+/// Holds no data: it only exists because every `Module` owns a handle.
+/// Heap-allocated during module creation and freed when the
+/// `Module<SchoolbookRef>` is dropped (via [`Backend::destroy`]).
+#[repr(C)]
+#[derive(Default)]
+pub struct SchoolbookRefHandle;
+
+impl Backend for SchoolbookRef {
+    type ScalarPrep = i128;
+    type ScalarBig = i128;
+    type OwnedBuf = AlignedBuf;
+    type Handle = SchoolbookRefHandle;
+    const PREPARED_LAYOUT: &'static str = "sb-i128";
+    fn alloc_bytes(len: usize) -> Self::OwnedBuf {
+        alloc_aligned(len)
+    }
+    fn from_bytes(bytes: Vec<u8>) -> Self::OwnedBuf {
+        AlignedBuf::from(bytes)
+    }
+    unsafe fn destroy(handle: NonNull<Self::Handle>) {
+        unsafe {
+            drop(Box::from_raw(handle.as_ptr()));
+        }
+    }
+}
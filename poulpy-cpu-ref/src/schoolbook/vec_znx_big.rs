@@ -0,0 +1,12 @@
+//! Large-coefficient (i128) ring element vector support for [`SchoolbookRef`](crate::SchoolbookRef).
+//!
+//! `SchoolbookRef` shares the `i128` big-coefficient layout of NTT120, so it
+//! uses the NTT120 `VecZnxBig` defaults with the scalar `I128BigOps` and
+//! `I128NormalizeOps` kernels.
+
+use crate::reference::ntt120::{I128BigOps, I128NormalizeOps};
+
+use super::SchoolbookRef;
+
+impl I128BigOps for SchoolbookRef {}
+impl I128NormalizeOps for SchoolbookRef {}
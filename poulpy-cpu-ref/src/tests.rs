@@ -140,6 +140,16 @@ cross_backend_test_suite! {
     }
 }
 
+cross_backend_test_suite! {
+    mod persistence,
+    backend_ref =  crate::NTT120Ref,
+    backend_test = crate::FFT64Ref,
+    params = TestParams { size: 1<<8, base2k: 12 },
+    tests = {
+        test_prepared_persistence => poulpy_hal::test_suite::serialization::test_prepared_persistence,
+    }
+}
+
 backend_test_suite! {
     mod sampling,
     backend = crate::NTT120Ref,
@@ -162,3 +172,368 @@ poulpy_core::core_backend_test_suite!(
     backend = crate::NTT120Ref,
     params = TestParams { size: 1<<8, base2k: 52 },
 );
+
+/// Runs the NTT120 HAL suites against [`NTT120Ref`](crate::NTT120Ref) instantiated with a non-default prime set.
+macro_rules! ntt120_prime_set_tests {
+    (mod $name:ident, primes = $primes:ident $(,)?) => {
+        mod $name {
+            use poulpy_hal::{api::ModuleNew, cross_backend_test_suite, layouts::Module};
+
+            use crate::{NTT120Ref, reference::ntt120::primes::$primes};
+
+            type NTT120Primes = NTT120Ref<$primes>;
+
+            cross_backend_test_suite! {
+                mod svp,
+                backend_ref = crate::FFT64Ref,
+                backend_test = super::NTT120Primes,
+                params = TestParams { size: 1<<8, base2k: 12 },
+                tests = {
+                    test_svp_apply_dft_to_dft => poulpy_hal::test_suite::svp::test_svp_apply_dft_to_dft,
+                    test_svp_apply_dft_to_dft_assign => poulpy_hal::test_suite::svp::test_svp_apply_dft_to_dft_assign,
+                }
+            }
+
+            cross_backend_test_suite! {
+                mod vec_znx_dft,
+                backend_ref = crate::FFT64Ref,
+                backend_test = super::NTT120Primes,
+                params = TestParams { size: 1<<8, base2k: 12 },
+                tests = {
+                    test_vec_znx_dft_add_into => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_dft_add_into,
+                    test_vec_znx_dft_sub => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_dft_sub,
+                    test_vec_znx_dft_sub_negate_assign => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_dft_sub_negate_assign,
+                    test_vec_znx_idft_apply => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_idft_apply,
+                    test_vec_znx_idft_apply_consume => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_idft_apply_consume,
+                    test_vec_znx_idft_apply_tmpa => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_idft_apply_tmpa,
+                }
+            }
+
+            cross_backend_test_suite! {
+                mod vmp,
+                backend_ref = crate::FFT64Ref,
+                backend_test = super::NTT120Primes,
+                params = TestParams { size: 1<<8, base2k: 12 },
+                tests = {
+                    test_vmp_apply_dft_to_dft => poulpy_hal::test_suite::vmp::test_vmp_apply_dft_to_dft,
+                }
+            }
+
+            cross_backend_test_suite! {
+                mod persistence,
+                backend_ref = crate::NTT120Ref,
+                backend_test = super::NTT120Primes,
+                params = TestParams { size: 1<<8, base2k: 12 },
+                tests = {
+                    test_prepared_persistence => poulpy_hal::test_suite::serialization::test_prepared_persistence,
+                }
+            }
+
+            #[test]
+            fn test_convolution() {
+                let module: Module<NTT120Primes> = Module::<NTT120Primes>::new(8);
+                poulpy_hal::test_suite::convolution::test_convolution(&module, 50);
+                poulpy_hal::test_suite::convolution::test_convolution_pairwise(&module, 50);
+            }
+        }
+    };
+}
+
+ntt120_prime_set_tests!(mod primes29, primes = Primes29);
+ntt120_prime_set_tests!(mod primes31, primes = Primes31);
+
+mod ntt60 {
+    use poulpy_hal::{api::ModuleNew, cross_backend_test_suite, layouts::Module};
+
+    use crate::NTT60Ref;
+
+    cross_backend_test_suite! {
+        mod svp,
+        backend_ref = crate::FFT64Ref,
+        backend_test = crate::NTT60Ref,
+        params = TestParams { size: 1<<8, base2k: 12 },
+        tests = {
+            test_svp_apply_dft_to_dft => poulpy_hal::test_suite::svp::test_svp_apply_dft_to_dft,
+            test_svp_apply_dft_to_dft_assign => poulpy_hal::test_suite::svp::test_svp_apply_dft_to_dft_assign,
+        }
+    }
+
+    cross_backend_test_suite! {
+        mod vec_znx_big,
+        backend_ref = crate::FFT64Ref,
+        backend_test = crate::NTT60Ref,
+        params = TestParams { size: 1<<8, base2k: 12 },
+        tests = {
+            test_vec_znx_big_add_into => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_add_into,
+            test_vec_znx_big_add_small_assign => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_add_small_assign,
+            test_vec_znx_big_sub => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_sub,
+            test_vec_znx_big_automorphism => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_automorphism,
+            test_vec_znx_big_normalize => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_normalize,
+            test_vec_znx_big_normalize_fused => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_normalize_fused,
+        }
+    }
+
+    cross_backend_test_suite! {
+        mod vec_znx_dft,
+        backend_ref = crate::FFT64Ref,
+        backend_test = crate::NTT60Ref,
+        params = TestParams { size: 1<<8, base2k: 12 },
+        tests = {
+            test_vec_znx_dft_add_into => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_dft_add_into,
+            test_vec_znx_dft_add_assign => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_dft_add_assign,
+            test_vec_znx_dft_sub => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_dft_sub,
+            test_vec_znx_dft_sub_assign => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_dft_sub_assign,
+            test_vec_znx_dft_sub_negate_assign => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_dft_sub_negate_assign,
+            test_vec_znx_dft_copy => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_copy,
+            test_vec_znx_idft_apply => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_idft_apply,
+            test_vec_znx_idft_apply_consume => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_idft_apply_consume,
+            test_vec_znx_idft_apply_tmpa => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_idft_apply_tmpa,
+        }
+    }
+
+    cross_backend_test_suite! {
+        mod vmp,
+        backend_ref = crate::FFT64Ref,
+        backend_test = crate::NTT60Ref,
+        params = TestParams { size: 1<<8, base2k: 12 },
+        tests = {
+            test_vmp_apply_dft_to_dft => poulpy_hal::test_suite::vmp::test_vmp_apply_dft_to_dft,
+        }
+    }
+
+    cross_backend_test_suite! {
+        mod persistence,
+        backend_ref = crate::FFT64Ref,
+        backend_test = crate::NTT60Ref,
+        params = TestParams { size: 1<<8, base2k: 12 },
+        tests = {
+            test_prepared_persistence => poulpy_hal::test_suite::serialization::test_prepared_persistence,
+        }
+    }
+
+    #[test]
+    fn test_convolution() {
+        let module: Module<NTT60Ref> = Module::<NTT60Ref>::new(8);
+        poulpy_hal::test_suite::convolution::test_convolution(&module, 17);
+        poulpy_hal::test_suite::convolution::test_convolution_pairwise(&module, 17);
+        poulpy_hal::test_suite::convolution::test_convolution_by_const(&module, 17);
+    }
+
+    poulpy_core::core_backend_test_suite!(
+        mod core,
+        backend = crate::NTT60Ref,
+        params = TestParams { size: 1<<8, base2k: 17 },
+    );
+}
+
+mod schoolbook {
+    use poulpy_hal::{api::ModuleNew, cross_backend_test_suite, layouts::Module};
+
+    use crate::SchoolbookRef;
+
+    cross_backend_test_suite! {
+        mod fft64_svp,
+        backend_ref = crate::SchoolbookRef,
+        backend_test = crate::FFT64Ref,
+        params = TestParams { size: 1<<6, base2k: 12 },
+        tests = {
+            test_svp_apply_dft_to_dft => poulpy_hal::test_suite::svp::test_svp_apply_dft_to_dft,
+            test_svp_apply_dft_to_dft_assign => poulpy_hal::test_suite::svp::test_svp_apply_dft_to_dft_assign,
+        }
+    }
+
+    cross_backend_test_suite! {
+        mod fft64_vec_znx_dft,
+        backend_ref = crate::SchoolbookRef,
+        backend_test = crate::FFT64Ref,
+        params = TestParams { size: 1<<6, base2k: 12 },
+        tests = {
+            test_vec_znx_dft_add_into => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_dft_add_into,
+            test_vec_znx_dft_add_assign => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_dft_add_assign,
+            test_vec_znx_dft_sub => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_dft_sub,
+            test_vec_znx_dft_sub_assign => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_dft_sub_assign,
+            test_vec_znx_dft_sub_negate_assign => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_dft_sub_negate_assign,
+            test_vec_znx_dft_copy => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_copy,
+            test_vec_znx_idft_apply => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_idft_apply,
+            test_vec_znx_idft_apply_consume => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_idft_apply_consume,
+            test_vec_znx_idft_apply_tmpa => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_idft_apply_tmpa,
+        }
+    }
+
+    cross_backend_test_suite! {
+        mod fft64_vmp,
+        backend_ref = crate::SchoolbookRef,
+        backend_test = crate::FFT64Ref,
+        params = TestParams { size: 1<<6, base2k: 12 },
+        tests = {
+            test_vmp_apply_dft_to_dft => poulpy_hal::test_suite::vmp::test_vmp_apply_dft_to_dft,
+        }
+    }
+
+    cross_backend_test_suite! {
+        mod fft64_lossy,
+        backend_ref = crate::SchoolbookRef,
+        backend_test = crate::FFT64Ref,
+        params = TestParams { size: 1<<10, base2k: 26 },
+        tests = {
+            test_vmp_apply_dft_to_dft_lossy => poulpy_hal::test_suite::precision::test_vmp_apply_dft_to_dft_lossy,
+            test_svp_apply_dft_to_dft_lossy => poulpy_hal::test_suite::precision::test_svp_apply_dft_to_dft_lossy,
+            test_convolution_lossy => poulpy_hal::test_suite::precision::test_convolution_lossy,
+        }
+    }
+
+    cross_backend_test_suite! {
+        mod ntt60_vmp,
+        backend_ref = crate::SchoolbookRef,
+        backend_test = crate::NTT60Ref,
+        params = TestParams { size: 1<<6, base2k: 12 },
+        tests = {
+            test_svp_apply_dft_to_dft => poulpy_hal::test_suite::svp::test_svp_apply_dft_to_dft,
+            test_vmp_apply_dft_to_dft => poulpy_hal::test_suite::vmp::test_vmp_apply_dft_to_dft,
+        }
+    }
+
+    cross_backend_test_suite! {
+        mod ntt120_vec_znx_big,
+        backend_ref = crate::SchoolbookRef,
+        backend_test = crate::NTT120Ref,
+        params = TestParams { size: 1<<6, base2k: 50 },
+        tests = {
+            test_vec_znx_big_add_into => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_add_into,
+            test_vec_znx_big_sub => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_sub,
+            test_vec_znx_big_automorphism => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_automorphism,
+            test_vec_znx_big_normalize => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_normalize,
+        }
+    }
+
+    cross_backend_test_suite! {
+        mod ntt120_vmp,
+        backend_ref = crate::SchoolbookRef,
+        backend_test = crate::NTT120Ref,
+        params = TestParams { size: 1<<6, base2k: 50 },
+        tests = {
+            test_svp_apply_dft_to_dft => poulpy_hal::test_suite::svp::test_svp_apply_dft_to_dft,
+            test_svp_apply_dft_to_dft_assign => poulpy_hal::test_suite::svp::test_svp_apply_dft_to_dft_assign,
+            test_vec_znx_idft_apply => poulpy_hal::test_suite::vec_znx_dft::test_vec_znx_idft_apply,
+            test_vmp_apply_dft_to_dft => poulpy_hal::test_suite::vmp::test_vmp_apply_dft_to_dft,
+        }
+    }
+
+    cross_backend_test_suite! {
+        mod persistence,
+        backend_ref = crate::FFT64Ref,
+        backend_test = crate::SchoolbookRef,
+        params = TestParams { size: 1<<6, base2k: 12 },
+        tests = {
+            test_prepared_persistence => poulpy_hal::test_suite::serialization::test_prepared_persistence,
+        }
+    }
+
+    #[test]
+    fn test_convolution() {
+        let module: Module<SchoolbookRef> = Module::<SchoolbookRef>::new(8);
+        poulpy_hal::test_suite::convolution::test_convolution(&module, 50);
+        poulpy_hal::test_suite::convolution::test_convolution_pairwise(&module, 50);
+        poulpy_hal::test_suite::convolution::test_convolution_by_const(&module, 50);
+    }
+}
+
+mod profiled {
+    use poulpy_hal::{
+        api::{
+            ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow, ScratchTakeBasic, VecZnxBigNormalize, VecZnxBigNormalizeTmpBytes,
+            VecZnxDftApply, VecZnxDftBytesOf, VecZnxIdftApplyConsume,
+        },
+        cross_backend_test_suite,
+        layouts::{AlignedBuf, FillUniform, Module, ScratchOwned, VecZnx, VecZnxBig, VecZnxDft},
+        profiling::{self, OpProfile, ProfileReport, Profiled, ShapeCount, TmpBytesCheck},
+        source::Source,
+    };
+
+    use crate::FFT64Ref;
+
+    type FFT64Prof = Profiled<FFT64Ref>;
+
+    #[test]
+    fn test_profile_report() {
+        let n: usize = 1 << 8;
+        let base2k: usize = 12;
+        let (cols, size) = (2, 4);
+        let module: Module<FFT64Prof> = Module::<FFT64Prof>::new(n as u64);
+
+        let mut source: Source = Source::new([0u8; 32]);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
+        a.fill_uniform(base2k, &mut source);
+        let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
+
+        let dft_bytes: usize = module.bytes_of_vec_znx_dft(cols, size);
+        let tmp_bytes: usize = dft_bytes + module.vec_znx_big_normalize_tmp_bytes();
+        let mut scratch: ScratchOwned<FFT64Prof> = ScratchOwned::alloc(tmp_bytes);
+
+        profiling::reset();
+        let (mut a_dft, scratch_1): (VecZnxDft<&mut [u8], FFT64Prof>, _) = scratch.borrow().take_vec_znx_dft(&module, cols, size);
+        for j in 0..cols {
+            module.vec_znx_dft_apply(1, 0, &mut a_dft, j, &a, j);
+        }
+        let a_big: VecZnxBig<&mut [u8], FFT64Prof> = module.vec_znx_idft_apply_consume(a_dft);
+        for j in 0..cols {
+            module.vec_znx_big_normalize(&mut res, base2k, 0, j, &a_big, base2k, j, scratch_1);
+        }
+        let report: ProfileReport = profiling::take_report();
+
+        assert_eq!(res, a);
+        assert_eq!(report.total_calls(), 2 * cols as u64 + 1);
+        assert_eq!(report.calls("vec_znx_idft_apply_consume"), 1);
+
+        let dft: &OpProfile = report.op("vec_znx_dft_apply").unwrap();
+        assert_eq!(dft.calls, cols as u64);
+        assert_eq!(dft.max_scratch, 0);
+        assert_eq!(
+            dft.shapes,
+            vec![ShapeCount {
+                dims: vec![(cols, size), (cols, size)],
+                calls: cols as u64,
+            }]
+        );
+
+        let normalize: &OpProfile = report.op("vec_znx_big_normalize").unwrap();
+        assert_eq!(normalize.calls, cols as u64);
+        assert!(normalize.max_scratch > 0);
+        assert!(normalize.max_scratch <= module.vec_znx_big_normalize_tmp_bytes());
+
+        let check: TmpBytesCheck = report.check_tmp_bytes(tmp_bytes);
+        assert!(check.is_sufficient(), "{check}");
+        assert!(check.peak > dft_bytes);
+        assert!(!report.check_tmp_bytes(dft_bytes).is_sufficient());
+
+        assert!(profiling::report().ops.is_empty());
+    }
+
+    cross_backend_test_suite! {
+        mod vec_znx_big,
+        backend_ref = crate::FFT64Ref,
+        backend_test = super::FFT64Prof,
+        params = TestParams { size: 1<<8, base2k: 12 },
+        tests = {
+            test_vec_znx_big_add_small_assign => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_add_small_assign,
+            test_vec_znx_big_normalize => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_normalize,
+            test_vec_znx_big_normalize_fused => poulpy_hal::test_suite::vec_znx_big::test_vec_znx_big_normalize_fused,
+        }
+    }
+
+    cross_backend_test_suite! {
+        mod vmp,
+        backend_ref = crate::FFT64Ref,
+        backend_test = super::FFT64Prof,
+        params = TestParams { size: 1<<8, base2k: 12 },
+        tests = {
+            test_vmp_apply_dft_to_dft => poulpy_hal::test_suite::vmp::test_vmp_apply_dft_to_dft,
+        }
+    }
+
+    poulpy_core::core_backend_test_suite!(
+        mod core_fft64,
+        backend = super::FFT64Prof,
+        params = TestParams { size: 1<<8, base2k: 17 },
+    );
+}
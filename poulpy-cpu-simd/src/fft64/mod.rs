@@ -0,0 +1,34 @@
+mod module;
+mod reim;
+
+/// Portable SIMD CPU backend for Poulpy HAL (FFT64 family).
+///
+/// `FFT64Simd` is a zero-sized marker type that selects the `std::simd` CPU backend
+/// when used as the type parameter `B` in [`Module<B>`](poulpy_hal::layouts::Module)
+/// and related HAL types. It overrides the `znx` kernels (add, sub, negate, normalize,
+/// automorphism), the reim FFT/IFFT and the reim vector arithmetic of the reference
+/// backend; every other kernel falls back to `poulpy-cpu-ref`.
+///
+/// # Backend characteristics
+///
+/// - **ScalarPrep**: `f64` — DFT-domain coefficients are 64-bit IEEE 754 floats.
+/// - **ScalarBig**: `i64` — large-coefficient ring elements use 64-bit signed integers.
+/// - **FFT tables**: the reference twiddle tables, stored in the module handle.
+///
+/// # CPU feature requirements
+///
+/// None. The kernels are written against `std::simd` and compile for any target; the
+/// vector width actually used depends on the target features enabled at build time
+/// (e.g. `-C target-cpu=native`). Results are bit-identical to
+/// [`FFT64Ref`](poulpy_cpu_ref::FFT64Ref), so prepared layouts are interchangeable.
+///
+/// # Thread safety
+///
+/// `FFT64Simd` is `Send + Sync` (derived from being a zero-sized, field-less struct).
+/// The `Module<FFT64Simd>` that holds the FFT tables is also `Send + Sync`.
+pub struct FFT64Simd {}
+
+#[cfg(test)]
+pub mod tests;
+
+pub use reim::{ReimFFTSimd, ReimIFFTSimd};
@@ -0,0 +1,383 @@
+use std::ptr::NonNull;
+
+use poulpy_cpu_ref::reference::{
+    fft64::{
+        convolution::I64Ops,
+        module::{FFT64HandleFactory, FFTHandleProvider},
+        reim::{ReimArith, ReimFFTExecute, ReimFFTTable, ReimIFFTTable, reim_copy_ref, reim_zero_ref},
+        reim4::{Reim4BlkMatVec, Reim4Convolution},
+    },
+    znx::{
+        ZnxAdd, ZnxAddAssign, ZnxAutomorphism, ZnxCopy, ZnxExtractDigitAddMul, ZnxMulAddPowerOfTwo, ZnxMulPowerOfTwo,
+        ZnxMulPowerOfTwoAssign, ZnxNegate, ZnxNegateAssign, ZnxNormalizeDigit, ZnxNormalizeFinalStep,
+        ZnxNormalizeFinalStepAssign, ZnxNormalizeFinalStepSub, ZnxNormalizeFirstStep, ZnxNormalizeFirstStepAssign,
+        ZnxNormalizeFirstStepCarryOnly, ZnxNormalizeMiddleStep, ZnxNormalizeMiddleStepAssign, ZnxNormalizeMiddleStepCarryOnly,
+        ZnxNormalizeMiddleStepSub, ZnxRotate, ZnxSub, ZnxSubAssign, ZnxSubNegateAssign, ZnxSwitchRing, ZnxZero, znx_copy_ref,
+        znx_mul_add_power_of_two_ref, znx_mul_power_of_two_assign_ref, znx_mul_power_of_two_ref, znx_rotate, znx_switch_ring_ref,
+        znx_zero_ref,
+    },
+};
+use poulpy_hal::{
+    alloc_aligned,
+    layouts::{AlignedBuf, Backend},
+};
+
+use crate::{
+    FFT64Simd,
+    fft64::reim::{
+        ReimFFTSimd, ReimIFFTSimd, reim_add_assign_simd, reim_add_simd, reim_addmul_simd, reim_from_znx_i64_masked_simd,
+        reim_from_znx_i64_simd, reim_mul_assign_simd, reim_mul_simd, reim_negate_assign_simd, reim_negate_simd,
+        reim_sub_assign_simd, reim_sub_negate_assign_simd, reim_sub_simd, reim_to_znx_i64_assign_simd, reim_to_znx_i64_simd,
+    },
+    znx_simd::{
+        znx_add_assign_simd, znx_add_simd, znx_automorphism_simd, znx_extract_digit_addmul_simd, znx_negate_assign_simd,
+        znx_negate_simd, znx_normalize_digit_simd, znx_normalize_final_step_assign_simd, znx_normalize_final_step_simd,
+        znx_normalize_final_step_sub_simd, znx_normalize_first_step_assign_simd, znx_normalize_first_step_carry_only_simd,
+        znx_normalize_first_step_simd, znx_normalize_middle_step_assign_simd, znx_normalize_middle_step_carry_only_simd,
+        znx_normalize_middle_step_simd, znx_normalize_middle_step_sub_simd, znx_sub_assign_simd, znx_sub_negate_assign_simd,
+        znx_sub_simd,
+    },
+};
+
+/// Backend-specific handle storing precomputed FFT/IFFT twiddle factors.
+///
+/// Allocated once during [`Module::new()`](poulpy_hal::layouts::Module::new) and
+/// destroyed via [`Backend::destroy()`](poulpy_hal::layouts::Backend::destroy) when
+/// the module is dropped. The tables are immutable after construction.
+#[repr(C)]
+pub struct FFT64SimdHandle {
+    table_fft: ReimFFTTable<f64>,
+    table_ifft: ReimIFFTTable<f64>,
+}
+
+impl Backend for FFT64Simd {
+    type ScalarPrep = f64;
+    type ScalarBig = i64;
+    type OwnedBuf = AlignedBuf;
+    type Handle = FFT64SimdHandle;
+    const PREPARED_LAYOUT: &'static str = "fft64";
+    fn alloc_bytes(len: usize) -> Self::OwnedBuf {
+        alloc_aligned(len)
+    }
+    fn from_bytes(bytes: Vec<u8>) -> Self::OwnedBuf {
+        AlignedBuf::from(bytes)
+    }
+    unsafe fn destroy(handle: NonNull<Self::Handle>) {
+        unsafe {
+            drop(Box::from_raw(handle.as_ptr()));
+        }
+    }
+}
+
+/// # Safety
+///
+/// The returned handle must be fully initialized for `n`.
+unsafe impl FFT64HandleFactory for FFT64SimdHandle {
+    fn create_fft64_handle(n: usize) -> Self {
+        FFT64SimdHandle {
+            table_fft: ReimFFTTable::new(n >> 1),
+            table_ifft: ReimIFFTTable::new(n >> 1),
+        }
+    }
+}
+
+unsafe impl FFTHandleProvider<f64> for FFT64SimdHandle {
+    fn get_fft_table(&self) -> &ReimFFTTable<f64> {
+        &self.table_fft
+    }
+
+    fn get_ifft_table(&self) -> &ReimIFFTTable<f64> {
+        &self.table_ifft
+    }
+}
+
+impl ZnxAdd for FFT64Simd {
+    #[inline(always)]
+    fn znx_add(res: &mut [i64], a: &[i64], b: &[i64]) {
+        znx_add_simd(res, a, b);
+    }
+}
+
+impl ZnxAddAssign for FFT64Simd {
+    #[inline(always)]
+    fn znx_add_assign(res: &mut [i64], a: &[i64]) {
+        znx_add_assign_simd(res, a);
+    }
+}
+
+impl ZnxSub for FFT64Simd {
+    #[inline(always)]
+    fn znx_sub(res: &mut [i64], a: &[i64], b: &[i64]) {
+        znx_sub_simd(res, a, b);
+    }
+}
+
+impl ZnxSubAssign for FFT64Simd {
+    #[inline(always)]
+    fn znx_sub_assign(res: &mut [i64], a: &[i64]) {
+        znx_sub_assign_simd(res, a);
+    }
+}
+
+impl ZnxSubNegateAssign for FFT64Simd {
+    #[inline(always)]
+    fn znx_sub_negate_assign(res: &mut [i64], a: &[i64]) {
+        znx_sub_negate_assign_simd(res, a);
+    }
+}
+
+impl ZnxAutomorphism for FFT64Simd {
+    #[inline(always)]
+    fn znx_automorphism(p: i64, res: &mut [i64], a: &[i64]) {
+        znx_automorphism_simd(p, res, a);
+    }
+}
+
+impl ZnxCopy for FFT64Simd {
+    #[inline(always)]
+    fn znx_copy(res: &mut [i64], a: &[i64]) {
+        znx_copy_ref(res, a);
+    }
+}
+
+impl ZnxNegate for FFT64Simd {
+    #[inline(always)]
+    fn znx_negate(res: &mut [i64], src: &[i64]) {
+        znx_negate_simd(res, src);
+    }
+}
+
+impl ZnxNegateAssign for FFT64Simd {
+    #[inline(always)]
+    fn znx_negate_assign(res: &mut [i64]) {
+        znx_negate_assign_simd(res);
+    }
+}
+
+impl ZnxMulAddPowerOfTwo for FFT64Simd {
+    #[inline(always)]
+    fn znx_muladd_power_of_two(k: i64, res: &mut [i64], a: &[i64]) {
+        znx_mul_add_power_of_two_ref(k, res, a);
+    }
+}
+
+impl ZnxMulPowerOfTwo for FFT64Simd {
+    #[inline(always)]
+    fn znx_mul_power_of_two(k: i64, res: &mut [i64], a: &[i64]) {
+        znx_mul_power_of_two_ref(k, res, a);
+    }
+}
+
+impl ZnxMulPowerOfTwoAssign for FFT64Simd {
+    #[inline(always)]
+    fn znx_mul_power_of_two_assign(k: i64, res: &mut [i64]) {
+        znx_mul_power_of_two_assign_ref(k, res);
+    }
+}
+
+impl ZnxRotate for FFT64Simd {
+    #[inline(always)]
+    fn znx_rotate(p: i64, res: &mut [i64], src: &[i64]) {
+        znx_rotate::<Self>(p, res, src);
+    }
+}
+
+impl ZnxZero for FFT64Simd {
+    #[inline(always)]
+    fn znx_zero(res: &mut [i64]) {
+        znx_zero_ref(res);
+    }
+}
+
+impl ZnxSwitchRing for FFT64Simd {
+    #[inline(always)]
+    fn znx_switch_ring(res: &mut [i64], a: &[i64]) {
+        znx_switch_ring_ref(res, a);
+    }
+}
+
+impl ZnxNormalizeFirstStep for FFT64Simd {
+    #[inline(always)]
+    fn znx_normalize_first_step<const OVERWRITE: bool>(base2k: usize, lsh: usize, x: &mut [i64], a: &[i64], carry: &mut [i64]) {
+        znx_normalize_first_step_simd::<OVERWRITE>(base2k, lsh, x, a, carry);
+    }
+}
+
+impl ZnxNormalizeMiddleStep for FFT64Simd {
+    #[inline(always)]
+    fn znx_normalize_middle_step<const OVERWRITE: bool>(base2k: usize, lsh: usize, x: &mut [i64], a: &[i64], carry: &mut [i64]) {
+        znx_normalize_middle_step_simd::<OVERWRITE>(base2k, lsh, x, a, carry);
+    }
+}
+
+impl ZnxNormalizeFinalStep for FFT64Simd {
+    #[inline(always)]
+    fn znx_normalize_final_step<const OVERWRITE: bool>(base2k: usize, lsh: usize, x: &mut [i64], a: &[i64], carry: &mut [i64]) {
+        znx_normalize_final_step_simd::<OVERWRITE>(base2k, lsh, x, a, carry);
+    }
+}
+
+impl ZnxNormalizeMiddleStepSub for FFT64Simd {
+    #[inline(always)]
+    fn znx_normalize_middle_step_sub(base2k: usize, lsh: usize, x: &mut [i64], a: &[i64], carry: &mut [i64]) {
+        znx_normalize_middle_step_sub_simd(base2k, lsh, x, a, carry);
+    }
+}
+
+impl ZnxNormalizeFinalStepSub for FFT64Simd {
+    #[inline(always)]
+    fn znx_normalize_final_step_sub(base2k: usize, lsh: usize, x: &mut [i64], a: &[i64], carry: &mut [i64]) {
+        znx_normalize_final_step_sub_simd(base2k, lsh, x, a, carry);
+    }
+}
+
+impl ZnxNormalizeFinalStepAssign for FFT64Simd {
+    #[inline(always)]
+    fn znx_normalize_final_step_assign(base2k: usize, lsh: usize, x: &mut [i64], carry: &mut [i64]) {
+        znx_normalize_final_step_assign_simd(base2k, lsh, x, carry);
+    }
+}
+
+impl ZnxNormalizeFirstStepCarryOnly for FFT64Simd {
+    #[inline(always)]
+    fn znx_normalize_first_step_carry_only(base2k: usize, lsh: usize, x: &[i64], carry: &mut [i64]) {
+        znx_normalize_first_step_carry_only_simd(base2k, lsh, x, carry);
+    }
+}
+
+impl ZnxNormalizeFirstStepAssign for FFT64Simd {
+    #[inline(always)]
+    fn znx_normalize_first_step_assign(base2k: usize, lsh: usize, x: &mut [i64], carry: &mut [i64]) {
+        znx_normalize_first_step_assign_simd(base2k, lsh, x, carry);
+    }
+}
+
+impl ZnxNormalizeMiddleStepCarryOnly for FFT64Simd {
+    #[inline(always)]
+    fn znx_normalize_middle_step_carry_only(base2k: usize, lsh: usize, x: &[i64], carry: &mut [i64]) {
+        znx_normalize_middle_step_carry_only_simd(base2k, lsh, x, carry);
+    }
+}
+
+impl ZnxNormalizeMiddleStepAssign for FFT64Simd {
+    #[inline(always)]
+    fn znx_normalize_middle_step_assign(base2k: usize, lsh: usize, x: &mut [i64], carry: &mut [i64]) {
+        znx_normalize_middle_step_assign_simd(base2k, lsh, x, carry);
+    }
+}
+
+impl ZnxExtractDigitAddMul for FFT64Simd {
+    #[inline(always)]
+    fn znx_extract_digit_addmul(base2k: usize, lsh: usize, res: &mut [i64], src: &mut [i64]) {
+        znx_extract_digit_addmul_simd(base2k, lsh, res, src);
+    }
+}
+
+impl ZnxNormalizeDigit for FFT64Simd {
+    #[inline(always)]
+    fn znx_normalize_digit(base2k: usize, res: &mut [i64], src: &mut [i64]) {
+        znx_normalize_digit_simd(base2k, res, src);
+    }
+}
+
+impl ReimFFTExecute<ReimFFTTable<f64>, f64> for FFT64Simd {
+    #[inline(always)]
+    fn reim_dft_execute(table: &ReimFFTTable<f64>, data: &mut [f64]) {
+        ReimFFTSimd::reim_dft_execute(table, data);
+    }
+}
+
+impl ReimFFTExecute<ReimIFFTTable<f64>, f64> for FFT64Simd {
+    #[inline(always)]
+    fn reim_dft_execute(table: &ReimIFFTTable<f64>, data: &mut [f64]) {
+        ReimIFFTSimd::reim_dft_execute(table, data);
+    }
+}
+
+impl ReimArith for FFT64Simd {
+    #[inline(always)]
+    fn reim_from_znx(res: &mut [f64], a: &[i64]) {
+        reim_from_znx_i64_simd(res, a)
+    }
+
+    #[inline(always)]
+    fn reim_from_znx_masked(res: &mut [f64], a: &[i64], mask: i64) {
+        reim_from_znx_i64_masked_simd(res, a, mask)
+    }
+
+    #[inline(always)]
+    fn reim_to_znx(res: &mut [i64], divisor: f64, a: &[f64]) {
+        reim_to_znx_i64_simd(res, divisor, a)
+    }
+
+    #[inline(always)]
+    fn reim_to_znx_assign(res: &mut [f64], divisor: f64) {
+        reim_to_znx_i64_assign_simd(res, divisor)
+    }
+
+    #[inline(always)]
+    fn reim_add(res: &mut [f64], a: &[f64], b: &[f64]) {
+        reim_add_simd(res, a, b)
+    }
+
+    #[inline(always)]
+    fn reim_add_assign(res: &mut [f64], a: &[f64]) {
+        reim_add_assign_simd(res, a)
+    }
+
+    #[inline(always)]
+    fn reim_sub(res: &mut [f64], a: &[f64], b: &[f64]) {
+        reim_sub_simd(res, a, b)
+    }
+
+    #[inline(always)]
+    fn reim_sub_assign(res: &mut [f64], a: &[f64]) {
+        reim_sub_assign_simd(res, a)
+    }
+
+    #[inline(always)]
+    fn reim_sub_negate_assign(res: &mut [f64], a: &[f64]) {
+        reim_sub_negate_assign_simd(res, a)
+    }
+
+    #[inline(always)]
+    fn reim_negate(res: &mut [f64], a: &[f64]) {
+        reim_negate_simd(res, a)
+    }
+
+    #[inline(always)]
+    fn reim_negate_assign(res: &mut [f64]) {
+        reim_negate_assign_simd(res)
+    }
+
+    #[inline(always)]
+    fn reim_mul(res: &mut [f64], a: &[f64], b: &[f64]) {
+        reim_mul_simd(res, a, b)
+    }
+
+    #[inline(always)]
+    fn reim_mul_assign(res: &mut [f64], a: &[f64]) {
+        reim_mul_assign_simd(res, a)
+    }
+
+    #[inline(always)]
+    fn reim_addmul(res: &mut [f64], a: &[f64], b: &[f64]) {
+        reim_addmul_simd(res, a, b)
+    }
+
+    #[inline(always)]
+    fn reim_copy(res: &mut [f64], a: &[f64]) {
+        reim_copy_ref(res, a)
+    }
+
+    #[inline(always)]
+    fn reim_zero(res: &mut [f64]) {
+        reim_zero_ref(res)
+    }
+}
+
+impl Reim4BlkMatVec for FFT64Simd {}
+
+impl Reim4Convolution for FFT64Simd {}
+
+impl I64Ops for FFT64Simd {}
@@ -0,0 +1,307 @@
+use std::simd::{StdFloat, num::SimdFloat, num::SimdInt};
+
+use poulpy_cpu_ref::reference::fft64::reim::{
+    reim_add_assign_ref, reim_add_ref, reim_from_znx_i64_masked_ref, reim_from_znx_i64_ref, reim_negate_assign_ref,
+    reim_negate_ref, reim_sub_assign_ref, reim_sub_negate_assign_ref, reim_sub_ref, reim_to_znx_i64_assign_ref,
+    reim_to_znx_i64_ref,
+};
+
+use crate::znx_simd::I64s;
+
+use super::{F64s, LANES};
+
+/// `res[i] = a[i] as f64`.
+#[inline(always)]
+pub fn reim_from_znx_i64_simd(res: &mut [f64], a: &[i64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(res.len(), a.len())
+    }
+
+    let (rr, rr_tail) = res.as_chunks_mut::<LANES>();
+    let (aa, aa_tail) = a.as_chunks::<LANES>();
+
+    for (r, a) in rr.iter_mut().zip(aa) {
+        *r = I64s::from_array(*a).cast::<f64>().to_array();
+    }
+
+    reim_from_znx_i64_ref(rr_tail, aa_tail);
+}
+
+/// `res[i] = (a[i] & mask) as f64`.
+#[inline(always)]
+pub fn reim_from_znx_i64_masked_simd(res: &mut [f64], a: &[i64], mask: i64) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(res.len(), a.len())
+    }
+
+    let mask_vec: I64s = I64s::splat(mask);
+    let (rr, rr_tail) = res.as_chunks_mut::<LANES>();
+    let (aa, aa_tail) = a.as_chunks::<LANES>();
+
+    for (r, a) in rr.iter_mut().zip(aa) {
+        *r = (I64s::from_array(*a) & mask_vec).cast::<f64>().to_array();
+    }
+
+    reim_from_znx_i64_masked_ref(rr_tail, aa_tail, mask);
+}
+
+/// `res[i] = round(a[i] / divisor)`.
+#[inline(always)]
+pub fn reim_to_znx_i64_simd(res: &mut [i64], divisor: f64, a: &[f64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(res.len(), a.len())
+    }
+
+    let inv_div: F64s = F64s::splat(1. / divisor);
+    let (rr, rr_tail) = res.as_chunks_mut::<LANES>();
+    let (aa, aa_tail) = a.as_chunks::<LANES>();
+
+    for (r, a) in rr.iter_mut().zip(aa) {
+        *r = (F64s::from_array(*a) * inv_div).round().cast::<i64>().to_array();
+    }
+
+    reim_to_znx_i64_ref(rr_tail, divisor, aa_tail);
+}
+
+/// In-place variant of [`reim_to_znx_i64_simd`], storing the `i64` bit patterns in `res`.
+#[inline(always)]
+pub fn reim_to_znx_i64_assign_simd(res: &mut [f64], divisor: f64) {
+    let inv_div: F64s = F64s::splat(1. / divisor);
+    let (rr, rr_tail) = res.as_chunks_mut::<LANES>();
+
+    for r in rr.iter_mut() {
+        let x: I64s = (F64s::from_array(*r) * inv_div).round().cast::<i64>();
+        *r = F64s::from_bits(x.cast::<u64>()).to_array();
+    }
+
+    reim_to_znx_i64_assign_ref(rr_tail, divisor);
+}
+
+/// `res[i] = a[i] + b[i]`.
+#[inline(always)]
+pub fn reim_add_simd(res: &mut [f64], a: &[f64], b: &[f64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.len(), res.len());
+        assert_eq!(b.len(), res.len());
+    }
+
+    let (rr, rr_tail) = res.as_chunks_mut::<LANES>();
+    let (aa, aa_tail) = a.as_chunks::<LANES>();
+    let (bb, bb_tail) = b.as_chunks::<LANES>();
+
+    for ((r, a), b) in rr.iter_mut().zip(aa).zip(bb) {
+        *r = (F64s::from_array(*a) + F64s::from_array(*b)).to_array();
+    }
+
+    reim_add_ref(rr_tail, aa_tail, bb_tail);
+}
+
+/// `res[i] += a[i]`.
+#[inline(always)]
+pub fn reim_add_assign_simd(res: &mut [f64], a: &[f64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.len(), res.len());
+    }
+
+    let (rr, rr_tail) = res.as_chunks_mut::<LANES>();
+    let (aa, aa_tail) = a.as_chunks::<LANES>();
+
+    for (r, a) in rr.iter_mut().zip(aa) {
+        *r = (F64s::from_array(*r) + F64s::from_array(*a)).to_array();
+    }
+
+    reim_add_assign_ref(rr_tail, aa_tail);
+}
+
+/// `res[i] = a[i] - b[i]`.
+#[inline(always)]
+pub fn reim_sub_simd(res: &mut [f64], a: &[f64], b: &[f64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.len(), res.len());
+        assert_eq!(b.len(), res.len());
+    }
+
+    let (rr, rr_tail) = res.as_chunks_mut::<LANES>();
+    let (aa, aa_tail) = a.as_chunks::<LANES>();
+    let (bb, bb_tail) = b.as_chunks::<LANES>();
+
+    for ((r, a), b) in rr.iter_mut().zip(aa).zip(bb) {
+        *r = (F64s::from_array(*a) - F64s::from_array(*b)).to_array();
+    }
+
+    reim_sub_ref(rr_tail, aa_tail, bb_tail);
+}
+
+/// `res[i] -= a[i]`.
+#[inline(always)]
+pub fn reim_sub_assign_simd(res: &mut [f64], a: &[f64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.len(), res.len());
+    }
+
+    let (rr, rr_tail) = res.as_chunks_mut::<LANES>();
+    let (aa, aa_tail) = a.as_chunks::<LANES>();
+
+    for (r, a) in rr.iter_mut().zip(aa) {
+        *r = (F64s::from_array(*r) - F64s::from_array(*a)).to_array();
+    }
+
+    reim_sub_assign_ref(rr_tail, aa_tail);
+}
+
+/// `res[i] = a[i] - res[i]`.
+#[inline(always)]
+pub fn reim_sub_negate_assign_simd(res: &mut [f64], a: &[f64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.len(), res.len());
+    }
+
+    let (rr, rr_tail) = res.as_chunks_mut::<LANES>();
+    let (aa, aa_tail) = a.as_chunks::<LANES>();
+
+    for (r, a) in rr.iter_mut().zip(aa) {
+        *r = (F64s::from_array(*a) - F64s::from_array(*r)).to_array();
+    }
+
+    reim_sub_negate_assign_ref(rr_tail, aa_tail);
+}
+
+/// `res[i] = -a[i]`.
+#[inline(always)]
+pub fn reim_negate_simd(res: &mut [f64], a: &[f64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.len(), res.len());
+    }
+
+    let (rr, rr_tail) = res.as_chunks_mut::<LANES>();
+    let (aa, aa_tail) = a.as_chunks::<LANES>();
+
+    for (r, a) in rr.iter_mut().zip(aa) {
+        *r = (-F64s::from_array(*a)).to_array();
+    }
+
+    reim_negate_ref(rr_tail, aa_tail);
+}
+
+/// `res[i] = -res[i]`.
+#[inline(always)]
+pub fn reim_negate_assign_simd(res: &mut [f64]) {
+    let (rr, rr_tail) = res.as_chunks_mut::<LANES>();
+
+    for r in rr.iter_mut() {
+        *r = (-F64s::from_array(*r)).to_array();
+    }
+
+    reim_negate_assign_ref(rr_tail);
+}
+
+/// Complex product `(ar + i·ai)·(br + i·bi)`, evaluated in the same order as the reference.
+#[inline(always)]
+fn cplx_mul(ar: F64s, ai: F64s, br: F64s, bi: F64s) -> (F64s, F64s) {
+    (ar * br - ai * bi, ar * bi + ai * br)
+}
+
+/// Scalar counterpart of [`cplx_mul`] for the tails.
+#[inline(always)]
+fn cplx_mul_scalar(ar: f64, ai: f64, br: f64, bi: f64) -> (f64, f64) {
+    (ar * br - ai * bi, ar * bi + ai * br)
+}
+
+/// `res = a ⊙ b` on the split `[re | im]` layout.
+#[inline(always)]
+pub fn reim_mul_simd(res: &mut [f64], a: &[f64], b: &[f64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.len(), res.len());
+        assert_eq!(b.len(), res.len());
+    }
+
+    let m: usize = res.len() >> 1;
+    let (rr, ri) = res.split_at_mut(m);
+    let (ar, ai) = a.split_at(m);
+    let (br, bi) = b.split_at(m);
+
+    let tail: usize = m - m % LANES;
+    for i in (0..tail).step_by(LANES) {
+        let (xr, xi) = cplx_mul(
+            F64s::from_slice(&ar[i..]),
+            F64s::from_slice(&ai[i..]),
+            F64s::from_slice(&br[i..]),
+            F64s::from_slice(&bi[i..]),
+        );
+        xr.copy_to_slice(&mut rr[i..]);
+        xi.copy_to_slice(&mut ri[i..]);
+    }
+    for i in tail..m {
+        (rr[i], ri[i]) = cplx_mul_scalar(ar[i], ai[i], br[i], bi[i]);
+    }
+}
+
+/// `res = a ⊙ res` on the split `[re | im]` layout.
+#[inline(always)]
+pub fn reim_mul_assign_simd(res: &mut [f64], a: &[f64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.len(), res.len());
+    }
+
+    let m: usize = res.len() >> 1;
+    let (rr, ri) = res.split_at_mut(m);
+    let (ar, ai) = a.split_at(m);
+
+    let tail: usize = m - m % LANES;
+    for i in (0..tail).step_by(LANES) {
+        let (xr, xi) = cplx_mul(
+            F64s::from_slice(&ar[i..]),
+            F64s::from_slice(&ai[i..]),
+            F64s::from_slice(&rr[i..]),
+            F64s::from_slice(&ri[i..]),
+        );
+        xr.copy_to_slice(&mut rr[i..]);
+        xi.copy_to_slice(&mut ri[i..]);
+    }
+    for i in tail..m {
+        (rr[i], ri[i]) = cplx_mul_scalar(ar[i], ai[i], rr[i], ri[i]);
+    }
+}
+
+/// `res += a ⊙ b` on the split `[re | im]` layout.
+#[inline(always)]
+pub fn reim_addmul_simd(res: &mut [f64], a: &[f64], b: &[f64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(a.len(), res.len());
+        assert_eq!(b.len(), res.len());
+    }
+
+    let m: usize = res.len() >> 1;
+    let (rr, ri) = res.split_at_mut(m);
+    let (ar, ai) = a.split_at(m);
+    let (br, bi) = b.split_at(m);
+
+    let tail: usize = m - m % LANES;
+    for i in (0..tail).step_by(LANES) {
+        let (xr, xi) = cplx_mul(
+            F64s::from_slice(&ar[i..]),
+            F64s::from_slice(&ai[i..]),
+            F64s::from_slice(&br[i..]),
+            F64s::from_slice(&bi[i..]),
+        );
+        (F64s::from_slice(&rr[i..]) + xr).copy_to_slice(&mut rr[i..]);
+        (F64s::from_slice(&ri[i..]) + xi).copy_to_slice(&mut ri[i..]);
+    }
+    for i in tail..m {
+        let (xr, xi) = cplx_mul_scalar(ar[i], ai[i], br[i], bi[i]);
+        rr[i] += xr;
+        ri[i] += xi;
+    }
+}
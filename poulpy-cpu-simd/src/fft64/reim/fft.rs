@@ -0,0 +1,208 @@
+use std::simd::simd_swizzle;
+
+use poulpy_cpu_ref::reference::fft64::reim::fft_ref;
+
+use super::{F64s, LANES, load, store};
+
+/// Forward FFT of `data = [re | im]` (length `2m`) with the twiddles of a
+/// [`ReimFFTTable`](poulpy_cpu_ref::reference::fft64::reim::ReimFFTTable).
+///
+/// Sizes below 16 have no full vector pass and are delegated to [`fft_ref`].
+pub(crate) fn fft_simd(m: usize, omg: &[f64], data: &mut [f64]) {
+    assert!(data.len() == 2 * m);
+
+    if m < 16 {
+        fft_ref(m, omg, data);
+        return;
+    }
+
+    let (re, im) = data.split_at_mut(m);
+
+    if m <= 2048 {
+        fft_bfs_16_simd(m, re, im, omg, 0);
+    } else {
+        fft_rec_16_simd(m, re, im, omg, 0);
+    }
+}
+
+fn fft_rec_16_simd(m: usize, re: &mut [f64], im: &mut [f64], omg: &[f64], mut pos: usize) -> usize {
+    if m <= 2048 {
+        return fft_bfs_16_simd(m, re, im, omg, pos);
+    };
+
+    let h: usize = m >> 1;
+    twiddle_fft_simd(h, re, im, omg[pos], omg[pos + 1]);
+    pos += 2;
+    pos = fft_rec_16_simd(h, re, im, omg, pos);
+    pos = fft_rec_16_simd(h, &mut re[h..], &mut im[h..], omg, pos);
+    pos
+}
+
+fn fft_bfs_16_simd(m: usize, re: &mut [f64], im: &mut [f64], omg: &[f64], mut pos: usize) -> usize {
+    let log_m: usize = (usize::BITS - (m - 1).leading_zeros()) as usize;
+    let mut mm: usize = m;
+
+    if !log_m.is_multiple_of(2) {
+        let h: usize = mm >> 1;
+        twiddle_fft_simd(h, re, im, omg[pos], omg[pos + 1]);
+        pos += 2;
+        mm = h
+    }
+
+    while mm > 16 {
+        let h: usize = mm >> 2;
+        for off in (0..m).step_by(mm) {
+            bitwiddle_fft_simd(h, &mut re[off..off + mm], &mut im[off..off + mm], &omg[pos..pos + 4]);
+            pos += 4;
+        }
+        mm = h
+    }
+
+    for off in (0..m).step_by(16) {
+        fft16_simd(&mut re[off..off + 16], &mut im[off..off + 16], &omg[pos..pos + 16]);
+        pos += 16;
+    }
+
+    pos
+}
+
+/// Vectorized `cplx_twiddle`: `(a, b) <- (a + w·b, a - w·b)` lane-wise.
+///
+/// `cplx_i_twiddle` with `w = (wr, wi)` is the same butterfly with
+/// `w = (-wi, wr)`; since negation is exact, both are served by this kernel.
+#[inline(always)]
+fn twiddle(ra: &mut F64s, ia: &mut F64s, rb: &mut F64s, ib: &mut F64s, wr: F64s, wi: F64s) {
+    let dr: F64s = *rb * wr - *ib * wi;
+    let di: F64s = *rb * wi + *ib * wr;
+    *rb = *ra - dr;
+    *ib = *ia - di;
+    *ra += dr;
+    *ia += di;
+}
+
+#[inline(always)]
+fn twiddle_fft_simd(h: usize, re: &mut [f64], im: &mut [f64], wr: f64, wi: f64) {
+    let (wr, wi): (F64s, F64s) = (F64s::splat(wr), F64s::splat(wi));
+    let (re_lhs, re_rhs) = re.split_at_mut(h);
+    let (im_lhs, im_rhs) = im.split_at_mut(h);
+
+    for i in (0..h).step_by(LANES) {
+        let (mut ra, mut ia) = (load(re_lhs, i), load(im_lhs, i));
+        let (mut rb, mut ib) = (load(re_rhs, i), load(im_rhs, i));
+        twiddle(&mut ra, &mut ia, &mut rb, &mut ib, wr, wi);
+        store(re_lhs, i, ra);
+        store(im_lhs, i, ia);
+        store(re_rhs, i, rb);
+        store(im_rhs, i, ib);
+    }
+}
+
+#[inline(always)]
+fn bitwiddle_fft_simd(h: usize, re: &mut [f64], im: &mut [f64], omg: &[f64]) {
+    let (w0r, w0i): (F64s, F64s) = (F64s::splat(omg[0]), F64s::splat(omg[1]));
+    let (w1r, w1i): (F64s, F64s) = (F64s::splat(omg[2]), F64s::splat(omg[3]));
+    let (w1r_i, w1i_i): (F64s, F64s) = (-w1i, w1r);
+
+    for i in (0..h).step_by(LANES) {
+        let (mut r0, mut i0) = (load(re, i), load(im, i));
+        let (mut r1, mut i1) = (load(re, h + i), load(im, h + i));
+        let (mut r2, mut i2) = (load(re, 2 * h + i), load(im, 2 * h + i));
+        let (mut r3, mut i3) = (load(re, 3 * h + i), load(im, 3 * h + i));
+
+        twiddle(&mut r0, &mut i0, &mut r2, &mut i2, w0r, w0i);
+        twiddle(&mut r1, &mut i1, &mut r3, &mut i3, w0r, w0i);
+        twiddle(&mut r0, &mut i0, &mut r1, &mut i1, w1r, w1i);
+        twiddle(&mut r2, &mut i2, &mut r3, &mut i3, w1r_i, w1i_i);
+
+        store(re, i, r0);
+        store(im, i, i0);
+        store(re, h + i, r1);
+        store(im, h + i, i1);
+        store(re, 2 * h + i, r2);
+        store(im, 2 * h + i, i2);
+        store(re, 3 * h + i, r3);
+        store(im, 3 * h + i, i3);
+    }
+}
+
+/// 16-point forward FFT, holding the block in four vectors per component.
+#[inline(always)]
+fn fft16_simd(re: &mut [f64], im: &mut [f64], omg: &[f64]) {
+    let o: &[f64; 16] = omg.try_into().unwrap();
+
+    let (mut r0, mut r1, mut r2, mut r3) = (load(re, 0), load(re, 4), load(re, 8), load(re, 12));
+    let (mut i0, mut i1, mut i2, mut i3) = (load(im, 0), load(im, 4), load(im, 8), load(im, 12));
+
+    // (j, j + 8) by omg[0..2].
+    {
+        let (wr, wi): (F64s, F64s) = (F64s::splat(o[0]), F64s::splat(o[1]));
+        twiddle(&mut r0, &mut i0, &mut r2, &mut i2, wr, wi);
+        twiddle(&mut r1, &mut i1, &mut r3, &mut i3, wr, wi);
+    }
+
+    // (j, j + 4) by omg[2..4], i-twiddled on the upper half.
+    {
+        let (wr, wi): (F64s, F64s) = (F64s::splat(o[2]), F64s::splat(o[3]));
+        twiddle(&mut r0, &mut i0, &mut r1, &mut i1, wr, wi);
+        twiddle(&mut r2, &mut i2, &mut r3, &mut i3, -wi, wr);
+    }
+
+    // (j, j + 2) by omg[4..6] (lower half) and omg[6..8] (upper half).
+    {
+        let layer = |r0: &mut F64s, i0: &mut F64s, r1: &mut F64s, i1: &mut F64s, w0: f64, w1: f64| {
+            let mut ra: F64s = simd_swizzle!(*r0, *r1, [0, 1, 4, 5]);
+            let mut rb: F64s = simd_swizzle!(*r0, *r1, [2, 3, 6, 7]);
+            let mut ia: F64s = simd_swizzle!(*i0, *i1, [0, 1, 4, 5]);
+            let mut ib: F64s = simd_swizzle!(*i0, *i1, [2, 3, 6, 7]);
+            let wr: F64s = F64s::from_array([w0, w0, -w1, -w1]);
+            let wi: F64s = F64s::from_array([w1, w1, w0, w0]);
+            twiddle(&mut ra, &mut ia, &mut rb, &mut ib, wr, wi);
+            *r0 = simd_swizzle!(ra, rb, [0, 1, 4, 5]);
+            *r1 = simd_swizzle!(ra, rb, [2, 3, 6, 7]);
+            *i0 = simd_swizzle!(ia, ib, [0, 1, 4, 5]);
+            *i1 = simd_swizzle!(ia, ib, [2, 3, 6, 7]);
+        };
+        layer(&mut r0, &mut i0, &mut r1, &mut i1, o[4], o[5]);
+        layer(&mut r2, &mut i2, &mut r3, &mut i3, o[6], o[7]);
+    }
+
+    // (j, j + 1) by omg[8..16].
+    {
+        let layer = |r0: &mut F64s, i0: &mut F64s, r1: &mut F64s, i1: &mut F64s, wr: [f64; 4], wi: [f64; 4]| {
+            let mut ra: F64s = simd_swizzle!(*r0, *r1, [0, 2, 4, 6]);
+            let mut rb: F64s = simd_swizzle!(*r0, *r1, [1, 3, 5, 7]);
+            let mut ia: F64s = simd_swizzle!(*i0, *i1, [0, 2, 4, 6]);
+            let mut ib: F64s = simd_swizzle!(*i0, *i1, [1, 3, 5, 7]);
+            twiddle(&mut ra, &mut ia, &mut rb, &mut ib, F64s::from_array(wr), F64s::from_array(wi));
+            *r0 = simd_swizzle!(ra, rb, [0, 4, 1, 5]);
+            *r1 = simd_swizzle!(ra, rb, [2, 6, 3, 7]);
+            *i0 = simd_swizzle!(ia, ib, [0, 4, 1, 5]);
+            *i1 = simd_swizzle!(ia, ib, [2, 6, 3, 7]);
+        };
+        layer(
+            &mut r0,
+            &mut i0,
+            &mut r1,
+            &mut i1,
+            [o[8], -o[12], o[9], -o[13]],
+            [o[12], o[8], o[13], o[9]],
+        );
+        layer(
+            &mut r2,
+            &mut i2,
+            &mut r3,
+            &mut i3,
+            [o[10], -o[14], o[11], -o[15]],
+            [o[14], o[10], o[15], o[11]],
+        );
+    }
+
+    store(re, 0, r0);
+    store(re, 4, r1);
+    store(re, 8, r2);
+    store(re, 12, r3);
+    store(im, 0, i0);
+    store(im, 4, i1);
+    store(im, 8, i2);
+    store(im, 12, i3);
+}
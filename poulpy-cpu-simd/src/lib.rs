@@ -0,0 +1,55 @@
+//! Portable SIMD CPU backends for the Poulpy lattice cryptography library.
+//!
+//! This crate provides [`FFT64Simd`], a backend implementation for [`poulpy_hal`]
+//! whose hot kernels are written against the nightly `std::simd` API instead of
+//! architecture-specific intrinsics. It compiles for any target supported by `rustc`
+//! and needs neither `-C target-feature` flags nor runtime CPU detection.
+//!
+//! # Architecture
+//!
+//! Like `poulpy-cpu-avx`, the crate implements the HAL open extension points through
+//! the shared `poulpy_cpu_ref::hal_defaults` and only overrides the primitive kernel
+//! traits of `poulpy_cpu_ref::reference`:
+//!
+//! | Module          | Domain                                                    |
+//! |-----------------|-----------------------------------------------------------|
+//! | `znx_simd`      | Single ring element (`Z[X]/(X^n+1)`) SIMD arithmetic      |
+//! | `fft64::reim`   | Real/imaginary split FFT/IFFT and pointwise arithmetic    |
+//! | `hal_impl`      | HAL wiring for the FFT64 backend family                   |
+//!
+//! Kernels without a SIMD version (e.g. the reim4 mat-vec products) fall back to the
+//! scalar reference implementation. See the crate README for measurements against
+//! [`FFT64Ref`](poulpy_cpu_ref::FFT64Ref).
+//!
+//! # Correctness guarantees
+//!
+//! Every SIMD kernel evaluates the same operations in the same order as its scalar
+//! counterpart, without fused multiply-add, so results are **bit-identical** to
+//! [`FFT64Ref`](poulpy_cpu_ref::FFT64Ref). Prepared layouts can therefore be exchanged
+//! freely with the reference backend.
+//!
+//! # Performance
+//!
+//! The vector width used by the generated code follows the target features enabled at
+//! build time: SSE2 on a generic `x86_64` build, AVX2 with `-C target-cpu=native` on a
+//! recent x86 host, NEON on `aarch64`. On x86_64 hosts with AVX2 and FMA, the
+//! hand-tuned `poulpy-cpu-avx` backends remain faster.
+//!
+//! # Toolchain
+//!
+//! `std::simd` is unstable; the crate enables `#![feature(portable_simd)]` and builds
+//! with the nightly toolchain pinned by the workspace.
+
+#![feature(portable_simd)]
+
+mod fft64;
+mod hal_impl;
+mod znx_simd;
+
+pub use fft64::{FFT64Simd, ReimFFTSimd, ReimIFFTSimd};
+
+use poulpy_core::oep::CoreImpl;
+
+unsafe impl CoreImpl<FFT64Simd> for FFT64Simd {
+    poulpy_core::impl_core_default_methods!(FFT64Simd);
+}
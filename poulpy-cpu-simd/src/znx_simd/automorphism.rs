@@ -0,0 +1,59 @@
+use std::simd::{Select, Simd, cmp::SimdPartialOrd, usizex4};
+
+use poulpy_cpu_ref::reference::znx::znx_automorphism_ref;
+
+use super::{I64s, LANES};
+
+/// Computes `p^{-1} mod 2^bits` for odd `p` by Hensel lifting.
+#[inline]
+fn inv_mod_pow2(p: usize, bits: u32) -> usize {
+    debug_assert!(p % 2 == 1);
+    let mut x: usize = 1usize;
+    let mut i: u32 = 1;
+    while i < bits {
+        x = x.wrapping_mul(2usize.wrapping_sub(p.wrapping_mul(x)));
+        i <<= 1;
+    }
+    x & ((1usize << bits) - 1)
+}
+
+/// `res = a(X^p)` in `Z[X]/(X^n+1)`.
+///
+/// Each output coefficient `j` is gathered from the input coefficient
+/// `t = j · p^{-1} mod 2n` (negated when `t >= n`), so the output is written
+/// in order, one vector at a time.
+pub fn znx_automorphism_simd(p: i64, res: &mut [i64], a: &[i64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(res.len(), a.len());
+    }
+
+    let n: usize = res.len();
+
+    if n < LANES {
+        znx_automorphism_ref(p, res, a);
+        return;
+    }
+
+    debug_assert!(n.is_power_of_two(), "polynomial degree {n} must be a power of two");
+    debug_assert!(p & 1 == 1, "p must be odd (invertible mod 2n)");
+
+    let two_n: usize = n << 1;
+    let mask_2n: usize = two_n - 1;
+    let p_2n: usize = (p & mask_2n as i64) as usize;
+    let inv: usize = inv_mod_pow2(p_2n, two_n.trailing_zeros());
+
+    let mask_2n_vec: usizex4 = usizex4::splat(mask_2n);
+    let mask_1n_vec: usizex4 = usizex4::splat(n - 1);
+    let n_vec: usizex4 = usizex4::splat(n);
+    let lane_offsets: usizex4 = usizex4::from_array([0, inv, (2 * inv) & mask_2n, (3 * inv) & mask_2n]);
+    let step: usize = (LANES * inv) & mask_2n;
+
+    let mut t_base: usize = 0;
+    for r in res.as_chunks_mut::<LANES>().0.iter_mut() {
+        let t: usizex4 = (Simd::splat(t_base) + lane_offsets) & mask_2n_vec;
+        let vals: I64s = I64s::gather_or_default(a, t & mask_1n_vec);
+        *r = t.simd_ge(n_vec).cast::<i64>().select(-vals, vals).to_array();
+        t_base = (t_base + step) & mask_2n;
+    }
+}
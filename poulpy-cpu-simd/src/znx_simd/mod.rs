@@ -0,0 +1,26 @@
+//! Portable SIMD kernels for single ring elements (`Z[X]/(X^n+1)`).
+//!
+//! Every kernel processes the slices in chunks of [`LANES`] coefficients with
+//! `std::simd` vectors and hands the remaining tail to the matching `_ref`
+//! function of `poulpy_cpu_ref::reference::znx`, so results are identical to
+//! the reference backend for any length.
+
+use std::simd::i64x4;
+
+mod add;
+mod automorphism;
+mod neg;
+mod normalization;
+mod sub;
+
+pub(crate) use add::*;
+pub(crate) use automorphism::*;
+pub(crate) use neg::*;
+pub(crate) use normalization::*;
+pub(crate) use sub::*;
+
+/// Number of `i64` coefficients processed per vector.
+pub(crate) const LANES: usize = 4;
+
+/// Vector of [`LANES`] `i64` coefficients.
+pub(crate) type I64s = i64x4;
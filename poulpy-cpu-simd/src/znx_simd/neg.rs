@@ -0,0 +1,33 @@
+use poulpy_cpu_ref::reference::znx::{znx_negate_assign_ref, znx_negate_ref};
+
+use super::{I64s, LANES};
+
+/// `res[i] = -src[i]`.
+#[inline(always)]
+pub fn znx_negate_simd(res: &mut [i64], src: &[i64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(res.len(), src.len());
+    }
+
+    let (rr, rr_tail) = res.as_chunks_mut::<LANES>();
+    let (ss, ss_tail) = src.as_chunks::<LANES>();
+
+    for (r, s) in rr.iter_mut().zip(ss) {
+        *r = (-I64s::from_array(*s)).to_array();
+    }
+
+    znx_negate_ref(rr_tail, ss_tail);
+}
+
+/// `res[i] = -res[i]`.
+#[inline(always)]
+pub fn znx_negate_assign_simd(res: &mut [i64]) {
+    let (rr, rr_tail) = res.as_chunks_mut::<LANES>();
+
+    for r in rr.iter_mut() {
+        *r = (-I64s::from_array(*r)).to_array();
+    }
+
+    znx_negate_assign_ref(rr_tail);
+}
@@ -0,0 +1,475 @@
+use poulpy_cpu_ref::reference::znx::{
+    znx_extract_digit_addmul_ref, znx_normalize_digit_ref, znx_normalize_final_step_assign_ref, znx_normalize_final_step_ref,
+    znx_normalize_final_step_sub_ref, znx_normalize_first_step_assign_ref, znx_normalize_first_step_carry_only_ref,
+    znx_normalize_first_step_ref, znx_normalize_middle_step_assign_ref, znx_normalize_middle_step_carry_only_ref,
+    znx_normalize_middle_step_ref, znx_normalize_middle_step_sub_ref,
+};
+
+use super::{I64s, LANES};
+
+/// Vectorized `get_digit_i64` / `get_carry_i64` for a fixed digit size `k`.
+#[derive(Clone, Copy)]
+struct Digits {
+    digit_shift: I64s,
+    carry_shift: I64s,
+}
+
+impl Digits {
+    #[inline(always)]
+    fn new(k: usize) -> Self {
+        Self {
+            digit_shift: I64s::splat((u64::BITS as usize - k) as i64),
+            carry_shift: I64s::splat(k as i64),
+        }
+    }
+
+    /// Balanced digit of `x` in `[-2^{k-1}, 2^{k-1})`.
+    #[inline(always)]
+    fn digit(self, x: I64s) -> I64s {
+        (x << self.digit_shift) >> self.digit_shift
+    }
+
+    /// `(x - digit) >> k`.
+    #[inline(always)]
+    fn carry(self, x: I64s, digit: I64s) -> I64s {
+        (x - digit) >> self.carry_shift
+    }
+}
+
+#[inline(always)]
+pub fn znx_normalize_first_step_carry_only_simd(base2k: usize, lsh: usize, x: &[i64], carry: &mut [i64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert!(x.len() <= carry.len());
+        assert!(lsh < base2k);
+    }
+
+    let n: usize = x.len();
+    let d_lsh: Digits = Digits::new(base2k - lsh);
+
+    let (xx, xx_tail) = x.as_chunks::<LANES>();
+    let (cc, cc_tail) = carry[..n].as_chunks_mut::<LANES>();
+
+    for (x, c) in xx.iter().zip(cc.iter_mut()) {
+        let xv: I64s = I64s::from_array(*x);
+        *c = d_lsh.carry(xv, d_lsh.digit(xv)).to_array();
+    }
+
+    znx_normalize_first_step_carry_only_ref(base2k, lsh, xx_tail, cc_tail);
+}
+
+#[inline(always)]
+pub fn znx_normalize_first_step_assign_simd(base2k: usize, lsh: usize, x: &mut [i64], carry: &mut [i64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert!(x.len() <= carry.len());
+        assert!(lsh < base2k);
+    }
+
+    let n: usize = x.len();
+    let d_lsh: Digits = Digits::new(base2k - lsh);
+    let lsh_vec: I64s = I64s::splat(lsh as i64);
+
+    let (xx, xx_tail) = x.as_chunks_mut::<LANES>();
+    let (cc, cc_tail) = carry[..n].as_chunks_mut::<LANES>();
+
+    for (x, c) in xx.iter_mut().zip(cc.iter_mut()) {
+        let xv: I64s = I64s::from_array(*x);
+        let digit: I64s = d_lsh.digit(xv);
+        *c = d_lsh.carry(xv, digit).to_array();
+        *x = (digit << lsh_vec).to_array();
+    }
+
+    znx_normalize_first_step_assign_ref(base2k, lsh, xx_tail, cc_tail);
+}
+
+#[inline(always)]
+pub fn znx_normalize_first_step_simd<const OVERWRITE: bool>(
+    base2k: usize,
+    lsh: usize,
+    x: &mut [i64],
+    a: &[i64],
+    carry: &mut [i64],
+) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(x.len(), a.len());
+        assert!(x.len() <= carry.len());
+        assert!(lsh < base2k);
+    }
+
+    let n: usize = x.len();
+    let d_lsh: Digits = Digits::new(base2k - lsh);
+    let lsh_vec: I64s = I64s::splat(lsh as i64);
+
+    let (xx, xx_tail) = x.as_chunks_mut::<LANES>();
+    let (aa, aa_tail) = a.as_chunks::<LANES>();
+    let (cc, cc_tail) = carry[..n].as_chunks_mut::<LANES>();
+
+    for ((x, a), c) in xx.iter_mut().zip(aa).zip(cc.iter_mut()) {
+        let av: I64s = I64s::from_array(*a);
+        let digit: I64s = d_lsh.digit(av);
+        *c = d_lsh.carry(av, digit).to_array();
+        let out: I64s = digit << lsh_vec;
+        *x = if OVERWRITE { out } else { I64s::from_array(*x) + out }.to_array();
+    }
+
+    znx_normalize_first_step_ref::<OVERWRITE>(base2k, lsh, xx_tail, aa_tail, cc_tail);
+}
+
+#[inline(always)]
+pub fn znx_normalize_middle_step_carry_only_simd(base2k: usize, lsh: usize, x: &[i64], carry: &mut [i64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert!(x.len() <= carry.len());
+        assert!(lsh < base2k);
+    }
+
+    let n: usize = x.len();
+    let d: Digits = Digits::new(base2k);
+    let d_lsh: Digits = Digits::new(base2k - lsh);
+    let lsh_vec: I64s = I64s::splat(lsh as i64);
+
+    let (xx, xx_tail) = x.as_chunks::<LANES>();
+    let (cc, cc_tail) = carry[..n].as_chunks_mut::<LANES>();
+
+    for (x, c) in xx.iter().zip(cc.iter_mut()) {
+        let xv: I64s = I64s::from_array(*x);
+        let digit: I64s = d_lsh.digit(xv);
+        let carry: I64s = d_lsh.carry(xv, digit);
+        let digit_plus_c: I64s = (digit << lsh_vec) + I64s::from_array(*c);
+        *c = (carry + d.carry(digit_plus_c, d.digit(digit_plus_c))).to_array();
+    }
+
+    znx_normalize_middle_step_carry_only_ref(base2k, lsh, xx_tail, cc_tail);
+}
+
+#[inline(always)]
+pub fn znx_normalize_middle_step_assign_simd(base2k: usize, lsh: usize, x: &mut [i64], carry: &mut [i64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert!(x.len() <= carry.len());
+        assert!(lsh < base2k);
+    }
+
+    let n: usize = x.len();
+    let d: Digits = Digits::new(base2k);
+    let d_lsh: Digits = Digits::new(base2k - lsh);
+    let lsh_vec: I64s = I64s::splat(lsh as i64);
+
+    let (xx, xx_tail) = x.as_chunks_mut::<LANES>();
+    let (cc, cc_tail) = carry[..n].as_chunks_mut::<LANES>();
+
+    for (x, c) in xx.iter_mut().zip(cc.iter_mut()) {
+        let xv: I64s = I64s::from_array(*x);
+        let digit: I64s = d_lsh.digit(xv);
+        let carry: I64s = d_lsh.carry(xv, digit);
+        let digit_plus_c: I64s = (digit << lsh_vec) + I64s::from_array(*c);
+        let x1: I64s = d.digit(digit_plus_c);
+        *x = x1.to_array();
+        *c = (carry + d.carry(digit_plus_c, x1)).to_array();
+    }
+
+    znx_normalize_middle_step_assign_ref(base2k, lsh, xx_tail, cc_tail);
+}
+
+#[inline(always)]
+pub fn znx_normalize_middle_step_simd<const OVERWRITE: bool>(
+    base2k: usize,
+    lsh: usize,
+    x: &mut [i64],
+    a: &[i64],
+    carry: &mut [i64],
+) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(x.len(), a.len());
+        assert!(x.len() <= carry.len());
+        assert!(lsh < base2k);
+    }
+
+    let n: usize = x.len();
+    let d: Digits = Digits::new(base2k);
+    let d_lsh: Digits = Digits::new(base2k - lsh);
+    let lsh_vec: I64s = I64s::splat(lsh as i64);
+
+    let (xx, xx_tail) = x.as_chunks_mut::<LANES>();
+    let (aa, aa_tail) = a.as_chunks::<LANES>();
+    let (cc, cc_tail) = carry[..n].as_chunks_mut::<LANES>();
+
+    for ((x, a), c) in xx.iter_mut().zip(aa).zip(cc.iter_mut()) {
+        let av: I64s = I64s::from_array(*a);
+        let digit: I64s = d_lsh.digit(av);
+        let carry: I64s = d_lsh.carry(av, digit);
+        let digit_plus_c: I64s = (digit << lsh_vec) + I64s::from_array(*c);
+        let x1: I64s = d.digit(digit_plus_c);
+        *x = if OVERWRITE { x1 } else { I64s::from_array(*x) + x1 }.to_array();
+        *c = (carry + d.carry(digit_plus_c, x1)).to_array();
+    }
+
+    znx_normalize_middle_step_ref::<OVERWRITE>(base2k, lsh, xx_tail, aa_tail, cc_tail);
+}
+
+#[inline(always)]
+pub fn znx_normalize_middle_step_sub_simd(base2k: usize, lsh: usize, x: &mut [i64], a: &[i64], carry: &mut [i64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(x.len(), a.len());
+        assert!(x.len() <= carry.len());
+        assert!(lsh < base2k);
+    }
+
+    let n: usize = x.len();
+    let d: Digits = Digits::new(base2k);
+    let d_lsh: Digits = Digits::new(base2k - lsh);
+    let lsh_vec: I64s = I64s::splat(lsh as i64);
+
+    let (xx, xx_tail) = x.as_chunks_mut::<LANES>();
+    let (aa, aa_tail) = a.as_chunks::<LANES>();
+    let (cc, cc_tail) = carry[..n].as_chunks_mut::<LANES>();
+
+    for ((x, a), c) in xx.iter_mut().zip(aa).zip(cc.iter_mut()) {
+        let av: I64s = I64s::from_array(*a);
+        let digit: I64s = d_lsh.digit(av);
+        let carry: I64s = d_lsh.carry(av, digit);
+        let digit_plus_c: I64s = (digit << lsh_vec) + I64s::from_array(*c);
+        let x1: I64s = d.digit(digit_plus_c);
+        *x = (I64s::from_array(*x) - x1).to_array();
+        *c = (carry + d.carry(digit_plus_c, x1)).to_array();
+    }
+
+    znx_normalize_middle_step_sub_ref(base2k, lsh, xx_tail, aa_tail, cc_tail);
+}
+
+#[inline(always)]
+pub fn znx_normalize_final_step_assign_simd(base2k: usize, lsh: usize, x: &mut [i64], carry: &mut [i64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert!(x.len() <= carry.len());
+        assert!(lsh < base2k);
+    }
+
+    let n: usize = x.len();
+    let d: Digits = Digits::new(base2k);
+    let d_lsh: Digits = Digits::new(base2k - lsh);
+    let lsh_vec: I64s = I64s::splat(lsh as i64);
+
+    let (xx, xx_tail) = x.as_chunks_mut::<LANES>();
+    let (cc, cc_tail) = carry[..n].as_chunks_mut::<LANES>();
+
+    for (x, c) in xx.iter_mut().zip(cc.iter()) {
+        let digit: I64s = d_lsh.digit(I64s::from_array(*x));
+        *x = d.digit((digit << lsh_vec) + I64s::from_array(*c)).to_array();
+    }
+
+    znx_normalize_final_step_assign_ref(base2k, lsh, xx_tail, cc_tail);
+}
+
+#[inline(always)]
+pub fn znx_normalize_final_step_simd<const OVERWRITE: bool>(
+    base2k: usize,
+    lsh: usize,
+    x: &mut [i64],
+    a: &[i64],
+    carry: &mut [i64],
+) {
+    #[cfg(debug_assertions)]
+    {
+        assert!(x.len() <= carry.len());
+        assert!(lsh < base2k);
+    }
+
+    let n: usize = x.len();
+    let d: Digits = Digits::new(base2k);
+    let d_lsh: Digits = Digits::new(base2k - lsh);
+    let lsh_vec: I64s = I64s::splat(lsh as i64);
+
+    let (xx, xx_tail) = x.as_chunks_mut::<LANES>();
+    let (aa, aa_tail) = a[..n].as_chunks::<LANES>();
+    let (cc, cc_tail) = carry[..n].as_chunks_mut::<LANES>();
+
+    for ((x, a), c) in xx.iter_mut().zip(aa).zip(cc.iter()) {
+        let digit: I64s = d_lsh.digit(I64s::from_array(*a));
+        let x1: I64s = d.digit((digit << lsh_vec) + I64s::from_array(*c));
+        *x = if OVERWRITE { x1 } else { I64s::from_array(*x) + x1 }.to_array();
+    }
+
+    znx_normalize_final_step_ref::<OVERWRITE>(base2k, lsh, xx_tail, aa_tail, cc_tail);
+}
+
+#[inline(always)]
+pub fn znx_normalize_final_step_sub_simd(base2k: usize, lsh: usize, x: &mut [i64], a: &[i64], carry: &mut [i64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert!(x.len() <= carry.len());
+        assert!(lsh < base2k);
+    }
+
+    let n: usize = x.len();
+    let d: Digits = Digits::new(base2k);
+    let d_lsh: Digits = Digits::new(base2k - lsh);
+    let lsh_vec: I64s = I64s::splat(lsh as i64);
+
+    let (xx, xx_tail) = x.as_chunks_mut::<LANES>();
+    let (aa, aa_tail) = a[..n].as_chunks::<LANES>();
+    let (cc, cc_tail) = carry[..n].as_chunks_mut::<LANES>();
+
+    for ((x, a), c) in xx.iter_mut().zip(aa).zip(cc.iter()) {
+        let digit: I64s = d_lsh.digit(I64s::from_array(*a));
+        let x1: I64s = d.digit((digit << lsh_vec) + I64s::from_array(*c));
+        *x = (I64s::from_array(*x) - x1).to_array();
+    }
+
+    znx_normalize_final_step_sub_ref(base2k, lsh, xx_tail, aa_tail, cc_tail);
+}
+
+#[inline(always)]
+pub fn znx_extract_digit_addmul_simd(base2k: usize, lsh: usize, res: &mut [i64], src: &mut [i64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(res.len(), src.len());
+    }
+
+    let d: Digits = Digits::new(base2k);
+    let lsh_vec: I64s = I64s::splat(lsh as i64);
+
+    let (rr, rr_tail) = res.as_chunks_mut::<LANES>();
+    let (ss, ss_tail) = src.as_chunks_mut::<LANES>();
+
+    for (r, s) in rr.iter_mut().zip(ss.iter_mut()) {
+        let sv: I64s = I64s::from_array(*s);
+        let digit: I64s = d.digit(sv);
+        *s = d.carry(sv, digit).to_array();
+        *r = (I64s::from_array(*r) + (digit << lsh_vec)).to_array();
+    }
+
+    znx_extract_digit_addmul_ref(base2k, lsh, rr_tail, ss_tail);
+}
+
+#[inline(always)]
+pub fn znx_normalize_digit_simd(base2k: usize, res: &mut [i64], src: &mut [i64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(res.len(), src.len());
+    }
+
+    let d: Digits = Digits::new(base2k);
+
+    let (rr, rr_tail) = res.as_chunks_mut::<LANES>();
+    let (ss, ss_tail) = src.as_chunks_mut::<LANES>();
+
+    for (r, s) in rr.iter_mut().zip(ss.iter_mut()) {
+        let rv: I64s = I64s::from_array(*r);
+        let digit: I64s = d.digit(rv);
+        *r = digit.to_array();
+        *s = (I64s::from_array(*s) + d.carry(rv, digit)).to_array();
+    }
+
+    znx_normalize_digit_ref(base2k, rr_tail, ss_tail);
+}
+
+#[cfg(test)]
+mod tests {
+    use poulpy_cpu_ref::reference::znx::{
+        znx_extract_digit_addmul_ref, znx_normalize_digit_ref, znx_normalize_final_step_assign_ref, znx_normalize_final_step_ref,
+        znx_normalize_first_step_assign_ref, znx_normalize_first_step_ref, znx_normalize_middle_step_assign_ref,
+        znx_normalize_middle_step_ref,
+    };
+
+    use super::*;
+
+    // Not a multiple of LANES, so that the scalar tail is exercised as well.
+    const X: [i64; 7] = [
+        7638646372408325293,
+        -61440197422348985,
+        6835891051541717957,
+        -4835376105455195188,
+        1174467039,
+        -144794816,
+        513122840,
+    ];
+
+    const C: [i64; 7] = [
+        621182201135793202,
+        -900085657331700623,
+        554225275542111366,
+        -603684726313169063,
+        -1466676977,
+        144794816,
+        -7,
+    ];
+
+    #[test]
+    fn test_znx_normalize_first_step_simd() {
+        let base2k: usize = 12;
+        for lsh in [0, base2k - 1] {
+            let (mut y0, mut y1) = (X, X);
+            let (mut c0, mut c1) = (C, C);
+            znx_normalize_first_step_assign_ref(base2k, lsh, &mut y0, &mut c0);
+            znx_normalize_first_step_assign_simd(base2k, lsh, &mut y1, &mut c1);
+            assert_eq!(y0, y1);
+            assert_eq!(c0, c1);
+
+            let (mut y0, mut y1) = ([0i64; 7], [0i64; 7]);
+            znx_normalize_first_step_ref::<true>(base2k, lsh, &mut y0, &X, &mut c0);
+            znx_normalize_first_step_simd::<true>(base2k, lsh, &mut y1, &X, &mut c1);
+            assert_eq!(y0, y1);
+            assert_eq!(c0, c1);
+        }
+    }
+
+    #[test]
+    fn test_znx_normalize_middle_step_simd() {
+        let base2k: usize = 12;
+        for lsh in [0, base2k - 1] {
+            let (mut y0, mut y1) = (X, X);
+            let (mut c0, mut c1) = (C, C);
+            znx_normalize_middle_step_assign_ref(base2k, lsh, &mut y0, &mut c0);
+            znx_normalize_middle_step_assign_simd(base2k, lsh, &mut y1, &mut c1);
+            assert_eq!(y0, y1);
+            assert_eq!(c0, c1);
+
+            let (mut y0, mut y1) = ([0i64; 7], [0i64; 7]);
+            znx_normalize_middle_step_ref::<true>(base2k, lsh, &mut y0, &X, &mut c0);
+            znx_normalize_middle_step_simd::<true>(base2k, lsh, &mut y1, &X, &mut c1);
+            assert_eq!(y0, y1);
+            assert_eq!(c0, c1);
+        }
+    }
+
+    #[test]
+    fn test_znx_normalize_final_step_simd() {
+        let base2k: usize = 12;
+        for lsh in [0, base2k - 1] {
+            let (mut y0, mut y1) = (X, X);
+            let (mut c0, mut c1) = (C, C);
+            znx_normalize_final_step_assign_ref(base2k, lsh, &mut y0, &mut c0);
+            znx_normalize_final_step_assign_simd(base2k, lsh, &mut y1, &mut c1);
+            assert_eq!(y0, y1);
+
+            let (mut y0, mut y1) = ([0i64; 7], [0i64; 7]);
+            znx_normalize_final_step_ref::<true>(base2k, lsh, &mut y0, &X, &mut c0);
+            znx_normalize_final_step_simd::<true>(base2k, lsh, &mut y1, &X, &mut c1);
+            assert_eq!(y0, y1);
+        }
+    }
+
+    #[test]
+    fn test_znx_digit_simd() {
+        let base2k: usize = 12;
+
+        let (mut r0, mut r1) = ([0i64; 7], [0i64; 7]);
+        let (mut s0, mut s1) = (X, X);
+        znx_extract_digit_addmul_ref(base2k, 3, &mut r0, &mut s0);
+        znx_extract_digit_addmul_simd(base2k, 3, &mut r1, &mut s1);
+        assert_eq!(r0, r1);
+        assert_eq!(s0, s1);
+
+        let (mut r0, mut r1) = (X, X);
+        let (mut s0, mut s1) = (C, C);
+        znx_normalize_digit_ref(base2k, &mut r0, &mut s0);
+        znx_normalize_digit_simd(base2k, &mut r1, &mut s1);
+        assert_eq!(r0, r1);
+        assert_eq!(s0, s1);
+    }
+}
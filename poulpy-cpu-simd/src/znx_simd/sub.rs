@@ -0,0 +1,59 @@
+use poulpy_cpu_ref::reference::znx::{znx_sub_assign_ref, znx_sub_negate_assign_ref, znx_sub_ref};
+
+use super::{I64s, LANES};
+
+/// `res[i] = a[i] - b[i]`.
+#[inline(always)]
+pub fn znx_sub_simd(res: &mut [i64], a: &[i64], b: &[i64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(res.len(), a.len());
+        assert_eq!(res.len(), b.len());
+    }
+
+    let (rr, rr_tail) = res.as_chunks_mut::<LANES>();
+    let (aa, aa_tail) = a.as_chunks::<LANES>();
+    let (bb, bb_tail) = b.as_chunks::<LANES>();
+
+    for ((r, a), b) in rr.iter_mut().zip(aa).zip(bb) {
+        *r = (I64s::from_array(*a) - I64s::from_array(*b)).to_array();
+    }
+
+    znx_sub_ref(rr_tail, aa_tail, bb_tail);
+}
+
+/// `res[i] -= a[i]`.
+#[inline(always)]
+pub fn znx_sub_assign_simd(res: &mut [i64], a: &[i64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(res.len(), a.len());
+    }
+
+    let (rr, rr_tail) = res.as_chunks_mut::<LANES>();
+    let (aa, aa_tail) = a.as_chunks::<LANES>();
+
+    for (r, a) in rr.iter_mut().zip(aa) {
+        *r = (I64s::from_array(*r) - I64s::from_array(*a)).to_array();
+    }
+
+    znx_sub_assign_ref(rr_tail, aa_tail);
+}
+
+/// `res[i] = a[i] - res[i]`.
+#[inline(always)]
+pub fn znx_sub_negate_assign_simd(res: &mut [i64], a: &[i64]) {
+    #[cfg(debug_assertions)]
+    {
+        assert_eq!(res.len(), a.len());
+    }
+
+    let (rr, rr_tail) = res.as_chunks_mut::<LANES>();
+    let (aa, aa_tail) = a.as_chunks::<LANES>();
+
+    for (r, a) in rr.iter_mut().zip(aa) {
+        *r = (I64s::from_array(*a) - I64s::from_array(*r)).to_array();
+    }
+
+    znx_sub_negate_assign_ref(rr_tail, aa_tail);
+}
@@ -0,0 +1,1089 @@
+#![allow(clippy::too_many_arguments)]
+
+use std::marker::PhantomData;
+
+use crate::{
+    layouts::{
+        CnvPVecLToMut, CnvPVecLToRef, CnvPVecRToMut, CnvPVecRToRef, Data, MatZnxToRef, Module, NoiseInfos, ScalarZnxToRef,
+        Scratch, ScratchOwned, SvpPPolToMut, SvpPPolToRef, VecZnxBig, VecZnxBigToMut, VecZnxBigToRef, VecZnxDft, VecZnxDftToMut,
+        VecZnxDftToRef, VecZnxToMut, VecZnxToRef, VmpPMatToMut, VmpPMatToRef, ZnxInfos,
+    },
+    oep::HalImpl,
+    source::Source,
+};
+
+use super::{DynBackend, DynBackendFamily, dyn_hal, retag_mut, retag_scratch, retag_scratch_owned, retag_scratch_ref};
+
+// Scratch management and the module-less methods are served by the family
+// layout; every other method is forwarded to the selected backend.
+unsafe impl<F: DynBackendFamily> HalImpl<DynBackend<F>> for DynBackend<F> {
+    fn scratch_owned_alloc(size: usize) -> ScratchOwned<Self> {
+        let scratch: ScratchOwned<F::Layout> = F::Layout::scratch_owned_alloc(size);
+        ScratchOwned {
+            data: scratch.into_data(),
+            _phantom: PhantomData,
+        }
+    }
+
+    fn scratch_owned_borrow(scratch: &mut ScratchOwned<Self>) -> &mut Scratch<Self> {
+        retag_scratch(F::Layout::scratch_owned_borrow(retag_scratch_owned(scratch)))
+    }
+
+    fn scratch_from_bytes(data: &mut [u8]) -> &mut Scratch<Self> {
+        retag_scratch(F::Layout::scratch_from_bytes(data))
+    }
+
+    fn scratch_available(scratch: &Scratch<Self>) -> usize {
+        F::Layout::scratch_available(retag_scratch_ref(scratch))
+    }
+
+    fn take_slice<T>(scratch: &mut Scratch<Self>, len: usize) -> (&mut [T], &mut Scratch<Self>) {
+        let (slice, rem) = F::Layout::take_slice::<T>(retag_scratch(scratch), len);
+        (slice, retag_scratch(rem))
+    }
+
+    fn new(n: u64) -> Module<Self> {
+        F::select(n)
+    }
+
+    fn vec_znx_big_from_small<R, A>(res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxToRef,
+    {
+        F::Layout::vec_znx_big_from_small(retag_mut::<_, F::Layout>(&mut res.to_mut()), res_col, a, a_col)
+    }
+
+    fn vec_znx_idft_apply_consume<D: Data>(module: &Module<Self>, mut a: VecZnxDft<D, Self>) -> VecZnxBig<D, Self>
+    where
+        VecZnxDft<D, Self>: VecZnxDftToMut<Self>,
+    {
+        dyn_hal(module).vec_znx_idft_apply_consume(a.to_mut());
+        a.into_big()
+    }
+
+    fn vec_znx_zero<R>(module: &Module<Self>, res: &mut R, res_col: usize)
+    where
+        R: VecZnxToMut,
+    {
+        dyn_hal(module).vec_znx_zero(&mut res.to_mut(), res_col)
+    }
+
+    fn vec_znx_normalize_tmp_bytes(module: &Module<Self>) -> usize {
+        dyn_hal(module).vec_znx_normalize_tmp_bytes()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_normalize<R, A>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_base2k: usize,
+        res_offset: i64,
+        res_col: usize,
+        a: &A,
+        a_base2k: usize,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_normalize(
+            &mut res.to_mut(),
+            res_base2k,
+            res_offset,
+            res_col,
+            &a.to_ref(),
+            a_base2k,
+            a_col,
+            scratch,
+        )
+    }
+
+    fn vec_znx_normalize_assign<A>(module: &Module<Self>, base2k: usize, a: &mut A, a_col: usize, scratch: &mut Scratch<Self>)
+    where
+        A: VecZnxToMut,
+    {
+        dyn_hal(module).vec_znx_normalize_assign(base2k, &mut a.to_mut(), a_col, scratch)
+    }
+
+    fn vec_znx_add_into<R, A, C>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+        C: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_add_into(&mut res.to_mut(), res_col, &a.to_ref(), a_col, &b.to_ref(), b_col)
+    }
+
+    fn vec_znx_add_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_add_assign(&mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_add_scalar_into<R, A, B>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &B,
+        b_col: usize,
+        b_limb: usize,
+    ) where
+        R: VecZnxToMut,
+        A: ScalarZnxToRef,
+        B: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_add_scalar_into(&mut res.to_mut(), res_col, &a.to_ref(), a_col, &b.to_ref(), b_col, b_limb)
+    }
+
+    fn vec_znx_add_scalar_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, res_limb: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: ScalarZnxToRef,
+    {
+        dyn_hal(module).vec_znx_add_scalar_assign(&mut res.to_mut(), res_col, res_limb, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_sub<R, A, C>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+        C: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_sub(&mut res.to_mut(), res_col, &a.to_ref(), a_col, &b.to_ref(), b_col)
+    }
+
+    fn vec_znx_sub_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_sub_assign(&mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_sub_negate_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_sub_negate_assign(&mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_sub_scalar<R, A, B>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &B,
+        b_col: usize,
+        b_limb: usize,
+    ) where
+        R: VecZnxToMut,
+        A: ScalarZnxToRef,
+        B: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_sub_scalar(&mut res.to_mut(), res_col, &a.to_ref(), a_col, &b.to_ref(), b_col, b_limb)
+    }
+
+    fn vec_znx_sub_scalar_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, res_limb: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: ScalarZnxToRef,
+    {
+        dyn_hal(module).vec_znx_sub_scalar_assign(&mut res.to_mut(), res_col, res_limb, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_negate<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_negate(&mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_negate_assign<A>(module: &Module<Self>, a: &mut A, a_col: usize)
+    where
+        A: VecZnxToMut,
+    {
+        dyn_hal(module).vec_znx_negate_assign(&mut a.to_mut(), a_col)
+    }
+
+    fn vec_znx_rsh_tmp_bytes(module: &Module<Self>) -> usize {
+        dyn_hal(module).vec_znx_rsh_tmp_bytes()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_rsh<R, A>(
+        module: &Module<Self>,
+        base2k: usize,
+        k: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_rsh(base2k, k, &mut res.to_mut(), res_col, &a.to_ref(), a_col, scratch)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_rsh_add_into<R, A>(
+        module: &Module<Self>,
+        base2k: usize,
+        k: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_rsh_add_into(base2k, k, &mut res.to_mut(), res_col, &a.to_ref(), a_col, scratch)
+    }
+
+    fn vec_znx_lsh_tmp_bytes(module: &Module<Self>) -> usize {
+        dyn_hal(module).vec_znx_lsh_tmp_bytes()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_lsh<R, A>(
+        module: &Module<Self>,
+        base2k: usize,
+        k: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_lsh(base2k, k, &mut res.to_mut(), res_col, &a.to_ref(), a_col, scratch)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_lsh_add_into<R, A>(
+        module: &Module<Self>,
+        base2k: usize,
+        k: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_lsh_add_into(base2k, k, &mut res.to_mut(), res_col, &a.to_ref(), a_col, scratch)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_lsh_sub<R, A>(
+        module: &Module<Self>,
+        base2k: usize,
+        k: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_lsh_sub(base2k, k, &mut res.to_mut(), res_col, &a.to_ref(), a_col, scratch)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_rsh_sub<R, A>(
+        module: &Module<Self>,
+        base2k: usize,
+        k: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_rsh_sub(base2k, k, &mut res.to_mut(), res_col, &a.to_ref(), a_col, scratch)
+    }
+
+    fn vec_znx_rsh_assign<R>(module: &Module<Self>, base2k: usize, k: usize, a: &mut R, a_col: usize, scratch: &mut Scratch<Self>)
+    where
+        R: VecZnxToMut,
+    {
+        dyn_hal(module).vec_znx_rsh_assign(base2k, k, &mut a.to_mut(), a_col, scratch)
+    }
+
+    fn vec_znx_lsh_assign<R>(module: &Module<Self>, base2k: usize, k: usize, a: &mut R, a_col: usize, scratch: &mut Scratch<Self>)
+    where
+        R: VecZnxToMut,
+    {
+        dyn_hal(module).vec_znx_lsh_assign(base2k, k, &mut a.to_mut(), a_col, scratch)
+    }
+
+    fn vec_znx_rotate<R, A>(module: &Module<Self>, k: i64, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_rotate(k, &mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_rotate_assign_tmp_bytes(module: &Module<Self>) -> usize {
+        dyn_hal(module).vec_znx_rotate_assign_tmp_bytes()
+    }
+
+    fn vec_znx_rotate_assign<A>(module: &Module<Self>, k: i64, a: &mut A, a_col: usize, scratch: &mut Scratch<Self>)
+    where
+        A: VecZnxToMut,
+    {
+        dyn_hal(module).vec_znx_rotate_assign(k, &mut a.to_mut(), a_col, scratch)
+    }
+
+    fn vec_znx_automorphism<R, A>(module: &Module<Self>, k: i64, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_automorphism(k, &mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_automorphism_assign_tmp_bytes(module: &Module<Self>) -> usize {
+        dyn_hal(module).vec_znx_automorphism_assign_tmp_bytes()
+    }
+
+    fn vec_znx_automorphism_assign<R>(module: &Module<Self>, k: i64, res: &mut R, res_col: usize, scratch: &mut Scratch<Self>)
+    where
+        R: VecZnxToMut,
+    {
+        dyn_hal(module).vec_znx_automorphism_assign(k, &mut res.to_mut(), res_col, scratch)
+    }
+
+    fn vec_znx_mul_xp_minus_one<R, A>(module: &Module<Self>, k: i64, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_mul_xp_minus_one(k, &mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_mul_xp_minus_one_assign_tmp_bytes(module: &Module<Self>) -> usize {
+        dyn_hal(module).vec_znx_mul_xp_minus_one_assign_tmp_bytes()
+    }
+
+    fn vec_znx_mul_xp_minus_one_assign<R>(module: &Module<Self>, k: i64, res: &mut R, res_col: usize, scratch: &mut Scratch<Self>)
+    where
+        R: VecZnxToMut,
+    {
+        dyn_hal(module).vec_znx_mul_xp_minus_one_assign(k, &mut res.to_mut(), res_col, scratch)
+    }
+
+    fn vec_znx_split_ring_tmp_bytes(module: &Module<Self>) -> usize {
+        dyn_hal(module).vec_znx_split_ring_tmp_bytes()
+    }
+
+    fn vec_znx_split_ring<R, A>(
+        module: &Module<Self>,
+        res: &mut [R],
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_split_ring(
+            &mut res.iter_mut().map(|r| r.to_mut()).collect::<Vec<_>>(),
+            res_col,
+            &a.to_ref(),
+            a_col,
+            scratch,
+        )
+    }
+
+    fn vec_znx_merge_rings_tmp_bytes(module: &Module<Self>) -> usize {
+        dyn_hal(module).vec_znx_merge_rings_tmp_bytes()
+    }
+
+    fn vec_znx_merge_rings<R, A>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &[A],
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_merge_rings(
+            &mut res.to_mut(),
+            res_col,
+            &a.iter().map(|a| a.to_ref()).collect::<Vec<_>>(),
+            a_col,
+            scratch,
+        )
+    }
+
+    fn vec_znx_switch_ring<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_switch_ring(&mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_copy<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_copy(&mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_fill_uniform<R>(module: &Module<Self>, base2k: usize, res: &mut R, res_col: usize, source: &mut Source)
+    where
+        R: VecZnxToMut,
+    {
+        dyn_hal(module).vec_znx_fill_uniform(base2k, &mut res.to_mut(), res_col, source)
+    }
+
+    fn vec_znx_fill_normal<R>(
+        module: &Module<Self>,
+        res_base2k: usize,
+        res: &mut R,
+        res_col: usize,
+        noise_infos: NoiseInfos,
+        source: &mut Source,
+    ) where
+        R: VecZnxToMut,
+    {
+        dyn_hal(module).vec_znx_fill_normal(res_base2k, &mut res.to_mut(), res_col, noise_infos, source)
+    }
+
+    fn vec_znx_add_normal<R>(
+        module: &Module<Self>,
+        res_base2k: usize,
+        res: &mut R,
+        res_col: usize,
+        noise_infos: NoiseInfos,
+        source: &mut Source,
+    ) where
+        R: VecZnxToMut,
+    {
+        dyn_hal(module).vec_znx_add_normal(res_base2k, &mut res.to_mut(), res_col, noise_infos, source)
+    }
+
+    fn vec_znx_big_add_normal<R>(
+        module: &Module<Self>,
+        res_base2k: usize,
+        res: &mut R,
+        res_col: usize,
+        noise_infos: NoiseInfos,
+        source: &mut Source,
+    ) where
+        R: VecZnxBigToMut<Self>,
+    {
+        dyn_hal(module).vec_znx_big_add_normal(res_base2k, &mut res.to_mut(), res_col, noise_infos, source)
+    }
+
+    fn vec_znx_big_add_into<R, A, C>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+        C: VecZnxBigToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_big_add_into(&mut res.to_mut(), res_col, &a.to_ref(), a_col, &b.to_ref(), b_col)
+    }
+
+    fn vec_znx_big_add_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_big_add_assign(&mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_big_add_small_into<R, A, C>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &C,
+        b_col: usize,
+    ) where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+        C: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_big_add_small_into(&mut res.to_mut(), res_col, &a.to_ref(), a_col, &b.to_ref(), b_col)
+    }
+
+    fn vec_znx_big_add_small_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_big_add_small_assign(&mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_big_sub<R, A, C>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+        C: VecZnxBigToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_big_sub(&mut res.to_mut(), res_col, &a.to_ref(), a_col, &b.to_ref(), b_col)
+    }
+
+    fn vec_znx_big_sub_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_big_sub_assign(&mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_big_sub_negate_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_big_sub_negate_assign(&mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_big_sub_small_a<R, A, C>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &C,
+        b_col: usize,
+    ) where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxToRef,
+        C: VecZnxBigToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_big_sub_small_a(&mut res.to_mut(), res_col, &a.to_ref(), a_col, &b.to_ref(), b_col)
+    }
+
+    fn vec_znx_big_sub_small_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_big_sub_small_assign(&mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_big_sub_small_b<R, A, C>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &C,
+        b_col: usize,
+    ) where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+        C: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_big_sub_small_b(&mut res.to_mut(), res_col, &a.to_ref(), a_col, &b.to_ref(), b_col)
+    }
+
+    fn vec_znx_big_sub_small_negate_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_big_sub_small_negate_assign(&mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_big_negate<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_big_negate(&mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_big_negate_assign<A>(module: &Module<Self>, a: &mut A, a_col: usize)
+    where
+        A: VecZnxBigToMut<Self>,
+    {
+        dyn_hal(module).vec_znx_big_negate_assign(&mut a.to_mut(), a_col)
+    }
+
+    fn vec_znx_big_normalize_tmp_bytes(module: &Module<Self>) -> usize {
+        dyn_hal(module).vec_znx_big_normalize_tmp_bytes()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_big_normalize<R, A>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_base2k: usize,
+        res_offset: i64,
+        res_col: usize,
+        a: &A,
+        a_base2k: usize,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxBigToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_big_normalize(
+            &mut res.to_mut(),
+            res_base2k,
+            res_offset,
+            res_col,
+            &a.to_ref(),
+            a_base2k,
+            a_col,
+            scratch,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_big_normalize_add_assign<R, A>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_base2k: usize,
+        res_offset: i64,
+        res_col: usize,
+        a: &A,
+        a_base2k: usize,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxBigToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_big_normalize_add_assign(
+            &mut res.to_mut(),
+            res_base2k,
+            res_offset,
+            res_col,
+            &a.to_ref(),
+            a_base2k,
+            a_col,
+            scratch,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn vec_znx_big_normalize_sub_assign<R, A>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_base2k: usize,
+        res_offset: i64,
+        res_col: usize,
+        a: &A,
+        a_base2k: usize,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxBigToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_big_normalize_sub_assign(
+            &mut res.to_mut(),
+            res_base2k,
+            res_offset,
+            res_col,
+            &a.to_ref(),
+            a_base2k,
+            a_col,
+            scratch,
+        )
+    }
+
+    fn vec_znx_big_automorphism<R, A>(module: &Module<Self>, k: i64, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_big_automorphism(k, &mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_big_automorphism_assign_tmp_bytes(module: &Module<Self>) -> usize {
+        dyn_hal(module).vec_znx_big_automorphism_assign_tmp_bytes()
+    }
+
+    fn vec_znx_big_automorphism_assign<A>(module: &Module<Self>, k: i64, a: &mut A, a_col: usize, scratch: &mut Scratch<Self>)
+    where
+        A: VecZnxBigToMut<Self>,
+    {
+        dyn_hal(module).vec_znx_big_automorphism_assign(k, &mut a.to_mut(), a_col, scratch)
+    }
+
+    fn vec_znx_dft_apply<R, A>(
+        module: &Module<Self>,
+        step: usize,
+        offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+    ) where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).vec_znx_dft_apply(step, offset, &mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_idft_apply_tmp_bytes(module: &Module<Self>) -> usize {
+        dyn_hal(module).vec_znx_idft_apply_tmp_bytes()
+    }
+
+    fn vec_znx_idft_apply<R, A>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_idft_apply(&mut res.to_mut(), res_col, &a.to_ref(), a_col, scratch)
+    }
+
+    fn vec_znx_idft_apply_tmpa<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &mut A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxDftToMut<Self>,
+    {
+        dyn_hal(module).vec_znx_idft_apply_tmpa(&mut res.to_mut(), res_col, &mut a.to_mut(), a_col)
+    }
+
+    fn vec_znx_dft_add_into<R, A, D>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &D, b_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+        D: VecZnxDftToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_dft_add_into(&mut res.to_mut(), res_col, &a.to_ref(), a_col, &b.to_ref(), b_col)
+    }
+
+    fn vec_znx_dft_add_scaled_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, a_scale: i64)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_dft_add_scaled_assign(&mut res.to_mut(), res_col, &a.to_ref(), a_col, a_scale)
+    }
+
+    fn vec_znx_dft_add_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_dft_add_assign(&mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_dft_sub<R, A, D>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &D, b_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+        D: VecZnxDftToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_dft_sub(&mut res.to_mut(), res_col, &a.to_ref(), a_col, &b.to_ref(), b_col)
+    }
+
+    fn vec_znx_dft_sub_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_dft_sub_assign(&mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_dft_sub_negate_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_dft_sub_negate_assign(&mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_dft_copy<R, A>(module: &Module<Self>, step: usize, offset: usize, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+    {
+        dyn_hal(module).vec_znx_dft_copy(step, offset, &mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vec_znx_dft_zero<R>(module: &Module<Self>, res: &mut R, res_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+    {
+        dyn_hal(module).vec_znx_dft_zero(&mut res.to_mut(), res_col)
+    }
+
+    fn svp_prepare<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: SvpPPolToMut<Self>,
+        A: ScalarZnxToRef,
+    {
+        dyn_hal(module).svp_prepare(&mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn svp_apply_dft<R, A, C>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: SvpPPolToRef<Self>,
+        C: VecZnxToRef,
+    {
+        dyn_hal(module).svp_apply_dft(&mut res.to_mut(), res_col, &a.to_ref(), a_col, &b.to_ref(), b_col)
+    }
+
+    fn svp_apply_dft_to_dft<R, A, C>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: SvpPPolToRef<Self>,
+        C: VecZnxDftToRef<Self>,
+    {
+        dyn_hal(module).svp_apply_dft_to_dft(&mut res.to_mut(), res_col, &a.to_ref(), a_col, &b.to_ref(), b_col)
+    }
+
+    fn svp_apply_dft_to_dft_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: SvpPPolToRef<Self>,
+    {
+        dyn_hal(module).svp_apply_dft_to_dft_assign(&mut res.to_mut(), res_col, &a.to_ref(), a_col)
+    }
+
+    fn vmp_prepare_tmp_bytes(module: &Module<Self>, rows: usize, cols_in: usize, cols_out: usize, size: usize) -> usize {
+        dyn_hal(module).vmp_prepare_tmp_bytes(rows, cols_in, cols_out, size)
+    }
+
+    fn vmp_prepare<R, A>(module: &Module<Self>, res: &mut R, a: &A, scratch: &mut Scratch<Self>)
+    where
+        R: VmpPMatToMut<Self>,
+        A: MatZnxToRef,
+    {
+        dyn_hal(module).vmp_prepare(&mut res.to_mut(), &a.to_ref(), scratch)
+    }
+
+    fn vmp_apply_dft_tmp_bytes(
+        module: &Module<Self>,
+        res_size: usize,
+        a_size: usize,
+        b_rows: usize,
+        b_cols_in: usize,
+        b_cols_out: usize,
+        b_size: usize,
+    ) -> usize {
+        dyn_hal(module).vmp_apply_dft_tmp_bytes(res_size, a_size, b_rows, b_cols_in, b_cols_out, b_size)
+    }
+
+    fn vmp_apply_dft<R, A, C>(module: &Module<Self>, res: &mut R, a: &A, b: &C, scratch: &mut Scratch<Self>)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxToRef,
+        C: VmpPMatToRef<Self>,
+    {
+        dyn_hal(module).vmp_apply_dft(&mut res.to_mut(), &a.to_ref(), &b.to_ref(), scratch)
+    }
+
+    fn vmp_apply_dft_to_dft_tmp_bytes(
+        module: &Module<Self>,
+        res_size: usize,
+        a_size: usize,
+        b_rows: usize,
+        b_cols_in: usize,
+        b_cols_out: usize,
+        b_size: usize,
+    ) -> usize {
+        dyn_hal(module).vmp_apply_dft_to_dft_tmp_bytes(res_size, a_size, b_rows, b_cols_in, b_cols_out, b_size)
+    }
+
+    fn vmp_apply_dft_to_dft<R, A, C>(
+        module: &Module<Self>,
+        res: &mut R,
+        a: &A,
+        b: &C,
+        limb_offset: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+        C: VmpPMatToRef<Self>,
+    {
+        dyn_hal(module).vmp_apply_dft_to_dft(&mut res.to_mut(), &a.to_ref(), &b.to_ref(), limb_offset, scratch)
+    }
+
+    fn vmp_zero<R>(module: &Module<Self>, res: &mut R)
+    where
+        R: VmpPMatToMut<Self>,
+    {
+        dyn_hal(module).vmp_zero(&mut res.to_mut())
+    }
+
+    fn cnv_prepare_left_tmp_bytes(module: &Module<Self>, res_size: usize, a_size: usize) -> usize {
+        dyn_hal(module).cnv_prepare_left_tmp_bytes(res_size, a_size)
+    }
+
+    fn cnv_prepare_left<R, A>(module: &Module<Self>, res: &mut R, a: &A, mask: i64, scratch: &mut Scratch<Self>)
+    where
+        R: CnvPVecLToMut<Self>,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).cnv_prepare_left(&mut res.to_mut(), &a.to_ref(), mask, scratch)
+    }
+
+    fn cnv_prepare_right_tmp_bytes(module: &Module<Self>, res_size: usize, a_size: usize) -> usize {
+        dyn_hal(module).cnv_prepare_right_tmp_bytes(res_size, a_size)
+    }
+
+    fn cnv_prepare_right<R, A>(module: &Module<Self>, res: &mut R, a: &A, mask: i64, scratch: &mut Scratch<Self>)
+    where
+        R: CnvPVecRToMut<Self>,
+        A: VecZnxToRef + ZnxInfos,
+    {
+        dyn_hal(module).cnv_prepare_right(&mut res.to_mut(), &a.to_ref(), mask, scratch)
+    }
+
+    fn cnv_apply_dft_tmp_bytes(module: &Module<Self>, cnv_offset: usize, res_size: usize, a_size: usize, b_size: usize) -> usize {
+        dyn_hal(module).cnv_apply_dft_tmp_bytes(cnv_offset, res_size, a_size, b_size)
+    }
+
+    fn cnv_by_const_apply_tmp_bytes(
+        module: &Module<Self>,
+        cnv_offset: usize,
+        res_size: usize,
+        a_size: usize,
+        b_size: usize,
+    ) -> usize {
+        dyn_hal(module).cnv_by_const_apply_tmp_bytes(cnv_offset, res_size, a_size, b_size)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn cnv_by_const_apply<R, A>(
+        module: &Module<Self>,
+        cnv_offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &[i64],
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxToRef,
+    {
+        dyn_hal(module).cnv_by_const_apply(cnv_offset, &mut res.to_mut(), res_col, &a.to_ref(), a_col, b, scratch)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn cnv_apply_dft<R, A, B>(
+        module: &Module<Self>,
+        cnv_offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &B,
+        b_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxDftToMut<Self>,
+        A: CnvPVecLToRef<Self>,
+        B: CnvPVecRToRef<Self>,
+    {
+        dyn_hal(module).cnv_apply_dft(
+            cnv_offset,
+            &mut res.to_mut(),
+            res_col,
+            &a.to_ref(),
+            a_col,
+            &b.to_ref(),
+            b_col,
+            scratch,
+        )
+    }
+
+    fn cnv_pairwise_apply_dft_tmp_bytes(
+        module: &Module<Self>,
+        cnv_offset: usize,
+        res_size: usize,
+        a_size: usize,
+        b_size: usize,
+    ) -> usize {
+        dyn_hal(module).cnv_pairwise_apply_dft_tmp_bytes(cnv_offset, res_size, a_size, b_size)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn cnv_pairwise_apply_dft<R, A, B>(
+        module: &Module<Self>,
+        cnv_offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        b: &B,
+        i: usize,
+        j: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxDftToMut<Self>,
+        A: CnvPVecLToRef<Self>,
+        B: CnvPVecRToRef<Self>,
+    {
+        dyn_hal(module).cnv_pairwise_apply_dft(
+            cnv_offset,
+            &mut res.to_mut(),
+            res_col,
+            &a.to_ref(),
+            &b.to_ref(),
+            i,
+            j,
+            scratch,
+        )
+    }
+
+    fn cnv_prepare_self_tmp_bytes(module: &Module<Self>, res_size: usize, a_size: usize) -> usize {
+        dyn_hal(module).cnv_prepare_self_tmp_bytes(res_size, a_size)
+    }
+
+    fn cnv_prepare_self<L, R, A>(
+        module: &Module<Self>,
+        left: &mut L,
+        right: &mut R,
+        a: &A,
+        mask: i64,
+        scratch: &mut Scratch<Self>,
+    ) where
+        L: CnvPVecLToMut<Self>,
+        R: CnvPVecRToMut<Self>,
+        A: VecZnxToRef + ZnxInfos,
+    {
+        dyn_hal(module).cnv_prepare_self(&mut left.to_mut(), &mut right.to_mut(), &a.to_ref(), mask, scratch)
+    }
+}
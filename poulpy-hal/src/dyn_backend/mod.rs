@@ -0,0 +1,207 @@
+//! Runtime backend dispatch.
+//!
+//! Every [`api`](crate::api) call site is generic over `BE: Backend`, so a
+//! scheme is monomorphised once per backend. [`DynBackend`] instead wraps any
+//! member of a *family* of interchangeable backends behind a trait object and
+//! implements [`HalImpl`] by forwarding each call, at runtime, to the backend
+//! selected when the module was created. Code written against the [`api`](crate::api)
+//! traits runs unchanged on an [`AnyModule`], and a single binary can select,
+//! for example, an AVX2 backend on machines that support it and the portable
+//! reference backend elsewhere.
+//!
+//! A family is described by a [`DynBackendFamily`] marker, which fixes the
+//! scalar and buffer types shared by its members (through a representative
+//! [`DynBackendFamily::Layout`] backend) and decides which member to
+//! instantiate for a given ring degree. Backend crates provide the families;
+//! `poulpy-cpu-avx` exports `FFT64Dyn` and `NTT120Dyn`.
+//!
+//! Members of a family must agree bit-for-bit on the memory layout of
+//! prepared and DFT-domain buffers and on scratch management, since those are
+//! allocated through the family layout and handed to whichever member was
+//! selected. [`Module::from_module`] checks the scalar and buffer types at
+//! compile time and the buffer sizes at runtime.
+//!
+//! Dispatch costs one indirect call per HAL operation, which is negligible
+//! next to the polynomial arithmetic behind it.
+
+mod hal_impl;
+mod object;
+
+use std::{marker::PhantomData, ptr::NonNull};
+
+use crate::{
+    layouts::{Backend, Module, Scratch, ScratchOwned},
+    oep::{HalImpl, Retag},
+};
+
+use object::DynHal;
+
+/// A family of backends sharing the same data representation, among which
+/// [`DynBackend`] selects at runtime.
+pub trait DynBackendFamily: 'static + Send + Sync + Sized {
+    /// Backend whose scalar types, buffers and scratch management are shared
+    /// by all members of the family.
+    type Layout: Backend + HalImpl<Self::Layout>;
+
+    /// Instantiates the preferred member of the family for the ring degree `n`,
+    /// typically after probing the host CPU.
+    fn select(n: u64) -> Module<DynBackend<Self>>;
+}
+
+/// Backend that dispatches every operation, at runtime, to a member of the
+/// family `F`.
+///
+/// See the [module documentation](crate::dyn_backend).
+pub struct DynBackend<F: DynBackendFamily>(PhantomData<F>);
+
+/// Module over a runtime-selected member of the backend family `F`.
+pub type AnyModule<F> = Module<DynBackend<F>>;
+
+/// Handle of a [`DynBackend`] module: the module of the selected backend,
+/// behind a trait object.
+pub struct DynHandle<F: DynBackendFamily> {
+    inner: Box<dyn DynHal<F>>,
+    name: &'static str,
+}
+
+impl<F: DynBackendFamily> Backend for DynBackend<F> {
+    type ScalarBig = <F::Layout as Backend>::ScalarBig;
+    type ScalarPrep = <F::Layout as Backend>::ScalarPrep;
+    type OwnedBuf = <F::Layout as Backend>::OwnedBuf;
+    type Handle = DynHandle<F>;
+    const PREPARED_LAYOUT: &'static str = F::Layout::PREPARED_LAYOUT;
+
+    fn alloc_bytes(len: usize) -> Self::OwnedBuf {
+        F::Layout::alloc_bytes(len)
+    }
+
+    fn from_bytes(bytes: Vec<u8>) -> Self::OwnedBuf {
+        F::Layout::from_bytes(bytes)
+    }
+
+    fn size_of_scalar_big() -> usize {
+        F::Layout::size_of_scalar_big()
+    }
+
+    fn size_of_scalar_prep() -> usize {
+        F::Layout::size_of_scalar_prep()
+    }
+
+    fn bytes_of_vec_znx_dft(n: usize, cols: usize, size: usize) -> usize {
+        F::Layout::bytes_of_vec_znx_dft(n, cols, size)
+    }
+
+    fn bytes_of_vec_znx_big(n: usize, cols: usize, size: usize) -> usize {
+        F::Layout::bytes_of_vec_znx_big(n, cols, size)
+    }
+
+    fn bytes_of_svp_ppol(n: usize, cols: usize) -> usize {
+        F::Layout::bytes_of_svp_ppol(n, cols)
+    }
+
+    fn bytes_of_vmp_pmat(n: usize, rows: usize, cols_in: usize, cols_out: usize, size: usize) -> usize {
+        F::Layout::bytes_of_vmp_pmat(n, rows, cols_in, cols_out, size)
+    }
+
+    fn bytes_of_cnv_pvec_left(n: usize, cols: usize, size: usize) -> usize {
+        F::Layout::bytes_of_cnv_pvec_left(n, cols, size)
+    }
+
+    fn bytes_of_cnv_pvec_right(n: usize, cols: usize, size: usize) -> usize {
+        F::Layout::bytes_of_cnv_pvec_right(n, cols, size)
+    }
+
+    unsafe fn destroy(handle: NonNull<Self::Handle>) {
+        unsafe {
+            drop(Box::from_raw(handle.as_ptr()));
+        }
+    }
+}
+
+impl<F: DynBackendFamily> Module<DynBackend<F>> {
+    /// Wraps a module of a concrete member `B` of the family `F`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `B` does not size its prepared buffers like
+    /// [`DynBackendFamily::Layout`].
+    pub fn from_module<B>(module: Module<B>) -> Self
+    where
+        B: Backend<
+                ScalarBig = <F::Layout as Backend>::ScalarBig,
+                ScalarPrep = <F::Layout as Backend>::ScalarPrep,
+                OwnedBuf = <F::Layout as Backend>::OwnedBuf,
+            > + HalImpl<B>
+            + 'static,
+    {
+        let n: usize = module.n();
+        assert_same_layout::<F::Layout, B>(n);
+
+        let name: &'static str = std::any::type_name::<B>().rsplit("::").next().unwrap_or_default();
+        let handle: Box<DynHandle<F>> = Box::new(DynHandle {
+            inner: Box::new(module),
+            name,
+        });
+        unsafe { Module::from_nonnull(NonNull::from(Box::leak(handle)), n as u64) }
+    }
+
+    /// Returns the name of the backend selected at runtime, e.g. `"FFT64Avx"`.
+    pub fn backend_name(&self) -> &'static str {
+        unsafe { (*self.ptr()).name }
+    }
+}
+
+/// Returns the module of the selected backend.
+fn dyn_hal<F: DynBackendFamily>(module: &Module<DynBackend<F>>) -> &dyn DynHal<F> {
+    unsafe { (*module.ptr()).inner.as_ref() }
+}
+
+fn assert_same_layout<A: Backend, B: Backend>(n: usize) {
+    let (cols, size, rows) = (2, 3, 4);
+    assert_eq!(A::PREPARED_LAYOUT, B::PREPARED_LAYOUT);
+    assert_eq!(A::bytes_of_vec_znx_dft(n, cols, size), B::bytes_of_vec_znx_dft(n, cols, size));
+    assert_eq!(A::bytes_of_vec_znx_big(n, cols, size), B::bytes_of_vec_znx_big(n, cols, size));
+    assert_eq!(A::bytes_of_svp_ppol(n, cols), B::bytes_of_svp_ppol(n, cols));
+    assert_eq!(
+        A::bytes_of_vmp_pmat(n, rows, cols, cols, size),
+        B::bytes_of_vmp_pmat(n, rows, cols, cols, size)
+    );
+    assert_eq!(
+        A::bytes_of_cnv_pvec_left(n, cols, size),
+        B::bytes_of_cnv_pvec_left(n, cols, size)
+    );
+    assert_eq!(
+        A::bytes_of_cnv_pvec_right(n, cols, size),
+        B::bytes_of_cnv_pvec_right(n, cols, size)
+    );
+}
+
+// The retagging helpers below are only called between a `DynBackend<F>` and a
+// member `B` of `F`, whose scalar and buffer types are equal to those of
+// `F::Layout` (enforced by `Module::from_module`). Membership is only known at
+// runtime, so they bypass the `SharedLayout` bound of their public counterparts
+// in `oep`.
+
+fn retag<T: Retag, B: Backend>(x: &T) -> &T::Tagged<B> {
+    unsafe { &*(x as *const T as *const T::Tagged<B>) }
+}
+
+fn retag_mut<T: Retag, B: Backend>(x: &mut T) -> &mut T::Tagged<B> {
+    unsafe { &mut *(x as *mut T as *mut T::Tagged<B>) }
+}
+
+fn retag_scratch<A: Backend, B: Backend>(scratch: &mut Scratch<A>) -> &mut Scratch<B> {
+    unsafe { &mut *(scratch as *mut Scratch<A> as *mut Scratch<B>) }
+}
+
+fn retag_scratch_ref<A: Backend, B: Backend>(scratch: &Scratch<A>) -> &Scratch<B> {
+    unsafe { &*(scratch as *const Scratch<A> as *const Scratch<B>) }
+}
+
+fn retag_scratch_owned<A, B>(scratch: &mut ScratchOwned<A>) -> &mut ScratchOwned<B>
+where
+    A: Backend,
+    B: Backend<OwnedBuf = A::OwnedBuf>,
+{
+    unsafe { &mut *(scratch as *mut ScratchOwned<A> as *mut ScratchOwned<B>) }
+}
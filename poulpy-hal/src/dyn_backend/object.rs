@@ -0,0 +1,1720 @@
+use std::marker::PhantomData;
+
+use crate::{
+    layouts::{
+        Backend, CnvPVecL, CnvPVecR, MatZnx, Module, NoiseInfos, ScalarZnx, Scratch, SvpPPol, VecZnx, VecZnxBig, VecZnxDft,
+        VmpPMat,
+    },
+    oep::HalImpl,
+    source::Source,
+};
+
+use super::{DynBackend, DynBackendFamily, retag, retag_mut, retag_scratch};
+
+/// Object-safe counterpart of [`HalImpl`], implemented by the modules of the
+/// members of a [`DynBackendFamily`].
+///
+/// Generic layout parameters are replaced by borrowed views; backend-tagged
+/// views and scratch carry the [`DynBackend`] tag and are retagged to the
+/// concrete backend before the call is forwarded. Static methods of
+/// [`HalImpl`] are served by [`DynBackendFamily::Layout`] and have no
+/// counterpart here.
+#[allow(clippy::too_many_arguments)]
+pub(crate) trait DynHal<F: DynBackendFamily>: Send + Sync {
+    fn vec_znx_zero(&self, res: &mut VecZnx<&mut [u8]>, res_col: usize);
+
+    fn vec_znx_normalize_tmp_bytes(&self) -> usize;
+
+    fn vec_znx_normalize(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_base2k: usize,
+        res_offset: i64,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_base2k: usize,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_normalize_assign(
+        &self,
+        base2k: usize,
+        a: &mut VecZnx<&mut [u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_add_into(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        b: &VecZnx<&[u8]>,
+        b_col: usize,
+    );
+
+    fn vec_znx_add_assign(&self, res: &mut VecZnx<&mut [u8]>, res_col: usize, a: &VecZnx<&[u8]>, a_col: usize);
+
+    fn vec_znx_add_scalar_into(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &ScalarZnx<&[u8]>,
+        a_col: usize,
+        b: &VecZnx<&[u8]>,
+        b_col: usize,
+        b_limb: usize,
+    );
+
+    fn vec_znx_add_scalar_assign(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        res_limb: usize,
+        a: &ScalarZnx<&[u8]>,
+        a_col: usize,
+    );
+
+    fn vec_znx_sub(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        b: &VecZnx<&[u8]>,
+        b_col: usize,
+    );
+
+    fn vec_znx_sub_assign(&self, res: &mut VecZnx<&mut [u8]>, res_col: usize, a: &VecZnx<&[u8]>, a_col: usize);
+
+    fn vec_znx_sub_negate_assign(&self, res: &mut VecZnx<&mut [u8]>, res_col: usize, a: &VecZnx<&[u8]>, a_col: usize);
+
+    fn vec_znx_sub_scalar(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &ScalarZnx<&[u8]>,
+        a_col: usize,
+        b: &VecZnx<&[u8]>,
+        b_col: usize,
+        b_limb: usize,
+    );
+
+    fn vec_znx_sub_scalar_assign(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        res_limb: usize,
+        a: &ScalarZnx<&[u8]>,
+        a_col: usize,
+    );
+
+    fn vec_znx_negate(&self, res: &mut VecZnx<&mut [u8]>, res_col: usize, a: &VecZnx<&[u8]>, a_col: usize);
+
+    fn vec_znx_negate_assign(&self, a: &mut VecZnx<&mut [u8]>, a_col: usize);
+
+    fn vec_znx_rsh_tmp_bytes(&self) -> usize;
+
+    fn vec_znx_rsh(
+        &self,
+        base2k: usize,
+        k: usize,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_rsh_add_into(
+        &self,
+        base2k: usize,
+        k: usize,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_lsh_tmp_bytes(&self) -> usize;
+
+    fn vec_znx_lsh(
+        &self,
+        base2k: usize,
+        k: usize,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_lsh_add_into(
+        &self,
+        base2k: usize,
+        k: usize,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_lsh_sub(
+        &self,
+        base2k: usize,
+        k: usize,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_rsh_sub(
+        &self,
+        base2k: usize,
+        k: usize,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_rsh_assign(
+        &self,
+        base2k: usize,
+        k: usize,
+        a: &mut VecZnx<&mut [u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_lsh_assign(
+        &self,
+        base2k: usize,
+        k: usize,
+        a: &mut VecZnx<&mut [u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_rotate(&self, k: i64, res: &mut VecZnx<&mut [u8]>, res_col: usize, a: &VecZnx<&[u8]>, a_col: usize);
+
+    fn vec_znx_rotate_assign_tmp_bytes(&self) -> usize;
+
+    fn vec_znx_rotate_assign(&self, k: i64, a: &mut VecZnx<&mut [u8]>, a_col: usize, scratch: &mut Scratch<DynBackend<F>>);
+
+    fn vec_znx_automorphism(&self, k: i64, res: &mut VecZnx<&mut [u8]>, res_col: usize, a: &VecZnx<&[u8]>, a_col: usize);
+
+    fn vec_znx_automorphism_assign_tmp_bytes(&self) -> usize;
+
+    fn vec_znx_automorphism_assign(
+        &self,
+        k: i64,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_mul_xp_minus_one(&self, k: i64, res: &mut VecZnx<&mut [u8]>, res_col: usize, a: &VecZnx<&[u8]>, a_col: usize);
+
+    fn vec_znx_mul_xp_minus_one_assign_tmp_bytes(&self) -> usize;
+
+    fn vec_znx_mul_xp_minus_one_assign(
+        &self,
+        k: i64,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_split_ring_tmp_bytes(&self) -> usize;
+
+    fn vec_znx_split_ring(
+        &self,
+        res: &mut [VecZnx<&mut [u8]>],
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_merge_rings_tmp_bytes(&self) -> usize;
+
+    fn vec_znx_merge_rings(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &[VecZnx<&[u8]>],
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_switch_ring(&self, res: &mut VecZnx<&mut [u8]>, res_col: usize, a: &VecZnx<&[u8]>, a_col: usize);
+
+    fn vec_znx_copy(&self, res: &mut VecZnx<&mut [u8]>, res_col: usize, a: &VecZnx<&[u8]>, a_col: usize);
+
+    fn vec_znx_fill_uniform(&self, base2k: usize, res: &mut VecZnx<&mut [u8]>, res_col: usize, source: &mut Source);
+
+    fn vec_znx_fill_normal(
+        &self,
+        res_base2k: usize,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        noise_infos: NoiseInfos,
+        source: &mut Source,
+    );
+
+    fn vec_znx_add_normal(
+        &self,
+        res_base2k: usize,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        noise_infos: NoiseInfos,
+        source: &mut Source,
+    );
+
+    fn vec_znx_big_add_normal(
+        &self,
+        res_base2k: usize,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        noise_infos: NoiseInfos,
+        source: &mut Source,
+    );
+
+    fn vec_znx_big_add_into(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_col: usize,
+        b: &VecZnxBig<&[u8], DynBackend<F>>,
+        b_col: usize,
+    );
+
+    fn vec_znx_big_add_assign(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_col: usize,
+    );
+
+    fn vec_znx_big_add_small_into(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_col: usize,
+        b: &VecZnx<&[u8]>,
+        b_col: usize,
+    );
+
+    fn vec_znx_big_add_small_assign(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+    );
+
+    fn vec_znx_big_sub(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_col: usize,
+        b: &VecZnxBig<&[u8], DynBackend<F>>,
+        b_col: usize,
+    );
+
+    fn vec_znx_big_sub_assign(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_col: usize,
+    );
+
+    fn vec_znx_big_sub_negate_assign(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_col: usize,
+    );
+
+    fn vec_znx_big_sub_small_a(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        b: &VecZnxBig<&[u8], DynBackend<F>>,
+        b_col: usize,
+    );
+
+    fn vec_znx_big_sub_small_assign(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+    );
+
+    fn vec_znx_big_sub_small_b(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_col: usize,
+        b: &VecZnx<&[u8]>,
+        b_col: usize,
+    );
+
+    fn vec_znx_big_sub_small_negate_assign(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+    );
+
+    fn vec_znx_big_negate(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_col: usize,
+    );
+
+    fn vec_znx_big_negate_assign(&self, a: &mut VecZnxBig<&mut [u8], DynBackend<F>>, a_col: usize);
+
+    fn vec_znx_big_normalize_tmp_bytes(&self) -> usize;
+
+    fn vec_znx_big_normalize(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_base2k: usize,
+        res_offset: i64,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_base2k: usize,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_big_normalize_add_assign(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_base2k: usize,
+        res_offset: i64,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_base2k: usize,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_big_normalize_sub_assign(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_base2k: usize,
+        res_offset: i64,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_base2k: usize,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_big_automorphism(
+        &self,
+        k: i64,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_col: usize,
+    );
+
+    fn vec_znx_big_automorphism_assign_tmp_bytes(&self) -> usize;
+
+    fn vec_znx_big_automorphism_assign(
+        &self,
+        k: i64,
+        a: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_dft_apply(
+        &self,
+        step: usize,
+        offset: usize,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+    );
+
+    fn vec_znx_idft_apply_tmp_bytes(&self) -> usize;
+
+    fn vec_znx_idft_apply(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxDft<&[u8], DynBackend<F>>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vec_znx_idft_apply_tmpa(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        a_col: usize,
+    );
+
+    /// Runs the in-place inverse DFT of [`HalImpl::vec_znx_idft_apply_consume`]
+    /// on a borrowed view; the caller reinterprets its buffer afterwards.
+    fn vec_znx_idft_apply_consume(&self, a: VecZnxDft<&mut [u8], DynBackend<F>>);
+
+    fn vec_znx_dft_add_into(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxDft<&[u8], DynBackend<F>>,
+        a_col: usize,
+        b: &VecZnxDft<&[u8], DynBackend<F>>,
+        b_col: usize,
+    );
+
+    fn vec_znx_dft_add_scaled_assign(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxDft<&[u8], DynBackend<F>>,
+        a_col: usize,
+        a_scale: i64,
+    );
+
+    fn vec_znx_dft_add_assign(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxDft<&[u8], DynBackend<F>>,
+        a_col: usize,
+    );
+
+    fn vec_znx_dft_sub(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxDft<&[u8], DynBackend<F>>,
+        a_col: usize,
+        b: &VecZnxDft<&[u8], DynBackend<F>>,
+        b_col: usize,
+    );
+
+    fn vec_znx_dft_sub_assign(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxDft<&[u8], DynBackend<F>>,
+        a_col: usize,
+    );
+
+    fn vec_znx_dft_sub_negate_assign(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxDft<&[u8], DynBackend<F>>,
+        a_col: usize,
+    );
+
+    fn vec_znx_dft_copy(
+        &self,
+        step: usize,
+        offset: usize,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxDft<&[u8], DynBackend<F>>,
+        a_col: usize,
+    );
+
+    fn vec_znx_dft_zero(&self, res: &mut VecZnxDft<&mut [u8], DynBackend<F>>, res_col: usize);
+
+    fn svp_prepare(&self, res: &mut SvpPPol<&mut [u8], DynBackend<F>>, res_col: usize, a: &ScalarZnx<&[u8]>, a_col: usize);
+
+    fn svp_apply_dft(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &SvpPPol<&[u8], DynBackend<F>>,
+        a_col: usize,
+        b: &VecZnx<&[u8]>,
+        b_col: usize,
+    );
+
+    fn svp_apply_dft_to_dft(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &SvpPPol<&[u8], DynBackend<F>>,
+        a_col: usize,
+        b: &VecZnxDft<&[u8], DynBackend<F>>,
+        b_col: usize,
+    );
+
+    fn svp_apply_dft_to_dft_assign(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &SvpPPol<&[u8], DynBackend<F>>,
+        a_col: usize,
+    );
+
+    fn vmp_prepare_tmp_bytes(&self, rows: usize, cols_in: usize, cols_out: usize, size: usize) -> usize;
+
+    fn vmp_prepare(&self, res: &mut VmpPMat<&mut [u8], DynBackend<F>>, a: &MatZnx<&[u8]>, scratch: &mut Scratch<DynBackend<F>>);
+
+    fn vmp_apply_dft_tmp_bytes(
+        &self,
+        res_size: usize,
+        a_size: usize,
+        b_rows: usize,
+        b_cols_in: usize,
+        b_cols_out: usize,
+        b_size: usize,
+    ) -> usize;
+
+    fn vmp_apply_dft(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        a: &VecZnx<&[u8]>,
+        b: &VmpPMat<&[u8], DynBackend<F>>,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vmp_apply_dft_to_dft_tmp_bytes(
+        &self,
+        res_size: usize,
+        a_size: usize,
+        b_rows: usize,
+        b_cols_in: usize,
+        b_cols_out: usize,
+        b_size: usize,
+    ) -> usize;
+
+    fn vmp_apply_dft_to_dft(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        a: &VecZnxDft<&[u8], DynBackend<F>>,
+        b: &VmpPMat<&[u8], DynBackend<F>>,
+        limb_offset: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn vmp_zero(&self, res: &mut VmpPMat<&mut [u8], DynBackend<F>>);
+
+    fn cnv_prepare_left_tmp_bytes(&self, res_size: usize, a_size: usize) -> usize;
+
+    fn cnv_prepare_left(
+        &self,
+        res: &mut CnvPVecL<&mut [u8], DynBackend<F>>,
+        a: &VecZnx<&[u8]>,
+        mask: i64,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn cnv_prepare_right_tmp_bytes(&self, res_size: usize, a_size: usize) -> usize;
+
+    fn cnv_prepare_right(
+        &self,
+        res: &mut CnvPVecR<&mut [u8], DynBackend<F>>,
+        a: &VecZnx<&[u8]>,
+        mask: i64,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn cnv_apply_dft_tmp_bytes(&self, cnv_offset: usize, res_size: usize, a_size: usize, b_size: usize) -> usize;
+
+    fn cnv_by_const_apply_tmp_bytes(&self, cnv_offset: usize, res_size: usize, a_size: usize, b_size: usize) -> usize;
+
+    fn cnv_by_const_apply(
+        &self,
+        cnv_offset: usize,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        b: &[i64],
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn cnv_apply_dft(
+        &self,
+        cnv_offset: usize,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &CnvPVecL<&[u8], DynBackend<F>>,
+        a_col: usize,
+        b: &CnvPVecR<&[u8], DynBackend<F>>,
+        b_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn cnv_pairwise_apply_dft_tmp_bytes(&self, cnv_offset: usize, res_size: usize, a_size: usize, b_size: usize) -> usize;
+
+    fn cnv_pairwise_apply_dft(
+        &self,
+        cnv_offset: usize,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &CnvPVecL<&[u8], DynBackend<F>>,
+        b: &CnvPVecR<&[u8], DynBackend<F>>,
+        i: usize,
+        j: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+
+    fn cnv_prepare_self_tmp_bytes(&self, res_size: usize, a_size: usize) -> usize;
+
+    fn cnv_prepare_self(
+        &self,
+        left: &mut CnvPVecL<&mut [u8], DynBackend<F>>,
+        right: &mut CnvPVecR<&mut [u8], DynBackend<F>>,
+        a: &VecZnx<&[u8]>,
+        mask: i64,
+        scratch: &mut Scratch<DynBackend<F>>,
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+impl<F, B> DynHal<F> for Module<B>
+where
+    F: DynBackendFamily,
+    B: Backend<
+            ScalarBig = <F::Layout as Backend>::ScalarBig,
+            ScalarPrep = <F::Layout as Backend>::ScalarPrep,
+            OwnedBuf = <F::Layout as Backend>::OwnedBuf,
+        > + HalImpl<B>,
+{
+    fn vec_znx_zero(&self, res: &mut VecZnx<&mut [u8]>, res_col: usize) {
+        <B as HalImpl<B>>::vec_znx_zero(self, res, res_col)
+    }
+
+    fn vec_znx_normalize_tmp_bytes(&self) -> usize {
+        <B as HalImpl<B>>::vec_znx_normalize_tmp_bytes(self)
+    }
+
+    fn vec_znx_normalize(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_base2k: usize,
+        res_offset: i64,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_base2k: usize,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_normalize(
+            self,
+            res,
+            res_base2k,
+            res_offset,
+            res_col,
+            a,
+            a_base2k,
+            a_col,
+            retag_scratch(scratch),
+        )
+    }
+
+    fn vec_znx_normalize_assign(
+        &self,
+        base2k: usize,
+        a: &mut VecZnx<&mut [u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_normalize_assign(self, base2k, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_add_into(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        b: &VecZnx<&[u8]>,
+        b_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_add_into(self, res, res_col, a, a_col, b, b_col)
+    }
+
+    fn vec_znx_add_assign(&self, res: &mut VecZnx<&mut [u8]>, res_col: usize, a: &VecZnx<&[u8]>, a_col: usize) {
+        <B as HalImpl<B>>::vec_znx_add_assign(self, res, res_col, a, a_col)
+    }
+
+    fn vec_znx_add_scalar_into(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &ScalarZnx<&[u8]>,
+        a_col: usize,
+        b: &VecZnx<&[u8]>,
+        b_col: usize,
+        b_limb: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_add_scalar_into(self, res, res_col, a, a_col, b, b_col, b_limb)
+    }
+
+    fn vec_znx_add_scalar_assign(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        res_limb: usize,
+        a: &ScalarZnx<&[u8]>,
+        a_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_add_scalar_assign(self, res, res_col, res_limb, a, a_col)
+    }
+
+    fn vec_znx_sub(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        b: &VecZnx<&[u8]>,
+        b_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_sub(self, res, res_col, a, a_col, b, b_col)
+    }
+
+    fn vec_znx_sub_assign(&self, res: &mut VecZnx<&mut [u8]>, res_col: usize, a: &VecZnx<&[u8]>, a_col: usize) {
+        <B as HalImpl<B>>::vec_znx_sub_assign(self, res, res_col, a, a_col)
+    }
+
+    fn vec_znx_sub_negate_assign(&self, res: &mut VecZnx<&mut [u8]>, res_col: usize, a: &VecZnx<&[u8]>, a_col: usize) {
+        <B as HalImpl<B>>::vec_znx_sub_negate_assign(self, res, res_col, a, a_col)
+    }
+
+    fn vec_znx_sub_scalar(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &ScalarZnx<&[u8]>,
+        a_col: usize,
+        b: &VecZnx<&[u8]>,
+        b_col: usize,
+        b_limb: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_sub_scalar(self, res, res_col, a, a_col, b, b_col, b_limb)
+    }
+
+    fn vec_znx_sub_scalar_assign(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        res_limb: usize,
+        a: &ScalarZnx<&[u8]>,
+        a_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_sub_scalar_assign(self, res, res_col, res_limb, a, a_col)
+    }
+
+    fn vec_znx_negate(&self, res: &mut VecZnx<&mut [u8]>, res_col: usize, a: &VecZnx<&[u8]>, a_col: usize) {
+        <B as HalImpl<B>>::vec_znx_negate(self, res, res_col, a, a_col)
+    }
+
+    fn vec_znx_negate_assign(&self, a: &mut VecZnx<&mut [u8]>, a_col: usize) {
+        <B as HalImpl<B>>::vec_znx_negate_assign(self, a, a_col)
+    }
+
+    fn vec_znx_rsh_tmp_bytes(&self) -> usize {
+        <B as HalImpl<B>>::vec_znx_rsh_tmp_bytes(self)
+    }
+
+    fn vec_znx_rsh(
+        &self,
+        base2k: usize,
+        k: usize,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_rsh(self, base2k, k, res, res_col, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_rsh_add_into(
+        &self,
+        base2k: usize,
+        k: usize,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_rsh_add_into(self, base2k, k, res, res_col, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_lsh_tmp_bytes(&self) -> usize {
+        <B as HalImpl<B>>::vec_znx_lsh_tmp_bytes(self)
+    }
+
+    fn vec_znx_lsh(
+        &self,
+        base2k: usize,
+        k: usize,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_lsh(self, base2k, k, res, res_col, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_lsh_add_into(
+        &self,
+        base2k: usize,
+        k: usize,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_lsh_add_into(self, base2k, k, res, res_col, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_lsh_sub(
+        &self,
+        base2k: usize,
+        k: usize,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_lsh_sub(self, base2k, k, res, res_col, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_rsh_sub(
+        &self,
+        base2k: usize,
+        k: usize,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_rsh_sub(self, base2k, k, res, res_col, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_rsh_assign(
+        &self,
+        base2k: usize,
+        k: usize,
+        a: &mut VecZnx<&mut [u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_rsh_assign(self, base2k, k, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_lsh_assign(
+        &self,
+        base2k: usize,
+        k: usize,
+        a: &mut VecZnx<&mut [u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_lsh_assign(self, base2k, k, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_rotate(&self, k: i64, res: &mut VecZnx<&mut [u8]>, res_col: usize, a: &VecZnx<&[u8]>, a_col: usize) {
+        <B as HalImpl<B>>::vec_znx_rotate(self, k, res, res_col, a, a_col)
+    }
+
+    fn vec_znx_rotate_assign_tmp_bytes(&self) -> usize {
+        <B as HalImpl<B>>::vec_znx_rotate_assign_tmp_bytes(self)
+    }
+
+    fn vec_znx_rotate_assign(&self, k: i64, a: &mut VecZnx<&mut [u8]>, a_col: usize, scratch: &mut Scratch<DynBackend<F>>) {
+        <B as HalImpl<B>>::vec_znx_rotate_assign(self, k, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_automorphism(&self, k: i64, res: &mut VecZnx<&mut [u8]>, res_col: usize, a: &VecZnx<&[u8]>, a_col: usize) {
+        <B as HalImpl<B>>::vec_znx_automorphism(self, k, res, res_col, a, a_col)
+    }
+
+    fn vec_znx_automorphism_assign_tmp_bytes(&self) -> usize {
+        <B as HalImpl<B>>::vec_znx_automorphism_assign_tmp_bytes(self)
+    }
+
+    fn vec_znx_automorphism_assign(
+        &self,
+        k: i64,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_automorphism_assign(self, k, res, res_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_mul_xp_minus_one(&self, k: i64, res: &mut VecZnx<&mut [u8]>, res_col: usize, a: &VecZnx<&[u8]>, a_col: usize) {
+        <B as HalImpl<B>>::vec_znx_mul_xp_minus_one(self, k, res, res_col, a, a_col)
+    }
+
+    fn vec_znx_mul_xp_minus_one_assign_tmp_bytes(&self) -> usize {
+        <B as HalImpl<B>>::vec_znx_mul_xp_minus_one_assign_tmp_bytes(self)
+    }
+
+    fn vec_znx_mul_xp_minus_one_assign(
+        &self,
+        k: i64,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_mul_xp_minus_one_assign(self, k, res, res_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_split_ring_tmp_bytes(&self) -> usize {
+        <B as HalImpl<B>>::vec_znx_split_ring_tmp_bytes(self)
+    }
+
+    fn vec_znx_split_ring(
+        &self,
+        res: &mut [VecZnx<&mut [u8]>],
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_split_ring(self, res, res_col, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_merge_rings_tmp_bytes(&self) -> usize {
+        <B as HalImpl<B>>::vec_znx_merge_rings_tmp_bytes(self)
+    }
+
+    fn vec_znx_merge_rings(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        a: &[VecZnx<&[u8]>],
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_merge_rings(self, res, res_col, a, a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_switch_ring(&self, res: &mut VecZnx<&mut [u8]>, res_col: usize, a: &VecZnx<&[u8]>, a_col: usize) {
+        <B as HalImpl<B>>::vec_znx_switch_ring(self, res, res_col, a, a_col)
+    }
+
+    fn vec_znx_copy(&self, res: &mut VecZnx<&mut [u8]>, res_col: usize, a: &VecZnx<&[u8]>, a_col: usize) {
+        <B as HalImpl<B>>::vec_znx_copy(self, res, res_col, a, a_col)
+    }
+
+    fn vec_znx_fill_uniform(&self, base2k: usize, res: &mut VecZnx<&mut [u8]>, res_col: usize, source: &mut Source) {
+        <B as HalImpl<B>>::vec_znx_fill_uniform(self, base2k, res, res_col, source)
+    }
+
+    fn vec_znx_fill_normal(
+        &self,
+        res_base2k: usize,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        noise_infos: NoiseInfos,
+        source: &mut Source,
+    ) {
+        <B as HalImpl<B>>::vec_znx_fill_normal(self, res_base2k, res, res_col, noise_infos, source)
+    }
+
+    fn vec_znx_add_normal(
+        &self,
+        res_base2k: usize,
+        res: &mut VecZnx<&mut [u8]>,
+        res_col: usize,
+        noise_infos: NoiseInfos,
+        source: &mut Source,
+    ) {
+        <B as HalImpl<B>>::vec_znx_add_normal(self, res_base2k, res, res_col, noise_infos, source)
+    }
+
+    fn vec_znx_big_add_normal(
+        &self,
+        res_base2k: usize,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        noise_infos: NoiseInfos,
+        source: &mut Source,
+    ) {
+        <B as HalImpl<B>>::vec_znx_big_add_normal(self, res_base2k, retag_mut::<_, B>(res), res_col, noise_infos, source)
+    }
+
+    fn vec_znx_big_add_into(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_col: usize,
+        b: &VecZnxBig<&[u8], DynBackend<F>>,
+        b_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_big_add_into(
+            self,
+            retag_mut::<_, B>(res),
+            res_col,
+            retag::<_, B>(a),
+            a_col,
+            retag::<_, B>(b),
+            b_col,
+        )
+    }
+
+    fn vec_znx_big_add_assign(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_big_add_assign(self, retag_mut::<_, B>(res), res_col, retag::<_, B>(a), a_col)
+    }
+
+    fn vec_znx_big_add_small_into(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_col: usize,
+        b: &VecZnx<&[u8]>,
+        b_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_big_add_small_into(self, retag_mut::<_, B>(res), res_col, retag::<_, B>(a), a_col, b, b_col)
+    }
+
+    fn vec_znx_big_add_small_assign(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_big_add_small_assign(self, retag_mut::<_, B>(res), res_col, a, a_col)
+    }
+
+    fn vec_znx_big_sub(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_col: usize,
+        b: &VecZnxBig<&[u8], DynBackend<F>>,
+        b_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_big_sub(
+            self,
+            retag_mut::<_, B>(res),
+            res_col,
+            retag::<_, B>(a),
+            a_col,
+            retag::<_, B>(b),
+            b_col,
+        )
+    }
+
+    fn vec_znx_big_sub_assign(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_big_sub_assign(self, retag_mut::<_, B>(res), res_col, retag::<_, B>(a), a_col)
+    }
+
+    fn vec_znx_big_sub_negate_assign(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_big_sub_negate_assign(self, retag_mut::<_, B>(res), res_col, retag::<_, B>(a), a_col)
+    }
+
+    fn vec_znx_big_sub_small_a(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        b: &VecZnxBig<&[u8], DynBackend<F>>,
+        b_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_big_sub_small_a(self, retag_mut::<_, B>(res), res_col, a, a_col, retag::<_, B>(b), b_col)
+    }
+
+    fn vec_znx_big_sub_small_assign(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_big_sub_small_assign(self, retag_mut::<_, B>(res), res_col, a, a_col)
+    }
+
+    fn vec_znx_big_sub_small_b(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_col: usize,
+        b: &VecZnx<&[u8]>,
+        b_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_big_sub_small_b(self, retag_mut::<_, B>(res), res_col, retag::<_, B>(a), a_col, b, b_col)
+    }
+
+    fn vec_znx_big_sub_small_negate_assign(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_big_sub_small_negate_assign(self, retag_mut::<_, B>(res), res_col, a, a_col)
+    }
+
+    fn vec_znx_big_negate(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_big_negate(self, retag_mut::<_, B>(res), res_col, retag::<_, B>(a), a_col)
+    }
+
+    fn vec_znx_big_negate_assign(&self, a: &mut VecZnxBig<&mut [u8], DynBackend<F>>, a_col: usize) {
+        <B as HalImpl<B>>::vec_znx_big_negate_assign(self, retag_mut::<_, B>(a), a_col)
+    }
+
+    fn vec_znx_big_normalize_tmp_bytes(&self) -> usize {
+        <B as HalImpl<B>>::vec_znx_big_normalize_tmp_bytes(self)
+    }
+
+    fn vec_znx_big_normalize(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_base2k: usize,
+        res_offset: i64,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_base2k: usize,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_big_normalize(
+            self,
+            res,
+            res_base2k,
+            res_offset,
+            res_col,
+            retag::<_, B>(a),
+            a_base2k,
+            a_col,
+            retag_scratch(scratch),
+        )
+    }
+
+    fn vec_znx_big_normalize_add_assign(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_base2k: usize,
+        res_offset: i64,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_base2k: usize,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_big_normalize_add_assign(
+            self,
+            res,
+            res_base2k,
+            res_offset,
+            res_col,
+            retag::<_, B>(a),
+            a_base2k,
+            a_col,
+            retag_scratch(scratch),
+        )
+    }
+
+    fn vec_znx_big_normalize_sub_assign(
+        &self,
+        res: &mut VecZnx<&mut [u8]>,
+        res_base2k: usize,
+        res_offset: i64,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_base2k: usize,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_big_normalize_sub_assign(
+            self,
+            res,
+            res_base2k,
+            res_offset,
+            res_col,
+            retag::<_, B>(a),
+            a_base2k,
+            a_col,
+            retag_scratch(scratch),
+        )
+    }
+
+    fn vec_znx_big_automorphism(
+        &self,
+        k: i64,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxBig<&[u8], DynBackend<F>>,
+        a_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_big_automorphism(self, k, retag_mut::<_, B>(res), res_col, retag::<_, B>(a), a_col)
+    }
+
+    fn vec_znx_big_automorphism_assign_tmp_bytes(&self) -> usize {
+        <B as HalImpl<B>>::vec_znx_big_automorphism_assign_tmp_bytes(self)
+    }
+
+    fn vec_znx_big_automorphism_assign(
+        &self,
+        k: i64,
+        a: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_big_automorphism_assign(self, k, retag_mut::<_, B>(a), a_col, retag_scratch(scratch))
+    }
+
+    fn vec_znx_dft_apply(
+        &self,
+        step: usize,
+        offset: usize,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_dft_apply(self, step, offset, retag_mut::<_, B>(res), res_col, a, a_col)
+    }
+
+    fn vec_znx_idft_apply_tmp_bytes(&self) -> usize {
+        <B as HalImpl<B>>::vec_znx_idft_apply_tmp_bytes(self)
+    }
+
+    fn vec_znx_idft_apply(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxDft<&[u8], DynBackend<F>>,
+        a_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vec_znx_idft_apply(
+            self,
+            retag_mut::<_, B>(res),
+            res_col,
+            retag::<_, B>(a),
+            a_col,
+            retag_scratch(scratch),
+        )
+    }
+
+    fn vec_znx_idft_apply_tmpa(
+        &self,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        a_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_idft_apply_tmpa(self, retag_mut::<_, B>(res), res_col, retag_mut::<_, B>(a), a_col)
+    }
+
+    fn vec_znx_idft_apply_consume(&self, a: VecZnxDft<&mut [u8], DynBackend<F>>) {
+        let a: VecZnxDft<&mut [u8], B> = VecZnxDft {
+            data: a.data,
+            n: a.n,
+            cols: a.cols,
+            size: a.size,
+            max_size: a.max_size,
+            _phantom: PhantomData,
+        };
+        <B as HalImpl<B>>::vec_znx_idft_apply_consume(self, a);
+    }
+
+    fn vec_znx_dft_add_into(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxDft<&[u8], DynBackend<F>>,
+        a_col: usize,
+        b: &VecZnxDft<&[u8], DynBackend<F>>,
+        b_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_dft_add_into(
+            self,
+            retag_mut::<_, B>(res),
+            res_col,
+            retag::<_, B>(a),
+            a_col,
+            retag::<_, B>(b),
+            b_col,
+        )
+    }
+
+    fn vec_znx_dft_add_scaled_assign(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxDft<&[u8], DynBackend<F>>,
+        a_col: usize,
+        a_scale: i64,
+    ) {
+        <B as HalImpl<B>>::vec_znx_dft_add_scaled_assign(self, retag_mut::<_, B>(res), res_col, retag::<_, B>(a), a_col, a_scale)
+    }
+
+    fn vec_znx_dft_add_assign(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxDft<&[u8], DynBackend<F>>,
+        a_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_dft_add_assign(self, retag_mut::<_, B>(res), res_col, retag::<_, B>(a), a_col)
+    }
+
+    fn vec_znx_dft_sub(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxDft<&[u8], DynBackend<F>>,
+        a_col: usize,
+        b: &VecZnxDft<&[u8], DynBackend<F>>,
+        b_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_dft_sub(
+            self,
+            retag_mut::<_, B>(res),
+            res_col,
+            retag::<_, B>(a),
+            a_col,
+            retag::<_, B>(b),
+            b_col,
+        )
+    }
+
+    fn vec_znx_dft_sub_assign(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxDft<&[u8], DynBackend<F>>,
+        a_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_dft_sub_assign(self, retag_mut::<_, B>(res), res_col, retag::<_, B>(a), a_col)
+    }
+
+    fn vec_znx_dft_sub_negate_assign(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxDft<&[u8], DynBackend<F>>,
+        a_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_dft_sub_negate_assign(self, retag_mut::<_, B>(res), res_col, retag::<_, B>(a), a_col)
+    }
+
+    fn vec_znx_dft_copy(
+        &self,
+        step: usize,
+        offset: usize,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnxDft<&[u8], DynBackend<F>>,
+        a_col: usize,
+    ) {
+        <B as HalImpl<B>>::vec_znx_dft_copy(self, step, offset, retag_mut::<_, B>(res), res_col, retag::<_, B>(a), a_col)
+    }
+
+    fn vec_znx_dft_zero(&self, res: &mut VecZnxDft<&mut [u8], DynBackend<F>>, res_col: usize) {
+        <B as HalImpl<B>>::vec_znx_dft_zero(self, retag_mut::<_, B>(res), res_col)
+    }
+
+    fn svp_prepare(&self, res: &mut SvpPPol<&mut [u8], DynBackend<F>>, res_col: usize, a: &ScalarZnx<&[u8]>, a_col: usize) {
+        <B as HalImpl<B>>::svp_prepare(self, retag_mut::<_, B>(res), res_col, a, a_col)
+    }
+
+    fn svp_apply_dft(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &SvpPPol<&[u8], DynBackend<F>>,
+        a_col: usize,
+        b: &VecZnx<&[u8]>,
+        b_col: usize,
+    ) {
+        <B as HalImpl<B>>::svp_apply_dft(self, retag_mut::<_, B>(res), res_col, retag::<_, B>(a), a_col, b, b_col)
+    }
+
+    fn svp_apply_dft_to_dft(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &SvpPPol<&[u8], DynBackend<F>>,
+        a_col: usize,
+        b: &VecZnxDft<&[u8], DynBackend<F>>,
+        b_col: usize,
+    ) {
+        <B as HalImpl<B>>::svp_apply_dft_to_dft(
+            self,
+            retag_mut::<_, B>(res),
+            res_col,
+            retag::<_, B>(a),
+            a_col,
+            retag::<_, B>(b),
+            b_col,
+        )
+    }
+
+    fn svp_apply_dft_to_dft_assign(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &SvpPPol<&[u8], DynBackend<F>>,
+        a_col: usize,
+    ) {
+        <B as HalImpl<B>>::svp_apply_dft_to_dft_assign(self, retag_mut::<_, B>(res), res_col, retag::<_, B>(a), a_col)
+    }
+
+    fn vmp_prepare_tmp_bytes(&self, rows: usize, cols_in: usize, cols_out: usize, size: usize) -> usize {
+        <B as HalImpl<B>>::vmp_prepare_tmp_bytes(self, rows, cols_in, cols_out, size)
+    }
+
+    fn vmp_prepare(&self, res: &mut VmpPMat<&mut [u8], DynBackend<F>>, a: &MatZnx<&[u8]>, scratch: &mut Scratch<DynBackend<F>>) {
+        <B as HalImpl<B>>::vmp_prepare(self, retag_mut::<_, B>(res), a, retag_scratch(scratch))
+    }
+
+    fn vmp_apply_dft_tmp_bytes(
+        &self,
+        res_size: usize,
+        a_size: usize,
+        b_rows: usize,
+        b_cols_in: usize,
+        b_cols_out: usize,
+        b_size: usize,
+    ) -> usize {
+        <B as HalImpl<B>>::vmp_apply_dft_tmp_bytes(self, res_size, a_size, b_rows, b_cols_in, b_cols_out, b_size)
+    }
+
+    fn vmp_apply_dft(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        a: &VecZnx<&[u8]>,
+        b: &VmpPMat<&[u8], DynBackend<F>>,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vmp_apply_dft(self, retag_mut::<_, B>(res), a, retag::<_, B>(b), retag_scratch(scratch))
+    }
+
+    fn vmp_apply_dft_to_dft_tmp_bytes(
+        &self,
+        res_size: usize,
+        a_size: usize,
+        b_rows: usize,
+        b_cols_in: usize,
+        b_cols_out: usize,
+        b_size: usize,
+    ) -> usize {
+        <B as HalImpl<B>>::vmp_apply_dft_to_dft_tmp_bytes(self, res_size, a_size, b_rows, b_cols_in, b_cols_out, b_size)
+    }
+
+    fn vmp_apply_dft_to_dft(
+        &self,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        a: &VecZnxDft<&[u8], DynBackend<F>>,
+        b: &VmpPMat<&[u8], DynBackend<F>>,
+        limb_offset: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::vmp_apply_dft_to_dft(
+            self,
+            retag_mut::<_, B>(res),
+            retag::<_, B>(a),
+            retag::<_, B>(b),
+            limb_offset,
+            retag_scratch(scratch),
+        )
+    }
+
+    fn vmp_zero(&self, res: &mut VmpPMat<&mut [u8], DynBackend<F>>) {
+        <B as HalImpl<B>>::vmp_zero(self, retag_mut::<_, B>(res))
+    }
+
+    fn cnv_prepare_left_tmp_bytes(&self, res_size: usize, a_size: usize) -> usize {
+        <B as HalImpl<B>>::cnv_prepare_left_tmp_bytes(self, res_size, a_size)
+    }
+
+    fn cnv_prepare_left(
+        &self,
+        res: &mut CnvPVecL<&mut [u8], DynBackend<F>>,
+        a: &VecZnx<&[u8]>,
+        mask: i64,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::cnv_prepare_left(self, retag_mut::<_, B>(res), a, mask, retag_scratch(scratch))
+    }
+
+    fn cnv_prepare_right_tmp_bytes(&self, res_size: usize, a_size: usize) -> usize {
+        <B as HalImpl<B>>::cnv_prepare_right_tmp_bytes(self, res_size, a_size)
+    }
+
+    fn cnv_prepare_right(
+        &self,
+        res: &mut CnvPVecR<&mut [u8], DynBackend<F>>,
+        a: &VecZnx<&[u8]>,
+        mask: i64,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::cnv_prepare_right(self, retag_mut::<_, B>(res), a, mask, retag_scratch(scratch))
+    }
+
+    fn cnv_apply_dft_tmp_bytes(&self, cnv_offset: usize, res_size: usize, a_size: usize, b_size: usize) -> usize {
+        <B as HalImpl<B>>::cnv_apply_dft_tmp_bytes(self, cnv_offset, res_size, a_size, b_size)
+    }
+
+    fn cnv_by_const_apply_tmp_bytes(&self, cnv_offset: usize, res_size: usize, a_size: usize, b_size: usize) -> usize {
+        <B as HalImpl<B>>::cnv_by_const_apply_tmp_bytes(self, cnv_offset, res_size, a_size, b_size)
+    }
+
+    fn cnv_by_const_apply(
+        &self,
+        cnv_offset: usize,
+        res: &mut VecZnxBig<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &VecZnx<&[u8]>,
+        a_col: usize,
+        b: &[i64],
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::cnv_by_const_apply(
+            self,
+            cnv_offset,
+            retag_mut::<_, B>(res),
+            res_col,
+            a,
+            a_col,
+            b,
+            retag_scratch(scratch),
+        )
+    }
+
+    fn cnv_apply_dft(
+        &self,
+        cnv_offset: usize,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &CnvPVecL<&[u8], DynBackend<F>>,
+        a_col: usize,
+        b: &CnvPVecR<&[u8], DynBackend<F>>,
+        b_col: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::cnv_apply_dft(
+            self,
+            cnv_offset,
+            retag_mut::<_, B>(res),
+            res_col,
+            retag::<_, B>(a),
+            a_col,
+            retag::<_, B>(b),
+            b_col,
+            retag_scratch(scratch),
+        )
+    }
+
+    fn cnv_pairwise_apply_dft_tmp_bytes(&self, cnv_offset: usize, res_size: usize, a_size: usize, b_size: usize) -> usize {
+        <B as HalImpl<B>>::cnv_pairwise_apply_dft_tmp_bytes(self, cnv_offset, res_size, a_size, b_size)
+    }
+
+    fn cnv_pairwise_apply_dft(
+        &self,
+        cnv_offset: usize,
+        res: &mut VecZnxDft<&mut [u8], DynBackend<F>>,
+        res_col: usize,
+        a: &CnvPVecL<&[u8], DynBackend<F>>,
+        b: &CnvPVecR<&[u8], DynBackend<F>>,
+        i: usize,
+        j: usize,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::cnv_pairwise_apply_dft(
+            self,
+            cnv_offset,
+            retag_mut::<_, B>(res),
+            res_col,
+            retag::<_, B>(a),
+            retag::<_, B>(b),
+            i,
+            j,
+            retag_scratch(scratch),
+        )
+    }
+
+    fn cnv_prepare_self_tmp_bytes(&self, res_size: usize, a_size: usize) -> usize {
+        <B as HalImpl<B>>::cnv_prepare_self_tmp_bytes(self, res_size, a_size)
+    }
+
+    fn cnv_prepare_self(
+        &self,
+        left: &mut CnvPVecL<&mut [u8], DynBackend<F>>,
+        right: &mut CnvPVecR<&mut [u8], DynBackend<F>>,
+        a: &VecZnx<&[u8]>,
+        mask: i64,
+        scratch: &mut Scratch<DynBackend<F>>,
+    ) {
+        <B as HalImpl<B>>::cnv_prepare_self(
+            self,
+            retag_mut::<_, B>(left),
+            retag_mut::<_, B>(right),
+            a,
+            mask,
+            retag_scratch(scratch),
+        )
+    }
+}
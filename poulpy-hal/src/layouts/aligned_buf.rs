@@ -0,0 +1,185 @@
+use std::{
+    alloc::{Layout, alloc_zeroed, dealloc, handle_alloc_error},
+    fmt,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+use crate::{DEFAULTALIGN, layouts::ZeroizeOwned};
+
+/// Owned, zero-initialized byte buffer with a guaranteed alignment.
+///
+/// `AlignedBuf` is the owned storage of all layouts (e.g. `VecZnx<AlignedBuf>`)
+/// and the [`Backend::OwnedBuf`](crate::layouts::Backend::OwnedBuf) of the CPU
+/// backends. Unlike a `Vec<u8>` built from an over-aligned allocation, it
+/// remembers the [`Layout`] it was allocated with and releases the memory with
+/// that same layout.
+///
+/// The buffer has a fixed length, given at allocation: it dereferences to
+/// `[u8]` but cannot grow. Its start is aligned to [`DEFAULTALIGN`] bytes
+/// unless created with [`AlignedBuf::zeroed_with_align`].
+///
+/// Existing `Vec<u8>` / `&[u8]` data is converted with [`From`] (copy into a
+/// fresh aligned allocation) and converted back with [`AlignedBuf::into_vec`].
+pub struct AlignedBuf {
+    ptr: NonNull<u8>,
+    len: usize,
+    layout: Layout,
+}
+
+// SAFETY: `AlignedBuf` uniquely owns its allocation, like a `Vec<u8>`.
+unsafe impl Send for AlignedBuf {}
+// SAFETY: shared access only hands out `&[u8]`.
+unsafe impl Sync for AlignedBuf {}
+
+impl AlignedBuf {
+    /// Allocates `len` zeroed bytes aligned to [`DEFAULTALIGN`] bytes.
+    pub fn zeroed(len: usize) -> Self {
+        Self::zeroed_with_align(len, DEFAULTALIGN)
+    }
+
+    /// Allocates `len` zeroed bytes aligned to `align` bytes.
+    ///
+    /// The underlying allocation is rounded up to a multiple of `align`.
+    ///
+    /// # Panics
+    /// Panics if `align` is not a power of two.
+    pub fn zeroed_with_align(len: usize, align: usize) -> Self {
+        assert!(align.is_power_of_two(), "Alignment must be a power of two but is {align}");
+        let layout: Layout = Layout::from_size_align(len.next_multiple_of(align), align).expect("Invalid layout");
+        if layout.size() == 0 {
+            return Self {
+                // An aligned, non-null dangling pointer, never dereferenced
+                // for more than zero bytes nor deallocated.
+                ptr: NonNull::new(std::ptr::without_provenance_mut(align)).unwrap(),
+                len: 0,
+                layout,
+            };
+        }
+        // SAFETY: `layout` has a non-zero size.
+        let ptr: *mut u8 = unsafe { alloc_zeroed(layout) };
+        let Some(ptr) = NonNull::new(ptr) else {
+            handle_alloc_error(layout)
+        };
+        Self { ptr, len, layout }
+    }
+
+    /// Allocates a buffer aligned to [`DEFAULTALIGN`] bytes holding a copy of `bytes`.
+    pub fn from_slice(bytes: &[u8]) -> Self {
+        let mut buf: Self = Self::zeroed(bytes.len());
+        buf.copy_from_slice(bytes);
+        buf
+    }
+
+    /// Alignment in bytes of the start of the buffer.
+    pub fn align(&self) -> usize {
+        self.layout.align()
+    }
+
+    /// Shortens the buffer to `len` bytes, keeping the allocation.
+    ///
+    /// Has no effect if `len` is greater than the current length.
+    pub fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+
+    /// Copies the content into a `Vec<u8>`.
+    pub fn into_vec(self) -> Vec<u8> {
+        self.to_vec()
+    }
+}
+
+impl Drop for AlignedBuf {
+    fn drop(&mut self) {
+        if self.layout.size() != 0 {
+            // SAFETY: allocated in `zeroed_with_align` with this exact layout.
+            unsafe { dealloc(self.ptr.as_ptr(), self.layout) }
+        }
+    }
+}
+
+impl Deref for AlignedBuf {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        // SAFETY: `ptr` is valid for `len` initialized bytes.
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl DerefMut for AlignedBuf {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        // SAFETY: `ptr` is valid for `len` initialized bytes, uniquely owned.
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl AsRef<[u8]> for AlignedBuf {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl AsMut<[u8]> for AlignedBuf {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+impl Default for AlignedBuf {
+    fn default() -> Self {
+        Self::zeroed(0)
+    }
+}
+
+/// Clones into a new allocation with the same alignment.
+impl Clone for AlignedBuf {
+    fn clone(&self) -> Self {
+        let mut buf: Self = Self::zeroed_with_align(self.len, self.align());
+        buf.copy_from_slice(self);
+        buf
+    }
+}
+
+/// Compares the contents, regardless of alignment.
+impl PartialEq for AlignedBuf {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for AlignedBuf {}
+
+impl fmt::Debug for AlignedBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl From<&[u8]> for AlignedBuf {
+    fn from(bytes: &[u8]) -> Self {
+        Self::from_slice(bytes)
+    }
+}
+
+/// Copies the bytes into an aligned allocation.
+impl From<Vec<u8>> for AlignedBuf {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::from_slice(&bytes)
+    }
+}
+
+impl From<AlignedBuf> for Vec<u8> {
+    fn from(buf: AlignedBuf) -> Self {
+        buf.into_vec()
+    }
+}
+
+/// Zeroizes the whole allocation, including bytes dropped by [`AlignedBuf::truncate`].
+impl ZeroizeOwned for AlignedBuf {
+    fn zeroize_owned(&mut self) {
+        // SAFETY: `ptr` is valid for `layout.size()` initialized bytes, uniquely owned.
+        let bytes: &mut [u8] = unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.layout.size()) };
+        zeroize::Zeroize::zeroize(bytes);
+    }
+}
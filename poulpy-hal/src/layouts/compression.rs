@@ -0,0 +1,70 @@
+//! Streaming zstd compression of the [`WriterTo`] / [`ReaderFrom`] encodings.
+//!
+//! [`WriteZstd::write_zstd`] feeds the raw encoding of a layout through a
+//! zstd encoder that flushes compressed blocks to the destination as it
+//! goes, and [`ReadZstd::read_zstd`] decodes it back in place. Neither holds
+//! a second full copy of the object: memory overhead is bounded by the zstd
+//! window, regardless of the size of the key.
+//!
+//! Each call produces or consumes exactly one zstd frame (with content
+//! checksum), so several objects can be written back to back into the same
+//! stream and read back in order.
+
+use std::io::{BufRead, Error, ErrorKind, Read, Result, Write};
+
+use crate::layouts::{ReaderFrom, WriterTo};
+
+/// Default zstd compression level used by [`WriteZstd`] callers that have
+/// no preference. Matches the `zstd` crate default.
+pub const ZSTD_DEFAULT_LEVEL: i32 = zstd::DEFAULT_COMPRESSION_LEVEL;
+
+/// Writes the [`WriterTo`] encoding of a layout as a single zstd frame.
+///
+/// Blanket-implemented for every [`WriterTo`].
+pub trait WriteZstd {
+    /// Compresses the encoding of `self` into `writer` at zstd `level`
+    /// (`1..=22`, `0` selects [`ZSTD_DEFAULT_LEVEL`]).
+    fn write_zstd<W: Write>(&self, writer: &mut W, level: i32) -> Result<()>;
+}
+
+impl<T: WriterTo + ?Sized> WriteZstd for T {
+    fn write_zstd<W: Write>(&self, writer: &mut W, level: i32) -> Result<()> {
+        let mut encoder = zstd::stream::write::Encoder::new(writer, level)?;
+        encoder.include_checksum(true)?;
+        self.write_to(&mut encoder)?;
+        encoder.finish()?;
+        Ok(())
+    }
+}
+
+/// Reads a layout from a zstd frame written by [`WriteZstd::write_zstd`].
+///
+/// Blanket-implemented for every [`ReaderFrom`].
+pub trait ReadZstd {
+    /// Decompresses one frame from `reader` into `self`.
+    ///
+    /// `reader` must be buffered so that the decoder stops exactly at the
+    /// end of the frame and leaves any following bytes unread; wrap files
+    /// and sockets in a [`std::io::BufReader`]. Fails with
+    /// [`ErrorKind::InvalidData`] if the frame is corrupted or holds more
+    /// data than `self` consumes.
+    fn read_zstd<R: BufRead>(&mut self, reader: &mut R) -> Result<()>;
+}
+
+impl<T: ReaderFrom + ?Sized> ReadZstd for T {
+    fn read_zstd<R: BufRead>(&mut self, reader: &mut R) -> Result<()> {
+        let mut decoder = zstd::stream::read::Decoder::with_buffer(reader)?.single_frame();
+        self.read_from(&mut decoder)?;
+
+        // Drives the decoder to the end of the frame, which also verifies the
+        // content checksum.
+        let mut trailing: [u8; 1] = [0u8; 1];
+        if decoder.read(&mut trailing)? != 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "zstd frame holds more data than the layout consumed",
+            ));
+        }
+        Ok(())
+    }
+}
@@ -8,6 +8,7 @@ use crate::layouts::{Backend, Data, DataMut, DataRef, DataView, DataViewMut, Dev
 /// ready to be used as the right operand of
 /// [`Convolution::cnv_apply_dft`](crate::api::Convolution::cnv_apply_dft).
 /// Created via [`Convolution::cnv_prepare_right`](crate::api::Convolution::cnv_prepare_right).
+#[repr(C)]
 pub struct CnvPVecR<D: Data, BE: Backend> {
     data: D,
     n: usize,
@@ -96,6 +97,7 @@ impl<D: Data, B: Backend> CnvPVecR<D, B> {
 /// ready to be used as the left operand of
 /// [`Convolution::cnv_apply_dft`](crate::api::Convolution::cnv_apply_dft).
 /// Created via [`Convolution::cnv_prepare_left`](crate::api::Convolution::cnv_prepare_left).
+#[repr(C)]
 pub struct CnvPVecL<D: Data, BE: Backend> {
     data: D,
     n: usize,
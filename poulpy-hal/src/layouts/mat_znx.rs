@@ -1,8 +1,8 @@
 use crate::{
     alloc_aligned,
     layouts::{
-        Data, DataMut, DataRef, DataView, DataViewMut, DigestU64, FillUniform, ReaderFrom, ToOwnedDeep, VecZnx, WriterTo,
-        ZnxInfos, ZnxView, ZnxViewMut, ZnxZero,
+        AlignedBuf, Data, DataMut, DataRef, DataView, DataViewMut, DigestU64, FillUniform, ReaderFrom, ToOwnedDeep, VecZnx,
+        WriterTo, ZnxInfos, ZnxView, ZnxViewMut, ZnxZero,
     },
     source::Source,
 };
@@ -48,10 +48,10 @@ impl<D: DataRef> DigestU64 for MatZnx<D> {
 }
 
 impl<D: DataRef> ToOwnedDeep for MatZnx<D> {
-    type Owned = MatZnx<Vec<u8>>;
+    type Owned = MatZnx<AlignedBuf>;
     fn to_owned_deep(&self) -> Self::Owned {
         MatZnx {
-            data: self.data.as_ref().to_vec(),
+            data: AlignedBuf::from_slice(self.data.as_ref()),
             n: self.n,
             size: self.size,
             rows: self.rows,
@@ -118,15 +118,15 @@ impl<D: Data> MatZnx<D> {
     }
 }
 
-impl MatZnx<Vec<u8>> {
+impl MatZnx<AlignedBuf> {
     /// Returns the number of bytes required to store the matrix.
     pub fn bytes_of(n: usize, rows: usize, cols_in: usize, cols_out: usize, size: usize) -> usize {
-        rows * cols_in * VecZnx::<Vec<u8>>::bytes_of(n, cols_out, size)
+        rows * cols_in * VecZnx::<AlignedBuf>::bytes_of(n, cols_out, size)
     }
 
     /// Allocates a zero-initialized `MatZnx` aligned to [`DEFAULTALIGN`](crate::DEFAULTALIGN).
     pub fn alloc(n: usize, rows: usize, cols_in: usize, cols_out: usize, size: usize) -> Self {
-        let data: Vec<u8> = alloc_aligned(Self::bytes_of(n, rows, cols_in, cols_out, size));
+        let data: AlignedBuf = alloc_aligned(Self::bytes_of(n, rows, cols_in, cols_out, size));
         Self {
             data,
             n,
@@ -137,8 +137,8 @@ impl MatZnx<Vec<u8>> {
         }
     }
 
-    pub fn from_bytes(n: usize, rows: usize, cols_in: usize, cols_out: usize, size: usize, bytes: impl Into<Vec<u8>>) -> Self {
-        let data: Vec<u8> = bytes.into();
+    pub fn from_bytes(n: usize, rows: usize, cols_in: usize, cols_out: usize, size: usize, bytes: impl Into<AlignedBuf>) -> Self {
+        let data: AlignedBuf = bytes.into();
         assert!(data.len() == Self::bytes_of(n, rows, cols_in, cols_out, size));
         crate::assert_alignment(data.as_ptr());
         Self {
@@ -166,7 +166,7 @@ impl<D: DataRef> MatZnx<D> {
         }
 
         let self_ref: MatZnx<&[u8]> = self.to_ref();
-        let nb_bytes: usize = VecZnx::<Vec<u8>>::bytes_of(self.n, self.cols_out, self.size);
+        let nb_bytes: usize = VecZnx::<AlignedBuf>::bytes_of(self.n, self.cols_out, self.size);
         let start: usize = nb_bytes * self.cols() * row + col * nb_bytes;
         let end: usize = start + nb_bytes;
 
@@ -199,7 +199,7 @@ impl<D: DataMut> MatZnx<D> {
         let size: usize = self.size();
 
         let self_ref: MatZnx<&mut [u8]> = self.to_mut();
-        let nb_bytes: usize = VecZnx::<Vec<u8>>::bytes_of(n, cols_out, size);
+        let nb_bytes: usize = VecZnx::<AlignedBuf>::bytes_of(n, cols_out, size);
         let start: usize = nb_bytes * cols_in * row + col * nb_bytes;
         let end: usize = start + nb_bytes;
 
@@ -229,8 +229,8 @@ impl<D: DataMut> FillUniform for MatZnx<D> {
     }
 }
 
-/// Owned `MatZnx` backed by a `Vec<u8>`.
-pub type MatZnxOwned = MatZnx<Vec<u8>>;
+/// Owned `MatZnx` backed by an [`AlignedBuf`].
+pub type MatZnxOwned = MatZnx<AlignedBuf>;
 /// Mutably borrowed `MatZnx`.
 pub type MatZnxMut<'a> = MatZnx<&'a mut [u8]>;
 /// Immutably borrowed `MatZnx`.
@@ -329,7 +329,7 @@ impl<D: DataRef> WriterTo for MatZnx<D> {
         writer.write_u64::<LittleEndian>(self.rows as u64)?;
         writer.write_u64::<LittleEndian>(self.cols_in as u64)?;
         writer.write_u64::<LittleEndian>(self.cols_out as u64)?;
-        let logical_len: usize = MatZnx::<Vec<u8>>::bytes_of(self.n, self.rows, self.cols_in, self.cols_out, self.size);
+        let logical_len: usize = MatZnx::<AlignedBuf>::bytes_of(self.n, self.rows, self.cols_in, self.cols_out, self.size);
         let buf: &[u8] = self.data.as_ref();
         if buf.len() < logical_len {
             return Err(std::io::Error::new(
@@ -2,19 +2,29 @@
 //!
 //! This module aggregates all layout-related types and re-exports them from
 //! their respective sub-modules, including convolution kernels, matrix and
-//! vector representations over polynomial rings, serialization support,
+//! vector representations over polynomial rings, the aligned owned buffer
+//! [`AlignedBuf`], serialization support
+//! (including the backend-tagged format of prepared layouts, and optional
+//! `serde` support behind the `serde` feature, and streaming zstd
+//! compression of the raw encodings),
 //! statistical utilities, and scratch-space management.
 //!
 //! It also defines a three-level trait alias hierarchy (`Data`, `DataRef`,
 //! `DataMut`) that governs ownership and borrowing semantics for the
 //! underlying byte-level data containers used throughout the crate.
 
+mod aligned_buf;
+mod compression;
 mod convolution;
 mod encoding;
 mod mat_znx;
 mod module;
+mod persist;
 mod scalar_znx;
 mod scratch;
+mod scratch_pool;
+#[cfg(feature = "serde")]
+mod serde_impls;
 mod serialization;
 mod stats;
 mod svp_ppol;
@@ -24,11 +34,15 @@ mod vec_znx_dft;
 mod vmp_pmat;
 mod znx_base;
 
+pub use aligned_buf::*;
+pub use compression::*;
 pub use convolution::*;
 pub use mat_znx::*;
 pub use module::*;
+pub use persist::{PREPARED_BLOCK_BYTES, prepared_meta_from_bytes, read_prepared_meta, write_prepared_meta};
 pub use scalar_znx::*;
 pub use scratch::*;
+pub use scratch_pool::*;
 pub use serialization::*;
 pub use stats::*;
 pub use svp_ppol::*;
@@ -44,9 +58,10 @@ use std::marker::PhantomData;
 /// Base trait alias for all data containers.
 ///
 /// Requires equality comparison ([`PartialEq`], [`Eq`]), a known size at
-/// compile time ([`Sized`]), and a default value ([`Default`]). Every
-/// layout type that holds raw data must satisfy at least this bound.
-pub trait Data = PartialEq + Eq + Sized + Default;
+/// compile time ([`Sized`]), a default value ([`Default`]) and erasure of
+/// owned memory ([`ZeroizeOwned`]). Every layout type that holds raw data
+/// must satisfy at least this bound.
+pub trait Data = PartialEq + Eq + Sized + Default + ZeroizeOwned;
 
 /// Trait alias for read-only (shared) data containers.
 ///
@@ -62,6 +77,35 @@ pub trait DataRef = Data + AsRef<[u8]> + Sync;
 /// support in-place modification and can be moved between threads.
 pub trait DataMut = DataRef + AsMut<[u8]> + Send;
 
+/// Erasure of the memory owned by a data container.
+///
+/// Owned buffers overwrite their bytes with zeros, with writes the compiler
+/// cannot elide; borrowed buffers are left untouched, erasing them is up to
+/// their owner. Secret-bearing layouts call it when dropped.
+pub trait ZeroizeOwned {
+    fn zeroize_owned(&mut self);
+}
+
+impl ZeroizeOwned for Vec<u8> {
+    fn zeroize_owned(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+impl ZeroizeOwned for &[u8] {
+    fn zeroize_owned(&mut self) {}
+}
+
+impl ZeroizeOwned for &mut [u8] {
+    fn zeroize_owned(&mut self) {}
+}
+
+impl<L, D: ZeroizeOwned> ZeroizeOwned for Located<L, D> {
+    fn zeroize_owned(&mut self) {
+        self.0.zeroize_owned();
+    }
+}
+
 /// Marker type for host-resident buffers.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct Host;
@@ -129,7 +173,7 @@ pub trait DigestU64 {
 pub type OwnedBuf<BE> = <BE as Backend>::OwnedBuf;
 
 /// Host-owned byte buffer tagged with the host location.
-pub type HostBuf = Located<Host, Vec<u8>>;
+pub type HostBuf = Located<Host, AlignedBuf>;
 
 /// Backend-owned byte buffer tagged with the device location.
 pub type DeviceBuf<BE> = Located<Device, OwnedBuf<BE>>;
@@ -34,6 +34,14 @@ pub trait Backend: Sized + Sync + Send {
     type OwnedBuf: DataMut;
     /// Opaque backend handle type (e.g. precomputed FFT twiddle factors).
     type Handle: 'static;
+    /// Identifier of the memory representation of prepared and DFT-domain
+    /// layouts, written in front of every persisted [`crate::layouts::VmpPMat`],
+    /// [`crate::layouts::SvpPPol`] and [`crate::layouts::VecZnxDft`].
+    ///
+    /// Backends storing these layouts identically must use the same
+    /// identifier, and others a different one. At most 8 ASCII bytes; the
+    /// empty default marks prepared layouts as not persistable.
+    const PREPARED_LAYOUT: &'static str = "";
     /// Allocates a backend-owned byte buffer of `len` bytes.
     fn alloc_bytes(len: usize) -> Self::OwnedBuf;
     /// Wraps/Uploads a host byte buffer into a backend-owned buffer.
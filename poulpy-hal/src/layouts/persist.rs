@@ -0,0 +1,253 @@
+//! Backend-tagged binary format of prepared layouts.
+
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+use byteorder::{ByteOrder, LittleEndian};
+
+use crate::{DEFAULTALIGN, is_aligned, layouts::Backend};
+
+/// Size in bytes of the header of a persisted prepared layout and of a
+/// metadata block, and granularity of all persisted records.
+///
+/// [`VmpPMat`](crate::layouts::VmpPMat), [`SvpPPol`](crate::layouts::SvpPPol)
+/// and [`VecZnxDft`](crate::layouts::VecZnxDft) are persisted as a record made
+/// of a header of [`PREPARED_BLOCK_BYTES`] bytes, followed by the raw buffer
+/// of the layout in the representation of the backend, zero-padded to a
+/// multiple of [`PREPARED_BLOCK_BYTES`] bytes:
+///
+/// | bytes     | content                                                   |
+/// |-----------|-----------------------------------------------------------|
+/// | `0..8`    | [`Backend::PREPARED_LAYOUT`], zero-padded                 |
+/// | `8..12`   | `u32` layout kind                                         |
+/// | `12..16`  | `u32` format version                                      |
+/// | `16..24`  | `u64` ring degree `n`                                     |
+/// | `24..56`  | `4 x u64` dimensions, depending on the kind               |
+/// | `56..64`  | `u64` byte length of the buffer                           |
+///
+/// Integers are little-endian, and the buffer is stored as laid out in
+/// memory. A record is only loaded by a backend with the same
+/// [`Backend::PREPARED_LAYOUT`], into a layout of the same ring degree.
+///
+/// Every record being a multiple of [`PREPARED_BLOCK_BYTES`] bytes long, the
+/// buffers of consecutive records, possibly interleaved with metadata blocks
+/// written by [`write_prepared_meta`], stay aligned to [`DEFAULTALIGN`] when
+/// the stream starts at such an address. They can then be used in place,
+/// see for example [`VmpPMat::from_bytes`](crate::layouts::VmpPMat::from_bytes).
+pub const PREPARED_BLOCK_BYTES: usize = DEFAULTALIGN;
+
+const PREPARED_FORMAT_VERSION: u32 = 1;
+const LAYOUT_ID_BYTES: usize = 8;
+
+/// Kind of a persisted prepared layout.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub(crate) enum PreparedKind {
+    SvpPPol = 1,
+    VmpPMat = 2,
+    VecZnxDft = 3,
+}
+
+/// Decoded header of a persisted prepared layout.
+pub(crate) struct PreparedHeader {
+    pub(crate) n: usize,
+    pub(crate) dims: [usize; 4],
+    pub(crate) len: usize,
+}
+
+fn invalid_data(msg: String) -> Error {
+    Error::new(ErrorKind::InvalidData, msg)
+}
+
+fn layout_id<B: Backend>() -> Result<[u8; LAYOUT_ID_BYTES]> {
+    let id: &[u8] = B::PREPARED_LAYOUT.as_bytes();
+    if id.is_empty() || id.len() > LAYOUT_ID_BYTES {
+        return Err(Error::new(
+            ErrorKind::Unsupported,
+            format!(
+                "backend {} does not define a persistable prepared layout",
+                std::any::type_name::<B>()
+            ),
+        ));
+    }
+    let mut bytes: [u8; LAYOUT_ID_BYTES] = [0; LAYOUT_ID_BYTES];
+    bytes[..id.len()].copy_from_slice(id);
+    Ok(bytes)
+}
+
+fn padding(len: usize) -> usize {
+    len.next_multiple_of(PREPARED_BLOCK_BYTES) - len
+}
+
+/// Writes a prepared layout of the backend `B` whose buffer starts with `data`.
+pub(crate) fn write_prepared<B: Backend, W: Write>(
+    writer: &mut W,
+    kind: PreparedKind,
+    n: usize,
+    dims: [usize; 4],
+    len: usize,
+    data: &[u8],
+) -> Result<()> {
+    if data.len() < len {
+        return Err(invalid_data(format!(
+            "{kind:?} buffer too small: data.len()={} < len={len}",
+            data.len()
+        )));
+    }
+
+    let mut header: [u8; PREPARED_BLOCK_BYTES] = [0; PREPARED_BLOCK_BYTES];
+    header[..8].copy_from_slice(&layout_id::<B>()?);
+    LittleEndian::write_u32(&mut header[8..12], kind as u32);
+    LittleEndian::write_u32(&mut header[12..16], PREPARED_FORMAT_VERSION);
+    LittleEndian::write_u64(&mut header[16..24], n as u64);
+    for (i, dim) in dims.iter().enumerate() {
+        LittleEndian::write_u64(&mut header[24 + 8 * i..32 + 8 * i], *dim as u64);
+    }
+    LittleEndian::write_u64(&mut header[56..64], len as u64);
+
+    writer.write_all(&header)?;
+    writer.write_all(&data[..len])?;
+    writer.write_all(&[0u8; PREPARED_BLOCK_BYTES][..padding(len)])
+}
+
+/// Decodes the header of a prepared layout of kind `kind` and ring degree `n`
+/// persisted by the backend `B`.
+fn parse_header<B: Backend>(header: &[u8], kind: PreparedKind, n: usize) -> Result<PreparedHeader> {
+    let id: [u8; LAYOUT_ID_BYTES] = layout_id::<B>()?;
+    if header[..8] != id {
+        let found: &str = std::str::from_utf8(&header[..8]).unwrap_or("?").trim_end_matches('\0');
+        return Err(invalid_data(format!(
+            "{kind:?} was persisted with the prepared layout \"{found}\", backend {} uses \"{}\"",
+            std::any::type_name::<B>(),
+            B::PREPARED_LAYOUT
+        )));
+    }
+
+    let found_kind: u32 = LittleEndian::read_u32(&header[8..12]);
+    if found_kind != kind as u32 {
+        return Err(invalid_data(format!(
+            "expected a persisted {kind:?}, found layout kind {found_kind}"
+        )));
+    }
+
+    let version: u32 = LittleEndian::read_u32(&header[12..16]);
+    if version != PREPARED_FORMAT_VERSION {
+        return Err(invalid_data(format!("unsupported prepared layout format version {version}")));
+    }
+
+    let found_n: usize = LittleEndian::read_u64(&header[16..24]) as usize;
+    if found_n != n {
+        return Err(invalid_data(format!(
+            "{kind:?} ring degree mismatch: persisted n={found_n} != n={n}"
+        )));
+    }
+
+    let mut dims: [usize; 4] = [0; 4];
+    for (i, dim) in dims.iter_mut().enumerate() {
+        *dim = LittleEndian::read_u64(&header[24 + 8 * i..32 + 8 * i]) as usize;
+    }
+
+    Ok(PreparedHeader {
+        n: found_n,
+        dims,
+        len: LittleEndian::read_u64(&header[56..64]) as usize,
+    })
+}
+
+/// Checks that the buffer length of a persisted prepared layout matches the
+/// length `expected` computed by the backend from its dimensions.
+pub(crate) fn check_prepared_len(kind: PreparedKind, header: &PreparedHeader, expected: usize) -> Result<()> {
+    if header.len != expected {
+        return Err(invalid_data(format!(
+            "{kind:?} metadata inconsistent: n={} dims={:?} require {expected} bytes != data len={}",
+            header.n, header.dims, header.len
+        )));
+    }
+    Ok(())
+}
+
+/// Reads the header of a prepared layout of kind `kind` and ring degree `n`.
+pub(crate) fn read_prepared_header<B: Backend, R: Read>(reader: &mut R, kind: PreparedKind, n: usize) -> Result<PreparedHeader> {
+    let mut header: [u8; PREPARED_BLOCK_BYTES] = [0; PREPARED_BLOCK_BYTES];
+    reader.read_exact(&mut header)?;
+    parse_header::<B>(&header, kind, n)
+}
+
+/// Reads the buffer described by `header` into `buf`, then skips its padding.
+pub(crate) fn read_prepared_data<R: Read>(
+    reader: &mut R,
+    kind: PreparedKind,
+    header: &PreparedHeader,
+    buf: &mut [u8],
+) -> Result<()> {
+    if buf.len() < header.len {
+        return Err(invalid_data(format!(
+            "{kind:?} buffer too small: self.data.len()={} < read len={}",
+            buf.len(),
+            header.len
+        )));
+    }
+    reader.read_exact(&mut buf[..header.len])?;
+    let mut pad: [u8; PREPARED_BLOCK_BYTES] = [0; PREPARED_BLOCK_BYTES];
+    reader.read_exact(&mut pad[..padding(header.len)])
+}
+
+/// Splits a prepared layout of kind `kind` and ring degree `n` off the front
+/// of `bytes`, returning its header, its buffer and the remaining bytes.
+pub(crate) fn split_prepared<B: Backend>(bytes: &[u8], kind: PreparedKind, n: usize) -> Result<(PreparedHeader, &[u8], &[u8])> {
+    if bytes.len() < PREPARED_BLOCK_BYTES {
+        return Err(Error::new(ErrorKind::UnexpectedEof, format!("truncated {kind:?} header")));
+    }
+    let (header, bytes) = bytes.split_at(PREPARED_BLOCK_BYTES);
+    let header: PreparedHeader = parse_header::<B>(header, kind, n)?;
+
+    let record: usize = header.len + padding(header.len);
+    if bytes.len() < record {
+        return Err(Error::new(
+            ErrorKind::UnexpectedEof,
+            format!("truncated {kind:?}: {} bytes left < {record}", bytes.len()),
+        ));
+    }
+    if !is_aligned(bytes.as_ptr()) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("{kind:?} buffer is not aligned to {DEFAULTALIGN} bytes"),
+        ));
+    }
+
+    let (data, rest) = bytes.split_at(record);
+    let data: &[u8] = &data[..header.len];
+    Ok((header, data, rest))
+}
+
+/// Writes `meta` as a metadata block of [`PREPARED_BLOCK_BYTES`] bytes,
+/// zero-padded, such that persisted prepared layouts following it stay
+/// aligned.
+///
+/// Used by layouts built on top of prepared layouts to persist their own
+/// parameters.
+pub fn write_prepared_meta<W: Write>(writer: &mut W, meta: &[u8]) -> Result<()> {
+    if meta.len() > PREPARED_BLOCK_BYTES {
+        return Err(invalid_data(format!(
+            "prepared metadata too large: {} > {PREPARED_BLOCK_BYTES} bytes",
+            meta.len()
+        )));
+    }
+    writer.write_all(meta)?;
+    writer.write_all(&[0u8; PREPARED_BLOCK_BYTES][..PREPARED_BLOCK_BYTES - meta.len()])
+}
+
+/// Reads a metadata block written by [`write_prepared_meta`].
+pub fn read_prepared_meta<R: Read>(reader: &mut R) -> Result<[u8; PREPARED_BLOCK_BYTES]> {
+    let mut meta: [u8; PREPARED_BLOCK_BYTES] = [0; PREPARED_BLOCK_BYTES];
+    reader.read_exact(&mut meta)?;
+    Ok(meta)
+}
+
+/// Splits a metadata block written by [`write_prepared_meta`] off the front
+/// of `bytes`, returning it together with the remaining bytes.
+pub fn prepared_meta_from_bytes(bytes: &[u8]) -> Result<(&[u8], &[u8])> {
+    if bytes.len() < PREPARED_BLOCK_BYTES {
+        return Err(Error::new(ErrorKind::UnexpectedEof, "truncated prepared metadata block"));
+    }
+    Ok(bytes.split_at(PREPARED_BLOCK_BYTES))
+}
@@ -7,8 +7,8 @@ use rand_distr::{Distribution, weighted::WeightedIndex};
 use crate::{
     alloc_aligned,
     layouts::{
-        Data, DataMut, DataRef, DataView, DataViewMut, DigestU64, FillUniform, ReaderFrom, ToOwnedDeep, VecZnx, WriterTo,
-        ZnxInfos, ZnxView, ZnxViewMut, ZnxZero,
+        AlignedBuf, Data, DataMut, DataRef, DataView, DataViewMut, DigestU64, FillUniform, ReaderFrom, ToOwnedDeep, VecZnx,
+        WriterTo, ZnxInfos, ZnxView, ZnxViewMut, ZnxZero,
     },
     source::Source,
 };
@@ -19,7 +19,7 @@ use crate::{
 /// (`size == 1`). It is the primary type for plaintext polynomials,
 /// secret keys, and other single-precision ring elements.
 ///
-/// The type parameter `D` controls ownership: `Vec<u8>` for owned,
+/// The type parameter `D` controls ownership: [`AlignedBuf`] for owned,
 /// `&[u8]` for shared borrows, `&mut [u8]` for mutable borrows.
 #[repr(C)]
 #[derive(PartialEq, Eq, Debug, Clone, Hash)]
@@ -40,10 +40,10 @@ impl<D: DataRef> DigestU64 for ScalarZnx<D> {
 }
 
 impl<D: DataRef> ToOwnedDeep for ScalarZnx<D> {
-    type Owned = ScalarZnx<Vec<u8>>;
+    type Owned = ScalarZnx<AlignedBuf>;
     fn to_owned_deep(&self) -> Self::Owned {
         ScalarZnx {
-            data: self.data.as_ref().to_vec(),
+            data: AlignedBuf::from_slice(self.data.as_ref()),
             n: self.n,
             cols: self.cols,
         }
@@ -166,7 +166,7 @@ impl<D: DataMut> ScalarZnx<D> {
     }
 }
 
-impl ScalarZnx<Vec<u8>> {
+impl ScalarZnx<AlignedBuf> {
     /// Returns the number of bytes required to store a `ScalarZnx` with
     /// ring degree `n` and `cols` columns: `n * cols * 8`.
     pub fn bytes_of(n: usize, cols: usize) -> usize {
@@ -175,18 +175,19 @@ impl ScalarZnx<Vec<u8>> {
 
     /// Allocates a zero-initialized `ScalarZnx` aligned to [`DEFAULTALIGN`](crate::DEFAULTALIGN).
     pub fn alloc(n: usize, cols: usize) -> Self {
-        let data: Vec<u8> = alloc_aligned::<u8>(Self::bytes_of(n, cols));
+        let data: AlignedBuf = alloc_aligned(Self::bytes_of(n, cols));
         Self { data, n, cols }
     }
 
     /// Wraps an existing byte buffer into a `ScalarZnx`.
+    /// A `Vec<u8>` is copied into an [`AlignedBuf`].
     ///
     /// # Panics
     ///
     /// Panics if the buffer length does not equal `bytes_of(n, cols)` or
     /// the buffer is not aligned to [`DEFAULTALIGN`](crate::DEFAULTALIGN).
-    pub fn from_bytes(n: usize, cols: usize, bytes: impl Into<Vec<u8>>) -> Self {
-        let data: Vec<u8> = bytes.into();
+    pub fn from_bytes(n: usize, cols: usize, bytes: impl Into<AlignedBuf>) -> Self {
+        let data: AlignedBuf = bytes.into();
         assert!(data.len() == Self::bytes_of(n, cols));
         crate::assert_alignment(data.as_ptr());
         Self { data, n, cols }
@@ -218,8 +219,8 @@ impl<D: DataMut> FillUniform for ScalarZnx<D> {
     }
 }
 
-/// Owned `ScalarZnx` backed by a `Vec<u8>`.
-pub type ScalarZnxOwned = ScalarZnx<Vec<u8>>;
+/// Owned `ScalarZnx` backed by an [`AlignedBuf`].
+pub type ScalarZnxOwned = ScalarZnx<AlignedBuf>;
 
 impl<D: Data> ScalarZnx<D> {
     /// Constructs a `ScalarZnx` from raw parts without validation.
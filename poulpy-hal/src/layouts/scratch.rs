@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use crate::layouts::{Backend, DeviceBuf};
+use crate::layouts::{Backend, DeviceBuf, ZeroizeOwned};
 
 /// Owned scratch buffer for temporary workspace during polynomial operations.
 ///
@@ -17,6 +17,22 @@ pub struct ScratchOwned<B: Backend> {
     pub _phantom: PhantomData<B>,
 }
 
+/// Zeroizes the buffer, which may hold temporaries derived from secrets
+/// (e.g. during encryption, decryption or key preparation).
+impl<B: Backend> Drop for ScratchOwned<B> {
+    fn drop(&mut self) {
+        self.data.zeroize_owned();
+    }
+}
+
+impl<B: Backend> ScratchOwned<B> {
+    /// Takes the buffer out of `self`, e.g. to re-tag it for a wrapper
+    /// backend, leaving it to the new owner to erase.
+    pub fn into_data(mut self) -> DeviceBuf<B> {
+        std::mem::take(&mut self.data)
+    }
+}
+
 /// Borrowed scratch buffer (unsized).
 ///
 /// `Scratch` is a dynamically sized type (DST) wrapping `[u8]`. It is
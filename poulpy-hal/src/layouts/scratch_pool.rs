@@ -0,0 +1,153 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread::{self, ThreadId},
+};
+
+use crate::{
+    api::{ScratchAvailable, ScratchOwnedAlloc, ScratchOwnedBorrow},
+    layouts::{Backend, Scratch, ScratchOwned},
+};
+
+/// Pool of per-thread [`ScratchOwned`] arenas, grown on demand.
+///
+/// Instead of sizing a [`ScratchOwned`] up front from the `*_tmp_bytes`
+/// estimates of every operation a routine may call, callers ask the pool for
+/// a [`Scratch`] of the size a single operation needs with
+/// [`ScratchPool::with_scratch`]. Each thread gets its own arena, which is
+/// reallocated whenever its [`ScratchAvailable::available`] space is smaller
+/// than the requested size and reused otherwise, so the memory settles at the
+/// largest request issued by the thread.
+///
+/// The pool records the largest request it served ([`ScratchPool::high_water_mark`])
+/// and how many times an arena had to be (re)allocated
+/// ([`ScratchPool::grow_count`]), which can then be used to size a fixed
+/// [`ScratchOwned`] for production code.
+///
+/// The pool does not check that `size` is enough for the operation run in
+/// [`ScratchPool::with_scratch`]: an operation taking more than `size` bytes
+/// panics exactly as it would on a [`ScratchOwned`] of `size` bytes. Estimates
+/// can be validated with [`profiling`](crate::profiling) and
+/// [`ProfileReport::check_tmp_bytes`](crate::profiling::ProfileReport::check_tmp_bytes).
+///
+/// A `ScratchPool` is `Sync` and meant to be shared (e.g. by reference or in
+/// an `Arc`) between the threads running operations on the same backend.
+/// Arenas are zeroized when dropped, like any [`ScratchOwned`].
+///
+/// # Arena lifetime
+///
+/// Arenas are keyed by [`ThreadId`] and are not dropped when their thread
+/// exits: they stay in the pool until [`ScratchPool::release`] is called on
+/// that thread, [`ScratchPool::clear`] is called, or the pool is dropped. A
+/// pool shared with short-lived threads (e.g. one spawned per request) thus
+/// holds one arena per thread that ever used it; such threads should call
+/// [`ScratchPool::release`] before exiting.
+pub struct ScratchPool<B: Backend> {
+    arenas: Mutex<HashMap<ThreadId, ScratchOwned<B>>>,
+    high_water_mark: AtomicUsize,
+    grow_count: AtomicUsize,
+}
+
+impl<B: Backend> ScratchPool<B> {
+    /// Creates an empty pool; arenas are allocated on first use.
+    pub fn new() -> Self {
+        Self {
+            arenas: Mutex::new(HashMap::new()),
+            high_water_mark: AtomicUsize::new(0),
+            grow_count: AtomicUsize::new(0),
+        }
+    }
+
+    /// Largest scratch size, in bytes, requested from the pool so far.
+    pub fn high_water_mark(&self) -> usize {
+        self.high_water_mark.load(Ordering::Relaxed)
+    }
+
+    /// Number of arenas allocated so far, either for a new thread or to grow
+    /// an existing arena.
+    pub fn grow_count(&self) -> usize {
+        self.grow_count.load(Ordering::Relaxed)
+    }
+
+    /// Total size, in bytes, of the arenas currently held by the pool.
+    pub fn allocated_bytes(&self) -> usize {
+        self.lock().values().map(|arena| arena.data.as_ref().len()).sum()
+    }
+
+    /// Drops the arena of the calling thread, if any. Recorded statistics are
+    /// kept.
+    pub fn release(&self) {
+        self.lock().remove(&thread::current().id());
+    }
+
+    /// Drops the arenas of all threads. Recorded statistics are kept.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<ThreadId, ScratchOwned<B>>> {
+        // A panic in an operation never leaves the map half-updated.
+        self.arenas.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<B: Backend> ScratchPool<B>
+where
+    ScratchOwned<B>: ScratchOwnedAlloc<B> + ScratchOwnedBorrow<B>,
+    Scratch<B>: ScratchAvailable,
+{
+    /// Runs `f` with a [`Scratch`] of at least `size` bytes taken from the
+    /// arena of the calling thread, allocating or growing the arena first if
+    /// needed.
+    ///
+    /// The arena is checked out for the duration of `f`: a nested call on the
+    /// same thread is served by a separate arena, and the larger of the two is
+    /// kept afterwards.
+    pub fn with_scratch<R>(&self, size: usize, f: impl FnOnce(&mut Scratch<B>) -> R) -> R {
+        self.high_water_mark.fetch_max(size, Ordering::Relaxed);
+
+        let id: ThreadId = thread::current().id();
+        let mut arena: Option<ScratchOwned<B>> = self.lock().remove(&id);
+        if arena.as_mut().is_none_or(|arena| arena.borrow().available() < size) {
+            self.grow_count.fetch_add(1, Ordering::Relaxed);
+            arena = Some(ScratchOwned::alloc(size));
+        }
+        let mut arena: ScratchOwned<B> = arena.unwrap();
+
+        let res: R = f(arena.borrow());
+
+        let mut arenas = self.lock();
+        match arenas.get(&id) {
+            Some(other) if other.data.as_ref().len() >= arena.data.as_ref().len() => {}
+            _ => {
+                arenas.insert(id, arena);
+            }
+        }
+        res
+    }
+}
+
+impl<B: Backend> Default for ScratchPool<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Backend> fmt::Debug for ScratchPool<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (arenas, allocated_bytes): (usize, usize) = {
+            let arenas = self.lock();
+            (arenas.len(), arenas.values().map(|arena| arena.data.as_ref().len()).sum())
+        };
+        f.debug_struct("ScratchPool")
+            .field("arenas", &arenas)
+            .field("allocated_bytes", &allocated_bytes)
+            .field("high_water_mark", &self.high_water_mark())
+            .field("grow_count", &self.grow_count())
+            .finish()
+    }
+}
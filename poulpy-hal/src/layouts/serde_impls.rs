@@ -0,0 +1,224 @@
+//! [`serde`] support for the owned layouts, enabled by the `serde` feature.
+//!
+//! [`VecZnx`], [`ScalarZnx`] and [`MatZnx`] serialize as a struct of their
+//! dimensions followed by the coefficient bytes, in the same order and with
+//! the same byte content as their [`WriterTo`](crate::layouts::WriterTo)
+//! encoding, except that [`VecZnx`] omits its `max_size`. Any backing storage
+//! can be serialized; deserialization produces an owned, [`AlignedBuf`]-backed
+//! layout and rejects inconsistent dimensions and a zero ring degree.
+//!
+//! A deserialized [`VecZnx`] is allocated with its active `size` only, so
+//! that untrusted input cannot request an allocation larger than its payload.
+//!
+//! The ring degree is not required to be a power of two, as these layouts
+//! also back LWE ciphertexts and secrets (e.g. a [`VecZnx`] of degree `n + 1`);
+//! the GLWE-level layouts of `poulpy-core` check it.
+
+use std::fmt;
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, SeqAccess, Visitor},
+};
+
+use crate::layouts::{AlignedBuf, DataRef, DataView, DataViewMut, MatZnx, ScalarZnx, VecZnx, ZnxInfos};
+
+/// Borrowed coefficient bytes, serialized as a byte string.
+struct Bytes<'a>(&'a [u8]);
+
+impl Serialize for Bytes<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+/// Owned coefficient bytes, deserialized from a byte string or a sequence of
+/// bytes (for formats without a native byte string, such as JSON).
+struct ByteBuf(Vec<u8>);
+
+impl<'de> Deserialize<'de> for ByteBuf {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ByteBufVisitor;
+
+        impl<'de> Visitor<'de> for ByteBufVisitor {
+            type Value = ByteBuf;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a byte string")
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<ByteBuf, E> {
+                Ok(ByteBuf(v.to_vec()))
+            }
+
+            fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<ByteBuf, E> {
+                Ok(ByteBuf(v))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<ByteBuf, A::Error> {
+                let mut bytes: Vec<u8> = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(1 << 20));
+                while let Some(b) = seq.next_element::<u8>()? {
+                    bytes.push(b);
+                }
+                Ok(ByteBuf(bytes))
+            }
+        }
+
+        deserializer.deserialize_byte_buf(ByteBufVisitor)
+    }
+}
+
+/// Returns the product of `dims` times `size_of::<i64>()`, or an error on overflow.
+fn coeff_bytes<E: de::Error>(what: &str, dims: &[usize]) -> Result<usize, E> {
+    dims.iter()
+        .try_fold(size_of::<i64>(), |acc, &d| acc.checked_mul(d))
+        .ok_or_else(|| E::custom(format!("{what} dimensions overflow: {dims:?}")))
+}
+
+fn check_degree<E: de::Error>(what: &str, n: usize) -> Result<(), E> {
+    if n == 0 {
+        return Err(E::custom(format!("{what} degree n={n} must be non-zero")));
+    }
+    Ok(())
+}
+
+fn check_len<E: de::Error>(what: &str, expected: usize, actual: usize) -> Result<(), E> {
+    if expected != actual {
+        return Err(E::custom(format!(
+            "{what} metadata inconsistent: expected {expected} coefficient bytes, got {actual}"
+        )));
+    }
+    Ok(())
+}
+
+#[derive(Serialize)]
+#[serde(rename = "VecZnx")]
+struct VecZnxSer<'a> {
+    n: usize,
+    cols: usize,
+    size: usize,
+    data: Bytes<'a>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "VecZnx")]
+struct VecZnxDe {
+    n: usize,
+    cols: usize,
+    size: usize,
+    data: ByteBuf,
+}
+
+impl<D: DataRef> Serialize for VecZnx<D> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let len: usize = VecZnx::<AlignedBuf>::bytes_of(self.n, self.cols, self.size);
+        VecZnxSer {
+            n: self.n,
+            cols: self.cols,
+            size: self.size,
+            data: Bytes(&self.data.as_ref()[..len]),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for VecZnx<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: VecZnxDe = VecZnxDe::deserialize(deserializer)?;
+        check_degree("VecZnx", de.n)?;
+        let len: usize = coeff_bytes("VecZnx", &[de.n, de.cols, de.size])?;
+        check_len("VecZnx", len, de.data.0.len())?;
+        let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(de.n, de.cols, de.size);
+        res.data[..len].copy_from_slice(&de.data.0);
+        Ok(res)
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename = "ScalarZnx")]
+struct ScalarZnxSer<'a> {
+    n: usize,
+    cols: usize,
+    data: Bytes<'a>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "ScalarZnx")]
+struct ScalarZnxDe {
+    n: usize,
+    cols: usize,
+    data: ByteBuf,
+}
+
+impl<D: DataRef> Serialize for ScalarZnx<D> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let len: usize = ScalarZnx::<AlignedBuf>::bytes_of(self.n, self.cols);
+        ScalarZnxSer {
+            n: self.n,
+            cols: self.cols,
+            data: Bytes(&self.data.as_ref()[..len]),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScalarZnx<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: ScalarZnxDe = ScalarZnxDe::deserialize(deserializer)?;
+        check_degree("ScalarZnx", de.n)?;
+        let len: usize = coeff_bytes("ScalarZnx", &[de.n, de.cols])?;
+        check_len("ScalarZnx", len, de.data.0.len())?;
+        let mut res: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(de.n, de.cols);
+        res.data[..len].copy_from_slice(&de.data.0);
+        Ok(res)
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename = "MatZnx")]
+struct MatZnxSer<'a> {
+    n: usize,
+    size: usize,
+    rows: usize,
+    cols_in: usize,
+    cols_out: usize,
+    data: Bytes<'a>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "MatZnx")]
+struct MatZnxDe {
+    n: usize,
+    size: usize,
+    rows: usize,
+    cols_in: usize,
+    cols_out: usize,
+    data: ByteBuf,
+}
+
+impl<D: DataRef> Serialize for MatZnx<D> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let len: usize = MatZnx::<AlignedBuf>::bytes_of(self.n(), self.rows(), self.cols_in(), self.cols_out(), self.size());
+        MatZnxSer {
+            n: self.n(),
+            size: self.size(),
+            rows: self.rows(),
+            cols_in: self.cols_in(),
+            cols_out: self.cols_out(),
+            data: Bytes(&self.data().as_ref()[..len]),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MatZnx<AlignedBuf> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let de: MatZnxDe = MatZnxDe::deserialize(deserializer)?;
+        check_degree("MatZnx", de.n)?;
+        let len: usize = coeff_bytes("MatZnx", &[de.n, de.rows, de.cols_in, de.cols_out, de.size])?;
+        check_len("MatZnx", len, de.data.0.len())?;
+        let mut res: MatZnx<AlignedBuf> = MatZnx::alloc(de.n, de.rows, de.cols_in, de.cols_out, de.size);
+        res.data_mut()[..len].copy_from_slice(&de.data.0);
+        Ok(res)
+    }
+}
@@ -1,10 +1,18 @@
 use std::{
     fmt,
     hash::{DefaultHasher, Hasher},
+    io::{Read, Result, Write},
     marker::PhantomData,
 };
 
-use crate::layouts::{Backend, Data, DataMut, DataRef, DataView, DataViewMut, Device, DeviceBuf, DigestU64, ZnxInfos, ZnxView};
+use crate::layouts::{
+    Backend, Data, DataMut, DataRef, DataView, DataViewMut, Device, DeviceBuf, DigestU64, ReaderFrom, WriterTo, ZnxInfos,
+    ZnxView,
+    persist::{
+        PreparedHeader, PreparedKind, check_prepared_len, read_prepared_data, read_prepared_header, split_prepared,
+        write_prepared,
+    },
+};
 
 /// Prepared (DFT-domain) scalar polynomial for scalar-vector products.
 ///
@@ -159,3 +167,49 @@ impl<D: DataRef, B: Backend> fmt::Display for SvpPPol<D, B> {
         Ok(())
     }
 }
+
+/// Persists the polynomial in the backend-tagged format described at
+/// [`PREPARED_BLOCK_BYTES`](crate::layouts::PREPARED_BLOCK_BYTES).
+impl<D: DataRef, B: Backend> WriterTo for SvpPPol<D, B> {
+    fn write_to<W: Write>(&self, writer: &mut W) -> Result<()> {
+        write_prepared::<B, W>(
+            writer,
+            PreparedKind::SvpPPol,
+            self.n,
+            [self.cols, 0, 0, 0],
+            B::bytes_of_svp_ppol(self.n, self.cols),
+            self.data.as_ref(),
+        )
+    }
+}
+
+/// Loads a polynomial persisted by [`WriterTo`], failing if it was written by
+/// a backend with a different [`Backend::PREPARED_LAYOUT`] or for a ring
+/// degree other than `self.n`.
+impl<D: DataMut, B: Backend> ReaderFrom for SvpPPol<D, B> {
+    fn read_from<R: Read>(&mut self, reader: &mut R) -> Result<()> {
+        let header: PreparedHeader = read_prepared_header::<B, R>(reader, PreparedKind::SvpPPol, self.n)?;
+        let cols: usize = header.dims[0];
+        check_prepared_len(PreparedKind::SvpPPol, &header, B::bytes_of_svp_ppol(self.n, cols))?;
+        read_prepared_data(reader, PreparedKind::SvpPPol, &header, self.data.as_mut())?;
+
+        // Only commit metadata after successful read.
+        self.cols = cols;
+        Ok(())
+    }
+}
+
+impl<'a, B: Backend> SvpPPol<&'a [u8], B> {
+    /// Zero-copy variant of [`ReaderFrom`]: views the polynomial persisted at
+    /// the front of `bytes` in place, and returns it with the remaining bytes.
+    ///
+    /// Fails if the polynomial was written by a backend with a different
+    /// [`Backend::PREPARED_LAYOUT`] or for a ring degree other than `n`, or if
+    /// its buffer is not aligned to [`DEFAULTALIGN`](crate::DEFAULTALIGN) bytes.
+    pub fn from_bytes(n: usize, bytes: &'a [u8]) -> Result<(Self, &'a [u8])> {
+        let (header, data, rest) = split_prepared::<B>(bytes, PreparedKind::SvpPPol, n)?;
+        let cols: usize = header.dims[0];
+        check_prepared_len(PreparedKind::SvpPPol, &header, B::bytes_of_svp_ppol(n, cols))?;
+        Ok((Self::from_data(data, n, cols), rest))
+    }
+}
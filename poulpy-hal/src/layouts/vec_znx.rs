@@ -6,8 +6,8 @@ use std::{
 use crate::{
     alloc_aligned,
     layouts::{
-        Data, DataMut, DataRef, DataView, DataViewMut, DigestU64, FillUniform, ReaderFrom, ScalarZnx, ToOwnedDeep, WriterTo,
-        ZnxInfos, ZnxView, ZnxViewMut, ZnxZero,
+        AlignedBuf, Data, DataMut, DataRef, DataView, DataViewMut, DigestU64, FillUniform, ReaderFrom, ScalarZnx, ToOwnedDeep,
+        WriterTo, ZnxInfos, ZnxView, ZnxViewMut, ZnxZero,
     },
     source::Source,
 };
@@ -25,7 +25,7 @@ use rand::Rng;
 /// **Memory layout:** limb-major, column-minor. Limb `j` of column `i`
 /// starts at scalar offset `N * (j * cols + i)`.
 ///
-/// The type parameter `D` controls ownership: `Vec<u8>` for owned,
+/// The type parameter `D` controls ownership: [`AlignedBuf`] for owned,
 /// `&[u8]` for shared borrows, `&mut [u8]` for mutable borrows.
 ///
 /// **Invariant:** `size <= max_size`. The `max_size` field records the
@@ -87,10 +87,10 @@ impl<D: DataRef> DigestU64 for VecZnx<D> {
 }
 
 impl<D: DataRef> ToOwnedDeep for VecZnx<D> {
-    type Owned = VecZnx<Vec<u8>>;
+    type Owned = VecZnx<AlignedBuf>;
     fn to_owned_deep(&self) -> Self::Owned {
         VecZnx {
-            data: self.data.as_ref().to_vec(),
+            data: AlignedBuf::from_slice(self.data.as_ref()),
             n: self.n,
             cols: self.cols,
             size: self.size,
@@ -159,7 +159,7 @@ impl<D: DataMut> VecZnx<D> {
     }
 }
 
-impl VecZnx<Vec<u8>> {
+impl VecZnx<AlignedBuf> {
     /// Returns the scratch space (in bytes) required by right-shift operations.
     pub fn rsh_tmp_bytes(n: usize) -> usize {
         n * size_of::<i64>()
@@ -187,7 +187,7 @@ impl<D: DataMut> ZnxZero for VecZnx<D> {
     }
 }
 
-impl VecZnx<Vec<u8>> {
+impl VecZnx<AlignedBuf> {
     /// Returns the number of bytes required: `n * cols * size * 8`.
     pub fn bytes_of(n: usize, cols: usize, size: usize) -> usize {
         n * cols * size * size_of::<i64>()
@@ -196,7 +196,7 @@ impl VecZnx<Vec<u8>> {
     /// Allocates a zero-initialized `VecZnx` aligned to [`DEFAULTALIGN`](crate::DEFAULTALIGN).
     /// Sets `max_size = size`.
     pub fn alloc(n: usize, cols: usize, size: usize) -> Self {
-        let data: Vec<u8> = alloc_aligned::<u8>(Self::bytes_of(n, cols, size));
+        let data: AlignedBuf = alloc_aligned(Self::bytes_of(n, cols, size));
         Self {
             data,
             n,
@@ -207,13 +207,14 @@ impl VecZnx<Vec<u8>> {
     }
 
     /// Wraps an existing byte buffer into a `VecZnx`.
+    /// A `Vec<u8>` is copied into an [`AlignedBuf`].
     ///
     /// # Panics
     ///
     /// Panics if the buffer length does not equal `bytes_of(n, cols, size)` or
     /// the buffer is not aligned to [`DEFAULTALIGN`](crate::DEFAULTALIGN).
-    pub fn from_bytes(n: usize, cols: usize, size: usize, bytes: impl Into<Vec<u8>>) -> Self {
-        let data: Vec<u8> = bytes.into();
+    pub fn from_bytes(n: usize, cols: usize, size: usize, bytes: impl Into<AlignedBuf>) -> Self {
+        let data: AlignedBuf = bytes.into();
         assert!(
             data.len() == Self::bytes_of(n, cols, size),
             "from_bytes: data.len()={} != bytes_of({}, {}, {})={}",
@@ -295,8 +296,8 @@ impl<D: DataMut> FillUniform for VecZnx<D> {
     }
 }
 
-/// Owned `VecZnx` backed by a `Vec<u8>`.
-pub type VecZnxOwned = VecZnx<Vec<u8>>;
+/// Owned `VecZnx` backed by an [`AlignedBuf`].
+pub type VecZnxOwned = VecZnx<AlignedBuf>;
 /// Mutably borrowed `VecZnx`.
 pub type VecZnxMut<'a> = VecZnx<&'a mut [u8]>;
 /// Immutably borrowed `VecZnx`.
@@ -1,14 +1,19 @@
 use std::{
     fmt,
     hash::{DefaultHasher, Hasher},
+    io::{Read, Result, Write},
     marker::PhantomData,
 };
 
 use rand_distr::num_traits::Zero;
 
 use crate::layouts::{
-    Backend, Data, DataMut, DataRef, DataView, DataViewMut, Device, DeviceBuf, DigestU64, Located, VecZnxBig, ZnxInfos, ZnxView,
-    ZnxViewMut, ZnxZero,
+    Backend, Data, DataMut, DataRef, DataView, DataViewMut, Device, DeviceBuf, DigestU64, Located, ReaderFrom, VecZnxBig,
+    WriterTo, ZnxInfos, ZnxView, ZnxViewMut, ZnxZero,
+    persist::{
+        PreparedHeader, PreparedKind, check_prepared_len, read_prepared_data, read_prepared_header, split_prepared,
+        write_prepared,
+    },
 };
 
 /// Polynomial vector in DFT (evaluation) domain.
@@ -234,3 +239,51 @@ impl<D: DataRef, B: Backend> fmt::Display for VecZnxDft<D, B> {
         Ok(())
     }
 }
+
+/// Persists the active limbs of the vector in the backend-tagged format
+/// described at [`PREPARED_BLOCK_BYTES`](crate::layouts::PREPARED_BLOCK_BYTES).
+impl<D: DataRef, B: Backend> WriterTo for VecZnxDft<D, B> {
+    fn write_to<W: Write>(&self, writer: &mut W) -> Result<()> {
+        write_prepared::<B, W>(
+            writer,
+            PreparedKind::VecZnxDft,
+            self.n,
+            [self.cols, self.size, 0, 0],
+            B::bytes_of_vec_znx_dft(self.n, self.cols, self.size),
+            self.data.as_ref(),
+        )
+    }
+}
+
+/// Loads a vector persisted by [`WriterTo`], failing if it was written by a
+/// backend with a different [`Backend::PREPARED_LAYOUT`] or for a ring degree
+/// other than `self.n`.
+impl<D: DataMut, B: Backend> ReaderFrom for VecZnxDft<D, B> {
+    fn read_from<R: Read>(&mut self, reader: &mut R) -> Result<()> {
+        let header: PreparedHeader = read_prepared_header::<B, R>(reader, PreparedKind::VecZnxDft, self.n)?;
+        let [cols, size, _, _] = header.dims;
+        check_prepared_len(PreparedKind::VecZnxDft, &header, B::bytes_of_vec_znx_dft(self.n, cols, size))?;
+        read_prepared_data(reader, PreparedKind::VecZnxDft, &header, self.data.as_mut())?;
+
+        // Only commit metadata after successful read.
+        self.cols = cols;
+        self.size = size;
+        self.max_size = size;
+        Ok(())
+    }
+}
+
+impl<'a, B: Backend> VecZnxDft<&'a [u8], B> {
+    /// Zero-copy variant of [`ReaderFrom`]: views the vector persisted at the
+    /// front of `bytes` in place, and returns it with the remaining bytes.
+    ///
+    /// Fails if the vector was written by a backend with a different
+    /// [`Backend::PREPARED_LAYOUT`] or for a ring degree other than `n`, or if
+    /// its buffer is not aligned to [`DEFAULTALIGN`](crate::DEFAULTALIGN) bytes.
+    pub fn from_bytes(n: usize, bytes: &'a [u8]) -> Result<(Self, &'a [u8])> {
+        let (header, data, rest) = split_prepared::<B>(bytes, PreparedKind::VecZnxDft, n)?;
+        let [cols, size, _, _] = header.dims;
+        check_prepared_len(PreparedKind::VecZnxDft, &header, B::bytes_of_vec_znx_dft(n, cols, size))?;
+        Ok((Self::from_data(data, n, cols, size), rest))
+    }
+}
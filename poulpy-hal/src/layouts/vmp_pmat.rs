@@ -1,10 +1,16 @@
 use std::{
     hash::{DefaultHasher, Hasher},
+    io::{Read, Result, Write},
     marker::PhantomData,
 };
 
 use crate::layouts::{
-    Backend, Data, DataMut, DataRef, DataView, DataViewMut, Device, DeviceBuf, DigestU64, Located, ZnxInfos, ZnxView,
+    Backend, Data, DataMut, DataRef, DataView, DataViewMut, Device, DeviceBuf, DigestU64, Located, ReaderFrom, WriterTo,
+    ZnxInfos, ZnxView,
+    persist::{
+        PreparedHeader, PreparedKind, check_prepared_len, read_prepared_data, read_prepared_header, split_prepared,
+        write_prepared,
+    },
 };
 
 /// Prepared (DFT-domain) polynomial matrix for vector-matrix products.
@@ -169,3 +175,60 @@ impl<D: Data, B: Backend> VmpPMat<D, B> {
         }
     }
 }
+
+/// Persists the matrix in the backend-tagged format described at
+/// [`PREPARED_BLOCK_BYTES`](crate::layouts::PREPARED_BLOCK_BYTES).
+impl<D: DataRef, B: Backend> WriterTo for VmpPMat<D, B> {
+    fn write_to<W: Write>(&self, writer: &mut W) -> Result<()> {
+        write_prepared::<B, W>(
+            writer,
+            PreparedKind::VmpPMat,
+            self.n,
+            [self.rows, self.cols_in, self.cols_out, self.size],
+            B::bytes_of_vmp_pmat(self.n, self.rows, self.cols_in, self.cols_out, self.size),
+            self.data.as_ref(),
+        )
+    }
+}
+
+/// Loads a matrix persisted by [`WriterTo`], failing if it was written by a
+/// backend with a different [`Backend::PREPARED_LAYOUT`] or for a ring degree
+/// other than `self.n()`.
+impl<D: DataMut, B: Backend> ReaderFrom for VmpPMat<D, B> {
+    fn read_from<R: Read>(&mut self, reader: &mut R) -> Result<()> {
+        let header: PreparedHeader = read_prepared_header::<B, R>(reader, PreparedKind::VmpPMat, self.n)?;
+        let [rows, cols_in, cols_out, size] = header.dims;
+        check_prepared_len(
+            PreparedKind::VmpPMat,
+            &header,
+            B::bytes_of_vmp_pmat(self.n, rows, cols_in, cols_out, size),
+        )?;
+        read_prepared_data(reader, PreparedKind::VmpPMat, &header, self.data.as_mut())?;
+
+        // Only commit metadata after successful read.
+        self.rows = rows;
+        self.cols_in = cols_in;
+        self.cols_out = cols_out;
+        self.size = size;
+        Ok(())
+    }
+}
+
+impl<'a, B: Backend> VmpPMat<&'a [u8], B> {
+    /// Zero-copy variant of [`ReaderFrom`]: views the matrix persisted at the
+    /// front of `bytes` in place, and returns it with the remaining bytes.
+    ///
+    /// Fails if the matrix was written by a backend with a different
+    /// [`Backend::PREPARED_LAYOUT`] or for a ring degree other than `n`, or if
+    /// its buffer is not aligned to [`DEFAULTALIGN`](crate::DEFAULTALIGN) bytes.
+    pub fn from_bytes(n: usize, bytes: &'a [u8]) -> Result<(Self, &'a [u8])> {
+        let (header, data, rest) = split_prepared::<B>(bytes, PreparedKind::VmpPMat, n)?;
+        let [rows, cols_in, cols_out, size] = header.dims;
+        check_prepared_len(
+            PreparedKind::VmpPMat,
+            &header,
+            B::bytes_of_vmp_pmat(n, rows, cols_in, cols_out, size),
+        )?;
+        Ok((Self::from_data(data, n, rows, cols_in, cols_out, size), rest))
+    }
+}
@@ -32,7 +32,7 @@
 //! - [`layouts::CnvPVecL`], [`layouts::CnvPVecR`] -- prepared left/right operands for bivariate convolution.
 //! - [`layouts::Scratch`], [`layouts::ScratchOwned`] -- aligned scratch memory for temporary workspace.
 //!
-//! All layout types are generic over a data container `D` (owned `Vec<u8>`, borrowed
+//! All layout types are generic over a data container `D` (owned [`layouts::AlignedBuf`], borrowed
 //! `&[u8]` / `&mut [u8]`), enabling zero-copy views and arena-style allocation via
 //! [`layouts::Scratch`].
 //!
@@ -97,13 +97,28 @@ pub mod api;
 /// the safe API layer to the unsafe backend implementations.
 pub mod delegates;
 
+/// Runtime dispatch to one of several interchangeable backends.
+///
+/// [`dyn_backend::DynBackend`] implements [`oep::HalImpl`] by forwarding each
+/// call to a backend selected when the module is created, so a single binary
+/// can pick the fastest backend supported by the host CPU.
+pub mod dyn_backend;
+
 /// Backend-agnostic data layout types for polynomials, vectors, matrices,
 /// and prepared (DFT-domain) representations.
 ///
-/// All types are generic over a data container `D` (`Vec<u8>`, `&[u8]`,
-/// `&mut [u8]`) enabling owned, borrowed, and scratch-backed usage.
+/// All types are generic over a data container `D` ([`layouts::AlignedBuf`],
+/// `&[u8]`, `&mut [u8]`) enabling owned, borrowed, and scratch-backed usage.
 pub mod layouts;
 
+/// Instrumented backend recording operation counts, shapes, timings and
+/// scratch consumption.
+///
+/// [`profiling::Profiled`] implements [`oep::HalImpl`] by forwarding each call
+/// to the backend it wraps, and [`profiling::report`] summarises what was
+/// executed.
+pub mod profiling;
+
 /// Open Extension Points: the `unsafe` backend extension layer centered on
 /// [`oep::HalImpl`].
 ///
@@ -112,7 +127,8 @@ pub mod layouts;
 /// [`doc::backend_safety`] for the safety contract.
 pub mod oep;
 
-/// Deterministic pseudorandom number generation based on ChaCha8.
+/// Pseudorandom number generation: deterministic (ChaCha8) or seeded from
+/// the operating system (ChaCha20).
 pub mod source;
 
 /// Fully generic, backend-parametric test functions.
@@ -163,7 +179,7 @@ pub fn is_aligned<T>(ptr: *const T) -> bool {
 pub fn assert_alignment<T>(ptr: *const T) {
     assert!(
         is_aligned(ptr),
-        "invalid alignment: ensure passed bytes have been allocated with [alloc_aligned_u8] or [alloc_aligned]"
+        "invalid alignment: ensure passed bytes have been allocated with alloc_aligned or AlignedBuf"
     )
 }
 
@@ -225,81 +241,24 @@ pub fn cast_mut<T, V>(data: &mut [T]) -> &mut [V] {
     unsafe { std::slice::from_raw_parts_mut(ptr, len) }
 }
 
-/// Allocates a block of bytes with a custom alignment.
-/// Alignment must be a power of two and size a multiple of the alignment.
-/// Allocated memory is initialized to zero.
-///
-/// # Known issue (CRITICAL-2)
-/// The returned `Vec<u8>` was allocated with custom alignment via `std::alloc::alloc`,
-/// but `Vec::drop` will call `std::alloc::dealloc` with `align_of::<u8>() = 1`.
-/// This is technically UB per the `GlobalAlloc` contract (mismatched layout).
-/// In practice it works on all major allocators (glibc, jemalloc, mimalloc) because
-/// they ignore the alignment parameter during deallocation. A proper fix requires
-/// replacing `Vec<u8>` with a custom `AlignedBuf` type that tracks the layout.
-fn alloc_aligned_custom_u8(size: usize, align: usize) -> Vec<u8> {
-    assert!(align.is_power_of_two(), "Alignment must be a power of two but is {align}");
-    assert_eq!(
-        (size * size_of::<u8>()) % align,
-        0,
-        "size={size} must be a multiple of align={align}"
-    );
-    unsafe {
-        let layout: std::alloc::Layout = std::alloc::Layout::from_size_align(size, align).expect("Invalid alignment");
-        let ptr: *mut u8 = std::alloc::alloc(layout);
-        if ptr.is_null() {
-            panic!("Memory allocation failed");
-        }
-        assert!(
-            is_aligned_custom(ptr, align),
-            "Memory allocation at {ptr:p} is not aligned to {align} bytes"
-        );
-        // Init allocated memory to zero
-        std::ptr::write_bytes(ptr, 0, size);
-        Vec::from_raw_parts(ptr, size, size)
-    }
-}
-
-/// Allocates a zero-initialized `Vec<T>` with custom alignment.
-///
-/// The total byte size (`size * size_of::<T>()`) must be a multiple of `align`,
-/// and `align` must be a power of two.
+/// Allocates a zero-initialized [`layouts::AlignedBuf`] of `size` bytes aligned to
+/// `align` bytes.
 ///
 /// # Panics
 ///
-/// - If `T` is zero-sized.
 /// - If `align` is not a power of two.
-/// - If `size * size_of::<T>()` is not a multiple of `align`.
-pub fn alloc_aligned_custom<T>(size: usize, align: usize) -> Vec<T> {
-    assert!(size_of::<T>() > 0, "alloc_aligned_custom: zero-sized types are not supported");
+/// - If `size` is not a multiple of `align`.
+pub fn alloc_aligned_custom(size: usize, align: usize) -> layouts::AlignedBuf {
     assert!(align.is_power_of_two(), "Alignment must be a power of two but is {align}");
-
-    assert_eq!(
-        (size * size_of::<T>()) % align,
-        0,
-        "size*size_of::<T>()={} must be a multiple of align={align}",
-        size * size_of::<T>(),
-    );
-
-    let mut vec_u8: Vec<u8> = alloc_aligned_custom_u8(size_of::<T>() * size, align);
-    let ptr: *mut T = vec_u8.as_mut_ptr() as *mut T;
-    let len: usize = vec_u8.len() / size_of::<T>();
-    let cap: usize = vec_u8.capacity() / size_of::<T>();
-    std::mem::forget(vec_u8);
-    unsafe { Vec::from_raw_parts(ptr, len, cap) }
+    assert_eq!(size % align, 0, "size={size} must be a multiple of align={align}");
+    layouts::AlignedBuf::zeroed_with_align(size, align)
 }
 
-/// Allocates a zero-initialized `Vec<T>` aligned to [`DEFAULTALIGN`] bytes.
+/// Allocates a zero-initialized [`layouts::AlignedBuf`] aligned to [`DEFAULTALIGN`] bytes.
 ///
-/// The allocation is padded so that the total byte size is a multiple of
+/// The allocation is padded so that its length is a multiple of
 /// [`DEFAULTALIGN`]. This is the primary allocation entry point for all
 /// layout types in the crate.
-///
-/// # Panics
-///
-/// Panics if `T` is zero-sized.
-pub fn alloc_aligned<T>(size: usize) -> Vec<T> {
-    alloc_aligned_custom::<T>(
-        (size * size_of::<T>()).next_multiple_of(DEFAULTALIGN) / size_of::<T>(),
-        DEFAULTALIGN,
-    )
+pub fn alloc_aligned(size: usize) -> layouts::AlignedBuf {
+    alloc_aligned_custom(size.next_multiple_of(DEFAULTALIGN), DEFAULTALIGN)
 }
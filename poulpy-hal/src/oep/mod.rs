@@ -7,5 +7,7 @@
 //! must uphold the backend safety contract.
 
 mod hal_impl;
+mod retag;
 
 pub use hal_impl::*;
+pub use retag::*;
@@ -0,0 +1,89 @@
+use crate::layouts::{Backend, CnvPVecL, CnvPVecR, Data, Scratch, ScratchOwned, SvpPPol, VecZnxBig, VecZnxDft, VmpPMat};
+
+/// Declares that the backend `Self` stores every backend-tagged layout,
+/// scratch space and owned buffer exactly like `B`.
+///
+/// Wrapper backends, which add behaviour on top of an existing backend
+/// without changing its data representation, implement this trait so that
+/// they can hand their buffers to the wrapped backend through [`retag`],
+/// [`retag_mut`] and [`retag_scratch`], and take scratch space back through
+/// [`wrap_scratch`].
+///
+/// # Safety
+///
+/// `Self` and `B` must have the same scalar and buffer types, the same
+/// `bytes_of_*` sizes for all dimensions, and must interpret the content of
+/// prepared, DFT-domain and scratch buffers identically. They should also
+/// share the same [`Backend::PREPARED_LAYOUT`].
+pub unsafe trait SharedLayout<B: Backend>:
+    Backend<ScalarBig = B::ScalarBig, ScalarPrep = B::ScalarPrep, OwnedBuf = B::OwnedBuf>
+{
+}
+
+/// Backend-tagged layouts whose memory representation does not depend on
+/// the tag.
+///
+/// # Safety
+///
+/// `Self` and `Self::Tagged<B>` must be `#[repr(C)]` with identical fields
+/// up to the [`PhantomData`](std::marker::PhantomData) of the tag.
+pub unsafe trait Retag: Sized {
+    /// Backend the layout is currently tagged with.
+    type Backend: Backend;
+    /// The same layout, tagged with the backend `B`.
+    type Tagged<B: Backend>: Sized;
+}
+
+macro_rules! impl_retag {
+    ($($ty:ident),*) => {
+        $(
+            unsafe impl<D: Data, X: Backend> Retag for $ty<D, X> {
+                type Backend = X;
+                type Tagged<B: Backend> = $ty<D, B>;
+            }
+        )*
+    };
+}
+
+impl_retag!(VecZnxDft, VecZnxBig, SvpPPol, VmpPMat, CnvPVecL, CnvPVecR);
+
+/// Views a layout of the backend `T::Backend` as a layout of `B`.
+pub fn retag<T, B>(x: &T) -> &T::Tagged<B>
+where
+    T: Retag,
+    T::Backend: SharedLayout<B>,
+    B: Backend,
+{
+    unsafe { &*(x as *const T as *const T::Tagged<B>) }
+}
+
+/// Mutable variant of [`retag`].
+pub fn retag_mut<T, B>(x: &mut T) -> &mut T::Tagged<B>
+where
+    T: Retag,
+    T::Backend: SharedLayout<B>,
+    B: Backend,
+{
+    unsafe { &mut *(x as *mut T as *mut T::Tagged<B>) }
+}
+
+/// Views the scratch space of the backend `A` as a scratch space of `B`.
+pub fn retag_scratch<A: SharedLayout<B>, B: Backend>(scratch: &mut Scratch<A>) -> &mut Scratch<B> {
+    unsafe { &mut *(scratch as *mut Scratch<A> as *mut Scratch<B>) }
+}
+
+/// Inverse of [`retag_scratch`]: views the scratch space of the wrapped
+/// backend `B` as a scratch space of `A`.
+pub fn wrap_scratch<A: SharedLayout<B>, B: Backend>(scratch: &mut Scratch<B>) -> &mut Scratch<A> {
+    unsafe { &mut *(scratch as *mut Scratch<B> as *mut Scratch<A>) }
+}
+
+/// Shared variant of [`retag_scratch`].
+pub fn retag_scratch_ref<A: SharedLayout<B>, B: Backend>(scratch: &Scratch<A>) -> &Scratch<B> {
+    unsafe { &*(scratch as *const Scratch<A> as *const Scratch<B>) }
+}
+
+/// Views an owned scratch space of the backend `A` as one of `B`.
+pub fn retag_scratch_owned<A: SharedLayout<B>, B: Backend>(scratch: &mut ScratchOwned<A>) -> &mut ScratchOwned<B> {
+    unsafe { &mut *(scratch as *mut ScratchOwned<A> as *mut ScratchOwned<B>) }
+}
@@ -0,0 +1,1554 @@
+#![allow(clippy::too_many_arguments)]
+
+use std::marker::PhantomData;
+
+use crate::{
+    layouts::{
+        Backend, CnvPVecLToMut, CnvPVecLToRef, CnvPVecRToMut, CnvPVecRToRef, Data, MatZnxToRef, Module, NoiseInfos,
+        ScalarZnxToRef, Scratch, ScratchOwned, SvpPPolToMut, SvpPPolToRef, VecZnxBig, VecZnxBigToMut, VecZnxBigToRef, VecZnxDft,
+        VecZnxDftToMut, VecZnxDftToRef, VecZnxToMut, VecZnxToRef, VmpPMatToMut, VmpPMatToRef, ZnxInfos,
+    },
+    oep::{HalImpl, retag, retag_mut, retag_scratch, retag_scratch_owned, retag_scratch_ref, wrap_scratch},
+    source::Source,
+};
+
+use super::{
+    Profiled,
+    recorder::{dims, profile, profile_scratch, record_alloc, record_borrow, record_take},
+};
+
+fn inner<BE: Backend + HalImpl<BE> + 'static>(module: &Module<Profiled<BE>>) -> &Module<BE> {
+    Profiled::inner(module)
+}
+
+// Scratch management is served by the wrapped backend and tracked on the
+// way; every other method is recorded and forwarded, except the
+// `*_tmp_bytes` queries, which are only forwarded.
+unsafe impl<BE: Backend + HalImpl<BE> + 'static> HalImpl<Profiled<BE>> for Profiled<BE> {
+    fn scratch_owned_alloc(size: usize) -> ScratchOwned<Self> {
+        let scratch: ScratchOwned<BE> = BE::scratch_owned_alloc(size);
+        record_alloc(scratch.data.as_ref());
+        ScratchOwned {
+            data: scratch.into_data(),
+            _phantom: PhantomData,
+        }
+    }
+
+    fn scratch_owned_borrow(scratch: &mut ScratchOwned<Self>) -> &mut Scratch<Self> {
+        let scratch: &mut Scratch<Self> = wrap_scratch(BE::scratch_owned_borrow(retag_scratch_owned(scratch)));
+        record_borrow(&scratch.data);
+        scratch
+    }
+
+    fn scratch_from_bytes(data: &mut [u8]) -> &mut Scratch<Self> {
+        let scratch: &mut Scratch<Self> = wrap_scratch(BE::scratch_from_bytes(data));
+        record_borrow(&scratch.data);
+        scratch
+    }
+
+    fn scratch_available(scratch: &Scratch<Self>) -> usize {
+        BE::scratch_available(retag_scratch_ref(scratch))
+    }
+
+    fn take_slice<T>(scratch: &mut Scratch<Self>, len: usize) -> (&mut [T], &mut Scratch<Self>) {
+        let start: usize = scratch.data.as_ptr() as usize;
+        let (slice, rem) = BE::take_slice::<T>(retag_scratch(scratch), len);
+        record_take(start, &rem.data);
+        (slice, wrap_scratch(rem))
+    }
+
+    fn new(n: u64) -> Module<Self> {
+        Self::wrap(BE::new(n))
+    }
+
+    fn vec_znx_big_from_small<R, A>(res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxToRef,
+    {
+        profile("vec_znx_big_from_small", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_big_from_small(retag_mut::<_, BE>(&mut res.to_mut()), res_col, a, a_col)
+        })
+    }
+
+    fn vec_znx_idft_apply_consume<D: Data>(module: &Module<Self>, mut a: VecZnxDft<D, Self>) -> VecZnxBig<D, Self>
+    where
+        VecZnxDft<D, Self>: VecZnxDftToMut<Self>,
+    {
+        profile("vec_znx_idft_apply_consume", &[dims(&a)], || {
+            let a_mut: VecZnxDft<&mut [u8], Self> = a.to_mut();
+            let a_inner: VecZnxDft<&mut [u8], BE> = VecZnxDft {
+                data: a_mut.data,
+                n: a_mut.n,
+                cols: a_mut.cols,
+                size: a_mut.size,
+                max_size: a_mut.max_size,
+                _phantom: PhantomData,
+            };
+            BE::vec_znx_idft_apply_consume(inner(module), a_inner);
+        });
+        a.into_big()
+    }
+
+    fn vec_znx_zero<R>(module: &Module<Self>, res: &mut R, res_col: usize)
+    where
+        R: VecZnxToMut,
+    {
+        profile("vec_znx_zero", &[dims(&res.to_mut())], || {
+            BE::vec_znx_zero(inner(module), res, res_col)
+        })
+    }
+
+    fn vec_znx_normalize_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_normalize_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_normalize<R, A>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_base2k: usize,
+        res_offset: i64,
+        res_col: usize,
+        a: &A,
+        a_base2k: usize,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        profile_scratch(
+            "vec_znx_normalize",
+            &[dims(&res.to_mut()), dims(&a.to_ref())],
+            scratch,
+            |scratch| {
+                BE::vec_znx_normalize(
+                    inner(module),
+                    res,
+                    res_base2k,
+                    res_offset,
+                    res_col,
+                    a,
+                    a_base2k,
+                    a_col,
+                    retag_scratch(scratch),
+                )
+            },
+        )
+    }
+
+    fn vec_znx_normalize_assign<A>(module: &Module<Self>, base2k: usize, a: &mut A, a_col: usize, scratch: &mut Scratch<Self>)
+    where
+        A: VecZnxToMut,
+    {
+        profile_scratch("vec_znx_normalize_assign", &[dims(&a.to_mut())], scratch, |scratch| {
+            BE::vec_znx_normalize_assign(inner(module), base2k, a, a_col, retag_scratch(scratch))
+        })
+    }
+
+    fn vec_znx_add_into<R, A, C>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+        C: VecZnxToRef,
+    {
+        profile(
+            "vec_znx_add_into",
+            &[dims(&res.to_mut()), dims(&a.to_ref()), dims(&b.to_ref())],
+            || BE::vec_znx_add_into(inner(module), res, res_col, a, a_col, b, b_col),
+        )
+    }
+
+    fn vec_znx_add_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        profile("vec_znx_add_assign", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_add_assign(inner(module), res, res_col, a, a_col)
+        })
+    }
+
+    fn vec_znx_add_scalar_into<R, A, B>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &B,
+        b_col: usize,
+        b_limb: usize,
+    ) where
+        R: VecZnxToMut,
+        A: ScalarZnxToRef,
+        B: VecZnxToRef,
+    {
+        profile(
+            "vec_znx_add_scalar_into",
+            &[dims(&res.to_mut()), dims(&a.to_ref()), dims(&b.to_ref())],
+            || BE::vec_znx_add_scalar_into(inner(module), res, res_col, a, a_col, b, b_col, b_limb),
+        )
+    }
+
+    fn vec_znx_add_scalar_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, res_limb: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: ScalarZnxToRef,
+    {
+        profile("vec_znx_add_scalar_assign", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_add_scalar_assign(inner(module), res, res_col, res_limb, a, a_col)
+        })
+    }
+
+    fn vec_znx_sub<R, A, C>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+        C: VecZnxToRef,
+    {
+        profile(
+            "vec_znx_sub",
+            &[dims(&res.to_mut()), dims(&a.to_ref()), dims(&b.to_ref())],
+            || BE::vec_znx_sub(inner(module), res, res_col, a, a_col, b, b_col),
+        )
+    }
+
+    fn vec_znx_sub_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        profile("vec_znx_sub_assign", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_sub_assign(inner(module), res, res_col, a, a_col)
+        })
+    }
+
+    fn vec_znx_sub_negate_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        profile("vec_znx_sub_negate_assign", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_sub_negate_assign(inner(module), res, res_col, a, a_col)
+        })
+    }
+
+    fn vec_znx_sub_scalar<R, A, B>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &B,
+        b_col: usize,
+        b_limb: usize,
+    ) where
+        R: VecZnxToMut,
+        A: ScalarZnxToRef,
+        B: VecZnxToRef,
+    {
+        profile(
+            "vec_znx_sub_scalar",
+            &[dims(&res.to_mut()), dims(&a.to_ref()), dims(&b.to_ref())],
+            || BE::vec_znx_sub_scalar(inner(module), res, res_col, a, a_col, b, b_col, b_limb),
+        )
+    }
+
+    fn vec_znx_sub_scalar_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, res_limb: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: ScalarZnxToRef,
+    {
+        profile("vec_znx_sub_scalar_assign", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_sub_scalar_assign(inner(module), res, res_col, res_limb, a, a_col)
+        })
+    }
+
+    fn vec_znx_negate<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        profile("vec_znx_negate", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_negate(inner(module), res, res_col, a, a_col)
+        })
+    }
+
+    fn vec_znx_negate_assign<A>(module: &Module<Self>, a: &mut A, a_col: usize)
+    where
+        A: VecZnxToMut,
+    {
+        profile("vec_znx_negate_assign", &[dims(&a.to_mut())], || {
+            BE::vec_znx_negate_assign(inner(module), a, a_col)
+        })
+    }
+
+    fn vec_znx_rsh_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_rsh_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_rsh<R, A>(
+        module: &Module<Self>,
+        base2k: usize,
+        k: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        profile_scratch("vec_znx_rsh", &[dims(&res.to_mut()), dims(&a.to_ref())], scratch, |scratch| {
+            BE::vec_znx_rsh(inner(module), base2k, k, res, res_col, a, a_col, retag_scratch(scratch))
+        })
+    }
+
+    fn vec_znx_rsh_add_into<R, A>(
+        module: &Module<Self>,
+        base2k: usize,
+        k: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        profile_scratch(
+            "vec_znx_rsh_add_into",
+            &[dims(&res.to_mut()), dims(&a.to_ref())],
+            scratch,
+            |scratch| BE::vec_znx_rsh_add_into(inner(module), base2k, k, res, res_col, a, a_col, retag_scratch(scratch)),
+        )
+    }
+
+    fn vec_znx_lsh_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_lsh_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_lsh<R, A>(
+        module: &Module<Self>,
+        base2k: usize,
+        k: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        profile_scratch("vec_znx_lsh", &[dims(&res.to_mut()), dims(&a.to_ref())], scratch, |scratch| {
+            BE::vec_znx_lsh(inner(module), base2k, k, res, res_col, a, a_col, retag_scratch(scratch))
+        })
+    }
+
+    fn vec_znx_lsh_add_into<R, A>(
+        module: &Module<Self>,
+        base2k: usize,
+        k: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        profile_scratch(
+            "vec_znx_lsh_add_into",
+            &[dims(&res.to_mut()), dims(&a.to_ref())],
+            scratch,
+            |scratch| BE::vec_znx_lsh_add_into(inner(module), base2k, k, res, res_col, a, a_col, retag_scratch(scratch)),
+        )
+    }
+
+    fn vec_znx_lsh_sub<R, A>(
+        module: &Module<Self>,
+        base2k: usize,
+        k: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        profile_scratch(
+            "vec_znx_lsh_sub",
+            &[dims(&res.to_mut()), dims(&a.to_ref())],
+            scratch,
+            |scratch| BE::vec_znx_lsh_sub(inner(module), base2k, k, res, res_col, a, a_col, retag_scratch(scratch)),
+        )
+    }
+
+    fn vec_znx_rsh_sub<R, A>(
+        module: &Module<Self>,
+        base2k: usize,
+        k: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        profile_scratch(
+            "vec_znx_rsh_sub",
+            &[dims(&res.to_mut()), dims(&a.to_ref())],
+            scratch,
+            |scratch| BE::vec_znx_rsh_sub(inner(module), base2k, k, res, res_col, a, a_col, retag_scratch(scratch)),
+        )
+    }
+
+    fn vec_znx_rsh_assign<R>(module: &Module<Self>, base2k: usize, k: usize, a: &mut R, a_col: usize, scratch: &mut Scratch<Self>)
+    where
+        R: VecZnxToMut,
+    {
+        profile_scratch("vec_znx_rsh_assign", &[dims(&a.to_mut())], scratch, |scratch| {
+            BE::vec_znx_rsh_assign(inner(module), base2k, k, a, a_col, retag_scratch(scratch))
+        })
+    }
+
+    fn vec_znx_lsh_assign<R>(module: &Module<Self>, base2k: usize, k: usize, a: &mut R, a_col: usize, scratch: &mut Scratch<Self>)
+    where
+        R: VecZnxToMut,
+    {
+        profile_scratch("vec_znx_lsh_assign", &[dims(&a.to_mut())], scratch, |scratch| {
+            BE::vec_znx_lsh_assign(inner(module), base2k, k, a, a_col, retag_scratch(scratch))
+        })
+    }
+
+    fn vec_znx_rotate<R, A>(module: &Module<Self>, k: i64, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        profile("vec_znx_rotate", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_rotate(inner(module), k, res, res_col, a, a_col)
+        })
+    }
+
+    fn vec_znx_rotate_assign_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_rotate_assign_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_rotate_assign<A>(module: &Module<Self>, k: i64, a: &mut A, a_col: usize, scratch: &mut Scratch<Self>)
+    where
+        A: VecZnxToMut,
+    {
+        profile_scratch("vec_znx_rotate_assign", &[dims(&a.to_mut())], scratch, |scratch| {
+            BE::vec_znx_rotate_assign(inner(module), k, a, a_col, retag_scratch(scratch))
+        })
+    }
+
+    fn vec_znx_automorphism<R, A>(module: &Module<Self>, k: i64, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        profile("vec_znx_automorphism", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_automorphism(inner(module), k, res, res_col, a, a_col)
+        })
+    }
+
+    fn vec_znx_automorphism_assign_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_automorphism_assign_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_automorphism_assign<R>(module: &Module<Self>, k: i64, res: &mut R, res_col: usize, scratch: &mut Scratch<Self>)
+    where
+        R: VecZnxToMut,
+    {
+        profile_scratch("vec_znx_automorphism_assign", &[dims(&res.to_mut())], scratch, |scratch| {
+            BE::vec_znx_automorphism_assign(inner(module), k, res, res_col, retag_scratch(scratch))
+        })
+    }
+
+    fn vec_znx_mul_xp_minus_one<R, A>(module: &Module<Self>, k: i64, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        profile("vec_znx_mul_xp_minus_one", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_mul_xp_minus_one(inner(module), k, res, res_col, a, a_col)
+        })
+    }
+
+    fn vec_znx_mul_xp_minus_one_assign_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_mul_xp_minus_one_assign_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_mul_xp_minus_one_assign<R>(module: &Module<Self>, k: i64, res: &mut R, res_col: usize, scratch: &mut Scratch<Self>)
+    where
+        R: VecZnxToMut,
+    {
+        profile_scratch(
+            "vec_znx_mul_xp_minus_one_assign",
+            &[dims(&res.to_mut())],
+            scratch,
+            |scratch| BE::vec_znx_mul_xp_minus_one_assign(inner(module), k, res, res_col, retag_scratch(scratch)),
+        )
+    }
+
+    fn vec_znx_split_ring_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_split_ring_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_split_ring<R, A>(
+        module: &Module<Self>,
+        res: &mut [R],
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        profile_scratch("vec_znx_split_ring", &[dims(&a.to_ref())], scratch, |scratch| {
+            BE::vec_znx_split_ring(inner(module), res, res_col, a, a_col, retag_scratch(scratch))
+        })
+    }
+
+    fn vec_znx_merge_rings_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_merge_rings_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_merge_rings<R, A>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &[A],
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        profile_scratch("vec_znx_merge_rings", &[dims(&res.to_mut())], scratch, |scratch| {
+            BE::vec_znx_merge_rings(inner(module), res, res_col, a, a_col, retag_scratch(scratch))
+        })
+    }
+
+    fn vec_znx_switch_ring<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        profile("vec_znx_switch_ring", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_switch_ring(inner(module), res, res_col, a, a_col)
+        })
+    }
+
+    fn vec_znx_copy<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxToMut,
+        A: VecZnxToRef,
+    {
+        profile("vec_znx_copy", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_copy(inner(module), res, res_col, a, a_col)
+        })
+    }
+
+    fn vec_znx_fill_uniform<R>(module: &Module<Self>, base2k: usize, res: &mut R, res_col: usize, source: &mut Source)
+    where
+        R: VecZnxToMut,
+    {
+        profile("vec_znx_fill_uniform", &[dims(&res.to_mut())], || {
+            BE::vec_znx_fill_uniform(inner(module), base2k, res, res_col, source)
+        })
+    }
+
+    fn vec_znx_fill_normal<R>(
+        module: &Module<Self>,
+        res_base2k: usize,
+        res: &mut R,
+        res_col: usize,
+        noise_infos: NoiseInfos,
+        source: &mut Source,
+    ) where
+        R: VecZnxToMut,
+    {
+        profile("vec_znx_fill_normal", &[dims(&res.to_mut())], || {
+            BE::vec_znx_fill_normal(inner(module), res_base2k, res, res_col, noise_infos, source)
+        })
+    }
+
+    fn vec_znx_add_normal<R>(
+        module: &Module<Self>,
+        res_base2k: usize,
+        res: &mut R,
+        res_col: usize,
+        noise_infos: NoiseInfos,
+        source: &mut Source,
+    ) where
+        R: VecZnxToMut,
+    {
+        profile("vec_znx_add_normal", &[dims(&res.to_mut())], || {
+            BE::vec_znx_add_normal(inner(module), res_base2k, res, res_col, noise_infos, source)
+        })
+    }
+
+    fn vec_znx_big_add_normal<R>(
+        module: &Module<Self>,
+        res_base2k: usize,
+        res: &mut R,
+        res_col: usize,
+        noise_infos: NoiseInfos,
+        source: &mut Source,
+    ) where
+        R: VecZnxBigToMut<Self>,
+    {
+        profile("vec_znx_big_add_normal", &[dims(&res.to_mut())], || {
+            BE::vec_znx_big_add_normal(
+                inner(module),
+                res_base2k,
+                retag_mut::<_, BE>(&mut res.to_mut()),
+                res_col,
+                noise_infos,
+                source,
+            )
+        })
+    }
+
+    fn vec_znx_big_add_into<R, A, C>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+        C: VecZnxBigToRef<Self>,
+    {
+        profile(
+            "vec_znx_big_add_into",
+            &[dims(&res.to_mut()), dims(&a.to_ref()), dims(&b.to_ref())],
+            || {
+                BE::vec_znx_big_add_into(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    res_col,
+                    retag::<_, BE>(&a.to_ref()),
+                    a_col,
+                    retag::<_, BE>(&b.to_ref()),
+                    b_col,
+                )
+            },
+        )
+    }
+
+    fn vec_znx_big_add_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+    {
+        profile("vec_znx_big_add_assign", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_big_add_assign(
+                inner(module),
+                retag_mut::<_, BE>(&mut res.to_mut()),
+                res_col,
+                retag::<_, BE>(&a.to_ref()),
+                a_col,
+            )
+        })
+    }
+
+    fn vec_znx_big_add_small_into<R, A, C>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &C,
+        b_col: usize,
+    ) where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+        C: VecZnxToRef,
+    {
+        profile(
+            "vec_znx_big_add_small_into",
+            &[dims(&res.to_mut()), dims(&a.to_ref()), dims(&b.to_ref())],
+            || {
+                BE::vec_znx_big_add_small_into(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    res_col,
+                    retag::<_, BE>(&a.to_ref()),
+                    a_col,
+                    b,
+                    b_col,
+                )
+            },
+        )
+    }
+
+    fn vec_znx_big_add_small_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxToRef,
+    {
+        profile(
+            "vec_znx_big_add_small_assign",
+            &[dims(&res.to_mut()), dims(&a.to_ref())],
+            || BE::vec_znx_big_add_small_assign(inner(module), retag_mut::<_, BE>(&mut res.to_mut()), res_col, a, a_col),
+        )
+    }
+
+    fn vec_znx_big_sub<R, A, C>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+        C: VecZnxBigToRef<Self>,
+    {
+        profile(
+            "vec_znx_big_sub",
+            &[dims(&res.to_mut()), dims(&a.to_ref()), dims(&b.to_ref())],
+            || {
+                BE::vec_znx_big_sub(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    res_col,
+                    retag::<_, BE>(&a.to_ref()),
+                    a_col,
+                    retag::<_, BE>(&b.to_ref()),
+                    b_col,
+                )
+            },
+        )
+    }
+
+    fn vec_znx_big_sub_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+    {
+        profile("vec_znx_big_sub_assign", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_big_sub_assign(
+                inner(module),
+                retag_mut::<_, BE>(&mut res.to_mut()),
+                res_col,
+                retag::<_, BE>(&a.to_ref()),
+                a_col,
+            )
+        })
+    }
+
+    fn vec_znx_big_sub_negate_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+    {
+        profile(
+            "vec_znx_big_sub_negate_assign",
+            &[dims(&res.to_mut()), dims(&a.to_ref())],
+            || {
+                BE::vec_znx_big_sub_negate_assign(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    res_col,
+                    retag::<_, BE>(&a.to_ref()),
+                    a_col,
+                )
+            },
+        )
+    }
+
+    fn vec_znx_big_sub_small_a<R, A, C>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &C,
+        b_col: usize,
+    ) where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxToRef,
+        C: VecZnxBigToRef<Self>,
+    {
+        profile(
+            "vec_znx_big_sub_small_a",
+            &[dims(&res.to_mut()), dims(&a.to_ref()), dims(&b.to_ref())],
+            || {
+                BE::vec_znx_big_sub_small_a(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    res_col,
+                    a,
+                    a_col,
+                    retag::<_, BE>(&b.to_ref()),
+                    b_col,
+                )
+            },
+        )
+    }
+
+    fn vec_znx_big_sub_small_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxToRef,
+    {
+        profile(
+            "vec_znx_big_sub_small_assign",
+            &[dims(&res.to_mut()), dims(&a.to_ref())],
+            || BE::vec_znx_big_sub_small_assign(inner(module), retag_mut::<_, BE>(&mut res.to_mut()), res_col, a, a_col),
+        )
+    }
+
+    fn vec_znx_big_sub_small_b<R, A, C>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &C,
+        b_col: usize,
+    ) where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+        C: VecZnxToRef,
+    {
+        profile(
+            "vec_znx_big_sub_small_b",
+            &[dims(&res.to_mut()), dims(&a.to_ref()), dims(&b.to_ref())],
+            || {
+                BE::vec_znx_big_sub_small_b(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    res_col,
+                    retag::<_, BE>(&a.to_ref()),
+                    a_col,
+                    b,
+                    b_col,
+                )
+            },
+        )
+    }
+
+    fn vec_znx_big_sub_small_negate_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxToRef,
+    {
+        profile(
+            "vec_znx_big_sub_small_negate_assign",
+            &[dims(&res.to_mut()), dims(&a.to_ref())],
+            || BE::vec_znx_big_sub_small_negate_assign(inner(module), retag_mut::<_, BE>(&mut res.to_mut()), res_col, a, a_col),
+        )
+    }
+
+    fn vec_znx_big_negate<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+    {
+        profile("vec_znx_big_negate", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_big_negate(
+                inner(module),
+                retag_mut::<_, BE>(&mut res.to_mut()),
+                res_col,
+                retag::<_, BE>(&a.to_ref()),
+                a_col,
+            )
+        })
+    }
+
+    fn vec_znx_big_negate_assign<A>(module: &Module<Self>, a: &mut A, a_col: usize)
+    where
+        A: VecZnxBigToMut<Self>,
+    {
+        profile("vec_znx_big_negate_assign", &[dims(&a.to_mut())], || {
+            BE::vec_znx_big_negate_assign(inner(module), retag_mut::<_, BE>(&mut a.to_mut()), a_col)
+        })
+    }
+
+    fn vec_znx_big_normalize_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_big_normalize_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_big_normalize<R, A>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_base2k: usize,
+        res_offset: i64,
+        res_col: usize,
+        a: &A,
+        a_base2k: usize,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxToMut,
+        A: VecZnxBigToRef<Self>,
+    {
+        profile_scratch(
+            "vec_znx_big_normalize",
+            &[dims(&res.to_mut()), dims(&a.to_ref())],
+            scratch,
+            |scratch| {
+                BE::vec_znx_big_normalize(
+                    inner(module),
+                    res,
+                    res_base2k,
+                    res_offset,
+                    res_col,
+                    retag::<_, BE>(&a.to_ref()),
+                    a_base2k,
+                    a_col,
+                    retag_scratch(scratch),
+                )
+            },
+        )
+    }
+
+    fn vec_znx_big_automorphism<R, A>(module: &Module<Self>, k: i64, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxBigToRef<Self>,
+    {
+        profile("vec_znx_big_automorphism", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_big_automorphism(
+                inner(module),
+                k,
+                retag_mut::<_, BE>(&mut res.to_mut()),
+                res_col,
+                retag::<_, BE>(&a.to_ref()),
+                a_col,
+            )
+        })
+    }
+
+    fn vec_znx_big_automorphism_assign_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_big_automorphism_assign_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_big_automorphism_assign<A>(module: &Module<Self>, k: i64, a: &mut A, a_col: usize, scratch: &mut Scratch<Self>)
+    where
+        A: VecZnxBigToMut<Self>,
+    {
+        profile_scratch("vec_znx_big_automorphism_assign", &[dims(&a.to_mut())], scratch, |scratch| {
+            BE::vec_znx_big_automorphism_assign(
+                inner(module),
+                k,
+                retag_mut::<_, BE>(&mut a.to_mut()),
+                a_col,
+                retag_scratch(scratch),
+            )
+        })
+    }
+
+    fn vec_znx_dft_apply<R, A>(
+        module: &Module<Self>,
+        step: usize,
+        offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+    ) where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxToRef,
+    {
+        profile("vec_znx_dft_apply", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_dft_apply(
+                inner(module),
+                step,
+                offset,
+                retag_mut::<_, BE>(&mut res.to_mut()),
+                res_col,
+                a,
+                a_col,
+            )
+        })
+    }
+
+    fn vec_znx_idft_apply_tmp_bytes(module: &Module<Self>) -> usize {
+        BE::vec_znx_idft_apply_tmp_bytes(inner(module))
+    }
+
+    fn vec_znx_idft_apply<R, A>(
+        module: &Module<Self>,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+    {
+        profile_scratch(
+            "vec_znx_idft_apply",
+            &[dims(&res.to_mut()), dims(&a.to_ref())],
+            scratch,
+            |scratch| {
+                BE::vec_znx_idft_apply(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    res_col,
+                    retag::<_, BE>(&a.to_ref()),
+                    a_col,
+                    retag_scratch(scratch),
+                )
+            },
+        )
+    }
+
+    fn vec_znx_idft_apply_tmpa<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &mut A, a_col: usize)
+    where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxDftToMut<Self>,
+    {
+        profile("vec_znx_idft_apply_tmpa", &[dims(&res.to_mut()), dims(&a.to_mut())], || {
+            BE::vec_znx_idft_apply_tmpa(
+                inner(module),
+                retag_mut::<_, BE>(&mut res.to_mut()),
+                res_col,
+                retag_mut::<_, BE>(&mut a.to_mut()),
+                a_col,
+            )
+        })
+    }
+
+    fn vec_znx_dft_add_into<R, A, D>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &D, b_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+        D: VecZnxDftToRef<Self>,
+    {
+        profile(
+            "vec_znx_dft_add_into",
+            &[dims(&res.to_mut()), dims(&a.to_ref()), dims(&b.to_ref())],
+            || {
+                BE::vec_znx_dft_add_into(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    res_col,
+                    retag::<_, BE>(&a.to_ref()),
+                    a_col,
+                    retag::<_, BE>(&b.to_ref()),
+                    b_col,
+                )
+            },
+        )
+    }
+
+    fn vec_znx_dft_add_scaled_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, a_scale: i64)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+    {
+        profile(
+            "vec_znx_dft_add_scaled_assign",
+            &[dims(&res.to_mut()), dims(&a.to_ref())],
+            || {
+                BE::vec_znx_dft_add_scaled_assign(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    res_col,
+                    retag::<_, BE>(&a.to_ref()),
+                    a_col,
+                    a_scale,
+                )
+            },
+        )
+    }
+
+    fn vec_znx_dft_add_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+    {
+        profile("vec_znx_dft_add_assign", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_dft_add_assign(
+                inner(module),
+                retag_mut::<_, BE>(&mut res.to_mut()),
+                res_col,
+                retag::<_, BE>(&a.to_ref()),
+                a_col,
+            )
+        })
+    }
+
+    fn vec_znx_dft_sub<R, A, D>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &D, b_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+        D: VecZnxDftToRef<Self>,
+    {
+        profile(
+            "vec_znx_dft_sub",
+            &[dims(&res.to_mut()), dims(&a.to_ref()), dims(&b.to_ref())],
+            || {
+                BE::vec_znx_dft_sub(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    res_col,
+                    retag::<_, BE>(&a.to_ref()),
+                    a_col,
+                    retag::<_, BE>(&b.to_ref()),
+                    b_col,
+                )
+            },
+        )
+    }
+
+    fn vec_znx_dft_sub_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+    {
+        profile("vec_znx_dft_sub_assign", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_dft_sub_assign(
+                inner(module),
+                retag_mut::<_, BE>(&mut res.to_mut()),
+                res_col,
+                retag::<_, BE>(&a.to_ref()),
+                a_col,
+            )
+        })
+    }
+
+    fn vec_znx_dft_sub_negate_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+    {
+        profile(
+            "vec_znx_dft_sub_negate_assign",
+            &[dims(&res.to_mut()), dims(&a.to_ref())],
+            || {
+                BE::vec_znx_dft_sub_negate_assign(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    res_col,
+                    retag::<_, BE>(&a.to_ref()),
+                    a_col,
+                )
+            },
+        )
+    }
+
+    fn vec_znx_dft_copy<R, A>(module: &Module<Self>, step: usize, offset: usize, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+    {
+        profile("vec_znx_dft_copy", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::vec_znx_dft_copy(
+                inner(module),
+                step,
+                offset,
+                retag_mut::<_, BE>(&mut res.to_mut()),
+                res_col,
+                retag::<_, BE>(&a.to_ref()),
+                a_col,
+            )
+        })
+    }
+
+    fn vec_znx_dft_zero<R>(module: &Module<Self>, res: &mut R, res_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+    {
+        profile("vec_znx_dft_zero", &[dims(&res.to_mut())], || {
+            BE::vec_znx_dft_zero(inner(module), retag_mut::<_, BE>(&mut res.to_mut()), res_col)
+        })
+    }
+
+    fn svp_prepare<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: SvpPPolToMut<Self>,
+        A: ScalarZnxToRef,
+    {
+        profile("svp_prepare", &[dims(&res.to_mut()), dims(&a.to_ref())], || {
+            BE::svp_prepare(inner(module), retag_mut::<_, BE>(&mut res.to_mut()), res_col, a, a_col)
+        })
+    }
+
+    fn svp_apply_dft<R, A, C>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: SvpPPolToRef<Self>,
+        C: VecZnxToRef,
+    {
+        profile(
+            "svp_apply_dft",
+            &[dims(&res.to_mut()), dims(&a.to_ref()), dims(&b.to_ref())],
+            || {
+                BE::svp_apply_dft(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    res_col,
+                    retag::<_, BE>(&a.to_ref()),
+                    a_col,
+                    b,
+                    b_col,
+                )
+            },
+        )
+    }
+
+    fn svp_apply_dft_to_dft<R, A, C>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize, b: &C, b_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: SvpPPolToRef<Self>,
+        C: VecZnxDftToRef<Self>,
+    {
+        profile(
+            "svp_apply_dft_to_dft",
+            &[dims(&res.to_mut()), dims(&a.to_ref()), dims(&b.to_ref())],
+            || {
+                BE::svp_apply_dft_to_dft(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    res_col,
+                    retag::<_, BE>(&a.to_ref()),
+                    a_col,
+                    retag::<_, BE>(&b.to_ref()),
+                    b_col,
+                )
+            },
+        )
+    }
+
+    fn svp_apply_dft_to_dft_assign<R, A>(module: &Module<Self>, res: &mut R, res_col: usize, a: &A, a_col: usize)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: SvpPPolToRef<Self>,
+    {
+        profile(
+            "svp_apply_dft_to_dft_assign",
+            &[dims(&res.to_mut()), dims(&a.to_ref())],
+            || {
+                BE::svp_apply_dft_to_dft_assign(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    res_col,
+                    retag::<_, BE>(&a.to_ref()),
+                    a_col,
+                )
+            },
+        )
+    }
+
+    fn vmp_prepare_tmp_bytes(module: &Module<Self>, rows: usize, cols_in: usize, cols_out: usize, size: usize) -> usize {
+        BE::vmp_prepare_tmp_bytes(inner(module), rows, cols_in, cols_out, size)
+    }
+
+    fn vmp_prepare<R, A>(module: &Module<Self>, res: &mut R, a: &A, scratch: &mut Scratch<Self>)
+    where
+        R: VmpPMatToMut<Self>,
+        A: MatZnxToRef,
+    {
+        profile_scratch("vmp_prepare", &[dims(&res.to_mut()), dims(&a.to_ref())], scratch, |scratch| {
+            BE::vmp_prepare(
+                inner(module),
+                retag_mut::<_, BE>(&mut res.to_mut()),
+                a,
+                retag_scratch(scratch),
+            )
+        })
+    }
+
+    fn vmp_apply_dft_tmp_bytes(
+        module: &Module<Self>,
+        res_size: usize,
+        a_size: usize,
+        b_rows: usize,
+        b_cols_in: usize,
+        b_cols_out: usize,
+        b_size: usize,
+    ) -> usize {
+        BE::vmp_apply_dft_tmp_bytes(inner(module), res_size, a_size, b_rows, b_cols_in, b_cols_out, b_size)
+    }
+
+    fn vmp_apply_dft<R, A, C>(module: &Module<Self>, res: &mut R, a: &A, b: &C, scratch: &mut Scratch<Self>)
+    where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxToRef,
+        C: VmpPMatToRef<Self>,
+    {
+        profile_scratch(
+            "vmp_apply_dft",
+            &[dims(&res.to_mut()), dims(&a.to_ref()), dims(&b.to_ref())],
+            scratch,
+            |scratch| {
+                BE::vmp_apply_dft(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    a,
+                    retag::<_, BE>(&b.to_ref()),
+                    retag_scratch(scratch),
+                )
+            },
+        )
+    }
+
+    fn vmp_apply_dft_to_dft_tmp_bytes(
+        module: &Module<Self>,
+        res_size: usize,
+        a_size: usize,
+        b_rows: usize,
+        b_cols_in: usize,
+        b_cols_out: usize,
+        b_size: usize,
+    ) -> usize {
+        BE::vmp_apply_dft_to_dft_tmp_bytes(inner(module), res_size, a_size, b_rows, b_cols_in, b_cols_out, b_size)
+    }
+
+    fn vmp_apply_dft_to_dft<R, A, C>(
+        module: &Module<Self>,
+        res: &mut R,
+        a: &A,
+        b: &C,
+        limb_offset: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxDftToMut<Self>,
+        A: VecZnxDftToRef<Self>,
+        C: VmpPMatToRef<Self>,
+    {
+        profile_scratch(
+            "vmp_apply_dft_to_dft",
+            &[dims(&res.to_mut()), dims(&a.to_ref()), dims(&b.to_ref())],
+            scratch,
+            |scratch| {
+                BE::vmp_apply_dft_to_dft(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    retag::<_, BE>(&a.to_ref()),
+                    retag::<_, BE>(&b.to_ref()),
+                    limb_offset,
+                    retag_scratch(scratch),
+                )
+            },
+        )
+    }
+
+    fn vmp_zero<R>(module: &Module<Self>, res: &mut R)
+    where
+        R: VmpPMatToMut<Self>,
+    {
+        profile("vmp_zero", &[dims(&res.to_mut())], || {
+            BE::vmp_zero(inner(module), retag_mut::<_, BE>(&mut res.to_mut()))
+        })
+    }
+
+    fn cnv_prepare_left_tmp_bytes(module: &Module<Self>, res_size: usize, a_size: usize) -> usize {
+        BE::cnv_prepare_left_tmp_bytes(inner(module), res_size, a_size)
+    }
+
+    fn cnv_prepare_left<R, A>(module: &Module<Self>, res: &mut R, a: &A, mask: i64, scratch: &mut Scratch<Self>)
+    where
+        R: CnvPVecLToMut<Self>,
+        A: VecZnxToRef,
+    {
+        profile_scratch(
+            "cnv_prepare_left",
+            &[dims(&res.to_mut()), dims(&a.to_ref())],
+            scratch,
+            |scratch| {
+                BE::cnv_prepare_left(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    a,
+                    mask,
+                    retag_scratch(scratch),
+                )
+            },
+        )
+    }
+
+    fn cnv_prepare_right_tmp_bytes(module: &Module<Self>, res_size: usize, a_size: usize) -> usize {
+        BE::cnv_prepare_right_tmp_bytes(inner(module), res_size, a_size)
+    }
+
+    fn cnv_prepare_right<R, A>(module: &Module<Self>, res: &mut R, a: &A, mask: i64, scratch: &mut Scratch<Self>)
+    where
+        R: CnvPVecRToMut<Self>,
+        A: VecZnxToRef + ZnxInfos,
+    {
+        profile_scratch(
+            "cnv_prepare_right",
+            &[dims(&res.to_mut()), dims(&a.to_ref())],
+            scratch,
+            |scratch| {
+                BE::cnv_prepare_right(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    a,
+                    mask,
+                    retag_scratch(scratch),
+                )
+            },
+        )
+    }
+
+    fn cnv_apply_dft_tmp_bytes(module: &Module<Self>, cnv_offset: usize, res_size: usize, a_size: usize, b_size: usize) -> usize {
+        BE::cnv_apply_dft_tmp_bytes(inner(module), cnv_offset, res_size, a_size, b_size)
+    }
+
+    fn cnv_by_const_apply_tmp_bytes(
+        module: &Module<Self>,
+        cnv_offset: usize,
+        res_size: usize,
+        a_size: usize,
+        b_size: usize,
+    ) -> usize {
+        BE::cnv_by_const_apply_tmp_bytes(inner(module), cnv_offset, res_size, a_size, b_size)
+    }
+
+    fn cnv_by_const_apply<R, A>(
+        module: &Module<Self>,
+        cnv_offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &[i64],
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxBigToMut<Self>,
+        A: VecZnxToRef,
+    {
+        profile_scratch(
+            "cnv_by_const_apply",
+            &[dims(&res.to_mut()), dims(&a.to_ref())],
+            scratch,
+            |scratch| {
+                BE::cnv_by_const_apply(
+                    inner(module),
+                    cnv_offset,
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    res_col,
+                    a,
+                    a_col,
+                    b,
+                    retag_scratch(scratch),
+                )
+            },
+        )
+    }
+
+    fn cnv_apply_dft<R, A, B>(
+        module: &Module<Self>,
+        cnv_offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        a_col: usize,
+        b: &B,
+        b_col: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxDftToMut<Self>,
+        A: CnvPVecLToRef<Self>,
+        B: CnvPVecRToRef<Self>,
+    {
+        profile_scratch(
+            "cnv_apply_dft",
+            &[dims(&res.to_mut()), dims(&a.to_ref()), dims(&b.to_ref())],
+            scratch,
+            |scratch| {
+                BE::cnv_apply_dft(
+                    inner(module),
+                    cnv_offset,
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    res_col,
+                    retag::<_, BE>(&a.to_ref()),
+                    a_col,
+                    retag::<_, BE>(&b.to_ref()),
+                    b_col,
+                    retag_scratch(scratch),
+                )
+            },
+        )
+    }
+
+    fn cnv_pairwise_apply_dft_tmp_bytes(
+        module: &Module<Self>,
+        cnv_offset: usize,
+        res_size: usize,
+        a_size: usize,
+        b_size: usize,
+    ) -> usize {
+        BE::cnv_pairwise_apply_dft_tmp_bytes(inner(module), cnv_offset, res_size, a_size, b_size)
+    }
+
+    fn cnv_pairwise_apply_dft<R, A, B>(
+        module: &Module<Self>,
+        cnv_offset: usize,
+        res: &mut R,
+        res_col: usize,
+        a: &A,
+        b: &B,
+        i: usize,
+        j: usize,
+        scratch: &mut Scratch<Self>,
+    ) where
+        R: VecZnxDftToMut<Self>,
+        A: CnvPVecLToRef<Self>,
+        B: CnvPVecRToRef<Self>,
+    {
+        profile_scratch(
+            "cnv_pairwise_apply_dft",
+            &[dims(&res.to_mut()), dims(&a.to_ref()), dims(&b.to_ref())],
+            scratch,
+            |scratch| {
+                BE::cnv_pairwise_apply_dft(
+                    inner(module),
+                    cnv_offset,
+                    retag_mut::<_, BE>(&mut res.to_mut()),
+                    res_col,
+                    retag::<_, BE>(&a.to_ref()),
+                    retag::<_, BE>(&b.to_ref()),
+                    i,
+                    j,
+                    retag_scratch(scratch),
+                )
+            },
+        )
+    }
+
+    fn cnv_prepare_self_tmp_bytes(module: &Module<Self>, res_size: usize, a_size: usize) -> usize {
+        BE::cnv_prepare_self_tmp_bytes(inner(module), res_size, a_size)
+    }
+
+    fn cnv_prepare_self<L, R, A>(
+        module: &Module<Self>,
+        left: &mut L,
+        right: &mut R,
+        a: &A,
+        mask: i64,
+        scratch: &mut Scratch<Self>,
+    ) where
+        L: CnvPVecLToMut<Self>,
+        R: CnvPVecRToMut<Self>,
+        A: VecZnxToRef + ZnxInfos,
+    {
+        profile_scratch(
+            "cnv_prepare_self",
+            &[dims(&left.to_mut()), dims(&right.to_mut()), dims(&a.to_ref())],
+            scratch,
+            |scratch| {
+                BE::cnv_prepare_self(
+                    inner(module),
+                    retag_mut::<_, BE>(&mut left.to_mut()),
+                    retag_mut::<_, BE>(&mut right.to_mut()),
+                    a,
+                    mask,
+                    retag_scratch(scratch),
+                )
+            },
+        )
+    }
+}
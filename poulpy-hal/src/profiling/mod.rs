@@ -0,0 +1,149 @@
+//! Instrumented backend for profiling.
+//!
+//! [`Profiled<BE>`] wraps any backend `BE` and implements [`HalImpl`] by
+//! forwarding every call to it. On the way, each operation records its name,
+//! the `(cols, size)` dimensions of its vector and matrix arguments, its wall
+//! time and, for operations that take a [`Scratch`], the number of scratch
+//! bytes the wrapped kernel actually wrote to. Running a scheme-level routine
+//! such as a CKKS multiplication or a circuit bootstrapping on a
+//! `Module<Profiled<BE>>` thus shows how many DFTs, vector-matrix products and
+//! normalizations it performs, and how much of its scratch space it uses.
+//!
+//! ```ignore
+//! use poulpy_cpu_ref::FFT64Ref;
+//! use poulpy_hal::{
+//!     api::{ModuleNew, ScratchOwnedAlloc, ScratchOwnedBorrow},
+//!     layouts::{Module, ScratchOwned},
+//!     profiling::{self, ProfileReport, Profiled},
+//! };
+//!
+//! let module: Module<Profiled<FFT64Ref>> = Module::<Profiled<FFT64Ref>>::new(1 << 12);
+//! let mut scratch: ScratchOwned<Profiled<FFT64Ref>> = ScratchOwned::alloc(tmp_bytes);
+//!
+//! profiling::reset();
+//! evaluate(&module, scratch.borrow());
+//! let report: ProfileReport = profiling::take_report();
+//!
+//! println!("{report}");
+//! println!("{}", report.check_tmp_bytes(tmp_bytes));
+//! ```
+//!
+//! # Recording
+//!
+//! Records are kept per thread: [`report`] and [`take_report`] return what the
+//! calling thread executed since the last [`reset`], whatever the module.
+//! Wrapping a multi-threaded backend is fine, as each operation is recorded
+//! once by the thread that issued it.
+//!
+//! # Scratch tracking
+//!
+//! The wrapped backend carves its temporaries out of the scratch space on its
+//! own, so its consumption cannot be observed through [`HalImpl::take_slice`].
+//! Instead, the free part of the scratch space is filled with a canary byte
+//! before each operation that takes one, and the highest byte overwritten by
+//! the operation is looked up afterwards. Together with the regions taken by
+//! the caller through [`HalImpl::take_slice`], this gives the peak consumption
+//! of every scratch buffer, see [`ProfileReport::scratch`].
+//!
+//! Filling and scanning cost time proportional to the scratch space handed to
+//! each operation; it is excluded from the recorded wall times, and can be
+//! turned off with [`set_scratch_tracking`]. Sizing the scratch space to the
+//! `*_tmp_bytes` estimate under test keeps it small.
+//!
+//! [`Scratch`]: crate::layouts::Scratch
+
+mod hal_impl;
+mod recorder;
+
+use std::{marker::PhantomData, ptr::NonNull};
+
+use crate::{
+    layouts::{Backend, Module},
+    oep::{HalImpl, SharedLayout},
+};
+
+pub use recorder::{
+    OpProfile, ProfileReport, ScratchProfile, ShapeCount, TmpBytesCheck, report, reset, set_scratch_tracking, take_report,
+};
+
+/// Backend recording every operation before forwarding it to `BE`.
+///
+/// See the [module documentation](crate::profiling).
+pub struct Profiled<BE: Backend + HalImpl<BE> + 'static>(PhantomData<BE>);
+
+/// Handle of a [`Profiled`] module: the module of the wrapped backend.
+pub struct ProfiledHandle<BE: Backend> {
+    inner: Module<BE>,
+}
+
+impl<BE: Backend + HalImpl<BE> + 'static> Backend for Profiled<BE> {
+    type ScalarBig = BE::ScalarBig;
+    type ScalarPrep = BE::ScalarPrep;
+    type OwnedBuf = BE::OwnedBuf;
+    type Handle = ProfiledHandle<BE>;
+    const PREPARED_LAYOUT: &'static str = BE::PREPARED_LAYOUT;
+
+    fn alloc_bytes(len: usize) -> Self::OwnedBuf {
+        BE::alloc_bytes(len)
+    }
+
+    fn from_bytes(bytes: Vec<u8>) -> Self::OwnedBuf {
+        BE::from_bytes(bytes)
+    }
+
+    fn size_of_scalar_big() -> usize {
+        BE::size_of_scalar_big()
+    }
+
+    fn size_of_scalar_prep() -> usize {
+        BE::size_of_scalar_prep()
+    }
+
+    fn bytes_of_vec_znx_dft(n: usize, cols: usize, size: usize) -> usize {
+        BE::bytes_of_vec_znx_dft(n, cols, size)
+    }
+
+    fn bytes_of_vec_znx_big(n: usize, cols: usize, size: usize) -> usize {
+        BE::bytes_of_vec_znx_big(n, cols, size)
+    }
+
+    fn bytes_of_svp_ppol(n: usize, cols: usize) -> usize {
+        BE::bytes_of_svp_ppol(n, cols)
+    }
+
+    fn bytes_of_vmp_pmat(n: usize, rows: usize, cols_in: usize, cols_out: usize, size: usize) -> usize {
+        BE::bytes_of_vmp_pmat(n, rows, cols_in, cols_out, size)
+    }
+
+    fn bytes_of_cnv_pvec_left(n: usize, cols: usize, size: usize) -> usize {
+        BE::bytes_of_cnv_pvec_left(n, cols, size)
+    }
+
+    fn bytes_of_cnv_pvec_right(n: usize, cols: usize, size: usize) -> usize {
+        BE::bytes_of_cnv_pvec_right(n, cols, size)
+    }
+
+    unsafe fn destroy(handle: NonNull<Self::Handle>) {
+        unsafe {
+            drop(Box::from_raw(handle.as_ptr()));
+        }
+    }
+}
+
+// `Profiled<BE>` delegates its whole `Backend` implementation to `BE`.
+unsafe impl<BE: Backend + HalImpl<BE> + 'static> SharedLayout<BE> for Profiled<BE> {}
+
+impl<BE: Backend + HalImpl<BE> + 'static> Profiled<BE> {
+    /// Wraps an existing module of the backend `BE`.
+    pub fn wrap(module: Module<BE>) -> Module<Self> {
+        let n: u64 = module.n() as u64;
+        let handle: Box<ProfiledHandle<BE>> = Box::new(ProfiledHandle { inner: module });
+        unsafe { Module::from_nonnull(NonNull::from(Box::leak(handle)), n) }
+    }
+
+    /// Returns the module of the wrapped backend, whose operations are not
+    /// recorded.
+    pub fn inner(module: &Module<Self>) -> &Module<BE> {
+        unsafe { &(*module.ptr()).inner }
+    }
+}
@@ -0,0 +1,422 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt,
+    time::{Duration, Instant},
+};
+
+use crate::layouts::{Backend, Scratch, ZnxInfos};
+
+/// Byte written to the free scratch space before an operation.
+const CANARY: u8 = 0xA5;
+
+thread_local! {
+    static RECORDER: RefCell<Recorder> = RefCell::new(Recorder::new());
+}
+
+struct Recorder {
+    ops: HashMap<&'static str, OpRecord>,
+    /// Scratch buffers, keyed by end address: a scratch space and every
+    /// remainder split off from it share the same end.
+    arenas: HashMap<usize, Arena>,
+    track_scratch: bool,
+}
+
+#[derive(Default)]
+struct OpRecord {
+    calls: u64,
+    time: Duration,
+    max_scratch: usize,
+    shapes: HashMap<Vec<(usize, usize)>, u64>,
+}
+
+struct Arena {
+    /// Lowest start address seen for the buffer.
+    start: usize,
+    /// Bytes from `dirty` to the end of the buffer hold the canary.
+    dirty: usize,
+    /// Highest address consumed since the last reset.
+    high: usize,
+}
+
+impl Recorder {
+    fn new() -> Self {
+        Self {
+            ops: HashMap::new(),
+            arenas: HashMap::new(),
+            track_scratch: true,
+        }
+    }
+
+    fn record(&mut self, name: &'static str, shape: &[(usize, usize)], time: Duration, scratch: usize) {
+        let op: &mut OpRecord = self.ops.entry(name).or_default();
+        op.calls += 1;
+        op.time += time;
+        op.max_scratch = op.max_scratch.max(scratch);
+        match op.shapes.get_mut(shape) {
+            Some(calls) => *calls += 1,
+            None => {
+                op.shapes.insert(shape.to_vec(), 1);
+            }
+        }
+    }
+
+    /// Returns the arena ending at `end`, registering `[start, end)` if
+    /// needed. Content of a newly seen region is unknown.
+    fn arena(&mut self, start: usize, end: usize) -> &mut Arena {
+        let arena: &mut Arena = self.arenas.entry(end).or_insert(Arena {
+            start,
+            dirty: end,
+            high: start,
+        });
+        if start < arena.start {
+            arena.start = start;
+            arena.high = arena.high.max(start);
+        }
+        arena
+    }
+}
+
+fn range(data: &[u8]) -> (usize, usize) {
+    let start: usize = data.as_ptr() as usize;
+    (start, start + data.len())
+}
+
+/// Returns the `(cols, size)` dimensions of a layout.
+pub(super) fn dims<T: ZnxInfos>(x: &T) -> (usize, usize) {
+    (x.cols(), x.size())
+}
+
+/// Runs and records an operation without scratch space.
+pub(super) fn profile<R>(name: &'static str, shape: &[(usize, usize)], f: impl FnOnce() -> R) -> R {
+    let start: Instant = Instant::now();
+    let res: R = f();
+    let time: Duration = start.elapsed();
+    RECORDER.with_borrow_mut(|rec| rec.record(name, shape, time, 0));
+    res
+}
+
+/// Runs and records an operation taking scratch space, measuring how much of
+/// it the operation overwrites.
+pub(super) fn profile_scratch<B: Backend, R>(
+    name: &'static str,
+    shape: &[(usize, usize)],
+    scratch: &mut Scratch<B>,
+    f: impl FnOnce(&mut Scratch<B>) -> R,
+) -> R {
+    let track: bool = RECORDER.with_borrow_mut(|rec| {
+        if !rec.track_scratch {
+            return false;
+        }
+        // The scratch space is exclusively borrowed by the operation, so
+        // everything it covers is free and can be overwritten.
+        let (start, end) = range(&scratch.data);
+        let arena: &mut Arena = rec.arena(start, end);
+        if arena.dirty > start {
+            scratch.data[..arena.dirty - start].fill(CANARY);
+            arena.dirty = start;
+        }
+        true
+    });
+
+    let t0: Instant = Instant::now();
+    let res: R = f(scratch);
+    let time: Duration = t0.elapsed();
+
+    RECORDER.with_borrow_mut(|rec| {
+        let mut used: usize = 0;
+        if track {
+            let (start, end) = range(&scratch.data);
+            used = high_water(&scratch.data);
+            let arena: &mut Arena = rec.arena(start, end);
+            arena.dirty = arena.dirty.max(start + used);
+            arena.high = arena.high.max(start + used);
+        }
+        rec.record(name, shape, time, used);
+    });
+    res
+}
+
+/// Records that `[start, end)` of a scratch buffer ending at `end` was taken
+/// by the caller, and `rem` is what remains.
+pub(super) fn record_take(start: usize, rem: &[u8]) {
+    RECORDER.with_borrow_mut(|rec| {
+        if !rec.track_scratch {
+            return;
+        }
+        let (taken, end) = range(rem);
+        let arena: &mut Arena = rec.arena(start, end);
+        arena.dirty = arena.dirty.max(taken);
+        arena.high = arena.high.max(taken);
+    })
+}
+
+/// Registers a newly allocated scratch buffer, replacing any stale record
+/// of a buffer ending at the same address.
+pub(super) fn record_alloc(data: &[u8]) {
+    RECORDER.with_borrow_mut(|rec| {
+        let (start, end) = range(data);
+        rec.arenas.insert(
+            end,
+            Arena {
+                start,
+                dirty: end,
+                high: start,
+            },
+        );
+    })
+}
+
+/// Registers a scratch buffer created from existing memory.
+pub(super) fn record_borrow(data: &[u8]) {
+    RECORDER.with_borrow_mut(|rec| {
+        let (start, end) = range(data);
+        rec.arena(start, end);
+    })
+}
+
+/// Returns one past the offset of the last byte of `data` that does not
+/// hold the canary.
+fn high_water(data: &[u8]) -> usize {
+    let mut end: usize = data.len();
+    for chunk in data.rchunks(64) {
+        match chunk.iter().rposition(|&b| b != CANARY) {
+            Some(i) => return end - chunk.len() + i + 1,
+            None => end -= chunk.len(),
+        }
+    }
+    0
+}
+
+/// Enables or disables scratch tracking on the calling thread (enabled by
+/// default).
+///
+/// Without it, [`OpProfile::max_scratch`] and [`ProfileReport::scratch`] stay
+/// empty, and operations no longer pay for filling and scanning their
+/// scratch space.
+pub fn set_scratch_tracking(enabled: bool) {
+    RECORDER.with_borrow_mut(|rec| rec.track_scratch = enabled)
+}
+
+/// Clears the records of the calling thread.
+pub fn reset() {
+    RECORDER.with_borrow_mut(|rec| {
+        rec.ops.clear();
+        rec.arenas.values_mut().for_each(|arena| arena.high = arena.start);
+    })
+}
+
+/// Returns the records of the calling thread since the last [`reset`].
+pub fn report() -> ProfileReport {
+    RECORDER.with_borrow(|rec| {
+        let mut ops: Vec<OpProfile> = rec
+            .ops
+            .iter()
+            .map(|(&name, op)| {
+                let mut shapes: Vec<ShapeCount> = op
+                    .shapes
+                    .iter()
+                    .map(|(dims, &calls)| ShapeCount {
+                        dims: dims.clone(),
+                        calls,
+                    })
+                    .collect();
+                shapes.sort_by(|a, b| b.calls.cmp(&a.calls).then_with(|| a.dims.cmp(&b.dims)));
+                OpProfile {
+                    name,
+                    calls: op.calls,
+                    time: op.time,
+                    max_scratch: op.max_scratch,
+                    shapes,
+                }
+            })
+            .collect();
+        ops.sort_by(|a, b| b.time.cmp(&a.time).then_with(|| a.name.cmp(b.name)));
+
+        let mut scratch: Vec<ScratchProfile> = rec
+            .arenas
+            .iter()
+            .filter(|(_, arena)| arena.high > arena.start)
+            .map(|(&end, arena)| ScratchProfile {
+                capacity: end - arena.start,
+                peak: arena.high - arena.start,
+            })
+            .collect();
+        scratch.sort_by(|a, b| b.peak.cmp(&a.peak).then_with(|| b.capacity.cmp(&a.capacity)));
+
+        ProfileReport { ops, scratch }
+    })
+}
+
+/// Returns the records of the calling thread and clears them.
+pub fn take_report() -> ProfileReport {
+    let report: ProfileReport = report();
+    reset();
+    report
+}
+
+/// Operations recorded by a [`Profiled`](super::Profiled) backend.
+#[derive(Clone, Debug, Default)]
+pub struct ProfileReport {
+    /// Recorded operations, by decreasing total time.
+    pub ops: Vec<OpProfile>,
+    /// Scratch buffers used by the recorded operations, by decreasing peak
+    /// consumption.
+    pub scratch: Vec<ScratchProfile>,
+}
+
+/// Calls of one operation.
+#[derive(Clone, Debug)]
+pub struct OpProfile {
+    /// Name of the [`HalImpl`](crate::oep::HalImpl) method.
+    pub name: &'static str,
+    /// Number of calls.
+    pub calls: u64,
+    /// Total wall time spent in the wrapped backend.
+    pub time: Duration,
+    /// Largest number of scratch bytes written by a single call.
+    pub max_scratch: usize,
+    /// Number of calls per argument dimensions, most frequent first.
+    pub shapes: Vec<ShapeCount>,
+}
+
+/// Number of calls of an operation with the given argument dimensions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShapeCount {
+    /// `(cols, size)` of each vector or matrix argument, in argument order.
+    pub dims: Vec<(usize, usize)>,
+    /// Number of calls.
+    pub calls: u64,
+}
+
+/// Consumption of a scratch buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScratchProfile {
+    /// Size of the buffer in bytes.
+    pub capacity: usize,
+    /// Largest number of bytes in use at once, from the start of the buffer.
+    pub peak: usize,
+}
+
+/// Comparison of a `*_tmp_bytes` estimate with the measured peak scratch
+/// consumption, see [`ProfileReport::check_tmp_bytes`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TmpBytesCheck {
+    /// Estimated number of bytes.
+    pub estimate: usize,
+    /// Measured peak consumption.
+    pub peak: usize,
+}
+
+impl TmpBytesCheck {
+    /// Returns `true` if the estimate covers the measured consumption.
+    pub fn is_sufficient(&self) -> bool {
+        self.peak <= self.estimate
+    }
+
+    /// Returns the number of estimated bytes that were never used.
+    pub fn unused(&self) -> usize {
+        self.estimate.saturating_sub(self.peak)
+    }
+}
+
+impl ProfileReport {
+    /// Returns the record of the operation `name`, if it was called.
+    pub fn op(&self, name: &str) -> Option<&OpProfile> {
+        self.ops.iter().find(|op| op.name == name)
+    }
+
+    /// Returns the number of calls of the operation `name`.
+    pub fn calls(&self, name: &str) -> u64 {
+        self.op(name).map_or(0, |op| op.calls)
+    }
+
+    /// Returns the total number of recorded calls.
+    pub fn total_calls(&self) -> u64 {
+        self.ops.iter().map(|op| op.calls).sum()
+    }
+
+    /// Returns the total wall time of the recorded calls.
+    pub fn total_time(&self) -> Duration {
+        self.ops.iter().map(|op| op.time).sum()
+    }
+
+    /// Returns the largest peak consumption among the scratch buffers.
+    pub fn peak_scratch(&self) -> usize {
+        self.scratch.first().map_or(0, |s| s.peak)
+    }
+
+    /// Compares `estimate`, typically the `*_tmp_bytes` of the profiled
+    /// routine, with [`ProfileReport::peak_scratch`].
+    pub fn check_tmp_bytes(&self, estimate: usize) -> TmpBytesCheck {
+        TmpBytesCheck {
+            estimate,
+            peak: self.peak_scratch(),
+        }
+    }
+}
+
+impl fmt::Display for ProfileReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total: Duration = self.total_time();
+        writeln!(
+            f,
+            "{:<40} {:>10} {:>12} {:>7} {:>12}  shapes",
+            "operation", "calls", "time (µs)", "time %", "scratch"
+        )?;
+        for op in &self.ops {
+            let share: f64 = if total.is_zero() {
+                0.0
+            } else {
+                100.0 * op.time.as_secs_f64() / total.as_secs_f64()
+            };
+            let shapes: Vec<String> = op
+                .shapes
+                .iter()
+                .take(3)
+                .map(|s| {
+                    let dims: Vec<String> = s.dims.iter().map(|(cols, size)| format!("{cols}x{size}")).collect();
+                    format!("[{}]x{}", dims.join(" "), s.calls)
+                })
+                .collect();
+            writeln!(
+                f,
+                "{:<40} {:>10} {:>12} {:>6.1}% {:>12}  {}{}",
+                op.name,
+                op.calls,
+                op.time.as_micros(),
+                share,
+                op.max_scratch,
+                shapes.join(", "),
+                if op.shapes.len() > 3 { ", ..." } else { "" }
+            )?;
+        }
+        write!(f, "{} calls, {} µs", self.total_calls(), total.as_micros())?;
+        for s in &self.scratch {
+            write!(f, "\nscratch: {} / {} bytes", s.peak, s.capacity)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for TmpBytesCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_sufficient() {
+            write!(
+                f,
+                "estimate {} bytes covers peak {} bytes ({} unused)",
+                self.estimate,
+                self.peak,
+                self.unused()
+            )
+        } else {
+            write!(
+                f,
+                "estimate {} bytes is short of peak {} bytes by {}",
+                self.estimate,
+                self.peak,
+                self.peak - self.estimate
+            )
+        }
+    }
+}
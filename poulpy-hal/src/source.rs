@@ -1,4 +1,5 @@
-use rand_chacha::{ChaCha8Rng, rand_core::SeedableRng};
+use rand::rngs::{SysError, SysRng};
+use rand_chacha::{ChaCha8Rng, ChaCha20Rng, rand_core::SeedableRng};
 use rand_core::{Infallible, Rng, TryRng};
 
 /// 2^53, the number of distinct values representable in the 53-bit significand
@@ -6,37 +7,76 @@ use rand_core::{Infallible, Rng, TryRng};
 /// `u64` to a uniformly distributed floating-point value in `[0, 1)`.
 const MAXF64: f64 = 9007199254740992.0;
 
-/// Deterministic pseudorandom number generator based on ChaCha8.
+/// Pseudorandom number generator used for all sampling in Poulpy.
 ///
-/// Wraps [`ChaCha8Rng`] to provide reproducible random sampling for
-/// lattice-based cryptographic operations. Given the same 32-byte seed,
-/// the output sequence is identical across platforms.
+/// A `Source` is created in one of two modes:
 ///
-/// **Not suitable for cryptographic key generation.** This type is intended
-/// for deterministic test vectors, noise sampling, and reproducible
-/// benchmarks, not for generating secrets.
+/// - [`Source::new`]: deterministic, based on [`ChaCha8Rng`]. Given the same
+///   32-byte seed, the output sequence is identical across platforms. Intended
+///   for test vectors, reproducible benchmarks and for regenerating public
+///   masks from a seed (compressed layouts). **Not suitable for generating
+///   secrets**, since anyone knowing the seed can replay the sequence.
+/// - [`Source::from_os_entropy`]: cryptographically secure, based on
+///   [`ChaCha20Rng`] seeded from the operating system's random number
+///   generator. To be used for secret, mask and error sampling outside of
+///   tests.
+///
+/// Both modes are accepted everywhere a `Source` is.
 pub struct Source {
-    source: ChaCha8Rng,
+    source: SourceRng,
+}
+
+enum SourceRng {
+    Deterministic(ChaCha8Rng),
+    Secure(ChaCha20Rng),
 }
 
 impl Source {
-    /// Creates a new `Source` from a 32-byte seed.
+    /// Creates a new deterministic `Source` from a 32-byte seed.
     ///
     /// The same seed always produces the same pseudorandom sequence.
     pub fn new(seed: [u8; 32]) -> Source {
         Source {
-            source: ChaCha8Rng::from_seed(seed),
+            source: SourceRng::Deterministic(ChaCha8Rng::from_seed(seed)),
         }
     }
 
+    /// Creates a new cryptographically secure `Source`, seeded from the
+    /// operating system's random number generator.
+    ///
+    /// # Panics
+    /// Panics if the operating system fails to provide entropy, see
+    /// [`Source::try_from_os_entropy`].
+    pub fn from_os_entropy() -> Source {
+        Self::try_from_os_entropy().expect("failed to obtain entropy from the operating system")
+    }
+
+    /// Fallible variant of [`Source::from_os_entropy`].
+    pub fn try_from_os_entropy() -> Result<Source, SysError> {
+        Ok(Source {
+            source: SourceRng::Secure(ChaCha20Rng::try_from_rng(&mut SysRng)?),
+        })
+    }
+
+    /// Returns `true` if `self` was created by [`Source::from_os_entropy`]
+    /// (or branched from such a source).
+    pub fn is_secure(&self) -> bool {
+        matches!(self.source, SourceRng::Secure(_))
+    }
+
     /// Derives an independent child `Source` for sub-stream splitting.
     ///
     /// Draws a fresh 32-byte seed from `self` and returns both the seed and
     /// a new `Source` seeded with it. The parent and child streams are
-    /// statistically independent.
+    /// statistically independent. The child of a secure source is itself
+    /// secure; the returned seed must then be kept secret.
     pub fn branch(&mut self) -> ([u8; 32], Self) {
         let seed: [u8; 32] = self.new_seed();
-        (seed, Source::new(seed))
+        let source: SourceRng = match self.source {
+            SourceRng::Deterministic(_) => SourceRng::Deterministic(ChaCha8Rng::from_seed(seed)),
+            SourceRng::Secure(_) => SourceRng::Secure(ChaCha20Rng::from_seed(seed)),
+        };
+        (seed, Source { source })
     }
 
     /// Draws 32 random bytes suitable for use as a derived seed.
@@ -96,7 +136,7 @@ impl Source {
     }
 }
 
-/// Implements [`TryRng`] by delegating to the inner [`ChaCha8Rng`].
+/// Implements [`TryRng`] by delegating to the inner [`ChaCha8Rng`] or [`ChaCha20Rng`].
 /// The blanket `impl<R: TryRng<Error = Infallible>> Rng for R` in `rand_core`
 /// then provides [`Rng`] automatically.
 impl TryRng for Source {
@@ -104,16 +144,25 @@ impl TryRng for Source {
 
     #[inline(always)]
     fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
-        self.source.try_next_u32()
+        match &mut self.source {
+            SourceRng::Deterministic(rng) => rng.try_next_u32(),
+            SourceRng::Secure(rng) => rng.try_next_u32(),
+        }
     }
 
     #[inline(always)]
     fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
-        self.source.try_next_u64()
+        match &mut self.source {
+            SourceRng::Deterministic(rng) => rng.try_next_u64(),
+            SourceRng::Secure(rng) => rng.try_next_u64(),
+        }
     }
 
     #[inline(always)]
     fn try_fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), Self::Error> {
-        self.source.try_fill_bytes(bytes)
+        match &mut self.source {
+            SourceRng::Deterministic(rng) => rng.try_fill_bytes(bytes),
+            SourceRng::Secure(rng) => rng.try_fill_bytes(bytes),
+        }
     }
 }
@@ -7,8 +7,8 @@ use crate::{
         VecZnxIdftApplyTmpA, VecZnxNormalizeAssign,
     },
     layouts::{
-        Backend, CnvPVecL, CnvPVecR, DeviceBuf, FillUniform, Scratch, ScratchOwned, VecZnx, VecZnxBig, VecZnxDft, VecZnxToMut,
-        VecZnxToRef, ZnxInfos, ZnxView, ZnxViewMut, ZnxZero,
+        AlignedBuf, Backend, CnvPVecL, CnvPVecR, DeviceBuf, FillUniform, Scratch, ScratchOwned, VecZnx, VecZnxBig, VecZnxDft,
+        VecZnxToMut, VecZnxToRef, ZnxInfos, ZnxView, ZnxViewMut, ZnxZero,
     },
     source::Source,
 };
@@ -36,11 +36,11 @@ where
     let b_size: usize = 15;
     let res_size: usize = a_size + b_size;
 
-    let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), a_cols, a_size);
-    let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), 1, b_size);
+    let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), a_cols, a_size);
+    let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), 1, b_size);
 
-    let mut res_want: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), 1, res_size);
-    let mut res_have: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), 1, res_size);
+    let mut res_want: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), 1, res_size);
+    let mut res_have: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), 1, res_size);
     let mut res_big: VecZnxBigOwned<BE> = module.vec_znx_big_alloc(1, res_size);
 
     a.fill_uniform(17, &mut source);
@@ -105,11 +105,11 @@ where
     let b_size: usize = 15;
     let res_size: usize = a_size + b_size;
 
-    let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), a_cols, a_size);
-    let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), b_cols, b_size);
+    let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), a_cols, a_size);
+    let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), b_cols, b_size);
 
-    let mut res_want: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), 1, res_size);
-    let mut res_have: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), 1, res_size);
+    let mut res_want: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), 1, res_size);
+    let mut res_have: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), 1, res_size);
     let mut res_dft: VecZnxDftOwned<BE> = module.vec_znx_dft_alloc(1, res_size);
     let mut res_big: VecZnxBigOwned<BE> = module.vec_znx_big_alloc(1, res_size);
 
@@ -181,13 +181,13 @@ where
     let b_size: usize = 15;
     let res_size: usize = a_size + b_size;
 
-    let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), cols, a_size);
-    let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), cols, b_size);
-    let mut tmp_a: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), 1, a_size);
-    let mut tmp_b: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), 1, b_size);
+    let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), cols, a_size);
+    let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), cols, b_size);
+    let mut tmp_a: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), 1, a_size);
+    let mut tmp_b: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), 1, b_size);
 
-    let mut res_want: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), 1, res_size);
-    let mut res_have: VecZnx<Vec<u8>> = VecZnx::alloc(module.n(), 1, res_size);
+    let mut res_want: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), 1, res_size);
+    let mut res_have: VecZnx<AlignedBuf> = VecZnx::alloc(module.n(), 1, res_size);
     let mut res_dft: VecZnxDftOwned<BE> = module.vec_znx_dft_alloc(1, res_size);
     let mut res_big: VecZnxBigOwned<BE> = module.vec_znx_big_alloc(1, res_size);
 
@@ -6,6 +6,7 @@
 //! Tests validate correctness against the [`crate::reference`] implementation.
 
 pub mod convolution;
+pub mod precision;
 pub mod serialization;
 pub mod svp;
 pub mod vec_znx;
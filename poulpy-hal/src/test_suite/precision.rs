@@ -0,0 +1,357 @@
+//! Differential tests of floating-point backends in the lossy regime.
+//!
+//! The suites of [`vmp`](super::vmp), [`svp`](super::svp) and
+//! [`convolution`](super::convolution) use small `base2k`, where the products
+//! of an `f64` FFT backend stay below `2^53` and round to the exact result, so
+//! they can assert equality. The tests of this module instead pick `base2k`
+//! and the number of limbs such that the exact coefficients of the products
+//! exceed `2^53` (but still fit an `i64`), and compare the big-coefficient
+//! outputs of the backend under test against an exact backend (typically
+//! `SchoolbookRef`) up to the bound below.
+//!
+//! # Error bound
+//!
+//! Let `ε = 2^-53` and `m = n/2` be the length of the complex FFT. Following
+//! Percival's bound on the floating-point FFT with twiddle factors accurate to
+//! `2ε`, the forward and inverse transforms of a vector `x` have an `ℓ2` error
+//! of at most `η·‖FFT(x)‖₂` with `η = ε·((5 + √5)·3·log2(m) + √5)`. Bounding
+//! the pointwise product of `FFT(a)` and `FFT(b)` with `‖FFT(b)‖∞ ≤ ‖b‖₁`, the
+//! product `a·b` of two integer polynomials then satisfies
+//!
+//! ```text
+//! ‖fl(a·b) − a·b‖∞ ≤ (2η + √5·ε) · (‖a‖₂·‖b‖₁ + ‖a‖₁·‖b‖₂)
+//! ```
+//!
+//! and an output accumulating `terms` such products before being rounded to
+//! the nearest integer is off by at most `terms` times this quantity plus
+//! `1/2`.
+
+use crate::{
+    api::{
+        CnvPVecAlloc, Convolution, ScratchOwnedAlloc, ScratchOwnedBorrow, SvpApplyDftToDft, SvpPPolAlloc, SvpPrepare,
+        VecZnxDftAlloc, VecZnxDftApply, VecZnxIdftApplyConsume, VmpApplyDftToDft, VmpApplyDftToDftTmpBytes, VmpPMatAlloc,
+        VmpPrepare, VmpPrepareTmpBytes,
+    },
+    layouts::{
+        AlignedBuf, Backend, CnvPVecL, CnvPVecR, DataRef, DeviceBuf, FillUniform, MatZnx, Module, ScalarZnx, ScratchOwned,
+        SvpPPolOwned, VecZnx, VecZnxBig, VecZnxDft, VmpPMat, ZnxInfos, ZnxView,
+    },
+    source::Source,
+};
+
+use super::TestParams;
+
+type VecZnxDftOwned<BE> = VecZnxDft<DeviceBuf<BE>, BE>;
+type VecZnxBigOwned<BE> = VecZnxBig<DeviceBuf<BE>, BE>;
+type VmpPMatOwned<BE> = VmpPMat<DeviceBuf<BE>, BE>;
+type CnvPVecLOwned<BE> = CnvPVecL<DeviceBuf<BE>, BE>;
+type CnvPVecROwned<BE> = CnvPVecR<DeviceBuf<BE>, BE>;
+
+/// Largest `ℓ1`, `ℓ2` and `ℓ∞` norms over the polynomials of a layout.
+#[derive(Clone, Copy, Debug, Default)]
+struct Norms {
+    l1: f64,
+    l2: f64,
+    linf: f64,
+}
+
+impl Norms {
+    fn of(raw: &[i64], n: usize) -> Self {
+        raw.chunks_exact(n).fold(Norms::default(), |acc, poly| Norms {
+            l1: acc.l1.max(poly.iter().map(|x| x.unsigned_abs() as f64).sum()),
+            l2: acc.l2.max(poly.iter().map(|&x| (x as f64) * (x as f64)).sum::<f64>().sqrt()),
+            linf: acc.linf.max(poly.iter().map(|x| x.unsigned_abs() as f64).fold(0.0, f64::max)),
+        })
+    }
+}
+
+/// Returns the bound on `|fl(c) − c|` for an output coefficient `c` of a
+/// backend computing in `f64` through a complex FFT of length `n/2`, where `c`
+/// accumulates `terms` negacyclic products of polynomials whose norms are at
+/// most `a` and `b` (see the [module documentation](self)).
+fn fft_product_error_bound(n: usize, terms: usize, a: Norms, b: Norms) -> f64 {
+    let eps: f64 = f64::EPSILON / 2.0;
+    let sqrt5: f64 = 5f64.sqrt();
+    let log_m: f64 = (n / 2).max(1).ilog2() as f64;
+    let eta: f64 = eps * ((5.0 + sqrt5) * 3.0 * log_m + sqrt5);
+    terms as f64 * (2.0 * eta + sqrt5 * eps) * (a.l2 * b.l1 + a.l1 * b.l2) + 0.5
+}
+
+/// Checks that the exact outputs can be held by an `i64` big coefficient and
+/// exceed `2^53`, so that the comparison exercises the lossy regime.
+fn assert_lossy_regime(terms: usize, a: Norms, b: Norms) {
+    let worst: f64 = terms as f64 * a.l1 * b.linf;
+    assert!(
+        worst < (1u64 << 62) as f64,
+        "worst-case output 2^{:.1} may overflow an i64",
+        worst.log2()
+    );
+}
+
+/// Returns `(max |test − ref|, max |ref|)` over all coefficients of two big vectors.
+fn max_abs_diff<BR: Backend, BT: Backend, DR: DataRef, DT: DataRef>(
+    res_ref: &VecZnxBig<DR, BR>,
+    res_test: &VecZnxBig<DT, BT>,
+) -> (u128, u128)
+where
+    BR::ScalarBig: Into<i128>,
+    BT::ScalarBig: Into<i128>,
+{
+    assert_eq!(res_ref.cols(), res_test.cols());
+    assert_eq!(res_ref.size(), res_test.size());
+    let mut max_diff: u128 = 0;
+    let mut max_ref: u128 = 0;
+    for col in 0..res_ref.cols() {
+        for limb in 0..res_ref.size() {
+            for (&r, &t) in res_ref.at(col, limb).iter().zip(res_test.at(col, limb)) {
+                let (r, t): (i128, i128) = (r.into(), t.into());
+                max_diff = max_diff.max((t - r).unsigned_abs());
+                max_ref = max_ref.max(r.unsigned_abs());
+            }
+        }
+    }
+    (max_diff, max_ref)
+}
+
+fn assert_within_bound(op: &str, max_diff: u128, max_ref: u128, bound: f64) {
+    assert!(
+        max_ref > 1u128 << 53,
+        "{op}: largest output 2^{:.1} does not exceed 2^53, the test is not in the lossy regime",
+        (max_ref as f64).log2()
+    );
+    assert!(
+        max_diff as f64 <= bound,
+        "{op}: max |test - ref| = {max_diff} exceeds the analytic bound {bound:.1}"
+    );
+}
+
+/// Compares [`VmpApplyDftToDft`] of `module_test` against `module_ref` on
+/// inputs uniform in `[-2^(base2k-1), 2^(base2k-1))`, up to the FFT error bound.
+pub fn test_vmp_apply_dft_to_dft_lossy<BR: Backend, BT: Backend>(
+    params: &TestParams,
+    module_ref: &Module<BR>,
+    module_test: &Module<BT>,
+) where
+    Module<BR>: VmpApplyDftToDftTmpBytes
+        + VmpApplyDftToDft<BR>
+        + VmpPMatAlloc<BR>
+        + VmpPrepare<BR>
+        + VmpPrepareTmpBytes
+        + VecZnxDftAlloc<BR>
+        + VecZnxDftApply<BR>
+        + VecZnxIdftApplyConsume<BR>,
+    Module<BT>: VmpApplyDftToDftTmpBytes
+        + VmpApplyDftToDft<BT>
+        + VmpPMatAlloc<BT>
+        + VmpPrepare<BT>
+        + VmpPrepareTmpBytes
+        + VecZnxDftAlloc<BT>
+        + VecZnxDftApply<BT>
+        + VecZnxIdftApplyConsume<BT>,
+    ScratchOwned<BR>: ScratchOwnedAlloc<BR> + ScratchOwnedBorrow<BR>,
+    ScratchOwned<BT>: ScratchOwnedAlloc<BT> + ScratchOwnedBorrow<BT>,
+    BR::ScalarBig: Into<i128>,
+    BT::ScalarBig: Into<i128>,
+{
+    let base2k: usize = params.base2k;
+    assert_eq!(module_ref.n(), module_test.n());
+    let n: usize = module_ref.n();
+
+    let cols_in: usize = 2;
+    let cols_out: usize = 2;
+    let size_in: usize = 3;
+    let size_out: usize = 4;
+    let rows: usize = size_in;
+
+    let mut source: Source = Source::new([0u8; 32]);
+
+    let mut scratch_ref: ScratchOwned<BR> = ScratchOwned::alloc(
+        module_ref
+            .vmp_apply_dft_to_dft_tmp_bytes(size_out, size_in, rows, cols_in, cols_out, size_out)
+            .max(module_ref.vmp_prepare_tmp_bytes(rows, cols_in, cols_out, size_out)),
+    );
+    let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(
+        module_test
+            .vmp_apply_dft_to_dft_tmp_bytes(size_out, size_in, rows, cols_in, cols_out, size_out)
+            .max(module_test.vmp_prepare_tmp_bytes(rows, cols_in, cols_out, size_out)),
+    );
+
+    let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols_in, size_in);
+    a.fill_uniform(base2k, &mut source);
+
+    let mut mat: MatZnx<AlignedBuf> = MatZnx::alloc(n, rows, cols_in, cols_out, size_out);
+    mat.fill_uniform(base2k, &mut source);
+
+    let terms: usize = rows * cols_in;
+    let a_norms: Norms = Norms::of(a.raw(), n);
+    let mat_norms: Norms = Norms::of(mat.raw(), n);
+    assert_lossy_regime(terms, a_norms, mat_norms);
+
+    let mut a_dft_ref: VecZnxDftOwned<BR> = module_ref.vec_znx_dft_alloc(cols_in, size_in);
+    let mut a_dft_test: VecZnxDftOwned<BT> = module_test.vec_znx_dft_alloc(cols_in, size_in);
+    for j in 0..cols_in {
+        module_ref.vec_znx_dft_apply(1, 0, &mut a_dft_ref, j, &a, j);
+        module_test.vec_znx_dft_apply(1, 0, &mut a_dft_test, j, &a, j);
+    }
+
+    let mut pmat_ref: VmpPMatOwned<BR> = module_ref.vmp_pmat_alloc(rows, cols_in, cols_out, size_out);
+    let mut pmat_test: VmpPMatOwned<BT> = module_test.vmp_pmat_alloc(rows, cols_in, cols_out, size_out);
+    module_ref.vmp_prepare(&mut pmat_ref, &mat, scratch_ref.borrow());
+    module_test.vmp_prepare(&mut pmat_test, &mat, scratch_test.borrow());
+
+    let mut res_dft_ref: VecZnxDftOwned<BR> = module_ref.vec_znx_dft_alloc(cols_out, size_out);
+    let mut res_dft_test: VecZnxDftOwned<BT> = module_test.vec_znx_dft_alloc(cols_out, size_out);
+    module_ref.vmp_apply_dft_to_dft(&mut res_dft_ref, &a_dft_ref, &pmat_ref, 0, scratch_ref.borrow());
+    module_test.vmp_apply_dft_to_dft(&mut res_dft_test, &a_dft_test, &pmat_test, 0, scratch_test.borrow());
+
+    let res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_idft_apply_consume(res_dft_ref);
+    let res_big_test: VecZnxBigOwned<BT> = module_test.vec_znx_idft_apply_consume(res_dft_test);
+
+    let (max_diff, max_ref) = max_abs_diff(&res_big_ref, &res_big_test);
+    let bound: f64 = fft_product_error_bound(n, terms, a_norms, mat_norms);
+    assert_within_bound("vmp_apply_dft_to_dft", max_diff, max_ref, bound);
+}
+
+/// Compares [`SvpApplyDftToDft`] of `module_test` against `module_ref` on
+/// inputs uniform in `[-2^(base2k-1), 2^(base2k-1))`, up to the FFT error bound.
+pub fn test_svp_apply_dft_to_dft_lossy<BR: Backend, BT: Backend>(
+    params: &TestParams,
+    module_ref: &Module<BR>,
+    module_test: &Module<BT>,
+) where
+    Module<BR>: SvpPrepare<BR>
+        + SvpApplyDftToDft<BR>
+        + SvpPPolAlloc<BR>
+        + VecZnxDftAlloc<BR>
+        + VecZnxDftApply<BR>
+        + VecZnxIdftApplyConsume<BR>,
+    Module<BT>: SvpPrepare<BT>
+        + SvpApplyDftToDft<BT>
+        + SvpPPolAlloc<BT>
+        + VecZnxDftAlloc<BT>
+        + VecZnxDftApply<BT>
+        + VecZnxIdftApplyConsume<BT>,
+    BR::ScalarBig: Into<i128>,
+    BT::ScalarBig: Into<i128>,
+{
+    let base2k: usize = params.base2k;
+    assert_eq!(module_ref.n(), module_test.n());
+    let n: usize = module_ref.n();
+
+    let cols: usize = 2;
+    let size: usize = 4;
+
+    let mut source: Source = Source::new([0u8; 32]);
+
+    let mut scalar: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, cols);
+    scalar.fill_uniform(base2k, &mut source);
+
+    let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
+    a.fill_uniform(base2k, &mut source);
+
+    let scalar_norms: Norms = Norms::of(scalar.raw(), n);
+    let a_norms: Norms = Norms::of(a.raw(), n);
+    assert_lossy_regime(1, scalar_norms, a_norms);
+
+    let mut svp_ref: SvpPPolOwned<BR> = module_ref.svp_ppol_alloc(cols);
+    let mut svp_test: SvpPPolOwned<BT> = module_test.svp_ppol_alloc(cols);
+
+    let mut a_dft_ref: VecZnxDftOwned<BR> = module_ref.vec_znx_dft_alloc(cols, size);
+    let mut a_dft_test: VecZnxDftOwned<BT> = module_test.vec_znx_dft_alloc(cols, size);
+
+    let mut res_dft_ref: VecZnxDftOwned<BR> = module_ref.vec_znx_dft_alloc(cols, size);
+    let mut res_dft_test: VecZnxDftOwned<BT> = module_test.vec_znx_dft_alloc(cols, size);
+
+    for j in 0..cols {
+        module_ref.svp_prepare(&mut svp_ref, j, &scalar, j);
+        module_test.svp_prepare(&mut svp_test, j, &scalar, j);
+        module_ref.vec_znx_dft_apply(1, 0, &mut a_dft_ref, j, &a, j);
+        module_test.vec_znx_dft_apply(1, 0, &mut a_dft_test, j, &a, j);
+        module_ref.svp_apply_dft_to_dft(&mut res_dft_ref, j, &svp_ref, j, &a_dft_ref, j);
+        module_test.svp_apply_dft_to_dft(&mut res_dft_test, j, &svp_test, j, &a_dft_test, j);
+    }
+
+    let res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_idft_apply_consume(res_dft_ref);
+    let res_big_test: VecZnxBigOwned<BT> = module_test.vec_znx_idft_apply_consume(res_dft_test);
+
+    let (max_diff, max_ref) = max_abs_diff(&res_big_ref, &res_big_test);
+    let bound: f64 = fft_product_error_bound(n, 1, scalar_norms, a_norms);
+    assert_within_bound("svp_apply_dft_to_dft", max_diff, max_ref, bound);
+}
+
+/// Compares [`Convolution::cnv_apply_dft`] of `module_test` against
+/// `module_ref` on inputs uniform in `[-2^(base2k-1), 2^(base2k-1))`, up to the
+/// FFT error bound.
+pub fn test_convolution_lossy<BR: Backend, BT: Backend>(params: &TestParams, module_ref: &Module<BR>, module_test: &Module<BT>)
+where
+    Module<BR>: Convolution<BR> + CnvPVecAlloc<BR> + VecZnxDftAlloc<BR> + VecZnxIdftApplyConsume<BR>,
+    Module<BT>: Convolution<BT> + CnvPVecAlloc<BT> + VecZnxDftAlloc<BT> + VecZnxIdftApplyConsume<BT>,
+    ScratchOwned<BR>: ScratchOwnedAlloc<BR> + ScratchOwnedBorrow<BR>,
+    ScratchOwned<BT>: ScratchOwnedAlloc<BT> + ScratchOwnedBorrow<BT>,
+    BR::ScalarBig: Into<i128>,
+    BT::ScalarBig: Into<i128>,
+{
+    let base2k: usize = params.base2k;
+    assert_eq!(module_ref.n(), module_test.n());
+    let n: usize = module_ref.n();
+
+    let a_size: usize = 4;
+    let b_size: usize = 4;
+    let res_size: usize = a_size + b_size - 1;
+
+    let mut source: Source = Source::new([0u8; 32]);
+
+    let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, 1, a_size);
+    let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(n, 1, b_size);
+    a.fill_uniform(base2k, &mut source);
+    b.fill_uniform(base2k, &mut source);
+
+    let terms: usize = a_size.min(b_size);
+    let a_norms: Norms = Norms::of(a.raw(), n);
+    let b_norms: Norms = Norms::of(b.raw(), n);
+    assert_lossy_regime(terms, a_norms, b_norms);
+
+    let mut scratch_ref: ScratchOwned<BR> = ScratchOwned::alloc(
+        module_ref
+            .cnv_apply_dft_tmp_bytes(0, res_size, a_size, b_size)
+            .max(module_ref.cnv_prepare_left_tmp_bytes(res_size, a_size))
+            .max(module_ref.cnv_prepare_right_tmp_bytes(res_size, b_size)),
+    );
+    let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(
+        module_test
+            .cnv_apply_dft_tmp_bytes(0, res_size, a_size, b_size)
+            .max(module_test.cnv_prepare_left_tmp_bytes(res_size, a_size))
+            .max(module_test.cnv_prepare_right_tmp_bytes(res_size, b_size)),
+    );
+
+    let mut a_prep_ref: CnvPVecLOwned<BR> = module_ref.cnv_pvec_left_alloc(1, a_size);
+    let mut b_prep_ref: CnvPVecROwned<BR> = module_ref.cnv_pvec_right_alloc(1, b_size);
+    let mut a_prep_test: CnvPVecLOwned<BT> = module_test.cnv_pvec_left_alloc(1, a_size);
+    let mut b_prep_test: CnvPVecROwned<BT> = module_test.cnv_pvec_right_alloc(1, b_size);
+
+    module_ref.cnv_prepare_left(&mut a_prep_ref, &a, !0i64, scratch_ref.borrow());
+    module_ref.cnv_prepare_right(&mut b_prep_ref, &b, !0i64, scratch_ref.borrow());
+    module_test.cnv_prepare_left(&mut a_prep_test, &a, !0i64, scratch_test.borrow());
+    module_test.cnv_prepare_right(&mut b_prep_test, &b, !0i64, scratch_test.borrow());
+
+    let mut res_dft_ref: VecZnxDftOwned<BR> = module_ref.vec_znx_dft_alloc(1, res_size);
+    let mut res_dft_test: VecZnxDftOwned<BT> = module_test.vec_znx_dft_alloc(1, res_size);
+    module_ref.cnv_apply_dft(0, &mut res_dft_ref, 0, &a_prep_ref, 0, &b_prep_ref, 0, scratch_ref.borrow());
+    module_test.cnv_apply_dft(
+        0,
+        &mut res_dft_test,
+        0,
+        &a_prep_test,
+        0,
+        &b_prep_test,
+        0,
+        scratch_test.borrow(),
+    );
+
+    let res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_idft_apply_consume(res_dft_ref);
+    let res_big_test: VecZnxBigOwned<BT> = module_test.vec_znx_idft_apply_consume(res_dft_test);
+
+    let (max_diff, max_ref) = max_abs_diff(&res_big_ref, &res_big_test);
+    let bound: f64 = fft_product_error_bound(n, terms, a_norms, b_norms);
+    assert_within_bound("cnv_apply_dft", max_diff, max_ref, bound);
+}
@@ -1,8 +1,15 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, io::ErrorKind};
+
+use rand::Rng;
 
 use crate::{
-    layouts::{FillUniform, ReaderFrom, WriterTo},
+    DEFAULTALIGN, alloc_aligned,
+    layouts::{
+        AlignedBuf, Backend, DataView, DataViewMut, DeviceBuf, DigestU64, FillUniform, Module, PREPARED_BLOCK_BYTES, ReadZstd,
+        ReaderFrom, SvpPPol, VecZnxDft, VmpPMat, WriteZstd, WriterTo, ZSTD_DEFAULT_LEVEL,
+    },
     source::Source,
+    test_suite::TestParams,
 };
 
 /// Generic test for serialization and deserialization.
@@ -34,18 +41,283 @@ where
 
 #[test]
 fn scalar_znx_serialize() {
-    let original: crate::layouts::ScalarZnx<Vec<u8>> = crate::layouts::ScalarZnx::alloc(1024, 3);
+    let original: crate::layouts::ScalarZnx<AlignedBuf> = crate::layouts::ScalarZnx::alloc(1024, 3);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn vec_znx_serialize() {
-    let original: crate::layouts::VecZnx<Vec<u8>> = crate::layouts::VecZnx::alloc(1024, 3, 4);
+    let original: crate::layouts::VecZnx<AlignedBuf> = crate::layouts::VecZnx::alloc(1024, 3, 4);
     test_reader_writer_interface(original);
 }
 
 #[test]
 fn mat_znx_serialize() {
-    let original: crate::layouts::MatZnx<Vec<u8>> = crate::layouts::MatZnx::alloc(1024, 3, 2, 2, 4);
+    let original: crate::layouts::MatZnx<AlignedBuf> = crate::layouts::MatZnx::alloc(1024, 3, 2, 2, 4);
     test_reader_writer_interface(original);
 }
+
+/// Round trip through [`WriteZstd`] / [`ReadZstd`], returning the size of the
+/// raw and of the compressed encoding.
+pub fn test_zstd_interface<T>(original: &T, receiver: &mut T) -> (usize, usize)
+where
+    T: WriterTo + ReaderFrom + PartialEq + Eq + Debug,
+{
+    let mut raw: Vec<u8> = Vec::new();
+    original.write_to(&mut raw).expect("write_to failed");
+
+    let mut compressed: Vec<u8> = Vec::new();
+    original
+        .write_zstd(&mut compressed, ZSTD_DEFAULT_LEVEL)
+        .expect("write_zstd failed");
+
+    let mut reader: &[u8] = &compressed;
+    receiver.read_zstd(&mut reader).expect("read_zstd failed");
+    assert!(reader.is_empty(), "read_zstd left {} bytes unread", reader.len());
+    assert_eq!(original, receiver, "Decompressed object does not match the original");
+
+    (raw.len(), compressed.len())
+}
+
+#[test]
+fn vec_znx_zstd() {
+    let mut source: Source = Source::new([0u8; 32]);
+    let mut original: crate::layouts::VecZnx<AlignedBuf> = crate::layouts::VecZnx::alloc(1024, 3, 4);
+    original.fill_uniform(12, &mut source);
+    let mut receiver: crate::layouts::VecZnx<AlignedBuf> = crate::layouts::VecZnx::alloc(1024, 3, 4);
+    let (raw, compressed) = test_zstd_interface(&original, &mut receiver);
+    // 12 significant bits out of 64 per coefficient.
+    assert!(compressed * 3 < raw, "raw={raw} compressed={compressed}");
+}
+
+#[test]
+fn zstd_back_to_back_frames() {
+    let mut source: Source = Source::new([0u8; 32]);
+    let mut a: crate::layouts::VecZnx<AlignedBuf> = crate::layouts::VecZnx::alloc(256, 2, 3);
+    a.fill_uniform(20, &mut source);
+    let mut b: crate::layouts::MatZnx<AlignedBuf> = crate::layouts::MatZnx::alloc(256, 2, 2, 1, 3);
+    b.fill_uniform(20, &mut source);
+
+    let mut bytes: Vec<u8> = Vec::new();
+    a.write_zstd(&mut bytes, 3).unwrap();
+    b.write_zstd(&mut bytes, 19).unwrap();
+    bytes.extend_from_slice(b"tail");
+
+    let mut reader: &[u8] = &bytes;
+    let mut a_have: crate::layouts::VecZnx<AlignedBuf> = crate::layouts::VecZnx::alloc(256, 2, 3);
+    let mut b_have: crate::layouts::MatZnx<AlignedBuf> = crate::layouts::MatZnx::alloc(256, 2, 2, 1, 3);
+    a_have.read_zstd(&mut reader).unwrap();
+    b_have.read_zstd(&mut reader).unwrap();
+    assert_eq!(a, a_have);
+    assert_eq!(b, b_have);
+    assert_eq!(reader, b"tail");
+}
+
+#[test]
+fn zstd_rejects_corrupted_and_oversized_frames() {
+    let mut source: Source = Source::new([0u8; 32]);
+    let mut original: crate::layouts::VecZnx<AlignedBuf> = crate::layouts::VecZnx::alloc(256, 2, 3);
+    original.fill_uniform(20, &mut source);
+
+    let mut bytes: Vec<u8> = Vec::new();
+    original.write_zstd(&mut bytes, ZSTD_DEFAULT_LEVEL).unwrap();
+
+    // Flipped byte in the compressed payload.
+    let mut corrupted: Vec<u8> = bytes.clone();
+    let mid: usize = corrupted.len() / 2;
+    corrupted[mid] ^= 0x55;
+    let mut receiver: crate::layouts::VecZnx<AlignedBuf> = crate::layouts::VecZnx::alloc(256, 2, 3);
+    assert!(receiver.read_zstd(&mut corrupted.as_slice()).is_err());
+
+    // Frame holding a larger object than the receiver reads.
+    let mut larger: crate::layouts::ScalarZnx<AlignedBuf> = crate::layouts::ScalarZnx::alloc(256, 2);
+    larger.fill_uniform(20, &mut source);
+    let mut scalar_bytes: Vec<u8> = Vec::new();
+    larger.write_to(&mut scalar_bytes).unwrap();
+    scalar_bytes.extend_from_slice(&[0u8; 8]);
+    let framed: Vec<u8> = zstd::stream::encode_all(scalar_bytes.as_slice(), ZSTD_DEFAULT_LEVEL).unwrap();
+    let mut receiver: crate::layouts::ScalarZnx<AlignedBuf> = crate::layouts::ScalarZnx::alloc(256, 2);
+    let err = receiver.read_zstd(&mut framed.as_slice()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+/// Round trip through JSON, bincode and CBOR.
+#[cfg(all(test, feature = "serde"))]
+fn test_serde_interface<T>(mut original: T)
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + PartialEq + Eq + Debug + FillUniform,
+{
+    original.fill_uniform(50, &mut Source::new([0u8; 32]));
+
+    let json: String = serde_json::to_string(&original).expect("serde_json serialization failed");
+    let have: T = serde_json::from_str(&json).expect("serde_json deserialization failed");
+    assert_eq!(original, have);
+
+    let config = bincode::config::standard();
+    let bytes: Vec<u8> = bincode::serde::encode_to_vec(&original, config).expect("bincode serialization failed");
+    let (have, len): (T, usize) = bincode::serde::decode_from_slice(&bytes, config).expect("bincode deserialization failed");
+    assert_eq!(len, bytes.len());
+    assert_eq!(original, have);
+
+    let mut cbor: Vec<u8> = Vec::new();
+    ciborium::into_writer(&original, &mut cbor).expect("ciborium serialization failed");
+    let have: T = ciborium::from_reader(cbor.as_slice()).expect("ciborium deserialization failed");
+    assert_eq!(original, have);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn scalar_znx_serde() {
+    test_serde_interface(crate::layouts::ScalarZnx::alloc(64, 3));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn vec_znx_serde() {
+    test_serde_interface(crate::layouts::VecZnx::alloc(64, 3, 4));
+
+    // Degrees below 8 and not a power of two, whose coefficients take less
+    // than the 64-byte padding of the allocation.
+    for (n, cols, size) in [(4, 1, 1), (1, 1, 1), (17, 1, 2), (5, 2, 3)] {
+        test_serde_interface(crate::layouts::VecZnx::alloc(n, cols, size));
+    }
+
+    // Active size below the capacity: only the active limbs are serialized
+    // and allocated.
+    let mut original: crate::layouts::VecZnx<AlignedBuf> = crate::layouts::VecZnx::alloc(64, 2, 4);
+    original.fill_uniform(50, &mut Source::new([1u8; 32]));
+    original.set_size(2);
+    let json: String = serde_json::to_string(&original).unwrap();
+    assert!(!json.contains("max_size"), "{json}");
+    let have: crate::layouts::VecZnx<AlignedBuf> = serde_json::from_str(&json).unwrap();
+    assert_eq!(have.size, 2);
+    assert_eq!(have.max_size, 2);
+    assert_eq!(have.data.len(), 64 * 2 * 2 * 8);
+    assert_eq!(have.data[..], original.data[..64 * 2 * 2 * 8]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn mat_znx_serde() {
+    test_serde_interface(crate::layouts::MatZnx::alloc(64, 3, 2, 2, 4));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn vec_znx_serde_rejects_inconsistent_metadata() {
+    let original: crate::layouts::VecZnx<AlignedBuf> = crate::layouts::VecZnx::alloc(64, 2, 3);
+    let json: String = serde_json::to_string(&original).unwrap().replace("\"cols\":2", "\"cols\":3");
+    let err = serde_json::from_str::<crate::layouts::VecZnx<AlignedBuf>>(&json).unwrap_err();
+    assert!(err.to_string().contains("metadata inconsistent"), "{err}");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn vec_znx_serde_rejects_invalid_dimensions() {
+    // A capacity written by an older encoder is ignored, never allocated.
+    let original: crate::layouts::VecZnx<AlignedBuf> = crate::layouts::VecZnx::alloc(64, 2, 3);
+    let json: String = serde_json::to_string(&original).unwrap();
+    let have: crate::layouts::VecZnx<AlignedBuf> =
+        serde_json::from_str(&json.replacen('{', &format!("{{\"max_size\":{},", usize::MAX), 1)).unwrap();
+    assert_eq!(have, original);
+
+    let empty: String = serde_json::to_string(&serde_json::json!({
+        "n": 0, "cols": 2, "size": 3, "data": Vec::<u8>::new(),
+    }))
+    .unwrap();
+    let err = serde_json::from_str::<crate::layouts::VecZnx<AlignedBuf>>(&empty).unwrap_err();
+    assert!(err.to_string().contains("must be non-zero"), "{err}");
+}
+
+/// Persists prepared layouts of `BT` and loads them back, through
+/// [`ReaderFrom`] and through the zero-copy `from_bytes`, into layouts of
+/// `BT` and of `BR`.
+///
+/// Loading into `BR` must succeed if and only if both backends share the
+/// same [`Backend::PREPARED_LAYOUT`], and loading into a layout of another
+/// ring degree must always fail.
+pub fn test_prepared_persistence<BR: Backend, BT: Backend>(
+    _params: &TestParams,
+    module_ref: &Module<BR>,
+    module_test: &Module<BT>,
+) {
+    assert_eq!(module_ref.n(), module_test.n());
+    let n: usize = module_test.n();
+    let (rows, cols_in, cols_out, size) = (3, 2, 3, 4);
+    let same_layout: bool = BR::PREPARED_LAYOUT == BT::PREPARED_LAYOUT;
+
+    let mut source: Source = Source::new([0u8; 32]);
+    let mut pmat: VmpPMat<DeviceBuf<BT>, BT> = VmpPMat::alloc(n, rows, cols_in, cols_out, size);
+    let mut ppol: SvpPPol<DeviceBuf<BT>, BT> = SvpPPol::alloc(n, cols_in);
+    let mut dft: VecZnxDft<DeviceBuf<BT>, BT> = VecZnxDft::alloc(n, cols_out, size);
+    source.fill_bytes(pmat.data_mut().as_mut());
+    source.fill_bytes(ppol.data_mut().as_mut());
+    source.fill_bytes(dft.data_mut().as_mut());
+
+    let mut bytes: Vec<u8> = Vec::new();
+    pmat.write_to(&mut bytes).expect("write_to failed");
+    ppol.write_to(&mut bytes).expect("write_to failed");
+    dft.write_to(&mut bytes).expect("write_to failed");
+    assert_eq!(bytes.len() % PREPARED_BLOCK_BYTES, 0);
+
+    // Streamed loading into layouts of the same backend.
+    {
+        let mut reader: &[u8] = &bytes;
+        let mut pmat_have: VmpPMat<DeviceBuf<BT>, BT> = VmpPMat::alloc(n, rows, cols_in, cols_out, size);
+        let mut ppol_have: SvpPPol<DeviceBuf<BT>, BT> = SvpPPol::alloc(n, cols_in);
+        let mut dft_have: VecZnxDft<DeviceBuf<BT>, BT> = VecZnxDft::alloc(n, cols_out, size);
+        pmat_have.read_from(&mut reader).expect("read_from failed");
+        ppol_have.read_from(&mut reader).expect("read_from failed");
+        dft_have.read_from(&mut reader).expect("read_from failed");
+        assert!(reader.is_empty());
+        assert_eq!(pmat_have.digest_u64(), pmat.digest_u64());
+        assert_eq!(ppol_have.digest_u64(), ppol.digest_u64());
+        assert_eq!(dft_have.digest_u64(), dft.digest_u64());
+    }
+
+    // Zero-copy loading, from an aligned copy of the stream.
+    {
+        let aligned: AlignedBuf = AlignedBuf::from_slice(&bytes);
+        let (pmat_have, rest) = VmpPMat::<&[u8], BT>::from_bytes(n, &aligned).expect("from_bytes failed");
+        let (ppol_have, rest) = SvpPPol::<&[u8], BT>::from_bytes(n, rest).expect("from_bytes failed");
+        let (dft_have, rest) = VecZnxDft::<&[u8], BT>::from_bytes(n, rest).expect("from_bytes failed");
+        assert!(rest.is_empty());
+        assert_eq!(pmat_have.digest_u64(), pmat.digest_u64());
+        assert_eq!(ppol_have.digest_u64(), ppol.digest_u64());
+        assert_eq!(dft_have.digest_u64(), dft.digest_u64());
+
+        let mut misaligned: AlignedBuf = alloc_aligned(bytes.len() + DEFAULTALIGN);
+        misaligned[8..8 + bytes.len()].copy_from_slice(&bytes);
+        let Err(err) = VmpPMat::<&[u8], BT>::from_bytes(n, &misaligned[8..8 + bytes.len()]) else {
+            panic!("from_bytes accepted a misaligned buffer");
+        };
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+
+    // Loading into a layout of another ring degree.
+    {
+        let mut pmat_have: VmpPMat<DeviceBuf<BT>, BT> = VmpPMat::alloc(n << 1, rows, cols_in, cols_out, size);
+        assert!(pmat_have.read_from(&mut bytes.as_slice()).is_err());
+        assert!(VmpPMat::<&[u8], BT>::from_bytes(n >> 1, &bytes).is_err());
+    }
+
+    // Loading into layouts of the other backend.
+    {
+        let mut reader: &[u8] = &bytes;
+        let mut pmat_have: VmpPMat<DeviceBuf<BR>, BR> = VmpPMat::alloc(n, rows, cols_in, cols_out, size);
+        let res = pmat_have.read_from(&mut reader);
+        assert_eq!(res.is_ok(), same_layout, "{:?}", res);
+        if same_layout {
+            let mut ppol_have: SvpPPol<DeviceBuf<BR>, BR> = SvpPPol::alloc(n, cols_in);
+            let mut dft_have: VecZnxDft<DeviceBuf<BR>, BR> = VecZnxDft::alloc(n, cols_out, size);
+            ppol_have.read_from(&mut reader).expect("read_from failed");
+            dft_have.read_from(&mut reader).expect("read_from failed");
+            assert_eq!(pmat_have.data().as_ref(), pmat.data().as_ref());
+            assert_eq!(ppol_have.data().as_ref(), ppol.data().as_ref());
+            assert_eq!(dft_have.data().as_ref(), dft.data().as_ref());
+        } else {
+            let err = res.unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidData);
+        }
+    }
+}
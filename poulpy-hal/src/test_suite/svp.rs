@@ -7,7 +7,8 @@ use crate::{
         VecZnxBigNormalize, VecZnxBigNormalizeTmpBytes, VecZnxDftAlloc, VecZnxDftApply, VecZnxIdftApplyConsume,
     },
     layouts::{
-        Backend, DataViewMut, DeviceBuf, DigestU64, FillUniform, Module, ScalarZnx, ScratchOwned, SvpPPolOwned, VecZnx, VecZnxDft,
+        AlignedBuf, Backend, DataViewMut, DeviceBuf, DigestU64, FillUniform, Module, ScalarZnx, ScratchOwned, SvpPPolOwned,
+        VecZnx, VecZnxDft,
     },
     source::Source,
 };
@@ -45,7 +46,7 @@ where
     let mut scratch_ref: ScratchOwned<BR> = ScratchOwned::alloc(module_ref.vec_znx_big_normalize_tmp_bytes());
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
-    let mut scalar: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, cols);
+    let mut scalar: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, cols);
     scalar.fill_uniform(base2k, &mut source);
 
     let scalar_digest: u64 = scalar.digest_u64();
@@ -65,7 +66,7 @@ where
 
     for a_size in [1, 2, 3, 4] {
         // Create a random input VecZnx
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
 
         let a_digest: u64 = a.digest_u64();
@@ -92,8 +93,8 @@ where
             let res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_idft_apply_consume(res_dft_ref);
             let res_big_test: VecZnxBigOwned<BT> = module_test.vec_znx_idft_apply_consume(res_dft_test);
 
-            let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             for j in 0..cols {
                 module_ref.vec_znx_big_normalize(&mut res_ref, base2k, 0, j, &res_big_ref, base2k, j, scratch_ref.borrow());
@@ -137,7 +138,7 @@ where
     let mut scratch_ref: ScratchOwned<BR> = ScratchOwned::alloc(module_ref.vec_znx_big_normalize_tmp_bytes());
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
-    let mut scalar: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, cols);
+    let mut scalar: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, cols);
     scalar.fill_uniform(base2k, &mut source);
 
     let scalar_digest: u64 = scalar.digest_u64();
@@ -157,7 +158,7 @@ where
 
     for a_size in [3] {
         // Create a random input VecZnx
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
 
         let a_digest: u64 = a.digest_u64();
@@ -199,8 +200,8 @@ where
             let res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_idft_apply_consume(res_dft_ref);
             let res_big_test: VecZnxBigOwned<BT> = module_test.vec_znx_idft_apply_consume(res_dft_test);
 
-            let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             for j in 0..cols {
                 module_ref.vec_znx_big_normalize(&mut res_ref, base2k, 0, j, &res_big_ref, base2k, j, scratch_ref.borrow());
@@ -247,7 +248,7 @@ pub fn test_svp_apply_dft_to_dft_assign<BR: Backend, BT: Backend>(
     let mut scratch_ref: ScratchOwned<BR> = ScratchOwned::alloc(module_ref.vec_znx_big_normalize_tmp_bytes());
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
-    let mut scalar: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, cols);
+    let mut scalar: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, cols);
     scalar.fill_uniform(base2k, &mut source);
 
     let scalar_digest: u64 = scalar.digest_u64();
@@ -266,7 +267,7 @@ pub fn test_svp_apply_dft_to_dft_assign<BR: Backend, BT: Backend>(
     let svp_test_digest: u64 = svp_test.digest_u64();
 
     for res_size in [1, 2, 3, 4] {
-        let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+        let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
         res.fill_uniform(base2k, &mut source);
         let res_digest: u64 = res.digest_u64();
 
@@ -292,8 +293,8 @@ pub fn test_svp_apply_dft_to_dft_assign<BR: Backend, BT: Backend>(
         let res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_idft_apply_consume(res_dft_ref);
         let res_big_test: VecZnxBigOwned<BT> = module_test.vec_znx_idft_apply_consume(res_dft_test);
 
-        let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-        let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+        let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+        let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
         for j in 0..cols {
             module_ref.vec_znx_big_normalize(&mut res_ref, base2k, 0, j, &res_big_ref, base2k, j, scratch_ref.borrow());
@@ -13,7 +13,8 @@ use crate::{
         VecZnxSwitchRing,
     },
     layouts::{
-        Backend, DigestU64, FillUniform, Module, NoiseInfos, ScalarZnx, ScratchOwned, VecZnx, ZnxInfos, ZnxView, ZnxViewMut,
+        AlignedBuf, Backend, DigestU64, FillUniform, Module, NoiseInfos, ScalarZnx, ScratchOwned, VecZnx, ZnxInfos, ZnxView,
+        ZnxViewMut,
     },
     source::Source,
 };
@@ -23,7 +24,7 @@ pub fn test_vec_znx_encode_vec_i64() {
     let base2k: usize = 17;
     let size: usize = 5;
     for k in [1, base2k / 2, size * base2k - 5] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, 2, size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, 2, size);
         let mut source = Source::new([0u8; 32]);
         let raw: &mut [i64] = a.raw_mut();
         raw.iter_mut().enumerate().for_each(|(i, x)| *x = i as i64);
@@ -60,18 +61,18 @@ pub fn test_vec_znx_add_scalar_into<BR: Backend, BT: Backend>(
     let mut source: Source = Source::new([0u8; 32]);
     let cols: usize = 2;
 
-    let mut a: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, cols);
+    let mut a: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, cols);
     a.fill_uniform(base2k, &mut source);
     let a_digest = a.digest_u64();
 
     for a_size in [1, 2, 3, 4] {
-        let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         b.fill_uniform(base2k, &mut source);
         let b_digest: u64 = b.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut rest_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut rest_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             // Set d to garbage
             rest_ref.fill_uniform(base2k, &mut source);
@@ -106,13 +107,13 @@ pub fn test_vec_znx_add_scalar_assign<BR: Backend, BT: Backend>(
     let mut source: Source = Source::new([0u8; 32]);
     let cols: usize = 2;
 
-    let mut b: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, cols);
+    let mut b: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, cols);
     b.fill_uniform(base2k, &mut source);
     let b_digest: u64 = b.digest_u64();
 
     for res_size in [1, 2, 3, 4] {
-        let mut rest_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-        let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+        let mut rest_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+        let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
         rest_ref.fill_uniform(base2k, &mut source);
         res_test.raw_mut().copy_from_slice(rest_ref.raw());
@@ -139,20 +140,20 @@ where
     let cols: usize = 2;
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
 
         let a_digest: u64 = a.digest_u64();
 
         for b_size in [1, 2, 3, 4] {
-            let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, b_size);
+            let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, b_size);
             b.fill_uniform(base2k, &mut source);
 
             let b_digest: u64 = b.digest_u64();
 
             for res_size in [1, 2, 3, 4] {
-                let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
                 // Set d to garbage
                 res_ref.fill_uniform(base2k, &mut source);
@@ -186,13 +187,13 @@ where
     let cols: usize = 2;
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest: u64 = a.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             res_ref.fill_uniform(base2k, &mut source);
             res_test.raw_mut().copy_from_slice(res_ref.raw());
@@ -221,13 +222,13 @@ where
     let cols: usize = 2;
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest: u64 = a.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             let p: i64 = -5;
 
@@ -275,8 +276,8 @@ pub fn test_vec_znx_automorphism_assign<BR: Backend, BT: Backend>(
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_automorphism_assign_tmp_bytes());
 
     for size in [1, 2, 3, 4] {
-        let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
-        let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
+        let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
+        let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
 
         // Fill a with random i64
         res_ref.fill_uniform(base2k, &mut source);
@@ -317,13 +318,13 @@ where
     let cols: usize = 2;
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest: u64 = a.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res_0: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_1: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_0: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_1: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             // Set d to garbage
             res_0.fill_uniform(base2k, &mut source);
@@ -358,7 +359,7 @@ where
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_merge_rings_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: [VecZnx<Vec<u8>>; 2] = [VecZnx::alloc(n >> 1, cols, a_size), VecZnx::alloc(n >> 1, cols, a_size)];
+        let mut a: [VecZnx<AlignedBuf>; 2] = [VecZnx::alloc(n >> 1, cols, a_size), VecZnx::alloc(n >> 1, cols, a_size)];
 
         a.iter_mut().for_each(|ai| {
             ai.fill_uniform(base2k, &mut source);
@@ -367,8 +368,8 @@ where
         let a_digests: [u64; 2] = [a[0].digest_u64(), a[1].digest_u64()];
 
         for res_size in [1, 2, 3, 4] {
-            let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             res_ref.fill_uniform(base2k, &mut source);
             res_test.fill_uniform(base2k, &mut source);
@@ -400,14 +401,14 @@ pub fn test_vec_znx_mul_xp_minus_one<BR: Backend, BT: Backend>(
     let cols: usize = 2;
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
 
         let a_digest: u64 = a.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             let p: i64 = -5;
 
@@ -455,8 +456,8 @@ pub fn test_vec_znx_mul_xp_minus_one_assign<BR: Backend, BT: Backend>(
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_mul_xp_minus_one_assign_tmp_bytes());
 
     for size in [1, 2, 3, 4] {
-        let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
-        let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
+        let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
+        let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
 
         // Fill a with random i64
         res_ref.fill_uniform(base2k, &mut source);
@@ -495,13 +496,13 @@ where
     let cols: usize = 2;
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest: u64 = a.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             res_ref.fill_uniform(base2k, &mut source);
             res_test.raw_mut().copy_from_slice(res_ref.raw());
@@ -533,8 +534,8 @@ pub fn test_vec_znx_negate_assign<BR: Backend, BT: Backend>(
     let cols: usize = 2;
 
     for res_size in [1, 2, 3, 4] {
-        let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-        let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+        let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+        let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
         res_ref.fill_uniform(base2k, &mut source);
         res_test.raw_mut().copy_from_slice(res_ref.raw());
@@ -566,13 +567,13 @@ where
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest: u64 = a.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             for res_offset in -(base2k as i64)..=(base2k as i64) {
                 // Set d to garbage
@@ -613,8 +614,8 @@ pub fn test_vec_znx_normalize_assign<BR: Backend, BT: Backend>(
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_normalize_tmp_bytes());
 
     for res_size in [1, 2, 3, 4] {
-        let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-        let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+        let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+        let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
         res_ref.fill_uniform(base2k, &mut source);
         res_test.raw_mut().copy_from_slice(res_ref.raw());
@@ -642,13 +643,13 @@ where
     let cols: usize = 2;
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest: u64 = a.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             let p: i64 = -5;
 
@@ -696,8 +697,8 @@ pub fn test_vec_znx_rotate_assign<BR: Backend, BT: Backend>(
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_rotate_assign_tmp_bytes());
 
     for size in [1, 2, 3, 4] {
-        let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
-        let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, size);
+        let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
+        let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, size);
 
         // Fill a with random i64
         res_ref.fill_uniform(base2k, &mut source);
@@ -832,14 +833,14 @@ where
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_lsh_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest: u64 = a.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
             for k in 0..res_size * base2k {
-                let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
                 // Set d to garbage
                 res_ref.fill_uniform(base2k, &mut source);
@@ -877,8 +878,8 @@ where
 
     for res_size in [1, 2, 3, 4] {
         for k in 0..base2k * res_size {
-            let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             res_ref.fill_uniform(base2k, &mut source);
             res_test.raw_mut().copy_from_slice(res_ref.raw());
@@ -910,14 +911,14 @@ where
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_rsh_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest: u64 = a.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
             for k in 0..res_size * base2k {
-                let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
                 // Set d to garbage
                 res_ref.fill_uniform(base2k, &mut source);
@@ -954,8 +955,8 @@ where
 
     for res_size in [1, 2, 3, 4] {
         for k in 0..base2k * res_size {
-            let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             res_ref.fill_uniform(base2k, &mut source);
             res_test.raw_mut().copy_from_slice(res_ref.raw());
@@ -987,15 +988,15 @@ where
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_split_ring_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest = a.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res_ref: [VecZnx<Vec<u8>>; 2] =
+            let mut res_ref: [VecZnx<AlignedBuf>; 2] =
                 [VecZnx::alloc(n >> 1, cols, res_size), VecZnx::alloc(n >> 1, cols, res_size)];
 
-            let mut res_test: [VecZnx<Vec<u8>>; 2] =
+            let mut res_test: [VecZnx<AlignedBuf>; 2] =
                 [VecZnx::alloc(n >> 1, cols, res_size), VecZnx::alloc(n >> 1, cols, res_size)];
 
             res_ref.iter_mut().for_each(|ri| {
@@ -1032,18 +1033,18 @@ where
     let mut source: Source = Source::new([0u8; 32]);
     let cols: usize = 2;
 
-    let mut a: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, cols);
+    let mut a: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, cols);
     a.fill_uniform(base2k, &mut source);
     let a_digest: u64 = a.digest_u64();
 
     for b_size in [1, 2, 3, 4] {
-        let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, b_size);
+        let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, b_size);
         b.fill_uniform(base2k, &mut source);
         let b_digest: u64 = b.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res_0: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_1: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_0: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_1: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             // Set d to garbage
             res_0.fill_uniform(base2k, &mut source);
@@ -1077,13 +1078,13 @@ pub fn test_vec_znx_sub_scalar_assign<BR: Backend, BT: Backend>(
     let mut source: Source = Source::new([0u8; 32]);
     let cols: usize = 2;
 
-    let mut a: ScalarZnx<Vec<u8>> = ScalarZnx::alloc(n, cols);
+    let mut a: ScalarZnx<AlignedBuf> = ScalarZnx::alloc(n, cols);
     a.fill_uniform(base2k, &mut source);
     let a_digest: u64 = a.digest_u64();
 
     for res_size in [1, 2, 3, 4] {
-        let mut res_0: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-        let mut res_1: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+        let mut res_0: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+        let mut res_1: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
         res_0.fill_uniform(base2k, &mut source);
         res_1.raw_mut().copy_from_slice(res_0.raw());
@@ -1111,18 +1112,18 @@ where
     let cols: usize = 2;
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest: u64 = a.digest_u64();
 
         for b_size in [1, 2, 3, 4] {
-            let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, b_size);
+            let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, b_size);
             b.fill_uniform(base2k, &mut source);
             let b_digest: u64 = b.digest_u64();
 
             for res_size in [1, 2, 3, 4] {
-                let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
                 // Set d to garbage
                 res_ref.fill_uniform(base2k, &mut source);
@@ -1156,13 +1157,13 @@ where
     let cols: usize = 2;
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest: u64 = a.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             res_ref.fill_uniform(base2k, &mut source);
             res_test.raw_mut().copy_from_slice(res_ref.raw());
@@ -1194,13 +1195,13 @@ pub fn test_vec_znx_sub_negate_assign<BR: Backend, BT: Backend>(
     let cols: usize = 2;
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest: u64 = a.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             res_ref.fill_uniform(base2k, &mut source);
             res_test.raw_mut().copy_from_slice(res_ref.raw());
@@ -1229,7 +1230,7 @@ where
     let cols: usize = 2;
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
 
         // Fill a with random i64
         a.fill_uniform(base2k, &mut source);
@@ -1237,8 +1238,8 @@ where
 
         for res_size in [1, 2, 3, 4] {
             {
-                let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n << 1, cols, res_size);
-                let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n << 1, cols, res_size);
+                let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n << 1, cols, res_size);
+                let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n << 1, cols, res_size);
 
                 res_ref.fill_uniform(base2k, &mut source);
                 res_test.fill_uniform(base2k, &mut source);
@@ -1254,8 +1255,8 @@ where
             }
 
             {
-                let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n >> 1, cols, res_size);
-                let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n >> 1, cols, res_size);
+                let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n >> 1, cols, res_size);
+                let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n >> 1, cols, res_size);
 
                 res_ref.fill_uniform(base2k, &mut source);
                 res_test.fill_uniform(base2k, &mut source);
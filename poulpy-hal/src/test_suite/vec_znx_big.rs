@@ -10,7 +10,8 @@ use crate::{
         VecZnxBigSubSmallAssign, VecZnxBigSubSmallB, VecZnxBigSubSmallNegateAssign,
     },
     layouts::{
-        Backend, DataViewMut, DeviceBuf, DigestU64, FillUniform, Module, ScratchOwned, VecZnx, VecZnxBig, ZnxView, ZnxViewMut,
+        AlignedBuf, Backend, DataViewMut, DeviceBuf, DigestU64, FillUniform, Module, ScratchOwned, VecZnx, VecZnxBig, ZnxView,
+        ZnxViewMut,
     },
     source::Source,
 };
@@ -37,7 +38,7 @@ where
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest = a.digest_u64();
 
@@ -55,7 +56,7 @@ where
         let a_test_digest: u64 = a_test.digest_u64();
 
         for b_size in [1, 2, 3, 4] {
-            let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, b_size);
+            let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, b_size);
             b.fill_uniform(base2k, &mut source);
             let b_digest = b.digest_u64();
 
@@ -91,8 +92,8 @@ where
                 assert_eq!(b_ref.digest_u64(), b_ref_digest);
                 assert_eq!(b_test.digest_u64(), b_test_digest);
 
-                let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
                 let res_ref_digest: u64 = res_big_ref.digest_u64();
                 let res_test_digest: u64 = res_big_test.digest_u64();
@@ -158,7 +159,7 @@ pub fn test_vec_znx_big_add_assign<BR: Backend, BT: Backend>(
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
 
         let mut a_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, a_size);
@@ -173,7 +174,7 @@ pub fn test_vec_znx_big_add_assign<BR: Backend, BT: Backend>(
         let a_test_digest: u64 = a_test.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
             res.fill_uniform(base2k, &mut source);
 
             let mut res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, res_size);
@@ -192,8 +193,8 @@ pub fn test_vec_znx_big_add_assign<BR: Backend, BT: Backend>(
             assert_eq!(a_ref.digest_u64(), a_ref_digest);
             assert_eq!(a_test.digest_u64(), a_test_digest);
 
-            let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             let res_ref_digest: u64 = res_big_ref.digest_u64();
             let res_test_digest: u64 = res_big_test.digest_u64();
@@ -258,7 +259,7 @@ pub fn test_vec_znx_big_add_small_into<BR: Backend, BT: Backend>(
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
 
         let mut a_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, a_size);
@@ -273,7 +274,7 @@ pub fn test_vec_znx_big_add_small_into<BR: Backend, BT: Backend>(
         let a_test_digest: u64 = a_test.digest_u64();
 
         for b_size in [1, 2, 3, 4] {
-            let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, b_size);
+            let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, b_size);
             b.fill_uniform(base2k, &mut source);
             let b_digest: u64 = b.digest_u64();
 
@@ -295,8 +296,8 @@ pub fn test_vec_znx_big_add_small_into<BR: Backend, BT: Backend>(
                 assert_eq!(a_test.digest_u64(), a_test_digest);
                 assert_eq!(b.digest_u64(), b_digest);
 
-                let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
                 let res_ref_digest: u64 = res_big_ref.digest_u64();
                 let res_test_digest: u64 = res_big_test.digest_u64();
@@ -362,13 +363,13 @@ pub fn test_vec_znx_big_add_small_assign<BR: Backend, BT: Backend>(
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
 
         let a_digest: u64 = a.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
             res.fill_uniform(base2k, &mut source);
 
             let mut res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, res_size);
@@ -386,8 +387,8 @@ pub fn test_vec_znx_big_add_small_assign<BR: Backend, BT: Backend>(
 
             assert_eq!(a.digest_u64(), a_digest);
 
-            let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             let res_ref_digest: u64 = res_big_ref.digest_u64();
             let res_test_digest: u64 = res_big_test.digest_u64();
@@ -452,7 +453,7 @@ pub fn test_vec_znx_big_automorphism<BR: Backend, BT: Backend>(
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
 
         let mut a_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, a_size);
@@ -484,8 +485,8 @@ pub fn test_vec_znx_big_automorphism<BR: Backend, BT: Backend>(
                 assert_eq!(a_ref.digest_u64(), a_ref_digest);
                 assert_eq!(a_test.digest_u64(), a_test_digest);
 
-                let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
                 let res_ref_digest: u64 = res_big_ref.digest_u64();
                 let res_test_digest: u64 = res_big_test.digest_u64();
@@ -557,7 +558,7 @@ pub fn test_vec_znx_big_automorphism_assign<BR: Backend, BT: Backend>(
     );
 
     for res_size in [1, 2, 3, 4] {
-        let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+        let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
         res.fill_uniform(base2k, &mut source);
 
         let mut res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, res_size);
@@ -574,8 +575,8 @@ pub fn test_vec_znx_big_automorphism_assign<BR: Backend, BT: Backend>(
                 module_test.vec_znx_big_automorphism_assign(p, &mut res_big_test, i, scratch_test.borrow());
             }
 
-            let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             let res_ref_digest: u64 = res_big_ref.digest_u64();
             let res_test_digest: u64 = res_big_test.digest_u64();
@@ -631,7 +632,7 @@ where
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
 
         let mut a_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, a_size);
@@ -662,8 +663,8 @@ where
             assert_eq!(a_ref.digest_u64(), a_ref_digest);
             assert_eq!(a_test.digest_u64(), a_test_digest);
 
-            let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             let res_ref_digest: u64 = res_big_ref.digest_u64();
             let res_test_digest: u64 = res_big_test.digest_u64();
@@ -730,7 +731,7 @@ pub fn test_vec_znx_big_negate_assign<BR: Backend, BT: Backend>(
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for res_size in [1, 2, 3, 4] {
-        let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+        let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
         res.fill_uniform(base2k, &mut source);
 
         let mut res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, res_size);
@@ -746,8 +747,8 @@ pub fn test_vec_znx_big_negate_assign<BR: Backend, BT: Backend>(
             module_test.vec_znx_big_negate_assign(&mut res_big_test, i);
         }
 
-        let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-        let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+        let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+        let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
         let res_ref_digest: u64 = res_big_ref.digest_u64();
         let res_test_digest: u64 = res_big_test.digest_u64();
@@ -815,7 +816,7 @@ pub fn test_vec_znx_big_normalize<BR: Backend, BT: Backend>(
     );
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(63, &mut source);
 
         let mut a_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, a_size);
@@ -830,8 +831,8 @@ pub fn test_vec_znx_big_normalize<BR: Backend, BT: Backend>(
         let a_test_digest: u64 = a_test.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             for res_offset in -(base2k as i64)..=(base2k as i64) {
                 // Set d to garbage
@@ -892,7 +893,7 @@ pub fn test_vec_znx_big_normalize_fused<BR: Backend, BT: Backend>(
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(63, &mut source);
 
         let mut a_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, a_size);
@@ -904,13 +905,13 @@ pub fn test_vec_znx_big_normalize_fused<BR: Backend, BT: Backend>(
 
         for res_size in [1, 2, 3, 4] {
             for res_offset in -(base2k as i64)..=(base2k as i64) {
-                let mut base_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut base_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut base_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut base_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
                 base_ref.fill_uniform(base2k, &mut source);
                 base_test.data_mut().copy_from_slice(&base_ref.data);
 
-                let mut normalized_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut normalized_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut normalized_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut normalized_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
                 module_ref.vec_znx_big_normalize_into(
                     &mut normalized_ref,
                     base2k,
@@ -1047,7 +1048,7 @@ where
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
 
         let mut a_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, a_size);
@@ -1062,7 +1063,7 @@ where
         let a_test_digest: u64 = a_test.digest_u64();
 
         for b_size in [1, 2, 3, 4] {
-            let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, b_size);
+            let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, b_size);
             b.fill_uniform(base2k, &mut source);
 
             let mut b_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, b_size);
@@ -1095,8 +1096,8 @@ where
                 assert_eq!(b_ref.digest_u64(), b_ref_digest);
                 assert_eq!(b_test.digest_u64(), b_test_digest);
 
-                let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
                 let res_ref_digest: u64 = res_big_ref.digest_u64();
                 let res_test_digest: u64 = res_big_test.digest_u64();
@@ -1162,7 +1163,7 @@ pub fn test_vec_znx_big_sub_assign<BR: Backend, BT: Backend>(
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
 
         let mut a_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, a_size);
@@ -1177,7 +1178,7 @@ pub fn test_vec_znx_big_sub_assign<BR: Backend, BT: Backend>(
         let a_test_digest: u64 = a_test.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
             res.fill_uniform(base2k, &mut source);
 
             let mut res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, res_size);
@@ -1196,8 +1197,8 @@ pub fn test_vec_znx_big_sub_assign<BR: Backend, BT: Backend>(
             assert_eq!(a_ref.digest_u64(), a_ref_digest);
             assert_eq!(a_test.digest_u64(), a_test_digest);
 
-            let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             let res_ref_digest: u64 = res_big_ref.digest_u64();
             let res_test_digest: u64 = res_big_test.digest_u64();
@@ -1262,7 +1263,7 @@ pub fn test_vec_znx_big_sub_negate_assign<BR: Backend, BT: Backend>(
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
 
         let mut a_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, a_size);
@@ -1277,7 +1278,7 @@ pub fn test_vec_znx_big_sub_negate_assign<BR: Backend, BT: Backend>(
         let a_test_digest: u64 = a_test.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
             res.fill_uniform(base2k, &mut source);
 
             let mut res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, res_size);
@@ -1296,8 +1297,8 @@ pub fn test_vec_znx_big_sub_negate_assign<BR: Backend, BT: Backend>(
             assert_eq!(a_ref.digest_u64(), a_ref_digest);
             assert_eq!(a_test.digest_u64(), a_test_digest);
 
-            let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             let res_ref_digest: u64 = res_big_ref.digest_u64();
             let res_test_digest: u64 = res_big_test.digest_u64();
@@ -1362,7 +1363,7 @@ pub fn test_vec_znx_big_sub_small_a<BR: Backend, BT: Backend>(
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
 
         let mut a_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, a_size);
@@ -1377,7 +1378,7 @@ pub fn test_vec_znx_big_sub_small_a<BR: Backend, BT: Backend>(
         let a_test_digest: u64 = a_test.digest_u64();
 
         for b_size in [1, 2, 3, 4] {
-            let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, b_size);
+            let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, b_size);
             b.fill_uniform(base2k, &mut source);
             let b_digest: u64 = b.digest_u64();
 
@@ -1399,8 +1400,8 @@ pub fn test_vec_znx_big_sub_small_a<BR: Backend, BT: Backend>(
                 assert_eq!(a_test.digest_u64(), a_test_digest);
                 assert_eq!(b.digest_u64(), b_digest);
 
-                let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
                 let res_ref_digest: u64 = res_big_ref.digest_u64();
                 let res_test_digest: u64 = res_big_test.digest_u64();
@@ -1466,7 +1467,7 @@ pub fn test_vec_znx_big_sub_small_b<BR: Backend, BT: Backend>(
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
 
         let mut a_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, a_size);
@@ -1481,7 +1482,7 @@ pub fn test_vec_znx_big_sub_small_b<BR: Backend, BT: Backend>(
         let a_test_digest: u64 = a_test.digest_u64();
 
         for b_size in [1, 2, 3, 4] {
-            let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, b_size);
+            let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, b_size);
             b.fill_uniform(base2k, &mut source);
             let b_digest: u64 = b.digest_u64();
 
@@ -1503,8 +1504,8 @@ pub fn test_vec_znx_big_sub_small_b<BR: Backend, BT: Backend>(
                 assert_eq!(a_test.digest_u64(), a_test_digest);
                 assert_eq!(b.digest_u64(), b_digest);
 
-                let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
                 let res_ref_digest: u64 = res_big_ref.digest_u64();
                 let res_test_digest: u64 = res_big_test.digest_u64();
@@ -1570,13 +1571,13 @@ pub fn test_vec_znx_big_sub_small_a_assign<BR: Backend, BT: Backend>(
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
 
         let a_digest: u64 = a.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
             res.fill_uniform(base2k, &mut source);
 
             let mut res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, res_size);
@@ -1594,8 +1595,8 @@ pub fn test_vec_znx_big_sub_small_a_assign<BR: Backend, BT: Backend>(
 
             assert_eq!(a.digest_u64(), a_digest);
 
-            let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             let res_ref_digest: u64 = res_big_ref.digest_u64();
             let res_test_digest: u64 = res_big_test.digest_u64();
@@ -1660,14 +1661,14 @@ pub fn test_vec_znx_big_sub_small_b_assign<BR: Backend, BT: Backend>(
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
 
         let a_digest: u64 = a.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
             for res_offset in -(base2k as i64)..=(base2k as i64) {
-                let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
                 res.fill_uniform(base2k, &mut source);
 
                 let mut res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_big_alloc(cols, res_size);
@@ -1685,8 +1686,8 @@ pub fn test_vec_znx_big_sub_small_b_assign<BR: Backend, BT: Backend>(
 
                 assert_eq!(a.digest_u64(), a_digest);
 
-                let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
                 let res_ref_digest: u64 = res_big_ref.digest_u64();
                 let res_test_digest: u64 = res_big_test.digest_u64();
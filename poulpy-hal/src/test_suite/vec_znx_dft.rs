@@ -7,7 +7,9 @@ use crate::{
         VecZnxDftAddAssign, VecZnxDftAddInto, VecZnxDftAlloc, VecZnxDftApply, VecZnxDftCopy, VecZnxDftSub, VecZnxDftSubAssign,
         VecZnxDftSubNegateAssign, VecZnxIdftApply, VecZnxIdftApplyConsume, VecZnxIdftApplyTmpA, VecZnxIdftApplyTmpBytes,
     },
-    layouts::{Backend, DataViewMut, DeviceBuf, DigestU64, FillUniform, Module, ScratchOwned, VecZnx, VecZnxBig, VecZnxDft},
+    layouts::{
+        AlignedBuf, Backend, DataViewMut, DeviceBuf, DigestU64, FillUniform, Module, ScratchOwned, VecZnx, VecZnxBig, VecZnxDft,
+    },
     source::Source,
 };
 
@@ -42,7 +44,7 @@ where
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest = a.digest_u64();
 
@@ -60,7 +62,7 @@ where
         let a_dft_test_digest: u64 = a_dft_test.digest_u64();
 
         for b_size in [1, 2, 3, 4] {
-            let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, b_size);
+            let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, b_size);
             b.fill_uniform(base2k, &mut source);
             let b_digest: u64 = b.digest_u64();
 
@@ -99,8 +101,8 @@ where
                 let res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_idft_apply_consume(res_dft_ref);
                 let res_big_test: VecZnxBigOwned<BT> = module_test.vec_znx_idft_apply_consume(res_dft_test);
 
-                let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
                 let res_ref_digest: u64 = res_big_ref.digest_u64();
                 let res_test_digest: u64 = res_big_test.digest_u64();
@@ -167,7 +169,7 @@ pub fn test_vec_znx_dft_add_assign<BR: Backend, BT: Backend>(
     let mut scratch_ref: ScratchOwned<BR> = ScratchOwned::alloc(module_ref.vec_znx_big_normalize_tmp_bytes());
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest = a.digest_u64();
 
@@ -185,7 +187,7 @@ pub fn test_vec_znx_dft_add_assign<BR: Backend, BT: Backend>(
         let a_dft_test_digest: u64 = a_dft_test.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+            let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
             res.fill_uniform(base2k, &mut source);
             let res_digest: u64 = res.digest_u64();
 
@@ -211,8 +213,8 @@ pub fn test_vec_znx_dft_add_assign<BR: Backend, BT: Backend>(
             let res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_idft_apply_consume(res_dft_ref);
             let res_big_test: VecZnxBigOwned<BT> = module_test.vec_znx_idft_apply_consume(res_dft_test);
 
-            let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             let res_ref_digest: u64 = res_big_ref.digest_u64();
             let res_test_digest: u64 = res_big_test.digest_u64();
@@ -276,7 +278,7 @@ where
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 6, 11] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest = a.digest_u64();
 
@@ -319,8 +321,8 @@ where
                 let res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_idft_apply_consume(res_dft_ref);
                 let res_big_test: VecZnxBigOwned<BT> = module_test.vec_znx_idft_apply_consume(res_dft_test);
 
-                let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
                 let res_ref_digest: u64 = res_big_ref.digest_u64();
                 let res_test_digest: u64 = res_big_test.digest_u64();
@@ -385,7 +387,7 @@ where
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest: u64 = a.digest_u64();
 
@@ -417,8 +419,8 @@ where
                 assert_eq!(res_dft_ref.digest_u64(), res_dft_ref_digest);
                 assert_eq!(res_dft_test.digest_u64(), rest_dft_test_digest);
 
-                let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
                 let res_ref_digest: u64 = res_big_ref.digest_u64();
                 let res_test_digest: u64 = res_big_test.digest_u64();
@@ -486,7 +488,7 @@ pub fn test_vec_znx_idft_apply_tmpa<BR: Backend, BT: Backend>(
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest: u64 = a.digest_u64();
 
@@ -513,8 +515,8 @@ pub fn test_vec_znx_idft_apply_tmpa<BR: Backend, BT: Backend>(
                     module_test.vec_znx_idft_apply_tmpa(&mut res_big_test, j, &mut res_dft_test, j);
                 }
 
-                let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
                 let res_ref_digest: u64 = res_big_ref.digest_u64();
                 let res_test_digest: u64 = res_big_test.digest_u64();
@@ -584,7 +586,7 @@ pub fn test_vec_znx_idft_apply_consume<BR: Backend, BT: Backend>(
         ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes() | module_test.vec_znx_idft_apply_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest: u64 = a.digest_u64();
 
@@ -606,8 +608,8 @@ pub fn test_vec_znx_idft_apply_consume<BR: Backend, BT: Backend>(
                 let res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_idft_apply_consume(res_dft_ref);
                 let res_big_test: VecZnxBigOwned<BT> = module_test.vec_znx_idft_apply_consume(res_dft_test);
 
-                let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
                 let res_ref_digest: u64 = res_big_ref.digest_u64();
                 let res_test_digest: u64 = res_big_test.digest_u64();
@@ -672,7 +674,7 @@ where
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
 
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest = a.digest_u64();
 
@@ -690,7 +692,7 @@ where
         let a_dft_test_digest: u64 = a_dft_test.digest_u64();
 
         for b_size in [1, 2, 3, 4] {
-            let mut b: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, b_size);
+            let mut b: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, b_size);
             b.fill_uniform(base2k, &mut source);
             let b_digest: u64 = b.digest_u64();
 
@@ -729,8 +731,8 @@ where
                 let res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_idft_apply_consume(res_dft_ref);
                 let res_big_test: VecZnxBigOwned<BT> = module_test.vec_znx_idft_apply_consume(res_dft_test);
 
-                let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-                let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+                let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
                 let res_ref_digest: u64 = res_big_ref.digest_u64();
                 let res_test_digest: u64 = res_big_test.digest_u64();
@@ -797,7 +799,7 @@ pub fn test_vec_znx_dft_sub_assign<BR: Backend, BT: Backend>(
     let mut scratch_ref: ScratchOwned<BR> = ScratchOwned::alloc(module_ref.vec_znx_big_normalize_tmp_bytes());
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest = a.digest_u64();
 
@@ -815,7 +817,7 @@ pub fn test_vec_znx_dft_sub_assign<BR: Backend, BT: Backend>(
         let a_dft_test_digest: u64 = a_dft_test.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+            let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
             res.fill_uniform(base2k, &mut source);
             let res_digest: u64 = res.digest_u64();
 
@@ -841,8 +843,8 @@ pub fn test_vec_znx_dft_sub_assign<BR: Backend, BT: Backend>(
             let res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_idft_apply_consume(res_dft_ref);
             let res_big_test: VecZnxBigOwned<BT> = module_test.vec_znx_idft_apply_consume(res_dft_test);
 
-            let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             let res_ref_digest: u64 = res_big_ref.digest_u64();
             let res_test_digest: u64 = res_big_test.digest_u64();
@@ -908,7 +910,7 @@ pub fn test_vec_znx_dft_sub_negate_assign<BR: Backend, BT: Backend>(
     let mut scratch_ref: ScratchOwned<BR> = ScratchOwned::alloc(module_ref.vec_znx_big_normalize_tmp_bytes());
     let mut scratch_test: ScratchOwned<BT> = ScratchOwned::alloc(module_test.vec_znx_big_normalize_tmp_bytes());
     for a_size in [1, 2, 3, 4] {
-        let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+        let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
         a.fill_uniform(base2k, &mut source);
         let a_digest = a.digest_u64();
 
@@ -926,7 +928,7 @@ pub fn test_vec_znx_dft_sub_negate_assign<BR: Backend, BT: Backend>(
         let a_dft_test_digest: u64 = a_dft_test.digest_u64();
 
         for res_size in [1, 2, 3, 4] {
-            let mut res: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, a_size);
+            let mut res: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, a_size);
             res.fill_uniform(base2k, &mut source);
             let res_digest: u64 = res.digest_u64();
 
@@ -952,8 +954,8 @@ pub fn test_vec_znx_dft_sub_negate_assign<BR: Backend, BT: Backend>(
             let res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_idft_apply_consume(res_dft_ref);
             let res_big_test: VecZnxBigOwned<BT> = module_test.vec_znx_idft_apply_consume(res_dft_test);
 
-            let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
-            let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
+            let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols, res_size);
 
             let res_ref_digest: u64 = res_big_ref.digest_u64();
             let res_test_digest: u64 = res_big_test.digest_u64();
@@ -6,8 +6,8 @@ use crate::{
         VmpPMatAlloc, VmpPrepare, VmpPrepareTmpBytes,
     },
     layouts::{
-        Backend, DataViewMut, DeviceBuf, DigestU64, FillUniform, MatZnx, Module, ScratchOwned, VecZnx, VecZnxBig, VecZnxDft,
-        VmpPMat,
+        AlignedBuf, Backend, DataViewMut, DeviceBuf, DigestU64, FillUniform, MatZnx, Module, ScratchOwned, VecZnx, VecZnxBig,
+        VecZnxDft, VmpPMat,
     },
     source::Source,
 };
@@ -59,11 +59,11 @@ where
                 for size_out in 1..max_size + 1 {
                     let rows: usize = cols_in;
 
-                    let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols_in, size_in);
+                    let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols_in, size_in);
                     a.fill_uniform(base2k, &mut source);
                     let a_digest: u64 = a.digest_u64();
 
-                    let mut mat: MatZnx<Vec<u8>> = MatZnx::alloc(n, rows, cols_in, cols_out, size_out);
+                    let mut mat: MatZnx<AlignedBuf> = MatZnx::alloc(n, rows, cols_in, cols_out, size_out);
                     mat.fill_uniform(base2k, &mut source);
                     let mat_digest: u64 = mat.digest_u64();
 
@@ -89,8 +89,8 @@ where
                     let res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_idft_apply_consume(res_dft_ref);
                     let res_big_test: VecZnxBigOwned<BT> = module_test.vec_znx_idft_apply_consume(res_dft_test);
 
-                    let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols_out, size_out);
-                    let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols_out, size_out);
+                    let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols_out, size_out);
+                    let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols_out, size_out);
 
                     let res_ref_digest: u64 = res_big_ref.digest_u64();
                     let res_test_digest: u64 = res_big_test.digest_u64();
@@ -183,7 +183,7 @@ where
                 for size_out in 1..max_size + 1 {
                     let rows: usize = size_in;
 
-                    let mut a: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols_in, size_in);
+                    let mut a: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols_in, size_in);
                     a.fill_uniform(base2k, &mut source);
                     let a_digest: u64 = a.digest_u64();
 
@@ -197,7 +197,7 @@ where
 
                     assert_eq!(a.digest_u64(), a_digest);
 
-                    let mut mat: MatZnx<Vec<u8>> = MatZnx::alloc(n, rows, cols_in, cols_out, size_out);
+                    let mut mat: MatZnx<AlignedBuf> = MatZnx::alloc(n, rows, cols_in, cols_out, size_out);
                     mat.fill_uniform(base2k, &mut source);
                     let mat_digest: u64 = mat.digest_u64();
 
@@ -221,8 +221,8 @@ where
                     let res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_idft_apply_consume(res_dft_ref);
                     let res_big_test: VecZnxBigOwned<BT> = module_test.vec_znx_idft_apply_consume(res_dft_test);
 
-                    let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols_out, size_out);
-                    let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols_out, size_out);
+                    let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols_out, size_out);
+                    let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols_out, size_out);
 
                     let res_ref_digest: u64 = res_big_ref.digest_u64();
                     let res_test_digest: u64 = res_big_test.digest_u64();
@@ -278,8 +278,8 @@ where
                         let res_big_ref: VecZnxBigOwned<BR> = module_ref.vec_znx_idft_apply_consume(res_dft_ref);
                         let res_big_test: VecZnxBigOwned<BT> = module_test.vec_znx_idft_apply_consume(res_dft_test);
 
-                        let mut res_small_ref: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols_out, size_out);
-                        let mut res_small_test: VecZnx<Vec<u8>> = VecZnx::alloc(n, cols_out, size_out);
+                        let mut res_small_ref: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols_out, size_out);
+                        let mut res_small_test: VecZnx<AlignedBuf> = VecZnx::alloc(n, cols_out, size_out);
 
                         for j in 0..cols_out {
                             module_ref.vec_znx_big_normalize(